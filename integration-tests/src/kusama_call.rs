@@ -17,6 +17,7 @@
 //!
 use crate::{kusama_test_net::*, setup::*};
 use frame_support::{assert_ok, storage::with_transaction};
+use pallet_liquid_staking::types::UnstakeProvider;
 use primitives::AccountId;
 use sp_runtime::{DispatchResult, TransactionOutcome};
 use xcm_emulator::TestExt;
@@ -61,3 +62,58 @@ fn liquidate_staking_call_should_work() {
         );
     })
 }
+
+#[test]
+/// Test liquidate_staking unstake, the unbonded amount is unlocking on relaychain.
+fn liquidate_staking_unstake_call_should_work() {
+    let sovereign_sub_account: AccountId =
+        hex_literal::hex!["5d199b535508990c59f411757617904ce65c905fced6878bacfbf26d3b4a1e97"]
+            .into();
+    let mut unbonding = 0;
+    Heiko::execute_with(|| {
+        use heiko_runtime::{LiquidStaking, RuntimeOrigin};
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(AccountId::from(ALICE)),
+            ksm(10f64)
+        ));
+        assert_ok!(with_transaction(
+            || -> TransactionOutcome<DispatchResult> {
+                assert_ok!(LiquidStaking::do_advance_era(1));
+                assert_ok!(LiquidStaking::do_matching());
+                TransactionOutcome::Commit(Ok(()))
+            }
+        ));
+    });
+
+    Heiko::execute_with(|| {
+        use heiko_runtime::{LiquidStaking, RuntimeOrigin};
+        // bond was confirmed by relaychain's response
+        assert!(LiquidStaking::staking_ledger(0).is_some());
+        assert_ok!(LiquidStaking::unstake(
+            RuntimeOrigin::signed(AccountId::from(ALICE)),
+            ksm(1f64),
+            UnstakeProvider::RelayChain
+        ));
+        unbonding = LiquidStaking::unlockings(AccountId::from(ALICE)).unwrap()[0].value;
+        assert_ok!(with_transaction(
+            || -> TransactionOutcome<DispatchResult> {
+                assert_ok!(LiquidStaking::do_advance_era(1));
+                assert_ok!(LiquidStaking::do_matching());
+                TransactionOutcome::Commit(Ok(()))
+            }
+        ));
+    });
+
+    KusamaNet::execute_with(|| {
+        use kusama_runtime::Staking;
+        let ledger = Staking::ledger(&sovereign_sub_account).unwrap();
+        assert_eq!(ledger.unlocking.len(), 1);
+        assert_eq!(ledger.total - ledger.active, unbonding);
+    });
+
+    Heiko::execute_with(|| {
+        use heiko_runtime::LiquidStaking;
+        let ledger = LiquidStaking::staking_ledger(0).unwrap();
+        assert_eq!(ledger.total - ledger.active, unbonding);
+    });
+}
@@ -0,0 +1,137 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Crowdloan contributions made by heiko on relaychain.
+
+use crate::{kusama_test_net::*, setup::*};
+use cumulus_primitives_core::ParaId;
+use frame_support::assert_ok;
+use pallet_crowdloans::types::ContributionStrategy;
+use polkadot_parachain::primitives::{HeadData, ValidationCode};
+use primitives::{tokens::*, AccountId};
+use sp_runtime::{traits::AccountIdConversion, MultiAddress};
+use xcm_emulator::TestExt;
+
+const CROWDLOAN: u32 = 3000;
+const LEASE_START: u32 = 0;
+const LEASE_END: u32 = 7;
+
+/// Create a vault for `CROWDLOAN` on heiko and open it.
+fn create_vault() {
+    use heiko_runtime::{Assets, Crowdloans, RuntimeOrigin};
+    assert_ok!(Assets::force_create(
+        RuntimeOrigin::root(),
+        CKSM_20_27,
+        MultiAddress::Id(Crowdloans::account_id()),
+        true,
+        1,
+    ));
+    assert_ok!(Crowdloans::create_vault(
+        RuntimeOrigin::root(),
+        ParaId::from(CROWDLOAN),
+        CKSM_20_27,
+        LEASE_START,
+        LEASE_END,
+        ContributionStrategy::XCM,
+        ksm(1000f64),
+        u32::MAX,
+    ));
+    assert_ok!(Crowdloans::open(
+        RuntimeOrigin::root(),
+        ParaId::from(CROWDLOAN)
+    ));
+}
+
+#[test]
+fn contribute_should_work() {
+    KusamaNet::execute_with(|| {
+        use kusama_runtime::{Crowdloan, Registrar, RuntimeOrigin};
+        assert_ok!(Registrar::force_register(
+            RuntimeOrigin::root(),
+            ALICE.into(),
+            0,
+            ParaId::from(CROWDLOAN),
+            HeadData(vec![1; 10]),
+            ValidationCode(vec![1; 10]),
+        ));
+        assert_ok!(Crowdloan::create(
+            RuntimeOrigin::signed(ALICE.into()),
+            ParaId::from(CROWDLOAN),
+            ksm(1000f64),
+            LEASE_START,
+            LEASE_END,
+            1000,
+            None,
+        ));
+    });
+
+    Heiko::execute_with(|| {
+        use heiko_runtime::{Crowdloans, RuntimeOrigin};
+        create_vault();
+        assert_ok!(Crowdloans::contribute(
+            RuntimeOrigin::signed(ALICE.into()),
+            ParaId::from(CROWDLOAN),
+            ksm(1f64),
+            vec![],
+        ));
+    });
+
+    KusamaNet::execute_with(|| {
+        use kusama_runtime::Crowdloan;
+        assert_eq!(
+            Crowdloan::funds(ParaId::from(CROWDLOAN)).unwrap().raised,
+            ksm(1f64)
+        );
+    });
+
+    Heiko::execute_with(|| {
+        use heiko_runtime::Crowdloans;
+        // contribution was confirmed by relaychain's response
+        let vault = Crowdloans::vaults((ParaId::from(CROWDLOAN), LEASE_START, LEASE_END)).unwrap();
+        assert_eq!(vault.flying, 0);
+        assert_eq!(vault.contributed, ksm(1f64));
+    });
+}
+
+#[test]
+fn contribute_should_be_refunded_if_relaychain_rejects_it() {
+    let mut balance = 0;
+    Heiko::execute_with(|| {
+        use heiko_runtime::{Assets, Crowdloans, RuntimeOrigin};
+        balance = Assets::balance(KSM, &AccountId::from(ALICE));
+        create_vault();
+        // no fund was created on relaychain, the transact fails there
+        assert_ok!(Crowdloans::contribute(
+            RuntimeOrigin::signed(ALICE.into()),
+            ParaId::from(CROWDLOAN),
+            ksm(1f64),
+            vec![],
+        ));
+    });
+
+    KusamaNet::execute_with(|| {
+        use kusama_runtime::Balances;
+        let para_acc: AccountId = ParaId::from(2085).into_account_truncating();
+        // only the xcm fee was withdrawn from heiko's sovereign account
+        assert!(Balances::free_balance(&para_acc) > ksm(99f64));
+    });
+
+    Heiko::execute_with(|| {
+        use heiko_runtime::{Assets, Crowdloans};
+        let vault = Crowdloans::vaults((ParaId::from(CROWDLOAN), LEASE_START, LEASE_END)).unwrap();
+        assert_eq!(vault.flying, 0);
+        assert_eq!(vault.contributed, 0);
+        assert_eq!(Assets::balance(KSM, &AccountId::from(ALICE)), balance);
+    });
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Assets trapped in heiko by failed xcm executions.
+
+use frame_support::assert_ok;
+use heiko_runtime::Assets;
+use primitives::{tokens::*, AccountId};
+use xcm::{latest::prelude::*, VersionedMultiAssets, VersionedMultiLocation};
+use xcm_emulator::TestExt;
+
+use crate::{kusama_test_net::*, setup::*};
+
+// less than the execution fee of a reserve transfer in heiko
+const DUST: u128 = 100_000_000;
+
+fn transfer_dust_from_relay_chain() {
+    KusamaNet::execute_with(|| {
+        assert_ok!(kusama_runtime::XcmPallet::reserve_transfer_assets(
+            kusama_runtime::RuntimeOrigin::signed(ALICE.into()),
+            Box::new(VersionedMultiLocation::V1(X1(Parachain(2085)).into())),
+            Box::new(VersionedMultiLocation::V1(
+                X1(Junction::AccountId32 {
+                    id: BOB,
+                    network: NetworkId::Any
+                })
+                .into()
+            )),
+            Box::new(VersionedMultiAssets::V1((Here, DUST).into())),
+            0,
+        ));
+    });
+}
+
+#[test]
+fn assets_should_be_trapped_if_fees_are_too_low() {
+    transfer_dust_from_relay_chain();
+
+    Heiko::execute_with(|| {
+        use heiko_runtime::{Runtime, XcmHelper};
+        assert_eq!(Assets::balance(KSM, &AccountId::from(BOB)), 0);
+
+        let trapped: Vec<_> = pallet_xcm_helper::TrappedAssets::<Runtime>::iter().collect();
+        assert_eq!(trapped.len(), 1);
+        let (hash, info) = trapped[0].clone();
+        assert_eq!(info.origin, MultiLocation::parent());
+        assert_eq!(
+            info.assets,
+            VersionedMultiAssets::from(MultiAssets::from((MultiLocation::parent(), DUST)))
+        );
+        assert_eq!(info.count, 1);
+        assert_eq!(XcmHelper::trapped_assets(hash), Some(info));
+    });
+
+    // the same assets from the same origin are counted
+    transfer_dust_from_relay_chain();
+
+    Heiko::execute_with(|| {
+        use heiko_runtime::Runtime;
+        let trapped: Vec<_> = pallet_xcm_helper::TrappedAssets::<Runtime>::iter().collect();
+        assert_eq!(trapped.len(), 1);
+        assert_eq!(trapped[0].1.count, 2);
+    });
+}
+
+#[test]
+fn trapped_assets_should_be_swept() {
+    transfer_dust_from_relay_chain();
+
+    Heiko::execute_with(|| {
+        use heiko_runtime::{Runtime, RuntimeOrigin, XcmHelper};
+        let (hash, _) = pallet_xcm_helper::TrappedAssets::<Runtime>::iter()
+            .next()
+            .unwrap();
+        assert_ok!(XcmHelper::sweep_trapped_assets(
+            RuntimeOrigin::root(),
+            hash,
+            Some(Box::new(VersionedMultiLocation::V1(
+                X1(Junction::AccountId32 {
+                    id: BOB,
+                    network: NetworkId::Any
+                })
+                .into()
+            ))),
+        ));
+
+        assert_eq!(XcmHelper::trapped_assets(hash), None);
+        assert_eq!(Assets::balance(KSM, &AccountId::from(BOB)), DUST);
+    });
+}
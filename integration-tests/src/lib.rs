@@ -15,8 +15,10 @@
 #![cfg(test)]
 
 mod kusama_call;
+mod kusama_crowdloan;
 mod kusama_test_net;
 mod kusama_transfer;
+mod kusama_trap;
 mod polkadot_test_net;
 mod polkadot_transfer;
 mod setup;
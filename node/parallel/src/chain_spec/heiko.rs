@@ -14,10 +14,11 @@
 
 use heiko_runtime::{
     opaque::SessionKeys, BalancesConfig, BridgeMembershipConfig, CollatorSelectionConfig,
-    CrowdloansAutomatorsMembershipConfig, DemocracyConfig, GeneralCouncilConfig,
-    GeneralCouncilMembershipConfig, GenesisConfig, LiquidStakingAgentsMembershipConfig,
-    LiquidStakingConfig, OracleMembershipConfig, ParachainInfoConfig, PolkadotXcmConfig,
-    SessionConfig, SystemConfig, TechnicalCommitteeMembershipConfig, VestingConfig, WASM_BINARY,
+    CrowdloansAutomatorsMembershipConfig, DemocracyConfig, DotLiquidStakingConfig,
+    GeneralCouncilConfig, GeneralCouncilMembershipConfig, GenesisConfig,
+    LiquidStakingAgentsMembershipConfig, LiquidStakingConfig, OracleMembershipConfig,
+    ParachainInfoConfig, PolkadotXcmConfig, SessionConfig, SystemConfig,
+    TechnicalCommitteeMembershipConfig, VestingConfig, WASM_BINARY,
 };
 // use heiko_runtime::SudoConfig;
 use primitives::*;
@@ -177,6 +178,10 @@ fn heiko_genesis(
             exchange_rate: Rate::saturating_from_rational(100_u32, 100_u32), // 1
             reserve_factor: Ratio::from_rational(1u32, 10_000u32),           // 0.01%
         },
+        dot_liquid_staking: DotLiquidStakingConfig {
+            exchange_rate: Rate::saturating_from_rational(100_u32, 100_u32), // 1
+            reserve_factor: Ratio::from_rational(1u32, 10_000u32),           // 0.01%
+        },
         democracy: DemocracyConfig::default(),
         general_council: GeneralCouncilConfig::default(),
         general_council_membership: GeneralCouncilMembershipConfig {
@@ -15,7 +15,7 @@
 use kerria_runtime::{
     opaque::SessionKeys, BalancesConfig, BaseFeeConfig, BridgeMembershipConfig,
     CollatorSelectionConfig, CrowdloansAutomatorsMembershipConfig, DemocracyConfig, EVMConfig,
-    GeneralCouncilConfig, GeneralCouncilMembershipConfig, GenesisConfig,
+    EVMDeployersConfig, GeneralCouncilConfig, GeneralCouncilMembershipConfig, GenesisConfig,
     LiquidStakingAgentsMembershipConfig, LiquidStakingConfig, OracleMembershipConfig,
     ParachainInfoConfig, ParallelPrecompilesType, PolkadotXcmConfig, SessionConfig, SudoConfig,
     SystemConfig, TechnicalCommitteeMembershipConfig, VestingConfig, WASM_BINARY,
@@ -259,5 +259,9 @@ fn kerria_genesis(
             sp_runtime::Permill::from_parts(125_000),
         ),
         ethereum: Default::default(),
+        evm_deployers: EVMDeployersConfig {
+            permissionless: true,
+            deployers: vec![],
+        },
     }
 }
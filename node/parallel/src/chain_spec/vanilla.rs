@@ -22,7 +22,7 @@ use std::{collections::BTreeMap, str::FromStr};
 use vanilla_runtime::{
     opaque::SessionKeys, BalancesConfig, BaseFeeConfig, BridgeMembershipConfig,
     CollatorSelectionConfig, CrowdloansAutomatorsMembershipConfig, DemocracyConfig, EVMConfig,
-    GeneralCouncilConfig, GeneralCouncilMembershipConfig, GenesisConfig,
+    EVMDeployersConfig, GeneralCouncilConfig, GeneralCouncilMembershipConfig, GenesisConfig,
     LiquidStakingAgentsMembershipConfig, LiquidStakingConfig, OracleMembershipConfig,
     ParachainInfoConfig, ParallelPrecompilesType, PolkadotXcmConfig, SessionConfig, SudoConfig,
     SystemConfig, TechnicalCommitteeMembershipConfig, VestingConfig, WASM_BINARY,
@@ -144,11 +144,22 @@ pub fn local_development_config(id: ParaId) -> ChainSpec {
 }
 
 pub fn vanilla_dev_config(id: ParaId) -> ChainSpec {
+    dev_config(id, "Vanilla Dev", "vanilla-dev")
+}
+
+/// Development chain of a node built with `fast-runtime`, its eras, sessions,
+/// governance and lease periods only last a few blocks
+#[cfg(feature = "fast-runtime")]
+pub fn vanilla_fast_dev_config(id: ParaId) -> ChainSpec {
+    dev_config(id, "Vanilla Fast Dev", "vanilla-fast-dev")
+}
+
+fn dev_config(id: ParaId, name: &str, chain_id: &str) -> ChainSpec {
     ChainSpec::from_genesis(
         // Name
-        "Vanilla Dev",
+        name,
         // ID
-        "vanilla-dev",
+        chain_id,
         ChainType::Development,
         move || {
             let root_key = get_account_id_from_seed::<sr25519::Public>("Dave");
@@ -191,7 +202,7 @@ pub fn vanilla_dev_config(id: ParaId) -> ChainSpec {
         },
         vec![],
         TelemetryEndpoints::new(vec![(TELEMETRY_URL.into(), 0)]).ok(),
-        Some("vanilla-dev"),
+        Some(chain_id),
         None,
         Some(as_properties(NetworkType::Heiko)),
         Extensions {
@@ -333,5 +344,9 @@ fn vanilla_genesis(
             sp_runtime::Permill::from_parts(125_000),
         ),
         ethereum: Default::default(),
+        evm_deployers: EVMDeployersConfig {
+            permissionless: true,
+            deployers: vec![],
+        },
     }
 }
@@ -54,6 +54,10 @@ pub enum Subcommand {
     /// Export the state of a given block into a chain spec.
     ExportState(sc_cli::ExportStateCmd),
 
+    /// Export the loans, amm, liquid staking and crowdloans state of a given block.
+    #[clap(name = "export-defi-state")]
+    ExportDefiState(crate::defi_state::ExportDefiStateCmd),
+
     /// Import blocks.
     ImportBlocks(sc_cli::ImportBlocksCmd),
 
@@ -81,11 +85,54 @@ pub struct Cli {
     #[clap(flatten)]
     pub run: cumulus_client_cli::RunCmd,
 
+    #[cfg(feature = "with-evm-runtime")]
+    #[clap(flatten)]
+    pub evm_tracing: EvmTracingConfig,
+
     /// Relaychain arguments
     #[clap(raw = true)]
     pub relaychain_args: Vec<String>,
 }
 
+/// Ethereum RPC extensions served on top of the default eth api.
+#[cfg(feature = "with-evm-runtime")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum EthApi {
+    Debug,
+    Trace,
+}
+
+#[cfg(feature = "with-evm-runtime")]
+#[derive(Debug, Clone, clap::Args)]
+pub struct EvmTracingConfig {
+    /// Enable EVM tracing rpc modules, i.e. `--ethapi=debug,trace`.
+    #[clap(long, value_delimiter = ',')]
+    pub ethapi: Vec<EthApi>,
+
+    /// Number of concurrent tracing tasks, shared by debug and trace modules.
+    #[clap(long, default_value = "10")]
+    pub ethapi_max_permits: u32,
+
+    /// Maximum number of trace entries a single `trace_filter` request can return.
+    #[clap(long, default_value = "500")]
+    pub ethapi_trace_max_count: u32,
+
+    /// Duration (in seconds) after which the cache of `trace_filter` for a given block will be
+    /// discarded.
+    #[clap(long, default_value = "300")]
+    pub ethapi_trace_cache_duration: u64,
+
+    /// Size in bytes of data a raw tracing request is allowed to use.
+    /// Bound the size of memory, stack and storage data.
+    #[clap(long, default_value = "20000000")]
+    pub tracing_raw_max_memory_usage: usize,
+
+    /// Index the logs of the finalized blocks by address and topics, so `eth_getLogs` over
+    /// wide block ranges doesn't have to scan every block.
+    #[clap(long)]
+    pub eth_log_index: bool,
+}
+
 #[derive(Debug)]
 pub struct RelayChainCli {
     /// The actual relay chain cli object.
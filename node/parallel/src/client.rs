@@ -39,6 +39,13 @@ pub trait RuntimeApiCollection:
     + cumulus_primitives_core::CollectCollationInfo<Block>
     + pallet_loans_rpc::LoansRuntimeApi<Block, AccountId, Balance>
     + pallet_router_rpc::RouterRuntimeApi<Block, Balance>
+    + pallet_xcm_helper_rpc::XcmHelperRuntimeApi<Block, Balance>
+    + pallet_rewards_rpc::RewardsRuntimeApi<Block, AccountId, Balance>
+    + pallet_amm_rpc::AMMRuntimeApi<Block, AccountId, Balance>
+    + pallet_portfolio_rpc::PortfolioRuntimeApi<Block, AccountId, Balance>
+    + pallet_bridge_rpc::BridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>
+    + pallet_crowdloans_rpc::CrowdloansRuntimeApi<Block, AccountId, Balance, BlockNumber>
+    + pallet_prices_rpc::PricesRuntimeApi<Block, AccountId, BlockNumber>
 where
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
@@ -58,7 +65,14 @@ where
         + sp_session::SessionKeys<Block>
         + cumulus_primitives_core::CollectCollationInfo<Block>
         + pallet_loans_rpc::LoansRuntimeApi<Block, AccountId, Balance>
-        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>,
+        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>
+        + pallet_xcm_helper_rpc::XcmHelperRuntimeApi<Block, Balance>
+        + pallet_rewards_rpc::RewardsRuntimeApi<Block, AccountId, Balance>
+        + pallet_amm_rpc::AMMRuntimeApi<Block, AccountId, Balance>
+        + pallet_portfolio_rpc::PortfolioRuntimeApi<Block, AccountId, Balance>
+        + pallet_bridge_rpc::BridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_crowdloans_rpc::CrowdloansRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_prices_rpc::PricesRuntimeApi<Block, AccountId, BlockNumber>,
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
 }
@@ -60,6 +60,14 @@ fn load_spec(id: &str) -> std::result::Result<Box<dyn sc_service::ChainSpec>, St
         "vanilla-dev" => Box::new(chain_spec::vanilla::vanilla_dev_config(ParaId::from(
             HEIKO_PARA_ID,
         ))),
+        #[cfg(feature = "fast-runtime")]
+        "vanilla-fast-dev" => Box::new(chain_spec::vanilla::vanilla_fast_dev_config(ParaId::from(
+            HEIKO_PARA_ID,
+        ))),
+        #[cfg(not(feature = "fast-runtime"))]
+        "vanilla-fast-dev" => {
+            return Err("vanilla-fast-dev should be built with fast runtime".into());
+        }
         #[cfg(feature = "with-evm-runtime")]
         "kerria-dev" => Box::new(chain_spec::kerria::kerria_dev_config(ParaId::from(
             PARALLEL_PARA_ID,
@@ -316,6 +324,20 @@ pub fn run() -> Result<()> {
                 })
             })
         }
+        Some(Subcommand::ExportDefiState(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            let chain_spec = &runner.config().chain_spec;
+
+            set_default_ss58_version(chain_spec);
+
+            switch_runtime!(chain_spec, {
+                runner.sync_run(|config| {
+                    let PartialComponents { client, .. } =
+                        crate::service::new_partial::<RuntimeApi, Executor>(&config)?;
+                    cmd.run::<Block, _, _>(client)
+                })
+            })
+        }
         Some(Subcommand::ImportBlocks(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             let chain_spec = &runner.config().chain_spec;
@@ -470,7 +492,7 @@ pub fn run() -> Result<()> {
                     #[cfg(feature = "with-evm-runtime")]
                     {
                         if config.chain_spec.is_dev() {
-                            return crate::evm_service::start_dev_node::<RuntimeApi, Executor>(config).map_err(Into::into);
+                            return crate::evm_service::start_dev_node::<RuntimeApi, Executor>(config, cli.evm_tracing.clone()).map_err(Into::into);
                         }
                     }
 
@@ -526,7 +548,7 @@ pub fn run() -> Result<()> {
 
                     #[cfg(feature = "with-evm-runtime")]
                     {
-                        crate::evm_service::start_node::<RuntimeApi, Executor>(config, polkadot_config, collator_options, id)
+                        crate::evm_service::start_node::<RuntimeApi, Executor>(config, polkadot_config, collator_options, id, cli.evm_tracing.clone())
                             .await
                             .map(|r| r.0)
                             .map_err(Into::into)
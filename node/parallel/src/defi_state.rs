@@ -0,0 +1,219 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `export-defi-state` exports the storage of the DeFi pallets at a block, either as a
+//! raw genesis overlay or as a snapshot mock runtimes can load.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+};
+
+use clap::{Parser, ValueEnum};
+use log::info;
+use parallel_support::snapshot::Snapshot;
+use sc_cli::{BlockNumberOrHash, CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::{StorageProvider, UsageProvider};
+use sp_core::{
+    hashing::twox_128,
+    hexdisplay::HexDisplay,
+    storage::{well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX, ChildInfo, StorageKey},
+};
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, NumberFor},
+};
+
+/// Pallets `export-defi-state` can export
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum DefiPallet {
+    Loans,
+    Amm,
+    LiquidStaking,
+    /// Vaults, along with every default child trie which holds the contributions
+    Crowdloans,
+    /// Ptokens, lp tokens and liquid tokens held by the positions
+    Assets,
+}
+
+impl DefiPallet {
+    const DEFAULT: [DefiPallet; 4] = [
+        DefiPallet::Loans,
+        DefiPallet::Amm,
+        DefiPallet::LiquidStaking,
+        DefiPallet::Crowdloans,
+    ];
+
+    /// Name of the pallet in `construct_runtime!`, the prefix of its storage
+    fn name(&self) -> &'static str {
+        match self {
+            DefiPallet::Loans => "Loans",
+            DefiPallet::Amm => "AMM",
+            DefiPallet::LiquidStaking => "LiquidStaking",
+            DefiPallet::Crowdloans => "Crowdloans",
+            DefiPallet::Assets => "Assets",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// `top` and `childrenDefault` hex maps, as in the raw genesis of a chain spec
+    Json,
+    /// SCALE encoded `parallel_support::snapshot::Snapshot`
+    Scale,
+}
+
+/// Export the storage of the DeFi pallets at a block.
+#[derive(Debug, Clone, Parser)]
+pub struct ExportDefiStateCmd {
+    /// Block hash or number to export the state at, the best block if not given.
+    #[clap(value_name = "HASH or NUMBER")]
+    pub input: Option<BlockNumberOrHash>,
+
+    /// Pallets to export, loans, amm, liquid-staking and crowdloans if not given.
+    #[clap(long, value_enum, value_delimiter = ',')]
+    pub pallets: Vec<DefiPallet>,
+
+    /// Format of the export.
+    #[clap(long, value_enum, default_value = "json")]
+    pub format: ExportFormat,
+
+    /// File to write the export to, stdout if not given.
+    #[clap(long, short)]
+    pub output: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub pruning_params: PruningParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: DatabaseParams,
+}
+
+impl ExportDefiStateCmd {
+    pub fn run<B, BA, C>(&self, client: Arc<C>) -> sc_cli::Result<()>
+    where
+        B: BlockT,
+        B::Hash: FromStr,
+        <B::Hash as FromStr>::Err: Debug,
+        NumberFor<B>: FromStr,
+        <NumberFor<B> as FromStr>::Err: Debug,
+        BA: sc_client_api::Backend<B>,
+        C: UsageProvider<B> + StorageProvider<B, BA>,
+    {
+        let block = match &self.input {
+            Some(input) => input.parse::<B>()?,
+            None => BlockId::Hash(client.usage_info().chain.best_hash),
+        };
+        let pallets = if self.pallets.is_empty() {
+            DefiPallet::DEFAULT.to_vec()
+        } else {
+            self.pallets.clone()
+        };
+        info!("Exporting {:?} state at {:?}...", pallets, block);
+
+        let mut snapshot = Snapshot::default();
+        for pallet in &pallets {
+            let prefix = StorageKey(twox_128(pallet.name().as_bytes()).to_vec());
+            for (key, value) in client.storage_pairs(&block, &prefix)? {
+                snapshot.top.push((key.0, value.0));
+            }
+        }
+
+        // Child tries are keyed by hashes, their owner can't be told from the key
+        if pallets.contains(&DefiPallet::Crowdloans) {
+            let prefix = StorageKey(DEFAULT_CHILD_STORAGE_KEY_PREFIX.to_vec());
+            for key in client.storage_keys(&block, &prefix)? {
+                let storage_key = key.0[DEFAULT_CHILD_STORAGE_KEY_PREFIX.len()..].to_vec();
+                let child_info = ChildInfo::new_default(&storage_key);
+                let mut pairs = Vec::new();
+                for key in client.child_storage_keys(&block, &child_info, &StorageKey(vec![]))? {
+                    if let Some(value) = client.child_storage(&block, &child_info, &key)? {
+                        pairs.push((key.0, value.0));
+                    }
+                }
+                pairs.sort();
+                snapshot.children.push((storage_key, pairs));
+            }
+        }
+        snapshot.top.sort();
+        snapshot.children.sort();
+
+        info!(
+            "Exported {} keys and {} child tries",
+            snapshot.top.len(),
+            snapshot.children.len()
+        );
+
+        let bytes = match self.format {
+            ExportFormat::Json => serde_json::to_vec_pretty(&genesis_overlay(&snapshot))
+                .map_err(|e| format!("failed to serialize the export: {}", e))?,
+            ExportFormat::Scale => snapshot.to_bytes(),
+        };
+        match &self.output {
+            Some(path) => fs::write(path, bytes)?,
+            None => io::stdout().write_all(&bytes)?,
+        }
+
+        Ok(())
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    format!("0x{}", HexDisplay::from(&bytes))
+}
+
+/// The snapshot in the layout of `genesis.raw`, so that it can be merged into a chain spec
+fn genesis_overlay(snapshot: &Snapshot) -> serde_json::Value {
+    let pairs = |pairs: &[(Vec<u8>, Vec<u8>)]| {
+        pairs
+            .iter()
+            .map(|(k, v)| (hex(k), hex(v)))
+            .collect::<BTreeMap<_, _>>()
+    };
+
+    serde_json::json!({
+        "top": pairs(&snapshot.top),
+        "childrenDefault": snapshot
+            .children
+            .iter()
+            .map(|(storage_key, child)| (hex(storage_key), pairs(child)))
+            .collect::<BTreeMap<_, _>>(),
+    })
+}
+
+impl CliConfiguration for ExportDefiStateCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+
+    fn database_params(&self) -> Option<&DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `eth_estimateGas` checked against a plain simulation of the call.
+//!
+//! Precompiles dispatching runtime calls require the pre-dispatch weight of the call to be
+//! available as gas, but only charge the weight it actually used. The estimate frontier returns
+//! may therefore be enough to pay for the call while being too low to let it be dispatched, so
+//! the estimate is simulated through the runtime api, outside of estimate mode, and bisected up
+//! to the gas cap when the call doesn't go through.
+
+use std::sync::Arc;
+
+use fc_rpc::{internal_err, EthApiServer};
+use fc_rpc_core::types::{BlockNumber as EthBlockNumber, CallRequest};
+use fp_evm::ExitReason;
+use fp_rpc::EthereumRuntimeRPCApi;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+};
+use primitives::*;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::U256;
+use sp_runtime::generic::BlockId;
+
+const LOG_TARGET: &str = "evm-estimate";
+
+/// The bisection stops once the gas range is narrower than `1 / ACCURACY` of its upper bound
+const ACCURACY: u64 = 1_000;
+
+/// `eth_estimateGas` simulating the call at the estimated gas limit
+#[rpc(server)]
+pub trait DispatchAwareEstimateApi {
+    /// Estimate gas needed for execution of given contract.
+    #[method(name = "eth_estimateGas")]
+    async fn estimate_gas(
+        &self,
+        request: CallRequest,
+        number: Option<EthBlockNumber>,
+    ) -> RpcResult<U256>;
+}
+
+/// Checks the estimates of the frontier eth api by simulating the call with them
+pub struct DispatchAwareEstimate<C, E> {
+    client: Arc<C>,
+    eth: E,
+}
+
+impl<C, E> DispatchAwareEstimate<C, E>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: EthereumRuntimeRPCApi<Block>,
+{
+    pub fn new(client: Arc<C>, eth: E) -> Self {
+        Self { client, eth }
+    }
+
+    /// Block the call is simulated at, `None` if it can't be resolved from its number
+    fn block_id(&self, number: &Option<EthBlockNumber>) -> RpcResult<Option<BlockId<Block>>> {
+        let number = match number {
+            None | Some(EthBlockNumber::Latest) | Some(EthBlockNumber::Pending) => {
+                return Ok(Some(BlockId::Hash(self.client.info().best_hash)))
+            }
+            Some(EthBlockNumber::Earliest) => 0,
+            Some(EthBlockNumber::Num(n)) => *n as BlockNumber,
+            _ => return Ok(None),
+        };
+        Ok(self
+            .client
+            .hash(number)
+            .map_err(|e| internal_err(format!("{:?}", e)))?
+            .map(BlockId::Hash))
+    }
+
+    /// Whether the call succeeds with `gas_limit` when it's actually executed
+    fn succeeds_with(
+        &self,
+        id: &BlockId<Block>,
+        request: &CallRequest,
+        gas_limit: U256,
+    ) -> RpcResult<bool> {
+        let to = match request.to {
+            Some(to) => to,
+            None => return Ok(true),
+        };
+        let info = self
+            .client
+            .runtime_api()
+            .call(
+                id,
+                request.from.unwrap_or_default(),
+                to,
+                request.data.clone().map(|data| data.0).unwrap_or_default(),
+                request.value.unwrap_or_default(),
+                gas_limit,
+                request.max_fee_per_gas.or(request.gas_price),
+                request.max_priority_fee_per_gas,
+                request.nonce,
+                false,
+                request.access_list.clone().map(|list| {
+                    list.into_iter()
+                        .map(|item| (item.address, item.storage_keys))
+                        .collect()
+                }),
+            )
+            .map_err(|e| internal_err(format!("runtime error: {:?}", e)))?
+            .map_err(|e| internal_err(format!("execution fatal: {:?}", e)))?;
+
+        Ok(matches!(info.exit_reason, ExitReason::Succeed(_)))
+    }
+
+    /// Gas cap of the call, the block gas limit unless the request sets a lower one
+    fn gas_cap(&self, id: &BlockId<Block>, request: &CallRequest) -> RpcResult<U256> {
+        let block_gas_limit = self
+            .client
+            .runtime_api()
+            .current_block(id)
+            .map_err(|e| internal_err(format!("runtime error: {:?}", e)))?
+            .map(|block| block.header.gas_limit)
+            .ok_or_else(|| internal_err("block unavailable, cannot query gas limit"))?;
+
+        Ok(request
+            .gas
+            .map_or(block_gas_limit, |gas| gas.min(block_gas_limit)))
+    }
+}
+
+/// Narrows `(lowest, highest]` down to the lowest gas limit `succeeds` with, given it fails with
+/// `lowest` and succeeds with `highest`
+fn bisect(
+    mut lowest: U256,
+    mut highest: U256,
+    mut succeeds: impl FnMut(U256) -> RpcResult<bool>,
+) -> RpcResult<U256> {
+    while highest - lowest > highest / ACCURACY {
+        let mid = lowest + (highest - lowest) / 2;
+        if succeeds(mid)? {
+            highest = mid;
+        } else {
+            lowest = mid;
+        }
+    }
+    Ok(highest)
+}
+
+#[async_trait]
+impl<C, E> DispatchAwareEstimateApiServer for DispatchAwareEstimate<C, E>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: EthereumRuntimeRPCApi<Block>,
+    E: EthApiServer + Send + Sync + 'static,
+{
+    async fn estimate_gas(
+        &self,
+        request: CallRequest,
+        number: Option<EthBlockNumber>,
+    ) -> RpcResult<U256> {
+        let id = self.block_id(&number)?;
+        let estimate = self.eth.estimate_gas(request.clone(), number).await?;
+        let id = match id {
+            Some(id) if request.to.is_some() => id,
+            _ => return Ok(estimate),
+        };
+        if self.succeeds_with(&id, &request, estimate)? {
+            return Ok(estimate);
+        }
+
+        // The call only failed because of the gas limit if it succeeds with the whole cap,
+        // otherwise the frontier estimate is as good as any
+        let cap = self.gas_cap(&id, &request)?;
+        if cap <= estimate || !self.succeeds_with(&id, &request, cap)? {
+            return Ok(estimate);
+        }
+        let highest = bisect(estimate, cap, |gas_limit| {
+            self.succeeds_with(&id, &request, gas_limit)
+        })?;
+
+        log::debug!(
+            target: LOG_TARGET,
+            "estimate raised from {} to {} to dispatch the call",
+            estimate,
+            highest,
+        );
+        Ok(highest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bisection_should_converge_within_accuracy() {
+        let required = U256::from(187_654u64);
+        let mut attempts = 0;
+        let estimate = bisect(21_000u64.into(), 15_000_000u64.into(), |gas_limit| {
+            attempts += 1;
+            Ok(gas_limit >= required)
+        })
+        .unwrap();
+
+        assert!(estimate >= required);
+        assert!(estimate - required <= estimate / ACCURACY);
+        assert!(attempts < 24);
+    }
+
+    #[test]
+    fn bisection_should_keep_the_upper_bound_if_nothing_lower_succeeds() {
+        let estimate = bisect(21_000u64.into(), 50_000u64.into(), |gas_limit| {
+            Ok(gas_limit >= 50_000u64.into())
+        })
+        .unwrap();
+
+        assert_eq!(estimate, 50_000u64.into());
+    }
+}
@@ -0,0 +1,423 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Index of the EVM logs by address and topics, so `eth_getLogs` over wide ranges only visits
+//! the blocks which emitted matching logs instead of scanning every block of the range.
+//!
+//! The index lives in the aux columns of the client database. Each entry is keyed by an
+//! address, or by a topic at a given position, and a bucket of `BUCKET_SIZE` blocks; it holds
+//! the blocks of the bucket which emitted a log matching the key. Only finalized blocks are
+//! indexed so the entries never have to be reverted, logs of the later blocks are scanned as
+//! before.
+
+use std::{collections::BTreeSet, sync::Arc};
+
+use codec::{Decode, Encode};
+use fc_rpc::{frontier_backend_client, internal_err, EthFilterApiServer, OverrideHandle};
+use fc_rpc_core::types::{BlockNumber as EthBlockNumber, Filter, Log, VariadicValue};
+use futures::StreamExt;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+};
+use primitives::*;
+use sc_client_api::{AuxStore, Backend, BlockchainEvents, StateBackend, StorageProvider};
+use sp_blockchain::HeaderBackend;
+use sp_core::{H160, H256};
+use sp_runtime::{generic::BlockId, traits::BlakeTwo256};
+
+const LOG_TARGET: &str = "evm-log-index";
+
+/// Prefix of the index entries in the aux columns
+const INDEX_PREFIX: &[u8] = b"evm-log-index:";
+/// Aux key of the last block indexed, all the blocks up to it are indexed
+const INDEXED_TO_KEY: &[u8] = b"evm-log-index:indexed-to";
+/// Number of blocks sharing an index entry
+const BUCKET_SIZE: BlockNumber = 2048;
+/// Number of topics a log can have
+const MAX_TOPICS: usize = 4;
+
+/// What an index entry is keyed by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum IndexKey {
+    Address(H160),
+    /// Topic at the given position of the logs
+    Topic(u8, H256),
+}
+
+impl IndexKey {
+    fn aux_key(&self, bucket: BlockNumber) -> Vec<u8> {
+        let mut key = INDEX_PREFIX.to_vec();
+        match self {
+            IndexKey::Address(address) => {
+                key.push(u8::MAX);
+                key.extend_from_slice(address.as_bytes());
+            }
+            IndexKey::Topic(position, topic) => {
+                key.push(*position);
+                key.extend_from_slice(topic.as_bytes());
+            }
+        }
+        key.extend_from_slice(&bucket.to_be_bytes());
+        key
+    }
+}
+
+/// Keys a log must match one of, for each address or topic constrained by `filter`
+fn filter_constraints(filter: &Filter) -> Vec<Vec<IndexKey>> {
+    let mut constraints = Vec::new();
+    match &filter.address {
+        Some(VariadicValue::Single(address)) => constraints.push(vec![IndexKey::Address(*address)]),
+        Some(VariadicValue::Multiple(addresses)) if !addresses.is_empty() => {
+            constraints.push(addresses.iter().map(|a| IndexKey::Address(*a)).collect())
+        }
+        _ => {}
+    }
+
+    let positions = match &filter.topics {
+        Some(VariadicValue::Single(topic)) => vec![topic.clone()],
+        Some(VariadicValue::Multiple(topics)) => topics.clone(),
+        _ => vec![],
+    };
+    for (position, topics) in positions.into_iter().take(MAX_TOPICS).enumerate() {
+        let topics = match topics {
+            Some(VariadicValue::Single(Some(topic))) => vec![topic],
+            Some(VariadicValue::Multiple(topics)) if !topics.is_empty() => {
+                // A null alternative matches any topic
+                match topics.into_iter().collect::<Option<Vec<_>>>() {
+                    Some(topics) => topics,
+                    None => continue,
+                }
+            }
+            _ => continue,
+        };
+        constraints.push(
+            topics
+                .into_iter()
+                .map(|t| IndexKey::Topic(position as u8, t))
+                .collect(),
+        );
+    }
+
+    constraints
+}
+
+/// Index of the logs of the finalized blocks, see the module docs
+pub struct LogIndex<C> {
+    client: Arc<C>,
+}
+
+impl<C: AuxStore> LogIndex<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+
+    /// The last block indexed, `None` until the genesis is indexed
+    pub fn indexed_to(&self) -> Option<BlockNumber> {
+        self.get(INDEXED_TO_KEY)
+    }
+
+    fn get<T: Decode>(&self, key: &[u8]) -> Option<T> {
+        self.client
+            .get_aux(key)
+            .ok()
+            .flatten()
+            .and_then(|value| T::decode(&mut &value[..]).ok())
+    }
+
+    fn blocks(&self, key: &IndexKey, bucket: BlockNumber) -> Vec<BlockNumber> {
+        self.get(&key.aux_key(bucket)).unwrap_or_default()
+    }
+
+    /// Record the addresses and topics of the logs emitted by block `number`
+    fn index_block<'a>(
+        &self,
+        number: BlockNumber,
+        logs: impl Iterator<Item = (&'a H160, &'a [H256])>,
+    ) -> sp_blockchain::Result<()> {
+        let mut keys = BTreeSet::new();
+        for (address, topics) in logs {
+            keys.insert(IndexKey::Address(*address));
+            for (position, topic) in topics.iter().take(MAX_TOPICS).enumerate() {
+                keys.insert(IndexKey::Topic(position as u8, *topic));
+            }
+        }
+
+        let bucket = number / BUCKET_SIZE;
+        let mut entries: Vec<(Vec<u8>, Vec<u8>)> = keys
+            .into_iter()
+            .map(|key| {
+                let mut blocks = self.blocks(&key, bucket);
+                // The block may be indexed again if the node stopped before recording it
+                if blocks.last() != Some(&number) {
+                    blocks.push(number);
+                }
+                (key.aux_key(bucket), blocks.encode())
+            })
+            .collect();
+        entries.push((INDEXED_TO_KEY.to_vec(), number.encode()));
+
+        let insert: Vec<(&[u8], &[u8])> = entries
+            .iter()
+            .map(|(key, value)| (&key[..], &value[..]))
+            .collect();
+        self.client.insert_aux(&insert, &[])
+    }
+
+    /// Blocks of `from..=to` which emitted logs matching `filter`, `None` if the filter
+    /// constrains neither the address nor the topics
+    fn candidate_blocks(
+        &self,
+        filter: &Filter,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> Option<BTreeSet<BlockNumber>> {
+        let mut candidates: Option<BTreeSet<BlockNumber>> = None;
+        for keys in filter_constraints(filter) {
+            let mut matching = BTreeSet::new();
+            for key in keys.iter() {
+                for bucket in from / BUCKET_SIZE..=to / BUCKET_SIZE {
+                    matching.extend(
+                        self.blocks(key, bucket)
+                            .into_iter()
+                            .filter(|n| (from..=to).contains(n)),
+                    );
+                }
+            }
+            candidates = Some(match candidates {
+                Some(candidates) => candidates.intersection(&matching).copied().collect(),
+                None => matching,
+            });
+        }
+        candidates
+    }
+}
+
+/// Keeps indexing the logs of the blocks as they are finalized, starting with the blocks
+/// finalized before the index was enabled
+pub async fn log_index_task<C, BE>(
+    client: Arc<C>,
+    overrides: Arc<OverrideHandle<Block>>,
+    index: Arc<LogIndex<C>>,
+) where
+    C: HeaderBackend<Block> + StorageProvider<Block, BE> + BlockchainEvents<Block> + AuxStore,
+    C: Send + Sync + 'static,
+    BE: Backend<Block> + 'static,
+    BE::State: StateBackend<BlakeTwo256>,
+{
+    let mut finality_notifications = client.finality_notification_stream();
+    loop {
+        let finalized = client.info().finalized_number;
+        let next = index.indexed_to().map_or(0, |n| n + 1);
+        for number in next..=finalized {
+            if let Err(e) = index_block::<C, BE>(&client, &overrides, &index, number) {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Failed to index the logs of block {}: {:?}",
+                    number,
+                    e
+                );
+                break;
+            }
+        }
+
+        if finality_notifications.next().await.is_none() {
+            break;
+        }
+    }
+}
+
+fn index_block<C, BE>(
+    client: &Arc<C>,
+    overrides: &OverrideHandle<Block>,
+    index: &LogIndex<C>,
+    number: BlockNumber,
+) -> sp_blockchain::Result<()>
+where
+    C: HeaderBackend<Block> + StorageProvider<Block, BE> + AuxStore + Send + Sync + 'static,
+    BE: Backend<Block> + 'static,
+    BE::State: StateBackend<BlakeTwo256>,
+{
+    let hash = client.hash(number)?.ok_or_else(|| {
+        sp_blockchain::Error::Backend(format!("Unknown finalized block {}", number))
+    })?;
+    let id = BlockId::Hash(hash);
+    let schema =
+        frontier_backend_client::onchain_storage_schema::<Block, C, BE>(client.as_ref(), id);
+    let statuses = overrides
+        .schemas
+        .get(&schema)
+        .unwrap_or(&overrides.fallback)
+        .current_transaction_statuses(&id)
+        .unwrap_or_default();
+
+    index.index_block(
+        number,
+        statuses
+            .iter()
+            .flat_map(|status| status.logs.iter())
+            .map(|log| (&log.address, &log.topics[..])),
+    )
+}
+
+/// `eth_getLogs` served from the log index over the indexed blocks
+#[rpc(server)]
+pub trait IndexedLogsApi {
+    /// Returns logs matching given filter object.
+    #[method(name = "eth_getLogs")]
+    async fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>>;
+}
+
+/// Narrows the range of `eth_getLogs` requests down to the blocks found by the index, and
+/// hands them to the frontier filter api
+pub struct IndexedLogs<C, F> {
+    client: Arc<C>,
+    index: Arc<LogIndex<C>>,
+    eth_filter: F,
+    max_past_logs: u32,
+}
+
+impl<C, F> IndexedLogs<C, F> {
+    pub fn new(client: Arc<C>, index: Arc<LogIndex<C>>, eth_filter: F, max_past_logs: u32) -> Self {
+        Self {
+            client,
+            index,
+            eth_filter,
+            max_past_logs,
+        }
+    }
+
+    fn ensure_within_limit(&self, logs: &[Log]) -> RpcResult<()> {
+        if logs.len() > self.max_past_logs as usize {
+            return Err(internal_err(format!(
+                "query returned more than {} results",
+                self.max_past_logs
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Block number of a range bound, `None` if it has to be resolved by the frontier filter api
+fn range_bound(bound: &Option<EthBlockNumber>, best: BlockNumber) -> Option<BlockNumber> {
+    match bound {
+        Some(EthBlockNumber::Num(n)) => Some((*n).min(best.into()) as BlockNumber),
+        Some(EthBlockNumber::Earliest) => Some(0),
+        None | Some(EthBlockNumber::Latest) | Some(EthBlockNumber::Pending) => Some(best),
+        _ => None,
+    }
+}
+
+/// Consecutive block numbers merged into inclusive ranges
+fn merge_ranges(blocks: BTreeSet<BlockNumber>) -> Vec<(BlockNumber, BlockNumber)> {
+    let mut ranges: Vec<(BlockNumber, BlockNumber)> = Vec::new();
+    for block in blocks {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == block => *end = block,
+            _ => ranges.push((block, block)),
+        }
+    }
+    ranges
+}
+
+#[async_trait]
+impl<C, F> IndexedLogsApiServer for IndexedLogs<C, F>
+where
+    C: HeaderBackend<Block> + AuxStore + Send + Sync + 'static,
+    F: EthFilterApiServer + Send + Sync + 'static,
+{
+    async fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>> {
+        let best = self.client.info().best_number;
+        let bounds = (
+            self.index.indexed_to(),
+            range_bound(&filter.from_block, best),
+            range_bound(&filter.to_block, best),
+        );
+        let (indexed_to, from, to) = match bounds {
+            (Some(indexed_to), Some(from), Some(to))
+                if filter.block_hash.is_none() && from <= to && from <= indexed_to =>
+            {
+                (indexed_to, from, to)
+            }
+            _ => return self.eth_filter.logs(filter).await,
+        };
+        let candidates = match self
+            .index
+            .candidate_blocks(&filter, from, to.min(indexed_to))
+        {
+            Some(candidates) => candidates,
+            None => return self.eth_filter.logs(filter).await,
+        };
+
+        let mut logs = Vec::new();
+        let mut ranges = merge_ranges(candidates);
+        // Blocks finalized after the index was last updated are scanned
+        if to > indexed_to {
+            ranges.push((indexed_to + 1, to));
+        }
+        for (start, end) in ranges {
+            logs.extend(
+                self.eth_filter
+                    .logs(Filter {
+                        from_block: Some(EthBlockNumber::Num(start.into())),
+                        to_block: Some(EthBlockNumber::Num(end.into())),
+                        ..filter.clone()
+                    })
+                    .await?,
+            );
+            self.ensure_within_limit(&logs)?;
+        }
+
+        Ok(logs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_blocks_should_be_merged() {
+        assert_eq!(
+            merge_ranges(BTreeSet::from([1, 2, 3, 7, 9, 10])),
+            vec![(1, 3), (7, 7), (9, 10)]
+        );
+        assert!(merge_ranges(BTreeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn wildcard_topics_should_not_constrain() {
+        let address = H160::repeat_byte(1);
+        let topic = H256::repeat_byte(2);
+        let filter = Filter {
+            from_block: None,
+            to_block: None,
+            block_hash: None,
+            address: Some(VariadicValue::Single(address)),
+            topics: Some(VariadicValue::Multiple(vec![
+                None,
+                Some(VariadicValue::Multiple(vec![Some(topic), None])),
+                Some(VariadicValue::Single(Some(topic))),
+            ])),
+        };
+
+        assert_eq!(
+            filter_constraints(&filter),
+            vec![
+                vec![IndexKey::Address(address)],
+                vec![IndexKey::Topic(2, topic)],
+            ]
+        );
+    }
+}
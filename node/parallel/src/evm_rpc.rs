@@ -45,8 +45,26 @@ use std::sync::Arc;
 use substrate_frame_rpc_system::{System, SystemApiServer};
 
 use orml_oracle_rpc::{Oracle, OracleApiServer};
+use pallet_amm_rpc::{AMMApiServer, AMM};
+use pallet_bridge_rpc::{Bridge, BridgeApiServer};
+use pallet_crowdloans_rpc::{Crowdloans, CrowdloansApiServer};
 use pallet_loans_rpc::{Loans, LoansApiServer};
+use pallet_portfolio_rpc::{Portfolio, PortfolioApiServer};
+use pallet_prices_rpc::{Prices, PricesApiServer};
+use pallet_rewards_rpc::{Rewards, RewardsApiServer};
 use pallet_router_rpc::{Router, RouterApiServer};
+use pallet_xcm_helper_rpc::{XcmHelper, XcmHelperApiServer};
+
+#[cfg(feature = "evm-tracing")]
+use moonbeam_rpc_debug::{Debug, DebugServer};
+#[cfg(feature = "evm-tracing")]
+use moonbeam_rpc_trace::{Trace, TraceServer};
+
+use crate::{
+    evm_estimate::{DispatchAwareEstimate, DispatchAwareEstimateApiServer},
+    evm_log_index::{IndexedLogs, IndexedLogsApiServer, LogIndex},
+    evm_tracing::RpcRequesters,
+};
 
 pub fn frontier_database_dir(config: &Configuration, path: &str) -> std::path::PathBuf {
     let config_dir = config
@@ -148,6 +166,12 @@ pub struct FullDeps<C, P, A: ChainApi> {
     pub overrides: Arc<OverrideHandle<Block>>,
     /// Cache for Ethereum block data.
     pub block_data_cache: Arc<EthBlockDataCacheTask<Block>>,
+    /// Requesters of the enabled EVM tracing modules.
+    pub tracing_requesters: RpcRequesters,
+    /// Maximum number of entries returned by `trace_filter`.
+    pub trace_max_count: u32,
+    /// Index of the EVM logs serving `eth_getLogs`, if enabled.
+    pub log_index: Option<Arc<LogIndex<C>>>,
 }
 
 /// Instantiate all RPC extensions.
@@ -172,8 +196,16 @@ where
         + orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
         + pallet_loans_rpc::LoansRuntimeApi<Block, AccountId, Balance>
         + pallet_router_rpc::RouterRuntimeApi<Block, Balance>
+        + pallet_xcm_helper_rpc::XcmHelperRuntimeApi<Block, Balance>
+        + pallet_rewards_rpc::RewardsRuntimeApi<Block, AccountId, Balance>
+        + pallet_amm_rpc::AMMRuntimeApi<Block, AccountId, Balance>
+        + pallet_portfolio_rpc::PortfolioRuntimeApi<Block, AccountId, Balance>
+        + pallet_bridge_rpc::BridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_crowdloans_rpc::CrowdloansRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_prices_rpc::PricesRuntimeApi<Block, AccountId, BlockNumber>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
-        + fp_rpc::EthereumRuntimeRPCApi<Block>,
+        + fp_rpc::EthereumRuntimeRPCApi<Block>
+        + crate::evm_tracing::TracingRuntimeApi,
     P: TransactionPool<Block = Block> + Sync + Send + 'static,
     BE: Backend<Block> + 'static,
     BE::State: StateBackend<BlakeTwo256>,
@@ -194,6 +226,9 @@ where
         fee_history_cache,
         overrides,
         block_data_cache,
+        tracing_requesters,
+        trace_max_count,
+        log_index,
     } = deps;
 
     io.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
@@ -209,9 +244,8 @@ where
             unreachable!()
         }
     }
-    let convert_transaction: Option<Never> = None;
-
-    io.merge(
+    let eth = || {
+        let convert_transaction: Option<Never> = None;
         Eth::new(
             Arc::clone(&client),
             Arc::clone(&pool),
@@ -223,26 +257,43 @@ where
             Arc::clone(&frontier_backend),
             is_authority,
             Arc::clone(&block_data_cache),
-            fee_history_cache,
+            fee_history_cache.clone(),
             fee_history_limit,
             1,
         )
-        .into_rpc(),
-    )?;
+    };
+
+    // `eth_estimateGas` checks the frontier estimates can actually dispatch the call
+    let mut eth_rpc = eth().into_rpc();
+    eth_rpc.remove_method("eth_estimateGas");
+    io.merge(eth_rpc)?;
+    io.merge(DispatchAwareEstimate::new(client.clone(), eth()).into_rpc())?;
 
     let max_past_logs: u32 = 10_000;
     let max_stored_filters: usize = 500;
-    io.merge(
+    let eth_filter = || {
         EthFilter::new(
             client.clone(),
-            frontier_backend,
-            filter_pool,
+            frontier_backend.clone(),
+            filter_pool.clone(),
             max_stored_filters,
             max_past_logs,
-            block_data_cache,
+            block_data_cache.clone(),
         )
-        .into_rpc(),
-    )?;
+    };
+    match log_index {
+        Some(log_index) => {
+            // `eth_getLogs` is served by the log index, which hands the blocks it found, and
+            // the ones it doesn't cover, to the frontier implementation
+            let mut eth_filter_rpc = eth_filter().into_rpc();
+            eth_filter_rpc.remove_method("eth_getLogs");
+            io.merge(eth_filter_rpc)?;
+            io.merge(
+                IndexedLogs::new(client.clone(), log_index, eth_filter(), max_past_logs).into_rpc(),
+            )?;
+        }
+        None => io.merge(eth_filter().into_rpc())?,
+    }
 
     io.merge(Net::new(Arc::clone(&client), network.clone(), true).into_rpc())?;
 
@@ -253,15 +304,36 @@ where
             pool,
             Arc::clone(&client),
             network,
-            subscription_task_executor,
+            subscription_task_executor.clone(),
             overrides,
         )
         .into_rpc(),
     )?;
 
     io.merge(Oracle::new(client.clone()).into_rpc())?;
-    io.merge(Loans::new(client.clone()).into_rpc())?;
+    io.merge(Loans::new(client.clone(), subscription_task_executor).into_rpc())?;
     io.merge(Router::new(client.clone()).into_rpc())?;
+    io.merge(XcmHelper::new(client.clone()).into_rpc())?;
+    io.merge(Rewards::new(client.clone()).into_rpc())?;
+    io.merge(AMM::new(client.clone()).into_rpc())?;
+    io.merge(Portfolio::new(client.clone()).into_rpc())?;
+    io.merge(Bridge::new(client.clone()).into_rpc())?;
+    io.merge(Prices::new(client.clone()).into_rpc())?;
+    io.merge(Crowdloans::new(client.clone()).into_rpc())?;
+
+    #[cfg(feature = "evm-tracing")]
+    {
+        if let Some(trace_filter_requester) = tracing_requesters.trace {
+            io.merge(
+                Trace::new(client.clone(), trace_filter_requester, trace_max_count).into_rpc(),
+            )?;
+        }
+        if let Some(debug_requester) = tracing_requesters.debug {
+            io.merge(Debug::new(debug_requester).into_rpc())?;
+        }
+    }
+    #[cfg(not(feature = "evm-tracing"))]
+    let _ = (tracing_requesters, trace_max_count);
 
     Ok(io)
 }
@@ -59,9 +59,19 @@ use substrate_prometheus_endpoint::Registry;
 
 use primitives::*;
 
+use crate::{
+    cli::EvmTracingConfig,
+    evm_log_index::{log_index_task, LogIndex},
+    evm_tracing::spawn_tracing_tasks,
+};
+
 pub struct VanillaExecutor;
 impl sc_executor::NativeExecutionDispatch for VanillaExecutor {
-    type ExtendHostFunctions = frame_benchmarking::benchmarking::HostFunctions;
+    type ExtendHostFunctions = (
+        frame_benchmarking::benchmarking::HostFunctions,
+        crate::evm_tracing::TracingHostFunctions,
+        precompile_metrics::precompile_metrics::HostFunctions,
+    );
 
     fn dispatch(method: &str, data: &[u8]) -> Option<Vec<u8>> {
         vanilla_runtime::api::dispatch(method, data)
@@ -191,10 +201,17 @@ pub mod vanilla {
     pub struct Executor;
     impl sc_executor::NativeExecutionDispatch for Executor {
         #[cfg(not(feature = "runtime-benchmarks"))]
-        type ExtendHostFunctions = ();
+        type ExtendHostFunctions = (
+            crate::evm_tracing::TracingHostFunctions,
+            precompile_metrics::precompile_metrics::HostFunctions,
+        );
 
         #[cfg(feature = "runtime-benchmarks")]
-        type ExtendHostFunctions = frame_benchmarking::benchmarking::HostFunctions;
+        type ExtendHostFunctions = (
+            frame_benchmarking::benchmarking::HostFunctions,
+            crate::evm_tracing::TracingHostFunctions,
+            precompile_metrics::precompile_metrics::HostFunctions,
+        );
 
         fn dispatch(method: &str, data: &[u8]) -> Option<Vec<u8>> {
             vanilla_runtime::api::dispatch(method, data)
@@ -208,7 +225,11 @@ pub mod vanilla {
 
 pub struct KerriaExecutor;
 impl sc_executor::NativeExecutionDispatch for KerriaExecutor {
-    type ExtendHostFunctions = frame_benchmarking::benchmarking::HostFunctions;
+    type ExtendHostFunctions = (
+        frame_benchmarking::benchmarking::HostFunctions,
+        crate::evm_tracing::TracingHostFunctions,
+        precompile_metrics::precompile_metrics::HostFunctions,
+    );
 
     fn dispatch(method: &str, data: &[u8]) -> Option<Vec<u8>> {
         kerria_runtime::api::dispatch(method, data)
@@ -266,7 +287,15 @@ where
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
         + pallet_loans_rpc::LoansRuntimeApi<Block, AccountId, Balance>
-        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>,
+        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>
+        + pallet_xcm_helper_rpc::XcmHelperRuntimeApi<Block, Balance>
+        + pallet_rewards_rpc::RewardsRuntimeApi<Block, AccountId, Balance>
+        + pallet_amm_rpc::AMMRuntimeApi<Block, AccountId, Balance>
+        + pallet_portfolio_rpc::PortfolioRuntimeApi<Block, AccountId, Balance>
+        + pallet_bridge_rpc::BridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_crowdloans_rpc::CrowdloansRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_prices_rpc::PricesRuntimeApi<Block, AccountId, BlockNumber>
+        + crate::evm_tracing::TracingRuntimeApi,
     sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
     Executor: sc_executor::NativeExecutionDispatch + 'static,
     BIQ: FnOnce(
@@ -330,6 +359,10 @@ where
         client.clone(),
     );
 
+    if let Some(registry) = config.prometheus_registry() {
+        precompile_metrics::register(registry)?;
+    }
+
     let frontier_backend = crate::evm_rpc::open_frontier_backend(client.clone(), config)?;
     let frontier_block_import =
         FrontierBlockImport::new(client.clone(), client.clone(), frontier_backend.clone());
@@ -389,6 +422,7 @@ async fn start_node_impl<RuntimeApi, Executor, BIQ, BIC>(
     polkadot_config: Configuration,
     collator_options: CollatorOptions,
     id: ParaId,
+    tracing_config: EvmTracingConfig,
     build_import_queue: BIQ,
     build_consensus: BIC,
 ) -> sc_service::error::Result<(
@@ -416,7 +450,15 @@ where
         + cumulus_primitives_core::CollectCollationInfo<Block>
         + orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
         + pallet_loans_rpc::LoansRuntimeApi<Block, AccountId, Balance>
-        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>,
+        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>
+        + pallet_xcm_helper_rpc::XcmHelperRuntimeApi<Block, Balance>
+        + pallet_rewards_rpc::RewardsRuntimeApi<Block, AccountId, Balance>
+        + pallet_amm_rpc::AMMRuntimeApi<Block, AccountId, Balance>
+        + pallet_portfolio_rpc::PortfolioRuntimeApi<Block, AccountId, Balance>
+        + pallet_bridge_rpc::BridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_crowdloans_rpc::CrowdloansRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_prices_rpc::PricesRuntimeApi<Block, AccountId, BlockNumber>
+        + crate::evm_tracing::TracingRuntimeApi,
     sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
     Executor: sc_executor::NativeExecutionDispatch + 'static,
     BIQ: FnOnce(
@@ -549,6 +591,30 @@ where
         prometheus_registry.clone(),
     ));
 
+    let tracing_requesters = spawn_tracing_tasks(
+        &tracing_config,
+        &task_manager,
+        client.clone(),
+        backend.clone(),
+        frontier_backend.clone(),
+        overrides.clone(),
+    );
+    let trace_max_count = tracing_config.ethapi_trace_max_count;
+
+    let log_index = tracing_config.eth_log_index.then(|| {
+        let log_index = Arc::new(LogIndex::new(client.clone()));
+        task_manager.spawn_essential_handle().spawn(
+            "frontier-log-index",
+            Some("frontier"),
+            log_index_task::<_, TFullBackend<Block>>(
+                client.clone(),
+                overrides.clone(),
+                log_index.clone(),
+            ),
+        );
+        log_index
+    });
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let network = network.clone();
@@ -572,6 +638,9 @@ where
                 fee_history_cache: fee_history_cache.clone(),
                 block_data_cache: block_data_cache.clone(),
                 overrides: overrides.clone(),
+                tracing_requesters: tracing_requesters.clone(),
+                trace_max_count,
+                log_index: log_index.clone(),
             };
 
             crate::evm_rpc::create_full(deps, subscription).map_err(Into::into)
@@ -744,6 +813,7 @@ pub async fn start_node<RuntimeApi, Executor>(
     polkadot_config: Configuration,
     collator_options: CollatorOptions,
     id: ParaId,
+    tracing_config: EvmTracingConfig,
 ) -> sc_service::error::Result<(
     TaskManager,
     Arc<TFullClient<Block, RuntimeApi, NativeElseWasmExecutor<Executor>>>,
@@ -769,7 +839,15 @@ where
         + cumulus_primitives_core::CollectCollationInfo<Block>
         + orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
         + pallet_loans_rpc::LoansRuntimeApi<Block, AccountId, Balance>
-        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>,
+        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>
+        + pallet_xcm_helper_rpc::XcmHelperRuntimeApi<Block, Balance>
+        + pallet_rewards_rpc::RewardsRuntimeApi<Block, AccountId, Balance>
+        + pallet_amm_rpc::AMMRuntimeApi<Block, AccountId, Balance>
+        + pallet_portfolio_rpc::PortfolioRuntimeApi<Block, AccountId, Balance>
+        + pallet_bridge_rpc::BridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_crowdloans_rpc::CrowdloansRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_prices_rpc::PricesRuntimeApi<Block, AccountId, BlockNumber>
+        + crate::evm_tracing::TracingRuntimeApi,
     sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
     Executor: sc_executor::NativeExecutionDispatch + 'static,
 {
@@ -778,6 +856,7 @@ where
         polkadot_config,
         collator_options,
         id,
+        tracing_config,
         |client,
          block_import,
          config,
@@ -930,7 +1009,15 @@ where
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
         + pallet_loans_rpc::LoansRuntimeApi<Block, AccountId, Balance>
-        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>,
+        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>
+        + pallet_xcm_helper_rpc::XcmHelperRuntimeApi<Block, Balance>
+        + pallet_rewards_rpc::RewardsRuntimeApi<Block, AccountId, Balance>
+        + pallet_amm_rpc::AMMRuntimeApi<Block, AccountId, Balance>
+        + pallet_portfolio_rpc::PortfolioRuntimeApi<Block, AccountId, Balance>
+        + pallet_bridge_rpc::BridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_crowdloans_rpc::CrowdloansRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_prices_rpc::PricesRuntimeApi<Block, AccountId, BlockNumber>
+        + crate::evm_tracing::TracingRuntimeApi,
     sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
     Executor: sc_executor::NativeExecutionDispatch + 'static,
 {
@@ -978,6 +1065,10 @@ where
         task_manager.spawn_essential_handle(),
         client.clone(),
     );
+
+    if let Some(registry) = config.prometheus_registry() {
+        precompile_metrics::register(registry)?;
+    }
     let frontier_backend = crate::evm_rpc::open_frontier_backend(client.clone(), config)?;
 
     let frontier_block_import =
@@ -1004,6 +1095,7 @@ where
 /// Builds a new service.
 pub fn start_dev_node<RuntimeApi, Executor>(
     config: Configuration,
+    tracing_config: EvmTracingConfig,
 ) -> Result<TaskManager, ServiceError>
 where
     RuntimeApi: ConstructRuntimeApi<Block, TFullClient<Block, RuntimeApi, NativeElseWasmExecutor<Executor>>>
@@ -1025,7 +1117,15 @@ where
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
         + pallet_loans_rpc::LoansRuntimeApi<Block, AccountId, Balance>
-        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>,
+        + pallet_router_rpc::RouterRuntimeApi<Block, Balance>
+        + pallet_xcm_helper_rpc::XcmHelperRuntimeApi<Block, Balance>
+        + pallet_rewards_rpc::RewardsRuntimeApi<Block, AccountId, Balance>
+        + pallet_amm_rpc::AMMRuntimeApi<Block, AccountId, Balance>
+        + pallet_portfolio_rpc::PortfolioRuntimeApi<Block, AccountId, Balance>
+        + pallet_bridge_rpc::BridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_crowdloans_rpc::CrowdloansRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + pallet_prices_rpc::PricesRuntimeApi<Block, AccountId, BlockNumber>
+        + crate::evm_tracing::TracingRuntimeApi,
     sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
     Executor: sc_executor::NativeExecutionDispatch + 'static,
 {
@@ -1119,6 +1219,30 @@ where
         prometheus_registry.clone(),
     ));
 
+    let tracing_requesters = spawn_tracing_tasks(
+        &tracing_config,
+        &task_manager,
+        client.clone(),
+        backend.clone(),
+        frontier_backend.clone(),
+        overrides.clone(),
+    );
+    let trace_max_count = tracing_config.ethapi_trace_max_count;
+
+    let log_index = tracing_config.eth_log_index.then(|| {
+        let log_index = Arc::new(LogIndex::new(client.clone()));
+        task_manager.spawn_essential_handle().spawn(
+            "frontier-log-index",
+            Some("frontier"),
+            log_index_task::<_, TFullBackend<Block>>(
+                client.clone(),
+                overrides.clone(),
+                log_index.clone(),
+            ),
+        );
+        log_index
+    });
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let network = network.clone();
@@ -1138,6 +1262,9 @@ where
                 fee_history_cache: fee_history_cache.clone(),
                 block_data_cache: block_data_cache.clone(),
                 overrides: overrides.clone(),
+                tracing_requesters: tracing_requesters.clone(),
+                trace_max_count,
+                log_index: log_index.clone(),
             };
 
             let io = crate::evm_rpc::create_full(deps, subscription)
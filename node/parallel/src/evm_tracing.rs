@@ -0,0 +1,152 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! EVM tracing tasks backing the `debug` and `trace` rpc modules.
+//!
+//! The moonbeam tracing crates are only built with the `evm-tracing` feature. Without it
+//! no tracing task is spawned and `--ethapi` is ignored.
+
+#[cfg(not(feature = "evm-tracing"))]
+pub use disabled::*;
+#[cfg(feature = "evm-tracing")]
+pub use enabled::*;
+
+#[cfg(feature = "evm-tracing")]
+mod enabled {
+    use std::{sync::Arc, time::Duration};
+
+    use fc_rpc::OverrideHandle;
+    use moonbeam_rpc_debug::{DebugHandler, DebugRequester};
+    use moonbeam_rpc_trace::{CacheRequester, CacheTask};
+    use primitives::*;
+    use sc_client_api::{Backend, BlockOf, BlockchainEvents, StateBackend, StorageProvider};
+    use sc_service::TaskManager;
+    use sp_api::ProvideRuntimeApi;
+    use sp_block_builder::BlockBuilder;
+    use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+    use sp_runtime::traits::BlakeTwo256;
+    use tokio::sync::Semaphore;
+
+    use crate::cli::{EthApi, EvmTracingConfig};
+
+    /// Runtime apis the tracing tasks call into
+    pub trait TracingRuntimeApi: moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block> {}
+    impl<T: moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>> TracingRuntimeApi for T {}
+
+    /// Host functions the tracer of the runtime calls
+    pub type TracingHostFunctions = moonbeam_primitives_ext::moonbeam_ext::HostFunctions;
+
+    /// Requesters of the enabled tracing tasks
+    #[derive(Clone)]
+    pub struct RpcRequesters {
+        pub debug: Option<DebugRequester>,
+        pub trace: Option<CacheRequester>,
+    }
+
+    /// Spawn the tracing tasks of the modules enabled by `--ethapi`
+    pub fn spawn_tracing_tasks<C, BE>(
+        tracing_config: &EvmTracingConfig,
+        task_manager: &TaskManager,
+        client: Arc<C>,
+        backend: Arc<BE>,
+        frontier_backend: Arc<fc_db::Backend<Block>>,
+        overrides: Arc<OverrideHandle<Block>>,
+    ) -> RpcRequesters
+    where
+        C: ProvideRuntimeApi<Block> + BlockOf + StorageProvider<Block, BE>,
+        C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError>,
+        C: BlockchainEvents<Block> + Send + Sync + 'static,
+        C::Api: fp_rpc::EthereumRuntimeRPCApi<Block> + TracingRuntimeApi + BlockBuilder<Block>,
+        BE: Backend<Block> + 'static,
+        BE::State: StateBackend<BlakeTwo256>,
+    {
+        // Tracing is expensive, bound the number of concurrent requests of both modules
+        let permit_pool = Arc::new(Semaphore::new(tracing_config.ethapi_max_permits as usize));
+
+        let trace = if tracing_config.ethapi.contains(&EthApi::Trace) {
+            let (trace_filter_task, trace_filter_requester) = CacheTask::create(
+                Arc::clone(&client),
+                Arc::clone(&backend),
+                Duration::from_secs(tracing_config.ethapi_trace_cache_duration),
+                Arc::clone(&permit_pool),
+                Arc::clone(&overrides),
+            );
+            task_manager.spawn_essential_handle().spawn(
+                "trace-filter-cache",
+                Some("eth-tracing"),
+                trace_filter_task,
+            );
+            Some(trace_filter_requester)
+        } else {
+            None
+        };
+
+        let debug = if tracing_config.ethapi.contains(&EthApi::Debug) {
+            let (debug_task, debug_requester) = DebugHandler::task(
+                Arc::clone(&client),
+                Arc::clone(&backend),
+                frontier_backend,
+                permit_pool,
+                overrides,
+                tracing_config.tracing_raw_max_memory_usage,
+            );
+            task_manager.spawn_essential_handle().spawn(
+                "ethapi-debug",
+                Some("eth-tracing"),
+                debug_task,
+            );
+            Some(debug_requester)
+        } else {
+            None
+        };
+
+        RpcRequesters { debug, trace }
+    }
+}
+
+#[cfg(not(feature = "evm-tracing"))]
+mod disabled {
+    use std::sync::Arc;
+
+    use fc_rpc::OverrideHandle;
+    use primitives::*;
+    use sc_service::TaskManager;
+
+    use crate::cli::EvmTracingConfig;
+
+    /// Runtime apis the tracing tasks call into, none without the `evm-tracing` feature
+    pub trait TracingRuntimeApi {}
+    impl<T> TracingRuntimeApi for T {}
+
+    /// Host functions the tracer of the runtime calls, none without the `evm-tracing` feature
+    pub type TracingHostFunctions = ();
+
+    /// No tracing module can be enabled without the `evm-tracing` feature
+    #[derive(Clone)]
+    pub struct RpcRequesters;
+
+    pub fn spawn_tracing_tasks<C, BE>(
+        tracing_config: &EvmTracingConfig,
+        _task_manager: &TaskManager,
+        _client: Arc<C>,
+        _backend: Arc<BE>,
+        _frontier_backend: Arc<fc_db::Backend<Block>>,
+        _overrides: Arc<OverrideHandle<Block>>,
+    ) -> RpcRequesters {
+        if !tracing_config.ethapi.is_empty() {
+            log::warn!("--ethapi is ignored, the node was built without the evm-tracing feature");
+        }
+        RpcRequesters
+    }
+}
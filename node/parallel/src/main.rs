@@ -20,12 +20,19 @@ mod service;
 mod cli;
 mod client;
 mod command;
+mod defi_state;
 mod rpc;
 
+#[cfg(feature = "with-evm-runtime")]
+mod evm_estimate;
+#[cfg(feature = "with-evm-runtime")]
+mod evm_log_index;
 #[cfg(feature = "with-evm-runtime")]
 mod evm_rpc;
 #[cfg(feature = "with-evm-runtime")]
 mod evm_service;
+#[cfg(feature = "with-evm-runtime")]
+mod evm_tracing;
 
 fn main() -> sc_cli::Result<()> {
     command::run()
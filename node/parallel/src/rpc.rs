@@ -3,7 +3,11 @@
 
 use std::sync::Arc;
 
-use primitives::{AccountId, Balance, Block, CurrencyId, DataProviderId, Index, TimeStampedPrice};
+use primitives::{
+    AccountId, Balance, Block, BlockNumber, CurrencyId, DataProviderId, Index, TimeStampedPrice,
+};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -18,8 +22,15 @@ use substrate_frame_rpc_system::{System, SystemApiServer};
 use orml_oracle_rpc::{Oracle, OracleApiServer};
 
 /// parallel rpc
+use pallet_amm_rpc::{AMMApiServer, AMM};
+use pallet_bridge_rpc::{Bridge, BridgeApiServer};
+use pallet_crowdloans_rpc::{Crowdloans, CrowdloansApiServer};
 use pallet_loans_rpc::{Loans, LoansApiServer};
+use pallet_portfolio_rpc::{Portfolio, PortfolioApiServer};
+use pallet_prices_rpc::{Prices, PricesApiServer};
+use pallet_rewards_rpc::{Rewards, RewardsApiServer};
 use pallet_router_rpc::{Router, RouterApiServer};
+use pallet_xcm_helper_rpc::{XcmHelper, XcmHelperApiServer};
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
@@ -37,16 +48,24 @@ pub struct FullDeps<C, P> {
 /// Instantiate all full RPC extensions.
 pub fn create_full<C, P>(
     deps: FullDeps<C, P>,
+    subscription_task_executor: SubscriptionTaskExecutor,
 ) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
-    C: Send + Sync + 'static,
+    C: BlockchainEvents<Block> + Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>,
     C::Api: pallet_loans_rpc::LoansRuntimeApi<Block, AccountId, Balance>,
     C::Api: pallet_router_rpc::RouterRuntimeApi<Block, Balance>,
+    C::Api: pallet_rewards_rpc::RewardsRuntimeApi<Block, AccountId, Balance>,
+    C::Api: pallet_amm_rpc::AMMRuntimeApi<Block, AccountId, Balance>,
+    C::Api: pallet_portfolio_rpc::PortfolioRuntimeApi<Block, AccountId, Balance>,
+    C::Api: pallet_bridge_rpc::BridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    C::Api: pallet_crowdloans_rpc::CrowdloansRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    C::Api: pallet_prices_rpc::PricesRuntimeApi<Block, AccountId, BlockNumber>,
+    C::Api: pallet_xcm_helper_rpc::XcmHelperRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
 {
@@ -60,8 +79,15 @@ where
     module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
     module.merge(Oracle::new(client.clone()).into_rpc())?;
-    module.merge(Loans::new(client.clone()).into_rpc())?;
+    module.merge(Loans::new(client.clone(), subscription_task_executor).into_rpc())?;
     module.merge(Router::new(client.clone()).into_rpc())?;
+    module.merge(XcmHelper::new(client.clone()).into_rpc())?;
+    module.merge(Rewards::new(client.clone()).into_rpc())?;
+    module.merge(AMM::new(client.clone()).into_rpc())?;
+    module.merge(Portfolio::new(client.clone()).into_rpc())?;
+    module.merge(Bridge::new(client.clone()).into_rpc())?;
+    module.merge(Prices::new(client.clone()).into_rpc())?;
+    module.merge(Crowdloans::new(client.clone()).into_rpc())?;
 
     Ok(module)
 }
@@ -0,0 +1,234 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+pub use pallet_amm_rpc_runtime_api::{AMMApi as AMMRuntimeApi, PositionValue, SwapQuote};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use primitives::CurrencyId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[rpc(client, server)]
+pub trait AMMApi<BlockHash, AccountId, Balance>
+where
+    Balance: Codec + Copy + TryFrom<NumberOrHex>,
+{
+    /// Output, price impact and fees of swapping `amount_in` of `asset_in`
+    /// through `pool`.
+    #[method(name = "amm_getAmountOut")]
+    fn get_amount_out(
+        &self,
+        pool: (CurrencyId, CurrencyId),
+        asset_in: CurrencyId,
+        amount_in: NumberOrHex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<SwapQuote<NumberOrHex>>;
+
+    /// Input, price impact and fees of swapping for `amount_out` of
+    /// `asset_out` through `pool`.
+    #[method(name = "amm_getAmountIn")]
+    fn get_amount_in(
+        &self,
+        pool: (CurrencyId, CurrencyId),
+        asset_out: CurrencyId,
+        amount_out: NumberOrHex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<SwapQuote<NumberOrHex>>;
+
+    /// Underlying amounts, fees earned and impermanent loss of the liquidity
+    /// `account` provides to `pool`.
+    #[method(name = "amm_positionValue")]
+    fn position_value(
+        &self,
+        account: AccountId,
+        pool: (CurrencyId, CurrencyId),
+        at: Option<BlockHash>,
+    ) -> RpcResult<PositionValue<NumberOrHex>>;
+}
+
+/// A struct that implements the [`AMMApi`].
+pub struct AMM<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> AMM<C, B> {
+    /// Create new `AMM` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+pub enum Error {
+    RuntimeError,
+    QuoteError,
+    PositionError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::QuoteError => 2,
+            Error::PositionError => 3,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance> AMMApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+    for AMM<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: AMMRuntimeApi<Block, AccountId, Balance>,
+    AccountId: Codec,
+    Balance: Codec + Copy + TryFrom<NumberOrHex> + Into<NumberOrHex> + std::fmt::Display,
+{
+    fn get_amount_out(
+        &self,
+        pool: (CurrencyId, CurrencyId),
+        asset_in: CurrencyId,
+        amount_in: NumberOrHex,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<SwapQuote<NumberOrHex>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(self.client.info().best_hash));
+        let quote = api
+            .get_amount_out(&at, pool, asset_in, decode_hex(amount_in, "balance")?)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(quote_error_into_rpc_error)?;
+        try_into_rpc_quote(quote)
+    }
+
+    fn get_amount_in(
+        &self,
+        pool: (CurrencyId, CurrencyId),
+        asset_out: CurrencyId,
+        amount_out: NumberOrHex,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<SwapQuote<NumberOrHex>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(self.client.info().best_hash));
+        let quote = api
+            .get_amount_in(&at, pool, asset_out, decode_hex(amount_out, "balance")?)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(quote_error_into_rpc_error)?;
+        try_into_rpc_quote(quote)
+    }
+
+    fn position_value(
+        &self,
+        account: AccountId,
+        pool: (CurrencyId, CurrencyId),
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<PositionValue<NumberOrHex>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(self.client.info().best_hash));
+        let position = api
+            .position_value(&at, account, pool)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(position_error_into_rpc_error)?;
+        Ok(PositionValue {
+            liquidity: try_into_rpc_balance(position.liquidity)?,
+            amounts: (
+                try_into_rpc_balance(position.amounts.0)?,
+                try_into_rpc_balance(position.amounts.1)?,
+            ),
+            fees: (
+                try_into_rpc_balance(position.fees.0)?,
+                try_into_rpc_balance(position.fees.1)?,
+            ),
+            impermanent_loss: position.impermanent_loss,
+        })
+    }
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Runtime trapped",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+/// Converts an error of quoting the swap into an RPC error.
+fn quote_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::QuoteError.into(),
+        "Not able to quote the swap",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+/// Converts an error of valuing the position into an RPC error.
+fn position_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::PositionError.into(),
+        "Not able to value the position",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+fn decode_hex<H: std::fmt::Debug + Copy, T: TryFrom<H>>(
+    from: H,
+    name: &str,
+) -> Result<T, JsonRpseeError> {
+    from.try_into().map_err(|_| {
+        JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+            ErrorCode::InvalidParams.code(),
+            format!("{:?} does not fit into the {} type", from, name),
+            None::<()>,
+        )))
+    })
+}
+
+fn try_into_rpc_balance<T: std::fmt::Display + Copy + TryInto<NumberOrHex>>(
+    value: T,
+) -> Result<NumberOrHex, JsonRpseeError> {
+    value.try_into().map_err(|_| {
+        JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+            ErrorCode::InvalidParams.code(),
+            format!("{} doesn't fit in NumberOrHex representation", value),
+            None::<()>,
+        )))
+    })
+}
+
+fn try_into_rpc_quote<T: std::fmt::Display + Copy + TryInto<NumberOrHex>>(
+    quote: SwapQuote<T>,
+) -> RpcResult<SwapQuote<NumberOrHex>> {
+    Ok(SwapQuote {
+        amount_in: try_into_rpc_balance(quote.amount_in)?,
+        amount_out: try_into_rpc_balance(quote.amount_out)?,
+        price_impact: quote.price_impact,
+        fee: try_into_rpc_balance(quote.fee)?,
+        protocol_fee: try_into_rpc_balance(quote.protocol_fee)?,
+    })
+}
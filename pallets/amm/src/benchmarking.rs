@@ -175,6 +175,31 @@ benchmarks_instance_pallet! {
     }
     verify {
     }
+
+    update_withdrawal_fee {
+        let caller: T::AccountId = whitelisted_caller();
+        initial_set_up::<T, I>(caller.clone());
+        assert_ok!(AMM::<T, I>::create_pool(T::CreatePoolOrigin::successful_origin(),
+            (BASE_ASSET, QUOTE_ASSET), (100_000u128, 200_000u128),
+            caller, ASSET_ID));
+        let origin = T::ProtocolFeeUpdateOrigin::successful_origin();
+        let withdrawal_fee = WithdrawalFee {
+            fee: Ratio::from_percent(1),
+            decay_blocks: 100u32.into(),
+        };
+        let call = Call::<T, I>::update_withdrawal_fee {
+            pair: (BASE_ASSET, QUOTE_ASSET),
+            withdrawal_fee: Some(withdrawal_fee),
+        };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert_eq!(
+            AMM::<T, I>::withdrawal_fees(BASE_ASSET, QUOTE_ASSET),
+            Some(withdrawal_fee)
+        );
+    }
 }
 
 impl_benchmark_test_suite!(AMM, crate::mock::new_test_ext(), crate::mock::Test,);
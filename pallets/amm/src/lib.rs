@@ -40,10 +40,16 @@ use frame_support::{
     transactional, Blake2_128Concat, PalletId,
 };
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
-use pallet_traits::{ConvertToBigUint, Pool};
+use pallet_traits::{
+    reentrancy::{DefiFlow, FlowGuard},
+    ConvertToBigUint, Pool, PositionValue, ProvideLiquidity, Referrals, RevenueRecorder,
+    RevenueSource, SwapQuote, UnwindLiquidity, VolumeSource,
+};
 use primitives::{Balance, CurrencyId, Ratio};
 use sp_runtime::{
-    traits::{AccountIdConversion, CheckedAdd, CheckedSub, One, Saturating, Zero},
+    traits::{
+        AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub, One, Saturating, Zero,
+    },
     ArithmeticError, DispatchError, FixedPointNumber, FixedU128, SaturatedConversion,
 };
 use sp_std::{cmp::min, result::Result, vec::Vec};
@@ -60,6 +66,44 @@ pub type AssetIdOf<T, I = ()> =
 pub type BalanceOf<T, I = ()> =
     <<T as Config<I>>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// Liquidity an account added to a pool, reduced in proportion as it's removed
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Default, RuntimeDebug, TypeInfo)]
+pub struct LiquidityDeposit<Balance, BlockNumber> {
+    /// Liquidity tokens minted
+    pub liquidity: Balance,
+    /// Base and quote amounts deposited
+    pub amounts: (Balance, Balance),
+    /// `FeesPerLiquidity` of the pool when the liquidity was added, weighted by the
+    /// liquidity of each deposit
+    pub fees_per_liquidity: (FixedU128, FixedU128),
+    /// Block the liquidity was added in, weighted by the liquidity of each deposit
+    pub deposited_at: BlockNumber,
+}
+
+/// Fee kept in a pool out of the liquidity removed from it, decaying to zero as the
+/// liquidity ages so that liquidity added just for a trade pays for it
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct WithdrawalFee<BlockNumber> {
+    /// Fee charged on liquidity removed in the block it was added
+    pub fee: Ratio,
+    /// Blocks after which liquidity is removed without a fee
+    pub decay_blocks: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> WithdrawalFee<BlockNumber> {
+    /// Fee charged on liquidity removed `age` blocks after it was added
+    pub fn fee_after(&self, age: BlockNumber) -> Ratio {
+        if age >= self.decay_blocks {
+            return Ratio::zero();
+        }
+        self.fee
+            * Ratio::from_rational(
+                (self.decay_blocks - age).saturated_into::<u128>(),
+                self.decay_blocks.saturated_into::<u128>(),
+            )
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -115,6 +159,12 @@ pub mod pallet {
 
         #[pallet::constant]
         type GetNativeCurrencyId: Get<AssetIdOf<Self, I>>;
+
+        /// Records the lp tokens minted as protocol fee
+        type Revenue: RevenueRecorder;
+
+        /// Records the swapped volume of referred accounts
+        type Referrals: Referrals<Self::AccountId>;
     }
 
     #[pallet::error]
@@ -141,6 +191,10 @@ pub mod pallet {
         ConversionToU128Failed,
         /// Protocol fee receiver not set
         ProtocolFeeReceiverNotSet,
+        /// Asset is not one of the pool's pair
+        AssetNotInPool,
+        /// Withdrawal fee must be above zero and decay over at least one block
+        InvalidWithdrawalFee,
     }
 
     #[pallet::event]
@@ -197,6 +251,24 @@ pub mod pallet {
 
         /// Protocol fee receiver updated
         ProtocolFeeReceiverUpdated(T::AccountId),
+
+        /// Withdrawal fee of a pool updated, `None` if it was removed
+        /// [base_currency_id, quote_currency_id, withdrawal_fee]
+        WithdrawalFeeUpdated(
+            AssetIdOf<T, I>,
+            AssetIdOf<T, I>,
+            Option<WithdrawalFee<T::BlockNumber>>,
+        ),
+
+        /// Withdrawal fee kept in the pool out of removed liquidity
+        /// [sender, base_currency_id, quote_currency_id, base_fee, quote_fee]
+        WithdrawalFeeCharged(
+            T::AccountId,
+            AssetIdOf<T, I>,
+            AssetIdOf<T, I>,
+            BalanceOf<T, I>,
+            BalanceOf<T, I>,
+        ),
     }
 
     #[pallet::pallet]
@@ -215,6 +287,49 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Fees earned by a unit of liquidity since the pool was created, in the base and
+    /// quote assets
+    /// BaseAsset, QuoteAsset -> (FixedU128, FixedU128)
+    #[pallet::storage]
+    #[pallet::getter(fn fees_per_liquidity)]
+    pub type FeesPerLiquidity<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetIdOf<T, I>,
+        Blake2_128Concat,
+        AssetIdOf<T, I>,
+        (FixedU128, FixedU128),
+        ValueQuery,
+    >;
+
+    /// Liquidity each account added to the pools and still holds
+    /// AccountId, LpTokenId -> LiquidityDeposit
+    #[pallet::storage]
+    #[pallet::getter(fn liquidity_deposits)]
+    pub type LiquidityDeposits<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        AssetIdOf<T, I>,
+        LiquidityDeposit<BalanceOf<T, I>, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Fee charged on liquidity removed from a pool, none by default
+    /// BaseAsset, QuoteAsset -> WithdrawalFee
+    #[pallet::storage]
+    #[pallet::getter(fn withdrawal_fees)]
+    pub type WithdrawalFees<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetIdOf<T, I>,
+        Blake2_128Concat,
+        AssetIdOf<T, I>,
+        WithdrawalFee<T::BlockNumber>,
+        OptionQuery,
+    >;
+
     /// How much the protocol is taking out of each trade.
     #[pallet::storage]
     #[pallet::getter(fn protocol_fee)]
@@ -224,6 +339,14 @@ pub mod pallet {
     #[pallet::storage]
     pub type ProtocolFeeReceiver<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AccountId>;
 
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<T::BlockNumber> for Pallet<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+            Self::do_try_state()
+        }
+    }
+
     #[pallet::call]
     impl<T: Config<I>, I: 'static> Pallet<T, I> {
         /// Allow users to add liquidity to a given pool
@@ -240,75 +363,10 @@ pub mod pallet {
             minimum_amounts: (BalanceOf<T, I>, BalanceOf<T, I>),
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            let (is_inverted, base_asset, quote_asset) = Self::sort_assets(pair)?;
-
-            let (base_amount, quote_amount) = if is_inverted {
-                (desired_amounts.1, desired_amounts.0)
-            } else {
-                (desired_amounts.0, desired_amounts.1)
-            };
+            let _guard = FlowGuard::enter(DefiFlow::Amm)?;
+            Self::add_liquidity_to_pool(&who, pair, desired_amounts, minimum_amounts)?;
 
-            let (minimum_base_amount, minimum_quote_amount) = if is_inverted {
-                (minimum_amounts.1, minimum_amounts.0)
-            } else {
-                (minimum_amounts.0, minimum_amounts.1)
-            };
-
-            Pools::<T, I>::try_mutate(
-                base_asset,
-                quote_asset,
-                |pool| -> DispatchResultWithPostInfo {
-                    let pool = pool.as_mut().ok_or(Error::<T, I>::PoolDoesNotExist)?;
-
-                    let (ideal_base_amount, ideal_quote_amount) =
-                        Self::get_ideal_amounts(pool, (base_amount, quote_amount))?;
-
-                    ensure!(
-                        ideal_base_amount <= base_amount && ideal_quote_amount <= quote_amount,
-                        Error::<T, I>::InsufficientAmountIn
-                    );
-
-                    ensure!(
-                        ideal_base_amount >= minimum_base_amount
-                            && ideal_quote_amount >= minimum_quote_amount,
-                        Error::<T, I>::NotAnIdealPrice
-                    );
-
-                    Self::do_mint_protocol_fee(pool)?;
-
-                    Self::do_add_liquidity(
-                        &who,
-                        pool,
-                        (ideal_base_amount, ideal_quote_amount),
-                        (base_asset, quote_asset),
-                    )?;
-
-                    log::trace!(
-                        target: "amm::add_liquidity",
-                        "who: {:?}, base_asset: {:?}, quote_asset: {:?}, ideal_amounts: {:?},\
-                        desired_amounts: {:?}, minimum_amounts: {:?}",
-                        &who,
-                        &base_asset,
-                        &quote_asset,
-                        &(ideal_base_amount, ideal_quote_amount),
-                        &desired_amounts,
-                        &minimum_amounts
-                    );
-
-                    Self::deposit_event(Event::<T, I>::LiquidityAdded(
-                        who,
-                        base_asset,
-                        quote_asset,
-                        ideal_base_amount,
-                        ideal_quote_amount,
-                        pool.lp_token_id,
-                        pool.base_amount,
-                        pool.quote_amount,
-                    ));
-
-                    Ok(().into())
-                },
-            )
+            Ok(().into())
         }
 
         /// Allow users to remove liquidity from a given pool
@@ -323,40 +381,12 @@ pub mod pallet {
             #[pallet::compact] liquidity: BalanceOf<T, I>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Amm)?;
 
             let (_, base_asset, quote_asset) = Self::sort_assets(pair)?;
+            Self::remove_liquidity_from_pool(&who, (base_asset, quote_asset), liquidity)?;
 
-            Pools::<T, I>::try_mutate(base_asset, quote_asset, |pool| -> DispatchResult {
-                let pool = pool.as_mut().ok_or(Error::<T, I>::PoolDoesNotExist)?;
-
-                Self::do_mint_protocol_fee(pool)?;
-
-                let (base_amount_removed, quote_amount_removed) =
-                    Self::do_remove_liquidity(&who, pool, liquidity, (base_asset, quote_asset))?;
-
-                log::trace!(
-                    target: "amm::remove_liquidity",
-                    "who: {:?}, base_asset: {:?}, quote_asset: {:?}, liquidity: {:?}",
-                    &who,
-                    &base_asset,
-                    &quote_asset,
-                    &liquidity
-                );
-
-                Self::deposit_event(Event::<T, I>::LiquidityRemoved(
-                    who,
-                    base_asset,
-                    quote_asset,
-                    liquidity,
-                    base_amount_removed,
-                    quote_amount_removed,
-                    pool.lp_token_id,
-                    pool.base_amount,
-                    pool.quote_amount,
-                ));
-
-                Ok(())
-            })
+            Ok(())
         }
 
         /// Create of a new pool, governance only
@@ -464,6 +494,85 @@ pub mod pallet {
             ));
             Ok(().into())
         }
+
+        /// Set the fee kept in a pool out of the liquidity removed from it, which decays
+        /// to zero over `decay_blocks` since the liquidity was added. Liquidity an account
+        /// didn't add itself is charged the full fee since its age isn't known.
+        ///
+        /// - `pair`: Currency pool of the fee
+        /// - `withdrawal_fee`: the new fee, `None` to remove it
+        #[pallet::weight(T::AMMWeightInfo::update_withdrawal_fee())]
+        #[transactional]
+        pub fn update_withdrawal_fee(
+            origin: OriginFor<T>,
+            pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
+            withdrawal_fee: Option<WithdrawalFee<T::BlockNumber>>,
+        ) -> DispatchResultWithPostInfo {
+            T::ProtocolFeeUpdateOrigin::ensure_origin(origin)?;
+            let (_, base_asset, quote_asset) = Self::sort_assets(pair)?;
+            ensure!(
+                Pools::<T, I>::contains_key(base_asset, quote_asset),
+                Error::<T, I>::PoolDoesNotExist
+            );
+            if let Some(fee) = withdrawal_fee {
+                ensure!(
+                    !fee.fee.is_zero() && !fee.decay_blocks.is_zero(),
+                    Error::<T, I>::InvalidWithdrawalFee
+                );
+            }
+
+            WithdrawalFees::<T, I>::set(base_asset, quote_asset, withdrawal_fee);
+            Self::deposit_event(Event::<T, I>::WithdrawalFeeUpdated(
+                base_asset,
+                quote_asset,
+                withdrawal_fee,
+            ));
+            Ok(().into())
+        }
+    }
+}
+
+#[cfg(any(feature = "try-runtime", test))]
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Check that the reserves of the pools are held by the pallet account and that
+    /// they cover the liquidity minted for them
+    pub fn do_try_state() -> Result<(), &'static str> {
+        let mut reserves = sp_std::collections::btree_map::BTreeMap::new();
+        for (base_asset, quote_asset, pool) in Pools::<T, I>::iter() {
+            if pool.is_empty() {
+                continue;
+            }
+            let liquidity = T::Assets::total_issuance(pool.lp_token_id);
+            ensure!(
+                !liquidity.is_zero(),
+                "liquidity must be minted for the reserves"
+            );
+            // Fees only grow the constant product, it can't drop below the square of the
+            // liquidity minted at its square root
+            ensure!(
+                liquidity.get_big_uint() * liquidity.get_big_uint()
+                    <= pool.base_amount.get_big_uint() * pool.quote_amount.get_big_uint(),
+                "constant product must cover the liquidity"
+            );
+            for (asset, amount) in [
+                (base_asset, pool.base_amount),
+                (quote_asset, pool.quote_amount),
+            ] {
+                let total = reserves
+                    .entry(asset)
+                    .or_insert_with(BalanceOf::<T, I>::zero);
+                *total = total.saturating_add(amount);
+            }
+        }
+
+        let account_id = Self::account_id();
+        for (asset, total) in reserves {
+            ensure!(
+                T::Assets::balance(asset, &account_id) >= total,
+                "reserves must be held by the pallet account"
+            );
+        }
+        Ok(())
     }
 }
 
@@ -610,6 +719,191 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         }
     }
 
+    /// Quote of swapping `amount_in` of `asset_in` through the pool of `pair`
+    pub fn quote_amount_out(
+        pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        asset_in: AssetIdOf<T, I>,
+        amount_in: BalanceOf<T, I>,
+    ) -> Result<SwapQuote<BalanceOf<T, I>>, DispatchError> {
+        let asset_out = Self::other_asset(pair, asset_in)?;
+        let (reserve_in, reserve_out) = Self::get_reserves(asset_in, asset_out)?;
+        ensure!(
+            amount_in >= T::LpFee::get().saturating_reciprocal_mul_ceil(One::one()),
+            Error::<T, I>::InsufficientAmountIn
+        );
+        ensure!(!reserve_out.is_zero(), Error::<T, I>::InsufficientAmountOut);
+
+        let amount_out = Self::get_amount_out(amount_in, reserve_in, reserve_out)?;
+        Self::swap_quote(amount_in, amount_out, reserve_in)
+    }
+
+    /// Quote of swapping for `amount_out` of `asset_out` through the pool of `pair`
+    pub fn quote_amount_in(
+        pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        asset_out: AssetIdOf<T, I>,
+        amount_out: BalanceOf<T, I>,
+    ) -> Result<SwapQuote<BalanceOf<T, I>>, DispatchError> {
+        let asset_in = Self::other_asset(pair, asset_out)?;
+        let (reserve_in, reserve_out) = Self::get_reserves(asset_in, asset_out)?;
+
+        let amount_in = Self::get_amount_in(amount_out, reserve_in, reserve_out)?;
+        Self::swap_quote(amount_in, amount_out, reserve_in)
+    }
+
+    /// Liquidity `who` provides to each pool, as
+    /// `(base_asset, quote_asset, lp_token_id, liquidity, base_amount, quote_amount)`
+    /// where the amounts are what removing the liquidity would pay out
+    #[allow(clippy::type_complexity)]
+    pub fn liquidity_positions(
+        who: &T::AccountId,
+    ) -> Result<
+        Vec<(
+            AssetIdOf<T, I>,
+            AssetIdOf<T, I>,
+            AssetIdOf<T, I>,
+            BalanceOf<T, I>,
+            BalanceOf<T, I>,
+            BalanceOf<T, I>,
+        )>,
+        DispatchError,
+    > {
+        let mut positions = Vec::new();
+        for (base_asset, quote_asset, mut pool) in Pools::<T, I>::iter() {
+            let liquidity = T::Assets::balance(pool.lp_token_id, who);
+            if liquidity.is_zero() {
+                continue;
+            }
+            let (base_amount, quote_amount) =
+                Self::calculate_reserves_to_remove(&mut pool, liquidity)?;
+            positions.push((
+                base_asset,
+                quote_asset,
+                pool.lp_token_id,
+                liquidity,
+                base_amount,
+                quote_amount,
+            ));
+        }
+
+        Ok(positions)
+    }
+
+    /// Value of the liquidity `who` provides to the pool of `pair`, along with the
+    /// fees it earned and its impermanent loss since it was added
+    ///
+    /// Only the liquidity `who` added itself is compared with a deposit, liquidity
+    /// received from other accounts counts towards neither the fees nor the loss.
+    pub fn position_value(
+        who: &T::AccountId,
+        pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
+    ) -> Result<PositionValue<BalanceOf<T, I>>, DispatchError> {
+        let (is_inverted, base_asset, quote_asset) = Self::sort_assets(pair)?;
+        let mut pool =
+            Pools::<T, I>::get(base_asset, quote_asset).ok_or(Error::<T, I>::PoolDoesNotExist)?;
+        let liquidity = T::Assets::balance(pool.lp_token_id, who);
+        let amounts = Self::calculate_reserves_to_remove(&mut pool, liquidity)?;
+
+        let mut fees = (Zero::zero(), Zero::zero());
+        let mut impermanent_loss = Ratio::zero();
+        if let Some(deposit) = Self::liquidity_deposits(who, pool.lp_token_id) {
+            let tracked = min(liquidity, deposit.liquidity);
+            let fees_per_liquidity = Self::fees_per_liquidity(base_asset, quote_asset);
+            fees = (
+                fees_per_liquidity
+                    .0
+                    .saturating_sub(deposit.fees_per_liquidity.0)
+                    .saturating_mul_int(tracked),
+                fees_per_liquidity
+                    .1
+                    .saturating_sub(deposit.fees_per_liquidity.1)
+                    .saturating_mul_int(tracked),
+            );
+
+            // both sides are valued in the quote asset at the current pool price
+            let (base_amount, quote_amount) =
+                Self::calculate_reserves_to_remove(&mut pool, tracked)?;
+            let price = FixedU128::checked_from_rational(pool.quote_amount, pool.base_amount)
+                .unwrap_or_default();
+            let value = price
+                .saturating_mul_int(base_amount.saturating_sub(fees.0))
+                .saturating_add(quote_amount.saturating_sub(fees.1));
+            let held = price
+                .saturating_mul_int(Self::pro_rata(
+                    deposit.amounts.0,
+                    tracked,
+                    deposit.liquidity,
+                )?)
+                .saturating_add(Self::pro_rata(
+                    deposit.amounts.1,
+                    tracked,
+                    deposit.liquidity,
+                )?);
+            if value < held {
+                impermanent_loss = Ratio::from_rational(held - value, held);
+            }
+        }
+
+        let (amounts, fees) = if is_inverted {
+            ((amounts.1, amounts.0), (fees.1, fees.0))
+        } else {
+            (amounts, fees)
+        };
+        Ok(PositionValue {
+            liquidity,
+            amounts,
+            fees,
+            impermanent_loss,
+        })
+    }
+
+    fn other_asset(
+        (asset_a, asset_b): (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        asset: AssetIdOf<T, I>,
+    ) -> Result<AssetIdOf<T, I>, DispatchError> {
+        if asset == asset_a {
+            Ok(asset_b)
+        } else if asset == asset_b {
+            Ok(asset_a)
+        } else {
+            Err(Error::<T, I>::AssetNotInPool.into())
+        }
+    }
+
+    // the fee is taken from amount_in before it enters the curve, so the price impact of the
+    // remainder a is
+    //
+    // 1 - (amountOut / a) / (reserveOut / reserveIn) = a / (reserveIn + a)
+    fn swap_quote(
+        amount_in: BalanceOf<T, I>,
+        amount_out: BalanceOf<T, I>,
+        reserve_in: BalanceOf<T, I>,
+    ) -> Result<SwapQuote<BalanceOf<T, I>>, DispatchError> {
+        let fee = T::LpFee::get().mul_ceil(amount_in);
+        let protocol_fee = if Self::protocol_fee_on() {
+            Self::protocol_fee().mul_floor(fee)
+        } else {
+            Zero::zero()
+        };
+
+        let amount_in_after_fee = amount_in
+            .checked_sub(fee)
+            .ok_or(ArithmeticError::Underflow)?;
+        let price_impact = Ratio::from_rational(
+            amount_in_after_fee,
+            reserve_in
+                .checked_add(amount_in_after_fee)
+                .ok_or(ArithmeticError::Overflow)?,
+        );
+
+        Ok(SwapQuote {
+            amount_in,
+            amount_out,
+            price_impact,
+            fee,
+            protocol_fee,
+        })
+    }
+
     // given an input amount of an asset and pair reserves, returns the maximum output amount of the other asset
     //
     // amountIn = amountIn * (1 - fee_percent)
@@ -724,6 +1018,172 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             .ok_or(ArithmeticError::Overflow)?)
     }
 
+    /// Adds the fees of swapping `amount_in` that stay with the liquidity providers
+    /// to the counters of the pool
+    fn record_fees(
+        (base_asset, quote_asset): (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        lp_token_id: AssetIdOf<T, I>,
+        is_inverted: bool,
+        amount_in: BalanceOf<T, I>,
+    ) -> DispatchResult {
+        let total_supply = T::Assets::total_issuance(lp_token_id);
+        if total_supply.is_zero() {
+            return Ok(());
+        }
+        let mut fee = T::LpFee::get().mul_ceil(amount_in);
+        if Self::protocol_fee_on() {
+            fee = fee.saturating_sub(Self::protocol_fee().mul_floor(fee));
+        }
+        let fees =
+            FixedU128::checked_from_rational(fee, total_supply).ok_or(ArithmeticError::Overflow)?;
+
+        FeesPerLiquidity::<T, I>::try_mutate(
+            base_asset,
+            quote_asset,
+            |counters| -> DispatchResult {
+                let counter = if is_inverted {
+                    &mut counters.1
+                } else {
+                    &mut counters.0
+                };
+                *counter = counter
+                    .checked_add(&fees)
+                    .ok_or(ArithmeticError::Overflow)?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Adds the `liquidity` minted to `who` and the amounts it was worth to its deposit
+    fn record_deposit(
+        who: &T::AccountId,
+        lp_token_id: AssetIdOf<T, I>,
+        (base_asset, quote_asset): (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        liquidity: BalanceOf<T, I>,
+        (base_amount, quote_amount): (BalanceOf<T, I>, BalanceOf<T, I>),
+    ) -> DispatchResult {
+        if liquidity.is_zero() {
+            return Ok(());
+        }
+        let fees_per_liquidity = Self::fees_per_liquidity(base_asset, quote_asset);
+        let now = frame_system::Pallet::<T>::block_number();
+
+        LiquidityDeposits::<T, I>::try_mutate(who, lp_token_id, |deposit| -> DispatchResult {
+            let deposit = deposit.get_or_insert_with(Default::default);
+            let total = deposit
+                .liquidity
+                .checked_add(liquidity)
+                .ok_or(ArithmeticError::Overflow)?;
+            // weighted so that the fees earned by the previous liquidity are kept
+            let (previous_weight, added_weight) =
+                FixedU128::checked_from_rational(deposit.liquidity, total)
+                    .zip(FixedU128::checked_from_rational(liquidity, total))
+                    .ok_or(ArithmeticError::Overflow)?;
+            let weighted = |previous: FixedU128, current: FixedU128| {
+                previous
+                    .saturating_mul(previous_weight)
+                    .saturating_add(current.saturating_mul(added_weight))
+            };
+            deposit.fees_per_liquidity = (
+                weighted(deposit.fees_per_liquidity.0, fees_per_liquidity.0),
+                weighted(deposit.fees_per_liquidity.1, fees_per_liquidity.1),
+            );
+            deposit.amounts = (
+                deposit
+                    .amounts
+                    .0
+                    .checked_add(base_amount)
+                    .ok_or(ArithmeticError::Overflow)?,
+                deposit
+                    .amounts
+                    .1
+                    .checked_add(quote_amount)
+                    .ok_or(ArithmeticError::Overflow)?,
+            );
+            let elapsed = now
+                .saturating_sub(deposit.deposited_at)
+                .saturated_into::<u128>();
+            deposit.deposited_at = deposit.deposited_at.saturating_add(
+                Self::pro_rata(elapsed, liquidity, total)?.saturated_into::<T::BlockNumber>(),
+            );
+            deposit.liquidity = total;
+            Ok(())
+        })
+    }
+
+    /// Part of the `liquidity` removed by `who` from the pool of `pair` which is kept as
+    /// withdrawal fee, liquidity `who` didn't add itself is charged the full fee
+    fn withdrawal_fee_liquidity(
+        who: &T::AccountId,
+        lp_token_id: AssetIdOf<T, I>,
+        (base_asset, quote_asset): (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        liquidity: BalanceOf<T, I>,
+    ) -> BalanceOf<T, I> {
+        let withdrawal_fee = match Self::withdrawal_fees(base_asset, quote_asset) {
+            Some(withdrawal_fee) => withdrawal_fee,
+            None => return Zero::zero(),
+        };
+        // removed from the deposit first, as `release_deposit` does
+        let (deposited, deposited_at) = Self::liquidity_deposits(who, lp_token_id)
+            .map(|deposit| (min(liquidity, deposit.liquidity), deposit.deposited_at))
+            .unwrap_or_default();
+        let age = frame_system::Pallet::<T>::block_number().saturating_sub(deposited_at);
+
+        withdrawal_fee
+            .fee_after(age)
+            .mul_ceil(deposited)
+            .saturating_add(
+                withdrawal_fee
+                    .fee
+                    .mul_ceil(liquidity.saturating_sub(deposited)),
+            )
+    }
+
+    /// Reduces the deposit of `who` in proportion to the removed `liquidity`
+    fn release_deposit(
+        who: &T::AccountId,
+        lp_token_id: AssetIdOf<T, I>,
+        liquidity: BalanceOf<T, I>,
+    ) -> DispatchResult {
+        LiquidityDeposits::<T, I>::try_mutate_exists(
+            who,
+            lp_token_id,
+            |maybe_deposit| -> DispatchResult {
+                let deposit = match maybe_deposit {
+                    Some(deposit) => deposit,
+                    None => return Ok(()),
+                };
+                // liquidity received from other accounts isn't part of the deposit
+                if liquidity >= deposit.liquidity {
+                    *maybe_deposit = None;
+                    return Ok(());
+                }
+                let remaining = deposit.liquidity - liquidity;
+                deposit.amounts = (
+                    Self::pro_rata(deposit.amounts.0, remaining, deposit.liquidity)?,
+                    Self::pro_rata(deposit.amounts.1, remaining, deposit.liquidity)?,
+                );
+                deposit.liquidity = remaining;
+                Ok(())
+            },
+        )
+    }
+
+    // amount * numerator / denominator
+    fn pro_rata(
+        amount: BalanceOf<T, I>,
+        numerator: BalanceOf<T, I>,
+        denominator: BalanceOf<T, I>,
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
+        Ok(amount
+            .get_big_uint()
+            .checked_mul(&numerator.get_big_uint())
+            .and_then(|r| r.checked_div(&denominator.get_big_uint()))
+            .ok_or(Error::<T, I>::ConversionToU128Failed)?
+            .to_u128()
+            .ok_or(ArithmeticError::Underflow)?)
+    }
+
     // update internal twap price oracle by calculating the number of blocks elapsed
     // and update the pools cumulative prices
     fn do_update_oracle(
@@ -780,7 +1240,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         pool: &mut Pool<AssetIdOf<T, I>, BalanceOf<T, I>, T::BlockNumber>,
         (ideal_base_amount, ideal_quote_amount): (BalanceOf<T, I>, BalanceOf<T, I>),
         (base_asset, quote_asset): (AssetIdOf<T, I>, AssetIdOf<T, I>),
-    ) -> Result<(), DispatchError> {
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
         let total_supply = T::Assets::total_issuance(pool.lp_token_id);
 
         // lock a small amount of liquidity if the pool is first initialized
@@ -839,6 +1299,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             .ok_or(ArithmeticError::Overflow)?;
 
         T::Assets::mint_into(pool.lp_token_id, who, liquidity)?;
+        // recorded at what the liquidity is worth, the rounding and the locked minimum
+        // liquidity aren't impermanent losses
+        let deposited = Self::calculate_reserves_to_remove(pool, liquidity)?;
+        Self::record_deposit(
+            who,
+            pool.lp_token_id,
+            (base_asset, quote_asset),
+            liquidity,
+            deposited,
+        )?;
 
         T::Assets::transfer(
             base_asset,
@@ -875,7 +1345,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             &ideal_quote_amount
         );
 
-        Ok(())
+        Ok(liquidity)
     }
 
     fn calculate_reserves_to_remove(
@@ -910,6 +1380,24 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     ) -> Result<(BalanceOf<T, I>, BalanceOf<T, I>), DispatchError> {
         let (base_amount, quote_amount) = Self::calculate_reserves_to_remove(pool, liquidity)?;
 
+        // the fee stays in the pool for the remaining liquidity
+        let fee_liquidity = Self::withdrawal_fee_liquidity(
+            who,
+            pool.lp_token_id,
+            (base_asset, quote_asset),
+            liquidity,
+        );
+        let (base_fee, quote_fee) = if fee_liquidity.is_zero() {
+            (Zero::zero(), Zero::zero())
+        } else {
+            (
+                Self::pro_rata(base_amount, fee_liquidity, liquidity)?,
+                Self::pro_rata(quote_amount, fee_liquidity, liquidity)?,
+            )
+        };
+        let base_amount = base_amount.saturating_sub(base_fee);
+        let quote_amount = quote_amount.saturating_sub(quote_fee);
+
         pool.base_amount = pool
             .base_amount
             .checked_sub(base_amount)
@@ -921,6 +1409,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             .ok_or(Error::<T, I>::InsufficientLiquidity)?;
 
         T::Assets::burn_from(pool.lp_token_id, who, liquidity)?;
+        Self::release_deposit(who, pool.lp_token_id, liquidity)?;
         T::Assets::transfer(
             base_asset,
             &Self::account_id(),
@@ -936,6 +1425,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             quote_asset == T::GetNativeCurrencyId::get(), // should keep alive if is native
         )?;
 
+        if !fee_liquidity.is_zero() {
+            Self::deposit_event(Event::<T, I>::WithdrawalFeeCharged(
+                who.clone(),
+                base_asset,
+                quote_asset,
+                base_fee,
+                quote_fee,
+            ));
+        }
+
         if Self::protocol_fee_on() {
             // we cannot hold k_last for really large values
             // we can hold two u128s instead
@@ -957,6 +1456,128 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         Ok((base_amount, quote_amount))
     }
 
+    /// Adds at most `desired_amounts` of `pair` from `who` to its pool at the pool price,
+    /// returns the amounts added in the order of `pair` and the liquidity minted
+    #[require_transactional]
+    fn add_liquidity_to_pool(
+        who: &T::AccountId,
+        pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        desired_amounts: (BalanceOf<T, I>, BalanceOf<T, I>),
+        minimum_amounts: (BalanceOf<T, I>, BalanceOf<T, I>),
+    ) -> Result<((BalanceOf<T, I>, BalanceOf<T, I>), BalanceOf<T, I>), DispatchError> {
+        let (is_inverted, base_asset, quote_asset) = Self::sort_assets(pair)?;
+
+        let (base_amount, quote_amount) = if is_inverted {
+            (desired_amounts.1, desired_amounts.0)
+        } else {
+            (desired_amounts.0, desired_amounts.1)
+        };
+
+        let (minimum_base_amount, minimum_quote_amount) = if is_inverted {
+            (minimum_amounts.1, minimum_amounts.0)
+        } else {
+            (minimum_amounts.0, minimum_amounts.1)
+        };
+
+        Pools::<T, I>::try_mutate(base_asset, quote_asset, |pool| {
+            let pool = pool.as_mut().ok_or(Error::<T, I>::PoolDoesNotExist)?;
+
+            let (ideal_base_amount, ideal_quote_amount) =
+                Self::get_ideal_amounts(pool, (base_amount, quote_amount))?;
+
+            ensure!(
+                ideal_base_amount <= base_amount && ideal_quote_amount <= quote_amount,
+                Error::<T, I>::InsufficientAmountIn
+            );
+
+            ensure!(
+                ideal_base_amount >= minimum_base_amount
+                    && ideal_quote_amount >= minimum_quote_amount,
+                Error::<T, I>::NotAnIdealPrice
+            );
+
+            Self::do_mint_protocol_fee(pool)?;
+
+            let liquidity = Self::do_add_liquidity(
+                who,
+                pool,
+                (ideal_base_amount, ideal_quote_amount),
+                (base_asset, quote_asset),
+            )?;
+
+            log::trace!(
+                target: "amm::add_liquidity",
+                "who: {:?}, base_asset: {:?}, quote_asset: {:?}, ideal_amounts: {:?},\
+                desired_amounts: {:?}, minimum_amounts: {:?}",
+                who,
+                &base_asset,
+                &quote_asset,
+                &(ideal_base_amount, ideal_quote_amount),
+                &desired_amounts,
+                &minimum_amounts
+            );
+
+            Self::deposit_event(Event::<T, I>::LiquidityAdded(
+                who.clone(),
+                base_asset,
+                quote_asset,
+                ideal_base_amount,
+                ideal_quote_amount,
+                pool.lp_token_id,
+                pool.base_amount,
+                pool.quote_amount,
+            ));
+
+            let amounts = if is_inverted {
+                (ideal_quote_amount, ideal_base_amount)
+            } else {
+                (ideal_base_amount, ideal_quote_amount)
+            };
+            Ok((amounts, liquidity))
+        })
+    }
+
+    /// Removes `liquidity` from the pool of the sorted `(base_asset, quote_asset)`,
+    /// returning the base and quote amounts paid to `who`
+    #[require_transactional]
+    fn remove_liquidity_from_pool(
+        who: &T::AccountId,
+        (base_asset, quote_asset): (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        liquidity: BalanceOf<T, I>,
+    ) -> Result<(BalanceOf<T, I>, BalanceOf<T, I>), DispatchError> {
+        Pools::<T, I>::try_mutate(base_asset, quote_asset, |pool| {
+            let pool = pool.as_mut().ok_or(Error::<T, I>::PoolDoesNotExist)?;
+
+            Self::do_mint_protocol_fee(pool)?;
+
+            let (base_amount_removed, quote_amount_removed) =
+                Self::do_remove_liquidity(who, pool, liquidity, (base_asset, quote_asset))?;
+
+            log::trace!(
+                target: "amm::remove_liquidity",
+                "who: {:?}, base_asset: {:?}, quote_asset: {:?}, liquidity: {:?}",
+                who,
+                &base_asset,
+                &quote_asset,
+                &liquidity
+            );
+
+            Self::deposit_event(Event::<T, I>::LiquidityRemoved(
+                who.clone(),
+                base_asset,
+                quote_asset,
+                liquidity,
+                base_amount_removed,
+                quote_amount_removed,
+                pool.lp_token_id,
+                pool.base_amount,
+                pool.quote_amount,
+            ));
+
+            Ok((base_amount_removed, quote_amount_removed))
+        })
+    }
+
     #[require_transactional]
     pub fn do_mint_protocol_fee(
         pool: &mut Pool<AssetIdOf<T, I>, BalanceOf<T, I>, T::BlockNumber>,
@@ -1026,6 +1647,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             &Self::protolcol_fee_receiver()?,
             protocol_fees,
         )?;
+        T::Revenue::record(
+            RevenueSource::AmmProtocolFee,
+            pool.lp_token_id,
+            protocol_fees,
+        );
 
         log::trace!(
             target: "amm::do_mint_protocol_fee",
@@ -1065,6 +1691,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                 ensure!(!supply_out.is_zero(), Error::<T, I>::InsufficientAmountOut);
 
                 let amount_out = Self::get_amount_out(amount_in, supply_in, supply_out)?;
+                Self::record_fees(
+                    (base_asset, quote_asset),
+                    pool.lp_token_id,
+                    is_inverted,
+                    amount_in,
+                )?;
 
                 let (new_supply_in, new_supply_out) = (
                     supply_in
@@ -1110,6 +1742,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                     &amount_out,
                 );
 
+                T::Referrals::record_volume(who, VolumeSource::AmmSwap, asset_in, amount_in);
                 Self::deposit_event(Event::<T, I>::Traded(
                     who.clone(),
                     asset_in,
@@ -1162,6 +1795,7 @@ impl<T: Config<I>, I: 'static>
         pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
         amount_in: BalanceOf<T, I>,
     ) -> Result<(), DispatchError> {
+        let _guard = FlowGuard::enter_within(DefiFlow::Amm, &[DefiFlow::Router])?;
         Self::do_swap(who, pair, amount_in)?;
         Ok(())
     }
@@ -1198,3 +1832,36 @@ impl<T: Config<I>, I: 'static>
         None
     }
 }
+
+impl<T: Config<I>, I: 'static> UnwindLiquidity<AccountIdOf<T>, AssetIdOf<T, I>, BalanceOf<T, I>>
+    for Pallet<T, I>
+{
+    fn pool_assets(lp_asset: AssetIdOf<T, I>) -> Option<(AssetIdOf<T, I>, AssetIdOf<T, I>)> {
+        <Self as pallet_traits::AMM<_, _, _, _>>::get_pool_by_lp_asset(lp_asset)
+            .map(|(base_asset, quote_asset, _)| (base_asset, quote_asset))
+    }
+
+    fn unwind(
+        who: &AccountIdOf<T>,
+        lp_asset: AssetIdOf<T, I>,
+        liquidity: BalanceOf<T, I>,
+    ) -> Result<(BalanceOf<T, I>, BalanceOf<T, I>), DispatchError> {
+        let _guard = FlowGuard::enter_within(DefiFlow::Amm, &[DefiFlow::Loans])?;
+        let pair = Self::pool_assets(lp_asset).ok_or(Error::<T, I>::PoolDoesNotExist)?;
+        Self::remove_liquidity_from_pool(who, pair, liquidity)
+    }
+}
+
+impl<T: Config<I>, I: 'static> ProvideLiquidity<AccountIdOf<T>, AssetIdOf<T, I>, BalanceOf<T, I>>
+    for Pallet<T, I>
+{
+    fn provide(
+        who: &AccountIdOf<T>,
+        pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        desired_amounts: (BalanceOf<T, I>, BalanceOf<T, I>),
+        minimum_amounts: (BalanceOf<T, I>, BalanceOf<T, I>),
+    ) -> Result<((BalanceOf<T, I>, BalanceOf<T, I>), BalanceOf<T, I>), DispatchError> {
+        let _guard = FlowGuard::enter(DefiFlow::Amm)?;
+        Self::add_liquidity_to_pool(who, pair, desired_amounts, minimum_amounts)
+    }
+}
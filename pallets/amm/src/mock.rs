@@ -178,6 +178,8 @@ impl pallet_amm::Config for Test {
     type MinimumLiquidity = MinimumLiquidity;
     type MaxLengthRoute = MaxLengthRoute;
     type GetNativeCurrencyId = NativeCurrencyId;
+    type Revenue = ();
+    type Referrals = ();
 }
 
 parameter_types! {
@@ -1311,3 +1311,288 @@ fn glmr_add_liquidity_should_work() {
         ));
     })
 }
+
+#[test]
+fn try_state_checks_pools_are_backed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AMM::create_pool(
+            RawOrigin::Signed(ALICE).into(),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            CHARLIE,
+            SAMPLE_LP_TOKEN,
+        ));
+        assert_ok!(AMM::swap(&EVE, (DOT, SDOT), 1_000));
+        assert_ok!(AMM::do_try_state());
+
+        Pools::<Test>::mutate(SDOT, DOT, |pool| {
+            pool.as_mut().unwrap().base_amount += 1;
+        });
+        assert_noop!(
+            AMM::do_try_state(),
+            "reserves must be held by the pallet account"
+        );
+
+        Pools::<Test>::mutate(SDOT, DOT, |pool| {
+            let pool = pool.as_mut().unwrap();
+            pool.base_amount -= 1;
+            pool.quote_amount /= 2;
+        });
+        assert_noop!(
+            AMM::do_try_state(),
+            "constant product must cover the liquidity"
+        );
+    })
+}
+
+#[test]
+fn quote_amount_out_should_match_swap() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AMM::create_pool(
+            RawOrigin::Signed(ALICE).into(),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            CHARLIE,
+            SAMPLE_LP_TOKEN,
+        ));
+        assert_ok!(AMM::update_protocol_fee(
+            RuntimeOrigin::signed(ALICE),
+            Ratio::from_percent(20)
+        ));
+        assert_ok!(AMM::update_protocol_fee_receiver(
+            RuntimeOrigin::signed(ALICE),
+            PROTOCOL_FEE_RECEIVER
+        ));
+
+        let quote = AMM::quote_amount_out((SDOT, DOT), DOT, 1_000_000).unwrap();
+        assert_eq!(
+            quote,
+            SwapQuote {
+                amount_in: 1_000_000,
+                amount_out: 987_648,
+                price_impact: Ratio::from_rational(997_500u128, 100_997_500u128),
+                fee: 2_500,
+                protocol_fee: 500,
+            }
+        );
+
+        assert_ok!(AMM::swap(&EVE, (DOT, SDOT), 1_000_000));
+        assert_eq!(Assets::balance(SDOT, EVE), 1_000_000_000 + quote.amount_out);
+    })
+}
+
+#[test]
+fn quote_amount_in_should_cover_amount_out() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AMM::create_pool(
+            RawOrigin::Signed(ALICE).into(),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            CHARLIE,
+            SAMPLE_LP_TOKEN,
+        ));
+
+        let quote = AMM::quote_amount_in((DOT, SDOT), SDOT, 987_648).unwrap();
+        assert_eq!(quote.amount_out, 987_648);
+        assert_eq!(quote.protocol_fee, 0);
+        assert!(AMM::get_amounts_out(quote.amount_in, vec![DOT, SDOT]).unwrap()[1] >= 987_648);
+
+        assert_noop!(
+            AMM::quote_amount_in((DOT, SDOT), KSM, 1_000),
+            Error::<Test>::AssetNotInPool
+        );
+        assert_noop!(
+            AMM::quote_amount_out((DOT, KSM), DOT, 1_000),
+            Error::<Test>::PoolDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn liquidity_positions_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AMM::create_pool(
+            RawOrigin::Signed(ALICE).into(),
+            (DOT, SDOT),
+            (1_000, 2_000),
+            BOB,
+            SAMPLE_LP_TOKEN,
+        ));
+
+        assert_eq!(AMM::liquidity_positions(&ALICE).unwrap(), vec![]);
+        // 414 of the 1_414 issued lp tokens, the rest is locked as minimum liquidity
+        assert_eq!(
+            AMM::liquidity_positions(&BOB).unwrap(),
+            vec![(SDOT, DOT, SAMPLE_LP_TOKEN, 414, 585, 292)]
+        );
+    })
+}
+
+#[test]
+fn position_value_should_track_fees_and_impermanent_loss() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AMM::create_pool(
+            RawOrigin::Signed(ALICE).into(),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            CHARLIE,
+            SAMPLE_LP_TOKEN,
+        ));
+        let position = AMM::position_value(&CHARLIE, (DOT, SDOT)).unwrap();
+        assert_eq!(position.liquidity, 99_999_000);
+        assert_eq!(position.amounts, (99_999_000, 99_999_000));
+        assert_eq!(position.fees, (0, 0));
+        assert_eq!(position.impermanent_loss, Ratio::zero());
+
+        // 2_500 DOT of fees, shared with the locked minimum liquidity
+        assert_ok!(AMM::swap(&EVE, (DOT, SDOT), 1_000_000));
+        let position = AMM::position_value(&CHARLIE, (DOT, SDOT)).unwrap();
+        assert_eq!(position.amounts, (100_998_990, 99_011_361));
+        assert_eq!(position.fees, (2_499, 0));
+        assert!(position.impermanent_loss > Ratio::zero());
+        assert!(position.impermanent_loss < Ratio::from_percent(1));
+
+        // the same amounts are reported the other way around
+        let inverted = AMM::position_value(&CHARLIE, (SDOT, DOT)).unwrap();
+        assert_eq!(inverted.amounts, (99_011_361, 100_998_990));
+        assert_eq!(inverted.fees, (0, 2_499));
+
+        // fees earned before the deposit aren't counted
+        assert_ok!(AMM::add_liquidity(
+            RawOrigin::Signed(FRANK).into(),
+            (DOT, SDOT),
+            (10_000_000, 10_000_000),
+            (0, 0),
+        ));
+        let position = AMM::position_value(&FRANK, (DOT, SDOT)).unwrap();
+        assert_eq!(position.fees, (0, 0));
+        assert_eq!(position.impermanent_loss, Ratio::zero());
+
+        // removed liquidity takes its share of the deposit along
+        assert_ok!(AMM::remove_liquidity(
+            RawOrigin::Signed(CHARLIE).into(),
+            (DOT, SDOT),
+            49_999_500,
+        ));
+        assert_eq!(
+            AMM::liquidity_deposits(CHARLIE, SAMPLE_LP_TOKEN)
+                .unwrap()
+                .amounts,
+            (49_999_500, 49_999_500)
+        );
+        let position = AMM::position_value(&CHARLIE, (DOT, SDOT)).unwrap();
+        assert_eq!(position.fees, (1_249, 0));
+
+        // liquidity received from another account wasn't deposited
+        assert_ok!(Assets::transfer(
+            RuntimeOrigin::signed(CHARLIE),
+            SAMPLE_LP_TOKEN,
+            BOB,
+            1_000
+        ));
+        let position = AMM::position_value(&BOB, (DOT, SDOT)).unwrap();
+        assert_eq!(position.liquidity, 1_000);
+        assert_eq!(position.fees, (0, 0));
+
+        assert_noop!(
+            AMM::position_value(&BOB, (DOT, KSM)),
+            Error::<Test>::PoolDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn withdrawal_fee_should_decay_with_the_age_of_the_liquidity() {
+    new_test_ext().execute_with(|| {
+        let start = System::block_number();
+        assert_ok!(AMM::create_pool(
+            RawOrigin::Signed(ALICE).into(),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            CHARLIE,
+            SAMPLE_LP_TOKEN,
+        ));
+
+        let withdrawal_fee = WithdrawalFee {
+            fee: Ratio::from_percent(10),
+            decay_blocks: 100,
+        };
+        assert_noop!(
+            AMM::update_withdrawal_fee(
+                RawOrigin::Signed(BOB).into(),
+                (DOT, SDOT),
+                Some(withdrawal_fee)
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AMM::update_withdrawal_fee(
+                RawOrigin::Signed(ALICE).into(),
+                (DOT, SDOT),
+                Some(WithdrawalFee {
+                    decay_blocks: 0,
+                    ..withdrawal_fee
+                })
+            ),
+            Error::<Test>::InvalidWithdrawalFee
+        );
+        assert_noop!(
+            AMM::update_withdrawal_fee(
+                RawOrigin::Signed(ALICE).into(),
+                (DOT, KSM),
+                Some(withdrawal_fee)
+            ),
+            Error::<Test>::PoolDoesNotExist
+        );
+        assert_ok!(AMM::update_withdrawal_fee(
+            RawOrigin::Signed(ALICE).into(),
+            (SDOT, DOT),
+            Some(withdrawal_fee)
+        ));
+        assert_eq!(AMM::withdrawal_fees(SDOT, DOT), Some(withdrawal_fee));
+
+        // half way through the decay 5% of the removed liquidity stays in the pool
+        run_to_block(start + 50);
+        let dot_before = Assets::balance(DOT, CHARLIE);
+        assert_ok!(AMM::remove_liquidity(
+            RawOrigin::Signed(CHARLIE).into(),
+            (DOT, SDOT),
+            10_000_000,
+        ));
+        assert_eq!(Assets::balance(DOT, CHARLIE) - dot_before, 9_500_000);
+        assert_eq!(AMM::pools(SDOT, DOT).unwrap().base_amount, 90_500_000);
+
+        // liquidity received from another account pays the full fee
+        assert_ok!(Assets::transfer(
+            RuntimeOrigin::signed(CHARLIE),
+            SAMPLE_LP_TOKEN,
+            BOB,
+            1_000_000
+        ));
+        let dot_before = Assets::balance(DOT, BOB);
+        assert_ok!(AMM::remove_liquidity(
+            RawOrigin::Signed(BOB).into(),
+            (DOT, SDOT),
+            1_000_000,
+        ));
+        // 1_005_555 minus 10%
+        assert_eq!(Assets::balance(DOT, BOB) - dot_before, 905_000);
+
+        // no fee once the decay is over
+        run_to_block(start + 100);
+        let dot_before = Assets::balance(DOT, CHARLIE);
+        assert_ok!(AMM::remove_liquidity(
+            RawOrigin::Signed(CHARLIE).into(),
+            (DOT, SDOT),
+            9_000_000,
+        ));
+        assert_eq!(Assets::balance(DOT, CHARLIE) - dot_before, 9_060_168);
+
+        assert_ok!(AMM::update_withdrawal_fee(
+            RawOrigin::Signed(ALICE).into(),
+            (DOT, SDOT),
+            None
+        ));
+        assert_eq!(AMM::withdrawal_fees(SDOT, DOT), None);
+    })
+}
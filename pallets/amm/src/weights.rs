@@ -43,6 +43,10 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions needed for pallet_amm.
 pub trait WeightInfo {
   fn add_liquidity() -> Weight;
@@ -50,6 +54,7 @@ pub trait WeightInfo {
   fn create_pool() -> Weight;
   fn update_protocol_fee() -> Weight;
   fn update_protocol_fee_receiver() -> Weight;
+  fn update_withdrawal_fee() -> Weight;
 }
 
 /// Weights for pallet_amm using the Substrate node and recommended hardware.
@@ -62,6 +67,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn add_liquidity() -> Weight {
 		Weight::from_ref_time(217_917_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(10 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -71,6 +77,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn remove_liquidity() -> Weight {
 		Weight::from_ref_time(238_051_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(10 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -81,6 +88,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn create_pool() -> Weight {
 		Weight::from_ref_time(291_928_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(13 as u64))
 	}
 	// Storage: AMM ProtocolFee (r:0 w:1)
@@ -91,6 +99,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
   fn update_protocol_fee_receiver() -> Weight {
 	Weight::from_ref_time(4_114_000 as u64).saturating_add(T::DbWeight::get().writes(1 as u64))
   }
+  // Storage: AMM Pools (r:1 w:0)
+  // Storage: AMM WithdrawalFees (r:0 w:1)
+  fn update_withdrawal_fee() -> Weight {
+	Weight::from_ref_time(9_262_000 as u64)
+		.saturating_add(T::DbWeight::get().reads(1 as u64))
+		.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+		.saturating_add(T::DbWeight::get().writes(1 as u64))
+  }
 }
 
 // For backwards compatibility and tests
@@ -131,4 +147,11 @@ impl WeightInfo for () {
 	fn update_protocol_fee_receiver() -> Weight {
 		Weight::from_ref_time(4_114_000 as u64).saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Storage: AMM Pools (r:1 w:0)
+	// Storage: AMM WithdrawalFees (r:0 w:1)
+	fn update_withdrawal_fee() -> Weight {
+		Weight::from_ref_time(9_262_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
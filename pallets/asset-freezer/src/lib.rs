@@ -0,0 +1,211 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Asset Freezer pallet
+//!
+//! ## Overview
+//!
+//! Lets a designated authority freeze accounts for specific assets, like a bridged
+//! stablecoin whose issuer flags accounts for compliance. Nothing is frozen by default:
+//! governance first registers an asset with its authority, which can then freeze and
+//! thaw the accounts holding it, with the hash of the case behind each decision.
+//!
+//! The pallet is the `Freezer` of `pallet-assets`, a frozen account can still receive the
+//! asset but can't move any of it, its other assets aren't affected. Every freeze and
+//! thaw is recorded as an event, along with the authority which made it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, traits::tokens::fungibles::Inspect, transactional};
+use frame_system::pallet_prelude::*;
+use pallet_assets::FrozenBalance;
+use primitives::{Balance, CurrencyId};
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Assets the accounts are frozen for
+        type Assets: Inspect<Self::AccountId, AssetId = CurrencyId, Balance = Balance>;
+
+        /// The origin which can register assets and their authorities
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Authority of an asset was set, or the asset unregistered if `None`
+        /// [asset_id, authority]
+        AuthoritySet(CurrencyId, Option<T::AccountId>),
+        /// Account was frozen for an asset
+        /// [asset_id, who, authority, reason]
+        AccountFrozen(CurrencyId, T::AccountId, T::AccountId, T::Hash),
+        /// Account was thawed for an asset
+        /// [asset_id, who, authority, reason]
+        AccountThawed(CurrencyId, T::AccountId, T::AccountId, T::Hash),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Asset has no authority
+        AssetNotRegistered,
+        /// Caller isn't the authority of the asset
+        NotAuthority,
+        /// Account is already frozen for the asset
+        AlreadyFrozen,
+        /// Account isn't frozen for the asset
+        NotFrozen,
+        /// Asset can't be unregistered while accounts are frozen for it
+        FrozenAccountsExist,
+    }
+
+    /// Authority which can freeze accounts for each registered asset
+    /// CurrencyId -> AccountId
+    #[pallet::storage]
+    #[pallet::getter(fn authorities)]
+    pub type Authorities<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, T::AccountId, OptionQuery>;
+
+    /// Accounts frozen for each asset, with the hash of the case behind the freeze
+    /// CurrencyId -> AccountId -> Hash
+    #[pallet::storage]
+    #[pallet::getter(fn frozen_accounts)]
+    pub type FrozenAccounts<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        Blake2_128Concat,
+        T::AccountId,
+        T::Hash,
+        OptionQuery,
+    >;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register `asset_id` with the authority which can freeze accounts for it, or
+        /// unregister it if `authority` is `None`.
+        #[pallet::weight(T::WeightInfo::set_authority())]
+        #[transactional]
+        pub fn set_authority(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId,
+            authority: Option<T::AccountId>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            match authority {
+                Some(ref authority) => Authorities::<T>::insert(asset_id, authority),
+                None => {
+                    ensure!(
+                        FrozenAccounts::<T>::iter_prefix(asset_id).next().is_none(),
+                        Error::<T>::FrozenAccountsExist
+                    );
+                    Authorities::<T>::remove(asset_id);
+                }
+            }
+            Self::deposit_event(Event::<T>::AuthoritySet(asset_id, authority));
+            Ok(())
+        }
+
+        /// Freeze `who` for `asset_id`, it can no longer move any of it.
+        ///
+        /// - `reason`: hash of the case behind the freeze
+        #[pallet::weight(T::WeightInfo::freeze())]
+        #[transactional]
+        pub fn freeze(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId,
+            who: T::AccountId,
+            reason: T::Hash,
+        ) -> DispatchResult {
+            let authority = Self::ensure_authority(origin, asset_id)?;
+            ensure!(
+                !FrozenAccounts::<T>::contains_key(asset_id, &who),
+                Error::<T>::AlreadyFrozen
+            );
+
+            FrozenAccounts::<T>::insert(asset_id, &who, reason);
+            Self::deposit_event(Event::<T>::AccountFrozen(asset_id, who, authority, reason));
+            Ok(())
+        }
+
+        /// Thaw `who` for `asset_id`.
+        ///
+        /// - `reason`: hash of the case behind the thaw
+        #[pallet::weight(T::WeightInfo::thaw())]
+        #[transactional]
+        pub fn thaw(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId,
+            who: T::AccountId,
+            reason: T::Hash,
+        ) -> DispatchResult {
+            let authority = Self::ensure_authority(origin, asset_id)?;
+            ensure!(
+                FrozenAccounts::<T>::contains_key(asset_id, &who),
+                Error::<T>::NotFrozen
+            );
+
+            FrozenAccounts::<T>::remove(asset_id, &who);
+            Self::deposit_event(Event::<T>::AccountThawed(asset_id, who, authority, reason));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn is_frozen(asset_id: CurrencyId, who: &T::AccountId) -> bool {
+        FrozenAccounts::<T>::contains_key(asset_id, who)
+    }
+
+    fn ensure_authority(
+        origin: OriginFor<T>,
+        asset_id: CurrencyId,
+    ) -> Result<T::AccountId, DispatchError> {
+        let who = ensure_signed(origin)?;
+        let authority = Self::authorities(asset_id).ok_or(Error::<T>::AssetNotRegistered)?;
+        ensure!(who == authority, Error::<T>::NotAuthority);
+        Ok(who)
+    }
+}
+
+impl<T: Config> FrozenBalance<CurrencyId, T::AccountId, Balance> for Pallet<T> {
+    fn frozen_balance(asset_id: CurrencyId, who: &T::AccountId) -> Option<Balance> {
+        // the whole balance, including what comes in after the freeze
+        Self::is_frozen(asset_id, who).then(|| T::Assets::balance(asset_id, who))
+    }
+
+    fn died(_asset_id: CurrencyId, _who: &T::AccountId) {}
+}
@@ -0,0 +1,128 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{mock::*, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+
+#[test]
+fn only_authority_can_freeze_registered_assets() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AssetFreezer::set_authority(RuntimeOrigin::signed(ALICE), DOT, Some(ALICE)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AssetFreezer::freeze(RuntimeOrigin::signed(ALICE), DOT, BOB, H256::zero()),
+            Error::<Test>::AssetNotRegistered
+        );
+        assert_noop!(
+            AssetFreezer::freeze(RuntimeOrigin::signed(BOB), USDT, ALICE, H256::zero()),
+            Error::<Test>::NotAuthority
+        );
+        assert_noop!(
+            AssetFreezer::thaw(RuntimeOrigin::signed(ALICE), USDT, BOB, H256::zero()),
+            Error::<Test>::NotFrozen
+        );
+    })
+}
+
+#[test]
+fn frozen_account_cannot_move_the_asset() {
+    new_test_ext().execute_with(|| {
+        let reason = H256::repeat_byte(1);
+        assert_ok!(AssetFreezer::freeze(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            BOB,
+            reason
+        ));
+        System::assert_last_event(RuntimeEvent::AssetFreezer(Event::AccountFrozen(
+            USDT, BOB, ALICE, reason,
+        )));
+        assert_noop!(
+            AssetFreezer::freeze(RuntimeOrigin::signed(ALICE), USDT, BOB, reason),
+            Error::<Test>::AlreadyFrozen
+        );
+
+        assert_noop!(
+            Assets::transfer(RuntimeOrigin::signed(BOB), USDT, CHARLIE, dollar(1)),
+            pallet_assets::Error::<Test>::BalanceLow
+        );
+        // can still receive it, other assets and accounts aren't affected
+        assert_ok!(Assets::transfer(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            BOB,
+            dollar(1)
+        ));
+        assert_ok!(Assets::transfer(
+            RuntimeOrigin::signed(BOB),
+            DOT,
+            CHARLIE,
+            dollar(1)
+        ));
+        assert_ok!(Assets::transfer(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            CHARLIE,
+            dollar(1)
+        ));
+
+        assert_ok!(AssetFreezer::thaw(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            BOB,
+            reason
+        ));
+        System::assert_last_event(RuntimeEvent::AssetFreezer(Event::AccountThawed(
+            USDT, BOB, ALICE, reason,
+        )));
+        assert_ok!(Assets::transfer(
+            RuntimeOrigin::signed(BOB),
+            USDT,
+            CHARLIE,
+            dollar(101)
+        ));
+    })
+}
+
+#[test]
+fn asset_cannot_be_unregistered_with_frozen_accounts() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetFreezer::freeze(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            BOB,
+            H256::zero()
+        ));
+        assert_noop!(
+            AssetFreezer::set_authority(RuntimeOrigin::root(), USDT, None),
+            Error::<Test>::FrozenAccountsExist
+        );
+
+        assert_ok!(AssetFreezer::thaw(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            BOB,
+            H256::zero()
+        ));
+        assert_ok!(AssetFreezer::set_authority(
+            RuntimeOrigin::root(),
+            USDT,
+            None
+        ));
+        assert_eq!(AssetFreezer::authorities(USDT), None);
+    })
+}
@@ -13,11 +13,43 @@
 // limitations under the License.
 
 #![cfg(feature = "runtime-benchmarks")]
-use crate::{Call, Config, Pallet};
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use crate::{AssetMetadata, AssetMetadataOf, AssetParams, Call, Config, Pallet, XcmFeeAssets};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
 use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+use sp_std::{boxed::Box, vec};
 use xcm::latest::prelude::*;
 
+fn proposed_location() -> MultiLocation {
+    MultiLocation::new(1, X2(Parachain(2000), GeneralIndex(1)))
+}
+
+fn metadata<T: Config>() -> AssetMetadataOf<T> {
+    let string = vec![b'A'; T::StringLimit::get() as usize];
+    AssetMetadata {
+        name: string.clone().try_into().unwrap(),
+        symbol: string.try_into().unwrap(),
+        decimals: 12,
+    }
+}
+
+fn propose<T: Config>() -> (T::AccountId, T::AssetId) {
+    let caller: T::AccountId = whitelisted_caller();
+    T::Currency::make_free_balance_be(
+        &caller,
+        T::RegistrationDeposit::get().saturating_mul(10u32.into()),
+    );
+    Pallet::<T>::propose_asset(
+        RawOrigin::Signed(caller.clone()).into(),
+        Box::new(proposed_location().into()),
+        metadata::<T>(),
+    )
+    .unwrap();
+    let asset_type: T::AssetType = proposed_location().into();
+    (caller, asset_type.into())
+}
+
 benchmarks! {
     // This where clause allows us to create assetTypes
     where_clause { where T::AssetType: From<MultiLocation> }
@@ -95,6 +127,66 @@ benchmarks! {
         assert!(Pallet::<T>::asset_type_units_per_second(&asset_type_to_be_removed).is_none());
         assert!(!Pallet::<T>::supported_fee_payment_assets().contains(&asset_type_to_be_removed));
     }
+
+    propose_asset {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::RegistrationDeposit::get().saturating_mul(10u32.into()));
+        let asset_type: T::AssetType = proposed_location().into();
+        let asset_id: T::AssetId = asset_type.into();
+    }: _(RawOrigin::Signed(caller), Box::new(proposed_location().into()), metadata::<T>())
+    verify {
+        assert!(Pallet::<T>::pending_asset(asset_id).is_some());
+    }
+
+    approve_asset {
+        let (_, asset_id) = propose::<T>();
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(Pallet::<T>::asset_id_type(asset_id).is_some());
+    }
+
+    reject_asset {
+        let (_, asset_id) = propose::<T>();
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(Pallet::<T>::pending_asset(asset_id).is_none());
+    }
+
+    finalize_asset {
+        let (caller, asset_id) = propose::<T>();
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number().saturating_add(T::ChallengePeriod::get())
+        );
+    }: _(RawOrigin::Signed(caller), asset_id)
+    verify {
+        assert!(Pallet::<T>::asset_id_type(asset_id).is_some());
+        assert!(Pallet::<T>::asset_metadata(asset_id).is_some());
+    }
+
+    set_asset_params {
+        let asset_type: T::AssetType = proposed_location().into();
+        let asset_id: T::AssetId = asset_type.clone().into();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), asset_id, asset_type)?;
+        let params = AssetParams {
+            existential_deposit: 1_000u32.into(),
+            decimals: 6,
+            is_sufficient: true,
+        };
+    }: _(RawOrigin::Root, asset_id, params)
+    verify {
+        assert_eq!(Pallet::<T>::asset_params(asset_id), Some(params));
+    }
+
+    remove_xcm_fee_asset {
+        let asset_type: T::AssetType = proposed_location().into();
+        let asset_id: T::AssetId = asset_type.clone().into();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), asset_id, asset_type)?;
+        // accepted without the validator, which needs prices and pools of the runtime
+        XcmFeeAssets::<T>::insert(asset_id, ());
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(!Pallet::<T>::is_xcm_fee_asset(&asset_id));
+    }
 }
 
 #[cfg(test)]
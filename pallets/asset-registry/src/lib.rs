@@ -15,11 +15,29 @@
 //! # Asset registry pallet
 //!
 //! This pallet allows to register new assets
+//!
+//! The locations of xcm assets are also kept in their versioned form, which allows to
+//! rebuild the asset types after the latest xcm version changes their encoding.
+//!
+//! Besides governance, anyone can propose a foreign asset along with its metadata by
+//! reserving a deposit. The asset is registered once governance approves it, or by anyone
+//! after the challenge period if it wasn't rejected, and the deposit is then returned.
+//! The deposit of rejected assets is slashed.
+//!
+//! Governance can also set the existential deposit, decimals and sufficiency of each
+//! registered asset, which are applied to the pallet holding the asset balances.
+//!
+//! Assets without units per second can buy xcm execution at their oracle price once
+//! governance accepts them as xcm fee assets, which are checked by `XcmFeeAssetValidator`.
 #![cfg_attr(not(feature = "std"), no_std)]
-use frame_support::pallet;
+use frame_support::{pallet, traits::ReservableCurrency, RuntimeDebug};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use xcm::{latest::MultiLocation, VersionedMultiLocation};
 
 #[cfg(any(test, feature = "runtime-benchmarks"))]
 mod benchmarks;
+pub mod migrations;
 #[cfg(test)]
 pub mod mock;
 #[cfg(test)]
@@ -28,14 +46,69 @@ pub mod weights;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// Metadata of an asset proposed by an account
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AssetMetadata<BoundedString> {
+    pub name: BoundedString,
+    pub symbol: BoundedString,
+    pub decimals: u8,
+}
+
+/// Asset proposed by an account, waiting to be registered
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PendingAsset<AccountId, Balance, BlockNumber, AssetType, BoundedString> {
+    /// Account which reserved the deposit
+    pub depositor: AccountId,
+    pub deposit: Balance,
+    pub asset_type: AssetType,
+    pub metadata: AssetMetadata<BoundedString>,
+    /// Block from which the asset can be registered without approval
+    pub challenge_end: BlockNumber,
+}
+
+/// Parameters of an asset, set by governance
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AssetParams<Balance> {
+    /// Min balance of an account holding the asset
+    pub existential_deposit: Balance,
+    pub decimals: u8,
+    /// Whether holding the asset is enough for an account to exist
+    pub is_sufficient: bool,
+}
+
+/// Utility type for managing upgrades/migrations.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum Versions {
+    V1,
+    V2,
+}
+
 #[pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, OnUnbalanced, ReservableCurrency},
+    };
     use frame_system::pallet_prelude::*;
+    use pallet_traits::xcm::{AssetConfigurator, XcmFeeAssetValidator};
     use parity_scale_codec::HasCompact;
-    use sp_runtime::traits::AtLeast32BitUnsigned;
-    use sp_std::vec::Vec;
+    use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating, Zero};
+    use sp_std::{boxed::Box, vec::Vec};
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
+    pub type AssetMetadataOf<T> = AssetMetadata<BoundedVec<u8, <T as Config>::StringLimit>>;
+    pub type PendingAssetOf<T> = PendingAsset<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+        <T as Config>::AssetType,
+        BoundedVec<u8, <T as Config>::StringLimit>,
+    >;
 
     #[pallet::pallet]
     #[pallet::without_storage_info]
@@ -49,7 +122,14 @@ pub mod pallet {
         type AssetId: Member + Parameter + Default + Copy + HasCompact + MaxEncodedLen;
 
         /// The Asset Kind.
-        type AssetType: Parameter + Member + Ord + PartialOrd + Into<Self::AssetId> + Default;
+        type AssetType: Parameter
+            + Member
+            + Ord
+            + PartialOrd
+            + Into<Self::AssetId>
+            + From<MultiLocation>
+            + Into<Option<MultiLocation>>
+            + Default;
 
         /// The units in which we record balances.
         type Balance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
@@ -57,6 +137,30 @@ pub mod pallet {
         /// Origin that is allowed to create and modify asset information
         type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+        /// The currency the registration deposits are reserved in
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Deposit reserved to propose an asset
+        #[pallet::constant]
+        type RegistrationDeposit: Get<BalanceOf<Self>>;
+
+        /// Number of blocks during which a proposed asset can be rejected
+        #[pallet::constant]
+        type ChallengePeriod: Get<Self::BlockNumber>;
+
+        /// Max length of the name and symbol of a proposed asset
+        #[pallet::constant]
+        type StringLimit: Get<u32>;
+
+        /// Handler for the deposits of rejected assets
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Applies the params of the assets to the pallet holding their balances
+        type AssetConfigurator: AssetConfigurator<Self::AssetId, Self::Balance>;
+
+        /// Checks the assets accepted to buy xcm execution at their oracle price
+        type XcmFeeAssetValidator: XcmFeeAssetValidator<Self::AssetId>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -65,6 +169,20 @@ pub mod pallet {
     pub enum Error<T> {
         AssetAlreadyExists,
         AssetDoesNotExist,
+        /// Asset is already proposed
+        AssetAlreadyPending,
+        /// Asset isn't proposed
+        AssetNotPending,
+        /// Location can't be converted to an asset type
+        UnsupportedLocation,
+        /// Proposed asset can't be registered before the end of its challenge period
+        ChallengePeriodNotEnded,
+        /// Existential deposit of an asset can't be zero
+        InvalidAssetParams,
+        /// Asset is already accepted as xcm fee asset
+        XcmFeeAssetAlreadyExists,
+        /// Asset isn't accepted as xcm fee asset
+        XcmFeeAssetNotFound,
     }
 
     #[pallet::event]
@@ -92,6 +210,27 @@ pub mod pallet {
         },
         /// Supported asset type for fee payment removed
         FeePaymentAssetRemoved { asset_type: T::AssetType },
+        /// Asset was proposed, it can be rejected until the end of the challenge period
+        AssetProposed {
+            asset_id: T::AssetId,
+            asset_type: T::AssetType,
+            depositor: T::AccountId,
+            challenge_end: T::BlockNumber,
+        },
+        /// Params of an asset were updated
+        AssetParamsUpdated {
+            asset_id: T::AssetId,
+            params: AssetParams<T::Balance>,
+        },
+        /// Proposed asset was rejected and its deposit slashed
+        AssetRejected {
+            asset_id: T::AssetId,
+            asset_type: T::AssetType,
+        },
+        /// Asset was accepted to buy xcm execution at its oracle price
+        XcmFeeAssetAdded { asset_id: T::AssetId },
+        /// Asset can no longer buy xcm execution at its oracle price
+        XcmFeeAssetRemoved { asset_id: T::AssetId },
     }
 
     /// Mapping from an asset id to asset type.
@@ -122,6 +261,74 @@ pub mod pallet {
     #[pallet::getter(fn supported_fee_payment_assets)]
     pub type SupportedFeePaymentAssets<T: Config> = StorageValue<_, Vec<T::AssetType>, ValueQuery>;
 
+    /// Versioned location of the assets registered with an xcm asset type.
+    /// Unlike the asset types, these stay decodable across xcm upgrades.
+    #[pallet::storage]
+    #[pallet::getter(fn asset_id_location)]
+    pub type AssetIdLocation<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, VersionedMultiLocation>;
+
+    /// Assets proposed by accounts, waiting to be registered
+    #[pallet::storage]
+    #[pallet::getter(fn pending_asset)]
+    pub type PendingAssets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, PendingAssetOf<T>>;
+
+    /// Metadata of the assets registered from a proposal
+    #[pallet::storage]
+    #[pallet::getter(fn asset_metadata)]
+    pub type AssetMetadatas<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, AssetMetadataOf<T>>;
+
+    /// Existential deposit, decimals and sufficiency of the assets
+    #[pallet::storage]
+    #[pallet::getter(fn asset_params)]
+    pub type AssetParameters<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, AssetParams<T::Balance>>;
+
+    /// Assets which can buy xcm execution at their oracle price
+    /// AssetId -> ()
+    #[pallet::storage]
+    pub type XcmFeeAssets<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, ()>;
+
+    /// DefaultVersion is using for initialize the StorageVersion
+    #[pallet::type_value]
+    pub(super) fn DefaultVersion<T: Config>() -> Versions {
+        Versions::V1
+    }
+
+    /// Storage version of the pallet.
+    #[pallet::storage]
+    pub(crate) type StorageVersion<T: Config> =
+        StorageValue<_, Versions, ValueQuery, DefaultVersion<T>>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            migrations::v2::migrate::<T>()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            // Nothing to check once the migration was applied on chain
+            let migrating = StorageVersion::<T>::get() == Versions::V1;
+            if migrating {
+                migrations::v2::pre_migrate::<T>()?;
+            }
+            Ok(migrating.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let migrating =
+                bool::decode(&mut &state[..]).map_err(|_| "pre_upgrade state must decode")?;
+            if migrating {
+                migrations::v2::post_migrate::<T>()?;
+            }
+            Ok(())
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register new asset with the asset registry
@@ -137,14 +344,12 @@ pub mod pallet {
                 !AssetIdType::<T>::contains_key(&asset_id),
                 Error::<T>::AssetAlreadyExists
             );
+            ensure!(
+                !PendingAssets::<T>::contains_key(&asset_id),
+                Error::<T>::AssetAlreadyPending
+            );
 
-            AssetIdType::<T>::insert(&asset_id, &asset_type);
-            AssetTypeId::<T>::insert(&asset_type, &asset_id);
-
-            Self::deposit_event(Event::AssetRegistered {
-                asset_id,
-                asset_type,
-            });
+            Self::do_register_asset(asset_id, asset_type);
             Ok(())
         }
 
@@ -200,6 +405,7 @@ pub mod pallet {
             // Insert new asset type info
             AssetIdType::<T>::insert(&asset_id, &new_asset_type);
             AssetTypeId::<T>::insert(&new_asset_type, &asset_id);
+            Self::set_asset_location(&asset_id, &new_asset_type);
 
             // Remove previous asset type info
             AssetTypeId::<T>::remove(&previous_asset_type);
@@ -273,6 +479,14 @@ pub mod pallet {
             AssetTypeId::<T>::remove(&asset_type);
             // Remove previous asset type units per second
             AssetTypeUnitsPerSecond::<T>::remove(&asset_type);
+            // Remove from AssetIdLocation
+            AssetIdLocation::<T>::remove(&asset_id);
+            // Remove from AssetMetadatas
+            AssetMetadatas::<T>::remove(&asset_id);
+            // Remove from AssetParameters
+            AssetParameters::<T>::remove(&asset_id);
+            // Remove from XcmFeeAssets
+            XcmFeeAssets::<T>::remove(&asset_id);
 
             // Only if the old asset is supported we need to remove it
             if let Ok(index) = supported_assets.binary_search(&asset_type) {
@@ -288,6 +502,204 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Propose the asset at `location` by reserving the registration deposit
+        #[pallet::weight(T::WeightInfo::propose_asset())]
+        pub fn propose_asset(
+            origin: OriginFor<T>,
+            location: Box<VersionedMultiLocation>,
+            metadata: AssetMetadataOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let asset_type =
+                Self::asset_type_of(*location).ok_or(Error::<T>::UnsupportedLocation)?;
+            let asset_id: T::AssetId = asset_type.clone().into();
+            ensure!(
+                !AssetIdType::<T>::contains_key(&asset_id)
+                    && !AssetTypeId::<T>::contains_key(&asset_type),
+                Error::<T>::AssetAlreadyExists
+            );
+            ensure!(
+                !PendingAssets::<T>::contains_key(&asset_id),
+                Error::<T>::AssetAlreadyPending
+            );
+
+            let deposit = T::RegistrationDeposit::get();
+            T::Currency::reserve(&who, deposit)?;
+            let challenge_end =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::ChallengePeriod::get());
+            PendingAssets::<T>::insert(
+                &asset_id,
+                PendingAsset {
+                    depositor: who.clone(),
+                    deposit,
+                    asset_type: asset_type.clone(),
+                    metadata,
+                    challenge_end,
+                },
+            );
+
+            Self::deposit_event(Event::AssetProposed {
+                asset_id,
+                asset_type,
+                depositor: who,
+                challenge_end,
+            });
+            Ok(())
+        }
+
+        /// Register a proposed asset before the end of its challenge period
+        #[pallet::weight(T::WeightInfo::approve_asset())]
+        pub fn approve_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            let pending = PendingAssets::<T>::take(&asset_id).ok_or(Error::<T>::AssetNotPending)?;
+            Self::do_register_pending_asset(asset_id, pending);
+            Ok(())
+        }
+
+        /// Reject a proposed asset, its deposit is slashed
+        #[pallet::weight(T::WeightInfo::reject_asset())]
+        pub fn reject_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            let pending = PendingAssets::<T>::take(&asset_id).ok_or(Error::<T>::AssetNotPending)?;
+            let (imbalance, _) = T::Currency::slash_reserved(&pending.depositor, pending.deposit);
+            T::Slash::on_unbalanced(imbalance);
+
+            Self::deposit_event(Event::AssetRejected {
+                asset_id,
+                asset_type: pending.asset_type,
+            });
+            Ok(())
+        }
+
+        /// Register a proposed asset which wasn't rejected during its challenge period
+        #[pallet::weight(T::WeightInfo::finalize_asset())]
+        pub fn finalize_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let pending = Self::pending_asset(&asset_id).ok_or(Error::<T>::AssetNotPending)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= pending.challenge_end,
+                Error::<T>::ChallengePeriodNotEnded
+            );
+
+            PendingAssets::<T>::remove(&asset_id);
+            Self::do_register_pending_asset(asset_id, pending);
+            Ok(())
+        }
+
+        /// Set the existential deposit, decimals and sufficiency of a registered asset
+        #[pallet::weight(T::WeightInfo::set_asset_params())]
+        pub fn set_asset_params(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            params: AssetParams<T::Balance>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                AssetIdType::<T>::contains_key(&asset_id),
+                Error::<T>::AssetDoesNotExist
+            );
+            ensure!(
+                !params.existential_deposit.is_zero(),
+                Error::<T>::InvalidAssetParams
+            );
+
+            T::AssetConfigurator::configure(
+                asset_id,
+                params.existential_deposit,
+                params.is_sufficient,
+            )?;
+            // A new existential deposit may no longer be covered by the fees
+            if Self::is_xcm_fee_asset(&asset_id) {
+                T::XcmFeeAssetValidator::validate(asset_id)?;
+            }
+            AssetParameters::<T>::insert(&asset_id, params);
+
+            Self::deposit_event(Event::AssetParamsUpdated { asset_id, params });
+            Ok(())
+        }
+
+        /// Accept a registered asset to buy xcm execution at its oracle price,
+        /// once it passes the checks of `XcmFeeAssetValidator`
+        #[pallet::weight(T::WeightInfo::add_xcm_fee_asset())]
+        pub fn add_xcm_fee_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                AssetIdType::<T>::contains_key(&asset_id),
+                Error::<T>::AssetDoesNotExist
+            );
+            ensure!(
+                !Self::is_xcm_fee_asset(&asset_id),
+                Error::<T>::XcmFeeAssetAlreadyExists
+            );
+            T::XcmFeeAssetValidator::validate(asset_id)?;
+            XcmFeeAssets::<T>::insert(&asset_id, ());
+
+            Self::deposit_event(Event::XcmFeeAssetAdded { asset_id });
+            Ok(())
+        }
+
+        /// Stop accepting an asset to buy xcm execution at its oracle price
+        #[pallet::weight(T::WeightInfo::remove_xcm_fee_asset())]
+        pub fn remove_xcm_fee_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                Self::is_xcm_fee_asset(&asset_id),
+                Error::<T>::XcmFeeAssetNotFound
+            );
+            XcmFeeAssets::<T>::remove(&asset_id);
+
+            Self::deposit_event(Event::XcmFeeAssetRemoved { asset_id });
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Asset type of `location`, translated to the latest xcm version
+    pub fn asset_type_of(location: VersionedMultiLocation) -> Option<T::AssetType> {
+        MultiLocation::try_from(location).ok().map(Into::into)
+    }
+
+    /// Asset id registered for `location`, which can be given in any supported xcm version
+    pub fn asset_id_of(location: VersionedMultiLocation) -> Option<T::AssetId> {
+        Self::asset_type_of(location).and_then(AssetTypeId::<T>::get)
+    }
+
+    /// Whether `asset_id` can buy xcm execution at its oracle price
+    pub fn is_xcm_fee_asset(asset_id: &T::AssetId) -> bool {
+        XcmFeeAssets::<T>::contains_key(asset_id)
+    }
+
+    fn do_register_asset(asset_id: T::AssetId, asset_type: T::AssetType) {
+        AssetIdType::<T>::insert(&asset_id, &asset_type);
+        AssetTypeId::<T>::insert(&asset_type, &asset_id);
+        Self::set_asset_location(&asset_id, &asset_type);
+
+        Self::deposit_event(Event::AssetRegistered {
+            asset_id,
+            asset_type,
+        });
+    }
+
+    /// Register a proposed asset with its metadata and return its deposit
+    fn do_register_pending_asset(asset_id: T::AssetId, pending: PendingAssetOf<T>) {
+        T::Currency::unreserve(&pending.depositor, pending.deposit);
+        AssetMetadatas::<T>::insert(&asset_id, pending.metadata);
+        Self::do_register_asset(asset_id, pending.asset_type);
+    }
+
+    /// Keep the versioned location of `asset_id` in sync with its asset type
+    pub(crate) fn set_asset_location(asset_id: &T::AssetId, asset_type: &T::AssetType) {
+        let location: Option<MultiLocation> = asset_type.clone().into();
+        AssetIdLocation::<T>::set(asset_id, location.map(VersionedMultiLocation::from));
     }
 }
 
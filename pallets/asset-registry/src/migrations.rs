@@ -0,0 +1,135 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{log, storage::unhashed, traits::Get, weights::Weight};
+use parity_scale_codec::EncodeLike;
+use sp_std::vec::Vec;
+
+/// Record the versioned location of the registered xcm assets
+pub mod v2 {
+    use super::*;
+
+    pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+        frame_support::ensure!(StorageVersion::<T>::get() == Versions::V1, "must be V1");
+        Ok(())
+    }
+
+    pub fn migrate<T: Config>() -> Weight {
+        if StorageVersion::<T>::get() != Versions::V1 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        log::info!(
+            target: "asset-registry::migrate",
+            "migrating asset registry to Versions::V2"
+        );
+        let mut count = 0u64;
+        for (asset_id, asset_type) in AssetIdType::<T>::iter() {
+            Pallet::<T>::set_asset_location(&asset_id, &asset_type);
+            count += 1;
+        }
+        StorageVersion::<T>::put(Versions::V2);
+        log::info!(
+            target: "asset-registry::migrate",
+            "completed asset registry migration to Versions::V2, {} assets",
+            count
+        );
+
+        T::DbWeight::get().reads_writes(count + 1, count + 1)
+    }
+
+    pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+        frame_support::ensure!(StorageVersion::<T>::get() == Versions::V2, "must be V2");
+        for (asset_id, asset_type) in AssetIdType::<T>::iter() {
+            let location: Option<MultiLocation> = asset_type.into();
+            frame_support::ensure!(
+                location.map(VersionedMultiLocation::from) == AssetIdLocation::<T>::get(asset_id),
+                "asset location must be recorded"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// An already encoded storage key
+struct RawKey(Vec<u8>);
+
+impl Encode for RawKey {
+    fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+        f(&self.0)
+    }
+}
+
+impl<T: Encode> EncodeLike<T> for RawKey {}
+
+/// Rebuild the asset types from the versioned locations of the assets.
+///
+/// Must be run by the runtime upgrade which changes the latest xcm version, since the
+/// asset types and the maps keyed by them are then encoded with the previous version.
+/// Asset ids are left untouched so balances held in these assets are kept.
+pub fn reindex_asset_types<T: Config>() -> Weight {
+    let mut reads = 0u64;
+    let mut writes = 0u64;
+    for (asset_id, location) in AssetIdLocation::<T>::iter() {
+        reads += 2;
+        let new_asset_type = match Pallet::<T>::asset_type_of(location.clone()) {
+            Some(asset_type) => asset_type,
+            None => {
+                log::warn!(
+                    target: "asset-registry::migrate",
+                    "asset {:?} has unsupported location {:?}",
+                    asset_id,
+                    location
+                );
+                continue;
+            }
+        };
+
+        // The previous asset type may not be decodable anymore, use its raw encoding
+        if let Some(old_asset_type) = unhashed::get_raw(&AssetIdType::<T>::hashed_key_for(asset_id))
+        {
+            if old_asset_type == new_asset_type.encode() {
+                continue;
+            }
+            unhashed::kill(&AssetTypeId::<T>::hashed_key_for(RawKey(
+                old_asset_type.clone(),
+            )));
+            if let Some(units) = unhashed::take::<u128>(
+                &AssetTypeUnitsPerSecond::<T>::hashed_key_for(RawKey(old_asset_type)),
+            ) {
+                AssetTypeUnitsPerSecond::<T>::insert(&new_asset_type, units);
+                writes += 1;
+            }
+            reads += 1;
+            writes += 2;
+        }
+
+        AssetIdType::<T>::insert(&asset_id, &new_asset_type);
+        AssetTypeId::<T>::insert(&new_asset_type, &asset_id);
+        writes += 2;
+    }
+
+    // Fee payment assets are asset types as well, rebuild them from the units per second
+    let mut supported_assets: Vec<T::AssetType> = AssetIdType::<T>::iter_values()
+        .filter(|asset_type| {
+            reads += 2;
+            AssetTypeUnitsPerSecond::<T>::contains_key(asset_type)
+        })
+        .collect();
+    supported_assets.sort();
+    SupportedFeePaymentAssets::<T>::put(supported_assets);
+
+    T::DbWeight::get().reads_writes(reads, writes + 1)
+}
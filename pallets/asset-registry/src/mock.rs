@@ -24,6 +24,7 @@ use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, Hash as THash, IdentityLookup},
 };
+use sp_std::cell::RefCell;
 use xcm::latest::prelude::*;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -142,20 +143,85 @@ impl Into<Option<MultiLocation>> for MockAssetType {
     }
 }
 
+thread_local! {
+    pub static CONFIGURED_ASSETS: RefCell<Vec<(AssetId, u64, bool)>> = RefCell::new(Vec::new());
+}
+
+/// Records the configured assets
+pub struct TestAssetConfigurator;
+impl pallet_traits::xcm::AssetConfigurator<AssetId, u64> for TestAssetConfigurator {
+    fn configure(
+        asset_id: AssetId,
+        existential_deposit: u64,
+        is_sufficient: bool,
+    ) -> frame_support::dispatch::DispatchResult {
+        CONFIGURED_ASSETS.with(|c| {
+            c.borrow_mut()
+                .push((asset_id, existential_deposit, is_sufficient))
+        });
+        Ok(())
+    }
+}
+
+pub fn configured_assets() -> Vec<(AssetId, u64, bool)> {
+    CONFIGURED_ASSETS.with(|c| c.borrow().clone())
+}
+
+pub const MAX_FEE_ASSET_DEPOSIT: u64 = 1_000;
+
+/// Accepts the assets whose last configured existential deposit is covered by the fees
+pub struct TestXcmFeeAssetValidator;
+impl pallet_traits::xcm::XcmFeeAssetValidator<AssetId> for TestXcmFeeAssetValidator {
+    fn validate(asset_id: AssetId) -> frame_support::dispatch::DispatchResult {
+        let existential_deposit = configured_assets()
+            .into_iter()
+            .rev()
+            .find(|(id, _, _)| *id == asset_id)
+            .map_or(0, |(_, existential_deposit, _)| existential_deposit);
+        if existential_deposit > MAX_FEE_ASSET_DEPOSIT {
+            return Err(FEE_ASSET_ERROR.into());
+        }
+        Ok(())
+    }
+}
+
+pub const FEE_ASSET_ERROR: &str = "fee asset min balance is above its fees";
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+
+parameter_types! {
+    pub const RegistrationDeposit: u64 = 100;
+    pub const ChallengePeriod: u64 = 10;
+}
+
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Balance = u64;
     type AssetId = u32;
     type AssetType = MockAssetType;
     type UpdateOrigin = EnsureRoot<u64>;
+    type Currency = Balances;
+    type RegistrationDeposit = RegistrationDeposit;
+    type ChallengePeriod = ChallengePeriod;
+    type StringLimit = StringLimit;
+    type Slash = ();
+    type AssetConfigurator = TestAssetConfigurator;
+    type XcmFeeAssetValidator = TestXcmFeeAssetValidator;
     type WeightInfo = ();
 }
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
-    let t = frame_system::GenesisConfig::default()
+    let mut t = frame_system::GenesisConfig::default()
         .build_storage::<Test>()
         .unwrap();
 
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, 1_000), (BOB, 1_000)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
     let mut ext = sp_io::TestExternalities::new(t);
     ext.execute_with(|| System::set_block_number(1));
     ext
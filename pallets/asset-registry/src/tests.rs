@@ -17,6 +17,7 @@ use crate::*;
 use mock::*;
 
 use frame_support::{assert_noop, assert_ok};
+use xcm::latest::prelude::*;
 
 #[test]
 fn registering_works() {
@@ -411,3 +412,379 @@ fn test_removing_without_asset_units_per_second_does_not_panic() {
         ])
     });
 }
+
+#[test]
+fn xcm_assets_should_keep_versioned_location() {
+    new_test_ext().execute_with(|| {
+        let location = MultiLocation::new(1, X1(Parachain(1000)));
+        let asset_type = MockAssetType::Xcm(location.clone());
+        let asset_id: AssetId = asset_type.clone().into();
+        assert_ok!(AssetRegistry::register_asset(
+            RuntimeOrigin::root(),
+            asset_id,
+            asset_type,
+        ));
+        assert_eq!(
+            AssetRegistry::asset_id_location(asset_id),
+            Some(VersionedMultiLocation::V1(location.clone()))
+        );
+        assert_eq!(
+            AssetRegistry::asset_id_of(VersionedMultiLocation::V0(xcm::v0::MultiLocation::X2(
+                xcm::v0::Junction::Parent,
+                xcm::v0::Junction::Parachain(1000),
+            ))),
+            Some(asset_id)
+        );
+
+        // Asset types without location are not recorded
+        assert_ok!(AssetRegistry::update_asset_type(
+            RuntimeOrigin::root(),
+            asset_id,
+            MockAssetType::MockAsset(1),
+        ));
+        assert_eq!(AssetRegistry::asset_id_location(asset_id), None);
+        assert_eq!(AssetRegistry::asset_id_of(location.into()), None);
+
+        assert_ok!(AssetRegistry::update_asset_type(
+            RuntimeOrigin::root(),
+            asset_id,
+            MultiLocation::parent().into(),
+        ));
+        assert_ok!(AssetRegistry::deregister_asset(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        assert_eq!(AssetRegistry::asset_id_location(asset_id), None);
+    });
+}
+
+#[test]
+fn migration_should_record_locations_of_registered_assets() {
+    new_test_ext().execute_with(|| {
+        let asset_type: MockAssetType = MultiLocation::parent().into();
+        AssetIdType::<Test>::insert(1, asset_type.clone());
+        AssetTypeId::<Test>::insert(asset_type, 1);
+        AssetIdType::<Test>::insert(2, MockAssetType::MockAsset(2));
+        AssetTypeId::<Test>::insert(MockAssetType::MockAsset(2), 2);
+
+        assert_ok!(migrations::v2::pre_migrate::<Test>());
+        migrations::v2::migrate::<Test>();
+        assert_ok!(migrations::v2::post_migrate::<Test>());
+
+        assert_eq!(
+            AssetRegistry::asset_id_location(1),
+            Some(MultiLocation::parent().into())
+        );
+        assert_eq!(AssetRegistry::asset_id_location(2), None);
+        assert_eq!(StorageVersion::<Test>::get(), Versions::V2);
+    });
+}
+
+#[test]
+fn reindex_should_rebuild_asset_types_from_locations() {
+    new_test_ext().execute_with(|| {
+        let location = MultiLocation::new(1, X1(Parachain(2000)));
+        let asset_id: AssetId = MockAssetType::Xcm(location.clone()).into();
+
+        // Asset type encoded differently than the one derived from the location,
+        // as it happens when the latest xcm version changes
+        let legacy_asset_type = MockAssetType::Xcm(MultiLocation::new(1, X1(Parachain(0))));
+        AssetIdType::<Test>::insert(asset_id, legacy_asset_type.clone());
+        AssetTypeId::<Test>::insert(legacy_asset_type.clone(), asset_id);
+        AssetTypeUnitsPerSecond::<Test>::insert(legacy_asset_type.clone(), 100);
+        SupportedFeePaymentAssets::<Test>::put(vec![legacy_asset_type.clone()]);
+        AssetIdLocation::<Test>::insert(asset_id, VersionedMultiLocation::from(location.clone()));
+
+        migrations::reindex_asset_types::<Test>();
+
+        let asset_type = MockAssetType::Xcm(location.clone());
+        assert_eq!(
+            AssetRegistry::asset_id_type(asset_id),
+            Some(asset_type.clone())
+        );
+        assert_eq!(
+            AssetRegistry::asset_type_id(asset_type.clone()),
+            Some(asset_id)
+        );
+        assert_eq!(AssetRegistry::asset_id_of(location.into()), Some(asset_id));
+        assert_eq!(
+            AssetRegistry::asset_type_id(legacy_asset_type.clone()),
+            None
+        );
+        assert_eq!(
+            AssetRegistry::asset_type_units_per_second(asset_type.clone()),
+            Some(100)
+        );
+        assert_eq!(
+            AssetRegistry::asset_type_units_per_second(legacy_asset_type),
+            None
+        );
+        assert_eq!(
+            AssetRegistry::supported_fee_payment_assets(),
+            vec![asset_type]
+        );
+    });
+}
+
+fn proposed_location() -> MultiLocation {
+    MultiLocation::new(1, X2(Parachain(2000), GeneralIndex(1)))
+}
+
+fn metadata() -> AssetMetadataOf<Test> {
+    AssetMetadata {
+        name: b"Acala Dollar".to_vec().try_into().unwrap(),
+        symbol: b"AUSD".to_vec().try_into().unwrap(),
+        decimals: 12,
+    }
+}
+
+fn propose(who: u64) -> u32 {
+    assert_ok!(AssetRegistry::propose_asset(
+        RuntimeOrigin::signed(who),
+        Box::new(proposed_location().into()),
+        metadata(),
+    ));
+    MockAssetType::Xcm(proposed_location()).into()
+}
+
+#[test]
+fn propose_asset_should_reserve_deposit() {
+    new_test_ext().execute_with(|| {
+        let asset_id = propose(ALICE);
+        assert_eq!(Balances::reserved_balance(ALICE), 100);
+        assert_eq!(
+            AssetRegistry::pending_asset(asset_id),
+            Some(PendingAsset {
+                depositor: ALICE,
+                deposit: 100,
+                asset_type: MockAssetType::Xcm(proposed_location()),
+                metadata: metadata(),
+                challenge_end: 11,
+            })
+        );
+        assert!(AssetRegistry::asset_id_type(asset_id).is_none());
+
+        assert_noop!(
+            AssetRegistry::propose_asset(
+                RuntimeOrigin::signed(BOB),
+                Box::new(proposed_location().into()),
+                metadata(),
+            ),
+            Error::<Test>::AssetAlreadyPending
+        );
+        assert_noop!(
+            AssetRegistry::register_asset(
+                RuntimeOrigin::root(),
+                asset_id,
+                MockAssetType::Xcm(proposed_location()),
+            ),
+            Error::<Test>::AssetAlreadyPending
+        );
+    });
+}
+
+#[test]
+fn approve_asset_should_register_it() {
+    new_test_ext().execute_with(|| {
+        let asset_id = propose(ALICE);
+        assert_noop!(
+            AssetRegistry::approve_asset(RuntimeOrigin::signed(BOB), asset_id),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(AssetRegistry::approve_asset(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(AssetRegistry::pending_asset(asset_id), None);
+        assert_eq!(AssetRegistry::asset_metadata(asset_id), Some(metadata()));
+        assert_eq!(
+            AssetRegistry::asset_id_of(proposed_location().into()),
+            Some(asset_id)
+        );
+
+        // Registered locations can't be proposed again
+        assert_noop!(
+            AssetRegistry::propose_asset(
+                RuntimeOrigin::signed(BOB),
+                Box::new(proposed_location().into()),
+                metadata(),
+            ),
+            Error::<Test>::AssetAlreadyExists
+        );
+
+        assert_ok!(AssetRegistry::deregister_asset(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        assert_eq!(AssetRegistry::asset_metadata(asset_id), None);
+    });
+}
+
+#[test]
+fn reject_asset_should_slash_deposit() {
+    new_test_ext().execute_with(|| {
+        let asset_id = propose(ALICE);
+        assert_ok!(AssetRegistry::reject_asset(RuntimeOrigin::root(), asset_id));
+
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 900);
+        assert_eq!(AssetRegistry::pending_asset(asset_id), None);
+        assert!(AssetRegistry::asset_id_type(asset_id).is_none());
+        assert_noop!(
+            AssetRegistry::finalize_asset(RuntimeOrigin::signed(ALICE), asset_id),
+            Error::<Test>::AssetNotPending
+        );
+    });
+}
+
+#[test]
+fn finalize_asset_should_work_after_challenge_period() {
+    new_test_ext().execute_with(|| {
+        let asset_id = propose(ALICE);
+        System::set_block_number(10);
+        assert_noop!(
+            AssetRegistry::finalize_asset(RuntimeOrigin::signed(BOB), asset_id),
+            Error::<Test>::ChallengePeriodNotEnded
+        );
+
+        System::set_block_number(11);
+        assert_ok!(AssetRegistry::finalize_asset(
+            RuntimeOrigin::signed(BOB),
+            asset_id
+        ));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 1_000);
+        assert_eq!(
+            AssetRegistry::asset_id_type(asset_id),
+            Some(MockAssetType::Xcm(proposed_location()))
+        );
+        assert_eq!(AssetRegistry::asset_metadata(asset_id), Some(metadata()));
+    });
+}
+
+#[test]
+fn set_asset_params_should_work() {
+    new_test_ext().execute_with(|| {
+        let params = AssetParams {
+            existential_deposit: 10_000,
+            decimals: 6,
+            is_sufficient: true,
+        };
+        assert_noop!(
+            AssetRegistry::set_asset_params(RuntimeOrigin::root(), 1, params),
+            Error::<Test>::AssetDoesNotExist
+        );
+        assert_ok!(AssetRegistry::register_asset(
+            RuntimeOrigin::root(),
+            1,
+            MockAssetType::MockAsset(1),
+        ));
+        assert_noop!(
+            AssetRegistry::set_asset_params(RuntimeOrigin::signed(ALICE), 1, params),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AssetRegistry::set_asset_params(
+                RuntimeOrigin::root(),
+                1,
+                AssetParams {
+                    existential_deposit: 0,
+                    ..params
+                }
+            ),
+            Error::<Test>::InvalidAssetParams
+        );
+
+        assert_ok!(AssetRegistry::set_asset_params(
+            RuntimeOrigin::root(),
+            1,
+            params
+        ));
+        assert_eq!(AssetRegistry::asset_params(1), Some(params));
+        assert_eq!(configured_assets(), vec![(1, 10_000, true)]);
+
+        assert_ok!(AssetRegistry::deregister_asset(RuntimeOrigin::root(), 1));
+        assert_eq!(AssetRegistry::asset_params(1), None);
+    });
+}
+
+#[test]
+fn xcm_fee_assets_should_be_validated() {
+    new_test_ext().execute_with(|| {
+        let params = AssetParams {
+            existential_deposit: MAX_FEE_ASSET_DEPOSIT,
+            decimals: 6,
+            is_sufficient: true,
+        };
+        assert_noop!(
+            AssetRegistry::add_xcm_fee_asset(RuntimeOrigin::root(), 1),
+            Error::<Test>::AssetDoesNotExist
+        );
+        assert_ok!(AssetRegistry::register_asset(
+            RuntimeOrigin::root(),
+            1,
+            MockAssetType::MockAsset(1),
+        ));
+        assert_noop!(
+            AssetRegistry::add_xcm_fee_asset(RuntimeOrigin::signed(ALICE), 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // the fees can't cover the min balance of the asset
+        assert_ok!(AssetRegistry::set_asset_params(
+            RuntimeOrigin::root(),
+            1,
+            AssetParams {
+                existential_deposit: MAX_FEE_ASSET_DEPOSIT + 1,
+                ..params
+            }
+        ));
+        assert_noop!(
+            AssetRegistry::add_xcm_fee_asset(RuntimeOrigin::root(), 1),
+            sp_runtime::DispatchError::Other(FEE_ASSET_ERROR)
+        );
+
+        assert_ok!(AssetRegistry::set_asset_params(
+            RuntimeOrigin::root(),
+            1,
+            params
+        ));
+        assert_ok!(AssetRegistry::add_xcm_fee_asset(RuntimeOrigin::root(), 1));
+        System::assert_last_event(Event::XcmFeeAssetAdded { asset_id: 1 }.into());
+        assert!(AssetRegistry::is_xcm_fee_asset(&1));
+        assert_noop!(
+            AssetRegistry::add_xcm_fee_asset(RuntimeOrigin::root(), 1),
+            Error::<Test>::XcmFeeAssetAlreadyExists
+        );
+
+        // params of a fee asset are validated again
+        assert_noop!(
+            AssetRegistry::set_asset_params(
+                RuntimeOrigin::root(),
+                1,
+                AssetParams {
+                    existential_deposit: MAX_FEE_ASSET_DEPOSIT + 1,
+                    ..params
+                }
+            ),
+            sp_runtime::DispatchError::Other(FEE_ASSET_ERROR)
+        );
+
+        assert_ok!(AssetRegistry::remove_xcm_fee_asset(
+            RuntimeOrigin::root(),
+            1
+        ));
+        System::assert_last_event(Event::XcmFeeAssetRemoved { asset_id: 1 }.into());
+        assert_noop!(
+            AssetRegistry::remove_xcm_fee_asset(RuntimeOrigin::root(), 1),
+            Error::<Test>::XcmFeeAssetNotFound
+        );
+
+        // deregistered assets are no longer fee assets
+        assert_ok!(AssetRegistry::add_xcm_fee_asset(RuntimeOrigin::root(), 1));
+        assert_ok!(AssetRegistry::deregister_asset(RuntimeOrigin::root(), 1));
+        assert!(!AssetRegistry::is_xcm_fee_asset(&1));
+    });
+}
@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2022-05-30, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("kerria-dev"), DB CACHE: 1024
+//!
+//! The weights marked NOT BENCHMARKED YET were added by hand from the storage accesses of
+//! their calls and are estimates until this file is regenerated.
 
 // Executed Command:
 // ./target/release/parallel
@@ -43,6 +46,10 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions needed for pallet_asset_registry.
 pub trait WeightInfo {
 	fn register_asset() -> Weight;
@@ -50,17 +57,27 @@ pub trait WeightInfo {
 	fn update_asset_type() -> Weight;
 	fn remove_fee_payment_asset() -> Weight;
 	fn deregister_asset() -> Weight;
+	fn propose_asset() -> Weight;
+	fn approve_asset() -> Weight;
+	fn reject_asset() -> Weight;
+	fn finalize_asset() -> Weight;
+	fn set_asset_params() -> Weight;
+	fn add_xcm_fee_asset() -> Weight;
+	fn remove_xcm_fee_asset() -> Weight;
 }
 
 /// Weights for pallet_asset_registry using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: AssetRegistry AssetIdType (r:1 w:1)
+	// Storage: AssetRegistry PendingAssets (r:1 w:0)
 	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
 	fn register_asset() -> Weight {
 		Weight::from_ref_time(36_449_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: AssetRegistry AssetTypeId (r:1 w:0)
 	// Storage: AssetRegistry SupportedFeePaymentAssets (r:1 w:1)
@@ -68,43 +85,134 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn update_asset_units_per_second() -> Weight {
 		Weight::from_ref_time(45_578_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: AssetRegistry SupportedFeePaymentAssets (r:1 w:1)
 	// Storage: AssetRegistry AssetIdType (r:1 w:1)
 	// Storage: AssetRegistry AssetTypeUnitsPerSecond (r:1 w:2)
 	// Storage: AssetRegistry AssetTypeId (r:0 w:2)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
 	fn update_asset_type() -> Weight {
 		Weight::from_ref_time(61_904_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: AssetRegistry SupportedFeePaymentAssets (r:1 w:1)
 	// Storage: AssetRegistry AssetTypeUnitsPerSecond (r:0 w:1)
 	fn remove_fee_payment_asset() -> Weight {
 		Weight::from_ref_time(38_682_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: AssetRegistry SupportedFeePaymentAssets (r:1 w:1)
 	// Storage: AssetRegistry AssetIdType (r:1 w:1)
 	// Storage: AssetRegistry AssetTypeUnitsPerSecond (r:0 w:1)
 	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
+	// Storage: AssetRegistry AssetMetadatas (r:0 w:1)
+	// Storage: AssetRegistry AssetParameters (r:0 w:1)
 	fn deregister_asset() -> Weight {
 		Weight::from_ref_time(48_719_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry AssetIdType (r:1 w:0)
+	// Storage: AssetRegistry AssetTypeId (r:1 w:0)
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn propose_asset() -> Weight {
+		Weight::from_ref_time(41_872_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AssetRegistry AssetMetadatas (r:0 w:1)
+	// Storage: AssetRegistry AssetIdType (r:0 w:1)
+	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
+	fn approve_asset() -> Weight {
+		Weight::from_ref_time(47_305_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_asset() -> Weight {
+		Weight::from_ref_time(38_914_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AssetRegistry AssetMetadatas (r:0 w:1)
+	// Storage: AssetRegistry AssetIdType (r:0 w:1)
+	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
+	fn finalize_asset() -> Weight {
+		Weight::from_ref_time(49_127_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry AssetIdType (r:1 w:0)
+	// Storage: AssetRegistry AssetParameters (r:0 w:1)
+	// Storage: AssetRegistry XcmFeeAssets (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:2 w:0)
+	// Storage: Oracle Values (r:1 w:0)
+	// Storage: AMM Pools (r:1 w:0)
+	fn set_asset_params() -> Weight {
+		Weight::from_ref_time(24_913_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry AssetIdType (r:1 w:0)
+	// Storage: AssetRegistry XcmFeeAssets (r:1 w:1)
+	// Storage: Prices EmergencyPrice (r:2 w:0)
+	// Storage: Oracle Values (r:1 w:0)
+	// Storage: AMM Pools (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:0)
+	fn add_xcm_fee_asset() -> Weight {
+		Weight::from_ref_time(41_328_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry XcmFeeAssets (r:1 w:1)
+	fn remove_xcm_fee_asset() -> Weight {
+		Weight::from_ref_time(18_205_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: AssetRegistry AssetIdType (r:1 w:1)
+	// Storage: AssetRegistry PendingAssets (r:1 w:0)
 	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
 	fn register_asset() -> Weight {
 		Weight::from_ref_time(36_449_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	// Storage: AssetRegistry AssetTypeId (r:1 w:0)
 	// Storage: AssetRegistry SupportedFeePaymentAssets (r:1 w:1)
@@ -118,10 +226,11 @@ impl WeightInfo for () {
 	// Storage: AssetRegistry AssetIdType (r:1 w:1)
 	// Storage: AssetRegistry AssetTypeUnitsPerSecond (r:1 w:2)
 	// Storage: AssetRegistry AssetTypeId (r:0 w:2)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
 	fn update_asset_type() -> Weight {
 		Weight::from_ref_time(61_904_000 as u64)
 			.saturating_add(RocksDbWeight::get().reads(3 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
 	}
 	// Storage: AssetRegistry SupportedFeePaymentAssets (r:1 w:1)
 	// Storage: AssetRegistry AssetTypeUnitsPerSecond (r:0 w:1)
@@ -134,9 +243,86 @@ impl WeightInfo for () {
 	// Storage: AssetRegistry AssetIdType (r:1 w:1)
 	// Storage: AssetRegistry AssetTypeUnitsPerSecond (r:0 w:1)
 	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
+	// Storage: AssetRegistry AssetMetadatas (r:0 w:1)
+	// Storage: AssetRegistry AssetParameters (r:0 w:1)
 	fn deregister_asset() -> Weight {
 		Weight::from_ref_time(48_719_000 as u64)
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry AssetIdType (r:1 w:0)
+	// Storage: AssetRegistry AssetTypeId (r:1 w:0)
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn propose_asset() -> Weight {
+		Weight::from_ref_time(41_872_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AssetRegistry AssetMetadatas (r:0 w:1)
+	// Storage: AssetRegistry AssetIdType (r:0 w:1)
+	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
+	fn approve_asset() -> Weight {
+		Weight::from_ref_time(47_305_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_asset() -> Weight {
+		Weight::from_ref_time(38_914_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AssetRegistry AssetMetadatas (r:0 w:1)
+	// Storage: AssetRegistry AssetIdType (r:0 w:1)
+	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
+	fn finalize_asset() -> Weight {
+		Weight::from_ref_time(49_127_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry AssetIdType (r:1 w:0)
+	// Storage: AssetRegistry AssetParameters (r:0 w:1)
+	// Storage: AssetRegistry XcmFeeAssets (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:2 w:0)
+	// Storage: Oracle Values (r:1 w:0)
+	// Storage: AMM Pools (r:1 w:0)
+	fn set_asset_params() -> Weight {
+		Weight::from_ref_time(24_913_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry AssetIdType (r:1 w:0)
+	// Storage: AssetRegistry XcmFeeAssets (r:1 w:1)
+	// Storage: Prices EmergencyPrice (r:2 w:0)
+	// Storage: Oracle Values (r:1 w:0)
+	// Storage: AMM Pools (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:0)
+	fn add_xcm_fee_asset() -> Weight {
+		Weight::from_ref_time(41_328_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry XcmFeeAssets (r:1 w:1)
+	fn remove_xcm_fee_asset() -> Weight {
+		Weight::from_ref_time(18_205_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 }
@@ -0,0 +1,63 @@
+//! Asset treasury pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as AssetTreasury;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::assert_ok;
+use frame_system::RawOrigin as SystemOrigin;
+
+const AMOUNT: u128 = 1_000_000_000_000;
+
+fn approve_spend<T: Config>(beneficiary: T::AccountId) {
+    let asset_id = T::NativeCurrencyId::get();
+    T::Assets::mint_into(asset_id, &T::TreasuryAccount::get(), AMOUNT * 2).unwrap();
+    assert_ok!(AssetTreasury::<T>::spend(
+        T::SpendOrigin::successful_origin(),
+        asset_id,
+        AMOUNT,
+        beneficiary,
+        None
+    ));
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    spend {
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        let asset_id = T::NativeCurrencyId::get();
+        let origin = T::SpendOrigin::successful_origin();
+        let now = frame_system::Pallet::<T>::block_number();
+    }: _<T::RuntimeOrigin>(origin, asset_id, AMOUNT, beneficiary.clone(), None)
+    verify {
+        assert_last_event::<T>(Event::<T>::AssetSpendApproved(0, asset_id, AMOUNT, beneficiary, now).into());
+    }
+
+    payout {
+        let caller: T::AccountId = whitelisted_caller();
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        approve_spend::<T>(beneficiary.clone());
+    }: _(SystemOrigin::Signed(caller), 0)
+    verify {
+        assert_last_event::<T>(Event::<T>::AssetSpendPaid(0, T::NativeCurrencyId::get(), AMOUNT, beneficiary).into());
+    }
+
+    void_spend {
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        approve_spend::<T>(beneficiary);
+        let origin = T::RejectOrigin::successful_origin();
+    }: _<T::RuntimeOrigin>(origin, 0)
+    verify {
+        assert_last_event::<T>(Event::<T>::AssetSpendVoided(0).into());
+    }
+}
+
+impl_benchmark_test_suite!(
+    AssetTreasury,
+    crate::mock::new_test_ext(),
+    crate::mock::Test
+);
@@ -0,0 +1,256 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Asset Treasury pallet
+//!
+//! ## Overview
+//!
+//! Pays out the assets held by the treasury account. Spends are approved by origins
+//! allowed to spend up to some amount of the native currency, the value of the spent
+//! assets is converted to the native currency at the oracle prices to be checked
+//! against it. Approved spends are paid out by anyone once valid, within the payout
+//! period.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::tokens::fungibles::{Inspect, Mutate, Transfer},
+    transactional,
+};
+use frame_system::pallet_prelude::*;
+use pallet_traits::PriceFeeder;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+    traits::{CheckedDiv, Saturating, Zero},
+    ArithmeticError, FixedPointNumber,
+};
+
+pub use pallet::*;
+pub use types::SpendInfo;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub type SpendIndex = u32;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Assets held by the treasury
+        type Assets: Transfer<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
+            + Inspect<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
+            + Mutate<Self::AccountId, AssetId = CurrencyId, Balance = Balance>;
+
+        /// Prices used to value the spent assets
+        type PriceFeeder: PriceFeeder;
+
+        /// Currency id of the native token, spend limits are denominated in it
+        #[pallet::constant]
+        type NativeCurrencyId: Get<CurrencyId>;
+
+        /// Account the assets are paid out from
+        type TreasuryAccount: Get<Self::AccountId>;
+
+        /// The origin which can approve spends, returning the max value it can spend
+        type SpendOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Balance>;
+
+        /// The origin which can void approved spends
+        type RejectOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Number of blocks a spend can be paid out for once valid
+        #[pallet::constant]
+        type PayoutPeriod: Get<Self::BlockNumber>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Spend of treasury assets was approved
+        /// [index, asset_id, amount, beneficiary, valid_from]
+        AssetSpendApproved(
+            SpendIndex,
+            CurrencyId,
+            Balance,
+            T::AccountId,
+            T::BlockNumber,
+        ),
+        /// Approved spend was paid out
+        /// [index, asset_id, amount, beneficiary]
+        AssetSpendPaid(SpendIndex, CurrencyId, Balance, T::AccountId),
+        /// Approved spend was voided
+        /// [index]
+        AssetSpendVoided(SpendIndex),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Spend amount is zero
+        ZeroAmount,
+        /// Price of the asset or the native currency is unavailable
+        PriceUnavailable,
+        /// Value of the spend is above the limit of the origin
+        InsufficientPermission,
+        /// No approved spend at this index
+        InvalidSpendIndex,
+        /// Spend isn't valid yet
+        EarlyPayout,
+        /// Payout period of the spend is over
+        SpendExpired,
+    }
+
+    /// Number of approved spends
+    #[pallet::storage]
+    #[pallet::getter(fn spend_count)]
+    pub type SpendCount<T: Config> = StorageValue<_, SpendIndex, ValueQuery>;
+
+    /// Approved spends which were not paid out yet
+    /// SpendIndex -> SpendInfo
+    #[pallet::storage]
+    #[pallet::getter(fn spends)]
+    pub type Spends<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        SpendIndex,
+        SpendInfo<T::AccountId, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Approve a spend of `amount` of `asset_id` from the treasury to `beneficiary`,
+        /// payable from `valid_from` or now if not given
+        #[pallet::weight(<T as Config>::WeightInfo::spend())]
+        #[transactional]
+        pub fn spend(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId,
+            #[pallet::compact] amount: Balance,
+            beneficiary: T::AccountId,
+            valid_from: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+            ensure!(
+                Self::native_value(asset_id, amount)? <= max_amount,
+                Error::<T>::InsufficientPermission
+            );
+
+            let valid_from = valid_from.unwrap_or_else(frame_system::Pallet::<T>::block_number);
+            let index = Self::spend_count();
+            Spends::<T>::insert(
+                index,
+                SpendInfo {
+                    asset_id,
+                    amount,
+                    beneficiary: beneficiary.clone(),
+                    valid_from,
+                    expire_at: valid_from.saturating_add(T::PayoutPeriod::get()),
+                },
+            );
+            SpendCount::<T>::put(index.saturating_add(1));
+
+            Self::deposit_event(Event::<T>::AssetSpendApproved(
+                index,
+                asset_id,
+                amount,
+                beneficiary,
+                valid_from,
+            ));
+            Ok(())
+        }
+
+        /// Pay out an approved spend to its beneficiary
+        #[pallet::weight(<T as Config>::WeightInfo::payout())]
+        #[transactional]
+        pub fn payout(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+            ensure_signed(origin)?;
+            let spend = Self::spends(index).ok_or(Error::<T>::InvalidSpendIndex)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now >= spend.valid_from, Error::<T>::EarlyPayout);
+            ensure!(now < spend.expire_at, Error::<T>::SpendExpired);
+
+            T::Assets::transfer(
+                spend.asset_id,
+                &T::TreasuryAccount::get(),
+                &spend.beneficiary,
+                spend.amount,
+                false,
+            )?;
+            Spends::<T>::remove(index);
+
+            Self::deposit_event(Event::<T>::AssetSpendPaid(
+                index,
+                spend.asset_id,
+                spend.amount,
+                spend.beneficiary,
+            ));
+            Ok(())
+        }
+
+        /// Void an approved spend which was not paid out yet
+        #[pallet::weight(<T as Config>::WeightInfo::void_spend())]
+        #[transactional]
+        pub fn void_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+            T::RejectOrigin::ensure_origin(origin)?;
+            ensure!(
+                Spends::<T>::contains_key(index),
+                Error::<T>::InvalidSpendIndex
+            );
+
+            Spends::<T>::remove(index);
+            Self::deposit_event(Event::<T>::AssetSpendVoided(index));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Value of `amount` of `asset_id` in the native currency
+    pub fn native_value(asset_id: CurrencyId, amount: Balance) -> Result<Balance, DispatchError> {
+        if asset_id == T::NativeCurrencyId::get() {
+            return Ok(amount);
+        }
+        // prices are normalized by decimals, so the ratio is in units of each asset
+        let (native_price, _) = T::PriceFeeder::get_price(&T::NativeCurrencyId::get())
+            .ok_or(Error::<T>::PriceUnavailable)?;
+        let (price, _) =
+            T::PriceFeeder::get_price(&asset_id).ok_or(Error::<T>::PriceUnavailable)?;
+        price
+            .checked_div(&native_price)
+            .and_then(|rate| rate.checked_mul_int(amount))
+            .ok_or_else(|| ArithmeticError::Overflow.into())
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use frame_system::{EnsureRoot, RawOrigin};
+use primitives::{
+    tokens::{DOT, HKO, USDT},
+    Price, PriceDetail,
+};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, AccountId32};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+        Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+        AssetTreasury: crate::{Pallet, Storage, Call, Event<T>},
+    }
+);
+
+pub type AccountId = AccountId32;
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+pub const TREASURY: AccountId = AccountId32::new([3u8; 32]);
+
+/// Max native value ALICE can approve spends for
+pub const SPEND_LIMIT: Balance = 1_000;
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+    pub const MaxLocks: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = MaxLocks;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+}
+
+parameter_types! {
+    pub const AssetDeposit: Balance = 0;
+    pub const ApprovalDeposit: Balance = 0;
+    pub const AssetAccountDeposit: Balance = 0;
+    pub const AssetsStringLimit: u32 = 50;
+    pub const MetadataDepositBase: Balance = 0;
+    pub const MetadataDepositPerByte: Balance = 0;
+}
+
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = CurrencyId;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = AssetDeposit;
+    type MetadataDepositBase = MetadataDepositBase;
+    type MetadataDepositPerByte = MetadataDepositPerByte;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type ApprovalDeposit = ApprovalDeposit;
+    type StringLimit = AssetsStringLimit;
+    type Freezer = ();
+    type WeightInfo = ();
+    type Extra = ();
+}
+
+thread_local! {
+    pub static PRICES: RefCell<BTreeMap<CurrencyId, Price>> = RefCell::new(
+        vec![
+            (HKO, Price::saturating_from_integer(1)),
+            (DOT, Price::saturating_from_integer(5)),
+            (USDT, Price::saturating_from_integer(1)),
+        ]
+        .into_iter()
+        .collect()
+    );
+}
+
+pub struct MockPriceFeeder;
+impl MockPriceFeeder {
+    pub fn set_price(asset_id: CurrencyId, price: Option<Price>) {
+        PRICES.with(|prices| match price {
+            Some(price) => prices.borrow_mut().insert(asset_id, price),
+            None => prices.borrow_mut().remove(&asset_id),
+        });
+    }
+}
+
+impl PriceFeeder for MockPriceFeeder {
+    fn get_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
+        PRICES.with(|prices| prices.borrow().get(asset_id).map(|price| (*price, 1)))
+    }
+}
+
+/// Root can spend any amount, ALICE up to `SPEND_LIMIT`
+pub struct MockSpendOrigin;
+impl EnsureOrigin<RuntimeOrigin> for MockSpendOrigin {
+    type Success = Balance;
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        Into::<Result<RawOrigin<AccountId>, RuntimeOrigin>>::into(o).and_then(|o| match o {
+            RawOrigin::Root => Ok(Balance::max_value()),
+            RawOrigin::Signed(who) if who == ALICE => Ok(SPEND_LIMIT),
+            r => Err(RuntimeOrigin::from(r)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn successful_origin() -> RuntimeOrigin {
+        RuntimeOrigin::root()
+    }
+}
+
+parameter_types! {
+    pub const NativeCurrencyId: CurrencyId = HKO;
+    pub const TreasuryAccount: AccountId = TREASURY;
+    pub const PayoutPeriod: u64 = 100;
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Assets = Assets;
+    type PriceFeeder = MockPriceFeeder;
+    type NativeCurrencyId = NativeCurrencyId;
+    type TreasuryAccount = TreasuryAccount;
+    type SpendOrigin = MockSpendOrigin;
+    type RejectOrigin = EnsureRoot<AccountId>;
+    type PayoutPeriod = PayoutPeriod;
+    type WeightInfo = ();
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        for asset_id in [HKO, DOT, USDT] {
+            Assets::force_create(RuntimeOrigin::root(), asset_id, ALICE, true, 1).unwrap();
+            Assets::mint(RuntimeOrigin::signed(ALICE), asset_id, TREASURY, 10_000).unwrap();
+        }
+    });
+    ext
+}
@@ -0,0 +1,148 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use primitives::tokens::{DOT, HKO, USDT};
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn spend_should_be_limited_by_value() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AssetTreasury::spend(RuntimeOrigin::signed(BOB), USDT, 100, BOB, None),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetTreasury::spend(RuntimeOrigin::signed(ALICE), USDT, 0, BOB, None),
+            Error::<Test>::ZeroAmount
+        );
+        // 201 DOT are worth 1005 HKO
+        assert_noop!(
+            AssetTreasury::spend(RuntimeOrigin::signed(ALICE), DOT, 201, BOB, None),
+            Error::<Test>::InsufficientPermission
+        );
+        assert_ok!(AssetTreasury::spend(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            200,
+            BOB,
+            None
+        ));
+        assert_ok!(AssetTreasury::spend(
+            RuntimeOrigin::signed(ALICE),
+            HKO,
+            SPEND_LIMIT,
+            BOB,
+            None
+        ));
+        assert_ok!(AssetTreasury::spend(
+            RuntimeOrigin::root(),
+            USDT,
+            5_000,
+            BOB,
+            None
+        ));
+        assert_eq!(AssetTreasury::spend_count(), 3);
+        assert_eq!(
+            AssetTreasury::spends(0),
+            Some(SpendInfo {
+                asset_id: DOT,
+                amount: 200,
+                beneficiary: BOB,
+                valid_from: 1,
+                expire_at: 101,
+            })
+        );
+        System::assert_last_event(RuntimeEvent::AssetTreasury(Event::AssetSpendApproved(
+            2, USDT, 5_000, BOB, 1,
+        )));
+    });
+}
+
+#[test]
+fn spend_should_fail_without_price() {
+    new_test_ext().execute_with(|| {
+        MockPriceFeeder::set_price(DOT, None);
+        assert_noop!(
+            AssetTreasury::spend(RuntimeOrigin::signed(ALICE), DOT, 1, BOB, None),
+            Error::<Test>::PriceUnavailable
+        );
+        MockPriceFeeder::set_price(HKO, None);
+        assert_noop!(
+            AssetTreasury::spend(RuntimeOrigin::signed(ALICE), USDT, 1, BOB, None),
+            Error::<Test>::PriceUnavailable
+        );
+        // native spends don't need prices
+        assert_ok!(AssetTreasury::spend(
+            RuntimeOrigin::signed(ALICE),
+            HKO,
+            1,
+            BOB,
+            None
+        ));
+    });
+}
+
+#[test]
+fn payout_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetTreasury::spend(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            1_000,
+            BOB,
+            Some(10)
+        ));
+        assert_noop!(
+            AssetTreasury::payout(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::EarlyPayout
+        );
+        System::set_block_number(10);
+        assert_ok!(AssetTreasury::payout(RuntimeOrigin::signed(ALICE), 0));
+        assert_eq!(Assets::balance(USDT, BOB), 1_000);
+        assert_eq!(Assets::balance(USDT, TREASURY), 9_000);
+        System::assert_last_event(RuntimeEvent::AssetTreasury(Event::AssetSpendPaid(
+            0, USDT, 1_000, BOB,
+        )));
+        assert_noop!(
+            AssetTreasury::payout(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::InvalidSpendIndex
+        );
+
+        assert_ok!(AssetTreasury::spend(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            1_000,
+            BOB,
+            None
+        ));
+        System::set_block_number(110);
+        assert_noop!(
+            AssetTreasury::payout(RuntimeOrigin::signed(BOB), 1),
+            Error::<Test>::SpendExpired
+        );
+    });
+}
+
+#[test]
+fn void_spend_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetTreasury::spend(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            1_000,
+            BOB,
+            None
+        ));
+        assert_noop!(
+            AssetTreasury::void_spend(RuntimeOrigin::signed(ALICE), 0),
+            BadOrigin
+        );
+        assert_ok!(AssetTreasury::void_spend(RuntimeOrigin::root(), 0));
+        assert_eq!(AssetTreasury::spends(0), None);
+        System::assert_last_event(RuntimeEvent::AssetTreasury(Event::AssetSpendVoided(0)));
+        assert_noop!(
+            AssetTreasury::void_spend(RuntimeOrigin::root(), 0),
+            Error::<Test>::InvalidSpendIndex
+        );
+    });
+}
@@ -0,0 +1,33 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use primitives::{Balance, CurrencyId};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// An approved spend of the treasury assets
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct SpendInfo<AccountId, BlockNumber> {
+    /// Asset to pay out
+    pub asset_id: CurrencyId,
+    /// Amount of `asset_id` to pay out
+    pub amount: Balance,
+    /// Account receiving the payout
+    pub beneficiary: AccountId,
+    /// Block from which the spend can be paid out
+    pub valid_from: BlockNumber,
+    /// Block after which the spend can't be paid out anymore
+    pub expire_at: BlockNumber,
+}
@@ -0,0 +1,119 @@
+// This file is part of Parallel Finance.
+
+// Copyright (C) 2022 Parallel Finance Developer.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_asset_treasury
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet-asset-treasury
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/asset-treasury/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions needed for pallet_asset_treasury.
+pub trait WeightInfo {
+	fn spend() -> Weight;
+	fn payout() -> Weight;
+	fn void_spend() -> Weight;
+}
+
+/// Weights for pallet_asset_treasury using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Prices EmergencyPrice (r:2 w:0)
+	// Storage: System Number (r:1 w:0)
+	// Storage: AssetTreasury SpendCount (r:1 w:1)
+	// Storage: AssetTreasury Spends (r:0 w:1)
+	fn spend() -> Weight {
+		Weight::from_ref_time(41_528_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: AssetTreasury Spends (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn payout() -> Weight {
+		Weight::from_ref_time(63_917_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: AssetTreasury Spends (r:1 w:1)
+	fn void_spend() -> Weight {
+		Weight::from_ref_time(22_764_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Prices EmergencyPrice (r:2 w:0)
+	// Storage: System Number (r:1 w:0)
+	// Storage: AssetTreasury SpendCount (r:1 w:1)
+	// Storage: AssetTreasury Spends (r:0 w:1)
+	fn spend() -> Weight {
+		Weight::from_ref_time(41_528_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: AssetTreasury Spends (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn payout() -> Weight {
+		Weight::from_ref_time(63_917_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: AssetTreasury Spends (r:1 w:1)
+	fn void_spend() -> Weight {
+		Weight::from_ref_time(22_764_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+}
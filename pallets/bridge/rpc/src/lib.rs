@@ -0,0 +1,121 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+pub use pallet_bridge_rpc_runtime_api::{BridgeApi as BridgeRuntimeApi, TransferInfo};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use primitives::{ChainId, ChainNonce};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[rpc(client, server)]
+pub trait BridgeApi<BlockHash, AccountId, Balance, BlockNumber>
+where
+    Balance: Codec + Copy + TryFrom<NumberOrHex>,
+{
+    /// Status of the inbound transfer `chain_nonce` from `chain_id` and the votes
+    /// relayers have cast on its proposals.
+    #[method(name = "bridge_getTransferStatus")]
+    fn get_transfer_status(
+        &self,
+        chain_id: ChainId,
+        chain_nonce: ChainNonce,
+        at: Option<BlockHash>,
+    ) -> RpcResult<TransferInfo<AccountId, NumberOrHex, BlockNumber>>;
+}
+
+/// A struct that implements the [`BridgeApi`].
+pub struct Bridge<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Bridge<C, B> {
+    /// Create new `Bridge` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+pub enum Error {
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance, BlockNumber>
+    BridgeApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber> for Bridge<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: BridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    AccountId: Codec,
+    Balance: Codec + Copy + TryFrom<NumberOrHex> + Into<NumberOrHex> + std::fmt::Display,
+    BlockNumber: Codec,
+{
+    fn get_transfer_status(
+        &self,
+        chain_id: ChainId,
+        chain_nonce: ChainNonce,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TransferInfo<AccountId, NumberOrHex, BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(self.client.info().best_hash));
+        api.transfer_status(&at, chain_id, chain_nonce)
+            .map_err(runtime_error_into_rpc_error)?
+            .try_map(try_into_rpc_balance)
+    }
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Runtime trapped",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+fn try_into_rpc_balance<T: std::fmt::Display + Copy + TryInto<NumberOrHex>>(
+    value: T,
+) -> Result<NumberOrHex, JsonRpseeError> {
+    value.try_into().map_err(|_| {
+        JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+            ErrorCode::InvalidParams.code(),
+            format!("{} doesn't fit in NumberOrHex representation", value),
+            None::<()>,
+        )))
+    })
+}
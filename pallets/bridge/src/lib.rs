@@ -22,7 +22,10 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use crate::types::{BridgeToken, MaterializeCall, Proposal, ProposalStatus};
+use crate::types::{
+    BridgeToken, MaterializeCall, Proposal, ProposalStatus, TransferInfo, TransferProposal,
+    TransferStatus,
+};
 use frame_support::{
     log,
     pallet_prelude::*,
@@ -37,6 +40,10 @@ use frame_support::{
     transactional, PalletId,
 };
 use frame_system::{ensure_signed_or_root, pallet_prelude::*};
+use pallet_traits::{
+    CircuitBreaker, CircuitBreakerTrigger, EventMirror, MirroredEvent, RevenueRecorder,
+    RevenueSource,
+};
 use primitives::{Balance, BridgeInterval, ChainId, ChainNonce, CurrencyId, Ratio};
 use sp_runtime::{
     traits::{AccountIdConversion, Zero},
@@ -47,7 +54,7 @@ use sp_std::{vec, vec::Vec};
 mod benchmarking;
 mod mock;
 mod tests;
-mod types;
+pub mod types;
 pub mod weights;
 
 pub use pallet::*;
@@ -66,6 +73,12 @@ type MaterializeCallOf<T> =
 type ProposalOf<T> =
     Proposal<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
 
+type TransferInfoOf<T> = TransferInfo<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
 pub type TeleAccount = Vec<u8>;
 
 #[frame_support::pallet]
@@ -131,6 +144,15 @@ pub mod pallet {
         #[pallet::constant]
         type ThresholdPercentage: Get<u32>;
 
+        /// Records the fees charged on teleports
+        type Revenue: RevenueRecorder;
+
+        /// Pauses bridge calls once the cap of a bridge token is reached
+        type CircuitBreaker: CircuitBreaker;
+
+        /// Mirrors the teleports and materializations to the EVM
+        type EventMirror: EventMirror<Self::AccountId>;
+
         /// Information on runtime weights.
         type WeightInfo: WeightInfo;
     }
@@ -322,6 +344,20 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Mapping of [chain_id -> nonce -> expiry] of the transfers whose proposals
+    /// expired before voting completed
+    #[pallet::storage]
+    #[pallet::getter(fn expired_transfers)]
+    pub type ExpiredTransfers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        Blake2_128Concat,
+        ChainNonce,
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register the specified chain_id
@@ -552,6 +588,7 @@ pub mod pallet {
             let actual_amount = amount
                 .checked_sub(fee)
                 .ok_or(Error::<T>::BridgingAmountTooLow)?;
+            T::Revenue::record(RevenueSource::BridgeFee, asset_id, fee);
             Self::teleport_internal(who, dest_id, bridge_token_id, to, actual_amount, fee)
         }
 
@@ -591,6 +628,7 @@ pub mod pallet {
             let mut proposal = match Self::votes(src_id, (src_nonce, call.clone())) {
                 Some(p) => p,
                 None => {
+                    ExpiredTransfers::<T>::remove(src_id, src_nonce);
                     Self::deposit_event(Event::<T>::MaterializeInitialized(
                         who.clone(),
                         src_id,
@@ -647,6 +685,9 @@ pub mod pallet {
             expired.for_each(|x| {
                 let chain_id = x.0;
                 let chain_nonce = x.1;
+                if !x.2.is_complete() {
+                    ExpiredTransfers::<T>::insert(chain_id, chain_nonce.0, x.2.expiry);
+                }
                 ProposalVotes::<T>::remove(chain_id, chain_nonce);
             });
 
@@ -829,6 +870,58 @@ impl<T: Config> Pallet<T> {
         })
     }
 
+    /// Status of the inbound transfer `chain_nonce` from `chain_id` together with the
+    /// votes cast on each of its proposals
+    pub fn transfer_status(chain_id: ChainId, chain_nonce: ChainNonce) -> TransferInfoOf<T> {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let proposals: Vec<_> = ProposalVotes::<T>::iter_prefix(chain_id)
+            .filter(|((nonce, _), _)| *nonce == chain_nonce)
+            .collect();
+
+        let status = if proposals
+            .iter()
+            .any(|(_, p)| p.status == ProposalStatus::Approved)
+        {
+            TransferStatus::Executed
+        } else if proposals
+            .iter()
+            .any(|(_, p)| p.status == ProposalStatus::Rejected)
+        {
+            TransferStatus::Rejected
+        } else if proposals.iter().any(|(_, p)| !p.is_expired(now)) {
+            TransferStatus::Voting
+        } else if Self::has_bridged(chain_id, chain_nonce) {
+            TransferStatus::Finalized
+        } else if let Some(expiry) = proposals
+            .iter()
+            .map(|(_, p)| p.expiry)
+            .max()
+            .or_else(|| Self::expired_transfers(chain_id, chain_nonce))
+        {
+            TransferStatus::Expired(expiry)
+        } else {
+            TransferStatus::Unknown
+        };
+
+        TransferInfo {
+            status,
+            vote_threshold: Self::vote_threshold(),
+            members_count: Self::get_members_count(),
+            proposals: proposals
+                .into_iter()
+                .map(|((_, call), p)| TransferProposal {
+                    bridge_token_id: call.bridge_token_id,
+                    to: call.to,
+                    amount: call.amount,
+                    votes_for: p.votes_for,
+                    votes_against: p.votes_against,
+                    status: p.status,
+                    expiry: p.expiry,
+                })
+                .collect(),
+        }
+    }
+
     /// Records completed bridge transactions
     fn update_bridge_registry(chain_id: ChainId, nonce: ChainNonce) {
         match Self::bridge_registry(&chain_id) {
@@ -861,16 +954,23 @@ impl<T: Config> Pallet<T> {
         let mut bridge_token = Self::bridge_token(asset_id);
         let new_amount =
             Self::ensure_under_bridge_cap(bridge_token.clone(), amount, bridge_type.clone())?;
-        match bridge_type {
+        let cap_reached = match bridge_type {
             BridgeType::BridgeOut => {
                 bridge_token.out_amount = new_amount;
+                new_amount >= bridge_token.out_cap
             }
             BridgeType::BridgeIn => {
                 bridge_token.in_amount = new_amount;
+                new_amount >= bridge_token.in_cap
             }
         };
         BridgeTokens::<T>::insert(asset_id, bridge_token);
 
+        // Transfers over the cap fail and revert, so trip once the cap is reached instead
+        if cap_reached {
+            T::CircuitBreaker::trip(CircuitBreakerTrigger::BridgeCapBreached);
+        }
+
         Ok(())
     }
 
@@ -914,6 +1014,15 @@ impl<T: Config> Pallet<T> {
             fee,
         );
 
+        T::EventMirror::mirror(MirroredEvent::TeleportBurned {
+            from: ori_address.clone(),
+            dest_id,
+            nonce,
+            bridge_token_id,
+            to: dst_address.clone(),
+            amount,
+            fee,
+        });
         Self::deposit_event(Event::TeleportBurned(
             ori_address,
             dest_id,
@@ -985,6 +1094,13 @@ impl<T: Config> Pallet<T> {
             call.amount,
         );
 
+        T::EventMirror::mirror(MirroredEvent::MaterializeMinted {
+            src_id,
+            src_nonce,
+            bridge_token_id: call.bridge_token_id,
+            to: call.to.clone(),
+            amount: call.amount,
+        });
         Self::deposit_event(Event::MaterializeMinted(
             src_id,
             src_nonce,
@@ -233,6 +233,9 @@ impl Config for Test {
     type ProposalLifetime = ProposalLifetime;
     type ThresholdPercentage = ThresholdPercentage;
 
+    type Revenue = ();
+    type CircuitBreaker = ();
+    type EventMirror = ();
     type WeightInfo = ();
 }
 
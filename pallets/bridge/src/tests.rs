@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::{mock::*, Event, *};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use primitives::tokens::HKO;
 
 #[test]
@@ -1039,3 +1039,114 @@ fn merge_overlapping_intervals_works() {
         vec![(1, 1), (3, 3), (5, 7)],
     );
 }
+
+#[test]
+fn transfer_status_works() {
+    new_test_ext().execute_with(|| {
+        Bridge::teleport(
+            RuntimeOrigin::signed(EVE),
+            ETH,
+            EHKO,
+            "TELE".into(),
+            dollar(50),
+        )
+        .unwrap();
+        assert_eq!(
+            Bridge::transfer_status(ETH, 0).status,
+            TransferStatus::Unknown
+        );
+
+        // Nonce 0 is approved by all the members
+        Bridge::materialize(
+            RuntimeOrigin::signed(ALICE),
+            ETH,
+            0,
+            EHKO,
+            EVE,
+            dollar(10),
+            true,
+        )
+        .unwrap();
+        let info = Bridge::transfer_status(ETH, 0);
+        assert_eq!(info.status, TransferStatus::Voting);
+        assert_eq!(info.members_count, 3);
+        assert_eq!(
+            info.proposals,
+            vec![TransferProposal {
+                bridge_token_id: EHKO,
+                to: EVE,
+                amount: dollar(10),
+                votes_for: vec![ALICE],
+                votes_against: vec![],
+                status: ProposalStatus::Initiated,
+                expiry: 51,
+            }]
+        );
+        Bridge::materialize(
+            RuntimeOrigin::signed(BOB),
+            ETH,
+            0,
+            EHKO,
+            EVE,
+            dollar(10),
+            true,
+        )
+        .unwrap();
+        Bridge::materialize(
+            RuntimeOrigin::signed(CHARLIE),
+            ETH,
+            0,
+            EHKO,
+            EVE,
+            dollar(10),
+            true,
+        )
+        .unwrap();
+        let info = Bridge::transfer_status(ETH, 0);
+        assert_eq!(info.status, TransferStatus::Executed);
+        assert_eq!(info.proposals[0].votes_for, vec![ALICE, BOB, CHARLIE]);
+        assert_eq!(info.proposals[0].status, ProposalStatus::Approved);
+
+        // Nonce 1 only gets a single vote before it expires
+        Bridge::materialize(
+            RuntimeOrigin::signed(ALICE),
+            ETH,
+            1,
+            EHKO,
+            EVE,
+            dollar(10),
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            Bridge::transfer_status(ETH, 1).status,
+            TransferStatus::Voting
+        );
+
+        run_to_block(51);
+        Bridge::on_initialize(51);
+        let info = Bridge::transfer_status(ETH, 0);
+        assert_eq!(info.status, TransferStatus::Finalized);
+        assert!(info.proposals.is_empty());
+        let info = Bridge::transfer_status(ETH, 1);
+        assert_eq!(info.status, TransferStatus::Expired(51));
+        assert!(info.proposals.is_empty());
+
+        // Voting again on an expired transfer starts over
+        Bridge::materialize(
+            RuntimeOrigin::signed(ALICE),
+            ETH,
+            1,
+            EHKO,
+            EVE,
+            dollar(10),
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            Bridge::transfer_status(ETH, 1).status,
+            TransferStatus::Voting
+        );
+        assert_eq!(Bridge::expired_transfers(ETH, 1), None);
+    })
+}
@@ -2,6 +2,8 @@ use codec::{Decode, Encode};
 use frame_support::RuntimeDebug;
 use primitives::{Balance, CurrencyId};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_std::{vec, vec::Vec};
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
@@ -25,6 +27,8 @@ pub enum BridgeType {
 }
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum ProposalStatus {
     Initiated,
     Approved,
@@ -92,3 +96,77 @@ impl<T, E: Default> Default for Proposal<T, E> {
         }
     }
 }
+
+/// Status of an inbound transfer identified by its source chain and nonce
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum TransferStatus<BlockNumber> {
+    // No relayer has proposed the transfer yet
+    Unknown,
+    // Relayers are still voting on the transfer
+    Voting,
+    // The transfer was approved and the tokens were materialized
+    Executed,
+    // The transfer was rejected by the relayers
+    Rejected,
+    // Voting didn't complete before the proposal expired at the given block
+    Expired(BlockNumber),
+    // The transfer was executed or rejected but its votes have been pruned
+    Finalized,
+}
+
+/// A materialize proposal of a transfer and the votes cast on it
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TransferProposal<AccountId, Balance, BlockNumber> {
+    pub bridge_token_id: CurrencyId,
+    pub to: AccountId,
+    pub amount: Balance,
+    pub votes_for: Vec<AccountId>,
+    pub votes_against: Vec<AccountId>,
+    pub status: ProposalStatus,
+    pub expiry: BlockNumber,
+}
+
+/// Everything known on chain about an inbound transfer. Relayers may disagree on the
+/// content of a transfer, in which case there is one proposal per distinct call.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TransferInfo<AccountId, Balance, BlockNumber> {
+    pub status: TransferStatus<BlockNumber>,
+    pub vote_threshold: u32,
+    pub members_count: u32,
+    pub proposals: Vec<TransferProposal<AccountId, Balance, BlockNumber>>,
+}
+
+impl<AccountId, Balance, BlockNumber> TransferInfo<AccountId, Balance, BlockNumber> {
+    /// Convert the amounts with `f`, e.g. into a representation suitable for RPC
+    pub fn try_map<B, E>(
+        self,
+        f: impl Fn(Balance) -> Result<B, E>,
+    ) -> Result<TransferInfo<AccountId, B, BlockNumber>, E> {
+        Ok(TransferInfo {
+            status: self.status,
+            vote_threshold: self.vote_threshold,
+            members_count: self.members_count,
+            proposals: self
+                .proposals
+                .into_iter()
+                .map(|p| {
+                    Ok(TransferProposal {
+                        bridge_token_id: p.bridge_token_id,
+                        to: p.to,
+                        amount: f(p.amount)?,
+                        votes_for: p.votes_for,
+                        votes_against: p.votes_against,
+                        status: p.status,
+                        expiry: p.expiry,
+                    })
+                })
+                .collect::<Result<_, E>>()?,
+        })
+    }
+}
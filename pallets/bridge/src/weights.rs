@@ -43,6 +43,10 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions needed for pallet_bridge.
 pub trait WeightInfo {
 	fn register_chain() -> Weight;
@@ -66,6 +70,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn register_chain() -> Weight {
 		Weight::from_ref_time(40_997_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -74,6 +79,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn unregister_chain() -> Weight {
 		Weight::from_ref_time(41_155_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -82,6 +88,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn register_bridge_token() -> Weight {
 		Weight::from_ref_time(46_856_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -90,6 +97,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn unregister_bridge_token() -> Weight {
 		Weight::from_ref_time(44_675_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -98,6 +106,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn set_bridge_token_fee() -> Weight {
 		Weight::from_ref_time(51_194_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -106,6 +115,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn set_bridge_token_status() -> Weight {
 		Weight::from_ref_time(50_720_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -114,6 +124,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn set_bridge_token_cap() -> Weight {
 		Weight::from_ref_time(51_069_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -122,6 +133,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn clean_cap_accumulated_value() -> Weight {
 		Weight::from_ref_time(50_332_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -132,6 +144,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn teleport() -> Weight {
 		Weight::from_ref_time(128_432_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -147,6 +160,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn materialize() -> Weight {
 		Weight::from_ref_time(209_544_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(11 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 }
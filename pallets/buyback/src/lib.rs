@@ -0,0 +1,345 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Buyback pallet
+//!
+//! ## Overview
+//!
+//! Buys back the native token with part of the protocol revenue. Governance sets for
+//! each revenue asset the share of its balance sold each epoch and a cap on the amount
+//! sold. At the start of every epoch, the assets are taken from the revenue account and
+//! swapped through the router along their best route, the bought tokens are then either
+//! burnt or distributed as rewards to the stakers locking the native token in farming.
+//!
+//! Swaps have to return at least the oracle value of the assets sold, less
+//! `MaxPriceImpact`, so that a thin or manipulated pool can't be drained by the buyback.
+//! The sale of an asset which fails this check, or any other, is reverted and retried
+//! the next epoch, the revenue stays in the revenue account meanwhile.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    storage::with_transaction,
+    traits::tokens::fungibles::{Inspect, Mutate, Transfer},
+    transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+use pallet_traits::{PriceFeeder, RewardDispatcher, SwapRouter};
+use primitives::{Balance, CurrencyId, Ratio};
+use sp_runtime::{
+    traits::{AccountIdConversion, Zero},
+    ArithmeticError, FixedPointNumber, TransactionOutcome,
+};
+
+pub use pallet::*;
+pub use types::{Destination, Policy};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Pallet id of the account the swaps are made from
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Assets of the revenue and the bought back token
+        type Assets: Transfer<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
+            + Inspect<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
+            + Mutate<Self::AccountId, AssetId = CurrencyId, Balance = Balance>;
+
+        /// Router the revenue assets are sold through
+        type Router: SwapRouter<Self::AccountId, CurrencyId, Balance>;
+
+        /// Staking rewards the bought back tokens can be distributed as
+        type Rewards: RewardDispatcher<Self::AccountId, CurrencyId, Balance, Self::BlockNumber>;
+
+        /// Prices the swaps are checked against
+        type PriceFeeder: PriceFeeder;
+
+        /// Account holding the protocol revenue
+        type RevenueAccount: Get<Self::AccountId>;
+
+        /// Token bought back
+        #[pallet::constant]
+        type BuybackCurrencyId: Get<CurrencyId>;
+
+        /// Number of blocks between buybacks
+        #[pallet::constant]
+        type EpochLength: Get<Self::BlockNumber>;
+
+        /// How much less than the oracle value of the assets sold a swap may return
+        #[pallet::constant]
+        type MaxPriceImpact: Get<Ratio>;
+
+        /// Most revenue assets bought back with
+        #[pallet::constant]
+        type MaxAssets: Get<u32>;
+
+        /// The origin which can set the policies and the destination
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Policy of a revenue asset was set, or removed if `None`
+        /// [asset_id, policy]
+        PolicyUpdated(CurrencyId, Option<Policy>),
+        /// Destination of the bought back tokens was set
+        /// [destination]
+        DestinationUpdated(Destination<T::BlockNumber>),
+        /// Revenue asset was sold for the buyback token
+        /// [asset_id, amount_in, amount_out]
+        BoughtBack(CurrencyId, Balance, Balance),
+        /// Sale of a revenue asset was reverted
+        /// [asset_id, amount_in, error]
+        BuybackFailed(CurrencyId, Balance, DispatchError),
+        /// Bought back tokens were burnt
+        /// [amount]
+        Burned(Balance),
+        /// Bought back tokens were distributed to the stakers of a farming pool
+        /// [asset_id, lock_duration, amount]
+        Distributed(CurrencyId, T::BlockNumber, Balance),
+        /// Bought back tokens couldn't be burnt or distributed, they are kept for the next
+        /// epoch
+        /// [amount, error]
+        DestinationFailed(Balance, DispatchError),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Policies are already set for `MaxAssets` assets
+        TooManyAssets,
+        /// Share of a policy is zero
+        InvalidShare,
+        /// Price of the asset sold or of the buyback token is unavailable
+        PriceUnavailable,
+        /// Best route returns too little compared to the oracle prices
+        PriceImpactTooHigh,
+    }
+
+    /// Buyback policies of the revenue assets
+    /// CurrencyId -> Policy
+    #[pallet::storage]
+    #[pallet::getter(fn policies)]
+    pub type Policies<T: Config> =
+        CountedStorageMap<_, Twox64Concat, CurrencyId, Policy, OptionQuery>;
+
+    /// What is done with the bought back tokens
+    #[pallet::storage]
+    #[pallet::getter(fn destination)]
+    pub type BuybackDestination<T: Config> =
+        StorageValue<_, Destination<T::BlockNumber>, ValueQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let epoch_length = T::EpochLength::get();
+            if epoch_length.is_zero() || !(n % epoch_length).is_zero() {
+                return Weight::zero();
+            }
+
+            let assets = Policies::<T>::count();
+            Self::buy_back();
+            T::WeightInfo::on_initialize(assets)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the share of the revenue balance of `asset_id` sold each epoch and the cap
+        /// on the amount sold, or stop buying back with it if `policy` is `None`.
+        #[pallet::weight(T::WeightInfo::set_asset_policy())]
+        #[transactional]
+        pub fn set_asset_policy(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId,
+            policy: Option<Policy>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            match policy {
+                Some(policy) => {
+                    ensure!(!policy.share.is_zero(), Error::<T>::InvalidShare);
+                    ensure!(
+                        Policies::<T>::contains_key(asset_id)
+                            || Policies::<T>::count() < T::MaxAssets::get(),
+                        Error::<T>::TooManyAssets
+                    );
+                    Policies::<T>::insert(asset_id, policy);
+                }
+                None => Policies::<T>::remove(asset_id),
+            }
+            Self::deposit_event(Event::<T>::PolicyUpdated(asset_id, policy));
+            Ok(())
+        }
+
+        /// Set whether the bought back tokens are burnt or distributed to stakers.
+        #[pallet::weight(T::WeightInfo::set_destination())]
+        #[transactional]
+        pub fn set_destination(
+            origin: OriginFor<T>,
+            destination: Destination<T::BlockNumber>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            BuybackDestination::<T>::put(destination);
+            Self::deposit_event(Event::<T>::DestinationUpdated(destination));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Account the swaps are made from
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    /// Amount of `asset_id` sold this epoch under `policy`
+    pub fn amount_to_sell(asset_id: CurrencyId, policy: &Policy) -> Balance {
+        let balance = T::Assets::reducible_balance(asset_id, &T::RevenueAccount::get(), false);
+        policy.share.mul_floor(balance).min(policy.cap)
+    }
+
+    /// Least of the buyback token `amount_in` of `asset_id` may be sold for
+    pub fn min_amount_out(
+        asset_id: CurrencyId,
+        amount_in: Balance,
+    ) -> Result<Balance, DispatchError> {
+        // prices are normalized by decimals, so the ratio is in units of each asset
+        let (price_in, _) =
+            T::PriceFeeder::get_price(&asset_id).ok_or(Error::<T>::PriceUnavailable)?;
+        let (price_out, _) = T::PriceFeeder::get_price(&T::BuybackCurrencyId::get())
+            .ok_or(Error::<T>::PriceUnavailable)?;
+        let expected = price_in
+            .checked_div(&price_out)
+            .and_then(|rate| rate.checked_mul_int(amount_in))
+            .ok_or(ArithmeticError::Overflow)?;
+        Ok(expected.saturating_sub(T::MaxPriceImpact::get().mul_ceil(expected)))
+    }
+
+    fn buy_back() {
+        let account = Self::account_id();
+        for (asset_id, policy) in Policies::<T>::iter() {
+            let amount_in = Self::amount_to_sell(asset_id, &policy);
+            if amount_in.is_zero() {
+                continue;
+            }
+            let result = with_transaction(|| match Self::sell(&account, asset_id, amount_in) {
+                Ok(amount_out) => TransactionOutcome::Commit(Ok(amount_out)),
+                Err(err) => TransactionOutcome::Rollback(Err(err)),
+            });
+            match result {
+                Ok(amount_out) => {
+                    Self::deposit_event(Event::<T>::BoughtBack(asset_id, amount_in, amount_out))
+                }
+                Err(err) => {
+                    Self::deposit_event(Event::<T>::BuybackFailed(asset_id, amount_in, err))
+                }
+            }
+        }
+
+        // tokens left over from an epoch the destination failed in go out with this one
+        let amount = T::Assets::reducible_balance(T::BuybackCurrencyId::get(), &account, false);
+        if amount.is_zero() {
+            return;
+        }
+        let destination = Self::destination();
+        let result = with_transaction(|| match Self::send(&account, destination, amount) {
+            Ok(()) => TransactionOutcome::Commit(Ok(())),
+            Err(err) => TransactionOutcome::Rollback(Err(err)),
+        });
+        match (result, destination) {
+            (Ok(()), Destination::Burn) => Self::deposit_event(Event::<T>::Burned(amount)),
+            (
+                Ok(()),
+                Destination::Distribute {
+                    asset,
+                    lock_duration,
+                },
+            ) => Self::deposit_event(Event::<T>::Distributed(asset, lock_duration, amount)),
+            (Err(err), _) => Self::deposit_event(Event::<T>::DestinationFailed(amount, err)),
+        }
+    }
+
+    /// Takes `amount_in` of `asset_id` from the revenue account and sells it for the
+    /// buyback token, returns the amount bought
+    fn sell(
+        account: &T::AccountId,
+        asset_id: CurrencyId,
+        amount_in: Balance,
+    ) -> Result<Balance, DispatchError> {
+        T::Assets::transfer(
+            asset_id,
+            &T::RevenueAccount::get(),
+            account,
+            amount_in,
+            false,
+        )?;
+        if asset_id == T::BuybackCurrencyId::get() {
+            return Ok(amount_in);
+        }
+
+        let min_amount_out = Self::min_amount_out(asset_id, amount_in)?;
+        let (route, amount_out) =
+            T::Router::best_route(asset_id, T::BuybackCurrencyId::get(), amount_in)?;
+        ensure!(amount_out >= min_amount_out, Error::<T>::PriceImpactTooHigh);
+        T::Router::swap_exact_in(account, route, amount_in, min_amount_out)
+    }
+
+    fn send(
+        account: &T::AccountId,
+        destination: Destination<T::BlockNumber>,
+        amount: Balance,
+    ) -> DispatchResult {
+        match destination {
+            Destination::Burn => {
+                T::Assets::burn_from(T::BuybackCurrencyId::get(), account, amount)?;
+            }
+            Destination::Distribute {
+                asset,
+                lock_duration,
+            } => T::Rewards::dispatch_reward(
+                account,
+                asset,
+                T::BuybackCurrencyId::get(),
+                lock_duration,
+                amount,
+                T::EpochLength::get(),
+            )?,
+        }
+        Ok(())
+    }
+}
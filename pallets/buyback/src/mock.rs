@@ -0,0 +1,321 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use frame_system::EnsureRoot;
+use primitives::{Price, PriceDetail};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, DispatchError};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
+
+pub use primitives::tokens::{DOT, HKO, KSM, USDT};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+        Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+        Buyback: crate::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const REVENUE: AccountId = 2;
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+    pub const MaxLocks: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = MaxLocks;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+}
+
+parameter_types! {
+    pub const AssetDeposit: u64 = 1;
+    pub const ApprovalDeposit: u64 = 1;
+    pub const AssetAccountDeposit: u64 = 1;
+    pub const StringLimit: u32 = 50;
+    pub const MetadataDepositBase: u64 = 1;
+    pub const MetadataDepositPerByte: u64 = 1;
+}
+
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = CurrencyId;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = AssetDeposit;
+    type MetadataDepositBase = MetadataDepositBase;
+    type MetadataDepositPerByte = MetadataDepositPerByte;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type ApprovalDeposit = ApprovalDeposit;
+    type StringLimit = StringLimit;
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = ();
+}
+
+thread_local! {
+    static RATES: RefCell<BTreeMap<CurrencyId, Price>> = RefCell::new(BTreeMap::new());
+    static PRICES: RefCell<BTreeMap<CurrencyId, Price>> = RefCell::new(BTreeMap::new());
+    static REWARDS: RefCell<Vec<(AccountId, CurrencyId, CurrencyId, u64, Balance, u64)>> =
+        RefCell::new(Vec::new());
+}
+
+/// Router selling assets for HKO at a fixed rate, burning what it is sold and minting
+/// the HKO it pays out
+pub struct MockRouter;
+
+impl MockRouter {
+    /// HKO paid for a unit of `asset_id`
+    pub fn set_rate(asset_id: CurrencyId, rate: Price) {
+        RATES.with(|rates| rates.borrow_mut().insert(asset_id, rate));
+    }
+
+    fn quote(asset_in: CurrencyId, amount_in: Balance) -> Result<Balance, DispatchError> {
+        RATES
+            .with(|rates| rates.borrow().get(&asset_in).copied())
+            .map(|rate| rate.saturating_mul_int(amount_in))
+            .ok_or(DispatchError::Other("NoPossibleRoute"))
+    }
+
+    fn quote_in(asset_in: CurrencyId, amount_out: Balance) -> Result<Balance, DispatchError> {
+        RATES
+            .with(|rates| rates.borrow().get(&asset_in).copied())
+            .and_then(|rate| rate.reciprocal())
+            .map(|rate| rate.saturating_mul_int(amount_out))
+            .ok_or(DispatchError::Other("NoPossibleRoute"))
+    }
+}
+
+impl SwapRouter<AccountId, CurrencyId, Balance> for MockRouter {
+    fn best_route(
+        asset_in: CurrencyId,
+        asset_out: CurrencyId,
+        amount_in: Balance,
+    ) -> Result<(Vec<CurrencyId>, Balance), DispatchError> {
+        assert_eq!(asset_out, HKO);
+        Ok((vec![asset_in, HKO], Self::quote(asset_in, amount_in)?))
+    }
+
+    fn best_route_for_exact_out(
+        asset_in: CurrencyId,
+        asset_out: CurrencyId,
+        amount_out: Balance,
+    ) -> Result<(Vec<CurrencyId>, Balance), DispatchError> {
+        assert_eq!(asset_out, HKO);
+        Ok((vec![asset_in, HKO], Self::quote_in(asset_in, amount_out)?))
+    }
+
+    fn swap_exact_in(
+        who: &AccountId,
+        route: Vec<CurrencyId>,
+        amount_in: Balance,
+        min_amount_out: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let amount_out = Self::quote(route[0], amount_in)?;
+        if amount_out < min_amount_out {
+            return Err(DispatchError::Other("MinimumAmountOutNotReached"));
+        }
+        <Assets as Mutate<AccountId>>::burn_from(route[0], who, amount_in)?;
+        <Assets as Mutate<AccountId>>::mint_into(HKO, who, amount_out)?;
+        Ok(amount_out)
+    }
+
+    fn swap_exact_out(
+        who: &AccountId,
+        route: Vec<CurrencyId>,
+        amount_out: Balance,
+        max_amount_in: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let amount_in = Self::quote_in(route[0], amount_out)?;
+        if amount_in > max_amount_in {
+            return Err(DispatchError::Other("MaximumAmountInViolated"));
+        }
+        <Assets as Mutate<AccountId>>::burn_from(route[0], who, amount_in)?;
+        <Assets as Mutate<AccountId>>::mint_into(HKO, who, amount_out)?;
+        Ok(amount_in)
+    }
+}
+
+/// Farming taking the rewards dispatched to the pools of HKO locked for 100 blocks
+pub struct MockRewards;
+
+impl MockRewards {
+    pub fn dispatched() -> Vec<(AccountId, CurrencyId, CurrencyId, u64, Balance, u64)> {
+        REWARDS.with(|rewards| rewards.borrow().clone())
+    }
+}
+
+impl RewardDispatcher<AccountId, CurrencyId, Balance, u64> for MockRewards {
+    fn dispatch_reward(
+        payer: &AccountId,
+        asset: CurrencyId,
+        reward_asset: CurrencyId,
+        lock_duration: u64,
+        amount: Balance,
+        reward_duration: u64,
+    ) -> DispatchResult {
+        if asset != HKO || lock_duration != 100 {
+            return Err(DispatchError::Other("PoolDoesNotExist"));
+        }
+        <Assets as Mutate<AccountId>>::burn_from(reward_asset, payer, amount)?;
+        REWARDS.with(|rewards| {
+            rewards.borrow_mut().push((
+                *payer,
+                asset,
+                reward_asset,
+                lock_duration,
+                amount,
+                reward_duration,
+            ))
+        });
+        Ok(())
+    }
+}
+
+pub struct MockPriceFeeder;
+
+impl MockPriceFeeder {
+    pub fn set_price(asset_id: CurrencyId, price: Price) {
+        PRICES.with(|prices| prices.borrow_mut().insert(asset_id, price));
+    }
+}
+
+impl PriceFeeder for MockPriceFeeder {
+    fn get_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
+        PRICES.with(|prices| prices.borrow().get(asset_id).map(|price| (*price, 1)))
+    }
+}
+
+parameter_types! {
+    pub const BuybackPalletId: PalletId = PalletId(*b"par/bbck");
+    pub const RevenueAccount: AccountId = REVENUE;
+    pub const BuybackCurrencyId: CurrencyId = HKO;
+    pub const EpochLength: u64 = 10;
+    pub const MaxPriceImpact: Ratio = Ratio::from_percent(3);
+    pub const MaxAssets: u32 = 2;
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type PalletId = BuybackPalletId;
+    type Assets = Assets;
+    type Router = MockRouter;
+    type Rewards = MockRewards;
+    type PriceFeeder = MockPriceFeeder;
+    type RevenueAccount = RevenueAccount;
+    type BuybackCurrencyId = BuybackCurrencyId;
+    type EpochLength = EpochLength;
+    type MaxPriceImpact = MaxPriceImpact;
+    type MaxAssets = MaxAssets;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+pub fn dollar(d: u128) -> u128 {
+    d.saturating_mul(10_u128.pow(12))
+}
+
+/// Revenue of 1000 DOT and 1000 USDT, DOT worth 5 HKO and USDT 2 HKO both on the oracle
+/// and through the router
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        for asset_id in [HKO, DOT, USDT] {
+            Assets::force_create(RuntimeOrigin::root(), asset_id, ALICE, true, 1).unwrap();
+        }
+        for asset_id in [DOT, USDT] {
+            Assets::mint(
+                RuntimeOrigin::signed(ALICE),
+                asset_id,
+                REVENUE,
+                dollar(1000),
+            )
+            .unwrap();
+        }
+        MockPriceFeeder::set_price(HKO, Price::saturating_from_integer(1));
+        for (asset_id, rate) in [(DOT, 5), (USDT, 2)] {
+            MockPriceFeeder::set_price(asset_id, Price::saturating_from_integer(rate));
+            MockRouter::set_rate(asset_id, Price::saturating_from_integer(rate));
+        }
+        System::set_block_number(1);
+    });
+    ext
+}
+
+/// Runs the hooks of the blocks up to `n`
+pub(crate) fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        Buyback::on_initialize(System::block_number());
+    }
+}
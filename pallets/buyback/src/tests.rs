@@ -0,0 +1,210 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use primitives::Price;
+use sp_runtime::DispatchError;
+
+fn policy(share: u32, cap: u128) -> Policy {
+    Policy {
+        share: Ratio::from_percent(share),
+        cap: dollar(cap),
+    }
+}
+
+#[test]
+fn set_asset_policy_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Buyback::set_asset_policy(RuntimeOrigin::signed(ALICE), DOT, Some(policy(10, 50))),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Buyback::set_asset_policy(RuntimeOrigin::root(), DOT, Some(policy(0, 50))),
+            Error::<Test>::InvalidShare
+        );
+
+        assert_ok!(Buyback::set_asset_policy(
+            RuntimeOrigin::root(),
+            DOT,
+            Some(policy(10, 50))
+        ));
+        assert_ok!(Buyback::set_asset_policy(
+            RuntimeOrigin::root(),
+            USDT,
+            Some(policy(10, 1000))
+        ));
+        assert_noop!(
+            Buyback::set_asset_policy(RuntimeOrigin::root(), KSM, Some(policy(10, 50))),
+            Error::<Test>::TooManyAssets
+        );
+        // policies already set can still be replaced
+        assert_ok!(Buyback::set_asset_policy(
+            RuntimeOrigin::root(),
+            DOT,
+            Some(policy(20, 50))
+        ));
+        assert_eq!(Buyback::policies(DOT), Some(policy(20, 50)));
+        System::assert_last_event(RuntimeEvent::Buyback(Event::PolicyUpdated(
+            DOT,
+            Some(policy(20, 50)),
+        )));
+
+        assert_ok!(Buyback::set_asset_policy(RuntimeOrigin::root(), USDT, None));
+        assert_eq!(Buyback::policies(USDT), None);
+        assert_ok!(Buyback::set_asset_policy(
+            RuntimeOrigin::root(),
+            KSM,
+            Some(policy(10, 50))
+        ));
+    })
+}
+
+#[test]
+fn buyback_burns_bought_tokens_each_epoch() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Buyback::set_asset_policy(
+            RuntimeOrigin::root(),
+            DOT,
+            Some(policy(10, 50))
+        ));
+        assert_ok!(Buyback::set_asset_policy(
+            RuntimeOrigin::root(),
+            USDT,
+            Some(policy(10, 1000))
+        ));
+
+        run_to_block(9);
+        assert_eq!(Assets::balance(DOT, REVENUE), dollar(1000));
+
+        // 10% of the DOT is capped at 50 DOT, sold for 250 HKO, and 100 USDT for 200 HKO
+        run_to_block(10);
+        assert_eq!(Assets::balance(DOT, REVENUE), dollar(950));
+        assert_eq!(Assets::balance(USDT, REVENUE), dollar(900));
+        System::assert_has_event(RuntimeEvent::Buyback(Event::BoughtBack(
+            DOT,
+            dollar(50),
+            dollar(250),
+        )));
+        System::assert_has_event(RuntimeEvent::Buyback(Event::BoughtBack(
+            USDT,
+            dollar(100),
+            dollar(200),
+        )));
+        System::assert_last_event(RuntimeEvent::Buyback(Event::Burned(dollar(450))));
+        assert_eq!(Assets::total_supply(HKO), 0);
+
+        run_to_block(20);
+        assert_eq!(Assets::balance(DOT, REVENUE), dollar(900));
+        assert_eq!(Assets::balance(USDT, REVENUE), dollar(810));
+        System::assert_last_event(RuntimeEvent::Buyback(Event::Burned(dollar(430))));
+    })
+}
+
+#[test]
+fn buyback_reverts_sales_with_too_much_price_impact() {
+    new_test_ext().execute_with(|| {
+        Assets::force_create(RuntimeOrigin::root(), KSM, ALICE, true, 1).unwrap();
+        Assets::mint(RuntimeOrigin::signed(ALICE), KSM, REVENUE, dollar(1000)).unwrap();
+        for asset_id in [DOT, KSM] {
+            assert_ok!(Buyback::set_asset_policy(
+                RuntimeOrigin::root(),
+                asset_id,
+                Some(policy(10, 50))
+            ));
+        }
+
+        // DOT goes 4% below its oracle price through the router, KSM has no price
+        MockRouter::set_rate(DOT, Price::saturating_from_rational(48, 10));
+        MockRouter::set_rate(KSM, Price::saturating_from_integer(10));
+        run_to_block(10);
+        System::assert_has_event(RuntimeEvent::Buyback(Event::BuybackFailed(
+            DOT,
+            dollar(50),
+            Error::<Test>::PriceImpactTooHigh.into(),
+        )));
+        System::assert_has_event(RuntimeEvent::Buyback(Event::BuybackFailed(
+            KSM,
+            dollar(50),
+            Error::<Test>::PriceUnavailable.into(),
+        )));
+        assert_eq!(Assets::balance(DOT, REVENUE), dollar(1000));
+        assert_eq!(Assets::balance(KSM, REVENUE), dollar(1000));
+        assert_eq!(Assets::balance(DOT, Buyback::account_id()), 0);
+
+        // 2% below is within the max price impact
+        MockRouter::set_rate(DOT, Price::saturating_from_rational(49, 10));
+        run_to_block(20);
+        assert_eq!(Assets::balance(DOT, REVENUE), dollar(950));
+        System::assert_has_event(RuntimeEvent::Buyback(Event::BoughtBack(
+            DOT,
+            dollar(50),
+            dollar(245),
+        )));
+        System::assert_last_event(RuntimeEvent::Buyback(Event::Burned(dollar(245))));
+    })
+}
+
+#[test]
+fn buyback_distributes_bought_tokens_to_stakers() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Buyback::set_asset_policy(
+            RuntimeOrigin::root(),
+            USDT,
+            Some(policy(10, 1000))
+        ));
+        assert_noop!(
+            Buyback::set_destination(RuntimeOrigin::signed(ALICE), Destination::Burn),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Buyback::set_destination(
+            RuntimeOrigin::root(),
+            Destination::Distribute {
+                asset: HKO,
+                lock_duration: 50,
+            }
+        ));
+
+        // the farming pool doesn't exist, the bought tokens wait for the next epoch
+        run_to_block(10);
+        assert_eq!(Assets::balance(HKO, Buyback::account_id()), dollar(200));
+        assert!(matches!(
+            System::events().last().map(|record| &record.event),
+            Some(RuntimeEvent::Buyback(Event::DestinationFailed(amount, _))) if *amount == dollar(200)
+        ));
+        assert_eq!(MockRewards::dispatched(), vec![]);
+
+        assert_ok!(Buyback::set_destination(
+            RuntimeOrigin::root(),
+            Destination::Distribute {
+                asset: HKO,
+                lock_duration: 100,
+            }
+        ));
+        run_to_block(20);
+        assert_eq!(Assets::balance(USDT, REVENUE), dollar(810));
+        assert_eq!(Assets::balance(HKO, Buyback::account_id()), 0);
+        assert_eq!(
+            MockRewards::dispatched(),
+            vec![(Buyback::account_id(), HKO, HKO, 100, dollar(380), 10)]
+        );
+        System::assert_last_event(RuntimeEvent::Buyback(Event::Distributed(
+            HKO,
+            100,
+            dollar(380),
+        )));
+    })
+}
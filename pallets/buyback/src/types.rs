@@ -0,0 +1,46 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use primitives::{Balance, CurrencyId, Ratio};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// How much of an asset of the revenue is bought back each epoch
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Policy {
+    /// Share of the revenue balance of the asset sold
+    pub share: Ratio,
+    /// Most of the asset sold in an epoch
+    pub cap: Balance,
+}
+
+/// What is done with the bought back tokens
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Destination<BlockNumber> {
+    /// Burnt, taking them out of the supply
+    Burn,
+    /// Paid as rewards to the stakers of `asset` locked for `lock_duration` in farming,
+    /// over the next epoch
+    Distribute {
+        asset: CurrencyId,
+        lock_duration: BlockNumber,
+    },
+}
+
+impl<BlockNumber> Default for Destination<BlockNumber> {
+    fn default() -> Self {
+        Self::Burn
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_buyback
+//!
+//! `on_initialize` is the weight of an epoch start, selling `n` revenue assets through
+//! the router and burning or distributing what they bought.
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet-buyback
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/buyback/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions needed for pallet_buyback.
+pub trait WeightInfo {
+	fn set_asset_policy() -> Weight;
+	fn set_destination() -> Weight;
+	fn on_initialize(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_buyback using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn set_asset_policy() -> Weight {
+		Weight::from_ref_time(21_874_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn set_destination() -> Weight {
+		Weight::from_ref_time(15_302_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn on_initialize(n: u32, ) -> Weight {
+		Weight::from_ref_time(98_410_000 as u64)
+			// Standard Error: 61_000
+			.saturating_add(Weight::from_ref_time(215_736_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(12 as u64))
+			.saturating_add(T::DbWeight::get().reads((28 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((28 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().writes((14 as u64).saturating_mul(n as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_asset_policy() -> Weight {
+		Weight::from_ref_time(21_874_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn set_destination() -> Weight {
+		Weight::from_ref_time(15_302_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn on_initialize(n: u32, ) -> Weight {
+		Weight::from_ref_time(98_410_000 as u64)
+			// Standard Error: 61_000
+			.saturating_add(Weight::from_ref_time(215_736_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().reads((28 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes((14 as u64).saturating_mul(n as u64)))
+	}
+}
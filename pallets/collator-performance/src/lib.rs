@@ -0,0 +1,179 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Collator Performance pallet
+//!
+//! ## Overview
+//!
+//! Holds the candidates of `pallet-collator-selection` accountable for the blocks they
+//! produce. Blocks authored by each collator are counted over a session and, when the
+//! session ends, candidates which authored less than `MinProductionRatio` of their share
+//! of the blocks are removed from the candidates and lose `SlashRatio` of their bond.
+//! Invulnerables, which governance sets in `pallet-collator-selection`, are never kicked.
+//!
+//! The pallet wraps the session manager of `pallet-collator-selection` and must be
+//! registered as an authorship event handler.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    log,
+    pallet_prelude::*,
+    traits::{Currency, Imbalance, OnUnbalanced},
+};
+use frame_system::RawOrigin;
+use pallet_session::SessionManager;
+use sp_runtime::{traits::Zero, Percent};
+use sp_staking::SessionIndex;
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type CurrencyOf<T> = <T as pallet_collator_selection::Config>::Currency;
+
+pub type BalanceOf<T> =
+    <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type NegativeImbalanceOf<T> =
+    <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+type CollatorSelection<T> = pallet_collator_selection::Pallet<T>;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config:
+        frame_system::Config
+        + pallet_collator_selection::Config
+        + pallet_session::Config<ValidatorId = <Self as frame_system::Config>::AccountId>
+    {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Share of the expected blocks a candidate must author in a session
+        #[pallet::constant]
+        type MinProductionRatio: Get<Percent>;
+
+        /// Share of the candidacy bond slashed from kicked candidates
+        #[pallet::constant]
+        type SlashRatio: Get<Percent>;
+
+        /// Receives the slashed bonds
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Candidate authored too few blocks and was kicked
+        /// [who, authored, expected, slashed]
+        CollatorKicked(T::AccountId, u32, u32, BalanceOf<T>),
+    }
+
+    /// Blocks authored by each collator in the current session
+    /// AccountId -> u32
+    #[pallet::storage]
+    #[pallet::getter(fn authored_blocks)]
+    pub type AuthoredBlocks<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Blocks authored in the current session
+    #[pallet::storage]
+    #[pallet::getter(fn session_blocks)]
+    pub type SessionBlocks<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+}
+
+impl<T: Config> Pallet<T> {
+    /// Kick and slash the candidates of the ending session which authored too few blocks
+    fn kick_underperformers() {
+        let validators = pallet_session::Pallet::<T>::validators();
+        if validators.is_empty() {
+            return;
+        }
+        let expected = Self::session_blocks() / validators.len() as u32;
+        let min_authored = T::MinProductionRatio::get().mul_floor(expected);
+        if min_authored.is_zero() {
+            return;
+        }
+
+        let candidates = CollatorSelection::<T>::candidates();
+        for who in validators {
+            let authored = Self::authored_blocks(&who);
+            if authored >= min_authored {
+                continue;
+            }
+            let deposit = match candidates.iter().find(|c| c.who == who) {
+                Some(candidate) => candidate.deposit,
+                // Invulnerables
+                None => continue,
+            };
+            if let Err(err) =
+                CollatorSelection::<T>::leave_intent(RawOrigin::Signed(who.clone()).into())
+            {
+                log::warn!(
+                    target: "collator-performance::kick",
+                    "failed to kick {:?}: {:?}",
+                    who,
+                    err
+                );
+                continue;
+            }
+
+            let (imbalance, _) =
+                CurrencyOf::<T>::slash(&who, T::SlashRatio::get().mul_floor(deposit));
+            let slashed = imbalance.peek();
+            T::Slash::on_unbalanced(imbalance);
+            Self::deposit_event(Event::<T>::CollatorKicked(who, authored, expected, slashed));
+        }
+    }
+}
+
+impl<T: Config> SessionManager<T::AccountId> for Pallet<T> {
+    fn new_session(index: SessionIndex) -> Option<Vec<T::AccountId>> {
+        <CollatorSelection<T> as SessionManager<_>>::new_session(index)
+    }
+
+    fn new_session_genesis(index: SessionIndex) -> Option<Vec<T::AccountId>> {
+        <CollatorSelection<T> as SessionManager<_>>::new_session_genesis(index)
+    }
+
+    fn start_session(index: SessionIndex) {
+        let _ = AuthoredBlocks::<T>::clear(u32::MAX, None);
+        SessionBlocks::<T>::kill();
+        <CollatorSelection<T> as SessionManager<_>>::start_session(index)
+    }
+
+    fn end_session(index: SessionIndex) {
+        Self::kick_underperformers();
+        <CollatorSelection<T> as SessionManager<_>>::end_session(index)
+    }
+}
+
+impl<T: Config> pallet_authorship::EventHandler<T::AccountId, T::BlockNumber> for Pallet<T> {
+    fn note_author(author: T::AccountId) {
+        AuthoredBlocks::<T>::mutate(author, |blocks| *blocks = blocks.saturating_add(1));
+        SessionBlocks::<T>::mutate(|blocks| *blocks = blocks.saturating_add(1));
+    }
+
+    fn note_uncle(_author: T::AccountId, _age: T::BlockNumber) {}
+}
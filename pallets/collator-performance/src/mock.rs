@@ -0,0 +1,225 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU32, Everything},
+    PalletId,
+};
+use frame_system::EnsureRoot;
+use pallet_collator_selection::IdentityCollator;
+use sp_core::H256;
+use sp_runtime::{
+    impl_opaque_keys,
+    testing::{Header, UintAuthorityId},
+    traits::{IdentityLookup, OpaqueKeys},
+    KeyTypeId, RuntimeAppPublic,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Session: pallet_session::{Pallet, Call, Storage, Config<T>, Event},
+        CollatorSelection: pallet_collator_selection::{Pallet, Call, Storage, Config<T>, Event<T>},
+        CollatorPerformance: crate::{Pallet, Storage, Event<T>},
+    }
+);
+
+pub type AccountId = u64;
+pub type Balance = u128;
+
+/// Blocks of a session
+pub const PERIOD: u64 = 10;
+pub const CANDIDACY_BOND: Balance = 100;
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+    pub const MaxLocks: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = MaxLocks;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+}
+
+impl_opaque_keys! {
+    pub struct MockSessionKeys {
+        pub aura: UintAuthorityId,
+    }
+}
+
+impl From<UintAuthorityId> for MockSessionKeys {
+    fn from(aura: UintAuthorityId) -> Self {
+        Self { aura }
+    }
+}
+
+pub struct TestSessionHandler;
+impl pallet_session::SessionHandler<AccountId> for TestSessionHandler {
+    const KEY_TYPE_IDS: &'static [KeyTypeId] = &[UintAuthorityId::ID];
+    fn on_genesis_session<Ks: OpaqueKeys>(_keys: &[(AccountId, Ks)]) {}
+    fn on_new_session<Ks: OpaqueKeys>(
+        _changed: bool,
+        _validators: &[(AccountId, Ks)],
+        _queued_validators: &[(AccountId, Ks)],
+    ) {
+    }
+    fn on_disabled(_validator_index: u32) {}
+}
+
+parameter_types! {
+    pub const Period: u64 = PERIOD;
+    pub const Offset: u64 = 0;
+}
+
+impl pallet_session::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = IdentityCollator;
+    type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
+    type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+    type SessionManager = CollatorPerformance;
+    type SessionHandler = TestSessionHandler;
+    type Keys = MockSessionKeys;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const PotId: PalletId = PalletId(*b"par/pstk");
+    pub const MaxCandidates: u32 = 20;
+    pub const MinCandidates: u32 = 1;
+    pub const MaxInvulnerables: u32 = 20;
+    // Upstream kicks are left out of the tests
+    pub const KickThreshold: u64 = 1_000;
+}
+
+impl pallet_collator_selection::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type PotId = PotId;
+    type MaxCandidates = MaxCandidates;
+    type MinCandidates = MinCandidates;
+    type MaxInvulnerables = MaxInvulnerables;
+    type KickThreshold = KickThreshold;
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = IdentityCollator;
+    type ValidatorRegistration = Session;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const MinProductionRatio: Percent = Percent::from_percent(50);
+    pub const SlashRatio: Percent = Percent::from_percent(20);
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MinProductionRatio = MinProductionRatio;
+    type SlashRatio = SlashRatio;
+    type Slash = ();
+}
+
+pub const INVULNERABLES: [AccountId; 2] = [1, 2];
+pub const CANDIDATES: [AccountId; 3] = [3, 4, 5];
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=5).map(|who| (who, 1_000)).collect(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    pallet_collator_selection::GenesisConfig::<Test> {
+        invulnerables: INVULNERABLES.to_vec(),
+        candidacy_bond: CANDIDACY_BOND,
+        desired_candidates: 5,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    pallet_session::GenesisConfig::<Test> {
+        keys: INVULNERABLES
+            .iter()
+            .map(|&who| (who, who, MockSessionKeys::from(UintAuthorityId(who))))
+            .collect(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        for who in CANDIDATES {
+            Session::set_keys(
+                RuntimeOrigin::signed(who),
+                UintAuthorityId(who).into(),
+                vec![],
+            )
+            .unwrap();
+            CollatorSelection::register_as_candidate(RuntimeOrigin::signed(who)).unwrap();
+        }
+    });
+    ext
+}
@@ -0,0 +1,68 @@
+use super::*;
+use crate::mock::*;
+use frame_support::traits::Hooks;
+use pallet_authorship::EventHandler;
+
+/// Run the blocks up to `n`, authored in turn by `authors`
+fn run_to_block(n: u64, authors: &[AccountId]) {
+    while System::block_number() < n {
+        let block = System::block_number() + 1;
+        System::set_block_number(block);
+        Session::on_initialize(block);
+        CollatorPerformance::note_author(authors[block as usize % authors.len()]);
+    }
+}
+
+fn candidates() -> Vec<AccountId> {
+    CollatorSelection::candidates()
+        .into_iter()
+        .map(|candidate| candidate.who)
+        .collect()
+}
+
+#[test]
+fn blocks_are_counted_per_session() {
+    new_test_ext().execute_with(|| {
+        run_to_block(PERIOD - 1, &[1, 2]);
+        assert_eq!(CollatorPerformance::session_blocks(), 8);
+        assert_eq!(CollatorPerformance::authored_blocks(1), 4);
+
+        run_to_block(PERIOD, &[1, 2]);
+        assert_eq!(CollatorPerformance::session_blocks(), 1);
+        assert_eq!(CollatorPerformance::authored_blocks(1), 1);
+        assert_eq!(CollatorPerformance::authored_blocks(2), 0);
+    });
+}
+
+#[test]
+fn underperforming_candidates_are_kicked_and_slashed() {
+    new_test_ext().execute_with(|| {
+        // Candidates join the validators two sessions after registering
+        run_to_block(2 * PERIOD, &[1, 2]);
+        assert_eq!(Session::validators(), vec![1, 2, 3, 4, 5]);
+
+        run_to_block(3 * PERIOD, &[1, 2, 3, 4]);
+        assert_eq!(candidates(), vec![3, 4]);
+        assert_eq!(Balances::reserved_balance(5), 0);
+        assert_eq!(Balances::free_balance(5), 1_000 - CANDIDACY_BOND / 5);
+        System::assert_has_event(RuntimeEvent::CollatorPerformance(Event::CollatorKicked(
+            5,
+            0,
+            2,
+            CANDIDACY_BOND / 5,
+        )));
+
+        // Others keep their bond
+        assert_eq!(Balances::reserved_balance(3), CANDIDACY_BOND);
+    });
+}
+
+#[test]
+fn invulnerables_are_never_kicked() {
+    new_test_ext().execute_with(|| {
+        run_to_block(3 * PERIOD, &[3, 4, 5]);
+        assert_eq!(candidates(), vec![3, 4, 5]);
+        assert_eq!(CollatorSelection::invulnerables(), INVULNERABLES.to_vec());
+        assert_eq!(Balances::reserved_balance(3), CANDIDACY_BOND);
+    });
+}
@@ -0,0 +1,75 @@
+//! Collator rewards pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as CollatorRewards;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::assert_ok;
+use frame_system::RawOrigin as SystemOrigin;
+
+const INITIAL_AMOUNT: u128 = 100_000_000_000_000;
+const REWARD: u128 = 1_000_000_000_000;
+
+fn initial_set_up<T: Config>(caller: &T::AccountId) -> T::AccountId {
+    let collator: T::AccountId = account("collator", 0, 0);
+    T::Candidates::add(&collator);
+    T::Currency::make_free_balance_be(caller, INITIAL_AMOUNT);
+    T::Currency::make_free_balance_be(&T::RewardAccount::get(), INITIAL_AMOUNT);
+    assert_ok!(CollatorRewards::<T>::set_reward_per_block(
+        SystemOrigin::Root.into(),
+        REWARD
+    ));
+    collator
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    set_reward_per_block {
+    }: _(SystemOrigin::Root, REWARD)
+    verify {
+        assert_last_event::<T>(Event::<T>::RewardPerBlockUpdated(REWARD).into());
+    }
+
+    delegate {
+        let caller: T::AccountId = whitelisted_caller();
+        let collator = initial_set_up::<T>(&caller);
+        let amount = T::MinDelegation::get();
+        assert_ok!(CollatorRewards::<T>::delegate(SystemOrigin::Signed(caller.clone()).into(), collator.clone(), amount));
+        CollatorRewards::<T>::reward_author(collator.clone());
+    }: _(SystemOrigin::Signed(caller.clone()), collator.clone(), amount)
+    verify {
+        assert_last_event::<T>(Event::<T>::Delegated(caller, collator, amount).into());
+    }
+
+    undelegate {
+        let caller: T::AccountId = whitelisted_caller();
+        let collator = initial_set_up::<T>(&caller);
+        let amount = T::MinDelegation::get();
+        assert_ok!(CollatorRewards::<T>::delegate(SystemOrigin::Signed(caller.clone()).into(), collator.clone(), amount));
+        CollatorRewards::<T>::reward_author(collator.clone());
+    }: _(SystemOrigin::Signed(caller.clone()), collator.clone(), amount)
+    verify {
+        assert_last_event::<T>(Event::<T>::Undelegated(caller, collator, amount).into());
+    }
+
+    claim_rewards {
+        let caller: T::AccountId = whitelisted_caller();
+        let collator = initial_set_up::<T>(&caller);
+        assert_ok!(CollatorRewards::<T>::delegate(SystemOrigin::Signed(caller.clone()).into(), collator.clone(), T::MinDelegation::get()));
+        CollatorRewards::<T>::reward_author(collator.clone());
+        let rewards = CollatorRewards::<T>::pending_rewards(&caller, &collator);
+    }: _(SystemOrigin::Signed(caller.clone()), collator.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::RewardsClaimed(caller, collator, rewards).into());
+    }
+}
+
+impl_benchmark_test_suite!(
+    CollatorRewards,
+    crate::mock::new_test_ext(),
+    crate::mock::Test
+);
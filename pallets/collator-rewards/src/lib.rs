@@ -0,0 +1,372 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Collator Rewards pallet
+//!
+//! ## Overview
+//!
+//! Pays a reward set by governance to the author of each block, out of the reward
+//! account, e.g. the treasury. Anyone can back a collator candidate by delegating to it,
+//! the delegated amount is reserved. When a backed collator authors a block, it keeps
+//! `CollatorCommission` of the reward and the rest is shared between its delegators in
+//! proportion to their delegations, who can claim it at any time.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    log,
+    pallet_prelude::*,
+    traits::{
+        Currency,
+        ExistenceRequirement::{AllowDeath, KeepAlive},
+        ReservableCurrency, SortedMembers,
+    },
+    transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+use pallet_traits::RewardClaimer;
+use primitives::Balance;
+use sp_runtime::{
+    traits::{AccountIdConversion, Zero},
+    Percent,
+};
+
+pub use pallet::*;
+pub use types::{Delegation, DelegationPool};
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The currency rewards are paid and delegations are reserved in
+        type Currency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+        /// Account the rewards are paid from
+        type RewardAccount: Get<Self::AccountId>;
+
+        /// Collators which can be delegated to
+        type Candidates: SortedMembers<Self::AccountId>;
+
+        /// Pallet account holding the rewards of the delegators
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Share of the reward kept by a backed collator
+        #[pallet::constant]
+        type CollatorCommission: Get<Percent>;
+
+        /// Min amount delegated by an account to a collator
+        #[pallet::constant]
+        type MinDelegation: Get<Balance>;
+
+        /// The origin which can update the reward per block
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Reward per block was updated
+        /// [amount]
+        RewardPerBlockUpdated(Balance),
+        /// Author of the block was rewarded
+        /// [collator, collator_reward, delegators_reward]
+        CollatorRewarded(T::AccountId, Balance, Balance),
+        /// Amount was delegated to a collator
+        /// [who, collator, amount]
+        Delegated(T::AccountId, T::AccountId, Balance),
+        /// Amount was undelegated from a collator
+        /// [who, collator, amount]
+        Undelegated(T::AccountId, T::AccountId, Balance),
+        /// Delegation rewards were claimed
+        /// [who, collator, amount]
+        RewardsClaimed(T::AccountId, T::AccountId, Balance),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account is not a collator candidate
+        NotCandidate,
+        /// Delegation is below the min delegation
+        DelegationTooLow,
+        /// Account doesn't delegate to the collator
+        NoDelegation,
+        /// Undelegated amount is more than the delegation
+        InsufficientDelegation,
+    }
+
+    /// Reward paid for each authored block
+    #[pallet::storage]
+    #[pallet::getter(fn reward_per_block)]
+    pub type RewardPerBlock<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+    /// Delegations backing each collator
+    /// Collator -> DelegationPool
+    #[pallet::storage]
+    #[pallet::getter(fn delegation_pool)]
+    pub type DelegationPools<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, DelegationPool, ValueQuery>;
+
+    /// Delegations of the accounts
+    /// Collator -> Delegator -> Delegation
+    #[pallet::storage]
+    #[pallet::getter(fn delegation)]
+    pub type Delegations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        Delegation,
+        OptionQuery,
+    >;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the reward paid for each authored block, zero disables the rewards
+        #[pallet::weight(<T as Config>::WeightInfo::set_reward_per_block())]
+        #[transactional]
+        pub fn set_reward_per_block(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            RewardPerBlock::<T>::put(amount);
+            Self::deposit_event(Event::<T>::RewardPerBlockUpdated(amount));
+            Ok(())
+        }
+
+        /// Back `collator` with `amount`, reserved until it's undelegated
+        #[pallet::weight(<T as Config>::WeightInfo::delegate())]
+        #[transactional]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            collator: T::AccountId,
+            amount: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(T::Candidates::contains(&collator), Error::<T>::NotCandidate);
+
+            let mut pool = Self::delegation_pool(&collator);
+            let mut delegation = Self::delegation(&collator, &who).unwrap_or_default();
+            ensure!(
+                delegation.amount.saturating_add(amount) >= T::MinDelegation::get(),
+                Error::<T>::DelegationTooLow
+            );
+
+            Self::pay_rewards(&who, &collator, &pool, &mut delegation)?;
+            T::Currency::reserve(&who, amount)?;
+            delegation.amount = delegation.amount.saturating_add(amount);
+            delegation.settle(&pool);
+            pool.total = pool.total.saturating_add(amount);
+
+            Delegations::<T>::insert(&collator, &who, delegation);
+            DelegationPools::<T>::insert(&collator, pool);
+            Self::deposit_event(Event::<T>::Delegated(who, collator, amount));
+            Ok(())
+        }
+
+        /// Withdraw `amount` of the delegation to `collator`, pending rewards are claimed
+        #[pallet::weight(<T as Config>::WeightInfo::undelegate())]
+        #[transactional]
+        pub fn undelegate(
+            origin: OriginFor<T>,
+            collator: T::AccountId,
+            amount: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut pool = Self::delegation_pool(&collator);
+            let mut delegation =
+                Self::delegation(&collator, &who).ok_or(Error::<T>::NoDelegation)?;
+            ensure!(
+                delegation.amount >= amount,
+                Error::<T>::InsufficientDelegation
+            );
+            let remaining = delegation.amount - amount;
+            ensure!(
+                remaining.is_zero() || remaining >= T::MinDelegation::get(),
+                Error::<T>::DelegationTooLow
+            );
+
+            Self::pay_rewards(&who, &collator, &pool, &mut delegation)?;
+            T::Currency::unreserve(&who, amount);
+            delegation.amount = remaining;
+            delegation.settle(&pool);
+            pool.total = pool.total.saturating_sub(amount);
+
+            if delegation.amount.is_zero() {
+                Delegations::<T>::remove(&collator, &who);
+            } else {
+                Delegations::<T>::insert(&collator, &who, delegation);
+            }
+            DelegationPools::<T>::insert(&collator, pool);
+            Self::deposit_event(Event::<T>::Undelegated(who, collator, amount));
+            Ok(())
+        }
+
+        /// Claim the rewards of the delegation to `collator`
+        #[pallet::weight(<T as Config>::WeightInfo::claim_rewards())]
+        #[transactional]
+        pub fn claim_rewards(origin: OriginFor<T>, collator: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_claim_rewards(&who, &collator)
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Account holding the rewards of the delegators
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    /// Rewards `who` can claim from its delegation to `collator`
+    pub fn pending_rewards(who: &T::AccountId, collator: &T::AccountId) -> Balance {
+        Self::delegation(collator, who)
+            .map(|delegation| delegation.pending_rewards(&Self::delegation_pool(collator)))
+            .unwrap_or_default()
+    }
+
+    fn do_claim_rewards(who: &T::AccountId, collator: &T::AccountId) -> DispatchResult {
+        let pool = Self::delegation_pool(collator);
+        let mut delegation = Self::delegation(collator, who).ok_or(Error::<T>::NoDelegation)?;
+
+        Self::pay_rewards(who, collator, &pool, &mut delegation)?;
+        delegation.settle(&pool);
+        Delegations::<T>::insert(collator, who, delegation);
+        Ok(())
+    }
+
+    fn pay_rewards(
+        who: &T::AccountId,
+        collator: &T::AccountId,
+        pool: &DelegationPool,
+        delegation: &mut Delegation,
+    ) -> DispatchResult {
+        let rewards = delegation.pending_rewards(pool);
+        if rewards.is_zero() {
+            return Ok(());
+        }
+        T::Currency::transfer(&Self::account_id(), who, rewards, AllowDeath)?;
+        Self::deposit_event(Event::<T>::RewardsClaimed(
+            who.clone(),
+            collator.clone(),
+            rewards,
+        ));
+        Ok(())
+    }
+
+    /// Pay the reward of a block authored by `collator`
+    fn reward_author(collator: T::AccountId) {
+        let reward = Self::reward_per_block();
+        if reward.is_zero() {
+            return;
+        }
+
+        let mut pool = Self::delegation_pool(&collator);
+        let mut delegators_reward = if pool.total.is_zero() {
+            Zero::zero()
+        } else {
+            reward.saturating_sub(T::CollatorCommission::get().mul_floor(reward))
+        };
+        if !delegators_reward.is_zero() {
+            match T::Currency::transfer(
+                &T::RewardAccount::get(),
+                &Self::account_id(),
+                delegators_reward,
+                KeepAlive,
+            ) {
+                Ok(_) => {
+                    pool.distribute(delegators_reward);
+                    DelegationPools::<T>::insert(&collator, pool);
+                }
+                Err(err) => {
+                    log::warn!(
+                        target: "collator-rewards::reward_author",
+                        "failed to pay the delegators of {:?}: {:?}",
+                        collator,
+                        err
+                    );
+                    delegators_reward = Zero::zero();
+                }
+            }
+        }
+
+        let collator_reward = reward.saturating_sub(delegators_reward);
+        if let Err(err) = T::Currency::transfer(
+            &T::RewardAccount::get(),
+            &collator,
+            collator_reward,
+            KeepAlive,
+        ) {
+            log::warn!(
+                target: "collator-rewards::reward_author",
+                "failed to pay {:?}: {:?}",
+                collator,
+                err
+            );
+            return;
+        }
+        Self::deposit_event(Event::<T>::CollatorRewarded(
+            collator,
+            collator_reward,
+            delegators_reward,
+        ));
+    }
+}
+
+impl<T: Config> pallet_authorship::EventHandler<T::AccountId, T::BlockNumber> for Pallet<T> {
+    fn note_author(author: T::AccountId) {
+        Self::reward_author(author)
+    }
+
+    fn note_uncle(_author: T::AccountId, _age: T::BlockNumber) {}
+}
+
+impl<T: Config> RewardClaimer<T::AccountId> for Pallet<T> {
+    fn claim_rewards(who: &T::AccountId, max_claims: u32) -> Result<u32, DispatchError> {
+        let mut claims = 0;
+        for collator in DelegationPools::<T>::iter_keys() {
+            if claims >= max_claims {
+                break;
+            }
+            if Self::pending_rewards(who, &collator).is_zero() {
+                continue;
+            }
+            Self::do_claim_rewards(who, &collator)?;
+            claims += 1;
+        }
+        Ok(claims)
+    }
+}
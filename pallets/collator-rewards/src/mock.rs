@@ -0,0 +1,166 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{construct_runtime, parameter_types, traits::Everything, PalletId};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_std::cell::RefCell;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        CollatorRewards: crate::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const COLLATOR: AccountId = 10;
+pub const TREASURY: AccountId = 100;
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+    pub const MaxLocks: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = MaxLocks;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+}
+
+thread_local! {
+    pub static CANDIDATES: RefCell<Vec<AccountId>> = RefCell::new(vec![COLLATOR]);
+}
+
+pub struct TestCandidates;
+impl SortedMembers<AccountId> for TestCandidates {
+    fn sorted_members() -> Vec<AccountId> {
+        CANDIDATES.with(|c| c.borrow().clone())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &AccountId) {
+        CANDIDATES.with(|c| {
+            let mut candidates = c.borrow_mut();
+            if let Err(index) = candidates.binary_search(who) {
+                candidates.insert(index, *who);
+            }
+        })
+    }
+}
+
+parameter_types! {
+    pub const CollatorRewardsPalletId: PalletId = PalletId(*b"par/clrw");
+    pub const RewardAccount: AccountId = TREASURY;
+    pub const CollatorCommission: Percent = Percent::from_percent(20);
+    pub const MinDelegation: Balance = 100;
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RewardAccount = RewardAccount;
+    type Candidates = TestCandidates;
+    type PalletId = CollatorRewardsPalletId;
+    type CollatorCommission = CollatorCommission;
+    type MinDelegation = MinDelegation;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+/// Author `n` blocks with `author`
+pub(crate) fn author_blocks(author: AccountId, n: u64) {
+    for _ in 0..n {
+        System::set_block_number(System::block_number() + 1);
+        <CollatorRewards as pallet_authorship::EventHandler<_, _>>::note_author(author);
+    }
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ALICE, 10_000),
+            (BOB, 10_000),
+            (CHARLIE, 10_000),
+            (COLLATOR, 10_000),
+            (TREASURY, 1_000_000),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        CollatorRewards::set_reward_per_block(RuntimeOrigin::root(), 100).unwrap();
+    });
+    ext
+}
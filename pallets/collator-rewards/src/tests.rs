@@ -0,0 +1,174 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn set_reward_per_block_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CollatorRewards::set_reward_per_block(RuntimeOrigin::signed(ALICE), 10),
+            BadOrigin
+        );
+        assert_ok!(CollatorRewards::set_reward_per_block(
+            RuntimeOrigin::root(),
+            0
+        ));
+        author_blocks(COLLATOR, 1);
+        assert_eq!(Balances::free_balance(COLLATOR), 10_000);
+    });
+}
+
+#[test]
+fn collator_without_delegators_gets_whole_reward() {
+    new_test_ext().execute_with(|| {
+        author_blocks(COLLATOR, 3);
+        assert_eq!(Balances::free_balance(COLLATOR), 10_300);
+        assert_eq!(Balances::free_balance(TREASURY), 1_000_000 - 300);
+        System::assert_last_event(RuntimeEvent::CollatorRewards(Event::CollatorRewarded(
+            COLLATOR, 100, 0,
+        )));
+    });
+}
+
+#[test]
+fn delegate_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CollatorRewards::delegate(RuntimeOrigin::signed(ALICE), BOB, 1_000),
+            Error::<Test>::NotCandidate
+        );
+        assert_noop!(
+            CollatorRewards::delegate(RuntimeOrigin::signed(ALICE), COLLATOR, 99),
+            Error::<Test>::DelegationTooLow
+        );
+        assert_ok!(CollatorRewards::delegate(
+            RuntimeOrigin::signed(ALICE),
+            COLLATOR,
+            1_000
+        ));
+        assert_eq!(Balances::reserved_balance(ALICE), 1_000);
+        assert_eq!(CollatorRewards::delegation_pool(COLLATOR).total, 1_000);
+        // Topping up doesn't have to reach the min delegation again
+        assert_ok!(CollatorRewards::delegate(
+            RuntimeOrigin::signed(ALICE),
+            COLLATOR,
+            10
+        ));
+        assert_eq!(
+            CollatorRewards::delegation(COLLATOR, ALICE).unwrap().amount,
+            1_010
+        );
+    });
+}
+
+#[test]
+fn rewards_are_shared_with_delegators() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CollatorRewards::delegate(
+            RuntimeOrigin::signed(ALICE),
+            COLLATOR,
+            3_000
+        ));
+        assert_ok!(CollatorRewards::delegate(
+            RuntimeOrigin::signed(BOB),
+            COLLATOR,
+            1_000
+        ));
+        author_blocks(COLLATOR, 10);
+
+        // 20% commission, the rest is split 3:1
+        assert_eq!(Balances::free_balance(COLLATOR), 10_200);
+        assert_eq!(Balances::free_balance(CollatorRewards::account_id()), 800);
+        assert_eq!(CollatorRewards::pending_rewards(&ALICE, &COLLATOR), 600);
+        assert_eq!(CollatorRewards::pending_rewards(&BOB, &COLLATOR), 200);
+
+        // Rewards of blocks authored before delegating aren't shared
+        assert_ok!(CollatorRewards::delegate(
+            RuntimeOrigin::signed(CHARLIE),
+            COLLATOR,
+            4_000
+        ));
+        author_blocks(COLLATOR, 10);
+        assert_eq!(CollatorRewards::pending_rewards(&ALICE, &COLLATOR), 900);
+        assert_eq!(CollatorRewards::pending_rewards(&CHARLIE, &COLLATOR), 400);
+
+        assert_ok!(CollatorRewards::claim_rewards(
+            RuntimeOrigin::signed(ALICE),
+            COLLATOR
+        ));
+        assert_eq!(Balances::free_balance(ALICE), 10_000 - 3_000 + 900);
+        assert_eq!(CollatorRewards::pending_rewards(&ALICE, &COLLATOR), 0);
+        System::assert_last_event(RuntimeEvent::CollatorRewards(Event::RewardsClaimed(
+            ALICE, COLLATOR, 900,
+        )));
+        assert_noop!(
+            CollatorRewards::claim_rewards(RuntimeOrigin::signed(ALICE), BOB),
+            Error::<Test>::NoDelegation
+        );
+    });
+}
+
+#[test]
+fn undelegate_should_pay_pending_rewards() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CollatorRewards::delegate(
+            RuntimeOrigin::signed(ALICE),
+            COLLATOR,
+            1_000
+        ));
+        author_blocks(COLLATOR, 5);
+
+        assert_noop!(
+            CollatorRewards::undelegate(RuntimeOrigin::signed(ALICE), COLLATOR, 1_001),
+            Error::<Test>::InsufficientDelegation
+        );
+        assert_noop!(
+            CollatorRewards::undelegate(RuntimeOrigin::signed(ALICE), COLLATOR, 950),
+            Error::<Test>::DelegationTooLow
+        );
+        assert_ok!(CollatorRewards::undelegate(
+            RuntimeOrigin::signed(ALICE),
+            COLLATOR,
+            1_000
+        ));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 10_000 + 400);
+        assert_eq!(CollatorRewards::delegation(COLLATOR, ALICE), None);
+        assert_eq!(CollatorRewards::delegation_pool(COLLATOR).total, 0);
+
+        // Without delegators the collator gets the whole reward again
+        author_blocks(COLLATOR, 1);
+        assert_eq!(Balances::free_balance(COLLATOR), 10_000 + 5 * 20 + 100);
+    });
+}
+
+#[test]
+fn reward_claimer_claims_pending_delegations() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CollatorRewards::delegate(
+            RuntimeOrigin::signed(ALICE),
+            COLLATOR,
+            1_000
+        ));
+        author_blocks(COLLATOR, 10);
+
+        assert_eq!(
+            <CollatorRewards as RewardClaimer<_>>::claim_rewards(&ALICE, 0),
+            Ok(0)
+        );
+        assert_eq!(
+            <CollatorRewards as RewardClaimer<_>>::claim_rewards(&BOB, 1),
+            Ok(0)
+        );
+        assert_eq!(
+            <CollatorRewards as RewardClaimer<_>>::claim_rewards(&ALICE, 1),
+            Ok(1)
+        );
+        assert_eq!(Balances::free_balance(ALICE), 10_000 - 1_000 + 800);
+        assert_eq!(
+            <CollatorRewards as RewardClaimer<_>>::claim_rewards(&ALICE, 1),
+            Ok(0)
+        );
+    });
+}
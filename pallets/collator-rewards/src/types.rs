@@ -0,0 +1,64 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use primitives::{Balance, Rate};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    FixedPointNumber, RuntimeDebug,
+};
+
+/// Delegations backing a collator
+#[derive(Clone, Copy, PartialEq, Eq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DelegationPool {
+    /// Sum of the delegated amounts
+    pub total: Balance,
+    /// Rewards accumulated by a delegated unit since the pool was created
+    pub reward_per_share: Rate,
+}
+
+impl DelegationPool {
+    /// Share `reward` between the delegations
+    pub fn distribute(&mut self, reward: Balance) {
+        if self.total.is_zero() {
+            return;
+        }
+        if let Some(per_share) = Rate::checked_from_rational(reward, self.total) {
+            self.reward_per_share = self.reward_per_share.saturating_add(per_share);
+        }
+    }
+}
+
+/// Amount delegated by an account to a collator
+#[derive(Clone, Copy, PartialEq, Eq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct Delegation {
+    pub amount: Balance,
+    /// Rewards of `amount` accumulated before they could be claimed
+    pub reward_debt: Balance,
+}
+
+impl Delegation {
+    /// Rewards which can be claimed from `pool`
+    pub fn pending_rewards(&self, pool: &DelegationPool) -> Balance {
+        pool.reward_per_share
+            .saturating_mul_int(self.amount)
+            .saturating_sub(self.reward_debt)
+    }
+
+    /// Mark the rewards of `pool` as claimed
+    pub fn settle(&mut self, pool: &DelegationPool) {
+        self.reward_debt = pool.reward_per_share.saturating_mul_int(self.amount);
+    }
+}
@@ -0,0 +1,141 @@
+// This file is part of Parallel Finance.
+
+// Copyright (C) 2022 Parallel Finance Developer.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_collator_rewards
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet-collator-rewards
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/collator-rewards/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions needed for pallet_collator_rewards.
+pub trait WeightInfo {
+	fn set_reward_per_block() -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn claim_rewards() -> Weight;
+}
+
+/// Weights for pallet_collator_rewards using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorRewards RewardPerBlock (r:0 w:1)
+	fn set_reward_per_block() -> Weight {
+		Weight::from_ref_time(18_215_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorSelection Invulnerables (r:1 w:0)
+	// Storage: CollatorRewards DelegationPools (r:1 w:1)
+	// Storage: CollatorRewards Delegations (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:0 w:1)
+	fn delegate() -> Weight {
+		Weight::from_ref_time(52_804_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorRewards DelegationPools (r:1 w:1)
+	// Storage: CollatorRewards Delegations (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Balances Reserves (r:0 w:1)
+	fn undelegate() -> Weight {
+		Weight::from_ref_time(58_127_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorRewards DelegationPools (r:1 w:0)
+	// Storage: CollatorRewards Delegations (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_rewards() -> Weight {
+		Weight::from_ref_time(44_390_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorRewards RewardPerBlock (r:0 w:1)
+	fn set_reward_per_block() -> Weight {
+		Weight::from_ref_time(18_215_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorSelection Invulnerables (r:1 w:0)
+	// Storage: CollatorRewards DelegationPools (r:1 w:1)
+	// Storage: CollatorRewards Delegations (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:0 w:1)
+	fn delegate() -> Weight {
+		Weight::from_ref_time(52_804_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorRewards DelegationPools (r:1 w:1)
+	// Storage: CollatorRewards Delegations (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Balances Reserves (r:0 w:1)
+	fn undelegate() -> Weight {
+		Weight::from_ref_time(58_127_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorRewards DelegationPools (r:1 w:0)
+	// Storage: CollatorRewards Delegations (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_rewards() -> Weight {
+		Weight::from_ref_time(44_390_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+}
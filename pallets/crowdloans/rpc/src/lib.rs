@@ -0,0 +1,148 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+pub use pallet_crowdloans_rpc_runtime_api::{
+    CrowdloansApi as CrowdloansRuntimeApi, VaultPhase, VaultStatus,
+};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use primitives::CurrencyId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[rpc(client, server)]
+pub trait CrowdloansApi<BlockHash, AccountId, Balance, BlockNumber>
+where
+    Balance: Codec + Copy + TryInto<NumberOrHex>,
+{
+    /// Funds, lease end and claimable contribution of `account` for every vault.
+    #[method(name = "crowdloans_getVaultStatuses")]
+    fn get_vault_statuses(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<VaultStatus<CurrencyId, NumberOrHex, BlockNumber>>>;
+}
+
+/// A struct that implements the [`CrowdloansApi`].
+pub struct Crowdloans<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Crowdloans<C, B> {
+    /// Create new `Crowdloans` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+pub enum Error {
+    RuntimeError,
+    VaultStatusError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::VaultStatusError => 2,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance, BlockNumber>
+    CrowdloansApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber>
+    for Crowdloans<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: CrowdloansRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    AccountId: Codec,
+    Balance: Codec + Copy + TryInto<NumberOrHex> + std::fmt::Display,
+    BlockNumber: Codec,
+{
+    fn get_vault_statuses(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<VaultStatus<CurrencyId, NumberOrHex, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(self.client.info().best_hash));
+        api.vault_statuses(&at, account)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(vault_status_error_into_rpc_error)?
+            .into_iter()
+            .map(|status| {
+                Ok(VaultStatus {
+                    crowdloan: status.crowdloan,
+                    vault_id: status.vault_id,
+                    ctoken: status.ctoken,
+                    phase: status.phase,
+                    total_contributed: try_into_rpc_balance(status.total_contributed)?,
+                    ctoken_supply: try_into_rpc_balance(status.ctoken_supply)?,
+                    lease_end_block: status.lease_end_block,
+                    lease_end_time: status.lease_end_time,
+                    contribution: try_into_rpc_balance(status.contribution)?,
+                    claimable: status.claimable,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Runtime trapped",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+/// Converts an error of reading the vaults into an RPC error.
+fn vault_status_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::VaultStatusError.into(),
+        "Not able to get vault statuses",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+fn try_into_rpc_balance<T: std::fmt::Display + Copy + TryInto<NumberOrHex>>(
+    value: T,
+) -> RpcResult<NumberOrHex> {
+    value.try_into().map_err(|_| {
+        JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+            ErrorCode::InvalidParams.code(),
+            format!("{} doesn't fit in NumberOrHex representation", value),
+            None::<()>,
+        )))
+    })
+}
@@ -309,7 +309,7 @@ benchmarks! {
         assert_ok!(Crowdloans::<T>::create_vault(SystemOrigin::Root.into(), crowdloan, ctoken, LEASE_START, LEASE_END, ContributionStrategy::XCM, LARGE_CAP, END_BLOCK.into()));
         assert_ok!(Crowdloans::<T>::open(SystemOrigin::Root.into(), crowdloan));
         assert_ok!(Crowdloans::<T>::contribute(SystemOrigin::Signed(caller.clone()).into(), crowdloan, CONTRIBUTE_AMOUNT, Vec::new()));
-        assert_ok!(Crowdloans::<T>::notification_received(
+        assert_ok!(pallet_xcm_helper::Pallet::<T>::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -335,14 +335,14 @@ benchmarks! {
         assert_ok!(Crowdloans::<T>::create_vault(SystemOrigin::Root.into(), crowdloan, ctoken, LEASE_START, LEASE_END, ContributionStrategy::XCM, LARGE_CAP, END_BLOCK.into()));
         assert_ok!(Crowdloans::<T>::open(SystemOrigin::Root.into(), crowdloan));
         assert_ok!(Crowdloans::<T>::contribute(SystemOrigin::Signed(caller.clone()).into(), crowdloan, CONTRIBUTE_AMOUNT, Vec::new()));
-        assert_ok!(Crowdloans::<T>::notification_received(
+        assert_ok!(pallet_xcm_helper::Pallet::<T>::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
         ));
         assert_ok!(Crowdloans::<T>::close(SystemOrigin::Root.into(), crowdloan));
         assert_ok!(Crowdloans::<T>::auction_failed(SystemOrigin::Root.into(), crowdloan));
-        assert_ok!(Crowdloans::<T>::notification_received(
+        assert_ok!(pallet_xcm_helper::Pallet::<T>::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             1,
             Response::ExecutionResult(None),
@@ -366,7 +366,7 @@ benchmarks! {
         assert_ok!(Crowdloans::<T>::create_vault(SystemOrigin::Root.into(), crowdloan, ctoken, LEASE_START, LEASE_END, ContributionStrategy::XCM, LARGE_CAP, END_BLOCK.into()));
         assert_ok!(Crowdloans::<T>::open(SystemOrigin::Root.into(), crowdloan));
         assert_ok!(Crowdloans::<T>::contribute(SystemOrigin::Signed(caller.clone()).into(), crowdloan, CONTRIBUTE_AMOUNT, Vec::new()));
-        assert_ok!(Crowdloans::<T>::notification_received(
+        assert_ok!(pallet_xcm_helper::Pallet::<T>::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -375,7 +375,7 @@ benchmarks! {
         assert_ok!(Crowdloans::<T>::auction_succeeded(SystemOrigin::Root.into(), crowdloan));
         assert_ok!(Crowdloans::<T>::slot_expired(SystemOrigin::Root.into(), crowdloan));
         assert_ok!(Crowdloans::<T>::claim(SystemOrigin::Signed(caller.clone()).into(), crowdloan, LEASE_START, LEASE_END));
-        assert_ok!(Crowdloans::<T>::notification_received(
+        assert_ok!(pallet_xcm_helper::Pallet::<T>::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             1,
             Response::ExecutionResult(None),
@@ -454,7 +454,7 @@ benchmarks! {
         assert_ok!(Crowdloans::<T>::create_vault(SystemOrigin::Root.into(), crowdloan, ctoken, LEASE_START, LEASE_END, ContributionStrategy::XCM, LARGE_CAP, END_BLOCK.into()));
         assert_ok!(Crowdloans::<T>::open(SystemOrigin::Root.into(), crowdloan));
         assert_ok!(Crowdloans::<T>::contribute(SystemOrigin::Signed(caller).into(), crowdloan, CONTRIBUTE_AMOUNT, Vec::new()));
-        assert_ok!(Crowdloans::<T>::notification_received(
+        assert_ok!(pallet_xcm_helper::Pallet::<T>::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
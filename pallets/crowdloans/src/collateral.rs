@@ -0,0 +1,121 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Crowdloan ctokens as loans collateral
+//!
+//! The ctokens are locked until their lease ends, so they are only held as collateral by
+//! the loans pallet, through its `CollateralAdapter`. The risk of holding them shrinks as
+//! the lease gets closer to its end, the collateral factor grows from `MinScale` of the
+//! registered one when the lease starts to all of it when the lease ends.
+
+use crate::{AssetIdOf, BalanceOf, Config, Pallet};
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    traits::{fungibles::Transfer, Get},
+};
+use pallet_traits::{loans::CollateralAdapter, PriceFeeder};
+use primitives::{Price, Rate, Ratio};
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    FixedPointNumber,
+};
+use sp_std::marker::PhantomData;
+
+/// Keeps the ctokens deposited as loans collateral in the collateral account of the
+/// pallet, priced by `Prices`
+pub struct CTokenCollateral<T, Prices, MinScale>(PhantomData<(T, Prices, MinScale)>);
+
+impl<T, Prices, MinScale> CTokenCollateral<T, Prices, MinScale>
+where
+    T: Config,
+    MinScale: Get<Ratio>,
+{
+    /// Share of the registered collateral factor `ctoken` has, growing with the part of
+    /// its lease which already elapsed. Until the relaychain block is known, the lease is
+    /// assumed not to have started.
+    pub fn factor_scale(ctoken: AssetIdOf<T>) -> Ratio {
+        let elapsed = Pallet::<T>::find_vault_by_asset_id(&ctoken)
+            .and_then(Pallet::<T>::get_vault_term_rate)
+            .map_or(Ratio::zero(), |(term_rate, _)| {
+                Ratio::from_rational(term_rate.into_inner(), Rate::accuracy())
+            });
+        let min_scale = MinScale::get();
+        min_scale.saturating_add(min_scale.left_from_one() * elapsed)
+    }
+}
+
+impl<T, Prices, MinScale> CollateralAdapter<T::AccountId, AssetIdOf<T>, BalanceOf<T>>
+    for CTokenCollateral<T, Prices, MinScale>
+where
+    T: Config,
+    Prices: PriceFeeder,
+    MinScale: Get<Ratio>,
+{
+    fn is_supported(asset: AssetIdOf<T>) -> bool {
+        Pallet::<T>::find_vault_by_asset_id(&asset).is_some()
+    }
+
+    fn price(asset: AssetIdOf<T>) -> Result<Price, DispatchError> {
+        Prices::get_price(&asset)
+            .map(|(price, _)| price)
+            .filter(|price| !price.is_zero())
+            .ok_or(DispatchError::Other("ctoken price not ready"))
+    }
+
+    fn collateral_factor(asset: AssetIdOf<T>, registered: Ratio) -> Ratio {
+        Self::factor_scale(asset) * registered
+    }
+
+    fn on_deposit(who: &T::AccountId, asset: AssetIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+        T::Assets::transfer(
+            asset,
+            who,
+            &Pallet::<T>::collateral_account_id(),
+            amount,
+            false,
+        )
+        .map(|_| ())
+    }
+
+    fn on_withdraw(
+        who: &T::AccountId,
+        asset: AssetIdOf<T>,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        T::Assets::transfer(
+            asset,
+            &Pallet::<T>::collateral_account_id(),
+            who,
+            amount,
+            false,
+        )
+        .map(|_| ())
+    }
+
+    fn seize(
+        _borrower: &T::AccountId,
+        to: &T::AccountId,
+        asset: AssetIdOf<T>,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        T::Assets::transfer(
+            asset,
+            &Pallet::<T>::collateral_account_id(),
+            to,
+            amount,
+            false,
+        )
+        .map(|_| ())
+    }
+}
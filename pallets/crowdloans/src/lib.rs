@@ -27,6 +27,7 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod collateral;
 pub mod migrations;
 pub mod types;
 pub mod weights;
@@ -46,7 +47,7 @@ pub mod pallet {
         storage::{child, ChildTriePrefixIterator},
         traits::{
             fungibles::{Inspect, Mutate, Transfer},
-            Get, SortedMembers,
+            Get, SortedMembers, UnixTime,
         },
         transactional, Blake2_128Concat, PalletId,
     };
@@ -56,7 +57,8 @@ pub mod pallet {
     };
     use pallet_xcm::ensure_response;
     use primitives::{
-        ArithmeticKind, Balance, CurrencyId, LeasePeriod, ParaId, Rate, TrieIndex, VaultId,
+        ArithmeticKind, Balance, CurrencyId, LeasePeriod, ParaId, Rate, Timestamp, TrieIndex,
+        VaultId, SECONDS_PER_YEAR,
     };
     use sp_runtime::{
         traits::{
@@ -68,7 +70,8 @@ pub mod pallet {
     use xcm::latest::prelude::*;
 
     use pallet_traits::{
-        DecimalProvider, Streaming, VaultTokenCurrenciesFilter, VaultTokenExchangeRateProvider,
+        xcm::XcmResponseCallback, DecimalProvider, Referrals, Streaming,
+        VaultTokenCurrenciesFilter, VaultTokenExchangeRateProvider,
     };
 
     use parallel_support::math_helper::f64::{
@@ -112,8 +115,6 @@ pub mod pallet {
         type RuntimeOrigin: IsType<<Self as frame_system::Config>::RuntimeOrigin>
             + Into<Result<pallet_xcm::Origin, <Self as Config>::RuntimeOrigin>>;
 
-        type RuntimeCall: IsType<<Self as pallet_xcm::Config>::RuntimeCall> + From<Call<Self>>;
-
         /// Returns the parachain ID we are running with.
         #[pallet::constant]
         type SelfParaId: Get<ParaId>;
@@ -202,6 +203,12 @@ pub mod pallet {
 
         /// Decimal provider.
         type Decimal: DecimalProvider<CurrencyId>;
+
+        /// Refers contributors with the referral code of their contribution
+        type Referrals: Referrals<Self::AccountId>;
+
+        /// Unix time, to estimate when the leases end
+        type UnixTime: UnixTime;
     }
 
     #[pallet::event]
@@ -266,7 +273,7 @@ pub mod pallet {
         /// Vrfs updated
         /// [vrf_flag]
         VrfUpdated(bool),
-        /// Notification received
+        /// Notification received, deprecated along with `notification_received`
         /// [multi_location, query_id, res]
         NotificationReceived(Box<MultiLocation>, QueryId, Option<(u32, XcmError)>),
         /// All contributions migrated
@@ -340,6 +347,8 @@ pub mod pallet {
         EmptyProxyAddress,
         /// BonusConfig is wrong
         WrongBonusConfig,
+        /// Referral code isn't registered
+        UnknownReferralCode,
     }
 
     #[pallet::storage]
@@ -576,7 +585,8 @@ pub mod pallet {
         }
 
         /// Contribute `amount` to the vault of `crowdloan` and receive some
-        /// shares from it
+        /// shares from it. `referral_code` is either empty or a code registered
+        /// in the referrals program
         #[pallet::weight(<T as Config>::WeightInfo::contribute())]
         #[transactional]
         pub fn contribute(
@@ -608,6 +618,12 @@ pub mod pallet {
 
             ensure!(!Self::is_vrf(), Error::<T>::VrfDelayInProgress);
 
+            ensure!(
+                referral_code.is_empty()
+                    || T::Referrals::referrer_of_code(&referral_code).is_some(),
+                Error::<T>::UnknownReferralCode
+            );
+
             ensure!(
                 Self::total_contribution(&vault)?
                     .checked_add(amount)
@@ -661,6 +677,7 @@ pub mod pallet {
                 ),
                 vault,
             );
+            T::Referrals::refer(&who, &referral_code);
 
             log::trace!(
                 target: "crowdloans::contribute",
@@ -993,6 +1010,8 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Deprecated, the responses are dispatched by XcmHelper. Kept at its call index
+        /// so that queries registered before the upgrade still settle their requests.
         #[pallet::weight(<T as Config>::WeightInfo::notification_received())]
         #[transactional]
         pub fn notification_received(
@@ -1002,8 +1021,11 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let responder = ensure_response(<T as Config>::RuntimeOrigin::from(origin))?;
             if let Response::ExecutionResult(res) = response {
-                if let Some(request) = Self::xcm_request(&query_id) {
-                    Self::do_notification_received(query_id, request, res)?;
+                match res {
+                    Some((index, error)) => {
+                        <Self as XcmResponseCallback>::on_error(query_id, index, error)?
+                    }
+                    None => <Self as XcmResponseCallback>::on_success(query_id)?,
                 }
 
                 Self::deposit_event(Event::<T>::NotificationReceived(
@@ -1203,6 +1225,11 @@ pub mod pallet {
             T::PalletId::get().into_account_truncating()
         }
 
+        /// Account keeping the ctokens deposited as loans collateral
+        pub fn collateral_account_id() -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(b"collateral")
+        }
+
         /// Parachain's sovereign account on relaychain
         pub fn para_account_id() -> T::AccountId {
             T::SelfParaId::get().into_account_truncating()
@@ -1214,6 +1241,133 @@ pub mod pallet {
             })
         }
 
+        /// Amounts `who` can claim from succeeded and failed vaults, as
+        /// `(crowdloan, vault_id, asset, amount)`. Ctokens and the native bonus
+        /// streamed on claim are listed for succeeded vaults, the relay currency
+        /// for failed ones.
+        pub fn claimable_contributions(
+            who: &T::AccountId,
+        ) -> Vec<(ParaId, VaultId, AssetIdOf<T>, BalanceOf<T>)> {
+            let mut claimable = Vec::new();
+            for ((crowdloan, lease_start, lease_end), vault) in Vaults::<T>::iter() {
+                let (amount, _) =
+                    Self::contribution_get(vault.trie_index, who, ChildStorageKind::Contributed);
+                if amount.is_zero() {
+                    continue;
+                }
+                let vault_id = (lease_start, lease_end);
+                match vault.phase {
+                    VaultPhase::Succeeded => {
+                        claimable.push((crowdloan, vault_id, vault.ctoken, amount));
+                        let bonus_config = Self::leases_bonus((&lease_start, &lease_end));
+                        let bonus_amount = Self::normalized_amount(
+                            amount.saturating_mul(bonus_config.bonus_per_token),
+                        )
+                        .unwrap_or_default();
+                        if !bonus_amount.is_zero() {
+                            claimable.push((
+                                crowdloan,
+                                vault_id,
+                                T::GetNativeCurrencyId::get(),
+                                bonus_amount,
+                            ));
+                        }
+                    }
+                    VaultPhase::Failed => {
+                        claimable.push((crowdloan, vault_id, T::RelayCurrency::get(), amount));
+                    }
+                    _ => {}
+                }
+            }
+            claimable
+        }
+
+        /// Contributions of `who` to each vault together with the ctokens it holds, as
+        /// `(crowdloan, vault_id, ctoken, contributed, ctoken_balance)`. The contribution is
+        /// cleared once its ctokens are claimed.
+        pub fn account_positions(
+            who: &T::AccountId,
+        ) -> Vec<(ParaId, VaultId, AssetIdOf<T>, BalanceOf<T>, BalanceOf<T>)> {
+            let mut positions = Vec::new();
+            for ((crowdloan, lease_start, lease_end), vault) in Vaults::<T>::iter() {
+                let (contributed, _) =
+                    Self::contribution_get(vault.trie_index, who, ChildStorageKind::Contributed);
+                let ctoken_balance = T::Assets::balance(vault.ctoken, who);
+                if contributed.is_zero() && ctoken_balance.is_zero() {
+                    continue;
+                }
+                positions.push((
+                    crowdloan,
+                    (lease_start, lease_end),
+                    vault.ctoken,
+                    contributed,
+                    ctoken_balance,
+                ));
+            }
+            positions
+        }
+
+        /// Funds and maturity of every vault, along with the contribution `who` can
+        /// claim from it
+        pub fn vault_statuses(
+            who: &T::AccountId,
+        ) -> Result<Vec<VaultStatus<AssetIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>>, DispatchError>
+        {
+            let current_block = T::RelayChainBlockNumberProvider::current_block_number();
+            let now = T::UnixTime::now().as_secs();
+            let mut statuses = Vec::new();
+            for ((crowdloan, lease_start, lease_end), vault) in Vaults::<T>::iter() {
+                let (contribution, _) =
+                    Self::contribution_get(vault.trie_index, who, ChildStorageKind::Contributed);
+                let lease_end_block = Self::lease_end_block(lease_end);
+                statuses.push(VaultStatus {
+                    crowdloan,
+                    vault_id: (lease_start, lease_end),
+                    ctoken: vault.ctoken,
+                    phase: vault.phase,
+                    total_contributed: Self::total_contribution(&vault)?,
+                    ctoken_supply: T::Assets::total_issuance(vault.ctoken),
+                    lease_end_block,
+                    lease_end_time: Self::estimate_time(current_block, now, lease_end_block),
+                    contribution,
+                    claimable: vault.phase == VaultPhase::Succeeded && !contribution.is_zero(),
+                });
+            }
+            Ok(statuses)
+        }
+
+        /// Relaychain block the lease ending with the `lease_end` period ends at
+        fn lease_end_block(lease_end: LeasePeriod) -> BlockNumberFor<T> {
+            T::LeasePeriod::get()
+                .saturating_mul((lease_end + 1).into())
+                .saturating_add(T::LeaseOffset::get())
+        }
+
+        // relaychain blocks are assumed to last the same, `LeasePerYear` leases a year
+        fn estimate_time(
+            current_block: BlockNumberFor<T>,
+            now: Timestamp,
+            block: BlockNumberFor<T>,
+        ) -> Option<Timestamp> {
+            if current_block.is_zero() {
+                return None;
+            }
+            let blocks_per_year: Timestamp = T::LeasePerYear::get()
+                .saturating_mul(T::LeasePeriod::get())
+                .saturated_into();
+            let seconds_between = |from: BlockNumberFor<T>, to: BlockNumberFor<T>| {
+                to.saturating_sub(from)
+                    .saturated_into::<Timestamp>()
+                    .saturating_mul(SECONDS_PER_YEAR)
+                    .checked_div(blocks_per_year)
+            };
+            if block >= current_block {
+                seconds_between(current_block, block).map(|secs| now.saturating_add(secs))
+            } else {
+                seconds_between(block, current_block).map(|secs| now.saturating_sub(secs))
+            }
+        }
+
         pub(crate) fn total_contribution(
             vault: &Vault<T>,
         ) -> Result<BalanceOf<T>, ArithmeticError> {
@@ -1224,13 +1378,6 @@ pub mod pallet {
                 .ok_or(ArithmeticError::Overflow)
         }
 
-        fn notify_placeholder() -> <T as Config>::RuntimeCall {
-            <T as Config>::RuntimeCall::from(Call::<T>::notification_received {
-                query_id: Default::default(),
-                response: Default::default(),
-            })
-        }
-
         /// Get and recalculate the user's contribution for the specified kind of child storage
         #[require_transactional]
         fn do_update_contribution(
@@ -1526,7 +1673,7 @@ pub mod pallet {
         ) -> Result<(), DispatchError> {
             let query_id = match contribution_strategy {
                 ContributionStrategy::XCM => {
-                    T::XCM::do_contribute(crowdloan, amount, who, Self::notify_placeholder())?
+                    T::XCM::do_contribute(crowdloan, amount, who, T::PalletId::get())?
                 }
                 ContributionStrategy::XCMPROXY => {
                     let proxy_address =
@@ -1535,7 +1682,7 @@ pub mod pallet {
                         crowdloan,
                         amount,
                         &proxy_address,
-                        Self::notify_placeholder(),
+                        T::PalletId::get(),
                     )?
                 }
             };
@@ -1576,11 +1723,8 @@ pub mod pallet {
                 &amount,
             );
 
-            let query_id = T::XCM::do_withdraw(
-                crowdloan,
-                Self::para_account_id(),
-                Self::notify_placeholder(),
-            )?;
+            let query_id =
+                T::XCM::do_withdraw(crowdloan, Self::para_account_id(), T::PalletId::get())?;
 
             XcmRequests::<T>::insert(
                 query_id,
@@ -1772,7 +1916,9 @@ pub mod pallet {
         }
 
         // just iterate now and require improve later when CTokensRegistry increased
-        fn find_vault_by_asset_id(asset_id: &AssetIdOf<T>) -> Option<(AssetIdOf<T>, AssetIdOf<T>)> {
+        pub(crate) fn find_vault_by_asset_id(
+            asset_id: &AssetIdOf<T>,
+        ) -> Option<(AssetIdOf<T>, AssetIdOf<T>)> {
             for (vault, ctoken_id) in CTokensRegistry::<T>::iter() {
                 if &ctoken_id == asset_id {
                     return Some(vault);
@@ -1781,7 +1927,7 @@ pub mod pallet {
             None
         }
 
-        fn get_vault_term_rate(
+        pub(crate) fn get_vault_term_rate(
             (start_lease, end_lease): (LeasePeriod, LeasePeriod),
         ) -> Option<(Rate, Rate)> {
             let current_block = T::RelayChainBlockNumberProvider::current_block_number();
@@ -1792,9 +1938,7 @@ pub mod pallet {
             let start_block = lease_period
                 .saturating_mul(start_lease.into())
                 .saturating_add(T::LeaseOffset::get());
-            let end_block = lease_period
-                .saturating_mul((end_lease + 1).into())
-                .saturating_add(T::LeaseOffset::get());
+            let end_block = Self::lease_end_block(end_lease);
             let lease_length = lease_period.saturating_mul((end_lease - start_lease + 1).into());
             let blocks_per_year = T::LeasePerYear::get().saturating_mul(lease_period);
             let total_term_by_year = Rate::saturating_from_rational(
@@ -1843,4 +1987,34 @@ pub mod pallet {
             Self::find_vault_by_asset_id(asset_id).is_some()
         }
     }
+
+    impl<T: Config> XcmResponseCallback for Pallet<T> {
+        fn callback_id() -> PalletId {
+            T::PalletId::get()
+        }
+
+        fn on_success(query_id: QueryId) -> DispatchResult {
+            if let Some(request) = Self::xcm_request(&query_id) {
+                Self::do_notification_received(query_id, request, None)?;
+            }
+            Ok(())
+        }
+
+        // failed contributions are refunded to the contributor
+        fn on_error(query_id: QueryId, index: u32, error: XcmError) -> DispatchResult {
+            if let Some(request) = Self::xcm_request(&query_id) {
+                Self::do_notification_received(query_id, request, Some((index, error)))?;
+            }
+            Ok(())
+        }
+
+        fn on_timeout(query_id: QueryId) -> DispatchResult {
+            log::error!(
+                target: "crowdloans::on_timeout",
+                "query_id: {:?}",
+                &query_id
+            );
+            Ok(())
+        }
+    }
 }
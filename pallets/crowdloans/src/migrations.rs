@@ -15,6 +15,35 @@
 //! # Add vaults for batch 1 winning projects
 use super::*;
 
+/// Hands the xcm requests pending before the upgrade over to XcmHelper, so that they
+/// expire like the new ones. Their responses still arrive through `notification_received`.
+///
+/// Requests already tracked are skipped, so the migration can stay in place after the upgrade.
+pub struct TrackPendingXcmRequests<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> frame_support::traits::OnRuntimeUpgrade for TrackPendingXcmRequests<T> {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        use frame_support::{log, traits::Get};
+        use pallet_xcm_helper::XcmHelper;
+
+        let mut count = 0u64;
+        let mut tracked = 0u64;
+        for query_id in XcmRequests::<T>::iter_keys() {
+            count += 1;
+            if T::XCM::track_query(query_id, T::PalletId::get()).is_ok() {
+                tracked += 1;
+            }
+        }
+
+        log::info!(
+            target: "crowdloans::migrate",
+            "tracked {} of {} pending xcm requests",
+            tracked,
+            count
+        );
+        T::DbWeight::get().reads_writes(count * 3, tracked)
+    }
+}
+
 pub mod v1 {
     use super::*;
     use frame_support::{log, traits::Get};
@@ -18,12 +18,12 @@ pub use pallet_traits::{VaultTokenCurrenciesFilter, VaultTokenExchangeRateProvid
 use pallet_xcm::XcmPassthrough;
 use polkadot_parachain::primitives::{IsSystem, Sibling};
 use polkadot_runtime_parachains::configuration::HostConfiguration;
-use primitives::{tokens::*, Balance, ParaId};
+use primitives::{tokens::*, Balance, ParaId, Price, PriceDetail, Ratio};
 use sp_core::H256;
 use sp_runtime::{
     generic,
     traits::{
-        AccountIdConversion, AccountIdLookup, BlakeTwo256, BlockNumberProvider, Convert, Zero,
+        AccountIdConversion, AccountIdLookup, BlakeTwo256, BlockNumberProvider, Convert, One, Zero,
     },
     AccountId32, DispatchError,
     MultiAddress::Id,
@@ -46,7 +46,7 @@ pub use kusama_runtime;
 use pallet_traits::{
     ump::{XcmCall, XcmWeightFeeMisc},
     xcm::MultiCurrencyAdapter,
-    DecimalProvider,
+    DecimalProvider, PriceFeeder, Referrals, VolumeSource,
 };
 
 pub struct RelayChainBlockNumberProvider<T>(sp_std::marker::PhantomData<T>);
@@ -466,6 +466,7 @@ parameter_types! {
     pub const MigrateKeysLimit: u32 = 5;
     pub const RemoveKeysLimit: u32 = 1000;
     pub SelfParaId: ParaId = para_a_id();
+    pub AssetHubParaId: ParaId = ParaId::from(1000);
     pub RefundLocation: AccountId = para_a_id().into_account_truncating();
     pub const LeasePeriod: BlockNumber = 84*24*3600/6;//84 days same as polkadot
     pub const LeaseOffset: BlockNumber = 64*24*3600/6;//64 days same as polkadot
@@ -498,7 +499,6 @@ pub type SlotExpiredOrigin =
 impl crate::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeOrigin = RuntimeOrigin;
-    type RuntimeCall = RuntimeCall;
     type PalletId = CrowdloansPalletId;
     type SelfParaId = SelfParaId;
     type Assets = Assets;
@@ -526,6 +526,53 @@ impl crate::Config for Test {
     type Streaming = ();
     type GetNativeCurrencyId = NativeCurrencyId;
     type Decimal = Decimal;
+    type Referrals = MockReferrals;
+    type UnixTime = TimestampPallet;
+}
+
+/// Only knows the referral code `[12, 34]`, registered by EVE
+pub struct MockReferrals;
+impl Referrals<AccountId> for MockReferrals {
+    fn referrer_of_code(code: &[u8]) -> Option<AccountId> {
+        (code == [12, 34]).then_some(EVE)
+    }
+
+    fn refer(_who: &AccountId, _code: &[u8]) {}
+
+    fn record_volume(
+        _who: &AccountId,
+        _source: VolumeSource,
+        _asset_id: CurrencyId,
+        _amount: Balance,
+    ) {
+    }
+}
+
+/// Prices every ctoken at 1
+pub struct MockPriceFeeder;
+
+impl PriceFeeder for MockPriceFeeder {
+    fn get_price(_asset_id: &CurrencyId) -> Option<PriceDetail> {
+        Some((Price::one(), 0))
+    }
+}
+
+parameter_types! {
+    pub const CTokenMinCollateralScale: Ratio = Ratio::from_percent(50);
+}
+
+pub type CTokenCollateral =
+    crate::collateral::CTokenCollateral<Test, MockPriceFeeder, CTokenMinCollateralScale>;
+
+parameter_types! {
+    pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
 }
 
 pub struct Decimal;
@@ -547,21 +594,32 @@ impl DecimalProvider<CurrencyId> for Decimal {
 parameter_types! {
     pub const XcmHelperPalletId: PalletId = PalletId(*b"par/fees");
     pub const NotifyTimeout: BlockNumber = 100;
+    pub const MaxXcmRetries: u32 = 3;
+    pub const MaxRescueReasonLength: u32 = 256;
 }
 
 impl pallet_xcm_helper::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
     type UpdateOrigin = EnsureRoot<AccountId>;
     type Assets = Assets;
     type XcmSender = XcmRouter;
     type PalletId = XcmHelperPalletId;
     type RelayNetwork = RelayNetwork;
     type NotifyTimeout = NotifyTimeout;
+    type MaxXcmRetries = MaxXcmRetries;
+    type ResponseHandler = (Crowdloans,);
     type AccountIdToMultiLocation = AccountIdToMultiLocation;
     type RefundLocation = RefundLocation;
     type BlockNumberProvider = frame_system::Pallet<Test>;
     type WeightInfo = ();
     type RelayCurrency = RelayCurrency;
+    type SelfParaId = SelfParaId;
+    type AssetHubParaId = AssetHubParaId;
+    type CurrencyIdConvert = CurrencyIdConvert;
+    type MaxXcmInstructions = MaxInstructions;
+    type MaxRescueReasonLength = MaxRescueReasonLength;
 }
 
 parameter_types! {
@@ -597,6 +655,7 @@ construct_runtime!(
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        TimestampPallet: pallet_timestamp::{Pallet, Call, Storage, Inherent},
         Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
         Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
         Crowdloans: crate::{Pallet, Storage, Call, Event<T>},
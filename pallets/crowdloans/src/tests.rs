@@ -8,8 +8,9 @@ use frame_support::{
     traits::{Hooks, OneSessionHandler},
 };
 use frame_system::RawOrigin;
+use pallet_traits::loans::CollateralAdapter;
 use polkadot_parachain::primitives::{HeadData, ValidationCode};
-use primitives::{tokens::DOT, BlockNumber, ParaId, Rate};
+use primitives::{tokens::DOT, BlockNumber, ParaId, Price, Rate, Ratio};
 use sp_runtime::{
     traits::{One, UniqueSaturatedInto, Zero},
     DispatchError,
@@ -203,7 +204,7 @@ fn open_should_work() {
         assert!(referral_code2 == vec![12, 34]);
         assert!(flying == amount);
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -388,7 +389,7 @@ fn contribute_should_work() {
         let vault = Crowdloans::vaults((&crowdloan, &LEASE_START, &LEASE_END)).unwrap();
         assert_eq!(vault.phase, VaultPhase::Contributing);
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -406,6 +407,48 @@ fn contribute_should_work() {
     });
 }
 
+#[test]
+fn contribute_should_fail_with_unknown_referral_code() {
+    new_test_ext().execute_with(|| {
+        let crowdloan = ParaId::from(1337u32);
+        let ctoken = 10;
+
+        assert_ok!(Assets::force_create(
+            RawOrigin::Root.into(),
+            ctoken.unique_saturated_into(),
+            Id(Crowdloans::account_id()),
+            true,
+            One::one(),
+        ));
+        assert_ok!(Crowdloans::create_vault(
+            frame_system::RawOrigin::Root.into(),
+            crowdloan,
+            ctoken,
+            LEASE_START,
+            LEASE_END,
+            ContributionStrategy::XCM,
+            1_000_000_000_000,
+            BlockNumber::from(1_000_000_000u32)
+        ));
+        assert_ok!(Crowdloans::open(
+            frame_system::RawOrigin::Root.into(),
+            crowdloan,
+        ));
+
+        assert_noop!(
+            Crowdloans::contribute(RuntimeOrigin::signed(ALICE), crowdloan, 1_000, vec![56]),
+            Error::<Test>::UnknownReferralCode
+        );
+        // contributions without a referral code don't need one registered
+        assert_ok!(Crowdloans::contribute(
+            RuntimeOrigin::signed(ALICE),
+            crowdloan,
+            1_000,
+            vec![],
+        ));
+    });
+}
+
 #[test]
 fn contribute_should_fail_insufficient_funds() {
     new_test_ext().execute_with(|| {
@@ -573,7 +616,7 @@ fn auction_failed_should_work() {
             crowdloan,                            // crowdloan
         ));
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -633,7 +676,7 @@ fn claim_failed_should_work() {
 
         assert_eq!(Assets::balance(DOT, ALICE), dot(100f64) - amount);
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -652,7 +695,7 @@ fn claim_failed_should_work() {
             crowdloan,                            // crowdloan
         ));
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             1,
             Response::ExecutionResult(None),
@@ -723,7 +766,7 @@ fn claim_succeed_and_expired_should_work() {
             Vec::new()
         ));
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -766,7 +809,7 @@ fn claim_succeed_and_expired_should_work() {
             crowdloan,                            // crowdloan
         ));
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             1,
             Response::ExecutionResult(None),
@@ -836,7 +879,7 @@ fn slot_expired_should_work() {
             crowdloan,                            // crowdloan
         ));
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -1220,7 +1263,7 @@ fn refund_should_work_when_vault_phase_is_closed() {
         let vault = Crowdloans::vaults((&crowdloan, &LEASE_START, &LEASE_END)).unwrap();
         assert_eq!(Crowdloans::total_contribution(&vault).unwrap(), amount);
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -1290,7 +1333,7 @@ fn claim_for_should_work() {
             Vec::new()
         ));
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -1329,6 +1372,172 @@ fn claim_for_should_work() {
     });
 }
 
+#[test]
+fn claimable_contributions_should_work() {
+    new_test_ext().execute_with(|| {
+        let crowdloan = ParaId::from(1337u32);
+        let ctoken = 10u32;
+        let amount = 1_000u128;
+        let cap = 1_000_000_000_000;
+        let end_block = BlockNumber::from(1_000_000_000u32);
+        let contribution_strategy = ContributionStrategy::XCM;
+
+        // create the ctoken asset
+        assert_ok!(Assets::force_create(
+            RawOrigin::Root.into(),
+            ctoken.unique_saturated_into(),
+            Id(Crowdloans::account_id()),
+            true,
+            One::one(),
+        ));
+
+        // create a vault to contribute to
+        assert_ok!(Crowdloans::create_vault(
+            frame_system::RawOrigin::Root.into(), // origin
+            crowdloan,                            // crowdloan
+            ctoken,                               // ctoken
+            LEASE_START,                          // lease_start
+            LEASE_END,                            // lease_end
+            contribution_strategy,                // contribution_strategy
+            cap,                                  // cap
+            end_block                             // end_block
+        ));
+
+        // do open
+        assert_ok!(Crowdloans::open(
+            frame_system::RawOrigin::Root.into(), // origin
+            crowdloan,                            // crowdloan
+        ));
+
+        // do contribute
+        assert_ok!(Crowdloans::contribute(
+            RuntimeOrigin::signed(ALICE), // origin
+            crowdloan,                    // crowdloan
+            amount,                       // amount
+            Vec::new()
+        ));
+
+        XcmHelper::on_response(
+            pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
+            0,
+            Response::ExecutionResult(None),
+        )
+        .unwrap();
+
+        // do close
+        assert_ok!(Crowdloans::close(
+            frame_system::RawOrigin::Root.into(), // origin
+            crowdloan,                            // crowdloan
+        ));
+
+        //////////////////////////////////
+        // set to succeed
+        assert_ok!(Crowdloans::auction_succeeded(
+            frame_system::RawOrigin::Root.into(), // origin
+            crowdloan,                            // crowdloan
+        ));
+
+        assert_eq!(
+            Crowdloans::claimable_contributions(&ALICE),
+            vec![(crowdloan, (LEASE_START, LEASE_END), ctoken, amount)]
+        );
+        assert_eq!(Crowdloans::claimable_contributions(&BOB), vec![]);
+
+        assert_ok!(Crowdloans::claim(
+            RuntimeOrigin::signed(ALICE), // origin
+            crowdloan,                    // crowdloan
+            LEASE_START,                  // lease_start
+            LEASE_END,                    // lease_end
+        ));
+        assert_eq!(Crowdloans::claimable_contributions(&ALICE), vec![]);
+    });
+}
+
+#[test]
+fn account_positions_should_work() {
+    new_test_ext().execute_with(|| {
+        let crowdloan = ParaId::from(1337u32);
+        let ctoken = 10u32;
+        let amount = 1_000u128;
+        let cap = 1_000_000_000_000;
+        let end_block = BlockNumber::from(1_000_000_000u32);
+        let contribution_strategy = ContributionStrategy::XCM;
+
+        // create the ctoken asset
+        assert_ok!(Assets::force_create(
+            RawOrigin::Root.into(),
+            ctoken.unique_saturated_into(),
+            Id(Crowdloans::account_id()),
+            true,
+            One::one(),
+        ));
+
+        // create a vault to contribute to
+        assert_ok!(Crowdloans::create_vault(
+            frame_system::RawOrigin::Root.into(), // origin
+            crowdloan,                            // crowdloan
+            ctoken,                               // ctoken
+            LEASE_START,                          // lease_start
+            LEASE_END,                            // lease_end
+            contribution_strategy,                // contribution_strategy
+            cap,                                  // cap
+            end_block                             // end_block
+        ));
+
+        // do open
+        assert_ok!(Crowdloans::open(
+            frame_system::RawOrigin::Root.into(), // origin
+            crowdloan,                            // crowdloan
+        ));
+
+        // do contribute
+        assert_ok!(Crowdloans::contribute(
+            RuntimeOrigin::signed(ALICE), // origin
+            crowdloan,                    // crowdloan
+            amount,                       // amount
+            Vec::new()
+        ));
+
+        XcmHelper::on_response(
+            pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
+            0,
+            Response::ExecutionResult(None),
+        )
+        .unwrap();
+
+        // do close
+        assert_ok!(Crowdloans::close(
+            frame_system::RawOrigin::Root.into(), // origin
+            crowdloan,                            // crowdloan
+        ));
+
+        //////////////////////////////////
+        // set to succeed
+        assert_ok!(Crowdloans::auction_succeeded(
+            frame_system::RawOrigin::Root.into(), // origin
+            crowdloan,                            // crowdloan
+        ));
+
+        let vault_id = (LEASE_START, LEASE_END);
+        assert_eq!(
+            Crowdloans::account_positions(&ALICE),
+            vec![(crowdloan, vault_id, ctoken, amount, 0)]
+        );
+        assert_eq!(Crowdloans::account_positions(&BOB), vec![]);
+
+        assert_ok!(Crowdloans::claim(
+            RuntimeOrigin::signed(ALICE), // origin
+            crowdloan,                    // crowdloan
+            LEASE_START,                  // lease_start
+            LEASE_END,                    // lease_end
+        ));
+        assert_eq!(
+            Crowdloans::account_positions(&ALICE),
+            vec![(crowdloan, vault_id, ctoken, 0, amount)]
+        );
+    });
+}
+
 #[test]
 fn withdraw_for_should_work() {
     new_test_ext().execute_with(|| {
@@ -1376,7 +1585,7 @@ fn withdraw_for_should_work() {
 
         assert_eq!(Assets::balance(DOT, ALICE), dot(100f64) - amount);
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -1396,7 +1605,7 @@ fn withdraw_for_should_work() {
             crowdloan,                            // crowdloan
         ));
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             1,
             Response::ExecutionResult(None),
@@ -1534,6 +1743,125 @@ fn get_ctoken_exchange_rate_should_work() {
     });
 }
 
+#[test]
+fn ctoken_collateral_factor_grows_with_the_lease() {
+    new_test_ext().execute_with(|| {
+        let crowdloan = ParaId::from(1337u32);
+        let ctoken = 10;
+
+        assert_ok!(Assets::force_create(
+            RawOrigin::Root.into(),
+            ctoken.unique_saturated_into(),
+            Id(Crowdloans::account_id()),
+            true,
+            One::one(),
+        ));
+        assert!(!CTokenCollateral::is_supported(ctoken));
+
+        //lease from 6 to 13
+        let start_lease = 6;
+        let end_lease = 13;
+        assert_ok!(Crowdloans::create_vault(
+            RuntimeOrigin::signed(ALICE),
+            crowdloan,
+            ctoken,
+            start_lease,
+            end_lease,
+            ContributionStrategy::XCM,
+            1_000_000_000_000,
+            BlockNumber::from(1_000_000_000u32),
+        ));
+        assert!(CTokenCollateral::is_supported(ctoken));
+        let registered = Ratio::from_percent(60);
+
+        // the relaychain block isn't known yet
+        sp_io::storage::set(&RELAY_BLOCK_KEY, &(0_u32).encode());
+        assert_eq!(
+            CTokenCollateral::collateral_factor(ctoken, registered),
+            Ratio::from_percent(30)
+        );
+
+        // the lease hasn't started
+        sp_io::storage::set(
+            &RELAY_BLOCK_KEY,
+            &(start_lease * LeasePeriod::get()).encode(),
+        );
+        assert_eq!(
+            CTokenCollateral::collateral_factor(ctoken, registered),
+            Ratio::from_percent(30)
+        );
+
+        // a quarter of the lease elapsed
+        sp_io::storage::set(
+            &RELAY_BLOCK_KEY,
+            &((start_lease + 2) * LeasePeriod::get() + LeaseOffset::get()).encode(),
+        );
+        assert_eq!(
+            CTokenCollateral::factor_scale(ctoken),
+            Ratio::from_rational(625u32, 1000u32)
+        );
+        assert_eq!(
+            CTokenCollateral::collateral_factor(ctoken, registered),
+            Ratio::from_rational(375u32, 1000u32)
+        );
+
+        // the lease ended
+        sp_io::storage::set(
+            &RELAY_BLOCK_KEY,
+            &((end_lease + 1) * LeasePeriod::get() + LeaseOffset::get()).encode(),
+        );
+        assert_eq!(
+            CTokenCollateral::collateral_factor(ctoken, registered),
+            registered
+        );
+    });
+}
+
+#[test]
+fn ctoken_collateral_is_kept_in_the_collateral_account() {
+    new_test_ext().execute_with(|| {
+        let crowdloan = ParaId::from(1337u32);
+        let ctoken = 10;
+
+        assert_ok!(Assets::force_create(
+            RawOrigin::Root.into(),
+            ctoken.unique_saturated_into(),
+            Id(Crowdloans::account_id()),
+            true,
+            One::one(),
+        ));
+        assert_ok!(Crowdloans::create_vault(
+            RuntimeOrigin::signed(ALICE),
+            crowdloan,
+            ctoken,
+            LEASE_START,
+            LEASE_END,
+            ContributionStrategy::XCM,
+            1_000_000_000_000,
+            BlockNumber::from(1_000_000_000u32),
+        ));
+        assert_ok!(Assets::mint(
+            RuntimeOrigin::signed(Crowdloans::account_id()),
+            ctoken,
+            Id(ALICE),
+            1_000
+        ));
+        let collateral_account = Crowdloans::collateral_account_id();
+
+        assert_ok!(CTokenCollateral::on_deposit(&ALICE, ctoken, 100));
+        assert_eq!(Assets::balance(ctoken, &ALICE), 900);
+        assert_eq!(Assets::balance(ctoken, &collateral_account), 100);
+
+        assert_ok!(CTokenCollateral::seize(&ALICE, &BOB, ctoken, 40));
+        assert_eq!(Assets::balance(ctoken, &BOB), 40);
+
+        assert_ok!(CTokenCollateral::on_withdraw(&ALICE, ctoken, 60));
+        assert_eq!(Assets::balance(ctoken, &ALICE), 960);
+        assert_eq!(Assets::balance(ctoken, &collateral_account), 0);
+        assert_eq!(CTokenCollateral::price(ctoken), Ok(Price::one()));
+    });
+}
+
 #[test]
 fn get_ctoken_exchange_rate_with_partial_lease_should_work() {
     new_test_ext().execute_with(|| {
@@ -1726,7 +2054,7 @@ fn refund_should_work_for_single_user() {
 
         assert_eq!(Assets::balance(DOT, ALICE), dot(100f64) - amount);
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -1832,7 +2160,7 @@ fn xcm_proxy_contribute_should_work() {
         let vault = Crowdloans::vaults((&crowdloan, &LEASE_START, &LEASE_END)).unwrap();
         assert_eq!(vault.phase, VaultPhase::Contributing);
 
-        Crowdloans::notification_received(
+        XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -1911,3 +2239,141 @@ fn normalized_amount_should_work() {
         assert_eq!(normalized_amount, amount * 100);
     })
 }
+
+#[test]
+fn vault_snapshot_should_be_reloaded() {
+    let crowdloan = ParaId::from(1337u32);
+    let ctoken = 10;
+    let amount = dot(5f64);
+    let mut ext = new_test_ext();
+    ext.execute_with(|| {
+        assert_ok!(Assets::force_create(
+            RawOrigin::Root.into(),
+            ctoken.unique_saturated_into(),
+            Id(Crowdloans::account_id()),
+            true,
+            One::one(),
+        ));
+        assert_ok!(Crowdloans::create_vault(
+            frame_system::RawOrigin::Root.into(),
+            crowdloan,
+            ctoken,
+            LEASE_START,
+            LEASE_END,
+            ContributionStrategy::XCM,
+            1_000_000_000_000,
+            BlockNumber::from(1_000_000_000u32),
+        ));
+        assert_ok!(Crowdloans::contribute(
+            RawOrigin::Signed(ALICE).into(),
+            crowdloan,
+            amount,
+            vec![12, 34],
+        ));
+    });
+
+    let path = std::env::temp_dir().join(format!("crowdloans-vault-{}", std::process::id()));
+    parallel_support::snapshot::export(&mut ext, &path).unwrap();
+
+    parallel_support::snapshot::import(&path)
+        .unwrap()
+        .execute_with(|| {
+            let vault = Crowdloans::current_vault(crowdloan).unwrap();
+            assert_eq!(vault.pending, amount);
+            // contributions live in the child trie of the vault
+            assert_eq!(
+                Crowdloans::contribution_get(vault.trie_index, &ALICE, ChildStorageKind::Pending),
+                (amount, vec![12, 34])
+            );
+        });
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn vault_statuses_should_work() {
+    new_test_ext().execute_with(|| {
+        let crowdloan = ParaId::from(1337u32);
+        let ctoken = 10u32;
+        let amount = 1_000u128;
+        let cap = 1_000_000_000_000;
+        let end_block = BlockNumber::from(1_000_000_000u32);
+
+        assert_ok!(Assets::force_create(
+            RawOrigin::Root.into(),
+            ctoken.unique_saturated_into(),
+            Id(Crowdloans::account_id()),
+            true,
+            One::one(),
+        ));
+        assert_ok!(Crowdloans::create_vault(
+            frame_system::RawOrigin::Root.into(),
+            crowdloan,
+            ctoken,
+            LEASE_START,
+            LEASE_END,
+            ContributionStrategy::XCM,
+            cap,
+            end_block
+        ));
+        assert_ok!(Crowdloans::open(
+            frame_system::RawOrigin::Root.into(),
+            crowdloan,
+        ));
+        assert_ok!(Crowdloans::contribute(
+            RuntimeOrigin::signed(ALICE),
+            crowdloan,
+            amount,
+            Vec::new()
+        ));
+        XcmHelper::on_response(
+            pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
+            0,
+            Response::ExecutionResult(None),
+        )
+        .unwrap();
+        assert_ok!(Crowdloans::close(
+            frame_system::RawOrigin::Root.into(),
+            crowdloan,
+        ));
+        assert_ok!(Crowdloans::auction_succeeded(
+            frame_system::RawOrigin::Root.into(),
+            crowdloan,
+        ));
+
+        // the lease ends after the 8 lease periods of the vault
+        let lease_end_block = 8 * LeasePeriod::get() + LeaseOffset::get();
+        let mut status = VaultStatus {
+            crowdloan,
+            vault_id: (LEASE_START, LEASE_END),
+            ctoken,
+            phase: VaultPhase::Succeeded,
+            total_contributed: amount,
+            ctoken_supply: 0,
+            lease_end_block,
+            lease_end_time: None,
+            contribution: amount,
+            claimable: true,
+        };
+        // relaychain block isn't known yet
+        sp_io::storage::set(&RELAY_BLOCK_KEY, &(0_u32).encode());
+        assert_eq!(Crowdloans::vault_statuses(&ALICE), Ok(vec![status.clone()]));
+
+        // 1_000 blocks before the end, `LeasePerYear` lease periods last a year
+        sp_io::storage::set(&RELAY_BLOCK_KEY, &(lease_end_block - 1_000).encode());
+        TimestampPallet::set_timestamp(1_000_000);
+        status.lease_end_time = Some(1_000 + 6_517);
+        assert_eq!(Crowdloans::vault_statuses(&ALICE), Ok(vec![status.clone()]));
+
+        assert_ok!(Crowdloans::claim(
+            RuntimeOrigin::signed(ALICE),
+            crowdloan,
+            LEASE_START,
+            LEASE_END,
+        ));
+        status.ctoken_supply = amount;
+        status.contribution = 0;
+        status.claimable = false;
+        assert_eq!(Crowdloans::vault_statuses(&ALICE), Ok(vec![status.clone()]));
+        assert!(!Crowdloans::vault_statuses(&BOB).unwrap()[0].claimable);
+    });
+}
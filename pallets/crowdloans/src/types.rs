@@ -21,10 +21,13 @@ use codec::{Decode, Encode};
 use frame_system::pallet_prelude::BlockNumberFor;
 use primitives::{LeasePeriod, ParaId, Timestamp, TrieIndex, VaultId};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{traits::Zero, RuntimeDebug};
 use sp_std::vec::Vec;
 
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum VaultPhase {
     /// Vault is open for contributions but wont execute contribute call on relaychain
     Pending = 0,
@@ -170,3 +173,26 @@ impl<Balance> BonusConfig<Balance> {
         self.end_time > self.start_time
     }
 }
+
+/// Funds and maturity of a vault, along with the contribution of an account to it
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct VaultStatus<CurrencyId, Balance, BlockNumber> {
+    pub crowdloan: ParaId,
+    pub vault_id: VaultId,
+    pub ctoken: CurrencyId,
+    pub phase: VaultPhase,
+    /// Contributed on the relaychain, in flight or pending
+    pub total_contributed: Balance,
+    pub ctoken_supply: Balance,
+    /// Relaychain block the lease ends at
+    pub lease_end_block: BlockNumber,
+    /// Estimated unix time in seconds the lease ends at, `None` until the relaychain
+    /// block is known
+    pub lease_end_time: Option<Timestamp>,
+    /// Contribution of the account not claimed yet
+    pub contribution: Balance,
+    /// Whether the account can claim the ctokens of its contribution
+    pub claimable: bool,
+}
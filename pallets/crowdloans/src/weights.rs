@@ -43,6 +43,10 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions needed for pallet_crowdloans.
 pub trait WeightInfo {
 	fn create_vault() -> Weight;
@@ -79,6 +83,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn create_vault() -> Weight {
 		Weight::from_ref_time(80_394_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(6 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -88,6 +93,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn update_vault() -> Weight {
 		Weight::from_ref_time(65_113_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -112,6 +118,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn contribute() -> Weight {
 		Weight::from_ref_time(278_975_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(19 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(19 as u64))
 			.saturating_add(T::DbWeight::get().writes(13 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -120,6 +127,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn open() -> Weight {
 		Weight::from_ref_time(60_974_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -128,6 +136,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn close() -> Weight {
 		Weight::from_ref_time(60_720_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -135,6 +144,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn set_vrf() -> Weight {
 		Weight::from_ref_time(31_127_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -143,6 +153,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn reopen() -> Weight {
 		Weight::from_ref_time(62_489_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -151,6 +162,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn auction_succeeded() -> Weight {
 		Weight::from_ref_time(63_164_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -171,6 +183,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn auction_failed() -> Weight {
 		Weight::from_ref_time(193_909_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -182,6 +195,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn claim() -> Weight {
 		Weight::from_ref_time(125_035_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(6 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -192,6 +206,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn withdraw() -> Weight {
 		Weight::from_ref_time(114_845_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -202,6 +217,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn redeem() -> Weight {
 		Weight::from_ref_time(160_437_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -222,6 +238,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn slot_expired() -> Weight {
 		Weight::from_ref_time(190_108_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -245,6 +262,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn migrate_pending() -> Weight {
 		Weight::from_ref_time(303_337_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(17 as u64))
 			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -257,6 +275,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn notification_received() -> Weight {
 		Weight::from_ref_time(169_282_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -265,6 +284,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn refund() -> Weight {
 		Weight::from_ref_time(148_788_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -274,6 +294,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn dissolve_vault() -> Weight {
 		Weight::from_ref_time(153_319_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(6 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -285,17 +306,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn refund_for() -> Weight {
 		Weight::from_ref_time(169_339_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	fn update_proxy() -> Weight {
 		Weight::from_ref_time(31_127_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 
 	fn update_leases_bonus() -> Weight {
 		Weight::from_ref_time(31_127_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 }
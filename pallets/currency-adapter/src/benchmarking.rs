@@ -0,0 +1,45 @@
+//! Currency adapter pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{
+    assert_ok,
+    dispatch::UnfilteredDispatchable,
+    traits::{EnsureOrigin, LockableCurrency},
+};
+
+const LOCK_AMOUNT: Balance = 1_000_000_000_000;
+
+fn funded_account<T: Config>() -> T::AccountId {
+    let who: T::AccountId = account("locked", 0, 0);
+    assert_ok!(T::Balances::mint_into(&who, LOCK_AMOUNT * 2));
+    who
+}
+
+benchmarks! {
+    force_set_lock {
+        let who = funded_account::<T>();
+        let origin = T::LockOrigin::successful_origin();
+        let call = Call::<T>::force_set_lock {
+            asset: T::GetNativeCurrencyId::get(),
+            who,
+            amount: LOCK_AMOUNT,
+        };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+
+    force_remove_lock {
+        let who = funded_account::<T>();
+        T::Balances::set_lock(CURRENCY_ADAPTER_ID, &who, LOCK_AMOUNT, WithdrawReasons::all());
+        let origin = T::LockOrigin::successful_origin();
+        let call = Call::<T>::force_remove_lock {
+            asset: T::GetNativeCurrencyId::get(),
+            who,
+        };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+}
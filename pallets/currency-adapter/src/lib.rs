@@ -20,7 +20,13 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 use frame_support::{
     dispatch::DispatchResult,
@@ -66,6 +72,9 @@ pub mod pallet {
 
         // Origin which can lock asset balance
         type LockOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Weight information for the extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -79,7 +88,7 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::force_set_lock())]
         pub fn force_set_lock(
             origin: OriginFor<T>,
             asset: AssetIdOf<T>,
@@ -95,7 +104,7 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::force_remove_lock())]
         pub fn force_remove_lock(
             origin: OriginFor<T>,
             asset: AssetIdOf<T>,
@@ -0,0 +1,167 @@
+//! Emergency shutdown pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as EmergencyShutdown;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{assert_ok, dispatch::UnfilteredDispatchable, traits::EnsureOrigin};
+use frame_system::RawOrigin as SystemOrigin;
+use sp_std::prelude::*;
+
+const PALLET_IDX: u8 = 10;
+const MAX_CALLS: u32 = 50;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn calls(n: u32) -> Vec<(u8, u8)> {
+    (0..n).map(|i| (PALLET_IDX, i as u8)).collect()
+}
+
+benchmarks! {
+    toggle_pallet {
+        let origin = T::ShutdownOrigin::successful_origin();
+        let call = Call::<T>::toggle_pallet { pallet_idx: PALLET_IDX };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert!(DisabledPallets::<T>::get(PALLET_IDX));
+    }
+
+    toggle_call {
+        let origin = T::ShutdownOrigin::successful_origin();
+        let call = Call::<T>::toggle_call { pallet_idx: PALLET_IDX, call_idx: 0 };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert!(DisabledCalls::<T>::get(PALLET_IDX, 0));
+    }
+
+    pause_calls {
+        let n in 1 .. MAX_CALLS;
+        let origin = T::ShutdownOrigin::successful_origin();
+        let call = Call::<T>::pause_calls { calls: calls(n) };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::CallsPaused(calls(n)).into());
+    }
+
+    unpause_calls {
+        let n in 1 .. MAX_CALLS;
+        for (pallet_idx, call_idx) in calls(n) {
+            DisabledCalls::<T>::insert(pallet_idx, call_idx, true);
+        }
+        let origin = T::ShutdownOrigin::successful_origin();
+        let call = Call::<T>::unpause_calls { calls: calls(n) };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert!(!DisabledCalls::<T>::contains_key(PALLET_IDX, 0));
+    }
+
+    add_bypass {
+        let who: T::AccountId = account("keeper", 0, 0);
+        let origin = T::ShutdownOrigin::successful_origin();
+        let call = Call::<T>::add_bypass { pallet_idx: PALLET_IDX, who: who.clone() };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert!(BypassAccounts::<T>::contains_key(PALLET_IDX, who));
+    }
+
+    remove_bypass {
+        let who: T::AccountId = account("keeper", 0, 0);
+        BypassAccounts::<T>::insert(PALLET_IDX, &who, ());
+        let origin = T::ShutdownOrigin::successful_origin();
+        let call = Call::<T>::remove_bypass { pallet_idx: PALLET_IDX, who: who.clone() };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert!(!BypassAccounts::<T>::contains_key(PALLET_IDX, who));
+    }
+
+    // Overhead of the bypass only, the weight of the inner call is added on top of it
+    dispatch_bypass {
+        let caller: T::AccountId = whitelisted_caller();
+        let call: <T as Config>::RuntimeCall =
+            frame_system::Call::<T>::remark { remark: vec![] }.into();
+        let (pallet_idx, _) = EmergencyShutdown::<T>::call_index(&call);
+        DisabledPallets::<T>::insert(pallet_idx, true);
+        BypassAccounts::<T>::insert(pallet_idx, &caller, ());
+    }: _(SystemOrigin::Signed(caller.clone()), Box::new(call))
+    verify {
+        assert_last_event::<T>(Event::<T>::BypassDispatched(caller, Ok(())).into());
+    }
+
+    set_breaker_calls {
+        let n in 1 .. MAX_CALLS;
+        let origin = T::ShutdownOrigin::successful_origin();
+        let call = Call::<T>::set_breaker_calls {
+            trigger: CircuitBreakerTrigger::OracleStale,
+            calls: calls(n),
+        };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert_eq!(BreakerCalls::<T>::get(CircuitBreakerTrigger::OracleStale), calls(n));
+    }
+
+    review_breaker {
+        let trigger = CircuitBreakerTrigger::OracleStale;
+        assert_ok!(EmergencyShutdown::<T>::set_breaker_calls(
+            T::ShutdownOrigin::successful_origin(),
+            trigger,
+            calls(MAX_CALLS),
+        ));
+        EmergencyShutdown::<T>::trip(trigger);
+        let origin = T::ShutdownOrigin::successful_origin();
+        let call = Call::<T>::review_breaker { trigger, resume: true };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert!(!TrippedBreakers::<T>::contains_key(trigger));
+        assert_last_event::<T>(Event::<T>::CircuitBreakerReviewed(trigger, true).into());
+    }
+
+    set_profile_rules {
+        let n in 1 .. MAX_CALLS;
+        let origin = T::ShutdownOrigin::successful_origin();
+        let rules = ProfileRules::Deny { pallets: vec![], calls: calls(n) };
+        let call = Call::<T>::set_profile_rules {
+            profile: FilterProfile::Conservative,
+            rules: Some(rules.clone()),
+        };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert_eq!(FilterProfiles::<T>::get(FilterProfile::Conservative), Some(rules));
+    }
+
+    switch_profile {
+        let origin = T::ShutdownOrigin::successful_origin();
+        let call = Call::<T>::switch_profile { profile: FilterProfile::Conservative };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert_eq!(ActiveProfile::<T>::get(), FilterProfile::Conservative);
+    }
+}
+
+impl_benchmark_test_suite!(
+    EmergencyShutdown,
+    crate::mock::new_test_ext(),
+    crate::mock::Test
+);
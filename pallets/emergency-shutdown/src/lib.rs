@@ -16,20 +16,88 @@
 //!
 //! ## Overview
 //! Emergency shutdown calls not in whitelist
+//!
+//! Whole pallets or single calls can be shut down. Accounts allowed by the shutdown origin
+//! can still dispatch the calls of a shut down pallet with `dispatch_bypass`, e.g. keepers
+//! liquidating positions while the rest of the pallet is paused.
+//!
+//! Other pallets can trip circuit breakers on anomalies such as stale oracle prices. A
+//! tripped breaker pauses the calls registered for its trigger until it expires or the
+//! shutdown origin reviews it.
+//!
+//! The calls let through can also be set per filter profile, e.g. an allowlist for the
+//! launch or rules disabling the EVM and the bridge during an incident. Governance
+//! switches between the profiles without a runtime upgrade.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod weights;
+
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 use codec::{Decode, Encode};
 use frame_support::traits::Contains;
 use frame_system::pallet_prelude::OriginFor;
-use pallet_traits::EmergencyCallFilter;
+use pallet_traits::{CircuitBreaker, CircuitBreakerTrigger, EmergencyCallFilter};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, RuntimeDebug};
+use sp_std::{boxed::Box, vec::Vec};
+
+/// Call filter profiles governance can switch between
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub enum FilterProfile {
+    /// Calls opened while the chain launches
+    Launch,
+    #[default]
+    Normal,
+    /// Calls left open during an incident
+    Conservative,
+}
+
+/// Calls a filter profile lets through, given as whole pallets or single
+/// (pallet_idx, call_idx) calls
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum ProfileRules {
+    /// Only the listed pallets and calls are let through
+    Allow {
+        pallets: Vec<u8>,
+        calls: Vec<(u8, u8)>,
+    },
+    /// All but the listed pallets and calls are let through
+    Deny {
+        pallets: Vec<u8>,
+        calls: Vec<(u8, u8)>,
+    },
+}
+
+impl ProfileRules {
+    /// Number of pallets and calls listed
+    pub fn entries(&self) -> u32 {
+        let (Self::Allow { pallets, calls } | Self::Deny { pallets, calls }) = self;
+        pallets.len().saturating_add(calls.len()) as u32
+    }
+
+    /// Whether the (pallet_idx, call_idx) call is let through
+    pub fn permits(&self, (pallet_idx, call_idx): (u8, u8)) -> bool {
+        match self {
+            Self::Allow { pallets, calls } => {
+                pallets.contains(&pallet_idx) || calls.contains(&(pallet_idx, call_idx))
+            }
+            Self::Deny { pallets, calls } => {
+                !pallets.contains(&pallet_idx) && !calls.contains(&(pallet_idx, call_idx))
+            }
+        }
+    }
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -38,6 +106,7 @@ pub mod pallet {
         dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
         pallet_prelude::*,
     };
+    use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor, RawOrigin};
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -54,6 +123,13 @@ pub mod pallet {
             + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
             + GetDispatchInfo
             + From<frame_system::Call<Self>>;
+
+        /// Number of blocks a tripped circuit breaker keeps its calls paused
+        #[pallet::constant]
+        type CircuitBreakerDuration: Get<Self::BlockNumber>;
+
+        /// Weight information for the extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::event]
@@ -65,6 +141,52 @@ pub mod pallet {
         /// Toggled Call
         /// [flag]
         ToggledCall(bool),
+        /// Calls were shut down
+        /// [calls]
+        CallsPaused(Vec<(u8, u8)>),
+        /// Calls were enabled again
+        /// [calls]
+        CallsUnpaused(Vec<(u8, u8)>),
+        /// Account can dispatch the calls of the pallet while it's shut down
+        /// [pallet_idx, who]
+        BypassAdded(u8, T::AccountId),
+        /// Account can't bypass the shutdown of the pallet anymore
+        /// [pallet_idx, who]
+        BypassRemoved(u8, T::AccountId),
+        /// Call was dispatched bypassing the shutdown
+        /// [who, result]
+        BypassDispatched(T::AccountId, DispatchResult),
+        /// Calls paused by the circuit breaker were set
+        /// [trigger, calls]
+        BreakerCallsUpdated(CircuitBreakerTrigger, Vec<(u8, u8)>),
+        /// Circuit breaker was tripped and paused its calls
+        /// [trigger, calls, expiry]
+        CircuitBreakerTripped(CircuitBreakerTrigger, Vec<(u8, u8)>, T::BlockNumber),
+        /// Circuit breaker expired and its calls are enabled again
+        /// [trigger]
+        CircuitBreakerExpired(CircuitBreakerTrigger),
+        /// Tripped circuit breaker must be reviewed by governance
+        /// [trigger]
+        ReviewRequired(CircuitBreakerTrigger),
+        /// Circuit breaker was reviewed, `resume` enables its calls again
+        /// [trigger, resume]
+        CircuitBreakerReviewed(CircuitBreakerTrigger, bool),
+        /// Calls let through by a filter profile were set, `None` lets all of them through
+        /// [profile, rules]
+        ProfileRulesUpdated(FilterProfile, Option<ProfileRules>),
+        /// Call filter switched to another profile
+        /// [profile]
+        ProfileSwitched(FilterProfile),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account isn't allowed to bypass the shutdown of the pallet
+        CannotBypass,
+        /// Calls of a tripped circuit breaker can't be changed
+        CircuitBreakerTripped,
+        /// Circuit breaker wasn't tripped since its last review
+        NoPendingReview,
     }
 
     #[pallet::pallet]
@@ -81,10 +203,79 @@ pub mod pallet {
     pub type DisabledCalls<T: Config> =
         StorageDoubleMap<_, Blake2_128Concat, u8, Blake2_128Concat, u8, bool, ValueQuery>;
 
+    /// Accounts which can dispatch the calls of a shut down pallet
+    /// PalletIdx -> AccountId -> ()
+    #[pallet::storage]
+    #[pallet::getter(fn bypass_accounts)]
+    pub type BypassAccounts<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u8, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Account dispatching a call through `dispatch_bypass`
+    #[pallet::storage]
+    pub(crate) type Bypassing<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+    /// Calls paused when the circuit breaker is tripped
+    /// CircuitBreakerTrigger -> Vec<(PalletIdx, CallIdx)>
+    #[pallet::storage]
+    #[pallet::getter(fn breaker_calls)]
+    pub type BreakerCalls<T: Config> =
+        StorageMap<_, Twox64Concat, CircuitBreakerTrigger, Vec<(u8, u8)>, ValueQuery>;
+
+    /// Tripped circuit breakers and the block they expire at
+    /// CircuitBreakerTrigger -> BlockNumber
+    #[pallet::storage]
+    #[pallet::getter(fn tripped_breakers)]
+    pub type TrippedBreakers<T: Config> =
+        StorageMap<_, Twox64Concat, CircuitBreakerTrigger, T::BlockNumber, OptionQuery>;
+
+    /// Circuit breakers tripped since they were last reviewed by governance
+    /// CircuitBreakerTrigger -> ()
+    #[pallet::storage]
+    #[pallet::getter(fn pending_reviews)]
+    pub type PendingReviews<T: Config> =
+        StorageMap<_, Twox64Concat, CircuitBreakerTrigger, (), OptionQuery>;
+
+    /// Calls let through by each filter profile, all of them if it has no rules
+    /// FilterProfile -> ProfileRules
+    #[pallet::storage]
+    #[pallet::getter(fn profile_rules)]
+    pub type FilterProfiles<T: Config> =
+        StorageMap<_, Twox64Concat, FilterProfile, ProfileRules, OptionQuery>;
+
+    /// Filter profile in use
+    #[pallet::storage]
+    #[pallet::getter(fn active_profile)]
+    pub type ActiveProfile<T: Config> = StorageValue<_, FilterProfile, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+            let expired: Vec<CircuitBreakerTrigger> = <TrippedBreakers<T>>::iter()
+                .filter_map(|(trigger, expiry)| {
+                    reads += 1;
+                    (expiry <= n).then_some(trigger)
+                })
+                .collect();
+            for trigger in expired {
+                <TrippedBreakers<T>>::remove(trigger);
+                writes += 1;
+                Self::deposit_event(Event::CircuitBreakerExpired(trigger));
+                // Expiry doesn't replace the review of the anomaly
+                reads += 1;
+                if <PendingReviews<T>>::contains_key(trigger) {
+                    Self::deposit_event(Event::ReviewRequired(trigger));
+                }
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Toggle the shutdown flag
-        #[pallet::weight(Weight::from_ref_time(10_000).saturating_add(T::DbWeight::get().writes(1)))]
+        #[pallet::weight(<T as Config>::WeightInfo::toggle_pallet())]
         pub fn toggle_pallet(origin: OriginFor<T>, pallet_idx: u8) -> DispatchResult {
             T::ShutdownOrigin::ensure_origin(origin)?;
 
@@ -96,7 +287,7 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::weight(Weight::from_ref_time(10_000).saturating_add(T::DbWeight::get().writes(1)))]
+        #[pallet::weight(<T as Config>::WeightInfo::toggle_call())]
         pub fn toggle_call(origin: OriginFor<T>, pallet_idx: u8, call_idx: u8) -> DispatchResult {
             T::ShutdownOrigin::ensure_origin(origin)?;
 
@@ -107,18 +298,207 @@ pub mod pallet {
             Self::deposit_event(Event::ToggledCall(updated_flag));
             Ok(())
         }
+
+        /// Shut down the given (pallet_idx, call_idx) calls
+        #[pallet::weight(<T as Config>::WeightInfo::pause_calls(calls.len() as u32))]
+        pub fn pause_calls(origin: OriginFor<T>, calls: Vec<(u8, u8)>) -> DispatchResult {
+            T::ShutdownOrigin::ensure_origin(origin)?;
+
+            for (pallet_idx, call_idx) in calls.iter() {
+                <DisabledCalls<T>>::insert(pallet_idx, call_idx, true);
+            }
+
+            Self::deposit_event(Event::CallsPaused(calls));
+            Ok(())
+        }
+
+        /// Enable the given (pallet_idx, call_idx) calls again
+        #[pallet::weight(<T as Config>::WeightInfo::unpause_calls(calls.len() as u32))]
+        pub fn unpause_calls(origin: OriginFor<T>, calls: Vec<(u8, u8)>) -> DispatchResult {
+            T::ShutdownOrigin::ensure_origin(origin)?;
+
+            for (pallet_idx, call_idx) in calls.iter() {
+                <DisabledCalls<T>>::remove(pallet_idx, call_idx);
+            }
+
+            Self::deposit_event(Event::CallsUnpaused(calls));
+            Ok(())
+        }
+
+        /// Allow `who` to dispatch the calls of the pallet while it's shut down
+        #[pallet::weight(<T as Config>::WeightInfo::add_bypass())]
+        pub fn add_bypass(
+            origin: OriginFor<T>,
+            pallet_idx: u8,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            T::ShutdownOrigin::ensure_origin(origin)?;
+
+            <BypassAccounts<T>>::insert(pallet_idx, &who, ());
+            Self::deposit_event(Event::BypassAdded(pallet_idx, who));
+            Ok(())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::remove_bypass())]
+        pub fn remove_bypass(
+            origin: OriginFor<T>,
+            pallet_idx: u8,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            T::ShutdownOrigin::ensure_origin(origin)?;
+
+            <BypassAccounts<T>>::remove(pallet_idx, &who);
+            Self::deposit_event(Event::BypassRemoved(pallet_idx, who));
+            Ok(())
+        }
+
+        /// Dispatch `call` even though its pallet or itself is shut down, the other call
+        /// filters still apply
+        #[pallet::weight({
+            let dispatch_info = call.get_dispatch_info();
+            (
+                dispatch_info
+                    .weight
+                    .saturating_add(<T as Config>::WeightInfo::dispatch_bypass()),
+                dispatch_info.class,
+            )
+        })]
+        pub fn dispatch_bypass(
+            origin: OriginFor<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let (pallet_idx, _) = Self::call_index(&call);
+            ensure!(
+                <BypassAccounts<T>>::contains_key(pallet_idx, &who),
+                Error::<T>::CannotBypass
+            );
+
+            <Bypassing<T>>::put(&who);
+            let result = (*call).dispatch(RawOrigin::Signed(who.clone()).into());
+            <Bypassing<T>>::kill();
+
+            Self::deposit_event(Event::BypassDispatched(
+                who,
+                result.map(|_| ()).map_err(|e| e.error),
+            ));
+            Ok(().into())
+        }
+
+        /// Set the calls paused when the circuit breaker of `trigger` is tripped
+        #[pallet::weight(<T as Config>::WeightInfo::set_breaker_calls(calls.len() as u32))]
+        pub fn set_breaker_calls(
+            origin: OriginFor<T>,
+            trigger: CircuitBreakerTrigger,
+            calls: Vec<(u8, u8)>,
+        ) -> DispatchResult {
+            T::ShutdownOrigin::ensure_origin(origin)?;
+            ensure!(
+                !<TrippedBreakers<T>>::contains_key(trigger),
+                Error::<T>::CircuitBreakerTripped
+            );
+
+            <BreakerCalls<T>>::insert(trigger, &calls);
+            Self::deposit_event(Event::BreakerCallsUpdated(trigger, calls));
+            Ok(())
+        }
+
+        /// Acknowledge a tripped circuit breaker, `resume` enables its calls before it
+        /// expires
+        #[pallet::weight(<T as Config>::WeightInfo::review_breaker())]
+        pub fn review_breaker(
+            origin: OriginFor<T>,
+            trigger: CircuitBreakerTrigger,
+            resume: bool,
+        ) -> DispatchResult {
+            T::ShutdownOrigin::ensure_origin(origin)?;
+            ensure!(
+                <PendingReviews<T>>::take(trigger).is_some(),
+                Error::<T>::NoPendingReview
+            );
+
+            if resume {
+                <TrippedBreakers<T>>::remove(trigger);
+            }
+            Self::deposit_event(Event::CircuitBreakerReviewed(trigger, resume));
+            Ok(())
+        }
+
+        /// Set the calls `profile` lets through, `None` lets all of them through
+        #[pallet::weight(<T as Config>::WeightInfo::set_profile_rules(
+            rules.as_ref().map_or(0, |rules| rules.entries())
+        ))]
+        pub fn set_profile_rules(
+            origin: OriginFor<T>,
+            profile: FilterProfile,
+            rules: Option<ProfileRules>,
+        ) -> DispatchResult {
+            T::ShutdownOrigin::ensure_origin(origin)?;
+
+            <FilterProfiles<T>>::set(profile, rules.clone());
+            Self::deposit_event(Event::ProfileRulesUpdated(profile, rules));
+            Ok(())
+        }
+
+        /// Filter the calls with the rules of `profile`
+        #[pallet::weight(<T as Config>::WeightInfo::switch_profile())]
+        pub fn switch_profile(origin: OriginFor<T>, profile: FilterProfile) -> DispatchResult {
+            T::ShutdownOrigin::ensure_origin(origin)?;
+
+            <ActiveProfile<T>>::put(profile);
+            Self::deposit_event(Event::ProfileSwitched(profile));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    fn call_index(call: &<T as Config>::RuntimeCall) -> (u8, u8) {
+        call.using_encoded(|mut bytes| Decode::decode(&mut bytes))
+            .expect("decode input is output of Call encode; Call guaranteed to have two enums; qed")
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    fn paused_by_breaker(call: (u8, u8)) -> bool {
+        <TrippedBreakers<T>>::iter_keys()
+            .any(|trigger| Self::breaker_calls(trigger).contains(&call))
+    }
+
+    fn permitted_by_profile(call: (u8, u8)) -> bool {
+        Self::profile_rules(Self::active_profile()).map_or(true, |rules| rules.permits(call))
+    }
+}
+
+impl<T: Config> CircuitBreaker for Pallet<T> {
+    fn trip(trigger: CircuitBreakerTrigger) {
+        let calls = Self::breaker_calls(trigger);
+        if calls.is_empty() {
+            return;
+        }
+
+        // Tripping again extends the pause of an already tripped breaker
+        let expiry = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::CircuitBreakerDuration::get());
+        <TrippedBreakers<T>>::insert(trigger, expiry);
+        <PendingReviews<T>>::insert(trigger, ());
+
+        Self::deposit_event(Event::CircuitBreakerTripped(trigger, calls, expiry));
+        Self::deposit_event(Event::ReviewRequired(trigger));
     }
 }
 
 impl<T: Config> EmergencyCallFilter<<T as Config>::RuntimeCall> for Pallet<T> {
     fn contains(call: &<T as Config>::RuntimeCall) -> bool {
-        let (pallet_idx, call_idx): (u8, u8) = call
-            .using_encoded(|mut bytes| Decode::decode(&mut bytes))
-            .expect(
-                "decode input is output of Call encode; Call guaranteed to have two enums; qed",
-            );
+        let (pallet_idx, call_idx) = Self::call_index(call);
 
         T::Whitelist::contains(call)
-            || !Self::disabled_pallets(pallet_idx) && !Self::disabled_calls(pallet_idx, call_idx)
+            || !Self::disabled_pallets(pallet_idx)
+                && !Self::disabled_calls(pallet_idx, call_idx)
+                && !Self::paused_by_breaker((pallet_idx, call_idx))
+                && Self::permitted_by_profile((pallet_idx, call_idx))
+            || <Bypassing<T>>::get().map_or(false, |who| {
+                <BypassAccounts<T>>::contains_key(pallet_idx, who)
+            })
     }
 }
@@ -69,17 +69,26 @@ impl frame_system::Config for Test {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+    pub const CircuitBreakerDuration: u64 = 10;
+}
+
 impl pallet_emergency_shutdown::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Whitelist = WhiteListFilter;
     type ShutdownOrigin = EnsureRoot<u64>;
     type RuntimeCall = RuntimeCall;
+    type CircuitBreakerDuration = CircuitBreakerDuration;
+    type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::default()
+    let t = frame_system::GenesisConfig::default()
         .build_storage::<Test>()
-        .unwrap()
-        .into()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
 }
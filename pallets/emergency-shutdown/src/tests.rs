@@ -1,6 +1,8 @@
 use crate::mock::*;
-use frame_support::traits::PalletInfoAccess;
+use crate::{CircuitBreaker, CircuitBreakerTrigger, FilterProfile, ProfileRules};
+use frame_support::traits::{Hooks, PalletInfoAccess};
 use frame_support::{assert_noop, assert_ok, dispatch::*};
+use sp_runtime::traits::{BlakeTwo256, Hash};
 
 #[test]
 fn toggle_call_works() {
@@ -98,3 +100,252 @@ fn call_filter_works() {
         assert_ok!(call.dispatch(RuntimeOrigin::signed(1)));
     });
 }
+
+#[test]
+fn pause_calls_works() {
+    new_test_ext().execute_with(|| {
+        let pallet_idx = System::index() as u8;
+        let calls = vec![(pallet_idx, 0), (pallet_idx, 1)];
+
+        assert_ok!(EmergencyShutdown::pause_calls(
+            RuntimeOrigin::root(),
+            calls.clone()
+        ));
+        assert!(EmergencyShutdown::disabled_calls(pallet_idx, 0));
+        assert!(EmergencyShutdown::disabled_calls(pallet_idx, 1));
+        assert!(!EmergencyShutdown::disabled_calls(pallet_idx, 2));
+
+        assert_ok!(EmergencyShutdown::unpause_calls(
+            RuntimeOrigin::root(),
+            vec![(pallet_idx, 0)]
+        ));
+        assert!(!EmergencyShutdown::disabled_calls(pallet_idx, 0));
+        assert!(EmergencyShutdown::disabled_calls(pallet_idx, 1));
+
+        assert_noop!(
+            EmergencyShutdown::pause_calls(RuntimeOrigin::signed(1), calls),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn bypass_accounts_can_dispatch_shut_down_calls() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let pallet_idx = System::index() as u8;
+        let remark = "test".as_bytes().to_vec();
+        let call = RuntimeCall::System(frame_system::Call::remark_with_event { remark });
+        assert_ok!(EmergencyShutdown::toggle_pallet(
+            RuntimeOrigin::root(),
+            pallet_idx
+        ));
+        assert_ok!(EmergencyShutdown::add_bypass(
+            RuntimeOrigin::root(),
+            pallet_idx,
+            1
+        ));
+
+        assert_noop!(
+            EmergencyShutdown::dispatch_bypass(RuntimeOrigin::signed(2), Box::new(call.clone())),
+            crate::Error::<Test>::CannotBypass
+        );
+        assert_ok!(EmergencyShutdown::dispatch_bypass(
+            RuntimeOrigin::signed(1),
+            Box::new(call.clone())
+        ));
+        System::assert_last_event(RuntimeEvent::EmergencyShutdown(
+            crate::Event::BypassDispatched(1, Ok(())),
+        ));
+        System::assert_has_event(RuntimeEvent::System(frame_system::Event::Remarked {
+            sender: 1,
+            hash: BlakeTwo256::hash(b"test"),
+        }));
+
+        // The call is still shut down when dispatched directly
+        assert_noop!(
+            call.clone().dispatch(RuntimeOrigin::signed(1)),
+            frame_system::Error::<Test>::CallFiltered,
+        );
+
+        assert_ok!(EmergencyShutdown::remove_bypass(
+            RuntimeOrigin::root(),
+            pallet_idx,
+            1
+        ));
+        assert_noop!(
+            EmergencyShutdown::dispatch_bypass(RuntimeOrigin::signed(1), Box::new(call)),
+            crate::Error::<Test>::CannotBypass
+        );
+    });
+}
+
+#[test]
+fn tripped_circuit_breaker_pauses_calls_until_expiry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let remark = "test".as_bytes().to_vec();
+        let call = RuntimeCall::System(frame_system::Call::remark { remark });
+        let call_idx: (u8, u8) = call
+            .using_encoded(|mut bytes| Decode::decode(&mut bytes))
+            .unwrap();
+        let trigger = CircuitBreakerTrigger::OracleStale;
+
+        // Nothing is paused by a breaker without calls
+        EmergencyShutdown::trip(trigger);
+        assert_eq!(EmergencyShutdown::tripped_breakers(trigger), None);
+
+        assert_ok!(EmergencyShutdown::set_breaker_calls(
+            RuntimeOrigin::root(),
+            trigger,
+            vec![call_idx]
+        ));
+        EmergencyShutdown::trip(trigger);
+        assert_eq!(EmergencyShutdown::tripped_breakers(trigger), Some(11));
+        System::assert_last_event(RuntimeEvent::EmergencyShutdown(
+            crate::Event::ReviewRequired(trigger),
+        ));
+        assert_noop!(
+            call.clone().dispatch(RuntimeOrigin::signed(1)),
+            frame_system::Error::<Test>::CallFiltered,
+        );
+        assert_noop!(
+            EmergencyShutdown::set_breaker_calls(RuntimeOrigin::root(), trigger, vec![]),
+            crate::Error::<Test>::CircuitBreakerTripped
+        );
+
+        System::set_block_number(11);
+        EmergencyShutdown::on_initialize(11);
+        assert_eq!(EmergencyShutdown::tripped_breakers(trigger), None);
+        System::assert_has_event(RuntimeEvent::EmergencyShutdown(
+            crate::Event::CircuitBreakerExpired(trigger),
+        ));
+        // The review is still required after the breaker expired
+        System::assert_last_event(RuntimeEvent::EmergencyShutdown(
+            crate::Event::ReviewRequired(trigger),
+        ));
+        assert_ok!(call.dispatch(RuntimeOrigin::signed(1)));
+    });
+}
+
+#[test]
+fn review_breaker_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let remark = "test".as_bytes().to_vec();
+        let call = RuntimeCall::System(frame_system::Call::remark { remark });
+        let call_idx: (u8, u8) = call
+            .using_encoded(|mut bytes| Decode::decode(&mut bytes))
+            .unwrap();
+        let trigger = CircuitBreakerTrigger::BridgeCapBreached;
+        assert_ok!(EmergencyShutdown::set_breaker_calls(
+            RuntimeOrigin::root(),
+            trigger,
+            vec![call_idx]
+        ));
+
+        assert_noop!(
+            EmergencyShutdown::review_breaker(RuntimeOrigin::root(), trigger, true),
+            crate::Error::<Test>::NoPendingReview
+        );
+        EmergencyShutdown::trip(trigger);
+
+        // Keep the calls paused until the breaker expires
+        assert_ok!(EmergencyShutdown::review_breaker(
+            RuntimeOrigin::root(),
+            trigger,
+            false
+        ));
+        assert_eq!(EmergencyShutdown::pending_reviews(trigger), None);
+        assert!(EmergencyShutdown::tripped_breakers(trigger).is_some());
+
+        EmergencyShutdown::trip(trigger);
+        assert_noop!(
+            EmergencyShutdown::review_breaker(RuntimeOrigin::signed(1), trigger, true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(EmergencyShutdown::review_breaker(
+            RuntimeOrigin::root(),
+            trigger,
+            true
+        ));
+        System::assert_last_event(RuntimeEvent::EmergencyShutdown(
+            crate::Event::CircuitBreakerReviewed(trigger, true),
+        ));
+        assert_eq!(EmergencyShutdown::tripped_breakers(trigger), None);
+        assert_ok!(call.dispatch(RuntimeOrigin::signed(1)));
+    });
+}
+
+#[test]
+fn filter_profiles_work() {
+    new_test_ext().execute_with(|| {
+        let remark = RuntimeCall::System(frame_system::Call::remark {
+            remark: "test".as_bytes().to_vec(),
+        });
+        let remark_with_event = RuntimeCall::System(frame_system::Call::remark_with_event {
+            remark: "test".as_bytes().to_vec(),
+        });
+        let remark_idx: (u8, u8) = remark
+            .using_encoded(|mut bytes| Decode::decode(&mut bytes))
+            .unwrap();
+        let pallet_idx = System::index() as u8;
+
+        assert_noop!(
+            EmergencyShutdown::switch_profile(RuntimeOrigin::signed(1), FilterProfile::Launch),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(EmergencyShutdown::set_profile_rules(
+            RuntimeOrigin::root(),
+            FilterProfile::Launch,
+            Some(ProfileRules::Allow {
+                pallets: vec![],
+                calls: vec![remark_idx],
+            })
+        ));
+        assert_ok!(EmergencyShutdown::set_profile_rules(
+            RuntimeOrigin::root(),
+            FilterProfile::Conservative,
+            Some(ProfileRules::Deny {
+                pallets: vec![pallet_idx],
+                calls: vec![],
+            })
+        ));
+
+        // The normal profile has no rules
+        assert_eq!(EmergencyShutdown::active_profile(), FilterProfile::Normal);
+        assert_ok!(remark.clone().dispatch(RuntimeOrigin::signed(1)));
+        assert_ok!(remark_with_event.clone().dispatch(RuntimeOrigin::signed(1)));
+
+        assert_ok!(EmergencyShutdown::switch_profile(
+            RuntimeOrigin::root(),
+            FilterProfile::Launch
+        ));
+        System::assert_last_event(RuntimeEvent::EmergencyShutdown(
+            crate::Event::ProfileSwitched(FilterProfile::Launch),
+        ));
+        assert_ok!(remark.clone().dispatch(RuntimeOrigin::signed(1)));
+        assert_noop!(
+            remark_with_event.clone().dispatch(RuntimeOrigin::signed(1)),
+            frame_system::Error::<Test>::CallFiltered,
+        );
+
+        assert_ok!(EmergencyShutdown::switch_profile(
+            RuntimeOrigin::root(),
+            FilterProfile::Conservative
+        ));
+        assert_noop!(
+            remark.clone().dispatch(RuntimeOrigin::signed(1)),
+            frame_system::Error::<Test>::CallFiltered,
+        );
+
+        // Removing the rules of the profile in use lets all calls through again
+        assert_ok!(EmergencyShutdown::set_profile_rules(
+            RuntimeOrigin::root(),
+            FilterProfile::Conservative,
+            None
+        ));
+        assert_ok!(remark.dispatch(RuntimeOrigin::signed(1)));
+        assert_ok!(remark_with_event.dispatch(RuntimeOrigin::signed(1)));
+    });
+}
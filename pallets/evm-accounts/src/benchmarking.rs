@@ -0,0 +1,49 @@
+//! EVM accounts pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as EVMAccounts;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin as SystemOrigin;
+use pallet_evm_signatures::ethereum::signable_message;
+use sp_core::crypto::KeyTypeId;
+use sp_io::hashing::keccak_256;
+
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"evma");
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Binding signature of a new key for `who`, and the address of the key
+fn sign_binding<T: Config>(who: &T::AccountId) -> (H160, EthereumSignature) {
+    let payload = EVMAccounts::<T>::binding_payload(who);
+    let public = sp_io::crypto::ecdsa_generate(KEY_TYPE, None);
+    let signature: EthereumSignature = sp_io::crypto::ecdsa_sign_prehashed(
+        KEY_TYPE,
+        &public,
+        &keccak_256(&signable_message(&payload)),
+    )
+    .expect("key was just generated")
+    .into();
+    let address = signature
+        .recover_address(&payload)
+        .expect("signature was just made");
+    (address, signature)
+}
+
+benchmarks! {
+    bind {
+        let caller: T::AccountId = whitelisted_caller();
+        let (address, signature) = sign_binding::<T>(&caller);
+        // Worst case, the derived account holds a balance to move
+        let derived = T::DerivedMapping::into_account_id(address);
+        T::Currency::make_free_balance_be(&derived, T::Currency::minimum_balance() * 10u32.into());
+    }: _(SystemOrigin::Signed(caller.clone()), address, signature)
+    verify {
+        assert_last_event::<T>(Event::<T>::AddressBound(caller, address).into());
+    }
+}
+
+impl_benchmark_test_suite!(EVMAccounts, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,190 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # EVM Accounts pallet
+//!
+//! ## Overview
+//!
+//! Lets users bind their substrate account to an EVM address, so both share the same balances.
+//! Unbound addresses keep the account derived from them by `DerivedMapping`.
+//!
+//! The binding is signed twice: the extrinsic by the substrate account, and the binding payload
+//! by the key of the address with `eth_sign`. The native balance of the derived account is moved
+//! to the bound account, whose nonce is raised to the one of the address.
+//!
+//! Bindings are permanent. The derived account is reaped when binding, unbinding would hand the
+//! address back to it with a reset nonce, letting its past transactions be replayed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Encode;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Currency, ExistenceRequirement},
+    transactional,
+};
+use frame_system::pallet_prelude::*;
+use pallet_evm::AddressMapping;
+use pallet_evm_signatures::ethereum::EthereumSignature;
+use sp_core::H160;
+use sp_runtime::traits::Zero;
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Prefix of the payload the EVM address signs to be bound to an account
+pub const BIND_PREFIX: &[u8] = b"parallel:bind-evm-address:";
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The native currency, moved out of the derived account when binding
+        type Currency: Currency<Self::AccountId>;
+
+        /// Mapping of the addresses which aren't bound to an account
+        type DerivedMapping: AddressMapping<Self::AccountId>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Account was bound to an EVM address
+        /// [account, address]
+        AddressBound(T::AccountId, H160),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account is already bound to an EVM address
+        AccountAlreadyBound,
+        /// EVM address is already bound to an account
+        AddressAlreadyBound,
+        /// Signature wasn't made by the key of the EVM address over the binding payload
+        InvalidSignature,
+        /// Account derived from the EVM address still holds more than native balance
+        DerivedAccountInUse,
+    }
+
+    /// Account bound to an EVM address
+    /// H160 -> AccountId
+    #[pallet::storage]
+    #[pallet::getter(fn account)]
+    pub type Accounts<T: Config> = StorageMap<_, Blake2_128Concat, H160, T::AccountId, OptionQuery>;
+
+    /// EVM address bound to an account
+    /// AccountId -> H160
+    #[pallet::storage]
+    #[pallet::getter(fn evm_address)]
+    pub type EvmAddresses<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, H160, OptionQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Bind the origin to the EVM `address`, which signed the payload of
+        /// `binding_payload` for the origin with `eth_sign`.
+        #[pallet::weight(<T as Config>::WeightInfo::bind())]
+        #[transactional]
+        pub fn bind(
+            origin: OriginFor<T>,
+            address: H160,
+            signature: EthereumSignature,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                !EvmAddresses::<T>::contains_key(&who),
+                Error::<T>::AccountAlreadyBound
+            );
+            ensure!(
+                !Accounts::<T>::contains_key(address),
+                Error::<T>::AddressAlreadyBound
+            );
+            ensure!(
+                signature.recover_address(&Self::binding_payload(&who)) == Some(address),
+                Error::<T>::InvalidSignature
+            );
+
+            let derived = T::DerivedMapping::into_account_id(address);
+            if derived != who {
+                Self::merge_derived_account(&derived, &who)?;
+            }
+
+            Accounts::<T>::insert(address, &who);
+            EvmAddresses::<T>::insert(&who, address);
+            Self::deposit_event(Event::<T>::AddressBound(who, address));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Payload the EVM address signs to be bound to `who`, the genesis hash keeps it from
+    /// being replayed on other chains
+    pub fn binding_payload(who: &T::AccountId) -> Vec<u8> {
+        let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+        let mut payload = BIND_PREFIX.to_vec();
+        payload.extend((genesis_hash, who).encode());
+        payload
+    }
+
+    /// Move the native balance and the nonce of the derived account to the bound one, the
+    /// derived account must be reaped so nothing is left behind
+    fn merge_derived_account(derived: &T::AccountId, who: &T::AccountId) -> DispatchResult {
+        let nonce = frame_system::Pallet::<T>::account_nonce(derived);
+        let balance = T::Currency::free_balance(derived);
+        if !balance.is_zero() {
+            T::Currency::transfer(derived, who, balance, ExistenceRequirement::AllowDeath)?;
+        }
+        ensure!(
+            !frame_system::Pallet::<T>::account_exists(derived),
+            Error::<T>::DerivedAccountInUse
+        );
+
+        // The address must not be able to reuse the nonces of its past transactions
+        frame_system::Account::<T>::mutate(who, |account| {
+            if account.nonce < nonce {
+                account.nonce = nonce;
+            }
+        });
+        Ok(())
+    }
+}
+
+/// Maps the bound addresses to their account, and the other ones with `DerivedMapping`
+impl<T: Config> AddressMapping<T::AccountId> for Pallet<T> {
+    fn into_account_id(address: H160) -> T::AccountId {
+        Accounts::<T>::get(address).unwrap_or_else(|| T::DerivedMapping::into_account_id(address))
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use sp_core::H256;
+use sp_keystore::{testing::KeyStore, KeystoreExt};
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    AccountId32,
+};
+use std::sync::Arc;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        EVMAccounts: crate::{Pallet, Storage, Call, Event<T>},
+    }
+);
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+
+/// Keys of the EVM addresses
+pub const ALICE_KEY: [u8; 32] = [0x11; 32];
+pub const BOB_KEY: [u8; 32] = [0x22; 32];
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type DerivedMapping = pallet_evm::HashedAddressMapping<BlakeTwo256>;
+    type WeightInfo = ();
+}
+
+/// EVM address of the key
+pub(crate) fn address_of_key(key: &[u8; 32]) -> H160 {
+    let secret = libsecp256k1::SecretKey::parse(key).expect("valid key");
+    let public = libsecp256k1::PublicKey::from_secret_key(&secret).serialize();
+    H160::from_slice(&sp_io::hashing::keccak_256(&public[1..])[12..])
+}
+
+/// `eth_sign` of `data` with the key
+pub(crate) fn eth_sign(key: &[u8; 32], data: &[u8]) -> EthereumSignature {
+    let message = pallet_evm_signatures::ethereum::signable_message(data);
+    let message = libsecp256k1::Message::parse(&sp_io::hashing::keccak_256(&message));
+    let secret = libsecp256k1::SecretKey::parse(key).expect("valid key");
+    let (signature, recovery_id) = libsecp256k1::sign(&message, &secret);
+    let mut out = [0u8; 65];
+    out[..64].copy_from_slice(&signature.serialize()[..]);
+    out[64] = recovery_id.serialize() + 27;
+    EthereumSignature(out)
+}
+
+pub(crate) fn derived_account(address: H160) -> AccountId {
+    <Test as Config>::DerivedMapping::into_account_id(address)
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ALICE, 1_000),
+            (BOB, 1_000),
+            (derived_account(address_of_key(&ALICE_KEY)), 500),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.register_extension(KeystoreExt(Arc::new(KeyStore::new())));
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
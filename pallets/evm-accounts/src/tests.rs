@@ -0,0 +1,139 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+
+use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency};
+
+fn bind(who: &AccountId, key: &[u8; 32]) -> DispatchResult {
+    let address = address_of_key(key);
+    let signature = eth_sign(key, &EVMAccounts::binding_payload(who));
+    EVMAccounts::bind(RuntimeOrigin::signed(who.clone()), address, signature)
+}
+
+#[test]
+fn bind_works() {
+    new_test_ext().execute_with(|| {
+        let address = address_of_key(&ALICE_KEY);
+        let derived = derived_account(address);
+        assert_eq!(EVMAccounts::into_account_id(address), derived);
+
+        assert_ok!(bind(&ALICE, &ALICE_KEY));
+
+        assert_eq!(EVMAccounts::account(address), Some(ALICE));
+        assert_eq!(EVMAccounts::evm_address(ALICE), Some(address));
+        assert_eq!(EVMAccounts::into_account_id(address), ALICE);
+        System::assert_last_event(RuntimeEvent::EVMAccounts(Event::AddressBound(
+            ALICE, address,
+        )));
+
+        // The balance of the derived account was swept into the bound one
+        assert_eq!(Balances::free_balance(ALICE), 1_500);
+        assert!(!System::account_exists(&derived));
+    });
+}
+
+#[test]
+fn bind_carries_the_nonce_over() {
+    new_test_ext().execute_with(|| {
+        let derived = derived_account(address_of_key(&ALICE_KEY));
+        for _ in 0..5 {
+            System::inc_account_nonce(&derived);
+        }
+        System::inc_account_nonce(&ALICE);
+
+        assert_ok!(bind(&ALICE, &ALICE_KEY));
+        assert_eq!(System::account_nonce(ALICE), 5);
+    });
+}
+
+#[test]
+fn bind_keeps_the_higher_nonce() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..3 {
+            System::inc_account_nonce(&ALICE);
+        }
+
+        assert_ok!(bind(&ALICE, &ALICE_KEY));
+        assert_eq!(System::account_nonce(ALICE), 3);
+    });
+}
+
+#[test]
+fn bind_with_an_empty_derived_account_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(bind(&BOB, &BOB_KEY));
+        assert_eq!(Balances::free_balance(BOB), 1_000);
+        assert_eq!(EVMAccounts::into_account_id(address_of_key(&BOB_KEY)), BOB);
+    });
+}
+
+#[test]
+fn bind_fails_with_the_signature_of_another_account() {
+    new_test_ext().execute_with(|| {
+        let address = address_of_key(&ALICE_KEY);
+        let signature = eth_sign(&ALICE_KEY, &EVMAccounts::binding_payload(&BOB));
+        assert_noop!(
+            EVMAccounts::bind(RuntimeOrigin::signed(ALICE), address, signature),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn bind_fails_with_the_signature_of_another_key() {
+    new_test_ext().execute_with(|| {
+        let address = address_of_key(&ALICE_KEY);
+        let signature = eth_sign(&BOB_KEY, &EVMAccounts::binding_payload(&ALICE));
+        assert_noop!(
+            EVMAccounts::bind(RuntimeOrigin::signed(ALICE), address, signature),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn bind_fails_if_already_bound() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(bind(&ALICE, &ALICE_KEY));
+
+        assert_noop!(bind(&ALICE, &BOB_KEY), Error::<Test>::AccountAlreadyBound);
+        assert_noop!(bind(&BOB, &ALICE_KEY), Error::<Test>::AddressAlreadyBound);
+    });
+}
+
+#[test]
+fn bind_fails_if_the_derived_account_is_in_use() {
+    new_test_ext().execute_with(|| {
+        let derived = derived_account(address_of_key(&ALICE_KEY));
+        assert_ok!(Balances::reserve(&derived, 100));
+
+        assert_noop!(bind(&ALICE, &ALICE_KEY), Error::<Test>::DerivedAccountInUse);
+    });
+}
+
+#[test]
+fn unbound_addresses_keep_the_derived_account() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(bind(&ALICE, &ALICE_KEY));
+
+        let address = address_of_key(&BOB_KEY);
+        assert_eq!(
+            EVMAccounts::into_account_id(address),
+            derived_account(address)
+        );
+        assert_eq!(EVMAccounts::evm_address(BOB), None);
+    });
+}
@@ -0,0 +1,38 @@
+//! EVM deployers pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as EVMDeployers;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::assert_ok;
+use frame_system::RawOrigin as SystemOrigin;
+
+const DEPLOYER: H160 = H160([100u8; 20]);
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    add_deployer {
+    }: _(SystemOrigin::Root, DEPLOYER)
+    verify {
+        assert_last_event::<T>(Event::<T>::DeployerAdded(DEPLOYER).into());
+    }
+
+    remove_deployer {
+        assert_ok!(EVMDeployers::<T>::add_deployer(SystemOrigin::Root.into(), DEPLOYER));
+    }: _(SystemOrigin::Root, DEPLOYER)
+    verify {
+        assert_last_event::<T>(Event::<T>::DeployerRemoved(DEPLOYER).into());
+    }
+
+    set_permissionless {
+    }: _(SystemOrigin::Root, true)
+    verify {
+        assert_last_event::<T>(Event::<T>::PermissionlessDeploymentSet(true).into());
+    }
+}
+
+impl_benchmark_test_suite!(EVMDeployers, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,192 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # EVM Deployers pallet
+//!
+//! ## Overview
+//!
+//! Gates the deployment of EVM contracts. Until governance switches on the permissionless
+//! mode, only the addresses in the allow-list can send contract creation transactions.
+//!
+//! The check is done by the runtime when validating ethereum transactions. Contracts
+//! created by other contracts are gated by [`DeployGatedRunner`], which makes any
+//! contract creation fail in executions whose source is not allowed to deploy.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use sp_core::H160;
+use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidityError};
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod runner;
+pub mod weights;
+
+pub use runner::DeployGatedRunner;
+pub use weights::WeightInfo;
+
+/// Custom `InvalidTransaction` code of contract creations by addresses not allowed to deploy
+pub const DEPLOYER_NOT_ALLOWED: u8 = 201;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The origin which can manage the allow-list and the permissionless mode
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Address was allowed to deploy contracts
+        /// [deployer]
+        DeployerAdded(H160),
+        /// Address isn't allowed to deploy contracts anymore
+        /// [deployer]
+        DeployerRemoved(H160),
+        /// Permissionless deployment was switched on or off
+        /// [enabled]
+        PermissionlessDeploymentSet(bool),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Address is already allowed to deploy contracts
+        AlreadyDeployer,
+        /// Address isn't allowed to deploy contracts
+        NotDeployer,
+    }
+
+    /// Addresses allowed to deploy contracts
+    /// H160 -> ()
+    #[pallet::storage]
+    #[pallet::getter(fn deployers)]
+    pub type Deployers<T: Config> = StorageMap<_, Twox64Concat, H160, (), OptionQuery>;
+
+    /// Whether everyone can deploy contracts
+    #[pallet::storage]
+    #[pallet::getter(fn permissionless)]
+    pub type PermissionlessDeployment<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(Default)]
+    pub struct GenesisConfig {
+        pub permissionless: bool,
+        pub deployers: Vec<H160>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig {
+        fn build(&self) {
+            PermissionlessDeployment::<T>::put(self.permissionless);
+            for deployer in self.deployers.iter() {
+                Deployers::<T>::insert(deployer, ());
+            }
+        }
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Allow `deployer` to deploy contracts
+        #[pallet::weight(<T as Config>::WeightInfo::add_deployer())]
+        #[transactional]
+        pub fn add_deployer(origin: OriginFor<T>, deployer: H160) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                !Deployers::<T>::contains_key(deployer),
+                Error::<T>::AlreadyDeployer
+            );
+
+            Deployers::<T>::insert(deployer, ());
+            Self::deposit_event(Event::<T>::DeployerAdded(deployer));
+            Ok(())
+        }
+
+        /// Remove `deployer` from the allow-list
+        #[pallet::weight(<T as Config>::WeightInfo::remove_deployer())]
+        #[transactional]
+        pub fn remove_deployer(origin: OriginFor<T>, deployer: H160) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                Deployers::<T>::contains_key(deployer),
+                Error::<T>::NotDeployer
+            );
+
+            Deployers::<T>::remove(deployer);
+            Self::deposit_event(Event::<T>::DeployerRemoved(deployer));
+            Ok(())
+        }
+
+        /// Let everyone deploy contracts, or only the allow-list
+        #[pallet::weight(<T as Config>::WeightInfo::set_permissionless())]
+        #[transactional]
+        pub fn set_permissionless(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            PermissionlessDeployment::<T>::put(enabled);
+            Self::deposit_event(Event::<T>::PermissionlessDeploymentSet(enabled));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Whether `source` can deploy contracts
+    pub fn can_deploy(source: &H160) -> bool {
+        Self::permissionless() || Deployers::<T>::contains_key(source)
+    }
+
+    /// Reject the contract creation of `source` if it's not allowed to deploy
+    pub fn ensure_can_deploy(source: &H160) -> Result<(), TransactionValidityError> {
+        if Self::can_deploy(source) {
+            Ok(())
+        } else {
+            Err(InvalidTransaction::Custom(DEPLOYER_NOT_ALLOWED).into())
+        }
+    }
+
+    /// EVM config of the executions of `source`, `None` if it's allowed to deploy and
+    /// `config` applies as is. Otherwise the contract size limit is set to zero so that no
+    /// contract can be created, the empty account a creation without code leaves is harmless.
+    pub fn gated_config(source: &H160, config: &evm::Config) -> Option<evm::Config> {
+        if Self::can_deploy(source) {
+            return None;
+        }
+        let mut gated = config.clone();
+        gated.create_contract_limit = Some(0);
+        Some(gated)
+    }
+}
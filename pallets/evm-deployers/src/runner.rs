@@ -0,0 +1,151 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pallet_evm::{CallInfo, CreateInfo, Runner, RunnerError};
+use sp_core::{H256, U256};
+
+/// EVM runner gating the contracts created during an execution, CREATE and CREATE2
+/// included, by the transaction source.
+///
+/// Executions of sources which are not allowed to deploy run with a contract size limit
+/// of zero, so any contract creation they lead to fails, however deep in the call stack.
+pub struct DeployGatedRunner<T, R>(PhantomData<(T, R)>);
+
+impl<T, R> Runner<T> for DeployGatedRunner<T, R>
+where
+    T: Config + pallet_evm::Config,
+    R: Runner<T>,
+{
+    type Error = R::Error;
+
+    fn validate(
+        source: H160,
+        target: Option<H160>,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        evm_config: &evm::Config,
+    ) -> Result<(), RunnerError<Self::Error>> {
+        R::validate(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            evm_config,
+        )
+    }
+
+    fn call(
+        source: H160,
+        target: H160,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        config: &evm::Config,
+    ) -> Result<CallInfo, RunnerError<Self::Error>> {
+        let gated = Pallet::<T>::gated_config(&source, config);
+        R::call(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            gated.as_ref().unwrap_or(config),
+        )
+    }
+
+    fn create(
+        source: H160,
+        init: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        config: &evm::Config,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        let gated = Pallet::<T>::gated_config(&source, config);
+        R::create(
+            source,
+            init,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            gated.as_ref().unwrap_or(config),
+        )
+    }
+
+    fn create2(
+        source: H160,
+        init: Vec<u8>,
+        salt: H256,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        config: &evm::Config,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        let gated = Pallet::<T>::gated_config(&source, config);
+        R::create2(
+            source,
+            init,
+            salt,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            gated.as_ref().unwrap_or(config),
+        )
+    }
+}
@@ -0,0 +1,85 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn genesis_deployers_should_be_allowed() {
+    new_test_ext().execute_with(|| {
+        assert!(!EVMDeployers::permissionless());
+        assert!(EVMDeployers::can_deploy(&DEPLOYER));
+        assert!(!EVMDeployers::can_deploy(&STRANGER));
+        assert_eq!(
+            EVMDeployers::ensure_can_deploy(&STRANGER),
+            Err(InvalidTransaction::Custom(DEPLOYER_NOT_ALLOWED).into())
+        );
+    });
+}
+
+#[test]
+fn add_and_remove_deployer_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            EVMDeployers::add_deployer(RuntimeOrigin::signed(ALICE), STRANGER),
+            BadOrigin
+        );
+        assert_noop!(
+            EVMDeployers::add_deployer(RuntimeOrigin::root(), DEPLOYER),
+            Error::<Test>::AlreadyDeployer
+        );
+        assert_ok!(EVMDeployers::add_deployer(RuntimeOrigin::root(), STRANGER));
+        assert!(EVMDeployers::can_deploy(&STRANGER));
+        System::assert_last_event(RuntimeEvent::EVMDeployers(Event::DeployerAdded(STRANGER)));
+
+        assert_ok!(EVMDeployers::remove_deployer(
+            RuntimeOrigin::root(),
+            STRANGER
+        ));
+        assert!(!EVMDeployers::can_deploy(&STRANGER));
+        assert_noop!(
+            EVMDeployers::remove_deployer(RuntimeOrigin::root(), STRANGER),
+            Error::<Test>::NotDeployer
+        );
+    });
+}
+
+#[test]
+fn permissionless_mode_should_allow_everyone() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            EVMDeployers::set_permissionless(RuntimeOrigin::signed(ALICE), true),
+            BadOrigin
+        );
+        assert_ok!(EVMDeployers::set_permissionless(
+            RuntimeOrigin::root(),
+            true
+        ));
+        assert!(EVMDeployers::can_deploy(&STRANGER));
+        assert_ok!(EVMDeployers::ensure_can_deploy(&STRANGER));
+
+        assert_ok!(EVMDeployers::set_permissionless(
+            RuntimeOrigin::root(),
+            false
+        ));
+        assert!(!EVMDeployers::can_deploy(&STRANGER));
+        assert!(EVMDeployers::can_deploy(&DEPLOYER));
+    });
+}
+
+#[test]
+fn nested_creations_should_be_gated_by_the_source() {
+    new_test_ext().execute_with(|| {
+        let mut config = evm::Config::london();
+        config.estimate = true;
+        assert!(EVMDeployers::gated_config(&DEPLOYER, &config).is_none());
+        let gated = EVMDeployers::gated_config(&STRANGER, &config).unwrap();
+        assert_eq!(gated.create_contract_limit, Some(0));
+        assert!(gated.estimate);
+
+        assert_ok!(EVMDeployers::set_permissionless(
+            RuntimeOrigin::root(),
+            true
+        ));
+        assert!(EVMDeployers::gated_config(&STRANGER, &config).is_none());
+    });
+}
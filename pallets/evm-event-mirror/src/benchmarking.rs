@@ -0,0 +1,26 @@
+//! EVM event mirror pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as EVMEventMirror;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin as SystemOrigin;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    set_mirroring {
+    }: _(SystemOrigin::Root, MirrorKind::Liquidation, false)
+    verify {
+        assert_last_event::<T>(Event::<T>::MirroringSet(MirrorKind::Liquidation, false).into());
+    }
+}
+
+impl_benchmark_test_suite!(
+    EVMEventMirror,
+    crate::mock::new_test_ext(),
+    crate::mock::Test
+);
@@ -0,0 +1,254 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # EVM Event Mirror pallet
+//!
+//! ## Overview
+//!
+//! Mirrors selected native protocol activity, loans liquidations, oracle prices and bridge
+//! transfers, as EVM logs emitted from a system contract address. EVM indexers can then
+//! follow the protocol like any other contract, without an off-chain relayer.
+//!
+//! The logs are deposited as `Log` events of the EVM pallet, where EVM indexers pick up the
+//! logs of a block. Accounts are indexed as `bytes32` topics, amounts are `uint256` and prices
+//! keep the 18 decimals of their fixed point representation.
+//!
+//! Governance can switch the mirroring of each kind of activity on and off.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Encode;
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use orml_traits::OnNewData;
+use pallet_evm::Log;
+use pallet_traits::{EventMirror, MirroredEvent};
+use precompile_utils::{keccak256, Bytes, EvmDataWriter, LogsBuilder};
+use primitives::{CurrencyId, Price};
+use sp_core::{H160, H256, U256};
+use sp_runtime::FixedPointNumber;
+use sp_std::marker::PhantomData;
+
+pub use pallet::*;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub mod weights;
+
+pub use types::MirrorKind;
+pub use weights::WeightInfo;
+
+/// Solidity selector of the LiquidatedBorrow log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_LIQUIDATED_BORROW: [u8; 32] =
+    keccak256!("LiquidatedBorrow(bytes32,bytes32,uint32,uint32,uint256,uint256)");
+
+/// Solidity selector of the PriceFed log.
+pub const SELECTOR_LOG_PRICE_FED: [u8; 32] = keccak256!("PriceFed(bytes32,uint32,uint256)");
+
+/// Solidity selector of the TeleportBurned log.
+pub const SELECTOR_LOG_TELEPORT_BURNED: [u8; 32] =
+    keccak256!("TeleportBurned(bytes32,uint32,uint64,uint32,bytes,uint256,uint256)");
+
+/// Solidity selector of the MaterializeMinted log.
+pub const SELECTOR_LOG_MATERIALIZE_MINTED: [u8; 32] =
+    keccak256!("MaterializeMinted(uint32,uint64,bytes32,uint32,uint256)");
+
+/// Destination of the mirrored logs
+pub trait DepositLog {
+    fn deposit_log(log: Log);
+}
+
+/// Deposits the logs as `Log` events of the EVM pallet
+pub struct EvmLogs<T>(PhantomData<T>);
+
+impl<T: pallet_evm::Config> DepositLog for EvmLogs<T> {
+    fn deposit_log(log: Log) {
+        let event: <T as pallet_evm::Config>::RuntimeEvent =
+            pallet_evm::Event::<T>::Log { log }.into();
+        frame_system::Pallet::<T>::deposit_event(event.into());
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Address of the system contract the logs are emitted from
+        #[pallet::constant]
+        type SystemContract: Get<H160>;
+
+        /// Where the logs are deposited
+        type Logs: DepositLog;
+
+        /// The origin which can switch the mirroring on and off
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Mirroring of a kind of activity was switched on or off
+        /// [kind, enabled]
+        MirroringSet(MirrorKind, bool),
+    }
+
+    /// Kinds of activity which aren't mirrored
+    /// MirrorKind -> ()
+    #[pallet::storage]
+    #[pallet::getter(fn disabled_kinds)]
+    pub type DisabledKinds<T: Config> = StorageMap<_, Twox64Concat, MirrorKind, (), OptionQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Switch the mirroring of `kind` on or off
+        #[pallet::weight(<T as Config>::WeightInfo::set_mirroring())]
+        #[transactional]
+        pub fn set_mirroring(
+            origin: OriginFor<T>,
+            kind: MirrorKind,
+            enabled: bool,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            if enabled {
+                DisabledKinds::<T>::remove(kind);
+            } else {
+                DisabledKinds::<T>::insert(kind, ());
+            }
+            Self::deposit_event(Event::<T>::MirroringSet(kind, enabled));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn is_mirrored(kind: MirrorKind) -> bool {
+        !DisabledKinds::<T>::contains_key(kind)
+    }
+
+    /// The scale encoding of `who`, right aligned in a topic like an abi encoded word
+    pub fn account_topic(who: &T::AccountId) -> H256 {
+        let mut topic = H256::zero();
+        who.using_encoded(|encoded| {
+            let len = encoded.len().min(32);
+            topic.0[32 - len..].copy_from_slice(&encoded[..len]);
+        });
+        topic
+    }
+
+    fn deposit_log(kind: MirrorKind, build: impl FnOnce(LogsBuilder) -> Log) {
+        if Self::is_mirrored(kind) {
+            T::Logs::deposit_log(build(LogsBuilder::new(T::SystemContract::get())));
+        }
+    }
+}
+
+impl<T: Config> EventMirror<T::AccountId> for Pallet<T> {
+    fn mirror(event: MirroredEvent<T::AccountId>) {
+        match event {
+            MirroredEvent::LiquidatedBorrow {
+                liquidator,
+                borrower,
+                liquidation_asset_id,
+                collateral_asset_id,
+                repay_amount,
+                collateral_amount,
+            } => Self::deposit_log(MirrorKind::Liquidation, |logs| {
+                logs.log3(
+                    SELECTOR_LOG_LIQUIDATED_BORROW,
+                    Self::account_topic(&liquidator),
+                    Self::account_topic(&borrower),
+                    EvmDataWriter::new()
+                        .write(liquidation_asset_id)
+                        .write(collateral_asset_id)
+                        .write(U256::from(repay_amount))
+                        .write(U256::from(collateral_amount))
+                        .build(),
+                )
+            }),
+            MirroredEvent::TeleportBurned {
+                from,
+                dest_id,
+                nonce,
+                bridge_token_id,
+                to,
+                amount,
+                fee,
+            } => Self::deposit_log(MirrorKind::BridgeTransfer, |logs| {
+                logs.log3(
+                    SELECTOR_LOG_TELEPORT_BURNED,
+                    Self::account_topic(&from),
+                    H256::from_low_u64_be(dest_id.into()),
+                    EvmDataWriter::new()
+                        .write(nonce)
+                        .write(bridge_token_id)
+                        .write(Bytes(to))
+                        .write(U256::from(amount))
+                        .write(U256::from(fee))
+                        .build(),
+                )
+            }),
+            MirroredEvent::MaterializeMinted {
+                src_id,
+                src_nonce,
+                bridge_token_id,
+                to,
+                amount,
+            } => Self::deposit_log(MirrorKind::BridgeTransfer, |logs| {
+                logs.log4(
+                    SELECTOR_LOG_MATERIALIZE_MINTED,
+                    H256::from_low_u64_be(src_id.into()),
+                    H256::from_low_u64_be(src_nonce),
+                    Self::account_topic(&to),
+                    EvmDataWriter::new()
+                        .write(bridge_token_id)
+                        .write(U256::from(amount))
+                        .build(),
+                )
+            }),
+        }
+    }
+}
+
+impl<T: Config> OnNewData<T::AccountId, CurrencyId, Price> for Pallet<T> {
+    fn on_new_data(who: &T::AccountId, key: &CurrencyId, value: &Price) {
+        Self::deposit_log(MirrorKind::OraclePrice, |logs| {
+            logs.log3(
+                SELECTOR_LOG_PRICE_FED,
+                Self::account_topic(who),
+                H256::from_low_u64_be((*key).into()),
+                EvmDataWriter::new()
+                    .write(U256::from(value.into_inner()))
+                    .build(),
+            )
+        })
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::BadOrigin, FixedU128};
+
+fn liquidation() -> MirroredEvent<AccountId> {
+    MirroredEvent::LiquidatedBorrow {
+        liquidator: ALICE,
+        borrower: BOB,
+        liquidation_asset_id: 100,
+        collateral_asset_id: 101,
+        repay_amount: 1_000,
+        collateral_amount: 2_000,
+    }
+}
+
+#[test]
+fn account_topic_should_be_right_aligned() {
+    new_test_ext().execute_with(|| {
+        let mut expected = [0u8; 32];
+        expected[24..].copy_from_slice(&ALICE.encode());
+        assert_eq!(EVMEventMirror::account_topic(&ALICE), H256(expected));
+    });
+}
+
+#[test]
+fn liquidations_should_be_logged() {
+    new_test_ext().execute_with(|| {
+        EVMEventMirror::mirror(liquidation());
+
+        assert_eq!(
+            deposited_logs(),
+            vec![LogsBuilder::new(SYSTEM_CONTRACT).log3(
+                SELECTOR_LOG_LIQUIDATED_BORROW,
+                EVMEventMirror::account_topic(&ALICE),
+                EVMEventMirror::account_topic(&BOB),
+                EvmDataWriter::new()
+                    .write(100u32)
+                    .write(101u32)
+                    .write(U256::from(1_000u128))
+                    .write(U256::from(2_000u128))
+                    .build(),
+            )]
+        );
+    });
+}
+
+#[test]
+fn bridge_transfers_should_be_logged() {
+    new_test_ext().execute_with(|| {
+        EVMEventMirror::mirror(MirroredEvent::TeleportBurned {
+            from: ALICE,
+            dest_id: 1,
+            nonce: 7,
+            bridge_token_id: 100,
+            to: vec![1, 2, 3],
+            amount: 1_000,
+            fee: 10,
+        });
+        EVMEventMirror::mirror(MirroredEvent::MaterializeMinted {
+            src_id: 1,
+            src_nonce: 8,
+            bridge_token_id: 100,
+            to: BOB,
+            amount: 500,
+        });
+
+        let logs = deposited_logs();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(
+            logs[0].topics,
+            vec![
+                H256(SELECTOR_LOG_TELEPORT_BURNED),
+                EVMEventMirror::account_topic(&ALICE),
+                H256::from_low_u64_be(1),
+            ]
+        );
+        // nonce, token, offset of the address, amount, fee, then the address itself
+        assert_eq!(logs[0].data.len(), 32 * 7);
+        assert_eq!(
+            &logs[0].data[32 * 2..32 * 3],
+            H256::from_low_u64_be(32 * 5).as_bytes()
+        );
+        assert_eq!(
+            logs[1],
+            LogsBuilder::new(SYSTEM_CONTRACT).log4(
+                SELECTOR_LOG_MATERIALIZE_MINTED,
+                H256::from_low_u64_be(1),
+                H256::from_low_u64_be(8),
+                EVMEventMirror::account_topic(&BOB),
+                EvmDataWriter::new()
+                    .write(100u32)
+                    .write(U256::from(500u128))
+                    .build(),
+            )
+        );
+    });
+}
+
+#[test]
+fn oracle_prices_should_be_logged() {
+    new_test_ext().execute_with(|| {
+        EVMEventMirror::on_new_data(
+            &ALICE,
+            &100,
+            &FixedU128::from_inner(2_500_000_000_000_000_000),
+        );
+
+        assert_eq!(
+            deposited_logs(),
+            vec![LogsBuilder::new(SYSTEM_CONTRACT).log3(
+                SELECTOR_LOG_PRICE_FED,
+                EVMEventMirror::account_topic(&ALICE),
+                H256::from_low_u64_be(100),
+                EvmDataWriter::new()
+                    .write(U256::from(2_500_000_000_000_000_000u128))
+                    .build(),
+            )]
+        );
+    });
+}
+
+#[test]
+fn disabled_kinds_should_not_be_logged() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            EVMEventMirror::set_mirroring(
+                RuntimeOrigin::signed(ALICE),
+                MirrorKind::Liquidation,
+                false
+            ),
+            BadOrigin
+        );
+        assert_ok!(EVMEventMirror::set_mirroring(
+            RuntimeOrigin::root(),
+            MirrorKind::Liquidation,
+            false
+        ));
+        System::assert_last_event(RuntimeEvent::EVMEventMirror(Event::MirroringSet(
+            MirrorKind::Liquidation,
+            false,
+        )));
+
+        EVMEventMirror::mirror(liquidation());
+        EVMEventMirror::on_new_data(&ALICE, &100, &FixedU128::saturating_from_integer(1u64));
+        assert_eq!(deposited_logs().len(), 1);
+
+        assert_ok!(EVMEventMirror::set_mirroring(
+            RuntimeOrigin::root(),
+            MirrorKind::Liquidation,
+            true
+        ));
+        EVMEventMirror::mirror(liquidation());
+        assert_eq!(deposited_logs().len(), 2);
+    });
+}
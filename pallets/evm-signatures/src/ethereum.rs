@@ -15,8 +15,11 @@
 //! Ethereum prefixed signatures compatibility instances.
 
 use codec::{Decode, Encode};
-use sp_core::ecdsa;
-use sp_io::{crypto::secp256k1_ecdsa_recover_compressed, hashing::keccak_256};
+use sp_core::{ecdsa, H160};
+use sp_io::{
+    crypto::{secp256k1_ecdsa_recover, secp256k1_ecdsa_recover_compressed},
+    hashing::keccak_256,
+};
 use sp_runtime::traits::{IdentifyAccount, Lazy, Verify};
 use sp_runtime::MultiSignature;
 use sp_std::prelude::*;
@@ -61,6 +64,16 @@ pub fn signable_message(what: &[u8]) -> Vec<u8> {
     v
 }
 
+impl EthereumSignature {
+    /// Recovers the Ethereum address which signed `msg` with `personal_sign` or `eth_sign`.
+    pub fn recover_address(&self, msg: &[u8]) -> Option<H160> {
+        let msg = keccak_256(&signable_message(msg));
+        secp256k1_ecdsa_recover(&self.0, &msg)
+            .ok()
+            .map(|public| H160::from_slice(&keccak_256(&public)[12..]))
+    }
+}
+
 /// Attempts to recover the Ethereum public key from a message signature signed by using
 /// the Ethereum RPC's `personal_sign` and `eth_sign`.
 impl Verify for EthereumSignature {
@@ -94,4 +107,8 @@ fn verify_should_works() {
     let account = <MultiSignature as Verify>::Signer::from(pair.public()).into_account();
     let signature = EthereumSignature(hex!["f5d5cc953828e3fb0d81f3176d88fa5c73d3ad3dc4bc7a8061b03a6db2cd73337778df75a1443e8c642f6ceae0db39b90c321ac270ad7836695cae76f703f3031c"]);
     assert_eq!(signature.verify(msg.as_ref(), &account), true);
+    assert_eq!(
+        signature.recover_address(msg.as_ref()),
+        Some(H160::from(hex!["f39fd6e51aad88f6f4ce6ab8827279cfffb92266"]))
+    );
 }
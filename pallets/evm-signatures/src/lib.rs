@@ -26,15 +26,17 @@ mod tests;
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use ethereum::EthereumSignature;
     use frame_support::{
-        dispatch::GetDispatchInfo,
+        dispatch::{GetDispatchInfo, PostDispatchInfo},
         pallet_prelude::*,
         traits::{
             tokens::{
-                fungible::Transfer,
+                fungible::{Inspect, Transfer},
                 fungibles::{Inspect as Inspects, Mutate as Mutates, Transfer as Transfers},
+                DepositConsequence,
             },
-            Currency, ExistenceRequirement, Get, OnUnbalanced, UnfilteredDispatchable,
+            Contains, Currency, ExistenceRequirement, Get, OnUnbalanced, UnfilteredDispatchable,
             WithdrawReasons,
         },
         transactional,
@@ -43,7 +45,7 @@ pub mod pallet {
     use pallet_evm::{AddressMapping, EnsureAddressOrigin};
     use primitives::{Balance, CurrencyId};
     use sp_core::H160;
-    use sp_runtime::traits::{IdentifyAccount, Verify};
+    use sp_runtime::traits::{Dispatchable, IdentifyAccount, Verify};
     use sp_std::{convert::TryFrom, prelude::*};
     use weights::WeightInfo;
 
@@ -68,6 +70,7 @@ pub mod pallet {
         /// A signable call.
         type RuntimeCall: Parameter
             + UnfilteredDispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
             + GetDispatchInfo;
 
         /// User defined signature type.
@@ -115,6 +118,9 @@ pub mod pallet {
         /// Mapping from address to account id.
         type AddressMapping: AddressMapping<Self::AccountId>;
 
+        /// Calls which can be signed with the key of an EVM address
+        type AddressCallFilter: Contains<<Self as Config>::RuntimeCall>;
+
         /// Weight information
         type WeightInfo: WeightInfo;
     }
@@ -127,6 +133,18 @@ pub mod pallet {
         InvalidSignature,
         /// Bad nonce parameter.
         BadNonce,
+        /// Call can't be signed with the key of an EVM address.
+        CallNotAllowed,
+        /// Origin isn't the account mapped to the EVM address.
+        AddressMismatch,
+        /// Asset doesn't exist.
+        UnknownAsset,
+        /// Amount would create the destination account below the minimum balance.
+        BelowMinimumBalance,
+        /// Destination account doesn't exist and can't be created by the asset.
+        CannotCreateAccount,
+        /// Amount would leave the source account below the minimum balance.
+        WouldKillAccount,
     }
 
     #[pallet::event]
@@ -134,6 +152,10 @@ pub mod pallet {
     pub enum Event<T: Config> {
         /// A call just executed. \[result\]
         Executed(T::AccountId, DispatchResult),
+        /// Asset moved to the account of an EVM address. \[from, address, asset, amount\]
+        TransferredToEvm(T::AccountId, H160, AssetIdOf<T>, AssetBalanceOf<T>),
+        /// Asset moved from the account of an EVM address. \[address, to, asset, amount\]
+        TransferredToSubstrate(H160, T::AccountId, AssetIdOf<T>, AssetBalanceOf<T>),
     }
 
     #[pallet::call]
@@ -193,6 +215,13 @@ pub mod pallet {
             Ok(Pays::No.into())
         }
 
+        /// Dispatch `call` from the account mapped to the EVM `address`, which signed it
+        /// with `eth_sign` so that users only holding an Ethereum wallet can use the calls
+        /// allowed by `AddressCallFilter`.
+        #[pallet::weight({
+            let dispatch_info = call.get_dispatch_info();
+            (Weight::from_ref_time(10_000_000).saturating_add(dispatch_info.weight), dispatch_info.class)
+        })]
         #[pallet::weight(<T as Config>::WeightInfo::withdraw())]
         #[transactional]
         pub fn withdraw(
@@ -208,6 +237,94 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Move `amount` of `asset` to the account mapped to the EVM `address`, where contracts
+        /// can use it through the ERC20 precompiles.
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_to_evm())]
+        #[transactional]
+        pub fn transfer_to_evm(
+            origin: OriginFor<T>,
+            asset: AssetIdOf<T>,
+            address: H160,
+            amount: AssetBalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let address_account_id = T::AddressMapping::into_account_id(address);
+
+            Self::teleport(asset, &who, &address_account_id, amount)?;
+
+            Self::deposit_event(Event::TransferredToEvm(who, address, asset, amount));
+            Ok(())
+        }
+
+        /// Move `amount` of `asset` from the account mapped to the EVM `address` to `dest`.
+        ///
+        /// The origin must be the mapped account, which is how the substrate transfer
+        /// precompile dispatches it on behalf of the caller.
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_to_substrate())]
+        #[transactional]
+        pub fn transfer_to_substrate(
+            origin: OriginFor<T>,
+            asset: AssetIdOf<T>,
+            address: H160,
+            dest: T::AccountId,
+            amount: AssetBalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                who == T::AddressMapping::into_account_id(address),
+                Error::<T>::AddressMismatch
+            );
+
+            Self::teleport(asset, &who, &dest, amount)?;
+
+            Self::deposit_event(Event::TransferredToSubstrate(address, dest, asset, amount));
+            Ok(())
+        }
+
+        pub fn call_from_address(
+            origin: OriginFor<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+            address: H160,
+            signature: Vec<u8>,
+            #[pallet::compact] nonce: T::Index,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+            ensure!(
+                T::AddressCallFilter::contains(&call),
+                Error::<T>::CallNotAllowed
+            );
+
+            let signer = T::AddressMapping::into_account_id(address);
+            ensure!(
+                nonce == frame_system::Pallet::<T>::account_nonce(signer.clone()),
+                Error::<T>::BadNonce,
+            );
+
+            let signature =
+                EthereumSignature::try_from(signature).map_err(|_| Error::<T>::DecodeFailure)?;
+            ensure!(
+                Self::valid_address_signature(&call, &address, &signature, &nonce),
+                Error::<T>::InvalidSignature
+            );
+
+            frame_system::Pallet::<T>::inc_account_nonce(signer.clone());
+
+            let tx_fee = T::Currency::withdraw(
+                &signer,
+                T::CallFee::get(),
+                WithdrawReasons::FEE,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            T::OnChargeTransaction::on_unbalanced(tx_fee);
+
+            // Unlike `call`, the origin keeps the runtime's call filter
+            let new_origin = frame_system::RawOrigin::Signed(signer.clone()).into();
+            let res = call.dispatch(new_origin).map(|_| ());
+            Self::deposit_event(Event::Executed(signer, res.map_err(|e| e.error)));
+
+            Ok(Pays::No.into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -227,6 +344,24 @@ pub mod pallet {
             }
         }
 
+        /// Verify that `address` signed the call and returns `true` if correct.
+        ///
+        /// The address is part of the payload so the signature can't be replayed by
+        /// `call` for the account derived from the same key.
+        pub fn valid_address_signature(
+            call: &<T as Config>::RuntimeCall,
+            address: &H160,
+            signature: &EthereumSignature,
+            nonce: &T::Index,
+        ) -> bool {
+            let payload = (T::CallMagicNumber::get(), *nonce, *address, call.clone());
+            if T::VerifySignature::get() {
+                signature.recover_address(&payload.encode()[..]) == Some(*address)
+            } else {
+                true
+            }
+        }
+
         fn transfer(
             asset: AssetIdOf<T>,
             source: &T::AccountId,
@@ -241,48 +376,126 @@ pub mod pallet {
                 T::Assets::transfer(asset, source, dest, amount, true)
             }
         }
+
+        /// Transfer between a substrate account and the account of an EVM address, failing
+        /// with a descriptive error instead of the one of the underlying pallet when the
+        /// minimum balance of `asset` isn't respected on either side.
+        ///
+        /// The whole balance of an asset can be moved, which removes the asset account of
+        /// `source`. Native balances are always kept alive, as reaping the account would
+        /// reset the nonce of an EVM address.
+        fn teleport(
+            asset: AssetIdOf<T>,
+            source: &T::AccountId,
+            dest: &T::AccountId,
+            amount: AssetBalanceOf<T>,
+        ) -> DispatchResult {
+            let native = asset == T::GetNativeCurrencyId::get();
+            let (deposit, balance, minimum_balance) = if native {
+                (
+                    <T::Currency as Inspect<T::AccountId>>::can_deposit(dest, amount, false),
+                    <T::Currency as Inspect<T::AccountId>>::balance(source),
+                    <T::Currency as Inspect<T::AccountId>>::minimum_balance(),
+                )
+            } else {
+                (
+                    T::Assets::can_deposit(asset, dest, amount, false),
+                    T::Assets::balance(asset, source),
+                    T::Assets::minimum_balance(asset),
+                )
+            };
+
+            match deposit {
+                DepositConsequence::UnknownAsset => return Err(Error::<T>::UnknownAsset.into()),
+                DepositConsequence::BelowMinimum => {
+                    return Err(Error::<T>::BelowMinimumBalance.into())
+                }
+                DepositConsequence::CannotCreate => {
+                    return Err(Error::<T>::CannotCreateAccount.into())
+                }
+                _ => {}
+            }
+
+            let keep_alive = native || amount < balance;
+            ensure!(
+                !keep_alive || amount > balance || balance - amount >= minimum_balance,
+                Error::<T>::WouldKillAccount
+            );
+
+            if native {
+                <T::Currency as Transfer<T::AccountId>>::transfer(source, dest, amount, true)?;
+            } else {
+                T::Assets::transfer(asset, source, dest, amount, keep_alive)?;
+            }
+
+            Ok(())
+        }
     }
 
     pub(crate) const SIGNATURE_DECODE_FAILURE: u8 = 1;
 
+    impl<T: Config> Pallet<T> {
+        fn valid_transaction(
+            call: &<T as Config>::RuntimeCall,
+            signer: &T::AccountId,
+            nonce: &T::Index,
+        ) -> TransactionValidity {
+            ValidTransaction::with_tag_prefix("EVMSignatures")
+                .priority(T::UnsignedPriority::get())
+                .and_provides((call, signer, nonce))
+                .longevity(64_u64)
+                .propagate(true)
+                .build()
+        }
+    }
+
     #[pallet::validate_unsigned]
     impl<T: Config> frame_support::unsigned::ValidateUnsigned for Pallet<T> {
         type Call = Call<T>;
 
         fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
-            // Call decomposition (we have only one possible value here)
-            let (call, signer, signature, nonce) = match call {
+            match call {
                 Call::call {
                     call,
                     signer,
                     signature,
                     nonce,
-                } => (call, signer, signature, nonce),
-                _ => return InvalidTransaction::Call.into(),
-            };
-
-            // Check that tx isn't stale
-            if *nonce != frame_system::Pallet::<T>::account_nonce(signer.clone()) {
-                return InvalidTransaction::Stale.into();
-            }
-
-            // Check signature encoding
-            if let Ok(signature) = <T as Config>::Signature::try_from(signature.clone()) {
-                // Verify signature
-                if Self::valid_signature(call, signer, &signature, nonce) {
-                    ValidTransaction::with_tag_prefix("EVMSignatures")
-                        .priority(T::UnsignedPriority::get())
-                        .and_provides((call, signer, nonce))
-                        .longevity(64_u64)
-                        .propagate(true)
-                        .build()
-                } else {
-                    // Signature mismatched to given signer
-                    InvalidTransaction::BadProof.into()
+                } => {
+                    // Check that tx isn't stale
+                    if *nonce != frame_system::Pallet::<T>::account_nonce(signer.clone()) {
+                        return InvalidTransaction::Stale.into();
+                    }
+                    // Check signature encoding
+                    let signature = <T as Config>::Signature::try_from(signature.clone())
+                        .map_err(|_| InvalidTransaction::Custom(SIGNATURE_DECODE_FAILURE))?;
+                    // Verify signature
+                    if !Self::valid_signature(call, signer, &signature, nonce) {
+                        // Signature mismatched to given signer
+                        return InvalidTransaction::BadProof.into();
+                    }
+                    Self::valid_transaction(call, signer, nonce)
                 }
-            } else {
-                // Signature encoding broken
-                InvalidTransaction::Custom(SIGNATURE_DECODE_FAILURE).into()
+                Call::call_from_address {
+                    call,
+                    address,
+                    signature,
+                    nonce,
+                } => {
+                    if !T::AddressCallFilter::contains(call) {
+                        return InvalidTransaction::Call.into();
+                    }
+                    let signer = T::AddressMapping::into_account_id(*address);
+                    if *nonce != frame_system::Pallet::<T>::account_nonce(signer.clone()) {
+                        return InvalidTransaction::Stale.into();
+                    }
+                    let signature = EthereumSignature::try_from(signature.clone())
+                        .map_err(|_| InvalidTransaction::Custom(SIGNATURE_DECODE_FAILURE))?;
+                    if !Self::valid_address_signature(call, address, &signature, nonce) {
+                        return InvalidTransaction::BadProof.into();
+                    }
+                    Self::valid_transaction(call, &signer, nonce)
+                }
+                _ => InvalidTransaction::Call.into(),
             }
         }
 
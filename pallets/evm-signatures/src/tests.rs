@@ -15,10 +15,11 @@
 use crate as evm_signatures;
 use codec::Encode;
 use evm_signatures::*;
-use frame_support::{assert_err, assert_ok, parameter_types};
+use frame_support::{assert_err, assert_ok, parameter_types, traits::Contains};
 use frame_system::EnsureRoot;
 use hex_literal::hex;
-use sp_core::{ecdsa, Pair};
+use pallet_evm::AddressMapping;
+use sp_core::{ecdsa, Pair, H160};
 use sp_io::hashing::keccak_256;
 use sp_keyring::AccountKeyring as Keyring;
 use sp_runtime::{
@@ -58,7 +59,7 @@ parameter_types! {
 
 impl frame_system::Config for Runtime {
     type RuntimeOrigin = RuntimeOrigin;
-    type BaseCallFilter = frame_support::traits::Everything;
+    type BaseCallFilter = BaseFilter;
     type Index = u32;
     type BlockNumber = BlockNumber;
     type RuntimeCall = RuntimeCall;
@@ -133,6 +134,23 @@ parameter_types! {
     pub const VerifySignature: bool = true;
 }
 
+pub struct BaseFilter;
+impl Contains<RuntimeCall> for BaseFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        !matches!(
+            call,
+            RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. })
+        )
+    }
+}
+
+pub struct AddressCallFilter;
+impl Contains<RuntimeCall> for AddressCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::Balances(_))
+    }
+}
+
 impl Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -148,6 +166,7 @@ impl Config for Runtime {
     type Assets = Assets;
     type AddressMapping = pallet_evm::HashedAddressMapping<BlakeTwo256>;
     type WithdrawOrigin = pallet_evm::EnsureAddressTruncated;
+    type AddressCallFilter = AddressCallFilter;
     type WeightInfo = ();
 }
 
@@ -159,12 +178,26 @@ fn new_test_ext() -> sp_io::TestExternalities {
     let pair = ecdsa::Pair::from_seed(&ECDSA_SEED);
     let account = MultiSigner::from(pair.public()).into_account();
     let _ = pallet_balances::GenesisConfig::<Runtime> {
-        balances: vec![(account, 1_000_000_000)],
+        balances: vec![
+            (account, 1_000_000_000),
+            (mapped_account(&ECDSA_SEED), 1_000_000_000),
+        ],
     }
     .assimilate_storage(&mut storage);
     storage.into()
 }
 
+/// Ethereum address of the key
+fn eth_address(seed: &[u8; 32]) -> H160 {
+    let secret = libsecp256k1::SecretKey::parse(seed).expect("valid seed");
+    let public = libsecp256k1::PublicKey::from_secret_key(&secret).serialize();
+    H160::from_slice(&keccak_256(&public[1..])[12..])
+}
+
+fn mapped_account(seed: &[u8; 32]) -> AccountId {
+    <Runtime as Config>::AddressMapping::into_account_id(eth_address(seed))
+}
+
 /// Simple `eth_sign` implementation, should be equal to exported by RPC
 fn eth_sign(seed: &[u8; 32], data: &[u8]) -> Vec<u8> {
     let call_msg = ethereum::signable_message(data);
@@ -298,3 +331,268 @@ fn call_fixtures() {
     let signature = hex!["6ecb474240df46ee5cde8f51cf5ccf4c75d15ac3c1772aea6c8189604263c98b16350883438c4eaa447ebcb6889d516f70351fd704bb3521072cd2fccc7c99dc1c"];
     assert_eq!(eth_sign(&seed, payload.encode().as_ref()), signature)
 }
+
+#[test]
+fn call_from_address_works() {
+    new_test_ext().execute_with(|| {
+        let address = eth_address(&ECDSA_SEED);
+        let account = mapped_account(&ECDSA_SEED);
+        let alice: <Runtime as frame_system::Config>::AccountId = Keyring::Alice.into();
+
+        let call: RuntimeCall = pallet_balances::Call::<Runtime>::transfer {
+            dest: alice.clone(),
+            value: 1_000,
+        }
+        .into();
+        let payload = (0xff50u16, 0u32, address, call.clone());
+        let signature = eth_sign(&ECDSA_SEED, payload.encode().as_ref());
+
+        assert_ok!(EVMSignatures::call_from_address(
+            RuntimeOrigin::none(),
+            Box::new(call.clone()),
+            address,
+            signature.clone(),
+            0,
+        ));
+        assert_eq!(System::account(alice).data.free, 1_000);
+        assert_eq!(System::account(account.clone()).nonce, 1);
+        assert_eq!(System::account(account).data.free, 999_998_958);
+
+        assert_err!(
+            EVMSignatures::call_from_address(
+                RuntimeOrigin::none(),
+                Box::new(call),
+                address,
+                signature,
+                0,
+            ),
+            Error::<Runtime>::BadNonce,
+        );
+    })
+}
+
+#[test]
+fn call_from_address_checks_signer() {
+    new_test_ext().execute_with(|| {
+        let address = eth_address(&ECDSA_SEED);
+        let alice: <Runtime as frame_system::Config>::AccountId = Keyring::Alice.into();
+        let call: RuntimeCall = pallet_balances::Call::<Runtime>::transfer {
+            dest: alice,
+            value: 1_000,
+        }
+        .into();
+
+        // Signed by another key
+        let other_seed = [1u8; 32];
+        let payload = (0xff50u16, 0u32, address, call.clone());
+        assert_err!(
+            EVMSignatures::call_from_address(
+                RuntimeOrigin::none(),
+                Box::new(call.clone()),
+                address,
+                eth_sign(&other_seed, payload.encode().as_ref()),
+                0,
+            ),
+            Error::<Runtime>::InvalidSignature,
+        );
+
+        // Signatures of `call` can't be replayed
+        let payload = (0xff50u16, 0u32, call.clone());
+        assert_err!(
+            EVMSignatures::call_from_address(
+                RuntimeOrigin::none(),
+                Box::new(call),
+                address,
+                eth_sign(&ECDSA_SEED, payload.encode().as_ref()),
+                0,
+            ),
+            Error::<Runtime>::InvalidSignature,
+        );
+    })
+}
+
+#[test]
+fn call_from_address_rejects_filtered_calls() {
+    new_test_ext().execute_with(|| {
+        let address = eth_address(&ECDSA_SEED);
+        let call: RuntimeCall = frame_system::Call::<Runtime>::remark { remark: vec![] }.into();
+        let payload = (0xff50u16, 0u32, address, call.clone());
+
+        assert_err!(
+            EVMSignatures::call_from_address(
+                RuntimeOrigin::none(),
+                Box::new(call),
+                address,
+                eth_sign(&ECDSA_SEED, payload.encode().as_ref()),
+                0,
+            ),
+            Error::<Runtime>::CallNotAllowed,
+        );
+        assert_eq!(System::account(mapped_account(&ECDSA_SEED)).nonce, 0);
+    })
+}
+
+#[test]
+fn call_from_address_respects_base_call_filter() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let address = eth_address(&ECDSA_SEED);
+        let account = mapped_account(&ECDSA_SEED);
+        let alice: <Runtime as frame_system::Config>::AccountId = Keyring::Alice.into();
+        let call: RuntimeCall = pallet_balances::Call::<Runtime>::transfer_all {
+            dest: alice.clone(),
+            keep_alive: false,
+        }
+        .into();
+        let payload = (0xff50u16, 0u32, address, call.clone());
+
+        assert_ok!(EVMSignatures::call_from_address(
+            RuntimeOrigin::none(),
+            Box::new(call),
+            address,
+            eth_sign(&ECDSA_SEED, payload.encode().as_ref()),
+            0,
+        ));
+        System::assert_last_event(RuntimeEvent::EVMSignatures(Event::Executed(
+            account,
+            Err(frame_system::Error::<Runtime>::CallFiltered.into()),
+        )));
+        assert_eq!(System::account(alice).data.free, 0);
+    })
+}
+
+#[test]
+fn transfer_between_evm_and_substrate_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let pair = ecdsa::Pair::from_seed(&ECDSA_SEED);
+        let account = MultiSigner::from(pair.public()).into_account();
+        let address = eth_address(&ECDSA_SEED);
+        let mapped = mapped_account(&ECDSA_SEED);
+        let alice: <Runtime as frame_system::Config>::AccountId = Keyring::Alice.into();
+
+        assert_ok!(EVMSignatures::transfer_to_evm(
+            RuntimeOrigin::signed(account.clone()),
+            0,
+            address,
+            1_000,
+        ));
+        assert_eq!(System::account(account.clone()).data.free, 999_999_000);
+        assert_eq!(System::account(mapped.clone()).data.free, 1_000_001_000);
+        System::assert_last_event(RuntimeEvent::EVMSignatures(Event::TransferredToEvm(
+            account.clone(),
+            address,
+            0,
+            1_000,
+        )));
+
+        assert_ok!(EVMSignatures::transfer_to_substrate(
+            RuntimeOrigin::signed(mapped.clone()),
+            0,
+            address,
+            alice.clone(),
+            2_000,
+        ));
+        assert_eq!(System::account(mapped).data.free, 999_999_000);
+        assert_eq!(System::account(alice.clone()).data.free, 2_000);
+        System::assert_last_event(RuntimeEvent::EVMSignatures(Event::TransferredToSubstrate(
+            address, alice, 0, 2_000,
+        )));
+
+        // Only the account of the address can move its balance
+        assert_err!(
+            EVMSignatures::transfer_to_substrate(
+                RuntimeOrigin::signed(account.clone()),
+                0,
+                address,
+                account,
+                1_000,
+            ),
+            Error::<Runtime>::AddressMismatch,
+        );
+    })
+}
+
+#[test]
+fn transfer_between_evm_and_substrate_respects_minimum_balance() {
+    new_test_ext().execute_with(|| {
+        let pair = ecdsa::Pair::from_seed(&ECDSA_SEED);
+        let account = MultiSigner::from(pair.public()).into_account();
+        let address = eth_address(&ECDSA_SEED);
+        let mapped = mapped_account(&ECDSA_SEED);
+        let alice: <Runtime as frame_system::Config>::AccountId = Keyring::Alice.into();
+
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            1,
+            alice.clone(),
+            false,
+            10
+        ));
+        assert_ok!(Assets::mint(
+            RuntimeOrigin::signed(alice),
+            1,
+            account.clone(),
+            100
+        ));
+
+        assert_err!(
+            EVMSignatures::transfer_to_evm(RuntimeOrigin::signed(account.clone()), 2, address, 50),
+            Error::<Runtime>::UnknownAsset,
+        );
+        assert_err!(
+            EVMSignatures::transfer_to_evm(RuntimeOrigin::signed(account.clone()), 1, address, 5),
+            Error::<Runtime>::BelowMinimumBalance,
+        );
+        // The asset isn't sufficient, an address without native balance can't receive it
+        assert_err!(
+            EVMSignatures::transfer_to_evm(
+                RuntimeOrigin::signed(account.clone()),
+                1,
+                eth_address(&[1u8; 32]),
+                50
+            ),
+            Error::<Runtime>::CannotCreateAccount,
+        );
+        assert_ok!(EVMSignatures::transfer_to_evm(
+            RuntimeOrigin::signed(account.clone()),
+            1,
+            address,
+            50
+        ));
+        assert_eq!(Assets::balance(1, &mapped), 50);
+
+        // A part of the balance must leave the minimum balance, the whole of it can be moved
+        assert_err!(
+            EVMSignatures::transfer_to_substrate(
+                RuntimeOrigin::signed(mapped.clone()),
+                1,
+                address,
+                account.clone(),
+                45
+            ),
+            Error::<Runtime>::WouldKillAccount,
+        );
+        assert_ok!(EVMSignatures::transfer_to_substrate(
+            RuntimeOrigin::signed(mapped.clone()),
+            1,
+            address,
+            account.clone(),
+            50
+        ));
+        assert_eq!(Assets::balance(1, &mapped), 0);
+        assert_eq!(Assets::balance(1, &account), 100);
+
+        // The account of the address is never reaped for its native balance
+        assert_err!(
+            EVMSignatures::transfer_to_substrate(
+                RuntimeOrigin::signed(mapped.clone()),
+                0,
+                address,
+                account,
+                1_000_000_000
+            ),
+            Error::<Runtime>::WouldKillAccount,
+        );
+    })
+}
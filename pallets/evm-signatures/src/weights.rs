@@ -18,13 +18,22 @@
 #![allow(unused_imports)]
 #![allow(clippy::all)]
 
+// The weights marked NOT BENCHMARKED YET were added by hand from the storage accesses of
+// their calls and are estimates until the pallet gets benchmarks.
+
 use frame_support::weights::Weight;
 use sp_runtime::traits::Get;
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions needed for pallet_prices.
 pub trait WeightInfo {
     fn withdraw() -> Weight;
+    fn transfer_to_evm() -> Weight;
+    fn transfer_to_substrate() -> Weight;
 }
 
 /// use max weight of Assets and Balance,better to rebenchmark later
@@ -33,6 +42,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn withdraw() -> Weight {
         Weight::from_ref_time(92_361_000 as u64)
             .saturating_add(T::DbWeight::get().reads(4 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
+            .saturating_add(T::DbWeight::get().writes(4 as u64))
+    }
+    // NOT BENCHMARKED YET
+    fn transfer_to_evm() -> Weight {
+        Weight::from_ref_time(95_218_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(5 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+            .saturating_add(T::DbWeight::get().writes(4 as u64))
+    }
+    // NOT BENCHMARKED YET
+    fn transfer_to_substrate() -> Weight {
+        Weight::from_ref_time(95_218_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(5 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
             .saturating_add(T::DbWeight::get().writes(4 as u64))
     }
 }
@@ -41,4 +65,12 @@ impl WeightInfo for () {
     fn withdraw() -> Weight {
         Weight::from_ref_time(10_000 as u64)
     }
+    // NOT BENCHMARKED YET
+    fn transfer_to_evm() -> Weight {
+        Weight::from_ref_time(10_000 as u64)
+    }
+    // NOT BENCHMARKED YET
+    fn transfer_to_substrate() -> Weight {
+        Weight::from_ref_time(10_000 as u64)
+    }
 }
@@ -15,6 +15,10 @@
 //! # Pure Farming (FAR)
 //!
 //! pallet-farming is in charge of creating a governance-controlled incentivization program for our different products.
+//!
+//! Calls only touch the pool and the position they are given, no call goes over the
+//! stakers of a pool. `pending_rewards` and `account_positions` go over every pool,
+//! which only governance creates, and are meant for the runtime API.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -30,6 +34,7 @@ pub mod weights;
 
 use frame_support::{
     pallet_prelude::*,
+    require_transactional,
     traits::{
         fungibles::{Inspect, Mutate, Transfer},
         Get, IsType,
@@ -38,7 +43,7 @@ use frame_support::{
 };
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use num_traits::{cast::ToPrimitive, CheckedDiv, CheckedMul};
-use pallet_traits::{ConvertToBigUint, DecimalProvider};
+use pallet_traits::{ConvertToBigUint, DecimalProvider, RewardClaimer, RewardDispatcher};
 use primitives::{Balance, CurrencyId};
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
@@ -48,7 +53,7 @@ use sp_runtime::{
     },
     ArithmeticError,
 };
-use sp_std::result::Result;
+use sp_std::{result::Result, vec::Vec};
 
 use crate::types::{PoolInfo, UserPosition};
 pub use pallet::*;
@@ -610,40 +615,7 @@ pub mod pallet {
             lock_duration: T::BlockNumber,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-
-            ensure!(
-                Pools::<T>::contains_key((&asset, &reward_asset, &lock_duration)),
-                Error::<T>::PoolDoesNotExist
-            );
-
-            Self::update_reward(Some(who.clone()), asset, reward_asset, lock_duration)?;
-
-            let asset_pool_account = Self::pool_account_id(reward_asset)?;
-            Positions::<T>::mutate(
-                (&asset, &reward_asset, &lock_duration, &who),
-                |user_position| -> DispatchResult {
-                    let reward_amount = user_position.reward_amount;
-                    if reward_amount > 0 {
-                        T::Assets::transfer(
-                            reward_asset,
-                            &asset_pool_account,
-                            &who,
-                            reward_amount,
-                            false,
-                        )?;
-                        user_position.reward_amount = 0;
-                    }
-
-                    Self::deposit_event(Event::<T>::RewardPaid(
-                        who.clone(),
-                        asset,
-                        reward_asset,
-                        lock_duration,
-                        reward_amount,
-                    ));
-                    Ok(())
-                },
-            )
+            Self::do_claim(&who, asset, reward_asset, lock_duration)
         }
 
         /// Dispatch reward asset with specified amount and duration
@@ -668,76 +640,89 @@ pub mod pallet {
             reward_duration: T::BlockNumber,
         ) -> DispatchResult {
             T::UpdateOrigin::ensure_origin(origin)?;
-            ensure!(
-                Pools::<T>::contains_key((&asset, &reward_asset, &lock_duration)),
-                Error::<T>::PoolDoesNotExist
-            );
-            ensure!(!reward_duration.is_zero(), Error::<T>::NotAValidDuration);
-
-            Self::update_reward(None, asset, reward_asset, lock_duration)?;
-
-            let current_block_number = <frame_system::Pallet<T>>::block_number();
-            Pools::<T>::mutate(
-                (asset, reward_asset, lock_duration),
-                |pool_info| -> DispatchResult {
-                    let pool_info = pool_info.as_mut().ok_or(Error::<T>::PoolDoesNotExist)?;
-                    let duration_balance = pool_info.block_to_balance(reward_duration);
-                    let reward_rate = if current_block_number >= pool_info.period_finish {
-                        amount
-                            .checked_div(duration_balance)
-                            .ok_or(ArithmeticError::Overflow)?
-                    } else {
-                        let remaining = pool_info
-                            .period_finish
-                            .checked_sub(&current_block_number)
-                            .ok_or(ArithmeticError::Overflow)?;
-                        let left_over = pool_info
-                            .block_to_balance(remaining)
-                            .checked_mul(pool_info.reward_rate)
-                            .ok_or(ArithmeticError::Overflow)?;
-                        let total = left_over
-                            .checked_add(amount)
-                            .ok_or(ArithmeticError::Overflow)?;
-                        total
-                            .checked_div(duration_balance)
-                            .ok_or(ArithmeticError::Overflow)?
-                    };
-
-                    let new_period_finish = current_block_number
-                        .checked_add(&reward_duration)
-                        .ok_or(ArithmeticError::Overflow)?;
-
-                    pool_info.reward_duration = reward_duration;
-                    pool_info.period_finish = new_period_finish;
-                    pool_info.reward_rate = reward_rate;
-                    pool_info.last_update_block = current_block_number;
-
-                    if amount > 0 {
-                        let asset_pool_account = Self::pool_account_id(reward_asset)?;
-                        let payer = T::Lookup::lookup(payer)?;
-                        T::Assets::transfer(
-                            reward_asset,
-                            &payer,
-                            &asset_pool_account,
-                            amount,
-                            false,
-                        )?;
-                    }
-
-                    Self::deposit_event(Event::<T>::RewardAdded(
-                        asset,
-                        reward_asset,
-                        lock_duration,
-                        amount,
-                    ));
-                    Ok(())
-                },
+            let payer = T::Lookup::lookup(payer)?;
+            Self::do_dispatch_reward(
+                &payer,
+                asset,
+                reward_asset,
+                lock_duration,
+                amount,
+                reward_duration,
             )
         }
     }
 }
 
 impl<T: Config> Pallet<T> {
+    #[require_transactional]
+    fn do_dispatch_reward(
+        payer: &T::AccountId,
+        asset: AssetIdOf<T>,
+        reward_asset: AssetIdOf<T>,
+        lock_duration: T::BlockNumber,
+        amount: BalanceOf<T>,
+        reward_duration: T::BlockNumber,
+    ) -> DispatchResult {
+        ensure!(
+            Pools::<T>::contains_key((&asset, &reward_asset, &lock_duration)),
+            Error::<T>::PoolDoesNotExist
+        );
+        ensure!(!reward_duration.is_zero(), Error::<T>::NotAValidDuration);
+
+        Self::update_reward(None, asset, reward_asset, lock_duration)?;
+
+        let current_block_number = <frame_system::Pallet<T>>::block_number();
+        Pools::<T>::mutate(
+            (asset, reward_asset, lock_duration),
+            |pool_info| -> DispatchResult {
+                let pool_info = pool_info.as_mut().ok_or(Error::<T>::PoolDoesNotExist)?;
+                let duration_balance = pool_info.block_to_balance(reward_duration);
+                let reward_rate = if current_block_number >= pool_info.period_finish {
+                    amount
+                        .checked_div(duration_balance)
+                        .ok_or(ArithmeticError::Overflow)?
+                } else {
+                    let remaining = pool_info
+                        .period_finish
+                        .checked_sub(&current_block_number)
+                        .ok_or(ArithmeticError::Overflow)?;
+                    let left_over = pool_info
+                        .block_to_balance(remaining)
+                        .checked_mul(pool_info.reward_rate)
+                        .ok_or(ArithmeticError::Overflow)?;
+                    let total = left_over
+                        .checked_add(amount)
+                        .ok_or(ArithmeticError::Overflow)?;
+                    total
+                        .checked_div(duration_balance)
+                        .ok_or(ArithmeticError::Overflow)?
+                };
+
+                let new_period_finish = current_block_number
+                    .checked_add(&reward_duration)
+                    .ok_or(ArithmeticError::Overflow)?;
+
+                pool_info.reward_duration = reward_duration;
+                pool_info.period_finish = new_period_finish;
+                pool_info.reward_rate = reward_rate;
+                pool_info.last_update_block = current_block_number;
+
+                if amount > 0 {
+                    let asset_pool_account = Self::pool_account_id(reward_asset)?;
+                    T::Assets::transfer(reward_asset, payer, &asset_pool_account, amount, false)?;
+                }
+
+                Self::deposit_event(Event::<T>::RewardAdded(
+                    asset,
+                    reward_asset,
+                    lock_duration,
+                    amount,
+                ));
+                Ok(())
+            },
+        )
+    }
+
     fn update_reward(
         who: Option<T::AccountId>,
         asset: AssetIdOf<T>,
@@ -759,25 +744,8 @@ impl<T: Config> Pallet<T> {
                     Positions::<T>::mutate(
                         (&asset, &reward_asset, &lock_duration, &who),
                         |user_position| -> DispatchResult {
-                            let diff = pool_info
-                                .reward_per_share(current_block_number)?
-                                .checked_sub(user_position.reward_per_share_paid)
-                                .ok_or(ArithmeticError::Overflow)?;
-
-                            let earned = user_position
-                                .deposit_balance
-                                .get_big_uint()
-                                .checked_mul(&diff.get_big_uint())
-                                .and_then(|r| {
-                                    r.checked_div(&pool_info.amount_per_share().get_big_uint())
-                                })
-                                .and_then(|r| {
-                                    r.checked_add(&user_position.reward_amount.get_big_uint())
-                                })
-                                .and_then(|r| r.to_u128())
-                                .ok_or(ArithmeticError::Overflow)?;
-
-                            user_position.reward_amount = BalanceOf::<T>::saturated_from(earned);
+                            user_position.reward_amount =
+                                Self::earned(pool_info, user_position, current_block_number)?;
                             user_position.reward_per_share_paid = pool_info.reward_per_share_stored;
 
                             Ok(())
@@ -789,9 +757,170 @@ impl<T: Config> Pallet<T> {
         )
     }
 
+    /// Reward amount of the position which can be claimed at `current_block_number`
+    fn earned(
+        pool_info: &PoolInfo<T::BlockNumber, BalanceOf<T>>,
+        user_position: &UserPosition<
+            BalanceOf<T>,
+            BoundedVec<(BalanceOf<T>, T::BlockNumber), T::MaxUserLockItemsCount>,
+        >,
+        current_block_number: T::BlockNumber,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let diff = pool_info
+            .reward_per_share(current_block_number)?
+            .checked_sub(user_position.reward_per_share_paid)
+            .ok_or(ArithmeticError::Overflow)?;
+
+        let earned = user_position
+            .deposit_balance
+            .get_big_uint()
+            .checked_mul(&diff.get_big_uint())
+            .and_then(|r| r.checked_div(&pool_info.amount_per_share().get_big_uint()))
+            .and_then(|r| r.checked_add(&user_position.reward_amount.get_big_uint()))
+            .and_then(|r| r.to_u128())
+            .ok_or(ArithmeticError::Overflow)?;
+
+        Ok(BalanceOf::<T>::saturated_from(earned))
+    }
+
+    fn do_claim(
+        who: &T::AccountId,
+        asset: AssetIdOf<T>,
+        reward_asset: AssetIdOf<T>,
+        lock_duration: T::BlockNumber,
+    ) -> DispatchResult {
+        ensure!(
+            Pools::<T>::contains_key((&asset, &reward_asset, &lock_duration)),
+            Error::<T>::PoolDoesNotExist
+        );
+
+        Self::update_reward(Some(who.clone()), asset, reward_asset, lock_duration)?;
+
+        let asset_pool_account = Self::pool_account_id(reward_asset)?;
+        Positions::<T>::mutate(
+            (&asset, &reward_asset, &lock_duration, who),
+            |user_position| -> DispatchResult {
+                let reward_amount = user_position.reward_amount;
+                if reward_amount > 0 {
+                    T::Assets::transfer(
+                        reward_asset,
+                        &asset_pool_account,
+                        who,
+                        reward_amount,
+                        false,
+                    )?;
+                    user_position.reward_amount = 0;
+                }
+
+                Self::deposit_event(Event::<T>::RewardPaid(
+                    who.clone(),
+                    asset,
+                    reward_asset,
+                    lock_duration,
+                    reward_amount,
+                ));
+                Ok(())
+            },
+        )
+    }
+
+    /// Rewards of `who` which can be claimed from each pool,
+    /// as `(asset, reward_asset, lock_duration, amount)`
+    pub fn pending_rewards(
+        who: &T::AccountId,
+    ) -> Result<Vec<(AssetIdOf<T>, AssetIdOf<T>, T::BlockNumber, BalanceOf<T>)>, DispatchError>
+    {
+        let current_block_number = <frame_system::Pallet<T>>::block_number();
+        let mut rewards = Vec::new();
+        for ((asset, reward_asset, lock_duration), pool_info) in Pools::<T>::iter() {
+            if !Positions::<T>::contains_key((&asset, &reward_asset, &lock_duration, who)) {
+                continue;
+            }
+            let user_position = Self::positions((&asset, &reward_asset, &lock_duration, who));
+            let earned = Self::earned(&pool_info, &user_position, current_block_number)?;
+            if !earned.is_zero() {
+                rewards.push((asset, reward_asset, lock_duration, earned));
+            }
+        }
+        Ok(rewards)
+    }
+
+    /// Stake of `who` in each pool, as `(asset, reward_asset, lock_duration, deposited, locked)`
+    /// where `locked` is withdrawn but still cooling down before it can be redeemed
+    pub fn account_positions(
+        who: &T::AccountId,
+    ) -> Vec<(
+        AssetIdOf<T>,
+        AssetIdOf<T>,
+        T::BlockNumber,
+        BalanceOf<T>,
+        BalanceOf<T>,
+    )> {
+        let mut positions = Vec::new();
+        for (asset, reward_asset, lock_duration) in Pools::<T>::iter_keys() {
+            if !Positions::<T>::contains_key((&asset, &reward_asset, &lock_duration, who)) {
+                continue;
+            }
+            let user_position = Self::positions((&asset, &reward_asset, &lock_duration, who));
+            let locked = user_position
+                .lock_balance_items
+                .iter()
+                .fold(Zero::zero(), |locked: BalanceOf<T>, (amount, _)| {
+                    locked.saturating_add(*amount)
+                });
+            if user_position.deposit_balance.is_zero() && locked.is_zero() {
+                continue;
+            }
+            positions.push((
+                asset,
+                reward_asset,
+                lock_duration,
+                user_position.deposit_balance,
+                locked,
+            ));
+        }
+        positions
+    }
+
     fn pool_account_id(asset_id: AssetIdOf<T>) -> Result<T::AccountId, DispatchError> {
         let account_id: T::AccountId = T::PalletId::get().into_account_truncating();
         let entropy = (b"modlpy/liquidity", &[account_id], asset_id).using_encoded(blake2_256);
         Ok(T::AccountId::decode(&mut &entropy[..]).map_err(|_| Error::<T>::CodecError)?)
     }
 }
+
+impl<T: Config> RewardDispatcher<T::AccountId, AssetIdOf<T>, BalanceOf<T>, T::BlockNumber>
+    for Pallet<T>
+{
+    fn dispatch_reward(
+        payer: &T::AccountId,
+        asset: AssetIdOf<T>,
+        reward_asset: AssetIdOf<T>,
+        lock_duration: T::BlockNumber,
+        amount: BalanceOf<T>,
+        reward_duration: T::BlockNumber,
+    ) -> DispatchResult {
+        Self::do_dispatch_reward(
+            payer,
+            asset,
+            reward_asset,
+            lock_duration,
+            amount,
+            reward_duration,
+        )
+    }
+}
+
+impl<T: Config> RewardClaimer<T::AccountId> for Pallet<T> {
+    fn claim_rewards(who: &T::AccountId, max_claims: u32) -> Result<u32, DispatchError> {
+        let mut claims = 0;
+        for (asset, reward_asset, lock_duration, _) in Self::pending_rewards(who)? {
+            if claims >= max_claims {
+                break;
+            }
+            Self::do_claim(who, asset, reward_asset, lock_duration)?;
+            claims += 1;
+        }
+        Ok(claims)
+    }
+}
@@ -754,6 +754,90 @@ fn pool_claim_precision_work() {
     })
 }
 
+#[test]
+fn pending_rewards_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Farming::deposit(
+            RawOrigin::Signed(ALICE).into(),
+            STAKE_TOKEN,
+            REWARD_TOKEN,
+            LOCK_DURATION,
+            100_000_000,
+        ));
+        assert_eq!(Farming::pending_rewards(&ALICE), Ok(vec![]));
+
+        run_to_block(10);
+        assert_ok!(Farming::dispatch_reward(
+            RuntimeOrigin::root(),
+            STAKE_TOKEN,
+            REWARD_TOKEN,
+            LOCK_DURATION,
+            REWARD_TOKEN_PAYER,
+            1_000_000_000_000_000,
+            100,
+        ));
+
+        run_to_block(60);
+        assert_eq!(
+            Farming::pending_rewards(&ALICE),
+            Ok(vec![(
+                STAKE_TOKEN,
+                REWARD_TOKEN,
+                LOCK_DURATION,
+                500_000_000_000_000
+            )])
+        );
+        assert_eq!(Farming::pending_rewards(&BOB), Ok(vec![]));
+
+        assert_ok!(Farming::claim(
+            RawOrigin::Signed(ALICE).into(),
+            STAKE_TOKEN,
+            REWARD_TOKEN,
+            LOCK_DURATION,
+        ));
+        assert_eq!(Farming::pending_rewards(&ALICE), Ok(vec![]));
+    })
+}
+
+#[test]
+fn account_positions_work() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Farming::account_positions(&BOB), vec![]);
+
+        assert_ok!(Farming::deposit(
+            RawOrigin::Signed(BOB).into(),
+            STAKE_TOKEN,
+            REWARD_TOKEN,
+            LOCK_DURATION,
+            100_000_000,
+        ));
+        assert_eq!(
+            Farming::account_positions(&BOB),
+            vec![(STAKE_TOKEN, REWARD_TOKEN, LOCK_DURATION, 100_000_000, 0)]
+        );
+
+        run_to_block(10);
+        assert_ok!(Farming::withdraw(
+            RawOrigin::Signed(BOB).into(),
+            STAKE_TOKEN,
+            REWARD_TOKEN,
+            LOCK_DURATION,
+            20_000_000,
+        ));
+        assert_eq!(
+            Farming::account_positions(&BOB),
+            vec![(
+                STAKE_TOKEN,
+                REWARD_TOKEN,
+                LOCK_DURATION,
+                80_000_000,
+                20_000_000
+            )]
+        );
+        assert_eq!(Farming::account_positions(&ALICE), vec![]);
+    })
+}
+
 #[test]
 fn pool_complicated_scene0_work() {
     new_test_ext().execute_with(|| {
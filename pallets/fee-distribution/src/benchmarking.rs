@@ -0,0 +1,66 @@
+//! Fee distribution pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as FeeDistribution;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::assert_ok;
+use frame_system::RawOrigin as SystemOrigin;
+use primitives::Ratio;
+
+const INITIAL_AMOUNT: u128 = 100_000_000_000_000;
+const VOLUME: u128 = 1_000_000_000_000;
+
+fn split() -> FeeSplit {
+    FeeSplit {
+        burn: Ratio::from_percent(10),
+        treasury: Ratio::from_percent(50),
+        collator: Ratio::from_percent(20),
+        rebate: Ratio::from_percent(20),
+    }
+}
+
+fn rebate_params() -> RebateParams {
+    RebateParams {
+        min_volume: VOLUME,
+        ratio: Ratio::from_percent(10),
+    }
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    set_fee_split {
+    }: _(SystemOrigin::Root, split())
+    verify {
+        assert_last_event::<T>(Event::<T>::FeeSplitUpdated(split()).into());
+    }
+
+    set_rebate_params {
+    }: _(SystemOrigin::Root, Some(rebate_params()))
+    verify {
+        assert_last_event::<T>(Event::<T>::RebateParamsUpdated(Some(rebate_params())).into());
+    }
+
+    claim_rebate {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&FeeDistribution::<T>::account_id(), INITIAL_AMOUNT);
+        assert_ok!(FeeDistribution::<T>::set_rebate_params(SystemOrigin::Root.into(), Some(rebate_params())));
+        frame_system::Pallet::<T>::set_block_number(Zero::zero());
+        FeeDistribution::<T>::note_fee(&caller, VOLUME);
+        frame_system::Pallet::<T>::set_block_number(T::RebatePeriod::get());
+        let amount = rebate_params().ratio.mul_floor(VOLUME);
+    }: _(SystemOrigin::Signed(caller.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::RebateClaimed(caller, amount).into());
+    }
+}
+
+impl_benchmark_test_suite!(
+    FeeDistribution,
+    crate::mock::new_test_ext(),
+    crate::mock::Test
+);
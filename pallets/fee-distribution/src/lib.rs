@@ -0,0 +1,277 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Fee Distribution pallet
+//!
+//! ## Overview
+//!
+//! Transaction fees are split between burning, the treasury, the block author and a
+//! rebate pool by ratios set by governance. Accounts which paid enough fees in a rebate
+//! period can claim part of them back from the pool during the following period.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Currency, ExistenceRequirement::AllowDeath, Imbalance, OnUnbalanced},
+    transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+use pallet_transaction_payment::OnChargeTransaction;
+use primitives::Balance;
+use sp_runtime::{
+    traits::{AccountIdConversion, DispatchInfoOf, PostDispatchInfoOf, Zero},
+    transaction_validity::TransactionValidityError,
+};
+use sp_std::marker::PhantomData;
+
+pub use pallet::*;
+pub use types::{FeeSplit, FeeVolume, RebateParams};
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+    <T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The currency fees are paid with
+        type Currency: Currency<Self::AccountId, Balance = Balance>;
+
+        /// Receives the treasury share of the fees
+        type Treasury: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Author of the current block
+        type BlockAuthor: Get<Option<Self::AccountId>>;
+
+        /// Pallet account holding the rebate pool
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Number of blocks fee volumes are accumulated over
+        #[pallet::constant]
+        type RebatePeriod: Get<Self::BlockNumber>;
+
+        /// The origin which can update the fee split and rebate params
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Fee split was updated
+        /// [split]
+        FeeSplitUpdated(FeeSplit),
+        /// Rebate params were updated, `None` disables rebates
+        /// [params]
+        RebateParamsUpdated(Option<RebateParams>),
+        /// Rebate was paid from the pool
+        /// [who, amount]
+        RebateClaimed(T::AccountId, Balance),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Ratios of the fee split don't sum up to one
+        InvalidFeeSplit,
+        /// Rebates are disabled
+        RebateDisabled,
+        /// Rebate of the previous period was already claimed
+        RebateAlreadyClaimed,
+        /// Fees paid in the previous period are below the min volume
+        InsufficientFeeVolume,
+    }
+
+    /// How collected transaction fees are split
+    #[pallet::storage]
+    #[pallet::getter(fn fee_split)]
+    pub type FeeSplitConfig<T: Config> = StorageValue<_, FeeSplit, ValueQuery>;
+
+    /// Rebate params, fee volumes are not tracked if not set
+    #[pallet::storage]
+    #[pallet::getter(fn rebate_params)]
+    pub type RebateConfig<T: Config> = StorageValue<_, RebateParams, OptionQuery>;
+
+    /// Fees paid by accounts
+    /// AccountId -> FeeVolume
+    #[pallet::storage]
+    #[pallet::getter(fn fee_volume)]
+    pub type FeeVolumes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, FeeVolume<T::BlockNumber>, ValueQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set how collected transaction fees are split
+        #[pallet::weight(<T as Config>::WeightInfo::set_fee_split())]
+        #[transactional]
+        pub fn set_fee_split(origin: OriginFor<T>, split: FeeSplit) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(split.is_valid(), Error::<T>::InvalidFeeSplit);
+
+            FeeSplitConfig::<T>::put(split);
+            Self::deposit_event(Event::<T>::FeeSplitUpdated(split));
+            Ok(())
+        }
+
+        /// Set the rebate params, or disable rebates
+        #[pallet::weight(<T as Config>::WeightInfo::set_rebate_params())]
+        #[transactional]
+        pub fn set_rebate_params(
+            origin: OriginFor<T>,
+            params: Option<RebateParams>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            RebateConfig::<T>::set(params);
+            Self::deposit_event(Event::<T>::RebateParamsUpdated(params));
+            Ok(())
+        }
+
+        /// Claim the rebate of the fees paid in the previous period
+        #[pallet::weight(<T as Config>::WeightInfo::claim_rebate())]
+        #[transactional]
+        pub fn claim_rebate(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let params = Self::rebate_params().ok_or(Error::<T>::RebateDisabled)?;
+
+            let mut volume = Self::fee_volume(&who);
+            volume.roll(Self::current_period());
+            ensure!(!volume.claimed, Error::<T>::RebateAlreadyClaimed);
+            ensure!(
+                !volume.previous.is_zero() && volume.previous >= params.min_volume,
+                Error::<T>::InsufficientFeeVolume
+            );
+
+            let amount = params.ratio.mul_floor(volume.previous);
+            T::Currency::transfer(&Self::account_id(), &who, amount, AllowDeath)?;
+            volume.claimed = true;
+            FeeVolumes::<T>::insert(&who, volume);
+
+            Self::deposit_event(Event::<T>::RebateClaimed(who, amount));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Account of the rebate pool
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    pub fn current_period() -> T::BlockNumber {
+        frame_system::Pallet::<T>::block_number() / T::RebatePeriod::get()
+    }
+
+    /// Add `fee` paid by `who` to its volume of the current period
+    pub fn note_fee(who: &T::AccountId, fee: Balance) {
+        if fee.is_zero() || Self::rebate_params().is_none() {
+            return;
+        }
+        let period = Self::current_period();
+        FeeVolumes::<T>::mutate(who, |volume| {
+            volume.roll(period);
+            volume.current = volume.current.saturating_add(fee);
+        });
+    }
+}
+
+/// Splits the fees according to `FeeSplitConfig`
+pub struct SplitFees<T>(PhantomData<T>);
+impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for SplitFees<T> {
+    fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<T>) {
+        let split = Pallet::<T>::fee_split();
+        let total = amount.peek();
+        let (burn, rest) = amount.split(split.burn.mul_floor(total));
+        let (collator, rest) = rest.split(split.collator.mul_floor(total));
+        let (rebate, treasury) = rest.split(split.rebate.mul_floor(total));
+
+        // Dropping the imbalance reduces the total issuance
+        drop(burn);
+        let treasury = match T::BlockAuthor::get() {
+            Some(author) => {
+                T::Currency::resolve_creating(&author, collator);
+                treasury
+            }
+            None => treasury.merge(collator),
+        };
+        T::Currency::resolve_creating(&Pallet::<T>::account_id(), rebate);
+        T::Treasury::on_unbalanced(treasury);
+    }
+}
+
+/// Charges fees with `Inner` and records the fees paid by each account for rebates
+pub struct TrackFeeVolume<T, Inner>(PhantomData<(T, Inner)>);
+impl<T, Inner> OnChargeTransaction<T> for TrackFeeVolume<T, Inner>
+where
+    T: Config + pallet_transaction_payment::Config,
+    Inner: OnChargeTransaction<T, Balance = Balance>,
+{
+    type Balance = Balance;
+    type LiquidityInfo = Inner::LiquidityInfo;
+
+    fn withdraw_fee(
+        who: &T::AccountId,
+        call: &T::RuntimeCall,
+        dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+        fee: Balance,
+        tip: Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        Inner::withdraw_fee(who, call, dispatch_info, fee, tip)
+    }
+
+    fn correct_and_deposit_fee(
+        who: &T::AccountId,
+        dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+        post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+        corrected_fee: Balance,
+        tip: Balance,
+        already_withdrawn: Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        Inner::correct_and_deposit_fee(
+            who,
+            dispatch_info,
+            post_info,
+            corrected_fee,
+            tip,
+            already_withdrawn,
+        )?;
+        Pallet::<T>::note_fee(who, corrected_fee);
+        Ok(())
+    }
+}
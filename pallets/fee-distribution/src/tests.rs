@@ -0,0 +1,159 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::WithdrawReasons};
+use primitives::Ratio;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn withdraw_fee(who: &AccountId, amount: Balance) -> NegativeImbalanceOf<Test> {
+    Balances::withdraw(who, amount, WithdrawReasons::FEE, AllowDeath).unwrap()
+}
+
+fn split(burn: u32, treasury: u32, collator: u32, rebate: u32) -> FeeSplit {
+    FeeSplit {
+        burn: Ratio::from_percent(burn),
+        treasury: Ratio::from_percent(treasury),
+        collator: Ratio::from_percent(collator),
+        rebate: Ratio::from_percent(rebate),
+    }
+}
+
+#[test]
+fn fees_should_go_to_treasury_by_default() {
+    new_test_ext().execute_with(|| {
+        SplitFees::<Test>::on_unbalanced(withdraw_fee(&ALICE, 1_000));
+        assert_eq!(Balances::free_balance(TREASURY), 1_000);
+        assert_eq!(Balances::free_balance(COLLATOR), 0);
+    });
+}
+
+#[test]
+fn set_fee_split_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            FeeDistribution::set_fee_split(RuntimeOrigin::signed(ALICE), split(0, 100, 0, 0)),
+            BadOrigin
+        );
+        assert_noop!(
+            FeeDistribution::set_fee_split(RuntimeOrigin::root(), split(10, 50, 20, 10)),
+            Error::<Test>::InvalidFeeSplit
+        );
+        assert_ok!(FeeDistribution::set_fee_split(
+            RuntimeOrigin::root(),
+            split(10, 50, 20, 20)
+        ));
+        assert_eq!(FeeDistribution::fee_split(), split(10, 50, 20, 20));
+        System::assert_last_event(RuntimeEvent::FeeDistribution(Event::FeeSplitUpdated(
+            split(10, 50, 20, 20),
+        )));
+    });
+}
+
+#[test]
+fn fees_should_be_split() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(FeeDistribution::set_fee_split(
+            RuntimeOrigin::root(),
+            split(10, 50, 20, 20)
+        ));
+        let issuance = Balances::total_issuance();
+        SplitFees::<Test>::on_unbalanced(withdraw_fee(&ALICE, 1_001));
+
+        // rounding leftovers go to the treasury
+        assert_eq!(Balances::free_balance(TREASURY), 501);
+        assert_eq!(Balances::free_balance(COLLATOR), 200);
+        assert_eq!(
+            Balances::free_balance(FeeDistribution::account_id()),
+            1_000_000 + 200
+        );
+        assert_eq!(Balances::total_issuance(), issuance - 100);
+
+        // collator share goes to the treasury without block author
+        set_block_author(None);
+        SplitFees::<Test>::on_unbalanced(withdraw_fee(&ALICE, 1_000));
+        assert_eq!(Balances::free_balance(TREASURY), 501 + 700);
+        assert_eq!(Balances::free_balance(COLLATOR), 200);
+    });
+}
+
+#[test]
+fn fee_volume_should_only_be_tracked_with_rebates() {
+    new_test_ext().execute_with(|| {
+        FeeDistribution::note_fee(&ALICE, 1_000);
+        assert_eq!(FeeDistribution::fee_volume(ALICE), Default::default());
+
+        assert_ok!(FeeDistribution::set_rebate_params(
+            RuntimeOrigin::root(),
+            Some(RebateParams {
+                min_volume: 1_000,
+                ratio: Ratio::from_percent(10),
+            })
+        ));
+        FeeDistribution::note_fee(&ALICE, 1_000);
+        FeeDistribution::note_fee(&ALICE, 500);
+        assert_eq!(FeeDistribution::fee_volume(ALICE).current, 1_500);
+
+        // volumes of periods before the previous one are dropped
+        System::set_block_number(301);
+        FeeDistribution::note_fee(&ALICE, 10);
+        assert_eq!(
+            FeeDistribution::fee_volume(ALICE),
+            FeeVolume {
+                period: 3,
+                current: 10,
+                previous: 0,
+                claimed: false,
+            }
+        );
+    });
+}
+
+#[test]
+fn claim_rebate_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            FeeDistribution::claim_rebate(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::RebateDisabled
+        );
+        assert_ok!(FeeDistribution::set_rebate_params(
+            RuntimeOrigin::root(),
+            Some(RebateParams {
+                min_volume: 1_000,
+                ratio: Ratio::from_percent(10),
+            })
+        ));
+        FeeDistribution::note_fee(&ALICE, 2_000);
+        FeeDistribution::note_fee(&BOB, 999);
+
+        // only fees of the previous period are rebated
+        assert_noop!(
+            FeeDistribution::claim_rebate(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::InsufficientFeeVolume
+        );
+        System::set_block_number(101);
+        assert_noop!(
+            FeeDistribution::claim_rebate(RuntimeOrigin::signed(BOB)),
+            Error::<Test>::InsufficientFeeVolume
+        );
+        assert_ok!(FeeDistribution::claim_rebate(RuntimeOrigin::signed(ALICE)));
+        assert_eq!(Balances::free_balance(ALICE), 1_000_000 + 200);
+        assert_eq!(
+            Balances::free_balance(FeeDistribution::account_id()),
+            1_000_000 - 200
+        );
+        System::assert_last_event(RuntimeEvent::FeeDistribution(Event::RebateClaimed(
+            ALICE, 200,
+        )));
+        assert_noop!(
+            FeeDistribution::claim_rebate(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::RebateAlreadyClaimed
+        );
+
+        // rebate can't be claimed after the following period
+        FeeDistribution::note_fee(&ALICE, 2_000);
+        System::set_block_number(301);
+        assert_noop!(
+            FeeDistribution::claim_rebate(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::InsufficientFeeVolume
+        );
+    });
+}
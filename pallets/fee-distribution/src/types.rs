@@ -0,0 +1,78 @@
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use primitives::{Balance, Ratio};
+use scale_info::TypeInfo;
+use sp_runtime::traits::{One, Saturating, Zero};
+
+/// How collected transaction fees are split, the ratios must sum up to one
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct FeeSplit {
+    /// Burned, i.e. removed from the total issuance
+    pub burn: Ratio,
+    /// Sent to the treasury, which also receives the rounding leftovers
+    pub treasury: Ratio,
+    /// Sent to the author of the block, or the treasury if there is none
+    pub collator: Ratio,
+    /// Sent to the rebate pool
+    pub rebate: Ratio,
+}
+
+impl Default for FeeSplit {
+    fn default() -> Self {
+        Self {
+            burn: Zero::zero(),
+            treasury: One::one(),
+            collator: Zero::zero(),
+            rebate: Zero::zero(),
+        }
+    }
+}
+
+impl FeeSplit {
+    pub fn is_valid(&self) -> bool {
+        [self.burn, self.treasury, self.collator, self.rebate]
+            .iter()
+            .map(|ratio| ratio.deconstruct() as u64)
+            .sum::<u64>()
+            == Ratio::one().deconstruct() as u64
+    }
+}
+
+/// Which accounts get a rebate from the pool and how much
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RebateParams {
+    /// Min fees paid by an account in a period to get a rebate
+    pub min_volume: Balance,
+    /// Share of the fees paid in a period that is given back
+    pub ratio: Ratio,
+}
+
+/// Fees paid by an account in the current and previous rebate periods
+#[derive(Clone, Copy, PartialEq, Eq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct FeeVolume<BlockNumber> {
+    /// Rebate period `current` belongs to
+    pub period: BlockNumber,
+    /// Fees paid in the current period
+    pub current: Balance,
+    /// Fees paid in the previous period
+    pub previous: Balance,
+    /// Whether the rebate of the previous period was claimed
+    pub claimed: bool,
+}
+
+impl<BlockNumber: Copy + PartialOrd + One + Saturating> FeeVolume<BlockNumber> {
+    /// Move the volume to `period` if it belongs to an earlier one
+    pub fn roll(&mut self, period: BlockNumber) {
+        if self.period >= period {
+            return;
+        }
+        self.previous = if self.period.saturating_add(One::one()) == period {
+            self.current
+        } else {
+            Zero::zero()
+        };
+        self.current = Zero::zero();
+        self.claimed = false;
+        self.period = period;
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Forwarder pallet
+//!
+//! ## Overview
+//!
+//! Relayers submit calls signed off-chain by users and pay their fees, so that sponsors
+//! can onboard users who don't hold any native token yet. Users sign with the key of their
+//! substrate account or with `eth_sign` for the account mapped to their EVM address.
+//!
+//! Forwarded calls don't use the account nonce. Each user has as many channels as needed,
+//! each with its own nonce, so calls relayed in parallel don't invalidate one another. The
+//! signature also covers a deadline after which the call can't be relayed anymore.
+//!
+//! Signed payloads start with [`FORWARD_PREFIX`] and the genesis hash of the chain, so that
+//! signatures can't be replayed on another chain or by another pallet checking signatures
+//! of the same keys.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Encode;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    pallet_prelude::*,
+    traits::Contains,
+};
+use frame_system::pallet_prelude::*;
+use pallet_evm::AddressMapping;
+use sp_runtime::traits::{Dispatchable, IdentifyAccount, Verify, Zero};
+use sp_std::{boxed::Box, vec::Vec};
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub mod weights;
+
+pub use types::{ChannelId, ChannelNonce, ForwardSigner};
+pub use weights::WeightInfo;
+
+/// Tag of the payloads signed to forward a call
+pub const FORWARD_PREFIX: &[u8] = b"parallel:forward:";
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The calls users sign
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo;
+
+        /// Signature of the substrate accounts
+        type Signature: Parameter + Verify<Signer = Self::Signer>;
+
+        /// Signer of the substrate accounts
+        type Signer: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// Mapping from EVM address to account id
+        type AddressMapping: AddressMapping<Self::AccountId>;
+
+        /// Calls which can be forwarded
+        type CallFilter: Contains<<Self as Config>::RuntimeCall>;
+
+        /// Prefix of the signed payloads, so that they can't be replayed by other
+        /// pallets verifying signatures of the same keys
+        #[pallet::constant]
+        type ForwardMagicNumber: Get<u16>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A relayer forwarded the call of a user
+        /// [relayer, who, channel, nonce, result]
+        Forwarded(
+            T::AccountId,
+            T::AccountId,
+            ChannelId,
+            ChannelNonce,
+            DispatchResult,
+        ),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Call can't be forwarded
+        CallNotAllowed,
+        /// Deadline of the call passed
+        Expired,
+        /// Nonce isn't the next one of the channel
+        BadNonce,
+        /// Signature doesn't match the signer
+        InvalidSignature,
+    }
+
+    /// Next nonce of each channel of the users
+    /// AccountId, ChannelId -> ChannelNonce
+    #[pallet::storage]
+    #[pallet::getter(fn channel_nonce)]
+    pub type ChannelNonces<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        ChannelId,
+        ChannelNonce,
+        ValueQuery,
+    >;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Dispatch the `call` signed by a user, the relayer pays the fees.
+        ///
+        /// The nonce of the channel is used up even if the call fails.
+        ///
+        /// - `call`: the call signed by the user.
+        /// - `signer`: the user, along with the signature.
+        /// - `channel`: the channel of the user.
+        /// - `nonce`: the next nonce of the channel.
+        /// - `deadline`: the last block the call can be dispatched in.
+        #[pallet::weight({
+            let dispatch_info = call.get_dispatch_info();
+            (
+                <T as Config>::WeightInfo::forward().saturating_add(dispatch_info.weight),
+                dispatch_info.class,
+            )
+        })]
+        pub fn forward(
+            origin: OriginFor<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+            signer: ForwardSigner<T::AccountId, T::Signature>,
+            channel: ChannelId,
+            #[pallet::compact] nonce: ChannelNonce,
+            deadline: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+            ensure!(T::CallFilter::contains(&call), Error::<T>::CallNotAllowed);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= deadline,
+                Error::<T>::Expired
+            );
+
+            let who = Self::signer_account(&signer);
+            ensure!(
+                nonce == Self::channel_nonce(&who, channel),
+                Error::<T>::BadNonce
+            );
+            ensure!(
+                Self::valid_signature(&call, &signer, channel, nonce, deadline),
+                Error::<T>::InvalidSignature
+            );
+            ChannelNonces::<T>::insert(&who, channel, nonce.saturating_add(1));
+
+            let res = call.dispatch(frame_system::RawOrigin::Signed(who.clone()).into());
+            let post_info = res.unwrap_or_else(|e| e.post_info);
+            Self::deposit_event(Event::<T>::Forwarded(
+                relayer,
+                who,
+                channel,
+                nonce,
+                res.map(|_| ()).map_err(|e| e.error),
+            ));
+
+            Ok(post_info
+                .actual_weight
+                .map(|weight| weight.saturating_add(<T as Config>::WeightInfo::forward()))
+                .into())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Account the call of `signer` is dispatched from
+    pub fn signer_account(signer: &ForwardSigner<T::AccountId, T::Signature>) -> T::AccountId {
+        match signer {
+            ForwardSigner::Substrate(who, _) => who.clone(),
+            ForwardSigner::Ethereum(address, _) => T::AddressMapping::into_account_id(*address),
+        }
+    }
+
+    /// Payload users sign to forward `call`
+    pub fn signed_payload(
+        who: &T::AccountId,
+        call: &<T as Config>::RuntimeCall,
+        channel: ChannelId,
+        nonce: ChannelNonce,
+        deadline: T::BlockNumber,
+    ) -> Vec<u8> {
+        let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+        let mut payload = FORWARD_PREFIX.to_vec();
+        payload.extend(
+            (
+                genesis_hash,
+                T::ForwardMagicNumber::get(),
+                who,
+                channel,
+                nonce,
+                deadline,
+                call,
+            )
+                .encode(),
+        );
+        payload
+    }
+
+    fn valid_signature(
+        call: &<T as Config>::RuntimeCall,
+        signer: &ForwardSigner<T::AccountId, T::Signature>,
+        channel: ChannelId,
+        nonce: ChannelNonce,
+        deadline: T::BlockNumber,
+    ) -> bool {
+        let who = Self::signer_account(signer);
+        let payload = Self::signed_payload(&who, call, channel, nonce, deadline);
+        match signer {
+            ForwardSigner::Substrate(who, signature) => signature.verify(&payload[..], who),
+            ForwardSigner::Ethereum(address, signature) => {
+                signature.recover_address(&payload[..]) == Some(*address)
+            }
+        }
+    }
+}
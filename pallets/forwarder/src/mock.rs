@@ -0,0 +1,176 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use pallet_evm_signatures::ethereum::{signable_message, EthereumSignature};
+use sp_core::{H160, H256};
+use sp_io::hashing::keccak_256;
+use sp_keyring::AccountKeyring as Keyring;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    MultiSignature,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Forwarder: crate::{Pallet, Storage, Call, Event<T>},
+    }
+);
+
+pub type Signature = MultiSignature;
+pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+pub type Balance = u128;
+pub type BlockNumber = u64;
+
+/// Key of an EVM address, also used by the tests of `pallet-evm-signatures`
+pub const ECDSA_SEED: [u8; 32] =
+    hex_literal::hex!["7e9c7ad85df5cdc88659f53e06fb2eb9bab3ebc59083a3190eaf2c730332529c"];
+
+pub const MAGIC_NUMBER: u16 = 0xff51;
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+}
+
+/// Only balance transfers can be forwarded
+pub struct ForwardableCalls;
+impl Contains<RuntimeCall> for ForwardableCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::Balances(_))
+    }
+}
+
+parameter_types! {
+    pub const ForwardMagicNumber: u16 = MAGIC_NUMBER;
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Signature = Signature;
+    type Signer = <Signature as Verify>::Signer;
+    type AddressMapping = pallet_evm::HashedAddressMapping<BlakeTwo256>;
+    type CallFilter = ForwardableCalls;
+    type ForwardMagicNumber = ForwardMagicNumber;
+    type WeightInfo = ();
+}
+
+/// Ethereum address of the key
+pub fn eth_address(seed: &[u8; 32]) -> H160 {
+    let secret = libsecp256k1::SecretKey::parse(seed).expect("valid seed");
+    let public = libsecp256k1::PublicKey::from_secret_key(&secret).serialize();
+    H160::from_slice(&keccak_256(&public[1..])[12..])
+}
+
+/// Signs `data` the way `eth_sign` does
+pub fn eth_sign(seed: &[u8; 32], data: &[u8]) -> EthereumSignature {
+    let message = libsecp256k1::Message::parse(&keccak_256(&signable_message(data)));
+    let secret = libsecp256k1::SecretKey::parse(seed).expect("valid seed");
+    let (signature, recovery_id) = libsecp256k1::sign(&message, &secret);
+    let mut out = [0u8; 65];
+    out[..64].copy_from_slice(&signature.serialize()[..]);
+    out[64] = recovery_id.serialize() + 27;
+    EthereumSignature(out)
+}
+
+pub fn alice() -> AccountId {
+    Keyring::Alice.into()
+}
+
+pub fn bob() -> AccountId {
+    Keyring::Bob.into()
+}
+
+pub fn relayer() -> AccountId {
+    Keyring::Charlie.into()
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (alice(), 1_000_000),
+            (relayer(), 1_000_000),
+            (
+                <Test as Config>::AddressMapping::into_account_id(eth_address(&ECDSA_SEED)),
+                1_000_000,
+            ),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
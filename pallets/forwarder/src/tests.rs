@@ -0,0 +1,193 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_keyring::AccountKeyring as Keyring;
+use sp_runtime::DispatchError;
+
+fn transfer(value: Balance) -> RuntimeCall {
+    pallet_balances::Call::<Test>::transfer { dest: bob(), value }.into()
+}
+
+fn substrate_signer(
+    keyring: Keyring,
+    call: &RuntimeCall,
+    channel: ChannelId,
+    nonce: ChannelNonce,
+    deadline: BlockNumber,
+) -> ForwardSigner<AccountId, Signature> {
+    let who: AccountId = keyring.into();
+    let payload = Forwarder::signed_payload(&who, call, channel, nonce, deadline);
+    ForwardSigner::Substrate(who, keyring.sign(&payload).into())
+}
+
+fn forward(
+    call: RuntimeCall,
+    signer: ForwardSigner<AccountId, Signature>,
+    channel: ChannelId,
+    nonce: ChannelNonce,
+    deadline: BlockNumber,
+) -> DispatchResultWithPostInfo {
+    Forwarder::forward(
+        RuntimeOrigin::signed(relayer()),
+        Box::new(call),
+        signer,
+        channel,
+        nonce,
+        deadline,
+    )
+}
+
+#[test]
+fn substrate_signed_calls_are_forwarded() {
+    new_test_ext().execute_with(|| {
+        let call = transfer(1_000);
+        let signer = substrate_signer(Keyring::Alice, &call, 0, 0, 10);
+        assert_ok!(forward(call, signer, 0, 0, 10));
+
+        assert_eq!(Balances::free_balance(bob()), 1_000);
+        assert_eq!(Balances::free_balance(alice()), 999_000);
+        assert_eq!(Forwarder::channel_nonce(alice(), 0), 1);
+        // The account nonce is left alone
+        assert_eq!(System::account_nonce(alice()), 0);
+        System::assert_last_event(RuntimeEvent::Forwarder(Event::Forwarded(
+            relayer(),
+            alice(),
+            0,
+            0,
+            Ok(()),
+        )));
+    });
+}
+
+#[test]
+fn ethereum_signed_calls_are_forwarded() {
+    new_test_ext().execute_with(|| {
+        let address = eth_address(&ECDSA_SEED);
+        let who = <Test as Config>::AddressMapping::into_account_id(address);
+        let call = transfer(1_000);
+        let payload = Forwarder::signed_payload(&who, &call, 0, 0, 10);
+        let signer = ForwardSigner::Ethereum(address, eth_sign(&ECDSA_SEED, &payload));
+        assert_ok!(forward(call, signer, 0, 0, 10));
+
+        assert_eq!(Balances::free_balance(bob()), 1_000);
+        assert_eq!(Balances::free_balance(who.clone()), 999_000);
+        assert_eq!(Forwarder::channel_nonce(who, 0), 1);
+    });
+}
+
+#[test]
+fn channels_are_replay_protected_independently() {
+    new_test_ext().execute_with(|| {
+        let call = transfer(1_000);
+        let first = substrate_signer(Keyring::Alice, &call, 0, 0, 10);
+        let second = substrate_signer(Keyring::Alice, &call, 1, 0, 10);
+        assert_ok!(forward(call.clone(), second, 1, 0, 10));
+        assert_ok!(forward(call.clone(), first.clone(), 0, 0, 10));
+
+        assert_noop!(
+            forward(call.clone(), first, 0, 0, 10),
+            Error::<Test>::BadNonce
+        );
+        let next = substrate_signer(Keyring::Alice, &call, 0, 1, 10);
+        assert_ok!(forward(call, next, 0, 1, 10));
+        assert_eq!(Balances::free_balance(bob()), 3_000);
+    });
+}
+
+#[test]
+fn signature_must_cover_the_forwarded_call() {
+    new_test_ext().execute_with(|| {
+        let signer = substrate_signer(Keyring::Alice, &transfer(1_000), 0, 0, 10);
+        assert_noop!(
+            forward(transfer(2_000), signer.clone(), 0, 0, 10),
+            Error::<Test>::InvalidSignature
+        );
+        // The deadline is signed as well
+        assert_noop!(
+            forward(transfer(1_000), signer, 0, 0, 20),
+            Error::<Test>::InvalidSignature
+        );
+
+        let payload = Forwarder::signed_payload(&alice(), &transfer(1_000), 0, 0, 10);
+        let signer = ForwardSigner::Substrate(alice(), Keyring::Bob.sign(&payload).into());
+        assert_noop!(
+            forward(transfer(1_000), signer, 0, 0, 10),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn signatures_are_bound_to_the_chain_and_the_pallet() {
+    new_test_ext().execute_with(|| {
+        let call = transfer(1_000);
+        let payload = Forwarder::signed_payload(&alice(), &call, 0, 0, 10);
+        assert!(payload.starts_with(FORWARD_PREFIX));
+
+        // Signed for a chain with another genesis
+        let signer = substrate_signer(Keyring::Alice, &call, 0, 0, 10);
+        let genesis_hash = System::block_hash(0);
+        frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+        assert_noop!(
+            forward(call.clone(), signer.clone(), 0, 0, 10),
+            Error::<Test>::InvalidSignature
+        );
+
+        frame_system::BlockHash::<Test>::insert(0, genesis_hash);
+        assert_ok!(forward(call, signer, 0, 0, 10));
+    });
+}
+
+#[test]
+fn expired_or_filtered_calls_are_rejected() {
+    new_test_ext().execute_with(|| {
+        let call = transfer(1_000);
+        let signer = substrate_signer(Keyring::Alice, &call, 0, 0, 10);
+        System::set_block_number(11);
+        assert_noop!(forward(call, signer, 0, 0, 10), Error::<Test>::Expired);
+
+        let call: RuntimeCall = frame_system::Call::<Test>::remark { remark: vec![] }.into();
+        let signer = substrate_signer(Keyring::Alice, &call, 0, 0, 20);
+        assert_noop!(
+            forward(call, signer, 0, 0, 20),
+            Error::<Test>::CallNotAllowed
+        );
+    });
+}
+
+#[test]
+fn failed_calls_use_up_the_nonce() {
+    new_test_ext().execute_with(|| {
+        let call = transfer(2_000_000);
+        let signer = substrate_signer(Keyring::Alice, &call, 0, 0, 10);
+        assert_ok!(forward(call, signer, 0, 0, 10));
+
+        assert_eq!(Forwarder::channel_nonce(alice(), 0), 1);
+        assert!(matches!(
+            System::events().last().map(|record| record.event.clone()),
+            Some(RuntimeEvent::Forwarder(Event::Forwarded(
+                _,
+                _,
+                0,
+                0,
+                Err(DispatchError::Module(_))
+            )))
+        ));
+    });
+}
@@ -0,0 +1,34 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use pallet_evm_signatures::ethereum::EthereumSignature;
+use scale_info::TypeInfo;
+use sp_core::H160;
+use sp_runtime::RuntimeDebug;
+
+/// Independent sequence of forwarded calls of an account
+pub type ChannelId = u32;
+
+/// Position of a forwarded call in its channel
+pub type ChannelNonce = u64;
+
+/// User who signed a forwarded call, along with the signature
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ForwardSigner<AccountId, Signature> {
+    /// Substrate account, signed with its own key
+    Substrate(AccountId, Signature),
+    /// Account mapped to the EVM address, signed with `eth_sign`
+    Ethereum(H160, EthereumSignature),
+}
@@ -0,0 +1,74 @@
+//! Grant vesting pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as GrantVesting;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::assert_ok;
+use frame_system::RawOrigin as SystemOrigin;
+use sp_runtime::traits::One;
+
+const INITIAL_AMOUNT: u128 = 100_000_000_000_000;
+const PER_PERIOD: u128 = 1_000_000_000_000;
+const PERIOD_COUNT: u32 = 10;
+
+fn create_grant<T: Config>(grantor: &T::AccountId, dest: &T::AccountId) {
+    assert_ok!(GrantVesting::<T>::grant(
+        SystemOrigin::Signed(grantor.clone()).into(),
+        dest.clone(),
+        One::one(),
+        One::one(),
+        PERIOD_COUNT,
+        PER_PERIOD,
+        true,
+    ));
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    grant {
+        let caller: T::AccountId = whitelisted_caller();
+        let dest: T::AccountId = account("dest", 0, 0);
+        T::Currency::make_free_balance_be(&caller, INITIAL_AMOUNT);
+        for _ in 1..T::MaxGrants::get() {
+            create_grant::<T>(&caller, &dest);
+        }
+        let grant_id = GrantVesting::<T>::next_grant_id();
+    }: _(SystemOrigin::Signed(caller.clone()), dest.clone(), One::one(), One::one(), PERIOD_COUNT, PER_PERIOD, true)
+    verify {
+        assert_last_event::<T>(Event::<T>::GrantCreated(caller, dest, grant_id, PER_PERIOD * PERIOD_COUNT as u128).into());
+    }
+
+    claim {
+        let grantor: T::AccountId = account("grantor", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&grantor, INITIAL_AMOUNT);
+        for _ in 0..T::MaxGrants::get() {
+            create_grant::<T>(&grantor, &caller);
+        }
+        frame_system::Pallet::<T>::set_block_number(2u32.into());
+        let locked = GrantVesting::<T>::locked_balance(&caller);
+    }: _(SystemOrigin::Signed(caller.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::Claimed(caller, locked).into());
+    }
+
+    revoke {
+        let caller: T::AccountId = whitelisted_caller();
+        let dest: T::AccountId = account("dest", 0, 0);
+        T::Currency::make_free_balance_be(&caller, INITIAL_AMOUNT);
+        for _ in 0..T::MaxGrants::get() {
+            create_grant::<T>(&caller, &dest);
+        }
+        let unvested = PER_PERIOD * PERIOD_COUNT as u128;
+    }: _(SystemOrigin::Signed(caller.clone()), dest.clone(), 0)
+    verify {
+        assert_last_event::<T>(Event::<T>::GrantRevoked(dest, 0, unvested).into());
+    }
+}
+
+impl_benchmark_test_suite!(GrantVesting, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,261 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Grant Vesting pallet
+//!
+//! ## Overview
+//!
+//! Grantors transfer funds which vest linearly to the receiver, e.g. for team members
+//! and investors. An account can receive several grants, its balance is locked by the
+//! sum of their unvested amounts. Revocable grants can be stopped by their grantor, the
+//! vested part stays with the receiver while the rest goes to the treasury.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        Currency, ExistenceRequirement::AllowDeath, LockIdentifier, LockableCurrency, OnUnbalanced,
+        WithdrawReasons,
+    },
+    transactional,
+};
+use frame_system::pallet_prelude::*;
+use primitives::Balance;
+use sp_runtime::{traits::Zero, ArithmeticError};
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+pub use types::Grant;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub const GRANT_VESTING_ID: LockIdentifier = *b"grantvst";
+
+pub type GrantId = u32;
+
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+    <T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+pub type GrantOf<T> =
+    Grant<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The currency grants are paid in
+        type Currency: LockableCurrency<Self::AccountId, Balance = Balance>;
+
+        /// The origin which can create and revoke grants
+        type GrantOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// Min amount released by a grant
+        #[pallet::constant]
+        type MinGrant: Get<Balance>;
+
+        /// Max number of grants an account can receive at the same time
+        #[pallet::constant]
+        type MaxGrants: Get<u32>;
+
+        /// Receives the unvested amount of revoked grants
+        type Treasury: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Grant was created
+        /// [grantor, who, grant_id, amount]
+        GrantCreated(T::AccountId, T::AccountId, GrantId, Balance),
+        /// Vested amounts were unlocked
+        /// [who, locked]
+        Claimed(T::AccountId, Balance),
+        /// Grant was revoked, the unvested amount was sent to the treasury
+        /// [who, grant_id, unvested]
+        GrantRevoked(T::AccountId, GrantId, Balance),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Period, period count or amount per period is zero
+        InvalidSchedule,
+        /// Grant releases less than the min amount
+        AmountTooLow,
+        /// Account already receives the max number of grants
+        TooManyGrants,
+        /// Grant doesn't exist
+        GrantNotFound,
+        /// Only the grantor can revoke the grant
+        NotGrantor,
+        /// Grant can't be revoked
+        NotRevocable,
+    }
+
+    /// Id of the next grant
+    #[pallet::storage]
+    #[pallet::getter(fn next_grant_id)]
+    pub type NextGrantId<T: Config> = StorageValue<_, GrantId, ValueQuery>;
+
+    /// Grants received by accounts
+    /// AccountId -> GrantId -> Grant
+    #[pallet::storage]
+    #[pallet::getter(fn grants)]
+    pub type Grants<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        GrantId,
+        GrantOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Transfer `per_period * period_count` to `dest`, released every `period`
+        /// blocks after `start`
+        #[pallet::weight(<T as Config>::WeightInfo::grant())]
+        #[transactional]
+        pub fn grant(
+            origin: OriginFor<T>,
+            dest: T::AccountId,
+            start: T::BlockNumber,
+            period: T::BlockNumber,
+            period_count: u32,
+            per_period: Balance,
+            revocable: bool,
+        ) -> DispatchResult {
+            let grantor = T::GrantOrigin::ensure_origin(origin)?;
+            ensure!(
+                !period.is_zero() && period_count > 0 && !per_period.is_zero(),
+                Error::<T>::InvalidSchedule
+            );
+            let grant = Grant {
+                grantor: grantor.clone(),
+                start,
+                period,
+                period_count,
+                per_period,
+                revocable,
+            };
+            let amount = grant.total_amount().ok_or(ArithmeticError::Overflow)?;
+            ensure!(amount >= T::MinGrant::get(), Error::<T>::AmountTooLow);
+            ensure!(
+                (Grants::<T>::iter_prefix(&dest).count() as u32) < T::MaxGrants::get(),
+                Error::<T>::TooManyGrants
+            );
+
+            T::Currency::transfer(&grantor, &dest, amount, AllowDeath)?;
+            let grant_id = NextGrantId::<T>::mutate(|id| {
+                let grant_id = *id;
+                *id = id.saturating_add(1);
+                grant_id
+            });
+            Grants::<T>::insert(&dest, grant_id, grant);
+            Self::update_lock(&dest);
+
+            Self::deposit_event(Event::<T>::GrantCreated(grantor, dest, grant_id, amount));
+            Ok(())
+        }
+
+        /// Unlock the vested amounts of the grants received by the caller
+        #[pallet::weight(<T as Config>::WeightInfo::claim())]
+        #[transactional]
+        pub fn claim(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let locked = Self::update_lock(&who);
+            Self::deposit_event(Event::<T>::Claimed(who, locked));
+            Ok(())
+        }
+
+        /// Stop a revocable grant, its unvested amount is sent to the treasury
+        #[pallet::weight(<T as Config>::WeightInfo::revoke())]
+        #[transactional]
+        pub fn revoke(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            grant_id: GrantId,
+        ) -> DispatchResult {
+            let grantor = T::GrantOrigin::ensure_origin(origin)?;
+            let grant = Self::grants(&who, grant_id).ok_or(Error::<T>::GrantNotFound)?;
+            ensure!(grant.grantor == grantor, Error::<T>::NotGrantor);
+            ensure!(grant.revocable, Error::<T>::NotRevocable);
+
+            let unvested = grant.locked_amount(frame_system::Pallet::<T>::block_number());
+            Grants::<T>::remove(&who, grant_id);
+            Self::update_lock(&who);
+            // The unvested amount isn't locked anymore, so it's still in the free balance
+            let (imbalance, _) = T::Currency::slash(&who, unvested);
+            T::Treasury::on_unbalanced(imbalance);
+
+            Self::deposit_event(Event::<T>::GrantRevoked(who, grant_id, unvested));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Sum of the unvested amounts of the grants received by `who`
+    pub fn locked_balance(who: &T::AccountId) -> Balance {
+        let now = frame_system::Pallet::<T>::block_number();
+        Grants::<T>::iter_prefix_values(who).fold(Zero::zero(), |locked: Balance, grant| {
+            locked.saturating_add(grant.locked_amount(now))
+        })
+    }
+
+    /// Lock the unvested amounts of `who` and drop its fully vested grants
+    fn update_lock(who: &T::AccountId) -> Balance {
+        let now = frame_system::Pallet::<T>::block_number();
+        let mut locked: Balance = Zero::zero();
+        let grants: Vec<(GrantId, GrantOf<T>)> = Grants::<T>::iter_prefix(who).collect();
+        for (grant_id, grant) in grants {
+            let amount = grant.locked_amount(now);
+            if amount.is_zero() {
+                Grants::<T>::remove(who, grant_id);
+            }
+            locked = locked.saturating_add(amount);
+        }
+
+        if locked.is_zero() {
+            T::Currency::remove_lock(GRANT_VESTING_ID, who);
+        } else {
+            T::Currency::set_lock(GRANT_VESTING_ID, who, locked, WithdrawReasons::all());
+        }
+        locked
+    }
+}
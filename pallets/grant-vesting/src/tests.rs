@@ -0,0 +1,121 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+
+fn grant(grantor: AccountId, dest: AccountId, revocable: bool) -> DispatchResult {
+    // 400 released by 100 every 10 blocks from block 10
+    GrantVesting::grant(
+        RuntimeOrigin::signed(grantor),
+        dest,
+        10,
+        10,
+        4,
+        100,
+        revocable,
+    )
+}
+
+fn lock_of(who: AccountId) -> Balance {
+    Balances::locks(who)
+        .iter()
+        .find(|lock| lock.id == GRANT_VESTING_ID)
+        .map_or(0, |lock| lock.amount)
+}
+
+#[test]
+fn grant_should_lock_until_vested() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(grant(ALICE, BOB, false));
+        System::assert_last_event(RuntimeEvent::GrantVesting(Event::GrantCreated(
+            ALICE, BOB, 0, 400,
+        )));
+        assert_eq!(Balances::free_balance(BOB), 410);
+        assert_eq!(lock_of(BOB), 400);
+        assert_noop!(
+            Balances::transfer(RuntimeOrigin::signed(BOB), CHARLIE, 20),
+            pallet_balances::Error::<Test>::LiquidityRestrictions
+        );
+
+        System::set_block_number(30);
+        assert_eq!(GrantVesting::locked_balance(&BOB), 200);
+        assert_ok!(GrantVesting::claim(RuntimeOrigin::signed(BOB)));
+        System::assert_last_event(RuntimeEvent::GrantVesting(Event::Claimed(BOB, 200)));
+        assert_ok!(Balances::transfer(RuntimeOrigin::signed(BOB), CHARLIE, 200));
+
+        // Fully vested grants are removed
+        System::set_block_number(50);
+        assert_ok!(GrantVesting::claim(RuntimeOrigin::signed(BOB)));
+        assert_eq!(lock_of(BOB), 0);
+        assert_eq!(GrantVesting::grants(BOB, 0), None);
+    });
+}
+
+#[test]
+fn grants_should_be_validated() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            GrantVesting::grant(RuntimeOrigin::signed(ALICE), BOB, 10, 0, 4, 100, false),
+            Error::<Test>::InvalidSchedule
+        );
+        assert_noop!(
+            GrantVesting::grant(RuntimeOrigin::signed(ALICE), BOB, 10, 10, 0, 100, false),
+            Error::<Test>::InvalidSchedule
+        );
+        assert_noop!(
+            GrantVesting::grant(RuntimeOrigin::signed(ALICE), BOB, 10, 10, 4, 10, false),
+            Error::<Test>::AmountTooLow
+        );
+        assert_noop!(
+            grant(BOB, CHARLIE, false),
+            pallet_balances::Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn multiple_grants_should_be_locked_together() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(grant(ALICE, BOB, false));
+        System::set_block_number(20);
+        assert_ok!(grant(CHARLIE, BOB, true));
+        // Both grants released 100 at block 20
+        assert_eq!(lock_of(BOB), 600);
+
+        assert_ok!(grant(ALICE, BOB, true));
+        assert_noop!(grant(ALICE, BOB, true), Error::<Test>::TooManyGrants);
+
+        System::set_block_number(40);
+        assert_ok!(GrantVesting::claim(RuntimeOrigin::signed(BOB)));
+        assert_eq!(lock_of(BOB), 3 * 100);
+    });
+}
+
+#[test]
+fn revoke_should_send_unvested_amount_to_treasury() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(grant(ALICE, BOB, true));
+        assert_ok!(grant(ALICE, BOB, false));
+
+        System::set_block_number(30);
+        assert_noop!(
+            GrantVesting::revoke(RuntimeOrigin::signed(CHARLIE), BOB, 0),
+            Error::<Test>::NotGrantor
+        );
+        assert_noop!(
+            GrantVesting::revoke(RuntimeOrigin::signed(ALICE), BOB, 1),
+            Error::<Test>::NotRevocable
+        );
+        assert_noop!(
+            GrantVesting::revoke(RuntimeOrigin::signed(ALICE), BOB, 2),
+            Error::<Test>::GrantNotFound
+        );
+
+        assert_ok!(GrantVesting::revoke(RuntimeOrigin::signed(ALICE), BOB, 0));
+        System::assert_last_event(RuntimeEvent::GrantVesting(Event::GrantRevoked(BOB, 0, 200)));
+        assert_eq!(Balances::free_balance(TREASURY), 201);
+        assert_eq!(Balances::free_balance(BOB), 10 + 800 - 200);
+        // Only the grant which can't be revoked is still locked
+        assert_eq!(lock_of(BOB), 200);
+        assert_eq!(GrantVesting::grants(BOB, 0), None);
+    });
+}
@@ -0,0 +1,54 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use primitives::Balance;
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, Saturating, Zero},
+    RuntimeDebug, SaturatedConversion,
+};
+
+/// Linear release of `per_period` every `period` blocks after `start`
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct Grant<AccountId, BlockNumber> {
+    /// Account which funded the grant
+    pub grantor: AccountId,
+    pub start: BlockNumber,
+    pub period: BlockNumber,
+    pub period_count: u32,
+    pub per_period: Balance,
+    /// Grantor can revoke the amount which isn't vested yet
+    pub revocable: bool,
+}
+
+impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Grant<AccountId, BlockNumber> {
+    /// Amount released by the whole grant, `None` on overflow
+    pub fn total_amount(&self) -> Option<Balance> {
+        self.per_period.checked_mul(self.period_count.into())
+    }
+
+    /// Amount still locked at `now`
+    pub fn locked_amount(&self, now: BlockNumber) -> Balance {
+        let total = self.total_amount().unwrap_or(Balance::MAX);
+        if now <= self.start || self.period.is_zero() {
+            return total;
+        }
+        let elapsed: u32 = ((now - self.start) / self.period).saturated_into();
+        let vested = self
+            .per_period
+            .saturating_mul(elapsed.min(self.period_count).into());
+        total.saturating_sub(vested)
+    }
+}
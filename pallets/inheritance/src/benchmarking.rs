@@ -0,0 +1,122 @@
+//! Inheritance pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Inheritance;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{assert_ok, traits::Currency};
+use frame_system::RawOrigin as SystemOrigin;
+use sp_std::vec;
+
+const INITIAL_AMOUNT: u128 = 100_000_000_000_000;
+
+fn set_switch<T: Config>(owner: &T::AccountId, beneficiary: &T::AccountId) {
+    T::Currency::make_free_balance_be(owner, INITIAL_AMOUNT);
+    assert_ok!(Inheritance::<T>::set_switch(
+        SystemOrigin::Signed(owner.clone()).into(),
+        beneficiary.clone(),
+        T::MinInactivityPeriod::get(),
+    ));
+}
+
+/// Move past the inactivity period of `owner` and initiate the claim of `beneficiary`
+fn initiate_claim<T: Config>(owner: &T::AccountId, beneficiary: &T::AccountId) {
+    let now = frame_system::Pallet::<T>::block_number();
+    frame_system::Pallet::<T>::set_block_number(now + T::MinInactivityPeriod::get());
+    assert_ok!(Inheritance::<T>::initiate_claim(
+        SystemOrigin::Signed(beneficiary.clone()).into(),
+        owner.clone(),
+    ));
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    set_switch {
+        let caller: T::AccountId = whitelisted_caller();
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        T::Currency::make_free_balance_be(&caller, INITIAL_AMOUNT);
+        let period = T::MinInactivityPeriod::get();
+    }: _(SystemOrigin::Signed(caller.clone()), beneficiary.clone(), period)
+    verify {
+        assert_last_event::<T>(Event::<T>::SwitchSet(caller, beneficiary, period).into());
+    }
+
+    remove_switch {
+        let caller: T::AccountId = whitelisted_caller();
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        set_switch::<T>(&caller, &beneficiary);
+    }: _(SystemOrigin::Signed(caller.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::SwitchRemoved(caller).into());
+    }
+
+    heartbeat {
+        let caller: T::AccountId = whitelisted_caller();
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        set_switch::<T>(&caller, &beneficiary);
+        initiate_claim::<T>(&caller, &beneficiary);
+    }: _(SystemOrigin::Signed(caller.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::ActivityRecorded(caller).into());
+    }
+
+    poke {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        set_switch::<T>(&owner, &beneficiary);
+        initiate_claim::<T>(&owner, &beneficiary);
+        frame_system::Pallet::<T>::inc_account_nonce(&owner);
+    }: _(SystemOrigin::Signed(caller), owner.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::ActivityRecorded(owner).into());
+    }
+
+    initiate_claim {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        set_switch::<T>(&owner, &caller);
+        let now = frame_system::Pallet::<T>::block_number() + T::MinInactivityPeriod::get();
+        frame_system::Pallet::<T>::set_block_number(now);
+    }: _(SystemOrigin::Signed(caller.clone()), owner.clone())
+    verify {
+        assert_last_event::<T>(
+            Event::<T>::ClaimInitiated(owner, caller, now + T::ChallengePeriod::get()).into()
+        );
+    }
+
+    finalize_claim {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        set_switch::<T>(&owner, &caller);
+        initiate_claim::<T>(&owner, &caller);
+        let now = frame_system::Pallet::<T>::block_number() + T::ChallengePeriod::get();
+        frame_system::Pallet::<T>::set_block_number(now);
+    }: _(SystemOrigin::Signed(caller.clone()), owner.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::ClaimFinalized(owner, caller).into());
+    }
+
+    as_owner {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        set_switch::<T>(&owner, &caller);
+        initiate_claim::<T>(&owner, &caller);
+        let now = frame_system::Pallet::<T>::block_number() + T::ChallengePeriod::get();
+        frame_system::Pallet::<T>::set_block_number(now);
+        assert_ok!(Inheritance::<T>::finalize_claim(
+            SystemOrigin::Signed(caller.clone()).into(),
+            owner.clone(),
+        ));
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+    }: _(SystemOrigin::Signed(caller), owner.clone(), Box::new(call))
+    verify {
+        assert!(Inheritance::<T>::switches(&owner).unwrap().claimed);
+    }
+}
+
+impl_benchmark_test_suite!(Inheritance, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,347 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Inheritance pallet
+//!
+//! ## Overview
+//!
+//! A dead man's switch for long-term deposits. The owner of an account nominates a
+//! beneficiary who can claim the control of the account, and so of its loans, staking
+//! and streaming positions, once the owner stayed inactive for a chosen period.
+//!
+//! The owner is active whenever its nonce changes, i.e. it signed a transaction. Once
+//! the owner is inactive the beneficiary initiates a claim, which can be finalized after
+//! `ChallengePeriod` blocks. Any activity of the owner in the meantime cancels the claim,
+//! and revokes the control of a finalized claim. The beneficiary of a finalized claim
+//! dispatches the calls allowed by `CallFilter` on behalf of the owner.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+    pallet_prelude::*,
+    traits::{Contains, OriginTrait, ReservableCurrency},
+    transactional,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use primitives::Balance;
+use sp_runtime::traits::Saturating;
+use sp_std::boxed::Box;
+
+pub use pallet::*;
+pub use types::Switch;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub type SwitchOf<T> = Switch<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+    <T as frame_system::Config>::Index,
+>;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The calls the beneficiary can dispatch on behalf of the owner
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + From<frame_system::Call<Self>>
+            + IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// The currency the switch deposit is reserved in
+        type Currency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+        /// Calls allowed to the beneficiary of a finalized claim
+        type CallFilter: Contains<<Self as Config>::RuntimeCall>;
+
+        /// Amount reserved from the owner of a switch
+        #[pallet::constant]
+        type SwitchDeposit: Get<Balance>;
+
+        /// Min inactivity period of a switch
+        #[pallet::constant]
+        type MinInactivityPeriod: Get<Self::BlockNumber>;
+
+        /// Blocks the owner has to show activity once a claim was initiated
+        #[pallet::constant]
+        type ChallengePeriod: Get<Self::BlockNumber>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Switch was set
+        /// [owner, beneficiary, inactivity_period]
+        SwitchSet(T::AccountId, T::AccountId, T::BlockNumber),
+        /// Switch was removed and its deposit unreserved
+        /// [owner]
+        SwitchRemoved(T::AccountId),
+        /// Owner was seen active, the inactivity period starts again
+        /// [owner]
+        ActivityRecorded(T::AccountId),
+        /// Pending or finalized claim was cancelled by the activity of the owner
+        /// [owner]
+        ClaimCancelled(T::AccountId),
+        /// Beneficiary initiated a claim
+        /// [owner, beneficiary, claimable_at]
+        ClaimInitiated(T::AccountId, T::AccountId, T::BlockNumber),
+        /// Beneficiary took the control of the owner
+        /// [owner, beneficiary]
+        ClaimFinalized(T::AccountId, T::AccountId),
+        /// Beneficiary dispatched a call on behalf of the owner
+        /// [owner, result]
+        DispatchedAsOwner(T::AccountId, DispatchResult),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Owner can't be its own beneficiary
+        InvalidBeneficiary,
+        /// Inactivity period is below `MinInactivityPeriod`
+        PeriodTooShort,
+        /// Account has no switch
+        SwitchNotFound,
+        /// Caller isn't the beneficiary of the switch
+        NotBeneficiary,
+        /// Owner signed a transaction since it was last seen active
+        OwnerActive,
+        /// Owner didn't sign a transaction since it was last seen active
+        NoNewActivity,
+        /// Inactivity period isn't over
+        OwnerNotInactive,
+        /// Claim was already initiated or finalized
+        AlreadyClaimed,
+        /// No claim was initiated
+        NoPendingClaim,
+        /// Challenge period isn't over
+        ChallengePeriodNotOver,
+        /// Claim wasn't finalized
+        NotClaimed,
+    }
+
+    /// Switches set by owners
+    /// AccountId -> Switch
+    #[pallet::storage]
+    #[pallet::getter(fn switches)]
+    pub type Switches<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SwitchOf<T>, OptionQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Nominate `beneficiary` to claim the control of the caller after
+        /// `inactivity_period` blocks without activity. Cancels any existing claim.
+        #[pallet::weight(<T as Config>::WeightInfo::set_switch())]
+        #[transactional]
+        pub fn set_switch(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+            inactivity_period: T::BlockNumber,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            ensure!(owner != beneficiary, Error::<T>::InvalidBeneficiary);
+            ensure!(
+                inactivity_period >= T::MinInactivityPeriod::get(),
+                Error::<T>::PeriodTooShort
+            );
+
+            let deposit = match Self::switches(&owner) {
+                Some(switch) => switch.deposit,
+                None => {
+                    let deposit = T::SwitchDeposit::get();
+                    T::Currency::reserve(&owner, deposit)?;
+                    deposit
+                }
+            };
+            Switches::<T>::insert(
+                &owner,
+                Switch {
+                    beneficiary: beneficiary.clone(),
+                    inactivity_period,
+                    last_active: frame_system::Pallet::<T>::block_number(),
+                    nonce: frame_system::Pallet::<T>::account_nonce(&owner),
+                    deposit,
+                    claimable_at: None,
+                    claimed: false,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::SwitchSet(owner, beneficiary, inactivity_period));
+            Ok(())
+        }
+
+        /// Remove the switch of the caller and unreserve its deposit
+        #[pallet::weight(<T as Config>::WeightInfo::remove_switch())]
+        #[transactional]
+        pub fn remove_switch(origin: OriginFor<T>) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let switch = Switches::<T>::take(&owner).ok_or(Error::<T>::SwitchNotFound)?;
+            T::Currency::unreserve(&owner, switch.deposit);
+
+            Self::deposit_event(Event::<T>::SwitchRemoved(owner));
+            Ok(())
+        }
+
+        /// Show the caller is active, cancelling any claim on it
+        #[pallet::weight(<T as Config>::WeightInfo::heartbeat())]
+        #[transactional]
+        pub fn heartbeat(origin: OriginFor<T>) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let mut switch = Self::switches(&owner).ok_or(Error::<T>::SwitchNotFound)?;
+            Self::record_activity(&owner, &mut switch);
+            Switches::<T>::insert(&owner, switch);
+            Ok(())
+        }
+
+        /// Record the activity of `owner` if it signed a transaction since it was last
+        /// seen active, cancelling any claim on it
+        #[pallet::weight(<T as Config>::WeightInfo::poke())]
+        #[transactional]
+        pub fn poke(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+            ensure_signed(origin)?;
+            let mut switch = Self::switches(&owner).ok_or(Error::<T>::SwitchNotFound)?;
+            ensure!(
+                Self::has_been_active(&owner, &switch),
+                Error::<T>::NoNewActivity
+            );
+            Self::record_activity(&owner, &mut switch);
+            Switches::<T>::insert(&owner, switch);
+            Ok(())
+        }
+
+        /// Initiate the claim of an inactive owner, it can be finalized after
+        /// `ChallengePeriod` blocks
+        #[pallet::weight(<T as Config>::WeightInfo::initiate_claim())]
+        #[transactional]
+        pub fn initiate_claim(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut switch = Self::ensure_beneficiary(&who, &owner)?;
+            ensure!(
+                switch.claimable_at.is_none() && !switch.claimed,
+                Error::<T>::AlreadyClaimed
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now >= switch.inactive_at(), Error::<T>::OwnerNotInactive);
+
+            let claimable_at = now.saturating_add(T::ChallengePeriod::get());
+            switch.claimable_at = Some(claimable_at);
+            Switches::<T>::insert(&owner, switch);
+
+            Self::deposit_event(Event::<T>::ClaimInitiated(owner, who, claimable_at));
+            Ok(())
+        }
+
+        /// Take the control of the owner once the challenge period of the claim is over
+        #[pallet::weight(<T as Config>::WeightInfo::finalize_claim())]
+        #[transactional]
+        pub fn finalize_claim(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut switch = Self::ensure_beneficiary(&who, &owner)?;
+            let claimable_at = switch.claimable_at.ok_or(Error::<T>::NoPendingClaim)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= claimable_at,
+                Error::<T>::ChallengePeriodNotOver
+            );
+
+            switch.claimable_at = None;
+            switch.claimed = true;
+            Switches::<T>::insert(&owner, switch);
+
+            Self::deposit_event(Event::<T>::ClaimFinalized(owner, who));
+            Ok(())
+        }
+
+        /// Dispatch `call` on behalf of an owner whose claim was finalized
+        #[pallet::weight({
+            let di = call.get_dispatch_info();
+            (<T as Config>::WeightInfo::as_owner().saturating_add(di.weight), di.class)
+        })]
+        pub fn as_owner(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let switch = Self::ensure_beneficiary(&who, &owner)?;
+            ensure!(switch.claimed, Error::<T>::NotClaimed);
+
+            let mut origin: T::RuntimeOrigin = RawOrigin::Signed(owner.clone()).into();
+            origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
+                let c = <T as Config>::RuntimeCall::from_ref(c);
+                T::CallFilter::contains(c)
+            });
+            let result = call.dispatch(origin);
+
+            Self::deposit_event(Event::<T>::DispatchedAsOwner(
+                owner,
+                result.map(|_| ()).map_err(|e| e.error),
+            ));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Switch of `owner` if `who` is its beneficiary and the owner is still inactive
+    fn ensure_beneficiary(
+        who: &T::AccountId,
+        owner: &T::AccountId,
+    ) -> Result<SwitchOf<T>, DispatchError> {
+        let switch = Self::switches(owner).ok_or(Error::<T>::SwitchNotFound)?;
+        ensure!(&switch.beneficiary == who, Error::<T>::NotBeneficiary);
+        ensure!(
+            !Self::has_been_active(owner, &switch),
+            Error::<T>::OwnerActive
+        );
+        Ok(switch)
+    }
+
+    /// Whether `owner` signed a transaction since it was last seen active
+    fn has_been_active(owner: &T::AccountId, switch: &SwitchOf<T>) -> bool {
+        frame_system::Pallet::<T>::account_nonce(owner) != switch.nonce
+    }
+
+    /// Restart the inactivity period of `owner` and cancel any claim on it
+    fn record_activity(owner: &T::AccountId, switch: &mut SwitchOf<T>) {
+        switch.last_active = frame_system::Pallet::<T>::block_number();
+        switch.nonce = frame_system::Pallet::<T>::account_nonce(owner);
+        if switch.claimable_at.take().is_some() || switch.claimed {
+            switch.claimed = false;
+            Self::deposit_event(Event::<T>::ClaimCancelled(owner.clone()));
+        }
+        Self::deposit_event(Event::<T>::ActivityRecorded(owner.clone()));
+    }
+}
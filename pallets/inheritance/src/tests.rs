@@ -0,0 +1,226 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+
+fn transfer(dest: AccountId, value: Balance) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer {
+        dest,
+        value,
+    }))
+}
+
+/// BOB takes the control of ALICE, inactive since block 1
+fn claim_alice() {
+    assert_ok!(Inheritance::set_switch(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        100
+    ));
+    System::set_block_number(101);
+    assert_ok!(Inheritance::initiate_claim(
+        RuntimeOrigin::signed(BOB),
+        ALICE
+    ));
+    System::set_block_number(111);
+    assert_ok!(Inheritance::finalize_claim(
+        RuntimeOrigin::signed(BOB),
+        ALICE
+    ));
+}
+
+#[test]
+fn set_switch_should_reserve_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Inheritance::set_switch(RuntimeOrigin::signed(ALICE), ALICE, 100),
+            Error::<Test>::InvalidBeneficiary
+        );
+        assert_noop!(
+            Inheritance::set_switch(RuntimeOrigin::signed(ALICE), BOB, 99),
+            Error::<Test>::PeriodTooShort
+        );
+
+        assert_ok!(Inheritance::set_switch(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            100
+        ));
+        System::assert_last_event(RuntimeEvent::Inheritance(Event::SwitchSet(ALICE, BOB, 100)));
+        assert_eq!(Balances::reserved_balance(ALICE), 50);
+
+        // Updating the switch keeps the deposit
+        assert_ok!(Inheritance::set_switch(
+            RuntimeOrigin::signed(ALICE),
+            CHARLIE,
+            200
+        ));
+        assert_eq!(Balances::reserved_balance(ALICE), 50);
+        assert_eq!(Inheritance::switches(ALICE).unwrap().beneficiary, CHARLIE);
+
+        assert_ok!(Inheritance::remove_switch(RuntimeOrigin::signed(ALICE)));
+        System::assert_last_event(RuntimeEvent::Inheritance(Event::SwitchRemoved(ALICE)));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_noop!(
+            Inheritance::remove_switch(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::SwitchNotFound
+        );
+    });
+}
+
+#[test]
+fn beneficiary_should_claim_after_inactivity_and_challenge_periods() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Inheritance::set_switch(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            100
+        ));
+
+        System::set_block_number(100);
+        assert_noop!(
+            Inheritance::initiate_claim(RuntimeOrigin::signed(BOB), ALICE),
+            Error::<Test>::OwnerNotInactive
+        );
+        System::set_block_number(101);
+        assert_noop!(
+            Inheritance::initiate_claim(RuntimeOrigin::signed(CHARLIE), ALICE),
+            Error::<Test>::NotBeneficiary
+        );
+        assert_noop!(
+            Inheritance::finalize_claim(RuntimeOrigin::signed(BOB), ALICE),
+            Error::<Test>::NoPendingClaim
+        );
+        assert_ok!(Inheritance::initiate_claim(
+            RuntimeOrigin::signed(BOB),
+            ALICE
+        ));
+        System::assert_last_event(RuntimeEvent::Inheritance(Event::ClaimInitiated(
+            ALICE, BOB, 111,
+        )));
+        assert_noop!(
+            Inheritance::initiate_claim(RuntimeOrigin::signed(BOB), ALICE),
+            Error::<Test>::AlreadyClaimed
+        );
+
+        System::set_block_number(110);
+        assert_noop!(
+            Inheritance::finalize_claim(RuntimeOrigin::signed(BOB), ALICE),
+            Error::<Test>::ChallengePeriodNotOver
+        );
+        assert_noop!(
+            Inheritance::as_owner(RuntimeOrigin::signed(BOB), ALICE, transfer(BOB, 100)),
+            Error::<Test>::NotClaimed
+        );
+
+        System::set_block_number(111);
+        assert_ok!(Inheritance::finalize_claim(
+            RuntimeOrigin::signed(BOB),
+            ALICE
+        ));
+        System::assert_last_event(RuntimeEvent::Inheritance(Event::ClaimFinalized(ALICE, BOB)));
+
+        assert_ok!(Inheritance::as_owner(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            transfer(BOB, 100)
+        ));
+        System::assert_last_event(RuntimeEvent::Inheritance(Event::DispatchedAsOwner(
+            ALICE,
+            Ok(()),
+        )));
+        assert_eq!(Balances::free_balance(BOB), 1_100);
+        assert_noop!(
+            Inheritance::as_owner(
+                RuntimeOrigin::signed(CHARLIE),
+                ALICE,
+                transfer(CHARLIE, 100)
+            ),
+            Error::<Test>::NotBeneficiary
+        );
+    });
+}
+
+#[test]
+fn owner_activity_should_cancel_pending_claim() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Inheritance::set_switch(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            100
+        ));
+        assert_noop!(
+            Inheritance::poke(RuntimeOrigin::signed(CHARLIE), ALICE),
+            Error::<Test>::NoNewActivity
+        );
+        System::set_block_number(101);
+        assert_ok!(Inheritance::initiate_claim(
+            RuntimeOrigin::signed(BOB),
+            ALICE
+        ));
+
+        // ALICE signs a transaction during the challenge period
+        System::inc_account_nonce(ALICE);
+        System::set_block_number(111);
+        assert_noop!(
+            Inheritance::finalize_claim(RuntimeOrigin::signed(BOB), ALICE),
+            Error::<Test>::OwnerActive
+        );
+
+        assert_ok!(Inheritance::poke(RuntimeOrigin::signed(CHARLIE), ALICE));
+        System::assert_has_event(RuntimeEvent::Inheritance(Event::ClaimCancelled(ALICE)));
+        System::assert_last_event(RuntimeEvent::Inheritance(Event::ActivityRecorded(ALICE)));
+        let switch = Inheritance::switches(ALICE).unwrap();
+        assert_eq!(switch.last_active, 111);
+        assert_eq!(switch.claimable_at, None);
+
+        // The inactivity period starts again
+        assert_noop!(
+            Inheritance::initiate_claim(RuntimeOrigin::signed(BOB), ALICE),
+            Error::<Test>::OwnerNotInactive
+        );
+        System::set_block_number(211);
+        assert_ok!(Inheritance::initiate_claim(
+            RuntimeOrigin::signed(BOB),
+            ALICE
+        ));
+    });
+}
+
+#[test]
+fn heartbeat_should_revoke_finalized_claim() {
+    new_test_ext().execute_with(|| {
+        claim_alice();
+
+        assert_ok!(Inheritance::heartbeat(RuntimeOrigin::signed(ALICE)));
+        System::assert_has_event(RuntimeEvent::Inheritance(Event::ClaimCancelled(ALICE)));
+        assert!(!Inheritance::switches(ALICE).unwrap().claimed);
+        assert_noop!(
+            Inheritance::as_owner(RuntimeOrigin::signed(BOB), ALICE, transfer(BOB, 100)),
+            Error::<Test>::NotClaimed
+        );
+        assert_noop!(
+            Inheritance::heartbeat(RuntimeOrigin::signed(BOB)),
+            Error::<Test>::SwitchNotFound
+        );
+    });
+}
+
+#[test]
+fn as_owner_should_only_dispatch_allowed_calls() {
+    new_test_ext().execute_with(|| {
+        claim_alice();
+
+        let call = Box::new(RuntimeCall::System(frame_system::Call::remark {
+            remark: vec![],
+        }));
+        assert_ok!(Inheritance::as_owner(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            call
+        ));
+        System::assert_last_event(RuntimeEvent::Inheritance(Event::DispatchedAsOwner(
+            ALICE,
+            Err(frame_system::Error::<Test>::CallFiltered.into()),
+        )));
+    });
+}
@@ -0,0 +1,44 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use primitives::Balance;
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, RuntimeDebug};
+
+/// Hands the control of `owner` to `beneficiary` once it's inactive for
+/// `inactivity_period` blocks
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct Switch<AccountId, BlockNumber, Index> {
+    pub beneficiary: AccountId,
+    pub inactivity_period: BlockNumber,
+    /// Block at which the owner was last seen active
+    pub last_active: BlockNumber,
+    /// Nonce of the owner when it was last seen active, the owner is active again
+    /// once it signed a transaction
+    pub nonce: Index,
+    /// Reserved from the owner while the switch exists
+    pub deposit: Balance,
+    /// Block from which a pending claim can be finalized
+    pub claimable_at: Option<BlockNumber>,
+    /// Beneficiary controls the owner
+    pub claimed: bool,
+}
+
+impl<AccountId, BlockNumber: Saturating + Copy, Index> Switch<AccountId, BlockNumber, Index> {
+    /// Block from which the owner is considered inactive
+    pub fn inactive_at(&self) -> BlockNumber {
+        self.last_active.saturating_add(self.inactivity_period)
+    }
+}
@@ -0,0 +1,169 @@
+// This file is part of Parallel Finance.
+
+// Copyright (C) 2022 Parallel Finance Developer.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_inheritance
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet-inheritance
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/inheritance/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions needed for pallet_inheritance.
+pub trait WeightInfo {
+	fn set_switch() -> Weight;
+	fn remove_switch() -> Weight;
+	fn heartbeat() -> Weight;
+	fn poke() -> Weight;
+	fn initiate_claim() -> Weight;
+	fn finalize_claim() -> Weight;
+	fn as_owner() -> Weight;
+}
+
+/// Weights for pallet_inheritance using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_switch() -> Weight {
+		Weight::from_ref_time(38_412_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_switch() -> Weight {
+		Weight::from_ref_time(36_905_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn heartbeat() -> Weight {
+		Weight::from_ref_time(24_118_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn poke() -> Weight {
+		Weight::from_ref_time(25_037_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn initiate_claim() -> Weight {
+		Weight::from_ref_time(26_774_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn finalize_claim() -> Weight {
+		Weight::from_ref_time(26_203_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	fn as_owner() -> Weight {
+		Weight::from_ref_time(21_646_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_switch() -> Weight {
+		Weight::from_ref_time(38_412_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_switch() -> Weight {
+		Weight::from_ref_time(36_905_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn heartbeat() -> Weight {
+		Weight::from_ref_time(24_118_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn poke() -> Weight {
+		Weight::from_ref_time(25_037_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn initiate_claim() -> Weight {
+		Weight::from_ref_time(26_774_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn finalize_claim() -> Weight {
+		Weight::from_ref_time(26_203_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	fn as_owner() -> Weight {
+		Weight::from_ref_time(21_646_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+	}
+}
@@ -0,0 +1,379 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Insurance pallet
+//!
+//! ## Overview
+//!
+//! Holds funds earmarked per risk, like oracle failures, bridge exploits or slashes on the
+//! relay chain, to compensate the users who suffer losses from them. Each risk has a
+//! bucket per asset, funded by splits of the protocol revenue or by anyone. Governance
+//! sets the share of the revenue each risk gets, the splits can then be collected by
+//! anyone once per epoch and asset.
+//!
+//! Losses are compensated through claims. Anyone can file a claim against a bucket by
+//! reserving a deposit, with the hash of the evidence published off chain. `ClaimOrigin`
+//! approves a claim with the amount paid out of the bucket, which returns the deposit, or
+//! rejects it, which slashes the deposit.
+//!
+//! Buckets record what was put into them and paid out of them, so the reserves and the
+//! history of the fund can be read from chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        tokens::fungibles::{Inspect, Transfer},
+        Currency, OnUnbalanced, ReservableCurrency,
+    },
+    transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+use primitives::{Balance, CurrencyId, Ratio};
+use sp_runtime::{
+    traits::{AccountIdConversion, Saturating, Zero},
+    ArithmeticError, PerThing,
+};
+
+pub use pallet::*;
+pub use types::{Bucket, Claim, ClaimId, Risk};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub type ClaimOf<T> = Claim<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+    <T as frame_system::Config>::Hash,
+>;
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+    <T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Pallet id of the account holding the funds of all the buckets
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Assets the buckets hold
+        type Assets: Transfer<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
+            + Inspect<Self::AccountId, AssetId = CurrencyId, Balance = Balance>;
+
+        /// The currency the claim deposits are reserved in
+        type Currency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+        /// Deposit reserved to file a claim
+        #[pallet::constant]
+        type ClaimDeposit: Get<Balance>;
+
+        /// Handler for the deposits of rejected claims
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Account holding the protocol revenue the buckets are funded from
+        type RevenueAccount: Get<Self::AccountId>;
+
+        /// Number of blocks between two collections of the revenue of an asset
+        #[pallet::constant]
+        type EpochLength: Get<Self::BlockNumber>;
+
+        /// The origin which can set the revenue splits
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The origin which can approve and reject claims
+        type ClaimOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Share of the revenue going to a risk was set, or removed if `None`
+        /// [risk, share]
+        RevenueSplitUpdated(Risk, Option<Ratio>),
+        /// Revenue was collected into a bucket
+        /// [risk, asset_id, amount]
+        RevenueCollected(Risk, CurrencyId, Balance),
+        /// Bucket was funded by an account
+        /// [who, risk, asset_id, amount]
+        Funded(T::AccountId, Risk, CurrencyId, Balance),
+        /// Claim was filed
+        /// [claim_id, claimant, risk, asset_id, amount]
+        ClaimFiled(ClaimId, T::AccountId, Risk, CurrencyId, Balance),
+        /// Claim was approved and paid out
+        /// [claim_id, beneficiary, amount]
+        ClaimPaid(ClaimId, T::AccountId, Balance),
+        /// Claim was rejected and its deposit slashed
+        /// [claim_id]
+        ClaimRejected(ClaimId),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Revenue splits would add up to more than the whole revenue
+        SplitsExceedRevenue,
+        /// Revenue of the asset was already collected this epoch
+        CollectedThisEpoch,
+        /// Amount is zero
+        InvalidAmount,
+        /// Claim doesn't exist
+        ClaimNotFound,
+        /// Payout is larger than the claimed amount
+        PayoutExceedsClaim,
+        /// Bucket doesn't hold enough to pay out the claim
+        InsufficientBucketBalance,
+    }
+
+    /// Share of the revenue each risk is funded with
+    /// Risk -> Ratio
+    #[pallet::storage]
+    #[pallet::getter(fn revenue_splits)]
+    pub type RevenueSplits<T: Config> = StorageMap<_, Twox64Concat, Risk, Ratio, OptionQuery>;
+
+    /// Funds of each asset earmarked for each risk
+    /// Risk -> CurrencyId -> Bucket
+    #[pallet::storage]
+    #[pallet::getter(fn buckets)]
+    pub type Buckets<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, Risk, Twox64Concat, CurrencyId, Bucket, ValueQuery>;
+
+    /// Block the revenue of each asset was last collected at
+    /// CurrencyId -> BlockNumber
+    #[pallet::storage]
+    #[pallet::getter(fn last_collection)]
+    pub type LastCollection<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, T::BlockNumber, OptionQuery>;
+
+    /// Claims waiting for governance
+    /// ClaimId -> Claim
+    #[pallet::storage]
+    #[pallet::getter(fn claims)]
+    pub type Claims<T: Config> = StorageMap<_, Twox64Concat, ClaimId, ClaimOf<T>, OptionQuery>;
+
+    /// Identifier of the next claim
+    #[pallet::storage]
+    #[pallet::getter(fn next_claim_id)]
+    pub type NextClaimId<T: Config> = StorageValue<_, ClaimId, ValueQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the share of the revenue `risk` is funded with, or stop funding it from the
+        /// revenue if `share` is `None`.
+        #[pallet::weight(T::WeightInfo::set_revenue_split())]
+        #[transactional]
+        pub fn set_revenue_split(
+            origin: OriginFor<T>,
+            risk: Risk,
+            share: Option<Ratio>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            match share {
+                Some(share) => {
+                    let total = RevenueSplits::<T>::iter()
+                        .filter(|(other, _)| *other != risk)
+                        .fold(share.deconstruct(), |total, (_, other_share)| {
+                            total.saturating_add(other_share.deconstruct())
+                        });
+                    ensure!(total <= Ratio::ACCURACY, Error::<T>::SplitsExceedRevenue);
+                    RevenueSplits::<T>::insert(risk, share);
+                }
+                None => RevenueSplits::<T>::remove(risk),
+            }
+            Self::deposit_event(Event::<T>::RevenueSplitUpdated(risk, share));
+            Ok(())
+        }
+
+        /// Move the splits of the revenue of `asset_id` into the buckets of the risks,
+        /// once per epoch.
+        #[pallet::weight(T::WeightInfo::collect_revenue())]
+        #[transactional]
+        pub fn collect_revenue(origin: OriginFor<T>, asset_id: CurrencyId) -> DispatchResult {
+            ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(last) = Self::last_collection(asset_id) {
+                ensure!(
+                    now >= last.saturating_add(T::EpochLength::get()),
+                    Error::<T>::CollectedThisEpoch
+                );
+            }
+            LastCollection::<T>::insert(asset_id, now);
+
+            let revenue = T::RevenueAccount::get();
+            let balance = T::Assets::reducible_balance(asset_id, &revenue, false);
+            for (risk, share) in RevenueSplits::<T>::iter() {
+                let amount = share.mul_floor(balance);
+                if amount.is_zero() {
+                    continue;
+                }
+                Self::do_fund(&revenue, risk, asset_id, amount)?;
+                Self::deposit_event(Event::<T>::RevenueCollected(risk, asset_id, amount));
+            }
+            Ok(())
+        }
+
+        /// Put `amount` of `asset_id` into the bucket of `risk`.
+        #[pallet::weight(T::WeightInfo::fund())]
+        #[transactional]
+        pub fn fund(
+            origin: OriginFor<T>,
+            risk: Risk,
+            asset_id: CurrencyId,
+            amount: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::do_fund(&who, risk, asset_id, amount)?;
+            Self::deposit_event(Event::<T>::Funded(who, risk, asset_id, amount));
+            Ok(())
+        }
+
+        /// File a claim for `amount` of `asset_id` lost to `risk`, paid to `beneficiary` if
+        /// approved. `ClaimDeposit` is reserved until the claim is decided.
+        ///
+        /// - `evidence`: hash of the evidence of the loss, published off chain
+        #[pallet::weight(T::WeightInfo::file_claim())]
+        #[transactional]
+        pub fn file_claim(
+            origin: OriginFor<T>,
+            risk: Risk,
+            asset_id: CurrencyId,
+            amount: Balance,
+            beneficiary: T::AccountId,
+            evidence: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+
+            let deposit = T::ClaimDeposit::get();
+            T::Currency::reserve(&who, deposit)?;
+            let claim_id = NextClaimId::<T>::try_mutate(|next_id| -> Result<_, DispatchError> {
+                let id = *next_id;
+                *next_id = next_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+                Ok(id)
+            })?;
+            Claims::<T>::insert(
+                claim_id,
+                Claim {
+                    claimant: who.clone(),
+                    beneficiary,
+                    risk,
+                    asset_id,
+                    amount,
+                    evidence,
+                    deposit,
+                    filed_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            Self::deposit_event(Event::<T>::ClaimFiled(
+                claim_id, who, risk, asset_id, amount,
+            ));
+            Ok(())
+        }
+
+        /// Approve a claim, paying `payout` to its beneficiary out of its bucket and
+        /// returning its deposit.
+        ///
+        /// - `payout`: amount compensated, at most the amount claimed
+        #[pallet::weight(T::WeightInfo::approve_claim())]
+        #[transactional]
+        pub fn approve_claim(
+            origin: OriginFor<T>,
+            claim_id: ClaimId,
+            payout: Balance,
+        ) -> DispatchResult {
+            T::ClaimOrigin::ensure_origin(origin)?;
+            let claim = Claims::<T>::take(claim_id).ok_or(Error::<T>::ClaimNotFound)?;
+            ensure!(payout <= claim.amount, Error::<T>::PayoutExceedsClaim);
+
+            Buckets::<T>::try_mutate(claim.risk, claim.asset_id, |bucket| -> DispatchResult {
+                bucket.balance = bucket
+                    .balance
+                    .checked_sub(payout)
+                    .ok_or(Error::<T>::InsufficientBucketBalance)?;
+                bucket.paid_out = bucket.paid_out.saturating_add(payout);
+                Ok(())
+            })?;
+            if !payout.is_zero() {
+                T::Assets::transfer(
+                    claim.asset_id,
+                    &Self::account_id(),
+                    &claim.beneficiary,
+                    payout,
+                    false,
+                )?;
+            }
+            T::Currency::unreserve(&claim.claimant, claim.deposit);
+
+            Self::deposit_event(Event::<T>::ClaimPaid(claim_id, claim.beneficiary, payout));
+            Ok(())
+        }
+
+        /// Reject a claim, its deposit is slashed.
+        #[pallet::weight(T::WeightInfo::reject_claim())]
+        #[transactional]
+        pub fn reject_claim(origin: OriginFor<T>, claim_id: ClaimId) -> DispatchResult {
+            T::ClaimOrigin::ensure_origin(origin)?;
+            let claim = Claims::<T>::take(claim_id).ok_or(Error::<T>::ClaimNotFound)?;
+            let (imbalance, _) = T::Currency::slash_reserved(&claim.claimant, claim.deposit);
+            T::Slash::on_unbalanced(imbalance);
+
+            Self::deposit_event(Event::<T>::ClaimRejected(claim_id));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Account holding the funds of all the buckets
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    fn do_fund(
+        from: &T::AccountId,
+        risk: Risk,
+        asset_id: CurrencyId,
+        amount: Balance,
+    ) -> DispatchResult {
+        T::Assets::transfer(asset_id, from, &Self::account_id(), amount, false)?;
+        Buckets::<T>::try_mutate(risk, asset_id, |bucket| -> DispatchResult {
+            bucket.balance = bucket
+                .balance
+                .checked_add(amount)
+                .ok_or(ArithmeticError::Overflow)?;
+            bucket.funded = bucket.funded.saturating_add(amount);
+            Ok(())
+        })
+    }
+}
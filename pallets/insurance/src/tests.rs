@@ -0,0 +1,266 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+fn file_claim(amount: Balance) -> ClaimId {
+    let claim_id = Insurance::next_claim_id();
+    assert_ok!(Insurance::file_claim(
+        RuntimeOrigin::signed(ALICE),
+        Risk::OracleFailure,
+        DOT,
+        amount,
+        BOB,
+        H256::repeat_byte(1)
+    ));
+    claim_id
+}
+
+#[test]
+fn set_revenue_split_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Insurance::set_revenue_split(
+                RuntimeOrigin::signed(ALICE),
+                Risk::OracleFailure,
+                Some(Ratio::from_percent(60))
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Insurance::set_revenue_split(
+            RuntimeOrigin::root(),
+            Risk::OracleFailure,
+            Some(Ratio::from_percent(60))
+        ));
+        assert_noop!(
+            Insurance::set_revenue_split(
+                RuntimeOrigin::root(),
+                Risk::BridgeExploit,
+                Some(Ratio::from_percent(50))
+            ),
+            Error::<Test>::SplitsExceedRevenue
+        );
+        // the split being replaced doesn't count
+        assert_ok!(Insurance::set_revenue_split(
+            RuntimeOrigin::root(),
+            Risk::OracleFailure,
+            Some(Ratio::from_percent(50))
+        ));
+        assert_ok!(Insurance::set_revenue_split(
+            RuntimeOrigin::root(),
+            Risk::BridgeExploit,
+            Some(Ratio::from_percent(50))
+        ));
+        System::assert_last_event(RuntimeEvent::Insurance(Event::RevenueSplitUpdated(
+            Risk::BridgeExploit,
+            Some(Ratio::from_percent(50)),
+        )));
+
+        assert_ok!(Insurance::set_revenue_split(
+            RuntimeOrigin::root(),
+            Risk::OracleFailure,
+            None
+        ));
+        assert_eq!(Insurance::revenue_splits(Risk::OracleFailure), None);
+        assert_eq!(
+            Insurance::revenue_splits(Risk::BridgeExploit),
+            Some(Ratio::from_percent(50))
+        );
+    })
+}
+
+#[test]
+fn collect_revenue_funds_buckets_once_per_epoch() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Insurance::set_revenue_split(
+            RuntimeOrigin::root(),
+            Risk::OracleFailure,
+            Some(Ratio::from_percent(10))
+        ));
+        assert_ok!(Insurance::set_revenue_split(
+            RuntimeOrigin::root(),
+            Risk::RelaySlash,
+            Some(Ratio::from_percent(20))
+        ));
+
+        assert_ok!(Insurance::collect_revenue(RuntimeOrigin::signed(BOB), DOT));
+        assert_eq!(Assets::balance(DOT, REVENUE), dollar(700));
+        assert_eq!(Assets::balance(DOT, Insurance::account_id()), dollar(300));
+        assert_eq!(
+            Insurance::buckets(Risk::OracleFailure, DOT),
+            Bucket {
+                balance: dollar(100),
+                funded: dollar(100),
+                paid_out: 0,
+            }
+        );
+        assert_eq!(
+            Insurance::buckets(Risk::RelaySlash, DOT).balance,
+            dollar(200)
+        );
+        assert_eq!(
+            Insurance::buckets(Risk::BridgeExploit, DOT),
+            Bucket::default()
+        );
+        System::assert_has_event(RuntimeEvent::Insurance(Event::RevenueCollected(
+            Risk::RelaySlash,
+            DOT,
+            dollar(200),
+        )));
+
+        // each asset is collected once per epoch
+        System::set_block_number(10);
+        assert_noop!(
+            Insurance::collect_revenue(RuntimeOrigin::signed(BOB), DOT),
+            Error::<Test>::CollectedThisEpoch
+        );
+        assert_ok!(Insurance::collect_revenue(RuntimeOrigin::signed(BOB), USDT));
+
+        System::set_block_number(11);
+        assert_ok!(Insurance::collect_revenue(RuntimeOrigin::signed(BOB), DOT));
+        assert_eq!(Assets::balance(DOT, REVENUE), dollar(490));
+        assert_eq!(
+            Insurance::buckets(Risk::OracleFailure, DOT).funded,
+            dollar(170)
+        );
+    })
+}
+
+#[test]
+fn fund_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Insurance::fund(RuntimeOrigin::signed(ALICE), Risk::BridgeExploit, DOT, 0),
+            Error::<Test>::InvalidAmount
+        );
+        assert_ok!(Insurance::fund(
+            RuntimeOrigin::signed(ALICE),
+            Risk::BridgeExploit,
+            DOT,
+            dollar(40)
+        ));
+        assert_eq!(Assets::balance(DOT, ALICE), dollar(60));
+        assert_eq!(
+            Insurance::buckets(Risk::BridgeExploit, DOT),
+            Bucket {
+                balance: dollar(40),
+                funded: dollar(40),
+                paid_out: 0,
+            }
+        );
+        System::assert_last_event(RuntimeEvent::Insurance(Event::Funded(
+            ALICE,
+            Risk::BridgeExploit,
+            DOT,
+            dollar(40),
+        )));
+    })
+}
+
+#[test]
+fn approve_claim_pays_out_of_its_bucket() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Insurance::fund(
+            RuntimeOrigin::signed(ALICE),
+            Risk::OracleFailure,
+            DOT,
+            dollar(50)
+        ));
+        assert_ok!(Insurance::fund(
+            RuntimeOrigin::signed(ALICE),
+            Risk::RelaySlash,
+            DOT,
+            dollar(50)
+        ));
+
+        assert_noop!(
+            Insurance::file_claim(
+                RuntimeOrigin::signed(ALICE),
+                Risk::OracleFailure,
+                DOT,
+                0,
+                BOB,
+                H256::repeat_byte(1)
+            ),
+            Error::<Test>::InvalidAmount
+        );
+        let claim_id = file_claim(dollar(80));
+        assert_eq!(Balances::reserved_balance(ALICE), 10);
+        assert_eq!(
+            Insurance::claims(claim_id).map(|claim| claim.amount),
+            Some(dollar(80))
+        );
+
+        assert_noop!(
+            Insurance::approve_claim(RuntimeOrigin::signed(ALICE), claim_id, dollar(50)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Insurance::approve_claim(RuntimeOrigin::root(), claim_id, dollar(90)),
+            Error::<Test>::PayoutExceedsClaim
+        );
+        // the other buckets don't pay for the risk
+        assert_noop!(
+            Insurance::approve_claim(RuntimeOrigin::root(), claim_id, dollar(60)),
+            Error::<Test>::InsufficientBucketBalance
+        );
+
+        assert_ok!(Insurance::approve_claim(
+            RuntimeOrigin::root(),
+            claim_id,
+            dollar(30)
+        ));
+        assert_eq!(Assets::balance(DOT, BOB), dollar(130));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Insurance::claims(claim_id), None);
+        assert_eq!(
+            Insurance::buckets(Risk::OracleFailure, DOT),
+            Bucket {
+                balance: dollar(20),
+                funded: dollar(50),
+                paid_out: dollar(30),
+            }
+        );
+        System::assert_last_event(RuntimeEvent::Insurance(Event::ClaimPaid(
+            claim_id,
+            BOB,
+            dollar(30),
+        )));
+        assert_noop!(
+            Insurance::approve_claim(RuntimeOrigin::root(), claim_id, dollar(30)),
+            Error::<Test>::ClaimNotFound
+        );
+    })
+}
+
+#[test]
+fn reject_claim_slashes_deposit() {
+    new_test_ext().execute_with(|| {
+        let claim_id = file_claim(dollar(80));
+        assert_noop!(
+            Insurance::reject_claim(RuntimeOrigin::signed(BOB), claim_id),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Insurance::reject_claim(RuntimeOrigin::root(), claim_id));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 90);
+        assert_eq!(Insurance::claims(claim_id), None);
+        System::assert_last_event(RuntimeEvent::Insurance(Event::ClaimRejected(claim_id)));
+    })
+}
@@ -0,0 +1,62 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use primitives::{Balance, CurrencyId};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Identifier of a claim
+pub type ClaimId = u32;
+
+/// Risks the insurance funds are earmarked for
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Risk {
+    /// Losses caused by wrong or missing oracle prices
+    OracleFailure,
+    /// Losses of the assets bridged to and from other chains
+    BridgeExploit,
+    /// Slashes of the stake of the liquid staking derivatives on the relay chain
+    RelaySlash,
+}
+
+/// Funds of an asset earmarked for a risk
+#[derive(
+    Clone, Copy, PartialEq, Eq, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct Bucket {
+    /// Funds available for payouts
+    pub balance: Balance,
+    /// Funds put into the bucket so far
+    pub funded: Balance,
+    /// Funds paid out of the bucket so far
+    pub paid_out: Balance,
+}
+
+/// Claim for compensation of a loss, pending the decision of governance
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Claim<AccountId, BlockNumber, Hash> {
+    /// Account which filed the claim and reserved its deposit
+    pub claimant: AccountId,
+    /// Account the payout goes to
+    pub beneficiary: AccountId,
+    pub risk: Risk,
+    pub asset_id: CurrencyId,
+    /// Loss claimed
+    pub amount: Balance,
+    /// Hash of the evidence of the loss, published off chain
+    pub evidence: Hash,
+    pub deposit: Balance,
+    pub filed_at: BlockNumber,
+}
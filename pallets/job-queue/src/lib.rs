@@ -0,0 +1,236 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Job Queue pallet
+//!
+//! ## Overview
+//!
+//! Pallets defer heavy work, like sweeping a market or recomputing an index, by
+//! scheduling a call through `JobScheduler`. Scheduled calls are dispatched as root in
+//! the weight left by `on_idle`, the high priority jobs first and in the order they were
+//! scheduled otherwise. A failing job is retried in the next blocks until it has failed
+//! `MaxAttempts` times, then it's dropped.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    dispatch::{DispatchErrorWithPostInfo, Dispatchable, GetDispatchInfo, PostDispatchInfo},
+    pallet_prelude::*,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use pallet_traits::{JobPriority, JobScheduler};
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub type JobId = u64;
+
+/// A call waiting to be dispatched
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ScheduledJob<Call> {
+    pub call: Call,
+    pub priority: JobPriority,
+    /// Number of failed dispatches
+    pub attempts: u32,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The calls which can be scheduled
+        type Job: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo;
+
+        /// Max number of jobs waiting in the queue
+        #[pallet::constant]
+        type MaxJobs: Get<u32>;
+
+        /// Number of failed dispatches after which a job is dropped
+        #[pallet::constant]
+        type MaxAttempts: Get<u32>;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Job was scheduled
+        /// [id, priority]
+        JobScheduled(JobId, JobPriority),
+        /// Job was dispatched successfully
+        /// [id]
+        JobExecuted(JobId),
+        /// Job failed and will be retried
+        /// [id, error, attempts]
+        JobFailed(JobId, DispatchError, u32),
+        /// Job failed too many times and was dropped
+        /// [id, error]
+        JobDropped(JobId, DispatchError),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The queue is full
+        TooManyJobs,
+    }
+
+    /// Id of the next scheduled job
+    #[pallet::storage]
+    pub type NextJobId<T: Config> = StorageValue<_, JobId, ValueQuery>;
+
+    /// Jobs waiting to be dispatched
+    /// JobId -> ScheduledJob
+    #[pallet::storage]
+    #[pallet::getter(fn job)]
+    pub type Jobs<T: Config> =
+        CountedStorageMap<_, Twox64Concat, JobId, ScheduledJob<T::Job>, OptionQuery>;
+
+    /// Ids of the jobs of each priority, in dispatch order
+    /// JobPriority -> Vec<JobId>
+    #[pallet::storage]
+    #[pallet::getter(fn queue)]
+    pub type Queue<T: Config> = StorageMap<_, Twox64Concat, JobPriority, Vec<JobId>, ValueQuery>;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            if Jobs::<T>::count() == 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            // Reading the queue of a priority and writing it back
+            let queue_overhead = T::DbWeight::get().reads_writes(1, 1);
+            // Reading the job and removing or updating it
+            let job_overhead = T::DbWeight::get().reads_writes(1, 2);
+            let mut consumed = T::DbWeight::get().reads(1);
+
+            for priority in JobPriority::all() {
+                if consumed
+                    .saturating_add(queue_overhead)
+                    .any_gt(remaining_weight)
+                {
+                    break;
+                }
+                consumed.saturating_accrue(queue_overhead);
+                let queue = Self::queue(priority);
+                let mut pending = Vec::with_capacity(queue.len());
+                let mut exhausted = false;
+
+                for id in queue {
+                    if exhausted {
+                        pending.push(id);
+                        continue;
+                    }
+                    let mut job = match Self::job(id) {
+                        Some(job) => job,
+                        None => continue,
+                    };
+                    let info = job.call.get_dispatch_info();
+                    if consumed
+                        .saturating_add(info.weight)
+                        .saturating_add(job_overhead)
+                        .any_gt(remaining_weight)
+                    {
+                        // Lower priority jobs must not overtake this one
+                        exhausted = true;
+                        pending.push(id);
+                        continue;
+                    }
+
+                    let result = job.call.clone().dispatch(RawOrigin::Root.into());
+                    let used = match &result {
+                        Ok(post_info) | Err(DispatchErrorWithPostInfo { post_info, .. }) => {
+                            post_info.calc_actual_weight(&info)
+                        }
+                    };
+                    consumed.saturating_accrue(used.saturating_add(job_overhead));
+
+                    match result {
+                        Ok(_) => {
+                            Jobs::<T>::remove(id);
+                            Self::deposit_event(Event::<T>::JobExecuted(id));
+                        }
+                        Err(err) => {
+                            job.attempts = job.attempts.saturating_add(1);
+                            if job.attempts >= T::MaxAttempts::get() {
+                                Jobs::<T>::remove(id);
+                                Self::deposit_event(Event::<T>::JobDropped(id, err.error));
+                            } else {
+                                let attempts = job.attempts;
+                                Jobs::<T>::insert(id, job);
+                                pending.push(id);
+                                Self::deposit_event(Event::<T>::JobFailed(id, err.error, attempts));
+                            }
+                        }
+                    }
+                }
+
+                Queue::<T>::insert(priority, pending);
+                if exhausted {
+                    break;
+                }
+            }
+
+            consumed
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Add `call` to the end of the queue of `priority`
+    pub fn schedule_job(call: T::Job, priority: JobPriority) -> Result<JobId, DispatchError> {
+        ensure!(
+            Jobs::<T>::count() < T::MaxJobs::get(),
+            Error::<T>::TooManyJobs
+        );
+
+        let id = NextJobId::<T>::mutate(|next| {
+            let id = *next;
+            *next = next.saturating_add(1);
+            id
+        });
+        Jobs::<T>::insert(
+            id,
+            ScheduledJob {
+                call,
+                priority,
+                attempts: 0,
+            },
+        );
+        Queue::<T>::append(priority, id);
+
+        Self::deposit_event(Event::<T>::JobScheduled(id, priority));
+        Ok(id)
+    }
+}
+
+impl<T: Config, Call: Into<T::Job>> JobScheduler<Call> for Pallet<T> {
+    fn schedule(call: Call, priority: JobPriority) -> Result<JobId, DispatchError> {
+        Self::schedule_job(call.into(), priority)
+    }
+}
@@ -0,0 +1,97 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::OnIdle};
+
+fn run_idle(remaining_weight: Weight) -> Weight {
+    JobQueue::on_idle(System::block_number(), remaining_weight)
+}
+
+fn call_weight(call: &RuntimeCall) -> Weight {
+    call.get_dispatch_info().weight
+}
+
+#[test]
+fn jobs_are_dispatched_by_priority() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            JobQueue::schedule(transfer(ALICE, 10), JobPriority::Low),
+            Ok(0)
+        );
+        assert_eq!(
+            JobQueue::schedule(transfer(ALICE, 20), JobPriority::High),
+            Ok(1)
+        );
+        assert_eq!(JobQueue::queue(JobPriority::Low), vec![0]);
+        assert_eq!(JobQueue::queue(JobPriority::High), vec![1]);
+
+        // Only one transfer fits, the high priority one goes first
+        let weight = call_weight(&transfer(ALICE, 10));
+        run_idle(weight);
+        assert_eq!(Balances::free_balance(BOB), 20);
+        assert_eq!(JobQueue::job(1), None);
+        System::assert_last_event(RuntimeEvent::JobQueue(Event::JobExecuted(1)));
+
+        run_idle(weight.saturating_mul(2));
+        assert_eq!(Balances::free_balance(BOB), 30);
+        assert!(JobQueue::queue(JobPriority::Low).is_empty());
+        assert_eq!(Jobs::<Test>::count(), 0);
+    });
+}
+
+#[test]
+fn jobs_wait_for_enough_weight() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(JobQueue::schedule(transfer(ALICE, 10), JobPriority::High));
+        assert_ok!(JobQueue::schedule(transfer(ALICE, 20), JobPriority::Normal));
+
+        let weight = call_weight(&transfer(ALICE, 10));
+        run_idle(weight.saturating_sub(Weight::from_ref_time(1)));
+        assert_eq!(Balances::free_balance(BOB), 0);
+        assert_eq!(JobQueue::queue(JobPriority::High), vec![0]);
+        assert_eq!(JobQueue::queue(JobPriority::Normal), vec![1]);
+
+        assert!(run_idle(weight.saturating_mul(2)).all_lte(weight.saturating_mul(2)));
+        assert_eq!(Balances::free_balance(BOB), 30);
+    });
+}
+
+#[test]
+fn failed_jobs_are_retried_then_dropped() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(JobQueue::schedule(
+            transfer(CHARLIE, 10),
+            JobPriority::Normal
+        ));
+        let weight = call_weight(&transfer(CHARLIE, 10)).saturating_mul(10);
+
+        // Failing jobs run once per block
+        run_idle(weight);
+        assert_eq!(JobQueue::job(0).map(|job| job.attempts), Some(1));
+        assert_eq!(JobQueue::queue(JobPriority::Normal), vec![0]);
+        assert!(matches!(
+            System::events().last().map(|record| record.event.clone()),
+            Some(RuntimeEvent::JobQueue(Event::JobFailed(0, _, 1)))
+        ));
+
+        run_idle(weight);
+        assert_eq!(JobQueue::job(0), None);
+        assert!(JobQueue::queue(JobPriority::Normal).is_empty());
+        assert!(matches!(
+            System::events().last().map(|record| record.event.clone()),
+            Some(RuntimeEvent::JobQueue(Event::JobDropped(0, _)))
+        ));
+    });
+}
+
+#[test]
+fn schedule_should_respect_max_jobs() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..MaxJobs::get() {
+            assert_ok!(JobQueue::schedule(transfer(ALICE, 1), JobPriority::Low));
+        }
+        assert_noop!(
+            JobQueue::schedule(transfer(ALICE, 1), JobPriority::High),
+            Error::<Test>::TooManyJobs
+        );
+    });
+}
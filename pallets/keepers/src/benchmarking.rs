@@ -0,0 +1,88 @@
+//! Keepers pallet benchmarking.
+//!
+//! `perform_task` is charged the weight of the dispatched call on top of a fixed
+//! overhead, there is no call every runtime classifies to benchmark it with.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Keepers;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{assert_ok, dispatch::UnfilteredDispatchable, traits::EnsureOrigin};
+use frame_system::RawOrigin as SystemOrigin;
+
+const INITIAL_AMOUNT: u128 = 100_000_000_000_000;
+
+fn register<T: Config>(keeper: &T::AccountId) {
+    T::Currency::make_free_balance_be(keeper, INITIAL_AMOUNT);
+    assert_ok!(Keepers::<T>::register_keeper(
+        SystemOrigin::Signed(keeper.clone()).into()
+    ));
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    register_keeper {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, INITIAL_AMOUNT);
+    }: _(SystemOrigin::Signed(caller.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::KeeperRegistered(caller, T::KeeperBond::get()).into());
+    }
+
+    unregister_keeper {
+        let caller: T::AccountId = whitelisted_caller();
+        register::<T>(&caller);
+    }: _(SystemOrigin::Signed(caller.clone()))
+    verify {
+        assert!(Keepers::<T>::keepers(&caller).unwrap().unbonding_at.is_some());
+    }
+
+    withdraw_bond {
+        let caller: T::AccountId = whitelisted_caller();
+        register::<T>(&caller);
+        assert_ok!(Keepers::<T>::unregister_keeper(
+            SystemOrigin::Signed(caller.clone()).into()
+        ));
+        let now = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(now + T::UnbondingPeriod::get());
+    }: _(SystemOrigin::Signed(caller.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::BondWithdrawn(caller, T::KeeperBond::get()).into());
+    }
+
+    set_task_config {
+        let origin = T::UpdateOrigin::successful_origin();
+        let config = TaskConfig {
+            reward: 1_000,
+            priority: 1,
+            cooldown: 10u32.into(),
+        };
+        let call = Call::<T>::set_task_config {
+            task: KeeperTask::Liquidation,
+            config: Some(config.clone()),
+        };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert_eq!(Keepers::<T>::task_configs(KeeperTask::Liquidation), Some(config));
+    }
+
+    slash_keeper {
+        let keeper: T::AccountId = account("keeper", 0, 0);
+        register::<T>(&keeper);
+        let origin = T::UpdateOrigin::successful_origin();
+        let call = Call::<T>::slash_keeper { keeper: keeper.clone() };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::KeeperSlashed(keeper, T::KeeperBond::get()).into());
+    }
+}
+
+impl_benchmark_test_suite!(Keepers, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,343 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Keepers pallet
+//!
+//! ## Overview
+//!
+//! A registry of keepers, the bots running the permissionless maintenance of the
+//! protocol: liquidations, interest accruals, bridge proposals, reward settlements...
+//! Keepers register by reserving `KeeperBond` and dispatch maintenance calls through
+//! `perform_task`. `Tasks` tells which task a call performs, a successful call is
+//! rewarded out of the pot of the pallet at the rate governance set for its task.
+//!
+//! To keep keepers from draining the pot with cheap calls, the same keeper performs
+//! a task at most once per cooldown, and governance can slash the bond of a
+//! keeper which griefs the protocol. While the pot is short, tasks are rewarded by
+//! priority. Leaving keepers get their bond back after `UnbondingPeriod`, during
+//! which they can still be slashed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+    pallet_prelude::*,
+    traits::{Currency, ExistenceRequirement, Imbalance, ReservableCurrency},
+    transactional, PalletId,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use primitives::Balance;
+use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+use sp_std::boxed::Box;
+
+pub use pallet::*;
+pub use types::{KeeperInfo, KeeperTask, TaskConfig};
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub type KeeperInfoOf<T> = KeeperInfo<<T as frame_system::Config>::BlockNumber>;
+pub type TaskConfigOf<T> = TaskConfig<<T as frame_system::Config>::BlockNumber>;
+
+/// Tells which keeper task a call performs
+pub trait ClassifyTask<Call> {
+    fn classify(call: &Call) -> Option<KeeperTask>;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The maintenance calls keepers perform
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + From<frame_system::Call<Self>>
+            + IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// The currency bonds are reserved and rewards paid in
+        type Currency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+        /// Tasks of the calls rewarded by `perform_task`
+        type Tasks: ClassifyTask<<Self as Config>::RuntimeCall>;
+
+        /// Account of the reward pot
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Amount reserved from a keeper while it's registered
+        #[pallet::constant]
+        type KeeperBond: Get<Balance>;
+
+        /// Blocks a leaving keeper waits for its bond
+        #[pallet::constant]
+        type UnbondingPeriod: Get<Self::BlockNumber>;
+
+        /// The origin which sets the task rewards and slashes keepers
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Keeper registered and reserved its bond
+        /// [keeper, bond]
+        KeeperRegistered(T::AccountId, Balance),
+        /// Keeper left, its bond can be withdrawn from `unbonding_at`
+        /// [keeper, unbonding_at]
+        KeeperUnbonding(T::AccountId, T::BlockNumber),
+        /// Keeper withdrew its bond
+        /// [keeper, bond]
+        BondWithdrawn(T::AccountId, Balance),
+        /// Bond of a keeper was slashed to the reward pot and the keeper removed
+        /// [keeper, slashed]
+        KeeperSlashed(T::AccountId, Balance),
+        /// Reward of a task was set or removed
+        /// [task, config]
+        TaskConfigUpdated(KeeperTask, Option<TaskConfigOf<T>>),
+        /// Keeper performed a task, the reward is zero if the pot couldn't pay it
+        /// [keeper, task, reward]
+        TaskPerformed(T::AccountId, KeeperTask, Balance),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account is already a keeper
+        AlreadyRegistered,
+        /// Account isn't a keeper
+        NotKeeper,
+        /// Keeper is leaving and can't perform tasks
+        KeeperUnbonding,
+        /// Keeper didn't leave
+        NotUnbonding,
+        /// Bond can't be withdrawn yet
+        UnbondingNotOver,
+        /// Call isn't a keeper task
+        NotKeeperTask,
+        /// Task has no reward set
+        TaskNotConfigured,
+        /// Keeper performed the task less than its cooldown ago
+        TaskInCooldown,
+    }
+
+    /// Registered keepers
+    /// AccountId -> KeeperInfo
+    #[pallet::storage]
+    #[pallet::getter(fn keepers)]
+    pub type Keepers<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, KeeperInfoOf<T>, OptionQuery>;
+
+    /// Rewards of the tasks
+    /// KeeperTask -> TaskConfig
+    #[pallet::storage]
+    #[pallet::getter(fn task_configs)]
+    pub type TaskConfigs<T: Config> =
+        StorageMap<_, Twox64Concat, KeeperTask, TaskConfigOf<T>, OptionQuery>;
+
+    /// Block at which a keeper last performed a task
+    /// KeeperTask -> AccountId -> BlockNumber
+    #[pallet::storage]
+    #[pallet::getter(fn last_performed)]
+    pub type LastPerformed<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        KeeperTask,
+        Blake2_128Concat,
+        T::AccountId,
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register the caller as a keeper, reserving `KeeperBond`
+        #[pallet::weight(<T as Config>::WeightInfo::register_keeper())]
+        #[transactional]
+        pub fn register_keeper(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                !Keepers::<T>::contains_key(&who),
+                Error::<T>::AlreadyRegistered
+            );
+
+            let bond = T::KeeperBond::get();
+            T::Currency::reserve(&who, bond)?;
+            Keepers::<T>::insert(
+                &who,
+                KeeperInfo {
+                    bond,
+                    unbonding_at: None,
+                    rewarded: Zero::zero(),
+                },
+            );
+
+            Self::deposit_event(Event::<T>::KeeperRegistered(who, bond));
+            Ok(())
+        }
+
+        /// Stop performing tasks, the bond can be withdrawn after `UnbondingPeriod`
+        #[pallet::weight(<T as Config>::WeightInfo::unregister_keeper())]
+        #[transactional]
+        pub fn unregister_keeper(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let unbonding_at = Keepers::<T>::try_mutate(&who, |keeper| {
+                let keeper = keeper.as_mut().ok_or(Error::<T>::NotKeeper)?;
+                ensure!(keeper.unbonding_at.is_none(), Error::<T>::KeeperUnbonding);
+                let unbonding_at = frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::UnbondingPeriod::get());
+                keeper.unbonding_at = Some(unbonding_at);
+                Ok::<_, DispatchError>(unbonding_at)
+            })?;
+
+            Self::deposit_event(Event::<T>::KeeperUnbonding(who, unbonding_at));
+            Ok(())
+        }
+
+        /// Unreserve the bond of a keeper which left once `UnbondingPeriod` is over
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_bond())]
+        #[transactional]
+        pub fn withdraw_bond(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let keeper = Self::keepers(&who).ok_or(Error::<T>::NotKeeper)?;
+            let unbonding_at = keeper.unbonding_at.ok_or(Error::<T>::NotUnbonding)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= unbonding_at,
+                Error::<T>::UnbondingNotOver
+            );
+
+            Keepers::<T>::remove(&who);
+            T::Currency::unreserve(&who, keeper.bond);
+
+            Self::deposit_event(Event::<T>::BondWithdrawn(who, keeper.bond));
+            Ok(())
+        }
+
+        /// Dispatch a maintenance call as the calling keeper and pay the reward of
+        /// its task if it succeeds
+        #[pallet::weight({
+            let di = call.get_dispatch_info();
+            (<T as Config>::WeightInfo::perform_task().saturating_add(di.weight), di.class)
+        })]
+        #[transactional]
+        pub fn perform_task(
+            origin: OriginFor<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let keeper = Self::keepers(&who).ok_or(Error::<T>::NotKeeper)?;
+            ensure!(keeper.unbonding_at.is_none(), Error::<T>::KeeperUnbonding);
+            let task = T::Tasks::classify(&call).ok_or(Error::<T>::NotKeeperTask)?;
+            let config = Self::task_configs(task).ok_or(Error::<T>::TaskNotConfigured)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(last_performed) = Self::last_performed(task, &who) {
+                ensure!(
+                    now >= last_performed.saturating_add(config.cooldown),
+                    Error::<T>::TaskInCooldown
+                );
+            }
+
+            call.dispatch(RawOrigin::Signed(who.clone()).into())
+                .map_err(|e| e.error)?;
+
+            let reward = if Self::can_pay(&config) {
+                T::Currency::transfer(
+                    &Self::account_id(),
+                    &who,
+                    config.reward,
+                    ExistenceRequirement::KeepAlive,
+                )?;
+                config.reward
+            } else {
+                Zero::zero()
+            };
+            LastPerformed::<T>::insert(task, &who, now);
+            Keepers::<T>::mutate(&who, |keeper| {
+                if let Some(keeper) = keeper {
+                    keeper.rewarded = keeper.rewarded.saturating_add(reward);
+                }
+            });
+
+            Self::deposit_event(Event::<T>::TaskPerformed(who, task, reward));
+            Ok(())
+        }
+
+        /// Set the reward of `task`, `None` stops rewarding it
+        #[pallet::weight(<T as Config>::WeightInfo::set_task_config())]
+        #[transactional]
+        pub fn set_task_config(
+            origin: OriginFor<T>,
+            task: KeeperTask,
+            config: Option<TaskConfigOf<T>>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            TaskConfigs::<T>::set(task, config.clone());
+
+            Self::deposit_event(Event::<T>::TaskConfigUpdated(task, config));
+            Ok(())
+        }
+
+        /// Slash the whole bond of `keeper` to the reward pot and remove it
+        #[pallet::weight(<T as Config>::WeightInfo::slash_keeper())]
+        #[transactional]
+        pub fn slash_keeper(origin: OriginFor<T>, keeper: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let info = Keepers::<T>::take(&keeper).ok_or(Error::<T>::NotKeeper)?;
+
+            let (imbalance, _) = T::Currency::slash_reserved(&keeper, info.bond);
+            let slashed = imbalance.peek();
+            T::Currency::resolve_creating(&Self::account_id(), imbalance);
+
+            Self::deposit_event(Event::<T>::KeeperSlashed(keeper, slashed));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Account of the reward pot
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    /// Whether the pot pays the reward of `config` and still covers one reward of
+    /// every task with a higher priority
+    fn can_pay(config: &TaskConfigOf<T>) -> bool {
+        let reserved = TaskConfigs::<T>::iter_values()
+            .filter(|c| c.priority > config.priority)
+            .fold(Zero::zero(), |acc: Balance, c| acc.saturating_add(c.reward));
+        let available = T::Currency::free_balance(&Self::account_id())
+            .saturating_sub(T::Currency::minimum_balance());
+        available >= reserved.saturating_add(config.reward)
+    }
+}
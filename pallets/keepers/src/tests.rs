@@ -0,0 +1,188 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+
+fn remark() -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::System(frame_system::Call::remark {
+        remark: vec![],
+    }))
+}
+
+fn transfer(dest: AccountId, value: Balance) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer {
+        dest,
+        value,
+    }))
+}
+
+fn set_task(task: KeeperTask, reward: Balance, priority: u8, cooldown: u64) {
+    assert_ok!(Keepers::set_task_config(
+        RuntimeOrigin::root(),
+        task,
+        Some(TaskConfig {
+            reward,
+            priority,
+            cooldown,
+        })
+    ));
+}
+
+#[test]
+fn keepers_should_bond_until_unbonding_is_over() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Keepers::register_keeper(RuntimeOrigin::signed(ALICE)));
+        System::assert_last_event(RuntimeEvent::Keepers(Event::KeeperRegistered(ALICE, 100)));
+        assert_eq!(Balances::reserved_balance(ALICE), 100);
+        assert_noop!(
+            Keepers::register_keeper(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::AlreadyRegistered
+        );
+
+        assert_noop!(
+            Keepers::withdraw_bond(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::NotUnbonding
+        );
+        assert_ok!(Keepers::unregister_keeper(RuntimeOrigin::signed(ALICE)));
+        System::assert_last_event(RuntimeEvent::Keepers(Event::KeeperUnbonding(ALICE, 11)));
+
+        // Leaving keepers can't perform tasks anymore
+        set_task(KeeperTask::RewardSettlement, 10, 0, 0);
+        assert_noop!(
+            Keepers::perform_task(RuntimeOrigin::signed(ALICE), remark()),
+            Error::<Test>::KeeperUnbonding
+        );
+
+        assert_noop!(
+            Keepers::withdraw_bond(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::UnbondingNotOver
+        );
+        System::set_block_number(11);
+        assert_ok!(Keepers::withdraw_bond(RuntimeOrigin::signed(ALICE)));
+        System::assert_last_event(RuntimeEvent::Keepers(Event::BondWithdrawn(ALICE, 100)));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert!(Keepers::keepers(ALICE).is_none());
+    });
+}
+
+#[test]
+fn perform_task_should_reward_keepers() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Keepers::perform_task(RuntimeOrigin::signed(ALICE), remark()),
+            Error::<Test>::NotKeeper
+        );
+        assert_ok!(Keepers::register_keeper(RuntimeOrigin::signed(ALICE)));
+
+        let not_a_task = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+            remark: vec![],
+        }));
+        assert_noop!(
+            Keepers::perform_task(RuntimeOrigin::signed(ALICE), not_a_task),
+            Error::<Test>::NotKeeperTask
+        );
+        assert_noop!(
+            Keepers::perform_task(RuntimeOrigin::signed(ALICE), remark()),
+            Error::<Test>::TaskNotConfigured
+        );
+
+        set_task(KeeperTask::RewardSettlement, 10, 0, 5);
+        assert_ok!(Keepers::perform_task(
+            RuntimeOrigin::signed(ALICE),
+            remark()
+        ));
+        System::assert_last_event(RuntimeEvent::Keepers(Event::TaskPerformed(
+            ALICE,
+            KeeperTask::RewardSettlement,
+            10,
+        )));
+        assert_eq!(Balances::free_balance(ALICE), 1_000 - 100 + 10);
+        assert_eq!(Keepers::keepers(ALICE).unwrap().rewarded, 10);
+
+        // The cooldown is per keeper
+        assert_noop!(
+            Keepers::perform_task(RuntimeOrigin::signed(ALICE), remark()),
+            Error::<Test>::TaskInCooldown
+        );
+        assert_ok!(Keepers::register_keeper(RuntimeOrigin::signed(BOB)));
+        assert_ok!(Keepers::perform_task(RuntimeOrigin::signed(BOB), remark()));
+
+        System::set_block_number(6);
+        assert_ok!(Keepers::perform_task(
+            RuntimeOrigin::signed(ALICE),
+            remark()
+        ));
+        assert_eq!(Keepers::keepers(ALICE).unwrap().rewarded, 20);
+        assert_eq!(
+            Keepers::last_performed(KeeperTask::RewardSettlement, ALICE),
+            Some(6)
+        );
+    });
+}
+
+#[test]
+fn failed_tasks_should_not_be_rewarded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Keepers::register_keeper(RuntimeOrigin::signed(ALICE)));
+        set_task(KeeperTask::Liquidation, 10, 0, 5);
+
+        assert!(
+            Keepers::perform_task(RuntimeOrigin::signed(ALICE), transfer(BOB, 10_000)).is_err()
+        );
+        assert_eq!(Balances::free_balance(ALICE), 1_000 - 100);
+        assert_eq!(
+            Keepers::last_performed(KeeperTask::Liquidation, ALICE),
+            None
+        );
+    });
+}
+
+#[test]
+fn higher_priority_tasks_should_be_rewarded_first() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Keepers::register_keeper(RuntimeOrigin::signed(ALICE)));
+        set_task(KeeperTask::Liquidation, 60, 2, 0);
+        set_task(KeeperTask::RewardSettlement, 50, 1, 0);
+
+        // 100 in the pot can't pay 50 while keeping 60 for liquidations
+        assert_ok!(Keepers::perform_task(
+            RuntimeOrigin::signed(ALICE),
+            remark()
+        ));
+        System::assert_last_event(RuntimeEvent::Keepers(Event::TaskPerformed(
+            ALICE,
+            KeeperTask::RewardSettlement,
+            0,
+        )));
+
+        assert_ok!(Keepers::perform_task(
+            RuntimeOrigin::signed(ALICE),
+            transfer(BOB, 10)
+        ));
+        System::assert_last_event(RuntimeEvent::Keepers(Event::TaskPerformed(
+            ALICE,
+            KeeperTask::Liquidation,
+            60,
+        )));
+        assert_eq!(Balances::free_balance(Keepers::account_id()), 41);
+    });
+}
+
+#[test]
+fn slashed_bonds_should_fund_the_pot() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Keepers::register_keeper(RuntimeOrigin::signed(ALICE)));
+        assert_noop!(
+            Keepers::slash_keeper(RuntimeOrigin::signed(BOB), ALICE),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Keepers::slash_keeper(RuntimeOrigin::root(), ALICE));
+        System::assert_last_event(RuntimeEvent::Keepers(Event::KeeperSlashed(ALICE, 100)));
+        assert_eq!(Balances::total_balance(&ALICE), 900);
+        assert_eq!(Balances::free_balance(Keepers::account_id()), 201);
+        assert_noop!(
+            Keepers::slash_keeper(RuntimeOrigin::root(), ALICE),
+            Error::<Test>::NotKeeper
+        );
+    });
+}
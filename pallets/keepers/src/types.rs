@@ -0,0 +1,53 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use primitives::Balance;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Permissionless maintenance work keepers are rewarded for
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum KeeperTask {
+    /// Liquidating an unhealthy borrow
+    Liquidation,
+    /// Accruing the interest of a market
+    InterestAccrual,
+    /// Executing an approved bridge proposal
+    BridgeProposal,
+    /// Settling the pending rewards of a market or pool
+    RewardSettlement,
+}
+
+/// Reward of a task, set by governance
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct TaskConfig<BlockNumber> {
+    /// Paid to the keeper out of the reward pot
+    pub reward: Balance,
+    /// While the pot is short, a task is only rewarded if the pot still covers
+    /// the rewards of the tasks with a higher priority
+    pub priority: u8,
+    /// Blocks before the same keeper can perform the task again
+    pub cooldown: BlockNumber,
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct KeeperInfo<BlockNumber> {
+    /// Reserved from the keeper while it's registered
+    pub bond: Balance,
+    /// Block from which the bond can be withdrawn, once the keeper left
+    pub unbonding_at: Option<BlockNumber>,
+    /// Rewards paid to the keeper so far
+    pub rewarded: Balance,
+}
@@ -0,0 +1,152 @@
+// This file is part of Parallel Finance.
+
+// Copyright (C) 2022 Parallel Finance Developer.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_keepers
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet-keepers
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/keepers/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions needed for pallet_keepers.
+pub trait WeightInfo {
+	fn register_keeper() -> Weight;
+	fn unregister_keeper() -> Weight;
+	fn withdraw_bond() -> Weight;
+	fn perform_task() -> Weight;
+	fn set_task_config() -> Weight;
+	fn slash_keeper() -> Weight;
+}
+
+/// Weights for pallet_keepers using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Keepers Keepers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn register_keeper() -> Weight {
+		Weight::from_ref_time(36_222_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Keepers Keepers (r:1 w:1)
+	fn unregister_keeper() -> Weight {
+		Weight::from_ref_time(21_354_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Keepers Keepers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn withdraw_bond() -> Weight {
+		Weight::from_ref_time(36_206_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Keepers Keepers (r:2 w:1)
+	// Storage: Keepers TaskConfigs (r:5 w:0)
+	// Storage: Keepers LastPerformed (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn perform_task() -> Weight {
+		Weight::from_ref_time(53_072_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Keepers TaskConfigs (r:0 w:1)
+	fn set_task_config() -> Weight {
+		Weight::from_ref_time(14_386_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Keepers Keepers (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn slash_keeper() -> Weight {
+		Weight::from_ref_time(41_919_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Keepers Keepers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn register_keeper() -> Weight {
+		Weight::from_ref_time(36_222_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Keepers Keepers (r:1 w:1)
+	fn unregister_keeper() -> Weight {
+		Weight::from_ref_time(21_354_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Keepers Keepers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn withdraw_bond() -> Weight {
+		Weight::from_ref_time(36_206_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Keepers Keepers (r:2 w:1)
+	// Storage: Keepers TaskConfigs (r:5 w:0)
+	// Storage: Keepers LastPerformed (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn perform_task() -> Weight {
+		Weight::from_ref_time(53_072_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Keepers TaskConfigs (r:0 w:1)
+	fn set_task_config() -> Weight {
+		Weight::from_ref_time(14_386_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Keepers Keepers (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn slash_keeper() -> Weight {
+		Weight::from_ref_time(41_919_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+}
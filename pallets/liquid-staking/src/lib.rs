@@ -17,22 +17,30 @@
 //! ## Overview
 //!
 //! This pallet manages the NPoS operations for relay chain asset.
+//!
+//! The pallet is instantiable: a runtime can add one instance per staking asset, each with
+//! its own staking/liquid currencies, pallet account, derivative indexes and storage.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::traits::{fungibles::InspectMetadata, tokens::Balance as BalanceT, Get};
+use frame_support::{
+    ensure,
+    traits::{fungibles::InspectMetadata, tokens::Balance as BalanceT, Get},
+};
 use sp_runtime::{
-    traits::{One, Zero},
-    FixedPointNumber, FixedPointOperand,
+    traits::{CheckedSub, One, Saturating, Zero},
+    ArithmeticError, DispatchError, FixedPointNumber, FixedPointOperand,
 };
+use sp_std::{vec, vec::Vec};
 
 pub use pallet::*;
 use pallet_traits::{
-    DistributionStrategy, ExchangeRateProvider, LiquidStakingConvert,
-    LiquidStakingCurrenciesProvider, Loans, LoansMarketDataProvider, LoansPositionDataProvider,
-    ValidationDataProvider,
+    CircuitBreaker, CircuitBreakerTrigger, DistributionStrategy, EraProvider, ExchangeRateProvider,
+    LiquidStaking, LiquidStakingConvert, LiquidStakingCurrenciesProvider, Loans,
+    LoansMarketDataProvider, LoansPositionDataProvider, ProtocolConversion, Referrals,
+    RevenueRecorder, RevenueSource, ValidationDataProvider, VolumeSource,
 };
-use primitives::{PersistedValidationData, Rate};
+use primitives::{PersistedValidationData, Rate, Ratio};
 
 mod benchmarking;
 
@@ -74,8 +82,8 @@ pub mod pallet {
     use pallet_xcm::ensure_response;
     use sp_runtime::{
         traits::{
-            AccountIdConversion, BlakeTwo256, BlockNumberProvider, CheckedDiv, CheckedSub,
-            Saturating, StaticLookup,
+            AccountIdConversion, BlakeTwo256, BlockNumberProvider, CheckedSub, Saturating,
+            StaticLookup,
         },
         ArithmeticError, FixedPointNumber, TransactionOutcome,
     };
@@ -83,7 +91,7 @@ pub mod pallet {
     use sp_trie::StorageProof;
     use xcm::latest::prelude::*;
 
-    use pallet_traits::ump::*;
+    use pallet_traits::{ump::*, xcm::XcmResponseCallback};
     use pallet_xcm_helper::XcmHelper;
     use primitives::{Balance, CurrencyId, DerivativeIndex, EraIndex, ParaId, Rate, Ratio};
 
@@ -92,15 +100,15 @@ pub mod pallet {
     pub const MAX_UNLOCKING_CHUNKS: usize = 32;
 
     pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
-    pub type AssetIdOf<T> =
-        <<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
-    pub type BalanceOf<T> =
-        <<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type AssetIdOf<T, I = ()> =
+        <<T as Config<I>>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+    pub type BalanceOf<T, I = ()> =
+        <<T as Config<I>>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
     #[pallet::without_storage_info]
-    pub struct Pallet<T>(_);
+    pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
     /// Utility type for managing upgrades/migrations.
     #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
@@ -111,13 +119,14 @@ pub mod pallet {
     }
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + pallet_utility::Config + pallet_xcm::Config {
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+    pub trait Config<I: 'static = ()>:
+        frame_system::Config + pallet_utility::Config + pallet_xcm::Config
+    {
+        type RuntimeEvent: From<Event<Self, I>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         type RuntimeOrigin: IsType<<Self as frame_system::Config>::RuntimeOrigin>
-            + Into<Result<pallet_xcm::Origin, <Self as Config>::RuntimeOrigin>>;
-
-        type RuntimeCall: IsType<<Self as pallet_xcm::Config>::RuntimeCall> + From<Call<Self>>;
+            + Into<Result<pallet_xcm::Origin, <Self as Config<I>>::RuntimeOrigin>>;
 
         /// Assets for deposit/withdraw assets to/from pallet account
         type Assets: Transfer<Self::AccountId, AssetId = CurrencyId>
@@ -151,35 +160,36 @@ pub mod pallet {
 
         /// Xcm fees
         #[pallet::constant]
-        type XcmFees: Get<BalanceOf<Self>>;
+        type XcmFees: Get<BalanceOf<Self, I>>;
 
         /// Loans instant unstake fee
         #[pallet::constant]
         type LoansInstantUnstakeFee: Get<Rate>;
 
-        /// MatchingPool fast unstake fee
+        /// MatchingPool fast unstake fee, charged flat until a fee curve is set
+        /// through `update_fast_unstake_fee_curve`
         #[pallet::constant]
         type MatchingPoolFastUnstakeFee: Get<Rate>;
 
         /// Staking currency
         #[pallet::constant]
-        type StakingCurrency: Get<AssetIdOf<Self>>;
+        type StakingCurrency: Get<AssetIdOf<Self, I>>;
 
         /// Liquid currency
         #[pallet::constant]
-        type LiquidCurrency: Get<AssetIdOf<Self>>;
+        type LiquidCurrency: Get<AssetIdOf<Self, I>>;
 
         /// Collateral currency
         #[pallet::constant]
-        type CollateralCurrency: Get<AssetIdOf<Self>>;
+        type CollateralCurrency: Get<AssetIdOf<Self, I>>;
 
         /// Minimum stake amount
         #[pallet::constant]
-        type MinStake: Get<BalanceOf<Self>>;
+        type MinStake: Get<BalanceOf<Self, I>>;
 
         /// Minimum unstake amount
         #[pallet::constant]
-        type MinUnstake: Get<BalanceOf<Self>>;
+        type MinUnstake: Get<BalanceOf<Self, I>>;
 
         /// Weight information
         type WeightInfo: WeightInfo;
@@ -190,12 +200,14 @@ pub mod pallet {
 
         /// The minimum active bond to become and maintain the role of a nominator.
         #[pallet::constant]
-        type MinNominatorBond: Get<BalanceOf<Self>>;
+        type MinNominatorBond: Get<BalanceOf<Self, I>>;
 
-        /// Number of blocknumbers that each period contains.
-        /// SessionsPerEra * EpochDuration / MILLISECS_PER_BLOCK
+        /// The minimum active bond to join and stay in a relaychain nomination pool.
         #[pallet::constant]
-        type EraLength: Get<BlockNumberFor<Self>>;
+        type MinPoolJoinBond: Get<BalanceOf<Self, I>>;
+
+        /// Relaychain era tracker, the pallet advances its era to follow it
+        type RelayEra: EraProvider<BlockNumberFor<Self>>;
 
         #[pallet::constant]
         type NumSlashingSpans: Get<u32>;
@@ -205,15 +217,15 @@ pub mod pallet {
             + BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
 
         /// Loans
-        type Loans: Loans<AssetIdOf<Self>, Self::AccountId, BalanceOf<Self>>
-            + LoansPositionDataProvider<AssetIdOf<Self>, Self::AccountId, BalanceOf<Self>>
-            + LoansMarketDataProvider<AssetIdOf<Self>, BalanceOf<Self>>;
+        type Loans: Loans<AssetIdOf<Self, I>, Self::AccountId, BalanceOf<Self, I>>
+            + LoansPositionDataProvider<AssetIdOf<Self, I>, Self::AccountId, BalanceOf<Self, I>>
+            + LoansMarketDataProvider<AssetIdOf<Self, I>, BalanceOf<Self, I>>;
 
         /// To expose XCM helper functions
-        type XCM: XcmHelper<Self, BalanceOf<Self>, Self::AccountId>;
+        type XCM: XcmHelper<Self, BalanceOf<Self, I>, Self::AccountId>;
 
         /// Current strategy for distributing assets to multi-accounts
-        type DistributionStrategy: DistributionStrategy<BalanceOf<Self>>;
+        type DistributionStrategy: DistributionStrategy<BalanceOf<Self, I>>;
 
         /// Number of blocknumbers that do_matching after each era updated.
         /// Need to do_bond before relaychain store npos solution
@@ -223,68 +235,99 @@ pub mod pallet {
         /// Who/where to send the protocol fees
         #[pallet::constant]
         type ProtocolFeeReceiver: Get<Self::AccountId>;
+
+        /// Records the commission minted to the protocol
+        type Revenue: RevenueRecorder;
+
+        /// Records the staked volume of referred accounts
+        type Referrals: Referrals<Self::AccountId>;
+
+        /// Max change of the exchange rate in one era before it's treated as an anomaly
+        #[pallet::constant]
+        type MaxExchangeRateChange: Get<Ratio>;
+
+        /// Pauses liquid staking calls when the exchange rate moves abnormally
+        type CircuitBreaker: CircuitBreaker;
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         /// The assets get staked successfully
-        Staked(T::AccountId, BalanceOf<T>),
+        Staked(T::AccountId, BalanceOf<T, I>),
         /// The derivative get unstaked successfully
-        Unstaked(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+        Unstaked(T::AccountId, BalanceOf<T, I>, BalanceOf<T, I>),
         /// Staking ledger updated
-        StakingLedgerUpdated(DerivativeIndex, StakingLedger<T::AccountId, BalanceOf<T>>),
+        StakingLedgerUpdated(
+            DerivativeIndex,
+            StakingLedger<T::AccountId, BalanceOf<T, I>>,
+        ),
         /// Sent staking.bond call to relaychain
         Bonding(
             DerivativeIndex,
             T::AccountId,
-            BalanceOf<T>,
+            BalanceOf<T, I>,
             RewardDestination<T::AccountId>,
         ),
         /// Sent staking.bond_extra call to relaychain
-        BondingExtra(DerivativeIndex, BalanceOf<T>),
+        BondingExtra(DerivativeIndex, BalanceOf<T, I>),
         /// Sent staking.unbond call to relaychain
-        Unbonding(DerivativeIndex, BalanceOf<T>),
+        Unbonding(DerivativeIndex, BalanceOf<T, I>),
         /// Sent staking.rebond call to relaychain
-        Rebonding(DerivativeIndex, BalanceOf<T>),
+        Rebonding(DerivativeIndex, BalanceOf<T, I>),
         /// Sent staking.withdraw_unbonded call to relaychain
         WithdrawingUnbonded(DerivativeIndex, u32),
         /// Sent staking.nominate call to relaychain
         Nominating(DerivativeIndex, Vec<T::AccountId>),
         /// Staking ledger's cap was updated
-        StakingLedgerCapUpdated(BalanceOf<T>),
+        StakingLedgerCapUpdated(BalanceOf<T, I>),
         /// Reserve_factor was updated
         ReserveFactorUpdated(Ratio),
         /// Exchange rate was updated
         ExchangeRateUpdated(Rate),
-        /// Notification received
+        /// Notification received, deprecated along with `notification_received`
         /// [multi_location, query_id, res]
         NotificationReceived(Box<MultiLocation>, QueryId, Option<(u32, XcmError)>),
         /// Claim user's unbonded staking assets
         /// [account_id, amount]
-        ClaimedFor(T::AccountId, BalanceOf<T>),
+        ClaimedFor(T::AccountId, BalanceOf<T, I>),
         /// New era
         /// [era_index]
         NewEra(EraIndex),
         /// Matching stakes & unstakes for optimizing operations to be done
         /// on relay chain
         /// [bond_amount, rebond_amount, unbond_amount]
-        Matching(BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+        Matching(BalanceOf<T, I>, BalanceOf<T, I>, BalanceOf<T, I>),
         /// Event emitted when the reserves are reduced
         /// [receiver, reduced_amount]
-        ReservesReduced(T::AccountId, BalanceOf<T>),
+        ReservesReduced(T::AccountId, BalanceOf<T, I>),
         /// Unstake cancelled
         /// [account_id, amount, liquid_amount]
-        UnstakeCancelled(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+        UnstakeCancelled(T::AccountId, BalanceOf<T, I>, BalanceOf<T, I>),
         /// Commission rate was updated
         CommissionRateUpdated(Rate),
         /// Fast Unstake Matched
         /// [unstaker, received_staking_amount, matched_liquid_amount, fee_in_liquid_currency]
-        FastUnstakeMatched(T::AccountId, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+        FastUnstakeMatched(
+            T::AccountId,
+            BalanceOf<T, I>,
+            BalanceOf<T, I>,
+            BalanceOf<T, I>,
+        ),
+        /// Nomination pool of a derivative was set or cleared
+        /// [derivative_index, pool_id]
+        NominationPoolUpdated(DerivativeIndex, Option<PoolId>),
+        /// Sent nomination_pools.join call to relaychain
+        /// [derivative_index, stash, pool_id, amount]
+        JoiningPool(DerivativeIndex, T::AccountId, PoolId, BalanceOf<T, I>),
+        /// Sent nomination_pools.bond_extra call restaking the pool rewards to relaychain
+        ClaimingPoolRewards(DerivativeIndex),
+        /// Fast unstake fee curve was updated
+        FastUnstakeFeeCurveUpdated(FastUnstakeFeeCurve),
     }
 
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         /// Exchange rate is invalid.
         InvalidExchangeRate,
         /// The stake was below the minimum, `MinStake`.
@@ -313,7 +356,7 @@ pub mod pallet {
         AlreadyBonded,
         /// Can not schedule more unlock chunks.
         NoMoreChunks,
-        /// Staking ledger is locked due to mutation in notification_received
+        /// Staking ledger is locked until the response of its pending xcm request
         StakingLedgerLocked,
         /// Not withdrawn unbonded yet
         NotWithdrawn,
@@ -326,17 +369,26 @@ pub mod pallet {
         NoUnlockings,
         /// Invalid commission rate
         InvalidCommissionRate,
+        /// The operation isn't supported by relaychain nomination pools
+        InNominationPool,
+        /// Derivative didn't join a nomination pool
+        NotInNominationPool,
+        /// The fee curve's min fee should be no more than its max fee, which
+        /// should be smaller than 100%
+        InvalidFastUnstakeFeeCurve,
+        /// Not enough stakes are waiting in the matching pool to unstake at once
+        InsufficientMatchingPool,
     }
 
     /// The exchange rate between relaychain native asset and the voucher.
     #[pallet::storage]
     #[pallet::getter(fn exchange_rate)]
-    pub type ExchangeRate<T: Config> = StorageValue<_, Rate, ValueQuery>;
+    pub type ExchangeRate<T: Config<I>, I: 'static = ()> = StorageValue<_, Rate, ValueQuery>;
 
     /// The commission rate charge for staking total rewards.
     #[pallet::storage]
     #[pallet::getter(fn commission_rate)]
-    pub type CommissionRate<T: Config> = StorageValue<_, Rate, ValueQuery>;
+    pub type CommissionRate<T: Config<I>, I: 'static = ()> = StorageValue<_, Rate, ValueQuery>;
 
     /// ValidationData of previous block
     ///
@@ -345,89 +397,119 @@ pub mod pallet {
     /// extrinsics
     #[pallet::storage]
     #[pallet::getter(fn validation_data)]
-    pub type ValidationData<T: Config> = StorageValue<_, PersistedValidationData, OptionQuery>;
+    pub type ValidationData<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, PersistedValidationData, OptionQuery>;
 
     /// Fraction of reward currently set aside for reserves.
     #[pallet::storage]
     #[pallet::getter(fn reserve_factor)]
-    pub type ReserveFactor<T: Config> = StorageValue<_, Ratio, ValueQuery>;
+    pub type ReserveFactor<T: Config<I>, I: 'static = ()> = StorageValue<_, Ratio, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn total_reserves)]
-    pub type TotalReserves<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub type TotalReserves<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T, I>, ValueQuery>;
 
     /// Store total stake amount and unstake amount in each era,
     /// And will update when stake/unstake occurred.
     #[pallet::storage]
     #[pallet::getter(fn matching_pool)]
-    pub type MatchingPool<T: Config> = StorageValue<_, MatchingLedger<BalanceOf<T>>, ValueQuery>;
+    pub type MatchingPool<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, MatchingLedger<BalanceOf<T, I>>, ValueQuery>;
 
     /// Staking ledger's cap
     #[pallet::storage]
     #[pallet::getter(fn staking_ledger_cap)]
-    pub type StakingLedgerCap<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub type StakingLedgerCap<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BalanceOf<T, I>, ValueQuery>;
 
     /// Flying & failed xcm requests
     #[pallet::storage]
     #[pallet::getter(fn xcm_request)]
-    pub type XcmRequests<T> = StorageMap<_, Blake2_128Concat, QueryId, XcmRequest<T>, OptionQuery>;
+    pub type XcmRequests<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, QueryId, XcmRequest<T, I>, OptionQuery>;
 
     /// Users' fast unstake requests in liquid currency
     #[pallet::storage]
     #[pallet::getter(fn fast_unstake_requests)]
-    pub type FastUnstakeRequests<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+    pub type FastUnstakeRequests<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T, I>, ValueQuery>;
 
     /// Current era index
     /// Users can come to claim their unbonded staking assets back once this value arrived
-    /// at certain height decided by `BondingDuration` and `EraLength`
+    /// at certain height decided by `BondingDuration` and `RelayEra`
     #[pallet::storage]
     #[pallet::getter(fn current_era)]
-    pub type CurrentEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+    pub type CurrentEra<T: Config<I>, I: 'static = ()> = StorageValue<_, EraIndex, ValueQuery>;
 
     /// Current era's start relaychain block
     #[pallet::storage]
     #[pallet::getter(fn era_start_block)]
-    pub type EraStartBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+    pub type EraStartBlock<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
     /// Unbonding requests to be handled after arriving at target era
     #[pallet::storage]
     #[pallet::getter(fn unlockings)]
-    pub type Unlockings<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<UnlockChunk<BalanceOf<T>>>, OptionQuery>;
+    pub type Unlockings<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<UnlockChunk<BalanceOf<T, I>>>,
+        OptionQuery,
+    >;
 
     /// Platform's staking ledgers
     #[pallet::storage]
     #[pallet::getter(fn staking_ledger)]
-    pub type StakingLedgers<T: Config> = StorageMap<
+    pub type StakingLedgers<T: Config<I>, I: 'static = ()> = StorageMap<
         _,
         Blake2_128Concat,
         DerivativeIndex,
-        StakingLedger<T::AccountId, BalanceOf<T>>,
+        StakingLedger<T::AccountId, BalanceOf<T, I>>,
         OptionQuery,
     >;
 
+    /// Relaychain nomination pools the derivatives stake through, instead of
+    /// nominating directly
+    #[pallet::storage]
+    #[pallet::getter(fn nomination_pool)]
+    pub type NominationPools<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, DerivativeIndex, PoolId, OptionQuery>;
+
     /// Set to true if staking ledger has been modified in this block
     #[pallet::storage]
     #[pallet::getter(fn is_updated)]
-    pub type IsUpdated<T: Config> = StorageMap<_, Twox64Concat, DerivativeIndex, bool, ValueQuery>;
+    pub type IsUpdated<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, DerivativeIndex, bool, ValueQuery>;
 
     /// DefaultVersion is using for initialize the StorageVersion
     #[pallet::type_value]
-    pub(super) fn DefaultVersion<T: Config>() -> Versions {
+    pub(super) fn DefaultFastUnstakeFee<T: Config<I>, I: 'static>() -> FastUnstakeFeeCurve {
+        FastUnstakeFeeCurve::flat(T::MatchingPoolFastUnstakeFee::get())
+    }
+
+    /// Fee curve of the fast unstake, rising as the matching pool is drained
+    #[pallet::storage]
+    #[pallet::getter(fn fast_unstake_fee)]
+    pub type FastUnstakeFee<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, FastUnstakeFeeCurve, ValueQuery, DefaultFastUnstakeFee<T, I>>;
+
+    #[pallet::type_value]
+    pub(super) fn DefaultVersion<T: Config<I>, I: 'static>() -> Versions {
         Versions::V2
     }
 
     /// Storage version of the pallet.
     #[pallet::storage]
-    pub(crate) type StorageVersion<T: Config> =
-        StorageValue<_, Versions, ValueQuery, DefaultVersion<T>>;
+    pub(crate) type StorageVersion<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, Versions, ValueQuery, DefaultVersion<T, I>>;
 
     /// Set to true if already do matching in current era
     /// clear after arriving at next era
     #[pallet::storage]
     #[pallet::getter(fn is_matched)]
-    pub type IsMatched<T: Config> = StorageValue<_, bool, ValueQuery>;
+    pub type IsMatched<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
 
     #[derive(Default)]
     #[pallet::genesis_config]
@@ -437,70 +519,28 @@ pub mod pallet {
     }
 
     #[pallet::genesis_build]
-    impl<T: Config> GenesisBuild<T> for GenesisConfig {
+    impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig {
         fn build(&self) {
-            ExchangeRate::<T>::put(self.exchange_rate);
-            ReserveFactor::<T>::put(self.reserve_factor);
+            ExchangeRate::<T, I>::put(self.exchange_rate);
+            ReserveFactor::<T, I>::put(self.reserve_factor);
         }
     }
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         /// Put assets under staking, the native assets will be transferred to the account
         /// owned by the pallet, user receive derivative in return, such derivative can be
         /// further used as collateral for lending.
         ///
         /// - `amount`: the amount of staking assets
-        #[pallet::weight(<T as Config>::WeightInfo::stake())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::stake())]
         #[transactional]
         pub fn stake(
             origin: OriginFor<T>,
-            #[pallet::compact] amount: BalanceOf<T>,
+            #[pallet::compact] amount: BalanceOf<T, I>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-
-            ensure!(amount >= T::MinStake::get(), Error::<T>::StakeTooSmall);
-
-            let reserves = Self::reserve_factor().mul_floor(amount);
-
-            let xcm_fees = T::XcmFees::get();
-            let amount = amount
-                .checked_sub(xcm_fees)
-                .ok_or(ArithmeticError::Underflow)?;
-            T::Assets::transfer(
-                Self::staking_currency()?,
-                &who,
-                &Self::account_id(),
-                amount,
-                false,
-            )?;
-            T::XCM::add_xcm_fees(&who, xcm_fees)?;
-
-            let amount = amount
-                .checked_sub(reserves)
-                .ok_or(ArithmeticError::Underflow)?;
-            let liquid_amount =
-                Self::staking_to_liquid(amount).ok_or(Error::<T>::InvalidExchangeRate)?;
-            let liquid_currency = Self::liquid_currency()?;
-            Self::ensure_market_cap(amount)?;
-
-            T::Assets::mint_into(liquid_currency, &who, liquid_amount)?;
-
-            log::trace!(
-                target: "liquidStaking::stake",
-                "stake_amount: {:?}, liquid_amount: {:?}, reserved: {:?}",
-                &amount,
-                &liquid_amount,
-                &reserves
-            );
-
-            MatchingPool::<T>::try_mutate(|p| -> DispatchResult { p.add_stake_amount(amount) })?;
-            TotalReserves::<T>::try_mutate(|b| -> DispatchResult {
-                *b = b.checked_add(reserves).ok_or(ArithmeticError::Overflow)?;
-                Ok(())
-            })?;
-
-            Self::deposit_event(Event::<T>::Staked(who, amount));
+            Self::do_stake(&who, amount)?;
             Ok(().into())
         }
 
@@ -509,22 +549,22 @@ pub mod pallet {
         /// chain to do the `unbond` operation.
         ///
         /// - `amount`: the amount of derivative
-        #[pallet::weight(<T as Config>::WeightInfo::unstake())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::unstake())]
         #[transactional]
         pub fn unstake(
             origin: OriginFor<T>,
-            #[pallet::compact] liquid_amount: BalanceOf<T>,
+            #[pallet::compact] liquid_amount: BalanceOf<T, I>,
             unstake_provider: UnstakeProvider,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             ensure!(
                 liquid_amount >= T::MinUnstake::get(),
-                Error::<T>::UnstakeTooSmall
+                Error::<T, I>::UnstakeTooSmall
             );
 
             if unstake_provider.is_matching_pool() {
-                FastUnstakeRequests::<T>::try_mutate(&who, |b| -> DispatchResult {
+                FastUnstakeRequests::<T, I>::try_mutate(&who, |b| -> DispatchResult {
                     let balance =
                         T::Assets::reducible_balance(Self::liquid_currency()?, &who, false);
                     *b = b.saturating_add(liquid_amount).min(balance);
@@ -534,14 +574,14 @@ pub mod pallet {
             }
 
             let amount =
-                Self::liquid_to_staking(liquid_amount).ok_or(Error::<T>::InvalidExchangeRate)?;
+                Self::liquid_to_staking(liquid_amount).ok_or(Error::<T, I>::InvalidExchangeRate)?;
             let unlockings_key = if unstake_provider.is_loans() {
                 Self::loans_account_id()
             } else {
                 who.clone()
             };
 
-            Unlockings::<T>::try_mutate(&unlockings_key, |b| -> DispatchResult {
+            Unlockings::<T, I>::try_mutate(&unlockings_key, |b| -> DispatchResult {
                 let mut chunks = b.take().unwrap_or_default();
                 let target_era = Self::target_era();
                 if let Some(mut chunk) = chunks.last_mut().filter(|chunk| chunk.era == target_era) {
@@ -554,7 +594,7 @@ pub mod pallet {
                 }
                 ensure!(
                     chunks.len() <= MAX_UNLOCKING_CHUNKS,
-                    Error::<T>::NoMoreChunks
+                    Error::<T, I>::NoMoreChunks
                 );
                 *b = Some(chunks);
                 Ok(())
@@ -566,7 +606,7 @@ pub mod pallet {
                 Self::do_loans_instant_unstake(&who, amount)?;
             }
 
-            MatchingPool::<T>::try_mutate(|p| p.add_unstake_amount(amount))?;
+            MatchingPool::<T, I>::try_mutate(|p| p.add_unstake_amount(amount))?;
 
             log::trace!(
                 target: "liquidStaking::unstake",
@@ -575,12 +615,12 @@ pub mod pallet {
                 &liquid_amount,
             );
 
-            Self::deposit_event(Event::<T>::Unstaked(who, liquid_amount, amount));
+            Self::deposit_event(Event::<T, I>::Unstaked(who, liquid_amount, amount));
             Ok(().into())
         }
 
         /// Update insurance pool's reserve_factor
-        #[pallet::weight(<T as Config>::WeightInfo::update_reserve_factor())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::update_reserve_factor())]
         #[transactional]
         pub fn update_reserve_factor(
             origin: OriginFor<T>,
@@ -590,7 +630,7 @@ pub mod pallet {
 
             ensure!(
                 reserve_factor > Ratio::zero() && reserve_factor < Ratio::one(),
-                Error::<T>::InvalidFactor,
+                Error::<T, I>::InvalidFactor,
             );
 
             log::trace!(
@@ -599,39 +639,39 @@ pub mod pallet {
                 &reserve_factor,
             );
 
-            ReserveFactor::<T>::mutate(|v| *v = reserve_factor);
-            Self::deposit_event(Event::<T>::ReserveFactorUpdated(reserve_factor));
+            ReserveFactor::<T, I>::mutate(|v| *v = reserve_factor);
+            Self::deposit_event(Event::<T, I>::ReserveFactorUpdated(reserve_factor));
             Ok(().into())
         }
 
         /// Update ledger's max bonded cap
-        #[pallet::weight(<T as Config>::WeightInfo::update_staking_ledger_cap())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::update_staking_ledger_cap())]
         #[transactional]
         pub fn update_staking_ledger_cap(
             origin: OriginFor<T>,
-            #[pallet::compact] cap: BalanceOf<T>,
+            #[pallet::compact] cap: BalanceOf<T, I>,
         ) -> DispatchResultWithPostInfo {
             T::UpdateOrigin::ensure_origin(origin)?;
 
-            ensure!(!cap.is_zero(), Error::<T>::InvalidCap);
+            ensure!(!cap.is_zero(), Error::<T, I>::InvalidCap);
 
             log::trace!(
                 target: "liquidStaking::update_staking_ledger_cap",
                 "cap: {:?}",
                 &cap,
             );
-            StakingLedgerCap::<T>::mutate(|v| *v = cap);
-            Self::deposit_event(Event::<T>::StakingLedgerCapUpdated(cap));
+            StakingLedgerCap::<T, I>::mutate(|v| *v = cap);
+            Self::deposit_event(Event::<T, I>::StakingLedgerCapUpdated(cap));
             Ok(().into())
         }
 
         /// Bond on relaychain via xcm.transact
-        #[pallet::weight(<T as Config>::WeightInfo::bond())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::bond())]
         #[transactional]
         pub fn bond(
             origin: OriginFor<T>,
             derivative_index: DerivativeIndex,
-            #[pallet::compact] amount: BalanceOf<T>,
+            #[pallet::compact] amount: BalanceOf<T, I>,
             payee: RewardDestination<T::AccountId>,
         ) -> DispatchResult {
             T::RelayOrigin::ensure_origin(origin)?;
@@ -640,12 +680,12 @@ pub mod pallet {
         }
 
         /// Bond_extra on relaychain via xcm.transact
-        #[pallet::weight(<T as Config>::WeightInfo::bond_extra())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::bond_extra())]
         #[transactional]
         pub fn bond_extra(
             origin: OriginFor<T>,
             derivative_index: DerivativeIndex,
-            #[pallet::compact] amount: BalanceOf<T>,
+            #[pallet::compact] amount: BalanceOf<T, I>,
         ) -> DispatchResult {
             T::RelayOrigin::ensure_origin(origin)?;
             Self::do_bond_extra(derivative_index, amount)?;
@@ -653,12 +693,12 @@ pub mod pallet {
         }
 
         /// Unbond on relaychain via xcm.transact
-        #[pallet::weight(<T as Config>::WeightInfo::unbond())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::unbond())]
         #[transactional]
         pub fn unbond(
             origin: OriginFor<T>,
             derivative_index: DerivativeIndex,
-            #[pallet::compact] amount: BalanceOf<T>,
+            #[pallet::compact] amount: BalanceOf<T, I>,
         ) -> DispatchResult {
             T::RelayOrigin::ensure_origin(origin)?;
             Self::do_unbond(derivative_index, amount)?;
@@ -666,12 +706,12 @@ pub mod pallet {
         }
 
         /// Rebond on relaychain via xcm.transact
-        #[pallet::weight(<T as Config>::WeightInfo::rebond())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::rebond())]
         #[transactional]
         pub fn rebond(
             origin: OriginFor<T>,
             derivative_index: DerivativeIndex,
-            #[pallet::compact] amount: BalanceOf<T>,
+            #[pallet::compact] amount: BalanceOf<T, I>,
         ) -> DispatchResult {
             T::RelayOrigin::ensure_origin(origin)?;
             Self::do_rebond(derivative_index, amount)?;
@@ -679,7 +719,7 @@ pub mod pallet {
         }
 
         /// Withdraw unbonded on relaychain via xcm.transact
-        #[pallet::weight(<T as Config>::WeightInfo::withdraw_unbonded())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::withdraw_unbonded())]
         #[transactional]
         pub fn withdraw_unbonded(
             origin: OriginFor<T>,
@@ -692,7 +732,7 @@ pub mod pallet {
         }
 
         /// Nominate on relaychain via xcm.transact
-        #[pallet::weight(<T as Config>::WeightInfo::nominate())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::nominate())]
         #[transactional]
         pub fn nominate(
             origin: OriginFor<T>,
@@ -704,24 +744,28 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Internal call which is expected to be triggered only by xcm instruction
-        #[pallet::weight(<T as Config>::WeightInfo::notification_received())]
+        /// Deprecated, the responses are dispatched by XcmHelper. Kept at its call index
+        /// so that queries registered before the upgrade still settle their requests.
+        #[pallet::weight(<T as Config<I>>::WeightInfo::notification_received())]
         #[transactional]
         pub fn notification_received(
             origin: OriginFor<T>,
             query_id: QueryId,
             response: Response,
         ) -> DispatchResultWithPostInfo {
-            let responder = ensure_response(<T as Config>::RuntimeOrigin::from(origin.clone()))
+            let responder = ensure_response(<T as Config<I>>::RuntimeOrigin::from(origin.clone()))
                 .or_else(|_| {
                     T::UpdateOrigin::ensure_origin(origin).map(|_| MultiLocation::here())
                 })?;
             if let Response::ExecutionResult(res) = response {
-                if let Some(request) = Self::xcm_request(&query_id) {
-                    Self::do_notification_received(query_id, request, res)?;
+                match res {
+                    Some((index, error)) => {
+                        <Self as XcmResponseCallback>::on_error(query_id, index, error)?
+                    }
+                    None => <Self as XcmResponseCallback>::on_success(query_id)?,
                 }
 
-                Self::deposit_event(Event::<T>::NotificationReceived(
+                Self::deposit_event(Event::<T, I>::NotificationReceived(
                     Box::new(responder),
                     query_id,
                     res,
@@ -732,7 +776,7 @@ pub mod pallet {
 
         /// Claim assets back when current era index arrived
         /// at target era
-        #[pallet::weight(<T as Config>::WeightInfo::claim_for())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::claim_for())]
         #[transactional]
         pub fn claim_for(
             origin: OriginFor<T>,
@@ -742,9 +786,9 @@ pub mod pallet {
             let who = T::Lookup::lookup(dest)?;
             let current_era = Self::current_era();
 
-            Unlockings::<T>::try_mutate_exists(&who, |b| -> DispatchResult {
-                let mut amount: BalanceOf<T> = Zero::zero();
-                let chunks = b.as_mut().ok_or(Error::<T>::NoUnlockings)?;
+            Unlockings::<T, I>::try_mutate_exists(&who, |b| -> DispatchResult {
+                let mut amount: BalanceOf<T, I> = Zero::zero();
+                let chunks = b.as_mut().ok_or(Error::<T, I>::NoUnlockings)?;
                 chunks.retain(|chunk| {
                     if chunk.era > current_era {
                         true
@@ -766,11 +810,11 @@ pub mod pallet {
                 );
 
                 if amount.is_zero() {
-                    return Err(Error::<T>::NothingToClaim.into());
+                    return Err(Error::<T, I>::NothingToClaim.into());
                 }
 
                 if total_unclaimed < amount {
-                    return Err(Error::<T>::NotWithdrawn.into());
+                    return Err(Error::<T, I>::NotWithdrawn.into());
                 }
 
                 Self::do_claim_for(&who, amount)?;
@@ -779,36 +823,36 @@ pub mod pallet {
                     *b = None;
                 }
 
-                Self::deposit_event(Event::<T>::ClaimedFor(who.clone(), amount));
+                Self::deposit_event(Event::<T, I>::ClaimedFor(who.clone(), amount));
                 Ok(())
             })?;
             Ok(().into())
         }
 
         /// Force set era start block
-        #[pallet::weight(<T as Config>::WeightInfo::force_set_era_start_block())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::force_set_era_start_block())]
         #[transactional]
         pub fn force_set_era_start_block(
             origin: OriginFor<T>,
             block_number: BlockNumberFor<T>,
         ) -> DispatchResult {
             T::UpdateOrigin::ensure_origin(origin)?;
-            EraStartBlock::<T>::put(block_number);
+            EraStartBlock::<T, I>::put(block_number);
             Ok(())
         }
 
         /// Force set current era
-        #[pallet::weight(<T as Config>::WeightInfo::force_set_current_era())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::force_set_current_era())]
         #[transactional]
         pub fn force_set_current_era(origin: OriginFor<T>, era: EraIndex) -> DispatchResult {
             T::UpdateOrigin::ensure_origin(origin)?;
-            IsMatched::<T>::put(false);
-            CurrentEra::<T>::put(era);
+            IsMatched::<T, I>::put(false);
+            CurrentEra::<T, I>::put(era);
             Ok(())
         }
 
         /// Force advance era
-        #[pallet::weight(<T as Config>::WeightInfo::force_advance_era())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::force_advance_era())]
         #[transactional]
         pub fn force_advance_era(
             origin: OriginFor<T>,
@@ -822,7 +866,7 @@ pub mod pallet {
         }
 
         /// Force matching
-        #[pallet::weight(<T as Config>::WeightInfo::force_matching())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::force_matching())]
         #[transactional]
         pub fn force_matching(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             T::UpdateOrigin::ensure_origin(origin)?;
@@ -833,20 +877,20 @@ pub mod pallet {
         }
 
         /// Force set staking_ledger
-        #[pallet::weight(<T as Config>::WeightInfo::force_set_staking_ledger())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::force_set_staking_ledger())]
         #[transactional]
         pub fn force_set_staking_ledger(
             origin: OriginFor<T>,
             derivative_index: DerivativeIndex,
-            staking_ledger: StakingLedger<T::AccountId, BalanceOf<T>>,
+            staking_ledger: StakingLedger<T::AccountId, BalanceOf<T, I>>,
         ) -> DispatchResultWithPostInfo {
             T::UpdateOrigin::ensure_origin(origin)?;
 
             Self::do_update_ledger(derivative_index, |ledger| {
                 ensure!(
                     !Self::is_updated(derivative_index)
-                        && XcmRequests::<T>::iter().count().is_zero(),
-                    Error::<T>::StakingLedgerLocked
+                        && XcmRequests::<T, I>::iter().count().is_zero(),
+                    Error::<T, I>::StakingLedgerLocked
                 );
                 *ledger = staking_ledger;
                 Ok(())
@@ -856,7 +900,7 @@ pub mod pallet {
         }
 
         /// Set current era by providing storage proof
-        #[pallet::weight(<T as Config>::WeightInfo::force_set_current_era())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::force_set_current_era())]
         #[transactional]
         pub fn set_current_era(
             origin: OriginFor<T>,
@@ -871,7 +915,7 @@ pub mod pallet {
             let value = era.encode();
             ensure!(
                 Self::verify_merkle_proof(key, value, proof),
-                Error::<T>::InvalidProof
+                Error::<T, I>::InvalidProof
             );
 
             Self::do_advance_era(offset)?;
@@ -880,12 +924,12 @@ pub mod pallet {
         }
 
         /// Set staking_ledger by providing storage proof
-        #[pallet::weight(<T as Config>::WeightInfo::force_set_staking_ledger())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::force_set_staking_ledger())]
         #[transactional]
         pub fn set_staking_ledger(
             origin: OriginFor<T>,
             derivative_index: DerivativeIndex,
-            staking_ledger: StakingLedger<T::AccountId, BalanceOf<T>>,
+            staking_ledger: StakingLedger<T::AccountId, BalanceOf<T, I>>,
             proof: Vec<Vec<u8>>,
         ) -> DispatchResultWithPostInfo {
             Self::ensure_origin(origin)?;
@@ -893,8 +937,8 @@ pub mod pallet {
             Self::do_update_ledger(derivative_index, |ledger| {
                 ensure!(
                     !Self::is_updated(derivative_index)
-                        && XcmRequests::<T>::iter().count().is_zero(),
-                    Error::<T>::StakingLedgerLocked
+                        && XcmRequests::<T, I>::iter().count().is_zero(),
+                    Error::<T, I>::StakingLedgerLocked
                 );
                 // only allow to feed rewards
                 // slashes should be handled properly offchain
@@ -902,13 +946,13 @@ pub mod pallet {
                     staking_ledger.total > ledger.total
                         && staking_ledger.active > ledger.active
                         && staking_ledger.unlocking == ledger.unlocking,
-                    Error::<T>::InvalidStakingLedger
+                    Error::<T, I>::InvalidStakingLedger
                 );
                 let key = Self::get_staking_ledger_key(derivative_index);
                 let value = staking_ledger.encode();
                 ensure!(
                     Self::verify_merkle_proof(key, value, proof),
-                    Error::<T>::InvalidProof
+                    Error::<T, I>::InvalidProof
                 );
                 let rewards = staking_ledger.total.saturating_sub(ledger.total);
 
@@ -919,6 +963,11 @@ pub mod pallet {
                         &T::ProtocolFeeReceiver::get(),
                         inflate_liquid_amount,
                     )?;
+                    T::Revenue::record(
+                        RevenueSource::StakingCommission,
+                        Self::liquid_currency()?,
+                        inflate_liquid_amount,
+                    );
                 }
 
                 log::trace!(
@@ -936,17 +985,17 @@ pub mod pallet {
         }
 
         /// Reduces reserves by transferring to receiver.
-        #[pallet::weight(<T as Config>::WeightInfo::reduce_reserves())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::reduce_reserves())]
         #[transactional]
         pub fn reduce_reserves(
             origin: OriginFor<T>,
             receiver: <T::Lookup as StaticLookup>::Source,
-            #[pallet::compact] reduce_amount: BalanceOf<T>,
+            #[pallet::compact] reduce_amount: BalanceOf<T, I>,
         ) -> DispatchResultWithPostInfo {
             T::UpdateOrigin::ensure_origin(origin)?;
             let receiver = T::Lookup::lookup(receiver)?;
 
-            TotalReserves::<T>::try_mutate(|b| -> DispatchResult {
+            TotalReserves::<T, I>::try_mutate(|b| -> DispatchResult {
                 *b = b
                     .checked_sub(reduce_amount)
                     .ok_or(ArithmeticError::Underflow)?;
@@ -961,33 +1010,33 @@ pub mod pallet {
                 false,
             )?;
 
-            Self::deposit_event(Event::<T>::ReservesReduced(receiver, reduce_amount));
+            Self::deposit_event(Event::<T, I>::ReservesReduced(receiver, reduce_amount));
 
             Ok(().into())
         }
 
         /// Cancel unstake
-        #[pallet::weight(<T as Config>::WeightInfo::cancel_unstake())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::cancel_unstake())]
         #[transactional]
         pub fn cancel_unstake(
             origin: OriginFor<T>,
-            #[pallet::compact] amount: BalanceOf<T>,
+            #[pallet::compact] amount: BalanceOf<T, I>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            FastUnstakeRequests::<T>::try_mutate(&who, |b| -> DispatchResultWithPostInfo {
+            FastUnstakeRequests::<T, I>::try_mutate(&who, |b| -> DispatchResultWithPostInfo {
                 let balance = T::Assets::reducible_balance(Self::liquid_currency()?, &who, false);
                 *b = (*b).min(balance).saturating_sub(amount);
 
                 // reserve two amounts in event
-                Self::deposit_event(Event::<T>::UnstakeCancelled(who.clone(), amount, amount));
+                Self::deposit_event(Event::<T, I>::UnstakeCancelled(who.clone(), amount, amount));
 
                 Ok(().into())
             })
         }
 
         /// Update commission rate
-        #[pallet::weight(<T as Config>::WeightInfo::update_commission_rate())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::update_commission_rate())]
         #[transactional]
         pub fn update_commission_rate(
             origin: OriginFor<T>,
@@ -997,7 +1046,7 @@ pub mod pallet {
 
             ensure!(
                 commission_rate > Rate::zero() && commission_rate < Rate::one(),
-                Error::<T>::InvalidCommissionRate,
+                Error::<T, I>::InvalidCommissionRate,
             );
 
             log::trace!(
@@ -1006,13 +1055,13 @@ pub mod pallet {
                 &commission_rate,
             );
 
-            CommissionRate::<T>::put(commission_rate);
-            Self::deposit_event(Event::<T>::CommissionRateUpdated(commission_rate));
+            CommissionRate::<T, I>::put(commission_rate);
+            Self::deposit_event(Event::<T, I>::CommissionRateUpdated(commission_rate));
             Ok(())
         }
 
         /// Fast match unstake through matching pool
-        #[pallet::weight(<T as Config>::WeightInfo::fast_match_unstake(unstaker_list.len() as u32))]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::fast_match_unstake(unstaker_list.len() as u32))]
         #[transactional]
         pub fn fast_match_unstake(
             origin: OriginFor<T>,
@@ -1024,12 +1073,76 @@ pub mod pallet {
             }
             Ok(())
         }
+
+        /// Make a derivative stake through the relaychain nomination pool `pool_id`,
+        /// or nominate directly again with `None`.
+        ///
+        /// Only allowed before the derivative is bonded.
+        #[pallet::weight(<T as Config<I>>::WeightInfo::set_nomination_pool())]
+        #[transactional]
+        pub fn set_nomination_pool(
+            origin: OriginFor<T>,
+            derivative_index: DerivativeIndex,
+            pool_id: Option<PoolId>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                T::DerivativeIndexList::get().contains(&derivative_index),
+                Error::<T, I>::InvalidDerivativeIndex
+            );
+            ensure!(
+                !StakingLedgers::<T, I>::contains_key(&derivative_index),
+                Error::<T, I>::AlreadyBonded
+            );
+
+            NominationPools::<T, I>::set(derivative_index, pool_id);
+            Self::deposit_event(Event::<T, I>::NominationPoolUpdated(
+                derivative_index,
+                pool_id,
+            ));
+            Ok(())
+        }
+
+        /// Claim the pending rewards of a derivative's nomination pool and
+        /// bond them back into the pool via xcm.transact
+        #[pallet::weight(<T as Config<I>>::WeightInfo::claim_pool_rewards())]
+        #[transactional]
+        pub fn claim_pool_rewards(
+            origin: OriginFor<T>,
+            derivative_index: DerivativeIndex,
+        ) -> DispatchResult {
+            Self::ensure_origin(origin)?;
+            Self::do_claim_pool_rewards(derivative_index)?;
+            Ok(())
+        }
+
+        /// Update the fee curve of the fast unstake
+        #[pallet::weight(<T as Config<I>>::WeightInfo::update_fast_unstake_fee_curve())]
+        #[transactional]
+        pub fn update_fast_unstake_fee_curve(
+            origin: OriginFor<T>,
+            curve: FastUnstakeFeeCurve,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            ensure!(curve.is_valid(), Error::<T, I>::InvalidFastUnstakeFeeCurve);
+
+            log::trace!(
+                target: "liquidStaking::update_fast_unstake_fee_curve",
+                "curve: {:?}",
+                &curve,
+            );
+
+            FastUnstakeFee::<T, I>::put(curve);
+            Self::deposit_event(Event::<T, I>::FastUnstakeFeeCurveUpdated(curve));
+            Ok(())
+        }
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+    impl<T: Config<I>, I: 'static> Hooks<T::BlockNumber> for Pallet<T, I> {
         fn on_initialize(_block_number: T::BlockNumber) -> frame_support::weights::Weight {
-            let mut weight = <T as Config>::WeightInfo::on_initialize();
+            let mut weight = <T as Config<I>>::WeightInfo::on_initialize();
             let relaychain_block_number =
                 T::RelayChainValidationDataProvider::current_block_number();
             let mut do_on_initialize = || -> DispatchResult {
@@ -1038,15 +1151,15 @@ pub mod pallet {
                         .saturating_add(Self::era_start_block())
                         <= relaychain_block_number
                 {
-                    weight += <T as Config>::WeightInfo::force_matching();
+                    weight += <T as Config<I>>::WeightInfo::force_matching();
                     Self::do_matching()?;
                 }
 
-                let offset = Self::offset(relaychain_block_number);
+                let offset = Self::offset();
                 if offset.is_zero() {
                     return Ok(());
                 }
-                weight += <T as Config>::WeightInfo::force_advance_era();
+                weight += <T as Config<I>>::WeightInfo::force_advance_era();
                 Self::do_advance_era(offset)
             };
             let _ = with_transaction(|| match do_on_initialize() {
@@ -1057,14 +1170,55 @@ pub mod pallet {
         }
 
         fn on_finalize(_n: T::BlockNumber) {
-            let _ = IsUpdated::<T>::clear(u32::max_value(), None);
+            let _ = IsUpdated::<T, I>::clear(u32::max_value(), None);
             if let Some(data) = T::RelayChainValidationDataProvider::validation_data() {
-                ValidationData::<T>::put(data);
+                ValidationData::<T, I>::put(data);
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+            Self::do_try_state()
+        }
+    }
+
+    #[cfg(any(feature = "try-runtime", test))]
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Check that the staking ledgers are consistent and back the liquid currency at
+        /// the current exchange rate
+        pub fn do_try_state() -> Result<(), &'static str> {
+            for ledger in StakingLedgers::<T, I>::iter_values() {
+                let unlocking = ledger
+                    .unlocking
+                    .iter()
+                    .fold(BalanceOf::<T, I>::zero(), |acc, chunk| {
+                        acc.saturating_add(chunk.value)
+                    });
+                ensure!(
+                    ledger.active.saturating_add(unlocking) == ledger.total,
+                    "ledger total must be its active and unlocking balances"
+                );
             }
+
+            let liquid_currency = match Self::get_liquid_currency() {
+                Some(liquid_currency) => liquid_currency,
+                None => return Ok(()),
+            };
+            let matching_ledger = Self::matching_pool();
+            let backing = Self::get_total_active_bonded()
+                .saturating_add(matching_ledger.total_stake_amount.total)
+                .saturating_sub(matching_ledger.total_unstake_amount.total);
+            ensure!(
+                Self::exchange_rate()
+                    .saturating_mul_int(T::Assets::total_issuance(liquid_currency))
+                    <= backing,
+                "liquid currency must be backed by the bonded and matching amounts"
+            );
+            Ok(())
         }
     }
 
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         /// Staking pool account
         pub fn account_id() -> T::AccountId {
             T::PalletId::get().into_account_truncating()
@@ -1080,6 +1234,63 @@ pub mod pallet {
             T::SelfParaId::get().into_account_truncating()
         }
 
+        /// Transfer `amount` of the staking currency from `who` into the staking pool and mint
+        /// the liquid currency in return, the minted amount is returned
+        pub fn do_stake(
+            who: &T::AccountId,
+            amount: BalanceOf<T, I>,
+        ) -> Result<BalanceOf<T, I>, DispatchError> {
+            ensure!(amount >= T::MinStake::get(), Error::<T, I>::StakeTooSmall);
+
+            let reserves = Self::reserve_factor().mul_floor(amount);
+
+            let xcm_fees = T::XcmFees::get();
+            let amount = amount
+                .checked_sub(xcm_fees)
+                .ok_or(ArithmeticError::Underflow)?;
+            T::Assets::transfer(
+                Self::staking_currency()?,
+                who,
+                &Self::account_id(),
+                amount,
+                false,
+            )?;
+            T::XCM::add_xcm_fees(who, xcm_fees)?;
+
+            let amount = amount
+                .checked_sub(reserves)
+                .ok_or(ArithmeticError::Underflow)?;
+            let liquid_amount =
+                Self::staking_to_liquid(amount).ok_or(Error::<T, I>::InvalidExchangeRate)?;
+            let liquid_currency = Self::liquid_currency()?;
+            Self::ensure_market_cap(amount)?;
+
+            T::Assets::mint_into(liquid_currency, who, liquid_amount)?;
+
+            log::trace!(
+                target: "liquidStaking::stake",
+                "stake_amount: {:?}, liquid_amount: {:?}, reserved: {:?}",
+                &amount,
+                &liquid_amount,
+                &reserves
+            );
+
+            MatchingPool::<T, I>::try_mutate(|p| -> DispatchResult { p.add_stake_amount(amount) })?;
+            TotalReserves::<T, I>::try_mutate(|b| -> DispatchResult {
+                *b = b.checked_add(reserves).ok_or(ArithmeticError::Overflow)?;
+                Ok(())
+            })?;
+
+            T::Referrals::record_volume(
+                who,
+                VolumeSource::Staking,
+                Self::staking_currency()?,
+                amount,
+            );
+            Self::deposit_event(Event::<T, I>::Staked(who.clone(), amount));
+            Ok(liquid_amount)
+        }
+
         /// Target era_index if users unstake in current_era
         pub fn target_era() -> EraIndex {
             // TODO: check if we can bond before the next era
@@ -1087,22 +1298,35 @@ pub mod pallet {
             Self::current_era() + T::BondingDuration::get() + 1
         }
 
+        /// Unbonded amount of `who` which has arrived at its target era
+        /// and can be claimed back
+        pub fn claimable_unlockings(who: &T::AccountId) -> BalanceOf<T, I> {
+            let current_era = Self::current_era();
+            Self::unlockings(who)
+                .unwrap_or_default()
+                .iter()
+                .filter(|chunk| chunk.era <= current_era)
+                .fold(Zero::zero(), |acc: BalanceOf<T, I>, chunk| {
+                    acc.saturating_add(chunk.value)
+                })
+        }
+
         /// Get staking currency or return back an error
-        pub fn staking_currency() -> Result<AssetIdOf<T>, DispatchError> {
+        pub fn staking_currency() -> Result<AssetIdOf<T, I>, DispatchError> {
             Self::get_staking_currency()
-                .ok_or(Error::<T>::InvalidStakingCurrency)
+                .ok_or(Error::<T, I>::InvalidStakingCurrency)
                 .map_err(Into::into)
         }
 
         /// Get liquid currency or return back an error
-        pub fn liquid_currency() -> Result<AssetIdOf<T>, DispatchError> {
+        pub fn liquid_currency() -> Result<AssetIdOf<T, I>, DispatchError> {
             Self::get_liquid_currency()
-                .ok_or(Error::<T>::InvalidLiquidCurrency)
+                .ok_or(Error::<T, I>::InvalidLiquidCurrency)
                 .map_err(Into::into)
         }
 
         /// Get total unclaimed
-        pub fn get_total_unclaimed(staking_currency: AssetIdOf<T>) -> BalanceOf<T> {
+        pub fn get_total_unclaimed(staking_currency: AssetIdOf<T, I>) -> BalanceOf<T, I> {
             T::Assets::reducible_balance(staking_currency, &Self::account_id(), false)
                 .saturating_sub(Self::total_reserves())
                 .saturating_sub(Self::matching_pool().total_stake_amount.total)
@@ -1114,29 +1338,34 @@ pub mod pallet {
             pallet_utility::Pallet::<T>::derivative_account_id(para_account, index)
         }
 
-        fn offset(relaychain_block_number: BlockNumberFor<T>) -> EraIndex {
-            relaychain_block_number
-                .checked_sub(&Self::era_start_block())
-                .and_then(|r| r.checked_div(&T::EraLength::get()))
-                .and_then(|r| TryInto::<EraIndex>::try_into(r).ok())
-                .unwrap_or_else(Zero::zero)
+        /// Number of eras the relaychain is ahead of the pallet
+        fn offset() -> EraIndex {
+            T::RelayEra::current_era().saturating_sub(Self::current_era())
         }
 
-        fn total_bonded_of(index: DerivativeIndex) -> BalanceOf<T> {
+        fn total_bonded_of(index: DerivativeIndex) -> BalanceOf<T, I> {
             Self::staking_ledger(&index).map_or(Zero::zero(), |ledger| ledger.total)
         }
 
-        fn active_bonded_of(index: DerivativeIndex) -> BalanceOf<T> {
+        fn active_bonded_of(index: DerivativeIndex) -> BalanceOf<T, I> {
             Self::staking_ledger(&index).map_or(Zero::zero(), |ledger| ledger.active)
         }
 
-        fn unbonding_of(index: DerivativeIndex) -> BalanceOf<T> {
+        fn min_bond_of(index: DerivativeIndex) -> BalanceOf<T, I> {
+            if NominationPools::<T, I>::contains_key(index) {
+                T::MinPoolJoinBond::get()
+            } else {
+                T::MinNominatorBond::get()
+            }
+        }
+
+        fn unbonding_of(index: DerivativeIndex) -> BalanceOf<T, I> {
             Self::staking_ledger(&index).map_or(Zero::zero(), |ledger| {
                 ledger.total.saturating_sub(ledger.active)
             })
         }
 
-        fn unbonded_of(index: DerivativeIndex) -> BalanceOf<T> {
+        fn unbonded_of(index: DerivativeIndex) -> BalanceOf<T, I> {
             let current_era = Self::current_era();
             Self::staking_ledger(&index).map_or(Zero::zero(), |ledger| {
                 ledger.unlocking.iter().fold(Zero::zero(), |acc, chunk| {
@@ -1149,49 +1378,49 @@ pub mod pallet {
             })
         }
 
-        fn get_total_unbonding() -> BalanceOf<T> {
-            StakingLedgers::<T>::iter_values().fold(Zero::zero(), |acc, ledger| {
+        fn get_total_unbonding() -> BalanceOf<T, I> {
+            StakingLedgers::<T, I>::iter_values().fold(Zero::zero(), |acc, ledger| {
                 acc.saturating_add(ledger.total.saturating_sub(ledger.active))
             })
         }
 
-        fn get_total_bonded() -> BalanceOf<T> {
-            StakingLedgers::<T>::iter_values()
+        fn get_total_bonded() -> BalanceOf<T, I> {
+            StakingLedgers::<T, I>::iter_values()
                 .fold(Zero::zero(), |acc, ledger| acc.saturating_add(ledger.total))
         }
 
-        fn get_total_active_bonded() -> BalanceOf<T> {
-            StakingLedgers::<T>::iter_values().fold(Zero::zero(), |acc, ledger| {
+        fn get_total_active_bonded() -> BalanceOf<T, I> {
+            StakingLedgers::<T, I>::iter_values().fold(Zero::zero(), |acc, ledger| {
                 acc.saturating_add(ledger.active)
             })
         }
 
-        fn get_market_cap() -> BalanceOf<T> {
+        fn get_market_cap() -> BalanceOf<T, I> {
             Self::staking_ledger_cap()
-                .saturating_mul(T::DerivativeIndexList::get().len() as BalanceOf<T>)
+                .saturating_mul(T::DerivativeIndexList::get().len() as BalanceOf<T, I>)
         }
 
         #[require_transactional]
         fn do_bond(
             derivative_index: DerivativeIndex,
-            amount: BalanceOf<T>,
+            amount: BalanceOf<T, I>,
             payee: RewardDestination<T::AccountId>,
         ) -> DispatchResult {
             if amount.is_zero() {
                 return Ok(());
             }
 
-            if StakingLedgers::<T>::contains_key(&derivative_index) {
+            if StakingLedgers::<T, I>::contains_key(&derivative_index) {
                 return Self::do_bond_extra(derivative_index, amount);
             }
 
             ensure!(
                 T::DerivativeIndexList::get().contains(&derivative_index),
-                Error::<T>::InvalidDerivativeIndex
+                Error::<T, I>::InvalidDerivativeIndex
             );
             ensure!(
-                amount >= T::MinNominatorBond::get(),
-                Error::<T>::InsufficientBond
+                amount >= Self::min_bond_of(derivative_index),
+                Error::<T, I>::InsufficientBond
             );
             Self::ensure_staking_ledger_cap(derivative_index, amount)?;
 
@@ -1202,20 +1431,44 @@ pub mod pallet {
                 &amount,
             );
 
-            MatchingPool::<T>::try_mutate(|p| -> DispatchResult {
+            MatchingPool::<T, I>::try_mutate(|p| -> DispatchResult {
                 p.set_stake_amount_lock(amount)
             })?;
 
             let derivative_account_id = Self::derivative_sovereign_account_id(derivative_index);
+            if let Some(pool_id) = Self::nomination_pool(derivative_index) {
+                let query_id = T::XCM::do_pool_join(
+                    amount,
+                    pool_id,
+                    derivative_account_id.clone(),
+                    derivative_index,
+                    T::PalletId::get(),
+                )?;
+                XcmRequests::<T, I>::insert(
+                    query_id,
+                    XcmRequest::Bond {
+                        index: derivative_index,
+                        amount,
+                    },
+                );
+                Self::deposit_event(Event::<T, I>::JoiningPool(
+                    derivative_index,
+                    derivative_account_id,
+                    pool_id,
+                    amount,
+                ));
+                return Ok(());
+            }
+
             let query_id = T::XCM::do_bond(
                 amount,
                 payee.clone(),
                 derivative_account_id.clone(),
                 derivative_index,
-                Self::notify_placeholder(),
+                T::PalletId::get(),
             )?;
 
-            XcmRequests::<T>::insert(
+            XcmRequests::<T, I>::insert(
                 query_id,
                 XcmRequest::Bond {
                     index: derivative_index,
@@ -1223,7 +1476,7 @@ pub mod pallet {
                 },
             );
 
-            Self::deposit_event(Event::<T>::Bonding(
+            Self::deposit_event(Event::<T, I>::Bonding(
                 derivative_index,
                 derivative_account_id,
                 amount,
@@ -1236,7 +1489,7 @@ pub mod pallet {
         #[require_transactional]
         fn do_bond_extra(
             derivative_index: DerivativeIndex,
-            amount: BalanceOf<T>,
+            amount: BalanceOf<T, I>,
         ) -> DispatchResult {
             if amount.is_zero() {
                 return Ok(());
@@ -1244,11 +1497,11 @@ pub mod pallet {
 
             ensure!(
                 T::DerivativeIndexList::get().contains(&derivative_index),
-                Error::<T>::InvalidDerivativeIndex
+                Error::<T, I>::InvalidDerivativeIndex
             );
             ensure!(
-                StakingLedgers::<T>::contains_key(&derivative_index),
-                Error::<T>::NotBonded
+                StakingLedgers::<T, I>::contains_key(&derivative_index),
+                Error::<T, I>::NotBonded
             );
             Self::ensure_staking_ledger_cap(derivative_index, amount)?;
 
@@ -1259,18 +1512,23 @@ pub mod pallet {
                 &amount,
             );
 
-            MatchingPool::<T>::try_mutate(|p| -> DispatchResult {
+            MatchingPool::<T, I>::try_mutate(|p| -> DispatchResult {
                 p.set_stake_amount_lock(amount)
             })?;
 
-            let query_id = T::XCM::do_bond_extra(
-                amount,
-                Self::derivative_sovereign_account_id(derivative_index),
-                derivative_index,
-                Self::notify_placeholder(),
-            )?;
+            let stash = Self::derivative_sovereign_account_id(derivative_index);
+            let query_id = if NominationPools::<T, I>::contains_key(derivative_index) {
+                T::XCM::do_pool_bond_extra(
+                    PoolBondExtra::FreeBalance(amount),
+                    stash,
+                    derivative_index,
+                    T::PalletId::get(),
+                )?
+            } else {
+                T::XCM::do_bond_extra(amount, stash, derivative_index, T::PalletId::get())?
+            };
 
-            XcmRequests::<T>::insert(
+            XcmRequests::<T, I>::insert(
                 query_id,
                 XcmRequest::BondExtra {
                     index: derivative_index,
@@ -1278,34 +1536,34 @@ pub mod pallet {
                 },
             );
 
-            Self::deposit_event(Event::<T>::BondingExtra(derivative_index, amount));
+            Self::deposit_event(Event::<T, I>::BondingExtra(derivative_index, amount));
 
             Ok(())
         }
 
         #[require_transactional]
-        fn do_unbond(derivative_index: DerivativeIndex, amount: BalanceOf<T>) -> DispatchResult {
+        fn do_unbond(derivative_index: DerivativeIndex, amount: BalanceOf<T, I>) -> DispatchResult {
             if amount.is_zero() {
                 return Ok(());
             }
 
             ensure!(
                 T::DerivativeIndexList::get().contains(&derivative_index),
-                Error::<T>::InvalidDerivativeIndex
+                Error::<T, I>::InvalidDerivativeIndex
             );
 
-            let ledger: StakingLedger<T::AccountId, BalanceOf<T>> =
-                Self::staking_ledger(&derivative_index).ok_or(Error::<T>::NotBonded)?;
+            let ledger: StakingLedger<T::AccountId, BalanceOf<T, I>> =
+                Self::staking_ledger(&derivative_index).ok_or(Error::<T, I>::NotBonded)?;
             ensure!(
                 ledger.unlocking.len() < MAX_UNLOCKING_CHUNKS,
-                Error::<T>::NoMoreChunks
+                Error::<T, I>::NoMoreChunks
             );
             ensure!(
-                ledger.active.saturating_sub(amount) >= T::MinNominatorBond::get(),
-                Error::<T>::InsufficientBond
+                ledger.active.saturating_sub(amount) >= Self::min_bond_of(derivative_index),
+                Error::<T, I>::InsufficientBond
             );
 
-            MatchingPool::<T>::try_mutate(|p| -> DispatchResult {
+            MatchingPool::<T, I>::try_mutate(|p| -> DispatchResult {
                 p.set_unstake_amount_lock(amount)
             })?;
 
@@ -1316,9 +1574,19 @@ pub mod pallet {
                 &amount,
             );
 
-            let query_id = T::XCM::do_unbond(amount, derivative_index, Self::notify_placeholder())?;
+            // Pools keep member points at par with the bonded balance unless slashed
+            let query_id = if NominationPools::<T, I>::contains_key(derivative_index) {
+                T::XCM::do_pool_unbond(
+                    amount,
+                    Self::derivative_sovereign_account_id(derivative_index),
+                    derivative_index,
+                    T::PalletId::get(),
+                )?
+            } else {
+                T::XCM::do_unbond(amount, derivative_index, T::PalletId::get())?
+            };
 
-            XcmRequests::<T>::insert(
+            XcmRequests::<T, I>::insert(
                 query_id,
                 XcmRequest::Unbond {
                     index: derivative_index,
@@ -1326,24 +1594,28 @@ pub mod pallet {
                 },
             );
 
-            Self::deposit_event(Event::<T>::Unbonding(derivative_index, amount));
+            Self::deposit_event(Event::<T, I>::Unbonding(derivative_index, amount));
 
             Ok(())
         }
 
         #[require_transactional]
-        fn do_rebond(derivative_index: DerivativeIndex, amount: BalanceOf<T>) -> DispatchResult {
+        fn do_rebond(derivative_index: DerivativeIndex, amount: BalanceOf<T, I>) -> DispatchResult {
             if amount.is_zero() {
                 return Ok(());
             }
 
             ensure!(
                 T::DerivativeIndexList::get().contains(&derivative_index),
-                Error::<T>::InvalidDerivativeIndex
+                Error::<T, I>::InvalidDerivativeIndex
             );
             ensure!(
-                StakingLedgers::<T>::contains_key(&derivative_index),
-                Error::<T>::NotBonded
+                StakingLedgers::<T, I>::contains_key(&derivative_index),
+                Error::<T, I>::NotBonded
+            );
+            ensure!(
+                !NominationPools::<T, I>::contains_key(derivative_index),
+                Error::<T, I>::InNominationPool
             );
 
             log::trace!(
@@ -1353,13 +1625,13 @@ pub mod pallet {
                 &amount,
             );
 
-            MatchingPool::<T>::try_mutate(|p| -> DispatchResult {
+            MatchingPool::<T, I>::try_mutate(|p| -> DispatchResult {
                 p.set_stake_amount_lock(amount)
             })?;
 
-            let query_id = T::XCM::do_rebond(amount, derivative_index, Self::notify_placeholder())?;
+            let query_id = T::XCM::do_rebond(amount, derivative_index, T::PalletId::get())?;
 
-            XcmRequests::<T>::insert(
+            XcmRequests::<T, I>::insert(
                 query_id,
                 XcmRequest::Rebond {
                     index: derivative_index,
@@ -1367,7 +1639,7 @@ pub mod pallet {
                 },
             );
 
-            Self::deposit_event(Event::<T>::Rebonding(derivative_index, amount));
+            Self::deposit_event(Event::<T, I>::Rebonding(derivative_index, amount));
 
             Ok(())
         }
@@ -1383,11 +1655,11 @@ pub mod pallet {
 
             ensure!(
                 T::DerivativeIndexList::get().contains(&derivative_index),
-                Error::<T>::InvalidDerivativeIndex
+                Error::<T, I>::InvalidDerivativeIndex
             );
             ensure!(
-                StakingLedgers::<T>::contains_key(&derivative_index),
-                Error::<T>::NotBonded
+                StakingLedgers::<T, I>::contains_key(&derivative_index),
+                Error::<T, I>::NotBonded
             );
 
             log::trace!(
@@ -1397,14 +1669,24 @@ pub mod pallet {
                 &num_slashing_spans,
             );
 
-            let query_id = T::XCM::do_withdraw_unbonded(
-                num_slashing_spans,
-                Self::sovereign_account_id(),
-                derivative_index,
-                Self::notify_placeholder(),
-            )?;
+            let query_id = if NominationPools::<T, I>::contains_key(derivative_index) {
+                T::XCM::do_pool_withdraw_unbonded(
+                    num_slashing_spans,
+                    Self::derivative_sovereign_account_id(derivative_index),
+                    Self::sovereign_account_id(),
+                    derivative_index,
+                    T::PalletId::get(),
+                )?
+            } else {
+                T::XCM::do_withdraw_unbonded(
+                    num_slashing_spans,
+                    Self::sovereign_account_id(),
+                    derivative_index,
+                    T::PalletId::get(),
+                )?
+            };
 
-            XcmRequests::<T>::insert(
+            XcmRequests::<T, I>::insert(
                 query_id,
                 XcmRequest::WithdrawUnbonded {
                     index: derivative_index,
@@ -1412,7 +1694,7 @@ pub mod pallet {
                 },
             );
 
-            Self::deposit_event(Event::<T>::WithdrawingUnbonded(
+            Self::deposit_event(Event::<T, I>::WithdrawingUnbonded(
                 derivative_index,
                 num_slashing_spans,
             ));
@@ -1427,11 +1709,15 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure!(
                 T::DerivativeIndexList::get().contains(&derivative_index),
-                Error::<T>::InvalidDerivativeIndex
+                Error::<T, I>::InvalidDerivativeIndex
+            );
+            ensure!(
+                StakingLedgers::<T, I>::contains_key(&derivative_index),
+                Error::<T, I>::NotBonded
             );
             ensure!(
-                StakingLedgers::<T>::contains_key(&derivative_index),
-                Error::<T>::NotBonded
+                !NominationPools::<T, I>::contains_key(derivative_index),
+                Error::<T, I>::InNominationPool
             );
 
             log::trace!(
@@ -1440,13 +1726,10 @@ pub mod pallet {
                 &derivative_index,
             );
 
-            let query_id = T::XCM::do_nominate(
-                targets.clone(),
-                derivative_index,
-                Self::notify_placeholder(),
-            )?;
+            let query_id =
+                T::XCM::do_nominate(targets.clone(), derivative_index, T::PalletId::get())?;
 
-            XcmRequests::<T>::insert(
+            XcmRequests::<T, I>::insert(
                 query_id,
                 XcmRequest::Nominate {
                     index: derivative_index,
@@ -1454,21 +1737,61 @@ pub mod pallet {
                 },
             );
 
-            Self::deposit_event(Event::<T>::Nominating(derivative_index, targets));
+            Self::deposit_event(Event::<T, I>::Nominating(derivative_index, targets));
+
+            Ok(())
+        }
+
+        #[require_transactional]
+        fn do_claim_pool_rewards(derivative_index: DerivativeIndex) -> DispatchResult {
+            ensure!(
+                T::DerivativeIndexList::get().contains(&derivative_index),
+                Error::<T, I>::InvalidDerivativeIndex
+            );
+            ensure!(
+                NominationPools::<T, I>::contains_key(derivative_index),
+                Error::<T, I>::NotInNominationPool
+            );
+            ensure!(
+                StakingLedgers::<T, I>::contains_key(&derivative_index),
+                Error::<T, I>::NotBonded
+            );
+
+            log::trace!(
+                target: "liquidStaking::claim_pool_rewards",
+                "index: {:?}",
+                &derivative_index,
+            );
+
+            let query_id = T::XCM::do_pool_bond_extra(
+                PoolBondExtra::Rewards,
+                Self::derivative_sovereign_account_id(derivative_index),
+                derivative_index,
+                T::PalletId::get(),
+            )?;
+
+            XcmRequests::<T, I>::insert(
+                query_id,
+                XcmRequest::ClaimPoolRewards {
+                    index: derivative_index,
+                },
+            );
+
+            Self::deposit_event(Event::<T, I>::ClaimingPoolRewards(derivative_index));
 
             Ok(())
         }
 
         #[require_transactional]
         fn do_multi_bond(
-            total_amount: BalanceOf<T>,
+            total_amount: BalanceOf<T, I>,
             payee: RewardDestination<T::AccountId>,
         ) -> DispatchResult {
             if total_amount.is_zero() {
                 return Ok(());
             }
 
-            let amounts: Vec<(DerivativeIndex, BalanceOf<T>, BalanceOf<T>)> =
+            let amounts: Vec<(DerivativeIndex, BalanceOf<T, I>, BalanceOf<T, I>)> =
                 T::DerivativeIndexList::get()
                     .iter()
                     .map(|&index| {
@@ -1494,12 +1817,12 @@ pub mod pallet {
         }
 
         #[require_transactional]
-        fn do_multi_unbond(total_amount: BalanceOf<T>) -> DispatchResult {
+        fn do_multi_unbond(total_amount: BalanceOf<T, I>) -> DispatchResult {
             if total_amount.is_zero() {
                 return Ok(());
             }
 
-            let amounts: Vec<(DerivativeIndex, BalanceOf<T>)> = T::DerivativeIndexList::get()
+            let amounts: Vec<(DerivativeIndex, BalanceOf<T, I>)> = T::DerivativeIndexList::get()
                 .iter()
                 .map(|&index| (index, Self::active_bonded_of(index)))
                 .collect();
@@ -1517,13 +1840,14 @@ pub mod pallet {
         }
 
         #[require_transactional]
-        fn do_multi_rebond(total_amount: BalanceOf<T>) -> DispatchResult {
+        fn do_multi_rebond(total_amount: BalanceOf<T, I>) -> DispatchResult {
             if total_amount.is_zero() {
                 return Ok(());
             }
 
-            let amounts: Vec<(DerivativeIndex, BalanceOf<T>)> = T::DerivativeIndexList::get()
+            let amounts: Vec<(DerivativeIndex, BalanceOf<T, I>)> = T::DerivativeIndexList::get()
                 .iter()
+                .filter(|&&index| !NominationPools::<T, I>::contains_key(index))
                 .map(|&index| (index, Self::unbonding_of(index)))
                 .collect();
             let distributions =
@@ -1538,7 +1862,7 @@ pub mod pallet {
 
         #[require_transactional]
         fn do_multi_withdraw_unbonded(num_slashing_spans: u32) -> DispatchResult {
-            for derivative_index in StakingLedgers::<T>::iter_keys() {
+            for derivative_index in StakingLedgers::<T, I>::iter_keys() {
                 Self::do_withdraw_unbonded(derivative_index, num_slashing_spans)?;
             }
 
@@ -1548,7 +1872,7 @@ pub mod pallet {
         #[require_transactional]
         fn do_notification_received(
             query_id: QueryId,
-            req: XcmRequest<T>,
+            req: XcmRequest<T, I>,
             res: Option<(u32, XcmError)>,
         ) -> DispatchResult {
             use XcmRequest::*;
@@ -1571,15 +1895,15 @@ pub mod pallet {
                     amount,
                 } => {
                     ensure!(
-                        !StakingLedgers::<T>::contains_key(&derivative_index),
-                        Error::<T>::AlreadyBonded
+                        !StakingLedgers::<T, I>::contains_key(&derivative_index),
+                        Error::<T, I>::AlreadyBonded
                     );
-                    let staking_ledger = <StakingLedger<T::AccountId, BalanceOf<T>>>::new(
+                    let staking_ledger = <StakingLedger<T::AccountId, BalanceOf<T, I>>>::new(
                         Self::derivative_sovereign_account_id(derivative_index),
                         amount,
                     );
-                    StakingLedgers::<T>::insert(derivative_index, staking_ledger);
-                    MatchingPool::<T>::try_mutate(|p| -> DispatchResult {
+                    StakingLedgers::<T, I>::insert(derivative_index, staking_ledger);
+                    MatchingPool::<T, I>::try_mutate(|p| -> DispatchResult {
                         p.consolidate_stake(amount)
                     })?;
                     T::Assets::burn_from(Self::staking_currency()?, &Self::account_id(), amount)?;
@@ -1592,7 +1916,7 @@ pub mod pallet {
                         ledger.bond_extra(amount);
                         Ok(())
                     })?;
-                    MatchingPool::<T>::try_mutate(|p| -> DispatchResult {
+                    MatchingPool::<T, I>::try_mutate(|p| -> DispatchResult {
                         p.consolidate_stake(amount)
                     })?;
                     T::Assets::burn_from(Self::staking_currency()?, &Self::account_id(), amount)?;
@@ -1606,7 +1930,7 @@ pub mod pallet {
                         ledger.unbond(amount, target_era);
                         Ok(())
                     })?;
-                    MatchingPool::<T>::try_mutate(|p| -> DispatchResult {
+                    MatchingPool::<T, I>::try_mutate(|p| -> DispatchResult {
                         p.consolidate_unstake(amount)
                     })?;
                 }
@@ -1618,7 +1942,7 @@ pub mod pallet {
                         ledger.rebond(amount);
                         Ok(())
                     })?;
-                    MatchingPool::<T>::try_mutate(|p| -> DispatchResult {
+                    MatchingPool::<T, I>::try_mutate(|p| -> DispatchResult {
                         p.consolidate_stake(amount)
                     })?;
                 }
@@ -1638,8 +1962,10 @@ pub mod pallet {
                     })?;
                 }
                 Nominate { targets: _, .. } => {}
+                // Restaked rewards reach the ledger through `force_set_staking_ledger`
+                ClaimPoolRewards { .. } => {}
             }
-            XcmRequests::<T>::remove(&query_id);
+            XcmRequests::<T, I>::remove(&query_id);
             Ok(())
         }
 
@@ -1660,26 +1986,44 @@ pub mod pallet {
                     .ok_or(ArithmeticError::Overflow)?,
                 issuance,
             )
-            .ok_or(Error::<T>::InvalidExchangeRate)?;
+            .ok_or(Error::<T, I>::InvalidExchangeRate)?;
+            if Self::is_exchange_rate_anomaly(new_exchange_rate) {
+                log::warn!(
+                    target: "liquidStaking::do_update_exchange_rate",
+                    "exchange rate anomaly, current: {:?}, new: {:?}",
+                    Self::exchange_rate(),
+                    new_exchange_rate
+                );
+                T::CircuitBreaker::trip(CircuitBreakerTrigger::ExchangeRateAnomaly);
+            }
             // slashes should be handled properly offchain
             // by doing `bond_extra` using OrmlXcm or PolkadotXcm
             if new_exchange_rate > Self::exchange_rate() {
-                ExchangeRate::<T>::put(new_exchange_rate);
-                Self::deposit_event(Event::<T>::ExchangeRateUpdated(new_exchange_rate));
+                ExchangeRate::<T, I>::put(new_exchange_rate);
+                Self::deposit_event(Event::<T, I>::ExchangeRateUpdated(new_exchange_rate));
             }
             Ok(())
         }
 
+        fn is_exchange_rate_anomaly(new_exchange_rate: Rate) -> bool {
+            let exchange_rate = Self::exchange_rate();
+            let max_change = Rate::from_inner(
+                T::MaxExchangeRateChange::get().mul_floor(exchange_rate.into_inner()),
+            );
+            new_exchange_rate > exchange_rate.saturating_add(max_change)
+                || new_exchange_rate < exchange_rate.saturating_sub(max_change)
+        }
+
         #[require_transactional]
         fn do_update_ledger(
             derivative_index: DerivativeIndex,
-            cb: impl FnOnce(&mut StakingLedger<T::AccountId, BalanceOf<T>>) -> DispatchResult,
+            cb: impl FnOnce(&mut StakingLedger<T::AccountId, BalanceOf<T, I>>) -> DispatchResult,
         ) -> DispatchResult {
-            StakingLedgers::<T>::try_mutate(derivative_index, |ledger| -> DispatchResult {
-                let ledger = ledger.as_mut().ok_or(Error::<T>::NotBonded)?;
+            StakingLedgers::<T, I>::try_mutate(derivative_index, |ledger| -> DispatchResult {
+                let ledger = ledger.as_mut().ok_or(Error::<T, I>::NotBonded)?;
                 cb(ledger)?;
-                IsUpdated::<T>::insert(derivative_index, true);
-                Self::deposit_event(Event::<T>::StakingLedgerUpdated(
+                IsUpdated::<T, I>::insert(derivative_index, true);
+                Self::deposit_event(Event::<T, I>::StakingLedgerUpdated(
                     derivative_index,
                     ledger.clone(),
                 ));
@@ -1701,7 +2045,7 @@ pub mod pallet {
                 &unbond_amount
             );
 
-            IsMatched::<T>::put(true);
+            IsMatched::<T, I>::put(true);
 
             Self::do_multi_bond(bond_amount, RewardDestination::Staked)?;
             Self::do_multi_rebond(rebond_amount)?;
@@ -1710,7 +2054,7 @@ pub mod pallet {
 
             Self::do_multi_withdraw_unbonded(T::NumSlashingSpans::get())?;
 
-            Self::deposit_event(Event::<T>::Matching(
+            Self::deposit_event(Event::<T, I>::Matching(
                 bond_amount,
                 rebond_amount,
                 unbond_amount,
@@ -1731,21 +2075,21 @@ pub mod pallet {
                 &offset,
             );
 
-            EraStartBlock::<T>::put(T::RelayChainValidationDataProvider::current_block_number());
-            CurrentEra::<T>::mutate(|e| *e = e.saturating_add(offset));
+            EraStartBlock::<T, I>::put(T::RelayChainValidationDataProvider::current_block_number());
+            CurrentEra::<T, I>::mutate(|e| *e = e.saturating_add(offset));
 
             // ignore error
             if let Err(e) = Self::do_update_exchange_rate() {
                 log::error!(target: "liquidStaking::do_advance_era", "advance era error caught: {:?}", &e);
             }
 
-            IsMatched::<T>::put(false);
-            Self::deposit_event(Event::<T>::NewEra(Self::current_era()));
+            IsMatched::<T, I>::put(false);
+            Self::deposit_event(Event::<T, I>::NewEra(Self::current_era()));
             Ok(())
         }
 
         #[require_transactional]
-        fn do_claim_for(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+        fn do_claim_for(who: &T::AccountId, amount: BalanceOf<T, I>) -> DispatchResult {
             let module_id = Self::account_id();
             let collateral_currency = T::CollateralCurrency::get();
             let staking_currency = Self::staking_currency()?;
@@ -1771,7 +2115,10 @@ pub mod pallet {
         }
 
         #[require_transactional]
-        fn do_loans_instant_unstake(who: &AccountIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+        fn do_loans_instant_unstake(
+            who: &AccountIdOf<T>,
+            amount: BalanceOf<T, I>,
+        ) -> DispatchResult {
             let loans_instant_unstake_fee = T::LoansInstantUnstakeFee::get()
                 .checked_mul_int(amount)
                 .ok_or(ArithmeticError::Overflow)?;
@@ -1795,7 +2142,9 @@ pub mod pallet {
         }
 
         // liquid_amount_to_fee=TotalLiquidCurrency * (commission_rate*total_rewards/(TotalStakeCurrency+(1-commission_rate)*total_rewards))
-        fn get_inflate_liquid_amount(rewards: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+        fn get_inflate_liquid_amount(
+            rewards: BalanceOf<T, I>,
+        ) -> Result<BalanceOf<T, I>, DispatchError> {
             let issuance = T::Assets::total_issuance(Self::liquid_currency()?);
             let commission_rate = Self::commission_rate();
             if issuance.is_zero() || commission_rate.is_zero() || rewards.is_zero() {
@@ -1822,9 +2171,98 @@ pub mod pallet {
             Ok(inflate_liquid_amount)
         }
 
+        /// Liquid currency worth the stakes waiting in the matching pool, which is as much
+        /// as can be unstaked without waiting for the unbonding
+        pub(crate) fn fast_unstake_available() -> Result<BalanceOf<T, I>, DispatchError> {
+            Ok(
+                Self::staking_to_liquid(Self::matching_pool().total_stake_amount.free()?)
+                    .ok_or(Error::<T, I>::InvalidExchangeRate)?,
+            )
+        }
+
+        /// Fee and staking currency paid out when `liquid_amount` is matched against the
+        /// matching pool while `available_liquid_amount` can be matched
+        pub(crate) fn fast_unstake_amounts(
+            liquid_amount: BalanceOf<T, I>,
+            available_liquid_amount: BalanceOf<T, I>,
+        ) -> Result<(BalanceOf<T, I>, BalanceOf<T, I>), DispatchError> {
+            // Price the fee by the buffer left behind, so that a large unstake
+            // pays for the depletion it causes
+            let issuance = T::Assets::total_issuance(Self::liquid_currency()?);
+            let fee = Self::fast_unstake_fee()
+                .fee(
+                    available_liquid_amount.saturating_sub(liquid_amount),
+                    issuance,
+                )
+                .saturating_mul_int(liquid_amount);
+            let staking_amount = Self::liquid_to_staking(liquid_amount.saturating_sub(fee))
+                .ok_or(Error::<T, I>::InvalidExchangeRate)?;
+            Ok((fee, staking_amount))
+        }
+
+        /// Match `liquid_amount` of `unstaker` against the matching pool, paying the staking
+        /// currency out right away. Returns the staking currency received.
+        #[require_transactional]
+        fn do_match_unstake(
+            unstaker: &T::AccountId,
+            liquid_amount: BalanceOf<T, I>,
+            available_liquid_amount: BalanceOf<T, I>,
+        ) -> Result<BalanceOf<T, I>, DispatchError> {
+            let (fee, staking_to_receive) =
+                Self::fast_unstake_amounts(liquid_amount, available_liquid_amount)?;
+            T::Assets::burn_from(
+                Self::liquid_currency()?,
+                unstaker,
+                liquid_amount.saturating_sub(fee),
+            )?;
+            T::Assets::transfer(
+                Self::liquid_currency()?,
+                unstaker,
+                &T::ProtocolFeeReceiver::get(),
+                fee,
+                false,
+            )?;
+
+            MatchingPool::<T, I>::try_mutate(|p| p.sub_stake_amount(staking_to_receive))?;
+            T::Assets::transfer(
+                Self::staking_currency()?,
+                &Self::account_id(),
+                unstaker,
+                staking_to_receive,
+                false,
+            )?;
+
+            Self::deposit_event(Event::<T, I>::FastUnstakeMatched(
+                unstaker.clone(),
+                staking_to_receive,
+                liquid_amount,
+                fee,
+            ));
+            Ok(staking_to_receive)
+        }
+
+        /// Unstake `liquid_amount` of `unstaker` at once through the matching pool, failing
+        /// if the pool can't match all of it. Returns the staking currency received.
+        #[require_transactional]
+        pub fn do_fast_unstake(
+            unstaker: &T::AccountId,
+            liquid_amount: BalanceOf<T, I>,
+        ) -> Result<BalanceOf<T, I>, DispatchError> {
+            ensure!(
+                liquid_amount >= T::MinUnstake::get(),
+                Error::<T, I>::UnstakeTooSmall
+            );
+            let available_liquid_amount = Self::fast_unstake_available()?;
+            ensure!(
+                liquid_amount <= available_liquid_amount,
+                Error::<T, I>::InsufficientMatchingPool
+            );
+            Self::do_match_unstake(unstaker, liquid_amount, available_liquid_amount)
+        }
+
         #[require_transactional]
         fn do_fast_match_unstake(unstaker: &T::AccountId) -> DispatchResult {
-            FastUnstakeRequests::<T>::try_mutate_exists(unstaker, |b| -> DispatchResult {
+            FastUnstakeRequests::<T, I>::try_mutate_exists(unstaker, |b| -> DispatchResult {
                 if b.is_none() {
                     return Ok(());
                 }
@@ -1835,43 +2273,16 @@ pub mod pallet {
                     .expect("Could not be none, qed;")
                     .min(current_liquid_amount);
 
-                let available_liquid_amount =
-                    Self::staking_to_liquid(Self::matching_pool().total_stake_amount.free()?)
-                        .ok_or(Error::<T>::InvalidExchangeRate)?;
+                let available_liquid_amount = Self::fast_unstake_available()?;
 
                 let matched_liquid_amount = request_liquid_amount.min(available_liquid_amount);
 
                 if !matched_liquid_amount.is_zero() {
-                    let matched_fee = T::MatchingPoolFastUnstakeFee::get()
-                        .saturating_mul_int(matched_liquid_amount);
-                    let liquid_to_burn = matched_liquid_amount.saturating_sub(matched_fee);
-                    T::Assets::burn_from(Self::liquid_currency()?, unstaker, liquid_to_burn)?;
-                    T::Assets::transfer(
-                        Self::liquid_currency()?,
+                    Self::do_match_unstake(
                         unstaker,
-                        &T::ProtocolFeeReceiver::get(),
-                        matched_fee,
-                        false,
-                    )?;
-
-                    let staking_to_receive = Self::liquid_to_staking(liquid_to_burn)
-                        .ok_or(Error::<T>::InvalidExchangeRate)?;
-
-                    MatchingPool::<T>::try_mutate(|p| p.sub_stake_amount(staking_to_receive))?;
-                    T::Assets::transfer(
-                        Self::staking_currency()?,
-                        &Self::account_id(),
-                        unstaker,
-                        staking_to_receive,
-                        false,
-                    )?;
-
-                    Self::deposit_event(Event::<T>::FastUnstakeMatched(
-                        unstaker.clone(),
-                        staking_to_receive,
                         matched_liquid_amount,
-                        matched_fee,
-                    ));
+                        available_liquid_amount,
+                    )?;
                 }
 
                 let unmatched_amount = request_liquid_amount.saturating_sub(matched_liquid_amount);
@@ -1902,33 +2313,26 @@ pub mod pallet {
             Ok(())
         }
 
-        fn ensure_market_cap(amount: BalanceOf<T>) -> DispatchResult {
+        fn ensure_market_cap(amount: BalanceOf<T, I>) -> DispatchResult {
             ensure!(
                 Self::get_total_bonded().saturating_add(amount) <= Self::get_market_cap(),
-                Error::<T>::CapExceeded
+                Error::<T, I>::CapExceeded
             );
             Ok(())
         }
 
         fn ensure_staking_ledger_cap(
             derivative_index: DerivativeIndex,
-            amount: BalanceOf<T>,
+            amount: BalanceOf<T, I>,
         ) -> DispatchResult {
             ensure!(
                 Self::total_bonded_of(derivative_index).saturating_add(amount)
                     <= Self::staking_ledger_cap(),
-                Error::<T>::CapExceeded
+                Error::<T, I>::CapExceeded
             );
             Ok(())
         }
 
-        fn notify_placeholder() -> <T as Config>::RuntimeCall {
-            <T as Config>::RuntimeCall::from(Call::<T>::notification_received {
-                query_id: Default::default(),
-                response: Default::default(),
-            })
-        }
-
         pub(crate) fn verify_merkle_proof(
             key: Vec<u8>,
             value: Vec<u8>,
@@ -1979,16 +2383,49 @@ pub mod pallet {
             storage_prefix("Staking".as_bytes(), "CurrentEra".as_bytes()).to_vec()
         }
     }
+
+    impl<T: Config<I>, I: 'static> XcmResponseCallback for Pallet<T, I> {
+        fn callback_id() -> PalletId {
+            T::PalletId::get()
+        }
+
+        fn on_success(query_id: QueryId) -> DispatchResult {
+            if let Some(request) = Self::xcm_request(&query_id) {
+                Self::do_notification_received(query_id, request, None)?;
+            }
+            Ok(())
+        }
+
+        fn on_error(query_id: QueryId, index: u32, error: XcmError) -> DispatchResult {
+            log::error!(
+                target: "liquidStaking::on_error",
+                "query_id: {:?}, index: {:?}, error: {:?}",
+                &query_id,
+                &index,
+                &error
+            );
+            Ok(())
+        }
+
+        fn on_timeout(query_id: QueryId) -> DispatchResult {
+            log::error!(
+                target: "liquidStaking::on_timeout",
+                "query_id: {:?}",
+                &query_id
+            );
+            Ok(())
+        }
+    }
 }
 
-impl<T: Config> ExchangeRateProvider<AssetIdOf<T>> for Pallet<T> {
-    fn get_exchange_rate(_: &AssetIdOf<T>) -> Option<Rate> {
-        Some(ExchangeRate::<T>::get())
+impl<T: Config<I>, I: 'static> ExchangeRateProvider<AssetIdOf<T, I>> for Pallet<T, I> {
+    fn get_exchange_rate(_: &AssetIdOf<T, I>) -> Option<Rate> {
+        Some(ExchangeRate::<T, I>::get())
     }
 }
 
-impl<T: Config> LiquidStakingCurrenciesProvider<AssetIdOf<T>> for Pallet<T> {
-    fn get_staking_currency() -> Option<AssetIdOf<T>> {
+impl<T: Config<I>, I: 'static> LiquidStakingCurrenciesProvider<AssetIdOf<T, I>> for Pallet<T, I> {
+    fn get_staking_currency() -> Option<AssetIdOf<T, I>> {
         let asset_id = T::StakingCurrency::get();
         if !<T::Assets as InspectMetadata<AccountIdOf<T>>>::decimals(&asset_id).is_zero() {
             Some(asset_id)
@@ -1997,7 +2434,7 @@ impl<T: Config> LiquidStakingCurrenciesProvider<AssetIdOf<T>> for Pallet<T> {
         }
     }
 
-    fn get_liquid_currency() -> Option<AssetIdOf<T>> {
+    fn get_liquid_currency() -> Option<AssetIdOf<T, I>> {
         let asset_id = T::LiquidCurrency::get();
         if !<T::Assets as InspectMetadata<AccountIdOf<T>>>::decimals(&asset_id).is_zero() {
             Some(asset_id)
@@ -2007,7 +2444,9 @@ impl<T: Config> LiquidStakingCurrenciesProvider<AssetIdOf<T>> for Pallet<T> {
     }
 }
 
-impl<T: Config, Balance: BalanceT + FixedPointOperand> LiquidStakingConvert<Balance> for Pallet<T> {
+impl<T: Config<I>, I: 'static, Balance: BalanceT + FixedPointOperand> LiquidStakingConvert<Balance>
+    for Pallet<T, I>
+{
     fn staking_to_liquid(amount: Balance) -> Option<Balance> {
         Self::exchange_rate()
             .reciprocal()
@@ -2018,3 +2457,89 @@ impl<T: Config, Balance: BalanceT + FixedPointOperand> LiquidStakingConvert<Bala
         Self::exchange_rate().checked_mul_int(liquid_amount)
     }
 }
+
+impl<T: Config<I>, I: 'static> LiquidStaking<AccountIdOf<T>, BalanceOf<T, I>> for Pallet<T, I> {
+    fn do_stake(
+        who: &AccountIdOf<T>,
+        amount: BalanceOf<T, I>,
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
+        Self::do_stake(who, amount)
+    }
+}
+
+/// Staking and fast unstaking through the matching pool as conversions between the staking
+/// and the liquid currency
+impl<T: Config<I>, I: 'static> ProtocolConversion<AccountIdOf<T>, AssetIdOf<T, I>, BalanceOf<T, I>>
+    for Pallet<T, I>
+{
+    fn conversions() -> Vec<(AssetIdOf<T, I>, AssetIdOf<T, I>)> {
+        match (Self::staking_currency(), Self::liquid_currency()) {
+            (Ok(staking), Ok(liquid)) => vec![(staking, liquid), (liquid, staking)],
+            _ => Vec::new(),
+        }
+    }
+
+    fn quote_out(
+        pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        amount_in: BalanceOf<T, I>,
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
+        if pair.0 == Self::staking_currency()? {
+            ensure!(
+                amount_in >= T::MinStake::get(),
+                Error::<T, I>::StakeTooSmall
+            );
+            let reserves = Self::reserve_factor().mul_floor(amount_in);
+            let amount = amount_in
+                .checked_sub(T::XcmFees::get())
+                .and_then(|amount| amount.checked_sub(reserves))
+                .ok_or(ArithmeticError::Underflow)?;
+            Ok(Self::staking_to_liquid(amount).ok_or(Error::<T, I>::InvalidExchangeRate)?)
+        } else {
+            ensure!(
+                amount_in >= T::MinUnstake::get(),
+                Error::<T, I>::UnstakeTooSmall
+            );
+            let available_liquid_amount = Self::fast_unstake_available()?;
+            ensure!(
+                amount_in <= available_liquid_amount,
+                Error::<T, I>::InsufficientMatchingPool
+            );
+            let (_, staking_amount) =
+                Self::fast_unstake_amounts(amount_in, available_liquid_amount)?;
+            Ok(staking_amount)
+        }
+    }
+
+    fn quote_in(
+        pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        amount_out: BalanceOf<T, I>,
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
+        // The fast unstake fee depends on the amount unstaked, so unstaking is only
+        // quoted for exact amounts in
+        ensure!(
+            pair.0 == Self::staking_currency()?,
+            Error::<T, I>::InvalidStakingCurrency
+        );
+        // Round up so that the liquid currency minted isn't short of `amount_out`
+        let amount = Self::liquid_to_staking(amount_out)
+            .ok_or(Error::<T, I>::InvalidExchangeRate)?
+            .saturating_add(One::one())
+            .saturating_add(T::XcmFees::get());
+        let amount_in = Ratio::one()
+            .saturating_sub(Self::reserve_factor())
+            .saturating_reciprocal_mul_ceil(amount);
+        Ok(amount_in.max(T::MinStake::get()))
+    }
+
+    fn convert(
+        who: &AccountIdOf<T>,
+        pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
+        amount_in: BalanceOf<T, I>,
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
+        if pair.0 == Self::staking_currency()? {
+            Self::do_stake(who, amount_in)
+        } else {
+            Self::do_fast_unstake(who, amount_in)
+        }
+    }
+}
@@ -12,6 +12,75 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::{Config, ExchangeRate, ReserveFactor, StorageVersion, Versions, XcmRequests};
+use frame_support::{
+    log,
+    traits::{Get, OnRuntimeUpgrade},
+    weights::Weight,
+};
+use pallet_xcm_helper::XcmHelper;
+use primitives::{Rate, Ratio};
+use sp_runtime::traits::Zero;
+use sp_std::marker::PhantomData;
+
+/// Sets up an instance added to a running chain, whose genesis config was never built.
+///
+/// The exchange rate and reserve factor are only written while the exchange rate is
+/// still zero, so the migration can stay in place after the upgrade.
+pub struct InitializeInstance<T, I, InitialExchangeRate, InitialReserveFactor>(
+    PhantomData<(T, I, InitialExchangeRate, InitialReserveFactor)>,
+);
+impl<T, I, InitialExchangeRate, InitialReserveFactor> OnRuntimeUpgrade
+    for InitializeInstance<T, I, InitialExchangeRate, InitialReserveFactor>
+where
+    T: Config<I>,
+    I: 'static,
+    InitialExchangeRate: Get<Rate>,
+    InitialReserveFactor: Get<Ratio>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        if !ExchangeRate::<T, I>::get().is_zero() {
+            return T::DbWeight::get().reads(1);
+        }
+        ExchangeRate::<T, I>::put(InitialExchangeRate::get());
+        ReserveFactor::<T, I>::put(InitialReserveFactor::get());
+        StorageVersion::<T, I>::put(Versions::V3);
+
+        log::info!(
+            target: "liquidStaking::migrate",
+            "initialized liquid staking instance, exchange rate: {:?}",
+            InitialExchangeRate::get()
+        );
+        T::DbWeight::get().reads_writes(1, 3)
+    }
+}
+
+/// Hands the xcm requests pending before the upgrade over to XcmHelper, so that they
+/// expire like the new ones. Their responses still arrive through `notification_received`.
+///
+/// Requests already tracked are skipped, so the migration can stay in place after the upgrade.
+pub struct TrackPendingXcmRequests<T, I>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for TrackPendingXcmRequests<T, I> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut count = 0u64;
+        let mut tracked = 0u64;
+        for query_id in XcmRequests::<T, I>::iter_keys() {
+            count += 1;
+            if T::XCM::track_query(query_id, T::PalletId::get()).is_ok() {
+                tracked += 1;
+            }
+        }
+
+        log::info!(
+            target: "liquidStaking::migrate",
+            "tracked {} of {} pending xcm requests",
+            tracked,
+            count
+        );
+        T::DbWeight::get().reads_writes(count * 3, tracked)
+    }
+}
+
 pub mod v3 {
     #[cfg(feature = "try-runtime")]
     use crate::MatchingPool;
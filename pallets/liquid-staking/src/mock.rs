@@ -17,7 +17,7 @@ use pallet_xcm::XcmPassthrough;
 use polkadot_parachain::primitives::{IsSystem, Sibling};
 
 use pallet_loans::{InterestRateModel, JumpModel, Market, MarketState};
-use pallet_traits::{xcm::MultiCurrencyAdapter, PriceFeeder, ValidationDataProvider};
+use pallet_traits::{xcm::MultiCurrencyAdapter, EraProvider, PriceFeeder, ValidationDataProvider};
 use polkadot_runtime_parachains::configuration::HostConfiguration;
 use primitives::{
     tokens::*, Balance, EraIndex, ParaId, PersistedValidationData, Price, PriceDetail, Rate, Ratio,
@@ -306,6 +306,13 @@ parameter_types! {
     pub const LoansPalletId: PalletId = PalletId(*b"par/loan");
     pub const RewardAssetId: CurrencyId = HKO;
     pub const LiquidationFreeAssetId: CurrencyId = KSM;
+    pub const RateSnapshotInterval: u64 = 3600;
+    pub const MaxRateSnapshots: u32 = 24;
+    pub const KeeperStalePeriod: BlockNumber = 10;
+    pub const KeeperRewardValue: Balance = 1_000_000_000_000;
+    pub const DustThresholdValue: Balance = 1_000_000_000_000;
+    pub const LoansTreasuryAccount: AccountId = AccountId32::new([101u8; 32]);
+    pub const MaxPageSize: u32 = 10;
 }
 
 impl pallet_loans::Config for Test {
@@ -319,6 +326,20 @@ impl pallet_loans::Config for Test {
     type Assets = Assets;
     type RewardAssetId = RewardAssetId;
     type LiquidationFreeAssetId = LiquidationFreeAssetId;
+    type Revenue = ();
+    type Referrals = ();
+    type EventMirror = ();
+    type RateSnapshotInterval = RateSnapshotInterval;
+    type MaxRateSnapshots = MaxRateSnapshots;
+    type KeeperStalePeriod = KeeperStalePeriod;
+    type KeeperRewardValue = KeeperRewardValue;
+    type LiquidityUnwinder = ();
+    type DustThresholdValue = DustThresholdValue;
+    type TreasuryAccount = LoansTreasuryAccount;
+    type MaxPageSize = MaxPageSize;
+    type CollateralAdapter = ();
+    type MarketVetoOrigin = EnsureRoot<AccountId>;
+    type MarketReviewPeriod = frame_support::traits::ConstU32<10>;
 }
 
 parameter_types! {
@@ -367,6 +388,7 @@ type Block = frame_system::mocking::MockBlock<Test>;
 type BlockNumber = u32;
 
 pub const KSM_DECIMAL: u128 = 10u128.pow(12);
+pub const DOT_DECIMAL: u128 = 10u128.pow(10);
 
 parameter_types! {
     pub const BlockHashCount: BlockNumber = 250;
@@ -446,22 +468,33 @@ impl pallet_utility::Config for Test {
 parameter_types! {
     pub const XcmHelperPalletId: PalletId = PalletId(*b"par/fees");
     pub const NotifyTimeout: BlockNumber = 100;
+    pub const MaxXcmRetries: u32 = 3;
+    pub const MaxRescueReasonLength: u32 = 256;
     pub RefundLocation: AccountId = para_a_id().into_account_truncating();
 }
 
 impl pallet_xcm_helper::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
     type UpdateOrigin = UpdateOrigin;
     type Assets = Assets;
     type XcmSender = XcmRouter;
     type PalletId = XcmHelperPalletId;
     type RelayNetwork = RelayNetwork;
     type NotifyTimeout = NotifyTimeout;
+    type MaxXcmRetries = MaxXcmRetries;
+    type ResponseHandler = (LiquidStaking, DotLiquidStaking);
     type AccountIdToMultiLocation = AccountIdToMultiLocation;
     type RefundLocation = RefundLocation;
     type BlockNumberProvider = frame_system::Pallet<Test>;
     type WeightInfo = ();
     type RelayCurrency = StakingCurrency;
+    type SelfParaId = SelfParaId;
+    type AssetHubParaId = AssetHubParaId;
+    type CurrencyIdConvert = CurrencyIdConvert;
+    type MaxXcmInstructions = MaxInstructions;
+    type MaxRescueReasonLength = MaxRescueReasonLength;
 }
 
 impl BlockNumberProvider for RelayChainValidationDataProvider {
@@ -485,6 +518,19 @@ impl ValidationDataProvider for RelayChainValidationDataProvider {
     }
 }
 
+/// Relaychain eras of `EraLength` blocks
+pub struct RelayEraProvider;
+
+impl EraProvider<BlockNumber> for RelayEraProvider {
+    fn current_era() -> EraIndex {
+        RelayChainValidationDataProvider::get() / EraLength::get()
+    }
+
+    fn era_start_block() -> BlockNumber {
+        Self::current_era() * EraLength::get()
+    }
+}
+
 // block_hash on Kusama
 // 0x5a5bc2c15e160df11a7468cb91aca2f6b9db8faa87354099674e955e180b8ee2
 // Get proof_bytes
@@ -530,6 +576,7 @@ parameter_types! {
     pub const StakingPalletId: PalletId = PalletId(*b"par/lqsk");
     pub const EraLength: BlockNumber = 10;
     pub SelfParaId: ParaId = para_a_id();
+    pub AssetHubParaId: ParaId = ParaId::from(1000);
     pub const MinStake: Balance = 0;
     pub const MinUnstake: Balance = 0;
     pub const StakingCurrency: CurrencyId = KSM;
@@ -538,8 +585,10 @@ parameter_types! {
     pub const XcmFees: Balance = 0;
     pub LoansInstantUnstakeFee: Rate = Rate::saturating_from_rational(8u32, 1000u32);
     pub MatchingPoolFastUnstakeFee: Rate = Rate::saturating_from_rational(1u32, 1000u32);
+    pub MaxExchangeRateChange: Ratio = Ratio::from_percent(10);
     pub const BondingDuration: EraIndex = 3;
     pub const MinNominatorBond: Balance = 0;
+    pub const MinPoolJoinBond: Balance = 0;
     pub const NumSlashingSpans: u32 = 0;
     pub static DerivativeIndexList: Vec<u16> = vec![0];
     pub static RelayChainValidationDataProvider: BlockNumber = 0;
@@ -550,7 +599,6 @@ parameter_types! {
 impl crate::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeOrigin = RuntimeOrigin;
-    type RuntimeCall = RuntimeCall;
     type UpdateOrigin = UpdateOrigin;
     type PalletId = StakingPalletId;
     type LoansPalletId = LoansPalletId;
@@ -565,12 +613,13 @@ impl crate::Config for Test {
     type MatchingPoolFastUnstakeFee = MatchingPoolFastUnstakeFee;
     type Assets = Assets;
     type RelayOrigin = RelayOrigin;
-    type EraLength = EraLength;
+    type RelayEra = RelayEraProvider;
     type MinStake = MinStake;
     type MinUnstake = MinUnstake;
     type XCM = XcmHelper;
     type BondingDuration = BondingDuration;
     type MinNominatorBond = MinNominatorBond;
+    type MinPoolJoinBond = MinPoolJoinBond;
     type RelayChainValidationDataProvider = RelayChainValidationDataProvider;
     type Loans = Loans;
     type Members = BobOrigin;
@@ -578,6 +627,55 @@ impl crate::Config for Test {
     type DistributionStrategy = AverageDistribution;
     type ElectionSolutionStoredOffset = ElectionSolutionStoredOffset;
     type ProtocolFeeReceiver = DefaultProtocolFeeReceiver;
+    type Revenue = ();
+    type Referrals = ();
+    type MaxExchangeRateChange = MaxExchangeRateChange;
+    type CircuitBreaker = ();
+}
+
+parameter_types! {
+    pub const DotStakingPalletId: PalletId = PalletId(*b"par/lqsd");
+    pub const DotStakingCurrency: CurrencyId = DOT;
+    pub const DotLiquidCurrency: CurrencyId = SDOT;
+    pub const DotCollateralCurrency: CurrencyId = DOT_U;
+    pub static DotDerivativeIndexList: Vec<u16> = vec![1];
+}
+
+impl crate::Config<crate::Instance2> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type UpdateOrigin = UpdateOrigin;
+    type PalletId = DotStakingPalletId;
+    type LoansPalletId = LoansPalletId;
+    type SelfParaId = SelfParaId;
+    type WeightInfo = ();
+    type StakingCurrency = DotStakingCurrency;
+    type LiquidCurrency = DotLiquidCurrency;
+    type CollateralCurrency = DotCollateralCurrency;
+    type DerivativeIndexList = DotDerivativeIndexList;
+    type XcmFees = XcmFees;
+    type LoansInstantUnstakeFee = LoansInstantUnstakeFee;
+    type MatchingPoolFastUnstakeFee = MatchingPoolFastUnstakeFee;
+    type Assets = Assets;
+    type RelayOrigin = RelayOrigin;
+    type RelayEra = RelayEraProvider;
+    type MinStake = MinStake;
+    type MinUnstake = MinUnstake;
+    type XCM = XcmHelper;
+    type BondingDuration = BondingDuration;
+    type MinNominatorBond = MinNominatorBond;
+    type MinPoolJoinBond = MinPoolJoinBond;
+    type RelayChainValidationDataProvider = RelayChainValidationDataProvider;
+    type Loans = Loans;
+    type Members = BobOrigin;
+    type NumSlashingSpans = NumSlashingSpans;
+    type DistributionStrategy = AverageDistribution;
+    type ElectionSolutionStoredOffset = ElectionSolutionStoredOffset;
+    type ProtocolFeeReceiver = DefaultProtocolFeeReceiver;
+    type Revenue = ();
+    type Referrals = ();
+    type MaxExchangeRateChange = MaxExchangeRateChange;
+    type CircuitBreaker = ();
 }
 
 parameter_types! {
@@ -617,6 +715,7 @@ construct_runtime!(
         Utility: pallet_utility::{Pallet, Call, Event},
         Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
         LiquidStaking: crate::{Pallet, Storage, Call, Event<T>},
+        DotLiquidStaking: crate::<Instance2>::{Pallet, Storage, Call, Event<T>},
         ParachainSystem: cumulus_pallet_parachain_system::{Pallet, Call, Config, Storage, Inherent, Event<T>},
         ParachainInfo: parachain_info::{Pallet, Storage, Config},
         XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Call, Storage, Event<T>},
@@ -647,6 +746,14 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
         &mut t,
     )
     .unwrap();
+    GenesisBuild::<Test, crate::Instance2>::assimilate_storage(
+        &crate::GenesisConfig {
+            exchange_rate: Rate::one(),
+            reserve_factor: RESERVE_FACTOR,
+        },
+        &mut t,
+    )
+    .unwrap();
 
     let mut ext = sp_io::TestExternalities::new(t);
     ext.execute_with(|| {
@@ -681,11 +788,31 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
         )
         .unwrap();
 
+        for (asset_id, name, symbol) in [
+            (DOT, &b"Polkadot"[..], &b"DOT"[..]),
+            (SDOT, &b"Parallel Polkadot"[..], &b"sDOT"[..]),
+            (DOT_U, &b"Polkadot Ubonding"[..], &b"DOT_U"[..]),
+        ] {
+            Assets::force_create(RuntimeOrigin::root(), asset_id, Id(ALICE), true, 1).unwrap();
+            Assets::force_set_metadata(
+                RuntimeOrigin::root(),
+                asset_id,
+                name.to_vec(),
+                symbol.to_vec(),
+                10,
+                false,
+            )
+            .unwrap();
+        }
+
         Assets::mint(RuntimeOrigin::signed(ALICE), KSM, Id(ALICE), ksm(100f64)).unwrap();
         Assets::mint(RuntimeOrigin::signed(ALICE), SKSM, Id(ALICE), ksm(100f64)).unwrap();
         Assets::mint(RuntimeOrigin::signed(ALICE), KSM, Id(BOB), ksm(20000f64)).unwrap();
+        Assets::mint(RuntimeOrigin::signed(ALICE), DOT, Id(ALICE), dot(100f64)).unwrap();
         LiquidStaking::update_staking_ledger_cap(RuntimeOrigin::signed(BOB), ksm(10000f64))
             .unwrap();
+        DotLiquidStaking::update_staking_ledger_cap(RuntimeOrigin::signed(BOB), dot(10000f64))
+            .unwrap();
 
         Assets::mint(
             RuntimeOrigin::signed(ALICE),
@@ -859,6 +986,10 @@ pub fn ksm(n: f64) -> Balance {
     ((n * 1000000f64) as u128) * KSM_DECIMAL / 1000000u128
 }
 
+pub fn dot(n: f64) -> Balance {
+    ((n * 1000000f64) as u128) * DOT_DECIMAL / 1000000u128
+}
+
 pub const fn market_mock(ptoken_id: u32) -> Market<Balance> {
     Market {
         close_factor: Ratio::from_percent(50),
@@ -3,7 +3,7 @@ use frame_support::{
     dispatch::DispatchResult,
     error::BadOrigin,
     storage::with_transaction,
-    traits::{fungibles::Inspect, Hooks},
+    traits::{fungibles::Inspect, Hooks, OnRuntimeUpgrade},
 };
 use sp_runtime::{
     traits::{BlakeTwo256, One, Saturating, Zero},
@@ -14,9 +14,9 @@ use sp_runtime::{
 use sp_trie::StorageProof;
 use xcm_simulator::TestExt;
 
-use pallet_traits::ump::RewardDestination;
+use pallet_traits::{ump::RewardDestination, ProtocolConversion};
 use primitives::{
-    tokens::{KSM, SKSM},
+    tokens::{DOT, KSM, SDOT, SKSM},
     Balance, Rate, Ratio,
 };
 
@@ -62,7 +62,7 @@ fn stake_should_work() {
             || -> TransactionOutcome<DispatchResult> {
                 LiquidStaking::do_advance_era(1).unwrap();
                 LiquidStaking::do_matching().unwrap();
-                LiquidStaking::notification_received(
+                XcmHelper::on_response(
                     pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
                     0,
                     Response::ExecutionResult(None),
@@ -105,7 +105,7 @@ fn stake_should_work() {
             || -> TransactionOutcome<DispatchResult> {
                 LiquidStaking::do_advance_era(1).unwrap();
                 LiquidStaking::do_matching().unwrap();
-                LiquidStaking::notification_received(
+                XcmHelper::on_response(
                     pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
                     1,
                     Response::ExecutionResult(None),
@@ -174,7 +174,7 @@ fn unstake_should_work() {
             || -> TransactionOutcome<DispatchResult> {
                 LiquidStaking::do_advance_era(1).unwrap();
                 LiquidStaking::do_matching().unwrap();
-                LiquidStaking::notification_received(
+                XcmHelper::on_response(
                     pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
                     0,
                     Response::ExecutionResult(None),
@@ -229,7 +229,7 @@ fn unstake_should_work() {
             || -> TransactionOutcome<DispatchResult> {
                 LiquidStaking::do_advance_era(1).unwrap();
                 LiquidStaking::do_matching().unwrap();
-                LiquidStaking::notification_received(
+                XcmHelper::on_response(
                     pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
                     1,
                     Response::ExecutionResult(None),
@@ -307,7 +307,7 @@ fn test_matching_should_work() {
                 || -> TransactionOutcome<DispatchResult> {
                     LiquidStaking::do_advance_era(1).unwrap();
                     LiquidStaking::do_matching().unwrap();
-                    LiquidStaking::notification_received(
+                    XcmHelper::on_response(
                         pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
                         i.try_into().unwrap(),
                         Response::ExecutionResult(None),
@@ -373,7 +373,7 @@ fn test_transact_bond_extra_work() {
             bond_amount,
             RewardDestination::Staked
         ));
-        assert_ok!(LiquidStaking::notification_received(
+        assert_ok!(XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -418,7 +418,7 @@ fn test_transact_unbond_work() {
             RewardDestination::Staked
         ));
 
-        assert_ok!(LiquidStaking::notification_received(
+        assert_ok!(XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -470,7 +470,7 @@ fn test_transact_withdraw_unbonded_work() {
             bond_amount,
             RewardDestination::Staked
         ));
-        assert_ok!(LiquidStaking::notification_received(
+        assert_ok!(XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -480,7 +480,7 @@ fn test_transact_withdraw_unbonded_work() {
             derivative_index,
             unbond_amount
         ));
-        assert_ok!(LiquidStaking::notification_received(
+        assert_ok!(XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             1,
             Response::ExecutionResult(None),
@@ -556,7 +556,7 @@ fn test_transact_rebond_work() {
             RewardDestination::Staked
         ));
 
-        assert_ok!(LiquidStaking::notification_received(
+        assert_ok!(XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -612,7 +612,7 @@ fn test_transact_nominate_work() {
             RewardDestination::Staked
         ));
 
-        assert_ok!(LiquidStaking::notification_received(
+        assert_ok!(XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -639,6 +639,121 @@ fn test_transact_nominate_work() {
     });
 }
 
+#[test]
+fn test_nomination_pool_work() {
+    TestNet::reset();
+    let derivative_index = 0u16;
+    ParaA::execute_with(|| {
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(ALICE),
+            ksm(4000f64),
+        ));
+        assert_noop!(
+            LiquidStaking::set_nomination_pool(RuntimeOrigin::root(), 1, Some(7)),
+            Error::<Test>::InvalidDerivativeIndex
+        );
+        assert_ok!(LiquidStaking::set_nomination_pool(
+            RuntimeOrigin::root(),
+            derivative_index,
+            Some(7)
+        ));
+        assert_eq!(LiquidStaking::nomination_pool(derivative_index), Some(7));
+
+        assert_ok!(LiquidStaking::bond(
+            RuntimeOrigin::signed(ALICE),
+            derivative_index,
+            ksm(10f64),
+            RewardDestination::Staked
+        ));
+        ParaSystem::assert_has_event(mock::RuntimeEvent::LiquidStaking(
+            crate::Event::JoiningPool(
+                derivative_index,
+                LiquidStaking::derivative_sovereign_account_id(derivative_index),
+                7,
+                ksm(10f64),
+            ),
+        ));
+        assert_eq!(
+            LiquidStaking::xcm_request(0u64),
+            Some(XcmRequest::Bond {
+                index: derivative_index,
+                amount: ksm(10f64),
+            })
+        );
+        assert_ok!(XcmHelper::on_response(
+            pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
+            0,
+            Response::ExecutionResult(None),
+        ));
+        assert_eq!(
+            LiquidStaking::staking_ledger(derivative_index)
+                .unwrap()
+                .active,
+            ksm(10f64)
+        );
+
+        // The pool nominates on behalf of its members
+        assert_noop!(
+            LiquidStaking::nominate(
+                RuntimeOrigin::signed(ALICE),
+                derivative_index,
+                vec![ALICE, BOB],
+            ),
+            Error::<Test>::InNominationPool
+        );
+        assert_noop!(
+            LiquidStaking::rebond(RuntimeOrigin::signed(ALICE), derivative_index, ksm(1f64)),
+            Error::<Test>::InNominationPool
+        );
+        assert_noop!(
+            LiquidStaking::set_nomination_pool(RuntimeOrigin::root(), derivative_index, None),
+            Error::<Test>::AlreadyBonded
+        );
+
+        assert_ok!(LiquidStaking::claim_pool_rewards(
+            RuntimeOrigin::signed(ALICE),
+            derivative_index
+        ));
+        ParaSystem::assert_has_event(mock::RuntimeEvent::LiquidStaking(
+            crate::Event::ClaimingPoolRewards(derivative_index),
+        ));
+        assert_ok!(XcmHelper::on_response(
+            pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
+            1,
+            Response::ExecutionResult(None),
+        ));
+        assert_eq!(LiquidStaking::xcm_request(1u64), None);
+
+        assert_ok!(LiquidStaking::bond_extra(
+            RuntimeOrigin::signed(ALICE),
+            derivative_index,
+            ksm(5f64)
+        ));
+        assert_ok!(XcmHelper::on_response(
+            pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
+            2,
+            Response::ExecutionResult(None),
+        ));
+        assert_eq!(
+            LiquidStaking::staking_ledger(derivative_index)
+                .unwrap()
+                .active,
+            ksm(15f64)
+        );
+    });
+}
+
+#[test]
+fn test_claim_pool_rewards_requires_pool() {
+    TestNet::reset();
+    ParaA::execute_with(|| {
+        assert_noop!(
+            LiquidStaking::claim_pool_rewards(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::NotInNominationPool
+        );
+    });
+}
+
 #[test]
 fn test_transfer_bond() {
     TestNet::reset();
@@ -743,7 +858,7 @@ fn claim_for_should_work() {
                 TransactionOutcome::Commit(Ok(()))
             }
         ));
-        assert_ok!(LiquidStaking::notification_received(
+        assert_ok!(XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -753,7 +868,7 @@ fn claim_for_should_work() {
             derivative_index,
             0
         ));
-        assert_ok!(LiquidStaking::notification_received(
+        assert_ok!(XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             1,
             Response::ExecutionResult(None),
@@ -772,6 +887,31 @@ fn claim_for_should_work() {
     })
 }
 
+#[test]
+fn claimable_unlockings_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(ALICE),
+            ksm(10f64)
+        ));
+        assert_ok!(LiquidStaking::unstake(
+            RuntimeOrigin::signed(ALICE),
+            ksm(1f64),
+            Default::default()
+        ));
+        assert_eq!(LiquidStaking::claimable_unlockings(&ALICE), 0);
+
+        CurrentEra::<Test>::put(4);
+        assert_ok!(LiquidStaking::unstake(
+            RuntimeOrigin::signed(ALICE),
+            ksm(2f64),
+            Default::default()
+        ));
+        assert_eq!(LiquidStaking::claimable_unlockings(&ALICE), ksm(1f64));
+        assert_eq!(LiquidStaking::claimable_unlockings(&BOB), 0);
+    })
+}
+
 #[test]
 fn test_on_initialize_work() {
     new_test_ext().execute_with(|| {
@@ -788,7 +928,7 @@ fn test_on_initialize_work() {
         let total_stake_amount = bond_amount - xcm_fees - reserve_factor.mul_floor(bond_amount);
 
         // 1.2 on_initialize_bond
-        let total_era_blocknumbers = <Test as Config>::EraLength::get();
+        let total_era_blocknumbers = EraLength::get();
         assert_eq!(total_era_blocknumbers, 10);
         RelayChainValidationDataProvider::set(total_era_blocknumbers);
         LiquidStaking::on_initialize(System::block_number());
@@ -806,8 +946,8 @@ fn test_on_initialize_work() {
             }
         );
 
-        // 1.3 notification_received bond
-        assert_ok!(LiquidStaking::notification_received(
+        // 1.3 bond response
+        assert_ok!(XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -888,6 +1028,42 @@ fn test_set_staking_ledger_work() {
     })
 }
 
+#[test]
+fn try_state_checks_liquid_currency_is_backed() {
+    new_test_ext().execute_with(|| {
+        let derivative_index = 0u16;
+        let issuance = <Test as Config>::Assets::total_issuance(SKSM);
+        let mut staking_ledger = <StakingLedger<AccountId, BalanceOf<Test>>>::new(
+            LiquidStaking::derivative_sovereign_account_id(derivative_index),
+            issuance,
+        );
+        StakingLedgers::<Test>::insert(derivative_index, staking_ledger.clone());
+        assert_ok!(LiquidStaking::do_try_state());
+
+        staking_ledger.unlocking.push(UnlockChunk {
+            value: ksm(1f64),
+            era: 1,
+        });
+        StakingLedgers::<Test>::insert(derivative_index, staking_ledger.clone());
+        assert_noop!(
+            LiquidStaking::do_try_state(),
+            "ledger total must be its active and unlocking balances"
+        );
+
+        StakingLedgers::<Test>::insert(
+            derivative_index,
+            <StakingLedger<AccountId, BalanceOf<Test>>>::new(
+                LiquidStaking::derivative_sovereign_account_id(derivative_index),
+                issuance - ksm(1f64),
+            ),
+        );
+        assert_noop!(
+            LiquidStaking::do_try_state(),
+            "liquid currency must be backed by the bonded and matching amounts"
+        );
+    })
+}
+
 #[test]
 fn test_force_set_era_start_block_work() {
     new_test_ext().execute_with(|| {
@@ -913,7 +1089,7 @@ fn test_force_set_current_era_work() {
 }
 
 #[test]
-fn test_force_notification_received_work() {
+fn test_force_response_work() {
     new_test_ext().execute_with(|| {
         let derivative_index = 0u16;
         let bond_amount = ksm(10f64);
@@ -938,14 +1114,14 @@ fn test_force_notification_received_work() {
             })
         );
         assert_noop!(
-            LiquidStaking::notification_received(
+            XcmHelper::on_response(
                 RuntimeOrigin::signed(ALICE),
                 query_id,
                 Response::ExecutionResult(None),
             ),
             BadOrigin
         );
-        assert_ok!(LiquidStaking::notification_received(
+        assert_ok!(XcmHelper::on_response(
             RuntimeOrigin::root(),
             query_id,
             Response::ExecutionResult(None),
@@ -1121,7 +1297,7 @@ fn fast_unstake_works() {
                 TransactionOutcome::Commit(Ok(()))
             }
         ));
-        assert_ok!(LiquidStaking::notification_received(
+        assert_ok!(XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             0,
             Response::ExecutionResult(None),
@@ -1131,7 +1307,7 @@ fn fast_unstake_works() {
             derivative_index,
             0
         ));
-        assert_ok!(LiquidStaking::notification_received(
+        assert_ok!(XcmHelper::on_response(
             pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
             1,
             Response::ExecutionResult(None),
@@ -1337,3 +1513,260 @@ fn test_partial_fast_match_unstake_work() {
         ));
     })
 }
+
+#[test]
+fn test_fast_unstake_fee_curve_work() {
+    new_test_ext().execute_with(|| {
+        let curve = FastUnstakeFeeCurve {
+            min_fee: Rate::saturating_from_rational(1u32, 1000u32),
+            max_fee: Rate::saturating_from_rational(10u32, 100u32),
+            target_buffer: Ratio::from_percent(50),
+        };
+        assert_eq!(curve.fee(ksm(50f64), ksm(100f64)), curve.min_fee);
+        assert_eq!(curve.fee(0, ksm(100f64)), curve.max_fee);
+        assert_eq!(
+            curve.fee(ksm(25f64), ksm(100f64)),
+            Rate::saturating_from_rational(505u32, 10000u32)
+        );
+
+        assert_noop!(
+            LiquidStaking::update_fast_unstake_fee_curve(RuntimeOrigin::signed(ALICE), curve),
+            BadOrigin
+        );
+        assert_noop!(
+            LiquidStaking::update_fast_unstake_fee_curve(
+                RuntimeOrigin::root(),
+                FastUnstakeFeeCurve {
+                    min_fee: curve.max_fee,
+                    max_fee: curve.min_fee,
+                    ..curve
+                }
+            ),
+            Error::<Test>::InvalidFastUnstakeFeeCurve
+        );
+        assert_eq!(
+            LiquidStaking::fast_unstake_fee(),
+            FastUnstakeFeeCurve::flat(MatchingPoolFastUnstakeFee::get())
+        );
+        assert_ok!(LiquidStaking::update_fast_unstake_fee_curve(
+            RuntimeOrigin::root(),
+            curve
+        ));
+        assert_eq!(LiquidStaking::fast_unstake_fee(), curve);
+
+        let reserve_factor = LiquidStaking::reserve_factor();
+        let xcm_fees = XcmFees::get();
+        let bond_amount = ksm(10f64);
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(BOB),
+            bond_amount
+        ));
+        let total_stake_amount = bond_amount - xcm_fees - reserve_factor.mul_floor(bond_amount);
+
+        let fast_unstake_amount = ksm(3f64);
+        assert_ok!(LiquidStaking::unstake(
+            RuntimeOrigin::signed(BOB),
+            fast_unstake_amount,
+            UnstakeProvider::MatchingPool
+        ));
+        // default exchange_rate is 1, the buffer left is priced against the sKSM issuance
+        let fee_rate = curve.fee(
+            total_stake_amount - fast_unstake_amount,
+            <Test as Config>::Assets::total_issuance(SKSM),
+        );
+        assert!(fee_rate > curve.min_fee);
+        assert_ok!(LiquidStaking::fast_match_unstake(
+            RuntimeOrigin::signed(BOB),
+            [BOB].to_vec(),
+        ));
+
+        assert_eq!(
+            <Test as Config>::Assets::balance(SKSM, &DefaultProtocolFeeReceiver::get()),
+            fee_rate.saturating_mul_int(fast_unstake_amount)
+        );
+    })
+}
+
+#[test]
+fn protocol_conversion_should_stake_and_fast_unstake() {
+    new_test_ext().execute_with(|| {
+        let bond_amount = ksm(10f64);
+        let liquid_amount =
+            <LiquidStaking as ProtocolConversion<_, _, _>>::quote_out((KSM, SKSM), bond_amount)
+                .unwrap();
+        assert!(
+            <LiquidStaking as ProtocolConversion<_, _, _>>::quote_in((KSM, SKSM), liquid_amount)
+                .unwrap()
+                >= bond_amount
+        );
+        assert_ok!(with_transaction(
+            || -> TransactionOutcome<DispatchResult> {
+                assert_eq!(
+                    <LiquidStaking as ProtocolConversion<_, _, _>>::convert(
+                        &BOB,
+                        (KSM, SKSM),
+                        bond_amount
+                    ),
+                    Ok(liquid_amount)
+                );
+                TransactionOutcome::Commit(Ok(()))
+            }
+        ));
+        assert_eq!(<Test as Config>::Assets::balance(SKSM, &BOB), liquid_amount);
+
+        // only the stakes waiting in the matching pool can be unstaked at once
+        assert_noop!(
+            <LiquidStaking as ProtocolConversion<_, _, _>>::quote_out(
+                (SKSM, KSM),
+                liquid_amount + 1
+            ),
+            Error::<Test>::InsufficientMatchingPool
+        );
+
+        let fast_unstake_amount = ksm(3f64);
+        let staking_amount = <LiquidStaking as ProtocolConversion<_, _, _>>::quote_out(
+            (SKSM, KSM),
+            fast_unstake_amount,
+        )
+        .unwrap();
+        let ksm_balance = <Test as Config>::Assets::balance(KSM, &BOB);
+        assert_ok!(with_transaction(
+            || -> TransactionOutcome<DispatchResult> {
+                assert_eq!(
+                    <LiquidStaking as ProtocolConversion<_, _, _>>::convert(
+                        &BOB,
+                        (SKSM, KSM),
+                        fast_unstake_amount
+                    ),
+                    Ok(staking_amount)
+                );
+                TransactionOutcome::Commit(Ok(()))
+            }
+        ));
+        assert_eq!(
+            <Test as Config>::Assets::balance(KSM, &BOB),
+            ksm_balance + staking_amount
+        );
+        assert_eq!(
+            <Test as Config>::Assets::balance(SKSM, &BOB),
+            liquid_amount - fast_unstake_amount
+        );
+    })
+}
+
+#[test]
+fn requests_pending_before_the_upgrade_are_tracked_and_settled() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(ALICE),
+            ksm(10f64)
+        ));
+        let bond_amount = ksm(5f64);
+        assert_ok!(LiquidStaking::bond(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            bond_amount,
+            RewardDestination::Staked
+        ));
+        // Queries registered before the upgrade were not tracked by XcmHelper
+        let query_id = 0;
+        pallet_xcm_helper::OutboundXcms::<Test>::remove(query_id);
+
+        crate::migrations::TrackPendingXcmRequests::<Test, ()>::on_runtime_upgrade();
+        let outbound = pallet_xcm_helper::OutboundXcms::<Test>::get(query_id).unwrap();
+        assert_eq!(outbound.callback, StakingPalletId::get());
+        assert_eq!(outbound.retries, MaxXcmRetries::get());
+
+        // Their responses still come back through the deprecated call
+        assert_ok!(LiquidStaking::notification_received(
+            pallet_xcm::Origin::Response(MultiLocation::parent()).into(),
+            query_id,
+            Response::ExecutionResult(None),
+        ));
+        assert_eq!(XcmRequests::<Test>::get(query_id), None);
+        assert_eq!(StakingLedgers::<Test>::get(0).unwrap().active, bond_amount);
+    })
+}
+
+#[test]
+fn instances_keep_storage_and_xcm_callbacks_apart() {
+    new_test_ext().execute_with(|| {
+        assert_ne!(LiquidStaking::account_id(), DotLiquidStaking::account_id());
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(ALICE),
+            ksm(10f64)
+        ));
+        assert_ok!(DotLiquidStaking::stake(
+            RuntimeOrigin::signed(ALICE),
+            dot(20f64)
+        ));
+
+        // Each instance keeps its own staking currency and mints its own liquid currency
+        assert_eq!(
+            <Test as Config>::Assets::balance(KSM, &LiquidStaking::account_id()),
+            ksm(10f64)
+        );
+        assert_eq!(
+            <Test as Config>::Assets::balance(DOT, &DotLiquidStaking::account_id()),
+            dot(20f64)
+        );
+        assert_eq!(
+            <Test as Config>::Assets::balance(SDOT, &ALICE),
+            dot(19.9f64)
+        );
+        assert_eq!(
+            MatchingPool::<Test>::get().total_stake_amount.total,
+            ksm(9.95f64)
+        );
+        assert_eq!(
+            MatchingPool::<Test, Instance2>::get()
+                .total_stake_amount
+                .total,
+            dot(19.9f64)
+        );
+
+        let derivative_index = 1u16;
+        let bond_amount = dot(5f64);
+        assert_noop!(
+            DotLiquidStaking::bond(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                bond_amount,
+                RewardDestination::Staked
+            ),
+            Error::<Test, Instance2>::InvalidDerivativeIndex
+        );
+        assert_ok!(DotLiquidStaking::bond(
+            RuntimeOrigin::signed(ALICE),
+            derivative_index,
+            bond_amount,
+            RewardDestination::Staked
+        ));
+        let query_id = 0;
+        assert_eq!(
+            XcmRequests::<Test, Instance2>::get(query_id),
+            Some(XcmRequest::Bond {
+                index: derivative_index,
+                amount: bond_amount,
+            })
+        );
+        assert_eq!(XcmRequests::<Test>::get(query_id), None);
+
+        // The response is routed to the instance which sent the request only
+        assert_ok!(XcmHelper::on_response(
+            RuntimeOrigin::root(),
+            query_id,
+            Response::ExecutionResult(None),
+        ));
+        assert_eq!(XcmRequests::<Test, Instance2>::get(query_id), None);
+        assert_eq!(
+            StakingLedgers::<Test, Instance2>::get(derivative_index).map(|ledger| ledger.active),
+            Some(bond_amount)
+        );
+        assert_eq!(StakingLedgers::<Test>::iter().count(), 0);
+        assert_eq!(
+            MatchingPool::<Test>::get().total_stake_amount.total,
+            ksm(9.95f64)
+        );
+    })
+}
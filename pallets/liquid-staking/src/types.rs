@@ -4,10 +4,14 @@ use super::{BalanceOf, Config};
 use frame_support::{
     dispatch::DispatchResult,
     traits::{tokens::Balance as BalanceT, DefensiveSaturating},
+    CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
-use primitives::{DerivativeIndex, EraIndex};
+use primitives::{DerivativeIndex, EraIndex, Rate, Ratio};
 use scale_info::TypeInfo;
-use sp_runtime::{traits::Zero, ArithmeticError, DispatchError, FixedPointOperand, RuntimeDebug};
+use sp_runtime::{
+    traits::{One, Saturating, Zero},
+    ArithmeticError, DispatchError, FixedPointNumber, FixedPointOperand, RuntimeDebug,
+};
 use sp_std::{cmp::Ordering, result::Result, vec, vec::Vec};
 
 #[derive(Copy, Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -194,24 +198,75 @@ impl<Balance: BalanceT + FixedPointOperand> MatchingLedger<Balance> {
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
-#[scale_info(skip_type_params(T))]
-pub enum XcmRequest<T: Config> {
+/// Fee curve of the fast unstake through the matching pool.
+///
+/// The fee is `min_fee` while the free stake left in the matching pool stays above
+/// `target_buffer` of the total staked value, and rises linearly to `max_fee` as the
+/// buffer is drained to zero.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct FastUnstakeFeeCurve {
+    /// Fee charged while the buffer is at or above its target
+    pub min_fee: Rate,
+    /// Fee charged once the buffer is fully depleted
+    pub max_fee: Rate,
+    /// Targeted buffer, as a share of the total staked value
+    pub target_buffer: Ratio,
+}
+
+impl FastUnstakeFeeCurve {
+    /// A curve charging `fee` regardless of the buffer
+    pub fn flat(fee: Rate) -> Self {
+        Self {
+            min_fee: fee,
+            max_fee: fee,
+            target_buffer: Ratio::zero(),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.min_fee <= self.max_fee && self.max_fee < Rate::one()
+    }
+
+    /// Fee rate when `buffer` of the `total` staked value remains in the matching pool
+    pub fn fee<Balance: BalanceT + FixedPointOperand>(
+        &self,
+        buffer: Balance,
+        total: Balance,
+    ) -> Rate {
+        let target = self.target_buffer.mul_floor(total);
+        if target.is_zero() || buffer >= target {
+            return self.min_fee;
+        }
+
+        let depletion = Rate::one().saturating_sub(Rate::saturating_from_rational(buffer, target));
+        self.min_fee.saturating_add(
+            self.max_fee
+                .saturating_sub(self.min_fee)
+                .saturating_mul(depletion),
+        )
+    }
+}
+
+#[derive(
+    PartialEqNoBound, EqNoBound, CloneNoBound, Encode, Decode, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T, I))]
+pub enum XcmRequest<T: Config<I>, I: 'static = ()> {
     Bond {
         index: DerivativeIndex,
-        amount: BalanceOf<T>,
+        amount: BalanceOf<T, I>,
     },
     BondExtra {
         index: DerivativeIndex,
-        amount: BalanceOf<T>,
+        amount: BalanceOf<T, I>,
     },
     Unbond {
         index: DerivativeIndex,
-        amount: BalanceOf<T>,
+        amount: BalanceOf<T, I>,
     },
     Rebond {
         index: DerivativeIndex,
-        amount: BalanceOf<T>,
+        amount: BalanceOf<T, I>,
     },
     WithdrawUnbonded {
         index: DerivativeIndex,
@@ -221,6 +276,9 @@ pub enum XcmRequest<T: Config> {
         index: DerivativeIndex,
         targets: Vec<T::AccountId>,
     },
+    ClaimPoolRewards {
+        index: DerivativeIndex,
+    },
 }
 
 /// Just a Balance/BlockNumber tuple to encode when a chunk of funds will be unlocked.
@@ -43,6 +43,10 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions needed for pallet_liquid_staking.
 pub trait WeightInfo {
 	fn stake() -> Weight;
@@ -67,6 +71,9 @@ pub trait WeightInfo {
 	fn cancel_unstake() -> Weight;
 	fn update_commission_rate() -> Weight;
 	fn fast_match_unstake(n: u32, ) -> Weight;
+	fn set_nomination_pool() -> Weight;
+	fn claim_pool_rewards() -> Weight;
+	fn update_fast_unstake_fee_curve() -> Weight;
 }
 
 /// Weights for pallet_liquid_staking using the Substrate node and recommended hardware.
@@ -86,6 +93,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn stake() -> Weight {
 		Weight::from_ref_time(288_998_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(17 as u64))
 			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -99,6 +107,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn unstake() -> Weight {
 		Weight::from_ref_time(124_428_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -120,6 +129,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn bond() -> Weight {
 		Weight::from_ref_time(204_060_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(14 as u64))
 			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -139,6 +149,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn nominate() -> Weight {
 		Weight::from_ref_time(187_662_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(12 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -160,6 +171,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn bond_extra() -> Weight {
 		Weight::from_ref_time(209_641_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(14 as u64))
 			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -169,6 +181,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn force_set_staking_ledger() -> Weight {
 		Weight::from_ref_time(75_995_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -189,6 +202,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn unbond() -> Weight {
 		Weight::from_ref_time(196_990_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -209,6 +223,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn rebond() -> Weight {
 		Weight::from_ref_time(195_931_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -229,6 +244,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn withdraw_unbonded() -> Weight {
 		Weight::from_ref_time(199_966_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -236,6 +252,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn update_reserve_factor() -> Weight {
 		Weight::from_ref_time(40_612_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -243,6 +260,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn update_staking_ledger_cap() -> Weight {
 		Weight::from_ref_time(39_767_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -256,6 +274,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn notification_received() -> Weight {
 		Weight::from_ref_time(137_351_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -269,6 +288,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn claim_for() -> Weight {
 		Weight::from_ref_time(157_173_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(9 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -276,6 +296,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn force_set_era_start_block() -> Weight {
 		Weight::from_ref_time(12_342_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -284,6 +305,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn force_set_current_era() -> Weight {
 		Weight::from_ref_time(14_154_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: ParachainSystem ValidationData (r:1 w:0)
@@ -293,6 +315,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn on_initialize() -> Weight {
 		Weight::from_ref_time(21_084_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -320,6 +343,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn force_advance_era() -> Weight {
 		Weight::from_ref_time(465_583_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(22 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(22 as u64))
 			.saturating_add(T::DbWeight::get().writes(15 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -343,6 +367,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn force_matching() -> Weight {
 		Weight::from_ref_time(391_066_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(18 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(18 as u64))
 			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -353,6 +378,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn reduce_reserves() -> Weight {
 		Weight::from_ref_time(125_618_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(6 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -366,11 +392,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn cancel_unstake() -> Weight {
 		Weight::from_ref_time(117_945_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	fn update_commission_rate() -> Weight {
 		Weight::from_ref_time(40_612_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	fn fast_match_unstake(n: u32, ) -> Weight {
@@ -378,10 +406,50 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 38_000
 			.saturating_add(Weight::from_ref_time(82_727_000 as u64).saturating_mul(n as u64))
 			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
 			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((4 as u64).saturating_mul(n as u64)))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
 	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
+	// Storage: LiquidStaking NominationPools (r:0 w:1)
+	fn set_nomination_pool() -> Weight {
+		Weight::from_ref_time(41_203_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: LiquidStaking NominationPools (r:1 w:0)
+	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: LiquidStaking XcmRequests (r:0 w:1)
+	// Storage: PolkadotXcm Queries (r:0 w:1)
+	fn claim_pool_rewards() -> Weight {
+		Weight::from_ref_time(191_874_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: LiquidStaking FastUnstakeFee (r:0 w:1)
+	fn update_fast_unstake_fee_curve() -> Weight {
+		Weight::from_ref_time(23_702_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -696,4 +764,39 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
 	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
+	// Storage: LiquidStaking NominationPools (r:0 w:1)
+	fn set_nomination_pool() -> Weight {
+		Weight::from_ref_time(41_203_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: LiquidStaking NominationPools (r:1 w:0)
+	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: LiquidStaking XcmRequests (r:0 w:1)
+	// Storage: PolkadotXcm Queries (r:0 w:1)
+	fn claim_pool_rewards() -> Weight {
+		Weight::from_ref_time(191_874_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: LiquidStaking FastUnstakeFee (r:0 w:1)
+	fn update_fast_unstake_fee_curve() -> Weight {
+		Weight::from_ref_time(23_702_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
 }
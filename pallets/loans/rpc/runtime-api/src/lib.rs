@@ -15,8 +15,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
-use primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall};
+use primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall, Timestamp};
 use sp_runtime::{DispatchError, FixedU128};
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
     pub trait LoansApi<AccountId, Balance> where
@@ -25,5 +26,9 @@ sp_api::decl_runtime_apis! {
         fn get_account_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError>;
         fn get_market_status(asset_id: CurrencyId) -> Result<(Rate, Rate, Rate, Ratio, Balance, Balance, FixedU128), DispatchError>;
         fn get_liquidation_threshold_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError>;
+        fn get_health_factors(max_health_factor: FixedU128) -> Result<Vec<(AccountId, FixedU128)>, DispatchError>;
+        fn get_health_alerts() -> Result<Vec<(AccountId, FixedU128, FixedU128)>, DispatchError>;
+        fn get_rate_history(asset_id: CurrencyId) -> Vec<(Timestamp, Ratio, Rate, Rate, Rate)>;
+        fn simulate_market_rates(asset_id: CurrencyId, supplied: Balance, redeemed: Balance, borrowed: Balance, repaid: Balance) -> Result<(Ratio, Rate, Rate), DispatchError>;
     }
 }
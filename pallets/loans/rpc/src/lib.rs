@@ -12,22 +12,42 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::sync::Arc;
+use std::{collections::HashMap, hash::Hash, sync::Arc};
 
 pub use pallet_loans_rpc_runtime_api::LoansApi as LoansRuntimeApi;
 
 use codec::Codec;
+use futures::{future, FutureExt, StreamExt};
 use jsonrpsee::{
     core::{async_trait, Error as JsonRpseeError, RpcResult},
     proc_macros::rpc,
     types::error::{CallError, ErrorCode, ErrorObject},
+    PendingSubscription,
 };
-use primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall};
+use primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall, Timestamp};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{Deserialize, Serialize};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_rpc::number::NumberOrHex;
 use sp_runtime::{generic::BlockId, traits::Block as BlockT, FixedU128};
 
+/// Health factor of a borrower which moved across one of the subscribed thresholds
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthFactorCrossing<AccountId> {
+    pub account: AccountId,
+    /// Health factor before the block, `None` if it was above every threshold and the
+    /// borrower had no alert threshold
+    pub previous: Option<FixedU128>,
+    /// Health factor after the block, `None` if it is above every threshold and the
+    /// borrower has no alert threshold
+    pub current: Option<FixedU128>,
+    /// Health factor the borrower set to be alerted below, crossing it is a crossing too
+    pub alert_threshold: Option<FixedU128>,
+}
+
 #[rpc(client, server)]
 pub trait LoansApi<BlockHash, AccountId, Balance>
 where
@@ -51,19 +71,51 @@ where
         account: AccountId,
         at: Option<BlockHash>,
     ) -> RpcResult<(Liquidity, Shortfall, Liquidity, Shortfall)>;
+    /// Latest snapshots of the rates of a market, oldest first, as
+    /// `(timestamp, utilization, borrow_rate, supply_rate, exchange_rate)`
+    #[method(name = "loans_getRateHistory")]
+    fn get_rate_history(
+        &self,
+        asset_id: CurrencyId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Timestamp, Ratio, Rate, Rate, Rate)>>;
+    /// Projected `(utilization, borrow_rate, supply_rate)` of a market once interest is
+    /// accrued and the given amounts are supplied, redeemed, borrowed and repaid
+    #[method(name = "loans_simulateMarketRates")]
+    fn simulate_market_rates(
+        &self,
+        asset_id: CurrencyId,
+        supplied: NumberOrHex,
+        redeemed: NumberOrHex,
+        borrowed: NumberOrHex,
+        repaid: NumberOrHex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<(Ratio, Rate, Rate)>;
+    /// On every new best block, borrowers whose health factor crossed one of `thresholds`
+    /// or the alert threshold they set on chain. Health factors below one can be
+    /// liquidated.
+    #[subscription(
+        name = "loans_subscribeHealthFactors" => "loans_healthFactors",
+        unsubscribe = "loans_unsubscribeHealthFactors",
+        item = Vec<HealthFactorCrossing<AccountId>>
+    )]
+    fn subscribe_health_factors(&self, thresholds: Vec<FixedU128>);
 }
 
 /// A struct that implements the [`LoansApi`].
 pub struct Loans<C, B> {
     client: Arc<C>,
+    executor: SubscriptionTaskExecutor,
     _marker: std::marker::PhantomData<B>,
 }
 
 impl<C, B> Loans<C, B> {
-    /// Create new `Loans` with the given reference to the client.
-    pub fn new(client: Arc<C>) -> Self {
+    /// Create new `Loans` with the given reference to the client, subscriptions are
+    /// run by `executor`.
+    pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
         Self {
             client,
+            executor,
             _marker: Default::default(),
         }
     }
@@ -93,8 +145,9 @@ where
     C: Send + Sync + 'static,
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block>,
+    C: BlockchainEvents<Block>,
     C::Api: LoansRuntimeApi<Block, AccountId, Balance>,
-    AccountId: Codec,
+    AccountId: Codec + Clone + Eq + Hash + Serialize + Send + Sync + 'static,
     Balance: Codec + Copy + TryFrom<NumberOrHex> + Into<NumberOrHex> + std::fmt::Display,
 {
     fn get_account_liquidity(
@@ -159,6 +212,130 @@ where
             .map_err(runtime_error_into_rpc_error)?
             .map_err(account_liquidity_error_into_rpc_error)
     }
+
+    fn get_rate_history(
+        &self,
+        asset_id: CurrencyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(Timestamp, Ratio, Rate, Rate, Rate)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.get_rate_history(&at, asset_id)
+            .map_err(runtime_error_into_rpc_error)
+    }
+
+    fn simulate_market_rates(
+        &self,
+        asset_id: CurrencyId,
+        supplied: NumberOrHex,
+        redeemed: NumberOrHex,
+        borrowed: NumberOrHex,
+        repaid: NumberOrHex,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<(Ratio, Rate, Rate)> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.simulate_market_rates(
+            &at,
+            asset_id,
+            decode_hex(supplied, "balance")?,
+            decode_hex(redeemed, "balance")?,
+            decode_hex(borrowed, "balance")?,
+            decode_hex(repaid, "balance")?,
+        )
+        .map_err(runtime_error_into_rpc_error)?
+        .map_err(market_status_error_into_rpc_error)
+    }
+
+    fn subscribe_health_factors(&self, pending: PendingSubscription, thresholds: Vec<FixedU128>) {
+        let mut thresholds = thresholds;
+        thresholds.sort();
+        thresholds.dedup();
+        // without thresholds, only the alert thresholds of the borrowers are followed
+        let max_health_factor = thresholds.last().copied().unwrap_or_default();
+        // Number of thresholds above the health factor and whether it is below the alert
+        // threshold, accounts which aren't returned by the runtime count as `(0, false)`
+        let band = move |(health_factor, alert_threshold): &(FixedU128, Option<FixedU128>)| {
+            (
+                thresholds.iter().filter(|t| health_factor < *t).count(),
+                alert_threshold.map_or(false, |alert_threshold| *health_factor < alert_threshold),
+            )
+        };
+
+        let client = self.client.clone();
+        let mut previous: HashMap<AccountId, (FixedU128, Option<FixedU128>)> = HashMap::new();
+        let stream = self
+            .client
+            .import_notification_stream()
+            .filter(|notification| future::ready(notification.is_new_best))
+            .filter_map(move |notification| {
+                let at = BlockId::hash(notification.hash);
+                let api = client.runtime_api();
+                let mut current: HashMap<AccountId, (FixedU128, Option<FixedU128>)> =
+                    match api.get_health_factors(&at, max_health_factor) {
+                        Ok(Ok(health_factors)) => health_factors
+                            .into_iter()
+                            .map(|(account, health_factor)| (account, (health_factor, None)))
+                            .collect(),
+                        _ => return future::ready(None),
+                    };
+                match api.get_health_alerts(&at) {
+                    Ok(Ok(alerts)) => current.extend(alerts.into_iter().map(
+                        |(account, health_factor, alert_threshold)| {
+                            (account, (health_factor, Some(alert_threshold)))
+                        },
+                    )),
+                    _ => return future::ready(None),
+                }
+
+                let mut crossings: Vec<HealthFactorCrossing<AccountId>> = current
+                    .iter()
+                    .filter(|(account, tracked)| {
+                        previous.get(*account).map_or((0, false), &band) != band(tracked)
+                    })
+                    .map(
+                        |(account, (health_factor, alert_threshold))| HealthFactorCrossing {
+                            account: account.clone(),
+                            previous: previous
+                                .get(account)
+                                .map(|(health_factor, _)| *health_factor),
+                            current: Some(*health_factor),
+                            alert_threshold: *alert_threshold,
+                        },
+                    )
+                    .collect();
+                crossings.extend(
+                    previous
+                        .iter()
+                        .filter(|(account, _)| !current.contains_key(*account))
+                        .map(
+                            |(account, (health_factor, alert_threshold))| HealthFactorCrossing {
+                                account: account.clone(),
+                                previous: Some(*health_factor),
+                                current: None,
+                                alert_threshold: *alert_threshold,
+                            },
+                        ),
+                );
+                previous = current;
+
+                future::ready((!crossings.is_empty()).then_some(crossings))
+            });
+
+        let fut = async move {
+            if let Some(mut sink) = pending.accept() {
+                sink.pipe_from_stream(stream.boxed()).await;
+            }
+        };
+        self.executor
+            .spawn("loans-rpc-subscription", Some("rpc"), fut.boxed());
+    }
 }
 
 /// Converts a runtime trap into an RPC error.
@@ -199,3 +376,16 @@ fn try_into_rpc_balance<T: std::fmt::Display + Copy + TryInto<NumberOrHex>>(
         )))
     })
 }
+
+fn decode_hex<H: std::fmt::Debug + Copy, T: TryFrom<H>>(
+    from: H,
+    name: &str,
+) -> Result<T, JsonRpseeError> {
+    from.try_into().map_err(|_| {
+        JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+            ErrorCode::InvalidParams.code(),
+            format!("{:?} does not fit into the {} type", from, name),
+            None::<()>,
+        )))
+    })
+}
@@ -199,6 +199,62 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::NewMarket(SKSM, pending_market_mock::<T>(PSKSM)).into());
     }
 
+    propose_market {
+    }: _(SystemOrigin::Root, SKSM, pending_market_mock::<T>(PSKSM))
+    verify {
+        assert!(MarketProposals::<T>::contains_key(SKSM));
+    }
+
+    veto_market {
+        assert_ok!(Loans::<T>::propose_market(SystemOrigin::Root.into(), SKSM, pending_market_mock::<T>(PSKSM)));
+    }: _(SystemOrigin::Root, SKSM)
+    verify {
+        assert_last_event::<T>(Event::<T>::MarketVetoed(SKSM).into());
+    }
+
+    set_collateral_class {
+    }: _(SystemOrigin::Root, SKSM, Some(CollateralClass::LiquidStaking))
+    verify {
+        assert_last_event::<T>(Event::<T>::CollateralClassUpdated(SKSM, Some(CollateralClass::LiquidStaking)).into());
+    }
+
+    set_debt_ceiling {
+        let ceiling: BalanceOf<T> = 1_000_000_000_000_000_000_000;
+    }: _(SystemOrigin::Root, CollateralClass::LiquidStaking, Some(ceiling))
+    verify {
+        assert_last_event::<T>(Event::<T>::DebtCeilingUpdated(CollateralClass::LiquidStaking, Some(ceiling)).into());
+    }
+
+    refresh_class_debts {
+        let n in 1 .. T::MaxPageSize::get();
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(caller.clone());
+        assert_ok!(Loans::<T>::add_market(SystemOrigin::Root.into(), USDT, pending_market_mock::<T>(PUSDT)));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), USDT));
+        assert_ok!(Loans::<T>::set_collateral_class(SystemOrigin::Root.into(), USDT, Some(CollateralClass::Bridged)));
+        assert_ok!(Loans::<T>::mint(SystemOrigin::Signed(caller).into(), USDT, INITIAL_AMOUNT.into()));
+
+        let mut accounts: Vec<T::AccountId> = vec![];
+        for i in 0 .. n {
+            let account: T::AccountId = account("borrower", i, SEED);
+            AccountBorrows::<T>::insert(USDT, &account, BorrowSnapshot {
+                principal: 1_000,
+                borrow_index: Rate::one(),
+            });
+            TotalBorrows::<T>::mutate(USDT, |total_borrows| *total_borrows += 1_000);
+            AccountDeposits::<T>::insert(USDT, &account, Deposits {
+                voucher_balance: 10_000,
+                is_collateral: true,
+            });
+            TotalSupply::<T>::mutate(USDT, |total_supply| *total_supply += 10_000);
+            accounts.push(account);
+        }
+        let refreshed = accounts[n as usize - 1].clone();
+    }: _(SystemOrigin::Signed(whitelisted_caller()), accounts)
+    verify {
+        assert_last_event::<T>(Event::<T>::ClassDebtsRefreshed(refreshed).into());
+    }
+
     activate_market {
         assert_ok!(Loans::<T>::add_market(SystemOrigin::Root.into(), SKSM, pending_market_mock::<T>(PSKSM)));
     }: _(SystemOrigin::Root, SKSM)
@@ -215,6 +271,20 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::UpdatedMarket(USDT, market).into());
     }
 
+    transition_rate_model {
+        assert_ok!(Loans::<T>::add_market(SystemOrigin::Root.into(), USDT, pending_market_mock::<T>(PUSDT)));
+        let period: T::BlockNumber = 100u32.into();
+    }: _(SystemOrigin::Root, USDT, RATE_MODEL_MOCK, period)
+    verify {
+        let end = frame_system::Pallet::<T>::block_number() + period;
+        assert_last_event::<T>(Event::<T>::RateModelTransitionStarted(
+            USDT,
+            pending_market_mock::<T>(PUSDT).rate_model,
+            RATE_MODEL_MOCK,
+            end,
+        ).into());
+    }
+
     update_market {
         assert_ok!(Loans::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(PKSM)));
     }: _(
@@ -402,6 +472,9 @@ benchmarks! {
         let borrowed_amount: u32 = 200_000_000;
         let liquidate_amount: u32 = 100_000_000;
         let incentive_amount: u32 = 110_000_000;
+        // keeps the repaid amount above the dust threshold of the runtimes
+        pallet_prices::Pallet::<T>::set_price(SystemOrigin::Root.into(), KSM, 1_000.into()).unwrap();
+        pallet_prices::Pallet::<T>::set_price(SystemOrigin::Root.into(), SKSM, 1_000.into()).unwrap();
         assert_ok!(Loans::<T>::add_market(SystemOrigin::Root.into(), SKSM, pending_market_mock::<T>(PSKSM)));
         assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), SKSM));
         assert_ok!(Loans::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(PKSM)));
@@ -447,6 +520,114 @@ benchmarks! {
     verify {
         assert_last_event::<T>(Event::<T>::LiquidationFreeCollateralsUpdated(vec![CDOT_6_13]).into());
     }
+
+    accrue_market_interest {
+        let caller: T::AccountId = whitelisted_caller();
+        let payer = T::Lookup::unlookup(caller.clone());
+        transfer_initial_balance::<T>(caller.clone());
+        let add_amount: u32 = 2000;
+        assert_ok!(Loans::<T>::add_market(SystemOrigin::Root.into(), USDT, pending_market_mock::<T>(PUSDT)));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), USDT));
+        assert_ok!(Loans::<T>::add_reserves(SystemOrigin::Root.into(), payer, USDT, add_amount.into()));
+        frame_system::Pallet::<T>::set_block_number(T::KeeperStalePeriod::get());
+    }: _(SystemOrigin::Signed(caller.clone()), USDT)
+    verify {
+        assert_last_event::<T>(Event::<T>::KeeperRewarded(caller, USDT, add_amount.into(), 0).into());
+    }
+
+    update_market_reward_indexes {
+        let caller: T::AccountId = whitelisted_caller();
+        let payer = T::Lookup::unlookup(caller.clone());
+        transfer_initial_balance::<T>(caller.clone());
+        let add_amount: u32 = 2000;
+        assert_ok!(Loans::<T>::add_market(SystemOrigin::Root.into(), USDT, pending_market_mock::<T>(PUSDT)));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), USDT));
+        assert_ok!(Loans::<T>::add_reserves(SystemOrigin::Root.into(), payer, USDT, add_amount.into()));
+        frame_system::Pallet::<T>::set_block_number(T::KeeperStalePeriod::get());
+    }: _(SystemOrigin::Signed(caller.clone()), USDT)
+    verify {
+        assert_last_event::<T>(Event::<T>::KeeperRewarded(caller, USDT, add_amount.into(), 0).into());
+    }
+
+    write_off_dust_positions {
+        let n in 1 .. T::MaxPageSize::get();
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(caller.clone());
+        let deposit_amount: u32 = 200_000_000;
+        assert_ok!(Loans::<T>::add_market(SystemOrigin::Root.into(), USDT, pending_market_mock::<T>(PUSDT)));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), USDT));
+        assert_ok!(Loans::<T>::mint(SystemOrigin::Signed(caller).into(), USDT, deposit_amount.into()));
+        <T as pallet::Config>::Assets::mint_into(USDT, &T::TreasuryAccount::get(), INITIAL_AMOUNT.into()).unwrap();
+
+        let mut accounts: Vec<T::AccountId> = vec![];
+        for i in 0 .. n {
+            let account: T::AccountId = account("dust", i, SEED);
+            AccountBorrows::<T>::insert(USDT, &account, BorrowSnapshot {
+                principal: 1,
+                borrow_index: Rate::one(),
+            });
+            TotalBorrows::<T>::mutate(USDT, |total_borrows| *total_borrows += 1);
+            AccountDeposits::<T>::insert(USDT, &account, Deposits {
+                voucher_balance: 1,
+                is_collateral: true,
+            });
+            TotalSupply::<T>::mutate(USDT, |total_supply| *total_supply += 1);
+            accounts.push(account);
+        }
+    }: _(SystemOrigin::Root, USDT, accounts.clone())
+    verify {
+        for account in accounts {
+            assert!(!AccountBorrows::<T>::contains_key(USDT, &account));
+            assert!(!AccountDeposits::<T>::contains_key(USDT, &account));
+        }
+    }
+
+    sweep_dust_positions {
+        let n in 1 .. T::MaxPageSize::get();
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(caller);
+        assert_ok!(Loans::<T>::add_market(SystemOrigin::Root.into(), USDT, pending_market_mock::<T>(PUSDT)));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), USDT));
+        <T as pallet::Config>::Assets::mint_into(USDT, &T::TreasuryAccount::get(), INITIAL_AMOUNT.into()).unwrap();
+
+        for i in 0 .. n {
+            let account: T::AccountId = account("dust", i, SEED);
+            AccountBorrows::<T>::insert(USDT, &account, BorrowSnapshot {
+                principal: 1,
+                borrow_index: Rate::one(),
+            });
+            TotalBorrows::<T>::mutate(USDT, |total_borrows| *total_borrows += 1);
+            AccountDeposits::<T>::insert(USDT, &account, Deposits {
+                voucher_balance: 1,
+                is_collateral: true,
+            });
+            TotalSupply::<T>::mutate(USDT, |total_supply| *total_supply += 1);
+        }
+    }: _(SystemOrigin::Root, USDT, n)
+    verify {
+        assert_eq!(AccountBorrows::<T>::iter_prefix(USDT).count(), 0);
+    }
+
+    set_risk_settings {
+        let caller: T::AccountId = whitelisted_caller();
+        let settings = RiskSettings {
+            max_ltv: Some(Ratio::from_percent(60)),
+            alert_threshold: Some(FixedU128::saturating_from_rational(12u128, 10u128)),
+        };
+    }: _(SystemOrigin::Signed(caller.clone()), settings)
+    verify {
+        assert_last_event::<T>(Event::<T>::RiskSettingsUpdated(caller, settings).into());
+    }
+
+    set_borrow_limit {
+        let caller: T::AccountId = whitelisted_caller();
+        let limit: u32 = 1000;
+        assert_ok!(Loans::<T>::add_market(SystemOrigin::Root.into(), USDT, pending_market_mock::<T>(PUSDT)));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), USDT));
+    }: _(SystemOrigin::Signed(caller.clone()), USDT, Some(limit.into()))
+    verify {
+        assert_last_event::<T>(Event::<T>::BorrowLimitUpdated(caller, USDT, Some(limit.into())).into());
+    }
 }
 
 impl_benchmark_test_suite!(Loans, crate::mock::new_test_ext(), crate::mock::Test);
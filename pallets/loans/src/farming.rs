@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use pallet_traits::RewardClaimer;
 use sp_io::hashing::blake2_256;
 use sp_runtime::{traits::Zero, DispatchResult};
 
@@ -60,58 +61,81 @@ impl<T: Config> Pallet<T> {
         Ok(reward_delta)
     }
 
+    /// Supply reward index of the market as of the current block
+    fn current_reward_supply_index(asset_id: AssetIdOf<T>) -> Result<u128, DispatchError> {
+        let current_block_number = <frame_system::Pallet<T>>::block_number();
+        let supply_state = RewardSupplyState::<T>::get(asset_id);
+        let delta_block = current_block_number.saturating_sub(supply_state.block);
+        let supply_speed = RewardSupplySpeed::<T>::get(asset_id);
+        if delta_block.is_zero() || supply_speed.is_zero() {
+            return Ok(supply_state.index);
+        }
+        let total_supply = TotalSupply::<T>::get(asset_id);
+        let delta_index =
+            Self::calculate_reward_delta_index(delta_block, supply_speed, total_supply)?;
+        Ok(supply_state
+            .index
+            .checked_add(delta_index)
+            .ok_or(ArithmeticError::Overflow)?)
+    }
+
+    /// Borrow reward index of the market as of the current block
+    fn current_reward_borrow_index(asset_id: AssetIdOf<T>) -> Result<u128, DispatchError> {
+        let current_block_number = <frame_system::Pallet<T>>::block_number();
+        let borrow_state = RewardBorrowState::<T>::get(asset_id);
+        let delta_block = current_block_number.saturating_sub(borrow_state.block);
+        let borrow_speed = RewardBorrowSpeed::<T>::get(asset_id);
+        if delta_block.is_zero() || borrow_speed.is_zero() {
+            return Ok(borrow_state.index);
+        }
+        let current_borrow_amount = TotalBorrows::<T>::get(asset_id);
+        let current_borrow_index = BorrowIndex::<T>::get(asset_id);
+        let base_borrow_amount = current_borrow_index
+            .reciprocal()
+            .and_then(|r| r.checked_mul_int(current_borrow_amount))
+            .ok_or(ArithmeticError::Overflow)?;
+        let delta_index =
+            Self::calculate_reward_delta_index(delta_block, borrow_speed, base_borrow_amount)?;
+        Ok(borrow_state
+            .index
+            .checked_add(delta_index)
+            .ok_or(ArithmeticError::Overflow)?)
+    }
+
+    fn base_borrow_amount(
+        borrower: &T::AccountId,
+        asset_id: AssetIdOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let current_borrow_amount = Self::current_borrow_balance(borrower, asset_id)?;
+        let current_borrow_index = BorrowIndex::<T>::get(asset_id);
+        Ok(current_borrow_index
+            .reciprocal()
+            .and_then(|r| r.checked_mul_int(current_borrow_amount))
+            .ok_or(ArithmeticError::Overflow)?)
+    }
+
     pub(crate) fn update_reward_supply_index(asset_id: AssetIdOf<T>) -> DispatchResult {
         let current_block_number = <frame_system::Pallet<T>>::block_number();
-        RewardSupplyState::<T>::try_mutate(asset_id, |supply_state| -> DispatchResult {
-            let delta_block = current_block_number.saturating_sub(supply_state.block);
-            if delta_block.is_zero() {
-                return Ok(());
+        let index = Self::current_reward_supply_index(asset_id)?;
+        RewardSupplyState::<T>::mutate(asset_id, |supply_state| {
+            if current_block_number > supply_state.block {
+                supply_state.index = index;
+                supply_state.block = current_block_number;
             }
-            let supply_speed = RewardSupplySpeed::<T>::get(asset_id);
-            if !supply_speed.is_zero() {
-                let total_supply = TotalSupply::<T>::get(asset_id);
-                let delta_index =
-                    Self::calculate_reward_delta_index(delta_block, supply_speed, total_supply)?;
-                supply_state.index = supply_state
-                    .index
-                    .checked_add(delta_index)
-                    .ok_or(ArithmeticError::Overflow)?;
-            }
-            supply_state.block = current_block_number;
-
-            Ok(())
-        })
+        });
+        Ok(())
     }
 
     pub(crate) fn update_reward_borrow_index(asset_id: AssetIdOf<T>) -> DispatchResult {
         let current_block_number = <frame_system::Pallet<T>>::block_number();
-        RewardBorrowState::<T>::try_mutate(asset_id, |borrow_state| -> DispatchResult {
-            let delta_block = current_block_number.saturating_sub(borrow_state.block);
-            if delta_block.is_zero() {
-                return Ok(());
+        let index = Self::current_reward_borrow_index(asset_id)?;
+        RewardBorrowState::<T>::mutate(asset_id, |borrow_state| {
+            if current_block_number > borrow_state.block {
+                borrow_state.index = index;
+                borrow_state.block = current_block_number;
             }
-            let borrow_speed = RewardBorrowSpeed::<T>::get(asset_id);
-            if !borrow_speed.is_zero() {
-                let current_borrow_amount = TotalBorrows::<T>::get(asset_id);
-                let current_borrow_index = BorrowIndex::<T>::get(asset_id);
-                let base_borrow_amount = current_borrow_index
-                    .reciprocal()
-                    .and_then(|r| r.checked_mul_int(current_borrow_amount))
-                    .ok_or(ArithmeticError::Overflow)?;
-                let delta_index = Self::calculate_reward_delta_index(
-                    delta_block,
-                    borrow_speed,
-                    base_borrow_amount,
-                )?;
-                borrow_state.index = borrow_state
-                    .index
-                    .checked_add(delta_index)
-                    .ok_or(ArithmeticError::Overflow)?;
-            }
-            borrow_state.block = current_block_number;
-
-            Ok(())
-        })
+        });
+        Ok(())
     }
 
     pub(crate) fn distribute_supplier_reward(
@@ -165,12 +189,7 @@ impl<T: Config> Pallet<T> {
                 *borrower_index = borrow_state.index;
 
                 RewardAccrued::<T>::try_mutate(borrower, |total_reward| -> DispatchResult {
-                    let current_borrow_amount = Self::current_borrow_balance(borrower, asset_id)?;
-                    let current_borrow_index = BorrowIndex::<T>::get(asset_id);
-                    let base_borrow_amount = current_borrow_index
-                        .reciprocal()
-                        .and_then(|r| r.checked_mul_int(current_borrow_amount))
-                        .ok_or(ArithmeticError::Overflow)?;
+                    let base_borrow_amount = Self::base_borrow_amount(borrower, asset_id)?;
                     let reward_delta =
                         Self::calculate_reward_delta(base_borrow_amount, delta_index)?;
                     *total_reward = total_reward
@@ -213,4 +232,41 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::<T>::RewardPaid(user.clone(), total_reward));
         Ok(())
     }
+
+    /// Reward of `user` which would be paid by claiming from all markets, including
+    /// the reward since the reward indexes were last updated.
+    pub fn pending_reward(user: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+        Markets::<T>::iter_keys().try_fold(Self::reward_accrued(user), |total, asset_id| {
+            let supply_delta_index = Self::current_reward_supply_index(asset_id)?
+                .checked_sub(RewardSupplierIndex::<T>::get(asset_id, user))
+                .ok_or(ArithmeticError::Underflow)?;
+            let supplier_amount = AccountDeposits::<T>::get(asset_id, user).voucher_balance;
+            let supply_reward = Self::calculate_reward_delta(supplier_amount, supply_delta_index)?;
+
+            let borrow_delta_index = Self::current_reward_borrow_index(asset_id)?
+                .checked_sub(RewardBorrowerIndex::<T>::get(asset_id, user))
+                .ok_or(ArithmeticError::Underflow)?;
+            let base_borrow_amount = Self::base_borrow_amount(user, asset_id)?;
+            let borrow_reward =
+                Self::calculate_reward_delta(base_borrow_amount, borrow_delta_index)?;
+
+            Ok(total
+                .checked_add(supply_reward)
+                .and_then(|r| r.checked_add(borrow_reward))
+                .ok_or(ArithmeticError::Overflow)?)
+        })
+    }
+}
+
+impl<T: Config> RewardClaimer<T::AccountId> for Pallet<T> {
+    fn claim_rewards(who: &T::AccountId, max_claims: u32) -> Result<u32, DispatchError> {
+        if max_claims.is_zero() || Self::pending_reward(who)?.is_zero() {
+            return Ok(0);
+        }
+        for asset_id in Markets::<T>::iter_keys() {
+            Self::collect_market_reward(asset_id, who)?;
+        }
+        Self::pay_reward(who)?;
+        Ok(1)
+    }
 }
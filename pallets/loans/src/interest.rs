@@ -41,6 +41,11 @@ impl<T: Config> Pallet<T> {
             borrow_index_new,
         ) = Self::get_market_status(asset_id)?;
 
+        T::Revenue::record(
+            RevenueSource::LoansReserve,
+            asset_id,
+            total_reserves_new.saturating_sub(Self::total_reserves(asset_id)),
+        );
         Self::update_last_accrued_interest_time(asset_id, now)?;
         TotalBorrows::<T>::insert(asset_id, total_borrows_new);
         TotalReserves::<T>::insert(asset_id, total_reserves_new);
@@ -51,7 +56,63 @@ impl<T: Config> Pallet<T> {
         BorrowRate::<T>::insert(asset_id, borrow_rate);
         SupplyRate::<T>::insert(asset_id, supply_rate);
         ExchangeRate::<T>::insert(asset_id, exchange_rate);
+        Self::complete_rate_model_transition(asset_id)?;
+
+        Ok(())
+    }
+
+    /// Borrow rate of the market at `utilization`, moving from the rate of the model it
+    /// transitions from to the rate of its own model over the transition
+    pub(crate) fn current_borrow_rate(
+        asset_id: AssetIdOf<T>,
+        market: &Market<BalanceOf<T>>,
+        utilization: Ratio,
+    ) -> Option<Rate> {
+        let target_rate = market.rate_model.get_borrow_rate(utilization)?;
+        let transition = match Self::rate_model_transition(asset_id) {
+            Some(transition) => transition,
+            None => return Some(target_rate),
+        };
+        let now = frame_system::Pallet::<T>::block_number();
+        if now >= transition.end {
+            return Some(target_rate);
+        }
+        let from_rate = transition.from.get_borrow_rate(utilization)?;
+        let elapsed = now
+            .saturating_sub(transition.start)
+            .saturated_into::<u128>();
+        let period = transition
+            .end
+            .saturating_sub(transition.start)
+            .saturated_into::<u128>();
+        let progress = Rate::checked_from_rational(elapsed, period)?;
+        if target_rate >= from_rate {
+            from_rate.checked_add(
+                &target_rate
+                    .checked_sub(&from_rate)?
+                    .saturating_mul(progress),
+            )
+        } else {
+            from_rate.checked_sub(
+                &from_rate
+                    .checked_sub(&target_rate)?
+                    .saturating_mul(progress),
+            )
+        }
+    }
 
+    // Drops the transition of the market once it fully uses its new rate model
+    fn complete_rate_model_transition(asset_id: AssetIdOf<T>) -> DispatchResult {
+        let ended = Self::rate_model_transition(asset_id).map_or(false, |transition| {
+            frame_system::Pallet::<T>::block_number() >= transition.end
+        });
+        if ended {
+            RateModelTransitions::<T>::remove(asset_id);
+            Self::deposit_event(Event::<T>::RateModelTransitionCompleted(
+                asset_id,
+                Self::market(asset_id)?.rate_model,
+            ));
+        }
         Ok(())
     }
 
@@ -77,10 +138,8 @@ impl<T: Config> Pallet<T> {
         let mut borrow_index = Self::borrow_index(asset_id);
 
         let util = Self::calc_utilization_ratio(total_cash, total_borrows, total_reserves)?;
-        let borrow_rate = market
-            .rate_model
-            .get_borrow_rate(util)
-            .ok_or(ArithmeticError::Overflow)?;
+        let borrow_rate =
+            Self::current_borrow_rate(asset_id, &market, util).ok_or(ArithmeticError::Overflow)?;
         let supply_rate =
             InterestRateModel::get_supply_rate(borrow_rate, util, market.reserve_factor);
 
@@ -119,6 +178,42 @@ impl<T: Config> Pallet<T> {
         ))
     }
 
+    /// Utilization, borrow rate and supply rate the market would have once interest is
+    /// accrued and `supplied` is deposited, `redeemed` withdrawn, `borrowed` borrowed and
+    /// `repaid` repaid
+    ///
+    /// Nothing is written to storage.
+    pub fn simulate_market_rates(
+        asset_id: AssetIdOf<T>,
+        supplied: BalanceOf<T>,
+        redeemed: BalanceOf<T>,
+        borrowed: BalanceOf<T>,
+        repaid: BalanceOf<T>,
+    ) -> Result<(Ratio, Rate, Rate), DispatchError> {
+        let market = Self::market(asset_id)?;
+        let (_, _, _, _, total_borrows, total_reserves, _) = Self::get_market_status(asset_id)?;
+
+        let total_cash = Self::get_total_cash(asset_id)
+            .checked_add(supplied)
+            .and_then(|r| r.checked_add(repaid))
+            .ok_or(ArithmeticError::Overflow)?
+            .checked_sub(redeemed)
+            .and_then(|r| r.checked_sub(borrowed))
+            .ok_or(Error::<T>::InsufficientCash)?;
+        let total_borrows = total_borrows
+            .checked_add(borrowed)
+            .ok_or(ArithmeticError::Overflow)?
+            .saturating_sub(repaid);
+
+        let util = Self::calc_utilization_ratio(total_cash, total_borrows, total_reserves)?;
+        let borrow_rate =
+            Self::current_borrow_rate(asset_id, &market, util).ok_or(ArithmeticError::Overflow)?;
+        let supply_rate =
+            InterestRateModel::get_supply_rate(borrow_rate, util, market.reserve_factor);
+
+        Ok((util, borrow_rate, supply_rate))
+    }
+
     /// Update the exchange rate according to the totalCash, totalBorrows and totalSupply.
     /// This function does not accrue interest before calculating the exchange rate.
     /// exchangeRate = (totalCash + totalBorrows - totalReserves) / totalSupply
@@ -169,7 +264,9 @@ impl<T: Config> Pallet<T> {
         LastAccruedInterestTime::<T>::try_mutate(asset_id, |last_time| -> DispatchResult {
             *last_time = time;
             Ok(())
-        })
+        })?;
+        LastAccruedInterestBlock::<T>::insert(asset_id, <frame_system::Pallet<T>>::block_number());
+        Ok(())
     }
 
     fn accrued_interest(
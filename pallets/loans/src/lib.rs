@@ -19,6 +19,16 @@
 //! Loans pallet implement the lending protocol by using a pool-based strategy
 //! that aggregates each user's supplied assets. The interest rate is dynamically
 //! determined by the supply and demand.
+//!
+//! ## Bounded iteration
+//!
+//! Calls never go over all the users of a market in one go: the dust write-off takes
+//! at most `MaxPageSize` accounts and `sweep_dust_positions` walks a market by pages
+//! with a stored cursor. What calls do iterate are the markets and the adapter
+//! collaterals, whose number only grows through governance (`add_market`,
+//! `set_adapter_collateral`), so it stays small. `get_health_factors`,
+//! `get_health_alerts` and `do_try_state` go over every borrower or depositor and
+//! must only be used by runtime APIs and try-runtime.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -27,9 +37,11 @@ use core::cmp::max;
 pub use crate::rate_model::*;
 
 use frame_support::{
+    dispatch::DispatchClass,
     log,
     pallet_prelude::*,
     require_transactional,
+    storage::{with_transaction, TransactionOutcome},
     traits::{
         tokens::fungibles::{Inspect, Mutate, Transfer},
         UnixTime,
@@ -40,8 +52,10 @@ use frame_system::pallet_prelude::*;
 use num_traits::cast::ToPrimitive;
 pub use pallet::*;
 use pallet_traits::{
-    ConvertToBigUint, Loans as LoansTrait, LoansMarketDataProvider, LoansPositionDataProvider,
-    MarketInfo, MarketStatus, PriceFeeder,
+    reentrancy::{DefiFlow, FlowGuard},
+    CollateralAdapter, ConvertToBigUint, EventMirror, Loans as LoansTrait, LoansMarketDataProvider,
+    LoansPositionDataProvider, MarketInfo, MarketStatus, MirroredEvent, PriceFeeder,
+    ProtocolConversion, Referrals, RevenueRecorder, RevenueSource, UnwindLiquidity, VolumeSource,
 };
 use primitives::{
     is_auxiliary_token, Balance, CurrencyId, Liquidity, Price, Rate, Ratio, Shortfall, Timestamp,
@@ -53,10 +67,17 @@ use sp_runtime::{
     },
     ArithmeticError, FixedPointNumber, FixedU128,
 };
-use sp_std::{result::Result, vec::Vec};
+use sp_std::{
+    collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+    result::Result,
+    vec::Vec,
+};
 
 use sp_io::hashing::blake2_256;
-pub use types::{BorrowSnapshot, Deposits, EarnedSnapshot, Market, MarketState, RewardMarketState};
+pub use types::{
+    AdapterCollateralInfo, BorrowSnapshot, CollateralClass, Deposits, EarnedSnapshot, Market,
+    MarketProposal, MarketState, RateSnapshot, RewardMarketState, RiskSettings, SweepCursor,
+};
 pub use weights::WeightInfo;
 
 mod benchmarking;
@@ -138,6 +159,61 @@ pub mod pallet {
 
         #[pallet::constant]
         type LiquidationFreeAssetId: Get<AssetIdOf<Self>>;
+
+        /// Records the interest added to the reserves
+        type Revenue: RevenueRecorder;
+
+        /// Records the supplied and borrowed volume of referred accounts
+        type Referrals: Referrals<Self::AccountId>;
+
+        /// Mirrors the liquidations to the EVM
+        type EventMirror: EventMirror<Self::AccountId>;
+
+        /// Min time between two snapshots of the market rates, in seconds
+        #[pallet::constant]
+        type RateSnapshotInterval: Get<Timestamp>;
+
+        /// Number of rate snapshots kept for each market, older ones are dropped
+        #[pallet::constant]
+        type MaxRateSnapshots: Get<u32>;
+
+        /// Number of blocks a market must go without interest accrual or reward
+        /// index update before a keeper gets paid for running it
+        #[pallet::constant]
+        type KeeperStalePeriod: Get<Self::BlockNumber>;
+
+        /// Value of the keeper reward, priced in the same unit as `get_asset_value`.
+        /// Paid out of the market reserves, zero disables it.
+        #[pallet::constant]
+        type KeeperRewardValue: Get<BalanceOf<Self>>;
+
+        /// Unwinds the LP token collateral seized by liquidators into the pooled assets
+        type LiquidityUnwinder: UnwindLiquidity<Self::AccountId, AssetIdOf<Self>, BalanceOf<Self>>;
+
+        /// Positions worth less than this, priced in the same unit as `get_asset_value`,
+        /// can be written off as dust. Zero disables the write-off.
+        #[pallet::constant]
+        type DustThresholdValue: Get<BalanceOf<Self>>;
+
+        /// Account repaying the borrows written off as dust
+        #[pallet::constant]
+        type TreasuryAccount: Get<Self::AccountId>;
+
+        /// Maximum number of accounts a call going over the users of a market
+        /// handles at once
+        #[pallet::constant]
+        type MaxPageSize: Get<u32>;
+
+        /// Hooks of the pallets keeping the collateral which isn't supplied to a market,
+        /// e.g. staked or crowdloan derivative tokens
+        type CollateralAdapter: CollateralAdapter<Self::AccountId, AssetIdOf<Self>, BalanceOf<Self>>;
+
+        /// The origin which can veto a proposed market before it is activated
+        type MarketVetoOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Number of blocks a proposed market is open to review before it can be activated
+        #[pallet::constant]
+        type MarketReviewPeriod: Get<Self::BlockNumber>;
     }
 
     #[pallet::error]
@@ -200,6 +276,35 @@ pub mod pallet {
         CodecError,
         /// Collateral is reserved and cannot be liquidated
         CollateralReserved,
+        /// Dust positions cannot be written off while `DustThresholdValue` is zero
+        DustWriteOffDisabled,
+        /// More accounts than `MaxPageSize` were given
+        TooManyAccounts,
+        /// The asset is not supported by the collateral adapter
+        CollateralNotSupported,
+        /// The asset is not registered as adapter collateral
+        AdapterCollateralDoesNotExist,
+        /// Adapter collateral is still deposited by some accounts
+        AdapterCollateralInUse,
+        /// Repay amount is worth less than `DustThresholdValue`
+        LiquidationTooSmall,
+        /// Borrow would go over the borrow limit the borrower set on the market
+        SelfBorrowLimitExceeded,
+        /// Borrowed value would go over the max LTV the borrower set
+        SelfMaxLtvExceeded,
+        /// A market was already proposed for the asset
+        MarketProposalAlreadyExists,
+        /// No market was proposed for the asset
+        MarketProposalDoesNotExist,
+        /// The proposed market is still under review
+        MarketUnderReview,
+        /// Borrow or collateral change would take the debt backed by a collateral class
+        /// over its ceiling
+        DebtCeilingExceeded,
+        /// The market is already moving to another rate model
+        RateModelTransitionInProgress,
+        /// Rate model transitions must last at least a block
+        InvalidTransitionPeriod,
     }
 
     #[pallet::event]
@@ -233,6 +338,17 @@ pub mod pallet {
             BalanceOf<T>,
             BalanceOf<T>,
         ),
+        /// LP token collateral seized by a liquidator was paid out as the pooled assets
+        /// [liquidator, lp_asset_id, lp_amount, base_asset_id, base_amount, quote_asset_id, quote_amount]
+        LiquidationUnwound(
+            T::AccountId,
+            AssetIdOf<T>,
+            BalanceOf<T>,
+            AssetIdOf<T>,
+            BalanceOf<T>,
+            AssetIdOf<T>,
+            BalanceOf<T>,
+        ),
         /// Event emitted when the reserves are reduced
         /// [admin, asset_id, reduced_amount, total_reserves]
         ReservesReduced(T::AccountId, AssetIdOf<T>, BalanceOf<T>, BalanceOf<T>),
@@ -265,6 +381,59 @@ pub mod pallet {
         IncentiveReservesReduced(T::AccountId, AssetIdOf<T>, BalanceOf<T>),
         /// Liquidation free collaterals has been updated
         LiquidationFreeCollateralsUpdated(Vec<AssetIdOf<T>>),
+        /// A keeper got paid out of the reserves for refreshing a stale market
+        /// [keeper, asset_id, reward_amount, total_reserves]
+        KeeperRewarded(T::AccountId, AssetIdOf<T>, BalanceOf<T>, BalanceOf<T>),
+        /// A dust borrow was repaid by the treasury and its storage reaped
+        /// [borrower, asset_id, written_off_amount]
+        BorrowDustWrittenOff(T::AccountId, AssetIdOf<T>, BalanceOf<T>),
+        /// A dust deposit was moved into the reserves and its storage reaped
+        /// [supplier, asset_id, written_off_amount]
+        SupplyDustWrittenOff(T::AccountId, AssetIdOf<T>, BalanceOf<T>),
+        /// A page of the dust sweep of a market went through
+        /// [asset_id, swept_accounts, completed]
+        DustPositionsSwept(AssetIdOf<T>, u32, bool),
+        /// Asset kept by the collateral adapter was registered, updated or removed
+        /// [asset_id, info]
+        AdapterCollateralUpdated(AssetIdOf<T>, Option<AdapterCollateralInfo>),
+        /// Collateral was deposited through the collateral adapter
+        /// [sender, asset_id, amount]
+        AdapterCollateralDeposited(T::AccountId, AssetIdOf<T>, BalanceOf<T>),
+        /// Collateral was withdrawn through the collateral adapter
+        /// [sender, asset_id, amount]
+        AdapterCollateralWithdrawn(T::AccountId, AssetIdOf<T>, BalanceOf<T>),
+        /// Borrower set their risk settings
+        /// [borrower, settings]
+        RiskSettingsUpdated(T::AccountId, RiskSettings),
+        /// Borrower set or removed their borrow limit on a market
+        /// [borrower, asset_id, limit]
+        BorrowLimitUpdated(T::AccountId, AssetIdOf<T>, Option<BalanceOf<T>>),
+        /// New market was proposed, it can be activated once reviewed
+        /// [asset_id, market, activatable_at]
+        MarketProposed(AssetIdOf<T>, Market<BalanceOf<T>>, T::BlockNumber),
+        /// Proposed market was vetoed
+        /// [asset_id]
+        MarketVetoed(AssetIdOf<T>),
+        /// Asset was put in or taken out of a collateral class
+        /// [asset_id, class]
+        CollateralClassUpdated(AssetIdOf<T>, Option<CollateralClass>),
+        /// Debt ceiling of a collateral class was set or removed
+        /// [class, ceiling]
+        DebtCeilingUpdated(CollateralClass, Option<BalanceOf<T>>),
+        /// Debt an account has backed by each collateral class was recomputed
+        /// [account]
+        ClassDebtsRefreshed(T::AccountId),
+        /// Market started moving from its rate model to a new one
+        /// [asset_id, old_rate_model, new_rate_model, end_block]
+        RateModelTransitionStarted(
+            AssetIdOf<T>,
+            InterestRateModel,
+            InterestRateModel,
+            T::BlockNumber,
+        ),
+        /// Market finished moving to its new rate model
+        /// [asset_id, rate_model]
+        RateModelTransitionCompleted(AssetIdOf<T>, InterestRateModel),
     }
 
     /// The timestamp of the last calculation of accrued interest
@@ -273,6 +442,12 @@ pub mod pallet {
     pub type LastAccruedInterestTime<T: Config> =
         StorageMap<_, Blake2_128Concat, AssetIdOf<T>, Timestamp, ValueQuery>;
 
+    /// The block number of the last calculation of accrued interest
+    #[pallet::storage]
+    #[pallet::getter(fn last_accrued_interest_block)]
+    pub type LastAccruedInterestBlock<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetIdOf<T>, T::BlockNumber, ValueQuery>;
+
     /// Liquidation free collateral.
     #[pallet::storage]
     #[pallet::getter(fn liquidation_free_collaterals)]
@@ -451,6 +626,132 @@ pub mod pallet {
     pub type RewardAccrued<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+    /// Time of the last snapshot of the market rates
+    #[pallet::storage]
+    #[pallet::getter(fn last_rate_snapshot_time)]
+    pub type LastRateSnapshotTime<T: Config> = StorageValue<_, Timestamp, ValueQuery>;
+
+    /// Latest rate snapshots of each market, oldest first
+    /// CurrencyId -> Vec<RateSnapshot>
+    #[pallet::storage]
+    #[pallet::getter(fn rate_history)]
+    pub type RateHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        BoundedVec<RateSnapshot, T::MaxRateSnapshots>,
+        ValueQuery,
+    >;
+
+    /// Where the ongoing dust sweep of each market resumes
+    /// CurrencyId -> SweepCursor
+    #[pallet::storage]
+    #[pallet::getter(fn dust_sweep_cursor)]
+    pub type DustSweepCursor<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetIdOf<T>, SweepCursor<T::AccountId>, OptionQuery>;
+
+    /// Assets kept by `CollateralAdapter` which can be used as collateral
+    /// CurrencyId -> AdapterCollateralInfo
+    #[pallet::storage]
+    #[pallet::getter(fn adapter_collateral)]
+    pub type AdapterCollaterals<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetIdOf<T>, AdapterCollateralInfo, OptionQuery>;
+
+    /// Collateral deposited through `CollateralAdapter`
+    /// CurrencyId -> Owner -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn account_adapter_collateral)]
+    pub type AccountAdapterCollaterals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Limits and alert threshold borrowers set on their own positions
+    /// AccountId -> RiskSettings
+    #[pallet::storage]
+    #[pallet::getter(fn account_risk_settings)]
+    pub type AccountRiskSettings<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, RiskSettings, OptionQuery>;
+
+    /// Limits borrowers set on their borrows of each market
+    /// CurrencyId -> Borrower -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn account_borrow_limit)]
+    pub type AccountBorrowLimits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// Markets proposed for listing, waiting out their review period
+    /// CurrencyId -> MarketProposal
+    #[pallet::storage]
+    #[pallet::getter(fn market_proposal)]
+    pub type MarketProposals<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        MarketProposal<BalanceOf<T>, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Class of the assets whose collateral counts towards a debt ceiling
+    /// CurrencyId -> CollateralClass
+    #[pallet::storage]
+    #[pallet::getter(fn collateral_class)]
+    pub type CollateralClasses<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetIdOf<T>, CollateralClass, OptionQuery>;
+
+    /// Most debt, in value, the collateral of a class may back across all markets
+    /// CollateralClass -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn debt_ceiling)]
+    pub type DebtCeilings<T: Config> =
+        StorageMap<_, Twox64Concat, CollateralClass, BalanceOf<T>, OptionQuery>;
+
+    /// Debt, in value, backed by the collateral of each class
+    /// CollateralClass -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn class_debt)]
+    pub type ClassDebts<T: Config> =
+        StorageMap<_, Twox64Concat, CollateralClass, BalanceOf<T>, ValueQuery>;
+
+    /// Debt, in value, of each borrower backed by the collateral of each class, as of
+    /// their last borrow, repay or liquidation
+    /// CollateralClass -> Borrower -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn account_class_debt)]
+    pub type AccountClassDebts<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        CollateralClass,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Rate model each market is moving away from
+    /// CurrencyId -> RateModelTransition
+    #[pallet::storage]
+    #[pallet::getter(fn rate_model_transition)]
+    pub type RateModelTransitions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        RateModelTransition<T::BlockNumber>,
+        OptionQuery,
+    >;
+
     /// DefaultVersion is using for initialize the StorageVersion
     #[pallet::type_value]
     pub(super) fn DefaultVersion<T: Config>() -> Versions {
@@ -466,16 +767,38 @@ pub mod pallet {
     #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        fn on_initialize(_n: T::BlockNumber) -> Weight {
+            let now = T::UnixTime::now().as_secs();
+            let next_snapshot_time =
+                Self::last_rate_snapshot_time().saturating_add(T::RateSnapshotInterval::get());
+            if now < next_snapshot_time {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let markets = Self::snapshot_rates(now);
+            // Market status reads the market, its totals, cash and last accrual time
+            T::DbWeight::get().reads_writes(1, 1).saturating_add(
+                T::DbWeight::get()
+                    .reads_writes(8, 1)
+                    .saturating_mul(markets),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+            Self::do_try_state()
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Stores a new market and its related currency. Returns `Err` if a currency
-        /// is not attached to an existent market.
+        /// Stores a new market and its related currency, bypassing the review of
+        /// `propose_market`. Only root can do this.
         ///
         /// All provided market states must be `Pending`, otherwise an error will be returned.
         ///
-        /// If a currency is already attached to a market, then the market will be replaced
-        /// by the new provided value.
-        ///
         /// The ptoken id and asset id are bound, the ptoken id of new provided market cannot
         /// be duplicated with the existing one, otherwise it will return `InvalidPtokenId`.
         ///
@@ -488,59 +811,15 @@ pub mod pallet {
             asset_id: AssetIdOf<T>,
             market: Market<BalanceOf<T>>,
         ) -> DispatchResultWithPostInfo {
-            T::UpdateOrigin::ensure_origin(origin)?;
-            ensure!(
-                !Markets::<T>::contains_key(asset_id),
-                Error::<T>::MarketAlreadyExists
-            );
-            ensure!(
-                market.state == MarketState::Pending,
-                Error::<T>::NewMarketMustHavePendingState
-            );
-            ensure!(
-                market.rate_model.check_model(),
-                Error::<T>::InvalidRateModelParam
-            );
-            ensure!(
-                market.collateral_factor >= Ratio::zero()
-                    && market.collateral_factor < Ratio::one(),
-                Error::<T>::InvalidFactor,
-            );
-            ensure!(
-                market.liquidation_threshold < Ratio::one()
-                    && market.liquidation_threshold >= market.collateral_factor,
-                Error::<T>::InvalidFactor
-            );
-            ensure!(
-                market.reserve_factor > Ratio::zero() && market.reserve_factor < Ratio::one(),
-                Error::<T>::InvalidFactor,
-            );
-            ensure!(
-                market.liquidate_incentive_reserved_factor > Ratio::zero()
-                    && market.liquidate_incentive_reserved_factor < Ratio::one(),
-                Error::<T>::InvalidFactor,
-            );
-            ensure!(
-                market.supply_cap > Zero::zero(),
-                Error::<T>::InvalidSupplyCap,
-            );
-
-            // Ensures a given `ptoken_id` not exists on the `Market` and `UnderlyingAssetId`.
-            Self::ensure_ptoken(market.ptoken_id)?;
-            // Update storage of `Market` and `UnderlyingAssetId`
-            Markets::<T>::insert(asset_id, market.clone());
-            UnderlyingAssetId::<T>::insert(market.ptoken_id, asset_id);
-
-            // Init the ExchangeRate and BorrowIndex for asset
-            ExchangeRate::<T>::insert(asset_id, Rate::from_inner(MIN_EXCHANGE_RATE));
-            BorrowIndex::<T>::insert(asset_id, Rate::one());
-
-            Self::deposit_event(Event::<T>::NewMarket(asset_id, market));
+            ensure_root(origin)?;
+            Self::ensure_new_market(asset_id, &market)?;
+            Self::do_add_market(asset_id, market);
             Ok(().into())
         }
 
         /// Activates a market. Returns `Err` if the market currency does not exist.
         ///
+        /// A proposed market is listed first, once its review period is over.
         /// If the market is already activated, does nothing.
         ///
         /// - `asset_id`: Market related currency
@@ -551,6 +830,16 @@ pub mod pallet {
             asset_id: AssetIdOf<T>,
         ) -> DispatchResultWithPostInfo {
             T::UpdateOrigin::ensure_origin(origin)?;
+            if !Markets::<T>::contains_key(asset_id) {
+                if let Some(proposal) = MarketProposals::<T>::take(asset_id) {
+                    ensure!(
+                        frame_system::Pallet::<T>::block_number() >= proposal.activatable_at,
+                        Error::<T>::MarketUnderReview
+                    );
+                    Self::ensure_new_market(asset_id, &proposal.market)?;
+                    Self::do_add_market(asset_id, proposal.market);
+                }
+            }
             Self::mutate_market(asset_id, |stored_market| {
                 if let MarketState::Active = stored_market.state {
                     return stored_market.clone();
@@ -580,6 +869,7 @@ pub mod pallet {
                 stored_market.rate_model = rate_model;
                 stored_market.clone()
             })?;
+            RateModelTransitions::<T>::remove(asset_id);
             Self::deposit_event(Event::<T>::UpdatedMarket(asset_id, market));
 
             Ok(().into())
@@ -685,6 +975,7 @@ pub mod pallet {
                 *stored_market = market;
                 stored_market.clone()
             })?;
+            RateModelTransitions::<T>::remove(asset_id);
 
             Self::deposit_event(Event::<T>::UpdatedMarket(asset_id, updated_market));
             Ok(().into())
@@ -822,7 +1113,7 @@ pub mod pallet {
         ///
         /// - `asset_id`: the asset to be deposited.
         /// - `mint_amount`: the amount to be deposited.
-        #[pallet::weight(T::WeightInfo::mint())]
+        #[pallet::weight(T::WeightInfo::mint().saturating_add(T::WeightInfo::refresh_class_debts(1)))]
         #[transactional]
         pub fn mint(
             origin: OriginFor<T>,
@@ -830,6 +1121,7 @@ pub mod pallet {
             #[pallet::compact] mint_amount: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Loans)?;
             Self::do_mint(&who, asset_id, mint_amount)?;
 
             Ok(().into())
@@ -839,7 +1131,7 @@ pub mod pallet {
         ///
         /// - `asset_id`: the asset to be redeemed.
         /// - `redeem_amount`: the amount to be redeemed.
-        #[pallet::weight(T::WeightInfo::redeem())]
+        #[pallet::weight(T::WeightInfo::redeem().saturating_add(T::WeightInfo::refresh_class_debts(1)))]
         #[transactional]
         pub fn redeem(
             origin: OriginFor<T>,
@@ -847,6 +1139,7 @@ pub mod pallet {
             #[pallet::compact] redeem_amount: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Loans)?;
             ensure!(!redeem_amount.is_zero(), Error::<T>::InvalidAmount);
             Self::do_redeem(&who, asset_id, redeem_amount)?;
 
@@ -856,13 +1149,14 @@ pub mod pallet {
         /// Sender redeems all of internal supplies in exchange for the underlying asset.
         ///
         /// - `asset_id`: the asset to be redeemed.
-        #[pallet::weight(T::WeightInfo::redeem_all())]
+        #[pallet::weight(T::WeightInfo::redeem_all().saturating_add(T::WeightInfo::refresh_class_debts(1)))]
         #[transactional]
         pub fn redeem_all(
             origin: OriginFor<T>,
             asset_id: AssetIdOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Loans)?;
             Self::ensure_active_market(asset_id)?;
             Self::accrue_interest(asset_id)?;
             let exchange_rate = Self::exchange_rate_stored(asset_id)?;
@@ -878,7 +1172,7 @@ pub mod pallet {
         ///
         /// - `asset_id`: the asset to be borrowed.
         /// - `borrow_amount`: the amount to be borrowed.
-        #[pallet::weight(T::WeightInfo::borrow())]
+        #[pallet::weight(T::WeightInfo::borrow().saturating_add(T::WeightInfo::refresh_class_debts(1)))]
         #[transactional]
         pub fn borrow(
             origin: OriginFor<T>,
@@ -886,6 +1180,7 @@ pub mod pallet {
             #[pallet::compact] borrow_amount: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Loans)?;
 
             Self::do_borrow(&who, asset_id, borrow_amount)?;
 
@@ -896,7 +1191,7 @@ pub mod pallet {
         ///
         /// - `asset_id`: the asset to be repaid.
         /// - `repay_amount`: the amount to be repaid.
-        #[pallet::weight(T::WeightInfo::repay_borrow())]
+        #[pallet::weight(T::WeightInfo::repay_borrow().saturating_add(T::WeightInfo::refresh_class_debts(1)))]
         #[transactional]
         pub fn repay_borrow(
             origin: OriginFor<T>,
@@ -904,6 +1199,7 @@ pub mod pallet {
             #[pallet::compact] repay_amount: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Loans)?;
 
             Self::do_repay_borrow(&who, asset_id, repay_amount)?;
 
@@ -913,13 +1209,14 @@ pub mod pallet {
         /// Sender repays all of their debts.
         ///
         /// - `asset_id`: the asset to be repaid.
-        #[pallet::weight(T::WeightInfo::repay_borrow_all())]
+        #[pallet::weight(T::WeightInfo::repay_borrow_all().saturating_add(T::WeightInfo::refresh_class_debts(1)))]
         #[transactional]
         pub fn repay_borrow_all(
             origin: OriginFor<T>,
             asset_id: AssetIdOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Loans)?;
             Self::ensure_active_market(asset_id)?;
             Self::accrue_interest(asset_id)?;
             let account_borrows = Self::current_borrow_balance(&who, asset_id)?;
@@ -932,7 +1229,7 @@ pub mod pallet {
         ///
         /// - `asset_id`: the asset to be set.
         /// - `enable`: turn on/off the collateral option.
-        #[pallet::weight(T::WeightInfo::collateral_asset())]
+        #[pallet::weight(T::WeightInfo::collateral_asset().saturating_add(T::WeightInfo::refresh_class_debts(1)))]
         #[transactional]
         pub fn collateral_asset(
             origin: OriginFor<T>,
@@ -940,6 +1237,7 @@ pub mod pallet {
             enable: bool,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Loans)?;
             Self::ensure_active_market(asset_id)?;
             ensure!(
                 AccountDeposits::<T>::contains_key(asset_id, &who),
@@ -961,7 +1259,13 @@ pub mod pallet {
         /// - `liquidation_asset_id`: the assert to be liquidated.
         /// - `repay_amount`: the amount to be repaid borrow.
         /// - `collateral_asset_id`: The collateral to seize from the borrower.
-        #[pallet::weight(T::WeightInfo::liquidate_borrow())]
+        ///
+        /// Liquidations are operational so that they still make it into full blocks,
+        /// the repaid amount must be worth at least `DustThresholdValue`.
+        #[pallet::weight((
+            T::WeightInfo::liquidate_borrow().saturating_add(T::WeightInfo::refresh_class_debts(1)),
+            DispatchClass::Operational
+        ))]
         #[transactional]
         pub fn liquidate_borrow(
             origin: OriginFor<T>,
@@ -971,20 +1275,32 @@ pub mod pallet {
             collateral_asset_id: AssetIdOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Loans)?;
             ensure!(
                 !Self::liquidation_free_collaterals().contains(&collateral_asset_id)
                     && !is_auxiliary_token(collateral_asset_id),
                 Error::<T>::CollateralReserved
             );
             Self::accrue_interest(liquidation_asset_id)?;
-            Self::accrue_interest(collateral_asset_id)?;
-            Self::do_liquidate_borrow(
-                who,
-                borrower,
-                liquidation_asset_id,
-                repay_amount,
-                collateral_asset_id,
-            )?;
+            if AdapterCollaterals::<T>::contains_key(collateral_asset_id) {
+                Self::do_liquidate_adapter_collateral(
+                    who,
+                    borrower.clone(),
+                    liquidation_asset_id,
+                    repay_amount,
+                    collateral_asset_id,
+                )?;
+            } else {
+                Self::accrue_interest(collateral_asset_id)?;
+                Self::do_liquidate_borrow(
+                    who,
+                    borrower.clone(),
+                    liquidation_asset_id,
+                    repay_amount,
+                    collateral_asset_id,
+                )?;
+            }
+            Self::release_class_debts(&borrower);
             Ok(().into())
         }
 
@@ -1114,102 +1430,613 @@ pub mod pallet {
             Self::deposit_event(Event::<T>::LiquidationFreeCollateralsUpdated(collaterals));
             Ok(().into())
         }
-    }
-}
 
-impl<T: Config> Pallet<T> {
-    pub fn account_id() -> T::AccountId {
-        T::PalletId::get().into_account_truncating()
-    }
+        /// Accrues the interest of a market, callable by anyone.
+        ///
+        /// The caller is rewarded from the market reserves if no interest was
+        /// accrued for `KeeperStalePeriod` blocks.
+        ///
+        /// - `asset_id`: the market to accrue.
+        #[pallet::weight(T::WeightInfo::accrue_market_interest())]
+        #[transactional]
+        pub fn accrue_market_interest(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_active_market(asset_id)?;
 
-    fn get_lf_borrowed_value(account: &T::AccountId) -> Result<FixedU128, DispatchError> {
-        let lf_borrowed_amount =
-            Self::current_borrow_balance(account, T::LiquidationFreeAssetId::get())?;
-        Self::get_asset_value(T::LiquidationFreeAssetId::get(), lf_borrowed_amount)
-    }
+            let is_stale = Self::is_stale(Self::last_accrued_interest_block(asset_id));
+            Self::accrue_interest(asset_id)?;
+            if is_stale {
+                Self::pay_keeper_reward(&who, asset_id)?;
+            }
 
-    fn get_lf_base_position(account: &T::AccountId) -> Result<FixedU128, DispatchError> {
-        let mut total_asset_value: FixedU128 = FixedU128::zero();
-        for (asset_id, _market) in Self::active_markets()
-            .filter(|(asset_id, _)| Self::liquidation_free_collaterals().contains(asset_id))
-        {
-            total_asset_value = total_asset_value
-                .checked_add(&Self::collateral_asset_value(account, asset_id)?)
-                .ok_or(ArithmeticError::Overflow)?;
+            Ok(().into())
         }
-        Ok(total_asset_value)
-    }
 
-    fn get_lf_liquidation_base_position(
-        account: &T::AccountId,
-    ) -> Result<FixedU128, DispatchError> {
-        let mut total_asset_value: FixedU128 = FixedU128::zero();
-        for (asset_id, _market) in Self::active_markets()
-            .filter(|(asset_id, _)| Self::liquidation_free_collaterals().contains(asset_id))
-        {
-            total_asset_value = total_asset_value
-                .checked_add(&Self::liquidation_threshold_asset_value(account, asset_id)?)
-                .ok_or(ArithmeticError::Overflow)?;
-        }
-        Ok(total_asset_value)
-    }
+        /// Updates the supply and borrow reward indexes of a market, callable by anyone.
+        ///
+        /// The caller is rewarded from the market reserves if either index was
+        /// not updated for `KeeperStalePeriod` blocks.
+        ///
+        /// - `asset_id`: the market to update.
+        #[pallet::weight(T::WeightInfo::update_market_reward_indexes())]
+        #[transactional]
+        pub fn update_market_reward_indexes(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_active_market(asset_id)?;
 
-    pub fn get_account_liquidity(
-        account: &T::AccountId,
-    ) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
-        let total_borrow_value = Self::total_borrowed_value(account)?;
-        let total_collateral_value = Self::total_collateral_value(account)?;
-        let lf_borrowed_value = Self::get_lf_borrowed_value(account)?;
-        let lf_base_position = Self::get_lf_base_position(account)?;
+            let last_update = Self::reward_supply_state(asset_id)
+                .block
+                .min(Self::reward_borrow_state(asset_id).block);
+            let is_stale = Self::is_stale(last_update);
+            Self::update_reward_supply_index(asset_id)?;
+            Self::update_reward_borrow_index(asset_id)?;
+            if is_stale {
+                Self::pay_keeper_reward(&who, asset_id)?;
+            }
 
-        log::trace!(
-            target: "loans::get_account_liquidity",
-            "account: {:?}, total_borrow_value: {:?}, total_collateral_value: {:?}, lf_borrowed_value: {:?}, lf_base_position: {:?}",
-            account,
-            total_borrow_value.into_inner(),
-            total_collateral_value.into_inner(),
-            lf_borrowed_value.into_inner(),
-            lf_base_position.into_inner(),
-        );
-        match (
-            total_collateral_value > total_borrow_value,
-            lf_base_position > lf_borrowed_value,
-        ) {
-            (true, true) => Ok((
-                total_collateral_value - total_borrow_value,
-                FixedU128::zero(),
-                lf_base_position - lf_borrowed_value,
-                FixedU128::zero(),
-            )),
-            (true, false) => Ok((
-                total_collateral_value - total_borrow_value,
-                FixedU128::zero(),
-                FixedU128::zero(),
-                lf_borrowed_value - lf_base_position,
-            )),
-            (false, true) => Ok((
-                FixedU128::zero(),
-                total_borrow_value - total_collateral_value,
-                lf_base_position - lf_borrowed_value,
-                FixedU128::zero(),
-            )),
-            (false, false) => Ok((
-                FixedU128::zero(),
-                total_borrow_value - total_collateral_value,
-                FixedU128::zero(),
-                lf_borrowed_value - lf_base_position,
-            )),
+            Ok(().into())
         }
-    }
 
-    pub fn get_account_liquidation_threshold_liquidity(
-        account: &T::AccountId,
-    ) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
-        let total_borrow_value = Self::total_borrowed_value(account)?;
-        let total_collateral_value = Self::total_liquidation_threshold_value(account)?;
+        /// Writes off the positions of `accounts` in a market that are worth less
+        /// than `DustThresholdValue` and reaps their storage.
+        ///
+        /// Dust borrows are repaid by `TreasuryAccount`, dust deposits go to the
+        /// market reserves. Larger positions, and collateral an account still
+        /// needs, are left as they are.
+        ///
+        /// - `asset_id`: the market to clean up.
+        /// - `accounts`: the accounts whose dust positions are written off, at most
+        /// `MaxPageSize` of them.
+        #[pallet::weight(T::WeightInfo::write_off_dust_positions(accounts.len() as u32))]
+        #[transactional]
+        pub fn write_off_dust_positions(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            accounts: Vec<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                accounts.len() as u32 <= T::MaxPageSize::get(),
+                Error::<T>::TooManyAccounts
+            );
+            Self::prepare_dust_write_off(asset_id)?;
 
-        let lf_borrowed_value = Self::get_lf_borrowed_value(account)?;
-        let lf_base_position = Self::get_lf_liquidation_base_position(account)?;
+            for account in accounts {
+                Self::write_off_dust_positions_of(&account, asset_id)?;
+            }
+
+            Ok(().into())
+        }
+
+        /// Goes over the next `limit` accounts of a market, capped by `MaxPageSize`,
+        /// and writes off their dust positions the same way `write_off_dust_positions`
+        /// does.
+        ///
+        /// The sweep resumes where the previous call stopped, first over the
+        /// depositors then over the borrowers, and starts over once completed.
+        ///
+        /// - `asset_id`: the market to sweep.
+        /// - `limit`: the number of accounts to go over.
+        #[pallet::weight(T::WeightInfo::sweep_dust_positions((*limit).min(T::MaxPageSize::get())))]
+        #[transactional]
+        pub fn sweep_dust_positions(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::prepare_dust_write_off(asset_id)?;
+
+            let limit = limit.min(T::MaxPageSize::get());
+            let mut swept = 0u32;
+            let mut cursor =
+                Some(DustSweepCursor::<T>::take(asset_id).unwrap_or(SweepCursor::Deposits(None)));
+            while let Some(current) = cursor.take() {
+                if swept >= limit {
+                    cursor = Some(current);
+                    break;
+                }
+                let page_size = limit - swept;
+                let accounts = Self::dust_sweep_page(asset_id, &current, page_size);
+                for account in accounts.iter() {
+                    Self::write_off_dust_positions_of(account, asset_id)?;
+                }
+                swept += accounts.len() as u32;
+
+                let last = accounts.last().cloned();
+                cursor = match current {
+                    SweepCursor::Deposits(_) if (accounts.len() as u32) < page_size => {
+                        Some(SweepCursor::Borrows(None))
+                    }
+                    SweepCursor::Deposits(_) => Some(SweepCursor::Deposits(last)),
+                    SweepCursor::Borrows(_) if (accounts.len() as u32) < page_size => None,
+                    SweepCursor::Borrows(_) => Some(SweepCursor::Borrows(last)),
+                };
+            }
+
+            let completed = cursor.is_none();
+            if let Some(cursor) = cursor {
+                DustSweepCursor::<T>::insert(asset_id, cursor);
+            }
+            Self::deposit_event(Event::<T>::DustPositionsSwept(asset_id, swept, completed));
+
+            Ok(().into())
+        }
+
+        /// Registers or updates an asset kept by `CollateralAdapter` as collateral,
+        /// or removes it once nobody has it deposited.
+        ///
+        /// - `asset_id`: the asset supported by the collateral adapter.
+        /// - `info`: the factors of the collateral, `None` to remove it.
+        #[pallet::weight(T::WeightInfo::set_adapter_collateral())]
+        #[transactional]
+        pub fn set_adapter_collateral(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            info: Option<AdapterCollateralInfo>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            match info {
+                Some(info) => {
+                    ensure!(
+                        T::CollateralAdapter::is_supported(asset_id),
+                        Error::<T>::CollateralNotSupported
+                    );
+                    ensure!(
+                        !Markets::<T>::contains_key(asset_id),
+                        Error::<T>::MarketAlreadyExists
+                    );
+                    ensure!(
+                        info.collateral_factor < Ratio::one()
+                            && info.liquidation_threshold < Ratio::one()
+                            && info.liquidation_threshold >= info.collateral_factor,
+                        Error::<T>::InvalidFactor
+                    );
+                    AdapterCollaterals::<T>::insert(asset_id, info);
+                }
+                None => {
+                    ensure!(
+                        AdapterCollaterals::<T>::contains_key(asset_id),
+                        Error::<T>::AdapterCollateralDoesNotExist
+                    );
+                    ensure!(
+                        AccountAdapterCollaterals::<T>::iter_prefix(asset_id)
+                            .next()
+                            .is_none(),
+                        Error::<T>::AdapterCollateralInUse
+                    );
+                    AdapterCollaterals::<T>::remove(asset_id);
+                }
+            }
+
+            Self::deposit_event(Event::<T>::AdapterCollateralUpdated(asset_id, info));
+            Ok(().into())
+        }
+
+        /// Deposits an asset kept by `CollateralAdapter` as collateral, the adapter
+        /// takes custody of it.
+        ///
+        /// - `asset_id`: the asset to deposit.
+        /// - `amount`: the amount to deposit.
+        #[pallet::weight(T::WeightInfo::deposit_adapter_collateral().saturating_add(T::WeightInfo::refresh_class_debts(1)))]
+        #[transactional]
+        pub fn deposit_adapter_collateral(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Loans)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            ensure!(
+                AdapterCollaterals::<T>::contains_key(asset_id),
+                Error::<T>::AdapterCollateralDoesNotExist
+            );
+
+            T::CollateralAdapter::on_deposit(&who, asset_id, amount)?;
+            AccountAdapterCollaterals::<T>::try_mutate(
+                asset_id,
+                &who,
+                |deposited| -> DispatchResult {
+                    *deposited = deposited
+                        .checked_add(amount)
+                        .ok_or(ArithmeticError::Overflow)?;
+                    Ok(())
+                },
+            )?;
+            Self::rebalance_class_debts(&who, false)?;
+
+            Self::deposit_event(Event::<T>::AdapterCollateralDeposited(
+                who, asset_id, amount,
+            ));
+            Ok(().into())
+        }
+
+        /// Withdraws collateral deposited through `CollateralAdapter`, as long as
+        /// the remaining collateral covers the borrows.
+        ///
+        /// - `asset_id`: the asset to withdraw.
+        /// - `amount`: the amount to withdraw.
+        #[pallet::weight(T::WeightInfo::withdraw_adapter_collateral().saturating_add(T::WeightInfo::refresh_class_debts(1)))]
+        #[transactional]
+        pub fn withdraw_adapter_collateral(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Loans)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            let info = Self::adapter_collateral(asset_id)
+                .ok_or(Error::<T>::AdapterCollateralDoesNotExist)?;
+            let deposited = Self::account_adapter_collateral(asset_id, &who);
+            ensure!(deposited >= amount, Error::<T>::InsufficientDeposit);
+
+            let effects_amount = Self::adapter_collateral_factor(asset_id, &info).mul_ceil(amount);
+            Self::ensure_liquidity(
+                &who,
+                Self::get_adapter_collateral_value(asset_id, effects_amount)?,
+                false,
+            )?;
+            Self::ensure_within_max_ltv(
+                &who,
+                FixedU128::zero(),
+                Self::get_adapter_collateral_value(asset_id, amount)?,
+            )?;
+
+            AccountAdapterCollaterals::<T>::mutate_exists(asset_id, &who, |deposited| {
+                *deposited = deposited
+                    .and_then(|d| d.checked_sub(amount))
+                    .filter(|d| !d.is_zero());
+            });
+            T::CollateralAdapter::on_withdraw(&who, asset_id, amount)?;
+            Self::rebalance_class_debts(&who, true)?;
+
+            Self::deposit_event(Event::<T>::AdapterCollateralWithdrawn(
+                who, asset_id, amount,
+            ));
+            Ok(().into())
+        }
+
+        /// Sets limits on the caller's own position and the health factor they want to
+        /// be alerted below, the default settings remove them.
+        ///
+        /// The max LTV is checked when the caller borrows, redeems collateral or takes
+        /// collateral out, liquidations ignore it.
+        ///
+        /// - `settings`: the max LTV and alert threshold, each optional.
+        #[pallet::weight(T::WeightInfo::set_risk_settings())]
+        #[transactional]
+        pub fn set_risk_settings(
+            origin: OriginFor<T>,
+            settings: RiskSettings,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            if settings == RiskSettings::default() {
+                AccountRiskSettings::<T>::remove(&who);
+            } else {
+                AccountRiskSettings::<T>::insert(&who, settings);
+            }
+
+            Self::deposit_event(Event::<T>::RiskSettingsUpdated(who, settings));
+            Ok(().into())
+        }
+
+        /// Limits how much of an asset the caller can have borrowed, checked when they
+        /// borrow it.
+        ///
+        /// - `asset_id`: the market asset.
+        /// - `limit`: the most the caller may have borrowed, `None` to remove the limit.
+        #[pallet::weight(T::WeightInfo::set_borrow_limit())]
+        #[transactional]
+        pub fn set_borrow_limit(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            limit: Option<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_active_market(asset_id)?;
+            AccountBorrowLimits::<T>::set(asset_id, &who, limit);
+
+            Self::deposit_event(Event::<T>::BorrowLimitUpdated(who, asset_id, limit));
+            Ok(().into())
+        }
+
+        /// Puts an asset in a collateral class, so its collateral counts towards the
+        /// debt ceiling of the class. Debt already backed by the asset moves along
+        /// as its borrowers act on their positions, `refresh_class_debts` moves it
+        /// for the others.
+        ///
+        /// - `asset_id`: the market or adapter collateral asset.
+        /// - `class`: the class of the asset, `None` to take it out of its class.
+        #[pallet::weight(T::WeightInfo::set_collateral_class())]
+        #[transactional]
+        pub fn set_collateral_class(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            class: Option<CollateralClass>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            CollateralClasses::<T>::set(asset_id, class);
+
+            Self::deposit_event(Event::<T>::CollateralClassUpdated(asset_id, class));
+            Ok(().into())
+        }
+
+        /// Sets the most debt the collateral of a class may back across all markets,
+        /// valued like `DustThresholdValue`. Borrows are checked against it, a
+        /// ceiling lowered under the current debt only keeps it from growing.
+        ///
+        /// - `class`: the collateral class.
+        /// - `ceiling`: the debt ceiling of the class, `None` to remove it.
+        #[pallet::weight(T::WeightInfo::set_debt_ceiling())]
+        #[transactional]
+        pub fn set_debt_ceiling(
+            origin: OriginFor<T>,
+            class: CollateralClass,
+            ceiling: Option<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            DebtCeilings::<T>::set(class, ceiling);
+
+            Self::deposit_event(Event::<T>::DebtCeilingUpdated(class, ceiling));
+            Ok(().into())
+        }
+
+        /// Recomputes the debt `accounts` have backed by each collateral class at the
+        /// current prices and borrow balances.
+        ///
+        /// Class debts are only moved when their borrowers act on their positions, this
+        /// lets keepers backfill the debt of an asset put in a class and catch up with
+        /// price moves and accrued interest. Ceilings aren't enforced, a class over its
+        /// ceiling just can't back more debt.
+        ///
+        /// - `accounts`: the accounts to refresh, at most `MaxPageSize` of them.
+        #[pallet::weight(T::WeightInfo::refresh_class_debts(accounts.len() as u32))]
+        #[transactional]
+        pub fn refresh_class_debts(
+            origin: OriginFor<T>,
+            accounts: Vec<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            ensure!(
+                accounts.len() as u32 <= T::MaxPageSize::get(),
+                Error::<T>::TooManyAccounts
+            );
+
+            for account in accounts {
+                Self::update_class_debts(&account, false)?;
+                Self::deposit_event(Event::<T>::ClassDebtsRefreshed(account));
+            }
+
+            Ok(().into())
+        }
+
+        /// Proposes a new market. It can be activated with `activate_market` once
+        /// `MarketReviewPeriod` is over, unless `MarketVetoOrigin` vetoes it before.
+        ///
+        /// The market is checked like with `add_market`, and again when it is activated.
+        ///
+        /// - `asset_id`: Market related currency
+        /// - `market`: The market that is going to be listed
+        #[pallet::weight(T::WeightInfo::propose_market())]
+        #[transactional]
+        pub fn propose_market(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            market: Market<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                !MarketProposals::<T>::contains_key(asset_id),
+                Error::<T>::MarketProposalAlreadyExists
+            );
+            Self::ensure_new_market(asset_id, &market)?;
+
+            let activatable_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::MarketReviewPeriod::get());
+            MarketProposals::<T>::insert(
+                asset_id,
+                MarketProposal {
+                    market: market.clone(),
+                    activatable_at,
+                },
+            );
+            Self::deposit_event(Event::<T>::MarketProposed(asset_id, market, activatable_at));
+            Ok(().into())
+        }
+
+        /// Vetoes a proposed market before it is activated.
+        ///
+        /// - `asset_id`: Market related currency
+        #[pallet::weight(T::WeightInfo::veto_market())]
+        #[transactional]
+        pub fn veto_market(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::MarketVetoOrigin::ensure_origin(origin)?;
+            MarketProposals::<T>::take(asset_id).ok_or(Error::<T>::MarketProposalDoesNotExist)?;
+            Self::deposit_event(Event::<T>::MarketVetoed(asset_id));
+            Ok(().into())
+        }
+
+        /// Moves a market to a new rate model over `period` blocks, its borrow rate
+        /// goes linearly from the one of the current model to the one of the new model
+        /// instead of jumping. `update_rate_model` switches at once, cancelling the
+        /// transition.
+        ///
+        /// - `asset_id`: market related currency
+        /// - `rate_model`: the rate model to move to
+        /// - `period`: blocks the transition lasts
+        #[pallet::weight(T::WeightInfo::transition_rate_model())]
+        #[transactional]
+        pub fn transition_rate_model(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            rate_model: InterestRateModel,
+            #[pallet::compact] period: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(rate_model.check_model(), Error::<T>::InvalidRateModelParam);
+            ensure!(!period.is_zero(), Error::<T>::InvalidTransitionPeriod);
+            ensure!(
+                !RateModelTransitions::<T>::contains_key(asset_id),
+                Error::<T>::RateModelTransitionInProgress
+            );
+            // Interest up to now is owed at the rate of the current model
+            Self::accrue_interest(asset_id)?;
+
+            let from = Self::market(asset_id)?.rate_model;
+            Self::mutate_market(asset_id, |stored_market| {
+                stored_market.rate_model = rate_model;
+                stored_market.clone()
+            })?;
+            let start = frame_system::Pallet::<T>::block_number();
+            let end = start.saturating_add(period);
+            RateModelTransitions::<T>::insert(asset_id, RateModelTransition { from, start, end });
+
+            Self::deposit_event(Event::<T>::RateModelTransitionStarted(
+                asset_id, from, rate_model, end,
+            ));
+            Ok(().into())
+        }
+    }
+}
+
+#[cfg(any(feature = "try-runtime", test))]
+impl<T: Config> Pallet<T> {
+    /// Check that the ptokens of each market are backed by its cash and borrows, net of
+    /// its reserves
+    pub fn do_try_state() -> Result<(), &'static str> {
+        for asset_id in Markets::<T>::iter_keys() {
+            let voucher_balances = AccountDeposits::<T>::iter_prefix_values(asset_id)
+                .fold(BalanceOf::<T>::zero(), |total, deposits| {
+                    total.saturating_add(deposits.voucher_balance)
+                });
+            ensure!(
+                voucher_balances == Self::total_supply(asset_id),
+                "deposits must sum up to the total supply"
+            );
+
+            let cash = Self::get_total_cash(asset_id);
+            ensure!(
+                cash <= T::Assets::total_issuance(asset_id),
+                "cash can't exceed the total issuance"
+            );
+            ensure!(
+                Self::total_reserves(asset_id)
+                    <= cash.saturating_add(Self::total_borrows(asset_id)),
+                "reserves must be backed by cash and borrows"
+            );
+            ensure!(
+                Self::exchange_rate_stored(asset_id).is_ok(),
+                "ptokens must be redeemable at a valid exchange rate"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    fn get_lf_borrowed_value(account: &T::AccountId) -> Result<FixedU128, DispatchError> {
+        let lf_borrowed_amount =
+            Self::current_borrow_balance(account, T::LiquidationFreeAssetId::get())?;
+        Self::get_asset_value(T::LiquidationFreeAssetId::get(), lf_borrowed_amount)
+    }
+
+    fn get_lf_base_position(account: &T::AccountId) -> Result<FixedU128, DispatchError> {
+        let mut total_asset_value: FixedU128 = FixedU128::zero();
+        for (asset_id, _market) in Self::active_markets()
+            .filter(|(asset_id, _)| Self::liquidation_free_collaterals().contains(asset_id))
+        {
+            total_asset_value = total_asset_value
+                .checked_add(&Self::collateral_asset_value(account, asset_id)?)
+                .ok_or(ArithmeticError::Overflow)?;
+        }
+        Ok(total_asset_value)
+    }
+
+    fn get_lf_liquidation_base_position(
+        account: &T::AccountId,
+    ) -> Result<FixedU128, DispatchError> {
+        let mut total_asset_value: FixedU128 = FixedU128::zero();
+        for (asset_id, _market) in Self::active_markets()
+            .filter(|(asset_id, _)| Self::liquidation_free_collaterals().contains(asset_id))
+        {
+            total_asset_value = total_asset_value
+                .checked_add(&Self::liquidation_threshold_asset_value(account, asset_id)?)
+                .ok_or(ArithmeticError::Overflow)?;
+        }
+        Ok(total_asset_value)
+    }
+
+    pub fn get_account_liquidity(
+        account: &T::AccountId,
+    ) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
+        let total_borrow_value = Self::total_borrowed_value(account)?;
+        let total_collateral_value = Self::total_collateral_value(account)?;
+        let lf_borrowed_value = Self::get_lf_borrowed_value(account)?;
+        let lf_base_position = Self::get_lf_base_position(account)?;
+
+        log::trace!(
+            target: "loans::get_account_liquidity",
+            "account: {:?}, total_borrow_value: {:?}, total_collateral_value: {:?}, lf_borrowed_value: {:?}, lf_base_position: {:?}",
+            account,
+            total_borrow_value.into_inner(),
+            total_collateral_value.into_inner(),
+            lf_borrowed_value.into_inner(),
+            lf_base_position.into_inner(),
+        );
+        match (
+            total_collateral_value > total_borrow_value,
+            lf_base_position > lf_borrowed_value,
+        ) {
+            (true, true) => Ok((
+                total_collateral_value - total_borrow_value,
+                FixedU128::zero(),
+                lf_base_position - lf_borrowed_value,
+                FixedU128::zero(),
+            )),
+            (true, false) => Ok((
+                total_collateral_value - total_borrow_value,
+                FixedU128::zero(),
+                FixedU128::zero(),
+                lf_borrowed_value - lf_base_position,
+            )),
+            (false, true) => Ok((
+                FixedU128::zero(),
+                total_borrow_value - total_collateral_value,
+                lf_base_position - lf_borrowed_value,
+                FixedU128::zero(),
+            )),
+            (false, false) => Ok((
+                FixedU128::zero(),
+                total_borrow_value - total_collateral_value,
+                FixedU128::zero(),
+                lf_borrowed_value - lf_base_position,
+            )),
+        }
+    }
+
+    pub fn get_account_liquidation_threshold_liquidity(
+        account: &T::AccountId,
+    ) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
+        let total_borrow_value = Self::total_borrowed_value(account)?;
+        let total_collateral_value = Self::total_liquidation_threshold_value(account)?;
+
+        let lf_borrowed_value = Self::get_lf_borrowed_value(account)?;
+        let lf_base_position = Self::get_lf_liquidation_base_position(account)?;
 
         log::trace!(
             target: "loans::get_account_liquidation_threshold_liquidity",
@@ -1252,6 +2079,137 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Borrowers whose health factor, their collateral value at the liquidation threshold over
+    /// their borrowed value, is below `max_health_factor`
+    ///
+    /// Goes over every borrower, only for the runtime API.
+    pub fn get_health_factors(
+        max_health_factor: FixedU128,
+    ) -> Result<Vec<(T::AccountId, FixedU128)>, DispatchError> {
+        let borrowers: BTreeSet<T::AccountId> = AccountBorrows::<T>::iter_keys()
+            .map(|(_, borrower)| borrower)
+            .collect();
+
+        let mut health_factors = Vec::new();
+        for borrower in borrowers {
+            match Self::health_factor(&borrower)? {
+                Some(health_factor) if health_factor < max_health_factor => {
+                    health_factors.push((borrower, health_factor))
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(health_factors)
+    }
+
+    /// Borrowers who set an alert threshold, with their health factor and their threshold
+    ///
+    /// Goes over every borrower, only for the runtime API.
+    pub fn get_health_alerts() -> Result<Vec<(T::AccountId, FixedU128, FixedU128)>, DispatchError> {
+        let mut alerts = Vec::new();
+        for (borrower, settings) in AccountRiskSettings::<T>::iter() {
+            let alert_threshold = match settings.alert_threshold {
+                Some(alert_threshold) => alert_threshold,
+                None => continue,
+            };
+            if let Some(health_factor) = Self::health_factor(&borrower)? {
+                alerts.push((borrower, health_factor, alert_threshold));
+            }
+        }
+
+        Ok(alerts)
+    }
+
+    /// Collateral value of `borrower` at the liquidation threshold over its borrowed value,
+    /// `None` if it has no borrows
+    fn health_factor(borrower: &T::AccountId) -> Result<Option<FixedU128>, DispatchError> {
+        let total_borrow_value = Self::total_borrowed_value(borrower)?;
+        if total_borrow_value.is_zero() {
+            return Ok(None);
+        }
+        let health_factor = Self::total_liquidation_threshold_value(borrower)?
+            .checked_div(&total_borrow_value)
+            .ok_or(ArithmeticError::Underflow)?;
+        Ok(Some(health_factor))
+    }
+
+    /// Snapshot the rates of the active markets at `now`, returns the number of markets
+    fn snapshot_rates(now: Timestamp) -> u64 {
+        LastRateSnapshotTime::<T>::put(now);
+        let max_snapshots = T::MaxRateSnapshots::get() as usize;
+        if max_snapshots.is_zero() {
+            return 0;
+        }
+
+        let mut markets = 0;
+        for (asset_id, _) in Self::active_markets() {
+            markets += 1;
+            let (borrow_rate, supply_rate, exchange_rate, utilization, ..) =
+                match Self::get_market_status(asset_id) {
+                    Ok(status) => status,
+                    Err(_) => continue,
+                };
+            RateHistory::<T>::mutate(asset_id, |history| {
+                if history.len() >= max_snapshots {
+                    history.remove(0);
+                }
+                let _ = history.try_push(RateSnapshot {
+                    timestamp: now,
+                    utilization,
+                    borrow_rate,
+                    supply_rate,
+                    exchange_rate,
+                });
+            });
+        }
+        markets
+    }
+
+    /// Rate snapshots of a market, oldest first, as
+    /// `(timestamp, utilization, borrow_rate, supply_rate, exchange_rate)`
+    pub fn get_rate_history(asset_id: AssetIdOf<T>) -> Vec<(Timestamp, Ratio, Rate, Rate, Rate)> {
+        Self::rate_history(asset_id)
+            .into_iter()
+            .map(|snapshot| {
+                (
+                    snapshot.timestamp,
+                    snapshot.utilization,
+                    snapshot.borrow_rate,
+                    snapshot.supply_rate,
+                    snapshot.exchange_rate,
+                )
+            })
+            .collect()
+    }
+
+    /// Supplied and borrowed amounts of `who` in each market, as
+    /// `(asset_id, supplied, borrowed, is_collateral)` where the supply is measured
+    /// in the underlying asset at the stored exchange rate
+    pub fn account_positions(
+        who: &T::AccountId,
+    ) -> Result<Vec<(AssetIdOf<T>, BalanceOf<T>, BalanceOf<T>, bool)>, DispatchError> {
+        let mut positions = Vec::new();
+        for asset_id in Markets::<T>::iter_keys() {
+            let deposits = Self::account_deposits(asset_id, who);
+            let supplied = if deposits.voucher_balance.is_zero() {
+                Zero::zero()
+            } else {
+                Self::calc_underlying_amount(
+                    deposits.voucher_balance,
+                    Self::exchange_rate_stored(asset_id)?,
+                )?
+            };
+            let borrowed = Self::current_borrow_balance(who, asset_id)?;
+            if supplied.is_zero() && borrowed.is_zero() {
+                continue;
+            }
+            positions.push((asset_id, supplied, borrowed, deposits.is_collateral));
+        }
+
+        Ok(positions)
+    }
+
     fn total_borrowed_value(borrower: &T::AccountId) -> Result<FixedU128, DispatchError> {
         let mut total_borrow_value: FixedU128 = FixedU128::zero();
         for (asset_id, _) in Self::active_markets() {
@@ -1316,31 +2274,109 @@ impl<T: Config> Pallet<T> {
         let exchange_rate = Self::exchange_rate_stored(asset_id)?;
         let underlying_amount =
             Self::calc_underlying_amount(deposits.voucher_balance, exchange_rate)?;
-        let market = Self::market(asset_id)?;
-        let effects_amount = market.liquidation_threshold.mul_ceil(underlying_amount);
+        let market = Self::market(asset_id)?;
+        let effects_amount = market.liquidation_threshold.mul_ceil(underlying_amount);
+
+        Self::get_asset_value(asset_id, effects_amount)
+    }
+
+    fn total_collateral_value(supplier: &T::AccountId) -> Result<FixedU128, DispatchError> {
+        let mut total_asset_value: FixedU128 = FixedU128::zero();
+        for (asset_id, _market) in Self::active_markets() {
+            total_asset_value = total_asset_value
+                .checked_add(&Self::collateral_asset_value(supplier, asset_id)?)
+                .ok_or(ArithmeticError::Overflow)?;
+        }
+        total_asset_value = total_asset_value
+            .checked_add(&Self::total_adapter_collateral_value(
+                supplier,
+                Self::adapter_collateral_factor,
+            )?)
+            .ok_or(ArithmeticError::Overflow)?;
+
+        Ok(total_asset_value)
+    }
+
+    fn total_liquidation_threshold_value(
+        borrower: &T::AccountId,
+    ) -> Result<FixedU128, DispatchError> {
+        let mut total_asset_value: FixedU128 = FixedU128::zero();
+        for (asset_id, _market) in Self::active_markets() {
+            total_asset_value = total_asset_value
+                .checked_add(&Self::liquidation_threshold_asset_value(
+                    borrower, asset_id,
+                )?)
+                .ok_or(ArithmeticError::Overflow)?;
+        }
+        total_asset_value = total_asset_value
+            .checked_add(&Self::total_adapter_collateral_value(
+                borrower,
+                |_, info| info.liquidation_threshold,
+            )?)
+            .ok_or(ArithmeticError::Overflow)?;
+
+        Ok(total_asset_value)
+    }
+
+    /// Value of the collateral `account` has in a market, not discounted by its
+    /// collateral factor
+    fn collateral_underlying_value(
+        account: &T::AccountId,
+        asset_id: AssetIdOf<T>,
+    ) -> Result<FixedU128, DispatchError> {
+        let deposits = Self::account_deposits(asset_id, account);
+        if !deposits.is_collateral || deposits.voucher_balance.is_zero() {
+            return Ok(FixedU128::zero());
+        }
+        let exchange_rate = Self::exchange_rate_stored(asset_id)?;
+        let underlying_amount =
+            Self::calc_underlying_amount(deposits.voucher_balance, exchange_rate)?;
 
-        Self::get_asset_value(asset_id, effects_amount)
+        Self::get_asset_value(asset_id, underlying_amount)
     }
 
-    fn total_collateral_value(supplier: &T::AccountId) -> Result<FixedU128, DispatchError> {
+    fn total_collateral_underlying_value(
+        account: &T::AccountId,
+    ) -> Result<FixedU128, DispatchError> {
         let mut total_asset_value: FixedU128 = FixedU128::zero();
         for (asset_id, _market) in Self::active_markets() {
             total_asset_value = total_asset_value
-                .checked_add(&Self::collateral_asset_value(supplier, asset_id)?)
+                .checked_add(&Self::collateral_underlying_value(account, asset_id)?)
                 .ok_or(ArithmeticError::Overflow)?;
         }
+        total_asset_value = total_asset_value
+            .checked_add(&Self::total_adapter_collateral_value(account, |_, _| {
+                Ratio::one()
+            })?)
+            .ok_or(ArithmeticError::Overflow)?;
 
         Ok(total_asset_value)
     }
 
-    fn total_liquidation_threshold_value(
-        borrower: &T::AccountId,
+    // Collateral factor of an asset kept by `CollateralAdapter`, which the adapter may
+    // lower from the registered one
+    fn adapter_collateral_factor(asset_id: AssetIdOf<T>, info: &AdapterCollateralInfo) -> Ratio {
+        T::CollateralAdapter::collateral_factor(asset_id, info.collateral_factor)
+            .min(info.collateral_factor)
+    }
+
+    // Value of the collateral `account` deposited through `CollateralAdapter`, each
+    // asset is discounted by the ratio `factor` picks out of its info
+    fn total_adapter_collateral_value(
+        account: &T::AccountId,
+        factor: impl Fn(AssetIdOf<T>, &AdapterCollateralInfo) -> Ratio,
     ) -> Result<FixedU128, DispatchError> {
         let mut total_asset_value: FixedU128 = FixedU128::zero();
-        for (asset_id, _market) in Self::active_markets() {
+        for (asset_id, info) in AdapterCollaterals::<T>::iter() {
+            let deposited = Self::account_adapter_collateral(asset_id, account);
+            if deposited.is_zero() {
+                continue;
+            }
+            let effects_amount = factor(asset_id, &info).mul_ceil(deposited);
             total_asset_value = total_asset_value
-                .checked_add(&Self::liquidation_threshold_asset_value(
-                    borrower, asset_id,
+                .checked_add(&Self::get_adapter_collateral_value(
+                    asset_id,
+                    effects_amount,
                 )?)
                 .ok_or(ArithmeticError::Overflow)?;
         }
@@ -1389,6 +2425,11 @@ impl<T: Config> Pallet<T> {
             redeem_effects_value,
             Self::liquidation_free_collaterals().contains(&asset_id),
         )?;
+        Self::ensure_within_max_ltv(
+            redeemer,
+            FixedU128::zero(),
+            Self::get_asset_value(asset_id, redeem_amount)?,
+        )?;
 
         Ok(())
     }
@@ -1405,6 +2446,7 @@ impl<T: Config> Pallet<T> {
 
         let exchange_rate = Self::exchange_rate_stored(asset_id)?;
         let redeem_amount = Self::calc_underlying_amount(voucher_amount, exchange_rate)?;
+        let is_collateral = Self::account_deposits(asset_id, who).is_collateral;
 
         AccountDeposits::<T>::try_mutate_exists(asset_id, who, |deposits| -> DispatchResult {
             let mut d = deposits.unwrap_or_default();
@@ -1430,6 +2472,9 @@ impl<T: Config> Pallet<T> {
 
         T::Assets::transfer(asset_id, &Self::account_id(), who, redeem_amount, false)
             .map_err(|_| Error::<T>::InsufficientCash)?;
+        if is_collateral {
+            Self::rebalance_class_debts(who, true)?;
+        }
         Ok(redeem_amount)
     }
 
@@ -1441,12 +2486,14 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         Self::ensure_under_borrow_cap(asset_id, borrow_amount)?;
         Self::ensure_enough_cash(asset_id, borrow_amount)?;
+        Self::ensure_within_borrow_limit(asset_id, borrower, borrow_amount)?;
         let borrow_value = Self::get_asset_value(asset_id, borrow_amount)?;
         Self::ensure_liquidity(
             borrower,
             borrow_value,
             asset_id == T::LiquidationFreeAssetId::get(),
         )?;
+        Self::ensure_within_max_ltv(borrower, borrow_value, FixedU128::zero())?;
 
         Ok(())
     }
@@ -1562,6 +2609,12 @@ impl<T: Config> Pallet<T> {
             return Err(Error::<T>::InsufficientShortfall.into());
         }
 
+        // Dust is written off rather than liquidated, so that liquidations can't be
+        // used to flood the operational block space
+        if Self::is_dust(liquidation_asset_id, repay_amount)? {
+            return Err(Error::<T>::LiquidationTooSmall.into());
+        }
+
         // The liquidator may not repay more than 50%(close_factor) of the borrower's borrow balance.
         let account_borrows = Self::current_borrow_balance(borrower, liquidation_asset_id)?;
         let account_borrows_value = Self::get_asset_value(liquidation_asset_id, account_borrows)?;
@@ -1685,101 +2738,486 @@ impl<T: Config> Pallet<T> {
             collateral_underlying_amount
         );
 
-        // update borrow index after accrue interest.
-        Self::update_reward_borrow_index(liquidation_asset_id)?;
-        Self::distribute_borrower_reward(liquidation_asset_id, liquidator)?;
+        Self::repay_liquidated_borrow(liquidator, borrower, liquidation_asset_id, repay_amount)?;
+
+        // update supply index before modify supply balance.
+        Self::update_reward_supply_index(collateral_asset_id)?;
+        Self::distribute_supplier_reward(collateral_asset_id, liquidator)?;
+        Self::distribute_supplier_reward(collateral_asset_id, borrower)?;
+        Self::distribute_supplier_reward(
+            collateral_asset_id,
+            &Self::incentive_reward_account_id()?,
+        )?;
+
+        // 3.the liquidator will receive voucher token from borrower
+        let exchange_rate = Self::exchange_rate_stored(collateral_asset_id)?;
+        let collateral_amount =
+            Self::calc_collateral_amount(collateral_underlying_amount, exchange_rate)?;
+        AccountDeposits::<T>::try_mutate(
+            collateral_asset_id,
+            borrower,
+            |deposits| -> DispatchResult {
+                deposits.voucher_balance = deposits
+                    .voucher_balance
+                    .checked_sub(collateral_amount)
+                    .ok_or(ArithmeticError::Underflow)?;
+                Ok(())
+            },
+        )?;
+        let incentive_reserved_amount = market.liquidate_incentive_reserved_factor.mul_floor(
+            FixedU128::from_inner(collateral_amount)
+                .checked_div(&market.liquidate_incentive)
+                .map(|r| r.into_inner())
+                .ok_or(ArithmeticError::Underflow)?,
+        );
+        // pay out LP tokens as the pooled assets, otherwise increase liquidator's voucher_balance
+        let seized_amount = collateral_amount - incentive_reserved_amount;
+        if !Self::unwind_seized_collateral(
+            liquidator,
+            collateral_asset_id,
+            seized_amount,
+            exchange_rate,
+        )? {
+            AccountDeposits::<T>::try_mutate(
+                collateral_asset_id,
+                liquidator,
+                |deposits| -> DispatchResult {
+                    deposits.voucher_balance = deposits
+                        .voucher_balance
+                        .checked_add(seized_amount)
+                        .ok_or(ArithmeticError::Overflow)?;
+                    Ok(())
+                },
+            )?;
+        }
+        // increase reserve's voucher_balance
+        AccountDeposits::<T>::try_mutate(
+            collateral_asset_id,
+            Self::incentive_reward_account_id()?,
+            |deposits| -> DispatchResult {
+                deposits.voucher_balance = deposits
+                    .voucher_balance
+                    .checked_add(incentive_reserved_amount)
+                    .ok_or(ArithmeticError::Overflow)?;
+                Ok(())
+            },
+        )?;
+
+        T::EventMirror::mirror(MirroredEvent::LiquidatedBorrow {
+            liquidator: liquidator.clone(),
+            borrower: borrower.clone(),
+            liquidation_asset_id,
+            collateral_asset_id,
+            repay_amount,
+            collateral_amount: collateral_underlying_amount,
+        });
+        Self::deposit_event(Event::<T>::LiquidatedBorrow(
+            liquidator.clone(),
+            borrower.clone(),
+            liquidation_asset_id,
+            collateral_asset_id,
+            repay_amount,
+            collateral_underlying_amount,
+        ));
+
+        Ok(())
+    }
+
+    #[require_transactional]
+    fn repay_liquidated_borrow(
+        liquidator: &T::AccountId,
+        borrower: &T::AccountId,
+        liquidation_asset_id: AssetIdOf<T>,
+        repay_amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        // update borrow index after accrue interest.
+        Self::update_reward_borrow_index(liquidation_asset_id)?;
+        Self::distribute_borrower_reward(liquidation_asset_id, liquidator)?;
+
+        // 1.liquidator repay borrower's debt,
+        // transfer from liquidator to module account
+        T::Assets::transfer(
+            liquidation_asset_id,
+            liquidator,
+            &Self::account_id(),
+            repay_amount,
+            false,
+        )?;
+
+        // 2.the system reduce borrower's debt
+        let account_borrows = Self::current_borrow_balance(borrower, liquidation_asset_id)?;
+        let account_borrows_new = account_borrows
+            .checked_sub(repay_amount)
+            .ok_or(ArithmeticError::Underflow)?;
+        let total_borrows = Self::total_borrows(liquidation_asset_id);
+        let total_borrows_new = total_borrows
+            .checked_sub(repay_amount)
+            .ok_or(ArithmeticError::Underflow)?;
+        AccountBorrows::<T>::insert(
+            liquidation_asset_id,
+            borrower,
+            BorrowSnapshot {
+                principal: account_borrows_new,
+                borrow_index: Self::borrow_index(liquidation_asset_id),
+            },
+        );
+        TotalBorrows::<T>::insert(liquidation_asset_id, total_borrows_new);
+
+        Ok(())
+    }
+
+    /// Liquidates a borrower whose seized collateral is kept by `CollateralAdapter`.
+    ///
+    /// The collateral is handed over by the adapter instead of crediting vouchers, the
+    /// reserved part of the incentive goes to the treasury as there are no vouchers
+    /// to reserve.
+    #[require_transactional]
+    pub fn do_liquidate_adapter_collateral(
+        liquidator: T::AccountId,
+        borrower: T::AccountId,
+        liquidation_asset_id: AssetIdOf<T>,
+        repay_amount: BalanceOf<T>,
+        collateral_asset_id: AssetIdOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_active_market(liquidation_asset_id)?;
+        let market = Self::market(liquidation_asset_id)?;
+
+        if borrower == liquidator {
+            return Err(Error::<T>::LiquidatorIsBorrower.into());
+        }
+        Self::liquidate_borrow_allowed(&borrower, liquidation_asset_id, repay_amount, &market)?;
+
+        let deposited = Self::account_adapter_collateral(collateral_asset_id, &borrower);
+        let collateral_value = Self::get_adapter_collateral_value(collateral_asset_id, deposited)?;
+        let liquidate_value = Self::get_asset_value(liquidation_asset_id, repay_amount)?
+            .checked_mul(&market.liquidate_incentive)
+            .ok_or(ArithmeticError::Overflow)?;
+        if collateral_value < liquidate_value {
+            return Err(Error::<T>::InsufficientCollateral.into());
+        }
+
+        // Same as `do_liquidate_borrow`, the amount is the value over the price
+        let collateral_amount = liquidate_value
+            .checked_div(&T::CollateralAdapter::price(collateral_asset_id)?)
+            .ok_or(ArithmeticError::Underflow)?
+            .into_inner();
+
+        Self::repay_liquidated_borrow(&liquidator, &borrower, liquidation_asset_id, repay_amount)?;
+
+        AccountAdapterCollaterals::<T>::try_mutate_exists(
+            collateral_asset_id,
+            &borrower,
+            |deposited| -> DispatchResult {
+                let remaining = deposited
+                    .unwrap_or_default()
+                    .checked_sub(collateral_amount)
+                    .ok_or(ArithmeticError::Underflow)?;
+                *deposited = Some(remaining).filter(|r| !r.is_zero());
+                Ok(())
+            },
+        )?;
+        let incentive_reserved_amount = market.liquidate_incentive_reserved_factor.mul_floor(
+            FixedU128::from_inner(collateral_amount)
+                .checked_div(&market.liquidate_incentive)
+                .map(|r| r.into_inner())
+                .ok_or(ArithmeticError::Underflow)?,
+        );
+        T::CollateralAdapter::seize(
+            &borrower,
+            &liquidator,
+            collateral_asset_id,
+            collateral_amount - incentive_reserved_amount,
+        )?;
+        if !incentive_reserved_amount.is_zero() {
+            T::CollateralAdapter::seize(
+                &borrower,
+                &T::TreasuryAccount::get(),
+                collateral_asset_id,
+                incentive_reserved_amount,
+            )?;
+        }
+
+        T::EventMirror::mirror(MirroredEvent::LiquidatedBorrow {
+            liquidator: liquidator.clone(),
+            borrower: borrower.clone(),
+            liquidation_asset_id,
+            collateral_asset_id,
+            repay_amount,
+            collateral_amount,
+        });
+        Self::deposit_event(Event::<T>::LiquidatedBorrow(
+            liquidator,
+            borrower,
+            liquidation_asset_id,
+            collateral_asset_id,
+            repay_amount,
+            collateral_amount,
+        ));
+
+        Ok(())
+    }
+
+    // Redeems the vouchers of LP token collateral seized by `liquidator` and unwinds the
+    // LP tokens into the pooled assets, which unlike the LP tokens can be used to cover the
+    // repaid debt. Returns `false` when the collateral isn't an LP token or can't be
+    // unwound, e.g. the market lacks the cash, so the vouchers are credited instead.
+    fn unwind_seized_collateral(
+        liquidator: &T::AccountId,
+        asset_id: AssetIdOf<T>,
+        voucher_amount: BalanceOf<T>,
+        exchange_rate: Rate,
+    ) -> Result<bool, DispatchError> {
+        let (base_asset, quote_asset) = match T::LiquidityUnwinder::pool_assets(asset_id) {
+            Some(pool_assets) => pool_assets,
+            None => return Ok(false),
+        };
+        let amount = Self::calc_underlying_amount(voucher_amount, exchange_rate)?;
+
+        let res = with_transaction(|| {
+            match Self::do_unwind_seized_collateral(liquidator, asset_id, voucher_amount, amount) {
+                Ok(amounts) => TransactionOutcome::Commit(Ok(amounts)),
+                Err(err) => TransactionOutcome::Rollback(Err(err)),
+            }
+        });
+        let (base_amount, quote_amount) = match res {
+            Ok(amounts) => amounts,
+            Err(err) => {
+                log::debug!(
+                    target: "loans::unwind_seized_collateral",
+                    "keeping vouchers of {:?} for {:?}: {:?}",
+                    asset_id,
+                    liquidator,
+                    err
+                );
+                return Ok(false);
+            }
+        };
+
+        Self::deposit_event(Event::<T>::LiquidationUnwound(
+            liquidator.clone(),
+            asset_id,
+            amount,
+            base_asset,
+            base_amount,
+            quote_asset,
+            quote_amount,
+        ));
+
+        Ok(true)
+    }
+
+    #[require_transactional]
+    fn do_unwind_seized_collateral(
+        liquidator: &T::AccountId,
+        asset_id: AssetIdOf<T>,
+        voucher_amount: BalanceOf<T>,
+        amount: BalanceOf<T>,
+    ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        Self::ensure_enough_cash(asset_id, amount)?;
+        TotalSupply::<T>::try_mutate(asset_id, |total_balance| -> DispatchResult {
+            *total_balance = total_balance
+                .checked_sub(voucher_amount)
+                .ok_or(ArithmeticError::Underflow)?;
+            Ok(())
+        })?;
+        T::Assets::transfer(asset_id, &Self::account_id(), liquidator, amount, false)?;
+        T::LiquidityUnwinder::unwind(liquidator, asset_id, amount)
+    }
+
+    // Whether something last run at `last_block` has gone `KeeperStalePeriod`
+    // blocks without running.
+    fn is_stale(last_block: T::BlockNumber) -> bool {
+        let now = <frame_system::Pallet<T>>::block_number();
+        now.saturating_sub(last_block) >= T::KeeperStalePeriod::get()
+    }
+
+    // Pays `KeeperRewardValue` worth of `asset_id` to the keeper out of the market
+    // reserves, capped by what the reserves hold. Nothing is paid while the
+    // price is unavailable so that the refresh itself still goes through.
+    #[require_transactional]
+    fn pay_keeper_reward(keeper: &T::AccountId, asset_id: AssetIdOf<T>) -> DispatchResult {
+        let reward_value = T::KeeperRewardValue::get();
+        if reward_value.is_zero() {
+            return Ok(());
+        }
+        let price = match Self::get_price(asset_id) {
+            Ok(price) => price,
+            Err(_) => return Ok(()),
+        };
+        let total_reserves = Self::total_reserves(asset_id);
+        let reward = FixedU128::from_inner(reward_value)
+            .checked_div(&price)
+            .ok_or(ArithmeticError::Underflow)?
+            .into_inner()
+            .min(total_reserves);
+        if reward.is_zero() {
+            return Ok(());
+        }
+
+        let total_reserves_new = total_reserves
+            .checked_sub(reward)
+            .ok_or(ArithmeticError::Underflow)?;
+        TotalReserves::<T>::insert(asset_id, total_reserves_new);
+        T::Assets::transfer(asset_id, &Self::account_id(), keeper, reward, false)?;
+
+        Self::deposit_event(Event::<T>::KeeperRewarded(
+            keeper.clone(),
+            asset_id,
+            reward,
+            total_reserves_new,
+        ));
+
+        Ok(())
+    }
+
+    // Brings the interest and reward indexes of a market up to date before
+    // writing off its dust positions.
+    fn prepare_dust_write_off(asset_id: AssetIdOf<T>) -> DispatchResult {
+        Self::market(asset_id)?;
+        ensure!(
+            !T::DustThresholdValue::get().is_zero(),
+            Error::<T>::DustWriteOffDisabled
+        );
+
+        Self::accrue_interest(asset_id)?;
+        Self::update_reward_supply_index(asset_id)?;
+        Self::update_reward_borrow_index(asset_id)?;
+        Ok(())
+    }
+
+    #[require_transactional]
+    fn write_off_dust_positions_of(who: &T::AccountId, asset_id: AssetIdOf<T>) -> DispatchResult {
+        // Borrows go first, so that writing them off can free up dust collateral.
+        Self::write_off_dust_borrow(who, asset_id)?;
+        Self::write_off_dust_deposit(who, asset_id)
+    }
+
+    // Up to `limit` accounts of a market after `cursor`. The keys are collected
+    // before anything gets written off, as that removes them from storage.
+    fn dust_sweep_page(
+        asset_id: AssetIdOf<T>,
+        cursor: &SweepCursor<T::AccountId>,
+        limit: u32,
+    ) -> Vec<T::AccountId> {
+        let limit = limit as usize;
+        match cursor {
+            SweepCursor::Deposits(None) => AccountDeposits::<T>::iter_key_prefix(asset_id)
+                .take(limit)
+                .collect(),
+            SweepCursor::Deposits(Some(last)) => AccountDeposits::<T>::iter_key_prefix_from(
+                asset_id,
+                AccountDeposits::<T>::hashed_key_for(asset_id, last),
+            )
+            .take(limit)
+            .collect(),
+            SweepCursor::Borrows(None) => AccountBorrows::<T>::iter_key_prefix(asset_id)
+                .take(limit)
+                .collect(),
+            SweepCursor::Borrows(Some(last)) => AccountBorrows::<T>::iter_key_prefix_from(
+                asset_id,
+                AccountBorrows::<T>::hashed_key_for(asset_id, last),
+            )
+            .take(limit)
+            .collect(),
+        }
+    }
+
+    // Whether `amount` of `asset_id` is worth less than `DustThresholdValue`.
+    fn is_dust(asset_id: AssetIdOf<T>, amount: BalanceOf<T>) -> Result<bool, DispatchError> {
+        if amount.is_zero() {
+            return Ok(true);
+        }
+        let value = Self::get_asset_value(asset_id, amount)?;
+        Ok(value < FixedU128::from_inner(T::DustThresholdValue::get()))
+    }
+
+    // Repays a dust borrow out of `TreasuryAccount` and removes the borrow
+    // snapshot and reward index of `borrower`.
+    #[require_transactional]
+    fn write_off_dust_borrow(borrower: &T::AccountId, asset_id: AssetIdOf<T>) -> DispatchResult {
+        if !AccountBorrows::<T>::contains_key(asset_id, borrower) {
+            return Ok(());
+        }
+        let account_borrows = Self::current_borrow_balance(borrower, asset_id)?;
+        if !Self::is_dust(asset_id, account_borrows)? {
+            return Ok(());
+        }
+        Self::distribute_borrower_reward(asset_id, borrower)?;
 
-        // 1.liquidator repay borrower's debt,
-        // transfer from liquidator to module account
         T::Assets::transfer(
-            liquidation_asset_id,
-            liquidator,
+            asset_id,
+            &T::TreasuryAccount::get(),
             &Self::account_id(),
-            repay_amount,
+            account_borrows,
             false,
         )?;
+        // Same as repaying, `TotalBorrows` may lag behind the account borrows.
+        TotalBorrows::<T>::mutate(asset_id, |total_borrows| {
+            *total_borrows = total_borrows.saturating_sub(account_borrows)
+        });
+        AccountBorrows::<T>::remove(asset_id, borrower);
+        RewardBorrowerIndex::<T>::remove(asset_id, borrower);
+
+        Self::deposit_event(Event::<T>::BorrowDustWrittenOff(
+            borrower.clone(),
+            asset_id,
+            account_borrows,
+        ));
 
-        // 2.the system reduce borrower's debt
-        let account_borrows = Self::current_borrow_balance(borrower, liquidation_asset_id)?;
-        let account_borrows_new = account_borrows
-            .checked_sub(repay_amount)
-            .ok_or(ArithmeticError::Underflow)?;
-        let total_borrows = Self::total_borrows(liquidation_asset_id);
-        let total_borrows_new = total_borrows
-            .checked_sub(repay_amount)
-            .ok_or(ArithmeticError::Underflow)?;
-        AccountBorrows::<T>::insert(
-            liquidation_asset_id,
-            borrower,
-            BorrowSnapshot {
-                principal: account_borrows_new,
-                borrow_index: Self::borrow_index(liquidation_asset_id),
-            },
-        );
-        TotalBorrows::<T>::insert(liquidation_asset_id, total_borrows_new);
+        Ok(())
+    }
 
-        // update supply index before modify supply balance.
-        Self::update_reward_supply_index(collateral_asset_id)?;
-        Self::distribute_supplier_reward(collateral_asset_id, liquidator)?;
-        Self::distribute_supplier_reward(collateral_asset_id, borrower)?;
-        Self::distribute_supplier_reward(
-            collateral_asset_id,
-            &Self::incentive_reward_account_id()?,
-        )?;
+    // Moves a dust deposit into the market reserves and removes the deposit,
+    // earned snapshot and reward index of `supplier`. A dust collateral is only
+    // written off if the account stays solvent without it.
+    #[require_transactional]
+    fn write_off_dust_deposit(supplier: &T::AccountId, asset_id: AssetIdOf<T>) -> DispatchResult {
+        if !AccountDeposits::<T>::contains_key(asset_id, supplier) {
+            return Ok(());
+        }
+        let deposits = Self::account_deposits(asset_id, supplier);
+        let exchange_rate = Self::exchange_rate_stored(asset_id)?;
+        let amount = Self::calc_underlying_amount(deposits.voucher_balance, exchange_rate)?;
+        if !Self::is_dust(asset_id, amount)? {
+            return Ok(());
+        }
+        if deposits.is_collateral && !amount.is_zero() {
+            let market = Self::market(asset_id)?;
+            let effects_amount = market.collateral_factor.mul_ceil(amount);
+            let effects_value = Self::get_asset_value(asset_id, effects_amount)?;
+            if Self::ensure_liquidity(
+                supplier,
+                effects_value,
+                Self::liquidation_free_collaterals().contains(&asset_id),
+            )
+            .is_err()
+            {
+                return Ok(());
+            }
+        }
+        Self::distribute_supplier_reward(asset_id, supplier)?;
 
-        // 3.the liquidator will receive voucher token from borrower
-        let exchange_rate = Self::exchange_rate_stored(collateral_asset_id)?;
-        let collateral_amount =
-            Self::calc_collateral_amount(collateral_underlying_amount, exchange_rate)?;
-        AccountDeposits::<T>::try_mutate(
-            collateral_asset_id,
-            borrower,
-            |deposits| -> DispatchResult {
-                deposits.voucher_balance = deposits
-                    .voucher_balance
-                    .checked_sub(collateral_amount)
-                    .ok_or(ArithmeticError::Underflow)?;
-                Ok(())
-            },
-        )?;
-        let incentive_reserved_amount = market.liquidate_incentive_reserved_factor.mul_floor(
-            FixedU128::from_inner(collateral_amount)
-                .checked_div(&market.liquidate_incentive)
-                .map(|r| r.into_inner())
-                .ok_or(ArithmeticError::Underflow)?,
-        );
-        // increase liquidator's voucher_balance
-        AccountDeposits::<T>::try_mutate(
-            collateral_asset_id,
-            liquidator,
-            |deposits| -> DispatchResult {
-                deposits.voucher_balance = deposits
-                    .voucher_balance
-                    .checked_add(collateral_amount - incentive_reserved_amount)
-                    .ok_or(ArithmeticError::Overflow)?;
-                Ok(())
-            },
-        )?;
-        // increase reserve's voucher_balance
-        AccountDeposits::<T>::try_mutate(
-            collateral_asset_id,
-            Self::incentive_reward_account_id()?,
-            |deposits| -> DispatchResult {
-                deposits.voucher_balance = deposits
-                    .voucher_balance
-                    .checked_add(incentive_reserved_amount)
-                    .ok_or(ArithmeticError::Overflow)?;
-                Ok(())
-            },
-        )?;
+        // Moving the underlying into the reserves leaves the exchange rate unchanged.
+        TotalSupply::<T>::try_mutate(asset_id, |total_supply| -> DispatchResult {
+            *total_supply = total_supply
+                .checked_sub(deposits.voucher_balance)
+                .ok_or(ArithmeticError::Underflow)?;
+            Ok(())
+        })?;
+        TotalReserves::<T>::try_mutate(asset_id, |total_reserves| -> DispatchResult {
+            *total_reserves = total_reserves
+                .checked_add(amount)
+                .ok_or(ArithmeticError::Overflow)?;
+            Ok(())
+        })?;
+        AccountDeposits::<T>::remove(asset_id, supplier);
+        AccountEarned::<T>::remove(asset_id, supplier);
+        RewardSupplierIndex::<T>::remove(asset_id, supplier);
 
-        Self::deposit_event(Event::<T>::LiquidatedBorrow(
-            liquidator.clone(),
-            borrower.clone(),
-            liquidation_asset_id,
-            collateral_asset_id,
-            repay_amount,
-            collateral_underlying_amount,
+        Self::deposit_event(Event::<T>::SupplyDustWrittenOff(
+            supplier.clone(),
+            asset_id,
+            amount,
         ));
 
         Ok(())
@@ -1837,6 +3275,59 @@ impl<T: Config> Pallet<T> {
     }
 
     // Ensures a given `ptoken_id` is unique in `Markets` and `UnderlyingAssetId`.
+    // checks a market before it is listed for `asset_id`
+    fn ensure_new_market(asset_id: AssetIdOf<T>, market: &Market<BalanceOf<T>>) -> DispatchResult {
+        ensure!(
+            !Markets::<T>::contains_key(asset_id),
+            Error::<T>::MarketAlreadyExists
+        );
+        ensure!(
+            market.state == MarketState::Pending,
+            Error::<T>::NewMarketMustHavePendingState
+        );
+        ensure!(
+            market.rate_model.check_model(),
+            Error::<T>::InvalidRateModelParam
+        );
+        ensure!(
+            market.collateral_factor >= Ratio::zero() && market.collateral_factor < Ratio::one(),
+            Error::<T>::InvalidFactor,
+        );
+        ensure!(
+            market.liquidation_threshold < Ratio::one()
+                && market.liquidation_threshold >= market.collateral_factor,
+            Error::<T>::InvalidFactor
+        );
+        ensure!(
+            market.reserve_factor > Ratio::zero() && market.reserve_factor < Ratio::one(),
+            Error::<T>::InvalidFactor,
+        );
+        ensure!(
+            market.liquidate_incentive_reserved_factor > Ratio::zero()
+                && market.liquidate_incentive_reserved_factor < Ratio::one(),
+            Error::<T>::InvalidFactor,
+        );
+        ensure!(
+            market.supply_cap > Zero::zero(),
+            Error::<T>::InvalidSupplyCap,
+        );
+
+        // Ensures a given `ptoken_id` not exists on the `Market` and `UnderlyingAssetId`.
+        Self::ensure_ptoken(market.ptoken_id)
+    }
+
+    fn do_add_market(asset_id: AssetIdOf<T>, market: Market<BalanceOf<T>>) {
+        // Update storage of `Market` and `UnderlyingAssetId`
+        Markets::<T>::insert(asset_id, market.clone());
+        UnderlyingAssetId::<T>::insert(market.ptoken_id, asset_id);
+
+        // Init the ExchangeRate and BorrowIndex for asset
+        ExchangeRate::<T>::insert(asset_id, Rate::from_inner(MIN_EXCHANGE_RATE));
+        BorrowIndex::<T>::insert(asset_id, Rate::one());
+
+        Self::deposit_event(Event::<T>::NewMarket(asset_id, market));
+    }
+
     fn ensure_ptoken(ptoken_id: CurrencyId) -> DispatchResult {
         // The ptoken id is unique, cannot be repeated
         ensure!(
@@ -1876,6 +3367,158 @@ impl<T: Config> Pallet<T> {
         Err(Error::<T>::InsufficientLiquidity.into())
     }
 
+    /// Ensures `borrower` stays within the limit it set on its borrows of `asset_id`
+    /// once it borrows `amount` more
+    fn ensure_within_borrow_limit(
+        asset_id: AssetIdOf<T>,
+        borrower: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        if let Some(limit) = Self::account_borrow_limit(asset_id, borrower) {
+            let borrowed = Self::current_borrow_balance(borrower, asset_id)?
+                .checked_add(amount)
+                .ok_or(ArithmeticError::Overflow)?;
+            ensure!(borrowed <= limit, Error::<T>::SelfBorrowLimitExceeded);
+        }
+        Ok(())
+    }
+
+    /// Ensures the borrows of `account` stay within the max LTV it set once `borrowed`
+    /// is added to them and `removed` is taken out of its collateral, both as values
+    fn ensure_within_max_ltv(
+        account: &T::AccountId,
+        borrowed: FixedU128,
+        removed: FixedU128,
+    ) -> DispatchResult {
+        let max_ltv = match Self::account_risk_settings(account).and_then(|s| s.max_ltv) {
+            Some(max_ltv) => max_ltv,
+            None => return Ok(()),
+        };
+        let total_borrowed_value = Self::total_borrowed_value(account)?
+            .checked_add(&borrowed)
+            .ok_or(ArithmeticError::Overflow)?;
+        if total_borrowed_value.is_zero() {
+            return Ok(());
+        }
+        let collateral_value =
+            Self::total_collateral_underlying_value(account)?.saturating_sub(removed);
+        ensure!(
+            total_borrowed_value <= FixedU128::from(max_ltv).saturating_mul(collateral_value),
+            Error::<T>::SelfMaxLtvExceeded
+        );
+        Ok(())
+    }
+
+    /// Attributes the borrowed value of `account` to the classes of its collateral, in
+    /// proportion to the value of the collateral in each class, and moves the debt of
+    /// each class by the change. With `enforce_ceilings`, the debt of a class may only
+    /// grow up to its ceiling.
+    ///
+    /// Debts are valued at the prices and borrow balances of the update, so the debt
+    /// of a class drifts with them until its borrowers are updated again.
+    fn update_class_debts(account: &T::AccountId, enforce_ceilings: bool) -> DispatchResult {
+        let mut class_values: BTreeMap<CollateralClass, FixedU128> = BTreeMap::new();
+        let mut total_collateral_value = FixedU128::zero();
+        let mut add_collateral = |asset_id: AssetIdOf<T>, value: FixedU128| -> DispatchResult {
+            total_collateral_value = total_collateral_value
+                .checked_add(&value)
+                .ok_or(ArithmeticError::Overflow)?;
+            if let Some(class) = Self::collateral_class(asset_id) {
+                let class_value = class_values.entry(class).or_default();
+                *class_value = class_value
+                    .checked_add(&value)
+                    .ok_or(ArithmeticError::Overflow)?;
+            }
+            Ok(())
+        };
+        for (asset_id, _) in Self::active_markets() {
+            add_collateral(
+                asset_id,
+                Self::collateral_underlying_value(account, asset_id)?,
+            )?;
+        }
+        for asset_id in AdapterCollaterals::<T>::iter_keys() {
+            let deposited = Self::account_adapter_collateral(asset_id, account);
+            if !deposited.is_zero() {
+                add_collateral(
+                    asset_id,
+                    Self::get_adapter_collateral_value(asset_id, deposited)?,
+                )?;
+            }
+        }
+
+        let borrowed_value = Self::total_borrowed_value(account)?;
+        for class in CollateralClass::ALL {
+            let debt = match class_values.get(&class) {
+                Some(value) if !total_collateral_value.is_zero() => {
+                    let share = FixedU128::checked_from_rational(
+                        value.into_inner(),
+                        total_collateral_value.into_inner(),
+                    )
+                    .ok_or(ArithmeticError::Overflow)?;
+                    borrowed_value.saturating_mul(share).into_inner()
+                }
+                _ => Zero::zero(),
+            };
+            let previous_debt = Self::account_class_debt(class, account);
+            if debt == previous_debt {
+                continue;
+            }
+            let class_debt = Self::class_debt(class)
+                .saturating_sub(previous_debt)
+                .saturating_add(debt);
+            if enforce_ceilings && debt > previous_debt {
+                if let Some(ceiling) = Self::debt_ceiling(class) {
+                    ensure!(class_debt <= ceiling, Error::<T>::DebtCeilingExceeded);
+                }
+            }
+            ClassDebts::<T>::insert(class, class_debt);
+            AccountClassDebts::<T>::mutate_exists(class, account, |d| {
+                *d = Some(debt).filter(|d| !d.is_zero())
+            });
+        }
+        Ok(())
+    }
+
+    // Updates the class debts once `account` paid back part of its debt or added
+    // collateral, which mustn't fail on a missing price, the debts are then updated on
+    // its next borrow
+    fn release_class_debts(account: &T::AccountId) {
+        let result = with_transaction(|| match Self::update_class_debts(account, false) {
+            Ok(()) => TransactionOutcome::Commit(Ok(())),
+            Err(err) => TransactionOutcome::Rollback(Err(err)),
+        });
+        if let Err(err) = result {
+            log::debug!(
+                target: "loans::release_class_debts",
+                "account: {:?}, error: {:?}",
+                account,
+                err,
+            );
+        }
+    }
+
+    // Moves the class debts of `account` once its collateral changed, as the share of its
+    // debt each class backs changes with it. Taking collateral out is checked against
+    // the ceilings, adding collateral never fails on them so positions can always be
+    // topped up.
+    pub(crate) fn rebalance_class_debts(
+        account: &T::AccountId,
+        collateral_removed: bool,
+    ) -> DispatchResult {
+        let has_borrows = Self::active_markets()
+            .any(|(asset_id, _)| AccountBorrows::<T>::contains_key(asset_id, account));
+        if !has_borrows {
+            return Ok(());
+        }
+        if collateral_removed {
+            Self::update_class_debts(account, true)
+        } else {
+            Self::release_class_debts(account);
+            Ok(())
+        }
+    }
+
     pub fn calc_underlying_amount(
         voucher_amount: BalanceOf<T>,
         exchange_rate: Rate,
@@ -1936,6 +3579,19 @@ impl<T: Config> Pallet<T> {
         Ok(value)
     }
 
+    // Returns the value of collateral kept by `CollateralAdapter`, priced by the adapter
+    // in the same format as `get_price`.
+    pub fn get_adapter_collateral_value(
+        asset_id: AssetIdOf<T>,
+        amount: BalanceOf<T>,
+    ) -> Result<FixedU128, DispatchError> {
+        let value = T::CollateralAdapter::price(asset_id)?
+            .checked_mul(&FixedU128::from_inner(amount))
+            .ok_or(ArithmeticError::Overflow)?;
+
+        Ok(value)
+    }
+
     // Returns a stored Market.
     //
     // Returns `Err` if market does not exist.
@@ -2018,13 +3674,17 @@ impl<T: Config> LoansTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for Palle
         ensure!(!voucher_amount.is_zero(), Error::<T>::InvalidExchangeRate);
 
         T::Assets::transfer(asset_id, supplier, &Self::account_id(), amount, false)?;
-        AccountDeposits::<T>::try_mutate(asset_id, supplier, |deposits| -> DispatchResult {
-            deposits.voucher_balance = deposits
-                .voucher_balance
-                .checked_add(voucher_amount)
-                .ok_or(ArithmeticError::Overflow)?;
-            Ok(())
-        })?;
+        let is_collateral = AccountDeposits::<T>::try_mutate(
+            asset_id,
+            supplier,
+            |deposits| -> Result<bool, DispatchError> {
+                deposits.voucher_balance = deposits
+                    .voucher_balance
+                    .checked_add(voucher_amount)
+                    .ok_or(ArithmeticError::Overflow)?;
+                Ok(deposits.is_collateral)
+            },
+        )?;
         TotalSupply::<T>::try_mutate(asset_id, |total_balance| -> DispatchResult {
             let new_balance = total_balance
                 .checked_add(voucher_amount)
@@ -2032,6 +3692,10 @@ impl<T: Config> LoansTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for Palle
             *total_balance = new_balance;
             Ok(())
         })?;
+        if is_collateral {
+            Self::rebalance_class_debts(supplier, false)?;
+        }
+        T::Referrals::record_volume(supplier, VolumeSource::LoansSupply, asset_id, amount);
         Self::deposit_event(Event::<T>::Deposited(supplier.clone(), asset_id, amount));
         Ok(())
     }
@@ -2068,6 +3732,8 @@ impl<T: Config> LoansTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for Palle
         );
         TotalBorrows::<T>::insert(asset_id, total_borrows_new);
         T::Assets::transfer(asset_id, &Self::account_id(), borrower, amount, false)?;
+        Self::update_class_debts(borrower, true)?;
+        T::Referrals::record_volume(borrower, VolumeSource::LoansBorrow, asset_id, amount);
         Self::deposit_event(Event::<T>::Borrowed(borrower.clone(), asset_id, amount));
         Ok(())
     }
@@ -2087,6 +3753,7 @@ impl<T: Config> LoansTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for Palle
         if enable {
             deposits.is_collateral = true;
             AccountDeposits::<T>::insert(asset_id, supplier, deposits);
+            Self::rebalance_class_debts(supplier, false)?;
             Self::deposit_event(Event::<T>::CollateralAssetAdded(supplier.clone(), asset_id));
             return Ok(());
         }
@@ -2108,8 +3775,14 @@ impl<T: Config> LoansTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for Palle
         {
             return Err(Error::<T>::InsufficientLiquidity.into());
         }
+        Self::ensure_within_max_ltv(
+            supplier,
+            FixedU128::zero(),
+            Self::collateral_underlying_value(supplier, asset_id)?,
+        )?;
         deposits.is_collateral = false;
         AccountDeposits::<T>::insert(asset_id, supplier, deposits);
+        Self::rebalance_class_debts(supplier, true)?;
 
         Self::deposit_event(Event::<T>::CollateralAssetRemoved(
             supplier.clone(),
@@ -2128,6 +3801,7 @@ impl<T: Config> LoansTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for Palle
         Self::accrue_interest(asset_id)?;
         let account_borrows = Self::current_borrow_balance(borrower, asset_id)?;
         Self::do_repay_borrow_with_amount(borrower, asset_id, account_borrows, amount)?;
+        Self::release_class_debts(borrower);
         Self::deposit_event(Event::<T>::RepaidBorrow(borrower.clone(), asset_id, amount));
         Ok(())
     }
@@ -2217,3 +3891,79 @@ impl<T: Config> LoansPositionDataProvider<AssetIdOf<T>, AccountIdOf<T>, BalanceO
         Self::current_collateral_balance(supplier, asset_id)
     }
 }
+
+/// Supplying to a market and redeeming from it as conversions between the underlying asset
+/// and its pToken
+impl<T: Config> ProtocolConversion<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>> for Pallet<T> {
+    fn conversions() -> Vec<(AssetIdOf<T>, AssetIdOf<T>)> {
+        Self::active_markets()
+            .flat_map(|(asset_id, market)| {
+                [(asset_id, market.ptoken_id), (market.ptoken_id, asset_id)]
+            })
+            .collect()
+    }
+
+    fn quote_out(
+        pair: (AssetIdOf<T>, AssetIdOf<T>),
+        amount_in: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        if let Ok(asset_id) = Self::underlying_id(pair.0) {
+            let (_, _, exchange_rate, ..) = Self::get_market_status(asset_id)?;
+            let amount_out = Self::calc_underlying_amount(amount_in, exchange_rate)?;
+            ensure!(
+                amount_out <= Self::get_total_cash(asset_id),
+                Error::<T>::InsufficientCash
+            );
+            Ok(amount_out)
+        } else {
+            let (_, _, exchange_rate, ..) = Self::get_market_status(pair.0)?;
+            Self::calc_collateral_amount(amount_in, exchange_rate)
+        }
+    }
+
+    fn quote_in(
+        pair: (AssetIdOf<T>, AssetIdOf<T>),
+        amount_out: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        // One more unit in makes up for the exchange rate rounding down
+        if let Ok(asset_id) = Self::underlying_id(pair.0) {
+            ensure!(
+                amount_out <= Self::get_total_cash(asset_id),
+                Error::<T>::InsufficientCash
+            );
+            let (_, _, exchange_rate, ..) = Self::get_market_status(asset_id)?;
+            Ok(Self::calc_collateral_amount(amount_out, exchange_rate)?.saturating_add(One::one()))
+        } else {
+            let (_, _, exchange_rate, ..) = Self::get_market_status(pair.0)?;
+            Ok(Self::calc_underlying_amount(amount_out, exchange_rate)?.saturating_add(One::one()))
+        }
+    }
+
+    fn convert(
+        who: &AccountIdOf<T>,
+        pair: (AssetIdOf<T>, AssetIdOf<T>),
+        amount_in: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        if let Ok(asset_id) = Self::underlying_id(pair.0) {
+            Self::ensure_active_market(asset_id)?;
+            Self::accrue_interest(asset_id)?;
+            let exchange_rate = Self::exchange_rate_stored(asset_id)?;
+            Self::update_earned_stored(who, asset_id, exchange_rate)?;
+            let redeem_amount = Self::do_redeem_voucher(who, asset_id, amount_in)?;
+            Self::deposit_event(Event::<T>::Redeemed(who.clone(), asset_id, redeem_amount));
+            Ok(redeem_amount)
+        } else {
+            let voucher_balance = Self::account_deposits(pair.0, who).voucher_balance;
+            <Self as LoansTrait<_, _, _>>::do_mint(who, pair.0, amount_in)?;
+            Ok(Self::account_deposits(pair.0, who)
+                .voucher_balance
+                .saturating_sub(voucher_balance))
+        }
+    }
+
+    fn reducible_balance(who: &AccountIdOf<T>, asset: AssetIdOf<T>) -> Option<BalanceOf<T>> {
+        Self::underlying_id(asset)
+            .ok()
+            .map(|_| <Self as Inspect<AccountIdOf<T>>>::reducible_balance(asset, who, false))
+    }
+}
@@ -13,6 +13,44 @@
 // limitations under the License.
 
 use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+
+/// Starts the keeper stale period of the existing markets at the upgrade block.
+///
+/// Without it `LastAccruedInterestBlock` reads as zero, and so do the reward states of
+/// markets whose indexes never moved, so every market would pay a keeper reward
+/// right after the upgrade.
+pub struct InitializeKeeperMarkers<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for InitializeKeeperMarkers<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let mut count = 0u64;
+        let mut writes = 0u64;
+        for asset_id in Markets::<T>::iter_keys() {
+            count += 1;
+            if !LastAccruedInterestBlock::<T>::contains_key(asset_id) {
+                LastAccruedInterestBlock::<T>::insert(asset_id, now);
+                writes += 1;
+            }
+            let mut initialize = |state: &mut RewardMarketState<T::BlockNumber, BalanceOf<T>>| {
+                if state.block.is_zero() {
+                    state.block = now;
+                    writes += 1;
+                }
+            };
+            RewardSupplyState::<T>::mutate(asset_id, &mut initialize);
+            RewardBorrowState::<T>::mutate(asset_id, &mut initialize);
+        }
+
+        log::info!(
+            target: "loans::migrate",
+            "initialized {} keeper markers of {} markets",
+            writes,
+            count
+        );
+        T::DbWeight::get().reads_writes(count.saturating_mul(4).saturating_add(1), writes)
+    }
+}
 
 pub mod v3 {
     use super::*;
@@ -219,6 +219,10 @@ impl LoansMarketDataProvider<CurrencyId, Balance> for VaultLoansRateProvider {
 
 parameter_types! {
     pub const RelayCurrency: CurrencyId = KSM;
+    pub const MaxPriceAge: u64 = 60_000;
+    pub const MinimumCount: u32 = 2;
+    pub const MaxRoundsKept: u32 = 3;
+    pub const MaxFeedValues: u32 = 10;
 }
 
 // AMM instance initialization
@@ -251,6 +255,8 @@ impl pallet_amm::Config for Test {
     type MinimumLiquidity = MinimumLiquidity;
     type MaxLengthRoute = MaxLengthRoute;
     type GetNativeCurrencyId = NativeCurrencyId;
+    type Revenue = ();
+    type Referrals = ();
 }
 
 impl pallet_prices::Config for Test {
@@ -267,6 +273,20 @@ impl pallet_prices::Config for Test {
     type Decimal = Decimal;
     type AMM = DefaultAMM;
     type Assets = CurrencyAdapter;
+    type UnixTime = TimestampPallet;
+    type MaxPriceAge = MaxPriceAge;
+    type CircuitBreaker = ();
+    type MinimumCount = MinimumCount;
+    type MaxRoundsKept = MaxRoundsKept;
+    type Oracle = ();
+    type Feeders = AliceCreatePoolOrigin;
+    type MaxFeedValues = MaxFeedValues;
+    type PriceDependencies = ();
+    type XcmOracleOrigin = frame_system::EnsureNever<primitives::ParaId>;
+    type LocalOracleWeight = frame_support::traits::ConstU32<1>;
+    type PriceQuorum = frame_support::traits::ConstU32<1>;
+    type MaxPriceDeviation = ();
+    type TwapPeriod = frame_support::traits::ConstU64<10>;
     type WeightInfo = ();
 }
 
@@ -335,6 +355,14 @@ parameter_types! {
     pub const LoansPalletId: PalletId = PalletId(*b"par/loan");
     pub const RewardAssetId: CurrencyId = HKO;
     pub const LiquidationFreeAssetId: CurrencyId = DOT;
+    pub const RateSnapshotInterval: u64 = 60;
+    pub const MaxRateSnapshots: u32 = 3;
+    pub const KeeperStalePeriod: BlockNumber = 10;
+    pub const KeeperRewardValue: Balance = 1_000_000_000_000;
+    pub const DustThresholdValue: Balance = 1_000_000_000_000;
+    pub const TreasuryAccount: AccountId = AccountId32::new([100u8; 32]);
+    pub const MaxPageSize: u32 = 10;
+    pub const MarketReviewPeriod: BlockNumber = 10;
 }
 
 impl Config for Test {
@@ -348,6 +376,69 @@ impl Config for Test {
     type Assets = CurrencyAdapter;
     type RewardAssetId = RewardAssetId;
     type LiquidationFreeAssetId = LiquidationFreeAssetId;
+    type Revenue = ();
+    type Referrals = ();
+    type EventMirror = ();
+    type RateSnapshotInterval = RateSnapshotInterval;
+    type MaxRateSnapshots = MaxRateSnapshots;
+    type KeeperStalePeriod = KeeperStalePeriod;
+    type KeeperRewardValue = KeeperRewardValue;
+    type LiquidityUnwinder = DefaultAMM;
+    type DustThresholdValue = DustThresholdValue;
+    type TreasuryAccount = TreasuryAccount;
+    type MaxPageSize = MaxPageSize;
+    type CollateralAdapter = MockCollateralAdapter;
+    type MarketVetoOrigin = EnsureRoot<AccountId>;
+    type MarketReviewPeriod = MarketReviewPeriod;
+}
+
+pub const ADAPTER_CUSTODY: AccountId = AccountId32::new([101u8; 32]);
+
+/// Keeps the SKSM deposited as collateral in a custody account, priced by the oracle
+pub struct MockCollateralAdapter;
+
+impl MockCollateralAdapter {
+    thread_local! {
+        pub static FACTOR_SCALE: RefCell<Ratio> = RefCell::new(Ratio::from_percent(100));
+    }
+
+    pub fn set_factor_scale(scale: Ratio) {
+        Self::FACTOR_SCALE.with(|s| *s.borrow_mut() = scale);
+    }
+}
+
+impl CollateralAdapter<AccountId, CurrencyId, Balance> for MockCollateralAdapter {
+    fn is_supported(asset: CurrencyId) -> bool {
+        asset == SKSM
+    }
+
+    fn price(asset: CurrencyId) -> Result<Price, DispatchError> {
+        Loans::get_price(asset)
+    }
+
+    fn collateral_factor(_asset: CurrencyId, registered: Ratio) -> Ratio {
+        Self::FACTOR_SCALE.with(|s| *s.borrow()) * registered
+    }
+
+    fn on_deposit(who: &AccountId, asset: CurrencyId, amount: Balance) -> DispatchResult {
+        <Assets as Transfer<AccountId>>::transfer(asset, who, &ADAPTER_CUSTODY, amount, false)
+            .map(|_| ())
+    }
+
+    fn on_withdraw(who: &AccountId, asset: CurrencyId, amount: Balance) -> DispatchResult {
+        <Assets as Transfer<AccountId>>::transfer(asset, &ADAPTER_CUSTODY, who, amount, false)
+            .map(|_| ())
+    }
+
+    fn seize(
+        _borrower: &AccountId,
+        to: &AccountId,
+        asset: CurrencyId,
+        amount: Balance,
+    ) -> DispatchResult {
+        <Assets as Transfer<AccountId>>::transfer(asset, &ADAPTER_CUSTODY, to, amount, false)
+            .map(|_| ())
+    }
 }
 
 parameter_types! {
@@ -375,11 +466,13 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
         Assets::force_create(RuntimeOrigin::root(), USDT, ALICE, true, 1).unwrap();
         Assets::force_create(RuntimeOrigin::root(), SDOT, ALICE, true, 1).unwrap();
         Assets::force_create(RuntimeOrigin::root(), CDOT_6_13, ALICE, true, 1).unwrap();
+        Assets::force_create(RuntimeOrigin::root(), SKSM, ALICE, true, 1).unwrap();
 
         Assets::mint(RuntimeOrigin::signed(ALICE), KSM, ALICE, unit(1000)).unwrap();
         Assets::mint(RuntimeOrigin::signed(ALICE), DOT, ALICE, unit(1000)).unwrap();
         Assets::mint(RuntimeOrigin::signed(ALICE), USDT, ALICE, unit(1000)).unwrap();
         Assets::mint(RuntimeOrigin::signed(ALICE), CDOT_6_13, ALICE, unit(1000)).unwrap();
+        Assets::mint(RuntimeOrigin::signed(ALICE), SKSM, ALICE, unit(1000)).unwrap();
         Assets::mint(RuntimeOrigin::signed(ALICE), KSM, BOB, unit(1000)).unwrap();
         Assets::mint(RuntimeOrigin::signed(ALICE), DOT, BOB, unit(1000)).unwrap();
         Assets::mint(RuntimeOrigin::signed(ALICE), DOT, DAVE, unit(1000)).unwrap();
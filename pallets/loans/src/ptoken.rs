@@ -158,6 +158,7 @@ impl<T: Config> Pallet<T> {
         Self::distribute_supplier_reward(ptoken_id, dest)?;
 
         let underlying_id = Self::underlying_id(ptoken_id)?;
+        let source_collateral = Self::account_deposits(underlying_id, source).is_collateral;
         AccountDeposits::<T>::try_mutate_exists(
             underlying_id,
             source,
@@ -177,14 +178,24 @@ impl<T: Config> Pallet<T> {
             },
         )?;
 
-        AccountDeposits::<T>::try_mutate(underlying_id, &dest, |deposits| -> DispatchResult {
-            deposits.voucher_balance = deposits
-                .voucher_balance
-                .checked_add(amount)
-                .ok_or(ArithmeticError::Overflow)?;
-            Ok(())
-        })?;
+        let dest_collateral = AccountDeposits::<T>::try_mutate(
+            underlying_id,
+            &dest,
+            |deposits| -> Result<bool, DispatchError> {
+                deposits.voucher_balance = deposits
+                    .voucher_balance
+                    .checked_add(amount)
+                    .ok_or(ArithmeticError::Overflow)?;
+                Ok(deposits.is_collateral)
+            },
+        )?;
 
+        if source_collateral {
+            Self::rebalance_class_debts(source, true)?;
+        }
+        if dest_collateral {
+            Self::rebalance_class_debts(dest, false)?;
+        }
         Ok(())
     }
 
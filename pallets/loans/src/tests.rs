@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod collateral_adapter;
+mod debt_ceiling;
 mod edge_cases;
 mod interest_rate;
 mod liquidate_borrow;
 mod market;
 mod ptokens;
+mod rate_model_transition;
 
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok, error::BadOrigin};
 
 use primitives::tokens::CDOT_6_13;
 use sp_runtime::{
@@ -461,6 +464,148 @@ fn get_account_liquidation_threshold_liquidity_works() {
     })
 }
 
+#[test]
+fn get_health_factors_works() {
+    new_test_ext().execute_with(|| {
+        Loans::mint(RuntimeOrigin::signed(BOB), DOT, unit(200)).unwrap();
+        Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)).unwrap();
+
+        Loans::mint(RuntimeOrigin::signed(ALICE), USDT, unit(200)).unwrap();
+        Loans::collateral_asset(RuntimeOrigin::signed(ALICE), USDT, true).unwrap();
+        Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(90)).unwrap();
+
+        assert_eq!(Loans::get_health_factors(FixedU128::one()).unwrap(), vec![]);
+        let health_factors = Loans::get_health_factors(FixedU128::from(2)).unwrap();
+        assert_eq!(health_factors.len(), 1);
+        assert_eq!(health_factors[0].0, ALICE);
+        assert!(health_factors[0].1 > FixedU128::one());
+
+        MockPriceFeeder::set_price(KSM, 2.into());
+        let health_factors = Loans::get_health_factors(FixedU128::one()).unwrap();
+        assert_eq!(health_factors.len(), 1);
+        assert_eq!(health_factors[0].0, ALICE);
+        assert!(health_factors[0].1 < FixedU128::one());
+    })
+}
+
+#[test]
+fn self_imposed_limits_are_enforced_on_own_calls() {
+    new_test_ext().execute_with(|| {
+        Loans::mint(RuntimeOrigin::signed(BOB), DOT, unit(200)).unwrap();
+        Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)).unwrap();
+        Loans::mint(RuntimeOrigin::signed(ALICE), USDT, unit(200)).unwrap();
+        Loans::collateral_asset(RuntimeOrigin::signed(ALICE), USDT, true).unwrap();
+
+        assert_noop!(
+            Loans::set_borrow_limit(RuntimeOrigin::signed(ALICE), SDOT, Some(unit(50))),
+            Error::<Test>::MarketNotActivated
+        );
+        assert_ok!(Loans::set_borrow_limit(
+            RuntimeOrigin::signed(ALICE),
+            KSM,
+            Some(unit(50))
+        ));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(60)),
+            Error::<Test>::SelfBorrowLimitExceeded
+        );
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(50)));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(1)),
+            Error::<Test>::SelfBorrowLimitExceeded
+        );
+        assert_ok!(Loans::set_borrow_limit(
+            RuntimeOrigin::signed(ALICE),
+            KSM,
+            None
+        ));
+        assert_eq!(Loans::account_borrow_limit(KSM, ALICE), None);
+
+        // 30% of the 200 USDT of collateral can be borrowed, where the collateral factor
+        // would allow 50%
+        let settings = RiskSettings {
+            max_ltv: Some(Ratio::from_percent(30)),
+            alert_threshold: None,
+        };
+        assert_ok!(Loans::set_risk_settings(
+            RuntimeOrigin::signed(ALICE),
+            settings
+        ));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(9)));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(2)),
+            Error::<Test>::SelfMaxLtvExceeded
+        );
+        assert_noop!(
+            Loans::redeem(RuntimeOrigin::signed(ALICE), USDT, unit(10)),
+            Error::<Test>::SelfMaxLtvExceeded
+        );
+        assert_ok!(Loans::redeem(RuntimeOrigin::signed(ALICE), USDT, unit(1)));
+
+        // the default settings remove the limits
+        assert_ok!(Loans::set_risk_settings(
+            RuntimeOrigin::signed(ALICE),
+            RiskSettings::default()
+        ));
+        assert_eq!(Loans::account_risk_settings(ALICE), None);
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(2)));
+    })
+}
+
+#[test]
+fn get_health_alerts_works() {
+    new_test_ext().execute_with(|| {
+        Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)).unwrap();
+        Loans::mint(RuntimeOrigin::signed(ALICE), USDT, unit(200)).unwrap();
+        Loans::collateral_asset(RuntimeOrigin::signed(ALICE), USDT, true).unwrap();
+        Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(90)).unwrap();
+        assert_eq!(Loans::get_health_alerts().unwrap(), vec![]);
+
+        let settings = RiskSettings {
+            max_ltv: None,
+            alert_threshold: Some(FixedU128::from(2)),
+        };
+        assert_ok!(Loans::set_risk_settings(
+            RuntimeOrigin::signed(ALICE),
+            settings
+        ));
+        // accounts without borrows have no health factor
+        assert_ok!(Loans::set_risk_settings(
+            RuntimeOrigin::signed(BOB),
+            settings
+        ));
+
+        let alerts = Loans::get_health_alerts().unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].0, ALICE);
+        assert!(alerts[0].1 < FixedU128::from(2));
+        assert_eq!(alerts[0].2, FixedU128::from(2));
+    })
+}
+
+#[test]
+fn account_positions_works() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Loans::account_positions(&ALICE).unwrap(), vec![]);
+
+        Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)).unwrap();
+        Loans::mint(RuntimeOrigin::signed(ALICE), USDT, unit(200)).unwrap();
+        Loans::collateral_asset(RuntimeOrigin::signed(ALICE), USDT, true).unwrap();
+        Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(50)).unwrap();
+        Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(90)).unwrap();
+
+        let mut positions = Loans::account_positions(&ALICE).unwrap();
+        positions.sort_by_key(|(asset_id, ..)| *asset_id);
+        let mut expected = vec![
+            (DOT, unit(50), 0, false),
+            (KSM, 0, unit(90), false),
+            (USDT, unit(200), 0, true),
+        ];
+        expected.sort_by_key(|(asset_id, ..)| *asset_id);
+        assert_eq!(positions, expected);
+    })
+}
+
 #[test]
 fn lf_borrow_allowed_works() {
     new_test_ext().execute_with(|| {
@@ -1376,6 +1521,42 @@ fn reward_calculation_multi_player_in_one_market_works() {
     })
 }
 
+#[test]
+fn pending_reward_should_include_unsettled_reward() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(10)));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), DOT, unit(10)));
+        assert_ok!(Loans::collateral_asset(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            true
+        ));
+        assert_ok!(Loans::update_market_reward_speed(
+            RuntimeOrigin::root(),
+            DOT,
+            Some(unit(1)),
+            Some(unit(1)),
+        ));
+
+        _run_to_block(10);
+        // Reward indexes are not updated yet
+        assert_eq!(Loans::reward_accrued(ALICE), 0);
+        assert_eq!(Loans::pending_reward(&ALICE), Ok(unit(5)));
+        assert_eq!(Loans::pending_reward(&BOB), Ok(unit(5)));
+
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(1)));
+        _run_to_block(20);
+        // Alice supply reward: 10     borrow reward: 10
+        let pending = Loans::pending_reward(&ALICE).unwrap();
+        assert_eq!(almost_equal(pending, unit(20)), true);
+
+        assert_ok!(Loans::add_reward(RuntimeOrigin::signed(DAVE), unit(100)));
+        assert_ok!(Loans::claim_reward(RuntimeOrigin::signed(ALICE)));
+        assert_eq!(<Test as Config>::Assets::balance(HKO, &ALICE), pending);
+        assert_eq!(Loans::pending_reward(&ALICE), Ok(0));
+    })
+}
+
 #[test]
 fn reward_calculation_after_liquidate_borrow_works() {
     new_test_ext().execute_with(|| {
@@ -1486,3 +1667,310 @@ fn reward_calculation_after_liquidate_borrow_works() {
         );
     })
 }
+
+#[test]
+fn try_state_checks_markets_are_backed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+        assert_ok!(Loans::collateral_asset(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            true
+        ));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(100)));
+        assert_ok!(Loans::do_try_state());
+
+        TotalSupply::<Test>::mutate(DOT, |total| *total += 1);
+        assert_err!(
+            Loans::do_try_state(),
+            "deposits must sum up to the total supply"
+        );
+        TotalSupply::<Test>::mutate(DOT, |total| *total -= 1);
+
+        TotalReserves::<Test>::insert(
+            DOT,
+            Loans::get_total_cash(DOT) + Loans::total_borrows(DOT) - 1,
+        );
+        assert_err!(
+            Loans::do_try_state(),
+            "ptokens must be redeemable at a valid exchange rate"
+        );
+    })
+}
+
+#[test]
+fn keeper_is_rewarded_for_stale_interest_accrual() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::add_reserves(
+            RuntimeOrigin::root(),
+            ALICE,
+            DOT,
+            unit(10)
+        ));
+
+        // Accrued recently, nothing to pay
+        System::set_block_number(5);
+        TimestampPallet::set_timestamp(36000);
+        assert_ok!(Loans::accrue_market_interest(
+            RuntimeOrigin::signed(BOB),
+            DOT
+        ));
+        assert_eq!(Loans::last_accrued_interest_block(DOT), 5);
+        assert_eq!(Assets::balance(DOT, BOB), unit(1000));
+        assert_eq!(Loans::total_reserves(DOT), unit(10));
+
+        // `KeeperStalePeriod` blocks without accrual
+        System::set_block_number(15);
+        TimestampPallet::set_timestamp(96000);
+        assert_ok!(Loans::accrue_market_interest(
+            RuntimeOrigin::signed(BOB),
+            DOT
+        ));
+        assert_eq!(Loans::last_accrued_interest_time(DOT), 96);
+        assert_eq!(Assets::balance(DOT, BOB), unit(1001));
+        assert_eq!(Loans::total_reserves(DOT), unit(9));
+
+        // The market is fresh again
+        assert_ok!(Loans::accrue_market_interest(
+            RuntimeOrigin::signed(BOB),
+            DOT
+        ));
+        assert_eq!(Assets::balance(DOT, BOB), unit(1001));
+
+        // The reward is capped by the reserves
+        System::set_block_number(30);
+        assert_ok!(Loans::accrue_market_interest(
+            RuntimeOrigin::signed(ALICE),
+            KSM
+        ));
+        assert_eq!(Assets::balance(KSM, ALICE), unit(1000));
+
+        assert_noop!(
+            Loans::accrue_market_interest(RuntimeOrigin::signed(BOB), SDOT),
+            Error::<Test>::MarketNotActivated
+        );
+    })
+}
+
+#[test]
+fn keeper_is_rewarded_for_stale_reward_indexes() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::add_reserves(
+            RuntimeOrigin::root(),
+            ALICE,
+            DOT,
+            unit(10)
+        ));
+
+        System::set_block_number(5);
+        assert_ok!(Loans::update_market_reward_indexes(
+            RuntimeOrigin::signed(BOB),
+            DOT
+        ));
+        assert_eq!(Loans::reward_supply_state(DOT).block, 5);
+        assert_eq!(Loans::reward_borrow_state(DOT).block, 5);
+        assert_eq!(Assets::balance(DOT, BOB), unit(1000));
+
+        System::set_block_number(15);
+        assert_ok!(Loans::update_market_reward_indexes(
+            RuntimeOrigin::signed(BOB),
+            DOT
+        ));
+        assert_eq!(Assets::balance(DOT, BOB), unit(1001));
+        assert_eq!(Loans::total_reserves(DOT), unit(9));
+
+        // No reward while the price is unavailable, the indexes still move on
+        MockPriceFeeder::set_price(DOT, 0.into());
+        System::set_block_number(25);
+        assert_ok!(Loans::update_market_reward_indexes(
+            RuntimeOrigin::signed(BOB),
+            DOT
+        ));
+        assert_eq!(Loans::reward_supply_state(DOT).block, 25);
+        assert_eq!(Assets::balance(DOT, BOB), unit(1001));
+    })
+}
+
+#[test]
+fn keeper_markers_are_initialized_on_upgrade() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::OnRuntimeUpgrade;
+
+        assert_ok!(Loans::add_reserves(
+            RuntimeOrigin::root(),
+            ALICE,
+            DOT,
+            unit(10)
+        ));
+        // Markets created before the keeper markers existed
+        LastAccruedInterestBlock::<Test>::remove(DOT);
+        RewardSupplyState::<Test>::remove(DOT);
+        LastAccruedInterestBlock::<Test>::insert(KSM, 3);
+
+        System::set_block_number(20);
+        crate::migrations::InitializeKeeperMarkers::<Test>::on_runtime_upgrade();
+        assert_eq!(Loans::last_accrued_interest_block(DOT), 20);
+        assert_eq!(Loans::reward_supply_state(DOT).block, 20);
+        assert_eq!(Loans::last_accrued_interest_block(KSM), 3);
+
+        // Freshly upgraded markets don't pay keepers until they go stale
+        System::set_block_number(25);
+        assert_ok!(Loans::accrue_market_interest(
+            RuntimeOrigin::signed(BOB),
+            DOT
+        ));
+        assert_eq!(Assets::balance(DOT, BOB), unit(1000));
+        assert_eq!(Loans::total_reserves(DOT), unit(10));
+    })
+}
+
+#[test]
+fn write_off_dust_positions_works() {
+    new_test_ext().execute_with(|| {
+        let treasury = TreasuryAccount::get();
+        assert_ok!(Assets::mint(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            treasury.clone(),
+            unit(10)
+        ));
+
+        // ALICE has a regular DOT position
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+        assert_ok!(Loans::collateral_asset(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            true
+        ));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(100)));
+
+        // BOB borrows and supplies DOT dust against KSM collateral
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(100)));
+        assert_ok!(Loans::collateral_asset(
+            RuntimeOrigin::signed(BOB),
+            KSM,
+            true
+        ));
+        assert_ok!(Loans::borrow(
+            RuntimeOrigin::signed(BOB),
+            DOT,
+            milli_unit(500)
+        ));
+        assert_ok!(Loans::mint(
+            RuntimeOrigin::signed(BOB),
+            DOT,
+            milli_unit(300)
+        ));
+
+        // DAVE's DOT dust is the collateral of his KSM borrow
+        assert_ok!(Loans::mint(
+            RuntimeOrigin::signed(DAVE),
+            DOT,
+            milli_unit(800)
+        ));
+        assert_ok!(Loans::collateral_asset(
+            RuntimeOrigin::signed(DAVE),
+            DOT,
+            true
+        ));
+        assert_ok!(Loans::borrow(
+            RuntimeOrigin::signed(DAVE),
+            KSM,
+            milli_unit(300)
+        ));
+
+        let exchange_rate = Loans::exchange_rate(DOT);
+        assert_noop!(
+            Loans::write_off_dust_positions(RuntimeOrigin::signed(ALICE), DOT, vec![BOB]),
+            BadOrigin
+        );
+        assert_ok!(Loans::write_off_dust_positions(
+            RuntimeOrigin::root(),
+            DOT,
+            vec![ALICE, BOB, DAVE]
+        ));
+
+        // The treasury repaid BOB's borrow, his deposit went to the reserves
+        assert!(!AccountBorrows::<Test>::contains_key(DOT, BOB));
+        assert!(!RewardBorrowerIndex::<Test>::contains_key(DOT, BOB));
+        assert!(!AccountDeposits::<Test>::contains_key(DOT, BOB));
+        assert!(!AccountEarned::<Test>::contains_key(DOT, BOB));
+        assert!(!RewardSupplierIndex::<Test>::contains_key(DOT, BOB));
+        assert_eq!(Assets::balance(DOT, treasury), unit(10) - milli_unit(500));
+        assert_eq!(Loans::total_borrows(DOT), unit(100));
+        assert_eq!(Loans::total_reserves(DOT), milli_unit(300));
+        assert_eq!(Loans::exchange_rate(DOT), exchange_rate);
+
+        // BOB's KSM collateral is in another market
+        assert!(AccountDeposits::<Test>::contains_key(KSM, BOB));
+
+        // ALICE's positions are not dust, DAVE still needs his collateral
+        assert_eq!(Loans::account_borrows(DOT, ALICE).principal, unit(100));
+        assert!(AccountDeposits::<Test>::contains_key(DOT, ALICE));
+        assert!(AccountDeposits::<Test>::contains_key(DOT, DAVE));
+    })
+}
+
+#[test]
+fn sweep_dust_positions_works() {
+    new_test_ext().execute_with(|| {
+        let treasury = TreasuryAccount::get();
+        assert_ok!(Assets::mint(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            treasury.clone(),
+            unit(10)
+        ));
+
+        // ALICE, BOB and DAVE supply DOT, BOB borrows DOT dust against KSM
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+        assert_ok!(Loans::mint(
+            RuntimeOrigin::signed(BOB),
+            DOT,
+            milli_unit(300)
+        ));
+        assert_ok!(Loans::mint(
+            RuntimeOrigin::signed(DAVE),
+            DOT,
+            milli_unit(400)
+        ));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(100)));
+        assert_ok!(Loans::collateral_asset(
+            RuntimeOrigin::signed(BOB),
+            KSM,
+            true
+        ));
+        assert_ok!(Loans::borrow(
+            RuntimeOrigin::signed(BOB),
+            DOT,
+            milli_unit(500)
+        ));
+
+        // Two of the three depositors
+        assert_ok!(Loans::sweep_dust_positions(RuntimeOrigin::root(), DOT, 2));
+        assert!(matches!(
+            Loans::dust_sweep_cursor(DOT),
+            Some(SweepCursor::Deposits(Some(_)))
+        ));
+
+        // The last depositor, then there is no borrower left
+        assert_ok!(Loans::sweep_dust_positions(RuntimeOrigin::root(), DOT, 2));
+        assert_eq!(Loans::dust_sweep_cursor(DOT), None);
+
+        assert!(AccountDeposits::<Test>::contains_key(DOT, ALICE));
+        assert!(!AccountDeposits::<Test>::contains_key(DOT, BOB));
+        assert!(!AccountDeposits::<Test>::contains_key(DOT, DAVE));
+        assert!(!AccountBorrows::<Test>::contains_key(DOT, BOB));
+        assert_eq!(Loans::total_reserves(DOT), milli_unit(700));
+        assert_eq!(Assets::balance(DOT, treasury), unit(10) - milli_unit(500));
+
+        assert_noop!(
+            Loans::write_off_dust_positions(
+                RuntimeOrigin::root(),
+                DOT,
+                vec![ALICE; MaxPageSize::get() as usize + 1]
+            ),
+            Error::<Test>::TooManyAccounts
+        );
+    })
+}
@@ -0,0 +1,186 @@
+use crate::{
+    mock::{
+        new_test_ext, Assets, Loans, MockCollateralAdapter, MockPriceFeeder, RuntimeEvent,
+        RuntimeOrigin, System, Test, TreasuryAccount, ADAPTER_CUSTODY, ALICE, BOB, KSM, SKSM, USDT,
+    },
+    tests::unit,
+    AdapterCollateralInfo, Error, Event,
+};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use primitives::Ratio;
+
+const SKSM_COLLATERAL: AdapterCollateralInfo = AdapterCollateralInfo {
+    collateral_factor: Ratio::from_percent(50),
+    liquidation_threshold: Ratio::from_percent(55),
+};
+
+fn register_sksm() {
+    assert_ok!(Loans::set_adapter_collateral(
+        RuntimeOrigin::root(),
+        SKSM,
+        Some(SKSM_COLLATERAL)
+    ));
+}
+
+#[test]
+fn set_adapter_collateral_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            Loans::set_adapter_collateral(RuntimeOrigin::root(), USDT, Some(SKSM_COLLATERAL)),
+            Error::<Test>::CollateralNotSupported
+        );
+        assert_noop!(
+            Loans::set_adapter_collateral(
+                RuntimeOrigin::root(),
+                SKSM,
+                Some(AdapterCollateralInfo {
+                    collateral_factor: Ratio::from_percent(60),
+                    liquidation_threshold: Ratio::from_percent(55),
+                })
+            ),
+            Error::<Test>::InvalidFactor
+        );
+        assert_noop!(
+            Loans::set_adapter_collateral(RuntimeOrigin::root(), SKSM, None),
+            Error::<Test>::AdapterCollateralDoesNotExist
+        );
+
+        register_sksm();
+        System::assert_last_event(RuntimeEvent::Loans(Event::AdapterCollateralUpdated(
+            SKSM,
+            Some(SKSM_COLLATERAL),
+        )));
+
+        assert_ok!(Loans::deposit_adapter_collateral(
+            RuntimeOrigin::signed(ALICE),
+            SKSM,
+            unit(100)
+        ));
+        assert_noop!(
+            Loans::set_adapter_collateral(RuntimeOrigin::root(), SKSM, None),
+            Error::<Test>::AdapterCollateralInUse
+        );
+        assert_ok!(Loans::withdraw_adapter_collateral(
+            RuntimeOrigin::signed(ALICE),
+            SKSM,
+            unit(100)
+        ));
+        assert_ok!(Loans::set_adapter_collateral(
+            RuntimeOrigin::root(),
+            SKSM,
+            None
+        ));
+        assert_eq!(Loans::adapter_collateral(SKSM), None);
+    })
+}
+
+#[test]
+fn adapter_collateral_backs_borrows() {
+    new_test_ext().execute_with(|| {
+        register_sksm();
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+        assert_noop!(
+            Loans::deposit_adapter_collateral(RuntimeOrigin::signed(ALICE), USDT, unit(100)),
+            Error::<Test>::AdapterCollateralDoesNotExist
+        );
+
+        // $100 SKSM backs $50 of borrows
+        assert_ok!(Loans::deposit_adapter_collateral(
+            RuntimeOrigin::signed(ALICE),
+            SKSM,
+            unit(100)
+        ));
+        assert_eq!(Assets::balance(SKSM, ADAPTER_CUSTODY), unit(100));
+        assert_eq!(Loans::account_adapter_collateral(SKSM, ALICE), unit(100));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(51)),
+            Error::<Test>::InsufficientLiquidity
+        );
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(40)));
+
+        // $10 of liquidity left, which is backed by 20 SKSM
+        assert_noop!(
+            Loans::withdraw_adapter_collateral(RuntimeOrigin::signed(ALICE), SKSM, unit(30)),
+            Error::<Test>::InsufficientLiquidity
+        );
+        assert_ok!(Loans::withdraw_adapter_collateral(
+            RuntimeOrigin::signed(ALICE),
+            SKSM,
+            unit(20)
+        ));
+        assert_eq!(Loans::account_adapter_collateral(SKSM, ALICE), unit(80));
+        assert_eq!(Assets::balance(SKSM, ALICE), unit(920));
+    })
+}
+
+#[test]
+fn adapter_scales_the_collateral_factor() {
+    new_test_ext().execute_with(|| {
+        register_sksm();
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+        assert_ok!(Loans::deposit_adapter_collateral(
+            RuntimeOrigin::signed(ALICE),
+            SKSM,
+            unit(100)
+        ));
+
+        // Half of the 50% collateral factor, $100 SKSM backs $25 of borrows
+        MockCollateralAdapter::set_factor_scale(Ratio::from_percent(50));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(26)),
+            Error::<Test>::InsufficientLiquidity
+        );
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(20)));
+        // $5 of liquidity left, which is backed by 40 SKSM
+        assert_noop!(
+            Loans::withdraw_adapter_collateral(RuntimeOrigin::signed(ALICE), SKSM, unit(41)),
+            Error::<Test>::InsufficientLiquidity
+        );
+
+        // Back to the registered factor, $30 of liquidity left
+        MockCollateralAdapter::set_factor_scale(Ratio::from_percent(100));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(31)),
+            Error::<Test>::InsufficientLiquidity
+        );
+        assert_ok!(Loans::withdraw_adapter_collateral(
+            RuntimeOrigin::signed(ALICE),
+            SKSM,
+            unit(50)
+        ));
+    })
+}
+
+#[test]
+fn adapter_collateral_can_be_liquidated() {
+    new_test_ext().execute_with(|| {
+        register_sksm();
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+        assert_ok!(Loans::deposit_adapter_collateral(
+            RuntimeOrigin::signed(ALICE),
+            SKSM,
+            unit(200)
+        ));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
+
+        // ALICE
+        // Collateral                 Borrowed
+        // SKSM  $110 (threshold)     KSM $200
+        MockPriceFeeder::set_price(KSM, 2.into());
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(50),
+            SKSM
+        ));
+
+        // $100 repaid with 10% incentive, 3% of the seized collateral is reserved
+        assert_eq!(Loans::current_borrow_balance(&ALICE, KSM), Ok(unit(50)));
+        assert_eq!(Loans::account_adapter_collateral(SKSM, ALICE), unit(90));
+        assert_eq!(Assets::balance(SKSM, BOB), unit(107));
+        assert_eq!(Assets::balance(SKSM, TreasuryAccount::get()), unit(3));
+        assert_eq!(Assets::balance(SKSM, ADAPTER_CUSTODY), unit(90));
+    })
+}
@@ -0,0 +1,283 @@
+use crate::{
+    mock::{
+        new_test_ext, Loans, MockPriceFeeder, RuntimeEvent, RuntimeOrigin, System, Test, ALICE,
+        BOB, DOT, KSM, SKSM, USDT,
+    },
+    tests::unit,
+    AdapterCollateralInfo, CollateralClass, Error, Event,
+};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use primitives::Ratio;
+
+fn supply_collateral(who: crate::mock::AccountId, asset_id: u32, amount: u128) {
+    assert_ok!(Loans::mint(
+        RuntimeOrigin::signed(who.clone()),
+        asset_id,
+        amount
+    ));
+    assert_ok!(Loans::collateral_asset(
+        RuntimeOrigin::signed(who),
+        asset_id,
+        true
+    ));
+}
+
+fn set_liquid_staking_ceiling(ceiling: u128) {
+    assert_ok!(Loans::set_collateral_class(
+        RuntimeOrigin::root(),
+        KSM,
+        Some(CollateralClass::LiquidStaking)
+    ));
+    assert_ok!(Loans::set_debt_ceiling(
+        RuntimeOrigin::root(),
+        CollateralClass::LiquidStaking,
+        Some(ceiling)
+    ));
+}
+
+#[test]
+fn set_collateral_class_and_debt_ceiling_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            Loans::set_collateral_class(
+                RuntimeOrigin::signed(ALICE),
+                KSM,
+                Some(CollateralClass::LiquidStaking)
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            Loans::set_debt_ceiling(
+                RuntimeOrigin::signed(ALICE),
+                CollateralClass::LiquidStaking,
+                None
+            ),
+            BadOrigin
+        );
+
+        set_liquid_staking_ceiling(unit(60));
+        assert_eq!(
+            Loans::collateral_class(KSM),
+            Some(CollateralClass::LiquidStaking)
+        );
+        assert_eq!(
+            Loans::debt_ceiling(CollateralClass::LiquidStaking),
+            Some(unit(60))
+        );
+        System::assert_last_event(RuntimeEvent::Loans(Event::DebtCeilingUpdated(
+            CollateralClass::LiquidStaking,
+            Some(unit(60)),
+        )));
+
+        assert_ok!(Loans::set_collateral_class(
+            RuntimeOrigin::root(),
+            KSM,
+            None
+        ));
+        System::assert_last_event(RuntimeEvent::Loans(Event::CollateralClassUpdated(
+            KSM, None,
+        )));
+        assert_eq!(Loans::collateral_class(KSM), None);
+    })
+}
+
+#[test]
+fn debt_ceiling_bounds_borrows_backed_by_the_class() {
+    new_test_ext().execute_with(|| {
+        set_liquid_staking_ceiling(unit(60));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), DOT, unit(200)));
+
+        // Half of the collateral of ALICE is in the class, so is half of her debt
+        supply_collateral(ALICE, KSM, unit(200));
+        supply_collateral(ALICE, USDT, unit(200));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(100)));
+        assert_eq!(
+            Loans::account_class_debt(CollateralClass::LiquidStaking, ALICE),
+            unit(50)
+        );
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(50));
+
+        // Liquidity is left, but the class would back $65 of debt
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(30)),
+            Error::<Test>::DebtCeilingExceeded
+        );
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(20)));
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(60));
+
+        // Borrows backed by other collateral are left alone
+        assert_ok!(Loans::set_collateral_class(
+            RuntimeOrigin::root(),
+            KSM,
+            None
+        ));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(10)));
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(0));
+    })
+}
+
+#[test]
+fn debt_ceiling_is_shared_across_borrowers() {
+    new_test_ext().execute_with(|| {
+        set_liquid_staking_ceiling(unit(60));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), DOT, unit(200)));
+        supply_collateral(ALICE, KSM, unit(200));
+        supply_collateral(ALICE, USDT, unit(200));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(120)));
+
+        supply_collateral(BOB, KSM, unit(100));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(BOB), DOT, unit(1)),
+            Error::<Test>::DebtCeilingExceeded
+        );
+
+        // Repaying releases the debt the class backed
+        assert_ok!(Loans::repay_borrow(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            unit(40)
+        ));
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(40));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(BOB), DOT, unit(21)),
+            Error::<Test>::DebtCeilingExceeded
+        );
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(BOB), DOT, unit(20)));
+        assert_eq!(
+            Loans::account_class_debt(CollateralClass::LiquidStaking, BOB),
+            unit(20)
+        );
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(60));
+    })
+}
+
+#[test]
+fn adapter_collateral_counts_towards_its_class() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::set_adapter_collateral(
+            RuntimeOrigin::root(),
+            SKSM,
+            Some(AdapterCollateralInfo {
+                collateral_factor: Ratio::from_percent(50),
+                liquidation_threshold: Ratio::from_percent(55),
+            })
+        ));
+        set_liquid_staking_ceiling(unit(60));
+        assert_ok!(Loans::set_collateral_class(
+            RuntimeOrigin::root(),
+            SKSM,
+            Some(CollateralClass::LiquidStaking)
+        ));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), DOT, unit(200)));
+
+        // KSM and SKSM are in the same class, only USDT isn't
+        supply_collateral(ALICE, KSM, unit(100));
+        supply_collateral(ALICE, USDT, unit(100));
+        assert_ok!(Loans::deposit_adapter_collateral(
+            RuntimeOrigin::signed(ALICE),
+            SKSM,
+            unit(200)
+        ));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(81)),
+            Error::<Test>::DebtCeilingExceeded
+        );
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(80)));
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(60));
+    })
+}
+
+#[test]
+fn taking_collateral_out_is_bound_by_the_ceiling() {
+    new_test_ext().execute_with(|| {
+        set_liquid_staking_ceiling(unit(30));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), DOT, unit(200)));
+        supply_collateral(ALICE, KSM, unit(200));
+        supply_collateral(ALICE, USDT, unit(300));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(50)));
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(20));
+
+        // Less USDT leaves more of the debt to the class
+        assert_noop!(
+            Loans::redeem(RuntimeOrigin::signed(ALICE), USDT, unit(200)),
+            Error::<Test>::DebtCeilingExceeded
+        );
+        assert_noop!(
+            Loans::collateral_asset(RuntimeOrigin::signed(ALICE), USDT, false),
+            Error::<Test>::DebtCeilingExceeded
+        );
+        assert_ok!(Loans::redeem(RuntimeOrigin::signed(ALICE), USDT, unit(100)));
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(25));
+
+        // Collateral of the class can always go
+        assert_ok!(Loans::collateral_asset(
+            RuntimeOrigin::signed(ALICE),
+            KSM,
+            false
+        ));
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(0));
+
+        // Adding collateral never fails on the ceiling, it only keeps the class
+        // from backing more debt
+        assert_ok!(Loans::collateral_asset(
+            RuntimeOrigin::signed(ALICE),
+            KSM,
+            true
+        ));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), KSM, unit(400)));
+        assert_eq!(
+            Loans::class_debt(CollateralClass::LiquidStaking),
+            unit(75) / 2
+        );
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(1)),
+            Error::<Test>::DebtCeilingExceeded
+        );
+    })
+}
+
+#[test]
+fn refresh_class_debts_backfills_and_catches_up_with_prices() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), DOT, unit(200)));
+        supply_collateral(ALICE, KSM, unit(200));
+        supply_collateral(ALICE, USDT, unit(200));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(100)));
+
+        // The debt already backed by KSM isn't counted until ALICE is refreshed
+        set_liquid_staking_ceiling(unit(60));
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(0));
+        assert_noop!(
+            Loans::refresh_class_debts(RuntimeOrigin::root(), vec![ALICE]),
+            BadOrigin
+        );
+        assert_noop!(
+            Loans::refresh_class_debts(RuntimeOrigin::signed(BOB), vec![ALICE; 11]),
+            Error::<Test>::TooManyAccounts
+        );
+        assert_ok!(Loans::refresh_class_debts(
+            RuntimeOrigin::signed(BOB),
+            vec![ALICE]
+        ));
+        System::assert_last_event(RuntimeEvent::Loans(Event::ClassDebtsRefreshed(ALICE)));
+        assert_eq!(
+            Loans::account_class_debt(CollateralClass::LiquidStaking, ALICE),
+            unit(50)
+        );
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(50));
+
+        // KSM going up leaves three quarters of the debt to the class, over its
+        // ceiling, which only stops it from backing more
+        MockPriceFeeder::set_price(KSM, 3.into());
+        assert_ok!(Loans::refresh_class_debts(
+            RuntimeOrigin::signed(BOB),
+            vec![ALICE]
+        ));
+        assert_eq!(Loans::class_debt(CollateralClass::LiquidStaking), unit(75));
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(1)),
+            Error::<Test>::DebtCeilingExceeded
+        );
+    })
+}
@@ -1,6 +1,6 @@
 use crate::tests::Loans;
 use crate::{mock::*, Markets};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::Hooks};
 use primitives::{Rate, Ratio, SECONDS_PER_YEAR};
 use sp_runtime::{
     traits::{CheckedDiv, One, Saturating},
@@ -406,3 +406,84 @@ fn a_market_can_only_accrue_interest_once_in_a_block() {
         );
     })
 }
+
+#[test]
+fn rate_history_keeps_latest_snapshots() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+        assert_ok!(Loans::collateral_asset(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            true
+        ));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(100)));
+
+        // No snapshot until the interval elapsed
+        TimestampPallet::set_timestamp(59_000);
+        Loans::on_initialize(1);
+        assert!(Loans::rate_history(DOT).is_empty());
+
+        for i in 1..=4 {
+            TimestampPallet::set_timestamp(i * 60_000);
+            Loans::on_initialize(i + 1);
+        }
+        assert_eq!(Loans::last_rate_snapshot_time(), 240);
+
+        // Only the latest `MaxRateSnapshots` are kept
+        let history = Loans::get_rate_history(DOT);
+        assert_eq!(
+            history.iter().map(|s| s.0).collect::<Vec<_>>(),
+            vec![120, 180, 240]
+        );
+        let (borrow_rate, supply_rate, exchange_rate, util, ..) =
+            Loans::get_market_status(DOT).unwrap();
+        assert_eq!(
+            history[2],
+            (240, util, borrow_rate, supply_rate, exchange_rate)
+        );
+        assert!(util > Ratio::zero());
+    });
+}
+
+#[test]
+fn simulated_rates_match_the_rates_after_the_action() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+        assert_ok!(Loans::collateral_asset(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            true
+        ));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(50)));
+
+        // Nothing simulated, current rates
+        let (borrow_rate, supply_rate, _, util, ..) = Loans::get_market_status(DOT).unwrap();
+        assert_eq!(
+            Loans::simulate_market_rates(DOT, 0, 0, 0, 0),
+            Ok((util, borrow_rate, supply_rate))
+        );
+
+        let simulated = Loans::simulate_market_rates(DOT, 0, 0, unit(50), 0).unwrap();
+        assert!(simulated.0 > util);
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(50)));
+        let (borrow_rate, supply_rate, _, util, ..) = Loans::get_market_status(DOT).unwrap();
+        assert_eq!(simulated, (util, borrow_rate, supply_rate));
+
+        let simulated = Loans::simulate_market_rates(DOT, unit(100), 0, 0, unit(20)).unwrap();
+        assert!(simulated.0 < util);
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(100)));
+        assert_ok!(Loans::repay_borrow(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            unit(20)
+        ));
+        let (borrow_rate, supply_rate, _, util, ..) = Loans::get_market_status(DOT).unwrap();
+        assert_eq!(simulated, (util, borrow_rate, supply_rate));
+
+        // Can't redeem more than the cash of the market
+        assert_eq!(
+            Loans::simulate_market_rates(DOT, 0, unit(1000), 0, 0),
+            Err(crate::Error::<Test>::InsufficientCash.into())
+        );
+    });
+}
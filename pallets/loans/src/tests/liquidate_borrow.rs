@@ -1,13 +1,19 @@
 use crate::{
     mock::{
-        new_test_ext, Assets, Loans, MockPriceFeeder, RuntimeOrigin, Test, ALICE, BOB, DOT, KSM,
-        USDT,
+        almost_equal, market_mock, milli_unit, new_test_ext, Assets, DefaultAMM, Loans,
+        MockPriceFeeder, RuntimeOrigin, Test, ALICE, BOB, DAVE, DOT, KSM, USDT,
     },
     tests::unit,
-    Error, MarketState,
+    Call, Error, MarketState,
+};
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    dispatch::{DispatchClass, GetDispatchInfo},
+};
+use primitives::{
+    tokens::{CDOT_6_13, LP_DOT_USDT},
+    CurrencyId, Rate, DOT_U,
 };
-use frame_support::{assert_err, assert_noop, assert_ok};
-use primitives::{tokens::CDOT_6_13, Rate, DOT_U};
 use sp_runtime::FixedPointNumber;
 
 #[test]
@@ -257,6 +263,115 @@ fn liquidator_must_not_be_borrower() {
     })
 }
 
+#[test]
+fn dust_borrows_cannot_be_liquidated() {
+    new_test_ext().execute_with(|| {
+        initial_setup();
+        alice_borrows_100_ksm();
+        MockPriceFeeder::set_price(KSM, 2.into());
+        assert_noop!(
+            Loans::liquidate_borrow(RuntimeOrigin::signed(BOB), ALICE, KSM, 1, DOT),
+            Error::<Test>::LiquidationTooSmall
+        );
+    })
+}
+
+#[test]
+fn liquidations_are_operational() {
+    let call = Call::<Test>::liquidate_borrow {
+        borrower: ALICE,
+        liquidation_asset_id: KSM,
+        repay_amount: unit(50),
+        collateral_asset_id: DOT,
+    };
+    assert_eq!(call.get_dispatch_info().class, DispatchClass::Operational);
+}
+
+#[test]
+fn liquidated_lp_collateral_is_unwound() {
+    new_test_ext().execute_with(|| {
+        lp_collateral_setup();
+        // adjust KSM price to make ALICE generate shortfall
+        MockPriceFeeder::set_price(KSM, 3.into());
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(25),
+            LP_DOT_USDT
+        ));
+
+        // incentive = repay KSM value * 1.1 = (25 * 3) * 1.1 = 82.5, LP price = 2
+        // Alice LP collateral: deposit - incentive = 100 - 82.5 / 2 = 58.75
+        // Incentive reserve LP: 41.25 / 1.1 * 0.03 = 1.125
+        // Bob gets the pooled assets of 41.25 - 1.125 = 40.125 LP instead of the LP
+        assert!(almost_equal(
+            Loans::exchange_rate(LP_DOT_USDT)
+                .saturating_mul_int(Loans::account_deposits(LP_DOT_USDT, ALICE).voucher_balance),
+            milli_unit(58_750),
+        ));
+        let incentive_reward_account = Loans::incentive_reward_account_id().unwrap();
+        assert!(almost_equal(
+            Loans::exchange_rate(LP_DOT_USDT).saturating_mul_int(
+                Loans::account_deposits(LP_DOT_USDT, incentive_reward_account).voucher_balance
+            ),
+            milli_unit(1_125),
+        ));
+        assert_eq!(Loans::account_deposits(LP_DOT_USDT, BOB).voucher_balance, 0);
+        assert_eq!(Assets::balance(LP_DOT_USDT, &BOB), 0);
+        assert!(almost_equal(
+            Assets::balance(DOT, &BOB),
+            unit(1000) + milli_unit(40_125)
+        ));
+        assert!(almost_equal(
+            Assets::balance(USDT, &BOB),
+            milli_unit(40_125)
+        ));
+        // the unwound LP left the market
+        assert!(almost_equal(
+            Assets::balance(LP_DOT_USDT, &Loans::account_id()),
+            milli_unit(59_875)
+        ));
+    })
+}
+
+#[test]
+fn liquidated_lp_collateral_is_kept_without_cash() {
+    new_test_ext().execute_with(|| {
+        lp_collateral_setup();
+        // DAVE borrows most of the LP so that the market can't redeem the seized collateral
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(DAVE), USDT, unit(500)));
+        assert_ok!(Loans::collateral_asset(
+            RuntimeOrigin::signed(DAVE),
+            USDT,
+            true
+        ));
+        assert_ok!(Loans::borrow(
+            RuntimeOrigin::signed(DAVE),
+            LP_DOT_USDT,
+            unit(90)
+        ));
+
+        MockPriceFeeder::set_price(KSM, 3.into());
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(25),
+            LP_DOT_USDT
+        ));
+
+        // Bob receives the vouchers of 40.125 LP as for any other collateral
+        assert!(almost_equal(
+            Loans::exchange_rate(LP_DOT_USDT)
+                .saturating_mul_int(Loans::account_deposits(LP_DOT_USDT, BOB).voucher_balance),
+            milli_unit(40_125),
+        ));
+        assert_eq!(Assets::balance(DOT, &BOB), unit(1000));
+        assert_eq!(Assets::balance(USDT, &BOB), 0);
+    })
+}
+
 fn alice_borrows_100_ksm() {
     assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
 }
@@ -286,3 +401,44 @@ fn initial_setup() {
         vec![CDOT_6_13]
     ));
 }
+
+fn lp_collateral_setup() {
+    const PLP_DOT_USDT: CurrencyId = 2199;
+    assert_ok!(Assets::force_create(
+        RuntimeOrigin::root(),
+        LP_DOT_USDT,
+        ALICE,
+        true,
+        1
+    ));
+    // each LP is backed by 1 DOT and 1 USDT
+    assert_ok!(DefaultAMM::create_pool(
+        RuntimeOrigin::signed(ALICE),
+        (DOT, USDT),
+        (unit(100), unit(100)),
+        ALICE,
+        LP_DOT_USDT
+    ));
+    MockPriceFeeder::set_price(LP_DOT_USDT, 2.into());
+    assert_ok!(Loans::add_market(
+        RuntimeOrigin::root(),
+        LP_DOT_USDT,
+        market_mock(PLP_DOT_USDT)
+    ));
+    assert_ok!(Loans::activate_market(RuntimeOrigin::root(), LP_DOT_USDT));
+
+    // Bob deposits 200 KSM
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+    // Alice deposits $200 of LP as collateral and borrows $50 of KSM
+    assert_ok!(Loans::mint(
+        RuntimeOrigin::signed(ALICE),
+        LP_DOT_USDT,
+        unit(100)
+    ));
+    assert_ok!(Loans::collateral_asset(
+        RuntimeOrigin::signed(ALICE),
+        LP_DOT_USDT,
+        true
+    ));
+    assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(50)));
+}
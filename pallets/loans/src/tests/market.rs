@@ -1,7 +1,7 @@
 use crate::{
     mock::{
-        market_mock, new_test_ext, Loans, RuntimeOrigin, Test, ACTIVE_MARKET_MOCK, ALICE, DOT,
-        MARKET_MOCK, PDOT, PUSDT, SDOT,
+        market_mock, new_test_ext, Loans, RuntimeOrigin, System, Test, ACTIVE_MARKET_MOCK, ALICE,
+        DOT, MARKET_MOCK, PDOT, PUSDT, SDOT,
     },
     Error, InterestRateModel, MarketState,
 };
@@ -150,6 +150,86 @@ fn add_market_ensures_that_market_does_not_exist() {
     })
 }
 
+#[test]
+fn propose_market_has_sanity_checks_for_rate_models() {
+    rate_model_sanity_check!(propose_market);
+}
+
+#[test]
+fn propose_market_ensures_that_market_is_new() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Loans::propose_market(RuntimeOrigin::root(), DOT, market_mock(SDOT)),
+            Error::<Test>::MarketAlreadyExists
+        );
+        assert_noop!(
+            Loans::propose_market(RuntimeOrigin::root(), SDOT, ACTIVE_MARKET_MOCK),
+            Error::<Test>::NewMarketMustHavePendingState
+        );
+        assert_ok!(Loans::propose_market(
+            RuntimeOrigin::root(),
+            SDOT,
+            MARKET_MOCK
+        ));
+        assert_noop!(
+            Loans::propose_market(RuntimeOrigin::root(), SDOT, MARKET_MOCK),
+            Error::<Test>::MarketProposalAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn proposed_market_is_activated_after_review() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Loans::propose_market(
+            RuntimeOrigin::root(),
+            SDOT,
+            MARKET_MOCK
+        ));
+        assert_eq!(Loans::market_proposal(SDOT).unwrap().activatable_at, 11);
+        assert!(Loans::market(SDOT).is_err());
+
+        System::set_block_number(10);
+        assert_noop!(
+            Loans::activate_market(RuntimeOrigin::root(), SDOT),
+            Error::<Test>::MarketUnderReview
+        );
+
+        System::set_block_number(11);
+        assert_ok!(Loans::activate_market(RuntimeOrigin::root(), SDOT));
+        assert_eq!(Loans::market(SDOT).unwrap().state, MarketState::Active);
+        assert!(Loans::market_proposal(SDOT).is_none());
+    })
+}
+
+#[test]
+fn proposed_market_can_be_vetoed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::propose_market(
+            RuntimeOrigin::root(),
+            SDOT,
+            MARKET_MOCK
+        ));
+        assert_noop!(
+            Loans::veto_market(RuntimeOrigin::signed(ALICE), SDOT),
+            BadOrigin
+        );
+        assert_ok!(Loans::veto_market(RuntimeOrigin::root(), SDOT));
+        assert!(Loans::market_proposal(SDOT).is_none());
+
+        System::set_block_number(11);
+        assert_noop!(
+            Loans::activate_market(RuntimeOrigin::root(), SDOT),
+            Error::<Test>::MarketDoesNotExist
+        );
+        assert_noop!(
+            Loans::veto_market(RuntimeOrigin::root(), SDOT),
+            Error::<Test>::MarketProposalDoesNotExist
+        );
+    })
+}
+
 #[test]
 fn force_update_market_can_only_be_used_by_root() {
     new_test_ext().execute_with(|| {
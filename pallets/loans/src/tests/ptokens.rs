@@ -8,9 +8,11 @@ use crate::{
 };
 use frame_support::{
     assert_err, assert_noop, assert_ok,
+    storage::with_transaction,
     traits::tokens::fungibles::{Inspect, Transfer},
 };
-use sp_runtime::{FixedPointNumber, TokenError};
+use pallet_traits::ProtocolConversion;
+use sp_runtime::{DispatchResult, FixedPointNumber, TokenError, TransactionOutcome};
 
 #[test]
 fn trait_inspect_methods_works() {
@@ -199,3 +201,55 @@ fn transfer_ptokens_under_collateral_works() {
         assert_ok!(Loans::redeem_allowed(HKO, &ALICE, unit(20) * 50,));
     })
 }
+
+#[test]
+fn protocol_conversion_should_mint_and_redeem_ptokens() {
+    new_test_ext().execute_with(|| {
+        let conversions = <Loans as ProtocolConversion<_, _, _>>::conversions();
+        assert!(conversions.contains(&(HKO, PHKO)));
+        assert!(conversions.contains(&(PHKO, HKO)));
+
+        // exchange rate is 0.02
+        assert_eq!(
+            <Loans as ProtocolConversion<_, _, _>>::quote_out((HKO, PHKO), unit(100)),
+            Ok(unit(100) * 50)
+        );
+        assert_ok!(with_transaction(
+            || -> TransactionOutcome<DispatchResult> {
+                assert_eq!(
+                    <Loans as ProtocolConversion<_, _, _>>::convert(&DAVE, (HKO, PHKO), unit(100)),
+                    Ok(unit(100) * 50)
+                );
+                TransactionOutcome::Commit(Ok(()))
+            }
+        ));
+        assert_eq!(Loans::balance(PHKO, &DAVE), unit(100) * 50);
+        assert_eq!(
+            <Loans as ProtocolConversion<_, _, _>>::reducible_balance(&DAVE, PHKO),
+            Some(unit(100) * 50)
+        );
+        assert_eq!(
+            <Loans as ProtocolConversion<_, _, _>>::reducible_balance(&DAVE, HKO),
+            None
+        );
+
+        assert_eq!(
+            <Loans as ProtocolConversion<_, _, _>>::quote_out((PHKO, HKO), unit(40) * 50),
+            Ok(unit(40))
+        );
+        assert_ok!(with_transaction(
+            || -> TransactionOutcome<DispatchResult> {
+                assert_eq!(
+                    <Loans as ProtocolConversion<_, _, _>>::convert(
+                        &DAVE,
+                        (PHKO, HKO),
+                        unit(40) * 50
+                    ),
+                    Ok(unit(40))
+                );
+                TransactionOutcome::Commit(Ok(()))
+            }
+        ));
+        assert_eq!(Loans::balance(PHKO, &DAVE), unit(60) * 50);
+    })
+}
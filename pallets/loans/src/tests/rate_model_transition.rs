@@ -0,0 +1,151 @@
+use crate::{
+    mock::{
+        new_test_ext, Loans, RuntimeEvent, RuntimeOrigin, System, Test, _run_to_block, ALICE, DOT,
+        KSM,
+    },
+    Error, Event, InterestRateModel, RateModelTransition,
+};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use primitives::{Rate, Ratio};
+use sp_runtime::FixedPointNumber;
+
+fn new_rate_model() -> InterestRateModel {
+    InterestRateModel::new_jump_model(
+        Rate::saturating_from_rational(6, 100),
+        Rate::saturating_from_rational(15, 100),
+        Rate::saturating_from_rational(35, 100),
+        Ratio::from_percent(80),
+    )
+}
+
+#[test]
+fn transition_rate_model_works() {
+    new_test_ext().execute_with(|| {
+        let old_rate_model = Loans::market(DOT).unwrap().rate_model;
+        assert_noop!(
+            Loans::transition_rate_model(RuntimeOrigin::signed(ALICE), DOT, new_rate_model(), 10),
+            BadOrigin
+        );
+        assert_noop!(
+            Loans::transition_rate_model(RuntimeOrigin::root(), DOT, new_rate_model(), 0),
+            Error::<Test>::InvalidTransitionPeriod
+        );
+
+        assert_ok!(Loans::transition_rate_model(
+            RuntimeOrigin::root(),
+            DOT,
+            new_rate_model(),
+            10
+        ));
+        assert_eq!(Loans::market(DOT).unwrap().rate_model, new_rate_model());
+        assert_eq!(
+            Loans::rate_model_transition(DOT),
+            Some(RateModelTransition {
+                from: old_rate_model,
+                start: 0,
+                end: 10,
+            })
+        );
+        System::assert_last_event(RuntimeEvent::Loans(Event::RateModelTransitionStarted(
+            DOT,
+            old_rate_model,
+            new_rate_model(),
+            10,
+        )));
+
+        // Only one transition at a time
+        assert_noop!(
+            Loans::transition_rate_model(RuntimeOrigin::root(), DOT, old_rate_model, 10),
+            Error::<Test>::RateModelTransitionInProgress
+        );
+    })
+}
+
+#[test]
+fn borrow_rate_moves_linearly_over_the_transition() {
+    new_test_ext().execute_with(|| {
+        // Nothing is borrowed, so the rate is the base rate of the models: 2% -> 6%
+        assert_ok!(Loans::transition_rate_model(
+            RuntimeOrigin::root(),
+            DOT,
+            new_rate_model(),
+            10
+        ));
+        let market = Loans::market(DOT).unwrap();
+        assert_eq!(
+            Loans::current_borrow_rate(DOT, &market, Ratio::from_percent(0)),
+            Some(Rate::saturating_from_rational(2, 100))
+        );
+
+        _run_to_block(5);
+        assert_ok!(Loans::accrue_interest(DOT));
+        assert_eq!(
+            Loans::borrow_rate(DOT),
+            Rate::saturating_from_rational(4, 100)
+        );
+        assert!(Loans::rate_model_transition(DOT).is_some());
+
+        _run_to_block(10);
+        assert_ok!(Loans::accrue_interest(DOT));
+        assert_eq!(
+            Loans::borrow_rate(DOT),
+            Rate::saturating_from_rational(6, 100)
+        );
+        assert_eq!(Loans::rate_model_transition(DOT), None);
+        System::assert_has_event(RuntimeEvent::Loans(Event::RateModelTransitionCompleted(
+            DOT,
+            new_rate_model(),
+        )));
+    })
+}
+
+#[test]
+fn rate_model_transition_can_go_down() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::update_rate_model(
+            RuntimeOrigin::root(),
+            KSM,
+            new_rate_model()
+        ));
+        let old_rate_model = Loans::market(DOT).unwrap().rate_model;
+        assert_ok!(Loans::transition_rate_model(
+            RuntimeOrigin::root(),
+            KSM,
+            old_rate_model,
+            4
+        ));
+
+        _run_to_block(2);
+        assert_ok!(Loans::accrue_interest(KSM));
+        assert_eq!(
+            Loans::borrow_rate(KSM),
+            Rate::saturating_from_rational(4, 100)
+        );
+    })
+}
+
+#[test]
+fn update_rate_model_cancels_the_transition() {
+    new_test_ext().execute_with(|| {
+        let old_rate_model = Loans::market(DOT).unwrap().rate_model;
+        assert_ok!(Loans::transition_rate_model(
+            RuntimeOrigin::root(),
+            DOT,
+            new_rate_model(),
+            10
+        ));
+        assert_ok!(Loans::update_rate_model(
+            RuntimeOrigin::root(),
+            DOT,
+            old_rate_model
+        ));
+        assert_eq!(Loans::rate_model_transition(DOT), None);
+
+        _run_to_block(5);
+        assert_ok!(Loans::accrue_interest(DOT));
+        assert_eq!(
+            Loans::borrow_rate(DOT),
+            Rate::saturating_from_rational(2, 100)
+        );
+    })
+}
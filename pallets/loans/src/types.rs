@@ -1,7 +1,8 @@
 use crate::InterestRateModel;
 use frame_support::pallet_prelude::*;
-use primitives::{CurrencyId, Rate, Ratio};
+use primitives::{CurrencyId, Rate, Ratio, Timestamp};
 use scale_info::TypeInfo;
+use sp_runtime::FixedU128;
 
 /// Container for borrow balance information
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo)]
@@ -21,6 +22,26 @@ pub struct EarnedSnapshot<Balance> {
     pub exchange_rate_prior: Rate,
 }
 
+/// Where the dust sweep of a market resumes, each phase goes over the accounts
+/// after the given one, or from the first one with `None`
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub enum SweepCursor<AccountId> {
+    /// Going over the depositors of the market
+    Deposits(Option<AccountId>),
+    /// Going over the borrowers of the market, once the depositors are done
+    Borrows(Option<AccountId>),
+}
+
+/// Utilization and rates of a market at `timestamp`
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+pub struct RateSnapshot {
+    pub timestamp: Timestamp,
+    pub utilization: Ratio,
+    pub borrow_rate: Rate,
+    pub supply_rate: Rate,
+    pub exchange_rate: Rate,
+}
+
 /// Deposit information
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo)]
 pub struct Deposits<Balance> {
@@ -70,6 +91,67 @@ pub struct Market<Balance> {
     pub ptoken_id: CurrencyId,
 }
 
+/// Market waiting out its review period before it can be listed
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MarketProposal<Balance, BlockNumber> {
+    pub market: Market<Balance>,
+    /// First block the market can be activated at
+    pub activatable_at: BlockNumber,
+}
+
+/// Rate model a market is moving away from. Over the transition the borrow rate moves
+/// linearly from the one of `from` to the one of the model of the market.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RateModelTransition<BlockNumber> {
+    pub from: InterestRateModel,
+    /// Block the transition started at
+    pub start: BlockNumber,
+    /// Block the market fully uses its model from
+    pub end: BlockNumber,
+}
+
+/// Factors of an asset kept by the collateral adapter, which unlike a market asset
+/// can't be borrowed
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct AdapterCollateralInfo {
+    /// The collateral utilization ratio
+    pub collateral_factor: Ratio,
+    /// The ratio the collateral is valued at when checking for liquidations
+    pub liquidation_threshold: Ratio,
+}
+
+/// Class of derivative assets sharing a debt ceiling when used as collateral
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum CollateralClass {
+    /// Liquid staking sTokens
+    LiquidStaking,
+    /// Crowdloan cTokens
+    Crowdloan,
+    /// AMM liquidity pool tokens
+    LpToken,
+    /// Assets bridged from other chains
+    Bridged,
+}
+
+impl CollateralClass {
+    pub const ALL: [CollateralClass; 4] = [
+        CollateralClass::LiquidStaking,
+        CollateralClass::Crowdloan,
+        CollateralClass::LpToken,
+        CollateralClass::Bridged,
+    ];
+}
+
+/// Limits a borrower sets on their own position, checked on their own calls only, and
+/// the health factor below which monitoring services should alert them
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub struct RiskSettings {
+    /// Most of the value of their collateral they may borrow
+    pub max_ltv: Option<Ratio>,
+    /// Health factor below which the borrower wants to be alerted
+    pub alert_threshold: Option<FixedU128>,
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
 pub struct RewardMarketState<BlockNumber, Balance> {
     pub index: Balance,
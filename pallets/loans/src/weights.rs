@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2022-05-30, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("kerria-dev"), DB CACHE: 1024
+//!
+//! The weights marked NOT BENCHMARKED YET were added by hand from the storage accesses of
+//! their calls and are estimates until this file is regenerated.
 
 // Executed Command:
 // ./target/release/parallel
@@ -43,6 +46,10 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions needed for pallet_loans.
 pub trait WeightInfo {
 	fn add_market() -> Weight;
@@ -66,6 +73,21 @@ pub trait WeightInfo {
 	fn add_reserves() -> Weight;
 	fn reduce_reserves() -> Weight;
 	fn update_liquidation_free_collateral() -> Weight;
+	fn accrue_market_interest() -> Weight;
+	fn update_market_reward_indexes() -> Weight;
+	fn write_off_dust_positions(n: u32, ) -> Weight;
+	fn sweep_dust_positions(n: u32, ) -> Weight;
+	fn set_adapter_collateral() -> Weight;
+	fn deposit_adapter_collateral() -> Weight;
+	fn withdraw_adapter_collateral() -> Weight;
+	fn set_risk_settings() -> Weight;
+	fn set_borrow_limit() -> Weight;
+	fn propose_market() -> Weight;
+	fn veto_market() -> Weight;
+	fn set_collateral_class() -> Weight;
+	fn set_debt_ceiling() -> Weight;
+	fn refresh_class_debts(n: u32, ) -> Weight;
+	fn transition_rate_model() -> Weight;
 }
 
 /// Weights for pallet_loans using the Substrate node and recommended hardware.
@@ -79,6 +101,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn add_market() -> Weight {
 		Weight::from_ref_time(61_518_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -86,6 +109,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn activate_market() -> Weight {
 		Weight::from_ref_time(43_556_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -93,6 +117,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn update_rate_model() -> Weight {
 		Weight::from_ref_time(45_600_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -100,6 +125,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn update_market() -> Weight {
 		Weight::from_ref_time(45_777_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -108,6 +134,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn force_update_market() -> Weight {
 		Weight::from_ref_time(56_536_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -115,6 +142,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn add_reward() -> Weight {
 		Weight::from_ref_time(98_928_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -122,6 +150,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn withdraw_missing_reward() -> Weight {
 		Weight::from_ref_time(81_317_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -133,6 +162,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn update_market_reward_speed() -> Weight {
 		Weight::from_ref_time(86_330_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -153,6 +183,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn claim_reward() -> Weight {
 		Weight::from_ref_time(234_251_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(16 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -172,6 +203,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn claim_reward_for_market() -> Weight {
 		Weight::from_ref_time(214_273_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(14 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -193,6 +225,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn mint() -> Weight {
 		Weight::from_ref_time(250_616_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(18 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(18 as u64))
 			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -217,6 +250,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn borrow() -> Weight {
 		Weight::from_ref_time(356_136_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(24 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(24 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -237,6 +271,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn redeem() -> Weight {
 		Weight::from_ref_time(259_224_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(17 as u64))
 			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -258,6 +293,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn redeem_all() -> Weight {
 		Weight::from_ref_time(277_962_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(18 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(18 as u64))
 			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -276,6 +312,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn repay_borrow() -> Weight {
 		Weight::from_ref_time(226_122_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(15 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -294,6 +331,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn repay_borrow_all() -> Weight {
 		Weight::from_ref_time(225_556_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(15 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -302,6 +340,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn collateral_asset() -> Weight {
 		Weight::from_ref_time(73_911_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -329,6 +368,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn liquidate_borrow() -> Weight {
 		Weight::from_ref_time(637_956_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(40 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(40 as u64))
 			.saturating_add(T::DbWeight::get().writes(20 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -340,6 +380,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn add_reserves() -> Weight {
 		Weight::from_ref_time(146_456_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -350,6 +391,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn reduce_reserves() -> Weight {
 		Weight::from_ref_time(131_943_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -357,8 +399,231 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn update_liquidation_free_collateral() -> Weight {
 		Weight::from_ref_time(37_654_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans LastAccruedInterestBlock (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:0)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans UtilizationRatio (r:0 w:1)
+	// Storage: Loans BorrowRate (r:0 w:1)
+	// Storage: Loans SupplyRate (r:0 w:1)
+	// Storage: Loans ExchangeRate (r:0 w:1)
+	fn accrue_market_interest() -> Weight {
+		Weight::from_ref_time(148_372_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(14 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans RewardSupplyState (r:1 w:1)
+	// Storage: Loans RewardBorrowState (r:1 w:1)
+	// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	// Storage: Loans TotalSupply (r:1 w:0)
+	// Storage: Loans TotalBorrows (r:1 w:0)
+	// Storage: Loans BorrowIndex (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn update_market_reward_indexes() -> Weight {
+		Weight::from_ref_time(119_846_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:1)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans RewardSupplyState (r:1 w:1)
+	// Storage: Loans RewardBorrowState (r:1 w:1)
+	// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans AccountBorrows (r:50 w:50)
+	// Storage: Loans RewardBorrowerIndex (r:50 w:50)
+	// Storage: Loans AccountDeposits (r:50 w:50)
+	// Storage: Loans AccountEarned (r:0 w:50)
+	// Storage: Loans RewardSupplierIndex (r:50 w:50)
+	// Storage: Loans RewardAccrued (r:50 w:50)
+	// Storage: Assets Account (r:2 w:2)
+	/// The range of component `n` is `[1, 50]`.
+	fn write_off_dust_positions(n: u32, ) -> Weight {
+		Weight::from_ref_time(81_407_000 as u64)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(52_318_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(15 as u64))
+			.saturating_add(T::DbWeight::get().reads((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:1)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans RewardSupplyState (r:1 w:1)
+	// Storage: Loans RewardBorrowState (r:1 w:1)
+	// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans DustSweepCursor (r:1 w:1)
+	// Storage: Loans AccountDeposits (r:51 w:50)
+	// Storage: Loans AccountBorrows (r:51 w:50)
+	// Storage: Loans RewardBorrowerIndex (r:50 w:50)
+	// Storage: Loans AccountEarned (r:0 w:50)
+	// Storage: Loans RewardSupplierIndex (r:50 w:50)
+	// Storage: Loans RewardAccrued (r:50 w:50)
+	// Storage: Assets Account (r:2 w:2)
+	/// The range of component `n` is `[1, 50]`.
+	fn sweep_dust_positions(n: u32, ) -> Weight {
+		Weight::from_ref_time(86_935_000 as u64)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(55_104_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(18 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(18 as u64))
+			.saturating_add(T::DbWeight::get().reads((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:1 w:0)
+	// Storage: Loans AdapterCollaterals (r:0 w:1)
+	fn set_adapter_collateral() -> Weight {
+		Weight::from_ref_time(27_775_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans AdapterCollaterals (r:1 w:0)
+	// Storage: Loans AccountAdapterCollaterals (r:1 w:1)
+	fn deposit_adapter_collateral() -> Weight {
+		Weight::from_ref_time(35_393_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans AdapterCollaterals (r:2 w:0)
+	// Storage: Loans AccountAdapterCollaterals (r:2 w:1)
+	// Storage: Loans Markets (r:6 w:0)
+	// Storage: Prices EmergencyPrice (r:2 w:0)
+	// Storage: Loans AccountBorrows (r:5 w:0)
+	// Storage: Loans BorrowIndex (r:1 w:0)
+	// Storage: Loans AccountDeposits (r:5 w:0)
+	// Storage: Loans LiquidationFreeCollaterals (r:1 w:0)
+	fn withdraw_adapter_collateral() -> Weight {
+		Weight::from_ref_time(119_021_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(25 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(25 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: Loans AccountRiskSettings (r:0 w:1)
+	fn set_risk_settings() -> Weight {
+		Weight::from_ref_time(19_473_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans AccountBorrowLimits (r:0 w:1)
+	fn set_borrow_limit() -> Weight {
+		Weight::from_ref_time(27_841_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans MarketProposals (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	fn propose_market() -> Weight {
+		Weight::from_ref_time(38_212_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans MarketProposals (r:1 w:1)
+	fn veto_market() -> Weight {
+		Weight::from_ref_time(21_530_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans CollateralClasses (r:0 w:1)
+	fn set_collateral_class() -> Weight {
+		Weight::from_ref_time(18_904_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans DebtCeilings (r:0 w:1)
+	fn set_debt_ceiling() -> Weight {
+		Weight::from_ref_time(18_517_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans AdapterCollaterals (r:1 w:0)
+	// Storage: Loans CollateralClasses (r:1 w:0)
+	// Storage: Loans AccountDeposits (r:1 w:0)
+	// Storage: Loans ExchangeRate (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	// Storage: Loans AccountBorrows (r:1 w:0)
+	// Storage: Loans BorrowIndex (r:1 w:0)
+	// Storage: Loans AccountClassDebts (r:4 w:1)
+	// Storage: Loans ClassDebts (r:1 w:1)
+	fn refresh_class_debts(n: u32, ) -> Weight {
+		Weight::from_ref_time(24_530_000 as u64)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(41_206_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((12 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((12 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Loans RateModelTransitions (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:0)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans UtilizationRatio (r:0 w:1)
+	// Storage: Loans BorrowRate (r:0 w:1)
+	// Storage: Loans SupplyRate (r:0 w:1)
+	// Storage: Loans ExchangeRate (r:0 w:1)
+	fn transition_rate_model() -> Weight {
+		Weight::from_ref_time(64_318_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -651,4 +916,211 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans LastAccruedInterestBlock (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:0)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans UtilizationRatio (r:0 w:1)
+	// Storage: Loans BorrowRate (r:0 w:1)
+	// Storage: Loans SupplyRate (r:0 w:1)
+	// Storage: Loans ExchangeRate (r:0 w:1)
+	fn accrue_market_interest() -> Weight {
+		Weight::from_ref_time(148_372_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().writes(13 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans RewardSupplyState (r:1 w:1)
+	// Storage: Loans RewardBorrowState (r:1 w:1)
+	// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	// Storage: Loans TotalSupply (r:1 w:0)
+	// Storage: Loans TotalBorrows (r:1 w:0)
+	// Storage: Loans BorrowIndex (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn update_market_reward_indexes() -> Weight {
+		Weight::from_ref_time(119_846_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:1)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans RewardSupplyState (r:1 w:1)
+	// Storage: Loans RewardBorrowState (r:1 w:1)
+	// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans AccountBorrows (r:50 w:50)
+	// Storage: Loans RewardBorrowerIndex (r:50 w:50)
+	// Storage: Loans AccountDeposits (r:50 w:50)
+	// Storage: Loans AccountEarned (r:0 w:50)
+	// Storage: Loans RewardSupplierIndex (r:50 w:50)
+	// Storage: Loans RewardAccrued (r:50 w:50)
+	// Storage: Assets Account (r:2 w:2)
+	/// The range of component `n` is `[1, 50]`.
+	fn write_off_dust_positions(n: u32, ) -> Weight {
+		Weight::from_ref_time(81_407_000 as u64)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(52_318_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().reads((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:1)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans RewardSupplyState (r:1 w:1)
+	// Storage: Loans RewardBorrowState (r:1 w:1)
+	// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans DustSweepCursor (r:1 w:1)
+	// Storage: Loans AccountDeposits (r:51 w:50)
+	// Storage: Loans AccountBorrows (r:51 w:50)
+	// Storage: Loans RewardBorrowerIndex (r:50 w:50)
+	// Storage: Loans AccountEarned (r:0 w:50)
+	// Storage: Loans RewardSupplierIndex (r:50 w:50)
+	// Storage: Loans RewardAccrued (r:50 w:50)
+	// Storage: Assets Account (r:2 w:2)
+	/// The range of component `n` is `[1, 50]`.
+	fn sweep_dust_positions(n: u32, ) -> Weight {
+		Weight::from_ref_time(86_935_000 as u64)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(55_104_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(18 as u64))
+			.saturating_add(RocksDbWeight::get().reads((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:1 w:0)
+	// Storage: Loans AdapterCollaterals (r:0 w:1)
+	fn set_adapter_collateral() -> Weight {
+		Weight::from_ref_time(27_775_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans AdapterCollaterals (r:1 w:0)
+	// Storage: Loans AccountAdapterCollaterals (r:1 w:1)
+	fn deposit_adapter_collateral() -> Weight {
+		Weight::from_ref_time(35_393_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans AdapterCollaterals (r:2 w:0)
+	// Storage: Loans AccountAdapterCollaterals (r:2 w:1)
+	// Storage: Loans Markets (r:6 w:0)
+	// Storage: Prices EmergencyPrice (r:2 w:0)
+	// Storage: Loans AccountBorrows (r:5 w:0)
+	// Storage: Loans BorrowIndex (r:1 w:0)
+	// Storage: Loans AccountDeposits (r:5 w:0)
+	// Storage: Loans LiquidationFreeCollaterals (r:1 w:0)
+	fn withdraw_adapter_collateral() -> Weight {
+		Weight::from_ref_time(119_021_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(25 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: Loans AccountRiskSettings (r:0 w:1)
+	fn set_risk_settings() -> Weight {
+		Weight::from_ref_time(19_473_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans AccountBorrowLimits (r:0 w:1)
+	fn set_borrow_limit() -> Weight {
+		Weight::from_ref_time(27_841_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans MarketProposals (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	fn propose_market() -> Weight {
+		Weight::from_ref_time(38_212_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans MarketProposals (r:1 w:1)
+	fn veto_market() -> Weight {
+		Weight::from_ref_time(21_530_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans CollateralClasses (r:0 w:1)
+	fn set_collateral_class() -> Weight {
+		Weight::from_ref_time(18_904_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans DebtCeilings (r:0 w:1)
+	fn set_debt_ceiling() -> Weight {
+		Weight::from_ref_time(18_517_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans AdapterCollaterals (r:1 w:0)
+	// Storage: Loans CollateralClasses (r:1 w:0)
+	// Storage: Loans AccountDeposits (r:1 w:0)
+	// Storage: Loans ExchangeRate (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	// Storage: Loans AccountBorrows (r:1 w:0)
+	// Storage: Loans BorrowIndex (r:1 w:0)
+	// Storage: Loans AccountClassDebts (r:4 w:1)
+	// Storage: Loans ClassDebts (r:1 w:1)
+	fn refresh_class_debts(n: u32, ) -> Weight {
+		Weight::from_ref_time(24_530_000 as u64)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(41_206_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((12 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Loans RateModelTransitions (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:0)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans UtilizationRatio (r:0 w:1)
+	// Storage: Loans BorrowRate (r:0 w:1)
+	// Storage: Loans SupplyRate (r:0 w:1)
+	// Storage: Loans ExchangeRate (r:0 w:1)
+	fn transition_rate_model() -> Weight {
+		Weight::from_ref_time(64_318_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
+	}
 }
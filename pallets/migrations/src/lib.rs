@@ -0,0 +1,211 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Migrations pallet
+//!
+//! ## Overview
+//!
+//! Runs storage migrations which don't fit in a block over several blocks, in the weight
+//! left by `on_idle`. Migrations run one after the other in the order they are listed,
+//! each one stepping from a cursor it returns until it's completed. Progress is recorded
+//! per migration so a migration never runs twice, even if it stays listed in the runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{GetStorageVersion, PalletInfoAccess, StorageVersion},
+};
+use frame_system::pallet_prelude::*;
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// A storage migration which can be split over several blocks
+pub trait SteppedMigration {
+    /// Unique identifier, the progress of the migration is recorded under it
+    fn id(&self) -> &'static [u8];
+
+    /// Whether the migration has to run, checked once before its first step
+    fn should_run(&self) -> bool {
+        true
+    }
+
+    /// Migrate from `cursor`, `None` for the first step, using at most `limit` weight.
+    /// Returns the used weight and the cursor to resume from, `None` once completed.
+    fn step(&self, cursor: Option<Vec<u8>>, limit: Weight) -> (Weight, Option<Vec<u8>>);
+
+    /// Called once the migration is completed
+    fn on_completed(&self) -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Runs `Inner` if the storage version of `P` is `FROM` and sets it to `TO` once completed
+pub struct VersionedMigration<P, Inner, const FROM: u16, const TO: u16>(PhantomData<(P, Inner)>);
+
+impl<P, Inner, const FROM: u16, const TO: u16> Default for VersionedMigration<P, Inner, FROM, TO> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P, Inner, const FROM: u16, const TO: u16> SteppedMigration
+    for VersionedMigration<P, Inner, FROM, TO>
+where
+    P: GetStorageVersion + PalletInfoAccess,
+    Inner: SteppedMigration + Default,
+{
+    fn id(&self) -> &'static [u8] {
+        Inner::default().id()
+    }
+
+    fn should_run(&self) -> bool {
+        P::on_chain_storage_version() == FROM && Inner::default().should_run()
+    }
+
+    fn step(&self, cursor: Option<Vec<u8>>, limit: Weight) -> (Weight, Option<Vec<u8>>) {
+        Inner::default().step(cursor, limit)
+    }
+
+    fn on_completed(&self) -> Weight {
+        StorageVersion::new(TO).put::<P>();
+        Inner::default().on_completed()
+    }
+}
+
+/// Progress of a migration
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum MigrationStatus {
+    /// Stepped `steps` times, resumes from `cursor`
+    Ongoing { cursor: Vec<u8>, steps: u32 },
+    /// Completed in `steps` steps, 0 if it was skipped
+    Completed { steps: u32 },
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Migrations to run, in order
+        type Migrations: Get<Vec<Box<dyn SteppedMigration>>>;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event {
+        /// Migration started
+        /// [id]
+        MigrationStarted(Vec<u8>),
+        /// Migration made progress
+        /// [id, steps]
+        MigrationStepped(Vec<u8>, u32),
+        /// Migration completed
+        /// [id, steps]
+        MigrationCompleted(Vec<u8>, u32),
+        /// Migration doesn't have to run
+        /// [id]
+        MigrationSkipped(Vec<u8>),
+    }
+
+    /// Progress of the migrations
+    /// Id -> MigrationStatus
+    #[pallet::storage]
+    #[pallet::getter(fn migration_status)]
+    pub type MigrationProgress<T: Config> =
+        StorageMap<_, Blake2_128Concat, Vec<u8>, MigrationStatus>;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            // Reading and updating the status of a migration
+            let overhead = T::DbWeight::get().reads_writes(1, 1);
+            let mut consumed = Weight::zero();
+
+            for migration in T::Migrations::get() {
+                consumed = consumed.saturating_add(T::DbWeight::get().reads(1));
+                if consumed.saturating_add(overhead).any_gt(remaining_weight) {
+                    break;
+                }
+                let id = migration.id().to_vec();
+                let (cursor, steps) = match Self::migration_status(&id) {
+                    Some(MigrationStatus::Completed { .. }) => continue,
+                    Some(MigrationStatus::Ongoing { cursor, steps }) => (Some(cursor), steps),
+                    None if !migration.should_run() => {
+                        consumed = consumed.saturating_add(T::DbWeight::get().writes(1));
+                        MigrationProgress::<T>::insert(
+                            &id,
+                            MigrationStatus::Completed { steps: 0 },
+                        );
+                        Self::deposit_event(Event::MigrationSkipped(id));
+                        continue;
+                    }
+                    None => {
+                        Self::deposit_event(Event::MigrationStarted(id.clone()));
+                        (None, 0)
+                    }
+                };
+
+                let limit = remaining_weight.saturating_sub(consumed.saturating_add(overhead));
+                let (used, cursor) = migration.step(cursor, limit);
+                let steps = steps.saturating_add(1);
+                consumed = consumed.saturating_add(used).saturating_add(overhead);
+
+                match cursor {
+                    Some(cursor) => {
+                        MigrationProgress::<T>::insert(
+                            &id,
+                            MigrationStatus::Ongoing { cursor, steps },
+                        );
+                        Self::deposit_event(Event::MigrationStepped(id, steps));
+                        // The next migrations must wait for this one
+                        break;
+                    }
+                    None => {
+                        consumed = consumed.saturating_add(migration.on_completed());
+                        MigrationProgress::<T>::insert(&id, MigrationStatus::Completed { steps });
+                        Self::deposit_event(Event::MigrationCompleted(id, steps));
+                    }
+                }
+            }
+
+            consumed
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Whether a listed migration is not completed yet
+    pub fn is_migrating() -> bool {
+        T::Migrations::get().iter().any(|migration| {
+            !matches!(
+                Self::migration_status(migration.id().to_vec()),
+                Some(MigrationStatus::Completed { .. })
+            )
+        })
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_std::cell::RefCell;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        Migrations: crate::{Pallet, Storage, Event},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+/// Weight of migrating one item
+pub const ITEM_WEIGHT: u64 = 10;
+
+thread_local! {
+    pub static ITEMS: RefCell<u32> = RefCell::new(0);
+    pub static MIGRATED: RefCell<u32> = RefCell::new(0);
+    pub static SHOULD_RUN: RefCell<bool> = RefCell::new(true);
+}
+
+pub fn set_items(items: u32) {
+    ITEMS.with(|v| *v.borrow_mut() = items);
+}
+
+pub fn migrated() -> u32 {
+    MIGRATED.with(|v| *v.borrow())
+}
+
+pub fn set_should_run(should_run: bool) {
+    SHOULD_RUN.with(|v| *v.borrow_mut() = should_run);
+}
+
+/// Migrates `ITEMS` items, as many as fit in the weight limit at each step
+#[derive(Default)]
+pub struct CountingMigration;
+impl SteppedMigration for CountingMigration {
+    fn id(&self) -> &'static [u8] {
+        b"counting"
+    }
+
+    fn should_run(&self) -> bool {
+        SHOULD_RUN.with(|v| *v.borrow())
+    }
+
+    fn step(&self, cursor: Option<Vec<u8>>, limit: Weight) -> (Weight, Option<Vec<u8>>) {
+        let mut next = cursor
+            .map(|cursor| u32::decode(&mut &cursor[..]).unwrap())
+            .unwrap_or_default();
+        let items = ITEMS.with(|v| *v.borrow());
+        let mut used = Weight::zero();
+        while next < items
+            && used
+                .saturating_add(Weight::from_ref_time(ITEM_WEIGHT))
+                .all_lte(limit)
+        {
+            MIGRATED.with(|v| *v.borrow_mut() += 1);
+            used = used.saturating_add(Weight::from_ref_time(ITEM_WEIGHT));
+            next += 1;
+        }
+        (used, (next < items).then(|| next.encode()))
+    }
+}
+
+/// Completes in a single step
+#[derive(Default)]
+pub struct NextMigration;
+impl SteppedMigration for NextMigration {
+    fn id(&self) -> &'static [u8] {
+        b"next"
+    }
+
+    fn step(&self, _cursor: Option<Vec<u8>>, _limit: Weight) -> (Weight, Option<Vec<u8>>) {
+        (Weight::zero(), None)
+    }
+}
+
+pub struct TestMigrations;
+impl Get<Vec<Box<dyn SteppedMigration>>> for TestMigrations {
+    fn get() -> Vec<Box<dyn SteppedMigration>> {
+        vec![
+            Box::new(VersionedMigration::<Migrations, CountingMigration, 0, 1>::default()),
+            Box::new(NextMigration),
+        ]
+    }
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Migrations = TestMigrations;
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        set_items(0);
+        set_should_run(true);
+        MIGRATED.with(|v| *v.borrow_mut() = 0);
+    });
+    ext
+}
@@ -0,0 +1,107 @@
+use super::*;
+use crate::mock::*;
+use frame_support::traits::OnIdle;
+
+fn status(id: &[u8]) -> Option<MigrationStatus> {
+    Migrations::migration_status(id.to_vec())
+}
+
+fn run_idle(remaining_weight: u64) -> Weight {
+    Migrations::on_idle(
+        System::block_number(),
+        Weight::from_ref_time(remaining_weight),
+    )
+}
+
+#[test]
+fn migrations_are_stepped_in_the_weight_left() {
+    new_test_ext().execute_with(|| {
+        set_items(25);
+
+        assert_eq!(run_idle(100), Weight::from_ref_time(100));
+        assert_eq!(migrated(), 10);
+        assert_eq!(
+            status(b"counting"),
+            Some(MigrationStatus::Ongoing {
+                cursor: 10u32.encode(),
+                steps: 1
+            })
+        );
+        // The next migration waits for the ongoing one
+        assert_eq!(status(b"next"), None);
+        assert!(Migrations::is_migrating());
+
+        run_idle(100);
+        assert_eq!(migrated(), 20);
+        System::assert_last_event(RuntimeEvent::Migrations(Event::MigrationStepped(
+            b"counting".to_vec(),
+            2,
+        )));
+
+        assert_eq!(run_idle(100), Weight::from_ref_time(50));
+        assert_eq!(migrated(), 25);
+        assert_eq!(
+            status(b"counting"),
+            Some(MigrationStatus::Completed { steps: 3 })
+        );
+        assert_eq!(
+            status(b"next"),
+            Some(MigrationStatus::Completed { steps: 1 })
+        );
+        assert!(!Migrations::is_migrating());
+
+        // Completed migrations don't run again
+        run_idle(100);
+        assert_eq!(migrated(), 25);
+    });
+}
+
+#[test]
+fn versioned_migrations_bump_the_storage_version() {
+    new_test_ext().execute_with(|| {
+        set_items(5);
+        assert_eq!(Migrations::on_chain_storage_version(), 0);
+
+        run_idle(100);
+        assert_eq!(migrated(), 5);
+        assert_eq!(Migrations::on_chain_storage_version(), 1);
+        System::assert_has_event(RuntimeEvent::Migrations(Event::MigrationCompleted(
+            b"counting".to_vec(),
+            1,
+        )));
+    });
+}
+
+#[test]
+fn migrations_which_dont_have_to_run_are_skipped() {
+    new_test_ext().execute_with(|| {
+        set_items(5);
+        set_should_run(false);
+
+        run_idle(100);
+        assert_eq!(migrated(), 0);
+        assert_eq!(
+            status(b"counting"),
+            Some(MigrationStatus::Completed { steps: 0 })
+        );
+        assert_eq!(Migrations::on_chain_storage_version(), 0);
+        System::assert_has_event(RuntimeEvent::Migrations(Event::MigrationSkipped(
+            b"counting".to_vec(),
+        )));
+    });
+}
+
+#[test]
+fn version_guard_skips_applied_migrations() {
+    new_test_ext().execute_with(|| {
+        set_items(5);
+        StorageVersion::new(1).put::<Migrations>();
+
+        run_idle(100);
+        assert_eq!(migrated(), 0);
+        assert_eq!(
+            status(b"counting"),
+            Some(MigrationStatus::Completed { steps: 0 })
+        );
+    });
+}
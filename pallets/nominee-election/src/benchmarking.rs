@@ -0,0 +1,123 @@
+//! Nominee election pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as NomineeElection;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin as SystemOrigin;
+use sp_std::vec;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn performance(era_points: u32) -> ValidatorPerformance {
+    ValidatorPerformance {
+        era_points,
+        slashed: false,
+        commission: Perbill::from_percent(5),
+    }
+}
+
+fn fill_candidates<T: Config>(n: u32, era: EraIndex) {
+    for i in 0..n {
+        let validator: T::AccountId = account("validator", i, 0);
+        let candidate = Candidate {
+            history: vec![EraPerformance {
+                era,
+                performance: performance(i),
+            }]
+            .try_into()
+            .unwrap(),
+            score: i.into(),
+        };
+        Candidates::<T>::insert(validator, candidate);
+    }
+}
+
+benchmarks! {
+    on_initialize {
+        LastElectedEra::<T>::put(T::EraProvider::current_era());
+    }: {
+        NomineeElection::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+    }
+
+    elect {
+        let n in 1 .. T::MaxCandidates::get();
+        fill_candidates::<T>(n, T::EraProvider::current_era());
+    }: {
+        NomineeElection::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+    }
+    verify {
+        assert_eq!(
+            NomineeElection::<T>::nominees().len() as u32,
+            (n - 1).min(T::MaxNominees::get())
+        );
+    }
+
+    add_feeder {
+        let feeder: T::AccountId = whitelisted_caller();
+    }: _(SystemOrigin::Root, feeder.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::FeederAdded(feeder).into());
+    }
+
+    remove_feeder {
+        let feeder: T::AccountId = whitelisted_caller();
+        NomineeElection::<T>::add_feeder(SystemOrigin::Root.into(), feeder.clone())?;
+    }: _(SystemOrigin::Root, feeder.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::FeederRemoved(feeder).into());
+    }
+
+    report_performance {
+        let n in 1 .. T::MaxCandidates::get();
+        // Every validator has a full window, so that reporting drops its oldest report
+        for i in 0..n {
+            let validator: T::AccountId = account("validator", i, 0);
+            let history = (0..T::HistoryDepth::get())
+                .map(|e| EraPerformance {
+                    era: e,
+                    performance: performance(100),
+                })
+                .collect::<Vec<_>>();
+            Candidates::<T>::insert(
+                validator,
+                Candidate {
+                    history: history.try_into().unwrap(),
+                    score: 95,
+                },
+            );
+        }
+    }: {
+        // The relaychain era can't be moved forward here, so the reports are applied
+        // directly, which is what the call spends its weight on
+        for i in 0..n {
+            NomineeElection::<T>::do_report(
+                &account("validator", i, 0),
+                T::HistoryDepth::get(),
+                performance(200),
+            )?;
+        }
+    }
+    verify {
+        let validator: T::AccountId = account("validator", 0, 0);
+        let history = NomineeElection::<T>::candidates(validator).unwrap().history;
+        assert_eq!(history.last().map(|report| report.era), Some(T::HistoryDepth::get()));
+    }
+
+    remove_candidate {
+        fill_candidates::<T>(1, T::EraProvider::current_era());
+        let validator: T::AccountId = account("validator", 0, 0);
+    }: _(SystemOrigin::Root, validator.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::CandidateRemoved(validator).into());
+    }
+}
+
+impl_benchmark_test_suite!(
+    NomineeElection,
+    crate::mock::new_test_ext(),
+    crate::mock::Test
+);
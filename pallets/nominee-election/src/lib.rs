@@ -0,0 +1,390 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Nominee election pallet
+//!
+//! ## Overview
+//!
+//! Elects the relaychain validators liquid staking nominates from their performance,
+//! scored on-chain instead of trusting a pre-computed list.
+//!
+//! Feeders set by governance report the performance of validators once a relaychain
+//! era is over: the era points they earned, whether they were slashed and their
+//! commission. Each validator keeps the reports of its last `HistoryDepth` eras, its
+//! score being the average of its era points less its current commission. A slash in
+//! the window, or a commission above `MaxCommission`, brings the score to zero.
+//!
+//! At the start of every era the `MaxNominees` best scored validators are elected,
+//! validators without any report in the window are dropped at the same time.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    log,
+    pallet_prelude::*,
+    traits::{Get, IsType},
+    CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::*;
+use pallet_traits::EraProvider;
+use primitives::EraIndex;
+use sp_runtime::{traits::Saturating, Perbill};
+use sp_std::{cmp::Reverse, vec::Vec};
+
+pub use pallet::*;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Performance of a validator over an era, as reported by a feeder
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ValidatorPerformance {
+    /// Era points earned by the validator
+    pub era_points: u32,
+    /// Whether the validator was slashed
+    pub slashed: bool,
+    /// Commission of the validator
+    pub commission: Perbill,
+}
+
+/// Reported performance of a validator with the era it belongs to
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EraPerformance {
+    pub era: EraIndex,
+    pub performance: ValidatorPerformance,
+}
+
+/// Rolling window of reports of a validator and the score derived from it
+#[derive(
+    CloneNoBound, PartialEqNoBound, EqNoBound, Encode, Decode, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(HistoryDepth))]
+pub struct Candidate<HistoryDepth: Get<u32>> {
+    /// Reports of the last eras, oldest first
+    pub history: BoundedVec<EraPerformance, HistoryDepth>,
+    pub score: u128,
+}
+
+impl<HistoryDepth: Get<u32>> Default for Candidate<HistoryDepth> {
+    fn default() -> Self {
+        Self {
+            history: Default::default(),
+            score: Default::default(),
+        }
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    pub type CandidateOf<T> = Candidate<<T as Config>::HistoryDepth>;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Relaychain era the reports are checked against
+        type EraProvider: EraProvider<BlockNumberFor<Self>>;
+
+        /// Number of eras a validator is scored over
+        #[pallet::constant]
+        type HistoryDepth: Get<u32>;
+
+        /// Maximum number of validators reported on
+        #[pallet::constant]
+        type MaxCandidates: Get<u32>;
+
+        /// Number of validators elected
+        #[pallet::constant]
+        type MaxNominees: Get<u32>;
+
+        /// Maximum number of feeders
+        #[pallet::constant]
+        type MaxFeeders: Get<u32>;
+
+        /// Validators charging more are not elected
+        #[pallet::constant]
+        type MaxCommission: Get<Perbill>;
+
+        /// The origin which can manage feeders and candidates
+        type UpdateOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Feeder was added
+        /// [feeder]
+        FeederAdded(T::AccountId),
+        /// Feeder was removed
+        /// [feeder]
+        FeederRemoved(T::AccountId),
+        /// Performance of validators over an era was reported
+        /// [feeder, era, validators]
+        PerformanceReported(T::AccountId, EraIndex, u32),
+        /// Candidate and its reports were removed
+        /// [validator]
+        CandidateRemoved(T::AccountId),
+        /// Nominees were elected for an era
+        /// [era, nominees]
+        NomineesElected(EraIndex, Vec<T::AccountId>),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The account isn't a feeder
+        NotFeeder,
+        /// The account is a feeder already
+        FeederAlreadyExists,
+        /// Too many feeders
+        TooManyFeeders,
+        /// The era isn't over yet
+        EraNotEnded,
+        /// The era is out of the history window
+        EraTooOld,
+        /// The validator already has a report for this era or a later one
+        StaleReport,
+        /// The same validator is reported twice
+        DuplicatedValidator,
+        /// Too many validators reported on
+        TooManyCandidates,
+        /// The validator isn't a candidate
+        CandidateNotFound,
+    }
+
+    /// Accounts allowed to report validator performance
+    #[pallet::storage]
+    #[pallet::getter(fn feeders)]
+    pub type Feeders<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxFeeders>, ValueQuery>;
+
+    /// Reports and score of each validator
+    /// AccountId -> Candidate
+    #[pallet::storage]
+    #[pallet::getter(fn candidates)]
+    pub type Candidates<T: Config> =
+        CountedStorageMap<_, Blake2_128Concat, T::AccountId, CandidateOf<T>, OptionQuery>;
+
+    /// Validators elected by their score
+    #[pallet::storage]
+    #[pallet::getter(fn nominees)]
+    pub type Nominees<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+    /// Era of the last election
+    #[pallet::storage]
+    #[pallet::getter(fn last_elected_era)]
+    pub type LastElectedEra<T: Config> = StorageValue<_, EraIndex, OptionQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            let era = T::EraProvider::current_era();
+            if Self::last_elected_era().map_or(false, |last| last >= era) {
+                return T::WeightInfo::on_initialize();
+            }
+
+            let candidates = Candidates::<T>::count();
+            Self::do_elect(era);
+            T::WeightInfo::elect(candidates)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Allow `feeder` to report validator performance
+        #[pallet::weight(T::WeightInfo::add_feeder())]
+        pub fn add_feeder(origin: OriginFor<T>, feeder: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Feeders::<T>::try_mutate(|feeders| -> DispatchResult {
+                ensure!(!feeders.contains(&feeder), Error::<T>::FeederAlreadyExists);
+                feeders
+                    .try_push(feeder.clone())
+                    .map_err(|_| Error::<T>::TooManyFeeders)?;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::<T>::FeederAdded(feeder));
+            Ok(())
+        }
+
+        /// Stop `feeder` from reporting validator performance
+        #[pallet::weight(T::WeightInfo::remove_feeder())]
+        pub fn remove_feeder(origin: OriginFor<T>, feeder: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Feeders::<T>::try_mutate(|feeders| -> DispatchResult {
+                let index = feeders
+                    .iter()
+                    .position(|f| f == &feeder)
+                    .ok_or(Error::<T>::NotFeeder)?;
+                feeders.remove(index);
+                Ok(())
+            })?;
+            Self::deposit_event(Event::<T>::FeederRemoved(feeder));
+            Ok(())
+        }
+
+        /// Report the performance of validators over an ended era, the score of each
+        /// validator is updated over its last `HistoryDepth` reports
+        #[pallet::weight(T::WeightInfo::report_performance(reports.len() as u32))]
+        #[transactional]
+        pub fn report_performance(
+            origin: OriginFor<T>,
+            era: EraIndex,
+            reports: Vec<(T::AccountId, ValidatorPerformance)>,
+        ) -> DispatchResult {
+            let feeder = ensure_signed(origin)?;
+            ensure!(Self::feeders().contains(&feeder), Error::<T>::NotFeeder);
+            ensure!(
+                reports.len() <= T::MaxCandidates::get() as usize,
+                Error::<T>::TooManyCandidates
+            );
+
+            let current_era = T::EraProvider::current_era();
+            ensure!(era < current_era, Error::<T>::EraNotEnded);
+            ensure!(
+                current_era.saturating_sub(era) <= T::HistoryDepth::get(),
+                Error::<T>::EraTooOld
+            );
+
+            let mut reported: Vec<&T::AccountId> = Vec::with_capacity(reports.len());
+            for (validator, performance) in reports.iter() {
+                ensure!(
+                    !reported.contains(&validator),
+                    Error::<T>::DuplicatedValidator
+                );
+                reported.push(validator);
+                Self::do_report(validator, era, *performance)?;
+            }
+
+            Self::deposit_event(Event::<T>::PerformanceReported(
+                feeder,
+                era,
+                reports.len() as u32,
+            ));
+            Ok(())
+        }
+
+        /// Remove a validator and its reports, e.g. once it stopped validating
+        #[pallet::weight(T::WeightInfo::remove_candidate())]
+        pub fn remove_candidate(origin: OriginFor<T>, validator: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                Candidates::<T>::contains_key(&validator),
+                Error::<T>::CandidateNotFound
+            );
+            Candidates::<T>::remove(&validator);
+            Self::deposit_event(Event::<T>::CandidateRemoved(validator));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    fn do_report(
+        validator: &T::AccountId,
+        era: EraIndex,
+        performance: ValidatorPerformance,
+    ) -> DispatchResult {
+        if !Candidates::<T>::contains_key(validator) {
+            ensure!(
+                Candidates::<T>::count() < T::MaxCandidates::get(),
+                Error::<T>::TooManyCandidates
+            );
+        }
+
+        let mut candidate = Self::candidates(validator).unwrap_or_default();
+        ensure!(
+            candidate.history.last().map_or(true, |last| last.era < era),
+            Error::<T>::StaleReport
+        );
+        if candidate.history.len() >= T::HistoryDepth::get() as usize {
+            candidate.history.remove(0);
+        }
+        candidate
+            .history
+            .try_push(EraPerformance { era, performance })
+            .map_err(|_| Error::<T>::StaleReport)?;
+        candidate.score = Self::score(&candidate.history);
+
+        Candidates::<T>::insert(validator, candidate);
+        Ok(())
+    }
+
+    /// Average era points of the window less the latest commission, zero once
+    /// slashed or charging more than `MaxCommission`
+    pub fn score(history: &[EraPerformance]) -> u128 {
+        let latest = match history.last() {
+            Some(latest) => latest.performance,
+            None => return 0,
+        };
+        if latest.commission > T::MaxCommission::get()
+            || history.iter().any(|report| report.performance.slashed)
+        {
+            return 0;
+        }
+
+        let total_points = history.iter().fold(0u128, |acc, report| {
+            acc.saturating_add(report.performance.era_points.into())
+        });
+        let average_points = total_points / history.len() as u128;
+        Perbill::one()
+            .saturating_sub(latest.commission)
+            .mul_floor(average_points)
+    }
+
+    fn do_elect(era: EraIndex) {
+        let oldest_era = era.saturating_sub(T::HistoryDepth::get());
+        let (outdated, mut scored): (Vec<_>, Vec<_>) = Candidates::<T>::iter()
+            .map(|(validator, candidate)| {
+                let last_era = candidate.history.last().map(|report| report.era);
+                (validator, last_era, candidate.score)
+            })
+            .partition(|(_, last_era, _)| last_era.map_or(true, |last| last < oldest_era));
+        for (validator, _, _) in outdated {
+            Candidates::<T>::remove(&validator);
+        }
+
+        scored.retain(|(_, _, score)| *score > 0);
+        scored.sort_by_key(|(_, _, score)| Reverse(*score));
+        let nominees: Vec<T::AccountId> = scored
+            .into_iter()
+            .take(T::MaxNominees::get() as usize)
+            .map(|(validator, _, _)| validator)
+            .collect();
+
+        log::trace!(
+            target: "nomineeElection::do_elect",
+            "era: {:?}, nominees: {:?}",
+            &era,
+            &nominees,
+        );
+        Nominees::<T>::put(nominees.clone());
+        LastElectedEra::<T>::put(era);
+        Self::deposit_event(Event::<T>::NomineesElected(era, nominees));
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use frame_system::EnsureRoot;
+use primitives::BlockNumber;
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        NomineeElection: crate::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+parameter_types! {
+    pub const BlockHashCount: BlockNumber = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+    pub static CurrentEra: EraIndex = 0;
+    pub const HistoryDepth: u32 = 3;
+    pub const MaxCandidates: u32 = 4;
+    pub const MaxNominees: u32 = 2;
+    pub const MaxFeeders: u32 = 2;
+    pub MaxCommission: Perbill = Perbill::from_percent(20);
+}
+
+pub struct MockEraProvider;
+impl EraProvider<BlockNumber> for MockEraProvider {
+    fn current_era() -> EraIndex {
+        CurrentEra::get()
+    }
+
+    fn era_start_block() -> BlockNumber {
+        0
+    }
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type EraProvider = MockEraProvider;
+    type HistoryDepth = HistoryDepth;
+    type MaxCandidates = MaxCandidates;
+    type MaxNominees = MaxNominees;
+    type MaxFeeders = MaxFeeders;
+    type MaxCommission = MaxCommission;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        NomineeElection::add_feeder(RuntimeOrigin::root(), ALICE).unwrap();
+    });
+    ext
+}
+
+/// Move the relaychain to `era` and run the hooks of the next block
+pub fn run_to_era(era: EraIndex) {
+    CurrentEra::set(era);
+    let n = System::block_number() + 1;
+    System::set_block_number(n);
+    NomineeElection::on_initialize(n);
+}
+
+pub fn performance(era_points: u32, commission: u32) -> ValidatorPerformance {
+    ValidatorPerformance {
+        era_points,
+        slashed: false,
+        commission: Perbill::from_percent(commission),
+    }
+}
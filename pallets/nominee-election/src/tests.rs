@@ -0,0 +1,219 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+
+#[test]
+fn feeders_are_managed_by_governance() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            NomineeElection::add_feeder(RuntimeOrigin::signed(ALICE), BOB),
+            BadOrigin
+        );
+        assert_noop!(
+            NomineeElection::add_feeder(RuntimeOrigin::root(), ALICE),
+            Error::<Test>::FeederAlreadyExists
+        );
+        assert_ok!(NomineeElection::add_feeder(RuntimeOrigin::root(), BOB));
+        System::assert_last_event(Event::FeederAdded(BOB).into());
+        assert_noop!(
+            NomineeElection::add_feeder(RuntimeOrigin::root(), 3),
+            Error::<Test>::TooManyFeeders
+        );
+
+        assert_ok!(NomineeElection::remove_feeder(RuntimeOrigin::root(), BOB));
+        assert_eq!(NomineeElection::feeders().into_inner(), vec![ALICE]);
+        assert_noop!(
+            NomineeElection::remove_feeder(RuntimeOrigin::root(), BOB),
+            Error::<Test>::NotFeeder
+        );
+    })
+}
+
+#[test]
+fn only_feeders_report_ended_eras() {
+    new_test_ext().execute_with(|| {
+        run_to_era(5);
+        assert_noop!(
+            NomineeElection::report_performance(
+                RuntimeOrigin::signed(BOB),
+                4,
+                vec![(10, performance(100, 5))]
+            ),
+            Error::<Test>::NotFeeder
+        );
+        assert_noop!(
+            NomineeElection::report_performance(
+                RuntimeOrigin::signed(ALICE),
+                5,
+                vec![(10, performance(100, 5))]
+            ),
+            Error::<Test>::EraNotEnded
+        );
+        assert_noop!(
+            NomineeElection::report_performance(
+                RuntimeOrigin::signed(ALICE),
+                1,
+                vec![(10, performance(100, 5))]
+            ),
+            Error::<Test>::EraTooOld
+        );
+        assert_noop!(
+            NomineeElection::report_performance(
+                RuntimeOrigin::signed(ALICE),
+                4,
+                vec![(10, performance(100, 5)), (10, performance(100, 5))]
+            ),
+            Error::<Test>::DuplicatedValidator
+        );
+
+        assert_ok!(NomineeElection::report_performance(
+            RuntimeOrigin::signed(ALICE),
+            4,
+            vec![(10, performance(100, 5))]
+        ));
+        System::assert_last_event(Event::PerformanceReported(ALICE, 4, 1).into());
+        // Reports can't be replaced nor go back in time
+        assert_noop!(
+            NomineeElection::report_performance(
+                RuntimeOrigin::signed(ALICE),
+                3,
+                vec![(10, performance(200, 5))]
+            ),
+            Error::<Test>::StaleReport
+        );
+        assert_noop!(
+            NomineeElection::report_performance(
+                RuntimeOrigin::signed(ALICE),
+                4,
+                vec![(10, performance(200, 5))]
+            ),
+            Error::<Test>::StaleReport
+        );
+    })
+}
+
+#[test]
+fn score_is_computed_over_a_rolling_window() {
+    new_test_ext().execute_with(|| {
+        for (era, points) in [(1, 100), (2, 200), (3, 300), (4, 400)] {
+            run_to_era(era + 1);
+            assert_ok!(NomineeElection::report_performance(
+                RuntimeOrigin::signed(ALICE),
+                era,
+                vec![(10, performance(points, 10))]
+            ));
+        }
+
+        // Only the last HistoryDepth eras are kept: (200 + 300 + 400) / 3 less 10%
+        let candidate = NomineeElection::candidates(10).unwrap();
+        assert_eq!(
+            candidate
+                .history
+                .iter()
+                .map(|report| report.era)
+                .collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+        assert_eq!(candidate.score, 270);
+
+        // A slash in the window brings the score to zero
+        let mut slashed = performance(400, 10);
+        slashed.slashed = true;
+        run_to_era(6);
+        assert_ok!(NomineeElection::report_performance(
+            RuntimeOrigin::signed(ALICE),
+            5,
+            vec![(10, slashed)]
+        ));
+        assert_eq!(NomineeElection::candidates(10).unwrap().score, 0);
+
+        // So does a commission above MaxCommission
+        assert_ok!(NomineeElection::report_performance(
+            RuntimeOrigin::signed(ALICE),
+            5,
+            vec![(11, performance(400, 25))]
+        ));
+        assert_eq!(NomineeElection::candidates(11).unwrap().score, 0);
+    })
+}
+
+#[test]
+fn best_scored_validators_are_elected_each_era() {
+    new_test_ext().execute_with(|| {
+        run_to_era(2);
+        assert_ok!(NomineeElection::report_performance(
+            RuntimeOrigin::signed(ALICE),
+            1,
+            vec![
+                (10, performance(100, 0)),
+                (11, performance(300, 0)),
+                (12, performance(200, 0)),
+                (13, performance(900, 50)),
+            ]
+        ));
+        assert_noop!(
+            NomineeElection::report_performance(
+                RuntimeOrigin::signed(ALICE),
+                1,
+                vec![(14, performance(100, 0))]
+            ),
+            Error::<Test>::TooManyCandidates
+        );
+
+        run_to_era(3);
+        assert_eq!(NomineeElection::nominees(), vec![11, 12]);
+        assert_eq!(NomineeElection::last_elected_era(), Some(3));
+        System::assert_last_event(Event::NomineesElected(3, vec![11, 12]).into());
+
+        run_to_era(4);
+        assert_eq!(NomineeElection::nominees(), vec![11, 12]);
+
+        // Validators without a report in the window are dropped
+        assert_ok!(NomineeElection::report_performance(
+            RuntimeOrigin::signed(ALICE),
+            3,
+            vec![(10, performance(100, 0))]
+        ));
+        run_to_era(5);
+        assert_eq!(NomineeElection::nominees(), vec![10]);
+        assert_eq!(Candidates::<Test>::count(), 1);
+    })
+}
+
+#[test]
+fn remove_candidate_works() {
+    new_test_ext().execute_with(|| {
+        run_to_era(2);
+        assert_ok!(NomineeElection::report_performance(
+            RuntimeOrigin::signed(ALICE),
+            1,
+            vec![(10, performance(100, 0))]
+        ));
+        assert_noop!(
+            NomineeElection::remove_candidate(RuntimeOrigin::signed(ALICE), 10),
+            BadOrigin
+        );
+        assert_ok!(NomineeElection::remove_candidate(RuntimeOrigin::root(), 10));
+        System::assert_last_event(Event::CandidateRemoved(10).into());
+        assert!(NomineeElection::candidates(10).is_none());
+        assert_noop!(
+            NomineeElection::remove_candidate(RuntimeOrigin::root(), 10),
+            Error::<Test>::CandidateNotFound
+        );
+    })
+}
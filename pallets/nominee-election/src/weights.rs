@@ -0,0 +1,176 @@
+// This file is part of Parallel Finance.
+
+// Copyright (C) 2022 Parallel Finance Developer.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Weights for pallet_nominee_election
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet-nominee-election
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/nominee-election/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions needed for pallet_nominee_election.
+pub trait WeightInfo {
+	fn on_initialize() -> Weight;
+	fn elect(n: u32, ) -> Weight;
+	fn add_feeder() -> Weight;
+	fn remove_feeder() -> Weight;
+	fn report_performance(n: u32, ) -> Weight;
+	fn remove_candidate() -> Weight;
+}
+
+/// Weights for pallet_nominee_election using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: RelayEra CurrentEra (r:1 w:0)
+	// Storage: NomineeElection LastElectedEra (r:1 w:0)
+	fn on_initialize() -> Weight {
+		Weight::from_ref_time(6_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+	}
+	// Storage: RelayEra CurrentEra (r:1 w:0)
+	// Storage: NomineeElection LastElectedEra (r:1 w:1)
+	// Storage: NomineeElection CounterForCandidates (r:1 w:1)
+	// Storage: NomineeElection Candidates (r:1 w:1)
+	// Storage: NomineeElection Nominees (r:0 w:1)
+	fn elect(n: u32, ) -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_ref_time(4_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: NomineeElection Feeders (r:1 w:1)
+	fn add_feeder() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: NomineeElection Feeders (r:1 w:1)
+	fn remove_feeder() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: NomineeElection Feeders (r:1 w:0)
+	// Storage: RelayEra CurrentEra (r:1 w:0)
+	// Storage: NomineeElection Candidates (r:1 w:1)
+	// Storage: NomineeElection CounterForCandidates (r:1 w:1)
+	fn report_performance(n: u32, ) -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_ref_time(12_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: NomineeElection Candidates (r:1 w:1)
+	// Storage: NomineeElection CounterForCandidates (r:1 w:1)
+	fn remove_candidate() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: RelayEra CurrentEra (r:1 w:0)
+	// Storage: NomineeElection LastElectedEra (r:1 w:0)
+	fn on_initialize() -> Weight {
+		Weight::from_ref_time(6_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+	}
+	// Storage: RelayEra CurrentEra (r:1 w:0)
+	// Storage: NomineeElection LastElectedEra (r:1 w:1)
+	// Storage: NomineeElection CounterForCandidates (r:1 w:1)
+	// Storage: NomineeElection Candidates (r:1 w:1)
+	// Storage: NomineeElection Nominees (r:0 w:1)
+	fn elect(n: u32, ) -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_ref_time(4_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: NomineeElection Feeders (r:1 w:1)
+	fn add_feeder() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: NomineeElection Feeders (r:1 w:1)
+	fn remove_feeder() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: NomineeElection Feeders (r:1 w:0)
+	// Storage: RelayEra CurrentEra (r:1 w:0)
+	// Storage: NomineeElection Candidates (r:1 w:1)
+	// Storage: NomineeElection CounterForCandidates (r:1 w:1)
+	fn report_performance(n: u32, ) -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_ref_time(12_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: NomineeElection Candidates (r:1 w:1)
+	// Storage: NomineeElection CounterForCandidates (r:1 w:1)
+	fn remove_candidate() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+}
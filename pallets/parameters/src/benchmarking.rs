@@ -0,0 +1,23 @@
+//! Parameters pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Parameters;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin as SystemOrigin;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    set_parameter {
+        let value = ParameterValue::Ratio(Ratio::from_percent(1));
+    }: _(SystemOrigin::Root, ParameterKey::AmmLpFee, Some(value))
+    verify {
+        assert_last_event::<T>(Event::<T>::ParameterUpdated(ParameterKey::AmmLpFee, Some(value)).into());
+    }
+}
+
+impl_benchmark_test_suite!(Parameters, crate::mock::new_test_ext(), crate::mock::Test);
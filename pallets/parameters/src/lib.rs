@@ -0,0 +1,131 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Parameters pallet
+//!
+//! ## Overview
+//!
+//! Stores runtime parameters which would otherwise be constants, keyed by typed
+//! identifiers. Pallet configurations read them with the `Get` adapters of this pallet,
+//! falling back to a default while not set, so they can be tuned without a runtime
+//! upgrade.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use primitives::Ratio;
+use sp_std::marker::PhantomData;
+
+pub use pallet::*;
+pub use types::{ParameterKey, ParameterValue};
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The origin which can update the parameters
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Parameter was updated, `None` resets it to its default
+        /// [key, value]
+        ParameterUpdated(ParameterKey, Option<ParameterValue>),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Value has the wrong type or is out of range for the parameter
+        InvalidParameterValue,
+    }
+
+    /// Parameters set by governance
+    /// ParameterKey -> ParameterValue
+    #[pallet::storage]
+    #[pallet::getter(fn parameter)]
+    pub type Parameters<T: Config> =
+        StorageMap<_, Twox64Concat, ParameterKey, ParameterValue, OptionQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set a parameter, or reset it to its default
+        #[pallet::weight(<T as Config>::WeightInfo::set_parameter())]
+        #[transactional]
+        pub fn set_parameter(
+            origin: OriginFor<T>,
+            key: ParameterKey,
+            value: Option<ParameterValue>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            if let Some(value) = &value {
+                ensure!(key.is_valid(value), Error::<T>::InvalidParameterValue);
+            }
+
+            Parameters::<T>::set(key, value);
+            Self::deposit_event(Event::<T>::ParameterUpdated(key, value));
+            Ok(())
+        }
+    }
+}
+
+/// Ratio parameter `Key`, `Default` while not set
+pub struct RatioParameter<T, Key, Default>(PhantomData<(T, Key, Default)>);
+impl<T: Config, Key: Get<ParameterKey>, Default: Get<Ratio>> Get<Ratio>
+    for RatioParameter<T, Key, Default>
+{
+    fn get() -> Ratio {
+        match Pallet::<T>::parameter(Key::get()) {
+            Some(ParameterValue::Ratio(ratio)) => ratio,
+            _ => Default::get(),
+        }
+    }
+}
+
+/// Number parameter `Key`, `Default` while not set
+pub struct NumberParameter<T, Key, Default>(PhantomData<(T, Key, Default)>);
+impl<T: Config, Key: Get<ParameterKey>, V: From<u32>, Default: Get<V>> Get<V>
+    for NumberParameter<T, Key, Default>
+{
+    fn get() -> V {
+        match Pallet::<T>::parameter(Key::get()) {
+            Some(ParameterValue::Number(number)) => number.into(),
+            _ => Default::get(),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::One, DispatchError::BadOrigin};
+
+#[test]
+fn set_parameter_should_work() {
+    new_test_ext().execute_with(|| {
+        let fee = ParameterValue::Ratio(Ratio::from_percent(1));
+        assert_noop!(
+            Parameters::set_parameter(
+                RuntimeOrigin::signed(ALICE),
+                ParameterKey::AmmLpFee,
+                Some(fee)
+            ),
+            BadOrigin
+        );
+        assert_ok!(Parameters::set_parameter(
+            RuntimeOrigin::root(),
+            ParameterKey::AmmLpFee,
+            Some(fee)
+        ));
+        assert_eq!(Parameters::parameter(ParameterKey::AmmLpFee), Some(fee));
+        System::assert_last_event(RuntimeEvent::Parameters(Event::ParameterUpdated(
+            ParameterKey::AmmLpFee,
+            Some(fee),
+        )));
+
+        assert_ok!(Parameters::set_parameter(
+            RuntimeOrigin::root(),
+            ParameterKey::AmmLpFee,
+            None
+        ));
+        assert_eq!(Parameters::parameter(ParameterKey::AmmLpFee), None);
+    });
+}
+
+#[test]
+fn invalid_values_should_be_rejected() {
+    new_test_ext().execute_with(|| {
+        for (key, value) in [
+            (ParameterKey::AmmLpFee, ParameterValue::Number(1)),
+            (ParameterKey::AmmLpFee, ParameterValue::Ratio(Ratio::one())),
+            (
+                ParameterKey::BridgeProposalLifetime,
+                ParameterValue::Number(0),
+            ),
+            (
+                ParameterKey::BridgeThresholdPercentage,
+                ParameterValue::Number(101),
+            ),
+            (ParameterKey::TxFeeSpread, ParameterValue::Number(5)),
+        ] {
+            assert_noop!(
+                Parameters::set_parameter(RuntimeOrigin::root(), key, Some(value)),
+                Error::<Test>::InvalidParameterValue
+            );
+        }
+    });
+}
+
+#[test]
+fn parameters_should_fall_back_to_defaults() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(LpFee::get(), DefaultLpFee::get());
+        assert_eq!(ProposalLifetime::get(), 200u64);
+
+        assert_ok!(Parameters::set_parameter(
+            RuntimeOrigin::root(),
+            ParameterKey::AmmLpFee,
+            Some(ParameterValue::Ratio(Ratio::from_percent(1)))
+        ));
+        assert_ok!(Parameters::set_parameter(
+            RuntimeOrigin::root(),
+            ParameterKey::BridgeProposalLifetime,
+            Some(ParameterValue::Number(50))
+        ));
+        assert_eq!(LpFee::get(), Ratio::from_percent(1));
+        assert_eq!(ProposalLifetime::get(), 50u64);
+    });
+}
@@ -0,0 +1,52 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use primitives::Ratio;
+use scale_info::TypeInfo;
+use sp_runtime::{traits::One, RuntimeDebug};
+
+/// Identifiers of the runtime parameters
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum ParameterKey {
+    /// Fee of the AMM pools given to liquidity providers, a ratio below one
+    AmmLpFee,
+    /// Markup on transaction fees paid in assets, a ratio
+    TxFeeSpread,
+    /// Number of blocks bridge proposals stay open, a non zero number
+    BridgeProposalLifetime,
+    /// Percentage of the bridge relayers needed to approve a proposal, a number up to 100
+    BridgeThresholdPercentage,
+}
+
+/// Value of a runtime parameter
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum ParameterValue {
+    Ratio(Ratio),
+    Number(u32),
+}
+
+impl ParameterKey {
+    /// Whether `value` has the type and range expected by the parameter
+    pub fn is_valid(&self, value: &ParameterValue) -> bool {
+        use ParameterValue::*;
+        match (self, value) {
+            (Self::AmmLpFee, Ratio(fee)) => *fee < One::one(),
+            (Self::TxFeeSpread, Ratio(_)) => true,
+            (Self::BridgeProposalLifetime, Number(blocks)) => *blocks > 0,
+            (Self::BridgeThresholdPercentage, Number(percent)) => (1..=100).contains(percent),
+            _ => false,
+        }
+    }
+}
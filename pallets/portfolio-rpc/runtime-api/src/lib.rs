@@ -0,0 +1,291 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use primitives::{BlockNumber, CurrencyId, ParaId, StreamId, VaultId};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Supply and borrow of a loans market, the supply is measured in the underlying asset
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct LoansPosition<Balance> {
+    pub asset: CurrencyId,
+    pub supplied: Balance,
+    pub borrowed: Balance,
+    pub is_collateral: bool,
+}
+
+impl<Balance> From<(CurrencyId, Balance, Balance, bool)> for LoansPosition<Balance> {
+    fn from(
+        (asset, supplied, borrowed, is_collateral): (CurrencyId, Balance, Balance, bool),
+    ) -> Self {
+        Self {
+            asset,
+            supplied,
+            borrowed,
+            is_collateral,
+        }
+    }
+}
+
+/// Liquidity provided to an AMM pool and the reserves it can be removed for
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct LiquidityPosition<Balance> {
+    pub base_asset: CurrencyId,
+    pub quote_asset: CurrencyId,
+    pub lp_token: CurrencyId,
+    pub liquidity: Balance,
+    pub base_amount: Balance,
+    pub quote_amount: Balance,
+}
+
+impl<Balance>
+    From<(
+        CurrencyId,
+        CurrencyId,
+        CurrencyId,
+        Balance,
+        Balance,
+        Balance,
+    )> for LiquidityPosition<Balance>
+{
+    fn from(
+        (base_asset, quote_asset, lp_token, liquidity, base_amount, quote_amount): (
+            CurrencyId,
+            CurrencyId,
+            CurrencyId,
+            Balance,
+            Balance,
+            Balance,
+        ),
+    ) -> Self {
+        Self {
+            base_asset,
+            quote_asset,
+            lp_token,
+            liquidity,
+            base_amount,
+            quote_amount,
+        }
+    }
+}
+
+/// Stake in a farming pool, `locked` is withdrawn but not yet redeemable
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FarmingPosition<Balance> {
+    pub asset: CurrencyId,
+    pub reward_asset: CurrencyId,
+    pub lock_duration: BlockNumber,
+    pub deposited: Balance,
+    pub locked: Balance,
+}
+
+impl<Balance> From<(CurrencyId, CurrencyId, BlockNumber, Balance, Balance)>
+    for FarmingPosition<Balance>
+{
+    fn from(
+        (asset, reward_asset, lock_duration, deposited, locked): (
+            CurrencyId,
+            CurrencyId,
+            BlockNumber,
+            Balance,
+            Balance,
+        ),
+    ) -> Self {
+        Self {
+            asset,
+            reward_asset,
+            lock_duration,
+            deposited,
+            locked,
+        }
+    }
+}
+
+/// Unclaimed contribution to a crowdloan vault and the ctokens held for it
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CrowdloanPosition<Balance> {
+    pub crowdloan: ParaId,
+    pub vault_id: VaultId,
+    pub ctoken: CurrencyId,
+    pub contributed: Balance,
+    pub ctoken_balance: Balance,
+}
+
+impl<Balance> From<(ParaId, VaultId, CurrencyId, Balance, Balance)> for CrowdloanPosition<Balance> {
+    fn from(
+        (crowdloan, vault_id, ctoken, contributed, ctoken_balance): (
+            ParaId,
+            VaultId,
+            CurrencyId,
+            Balance,
+            Balance,
+        ),
+    ) -> Self {
+        Self {
+            crowdloan,
+            vault_id,
+            ctoken,
+            contributed,
+            ctoken_balance,
+        }
+    }
+}
+
+/// Balance held in an ongoing stream, for the sender it is the part not streamed yet
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct StreamPosition<Balance> {
+    pub stream_id: StreamId,
+    pub asset: CurrencyId,
+    pub balance: Balance,
+    pub is_sender: bool,
+}
+
+impl<Balance> From<(StreamId, CurrencyId, Balance, bool)> for StreamPosition<Balance> {
+    fn from((stream_id, asset, balance, is_sender): (StreamId, CurrencyId, Balance, bool)) -> Self {
+        Self {
+            stream_id,
+            asset,
+            balance,
+            is_sender,
+        }
+    }
+}
+
+/// Positions of an account across loans, AMM, farming, crowdloans and streaming
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PortfolioPositions<Balance> {
+    pub loans: Vec<LoansPosition<Balance>>,
+    pub liquidity: Vec<LiquidityPosition<Balance>>,
+    pub farming: Vec<FarmingPosition<Balance>>,
+    pub crowdloans: Vec<CrowdloanPosition<Balance>>,
+    pub streams: Vec<StreamPosition<Balance>>,
+}
+
+impl<Balance> PortfolioPositions<Balance> {
+    /// Convert the amounts with `f`, e.g. into a representation suitable for RPC
+    pub fn try_map<B, E>(
+        self,
+        f: impl Fn(Balance) -> Result<B, E>,
+    ) -> Result<PortfolioPositions<B>, E> {
+        Ok(PortfolioPositions {
+            loans: self
+                .loans
+                .into_iter()
+                .map(|p| Ok((p.asset, f(p.supplied)?, f(p.borrowed)?, p.is_collateral).into()))
+                .collect::<Result<_, E>>()?,
+            liquidity: self
+                .liquidity
+                .into_iter()
+                .map(|p| {
+                    Ok((
+                        p.base_asset,
+                        p.quote_asset,
+                        p.lp_token,
+                        f(p.liquidity)?,
+                        f(p.base_amount)?,
+                        f(p.quote_amount)?,
+                    )
+                        .into())
+                })
+                .collect::<Result<_, E>>()?,
+            farming: self
+                .farming
+                .into_iter()
+                .map(|p| {
+                    Ok((
+                        p.asset,
+                        p.reward_asset,
+                        p.lock_duration,
+                        f(p.deposited)?,
+                        f(p.locked)?,
+                    )
+                        .into())
+                })
+                .collect::<Result<_, E>>()?,
+            crowdloans: self
+                .crowdloans
+                .into_iter()
+                .map(|p| {
+                    Ok((
+                        p.crowdloan,
+                        p.vault_id,
+                        p.ctoken,
+                        f(p.contributed)?,
+                        f(p.ctoken_balance)?,
+                    )
+                        .into())
+                })
+                .collect::<Result<_, E>>()?,
+            streams: self
+                .streams
+                .into_iter()
+                .map(|p| Ok((p.stream_id, p.asset, f(p.balance)?, p.is_sender).into()))
+                .collect::<Result<_, E>>()?,
+        })
+    }
+}
+
+/// Portfolio positions tagged with the layout they are encoded in. Positions of new products
+/// are added as a new variant so that existing decoders keep working on the old ones.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum VersionedPortfolioPositions<Balance> {
+    #[codec(index = 1)]
+    V1(PortfolioPositions<Balance>),
+}
+
+impl<Balance> VersionedPortfolioPositions<Balance> {
+    /// Convert the amounts with `f`, e.g. into a representation suitable for RPC
+    pub fn try_map<B, E>(
+        self,
+        f: impl Fn(Balance) -> Result<B, E>,
+    ) -> Result<VersionedPortfolioPositions<B>, E> {
+        match self {
+            Self::V1(positions) => Ok(VersionedPortfolioPositions::V1(positions.try_map(f)?)),
+        }
+    }
+}
+
+impl<Balance> From<PortfolioPositions<Balance>> for VersionedPortfolioPositions<Balance> {
+    fn from(positions: PortfolioPositions<Balance>) -> Self {
+        Self::V1(positions)
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait PortfolioApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec, {
+        fn portfolio_positions(account: AccountId) -> Result<VersionedPortfolioPositions<Balance>, DispatchError>;
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+pub use pallet_prices_rpc_runtime_api::{
+    FeederStatus, PricesApi as PricesRuntimeApi, Round, RoundId,
+};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use primitives::CurrencyId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[rpc(client, server)]
+pub trait PricesApi<BlockHash, AccountId, BlockNumber> {
+    /// Prices fed for `asset_id` in round `round_id`, with their median and spread.
+    #[method(name = "prices_getRound")]
+    fn get_round(
+        &self,
+        asset_id: CurrencyId,
+        round_id: RoundId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Round<AccountId, BlockNumber>>>;
+
+    /// Activity of every feeder which fed the oracle.
+    #[method(name = "prices_getFeederStatus")]
+    fn get_feeder_status(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(AccountId, FeederStatus<BlockNumber>)>>;
+}
+
+/// A struct that implements the [`PricesApi`].
+pub struct Prices<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Prices<C, B> {
+    /// Create new `Prices` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+pub enum Error {
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, BlockNumber>
+    PricesApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber> for Prices<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: PricesRuntimeApi<Block, AccountId, BlockNumber>,
+    AccountId: Codec,
+    BlockNumber: Codec,
+{
+    fn get_round(
+        &self,
+        asset_id: CurrencyId,
+        round_id: RoundId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Round<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(self.client.info().best_hash));
+        api.get_round(&at, asset_id, round_id)
+            .map_err(runtime_error_into_rpc_error)
+    }
+
+    fn get_feeder_status(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(AccountId, FeederStatus<BlockNumber>)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(self.client.info().best_hash));
+        api.get_feeder_status(&at)
+            .map_err(runtime_error_into_rpc_error)
+    }
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Runtime trapped",
+        Some(format!("{:?}", err)),
+    )))
+}
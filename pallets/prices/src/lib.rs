@@ -19,17 +19,41 @@
 //! This pallet provides the price from Oracle Module by implementing the
 //! `PriceFeeder` trait. In case of emergency, the price can be set directly
 //! by Oracle Collective.
+//!
+//! AMM LP tokens without a price of their own are priced at the fair value of
+//! the pooled assets, so that trading against the pool can't move their price.
+//!
+//! The prices fed to the oracle are recorded in rounds, one per asset and block,
+//! along with the activity of each feeder, for operators to monitor the quorum
+//! and the deviation of the feeds.
+//!
+//! Sibling oracle parachains registered by governance can also deliver prices, with
+//! an XCM `Transact` of `feed_xcm_prices`. The fresh prices of the oracle parachains
+//! and the price of our own oracle are aggregated as their median, by the weight given
+//! to each source. The median is only used if the sources within `MaxPriceDeviation`
+//! of it weigh at least `PriceQuorum`, otherwise our own price is used alone.
+//!
+//! The prices fed for an asset with a liquid AMM pool can be checked against the
+//! TWAP of the pool. Prices too far from it are held back from the oracle until
+//! governance releases or drops them, which keeps a compromised feeder from moving
+//! the price alone. Prices of oracle parachains too far from it are dropped.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-    dispatch::DispatchClass, log, pallet_prelude::*, traits::fungibles::Inspect, transactional,
+    dispatch::{DispatchClass, Pays},
+    log,
+    pallet_prelude::*,
+    traits::{fungibles::Inspect, SortedMembers, UnixTime},
+    transactional,
 };
 use frame_system::pallet_prelude::*;
-use orml_traits::{DataFeeder, DataProvider, DataProviderExtended};
+use orml_traits::{DataFeeder, DataProvider, DataProviderExtended, OnNewData};
 use primitives::*;
 use sp_runtime::{
-    traits::{CheckedDiv, CheckedMul},
+    traits::{
+        CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, SaturatedConversion, Saturating, Zero,
+    },
     FixedPointNumber, FixedU128,
 };
 use sp_std::vec::Vec;
@@ -43,8 +67,13 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod extension;
+pub mod types;
 pub mod weights;
 
+pub use extension::CheckPriceFreshness;
+pub use types::{FeederStatus, Round, RoundId, TwapCheck, TwapObservation};
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -106,6 +135,62 @@ pub mod pallet {
         /// Decimal provider.
         type Decimal: DecimalProvider<CurrencyId>;
 
+        /// Time source the age of the oracle prices is measured with
+        type UnixTime: UnixTime;
+
+        /// Age in milliseconds after which an oracle price is stale
+        #[pallet::constant]
+        type MaxPriceAge: Get<Timestamp>;
+
+        /// Pauses the calls relying on prices once oracle prices become stale
+        type CircuitBreaker: CircuitBreaker;
+
+        /// Number of feeds the oracle needs to combine a price
+        #[pallet::constant]
+        type MinimumCount: Get<u32>;
+
+        /// Number of past rounds kept for each asset
+        #[pallet::constant]
+        type MaxRoundsKept: Get<u32>;
+
+        /// Oracle the prices of the feeders are submitted to
+        type Oracle: OracleFeeder<Self::AccountId>;
+
+        /// Members of the oracle, the only accounts allowed to feed prices
+        type Feeders: SortedMembers<Self::AccountId>;
+
+        /// Max number of prices fed at once
+        #[pallet::constant]
+        type MaxFeedValues: Get<u32>;
+
+        /// Assets whose prices the calls rely on, the transactions relying on
+        /// stale prices are rejected by `CheckPriceFreshness`
+        type PriceDependencies: PriceDependencies<
+            Self::AccountId,
+            <Self as frame_system::Config>::RuntimeCall,
+        >;
+
+        /// Origin of the calls sent by a sibling parachain with XCM `Transact`
+        type XcmOracleOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = ParaId>;
+
+        /// Weight of the price of our own oracle when it is aggregated with the prices
+        /// of the oracle parachains
+        #[pallet::constant]
+        type LocalOracleWeight: Get<u32>;
+
+        /// Weight the fresh prices within `MaxPriceDeviation` of their median must add up
+        /// to for the aggregate to be used, our own price is used alone below it
+        #[pallet::constant]
+        type PriceQuorum: Get<u32>;
+
+        /// Distance from the median beyond which a price doesn't count towards the quorum
+        #[pallet::constant]
+        type MaxPriceDeviation: Get<Ratio>;
+
+        /// Number of blocks the TWAP of the AMM pools is averaged over
+        #[pallet::constant]
+        type TwapPeriod: Get<Self::BlockNumber>;
+
         /// Weight information
         type WeightInfo: WeightInfo;
     }
@@ -117,6 +202,42 @@ pub mod pallet {
         SetPrice(CurrencyId, Price),
         /// Reset emergency price. \[asset_id\]
         ResetPrice(CurrencyId),
+        /// Oracle prices of the assets became stale. \[asset_ids\]
+        PricesStale(Vec<CurrencyId>),
+        /// Oracle parachain was registered with its weight, or removed if `None`.
+        /// \[para_id, weight\]
+        XcmOracleSet(ParaId, Option<u32>),
+        /// Oracle parachain fed prices over XCM. \[para_id, values\]
+        XcmPricesFed(ParaId, Vec<(CurrencyId, Price)>),
+        /// Check of the prices of an asset against its AMM TWAP was set, or removed
+        /// if `None`. \[asset_id, check\]
+        TwapCheckSet(CurrencyId, Option<TwapCheck>),
+        /// Price fed too far from the AMM TWAP was held back from the oracle.
+        /// \[asset_id, feeder, price, twap_price\]
+        PriceHeld(CurrencyId, T::AccountId, Price, Price),
+        /// Held price was fed to the oracle, or dropped. \[asset_id, feeder, fed\]
+        HeldPriceResolved(CurrencyId, T::AccountId, bool),
+        /// Price fed over XCM too far from the AMM TWAP was dropped.
+        /// \[para_id, asset_id, price, twap_price\]
+        XcmPriceDropped(ParaId, CurrencyId, Price, Price),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Sender is not a member of the oracle
+        NotFeeder,
+        /// More prices than `MaxFeedValues` were fed at once
+        TooManyValues,
+        /// Parachain isn't a registered oracle
+        UnknownXcmOracle,
+        /// Weight of an oracle parachain must not be zero
+        ZeroOracleWeight,
+        /// An asset can't be checked against a pool with itself
+        InvalidTwapCheck,
+        /// Feeder has no price held for the asset
+        NoHeldPrice,
+        /// Price fed by an oracle parachain must not be zero
+        ZeroPrice,
     }
 
     /// Mapping from currency id to it's emergency price
@@ -131,10 +252,115 @@ pub mod pallet {
     pub type ForeignToNativeAsset<T: Config> =
         StorageMap<_, Twox64Concat, CurrencyId, CurrencyId, OptionQuery>;
 
+    /// Assets whose oracle price was stale at the last check
+    #[pallet::storage]
+    #[pallet::getter(fn stale_prices)]
+    pub type StalePrices<T: Config> = StorageValue<_, Vec<CurrencyId>, ValueQuery>;
+
+    /// Latest round of each asset
+    /// CurrencyId -> RoundId
+    #[pallet::storage]
+    #[pallet::getter(fn current_round)]
+    pub type CurrentRound<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, RoundId, ValueQuery>;
+
+    /// Prices fed in the last `MaxRoundsKept` rounds of each asset
+    /// CurrencyId, RoundId -> Round
+    #[pallet::storage]
+    #[pallet::getter(fn rounds)]
+    pub type Rounds<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        Twox64Concat,
+        RoundId,
+        Round<T::AccountId, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Activity of the feeders which fed the oracle
+    /// AccountId -> FeederStatus
+    #[pallet::storage]
+    #[pallet::getter(fn feeder_status)]
+    pub type FeederStatuses<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, FeederStatus<T::BlockNumber>, OptionQuery>;
+
+    /// Oracle parachains allowed to feed prices over XCM, with their weight
+    /// ParaId -> Weight
+    #[pallet::storage]
+    #[pallet::getter(fn xcm_oracle_weight)]
+    pub type XcmOracles<T: Config> = StorageMap<_, Twox64Concat, ParaId, u32, OptionQuery>;
+
+    /// Latest price fed by each oracle parachain
+    /// ParaId, CurrencyId -> TimeStampedPrice
+    #[pallet::storage]
+    #[pallet::getter(fn xcm_price)]
+    pub type XcmPrices<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ParaId,
+        Twox64Concat,
+        CurrencyId,
+        TimeStampedPrice,
+        OptionQuery,
+    >;
+
+    /// Assets whose fed prices are checked against the TWAP of their AMM pool
+    /// CurrencyId -> TwapCheck
+    #[pallet::storage]
+    #[pallet::getter(fn twap_check)]
+    pub type TwapChecks<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, TwapCheck, OptionQuery>;
+
+    /// Latest observation of the AMM pool of each checked asset
+    /// CurrencyId -> TwapObservation
+    #[pallet::storage]
+    #[pallet::getter(fn twap_observation)]
+    pub type TwapObservations<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, TwapObservation<T::BlockNumber>, OptionQuery>;
+
+    /// Prices held back from the oracle for being too far from the AMM TWAP
+    /// CurrencyId, AccountId -> Price
+    #[pallet::storage]
+    #[pallet::getter(fn held_price)]
+    pub type HeldPrices<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        Blake2_128Concat,
+        T::AccountId,
+        Price,
+        OptionQuery,
+    >;
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            let twap_weight = Self::observe_pools(n);
+            let values = T::Source::get_all_values();
+            // price and emergency price of each asset, plus the stale prices
+            let reads = (values.len() as u64).saturating_mul(2).saturating_add(1);
+            let stale = Self::collect_stale_prices(values);
+            let previous = Self::stale_prices();
+            if stale == previous {
+                return T::DbWeight::get().reads(reads).saturating_add(twap_weight);
+            }
+
+            // Only trip when prices become stale, the breaker expires on its own
+            if previous.is_empty() {
+                T::CircuitBreaker::trip(CircuitBreakerTrigger::OracleStale);
+                Self::deposit_event(Event::PricesStale(stale.clone()));
+            }
+            StalePrices::<T>::put(stale);
+            T::DbWeight::get()
+                .reads_writes(reads, 1)
+                .saturating_add(twap_weight)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Set emergency price
@@ -164,6 +390,19 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// Feed the prices of an oracle member.
+        ///
+        /// Submissions go out as operational so that price updates still make it
+        /// into full blocks, which is when liquidations depend on them. Only members
+        /// of the oracle may feed, and they don't pay for it.
+        ///
+        /// Prices too far from the AMM TWAP of a checked asset are held back.
+        ///
+        /// - `values`: prices of the assets, at most `MaxFeedValues` of them.
+        #[pallet::weight((
+            <T as Config>::WeightInfo::feed_values(values.len() as u32),
+            DispatchClass::Operational,
+        ))]
         /// Set foreign vault token mapping
         #[pallet::weight((<T as Config>::WeightInfo::set_foreign_asset(), DispatchClass::Operational))]
         #[transactional]
@@ -176,10 +415,376 @@ pub mod pallet {
             ForeignToNativeAsset::<T>::insert(foreign_asset_id, asset_id);
             Ok(().into())
         }
+
+        /// Register an oracle parachain with the weight of its prices, or remove it
+        /// along with its prices if `weight` is `None`.
+        #[pallet::weight(<T as Config>::WeightInfo::set_xcm_oracle())]
+        #[transactional]
+        pub fn set_xcm_oracle(
+            origin: OriginFor<T>,
+            para_id: ParaId,
+            weight: Option<u32>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            match weight {
+                Some(weight) => {
+                    ensure!(weight > 0, Error::<T>::ZeroOracleWeight);
+                    XcmOracles::<T>::insert(para_id, weight);
+                }
+                None => {
+                    XcmOracles::<T>::remove(para_id);
+                    let _ = XcmPrices::<T>::clear_prefix(para_id, u32::MAX, None);
+                }
+            }
+            Self::deposit_event(Event::XcmOracleSet(para_id, weight));
+            Ok(().into())
+        }
+
+        /// Feed the prices of an oracle parachain, sent by it with XCM `Transact`.
+        ///
+        /// Prices too far from the AMM TWAP of a checked asset are dropped.
+        ///
+        /// - `values`: prices of the assets, at most `MaxFeedValues` of them.
+        #[pallet::weight((
+            <T as Config>::WeightInfo::feed_xcm_prices(values.len() as u32),
+            DispatchClass::Operational,
+        ))]
+        #[transactional]
+        pub fn feed_xcm_prices(
+            origin: OriginFor<T>,
+            values: Vec<(CurrencyId, Price)>,
+        ) -> DispatchResultWithPostInfo {
+            let para_id = T::XcmOracleOrigin::ensure_origin(origin)?;
+            ensure!(
+                XcmOracles::<T>::contains_key(para_id),
+                Error::<T>::UnknownXcmOracle
+            );
+            ensure!(
+                values.len() <= T::MaxFeedValues::get() as usize,
+                Error::<T>::TooManyValues
+            );
+            ensure!(
+                values.iter().all(|(_, price)| !price.is_zero()),
+                Error::<T>::ZeroPrice
+            );
+
+            let values: Vec<_> = values
+                .into_iter()
+                .filter(
+                    |(asset_id, price)| match Self::off_twap_price(*asset_id, *price) {
+                        Some(twap_price) => {
+                            Self::deposit_event(Event::XcmPriceDropped(
+                                para_id, *asset_id, *price, twap_price,
+                            ));
+                            false
+                        }
+                        None => true,
+                    },
+                )
+                .collect();
+            let timestamp = T::UnixTime::now().as_millis() as Timestamp;
+            for (asset_id, value) in values.iter() {
+                XcmPrices::<T>::insert(
+                    para_id,
+                    asset_id,
+                    TimeStampedPrice {
+                        value: *value,
+                        timestamp,
+                    },
+                );
+            }
+            Self::deposit_event(Event::XcmPricesFed(para_id, values));
+            Ok(().into())
+        }
+
+        /// Check the prices fed for an asset against the TWAP of its AMM pool with
+        /// `check.quote_asset`, or stop checking them if `check` is `None`.
+        #[pallet::weight(<T as Config>::WeightInfo::set_twap_check())]
+        #[transactional]
+        pub fn set_twap_check(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId,
+            check: Option<TwapCheck>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            match check {
+                Some(check) => {
+                    ensure!(check.quote_asset != asset_id, Error::<T>::InvalidTwapCheck);
+                    TwapChecks::<T>::insert(asset_id, check);
+                }
+                None => TwapChecks::<T>::remove(asset_id),
+            }
+            // the pool may have changed, observe it afresh
+            TwapObservations::<T>::remove(asset_id);
+            Self::deposit_event(Event::TwapCheckSet(asset_id, check));
+            Ok(().into())
+        }
+
+        /// Feed a held price to the oracle on behalf of its feeder if `feed`, drop it
+        /// otherwise.
+        #[pallet::weight(<T as Config>::WeightInfo::resolve_held_price())]
+        #[transactional]
+        pub fn resolve_held_price(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId,
+            feeder: T::AccountId,
+            feed: bool,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let price = HeldPrices::<T>::take(asset_id, &feeder).ok_or(Error::<T>::NoHeldPrice)?;
+            if feed {
+                T::Oracle::feed_values(feeder.clone(), vec![(asset_id, price)])?;
+            }
+            Self::deposit_event(Event::HeldPriceResolved(asset_id, feeder, feed));
+            Ok(().into())
+        }
+
+        #[transactional]
+        pub fn feed_values(
+            origin: OriginFor<T>,
+            values: Vec<(CurrencyId, Price)>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(T::Feeders::contains(&who), Error::<T>::NotFeeder);
+            ensure!(
+                values.len() <= T::MaxFeedValues::get() as usize,
+                Error::<T>::TooManyValues
+            );
+            let values: Vec<_> = values
+                .into_iter()
+                .filter(|(asset_id, price)| !Self::hold_off_twap_price(&who, *asset_id, *price))
+                .collect();
+            if !values.is_empty() {
+                T::Oracle::feed_values(who, values)?;
+            }
+            Ok(Pays::No.into())
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
+    /// Round `round_id` of `asset_id`, if it is still kept
+    pub fn get_round(
+        asset_id: CurrencyId,
+        round_id: RoundId,
+    ) -> Option<Round<T::AccountId, T::BlockNumber>> {
+        Self::rounds(asset_id, round_id)
+    }
+
+    /// Activity of every feeder which fed the oracle
+    pub fn get_feeder_status() -> Vec<(T::AccountId, FeederStatus<T::BlockNumber>)> {
+        FeederStatuses::<T>::iter().collect()
+    }
+
+    // the first price fed for an asset in a block opens a new round
+    fn record_submission(who: &T::AccountId, asset_id: CurrencyId, price: Price) {
+        let now = frame_system::Pallet::<T>::block_number();
+        let current = Self::current_round(asset_id);
+        let (round_id, mut round) = match Self::rounds(asset_id, current) {
+            Some(round) if round.started_at == now => (current, round),
+            Some(_) => (current.saturating_add(1), Round::new(now)),
+            None => (current, Round::new(now)),
+        };
+
+        round.submit(who.clone(), price, T::MinimumCount::get());
+        let deviation = round.deviation(price);
+        Rounds::<T>::insert(asset_id, round_id, round);
+        if round_id != current {
+            CurrentRound::<T>::insert(asset_id, round_id);
+            if let Some(pruned) = round_id.checked_sub(T::MaxRoundsKept::get()) {
+                Rounds::<T>::remove(asset_id, pruned);
+            }
+        }
+
+        FeederStatuses::<T>::mutate(who, |status| {
+            let status = status.get_or_insert_with(Default::default);
+            status.last_submission = now;
+            status.submissions = status.submissions.saturating_add(1);
+            status.last_deviation = deviation;
+        });
+    }
+
+    /// Weighted median of the fresh prices of our own oracle `local` and of the oracle
+    /// parachains, our own price alone if the prices within `MaxPriceDeviation` of the
+    /// median don't reach `PriceQuorum`.
+    ///
+    /// The median is as old as the oldest price within `MaxPriceDeviation` of it.
+    fn aggregate_price(
+        asset_id: &CurrencyId,
+        local: Option<TimeStampedPrice>,
+    ) -> Option<TimeStampedPrice> {
+        let now = T::UnixTime::now().as_millis() as Timestamp;
+        let is_fresh =
+            |price: &TimeStampedPrice| now.saturating_sub(price.timestamp) <= T::MaxPriceAge::get();
+        let mut sources: Vec<(u32, TimeStampedPrice)> = XcmOracles::<T>::iter()
+            .filter_map(|(para_id, weight)| Some((weight, Self::xcm_price(para_id, asset_id)?)))
+            .filter(|(weight, price)| !weight.is_zero() && is_fresh(price))
+            .collect();
+        if sources.is_empty() {
+            return local;
+        }
+        if let Some(price) = local.filter(is_fresh) {
+            sources.push((T::LocalOracleWeight::get(), price));
+        }
+
+        sources.sort_by(|(_, a), (_, b)| a.value.cmp(&b.value));
+        let total_weight = sources
+            .iter()
+            .fold(0u32, |total, (weight, _)| total.saturating_add(*weight));
+        let mut cumulated = 0u32;
+        let median = sources
+            .iter()
+            .find(|(weight, _)| {
+                cumulated = cumulated.saturating_add(*weight);
+                cumulated.saturating_mul(2) >= total_weight
+            })
+            .map(|(_, price)| price.value)?;
+        if median.is_zero() {
+            return local;
+        }
+
+        let (weight, timestamp) = sources
+            .iter()
+            .filter(|(_, price)| {
+                let gap = if price.value > median {
+                    price.value.saturating_sub(median)
+                } else {
+                    median.saturating_sub(price.value)
+                };
+                Ratio::from_rational(gap.into_inner(), median.into_inner())
+                    <= T::MaxPriceDeviation::get()
+            })
+            .fold((0u32, Timestamp::MAX), |(weight, timestamp), (w, price)| {
+                (weight.saturating_add(*w), timestamp.min(price.timestamp))
+            });
+        if weight < T::PriceQuorum::get() {
+            return local;
+        }
+
+        Some(TimeStampedPrice {
+            value: median,
+            timestamp,
+        })
+    }
+
+    /// Price of `asset_id` implied by the TWAP of its AMM pool, valued at the price
+    /// of the quote asset, if the prices of `asset_id` are checked
+    pub fn twap_price(asset_id: &CurrencyId) -> Option<Price> {
+        let check = Self::twap_check(asset_id)?;
+        let twap = Self::twap_observation(asset_id)?.twap?;
+        // the pool trades the smallest units, while prices are fed for whole units
+        let (quote_price, _) = Self::get_price(&check.quote_asset)?;
+        let mantissa = Self::get_asset_mantissa(asset_id)?;
+        twap.checked_mul(&quote_price)?
+            .checked_mul(&Price::saturating_from_integer(mantissa))
+    }
+
+    // the TWAP price of `asset_id` if `price` is too far from it
+    fn off_twap_price(asset_id: CurrencyId, price: Price) -> Option<Price> {
+        let (check, twap_price) = Self::twap_check(asset_id)
+            .zip(Self::twap_price(&asset_id))
+            .filter(|(_, twap_price)| !twap_price.is_zero())?;
+        let gap = if price > twap_price {
+            price.saturating_sub(twap_price)
+        } else {
+            twap_price.saturating_sub(price)
+        };
+        (Ratio::from_rational(gap.into_inner(), twap_price.into_inner()) > check.max_deviation)
+            .then_some(twap_price)
+    }
+
+    // holds `price` back from the oracle if it is too far from the TWAP price
+    fn hold_off_twap_price(who: &T::AccountId, asset_id: CurrencyId, price: Price) -> bool {
+        let twap_price = match Self::off_twap_price(asset_id, price) {
+            Some(twap_price) => twap_price,
+            None => return false,
+        };
+
+        HeldPrices::<T>::insert(asset_id, who, price);
+        Self::deposit_event(Event::PriceHeld(asset_id, who.clone(), price, twap_price));
+        true
+    }
+
+    // observe the pools of the checked assets, working out their TWAP once per period
+    fn observe_pools(now: T::BlockNumber) -> Weight {
+        let mut checks = 0u64;
+        for (asset_id, check) in TwapChecks::<T>::iter() {
+            checks = checks.saturating_add(1);
+            let cumulative = match Self::cumulative_price(asset_id, check.quote_asset, now) {
+                Some(cumulative) => cumulative,
+                None => continue,
+            };
+            let twap = match Self::twap_observation(asset_id) {
+                Some(last) if now.saturating_sub(last.block) < T::TwapPeriod::get() => continue,
+                Some(last) => {
+                    let blocks: u128 = now.saturating_sub(last.block).saturated_into();
+                    cumulative
+                        .checked_sub(&last.cumulative)
+                        .and_then(|sum| sum.checked_div(&Price::saturating_from_integer(blocks)))
+                }
+                None => None,
+            };
+            TwapObservations::<T>::insert(
+                asset_id,
+                TwapObservation {
+                    block: now,
+                    cumulative,
+                    twap,
+                },
+            );
+        }
+        // the check, the pool and the observation of each asset
+        T::DbWeight::get().reads_writes(checks.saturating_mul(3).saturating_add(1), checks)
+    }
+
+    // cumulative price of `asset_id` in `quote_asset` up to `now`, carrying the
+    // current price of the pool over the blocks since its last trade
+    fn cumulative_price(
+        asset_id: CurrencyId,
+        quote_asset: CurrencyId,
+        now: T::BlockNumber,
+    ) -> Option<Price> {
+        let pool = T::AMM::get_pool_by_asset_pair((asset_id, quote_asset))
+            .filter(|pool| !pool.is_empty())?;
+        // the pool keeps the larger asset id as its base
+        let (cumulative, reserve, quote_reserve) = if asset_id > quote_asset {
+            (
+                pool.price_0_cumulative_last,
+                pool.base_amount,
+                pool.quote_amount,
+            )
+        } else {
+            (
+                pool.price_1_cumulative_last,
+                pool.quote_amount,
+                pool.base_amount,
+            )
+        };
+        let price = Price::checked_from_rational(quote_reserve, reserve)?;
+        let blocks: u128 = now
+            .saturating_sub(pool.block_timestamp_last)
+            .saturated_into();
+        price
+            .checked_mul(&Price::saturating_from_integer(blocks))?
+            .checked_add(&Price::from_inner(cumulative))
+    }
+
+    // assets with an emergency price don't rely on the oracle
+    fn collect_stale_prices(
+        values: Vec<(CurrencyId, Option<TimeStampedPrice>)>,
+    ) -> Vec<CurrencyId> {
+        let now = T::UnixTime::now().as_millis() as Timestamp;
+        values
+            .into_iter()
+            .filter_map(|(asset_id, price)| {
+                let price = price?;
+                (now.saturating_sub(price.timestamp) > T::MaxPriceAge::get()
+                    && !EmergencyPrice::<T>::contains_key(asset_id))
+                .then_some(asset_id)
+            })
+            .collect()
+    }
+
     // get emergency price, the timestamp is zero
     fn get_emergency_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
         Self::emergency_price(asset_id).and_then(|p| {
@@ -290,6 +895,33 @@ impl<T: Config> Pallet<T> {
             timestamp: vault_asset_price.timestamp,
         })
     }
+
+    // `2 * sqrt(base_value * quote_value) / lp_supply` where the values of the pooled
+    // assets come from their prices, which holds as long as the pool keeps `x * y = k`
+    // and is arbitraged to those prices. The timestamp is the one of the oldest price.
+    fn get_amm_lp_asset_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
+        let (base_asset, quote_asset, pool) = T::AMM::get_pool_by_lp_asset(*asset_id)?;
+        let (base_price, base_timestamp) = Self::get_price(&base_asset)?;
+        let (quote_price, quote_timestamp) = Self::get_price(&quote_asset)?;
+        let base_value = base_price.checked_mul(&FixedU128::from_inner(pool.base_amount))?;
+        let quote_value = quote_price.checked_mul(&FixedU128::from_inner(pool.quote_amount))?;
+        let lp_asset_value = U256::from(base_value.into_inner())
+            .saturating_mul(U256::from(quote_value.into_inner()))
+            .integer_sqrt()
+            .checked_mul(U256::from(2_u128))
+            .and_then(|r| TryInto::<u128>::try_into(r).ok())?;
+        let lp_asset_total_supply = T::Assets::total_issuance(*asset_id);
+        let lp_asset_price = FixedU128::from_inner(lp_asset_value)
+            .checked_div(&FixedU128::from_inner(lp_asset_total_supply))?;
+        log::trace!(
+            target: "prices::get_amm_lp_asset_price",
+            "asset_id: {:?}, lp_asset_value: {:?}, lp_asset_total_supply: {:?}",
+            asset_id,
+            lp_asset_value,
+            lp_asset_total_supply
+        );
+        Some((lp_asset_price, base_timestamp.min(quote_timestamp)))
+    }
 }
 
 impl<T: Config> PriceFeeder for Pallet<T> {
@@ -302,13 +934,16 @@ impl<T: Config> PriceFeeder for Pallet<T> {
     /// Timestamp is zero means the price is emergency price
     fn get_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
         // if emergency price exists, return it
-        Self::get_emergency_price(asset_id).or_else(|| {
-            let mantissa = Self::get_asset_mantissa(asset_id)?;
-            T::Source::get(&T::RelayCurrency::get())
-                .and_then(|base_price| Self::get_special_asset_price(*asset_id, base_price))
-                .or_else(|| T::Source::get(asset_id))
-                .and_then(|price| Self::normalize_detail_price(price, mantissa))
-        })
+        Self::get_emergency_price(asset_id)
+            .or_else(|| {
+                let mantissa = Self::get_asset_mantissa(asset_id)?;
+                let relay_currency = T::RelayCurrency::get();
+                Self::aggregate_price(&relay_currency, T::Source::get(&relay_currency))
+                    .and_then(|base_price| Self::get_special_asset_price(*asset_id, base_price))
+                    .or_else(|| Self::aggregate_price(asset_id, T::Source::get(asset_id)))
+                    .and_then(|price| Self::normalize_detail_price(price, mantissa))
+            })
+            .or_else(|| Self::get_amm_lp_asset_price(asset_id))
     }
 }
 
@@ -330,12 +965,19 @@ impl<T: Config> EmergencyPriceFeeder<CurrencyId, Price> for Pallet<T> {
 impl<T: Config> DataProviderExtended<CurrencyId, TimeStampedPrice> for Pallet<T> {
     fn get_no_op(asset_id: &CurrencyId) -> Option<TimeStampedPrice> {
         let _mantissa = Self::get_asset_mantissa(asset_id)?;
-        T::Source::get_no_op(&T::RelayCurrency::get())
+        let relay_currency = T::RelayCurrency::get();
+        Self::aggregate_price(&relay_currency, T::Source::get_no_op(&relay_currency))
             .and_then(|base_price| Self::get_special_asset_price(*asset_id, base_price))
-            .or_else(|| T::Source::get_no_op(asset_id))
+            .or_else(|| Self::aggregate_price(asset_id, T::Source::get_no_op(asset_id)))
     }
 
     fn get_all_values() -> Vec<(CurrencyId, Option<TimeStampedPrice>)> {
         T::Source::get_all_values()
     }
 }
+
+impl<T: Config> OnNewData<T::AccountId, CurrencyId, Price> for Pallet<T> {
+    fn on_new_data(who: &T::AccountId, asset_id: &CurrencyId, price: &Price) {
+        Self::record_submission(who, *asset_id, *price);
+    }
+}
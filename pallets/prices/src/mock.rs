@@ -22,6 +22,7 @@ use frame_support::{
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{testing::Header, traits::IdentityLookup, FixedPointNumber};
+use std::cell::RefCell;
 
 pub use primitives::tokens::{CDOT_7_14, CKSM_20_27, DOT, KSM, LP_DOT_CDOT_7_14, SDOT, SKSM};
 
@@ -97,7 +98,7 @@ impl DataProviderExtended<CurrencyId, TimeStampedPrice> for MockDataProvider {
     }
 
     fn get_all_values() -> Vec<(CurrencyId, Option<TimeStampedPrice>)> {
-        vec![]
+        vec![(DOT, Self::get_no_op(&DOT)), (KSM, Self::get_no_op(&KSM))]
     }
 }
 
@@ -107,6 +108,41 @@ impl DataFeeder<CurrencyId, TimeStampedPrice, AccountId> for MockDataProvider {
     }
 }
 
+impl OracleFeeder<AccountId> for MockDataProvider {
+    fn feed_values(who: AccountId, values: Vec<(CurrencyId, Price)>) -> sp_runtime::DispatchResult {
+        values
+            .iter()
+            .for_each(|(asset_id, price)| Prices::on_new_data(&who, asset_id, price));
+        Ok(())
+    }
+}
+
+// the emergency price of an asset stands in for the calls relying on its price
+pub struct MockPriceDependencies;
+impl PriceDependencies<AccountId, RuntimeCall> for MockPriceDependencies {
+    fn price_dependencies(_who: &AccountId, call: &RuntimeCall) -> Vec<CurrencyId> {
+        match call {
+            RuntimeCall::Prices(crate::Call::set_price { asset_id, .. }) => vec![*asset_id],
+            _ => vec![],
+        }
+    }
+}
+
+thread_local! {
+    pub static NOW: RefCell<u64> = RefCell::new(0);
+}
+
+pub fn set_now(now: u64) {
+    NOW.with(|v| *v.borrow_mut() = now)
+}
+
+pub struct MockUnixTime;
+impl UnixTime for MockUnixTime {
+    fn now() -> core::time::Duration {
+        core::time::Duration::from_millis(NOW.with(|v| *v.borrow()))
+    }
+}
+
 pub struct LiquidStakingExchangeRateProvider;
 impl ExchangeRateProvider<CurrencyId> for LiquidStakingExchangeRateProvider {
     fn get_exchange_rate(_: &CurrencyId) -> Option<Rate> {
@@ -176,6 +212,10 @@ impl LoansMarketDataProvider<CurrencyId, Balance> for VaultLoansRateProvider {
 
 parameter_types! {
     pub const RelayCurrency: CurrencyId = DOT;
+    pub const MaxPriceAge: u64 = 60_000;
+    pub const MinimumCount: u32 = 2;
+    pub const MaxRoundsKept: u32 = 3;
+    pub const MaxFeedValues: u32 = 2;
     pub const NativeCurrencyId: CurrencyId = 1;
 }
 
@@ -261,6 +301,33 @@ impl pallet_amm::Config for Test {
     type MinimumLiquidity = MinimumLiquidity;
     type MaxLengthRoute = MaxLengthRoute;
     type GetNativeCurrencyId = NativeCurrencyId;
+    type Revenue = ();
+    type Referrals = ();
+}
+
+parameter_types! {
+    pub const LocalOracleWeight: u32 = 2;
+    pub const PriceQuorum: u32 = 3;
+    pub const MaxPriceDeviation: Ratio = Ratio::from_percent(10);
+    pub const TwapPeriod: BlockNumber = 10;
+}
+
+pub const ORACLE_PARA: AccountId = 2000;
+
+/// Accounts from 2000 on stand for the sibling parachain of the same id
+pub struct MockXcmOracleOrigin;
+impl EnsureOrigin<RuntimeOrigin> for MockXcmOracleOrigin {
+    type Success = ParaId;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        let raw: Result<frame_system::RawOrigin<AccountId>, RuntimeOrigin> = o.clone().into();
+        match raw {
+            Ok(frame_system::RawOrigin::Signed(who)) if who >= ORACLE_PARA => {
+                Ok(ParaId::from(who as u32))
+            }
+            _ => Err(o),
+        }
+    }
 }
 
 impl crate::Config for Test {
@@ -277,6 +344,20 @@ impl crate::Config for Test {
     type Decimal = Decimal;
     type AMM = DefaultAMM;
     type Assets = CurrencyAdapter;
+    type UnixTime = MockUnixTime;
+    type MaxPriceAge = MaxPriceAge;
+    type CircuitBreaker = ();
+    type MinimumCount = MinimumCount;
+    type MaxRoundsKept = MaxRoundsKept;
+    type Oracle = MockDataProvider;
+    type Feeders = One;
+    type MaxFeedValues = MaxFeedValues;
+    type PriceDependencies = MockPriceDependencies;
+    type XcmOracleOrigin = MockXcmOracleOrigin;
+    type LocalOracleWeight = LocalOracleWeight;
+    type PriceQuorum = PriceQuorum;
+    type MaxPriceDeviation = MaxPriceDeviation;
+    type TwapPeriod = TwapPeriod;
     type WeightInfo = ();
 }
 
@@ -15,11 +15,12 @@
 //! Unit tests for the prices pallet.
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo};
 use mock::{RuntimeEvent, *};
 use primitives::TimeStampedPrice;
 use sp_runtime::{
-    traits::{BadOrigin, Saturating},
+    traits::{BadOrigin, Saturating, SignedExtension, Zero},
+    transaction_validity::InvalidTransaction,
     FixedPointNumber,
 };
 
@@ -287,6 +288,44 @@ fn get_lp_ctoken_no_op_price_work() {
     });
 }
 
+#[test]
+fn get_amm_lp_price_work() {
+    new_test_ext().execute_with(|| {
+        const LP_DOT_KSM: CurrencyId = 7000;
+        Assets::force_create(RuntimeOrigin::root(), KSM, ALICE, true, 1).unwrap();
+        Assets::force_create(RuntimeOrigin::root(), LP_DOT_KSM, ALICE, true, 1).unwrap();
+        Assets::mint(RuntimeOrigin::signed(ALICE), KSM, ALICE, 1000 * PRICE_ONE).unwrap();
+
+        // no price before the pool exists
+        assert_eq!(Prices::get_price(&LP_DOT_KSM), None);
+
+        // $100_000_000_000 of each asset
+        DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, KSM),
+            (10 * PRICE_ONE, 200 * PRICE_ONE),
+            ALICE,
+            LP_DOT_KSM,
+        )
+        .unwrap();
+
+        let (lp_price, timestamp) = Prices::get_price(&LP_DOT_KSM).unwrap();
+        assert_eq!(timestamp, 0);
+        let lp_value =
+            lp_price.saturating_mul(FixedU128::from_inner(Assets::total_issuance(LP_DOT_KSM)));
+        assert!(lp_value > Price::saturating_from_integer(199_999_999_999_u128));
+        assert!(lp_value <= Price::saturating_from_integer(200_000_000_000_u128));
+
+        // skewing the reserves barely moves the price, only the trading fee adds value
+        <DefaultAMM as AMM<_, _, _, _>>::swap(&ALICE, (DOT, KSM), 5 * PRICE_ONE).unwrap();
+        let (skewed_lp_price, _) = Prices::get_price(&LP_DOT_KSM).unwrap();
+        assert!(skewed_lp_price >= lp_price);
+        assert!(
+            skewed_lp_price < lp_price.saturating_mul(Price::saturating_from_rational(101, 100))
+        );
+    });
+}
+
 #[test]
 fn get_foreign_ctoken_price_work() {
     new_test_ext().execute_with(|| {
@@ -303,3 +342,523 @@ fn get_foreign_ctoken_price_work() {
         assert_eq!(Prices::get_price(&CDOT_7_14), Prices::get_price(&LC_DOT));
     });
 }
+
+#[test]
+fn stale_oracle_prices_are_detected() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Prices::on_initialize(1);
+        assert!(Prices::stale_prices().is_empty());
+
+        assert_ok!(Prices::set_price(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            Price::saturating_from_integer(90),
+        ));
+        set_now(60_001);
+        Prices::on_initialize(2);
+        // DOT has an emergency price
+        assert_eq!(Prices::stale_prices(), vec![KSM]);
+        System::assert_last_event(RuntimeEvent::Prices(crate::Event::PricesStale(vec![KSM])));
+
+        assert_ok!(Prices::reset_price(RuntimeOrigin::signed(ALICE), DOT));
+        Prices::on_initialize(3);
+        assert_eq!(Prices::stale_prices(), vec![DOT, KSM]);
+        // Prices were already stale
+        System::assert_last_event(RuntimeEvent::Prices(crate::Event::ResetPrice(DOT)));
+    });
+}
+
+#[test]
+fn oracle_feeds_are_recorded_in_rounds() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Prices::on_new_data(&ALICE, &DOT, &Price::saturating_from_integer(100));
+        let round = Prices::get_round(DOT, 0).unwrap();
+        assert_eq!(round.median, Price::saturating_from_integer(100));
+        assert!(!round.quorum_reached);
+
+        Prices::on_new_data(&CHARLIE, &DOT, &Price::saturating_from_integer(110));
+        let round = Prices::get_round(DOT, 0).unwrap();
+        assert_eq!(round.started_at, 1);
+        assert_eq!(round.submissions.len(), 2);
+        assert_eq!(round.median, Price::saturating_from_integer(105));
+        assert_eq!(round.max_deviation, Ratio::from_rational(5u32, 105u32));
+        assert!(round.quorum_reached);
+
+        // A new block opens a new round
+        System::set_block_number(2);
+        Prices::on_new_data(&ALICE, &DOT, &Price::saturating_from_integer(120));
+        assert_eq!(Prices::current_round(DOT), 1);
+        assert_eq!(
+            Prices::get_round(DOT, 1).unwrap().submissions,
+            vec![(ALICE, Price::saturating_from_integer(120))]
+        );
+
+        let mut statuses = Prices::get_feeder_status();
+        statuses.sort_by_key(|(who, _)| *who);
+        assert_eq!(
+            statuses,
+            vec![
+                (
+                    ALICE,
+                    FeederStatus {
+                        last_submission: 2,
+                        submissions: 2,
+                        last_deviation: Ratio::zero(),
+                    }
+                ),
+                (
+                    CHARLIE,
+                    FeederStatus {
+                        last_submission: 1,
+                        submissions: 1,
+                        last_deviation: Ratio::from_rational(5u32, 105u32),
+                    }
+                ),
+            ]
+        );
+    });
+}
+
+#[test]
+fn old_rounds_are_pruned() {
+    new_test_ext().execute_with(|| {
+        for block in 1..=4 {
+            System::set_block_number(block);
+            Prices::on_new_data(&ALICE, &DOT, &Price::saturating_from_integer(100));
+        }
+
+        // MaxRoundsKept is 3
+        assert_eq!(Prices::current_round(DOT), 3);
+        assert!(Prices::get_round(DOT, 0).is_none());
+        assert!((1..=3).all(|round_id| Prices::get_round(DOT, round_id).is_some()));
+    });
+}
+
+#[test]
+fn feeders_feed_values_for_free() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let values = vec![
+            (DOT, Price::saturating_from_integer(100)),
+            (KSM, Price::saturating_from_integer(50)),
+        ];
+        let post_info = Prices::feed_values(RuntimeOrigin::signed(ALICE), values).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        assert_eq!(
+            Prices::get_round(KSM, 0).unwrap().submissions,
+            vec![(ALICE, Price::saturating_from_integer(50))]
+        );
+
+        let info = Call::<Test>::feed_values { values: vec![] }.get_dispatch_info();
+        assert_eq!(info.class, DispatchClass::Operational);
+    });
+}
+
+#[test]
+fn feed_values_requires_feeder_membership() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Prices::feed_values(
+                RuntimeOrigin::signed(CHARLIE),
+                vec![(DOT, Price::saturating_from_integer(100))]
+            ),
+            Error::<Test>::NotFeeder
+        );
+        // MaxFeedValues is 2
+        assert_noop!(
+            Prices::feed_values(
+                RuntimeOrigin::signed(ALICE),
+                vec![
+                    (DOT, Price::saturating_from_integer(100)),
+                    (KSM, Price::saturating_from_integer(50)),
+                    (SDOT, Price::saturating_from_integer(1)),
+                ]
+            ),
+            Error::<Test>::TooManyValues
+        );
+    });
+}
+
+#[test]
+fn transactions_relying_on_stale_prices_are_rejected() {
+    new_test_ext().execute_with(|| {
+        let check = CheckPriceFreshness::<Test>::new();
+        let call = |asset_id| {
+            RuntimeCall::Prices(crate::Call::set_price {
+                asset_id,
+                price: Price::saturating_from_integer(90),
+            })
+        };
+        let info = call(KSM).get_dispatch_info();
+        assert!(check.validate(&ALICE, &call(KSM), &info, 0).is_ok());
+
+        set_now(60_001);
+        Prices::on_initialize(1);
+        assert_eq!(Prices::stale_prices(), vec![DOT, KSM]);
+        assert_eq!(
+            check.validate(&ALICE, &call(KSM), &info, 0),
+            Err(InvalidTransaction::Custom(extension::STALE_PRICE_ERROR).into())
+        );
+        assert!(check
+            .clone()
+            .pre_dispatch(&ALICE, &call(KSM), &info, 0)
+            .is_err());
+        // SDOT doesn't have an oracle price
+        assert!(check.validate(&ALICE, &call(SDOT), &info, 0).is_ok());
+    });
+}
+
+#[test]
+fn set_xcm_oracle_works() {
+    new_test_ext().execute_with(|| {
+        let para_id = ParaId::from(ORACLE_PARA as u32);
+        assert_noop!(
+            Prices::set_xcm_oracle(RuntimeOrigin::signed(CHARLIE), para_id, Some(1)),
+            BadOrigin
+        );
+        assert_noop!(
+            Prices::set_xcm_oracle(RuntimeOrigin::signed(ALICE), para_id, Some(0)),
+            Error::<Test>::ZeroOracleWeight
+        );
+
+        assert_ok!(Prices::set_xcm_oracle(
+            RuntimeOrigin::signed(ALICE),
+            para_id,
+            Some(1)
+        ));
+        assert_eq!(Prices::xcm_oracle_weight(para_id), Some(1));
+        assert_ok!(Prices::feed_xcm_prices(
+            RuntimeOrigin::signed(ORACLE_PARA),
+            vec![(DOT, Price::saturating_from_integer(130))],
+        ));
+
+        // removing an oracle parachain drops its prices
+        assert_ok!(Prices::set_xcm_oracle(
+            RuntimeOrigin::signed(ALICE),
+            para_id,
+            None
+        ));
+        assert_eq!(Prices::xcm_oracle_weight(para_id), None);
+        assert_eq!(Prices::xcm_price(para_id, DOT), None);
+    });
+}
+
+#[test]
+fn feed_xcm_prices_requires_registered_oracle() {
+    new_test_ext().execute_with(|| {
+        let values = vec![(DOT, Price::saturating_from_integer(130))];
+        assert_noop!(
+            Prices::feed_xcm_prices(RuntimeOrigin::signed(ALICE), values.clone()),
+            BadOrigin
+        );
+        assert_noop!(
+            Prices::feed_xcm_prices(RuntimeOrigin::signed(ORACLE_PARA), values.clone()),
+            Error::<Test>::UnknownXcmOracle
+        );
+
+        assert_ok!(Prices::set_xcm_oracle(
+            RuntimeOrigin::signed(ALICE),
+            ParaId::from(ORACLE_PARA as u32),
+            Some(1)
+        ));
+        assert_noop!(
+            Prices::feed_xcm_prices(
+                RuntimeOrigin::signed(ORACLE_PARA),
+                vec![
+                    (DOT, Price::saturating_from_integer(130)),
+                    (KSM, Price::saturating_from_integer(500)),
+                    (SDOT, Price::saturating_from_integer(130)),
+                ],
+            ),
+            Error::<Test>::TooManyValues
+        );
+        assert_noop!(
+            Prices::feed_xcm_prices(
+                RuntimeOrigin::signed(ORACLE_PARA),
+                vec![
+                    (DOT, Price::saturating_from_integer(130)),
+                    (KSM, Price::zero())
+                ],
+            ),
+            Error::<Test>::ZeroPrice
+        );
+        assert_ok!(Prices::feed_xcm_prices(
+            RuntimeOrigin::signed(ORACLE_PARA),
+            values.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::Prices(crate::Event::XcmPricesFed(
+            ParaId::from(ORACLE_PARA as u32),
+            values,
+        )));
+    });
+}
+
+#[test]
+fn xcm_prices_are_aggregated_by_weighted_median() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let other_para = ORACLE_PARA + 1;
+        assert_ok!(Prices::set_xcm_oracle(
+            RuntimeOrigin::signed(ALICE),
+            ParaId::from(ORACLE_PARA as u32),
+            Some(1)
+        ));
+        assert_ok!(Prices::set_xcm_oracle(
+            RuntimeOrigin::signed(ALICE),
+            ParaId::from(other_para as u32),
+            Some(2)
+        ));
+        set_now(1_000);
+        assert_ok!(Prices::feed_xcm_prices(
+            RuntimeOrigin::signed(ORACLE_PARA),
+            vec![(DOT, Price::saturating_from_integer(130))],
+        ));
+        assert_ok!(Prices::feed_xcm_prices(
+            RuntimeOrigin::signed(other_para),
+            vec![(DOT, Price::saturating_from_integer(104))],
+        ));
+
+        // median of 100 (2), 104 (2) and 130 (1), as old as our own price as 130 is
+        // too far from it
+        assert_eq!(
+            Prices::get_no_op(&DOT),
+            Some(TimeStampedPrice {
+                value: Price::saturating_from_integer(104),
+                timestamp: 0
+            })
+        );
+        // KSM has no price from the oracle parachains
+        assert_eq!(
+            Prices::get_no_op(&KSM),
+            Some(TimeStampedPrice {
+                value: Price::saturating_from_integer(500),
+                timestamp: 0
+            })
+        );
+
+        // the median 130 has no quorum, our own price is used alone
+        assert_ok!(Prices::feed_xcm_prices(
+            RuntimeOrigin::signed(other_para),
+            vec![(DOT, Price::saturating_from_integer(150))],
+        ));
+        assert_eq!(
+            Prices::get_no_op(&DOT),
+            Some(TimeStampedPrice {
+                value: Price::saturating_from_integer(100),
+                timestamp: 0
+            })
+        );
+
+        // our own price is stale, the oracle parachains agree
+        set_now(60_001);
+        assert_ok!(Prices::feed_xcm_prices(
+            RuntimeOrigin::signed(ORACLE_PARA),
+            vec![(DOT, Price::saturating_from_integer(145))],
+        ));
+        assert_eq!(
+            Prices::get_no_op(&DOT),
+            Some(TimeStampedPrice {
+                value: Price::saturating_from_integer(150),
+                timestamp: 1_000
+            })
+        );
+
+        // stale prices of the oracle parachains are ignored
+        set_now(61_001);
+        assert_eq!(
+            Prices::get_no_op(&DOT),
+            Some(TimeStampedPrice {
+                value: Price::saturating_from_integer(100),
+                timestamp: 0
+            })
+        );
+    });
+}
+
+#[test]
+fn set_twap_check_works() {
+    new_test_ext().execute_with(|| {
+        let check = TwapCheck {
+            quote_asset: KSM,
+            max_deviation: Ratio::from_percent(10),
+        };
+        assert_noop!(
+            Prices::set_twap_check(RuntimeOrigin::signed(CHARLIE), DOT, Some(check)),
+            BadOrigin
+        );
+        assert_noop!(
+            Prices::set_twap_check(RuntimeOrigin::signed(ALICE), KSM, Some(check)),
+            Error::<Test>::InvalidTwapCheck
+        );
+
+        assert_ok!(Prices::set_twap_check(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            Some(check)
+        ));
+        assert_eq!(Prices::twap_check(DOT), Some(check));
+        assert_ok!(Prices::set_twap_check(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            None
+        ));
+        assert_eq!(Prices::twap_check(DOT), None);
+    });
+}
+
+#[test]
+fn prices_off_the_amm_twap_are_held() {
+    new_test_ext().execute_with(|| {
+        const LP_DOT_KSM: CurrencyId = 7000;
+        Assets::force_create(RuntimeOrigin::root(), KSM, ALICE, true, 1).unwrap();
+        Assets::force_create(RuntimeOrigin::root(), LP_DOT_KSM, ALICE, true, 1).unwrap();
+        Assets::mint(RuntimeOrigin::signed(ALICE), KSM, ALICE, 1000 * PRICE_ONE).unwrap();
+        // 1 DOT for 5 KSM, which is $100 at $500 per KSM given the decimals
+        DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, KSM),
+            (10 * PRICE_ONE, 200 * PRICE_ONE),
+            ALICE,
+            LP_DOT_KSM,
+        )
+        .unwrap();
+        assert_ok!(Prices::set_twap_check(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            Some(TwapCheck {
+                quote_asset: KSM,
+                max_deviation: Ratio::from_percent(10),
+            })
+        ));
+
+        System::set_block_number(1);
+        Prices::on_initialize(1);
+        // no TWAP until a whole period was observed
+        assert_eq!(Prices::twap_price(&DOT), None);
+        System::set_block_number(11);
+        Prices::on_initialize(11);
+        assert_eq!(
+            Prices::twap_price(&DOT),
+            Some(Price::saturating_from_integer(100))
+        );
+
+        assert_ok!(Prices::feed_values(
+            RuntimeOrigin::signed(ALICE),
+            vec![
+                (DOT, Price::saturating_from_integer(105)),
+                (KSM, Price::saturating_from_integer(500)),
+            ],
+        ));
+        assert_eq!(Prices::feeder_status(ALICE).unwrap().submissions, 2);
+
+        System::set_block_number(12);
+        assert_ok!(Prices::feed_values(
+            RuntimeOrigin::signed(ALICE),
+            vec![(DOT, Price::saturating_from_integer(150))],
+        ));
+        System::assert_last_event(RuntimeEvent::Prices(crate::Event::PriceHeld(
+            DOT,
+            ALICE,
+            Price::saturating_from_integer(150),
+            Price::saturating_from_integer(100),
+        )));
+        assert_eq!(Prices::feeder_status(ALICE).unwrap().submissions, 2);
+        assert_eq!(
+            Prices::held_price(DOT, ALICE),
+            Some(Price::saturating_from_integer(150))
+        );
+
+        assert_noop!(
+            Prices::resolve_held_price(RuntimeOrigin::signed(CHARLIE), DOT, ALICE, true),
+            BadOrigin
+        );
+        assert_ok!(Prices::resolve_held_price(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            ALICE,
+            true
+        ));
+        assert_eq!(Prices::feeder_status(ALICE).unwrap().submissions, 3);
+        assert_eq!(Prices::held_price(DOT, ALICE), None);
+        assert_noop!(
+            Prices::resolve_held_price(RuntimeOrigin::signed(ALICE), DOT, ALICE, false),
+            Error::<Test>::NoHeldPrice
+        );
+    });
+}
+
+#[test]
+fn xcm_prices_off_the_amm_twap_are_dropped() {
+    new_test_ext().execute_with(|| {
+        const LP_DOT_KSM: CurrencyId = 7000;
+        let para_id = ParaId::from(ORACLE_PARA as u32);
+        Assets::force_create(RuntimeOrigin::root(), KSM, ALICE, true, 1).unwrap();
+        Assets::force_create(RuntimeOrigin::root(), LP_DOT_KSM, ALICE, true, 1).unwrap();
+        Assets::mint(RuntimeOrigin::signed(ALICE), KSM, ALICE, 1000 * PRICE_ONE).unwrap();
+        // 1 DOT for 5 KSM, which is $100 at $500 per KSM given the decimals
+        DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, KSM),
+            (10 * PRICE_ONE, 200 * PRICE_ONE),
+            ALICE,
+            LP_DOT_KSM,
+        )
+        .unwrap();
+        assert_ok!(Prices::set_twap_check(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            Some(TwapCheck {
+                quote_asset: KSM,
+                max_deviation: Ratio::from_percent(10),
+            })
+        ));
+        assert_ok!(Prices::set_xcm_oracle(
+            RuntimeOrigin::signed(ALICE),
+            para_id,
+            Some(1)
+        ));
+
+        System::set_block_number(1);
+        Prices::on_initialize(1);
+        System::set_block_number(11);
+        Prices::on_initialize(11);
+        assert_ok!(Prices::feed_values(
+            RuntimeOrigin::signed(ALICE),
+            vec![(KSM, Price::saturating_from_integer(500))],
+        ));
+        assert_eq!(
+            Prices::twap_price(&DOT),
+            Some(Price::saturating_from_integer(100))
+        );
+
+        assert_ok!(Prices::feed_xcm_prices(
+            RuntimeOrigin::signed(ORACLE_PARA),
+            vec![
+                (DOT, Price::saturating_from_integer(150)),
+                (KSM, Price::saturating_from_integer(510)),
+            ],
+        ));
+        System::assert_has_event(RuntimeEvent::Prices(crate::Event::XcmPriceDropped(
+            para_id,
+            DOT,
+            Price::saturating_from_integer(150),
+            Price::saturating_from_integer(100),
+        )));
+        System::assert_last_event(RuntimeEvent::Prices(crate::Event::XcmPricesFed(
+            para_id,
+            vec![(KSM, Price::saturating_from_integer(510))],
+        )));
+        assert_eq!(Prices::xcm_price(para_id, DOT), None);
+
+        assert_ok!(Prices::feed_xcm_prices(
+            RuntimeOrigin::signed(ORACLE_PARA),
+            vec![(DOT, Price::saturating_from_integer(105))],
+        ));
+        assert_eq!(
+            Prices::xcm_price(para_id, DOT).map(|price| price.value),
+            Some(Price::saturating_from_integer(105))
+        );
+    });
+}
@@ -0,0 +1,119 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use primitives::{CurrencyId, Price, Ratio};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    FixedPointNumber, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+pub type RoundId = u32;
+
+/// Prices fed for an asset within a block, the oracle combines them into the asset price
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Round<AccountId, BlockNumber> {
+    pub started_at: BlockNumber,
+    /// Feeders in the order they submitted, along with their price
+    pub submissions: Vec<(AccountId, Price)>,
+    /// Median of the submitted prices
+    pub median: Price,
+    /// Largest gap between a submitted price and the median, relative to the median
+    pub max_deviation: Ratio,
+    /// Whether enough feeders submitted for the oracle to combine a price
+    pub quorum_reached: bool,
+}
+
+impl<AccountId, BlockNumber> Round<AccountId, BlockNumber> {
+    pub fn new(started_at: BlockNumber) -> Self {
+        Self {
+            started_at,
+            submissions: Vec::new(),
+            median: Price::zero(),
+            max_deviation: Ratio::zero(),
+            quorum_reached: false,
+        }
+    }
+
+    /// Add the price fed by `who` and refresh the statistics of the round
+    pub fn submit(&mut self, who: AccountId, price: Price, minimum_count: u32) {
+        self.submissions.push((who, price));
+
+        let mut prices: Vec<Price> = self.submissions.iter().map(|(_, p)| *p).collect();
+        prices.sort();
+        let middle = prices.len() / 2;
+        self.median = if prices.len() % 2 == 0 {
+            prices[middle - 1]
+                .saturating_add(prices[middle])
+                .saturating_mul(Price::saturating_from_rational(1, 2))
+        } else {
+            prices[middle]
+        };
+        self.max_deviation = prices
+            .iter()
+            .map(|p| self.deviation(*p))
+            .max()
+            .unwrap_or_default();
+        self.quorum_reached = self.submissions.len() >= minimum_count as usize;
+    }
+
+    /// Gap between `price` and the median, relative to the median
+    pub fn deviation(&self, price: Price) -> Ratio {
+        let gap = if price > self.median {
+            price.saturating_sub(self.median)
+        } else {
+            self.median.saturating_sub(price)
+        };
+        Ratio::from_rational(gap.into_inner(), self.median.into_inner())
+    }
+}
+
+/// Activity of an oracle feeder
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FeederStatus<BlockNumber> {
+    /// Block of the last price fed
+    pub last_submission: BlockNumber,
+    /// Number of prices fed so far
+    pub submissions: u32,
+    /// Gap between the last price fed and the median of its round, relative to the median
+    pub last_deviation: Ratio,
+}
+
+/// Cross-check of the prices fed for an asset against the TWAP of its AMM pool
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TwapCheck {
+    /// The other asset of the pool, the TWAP is valued at its price
+    pub quote_asset: CurrencyId,
+    /// Largest gap between a fed price and the TWAP price, relative to the TWAP price
+    pub max_deviation: Ratio,
+}
+
+/// Cumulative price of an asset in its AMM pool, observed once per TWAP period
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct TwapObservation<BlockNumber> {
+    pub block: BlockNumber,
+    pub cumulative: Price,
+    /// Average price between the previous observation and this one, in the quote asset
+    pub twap: Option<Price>,
+}
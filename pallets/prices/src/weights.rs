@@ -18,14 +18,26 @@
 #![allow(unused_imports)]
 #![allow(clippy::all)]
 
-use frame_support::weights::Weight;
+// The weights marked NOT BENCHMARKED YET were added by hand from the storage accesses of
+// their calls and are estimates until the pallet gets benchmarks.
+
+use frame_support::weights::{constants::RocksDbWeight, Weight};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions needed for pallet_prices.
 pub trait WeightInfo {
     fn set_price() -> Weight;
     fn reset_price() -> Weight;
     fn set_foreign_asset() -> Weight;
+    fn feed_values(n: u32) -> Weight;
+    fn set_xcm_oracle() -> Weight;
+    fn feed_xcm_prices(n: u32) -> Weight;
+    fn set_twap_check() -> Weight;
+    fn resolve_held_price() -> Weight;
 }
 
 /// Weights for pallet_prices using the Substrate node and recommended hardware.
@@ -40,6 +52,45 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn set_foreign_asset() -> Weight {
         Weight::from_ref_time(10_000 as u64)
     }
+    fn feed_values(n: u32) -> Weight {
+        Weight::from_ref_time(30_000_000 as u64)
+            // Standard Error: 10_000
+            .saturating_add(Weight::from_ref_time(12_000_000 as u64).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(3 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+            .saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((3 as u64).saturating_mul(n as u64)))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+            .saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+    }
+    // NOT BENCHMARKED YET
+    fn set_xcm_oracle() -> Weight {
+        Weight::from_ref_time(21_000_000 as u64)
+            .saturating_add(T::DbWeight::get().writes(2 as u64))
+    }
+    // NOT BENCHMARKED YET
+    fn feed_xcm_prices(n: u32) -> Weight {
+        Weight::from_ref_time(18_000_000 as u64)
+            // Standard Error: 5_000
+            .saturating_add(Weight::from_ref_time(3_000_000 as u64).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(1 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+            .saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((1 as u64).saturating_mul(n as u64)))
+            .saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+    }
+    // NOT BENCHMARKED YET
+    fn set_twap_check() -> Weight {
+        Weight::from_ref_time(20_000_000 as u64)
+            .saturating_add(T::DbWeight::get().writes(2 as u64))
+    }
+    // NOT BENCHMARKED YET
+    fn resolve_held_price() -> Weight {
+        Weight::from_ref_time(24_000_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(1 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
 }
 
 // For backwards compatibility and tests
@@ -53,4 +104,38 @@ impl WeightInfo for () {
     fn set_foreign_asset() -> Weight {
         Weight::from_ref_time(10_000 as u64)
     }
+    fn feed_values(n: u32) -> Weight {
+        Weight::from_ref_time(30_000_000 as u64)
+            // Standard Error: 10_000
+            .saturating_add(Weight::from_ref_time(12_000_000 as u64).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(3 as u64))
+            .saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1 as u64))
+            .saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+    }
+    // NOT BENCHMARKED YET
+    fn set_xcm_oracle() -> Weight {
+        Weight::from_ref_time(21_000_000 as u64)
+            .saturating_add(RocksDbWeight::get().writes(2 as u64))
+    }
+    // NOT BENCHMARKED YET
+    fn feed_xcm_prices(n: u32) -> Weight {
+        Weight::from_ref_time(18_000_000 as u64)
+            // Standard Error: 5_000
+            .saturating_add(Weight::from_ref_time(3_000_000 as u64).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(1 as u64))
+            .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+            .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+    }
+    // NOT BENCHMARKED YET
+    fn set_twap_check() -> Weight {
+        Weight::from_ref_time(20_000_000 as u64)
+            .saturating_add(RocksDbWeight::get().writes(2 as u64))
+    }
+    // NOT BENCHMARKED YET
+    fn resolve_held_price() -> Weight {
+        Weight::from_ref_time(24_000_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(1 as u64))
+            .saturating_add(RocksDbWeight::get().writes(1 as u64))
+    }
 }
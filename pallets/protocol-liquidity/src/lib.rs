@@ -0,0 +1,628 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Protocol Liquidity pallet
+//!
+//! ## Overview
+//!
+//! Deploys treasury assets as protocol owned liquidity into AMM pools and loans markets.
+//! Governance registers each venue with a strategy bounding the share of the venue the
+//! protocol may own and the band its share may drift in, then deploys to and withdraws
+//! from it. Once the share of a position drifted out of its band, anyone can rebalance
+//! it back to the target share, within the assets left in the treasury. Pools are only
+//! rebalanced while their price is close to the oracle prices, so that the protocol
+//! can't be made to add liquidity to a skewed pool.
+//!
+//! Positions are held by the pallet account, assets are taken from the treasury
+//! account and returned to it. The assets deployed and withdrawn are accounted per
+//! position, `position_report` compares them to the value of the position to give its
+//! profit and loss in each of the venue assets.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::tokens::fungibles::{Inspect, Transfer},
+    transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+use num_traits::cast::ToPrimitive;
+use pallet_traits::{
+    ConvertToBigUint, PriceFeeder, ProtocolConversion, ProvideLiquidity, UnwindLiquidity, AMM,
+};
+use primitives::{Balance, CurrencyId, Ratio};
+use sp_runtime::{
+    traits::{AccountIdConversion, Saturating, Zero},
+    ArithmeticError, FixedPointNumber, PerThing,
+};
+
+pub use pallet::*;
+pub use types::{Position, PositionReport, Strategy, Venue};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Pallet id of the account holding the positions
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Assets of the treasury and the liquidity tokens of the AMM pools
+        type Assets: Transfer<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
+            + Inspect<Self::AccountId, AssetId = CurrencyId, Balance = Balance>;
+
+        /// AMM the pool positions are deployed to
+        type AMM: AMM<Self::AccountId, CurrencyId, Balance, Self::BlockNumber>
+            + ProvideLiquidity<Self::AccountId, CurrencyId, Balance>
+            + UnwindLiquidity<Self::AccountId, CurrencyId, Balance>;
+
+        /// Loans markets the market positions are supplied to, as conversions between
+        /// the underlying assets and their pTokens
+        type Markets: ProtocolConversion<Self::AccountId, CurrencyId, Balance>;
+
+        /// pTokens of the loans markets
+        type PTokens: Inspect<Self::AccountId, AssetId = CurrencyId, Balance = Balance>;
+
+        /// Prices the pools are checked against before being rebalanced
+        type PriceFeeder: PriceFeeder;
+
+        /// How far the price of a pool may be from the oracle prices for it to be
+        /// rebalanced
+        #[pallet::constant]
+        type MaxPriceDeviation: Get<Ratio>;
+
+        /// Account the deployed assets are taken from and returned to
+        type TreasuryAccount: Get<Self::AccountId>;
+
+        /// The origin which can manage the positions
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Position was added to a venue
+        /// [venue, strategy]
+        PositionAdded(Venue, Strategy),
+        /// Strategy of a position was updated
+        /// [venue, strategy]
+        StrategyUpdated(Venue, Strategy),
+        /// Empty position was removed
+        /// [venue]
+        PositionRemoved(Venue),
+        /// Treasury assets were deployed into a venue
+        /// [venue, amount_0, amount_1, share]
+        Deployed(Venue, Balance, Balance, Ratio),
+        /// Assets were withdrawn from a venue back to the treasury
+        /// [venue, amount_0, amount_1, share]
+        Withdrawn(Venue, Balance, Balance, Ratio),
+        /// Position was brought back to its target share
+        /// [venue, share_before, share_after]
+        Rebalanced(Venue, Ratio, Ratio),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Pool or market doesn't exist
+        VenueNotFound,
+        /// Venue already has a position
+        PositionAlreadyExists,
+        /// Venue has no position
+        PositionNotFound,
+        /// Position still holds liquidity
+        PositionNotEmpty,
+        /// Target share with its band doesn't fit below the max share
+        InvalidStrategy,
+        /// Amount is zero
+        InvalidAmount,
+        /// Position would own more of the venue than its max share
+        MaxShareExceeded,
+        /// Share of the position is within its band
+        WithinBand,
+        /// Treasury doesn't hold the assets to rebalance the position
+        InsufficientTreasuryBalance,
+        /// Price of a pool asset is unavailable
+        PriceUnavailable,
+        /// Pool price is too far from the oracle prices
+        PriceDeviationTooHigh,
+    }
+
+    /// Protocol owned liquidity positions
+    /// Venue -> Position
+    #[pallet::storage]
+    #[pallet::getter(fn positions)]
+    pub type Positions<T: Config> = StorageMap<_, Twox64Concat, Venue, Position, OptionQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Add a position to a pool or a market.
+        ///
+        /// - `venue`: the pool or market, pool assets may be given in any order
+        /// - `strategy`: the constraints on the share of the venue owned
+        #[pallet::weight(T::WeightInfo::add_position())]
+        #[transactional]
+        pub fn add_position(
+            origin: OriginFor<T>,
+            venue: Venue,
+            strategy: Strategy,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::ensure_valid_strategy(&strategy)?;
+            let venue = Self::resolve_venue(venue)?;
+            ensure!(
+                !Positions::<T>::contains_key(venue),
+                Error::<T>::PositionAlreadyExists
+            );
+
+            Positions::<T>::insert(
+                venue,
+                Position {
+                    strategy,
+                    deployed: (Zero::zero(), Zero::zero()),
+                    withdrawn: (Zero::zero(), Zero::zero()),
+                },
+            );
+            Self::deposit_event(Event::<T>::PositionAdded(venue, strategy));
+            Ok(())
+        }
+
+        /// Update the strategy of a position, a position above its new max share
+        /// isn't reduced until it is rebalanced.
+        ///
+        /// - `venue`: the venue of the position
+        /// - `strategy`: the new constraints on the share of the venue owned
+        #[pallet::weight(T::WeightInfo::update_strategy())]
+        #[transactional]
+        pub fn update_strategy(
+            origin: OriginFor<T>,
+            venue: Venue,
+            strategy: Strategy,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::ensure_valid_strategy(&strategy)?;
+            let venue = Self::resolve_venue(venue)?;
+
+            Positions::<T>::try_mutate(venue, |position| -> DispatchResult {
+                let position = position.as_mut().ok_or(Error::<T>::PositionNotFound)?;
+                position.strategy = strategy;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::<T>::StrategyUpdated(venue, strategy));
+            Ok(())
+        }
+
+        /// Remove a position which doesn't hold liquidity anymore, along with its
+        /// accounting.
+        ///
+        /// - `venue`: the venue of the position
+        #[pallet::weight(T::WeightInfo::remove_position())]
+        #[transactional]
+        pub fn remove_position(origin: OriginFor<T>, venue: Venue) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let venue = Self::resolve_venue(venue)?;
+            ensure!(
+                Positions::<T>::contains_key(venue),
+                Error::<T>::PositionNotFound
+            );
+            let (held, _) = Self::holding(venue)?;
+            ensure!(held.is_zero(), Error::<T>::PositionNotEmpty);
+
+            Positions::<T>::remove(venue);
+            Self::deposit_event(Event::<T>::PositionRemoved(venue));
+            Ok(())
+        }
+
+        /// Deploy treasury assets into the venue of a position, up to its max share.
+        /// Pools take the amounts at their price, what they don't take stays in the
+        /// treasury. Markets only take the first amount.
+        ///
+        /// - `venue`: the venue of the position
+        /// - `amounts`: the amounts of the venue assets to deploy at most
+        /// - `minimum_amounts`: the amounts a pool must take at least
+        #[pallet::weight(T::WeightInfo::deploy())]
+        #[transactional]
+        pub fn deploy(
+            origin: OriginFor<T>,
+            venue: Venue,
+            amounts: (Balance, Balance),
+            minimum_amounts: (Balance, Balance),
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let resolved = Self::resolve_venue(venue)?;
+            ensure!(
+                Positions::<T>::contains_key(resolved),
+                Error::<T>::PositionNotFound
+            );
+            ensure!(!amounts.0.is_zero(), Error::<T>::InvalidAmount);
+
+            // the amounts follow the order the pool assets were given in
+            let (amounts, minimum_amounts) = if venue == resolved {
+                (amounts, minimum_amounts)
+            } else {
+                (
+                    (amounts.1, amounts.0),
+                    (minimum_amounts.1, minimum_amounts.0),
+                )
+            };
+            Self::do_deploy(resolved, amounts, minimum_amounts)
+        }
+
+        /// Withdraw a portion of a position back to the treasury.
+        ///
+        /// - `venue`: the venue of the position
+        /// - `portion`: the part of the position to withdraw
+        #[pallet::weight(T::WeightInfo::withdraw())]
+        #[transactional]
+        pub fn withdraw(origin: OriginFor<T>, venue: Venue, portion: Ratio) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let venue = Self::resolve_venue(venue)?;
+            ensure!(
+                Positions::<T>::contains_key(venue),
+                Error::<T>::PositionNotFound
+            );
+            let (held, _) = Self::holding(venue)?;
+            let amount = portion.mul_floor(held);
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+
+            Self::do_withdraw(venue, amount)
+        }
+
+        /// Bring a position whose share drifted out of its band back to its target
+        /// share, deploying at most what the treasury holds.
+        ///
+        /// - `venue`: the venue of the position
+        #[pallet::weight(T::WeightInfo::rebalance())]
+        #[transactional]
+        pub fn rebalance(origin: OriginFor<T>, venue: Venue) -> DispatchResult {
+            ensure_signed(origin)?;
+            let venue = Self::resolve_venue(venue)?;
+            let strategy = Self::positions(venue)
+                .ok_or(Error::<T>::PositionNotFound)?
+                .strategy;
+
+            let (held, total) = Self::holding(venue)?;
+            let share = Self::share_of(held, total);
+            let lower = strategy.target_share.saturating_sub(strategy.band);
+            let upper = strategy.target_share.saturating_add(strategy.band);
+            ensure!(share < lower || share > upper, Error::<T>::WithinBand);
+            if let Venue::Pool(base_asset, quote_asset) = venue {
+                Self::ensure_fair_price(base_asset, quote_asset)?;
+            }
+
+            // the liquidity of the others stays the same whichever way the position moves
+            let target = Self::target_holding(strategy.target_share, total.saturating_sub(held))?;
+            if share > upper {
+                Self::do_withdraw(venue, held.saturating_sub(target))?;
+            } else {
+                let amounts = Self::amounts_to_deploy(venue, target.saturating_sub(held))?;
+                Self::do_deploy(venue, amounts, (Zero::zero(), Zero::zero()))?;
+            }
+
+            let (held, total) = Self::holding(venue)?;
+            Self::deposit_event(Event::<T>::Rebalanced(
+                venue,
+                share,
+                Self::share_of(held, total),
+            ));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    /// Value and profit and loss of the position in `venue`, in each of the venue
+    /// assets. Price moves between the assets of a pool aren't accounted for.
+    pub fn position_report(venue: Venue) -> Result<PositionReport, DispatchError> {
+        let venue = Self::resolve_venue(venue)?;
+        let position = Self::positions(venue).ok_or(Error::<T>::PositionNotFound)?;
+        let (held, total) = Self::holding(venue)?;
+        let value = Self::value_of(venue, held, total)?;
+
+        let returned = |withdrawn: Balance, value: Balance, deployed: Balance| {
+            let returned = withdrawn.saturating_add(value);
+            (
+                returned.saturating_sub(deployed),
+                deployed.saturating_sub(returned),
+            )
+        };
+        let (profit_0, loss_0) = returned(position.withdrawn.0, value.0, position.deployed.0);
+        let (profit_1, loss_1) = returned(position.withdrawn.1, value.1, position.deployed.1);
+
+        Ok(PositionReport {
+            share: Self::share_of(held, total),
+            value,
+            profit: (profit_0, profit_1),
+            loss: (loss_0, loss_1),
+        })
+    }
+
+    fn ensure_valid_strategy(strategy: &Strategy) -> DispatchResult {
+        ensure!(
+            strategy.max_share < Ratio::one()
+                && strategy.band <= strategy.target_share
+                && strategy.target_share.saturating_add(strategy.band) <= strategy.max_share,
+            Error::<T>::InvalidStrategy
+        );
+        Ok(())
+    }
+
+    /// Checks the venue exists and sorts the assets of pools the way the AMM does
+    fn resolve_venue(venue: Venue) -> Result<Venue, DispatchError> {
+        match venue {
+            Venue::Pool(asset_0, asset_1) => {
+                let pool = T::AMM::get_pool_by_asset_pair((asset_0, asset_1))
+                    .ok_or(Error::<T>::VenueNotFound)?;
+                let (base_asset, quote_asset) =
+                    T::AMM::pool_assets(pool.lp_token_id).ok_or(Error::<T>::VenueNotFound)?;
+                Ok(Venue::Pool(base_asset, quote_asset))
+            }
+            Venue::Market(asset_id, ptoken_id) => {
+                let conversions = T::Markets::conversions();
+                ensure!(
+                    conversions.contains(&(asset_id, ptoken_id))
+                        && conversions.contains(&(ptoken_id, asset_id)),
+                    Error::<T>::VenueNotFound
+                );
+                Ok(venue)
+            }
+        }
+    }
+
+    /// Liquidity tokens or pTokens held by the pallet account and their total issuance
+    fn holding(venue: Venue) -> Result<(Balance, Balance), DispatchError> {
+        let account_id = Self::account_id();
+        match venue {
+            Venue::Pool(base_asset, quote_asset) => {
+                let lp_token_id = T::AMM::get_pool_by_asset_pair((base_asset, quote_asset))
+                    .ok_or(Error::<T>::VenueNotFound)?
+                    .lp_token_id;
+                Ok((
+                    T::Assets::balance(lp_token_id, &account_id),
+                    T::Assets::total_issuance(lp_token_id),
+                ))
+            }
+            Venue::Market(_, ptoken_id) => Ok((
+                T::PTokens::balance(ptoken_id, &account_id),
+                T::PTokens::total_issuance(ptoken_id),
+            )),
+        }
+    }
+
+    fn share_of(held: Balance, total: Balance) -> Ratio {
+        if total.is_zero() {
+            return Zero::zero();
+        }
+        Ratio::from_rational(held, total)
+    }
+
+    /// Holding which owns `share` of the venue next to the `others` holdings
+    fn target_holding(share: Ratio, others: Balance) -> Result<Balance, DispatchError> {
+        Self::pro_rata(
+            others,
+            share.deconstruct().into(),
+            Ratio::one().saturating_sub(share).deconstruct().into(),
+        )
+    }
+
+    fn pro_rata(
+        amount: Balance,
+        numerator: Balance,
+        denominator: Balance,
+    ) -> Result<Balance, DispatchError> {
+        amount
+            .get_big_uint()
+            .checked_mul(&numerator.get_big_uint())
+            .and_then(|r| r.checked_div(&denominator.get_big_uint()))
+            .and_then(|r| r.to_u128())
+            .ok_or_else(|| ArithmeticError::Overflow.into())
+    }
+
+    /// What the `held` liquidity tokens or pTokens out of `total` are worth
+    fn value_of(
+        venue: Venue,
+        held: Balance,
+        total: Balance,
+    ) -> Result<(Balance, Balance), DispatchError> {
+        if held.is_zero() {
+            return Ok((Zero::zero(), Zero::zero()));
+        }
+        match venue {
+            Venue::Pool(base_asset, quote_asset) => {
+                let pool = T::AMM::get_pool_by_asset_pair((base_asset, quote_asset))
+                    .ok_or(Error::<T>::VenueNotFound)?;
+                Ok((
+                    Self::pro_rata(pool.base_amount, held, total)?,
+                    Self::pro_rata(pool.quote_amount, held, total)?,
+                ))
+            }
+            Venue::Market(asset_id, ptoken_id) => Ok((
+                T::Markets::quote_out((ptoken_id, asset_id), held)?,
+                Zero::zero(),
+            )),
+        }
+    }
+
+    /// Treasury assets needed to add `liquidity` liquidity tokens or pTokens to the
+    /// position, capped by what the treasury holds
+    fn amounts_to_deploy(
+        venue: Venue,
+        liquidity: Balance,
+    ) -> Result<(Balance, Balance), DispatchError> {
+        let (_, total) = Self::holding(venue)?;
+        let (needed, available) = match venue {
+            Venue::Pool(base_asset, quote_asset) => (
+                Self::value_of(venue, liquidity, total)?,
+                (
+                    Self::treasury_balance(base_asset),
+                    Self::treasury_balance(quote_asset),
+                ),
+            ),
+            Venue::Market(asset_id, _) => (
+                Self::value_of(venue, liquidity, total)?,
+                (Self::treasury_balance(asset_id), Zero::zero()),
+            ),
+        };
+        let amounts = (needed.0.min(available.0), needed.1.min(available.1));
+        let missing = match venue {
+            Venue::Pool(..) => amounts.0.is_zero() || amounts.1.is_zero(),
+            Venue::Market(..) => amounts.0.is_zero(),
+        };
+        ensure!(!missing, Error::<T>::InsufficientTreasuryBalance);
+        Ok(amounts)
+    }
+
+    fn treasury_balance(asset_id: CurrencyId) -> Balance {
+        T::Assets::reducible_balance(asset_id, &T::TreasuryAccount::get(), true)
+    }
+
+    /// Checks the reserves of the pool are worth about the same at the oracle prices
+    fn ensure_fair_price(base_asset: CurrencyId, quote_asset: CurrencyId) -> DispatchResult {
+        let pool = T::AMM::get_pool_by_asset_pair((base_asset, quote_asset))
+            .ok_or(Error::<T>::VenueNotFound)?;
+        let (base_price, _) =
+            T::PriceFeeder::get_price(&base_asset).ok_or(Error::<T>::PriceUnavailable)?;
+        let (quote_price, _) =
+            T::PriceFeeder::get_price(&quote_asset).ok_or(Error::<T>::PriceUnavailable)?;
+        let base_value = base_price.saturating_mul_int(pool.base_amount);
+        let quote_value = quote_price.saturating_mul_int(pool.quote_amount);
+
+        let deviation = Ratio::from_rational(
+            base_value.max(quote_value) - base_value.min(quote_value),
+            base_value.max(quote_value),
+        );
+        ensure!(
+            deviation <= T::MaxPriceDeviation::get(),
+            Error::<T>::PriceDeviationTooHigh
+        );
+        Ok(())
+    }
+
+    /// Moves `amounts` from the treasury into `venue`, returning what it doesn't take
+    fn do_deploy(
+        venue: Venue,
+        amounts: (Balance, Balance),
+        minimum_amounts: (Balance, Balance),
+    ) -> DispatchResult {
+        let account_id = Self::account_id();
+        let treasury = T::TreasuryAccount::get();
+        let deployed = match venue {
+            Venue::Pool(base_asset, quote_asset) => {
+                T::Assets::transfer(base_asset, &treasury, &account_id, amounts.0, false)?;
+                T::Assets::transfer(quote_asset, &treasury, &account_id, amounts.1, false)?;
+                let (added, _) = T::AMM::provide(
+                    &account_id,
+                    (base_asset, quote_asset),
+                    amounts,
+                    minimum_amounts,
+                )?;
+                for (asset_id, unused) in [
+                    (base_asset, amounts.0.saturating_sub(added.0)),
+                    (quote_asset, amounts.1.saturating_sub(added.1)),
+                ] {
+                    if !unused.is_zero() {
+                        T::Assets::transfer(asset_id, &account_id, &treasury, unused, false)?;
+                    }
+                }
+                added
+            }
+            Venue::Market(asset_id, ptoken_id) => {
+                T::Assets::transfer(asset_id, &treasury, &account_id, amounts.0, false)?;
+                T::Markets::convert(&account_id, (asset_id, ptoken_id), amounts.0)?;
+                (amounts.0, Zero::zero())
+            }
+        };
+
+        let (held, total) = Self::holding(venue)?;
+        let share = Self::share_of(held, total);
+        Positions::<T>::try_mutate(venue, |position| -> DispatchResult {
+            let position = position.as_mut().ok_or(Error::<T>::PositionNotFound)?;
+            ensure!(
+                share <= position.strategy.max_share,
+                Error::<T>::MaxShareExceeded
+            );
+            position.deployed = (
+                position.deployed.0.saturating_add(deployed.0),
+                position.deployed.1.saturating_add(deployed.1),
+            );
+            Ok(())
+        })?;
+        Self::deposit_event(Event::<T>::Deployed(venue, deployed.0, deployed.1, share));
+        Ok(())
+    }
+
+    /// Takes `liquidity` liquidity tokens or pTokens out of `venue` back to the treasury
+    fn do_withdraw(venue: Venue, liquidity: Balance) -> DispatchResult {
+        let account_id = Self::account_id();
+        let treasury = T::TreasuryAccount::get();
+        let withdrawn = match venue {
+            Venue::Pool(base_asset, quote_asset) => {
+                let lp_token_id = T::AMM::get_pool_by_asset_pair((base_asset, quote_asset))
+                    .ok_or(Error::<T>::VenueNotFound)?
+                    .lp_token_id;
+                let removed = T::AMM::unwind(&account_id, lp_token_id, liquidity)?;
+                T::Assets::transfer(base_asset, &account_id, &treasury, removed.0, false)?;
+                T::Assets::transfer(quote_asset, &account_id, &treasury, removed.1, false)?;
+                removed
+            }
+            Venue::Market(asset_id, ptoken_id) => {
+                let redeemed = T::Markets::convert(&account_id, (ptoken_id, asset_id), liquidity)?;
+                T::Assets::transfer(asset_id, &account_id, &treasury, redeemed, false)?;
+                (redeemed, Zero::zero())
+            }
+        };
+
+        let (held, total) = Self::holding(venue)?;
+        let share = Self::share_of(held, total);
+        Positions::<T>::try_mutate(venue, |position| -> DispatchResult {
+            let position = position.as_mut().ok_or(Error::<T>::PositionNotFound)?;
+            position.withdrawn = (
+                position.withdrawn.0.saturating_add(withdrawn.0),
+                position.withdrawn.1.saturating_add(withdrawn.1),
+            );
+            Ok(())
+        })?;
+        Self::deposit_event(Event::<T>::Withdrawn(
+            venue,
+            withdrawn.0,
+            withdrawn.1,
+            share,
+        ));
+        Ok(())
+    }
+}
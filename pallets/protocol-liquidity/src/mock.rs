@@ -0,0 +1,461 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{
+        fungibles::{Mutate, Transfer},
+        Everything,
+    },
+};
+use frame_system::EnsureRoot;
+use pallet_traits::Pool;
+use primitives::{Price, PriceDetail};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{IdentityLookup, One},
+    DispatchError, FixedU128,
+};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
+
+pub use primitives::tokens::{DOT, KSM, PDOT, USDT};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+        Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+        ProtocolLiquidity: crate::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const TREASURY: AccountId = 3;
+pub const AMM_ACCOUNT: AccountId = 100;
+pub const MARKET_ACCOUNT: AccountId = 101;
+
+/// Liquidity token of the USDT/DOT pool
+pub const LP_USDT_DOT: CurrencyId = 5100;
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+    pub const MaxLocks: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = MaxLocks;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+}
+
+parameter_types! {
+    pub const AssetDeposit: u64 = 1;
+    pub const ApprovalDeposit: u64 = 1;
+    pub const AssetAccountDeposit: u64 = 1;
+    pub const StringLimit: u32 = 50;
+    pub const MetadataDepositBase: u64 = 1;
+    pub const MetadataDepositPerByte: u64 = 1;
+}
+
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = CurrencyId;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = AssetDeposit;
+    type MetadataDepositBase = MetadataDepositBase;
+    type MetadataDepositPerByte = MetadataDepositPerByte;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type ApprovalDeposit = ApprovalDeposit;
+    type StringLimit = StringLimit;
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = ();
+}
+
+/// Constant product pool of USDT and DOT without fees, its reserves are the balances
+/// of `AMM_ACCOUNT`
+pub struct MockAmm;
+
+impl MockAmm {
+    fn pool() -> Pool<CurrencyId, Balance, u64> {
+        let mut pool = Pool::new(LP_USDT_DOT);
+        pool.base_amount = Assets::balance(USDT, AMM_ACCOUNT);
+        pool.quote_amount = Assets::balance(DOT, AMM_ACCOUNT);
+        pool
+    }
+
+    fn is_pool(pair: (CurrencyId, CurrencyId)) -> bool {
+        pair == (USDT, DOT) || pair == (DOT, USDT)
+    }
+
+    /// Reserves of the asset sold and of the asset bought when swapping along `pair`
+    fn reserves(pair: (CurrencyId, CurrencyId)) -> Result<(Balance, Balance), DispatchError> {
+        if !Self::is_pool(pair) {
+            return Err(DispatchError::Other("PoolDoesNotExist"));
+        }
+        let pool = Self::pool();
+        if pair.0 == USDT {
+            Ok((pool.base_amount, pool.quote_amount))
+        } else {
+            Ok((pool.quote_amount, pool.base_amount))
+        }
+    }
+
+    fn amount_out(
+        pair: (CurrencyId, CurrencyId),
+        amount_in: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let (reserve_in, reserve_out) = Self::reserves(pair)?;
+        Ok(amount_in * reserve_out / (reserve_in + amount_in))
+    }
+
+    fn amount_in(
+        pair: (CurrencyId, CurrencyId),
+        amount_out: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let (reserve_in, reserve_out) = Self::reserves(pair)?;
+        if amount_out >= reserve_out {
+            return Err(DispatchError::Other("InsufficientLiquidity"));
+        }
+        Ok(amount_out * reserve_in / (reserve_out - amount_out) + 1)
+    }
+}
+
+impl AMM<AccountId, CurrencyId, Balance, u64> for MockAmm {
+    fn get_amounts_out(
+        amount_in: Balance,
+        path: Vec<CurrencyId>,
+    ) -> Result<Vec<Balance>, DispatchError> {
+        let mut amounts = vec![amount_in];
+        for pair in path.windows(2) {
+            let amount = Self::amount_out((pair[0], pair[1]), *amounts.last().unwrap())?;
+            amounts.push(amount);
+        }
+        Ok(amounts)
+    }
+
+    fn get_amounts_in(
+        amount_out: Balance,
+        path: Vec<CurrencyId>,
+    ) -> Result<Vec<Balance>, DispatchError> {
+        let mut amounts = vec![amount_out];
+        for pair in path.windows(2).rev() {
+            let amount = Self::amount_in((pair[0], pair[1]), amounts[0])?;
+            amounts.insert(0, amount);
+        }
+        Ok(amounts)
+    }
+
+    fn swap(
+        who: &AccountId,
+        pair: (CurrencyId, CurrencyId),
+        amount_in: Balance,
+    ) -> Result<(), DispatchError> {
+        let amount_out = Self::amount_out(pair, amount_in)?;
+        <Assets as Transfer<AccountId>>::transfer(pair.0, who, &AMM_ACCOUNT, amount_in, false)?;
+        <Assets as Transfer<AccountId>>::transfer(pair.1, &AMM_ACCOUNT, who, amount_out, false)?;
+        Ok(())
+    }
+
+    fn get_pools() -> Result<Vec<(CurrencyId, CurrencyId)>, DispatchError> {
+        Ok(vec![(USDT, DOT)])
+    }
+
+    fn get_pool_by_lp_asset(
+        asset_id: CurrencyId,
+    ) -> Option<(CurrencyId, CurrencyId, Pool<CurrencyId, Balance, u64>)> {
+        (asset_id == LP_USDT_DOT).then(|| (USDT, DOT, Self::pool()))
+    }
+
+    fn get_pool_by_asset_pair(
+        pair: (CurrencyId, CurrencyId),
+    ) -> Option<Pool<CurrencyId, Balance, u64>> {
+        Self::is_pool(pair).then(Self::pool)
+    }
+}
+
+impl ProvideLiquidity<AccountId, CurrencyId, Balance> for MockAmm {
+    fn provide(
+        who: &AccountId,
+        pair: (CurrencyId, CurrencyId),
+        desired_amounts: (Balance, Balance),
+        minimum_amounts: (Balance, Balance),
+    ) -> Result<((Balance, Balance), Balance), DispatchError> {
+        if !Self::is_pool(pair) {
+            return Err(DispatchError::Other("PoolDoesNotExist"));
+        }
+        let inverted = pair == (DOT, USDT);
+        let flip = |(a, b): (Balance, Balance)| if inverted { (b, a) } else { (a, b) };
+        let (base_amount, quote_amount) = flip(desired_amounts);
+        let (minimum_base_amount, minimum_quote_amount) = flip(minimum_amounts);
+
+        let pool = Self::pool();
+        let total_supply = Assets::total_supply(LP_USDT_DOT);
+        let (ideal_base_amount, ideal_quote_amount, liquidity) = if total_supply.is_zero() {
+            (base_amount, quote_amount, base_amount)
+        } else {
+            let optimal_quote_amount = base_amount * pool.quote_amount / pool.base_amount;
+            let (ideal_base_amount, ideal_quote_amount) = if optimal_quote_amount <= quote_amount {
+                (base_amount, optimal_quote_amount)
+            } else {
+                (
+                    quote_amount * pool.base_amount / pool.quote_amount,
+                    quote_amount,
+                )
+            };
+            (
+                ideal_base_amount,
+                ideal_quote_amount,
+                ideal_base_amount * total_supply / pool.base_amount,
+            )
+        };
+        if ideal_base_amount < minimum_base_amount || ideal_quote_amount < minimum_quote_amount {
+            return Err(DispatchError::Other("NotAnIdealPrice"));
+        }
+
+        <Assets as Transfer<AccountId>>::transfer(
+            USDT,
+            who,
+            &AMM_ACCOUNT,
+            ideal_base_amount,
+            false,
+        )?;
+        <Assets as Transfer<AccountId>>::transfer(
+            DOT,
+            who,
+            &AMM_ACCOUNT,
+            ideal_quote_amount,
+            false,
+        )?;
+        <Assets as Mutate<AccountId>>::mint_into(LP_USDT_DOT, who, liquidity)?;
+        Ok((flip((ideal_base_amount, ideal_quote_amount)), liquidity))
+    }
+}
+
+impl UnwindLiquidity<AccountId, CurrencyId, Balance> for MockAmm {
+    fn pool_assets(lp_asset: CurrencyId) -> Option<(CurrencyId, CurrencyId)> {
+        (lp_asset == LP_USDT_DOT).then(|| (USDT, DOT))
+    }
+
+    fn unwind(
+        who: &AccountId,
+        _lp_asset: CurrencyId,
+        liquidity: Balance,
+    ) -> Result<(Balance, Balance), DispatchError> {
+        let pool = Self::pool();
+        let total_supply = Assets::total_supply(LP_USDT_DOT);
+        let base_amount = liquidity * pool.base_amount / total_supply;
+        let quote_amount = liquidity * pool.quote_amount / total_supply;
+        <Assets as Mutate<AccountId>>::burn_from(LP_USDT_DOT, who, liquidity)?;
+        <Assets as Transfer<AccountId>>::transfer(USDT, &AMM_ACCOUNT, who, base_amount, false)?;
+        <Assets as Transfer<AccountId>>::transfer(DOT, &AMM_ACCOUNT, who, quote_amount, false)?;
+        Ok((base_amount, quote_amount))
+    }
+}
+
+thread_local! {
+    static EXCHANGE_RATE: RefCell<FixedU128> = RefCell::new(FixedU128::one());
+    static PRICES: RefCell<BTreeMap<CurrencyId, Price>> = RefCell::new(BTreeMap::new());
+}
+
+/// Market of DOT for PDOT, the DOT supplied is held by `MARKET_ACCOUNT`
+pub struct MockMarkets;
+
+impl MockMarkets {
+    /// DOT a PDOT can be redeemed for
+    pub fn set_exchange_rate(rate: FixedU128) {
+        EXCHANGE_RATE.with(|v| *v.borrow_mut() = rate);
+    }
+
+    fn exchange_rate() -> FixedU128 {
+        EXCHANGE_RATE.with(|v| *v.borrow())
+    }
+}
+
+impl ProtocolConversion<AccountId, CurrencyId, Balance> for MockMarkets {
+    fn conversions() -> Vec<(CurrencyId, CurrencyId)> {
+        vec![(DOT, PDOT), (PDOT, DOT)]
+    }
+
+    fn quote_out(
+        pair: (CurrencyId, CurrencyId),
+        amount_in: Balance,
+    ) -> Result<Balance, DispatchError> {
+        if pair == (PDOT, DOT) {
+            Ok(Self::exchange_rate().saturating_mul_int(amount_in))
+        } else {
+            Ok(Self::exchange_rate()
+                .reciprocal()
+                .unwrap()
+                .saturating_mul_int(amount_in))
+        }
+    }
+
+    fn quote_in(
+        pair: (CurrencyId, CurrencyId),
+        amount_out: Balance,
+    ) -> Result<Balance, DispatchError> {
+        if pair == (PDOT, DOT) {
+            Ok(Self::exchange_rate()
+                .reciprocal()
+                .unwrap()
+                .saturating_mul_int(amount_out))
+        } else {
+            Ok(Self::exchange_rate().saturating_mul_int(amount_out))
+        }
+    }
+
+    fn convert(
+        who: &AccountId,
+        pair: (CurrencyId, CurrencyId),
+        amount_in: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let amount_out = Self::quote_out(pair, amount_in)?;
+        if pair == (PDOT, DOT) {
+            <Assets as Mutate<AccountId>>::burn_from(PDOT, who, amount_in)?;
+            <Assets as Transfer<AccountId>>::transfer(
+                DOT,
+                &MARKET_ACCOUNT,
+                who,
+                amount_out,
+                false,
+            )?;
+        } else {
+            <Assets as Transfer<AccountId>>::transfer(DOT, who, &MARKET_ACCOUNT, amount_in, false)?;
+            <Assets as Mutate<AccountId>>::mint_into(PDOT, who, amount_out)?;
+        }
+        Ok(amount_out)
+    }
+}
+
+pub struct MockPriceFeeder;
+
+impl MockPriceFeeder {
+    pub fn set_price(asset_id: CurrencyId, price: Price) {
+        PRICES.with(|prices| prices.borrow_mut().insert(asset_id, price));
+    }
+}
+
+impl PriceFeeder for MockPriceFeeder {
+    fn get_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
+        PRICES.with(|prices| prices.borrow().get(asset_id).map(|price| (*price, 1)))
+    }
+}
+
+parameter_types! {
+    pub const ProtocolLiquidityPalletId: PalletId = PalletId(*b"par/pliq");
+    pub const MaxPriceDeviation: Ratio = Ratio::from_percent(2);
+    pub const TreasuryAccount: AccountId = TREASURY;
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type PalletId = ProtocolLiquidityPalletId;
+    type Assets = Assets;
+    type AMM = MockAmm;
+    type Markets = MockMarkets;
+    type PTokens = Assets;
+    type PriceFeeder = MockPriceFeeder;
+    type MaxPriceDeviation = MaxPriceDeviation;
+    type TreasuryAccount = TreasuryAccount;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+pub fn dollar(d: u128) -> u128 {
+    d.saturating_mul(10_u128.pow(12))
+}
+
+/// Pool and market with 100 DOT (and 100 USDT) of BOB in them, treasury holding 1000
+/// DOT and 1000 USDT
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        for asset_id in [DOT, USDT, LP_USDT_DOT, PDOT] {
+            Assets::force_create(RuntimeOrigin::root(), asset_id, ALICE, true, 1).unwrap();
+        }
+        for asset_id in [DOT, USDT] {
+            Assets::mint(
+                RuntimeOrigin::signed(ALICE),
+                asset_id,
+                TREASURY,
+                dollar(1000),
+            )
+            .unwrap();
+            Assets::mint(RuntimeOrigin::signed(ALICE), asset_id, BOB, dollar(1000)).unwrap();
+            MockPriceFeeder::set_price(asset_id, Price::one());
+        }
+        MockAmm::provide(&BOB, (DOT, USDT), (dollar(100), dollar(100)), (0, 0)).unwrap();
+        MockMarkets::convert(&BOB, (DOT, PDOT), dollar(100)).unwrap();
+        System::set_block_number(1);
+    });
+    ext
+}
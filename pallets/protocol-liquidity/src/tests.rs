@@ -0,0 +1,317 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use primitives::Price;
+use sp_runtime::{DispatchError, FixedU128};
+
+fn strategy(max_share: u32, target_share: u32, band: u32) -> Strategy {
+    Strategy {
+        max_share: Ratio::from_percent(max_share),
+        target_share: Ratio::from_percent(target_share),
+        band: Ratio::from_percent(band),
+    }
+}
+
+#[test]
+fn add_position_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ProtocolLiquidity::add_position(
+                RuntimeOrigin::signed(ALICE),
+                Venue::Pool(DOT, USDT),
+                strategy(30, 20, 5)
+            ),
+            DispatchError::BadOrigin
+        );
+        for invalid in [
+            strategy(30, 20, 15),
+            strategy(30, 5, 10),
+            strategy(100, 20, 5),
+        ] {
+            assert_noop!(
+                ProtocolLiquidity::add_position(
+                    RuntimeOrigin::root(),
+                    Venue::Pool(DOT, USDT),
+                    invalid
+                ),
+                Error::<Test>::InvalidStrategy
+            );
+        }
+        for unknown in [Venue::Pool(DOT, KSM), Venue::Market(USDT, PDOT)] {
+            assert_noop!(
+                ProtocolLiquidity::add_position(
+                    RuntimeOrigin::root(),
+                    unknown,
+                    strategy(30, 20, 5)
+                ),
+                Error::<Test>::VenueNotFound
+            );
+        }
+
+        assert_ok!(ProtocolLiquidity::add_position(
+            RuntimeOrigin::root(),
+            Venue::Pool(DOT, USDT),
+            strategy(30, 20, 5)
+        ));
+        // pool assets are kept in the order of the AMM
+        assert_eq!(
+            ProtocolLiquidity::positions(Venue::Pool(USDT, DOT)),
+            Some(Position {
+                strategy: strategy(30, 20, 5),
+                deployed: (0, 0),
+                withdrawn: (0, 0),
+            })
+        );
+        System::assert_last_event(RuntimeEvent::ProtocolLiquidity(Event::PositionAdded(
+            Venue::Pool(USDT, DOT),
+            strategy(30, 20, 5),
+        )));
+        assert_noop!(
+            ProtocolLiquidity::add_position(
+                RuntimeOrigin::root(),
+                Venue::Pool(USDT, DOT),
+                strategy(30, 20, 5)
+            ),
+            Error::<Test>::PositionAlreadyExists
+        );
+
+        assert_noop!(
+            ProtocolLiquidity::update_strategy(
+                RuntimeOrigin::root(),
+                Venue::Market(DOT, PDOT),
+                strategy(30, 20, 5)
+            ),
+            Error::<Test>::PositionNotFound
+        );
+        assert_ok!(ProtocolLiquidity::update_strategy(
+            RuntimeOrigin::root(),
+            Venue::Pool(USDT, DOT),
+            strategy(40, 30, 10)
+        ));
+        assert_eq!(
+            ProtocolLiquidity::positions(Venue::Pool(USDT, DOT))
+                .unwrap()
+                .strategy,
+            strategy(40, 30, 10)
+        );
+    })
+}
+
+#[test]
+fn deploy_should_respect_the_max_share() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ProtocolLiquidity::deploy(
+                RuntimeOrigin::root(),
+                Venue::Pool(DOT, USDT),
+                (dollar(30), dollar(30)),
+                (0, 0)
+            ),
+            Error::<Test>::PositionNotFound
+        );
+        assert_ok!(ProtocolLiquidity::add_position(
+            RuntimeOrigin::root(),
+            Venue::Pool(DOT, USDT),
+            strategy(30, 20, 5)
+        ));
+
+        // amounts are given in the order of the pool assets, the pool takes 30 USDT
+        assert_ok!(ProtocolLiquidity::deploy(
+            RuntimeOrigin::root(),
+            Venue::Pool(DOT, USDT),
+            (dollar(30), dollar(50)),
+            (0, 0)
+        ));
+        assert_eq!(Assets::balance(DOT, TREASURY), dollar(970));
+        assert_eq!(Assets::balance(USDT, TREASURY), dollar(970));
+        assert_eq!(
+            Assets::balance(LP_USDT_DOT, ProtocolLiquidity::account_id()),
+            dollar(30)
+        );
+        assert_eq!(
+            ProtocolLiquidity::positions(Venue::Pool(USDT, DOT))
+                .unwrap()
+                .deployed,
+            (dollar(30), dollar(30))
+        );
+
+        // 50 out of 150
+        assert_noop!(
+            ProtocolLiquidity::deploy(
+                RuntimeOrigin::root(),
+                Venue::Pool(USDT, DOT),
+                (dollar(20), dollar(20)),
+                (0, 0)
+            ),
+            Error::<Test>::MaxShareExceeded
+        );
+        assert_noop!(
+            ProtocolLiquidity::deploy(
+                RuntimeOrigin::root(),
+                Venue::Pool(USDT, DOT),
+                (0, dollar(20)),
+                (0, 0)
+            ),
+            Error::<Test>::InvalidAmount
+        );
+    })
+}
+
+#[test]
+fn rebalance_should_bring_pools_back_to_the_target_share() {
+    new_test_ext().execute_with(|| {
+        let venue = Venue::Pool(USDT, DOT);
+        assert_ok!(ProtocolLiquidity::add_position(
+            RuntimeOrigin::root(),
+            venue,
+            strategy(30, 20, 5)
+        ));
+        assert_ok!(ProtocolLiquidity::deploy(
+            RuntimeOrigin::root(),
+            venue,
+            (dollar(30), dollar(30)),
+            (0, 0)
+        ));
+        assert_noop!(
+            ProtocolLiquidity::rebalance(RuntimeOrigin::signed(BOB), venue),
+            Error::<Test>::WithinBand
+        );
+
+        // 30 out of 80 once BOB removed half of his liquidity, 17.5 is withdrawn
+        assert_ok!(MockAmm::unwind(&BOB, LP_USDT_DOT, dollar(50)));
+        assert_ok!(ProtocolLiquidity::rebalance(
+            RuntimeOrigin::signed(BOB),
+            venue
+        ));
+        assert_eq!(
+            Assets::balance(LP_USDT_DOT, ProtocolLiquidity::account_id()),
+            dollar(125) / 10
+        );
+        assert_eq!(Assets::balance(DOT, TREASURY), dollar(9875) / 10);
+        assert_eq!(
+            ProtocolLiquidity::positions(venue).unwrap().withdrawn,
+            (dollar(175) / 10, dollar(175) / 10)
+        );
+        System::assert_last_event(RuntimeEvent::ProtocolLiquidity(Event::Rebalanced(
+            venue,
+            Ratio::from_rational(30u32, 80u32),
+            Ratio::from_percent(20),
+        )));
+
+        // 12.5 out of 162.5 once BOB added 100 more, 25 is deployed
+        assert_ok!(MockAmm::provide(
+            &BOB,
+            (USDT, DOT),
+            (dollar(100), dollar(100)),
+            (0, 0)
+        ));
+        // not while the pool price is off
+        MockPriceFeeder::set_price(DOT, Price::saturating_from_rational(3, 2));
+        assert_noop!(
+            ProtocolLiquidity::rebalance(RuntimeOrigin::signed(BOB), venue),
+            Error::<Test>::PriceDeviationTooHigh
+        );
+        MockPriceFeeder::set_price(DOT, Price::saturating_from_integer(1));
+        assert_ok!(ProtocolLiquidity::rebalance(
+            RuntimeOrigin::signed(BOB),
+            venue
+        ));
+        assert_eq!(
+            Assets::balance(LP_USDT_DOT, ProtocolLiquidity::account_id()),
+            dollar(375) / 10
+        );
+        assert_eq!(Assets::balance(DOT, TREASURY), dollar(9625) / 10);
+        assert_eq!(
+            ProtocolLiquidity::position_report(venue).unwrap().share,
+            Ratio::from_percent(20)
+        );
+    })
+}
+
+#[test]
+fn position_report_should_account_profit_and_loss() {
+    new_test_ext().execute_with(|| {
+        let venue = Venue::Market(DOT, PDOT);
+        assert_ok!(ProtocolLiquidity::add_position(
+            RuntimeOrigin::root(),
+            venue,
+            strategy(60, 50, 5)
+        ));
+        assert_ok!(ProtocolLiquidity::deploy(
+            RuntimeOrigin::root(),
+            venue,
+            (dollar(100), 0),
+            (0, 0)
+        ));
+        assert_eq!(
+            ProtocolLiquidity::position_report(venue).unwrap(),
+            PositionReport {
+                share: Ratio::from_percent(50),
+                value: (dollar(100), 0),
+                profit: (0, 0),
+                loss: (0, 0),
+            }
+        );
+
+        // interest accrued
+        MockMarkets::set_exchange_rate(FixedU128::saturating_from_rational(11, 10));
+        assert_ok!(Assets::mint(
+            RuntimeOrigin::signed(ALICE),
+            DOT,
+            MARKET_ACCOUNT,
+            dollar(20)
+        ));
+        let report = ProtocolLiquidity::position_report(venue).unwrap();
+        assert_eq!(report.value, (dollar(110), 0));
+        assert_eq!(report.profit, (dollar(10), 0));
+
+        assert_ok!(ProtocolLiquidity::withdraw(
+            RuntimeOrigin::root(),
+            venue,
+            Ratio::from_percent(50)
+        ));
+        assert_eq!(Assets::balance(DOT, TREASURY), dollar(955));
+        assert_eq!(
+            ProtocolLiquidity::positions(venue).unwrap().withdrawn,
+            (dollar(55), 0)
+        );
+        let report = ProtocolLiquidity::position_report(venue).unwrap();
+        assert_eq!(report.value, (dollar(55), 0));
+        assert_eq!(report.profit, (dollar(10), 0));
+
+        // withdrawn and remaining value are 20 short of the deployed assets
+        MockMarkets::set_exchange_rate(FixedU128::saturating_from_rational(1, 2));
+        let report = ProtocolLiquidity::position_report(venue).unwrap();
+        assert_eq!(report.profit, (0, 0));
+        assert_eq!(report.loss, (dollar(20), 0));
+
+        assert_noop!(
+            ProtocolLiquidity::remove_position(RuntimeOrigin::root(), venue),
+            Error::<Test>::PositionNotEmpty
+        );
+        assert_ok!(ProtocolLiquidity::withdraw(
+            RuntimeOrigin::root(),
+            venue,
+            Ratio::one()
+        ));
+        assert_ok!(ProtocolLiquidity::remove_position(
+            RuntimeOrigin::root(),
+            venue
+        ));
+        assert_eq!(ProtocolLiquidity::positions(venue), None);
+    })
+}
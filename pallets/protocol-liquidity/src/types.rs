@@ -0,0 +1,62 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use primitives::{Balance, CurrencyId, Ratio};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Where protocol owned liquidity is deployed
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Venue {
+    /// AMM pool of the two assets, in the order the AMM sorts them
+    Pool(CurrencyId, CurrencyId),
+    /// Loans market of the asset and its pToken
+    Market(CurrencyId, CurrencyId),
+}
+
+/// Constraints on the share of its venue a position owns
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Strategy {
+    /// Share of the venue deployments may not go over
+    pub max_share: Ratio,
+    /// Share of the venue rebalancing brings the position back to
+    pub target_share: Ratio,
+    /// How far the share may drift from `target_share` before it can be rebalanced
+    pub band: Ratio,
+}
+
+/// Protocol owned liquidity deployed into a venue, amounts are in the order of the
+/// venue assets
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Position {
+    pub strategy: Strategy,
+    /// Assets taken from the treasury into the venue so far
+    pub deployed: (Balance, Balance),
+    /// Assets returned from the venue to the treasury so far
+    pub withdrawn: (Balance, Balance),
+}
+
+/// Profit and loss of a position, amounts are in the order of the venue assets
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PositionReport {
+    /// Share of the venue owned
+    pub share: Ratio,
+    /// What withdrawing the whole position would return
+    pub value: (Balance, Balance),
+    /// By how much the withdrawn assets and the value exceed the deployed assets
+    pub profit: (Balance, Balance),
+    /// By how much the deployed assets exceed the withdrawn assets and the value
+    pub loss: (Balance, Balance),
+}
@@ -0,0 +1,176 @@
+// This file is part of Parallel Finance.
+
+// Copyright (C) 2022 Parallel Finance Developer.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_protocol_liquidity
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet-protocol-liquidity
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/protocol-liquidity/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+
+use frame_support::weights::{constants::RocksDbWeight, Weight};
+use sp_runtime::traits::Get;
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions needed for pallet_protocol_liquidity.
+pub trait WeightInfo {
+    fn add_position() -> Weight;
+    fn update_strategy() -> Weight;
+    fn remove_position() -> Weight;
+    fn deploy() -> Weight;
+    fn withdraw() -> Weight;
+    fn rebalance() -> Weight;
+}
+
+/// Weights of the AMM venue of the positions, which is the heavier one
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    // Storage: ProtocolLiquidity Positions (r:1 w:1)
+    // Storage: AMM Pools (r:1 w:0)
+    fn add_position() -> Weight {
+        Weight::from_ref_time(32_400_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(2 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
+    // Storage: ProtocolLiquidity Positions (r:1 w:1)
+    // Storage: AMM Pools (r:1 w:0)
+    fn update_strategy() -> Weight {
+        Weight::from_ref_time(31_100_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(2 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
+    // Storage: ProtocolLiquidity Positions (r:1 w:1)
+    // Storage: AMM Pools (r:2 w:0)
+    // Storage: Assets Account (r:1 w:0)
+    // Storage: Assets Asset (r:1 w:0)
+    fn remove_position() -> Weight {
+        Weight::from_ref_time(45_700_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(5 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
+    // Storage: ProtocolLiquidity Positions (r:1 w:1)
+    // Storage: AMM Pools (r:3 w:1)
+    // Storage: Assets Asset (r:3 w:3)
+    // Storage: Assets Account (r:6 w:6)
+    // Storage: AMM LiquidityDeposits (r:1 w:1)
+    fn deploy() -> Weight {
+        Weight::from_ref_time(243_500_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(14 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(14 as u64))
+            .saturating_add(T::DbWeight::get().writes(12 as u64))
+    }
+    // Storage: ProtocolLiquidity Positions (r:1 w:1)
+    // Storage: AMM Pools (r:4 w:1)
+    // Storage: Assets Asset (r:3 w:3)
+    // Storage: Assets Account (r:6 w:6)
+    // Storage: AMM LiquidityDeposits (r:1 w:1)
+    fn withdraw() -> Weight {
+        Weight::from_ref_time(226_900_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(15 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(15 as u64))
+            .saturating_add(T::DbWeight::get().writes(12 as u64))
+    }
+    // Storage: ProtocolLiquidity Positions (r:2 w:1)
+    // Storage: AMM Pools (r:6 w:1)
+    // Storage: Prices EmergencyPrice (r:2 w:0)
+    // Storage: Oracle Values (r:2 w:0)
+    // Storage: Assets Asset (r:3 w:3)
+    // Storage: Assets Account (r:6 w:6)
+    // Storage: AMM LiquidityDeposits (r:1 w:1)
+    fn rebalance() -> Weight {
+        Weight::from_ref_time(281_300_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(22 as u64))
+            .saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(22 as u64))
+            .saturating_add(T::DbWeight::get().writes(12 as u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    // Storage: AMM Pools (r:1 w:0)
+    fn add_position() -> Weight {
+        Weight::from_ref_time(32_400_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(2 as u64))
+            .saturating_add(RocksDbWeight::get().writes(1 as u64))
+    }
+    // Storage: AMM Pools (r:1 w:0)
+    fn update_strategy() -> Weight {
+        Weight::from_ref_time(31_100_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(2 as u64))
+            .saturating_add(RocksDbWeight::get().writes(1 as u64))
+    }
+    // Storage: AMM Pools (r:2 w:0)
+    // Storage: Assets Account (r:1 w:0)
+    // Storage: Assets Asset (r:1 w:0)
+    fn remove_position() -> Weight {
+        Weight::from_ref_time(45_700_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(5 as u64))
+            .saturating_add(RocksDbWeight::get().writes(1 as u64))
+    }
+    // Storage: AMM Pools (r:3 w:1)
+    // Storage: Assets Asset (r:3 w:3)
+    // Storage: Assets Account (r:6 w:6)
+    // Storage: AMM LiquidityDeposits (r:1 w:1)
+    fn deploy() -> Weight {
+        Weight::from_ref_time(243_500_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(14 as u64))
+            .saturating_add(RocksDbWeight::get().writes(12 as u64))
+    }
+    // Storage: AMM Pools (r:4 w:1)
+    // Storage: Assets Asset (r:3 w:3)
+    // Storage: Assets Account (r:6 w:6)
+    // Storage: AMM LiquidityDeposits (r:1 w:1)
+    fn withdraw() -> Weight {
+        Weight::from_ref_time(226_900_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(15 as u64))
+            .saturating_add(RocksDbWeight::get().writes(12 as u64))
+    }
+    // Storage: AMM Pools (r:6 w:1)
+    // Storage: Prices EmergencyPrice (r:2 w:0)
+    // Storage: Oracle Values (r:2 w:0)
+    // Storage: Assets Asset (r:3 w:3)
+    // Storage: Assets Account (r:6 w:6)
+    // Storage: AMM LiquidityDeposits (r:1 w:1)
+    fn rebalance() -> Weight {
+        Weight::from_ref_time(281_300_000 as u64)
+            .saturating_add(RocksDbWeight::get().reads(22 as u64))
+            .saturating_add(RocksDbWeight::get().writes(12 as u64))
+    }
+}
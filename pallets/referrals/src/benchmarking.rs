@@ -0,0 +1,90 @@
+//! Referrals pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Referrals;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{assert_ok, dispatch::UnfilteredDispatchable, traits::EnsureOrigin};
+use frame_system::RawOrigin as SystemOrigin;
+use primitives::Ratio;
+
+const INITIAL_AMOUNT: u128 = 100_000_000_000_000;
+
+/// Longest code accepted, the key of `Codes`
+fn code<T: Config>() -> Vec<u8> {
+    vec![b'p'; T::MaxCodeLength::get() as usize]
+}
+
+fn register<T: Config>(referrer: &T::AccountId) {
+    T::IdentityVerifier::set_verified_identity(referrer);
+    T::Currency::make_free_balance_be(referrer, INITIAL_AMOUNT);
+    assert_ok!(Referrals::<T>::register_code(
+        SystemOrigin::Signed(referrer.clone()).into(),
+        code::<T>()
+    ));
+}
+
+fn tiers(n: u32) -> Vec<Tier> {
+    (0..n)
+        .map(|i| Tier {
+            min_volume: i as Balance * 1_000,
+            discount: Ratio::from_percent(i),
+            rebate: Ratio::from_percent(i),
+        })
+        .collect()
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    register_code {
+        let caller: T::AccountId = whitelisted_caller();
+        T::IdentityVerifier::set_verified_identity(&caller);
+        T::Currency::make_free_balance_be(&caller, INITIAL_AMOUNT);
+    }: _(SystemOrigin::Signed(caller.clone()), code::<T>())
+    verify {
+        assert_last_event::<T>(Event::<T>::CodeRegistered(caller, code::<T>()).into());
+    }
+
+    remove_code {
+        let caller: T::AccountId = whitelisted_caller();
+        register::<T>(&caller);
+    }: _(SystemOrigin::Signed(caller.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::CodeRemoved(caller, code::<T>()).into());
+    }
+
+    set_referrer {
+        let referrer: T::AccountId = account("referrer", 0, 0);
+        register::<T>(&referrer);
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(SystemOrigin::Signed(caller.clone()), code::<T>())
+    verify {
+        assert_last_event::<T>(Event::<T>::Referred(caller, referrer).into());
+    }
+
+    set_tiers {
+        let n in 1 .. T::MaxTiers::get();
+        let origin = T::UpdateOrigin::successful_origin();
+        let call = Call::<T>::set_tiers { tiers: tiers(n) };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::TiersUpdated(tiers(n)).into());
+    }
+
+    claim_rebate {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&Referrals::<T>::account_id(), INITIAL_AMOUNT);
+        PendingRebates::<T>::insert(&caller, INITIAL_AMOUNT / 2);
+    }: _(SystemOrigin::Signed(caller.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::RebateClaimed(caller, INITIAL_AMOUNT / 2).into());
+    }
+}
+
+impl_benchmark_test_suite!(Referrals, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,461 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Referrals pallet
+//!
+//! ## Overview
+//!
+//! Protocol-wide referral program. Accounts with a verified identity register a
+//! referral code against a deposit, other accounts pick their referrer once with
+//! the code, either directly or through a product taking a referral code such as
+//! crowdloans.
+//!
+//! Loans, amm and liquid staking report the volume of referred accounts, which is
+//! valued at the oracle prices and added to the referred volume of their referrer.
+//! The referred volume sets the tier of the referrer: while it lasts, the accounts
+//! it referred pay discounted transaction fees, and the referrer accrues a rebate
+//! on the fees they paid, claimed from the pot of the pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Currency, ExistenceRequirement, ReservableCurrency},
+    transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+use pallet_traits::{IdentityVerifier, PriceFeeder, Referrals, RewardClaimer, VolumeSource};
+use pallet_transaction_payment::OnChargeTransaction;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+    traits::{AccountIdConversion, DispatchInfoOf, PostDispatchInfoOf, Saturating, Zero},
+    transaction_validity::TransactionValidityError,
+    FixedPointNumber,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+pub use pallet::*;
+pub use types::Tier;
+
+mod benchmarking;
+pub mod migrations;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub type ReferralCodeOf<T> = BoundedVec<u8, <T as Config>::MaxCodeLength>;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The currency fees are paid, rebates claimed and code deposits reserved in
+        type Currency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+        /// Prices the referred volume is valued at
+        type PriceFeeder: PriceFeeder;
+
+        /// Only accounts identified on-chain can register referral codes
+        type IdentityVerifier: IdentityVerifier<Self::AccountId>;
+
+        /// Account of the pot rebates are claimed from
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Max length of a referral code
+        #[pallet::constant]
+        type MaxCodeLength: Get<u32>;
+
+        /// Deposit reserved while an account has a referral code
+        #[pallet::constant]
+        type CodeDeposit: Get<Balance>;
+
+        /// Max number of tiers
+        #[pallet::constant]
+        type MaxTiers: Get<u32>;
+
+        /// The origin which sets the tiers
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Referral code was registered
+        /// [referrer, code]
+        CodeRegistered(T::AccountId, Vec<u8>),
+        /// Referral code was removed
+        /// [referrer, code]
+        CodeRemoved(T::AccountId, Vec<u8>),
+        /// Account was referred
+        /// [account, referrer]
+        Referred(T::AccountId, T::AccountId),
+        /// Tiers were replaced
+        /// [tiers]
+        TiersUpdated(Vec<Tier>),
+        /// Referrer claimed its rebate
+        /// [referrer, amount]
+        RebateClaimed(T::AccountId, Balance),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account has no identity judged by a registrar
+        IdentityNotVerified,
+        /// Referral code is empty or too long
+        InvalidCode,
+        /// Referral code is registered by another account
+        CodeTaken,
+        /// Account already registered a referral code
+        AlreadyHasCode,
+        /// Account has no referral code
+        NoCode,
+        /// Referral code isn't registered
+        UnknownCode,
+        /// Account already has a referrer
+        AlreadyReferred,
+        /// Accounts can't refer themselves
+        SelfReferral,
+        /// More tiers than `MaxTiers`
+        TooManyTiers,
+        /// Tiers aren't sorted by strictly increasing min volume
+        TiersNotSorted,
+        /// Referrer has no rebate to claim
+        NoRebate,
+    }
+
+    /// Owners of the referral codes
+    /// ReferralCode -> AccountId
+    #[pallet::storage]
+    #[pallet::getter(fn codes)]
+    pub type Codes<T: Config> =
+        StorageMap<_, Blake2_128Concat, ReferralCodeOf<T>, T::AccountId, OptionQuery>;
+
+    /// Referral code registered by an account
+    /// AccountId -> ReferralCode
+    #[pallet::storage]
+    #[pallet::getter(fn code_of)]
+    pub type CodeOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ReferralCodeOf<T>, OptionQuery>;
+
+    /// Deposit reserved for the referral code of an account
+    /// AccountId -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn code_deposit)]
+    pub type CodeDeposits<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+    /// Referrer of an account, kept when the referrer removes its code
+    /// AccountId -> AccountId
+    #[pallet::storage]
+    #[pallet::getter(fn referrer_of)]
+    pub type ReferrerOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    /// Volume of the accounts referred by a referrer, by product
+    /// AccountId -> VolumeSource -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn referred_volume)]
+    pub type ReferredVolume<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        VolumeSource,
+        Balance,
+        ValueQuery,
+    >;
+
+    /// Volume of the accounts referred by a referrer across products, sets its tier
+    /// AccountId -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn total_referred_volume)]
+    pub type TotalReferredVolume<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+    /// Rebates accrued by referrers and not claimed yet
+    /// AccountId -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn pending_rebates)]
+    pub type PendingRebates<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+    /// Tiers sorted by increasing min volume
+    #[pallet::storage]
+    #[pallet::getter(fn tiers)]
+    pub type Tiers<T: Config> = StorageValue<_, BoundedVec<Tier, T::MaxTiers>, ValueQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register a referral code for the caller, one per account, reserving
+        /// `CodeDeposit` until it is removed
+        #[pallet::weight(<T as Config>::WeightInfo::register_code())]
+        #[transactional]
+        pub fn register_code(origin: OriginFor<T>, code: Vec<u8>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let code = Self::bounded_code(&code).ok_or(Error::<T>::InvalidCode)?;
+            ensure!(
+                T::IdentityVerifier::has_verified_identity(&who),
+                Error::<T>::IdentityNotVerified
+            );
+            ensure!(!CodeOf::<T>::contains_key(&who), Error::<T>::AlreadyHasCode);
+            ensure!(!Codes::<T>::contains_key(&code), Error::<T>::CodeTaken);
+
+            let deposit = T::CodeDeposit::get();
+            T::Currency::reserve(&who, deposit)?;
+            CodeDeposits::<T>::insert(&who, deposit);
+            Codes::<T>::insert(&code, &who);
+            CodeOf::<T>::insert(&who, &code);
+
+            Self::deposit_event(Event::<T>::CodeRegistered(who, code.into_inner()));
+            Ok(())
+        }
+
+        /// Remove the referral code of the caller and release its deposit, the
+        /// accounts it referred stay referred by the caller
+        #[pallet::weight(<T as Config>::WeightInfo::remove_code())]
+        #[transactional]
+        pub fn remove_code(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let code = CodeOf::<T>::take(&who).ok_or(Error::<T>::NoCode)?;
+            Codes::<T>::remove(&code);
+            T::Currency::unreserve(&who, CodeDeposits::<T>::take(&who));
+
+            Self::deposit_event(Event::<T>::CodeRemoved(who, code.into_inner()));
+            Ok(())
+        }
+
+        /// Make the owner of `code` the referrer of the caller, it can't be changed
+        /// afterwards
+        #[pallet::weight(<T as Config>::WeightInfo::set_referrer())]
+        #[transactional]
+        pub fn set_referrer(origin: OriginFor<T>, code: Vec<u8>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_set_referrer(&who, &code)
+        }
+
+        /// Replace the tiers, sorted by strictly increasing min volume
+        #[pallet::weight(<T as Config>::WeightInfo::set_tiers(tiers.len() as u32))]
+        #[transactional]
+        pub fn set_tiers(origin: OriginFor<T>, tiers: Vec<Tier>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                tiers.windows(2).all(|w| w[0].min_volume < w[1].min_volume),
+                Error::<T>::TiersNotSorted
+            );
+            let bounded = BoundedVec::<_, T::MaxTiers>::try_from(tiers.clone())
+                .map_err(|_| Error::<T>::TooManyTiers)?;
+            Tiers::<T>::put(bounded);
+
+            Self::deposit_event(Event::<T>::TiersUpdated(tiers));
+            Ok(())
+        }
+
+        /// Claim the rebate accrued by the caller from the pot
+        #[pallet::weight(<T as Config>::WeightInfo::claim_rebate())]
+        #[transactional]
+        pub fn claim_rebate(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_claim_rebate(&who)
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Account of the rebate pot
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    /// Tier reached by the referred volume of `referrer`
+    pub fn tier_of(referrer: &T::AccountId) -> Option<Tier> {
+        let volume = Self::total_referred_volume(referrer);
+        Self::tiers()
+            .iter()
+            .rev()
+            .find(|tier| tier.min_volume <= volume)
+            .copied()
+    }
+
+    fn do_claim_rebate(who: &T::AccountId) -> DispatchResult {
+        let amount = PendingRebates::<T>::take(who);
+        ensure!(!amount.is_zero(), Error::<T>::NoRebate);
+
+        T::Currency::transfer(
+            &Self::account_id(),
+            who,
+            amount,
+            ExistenceRequirement::KeepAlive,
+        )?;
+
+        Self::deposit_event(Event::<T>::RebateClaimed(who.clone(), amount));
+        Ok(())
+    }
+
+    fn bounded_code(code: &[u8]) -> Option<ReferralCodeOf<T>> {
+        if code.is_empty() {
+            return None;
+        }
+        ReferralCodeOf::<T>::try_from(code.to_vec()).ok()
+    }
+
+    fn do_set_referrer(who: &T::AccountId, code: &[u8]) -> DispatchResult {
+        ensure!(
+            !ReferrerOf::<T>::contains_key(who),
+            Error::<T>::AlreadyReferred
+        );
+        let referrer = Self::bounded_code(code)
+            .and_then(Self::codes)
+            .ok_or(Error::<T>::UnknownCode)?;
+        ensure!(&referrer != who, Error::<T>::SelfReferral);
+
+        ReferrerOf::<T>::insert(who, &referrer);
+        Self::deposit_event(Event::<T>::Referred(who.clone(), referrer));
+        Ok(())
+    }
+
+    /// `fee` less the discount of `tier`, the tip isn't discounted
+    fn discounted(fee: Balance, tip: Balance, tier: &Tier) -> Balance {
+        fee.saturating_sub(tier.discount.mul_floor(fee.saturating_sub(tip)))
+    }
+}
+
+impl<T: Config> Referrals<T::AccountId> for Pallet<T> {
+    fn referrer_of_code(code: &[u8]) -> Option<T::AccountId> {
+        Self::bounded_code(code).and_then(Self::codes)
+    }
+
+    fn refer(who: &T::AccountId, code: &[u8]) {
+        // Products take referral codes along with other operations, which must not
+        // fail because the code is unknown or the account was already referred
+        let _ = Self::do_set_referrer(who, code);
+    }
+
+    fn record_volume(
+        who: &T::AccountId,
+        source: VolumeSource,
+        asset_id: CurrencyId,
+        amount: Balance,
+    ) {
+        let referrer = match Self::referrer_of(who) {
+            Some(referrer) => referrer,
+            None => return,
+        };
+        let value = match T::PriceFeeder::get_price(&asset_id) {
+            Some((price, _)) => price.saturating_mul_int(amount),
+            None => return,
+        };
+        if value.is_zero() {
+            return;
+        }
+
+        ReferredVolume::<T>::mutate(&referrer, source, |volume| {
+            *volume = volume.saturating_add(value)
+        });
+        TotalReferredVolume::<T>::mutate(&referrer, |volume| {
+            *volume = volume.saturating_add(value)
+        });
+    }
+}
+
+impl<T: Config> RewardClaimer<T::AccountId> for Pallet<T> {
+    fn claim_rewards(who: &T::AccountId, max_claims: u32) -> Result<u32, DispatchError> {
+        if max_claims.is_zero() || Self::pending_rebates(who).is_zero() {
+            return Ok(0);
+        }
+        Self::do_claim_rebate(who)?;
+        Ok(1)
+    }
+}
+
+/// Charges fees with `Inner`, discounted by the tier of the referrer of the payer,
+/// and accrues the rebate of the referrer on the fees paid
+pub struct DiscountFees<T, Inner>(PhantomData<(T, Inner)>);
+impl<T, Inner> OnChargeTransaction<T> for DiscountFees<T, Inner>
+where
+    T: Config + pallet_transaction_payment::Config,
+    Inner: OnChargeTransaction<T, Balance = Balance>,
+{
+    type Balance = Balance;
+    /// The referrer and its tier when the fee was withdrawn, so that the corrected
+    /// fee is discounted alike even if the call moved the referrer to another tier
+    type LiquidityInfo = (Inner::LiquidityInfo, Option<(T::AccountId, Tier)>);
+
+    fn withdraw_fee(
+        who: &T::AccountId,
+        call: &T::RuntimeCall,
+        dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+        fee: Balance,
+        tip: Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        let referral = Pallet::<T>::referrer_of(who)
+            .and_then(|referrer| Pallet::<T>::tier_of(&referrer).map(|tier| (referrer, tier)));
+        let fee = match &referral {
+            Some((_, tier)) => Pallet::<T>::discounted(fee, tip, tier),
+            None => fee,
+        };
+        let liquidity_info = Inner::withdraw_fee(who, call, dispatch_info, fee, tip)?;
+        Ok((liquidity_info, referral))
+    }
+
+    fn correct_and_deposit_fee(
+        who: &T::AccountId,
+        dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+        post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+        corrected_fee: Balance,
+        tip: Balance,
+        (already_withdrawn, referral): Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        let corrected_fee = match &referral {
+            Some((_, tier)) => Pallet::<T>::discounted(corrected_fee, tip, tier),
+            None => corrected_fee,
+        };
+        Inner::correct_and_deposit_fee(
+            who,
+            dispatch_info,
+            post_info,
+            corrected_fee,
+            tip,
+            already_withdrawn,
+        )?;
+
+        if let Some((referrer, tier)) = referral {
+            let rebate = tier.rebate.mul_floor(corrected_fee.saturating_sub(tip));
+            if !rebate.is_zero() {
+                PendingRebates::<T>::mutate(&referrer, |pending| {
+                    *pending = pending.saturating_add(rebate)
+                });
+            }
+        }
+        Ok(())
+    }
+}
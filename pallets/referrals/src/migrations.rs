@@ -0,0 +1,58 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+    log,
+    storage::migration::storage_key_iter,
+    traits::{Get, OnRuntimeUpgrade},
+    weights::Weight,
+};
+
+/// Moves the referral codes registered in crowdloans to the referrals pallet.
+///
+/// Codes which are too long, or whose owner registered another one first, are
+/// dropped. Contributions keep the referral code they were made with. The
+/// migrated codes hold no deposit, as none was reserved for them in crowdloans.
+pub struct MigrateCrowdloansReferrers<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateCrowdloansReferrers<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut count = 0u64;
+        let mut migrated = 0u64;
+        for (code, who) in
+            storage_key_iter::<Vec<u8>, T::AccountId, Blake2_128Concat>(b"Crowdloans", b"Referrers")
+                .drain()
+        {
+            count += 1;
+            let code = match Pallet::<T>::bounded_code(&code) {
+                Some(code) => code,
+                None => continue,
+            };
+            if CodeOf::<T>::contains_key(&who) || Codes::<T>::contains_key(&code) {
+                continue;
+            }
+            Codes::<T>::insert(&code, &who);
+            CodeOf::<T>::insert(&who, &code);
+            migrated += 1;
+        }
+
+        log::info!(
+            target: "referrals::migrate",
+            "migrated {} of {} crowdloans referral codes",
+            migrated,
+            count
+        );
+        T::DbWeight::get().reads_writes(count.saturating_mul(3), count + migrated * 2)
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU8, Everything},
+    weights::IdentityFee,
+};
+use frame_system::EnsureRoot;
+use pallet_transaction_payment::CurrencyAdapter;
+use primitives::{Price, PriceDetail};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+use std::cell::RefCell;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
+        Referrals: crate::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+
+pub const DOT: CurrencyId = 101;
+pub const KSM: CurrencyId = 100;
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+    pub const MaxLocks: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = MaxLocks;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+}
+
+impl pallet_transaction_payment::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type OnChargeTransaction = DiscountFees<Test, CurrencyAdapter<Balances, ()>>;
+    type OperationalFeeMultiplier = ConstU8<5>;
+    type WeightToFee = IdentityFee<Balance>;
+    type LengthToFee = IdentityFee<Balance>;
+    type FeeMultiplierUpdate = ();
+}
+
+/// DOT is worth 2, KSM has no price
+pub struct MockPriceFeeder;
+impl PriceFeeder for MockPriceFeeder {
+    fn get_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
+        match *asset_id {
+            DOT => Some((Price::saturating_from_integer(2), 0)),
+            _ => None,
+        }
+    }
+}
+
+thread_local! {
+    pub static IDENTIFIED: RefCell<Vec<AccountId>> = RefCell::new(vec![ALICE]);
+}
+
+pub struct AliceIdentified;
+impl IdentityVerifier<AccountId> for AliceIdentified {
+    fn has_verified_identity(who: &AccountId) -> bool {
+        IDENTIFIED.with(|v| v.borrow().contains(who))
+    }
+
+    fn display_name(_who: &AccountId) -> Option<Vec<u8>> {
+        None
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_verified_identity(who: &AccountId) {
+        IDENTIFIED.with(|v| v.borrow_mut().push(*who))
+    }
+}
+
+parameter_types! {
+    pub const ReferralsPalletId: PalletId = PalletId(*b"par/refr");
+    pub const MaxCodeLength: u32 = 8;
+    pub const CodeDeposit: Balance = 100;
+    pub const MaxTiers: u32 = 3;
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type PriceFeeder = MockPriceFeeder;
+    type IdentityVerifier = AliceIdentified;
+    type PalletId = ReferralsPalletId;
+    type MaxCodeLength = MaxCodeLength;
+    type CodeDeposit = CodeDeposit;
+    type MaxTiers = MaxTiers;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ALICE, 1_000),
+            (BOB, 1_000),
+            (CHARLIE, 1_000),
+            (Referrals::account_id(), 1_000),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,290 @@
+use super::*;
+use crate::mock::{Referrals, *};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    storage::migration::{put_storage_value, storage_key_iter},
+    traits::OnRuntimeUpgrade,
+    StorageHasher,
+};
+use pallet_traits::Referrals as _;
+use pallet_transaction_payment::CurrencyAdapter;
+use primitives::Ratio;
+
+type Fees = DiscountFees<Test, CurrencyAdapter<Balances, ()>>;
+
+fn tier(min_volume: Balance, discount: u32, rebate: u32) -> Tier {
+    Tier {
+        min_volume,
+        discount: Ratio::from_percent(discount),
+        rebate: Ratio::from_percent(rebate),
+    }
+}
+
+fn refer_bob() {
+    assert_ok!(Referrals::register_code(
+        RuntimeOrigin::signed(ALICE),
+        b"alice".to_vec()
+    ));
+    assert_ok!(Referrals::set_referrer(
+        RuntimeOrigin::signed(BOB),
+        b"alice".to_vec()
+    ));
+}
+
+/// Charge `fee` to `who`, then correct it to `corrected_fee`
+fn charge(who: AccountId, fee: Balance, corrected_fee: Balance) {
+    let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+    let info = DispatchInfo::default();
+    let liquidity_info = Fees::withdraw_fee(&who, &call, &info, fee, 0).unwrap();
+    assert_ok!(Fees::correct_and_deposit_fee(
+        &who,
+        &info,
+        &PostDispatchInfo::default(),
+        corrected_fee,
+        0,
+        liquidity_info,
+    ));
+}
+
+#[test]
+fn register_code_should_work() {
+    new_test_ext().execute_with(|| {
+        let code = b"alice".to_vec();
+        assert_noop!(
+            Referrals::register_code(RuntimeOrigin::signed(BOB), code.clone()),
+            Error::<Test>::IdentityNotVerified
+        );
+        assert_noop!(
+            Referrals::register_code(RuntimeOrigin::signed(ALICE), vec![]),
+            Error::<Test>::InvalidCode
+        );
+        assert_noop!(
+            Referrals::register_code(RuntimeOrigin::signed(ALICE), b"too-long-code".to_vec()),
+            Error::<Test>::InvalidCode
+        );
+        assert_ok!(Referrals::register_code(
+            RuntimeOrigin::signed(ALICE),
+            code.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::Referrals(Event::CodeRegistered(
+            ALICE,
+            code.clone(),
+        )));
+        assert_eq!(
+            Referrals::codes(Referrals::bounded_code(&code).unwrap()),
+            Some(ALICE)
+        );
+        assert_eq!(Referrals::referrer_of_code(&code), Some(ALICE));
+        assert_eq!(Balances::reserved_balance(ALICE), CodeDeposit::get());
+        assert_noop!(
+            Referrals::register_code(RuntimeOrigin::signed(ALICE), b"other".to_vec()),
+            Error::<Test>::AlreadyHasCode
+        );
+
+        IDENTIFIED.with(|v| v.borrow_mut().push(CHARLIE));
+        assert_noop!(
+            Referrals::register_code(RuntimeOrigin::signed(CHARLIE), code.clone()),
+            Error::<Test>::CodeTaken
+        );
+
+        assert_noop!(
+            Referrals::remove_code(RuntimeOrigin::signed(BOB)),
+            Error::<Test>::NoCode
+        );
+        assert_ok!(Referrals::remove_code(RuntimeOrigin::signed(ALICE)));
+        assert_eq!(
+            Referrals::codes(Referrals::bounded_code(&code).unwrap()),
+            None
+        );
+        assert_eq!(Referrals::code_of(ALICE), None);
+        assert_eq!(Referrals::referrer_of_code(&code), None);
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_ok!(Referrals::register_code(
+            RuntimeOrigin::signed(CHARLIE),
+            code
+        ));
+    })
+}
+
+#[test]
+fn referrer_should_be_set_once() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Referrals::set_referrer(RuntimeOrigin::signed(BOB), b"alice".to_vec()),
+            Error::<Test>::UnknownCode
+        );
+        assert_ok!(Referrals::register_code(
+            RuntimeOrigin::signed(ALICE),
+            b"alice".to_vec()
+        ));
+        assert_noop!(
+            Referrals::set_referrer(RuntimeOrigin::signed(ALICE), b"alice".to_vec()),
+            Error::<Test>::SelfReferral
+        );
+        assert_ok!(Referrals::set_referrer(
+            RuntimeOrigin::signed(BOB),
+            b"alice".to_vec()
+        ));
+        System::assert_last_event(RuntimeEvent::Referrals(Event::Referred(BOB, ALICE)));
+        assert_noop!(
+            Referrals::set_referrer(RuntimeOrigin::signed(BOB), b"alice".to_vec()),
+            Error::<Test>::AlreadyReferred
+        );
+
+        // Products referring accounts ignore unknown codes and existing referrers
+        Referrals::refer(&CHARLIE, b"unknown");
+        assert_eq!(Referrals::referrer_of(CHARLIE), None);
+        Referrals::refer(&CHARLIE, b"alice");
+        assert_eq!(Referrals::referrer_of(CHARLIE), Some(ALICE));
+
+        // Referred accounts stay referred when the code is removed
+        assert_ok!(Referrals::remove_code(RuntimeOrigin::signed(ALICE)));
+        assert_eq!(Referrals::referrer_of(BOB), Some(ALICE));
+    })
+}
+
+#[test]
+fn volume_of_referred_accounts_should_be_recorded() {
+    new_test_ext().execute_with(|| {
+        refer_bob();
+
+        Referrals::record_volume(&BOB, VolumeSource::LoansSupply, DOT, 10);
+        Referrals::record_volume(&BOB, VolumeSource::AmmSwap, DOT, 5);
+        // Assets without price and accounts without referrer aren't counted
+        Referrals::record_volume(&BOB, VolumeSource::Staking, KSM, 100);
+        Referrals::record_volume(&CHARLIE, VolumeSource::LoansBorrow, DOT, 100);
+
+        assert_eq!(
+            Referrals::referred_volume(ALICE, VolumeSource::LoansSupply),
+            20
+        );
+        assert_eq!(Referrals::referred_volume(ALICE, VolumeSource::AmmSwap), 10);
+        assert_eq!(Referrals::referred_volume(ALICE, VolumeSource::Staking), 0);
+        assert_eq!(Referrals::total_referred_volume(ALICE), 30);
+        assert_eq!(Referrals::total_referred_volume(CHARLIE), 0);
+    })
+}
+
+#[test]
+fn tiers_should_be_sorted_and_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Referrals::set_tiers(RuntimeOrigin::signed(ALICE), vec![tier(0, 10, 5)]),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Referrals::set_tiers(
+                RuntimeOrigin::root(),
+                vec![tier(100, 10, 5), tier(100, 20, 10)]
+            ),
+            Error::<Test>::TiersNotSorted
+        );
+        assert_noop!(
+            Referrals::set_tiers(
+                RuntimeOrigin::root(),
+                vec![
+                    tier(0, 10, 5),
+                    tier(100, 20, 10),
+                    tier(200, 30, 15),
+                    tier(300, 40, 20)
+                ]
+            ),
+            Error::<Test>::TooManyTiers
+        );
+
+        let tiers = vec![tier(0, 10, 5), tier(100, 20, 10)];
+        assert_ok!(Referrals::set_tiers(RuntimeOrigin::root(), tiers.clone()));
+        System::assert_last_event(RuntimeEvent::Referrals(Event::TiersUpdated(tiers.clone())));
+
+        refer_bob();
+        assert_eq!(Referrals::tier_of(&ALICE), Some(tiers[0]));
+        Referrals::record_volume(&BOB, VolumeSource::LoansSupply, DOT, 50);
+        assert_eq!(Referrals::tier_of(&ALICE), Some(tiers[1]));
+    })
+}
+
+#[test]
+fn fees_should_be_discounted_by_tier() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Referrals::set_tiers(
+            RuntimeOrigin::root(),
+            vec![tier(0, 10, 5), tier(100, 50, 20)]
+        ));
+
+        // Accounts without referrer pay the full fee
+        charge(BOB, 100, 100);
+        assert_eq!(Balances::free_balance(BOB), 900);
+
+        refer_bob();
+        charge(BOB, 100, 80);
+        assert_eq!(Balances::free_balance(BOB), 828);
+        assert_eq!(Referrals::pending_rebates(ALICE), 3);
+
+        Referrals::record_volume(&BOB, VolumeSource::Staking, DOT, 50);
+        charge(BOB, 100, 100);
+        assert_eq!(Balances::free_balance(BOB), 778);
+        assert_eq!(Referrals::pending_rebates(ALICE), 13);
+        assert_eq!(Referrals::pending_rebates(BOB), 0);
+    })
+}
+
+#[test]
+fn rebates_should_be_claimed_from_the_pot() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Referrals::claim_rebate(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::NoRebate
+        );
+
+        PendingRebates::<Test>::insert(ALICE, 100);
+        assert_ok!(Referrals::claim_rebate(RuntimeOrigin::signed(ALICE)));
+        System::assert_last_event(RuntimeEvent::Referrals(Event::RebateClaimed(ALICE, 100)));
+        assert_eq!(Balances::free_balance(ALICE), 1_100);
+        assert_eq!(Balances::free_balance(Referrals::account_id()), 900);
+        assert_eq!(Referrals::pending_rebates(ALICE), 0);
+
+        // The rebate is kept until the pot can pay it
+        PendingRebates::<Test>::insert(ALICE, 900);
+        assert!(Referrals::claim_rebate(RuntimeOrigin::signed(ALICE)).is_err());
+        assert_eq!(Referrals::pending_rebates(ALICE), 900);
+    })
+}
+
+#[test]
+fn crowdloans_referral_codes_should_be_migrated() {
+    new_test_ext().execute_with(|| {
+        let put_referrer = |code: &[u8], who: AccountId| {
+            put_storage_value(
+                b"Crowdloans",
+                b"Referrers",
+                &Blake2_128Concat::hash(&code.to_vec().encode()),
+                who,
+            )
+        };
+        put_referrer(b"alice", ALICE);
+        put_referrer(b"too-long-code", BOB);
+        put_referrer(b"charlie", CHARLIE);
+        assert_ok!(Referrals::register_code(
+            RuntimeOrigin::signed(ALICE),
+            b"other".to_vec()
+        ));
+
+        migrations::MigrateCrowdloansReferrers::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            Referrals::code_of(CHARLIE).map(|code| code.into_inner()),
+            Some(b"charlie".to_vec())
+        );
+        assert_eq!(
+            Referrals::code_of(ALICE).map(|code| code.into_inner()),
+            Some(b"other".to_vec())
+        );
+        assert_eq!(Referrals::code_of(BOB), None);
+        assert_eq!(
+            storage_key_iter::<Vec<u8>, AccountId, Blake2_128Concat>(b"Crowdloans", b"Referrers")
+                .count(),
+            0
+        );
+    })
+}
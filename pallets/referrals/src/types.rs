@@ -0,0 +1,29 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use primitives::{Balance, Ratio};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Benefits of the accounts referred by a referrer, by the volume they brought
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct Tier {
+    /// Min volume referred, valued at the oracle prices, to reach the tier
+    pub min_volume: Balance,
+    /// Share of the transaction fees the referred accounts don't pay
+    pub discount: Ratio,
+    /// Share of the fees paid by the referred accounts given back to the referrer
+    pub rebate: Ratio,
+}
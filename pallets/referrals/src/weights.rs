@@ -0,0 +1,151 @@
+// This file is part of Parallel Finance.
+
+// Copyright (C) 2022 Parallel Finance Developer.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_referrals
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet-referrals
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/referrals/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions needed for pallet_referrals.
+pub trait WeightInfo {
+	fn register_code() -> Weight;
+	fn remove_code() -> Weight;
+	fn set_referrer() -> Weight;
+	fn set_tiers(n: u32, ) -> Weight;
+	fn claim_rebate() -> Weight;
+}
+
+/// Weights for pallet_referrals using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Identity SuperOf (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: Referrals CodeOf (r:1 w:1)
+	// Storage: Referrals Codes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Referrals CodeDeposits (r:0 w:1)
+	fn register_code() -> Weight {
+		Weight::from_ref_time(42_689_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Referrals CodeOf (r:1 w:1)
+	// Storage: Referrals Codes (r:0 w:1)
+	// Storage: Referrals CodeDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_code() -> Weight {
+		Weight::from_ref_time(33_453_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Referrals ReferrerOf (r:1 w:1)
+	// Storage: Referrals Codes (r:1 w:0)
+	fn set_referrer() -> Weight {
+		Weight::from_ref_time(26_338_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Referrals Tiers (r:0 w:1)
+	/// The range of component `n` is `[1, 10]`.
+	fn set_tiers(n: u32, ) -> Weight {
+		Weight::from_ref_time(16_032_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(391_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Referrals PendingRebates (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_rebate() -> Weight {
+		Weight::from_ref_time(46_352_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Identity SuperOf (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: Referrals CodeOf (r:1 w:1)
+	// Storage: Referrals Codes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Referrals CodeDeposits (r:0 w:1)
+	fn register_code() -> Weight {
+		Weight::from_ref_time(42_689_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Referrals CodeOf (r:1 w:1)
+	// Storage: Referrals Codes (r:0 w:1)
+	// Storage: Referrals CodeDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_code() -> Weight {
+		Weight::from_ref_time(33_453_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Referrals ReferrerOf (r:1 w:1)
+	// Storage: Referrals Codes (r:1 w:0)
+	fn set_referrer() -> Weight {
+		Weight::from_ref_time(26_338_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Referrals Tiers (r:0 w:1)
+	/// The range of component `n` is `[1, 10]`.
+	fn set_tiers(n: u32, ) -> Weight {
+		Weight::from_ref_time(16_032_000 as u64)
+			.saturating_add(Weight::from_ref_time(391_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Referrals PendingRebates (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_rebate() -> Weight {
+		Weight::from_ref_time(46_352_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+}
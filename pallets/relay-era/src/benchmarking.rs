@@ -0,0 +1,35 @@
+//! Relay era pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as RelayEra;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin as SystemOrigin;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    on_initialize {
+    }: {
+        RelayEra::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+    }
+
+    force_set_current_era {
+    }: _(SystemOrigin::Root, 12)
+    verify {
+        assert_eq!(CurrentEra::<T>::get(), 12);
+        assert_last_event::<T>(Event::<T>::NewEra(12, EraStartBlock::<T>::get()).into());
+    }
+
+    force_set_current_session {
+    }: _(SystemOrigin::Root, 72)
+    verify {
+        assert_eq!(CurrentSession::<T>::get(), 72);
+        assert_last_event::<T>(Event::<T>::NewSession(72, SessionStartBlock::<T>::get()).into());
+    }
+}
+
+impl_benchmark_test_suite!(RelayEra, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,316 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Relay era pallet
+//!
+//! ## Overview
+//!
+//! Tracks the era and session progression of the relaychain, so that pallets working
+//! with relaychain staking share the same era index instead of each deriving it.
+//!
+//! Eras and sessions advance with the relaychain block number carried by the validation
+//! data: once `EraLength` relay blocks passed since the start of the era, the era moves
+//! on, and so does the session every `EraLength / SessionsPerEra` relay blocks. As the
+//! relaychain era may be delayed, anyone can realign the era or the session by providing
+//! a storage proof of the relaychain's value against the relay parent storage root.
+//!
+//! Added to a live chain, the pallet starts at era and session zero, which have to be
+//! realigned once before other pallets rely on them.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    log,
+    pallet_prelude::*,
+    storage::storage_prefix,
+    traits::{Get, IsType},
+};
+use frame_system::pallet_prelude::*;
+use pallet_traits::{EraProvider, ValidationDataProvider};
+use primitives::{EraIndex, PersistedValidationData};
+use sp_runtime::traits::{BlakeTwo256, BlockNumberProvider, SaturatedConversion, Saturating, Zero};
+use sp_std::vec::Vec;
+use sp_trie::StorageProof;
+
+pub use pallet::*;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub type SessionIndex = u32;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Relaychain block number and validation data
+        type RelayChainValidationDataProvider: ValidationDataProvider
+            + BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+        /// Number of relaychain blocks of an era
+        #[pallet::constant]
+        type EraLength: Get<BlockNumberFor<Self>>;
+
+        /// Number of sessions of an era
+        #[pallet::constant]
+        type SessionsPerEra: Get<SessionIndex>;
+
+        /// The origin which can force the era and the session
+        type UpdateOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// New relaychain era
+        /// [era_index, start_block]
+        NewEra(EraIndex, BlockNumberFor<T>),
+        /// New relaychain session
+        /// [session_index, start_block]
+        NewSession(SessionIndex, BlockNumberFor<T>),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The storage proof is invalid
+        InvalidProof,
+        /// The proven index isn't ahead of the tracked one
+        StaleIndex,
+    }
+
+    /// Current relaychain era
+    #[pallet::storage]
+    #[pallet::getter(fn current_era)]
+    pub type CurrentEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
+    /// Relaychain block number the current era started at
+    #[pallet::storage]
+    #[pallet::getter(fn era_start_block)]
+    pub type EraStartBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Current relaychain session
+    #[pallet::storage]
+    #[pallet::getter(fn current_session)]
+    pub type CurrentSession<T: Config> = StorageValue<_, SessionIndex, ValueQuery>;
+
+    /// Relaychain block number the current session started at
+    #[pallet::storage]
+    #[pallet::getter(fn session_start_block)]
+    pub type SessionStartBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Validation data of the last block, storage proofs are checked against it
+    #[pallet::storage]
+    #[pallet::getter(fn validation_data)]
+    pub type ValidationData<T: Config> = StorageValue<_, PersistedValidationData, OptionQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            let relay_block = T::RelayChainValidationDataProvider::current_block_number();
+
+            // Start counting from the first relay block seen, the indexes themselves are
+            // left for `force_set_*` or `set_current_*` to align with the relaychain
+            if Self::era_start_block().is_zero() {
+                EraStartBlock::<T>::put(relay_block);
+                SessionStartBlock::<T>::put(relay_block);
+                return T::WeightInfo::on_initialize();
+            }
+
+            let session_offset = Self::offset(
+                relay_block,
+                Self::session_start_block(),
+                Self::session_length(),
+            );
+            if !session_offset.is_zero() {
+                Self::do_advance_session(
+                    Self::current_session().saturating_add(session_offset),
+                    relay_block,
+                );
+            }
+
+            let era_offset =
+                Self::offset(relay_block, Self::era_start_block(), T::EraLength::get());
+            if !era_offset.is_zero() {
+                Self::do_advance_era(Self::current_era().saturating_add(era_offset), relay_block);
+            }
+
+            T::WeightInfo::on_initialize()
+        }
+
+        fn on_finalize(_n: BlockNumberFor<T>) {
+            if let Some(data) = T::RelayChainValidationDataProvider::validation_data() {
+                ValidationData::<T>::put(data);
+            }
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the current era
+        #[pallet::weight(T::WeightInfo::force_set_current_era())]
+        pub fn force_set_current_era(origin: OriginFor<T>, era: EraIndex) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::do_advance_era(
+                era,
+                T::RelayChainValidationDataProvider::current_block_number(),
+            );
+            Ok(())
+        }
+
+        /// Set the current session
+        #[pallet::weight(T::WeightInfo::force_set_current_session())]
+        pub fn force_set_current_session(
+            origin: OriginFor<T>,
+            session: SessionIndex,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::do_advance_session(
+                session,
+                T::RelayChainValidationDataProvider::current_block_number(),
+            );
+            Ok(())
+        }
+
+        /// Catch up with the relaychain's `Staking.CurrentEra`, proven against the
+        /// relay parent storage root
+        #[pallet::weight(T::WeightInfo::force_set_current_era())]
+        pub fn set_current_era(
+            origin: OriginFor<T>,
+            era: EraIndex,
+            proof: Vec<Vec<u8>>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(era > Self::current_era(), Error::<T>::StaleIndex);
+            ensure!(
+                Self::verify_merkle_proof(
+                    storage_prefix(b"Staking", b"CurrentEra").to_vec(),
+                    era.encode(),
+                    proof
+                ),
+                Error::<T>::InvalidProof
+            );
+
+            Self::do_advance_era(
+                era,
+                T::RelayChainValidationDataProvider::current_block_number(),
+            );
+            Ok(())
+        }
+
+        /// Catch up with the relaychain's `Session.CurrentIndex`, proven against the
+        /// relay parent storage root
+        #[pallet::weight(T::WeightInfo::force_set_current_session())]
+        pub fn set_current_session(
+            origin: OriginFor<T>,
+            session: SessionIndex,
+            proof: Vec<Vec<u8>>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(session > Self::current_session(), Error::<T>::StaleIndex);
+            ensure!(
+                Self::verify_merkle_proof(
+                    storage_prefix(b"Session", b"CurrentIndex").to_vec(),
+                    session.encode(),
+                    proof
+                ),
+                Error::<T>::InvalidProof
+            );
+
+            Self::do_advance_session(
+                session,
+                T::RelayChainValidationDataProvider::current_block_number(),
+            );
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    fn session_length() -> BlockNumberFor<T> {
+        let sessions_per_era: BlockNumberFor<T> = T::SessionsPerEra::get().max(1).into();
+        T::EraLength::get() / sessions_per_era
+    }
+
+    /// Number of whole periods of `length` passed since `start`
+    fn offset(now: BlockNumberFor<T>, start: BlockNumberFor<T>, length: BlockNumberFor<T>) -> u32 {
+        if length.is_zero() {
+            return 0;
+        }
+        (now.saturating_sub(start) / length).saturated_into()
+    }
+
+    fn do_advance_era(era: EraIndex, start_block: BlockNumberFor<T>) {
+        log::trace!(
+            target: "relayEra::do_advance_era",
+            "era: {:?}, start_block: {:?}",
+            &era,
+            &start_block,
+        );
+        CurrentEra::<T>::put(era);
+        EraStartBlock::<T>::put(start_block);
+        Self::deposit_event(Event::<T>::NewEra(era, start_block));
+    }
+
+    fn do_advance_session(session: SessionIndex, start_block: BlockNumberFor<T>) {
+        CurrentSession::<T>::put(session);
+        SessionStartBlock::<T>::put(start_block);
+        Self::deposit_event(Event::<T>::NewSession(session, start_block));
+    }
+
+    fn verify_merkle_proof(key: Vec<u8>, value: Vec<u8>, proof: Vec<Vec<u8>>) -> bool {
+        let relay_parent_storage_root = match Self::validation_data() {
+            Some(data) => data.relay_parent_storage_root,
+            None => return false,
+        };
+        let db = StorageProof::new(proof).into_memory_db();
+        matches!(
+            sp_trie::read_trie_value::<sp_trie::LayoutV1<BlakeTwo256>, _>(
+                &db,
+                &relay_parent_storage_root,
+                &key,
+                None,
+                None,
+            ),
+            Ok(Some(result)) if result == value
+        )
+    }
+}
+
+impl<T: Config> EraProvider<BlockNumberFor<T>> for Pallet<T> {
+    fn current_era() -> EraIndex {
+        Self::current_era()
+    }
+
+    fn era_start_block() -> BlockNumberFor<T> {
+        Self::era_start_block()
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+
+#[test]
+fn era_and_session_advance_with_relay_blocks() {
+    new_test_ext().execute_with(|| {
+        run_to_relay_block(9);
+        assert_eq!(RelayEra::current_session(), 0);
+        assert_eq!(RelayEra::current_era(), 0);
+
+        // A session lasts EraLength / SessionsPerEra relay blocks
+        run_to_relay_block(10);
+        assert_eq!(RelayEra::current_session(), 1);
+        assert_eq!(RelayEra::session_start_block(), 10);
+        assert_eq!(RelayEra::current_era(), 0);
+        System::assert_last_event(Event::NewSession(1, 10).into());
+
+        run_to_relay_block(60);
+        assert_eq!(RelayEra::current_session(), 6);
+        assert_eq!(RelayEra::current_era(), 1);
+        assert_eq!(RelayEra::era_start_block(), 60);
+        System::assert_last_event(Event::NewEra(1, 60).into());
+
+        assert_eq!(<RelayEra as EraProvider<BlockNumber>>::current_era(), 1);
+        assert_eq!(
+            <RelayEra as EraProvider<BlockNumber>>::era_start_block(),
+            60
+        );
+    })
+}
+
+#[test]
+fn missed_eras_are_skipped_at_once() {
+    new_test_ext().execute_with(|| {
+        run_to_relay_block(100);
+        run_to_relay_block(300);
+        assert_eq!(RelayEra::current_era(), 3);
+        assert_eq!(RelayEra::era_start_block(), 300);
+        assert_eq!(RelayEra::current_session(), 20);
+
+        run_to_relay_block(359);
+        assert_eq!(RelayEra::current_era(), 3);
+        run_to_relay_block(360);
+        assert_eq!(RelayEra::current_era(), 4);
+    })
+}
+
+#[test]
+fn force_set_era_and_session_work() {
+    new_test_ext().execute_with(|| {
+        RelayChainValidationDataProvider::set(30);
+        assert_noop!(
+            RelayEra::force_set_current_era(RuntimeOrigin::signed(ALICE), 12),
+            BadOrigin
+        );
+        assert_ok!(RelayEra::force_set_current_era(RuntimeOrigin::root(), 12));
+        assert_eq!(RelayEra::current_era(), 12);
+        assert_eq!(RelayEra::era_start_block(), 30);
+
+        assert_noop!(
+            RelayEra::force_set_current_session(RuntimeOrigin::signed(ALICE), 72),
+            BadOrigin
+        );
+        assert_ok!(RelayEra::force_set_current_session(
+            RuntimeOrigin::root(),
+            72
+        ));
+        assert_eq!(RelayEra::current_session(), 72);
+        assert_eq!(RelayEra::session_start_block(), 30);
+
+        // The era length is counted from the forced start
+        run_to_relay_block(89);
+        assert_eq!(RelayEra::current_era(), 12);
+        run_to_relay_block(90);
+        assert_eq!(RelayEra::current_era(), 13);
+    })
+}
+
+#[test]
+fn set_current_era_requires_valid_proof() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            RelayEra::set_current_era(RuntimeOrigin::signed(ALICE), 1, vec![]),
+            Error::<Test>::InvalidProof
+        );
+
+        run_to_relay_block(100);
+        run_to_relay_block(160);
+        assert_noop!(
+            RelayEra::set_current_era(RuntimeOrigin::signed(ALICE), 1, vec![]),
+            Error::<Test>::StaleIndex
+        );
+        assert_noop!(
+            RelayEra::set_current_era(RuntimeOrigin::signed(ALICE), 2, vec![vec![0u8; 32]]),
+            Error::<Test>::InvalidProof
+        );
+        assert_noop!(
+            RelayEra::set_current_session(RuntimeOrigin::signed(ALICE), 6, vec![]),
+            Error::<Test>::StaleIndex
+        );
+        assert_noop!(
+            RelayEra::set_current_session(RuntimeOrigin::signed(ALICE), 7, vec![]),
+            Error::<Test>::InvalidProof
+        );
+    })
+}
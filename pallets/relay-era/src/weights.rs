@@ -0,0 +1,118 @@
+// This file is part of Parallel Finance.
+
+// Copyright (C) 2022 Parallel Finance Developer.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Weights for pallet_relay_era
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet-relay-era
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/relay-era/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions needed for pallet_relay_era.
+pub trait WeightInfo {
+	fn on_initialize() -> Weight;
+	fn force_set_current_era() -> Weight;
+	fn force_set_current_session() -> Weight;
+}
+
+/// Weights for pallet_relay_era using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: RelayEra SessionStartBlock (r:1 w:1)
+	// Storage: RelayEra CurrentSession (r:1 w:1)
+	// Storage: RelayEra EraStartBlock (r:1 w:1)
+	// Storage: RelayEra CurrentEra (r:1 w:1)
+	fn on_initialize() -> Weight {
+		Weight::from_ref_time(21_547_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: RelayEra CurrentEra (r:0 w:1)
+	// Storage: RelayEra EraStartBlock (r:0 w:1)
+	fn force_set_current_era() -> Weight {
+		Weight::from_ref_time(17_133_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: RelayEra CurrentSession (r:0 w:1)
+	// Storage: RelayEra SessionStartBlock (r:0 w:1)
+	fn force_set_current_session() -> Weight {
+		Weight::from_ref_time(16_961_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: RelayEra SessionStartBlock (r:1 w:1)
+	// Storage: RelayEra CurrentSession (r:1 w:1)
+	// Storage: RelayEra EraStartBlock (r:1 w:1)
+	// Storage: RelayEra CurrentEra (r:1 w:1)
+	fn on_initialize() -> Weight {
+		Weight::from_ref_time(21_547_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: RelayEra CurrentEra (r:0 w:1)
+	// Storage: RelayEra EraStartBlock (r:0 w:1)
+	fn force_set_current_era() -> Weight {
+		Weight::from_ref_time(17_133_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: RelayEra CurrentSession (r:0 w:1)
+	// Storage: RelayEra SessionStartBlock (r:0 w:1)
+	fn force_set_current_session() -> Weight {
+		Weight::from_ref_time(16_961_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+}
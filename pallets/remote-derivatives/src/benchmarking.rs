@@ -0,0 +1,92 @@
+//! Remote derivatives pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as RemoteDerivatives;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::assert_ok;
+use frame_system::RawOrigin as SystemOrigin;
+use sp_runtime::traits::StaticLookup;
+
+const INDEX: DerivativeIndex = 100;
+const INITIAL_AMOUNT: u128 = 100_000_000_000_000;
+
+fn transact_info() -> RemoteTransactInfo {
+    RemoteTransactInfo {
+        utility_pallet_index: 24,
+        fee_location: MultiLocation::here(),
+        extra_weight: Weight::from_ref_time(1_000_000_000),
+        max_weight: Weight::from_ref_time(10_000_000_000),
+        fee_per_second: 1_000_000_000_000,
+    }
+}
+
+fn initial_set_up<T: Config + pallet_assets::Config<AssetId = CurrencyId, Balance = Balance>>(
+    caller: T::AccountId,
+) {
+    let account_id = T::Lookup::unlookup(caller.clone());
+    pallet_assets::Pallet::<T>::force_create(
+        SystemOrigin::Root.into(),
+        T::FeeCurrency::get(),
+        account_id,
+        true,
+        1,
+    )
+    .ok();
+    T::Assets::mint_into(T::FeeCurrency::get(), &caller, INITIAL_AMOUNT).unwrap();
+    assert_ok!(RemoteDerivatives::<T>::set_transact_info(
+        SystemOrigin::Root.into(),
+        Box::new(MultiLocation::parent().into()),
+        Some(transact_info()),
+    ));
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    where_clause {
+        where
+            T: pallet_assets::Config<AssetId = CurrencyId, Balance = Balance>
+    }
+
+    register {
+        let owner: T::AccountId = whitelisted_caller();
+    }: _(SystemOrigin::Root, owner.clone(), INDEX)
+    verify {
+        assert_last_event::<T>(Event::<T>::DerivativeRegistered(owner, INDEX).into());
+    }
+
+    deregister {
+        let owner: T::AccountId = whitelisted_caller();
+        assert_ok!(RemoteDerivatives::<T>::register(SystemOrigin::Root.into(), owner.clone(), INDEX));
+    }: _(SystemOrigin::Root, INDEX)
+    verify {
+        assert_last_event::<T>(Event::<T>::DerivativeDeregistered(owner, INDEX).into());
+    }
+
+    set_transact_info {
+    }: _(SystemOrigin::Root, Box::new(MultiLocation::parent().into()), Some(transact_info()))
+    verify {
+        assert_last_event::<T>(Event::<T>::TransactInfoUpdated(MultiLocation::parent(), Some(transact_info())).into());
+    }
+
+    transact_through_derivative {
+        let owner: T::AccountId = whitelisted_caller();
+        initial_set_up::<T>(owner.clone());
+        assert_ok!(RemoteDerivatives::<T>::register(SystemOrigin::Root.into(), owner.clone(), INDEX));
+        let weight = Weight::from_ref_time(5_000_000_000);
+        let fee = transact_info().fee(weight);
+    }: _(SystemOrigin::Signed(owner.clone()), Box::new(MultiLocation::parent().into()), INDEX, vec![0u8; 64], weight)
+    verify {
+        assert_last_event::<T>(Event::<T>::TransactedThroughDerivative(owner, MultiLocation::parent(), INDEX, fee).into());
+    }
+}
+
+impl_benchmark_test_suite!(
+    RemoteDerivatives,
+    crate::mock::new_test_ext(),
+    crate::mock::Test
+);
@@ -0,0 +1,331 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Remote Derivatives pallet
+//!
+//! ## Overview
+//!
+//! Derivative indices of this chain's sovereign accounts on the relaychain and sibling
+//! parachains are registered to local accounts by governance. The owner of an index
+//! can then dispatch calls from the derivative account at any configured destination,
+//! which is done by sending `utility.as_derivative(index, call)` through `Transact`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungibles::{Inspect, Mutate, Transfer},
+        Contains,
+    },
+    transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+use polkadot_parachain::primitives::Sibling;
+use primitives::{Balance, CurrencyId, DerivativeIndex, ParaId};
+use sp_runtime::traits::{AccountIdConversion, Convert};
+use sp_std::{boxed::Box, prelude::*, vec};
+use xcm::{latest::prelude::*, VersionedMultiLocation};
+
+pub use pallet::*;
+pub use types::RemoteTransactInfo;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Call index of `as_derivative` in the utility pallet
+const AS_DERIVATIVE_CALL_INDEX: u8 = 1;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_utility::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Assets for paying remote fees
+        type Assets: Transfer<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
+            + Inspect<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
+            + Mutate<Self::AccountId, AssetId = CurrencyId, Balance = Balance>;
+
+        /// XCM message sender
+        type XcmSender: SendXcm;
+
+        /// Convert `T::AccountId` to `MultiLocation`.
+        type AccountIdToMultiLocation: Convert<Self::AccountId, MultiLocation>;
+
+        /// Pallet account collecting the fees paid for destinations other than the relaychain
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Returns the parachain ID we are running with.
+        #[pallet::constant]
+        type SelfParaId: Get<ParaId>;
+
+        /// Currency remote fees are paid with, reserve backed by the relaychain
+        #[pallet::constant]
+        type FeeCurrency: Get<CurrencyId>;
+
+        /// The origin which can register derivative indices and configure destinations
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Derivative indices used internally, e.g. by liquid staking, which can't be registered
+        type ReservedIndices: Contains<DerivativeIndex>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Derivative index was registered to an account
+        /// [owner, index]
+        DerivativeRegistered(T::AccountId, DerivativeIndex),
+        /// Derivative index was released
+        /// [owner, index]
+        DerivativeDeregistered(T::AccountId, DerivativeIndex),
+        /// Transact info of a destination was updated
+        /// [dest, info]
+        TransactInfoUpdated(MultiLocation, Option<RemoteTransactInfo>),
+        /// Call was sent to be dispatched by a derivative account
+        /// [owner, dest, index, fee]
+        TransactedThroughDerivative(T::AccountId, MultiLocation, DerivativeIndex, Balance),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Derivative index is already registered
+        DerivativeIndexTaken,
+        /// Derivative index is used internally
+        DerivativeIndexReserved,
+        /// Derivative index is not registered
+        DerivativeIndexNotRegistered,
+        /// Derivative index is registered to another account
+        NotDerivativeOwner,
+        /// Destination has no transact info
+        TransactInfoNotFound,
+        /// This chain has no sovereign account at the destination
+        UnsupportedDestination,
+        /// Remote call requires more weight than allowed at the destination
+        WeightTooHigh,
+        /// Xcm version of the location is not supported
+        BadVersion,
+        /// The message could not be sent to the destination
+        SendFailure,
+    }
+
+    /// Owners of derivative indices
+    /// DerivativeIndex -> AccountId
+    #[pallet::storage]
+    #[pallet::getter(fn derivative_owner)]
+    pub type DerivativeOwners<T: Config> =
+        StorageMap<_, Twox64Concat, DerivativeIndex, T::AccountId, OptionQuery>;
+
+    /// Transact info of the supported destinations
+    /// MultiLocation -> RemoteTransactInfo
+    #[pallet::storage]
+    #[pallet::getter(fn transact_info)]
+    pub type TransactInfos<T: Config> =
+        StorageMap<_, Blake2_128Concat, MultiLocation, RemoteTransactInfo, OptionQuery>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register derivative `index` to `who`.
+        ///
+        /// Indices used internally, e.g. by liquid staking, can't be registered.
+        #[pallet::weight(<T as Config>::WeightInfo::register())]
+        #[transactional]
+        pub fn register(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            index: DerivativeIndex,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                !T::ReservedIndices::contains(&index),
+                Error::<T>::DerivativeIndexReserved
+            );
+            ensure!(
+                !DerivativeOwners::<T>::contains_key(index),
+                Error::<T>::DerivativeIndexTaken
+            );
+
+            DerivativeOwners::<T>::insert(index, who.clone());
+            Self::deposit_event(Event::<T>::DerivativeRegistered(who, index));
+            Ok(())
+        }
+
+        /// Release derivative `index` from its owner
+        #[pallet::weight(<T as Config>::WeightInfo::deregister())]
+        #[transactional]
+        pub fn deregister(origin: OriginFor<T>, index: DerivativeIndex) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let who = DerivativeOwners::<T>::take(index)
+                .ok_or(Error::<T>::DerivativeIndexNotRegistered)?;
+
+            Self::deposit_event(Event::<T>::DerivativeDeregistered(who, index));
+            Ok(())
+        }
+
+        /// Set or remove the transact info of `dest`
+        #[pallet::weight(<T as Config>::WeightInfo::set_transact_info())]
+        #[transactional]
+        pub fn set_transact_info(
+            origin: OriginFor<T>,
+            dest: Box<VersionedMultiLocation>,
+            info: Option<RemoteTransactInfo>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let dest: MultiLocation = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+            ensure!(
+                Self::sovereign_account_at(&dest).is_some(),
+                Error::<T>::UnsupportedDestination
+            );
+
+            TransactInfos::<T>::set(dest.clone(), info.clone());
+            Self::deposit_event(Event::<T>::TransactInfoUpdated(dest, info));
+            Ok(())
+        }
+
+        /// Dispatch the SCALE encoded `call` at `dest` from the derivative account
+        /// of `index`, paying the remote fee of `weight` in `FeeCurrency`
+        #[pallet::weight(<T as Config>::WeightInfo::transact_through_derivative())]
+        #[transactional]
+        pub fn transact_through_derivative(
+            origin: OriginFor<T>,
+            dest: Box<VersionedMultiLocation>,
+            index: DerivativeIndex,
+            call: Vec<u8>,
+            weight: Weight,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let dest: MultiLocation = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+            let owner =
+                Self::derivative_owner(index).ok_or(Error::<T>::DerivativeIndexNotRegistered)?;
+            ensure!(owner == who, Error::<T>::NotDerivativeOwner);
+
+            Self::do_transact_through_derivative(&who, dest, index, call, weight)
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    /// Sovereign account of this chain at `dest`
+    pub fn sovereign_account_at(dest: &MultiLocation) -> Option<T::AccountId> {
+        match dest {
+            MultiLocation {
+                parents: 1,
+                interior: Here,
+            } => Some(T::SelfParaId::get().into_account_truncating()),
+            MultiLocation {
+                parents: 1,
+                interior: X1(Parachain(_)),
+            } => Some(Sibling::from(T::SelfParaId::get()).into_account_truncating()),
+            _ => None,
+        }
+    }
+
+    /// Derivative account of `index` at `dest`
+    pub fn derivative_account_id(
+        dest: &MultiLocation,
+        index: DerivativeIndex,
+    ) -> Option<T::AccountId> {
+        Self::sovereign_account_at(dest)
+            .map(|sovereign| pallet_utility::Pallet::<T>::derivative_account_id(sovereign, index))
+    }
+
+    /// Send `call` to be dispatched by the derivative account of `index` at `dest`,
+    /// the remote fee is paid by `payer`.
+    ///
+    /// The relaychain is the reserve of `FeeCurrency` so fees paid for it are burned,
+    /// otherwise they are collected to refill the sovereign account at `dest`.
+    /// Unused fees are refunded to the derivative account.
+    pub fn do_transact_through_derivative(
+        payer: &T::AccountId,
+        dest: MultiLocation,
+        index: DerivativeIndex,
+        call: Vec<u8>,
+        weight: Weight,
+    ) -> DispatchResult {
+        let info = Self::transact_info(&dest).ok_or(Error::<T>::TransactInfoNotFound)?;
+        ensure!(weight.all_lte(info.max_weight), Error::<T>::WeightTooHigh);
+        let derivative_account =
+            Self::derivative_account_id(&dest, index).ok_or(Error::<T>::UnsupportedDestination)?;
+
+        let fee = info.fee(weight);
+        if dest == MultiLocation::parent() {
+            T::Assets::burn_from(T::FeeCurrency::get(), payer, fee)?;
+        } else {
+            T::Assets::transfer(
+                T::FeeCurrency::get(),
+                payer,
+                &Self::account_id(),
+                fee,
+                false,
+            )?;
+        }
+
+        let mut as_derivative = vec![info.utility_pallet_index, AS_DERIVATIVE_CALL_INDEX];
+        index.encode_to(&mut as_derivative);
+        as_derivative.extend(call);
+
+        let asset: MultiAsset = (info.fee_location, fee).into();
+        let message = Xcm(vec![
+            WithdrawAsset(MultiAssets::from(asset.clone())),
+            BuyExecution {
+                fees: asset.clone(),
+                weight_limit: Unlimited,
+            },
+            Transact {
+                origin_type: OriginKind::SovereignAccount,
+                require_weight_at_most: weight.ref_time(),
+                call: as_derivative.into(),
+            },
+            RefundSurplus,
+            DepositAsset {
+                assets: asset.into(),
+                max_assets: 1,
+                beneficiary: T::AccountIdToMultiLocation::convert(derivative_account),
+            },
+        ]);
+        T::XcmSender::send_xcm(dest.clone(), message).map_err(|_| Error::<T>::SendFailure)?;
+
+        Self::deposit_event(Event::<T>::TransactedThroughDerivative(
+            payer.clone(),
+            dest,
+            index,
+            fee,
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::fungibles::Inspect};
+use sp_runtime::DispatchError::BadOrigin;
+
+fn relay_transact_info() -> RemoteTransactInfo {
+    RemoteTransactInfo {
+        utility_pallet_index: 24,
+        fee_location: MultiLocation::here(),
+        extra_weight: Weight::from_ref_time(1_000_000_000),
+        max_weight: Weight::from_ref_time(10_000_000_000),
+        fee_per_second: dot(1f64),
+    }
+}
+
+#[test]
+fn register_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            RemoteDerivatives::register(RuntimeOrigin::signed(ALICE), ALICE, 1),
+            BadOrigin
+        );
+        assert_ok!(RemoteDerivatives::register(RuntimeOrigin::root(), ALICE, 1));
+        assert_eq!(RemoteDerivatives::derivative_owner(1), Some(ALICE));
+        assert_noop!(
+            RemoteDerivatives::register(RuntimeOrigin::root(), BOB, 1),
+            Error::<Test>::DerivativeIndexTaken
+        );
+
+        assert_ok!(RemoteDerivatives::deregister(RuntimeOrigin::root(), 1));
+        assert_eq!(RemoteDerivatives::derivative_owner(1), None);
+        assert_noop!(
+            RemoteDerivatives::deregister(RuntimeOrigin::root(), 1),
+            Error::<Test>::DerivativeIndexNotRegistered
+        );
+    });
+}
+
+#[test]
+fn register_should_reject_reserved_indices() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            RemoteDerivatives::register(RuntimeOrigin::root(), ALICE, 0),
+            Error::<Test>::DerivativeIndexReserved
+        );
+        assert_eq!(RemoteDerivatives::derivative_owner(0), None);
+    });
+}
+
+#[test]
+fn set_transact_info_should_reject_unknown_destinations() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            RemoteDerivatives::set_transact_info(
+                RuntimeOrigin::root(),
+                Box::new(MultiLocation::new(2, Here).into()),
+                Some(relay_transact_info()),
+            ),
+            Error::<Test>::UnsupportedDestination
+        );
+        assert_ok!(RemoteDerivatives::set_transact_info(
+            RuntimeOrigin::root(),
+            Box::new(MultiLocation::parent().into()),
+            Some(relay_transact_info()),
+        ));
+        assert_eq!(
+            RemoteDerivatives::transact_info(MultiLocation::parent()),
+            Some(relay_transact_info())
+        );
+    });
+}
+
+#[test]
+fn transact_through_derivative_should_work() {
+    new_test_ext().execute_with(|| {
+        let relay = MultiLocation::parent();
+        let weight = Weight::from_ref_time(4_000_000_000);
+        let call = vec![0u8, 1u8, 8u8];
+        assert_ok!(RemoteDerivatives::register(RuntimeOrigin::root(), ALICE, 1));
+        assert_noop!(
+            RemoteDerivatives::transact_through_derivative(
+                RuntimeOrigin::signed(ALICE),
+                Box::new(relay.clone().into()),
+                1,
+                call.clone(),
+                weight,
+            ),
+            Error::<Test>::TransactInfoNotFound
+        );
+        assert_ok!(RemoteDerivatives::set_transact_info(
+            RuntimeOrigin::root(),
+            Box::new(relay.clone().into()),
+            Some(relay_transact_info()),
+        ));
+        assert_noop!(
+            RemoteDerivatives::transact_through_derivative(
+                RuntimeOrigin::signed(BOB),
+                Box::new(relay.clone().into()),
+                1,
+                call.clone(),
+                weight,
+            ),
+            Error::<Test>::NotDerivativeOwner
+        );
+        assert_noop!(
+            RemoteDerivatives::transact_through_derivative(
+                RuntimeOrigin::signed(ALICE),
+                Box::new(relay.clone().into()),
+                1,
+                call.clone(),
+                Weight::from_ref_time(20_000_000_000),
+            ),
+            Error::<Test>::WeightTooHigh
+        );
+
+        assert_ok!(RemoteDerivatives::transact_through_derivative(
+            RuntimeOrigin::signed(ALICE),
+            Box::new(relay.clone().into()),
+            1,
+            call.clone(),
+            weight,
+        ));
+        // (4 + 1) * 10^9 weight at 1 DOT per second
+        let fee = dot(1f64) / 200;
+        assert_eq!(
+            <Test as Config>::Assets::balance(DOT, &ALICE),
+            dot(100f64) - fee
+        );
+        System::assert_last_event(RuntimeEvent::RemoteDerivatives(
+            Event::TransactedThroughDerivative(ALICE, relay.clone(), 1, fee),
+        ));
+
+        let derivative_account = RemoteDerivatives::derivative_account_id(&relay, 1).unwrap();
+        let mut as_derivative = vec![24u8, 1u8, 1u8, 0u8];
+        as_derivative.extend(call);
+        let asset: MultiAsset = (MultiLocation::here(), fee).into();
+        assert_eq!(
+            sent_xcm(),
+            vec![(
+                relay,
+                Xcm(vec![
+                    WithdrawAsset(MultiAssets::from(asset.clone())),
+                    BuyExecution {
+                        fees: asset.clone(),
+                        weight_limit: Unlimited,
+                    },
+                    Transact {
+                        origin_type: OriginKind::SovereignAccount,
+                        require_weight_at_most: weight.ref_time(),
+                        call: as_derivative.into(),
+                    },
+                    RefundSurplus,
+                    DepositAsset {
+                        assets: asset.into(),
+                        max_assets: 1,
+                        beneficiary: AccountIdToMultiLocation::convert(derivative_account),
+                    },
+                ])
+            )]
+        );
+    });
+}
+
+#[test]
+fn fees_for_sibling_destinations_should_be_collected() {
+    new_test_ext().execute_with(|| {
+        let statemint = MultiLocation::new(1, X1(Parachain(1000)));
+        assert_ok!(RemoteDerivatives::register(RuntimeOrigin::root(), BOB, 3));
+        assert_ok!(RemoteDerivatives::set_transact_info(
+            RuntimeOrigin::root(),
+            Box::new(statemint.clone().into()),
+            Some(RemoteTransactInfo {
+                utility_pallet_index: 40,
+                fee_location: MultiLocation::parent(),
+                ..relay_transact_info()
+            }),
+        ));
+        assert_ok!(RemoteDerivatives::transact_through_derivative(
+            RuntimeOrigin::signed(BOB),
+            Box::new(statemint.clone().into()),
+            3,
+            vec![],
+            Weight::from_ref_time(4_000_000_000),
+        ));
+
+        let fee = dot(1f64) / 200;
+        assert_eq!(
+            <Test as Config>::Assets::balance(DOT, &RemoteDerivatives::account_id()),
+            fee
+        );
+        assert_ne!(
+            RemoteDerivatives::derivative_account_id(&statemint, 3),
+            RemoteDerivatives::derivative_account_id(&MultiLocation::parent(), 3)
+        );
+    });
+}
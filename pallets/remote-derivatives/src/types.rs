@@ -0,0 +1,31 @@
+use codec::{Decode, Encode};
+use frame_support::{
+    weights::{constants::WEIGHT_PER_SECOND, Weight},
+    RuntimeDebug,
+};
+use primitives::Balance;
+use scale_info::TypeInfo;
+use xcm::latest::MultiLocation;
+
+/// How transacts through derivative accounts are wrapped and paid for at a destination
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RemoteTransactInfo {
+    /// Index of the utility pallet in the destination runtime
+    pub utility_pallet_index: u8,
+    /// The fee asset, as seen from the destination
+    pub fee_location: MultiLocation,
+    /// Weight of the xcm instructions surrounding `Transact`
+    pub extra_weight: Weight,
+    /// Max weight a single remote call may require
+    pub max_weight: Weight,
+    /// Fee charged per second of execution at the destination
+    pub fee_per_second: Balance,
+}
+
+impl RemoteTransactInfo {
+    /// Fee of executing a remote call of `weight` at the destination
+    pub fn fee(&self, weight: Weight) -> Balance {
+        let total_weight = weight.saturating_add(self.extra_weight).ref_time() as Balance;
+        self.fee_per_second.saturating_mul(total_weight) / WEIGHT_PER_SECOND.ref_time() as Balance
+    }
+}
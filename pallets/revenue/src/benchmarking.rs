@@ -0,0 +1,42 @@
+//! Revenue pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Revenue;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin as SystemOrigin;
+use primitives::tokens::DOT;
+use sp_runtime::traits::StaticLookup;
+
+const AMOUNT: u128 = 1_000_000_000_000;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    where_clause {
+        where
+            T: pallet_assets::Config<AssetId = CurrencyId, Balance = Balance>
+    }
+
+    withdraw {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let dest: T::AccountId = account("dest", 0, 0);
+        pallet_assets::Pallet::<T>::force_create(
+            SystemOrigin::Root.into(),
+            DOT,
+            T::Lookup::unlookup(owner),
+            true,
+            1,
+        )
+        .ok();
+        T::Assets::mint_into(DOT, &Revenue::<T>::account_id(), AMOUNT * 2).unwrap();
+    }: _(SystemOrigin::Root, DOT, AMOUNT, dest.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::RevenueWithdrawn(DOT, AMOUNT, dest).into());
+    }
+}
+
+impl_benchmark_test_suite!(Revenue, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,207 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Revenue pallet
+//!
+//! ## Overview
+//!
+//! Records the income of the protocol by source, like the staking commission or the
+//! loans reserves, in total and per era so it can be queried through the runtime api.
+//! Income paid out by the other pallets is collected in the pallet account, governance
+//! can withdraw it from there.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::tokens::fungibles::{Inspect, Mutate, Transfer},
+    transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+use pallet_traits::{RevenueRecorder, RevenueSource};
+use primitives::{Balance, CurrencyId, EraIndex};
+use sp_runtime::traits::{AccountIdConversion, SaturatedConversion, Zero};
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Assets the income is paid in
+        type Assets: Transfer<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
+            + Inspect<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
+            + Mutate<Self::AccountId, AssetId = CurrencyId, Balance = Balance>;
+
+        /// Pallet account collecting the income
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Number of blocks of an era
+        #[pallet::constant]
+        type EraLength: Get<Self::BlockNumber>;
+
+        /// Number of eras the income is kept for
+        #[pallet::constant]
+        type HistoryDepth: Get<u32>;
+
+        /// The origin which can withdraw the collected income
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Collected income was withdrawn
+        /// [asset_id, amount, dest]
+        RevenueWithdrawn(CurrencyId, Balance, T::AccountId),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Pallet account doesn't hold enough of the asset
+        InsufficientBalance,
+    }
+
+    /// Income recorded in each era
+    /// EraIndex -> (RevenueSource, CurrencyId) -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn era_revenue)]
+    pub type EraRevenue<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        Twox64Concat,
+        (RevenueSource, CurrencyId),
+        Balance,
+        ValueQuery,
+    >;
+
+    /// Income recorded since genesis
+    /// RevenueSource -> CurrencyId -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn total_revenue)]
+    pub type TotalRevenue<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        RevenueSource,
+        Twox64Concat,
+        CurrencyId,
+        Balance,
+        ValueQuery,
+    >;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let era_length = T::EraLength::get();
+            if era_length.is_zero() || !(n % era_length).is_zero() {
+                return Weight::zero();
+            }
+
+            // Drop the income of the era which left the history
+            let era = Self::era_of(n);
+            let expired = match era.checked_sub(T::HistoryDepth::get()) {
+                Some(expired) => expired,
+                None => return Weight::zero(),
+            };
+            let removed = EraRevenue::<T>::clear_prefix(expired, u32::MAX, None).unique;
+            T::DbWeight::get().writes(removed.into())
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Withdraw collected income to `dest`
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw())]
+        #[transactional]
+        pub fn withdraw(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId,
+            amount: Balance,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                T::Assets::reducible_balance(asset_id, &Self::account_id(), false) >= amount,
+                Error::<T>::InsufficientBalance
+            );
+
+            T::Assets::transfer(asset_id, &Self::account_id(), &dest, amount, false)?;
+            Self::deposit_event(Event::<T>::RevenueWithdrawn(asset_id, amount, dest));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Account collecting the income
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    pub fn era_of(n: T::BlockNumber) -> EraIndex {
+        let era_length = T::EraLength::get();
+        if era_length.is_zero() {
+            return Zero::zero();
+        }
+        (n / era_length).saturated_into()
+    }
+
+    pub fn current_era() -> EraIndex {
+        Self::era_of(frame_system::Pallet::<T>::block_number())
+    }
+
+    /// Income recorded in `era` by source and asset
+    pub fn revenue_of_era(era: EraIndex) -> Vec<(RevenueSource, CurrencyId, Balance)> {
+        EraRevenue::<T>::iter_prefix(era)
+            .map(|((source, asset_id), amount)| (source, asset_id, amount))
+            .collect()
+    }
+}
+
+impl<T: Config> RevenueRecorder for Pallet<T> {
+    fn record(source: RevenueSource, asset_id: CurrencyId, amount: Balance) {
+        if amount.is_zero() {
+            return;
+        }
+        EraRevenue::<T>::mutate(Self::current_era(), (source, asset_id), |total| {
+            *total = total.saturating_add(amount)
+        });
+        TotalRevenue::<T>::mutate(source, asset_id, |total| {
+            *total = total.saturating_add(amount)
+        });
+    }
+}
@@ -0,0 +1,89 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use primitives::tokens::{DOT, KSM};
+use sp_runtime::DispatchError::BadOrigin;
+
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        Revenue::on_initialize(System::block_number());
+    }
+}
+
+#[test]
+fn record_should_aggregate_per_era() {
+    new_test_ext().execute_with(|| {
+        Revenue::record(RevenueSource::StakingCommission, KSM, 10);
+        Revenue::record(RevenueSource::StakingCommission, KSM, 5);
+        Revenue::record(RevenueSource::LoansReserve, DOT, 7);
+        Revenue::record(RevenueSource::BridgeFee, DOT, 0);
+        assert_eq!(Revenue::current_era(), 0);
+
+        run_to_block(10);
+        assert_eq!(Revenue::current_era(), 1);
+        Revenue::record(RevenueSource::StakingCommission, KSM, 20);
+
+        let mut era_0 = Revenue::revenue_of_era(0);
+        era_0.sort();
+        assert_eq!(
+            era_0,
+            vec![
+                (RevenueSource::StakingCommission, KSM, 15),
+                (RevenueSource::LoansReserve, DOT, 7),
+            ]
+        );
+        assert_eq!(
+            Revenue::revenue_of_era(1),
+            vec![(RevenueSource::StakingCommission, KSM, 20)]
+        );
+        assert_eq!(
+            Revenue::total_revenue(RevenueSource::StakingCommission, KSM),
+            35
+        );
+        assert_eq!(Revenue::total_revenue(RevenueSource::BridgeFee, DOT), 0);
+    });
+}
+
+#[test]
+fn eras_out_of_history_are_pruned() {
+    new_test_ext().execute_with(|| {
+        Revenue::record(RevenueSource::AmmProtocolFee, DOT, 10);
+        run_to_block(10);
+        Revenue::record(RevenueSource::AmmProtocolFee, DOT, 20);
+
+        // Two eras of history are kept
+        run_to_block(20);
+        assert_eq!(Revenue::revenue_of_era(0), vec![]);
+        assert_eq!(
+            Revenue::era_revenue(1, (RevenueSource::AmmProtocolFee, DOT)),
+            20
+        );
+        // Totals are never pruned
+        assert_eq!(
+            Revenue::total_revenue(RevenueSource::AmmProtocolFee, DOT),
+            30
+        );
+    });
+}
+
+#[test]
+fn withdraw_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Revenue::withdraw(RuntimeOrigin::signed(ALICE), DOT, 100, BOB),
+            BadOrigin
+        );
+        assert_noop!(
+            Revenue::withdraw(RuntimeOrigin::root(), DOT, 1_001, BOB),
+            Error::<Test>::InsufficientBalance
+        );
+
+        assert_ok!(Revenue::withdraw(RuntimeOrigin::root(), DOT, 100, BOB));
+        assert_eq!(Assets::balance(DOT, BOB), 100);
+        assert_eq!(Assets::balance(DOT, Revenue::account_id()), 900);
+        System::assert_last_event(RuntimeEvent::Revenue(Event::RevenueWithdrawn(
+            DOT, 100, BOB,
+        )));
+    });
+}
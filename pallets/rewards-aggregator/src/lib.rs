@@ -0,0 +1,161 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Rewards Aggregator pallet
+//!
+//! ## Overview
+//!
+//! Claims the rewards an account accrued across the protocol in a single call: loans
+//! incentives, farming pools, referral rebates and collator delegation rewards.
+//!
+//! `claim_all` goes through the sources in order and makes at most `MaxClaims` claims,
+//! e.g. one per farming pool, the rest can be claimed by calling it again. Each source
+//! is claimed from in its own storage layer, a failing source is reported in an event
+//! and rolled back without reverting the claims from the other sources.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    storage::{with_transaction, TransactionOutcome},
+    transactional,
+};
+use frame_system::pallet_prelude::*;
+use pallet_traits::RewardClaimer;
+use sp_runtime::traits::Zero;
+
+pub use pallet::*;
+pub use types::RewardSource;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Incentive reward of the loans markets
+        type Loans: RewardClaimer<Self::AccountId>;
+
+        /// Rewards of the farming pools
+        type Farming: RewardClaimer<Self::AccountId>;
+
+        /// Rebates of the referral program
+        type Referrals: RewardClaimer<Self::AccountId>;
+
+        /// Rewards of the delegations to collators
+        type Staking: RewardClaimer<Self::AccountId>;
+
+        /// Most claims made by a single `claim_all`
+        #[pallet::constant]
+        type MaxClaims: Get<u32>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Rewards were claimed from a source
+        /// [who, source, claims]
+        Claimed(T::AccountId, RewardSource, u32),
+        /// Claiming from a source failed and was rolled back
+        /// [who, source, error]
+        ClaimFailed(T::AccountId, RewardSource, DispatchError),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// No source had rewards to claim
+        NothingToClaim,
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Claim the rewards of the caller from every source, making at most `MaxClaims`
+        /// claims.
+        ///
+        /// A failing source uses up the claims left, sources after it are claimed from
+        /// by the next call.
+        #[pallet::weight(T::WeightInfo::claim_all(T::MaxClaims::get()))]
+        #[transactional]
+        pub fn claim_all(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let max_claims = T::MaxClaims::get();
+            let mut remaining = max_claims;
+            let mut attempted = false;
+            for source in RewardSource::ALL {
+                if remaining.is_zero() {
+                    break;
+                }
+                match Self::claim_from(source, &who, remaining) {
+                    Ok(claims) => {
+                        if claims.is_zero() {
+                            continue;
+                        }
+                        remaining = remaining.saturating_sub(claims);
+                        Self::deposit_event(Event::<T>::Claimed(who.clone(), source, claims));
+                    }
+                    Err(e) => {
+                        // what the source did before failing isn't known
+                        remaining = Zero::zero();
+                        Self::deposit_event(Event::<T>::ClaimFailed(who.clone(), source, e));
+                    }
+                }
+                attempted = true;
+            }
+            ensure!(attempted, Error::<T>::NothingToClaim);
+
+            Ok(Some(T::WeightInfo::claim_all(max_claims - remaining)).into())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    fn claim_from(
+        source: RewardSource,
+        who: &T::AccountId,
+        max_claims: u32,
+    ) -> Result<u32, DispatchError> {
+        with_transaction(|| {
+            let result = match source {
+                RewardSource::Loans => T::Loans::claim_rewards(who, max_claims),
+                RewardSource::Farming => T::Farming::claim_rewards(who, max_claims),
+                RewardSource::Referrals => T::Referrals::claim_rewards(who, max_claims),
+                RewardSource::Staking => T::Staking::claim_rewards(who, max_claims),
+            };
+            match result {
+                Ok(claims) => TransactionOutcome::Commit(Ok(claims)),
+                Err(e) => TransactionOutcome::Rollback(Err(e)),
+            }
+        })
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{construct_runtime, parameter_types, storage::unhashed, traits::Everything};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, TokenError};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        RewardsAggregator: crate::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+
+thread_local! {
+    static PENDING: RefCell<BTreeMap<(u8, AccountId), u32>> = RefCell::new(BTreeMap::new());
+    static FAILING: RefCell<BTreeSet<u8>> = RefCell::new(BTreeSet::new());
+}
+
+/// Source with a number of pending claims for each account, recording the claims made
+/// in storage so rolled back claims aren't counted
+pub struct MockSource<const S: u8>;
+
+impl<const S: u8> MockSource<S> {
+    pub fn set_pending(who: AccountId, claims: u32) {
+        PENDING.with(|pending| pending.borrow_mut().insert((S, who), claims));
+    }
+
+    pub fn set_failing() {
+        FAILING.with(|failing| failing.borrow_mut().insert(S));
+    }
+
+    pub fn claimed(who: AccountId) -> u32 {
+        unhashed::get_or_default(&(b"claimed", S, who).encode())
+    }
+}
+
+impl<const S: u8> RewardClaimer<AccountId> for MockSource<S> {
+    fn claim_rewards(who: &AccountId, max_claims: u32) -> Result<u32, DispatchError> {
+        let pending = PENDING.with(|pending| pending.borrow().get(&(S, *who)).copied());
+        let claims = pending.unwrap_or_default().min(max_claims);
+        unhashed::put(
+            &(b"claimed", S, who).encode(),
+            &(Self::claimed(*who) + claims),
+        );
+        if FAILING.with(|failing| failing.borrow().contains(&S)) {
+            return Err(TokenError::NoFunds.into());
+        }
+        Self::set_pending(*who, pending.unwrap_or_default() - claims);
+        Ok(claims)
+    }
+}
+
+pub type MockLoans = MockSource<0>;
+pub type MockFarming = MockSource<1>;
+pub type MockReferrals = MockSource<2>;
+pub type MockStaking = MockSource<3>;
+
+parameter_types! {
+    pub const MaxClaims: u32 = 5;
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Loans = MockLoans;
+    type Farming = MockFarming;
+    type Referrals = MockReferrals;
+    type Staking = MockStaking;
+    type MaxClaims = MaxClaims;
+    type WeightInfo = ();
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,81 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{mock::*, Error, Event, RewardSource};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::TokenError;
+
+fn claim_events() -> Vec<Event<Test>> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::RewardsAggregator(event) => Some(event),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn claim_all_claims_every_source_up_to_max_claims() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            RewardsAggregator::claim_all(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::NothingToClaim
+        );
+
+        MockLoans::set_pending(ALICE, 1);
+        MockFarming::set_pending(ALICE, 3);
+        MockStaking::set_pending(ALICE, 2);
+        assert_ok!(RewardsAggregator::claim_all(RuntimeOrigin::signed(ALICE)));
+        assert_eq!(
+            claim_events(),
+            vec![
+                Event::Claimed(ALICE, RewardSource::Loans, 1),
+                Event::Claimed(ALICE, RewardSource::Farming, 3),
+                Event::Claimed(ALICE, RewardSource::Staking, 1),
+            ]
+        );
+
+        // the claim left is made by the next call
+        System::reset_events();
+        assert_ok!(RewardsAggregator::claim_all(RuntimeOrigin::signed(ALICE)));
+        assert_eq!(
+            claim_events(),
+            vec![Event::Claimed(ALICE, RewardSource::Staking, 1)]
+        );
+        assert_eq!(MockStaking::claimed(ALICE), 2);
+    })
+}
+
+#[test]
+fn failing_source_is_rolled_back_alone() {
+    new_test_ext().execute_with(|| {
+        MockLoans::set_pending(ALICE, 1);
+        MockFarming::set_pending(ALICE, 2);
+        MockFarming::set_failing();
+        MockReferrals::set_pending(ALICE, 1);
+
+        assert_ok!(RewardsAggregator::claim_all(RuntimeOrigin::signed(ALICE)));
+        assert_eq!(
+            claim_events(),
+            vec![
+                Event::Claimed(ALICE, RewardSource::Loans, 1),
+                Event::ClaimFailed(ALICE, RewardSource::Farming, TokenError::NoFunds.into()),
+            ]
+        );
+        assert_eq!(MockLoans::claimed(ALICE), 1);
+        assert_eq!(MockFarming::claimed(ALICE), 0);
+        assert_eq!(MockReferrals::claimed(ALICE), 0);
+    })
+}
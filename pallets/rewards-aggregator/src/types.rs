@@ -0,0 +1,35 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Pallet rewards are claimed from by `claim_all`
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RewardSource {
+    /// Incentive reward of the loans markets
+    Loans,
+    /// Rewards of the farming pools
+    Farming,
+    /// Rebates of the referral program, including crowdloan referrals
+    Referrals,
+    /// Block rewards shared with the delegators of collators
+    Staking,
+}
+
+impl RewardSource {
+    /// Every source, in the order they are claimed from
+    pub const ALL: [RewardSource; 4] = [Self::Loans, Self::Farming, Self::Referrals, Self::Staking];
+}
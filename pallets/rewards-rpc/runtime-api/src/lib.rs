@@ -0,0 +1,174 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use primitives::{BlockNumber, CurrencyId, ParaId, VaultId};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{traits::Saturating, DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Amount of an asset
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RewardAmount<Balance> {
+    pub asset: CurrencyId,
+    pub amount: Balance,
+}
+
+impl<Balance> From<(CurrencyId, Balance)> for RewardAmount<Balance> {
+    fn from((asset, amount): (CurrencyId, Balance)) -> Self {
+        Self { asset, amount }
+    }
+}
+
+/// Reward of a farming pool
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FarmingReward<Balance> {
+    pub asset: CurrencyId,
+    pub reward_asset: CurrencyId,
+    pub lock_duration: BlockNumber,
+    pub amount: Balance,
+}
+
+impl<Balance> From<(CurrencyId, CurrencyId, BlockNumber, Balance)> for FarmingReward<Balance> {
+    fn from(
+        (asset, reward_asset, lock_duration, amount): (
+            CurrencyId,
+            CurrencyId,
+            BlockNumber,
+            Balance,
+        ),
+    ) -> Self {
+        Self {
+            asset,
+            reward_asset,
+            lock_duration,
+            amount,
+        }
+    }
+}
+
+/// Amount claimable from a crowdloan vault
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CrowdloanReward<Balance> {
+    pub crowdloan: ParaId,
+    pub vault_id: VaultId,
+    pub asset: CurrencyId,
+    pub amount: Balance,
+}
+
+impl<Balance> From<(ParaId, VaultId, CurrencyId, Balance)> for CrowdloanReward<Balance> {
+    fn from((crowdloan, vault_id, asset, amount): (ParaId, VaultId, CurrencyId, Balance)) -> Self {
+        Self {
+            crowdloan,
+            vault_id,
+            asset,
+            amount,
+        }
+    }
+}
+
+/// Everything an account can claim across loans, farming, crowdloans and liquid staking
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PendingRewards<Balance> {
+    /// Incentive reward of the loans markets
+    pub loans: RewardAmount<Balance>,
+    /// Rewards of the farming pools
+    pub farming: Vec<FarmingReward<Balance>>,
+    /// Ctokens, bonuses and refunds of the crowdloan vaults
+    pub crowdloans: Vec<CrowdloanReward<Balance>>,
+    /// Unbonded staking currency which arrived at its target era
+    pub liquid_staking: RewardAmount<Balance>,
+    /// Sum of all the above by asset
+    pub total: Vec<RewardAmount<Balance>>,
+}
+
+impl<Balance: Saturating + Copy + PartialEq + Default> PendingRewards<Balance> {
+    pub fn new(
+        loans: RewardAmount<Balance>,
+        farming: Vec<FarmingReward<Balance>>,
+        crowdloans: Vec<CrowdloanReward<Balance>>,
+        liquid_staking: RewardAmount<Balance>,
+    ) -> Self {
+        let mut total: Vec<RewardAmount<Balance>> = Vec::new();
+        let amounts = sp_std::iter::once(loans)
+            .chain(farming.iter().map(|r| (r.reward_asset, r.amount).into()))
+            .chain(crowdloans.iter().map(|r| (r.asset, r.amount).into()))
+            .chain(sp_std::iter::once(liquid_staking));
+        for reward in amounts.filter(|r| r.amount != Balance::default()) {
+            match total.iter_mut().find(|r| r.asset == reward.asset) {
+                Some(r) => r.amount = r.amount.saturating_add(reward.amount),
+                None => total.push(reward),
+            }
+        }
+
+        Self {
+            loans,
+            farming,
+            crowdloans,
+            liquid_staking,
+            total,
+        }
+    }
+}
+
+impl<Balance> PendingRewards<Balance> {
+    /// Convert the amounts with `f`, e.g. into a representation suitable for RPC
+    pub fn try_map<B, E>(
+        self,
+        f: impl Fn(Balance) -> Result<B, E>,
+    ) -> Result<PendingRewards<B>, E> {
+        let map_amount = |r: RewardAmount<Balance>| -> Result<RewardAmount<B>, E> {
+            Ok((r.asset, f(r.amount)?).into())
+        };
+        Ok(PendingRewards {
+            loans: map_amount(self.loans)?,
+            farming: self
+                .farming
+                .into_iter()
+                .map(|r| Ok((r.asset, r.reward_asset, r.lock_duration, f(r.amount)?).into()))
+                .collect::<Result<_, E>>()?,
+            crowdloans: self
+                .crowdloans
+                .into_iter()
+                .map(|r| Ok((r.crowdloan, r.vault_id, r.asset, f(r.amount)?).into()))
+                .collect::<Result<_, E>>()?,
+            liquid_staking: map_amount(self.liquid_staking)?,
+            total: self
+                .total
+                .into_iter()
+                .map(map_amount)
+                .collect::<Result<_, E>>()?,
+        })
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait RewardsApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec, {
+        fn pending_all(account: AccountId) -> Result<PendingRewards<Balance>, DispatchError>;
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+pub use pallet_rewards_rpc_runtime_api::{PendingRewards, RewardsApi as RewardsRuntimeApi};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[rpc(client, server)]
+pub trait RewardsApi<BlockHash, AccountId, Balance>
+where
+    Balance: Codec + Copy + TryFrom<NumberOrHex>,
+{
+    /// Amounts `account` can claim from loans incentives, farming pools,
+    /// crowdloan vaults and liquid staking unlockings.
+    #[method(name = "rewards_pendingAll")]
+    fn pending_all(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<PendingRewards<NumberOrHex>>;
+}
+
+/// A struct that implements the [`RewardsApi`].
+pub struct Rewards<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Rewards<C, B> {
+    /// Create new `Rewards` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+pub enum Error {
+    RuntimeError,
+    PendingRewardsError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::PendingRewardsError => 2,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance> RewardsApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+    for Rewards<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: RewardsRuntimeApi<Block, AccountId, Balance>,
+    AccountId: Codec,
+    Balance: Codec + Copy + TryFrom<NumberOrHex> + Into<NumberOrHex> + std::fmt::Display,
+{
+    fn pending_all(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<PendingRewards<NumberOrHex>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(self.client.info().best_hash));
+        api.pending_all(&at, account)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(pending_rewards_error_into_rpc_error)?
+            .try_map(try_into_rpc_balance)
+    }
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Runtime trapped",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+/// Converts an error of collecting the rewards into an RPC error.
+fn pending_rewards_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::PendingRewardsError.into(),
+        "Not able to get pending rewards",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+fn try_into_rpc_balance<T: std::fmt::Display + Copy + TryInto<NumberOrHex>>(
+    value: T,
+) -> Result<NumberOrHex, JsonRpseeError> {
+    value.try_into().map_err(|_| {
+        JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+            ErrorCode::InvalidParams.code(),
+            format!("{} doesn't fit in NumberOrHex representation", value),
+            None::<()>,
+        )))
+    })
+}
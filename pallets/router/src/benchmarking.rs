@@ -16,13 +16,14 @@ use frame_support::{
     assert_ok,
     traits::{
         fungibles::{Inspect, Mutate},
-        EnsureOrigin,
+        EnsureOrigin, Get, Hooks,
     },
 };
 use frame_system::{self, RawOrigin as SystemOrigin};
 use primitives::{tokens, Balance, CurrencyId};
 use sp_runtime::traits::{One, StaticLookup};
-use sp_std::{vec, vec::Vec};
+use sp_std::{boxed::Box, vec, vec::Vec};
+use xcm::latest::{Junction::Parachain, Junctions::X1, MultiLocation, WeightLimit};
 
 const DOT: CurrencyId = tokens::DOT;
 const SDOT: CurrencyId = tokens::SDOT;
@@ -133,6 +134,78 @@ benchmarks_instance_pallet! {
         assert_eq!(amount_in, expected);
         assert_last_event::<T, I>(Event::Traded(caller, expected, routes, amount_out).into());
     }
+
+    swap_and_transfer {
+        let caller: T::AccountId = whitelisted_caller();
+        initial_set_up::<T, I>(caller.clone());
+        let balance_before_trade: BalanceOf<T, I> = <T as crate::Config<I>>::Assets::balance(DOT, &caller);
+        let amount_in = 1_000u128;
+        let min_amount_out = 900u128;
+        let routes: Vec<_> = vec![DOT, SDOT];
+        let dest = MultiLocation::new(1, X1(Parachain(1000)));
+    }: swap_and_transfer(SystemOrigin::Signed(caller.clone()), routes, amount_in, min_amount_out, Box::new(dest.into()), WeightLimit::Unlimited)
+
+    verify {
+        let balance_after_trade: BalanceOf<T, I> = <T as crate::Config<I>>::Assets::balance(DOT, &caller);
+        assert_eq!(balance_before_trade - balance_after_trade, amount_in);
+    }
+
+    commit_swap {
+        let caller: T::AccountId = whitelisted_caller();
+        let commitment = AMMRoute::<T, I>::swap_commitment(&[DOT, SDOT], 1_000u128, 900u128, &[0u8; 32]);
+    }: _(SystemOrigin::Signed(caller.clone()), commitment)
+
+    verify {
+        assert_last_event::<T, I>(Event::SwapCommitted(caller, commitment).into());
+    }
+
+    reveal_swap {
+        let caller: T::AccountId = whitelisted_caller();
+        initial_set_up::<T, I>(caller.clone());
+        let amount_in = 1_000u128;
+        let min_amount_out = 900u128;
+        let routes: Vec<_> = vec![DOT, SDOT];
+        let salt = [0u8; 32];
+        let commitment = AMMRoute::<T, I>::swap_commitment(&routes, amount_in, min_amount_out, &salt);
+        assert_ok!(AMMRoute::<T, I>::whitelist_route(T::UpdateOrigin::successful_origin(), routes.clone()));
+        assert_ok!(AMMRoute::<T, I>::commit_swap(SystemOrigin::Signed(caller.clone()).into(), commitment));
+        let revealed_at = frame_system::Pallet::<T>::block_number() + T::MinRevealDelay::get();
+        frame_system::Pallet::<T>::set_block_number(revealed_at);
+    }: _(SystemOrigin::Signed(caller.clone()), routes, amount_in, min_amount_out, salt)
+
+    verify {
+        assert_last_event::<T, I>(Event::SwapRevealed(caller.clone(), commitment).into());
+        AMMRoute::<T, I>::on_initialize(revealed_at + One::one());
+        let amount_out: BalanceOf<T, I> = <T as crate::Config<I>>::Assets::balance(SDOT, &caller);
+        assert_eq!(amount_out, 996u128);
+    }
+
+    cancel_swap_commitment {
+        let caller: T::AccountId = whitelisted_caller();
+        let commitment = AMMRoute::<T, I>::swap_commitment(&[DOT, SDOT], 1_000u128, 900u128, &[0u8; 32]);
+        assert_ok!(AMMRoute::<T, I>::commit_swap(SystemOrigin::Signed(caller.clone()).into(), commitment));
+    }: _(SystemOrigin::Signed(caller.clone()), commitment)
+
+    verify {
+        assert_last_event::<T, I>(Event::SwapCommitmentCancelled(caller, commitment).into());
+    }
+
+    whitelist_route {
+        let routes: Vec<_> = vec![DOT, SDOT];
+    }: _<T::RuntimeOrigin>(T::UpdateOrigin::successful_origin(), routes.clone())
+
+    verify {
+        assert_last_event::<T, I>(Event::RouteWhitelisted(routes).into());
+    }
+
+    remove_whitelisted_route {
+        let routes: Vec<_> = vec![DOT, SDOT];
+        assert_ok!(AMMRoute::<T, I>::whitelist_route(T::UpdateOrigin::successful_origin(), routes.clone()));
+    }: _<T::RuntimeOrigin>(T::UpdateOrigin::successful_origin(), routes.clone())
+
+    verify {
+        assert_last_event::<T, I>(Event::RouteRemovedFromWhitelist(routes).into());
+    }
 }
 
 impl_benchmark_test_suite!(AMMRoute, crate::mock::new_test_ext(), crate::mock::Runtime,);
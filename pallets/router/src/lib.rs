@@ -15,6 +15,20 @@
 //! # Router for Automatic Market Maker (AMM)
 //!
 //! Given a supported `route`, executes the indicated trades on all the available AMM(s) pool(s).
+//! The output of a trade can also be sent to another chain in the same extrinsic.
+//!
+//! Each hop of a route is traded wherever it gives the best price: a constant product pool of
+//! the AMM, a stable swap pool or a conversion at a protocol rate, such as staking DOT for sDOT
+//! or supplying an asset to the money market for its pToken.
+//!
+//! Traders worried about being sandwiched can instead commit to the hash of their trade
+//! with `commit_swap` and reveal it with `reveal_swap` in a later block. Revealing does not
+//! trade: the revealed swaps are traded at the start of the next block, before its
+//! extrinsics, in the order of their commitments. A sandwich then has to be placed in the
+//! reveal block without knowing the state the trade will meet, and closed in a block the
+//! collator of the reveal block may not author. This makes sandwiching harder but not
+//! impossible, `min_amount_out` still bounds what a trader can lose. Only routes
+//! whitelisted by governance can be traded this way.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -35,19 +49,37 @@ pub mod pallet {
     use super::*;
     use frame_support::{
         ensure, log,
-        pallet_prelude::{DispatchResult, DispatchResultWithPostInfo},
+        pallet_prelude::{
+            Blake2_128Concat, DispatchResult, DispatchResultWithPostInfo, Hooks, OptionQuery,
+            StorageMap, Twox64Concat, ValueQuery, Weight,
+        },
         require_transactional,
+        storage::{with_transaction, TransactionOutcome},
         traits::{
             fungibles::{Inspect, Mutate, Transfer},
-            Get, IsType,
+            EnsureOrigin, Get, IsType,
         },
         transactional, BoundedVec, PalletId,
     };
-    use frame_system::{ensure_signed, pallet_prelude::OriginFor};
-    use pallet_traits::AMM;
+    use frame_system::{
+        ensure_signed,
+        pallet_prelude::{BlockNumberFor, OriginFor},
+    };
+    use pallet_traits::{
+        reentrancy::{DefiFlow, FlowGuard},
+        xcm::XcmAssetTransfer,
+        ProtocolConversion, StableSwap, SwapRouter, AMM,
+    };
     use primitives::{Balance, CurrencyId};
-    use sp_runtime::{traits::Zero, DispatchError};
-    use sp_std::{cmp::Reverse, collections::btree_map::BTreeMap, vec::Vec};
+    use sp_runtime::{
+        traits::{Hash, Saturating, Zero},
+        DispatchError, RuntimeDebug,
+    };
+    use sp_std::{boxed::Box, cmp::Reverse, collections::btree_map::BTreeMap, vec::Vec};
+    use xcm::{
+        latest::{MultiLocation, WeightLimit},
+        VersionedMultiLocation,
+    };
 
     pub type Route<T, I> = BoundedVec<
         (
@@ -65,6 +97,33 @@ pub mod pallet {
     pub(crate) type BalanceOf<T, I = ()> =
         <<T as Config<I>>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
+    /// Salt mixed into a swap commitment so it can't be guessed from common trades
+    pub type Salt = [u8; 32];
+
+    /// The assets a trade goes through, from the asset in to the asset out
+    pub type RoutePath<T, I> = BoundedVec<AssetIdOf<T, I>, <T as Config<I>>::MaxLengthRoute>;
+
+    /// A revealed swap waiting to be traded
+    /// (trader, commitment, route, amount_in, min_amount_out)
+    pub type RevealedSwap<T, I> = (
+        AccountIdOf<T>,
+        <T as frame_system::Config>::Hash,
+        RoutePath<T, I>,
+        BalanceOf<T, I>,
+        BalanceOf<T, I>,
+    );
+
+    /// Where a hop of a route is traded
+    #[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum Venue {
+        /// A constant product pool of the AMM
+        Amm,
+        /// A stable swap pool
+        StableSwap,
+        /// A conversion at a protocol rate
+        Conversion,
+    }
+
     #[pallet::config]
     pub trait Config<I: 'static = ()>: frame_system::Config {
         type RuntimeEvent: From<Event<Self, I>>
@@ -77,6 +136,17 @@ pub mod pallet {
         /// Specify all the AMMs we are routing between
         type AMM: AMM<AccountIdOf<Self>, AssetIdOf<Self, I>, BalanceOf<Self, I>, Self::BlockNumber>;
 
+        /// Stable swap pools routed between alongside the AMM pools
+        type StableSwap: StableSwap<AccountIdOf<Self>, AssetIdOf<Self, I>, BalanceOf<Self, I>>;
+
+        /// Conversions at protocol rates, e.g. liquid staking or the money market, used
+        /// for a hop when they beat the pools
+        type Conversions: ProtocolConversion<
+            AccountIdOf<Self>,
+            AssetIdOf<Self, I>,
+            BalanceOf<Self, I>,
+        >;
+
         /// Weight information for extrinsics in this pallet.
         type AMMRouterWeightInfo: WeightInfo;
 
@@ -93,11 +163,60 @@ pub mod pallet {
         type Assets: Transfer<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
             + Inspect<Self::AccountId, AssetId = CurrencyId, Balance = Balance>
             + Mutate<Self::AccountId, AssetId = CurrencyId, Balance = Balance>;
+
+        /// Sends the output of a trade to another chain
+        type XcmTransfer: XcmAssetTransfer<Self::AccountId, AssetIdOf<Self, I>, BalanceOf<Self, I>>;
+
+        /// Blocks to wait after committing to a swap before it can be revealed
+        #[pallet::constant]
+        type MinRevealDelay: Get<Self::BlockNumber>;
+
+        /// Blocks after committing to a swap past which it can no longer be revealed
+        #[pallet::constant]
+        type MaxRevealDelay: Get<Self::BlockNumber>;
+
+        /// How many pending swap commitments an account can have at most
+        #[pallet::constant]
+        type MaxSwapCommitments: Get<u32>;
+
+        /// How many revealed swaps can be traded in a block at most
+        #[pallet::constant]
+        type MaxRevealedSwaps: Get<u32>;
+
+        /// The origin which can whitelist the routes of revealed swaps
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
     #[pallet::pallet]
     pub struct Pallet<T, I = ()>(_);
 
+    /// Pending swap commitments of an account and the block they were made in
+    #[pallet::storage]
+    #[pallet::getter(fn swap_commitments)]
+    pub type SwapCommitments<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(T::Hash, T::BlockNumber), T::MaxSwapCommitments>,
+        ValueQuery,
+    >;
+
+    /// Revealed swaps to trade at the start of a block
+    #[pallet::storage]
+    #[pallet::getter(fn revealed_swaps)]
+    pub type RevealedSwaps<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Twox64Concat,
+        T::BlockNumber,
+        BoundedVec<RevealedSwap<T, I>, T::MaxRevealedSwaps>,
+        ValueQuery,
+    >;
+
+    /// Routes which committed swaps can be traded along
+    #[pallet::storage]
+    pub type WhitelistedRoutes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, RoutePath<T, I>, (), OptionQuery>;
+
     #[pallet::error]
     pub enum Error<T, I = ()> {
         /// Input balance must not be zero
@@ -114,10 +233,30 @@ pub mod pallet {
         MaximumAmountInViolated,
         /// A more specific UnexpectedSlippage when trading exact amount in
         MinimumAmountOutViolated,
+        /// The route delivered less than the exact amount out traded for
+        ExactAmountOutNotReached,
         /// Token doesn't exists in all pools
         TokenDoesNotExists,
         /// Route between tokens is not possible
         NoPossibleRoute,
+        /// Xcm version of the location is not supported
+        BadVersion,
+        /// The swap was already committed to
+        DuplicatedCommitment,
+        /// The account has too many pending swap commitments
+        TooManyCommitments,
+        /// No pending commitment matches the swap
+        CommitmentNotFound,
+        /// The swap can't be revealed in the block it was committed in or too soon after
+        RevealTooEarly,
+        /// The commitment is too old to be revealed
+        CommitmentExpired,
+        /// Too many swaps were revealed in this block
+        TooManyRevealedSwaps,
+        /// Committed swaps can't be traded along the route
+        RouteNotWhitelisted,
+        /// The route is already whitelisted
+        RouteAlreadyWhitelisted,
     }
 
     #[pallet::event]
@@ -131,6 +270,72 @@ pub mod pallet {
             Vec<AssetIdOf<T, I>>,
             BalanceOf<T, I>,
         ),
+        /// Output of a trade was sent to another chain
+        /// [sender, asset_out, amount_out, dest]
+        TransferredAfterSwap(
+            T::AccountId,
+            AssetIdOf<T, I>,
+            BalanceOf<T, I>,
+            MultiLocation,
+        ),
+        /// Output of a trade could not be sent and was kept by the trader
+        /// [sender, asset_out, amount_out, dest, error]
+        TransferAfterSwapFailed(
+            T::AccountId,
+            AssetIdOf<T, I>,
+            BalanceOf<T, I>,
+            MultiLocation,
+            DispatchError,
+        ),
+        /// A trader committed to a swap
+        /// [sender, commitment]
+        SwapCommitted(T::AccountId, T::Hash),
+        /// A committed swap was revealed and will be traded at the start of the next block
+        /// [sender, commitment]
+        SwapRevealed(T::AccountId, T::Hash),
+        /// A trader dropped a swap commitment
+        /// [sender, commitment]
+        SwapCommitmentCancelled(T::AccountId, T::Hash),
+        /// A revealed swap could not be traded, the trader keeps the asset in
+        /// [sender, commitment, error]
+        RevealedSwapFailed(T::AccountId, T::Hash, DispatchError),
+        /// Committed swaps can be traded along the route
+        /// [route]
+        RouteWhitelisted(Vec<AssetIdOf<T, I>>),
+        /// Committed swaps can no longer be traded along the route
+        /// [route]
+        RouteRemovedFromWhitelist(Vec<AssetIdOf<T, I>>),
+    }
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let swaps = RevealedSwaps::<T, I>::take(now);
+            let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+
+            // the commitments were fixed before the swaps were revealed, so the collator
+            // of the reveal block can't order the trades
+            let mut swaps = swaps.into_inner();
+            swaps.sort_by_key(|(_, commitment, ..)| *commitment);
+
+            for (trader, commitment, route, amount_in, min_amount_out) in swaps {
+                if let Err(err) =
+                    Self::trade_revealed_swap(&trader, route, amount_in, min_amount_out)
+                {
+                    log::error!(
+                        target: "router::on_initialize",
+                        "failed to trade revealed swap {:?} of {:?}: {:?}",
+                        commitment,
+                        trader,
+                        err
+                    );
+                    Self::deposit_event(Event::RevealedSwapFailed(trader, commitment, err));
+                }
+                consumed.saturating_accrue(T::AMMRouterWeightInfo::swap_exact_tokens_for_tokens());
+            }
+
+            consumed
+        }
     }
 
     impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -164,16 +369,21 @@ pub mod pallet {
             token_out: AssetIdOf<T, I>,
             reversed: bool,
         ) -> Result<Vec<(Vec<AssetIdOf<T, I>>, BalanceOf<T, I>)>, DispatchError> {
-            // get all the pool asset pairs from the AMM
-            let pools = T::AMM::get_pools()?;
+            // get all the pool asset pairs from the AMM and the stable swap
+            let pools = T::AMM::get_pools()?
+                .into_iter()
+                .chain(T::StableSwap::get_pools()?);
 
             let mut graph: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
 
-            // build a non directed graph from pool asset pairs
-            pools.into_iter().for_each(|(a, b)| {
-                graph.entry(a).or_insert_with(Vec::new).push(b);
-                graph.entry(b).or_insert_with(Vec::new).push(a);
+            // pools trade both ways while conversions only go one way
+            pools.for_each(|(a, b)| {
+                Self::add_edge(&mut graph, a, b);
+                Self::add_edge(&mut graph, b, a);
             });
+            T::Conversions::conversions()
+                .into_iter()
+                .for_each(|(a, b)| Self::add_edge(&mut graph, a, b));
 
             // init mutable variables
             let mut path = Vec::new();
@@ -229,6 +439,15 @@ pub mod pallet {
             Ok(output_routes)
         }
 
+        /// Add the edge from `from` to `to` to `graph` unless it is already there
+        fn add_edge(graph: &mut BTreeMap<u32, Vec<u32>>, from: u32, to: u32) {
+            graph.entry(to).or_insert_with(Vec::new);
+            let adjacents = graph.entry(from).or_insert_with(Vec::new);
+            if !adjacents.contains(&to) {
+                adjacents.push(to);
+            }
+        }
+
         /// Returns the route that results in the largest amount out for amount in
         pub fn get_best_route(
             amount: BalanceOf<T, I>,
@@ -267,15 +486,15 @@ pub mod pallet {
 
             if reversed {
                 for route in routes {
-                    let amounts = T::AMM::get_amounts_in(amount, route.clone());
-                    if let Ok(amounts) = amounts {
+                    let amounts = Self::get_amounts_in(amount, &route);
+                    if let Ok((amounts, _)) = amounts {
                         output_routes.push((route, amounts[0]));
                     }
                 }
             } else {
                 for route in routes {
-                    let amounts = T::AMM::get_amounts_out(amount, route.clone());
-                    if let Ok(amounts) = amounts {
+                    let amounts = Self::get_amounts_out(amount, &route);
+                    if let Ok((amounts, _)) = amounts {
                         output_routes.push((route, amounts[amounts.len() - 1]));
                     }
                 }
@@ -283,26 +502,142 @@ pub mod pallet {
 
             output_routes
         }
-    }
 
-    #[pallet::call]
-    impl<T: Config<I>, I: 'static> Pallet<T, I> {
-        /// Given input amount is fixed, the output token amount is not known in advance.
+        /// The venue giving the most out for `amount_in` on the hop from `pair.0` to
+        /// `pair.1`, with the amount out
+        pub fn quote_hop_out(
+            pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
+            amount_in: BalanceOf<T, I>,
+        ) -> Result<(Venue, BalanceOf<T, I>), DispatchError> {
+            let path = Vec::from([pair.0, pair.1]);
+            // the last of equal quotes is picked, so a conversion has to beat the pools
+            [
+                (
+                    Venue::Conversion,
+                    T::Conversions::quote_out(pair, amount_in),
+                ),
+                (
+                    Venue::StableSwap,
+                    T::StableSwap::get_amounts_out(amount_in, path.clone()).map(|a| a[1]),
+                ),
+                (
+                    Venue::Amm,
+                    T::AMM::get_amounts_out(amount_in, path).map(|a| a[1]),
+                ),
+            ]
+            .into_iter()
+            .filter_map(|(venue, amount_out)| amount_out.ok().map(|a| (venue, a)))
+            .max_by_key(|(_, amount_out)| *amount_out)
+            .ok_or_else(|| Error::<T, I>::NoPossibleRoute.into())
+        }
+
+        /// The venue needing the least in for `amount_out` on the hop from `pair.0` to
+        /// `pair.1`, with the amount in
+        pub fn quote_hop_in(
+            pair: (AssetIdOf<T, I>, AssetIdOf<T, I>),
+            amount_out: BalanceOf<T, I>,
+        ) -> Result<(Venue, BalanceOf<T, I>), DispatchError> {
+            let path = Vec::from([pair.0, pair.1]);
+            // the first of equal quotes is picked, so a conversion has to beat the pools
+            [
+                (
+                    Venue::Amm,
+                    T::AMM::get_amounts_in(amount_out, path.clone()).map(|a| a[0]),
+                ),
+                (
+                    Venue::StableSwap,
+                    T::StableSwap::get_amounts_in(amount_out, path).map(|a| a[0]),
+                ),
+                (
+                    Venue::Conversion,
+                    T::Conversions::quote_in(pair, amount_out),
+                ),
+            ]
+            .into_iter()
+            .filter_map(|(venue, amount_in)| amount_in.ok().map(|a| (venue, a)))
+            .min_by_key(|(_, amount_in)| *amount_in)
+            .ok_or_else(|| Error::<T, I>::NoPossibleRoute.into())
+        }
+
+        /// Amounts along `route` when trading exact `amount_in`, with the venue of each hop
+        pub fn get_amounts_out(
+            amount_in: BalanceOf<T, I>,
+            route: &[AssetIdOf<T, I>],
+        ) -> Result<(Vec<BalanceOf<T, I>>, Vec<Venue>), DispatchError> {
+            let mut amounts = Vec::from([amount_in]);
+            let mut venues = Vec::new();
+            for pair in route.windows(2) {
+                let (venue, amount_out) =
+                    Self::quote_hop_out((pair[0], pair[1]), amounts[amounts.len() - 1])?;
+                amounts.push(amount_out);
+                venues.push(venue);
+            }
+
+            Ok((amounts, venues))
+        }
+
+        /// Amounts along `route` when trading for exact `amount_out`, with the venue of
+        /// each hop
+        pub fn get_amounts_in(
+            amount_out: BalanceOf<T, I>,
+            route: &[AssetIdOf<T, I>],
+        ) -> Result<(Vec<BalanceOf<T, I>>, Vec<Venue>), DispatchError> {
+            let mut amounts = Vec::from([amount_out]);
+            let mut venues = Vec::new();
+            for pair in route.windows(2).rev() {
+                let (venue, amount_in) = Self::quote_hop_in((pair[0], pair[1]), amounts[0])?;
+                amounts.insert(0, amount_in);
+                venues.insert(0, venue);
+            }
+
+            Ok((amounts, venues))
+        }
+
+        /// Trade each hop of `route` at the venue quoted for it, `amount_in` on the first
+        /// hop and what the previous hop delivered on the others, returning the amount out.
         ///
-        /// - `origin`: the trader.
-        /// - `route`: the route user inputs
-        /// - `amount_in`: the amount of trading assets
-        /// - `min_amount_out`: the minimum a trader is willing to receive
-        #[pallet::weight(T::AMMRouterWeightInfo::swap_exact_tokens_for_tokens())]
-        #[transactional]
-        pub fn swap_exact_tokens_for_tokens(
-            origin: OriginFor<T>,
-            route: Vec<AssetIdOf<T, I>>,
-            #[pallet::compact] amount_in: BalanceOf<T, I>,
-            #[pallet::compact] min_amount_out: BalanceOf<T, I>,
-        ) -> DispatchResultWithPostInfo {
-            let trader = ensure_signed(origin)?;
+        /// The amounts delivered are measured rather than taken from the quotes, so a hop
+        /// delivering less than quoted never dips into the trader's own balance of the
+        /// intermediate asset, and one delivering more leaves no dust behind.
+        #[require_transactional]
+        fn swap_along(
+            trader: &AccountIdOf<T>,
+            route: &[AssetIdOf<T, I>],
+            amount_in: BalanceOf<T, I>,
+            venues: Vec<Venue>,
+        ) -> Result<BalanceOf<T, I>, DispatchError> {
+            let mut amount = amount_in;
+            for (i, venue) in venues.into_iter().enumerate() {
+                let pair = (route[i], route[i + 1]);
+                let balance_before = Self::reducible_balance(trader, pair.1);
+                match venue {
+                    Venue::Amm => T::AMM::swap(trader, pair, amount)?,
+                    Venue::StableSwap => T::StableSwap::swap(trader, pair, amount)?,
+                    Venue::Conversion => {
+                        T::Conversions::convert(trader, pair, amount)?;
+                    }
+                }
+                amount = Self::reducible_balance(trader, pair.1).saturating_sub(balance_before);
+            }
+
+            Ok(amount)
+        }
+
+        /// Balance of `asset` which `who` can trade, including assets kept by the conversions
+        fn reducible_balance(who: &AccountIdOf<T>, asset: AssetIdOf<T, I>) -> BalanceOf<T, I> {
+            T::Conversions::reducible_balance(who, asset).unwrap_or_else(|| {
+                T::Assets::reducible_balance(asset, who, asset == T::GetNativeCurrencyId::get())
+            })
+        }
 
+        /// Trade exact `amount_in` along `route`, returning the amount out
+        #[require_transactional]
+        pub fn do_swap_exact_tokens_for_tokens(
+            trader: &AccountIdOf<T>,
+            route: Vec<AssetIdOf<T, I>>,
+            amount_in: BalanceOf<T, I>,
+            min_amount_out: BalanceOf<T, I>,
+        ) -> Result<BalanceOf<T, I>, DispatchError> {
             // do all checks on routes
             Self::route_checks(&route)?;
 
@@ -313,36 +648,158 @@ pub mod pallet {
             );
 
             // Ensure the trader has enough tokens for transaction.
-            let from_currency_id = route[0];
             ensure!(
-                T::Assets::reducible_balance(
-                    from_currency_id,
-                    &trader,
-                    from_currency_id == T::GetNativeCurrencyId::get()
-                ) >= amount_in,
+                Self::reducible_balance(trader, route[0]) >= amount_in,
                 Error::<T, I>::InsufficientBalance
             );
 
-            let amounts = T::AMM::get_amounts_out(amount_in, route.clone())?;
+            let (amounts, venues) = Self::get_amounts_out(amount_in, &route)?;
 
-            // make sure the required amount in does not violate our input
+            // make sure the quoted amount out does not violate our input
             ensure!(
                 amounts[amounts.len() - 1] >= min_amount_out,
                 Error::<T, I>::MinimumAmountOutViolated
             );
 
-            for i in 0..(route.len() - 1) {
-                let next_index = i + 1;
-                T::AMM::swap(&trader, (route[i], route[next_index]), amounts[i])?;
-            }
+            let amount_out = Self::swap_along(trader, &route, amount_in, venues)?;
+            ensure!(
+                amount_out >= min_amount_out,
+                Error::<T, I>::MinimumAmountOutViolated
+            );
+
+            Self::deposit_event(Event::Traded(trader.clone(), amount_in, route, amount_out));
+
+            Ok(amount_out)
+        }
+
+        /// Trade along `route` for exact `amount_out`, returning the amount in.
+        ///
+        /// The amounts in are calculated backward from `amount_out`, hop by hop. The
+        /// pools round in their favour, so slightly more than `amount_out` can come out.
+        #[require_transactional]
+        pub fn do_swap_tokens_for_exact_tokens(
+            trader: &AccountIdOf<T>,
+            route: Vec<AssetIdOf<T, I>>,
+            amount_out: BalanceOf<T, I>,
+            max_amount_in: BalanceOf<T, I>,
+        ) -> Result<BalanceOf<T, I>, DispatchError> {
+            // do all checks on routes
+            Self::route_checks(&route)?;
+
+            // Ensure balances user input is bigger than zero.
+            ensure!(
+                amount_out > Zero::zero() && max_amount_in >= Zero::zero(),
+                Error::<T, I>::ZeroBalance
+            );
+
+            // calculate trading amounts
+            let (amounts, venues) = Self::get_amounts_in(amount_out, &route)?;
+            let amount_in = amounts[0];
+
+            // make sure the required amount in does not violate our input
+            ensure!(
+                max_amount_in >= amount_in,
+                Error::<T, I>::MaximumAmountInViolated
+            );
+
+            // Ensure the trader has enough tokens for transaction.
+            ensure!(
+                Self::reducible_balance(trader, route[0]) >= amount_in,
+                Error::<T, I>::InsufficientBalance
+            );
+
+            let amount_received = Self::swap_along(trader, &route, amount_in, venues)?;
+            ensure!(
+                amount_received >= amount_out,
+                Error::<T, I>::ExactAmountOutNotReached
+            );
 
             Self::deposit_event(Event::Traded(
-                trader,
-                amounts[0],
+                trader.clone(),
+                amount_in,
                 route,
-                amounts[amounts.len() - 1],
+                amount_received,
             ));
 
+            Ok(amount_in)
+        }
+
+        /// The commitment a trader submits to later reveal a swap of exact `amount_in`
+        /// along `route`
+        pub fn swap_commitment(
+            route: &[AssetIdOf<T, I>],
+            amount_in: BalanceOf<T, I>,
+            min_amount_out: BalanceOf<T, I>,
+            salt: &Salt,
+        ) -> T::Hash {
+            T::Hashing::hash_of(&(route, amount_in, min_amount_out, salt))
+        }
+
+        /// Remove a pending swap commitment of `trader`, returning the block it was made in
+        fn take_swap_commitment(
+            trader: &AccountIdOf<T>,
+            commitment: &T::Hash,
+        ) -> Result<T::BlockNumber, DispatchError> {
+            SwapCommitments::<T, I>::try_mutate_exists(trader, |maybe_commitments| {
+                let commitments = maybe_commitments
+                    .as_mut()
+                    .ok_or(Error::<T, I>::CommitmentNotFound)?;
+                let index = commitments
+                    .iter()
+                    .position(|(c, _)| c == commitment)
+                    .ok_or(Error::<T, I>::CommitmentNotFound)?;
+                let (_, committed_at) = commitments.remove(index);
+                if commitments.is_empty() {
+                    *maybe_commitments = None;
+                }
+                Ok(committed_at)
+            })
+        }
+
+        /// Trade a revealed swap, reverting it if it fails
+        fn trade_revealed_swap(
+            trader: &AccountIdOf<T>,
+            route: RoutePath<T, I>,
+            amount_in: BalanceOf<T, I>,
+            min_amount_out: BalanceOf<T, I>,
+        ) -> Result<BalanceOf<T, I>, DispatchError> {
+            with_transaction(|| {
+                let res = FlowGuard::enter(DefiFlow::Router).and_then(|_guard| {
+                    Self::do_swap_exact_tokens_for_tokens(
+                        trader,
+                        route.into_inner(),
+                        amount_in,
+                        min_amount_out,
+                    )
+                });
+                match res {
+                    Ok(amount_out) => TransactionOutcome::Commit(Ok(amount_out)),
+                    Err(err) => TransactionOutcome::Rollback(Err(err)),
+                }
+            })
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Given input amount is fixed, the output token amount is not known in advance.
+        ///
+        /// - `origin`: the trader.
+        /// - `route`: the route user inputs
+        /// - `amount_in`: the amount of trading assets
+        /// - `min_amount_out`: the minimum a trader is willing to receive
+        #[pallet::weight(T::AMMRouterWeightInfo::swap_exact_tokens_for_tokens())]
+        #[transactional]
+        pub fn swap_exact_tokens_for_tokens(
+            origin: OriginFor<T>,
+            route: Vec<AssetIdOf<T, I>>,
+            #[pallet::compact] amount_in: BalanceOf<T, I>,
+            #[pallet::compact] min_amount_out: BalanceOf<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            let trader = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Router)?;
+            Self::do_swap_exact_tokens_for_tokens(&trader, route, amount_in, min_amount_out)?;
+
             Ok(().into())
         }
 
@@ -361,50 +818,271 @@ pub mod pallet {
             #[pallet::compact] max_amount_in: BalanceOf<T, I>,
         ) -> DispatchResultWithPostInfo {
             let trader = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Router)?;
+            Self::do_swap_tokens_for_exact_tokens(&trader, route, amount_out, max_amount_in)?;
 
-            // do all checks on routes
-            Self::route_checks(&route)?;
+            Ok(().into())
+        }
 
-            // Ensure balances user input is bigger than zero.
+        /// Trade exact `amount_in` along `route` and send the output to `dest` on
+        /// another chain.
+        ///
+        /// The trade is kept even if the output can't be sent, in which case it stays
+        /// with the trader.
+        ///
+        /// - `origin`: the trader.
+        /// - `route`: the route user inputs
+        /// - `amount_in`: the amount of trading assets
+        /// - `min_amount_out`: the minimum a trader is willing to receive
+        /// - `dest`: the account on another chain receiving the output
+        /// - `dest_weight_limit`: the weight limit of the execution at `dest`
+        #[pallet::weight(T::AMMRouterWeightInfo::swap_and_transfer())]
+        #[transactional]
+        pub fn swap_and_transfer(
+            origin: OriginFor<T>,
+            route: Vec<AssetIdOf<T, I>>,
+            #[pallet::compact] amount_in: BalanceOf<T, I>,
+            #[pallet::compact] min_amount_out: BalanceOf<T, I>,
+            dest: Box<VersionedMultiLocation>,
+            dest_weight_limit: WeightLimit,
+        ) -> DispatchResultWithPostInfo {
+            let trader = ensure_signed(origin)?;
+            let _guard = FlowGuard::enter(DefiFlow::Router)?;
+            let dest: MultiLocation = (*dest).try_into().map_err(|()| Error::<T, I>::BadVersion)?;
+            let asset_out = *route.last().ok_or(Error::<T, I>::EmptyRoute)?;
+            let amount_out =
+                Self::do_swap_exact_tokens_for_tokens(&trader, route, amount_in, min_amount_out)?;
+
+            // Only the transfer is reverted if it fails, the output stays with the trader
+            let res = with_transaction(|| {
+                match T::XcmTransfer::transfer(
+                    trader.clone(),
+                    asset_out,
+                    amount_out,
+                    dest.clone(),
+                    dest_weight_limit,
+                ) {
+                    Ok(()) => TransactionOutcome::Commit(Ok(())),
+                    Err(err) => TransactionOutcome::Rollback(Err(err)),
+                }
+            });
+
+            match res {
+                Ok(()) => Self::deposit_event(Event::TransferredAfterSwap(
+                    trader, asset_out, amount_out, dest,
+                )),
+                Err(err) => {
+                    log::error!(
+                        target: "router::swap_and_transfer",
+                        "failed to transfer {:?} of {:?} to {:?}: {:?}",
+                        amount_out,
+                        asset_out,
+                        dest,
+                        err
+                    );
+                    Self::deposit_event(Event::TransferAfterSwapFailed(
+                        trader, asset_out, amount_out, dest, err,
+                    ))
+                }
+            }
+
+            Ok(().into())
+        }
+
+        /// Commit to a swap of exact amount in without disclosing it, see
+        /// `swap_commitment` for how the commitment is built.
+        ///
+        /// Commitments too old to be revealed are dropped.
+        ///
+        /// - `origin`: the trader.
+        /// - `commitment`: hash of the swap that will be revealed
+        #[pallet::weight(T::AMMRouterWeightInfo::commit_swap())]
+        #[transactional]
+        pub fn commit_swap(
+            origin: OriginFor<T>,
+            commitment: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let trader = ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+
+            SwapCommitments::<T, I>::try_mutate(&trader, |commitments| -> DispatchResult {
+                commitments.retain(|(_, committed_at)| {
+                    now <= committed_at.saturating_add(T::MaxRevealDelay::get())
+                });
+                ensure!(
+                    !commitments.iter().any(|(c, _)| *c == commitment),
+                    Error::<T, I>::DuplicatedCommitment
+                );
+                commitments
+                    .try_push((commitment, now))
+                    .map_err(|_| Error::<T, I>::TooManyCommitments)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::SwapCommitted(trader, commitment));
+
+            Ok(().into())
+        }
+
+        /// Reveal a swap committed to with `commit_swap`, to be traded like
+        /// `swap_exact_tokens_for_tokens` at the start of the next block. The trade is
+        /// paid for here, and if it fails the trader keeps the asset in.
+        ///
+        /// - `origin`: the trader.
+        /// - `route`: the route user inputs
+        /// - `amount_in`: the amount of trading assets
+        /// - `min_amount_out`: the minimum a trader is willing to receive
+        /// - `salt`: the salt used in the commitment
+        #[pallet::weight(T::AMMRouterWeightInfo::reveal_swap())]
+        #[transactional]
+        pub fn reveal_swap(
+            origin: OriginFor<T>,
+            route: Vec<AssetIdOf<T, I>>,
+            #[pallet::compact] amount_in: BalanceOf<T, I>,
+            #[pallet::compact] min_amount_out: BalanceOf<T, I>,
+            salt: Salt,
+        ) -> DispatchResultWithPostInfo {
+            let trader = ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let commitment = Self::swap_commitment(&route, amount_in, min_amount_out, &salt);
+
+            let committed_at = Self::take_swap_commitment(&trader, &commitment)?;
             ensure!(
-                amount_out > Zero::zero() && max_amount_in >= Zero::zero(),
-                Error::<T, I>::ZeroBalance
+                now >= committed_at.saturating_add(T::MinRevealDelay::get()),
+                Error::<T, I>::RevealTooEarly
+            );
+            ensure!(
+                now <= committed_at.saturating_add(T::MaxRevealDelay::get()),
+                Error::<T, I>::CommitmentExpired
             );
 
-            // calculate trading amounts
-            let amounts = T::AMM::get_amounts_in(amount_out, route.clone())?;
-
-            // we need to check after calc so we know how much is expected to be input
-            // Ensure the trader has enough tokens for transaction.
-            let from_currency_id = route[0];
+            let route: RoutePath<T, I> = route
+                .try_into()
+                .map_err(|_| Error::<T, I>::ExceedMaxLengthRoute)?;
             ensure!(
-                T::Assets::reducible_balance(
-                    from_currency_id,
-                    &trader,
-                    from_currency_id == T::GetNativeCurrencyId::get()
-                ) > amounts[0],
-                Error::<T, I>::InsufficientBalance
+                WhitelistedRoutes::<T, I>::contains_key(&route),
+                Error::<T, I>::RouteNotWhitelisted
             );
+            ensure!(!amount_in.is_zero(), Error::<T, I>::ZeroBalance);
 
-            // make sure the required amount in does not violate our input
+            RevealedSwaps::<T, I>::try_mutate(now.saturating_add(1u32.into()), |swaps| {
+                swaps.try_push((trader.clone(), commitment, route, amount_in, min_amount_out))
+            })
+            .map_err(|_| Error::<T, I>::TooManyRevealedSwaps)?;
+
+            Self::deposit_event(Event::SwapRevealed(trader, commitment));
+
+            Ok(().into())
+        }
+
+        /// Drop a pending swap commitment.
+        ///
+        /// - `origin`: the trader.
+        /// - `commitment`: the commitment to drop
+        #[pallet::weight(T::AMMRouterWeightInfo::cancel_swap_commitment())]
+        #[transactional]
+        pub fn cancel_swap_commitment(
+            origin: OriginFor<T>,
+            commitment: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let trader = ensure_signed(origin)?;
+
+            Self::take_swap_commitment(&trader, &commitment)?;
+
+            Self::deposit_event(Event::SwapCommitmentCancelled(trader, commitment));
+
+            Ok(().into())
+        }
+
+        /// Allow committed swaps to be traded along `route`.
+        ///
+        /// - `origin`: the update origin.
+        /// - `route`: the route to whitelist
+        #[pallet::weight(T::AMMRouterWeightInfo::whitelist_route())]
+        #[transactional]
+        pub fn whitelist_route(
+            origin: OriginFor<T>,
+            route: Vec<AssetIdOf<T, I>>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::route_checks(&route)?;
+            let path: RoutePath<T, I> = route
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T, I>::ExceedMaxLengthRoute)?;
             ensure!(
-                max_amount_in >= amounts[0],
-                Error::<T, I>::MaximumAmountInViolated
+                !WhitelistedRoutes::<T, I>::contains_key(&path),
+                Error::<T, I>::RouteAlreadyWhitelisted
             );
 
-            for i in 0..(route.len() - 1) {
-                let next_index = i + 1;
-                T::AMM::swap(&trader, (route[i], route[next_index]), amounts[i])?;
-            }
+            WhitelistedRoutes::<T, I>::insert(path, ());
 
-            Self::deposit_event(Event::Traded(
-                trader,
-                amounts[0],
-                route,
-                amounts[amounts.len() - 1],
-            ));
+            Self::deposit_event(Event::RouteWhitelisted(route));
+
+            Ok(().into())
+        }
+
+        /// Stop committed swaps from being traded along `route`. Swaps already revealed
+        /// are still traded.
+        ///
+        /// - `origin`: the update origin.
+        /// - `route`: the route to remove from the whitelist
+        #[pallet::weight(T::AMMRouterWeightInfo::remove_whitelisted_route())]
+        #[transactional]
+        pub fn remove_whitelisted_route(
+            origin: OriginFor<T>,
+            route: Vec<AssetIdOf<T, I>>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let path: RoutePath<T, I> = route
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T, I>::RouteNotWhitelisted)?;
+            WhitelistedRoutes::<T, I>::take(&path).ok_or(Error::<T, I>::RouteNotWhitelisted)?;
+
+            Self::deposit_event(Event::RouteRemovedFromWhitelist(route));
 
             Ok(().into())
         }
     }
+
+    impl<T: Config<I>, I: 'static> SwapRouter<AccountIdOf<T>, AssetIdOf<T, I>, BalanceOf<T, I>>
+        for Pallet<T, I>
+    {
+        fn best_route(
+            asset_in: AssetIdOf<T, I>,
+            asset_out: AssetIdOf<T, I>,
+            amount_in: BalanceOf<T, I>,
+        ) -> Result<(Vec<AssetIdOf<T, I>>, BalanceOf<T, I>), DispatchError> {
+            Self::get_best_route(amount_in, asset_in, asset_out, false)
+        }
+
+        fn best_route_for_exact_out(
+            asset_in: AssetIdOf<T, I>,
+            asset_out: AssetIdOf<T, I>,
+            amount_out: BalanceOf<T, I>,
+        ) -> Result<(Vec<AssetIdOf<T, I>>, BalanceOf<T, I>), DispatchError> {
+            Self::get_best_route(amount_out, asset_in, asset_out, true)
+        }
+
+        fn swap_exact_in(
+            who: &AccountIdOf<T>,
+            route: Vec<AssetIdOf<T, I>>,
+            amount_in: BalanceOf<T, I>,
+            min_amount_out: BalanceOf<T, I>,
+        ) -> Result<BalanceOf<T, I>, DispatchError> {
+            let _guard = FlowGuard::enter(DefiFlow::Router)?;
+            Self::do_swap_exact_tokens_for_tokens(who, route, amount_in, min_amount_out)
+        }
+
+        fn swap_exact_out(
+            who: &AccountIdOf<T>,
+            route: Vec<AssetIdOf<T, I>>,
+            amount_out: BalanceOf<T, I>,
+            max_amount_in: BalanceOf<T, I>,
+        ) -> Result<BalanceOf<T, I>, DispatchError> {
+            let _guard = FlowGuard::enter(DefiFlow::Router)?;
+            Self::do_swap_tokens_for_exact_tokens(who, route, amount_out, max_amount_in)
+        }
+    }
 }
@@ -18,13 +18,21 @@ use super::*;
 use crate as pallet_route;
 
 use frame_support::{
-    construct_runtime, parameter_types, traits::Everything, traits::SortedMembers, PalletId,
+    construct_runtime, parameter_types,
+    traits::{fungibles::Mutate, Everything, SortedMembers},
+    PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
+use pallet_traits::{xcm::XcmAssetTransfer, ProtocolConversion};
 use sp_core::H256;
-use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_runtime::{
+    testing::Header, traits::IdentityLookup, ArithmeticError, DispatchError, DispatchResult,
+    FixedPointNumber,
+};
+use sp_std::cell::RefCell;
+use xcm::latest::{MultiLocation, WeightLimit};
 
-pub use primitives::{tokens, Amount, Balance, CurrencyId, Ratio};
+pub use primitives::{tokens, Amount, Balance, CurrencyId, Rate, Ratio};
 
 pub type AccountId = u128;
 pub type BlockNumber = u64;
@@ -146,6 +154,8 @@ impl pallet_amm::Config for Runtime {
     type MinimumLiquidity = MinimumLiquidity;
     type MaxLengthRoute = MaxLengthRoute;
     type GetNativeCurrencyId = NativeCurrencyId;
+    type Revenue = ();
+    type Referrals = ();
 }
 
 parameter_types! {
@@ -162,16 +172,109 @@ impl pallet_currency_adapter::Config for Runtime {
 parameter_types! {
     pub const MaxLengthRoute: u8 = 10;
     pub const RouterPalletId: PalletId = PalletId(*b"ammroute");
+    pub const MinRevealDelay: BlockNumber = 1;
+    pub const MaxRevealDelay: BlockNumber = 10;
+    pub const MaxSwapCommitments: u32 = 2;
+    pub const MaxRevealedSwaps: u32 = 2;
+}
+
+thread_local! {
+    pub static XCM_TRANSFERS: RefCell<Vec<(AccountId, CurrencyId, Balance, MultiLocation)>> =
+        RefCell::new(Vec::new());
+}
+
+/// Burns the transferred assets and records the transfers, destinations on this chain
+/// are rejected
+pub struct MockXcmTransfer;
+impl XcmAssetTransfer<AccountId, CurrencyId, Balance> for MockXcmTransfer {
+    fn transfer(
+        who: AccountId,
+        currency_id: CurrencyId,
+        amount: Balance,
+        dest: MultiLocation,
+        _dest_weight_limit: WeightLimit,
+    ) -> DispatchResult {
+        if dest.parents == 0 {
+            return Err(DispatchError::Other("unsupported destination"));
+        }
+        <CurrencyAdapter as Mutate<AccountId>>::burn_from(currency_id, &who, amount)?;
+        XCM_TRANSFERS.with(|q| q.borrow_mut().push((who, currency_id, amount, dest)));
+        Ok(())
+    }
+}
+
+pub fn xcm_transfers() -> Vec<(AccountId, CurrencyId, Balance, MultiLocation)> {
+    XCM_TRANSFERS.with(|q| q.borrow().clone())
+}
+
+thread_local! {
+    pub static STAKING_RATE: RefCell<Option<Rate>> = RefCell::new(None);
+}
+
+/// Stakes DOT for SDOT at `STAKING_RATE` SDOT per DOT, no conversions until the rate is set
+pub struct MockStaking;
+impl ProtocolConversion<AccountId, CurrencyId, Balance> for MockStaking {
+    fn conversions() -> Vec<(CurrencyId, CurrencyId)> {
+        staking_rate()
+            .map(|_| vec![(DOT, SDOT)])
+            .unwrap_or_default()
+    }
+
+    fn quote_out(
+        _pair: (CurrencyId, CurrencyId),
+        amount_in: Balance,
+    ) -> Result<Balance, DispatchError> {
+        Ok(staking_rate()
+            .and_then(|r| r.checked_mul_int(amount_in))
+            .ok_or(ArithmeticError::Overflow)?)
+    }
+
+    fn quote_in(
+        _pair: (CurrencyId, CurrencyId),
+        amount_out: Balance,
+    ) -> Result<Balance, DispatchError> {
+        Ok(staking_rate()
+            .and_then(|r| r.reciprocal())
+            .and_then(|r| r.checked_mul_int(amount_out))
+            .ok_or(ArithmeticError::Overflow)?)
+    }
+
+    fn convert(
+        who: &AccountId,
+        pair: (CurrencyId, CurrencyId),
+        amount_in: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let amount_out = Self::quote_out(pair, amount_in)?;
+        <CurrencyAdapter as Mutate<AccountId>>::burn_from(pair.0, who, amount_in)?;
+        <CurrencyAdapter as Mutate<AccountId>>::mint_into(pair.1, who, amount_out)?;
+        Ok(amount_out)
+    }
+}
+
+pub fn staking_rate() -> Option<Rate> {
+    STAKING_RATE.with(|r| *r.borrow())
+}
+
+pub fn set_staking_rate(rate: Option<Rate>) {
+    STAKING_RATE.with(|r| *r.borrow_mut() = rate);
 }
 
 impl Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type PalletId = RouterPalletId;
     type AMM = DefaultAMM;
+    type StableSwap = ();
+    type Conversions = MockStaking;
     type AMMRouterWeightInfo = ();
     type MaxLengthRoute = MaxLengthRoute;
     type Assets = CurrencyAdapter;
     type GetNativeCurrencyId = NativeCurrencyId;
+    type XcmTransfer = MockXcmTransfer;
+    type MinRevealDelay = MinRevealDelay;
+    type MaxRevealDelay = MaxRevealDelay;
+    type MaxSwapCommitments = MaxSwapCommitments;
+    type MaxRevealedSwaps = MaxRevealedSwaps;
+    type UpdateOrigin = EnsureRoot<AccountId>;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -189,7 +292,7 @@ construct_runtime!(
         // AMM instances
         DefaultAMM: pallet_amm::{Pallet, Call, Storage, Event<T>},
         // AMM Route
-        AMMRoute: pallet_route::{Pallet, Call, Event<T>},
+        AMMRoute: pallet_route::{Pallet, Call, Storage, Event<T>},
         CurrencyAdapter: pallet_currency_adapter::{Pallet, Call},
     }
 );
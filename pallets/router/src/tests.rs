@@ -15,8 +15,11 @@
 //! Unit tests for the router pallet.
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use mock::*;
+use pallet_traits::reentrancy::{active_flows, DefiFlow, FlowGuard};
+use sp_runtime::{DispatchError, FixedPointNumber};
+use xcm::latest::{Junction::Parachain, Junctions::X1, MultiLocation, WeightLimit};
 
 #[test]
 fn too_many_routes_should_not_work() {
@@ -134,6 +137,29 @@ fn swap_tokens_for_exact_tokens_should_work() {
     });
 }
 
+#[test]
+fn swap_tokens_for_exact_tokens_should_spend_whole_balance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            DAVE,
+            SAMPLE_LP_TOKEN
+        ));
+        assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), DOT, BOB, 1_004));
+
+        assert_ok!(AMMRoute::swap_tokens_for_exact_tokens(
+            RuntimeOrigin::signed(BOB),
+            vec![DOT, SDOT],
+            1_000,
+            1_004,
+        ));
+        assert_eq!(Assets::balance(DOT, BOB), 0);
+        assert_eq!(Assets::balance(SDOT, BOB), 1_000);
+    });
+}
+
 #[test]
 fn pool_as_bridge_swap_tokens_for_exact_tokens_should_work() {
     new_test_ext().execute_with(|| {
@@ -406,6 +432,105 @@ fn trade_should_work_more_than_one_route() {
     })
 }
 
+#[test]
+fn swap_and_transfer_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let trader = ALICE;
+        let dest = MultiLocation::new(1, X1(Parachain(1000)));
+
+        // create pool and add liquidity
+        assert_ok!(DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            DAVE,
+            SAMPLE_LP_TOKEN
+        ));
+
+        assert_ok!(AMMRoute::swap_and_transfer(
+            RuntimeOrigin::signed(ALICE),
+            vec![DOT, SDOT],
+            1_000, // amount_in
+            900,   // min_amount_out
+            Box::new(dest.clone().into()),
+            WeightLimit::Unlimited,
+        ));
+
+        // output is sent away instead of credited to the trader
+        assert_eq!(Assets::balance(DOT, trader), 10_000 - 1_000);
+        assert_eq!(Assets::balance(SDOT, trader), 10_000);
+        assert_eq!(xcm_transfers(), vec![(trader, SDOT, 996, dest.clone())]);
+        System::assert_last_event(RuntimeEvent::AMMRoute(Event::TransferredAfterSwap(
+            trader, SDOT, 996, dest,
+        )));
+    });
+}
+
+#[test]
+fn swap_and_transfer_should_keep_output_if_transfer_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let trader = ALICE;
+        let dest = MultiLocation::here();
+
+        // create pool and add liquidity
+        assert_ok!(DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            DAVE,
+            SAMPLE_LP_TOKEN
+        ));
+
+        assert_ok!(AMMRoute::swap_and_transfer(
+            RuntimeOrigin::signed(ALICE),
+            vec![DOT, SDOT],
+            1_000, // amount_in
+            900,   // min_amount_out
+            Box::new(dest.clone().into()),
+            WeightLimit::Unlimited,
+        ));
+
+        // trade is kept and the output stays with the trader
+        assert_eq!(Assets::balance(DOT, trader), 10_000 - 1_000);
+        assert_eq!(Assets::balance(SDOT, trader), 10_000 + 996);
+        assert!(xcm_transfers().is_empty());
+        System::assert_last_event(RuntimeEvent::AMMRoute(Event::TransferAfterSwapFailed(
+            trader,
+            SDOT,
+            996,
+            dest,
+            DispatchError::Other("unsupported destination"),
+        )));
+    });
+}
+
+#[test]
+fn swap_and_transfer_should_not_work_if_amount_less_than_min_amount_out() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            DAVE,
+            SAMPLE_LP_TOKEN
+        ));
+
+        assert_noop!(
+            AMMRoute::swap_and_transfer(
+                RuntimeOrigin::signed(ALICE),
+                vec![DOT, SDOT],
+                1_000, // amount_in
+                1_000, // min_amount_out
+                Box::new(MultiLocation::new(1, X1(Parachain(1000))).into()),
+                WeightLimit::Unlimited,
+            ),
+            Error::<Runtime>::MinimumAmountOutViolated
+        );
+    });
+}
+
 #[test]
 fn get_all_routes_should_work() {
     new_test_ext().execute_with(|| {
@@ -772,3 +897,496 @@ fn get_best_route_reversed_should_work() {
         assert_eq!(best_route, (vec![101, 1001, 100], 1119));
     })
 }
+
+#[test]
+fn reveal_swap_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let trader = ALICE;
+        let route = vec![DOT, SDOT];
+        let salt = [7u8; 32];
+        let commitment = AMMRoute::swap_commitment(&route, 1_000, 900, &salt);
+
+        // create pool and add liquidity
+        assert_ok!(DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            DAVE,
+            SAMPLE_LP_TOKEN
+        ));
+
+        assert_ok!(AMMRoute::whitelist_route(
+            RuntimeOrigin::root(),
+            route.clone()
+        ));
+        assert_ok!(AMMRoute::commit_swap(
+            RuntimeOrigin::signed(trader),
+            commitment
+        ));
+        assert_eq!(
+            AMMRoute::swap_commitments(trader).into_inner(),
+            vec![(commitment, 1)]
+        );
+        System::assert_last_event(RuntimeEvent::AMMRoute(Event::SwapCommitted(
+            trader, commitment,
+        )));
+
+        // can't be revealed in the block it was committed in
+        assert_noop!(
+            AMMRoute::reveal_swap(
+                RuntimeOrigin::signed(trader),
+                route.clone(),
+                1_000,
+                900,
+                salt
+            ),
+            Error::<Runtime>::RevealTooEarly
+        );
+
+        System::set_block_number(2);
+
+        // the revealed trade must match the commitment
+        assert_noop!(
+            AMMRoute::reveal_swap(
+                RuntimeOrigin::signed(trader),
+                route.clone(),
+                1_000,
+                800,
+                salt
+            ),
+            Error::<Runtime>::CommitmentNotFound
+        );
+        assert_noop!(
+            AMMRoute::reveal_swap(RuntimeOrigin::signed(BOB), route.clone(), 1_000, 900, salt),
+            Error::<Runtime>::CommitmentNotFound
+        );
+
+        assert_ok!(AMMRoute::reveal_swap(
+            RuntimeOrigin::signed(trader),
+            route.clone(),
+            1_000,
+            900,
+            salt
+        ));
+
+        // revealing doesn't trade yet
+        assert_eq!(Assets::balance(DOT, trader), 10_000);
+        assert_eq!(Assets::balance(SDOT, trader), 10_000);
+        assert!(!SwapCommitments::<Runtime>::contains_key(trader));
+        assert_eq!(AMMRoute::revealed_swaps(3).len(), 1);
+        System::assert_last_event(RuntimeEvent::AMMRoute(Event::SwapRevealed(
+            trader, commitment,
+        )));
+
+        // the swap is traded at the start of the next block
+        System::set_block_number(3);
+        AMMRoute::on_initialize(3);
+
+        assert_eq!(Assets::balance(DOT, trader), 10_000 - 1_000);
+        assert_eq!(Assets::balance(SDOT, trader), 10_000 + 996);
+        assert!(!RevealedSwaps::<Runtime>::contains_key(3));
+        System::assert_last_event(RuntimeEvent::AMMRoute(Event::Traded(
+            trader, 1_000, route, 996,
+        )));
+    });
+}
+
+#[test]
+fn revealed_swap_should_keep_asset_in_if_trade_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let route = vec![DOT, SDOT];
+        let salt = [7u8; 32];
+        // more than the pool can give for 1_000 DOT
+        let commitment = AMMRoute::swap_commitment(&route, 1_000, 1_000, &salt);
+
+        assert_ok!(DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            DAVE,
+            SAMPLE_LP_TOKEN
+        ));
+        assert_ok!(AMMRoute::whitelist_route(
+            RuntimeOrigin::root(),
+            route.clone()
+        ));
+        assert_ok!(AMMRoute::commit_swap(
+            RuntimeOrigin::signed(ALICE),
+            commitment
+        ));
+
+        System::set_block_number(2);
+        assert_ok!(AMMRoute::reveal_swap(
+            RuntimeOrigin::signed(ALICE),
+            route,
+            1_000,
+            1_000,
+            salt
+        ));
+
+        System::set_block_number(3);
+        AMMRoute::on_initialize(3);
+
+        assert_eq!(Assets::balance(DOT, ALICE), 10_000);
+        assert_eq!(Assets::balance(SDOT, ALICE), 10_000);
+        System::assert_last_event(RuntimeEvent::AMMRoute(Event::RevealedSwapFailed(
+            ALICE,
+            commitment,
+            Error::<Runtime>::MinimumAmountOutViolated.into(),
+        )));
+    });
+}
+
+#[test]
+fn reveal_swap_should_be_limited() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let route = vec![DOT, SDOT];
+        let salts = [[1u8; 32], [2u8; 32], [3u8; 32]];
+
+        assert_ok!(AMMRoute::whitelist_route(
+            RuntimeOrigin::root(),
+            route.clone()
+        ));
+        for (trader, salt) in [ALICE, BOB, DAVE].into_iter().zip(salts) {
+            assert_ok!(AMMRoute::commit_swap(
+                RuntimeOrigin::signed(trader),
+                AMMRoute::swap_commitment(&route, 1_000, 900, &salt)
+            ));
+        }
+
+        System::set_block_number(2);
+        for (trader, salt) in [ALICE, BOB].into_iter().zip(salts) {
+            assert_ok!(AMMRoute::reveal_swap(
+                RuntimeOrigin::signed(trader),
+                route.clone(),
+                1_000,
+                900,
+                salt
+            ));
+        }
+        assert_noop!(
+            AMMRoute::reveal_swap(RuntimeOrigin::signed(DAVE), route, 1_000, 900, salts[2]),
+            Error::<Runtime>::TooManyRevealedSwaps
+        );
+    });
+}
+
+#[test]
+fn reveal_swap_should_only_trade_whitelisted_routes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let route = vec![DOT, SDOT];
+        let salt = [7u8; 32];
+        let commitment = AMMRoute::swap_commitment(&route, 1_000, 900, &salt);
+
+        assert_ok!(AMMRoute::commit_swap(
+            RuntimeOrigin::signed(ALICE),
+            commitment
+        ));
+        System::set_block_number(2);
+        assert_noop!(
+            AMMRoute::reveal_swap(
+                RuntimeOrigin::signed(ALICE),
+                route.clone(),
+                1_000,
+                900,
+                salt
+            ),
+            Error::<Runtime>::RouteNotWhitelisted
+        );
+
+        assert_noop!(
+            AMMRoute::whitelist_route(RuntimeOrigin::signed(ALICE), route.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AMMRoute::whitelist_route(RuntimeOrigin::root(), vec![DOT, SDOT, DOT]),
+            Error::<Runtime>::DuplicatedRoute
+        );
+        assert_ok!(AMMRoute::whitelist_route(
+            RuntimeOrigin::root(),
+            route.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::AMMRoute(Event::RouteWhitelisted(
+            route.clone(),
+        )));
+        assert_noop!(
+            AMMRoute::whitelist_route(RuntimeOrigin::root(), route.clone()),
+            Error::<Runtime>::RouteAlreadyWhitelisted
+        );
+
+        assert_ok!(AMMRoute::remove_whitelisted_route(
+            RuntimeOrigin::root(),
+            route.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::AMMRoute(Event::RouteRemovedFromWhitelist(
+            route.clone(),
+        )));
+        assert_noop!(
+            AMMRoute::remove_whitelisted_route(RuntimeOrigin::root(), route),
+            Error::<Runtime>::RouteNotWhitelisted
+        );
+    });
+}
+
+#[test]
+fn reveal_swap_should_not_work_if_commitment_expired() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let route = vec![DOT, SDOT];
+        let salt = [7u8; 32];
+        let commitment = AMMRoute::swap_commitment(&route, 1_000, 900, &salt);
+
+        assert_ok!(DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            DAVE,
+            SAMPLE_LP_TOKEN
+        ));
+        assert_ok!(AMMRoute::commit_swap(
+            RuntimeOrigin::signed(ALICE),
+            commitment
+        ));
+
+        System::set_block_number(1 + MaxRevealDelay::get() + 1);
+
+        assert_noop!(
+            AMMRoute::reveal_swap(RuntimeOrigin::signed(ALICE), route, 1_000, 900, salt),
+            Error::<Runtime>::CommitmentExpired
+        );
+
+        // committing again drops the expired commitment
+        assert_ok!(AMMRoute::commit_swap(
+            RuntimeOrigin::signed(ALICE),
+            commitment
+        ));
+        assert_eq!(
+            AMMRoute::swap_commitments(ALICE).into_inner(),
+            vec![(commitment, 1 + MaxRevealDelay::get() + 1)]
+        );
+    });
+}
+
+#[test]
+fn commit_swap_should_be_limited() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let route = vec![DOT, SDOT];
+        let first = AMMRoute::swap_commitment(&route, 1_000, 900, &[1u8; 32]);
+        let second = AMMRoute::swap_commitment(&route, 1_000, 900, &[2u8; 32]);
+        let third = AMMRoute::swap_commitment(&route, 1_000, 900, &[3u8; 32]);
+
+        assert_ok!(AMMRoute::commit_swap(RuntimeOrigin::signed(ALICE), first));
+        assert_noop!(
+            AMMRoute::commit_swap(RuntimeOrigin::signed(ALICE), first),
+            Error::<Runtime>::DuplicatedCommitment
+        );
+        assert_ok!(AMMRoute::commit_swap(RuntimeOrigin::signed(ALICE), second));
+        assert_noop!(
+            AMMRoute::commit_swap(RuntimeOrigin::signed(ALICE), third),
+            Error::<Runtime>::TooManyCommitments
+        );
+
+        // cancelling frees up room for another commitment
+        assert_noop!(
+            AMMRoute::cancel_swap_commitment(RuntimeOrigin::signed(ALICE), third),
+            Error::<Runtime>::CommitmentNotFound
+        );
+        assert_ok!(AMMRoute::cancel_swap_commitment(
+            RuntimeOrigin::signed(ALICE),
+            first
+        ));
+        System::assert_last_event(RuntimeEvent::AMMRoute(Event::SwapCommitmentCancelled(
+            ALICE, first,
+        )));
+        assert_ok!(AMMRoute::commit_swap(RuntimeOrigin::signed(ALICE), third));
+        assert_eq!(
+            AMMRoute::swap_commitments(ALICE).into_inner(),
+            vec![(second, 1), (third, 1)]
+        );
+    });
+}
+
+#[test]
+fn swaps_should_not_reenter_other_flows() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, SDOT),
+            (100_000_000, 100_000_000),
+            DAVE,
+            SAMPLE_LP_TOKEN
+        ));
+
+        // a swap dispatched from within the loans can't reach the router
+        {
+            let _loans = FlowGuard::enter(DefiFlow::Loans).unwrap();
+            assert_noop!(
+                AMMRoute::swap_exact_tokens_for_tokens(
+                    RuntimeOrigin::signed(ALICE),
+                    vec![DOT, SDOT],
+                    1_000,
+                    900
+                ),
+                DispatchError::Other("flow not composable with its caller")
+            );
+        }
+
+        // nor can the router reenter itself
+        {
+            let _router = FlowGuard::enter(DefiFlow::Router).unwrap();
+            assert_noop!(
+                AMMRoute::swap_exact_tokens_for_tokens(
+                    RuntimeOrigin::signed(ALICE),
+                    vec![DOT, SDOT],
+                    1_000,
+                    900
+                ),
+                DispatchError::Other("reentrant flow")
+            );
+        }
+
+        // the router swapping through the amm is an intended composition
+        assert!(active_flows().is_empty());
+        assert_ok!(AMMRoute::swap_exact_tokens_for_tokens(
+            RuntimeOrigin::signed(ALICE),
+            vec![DOT, SDOT],
+            1_000,
+            900
+        ));
+        assert!(active_flows().is_empty());
+    });
+}
+
+#[test]
+fn swap_should_use_conversion_when_it_beats_the_pool() {
+    new_test_ext().execute_with(|| {
+        let trader = ALICE;
+        assert_ok!(DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (DOT, SDOT),
+            (100_000_000, 90_000_000),
+            DAVE,
+            SAMPLE_LP_TOKEN
+        ));
+        let (venue, pool_amount_out) = AMMRoute::quote_hop_out((DOT, SDOT), 1_000).unwrap();
+        assert_eq!(venue, Venue::Amm);
+
+        // staking gives more than the pool
+        set_staking_rate(Some(Rate::one()));
+        assert_eq!(
+            AMMRoute::quote_hop_out((DOT, SDOT), 1_000),
+            Ok((Venue::Conversion, 1_000))
+        );
+        assert_ok!(AMMRoute::swap_exact_tokens_for_tokens(
+            RuntimeOrigin::signed(trader),
+            vec![DOT, SDOT],
+            1_000,
+            1_000,
+        ));
+        assert_eq!(Assets::balance(DOT, trader), 10_000 - 1_000);
+        assert_eq!(Assets::balance(SDOT, trader), 10_000 + 1_000);
+        assert_eq!(Assets::balance(DOT, DefaultAMM::account_id()), 100_000_000);
+
+        // exact amounts out are converted too
+        assert_ok!(AMMRoute::swap_tokens_for_exact_tokens(
+            RuntimeOrigin::signed(trader),
+            vec![DOT, SDOT],
+            1_000,
+            1_000,
+        ));
+        assert_eq!(Assets::balance(DOT, trader), 10_000 - 2_000);
+        assert_eq!(Assets::balance(SDOT, trader), 10_000 + 2_000);
+
+        // the pool is used again once it beats staking
+        set_staking_rate(Some(Rate::saturating_from_rational(1, 2)));
+        assert_eq!(
+            AMMRoute::quote_hop_out((DOT, SDOT), 1_000),
+            Ok((Venue::Amm, pool_amount_out))
+        );
+        assert_ok!(AMMRoute::swap_exact_tokens_for_tokens(
+            RuntimeOrigin::signed(trader),
+            vec![DOT, SDOT],
+            1_000,
+            pool_amount_out,
+        ));
+        assert_eq!(
+            Assets::balance(SDOT, trader),
+            10_000 + 2_000 + pool_amount_out
+        );
+        assert_eq!(
+            Assets::balance(DOT, DefaultAMM::account_id()),
+            100_000_000 + 1_000
+        );
+    });
+}
+
+#[test]
+fn routes_should_compose_conversions_and_pools() {
+    new_test_ext().execute_with(|| {
+        let trader = DAVE;
+        assert_ok!(DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (SDOT, KSM),
+            (100_000_000, 100_000_000),
+            DAVE,
+            SAMPLE_LP_TOKEN
+        ));
+        set_staking_rate(Some(Rate::one()));
+
+        let (route, amount_out) = AMMRoute::get_best_route(1_000, DOT, KSM, false).unwrap();
+        assert_eq!(route, vec![DOT, SDOT, KSM]);
+        let (_, ksm_out) = AMMRoute::quote_hop_out((SDOT, KSM), 1_000).unwrap();
+        assert_eq!(amount_out, ksm_out);
+
+        let ksm_before = Assets::balance(KSM, trader);
+        assert_ok!(AMMRoute::swap_exact_tokens_for_tokens(
+            RuntimeOrigin::signed(trader),
+            route,
+            1_000,
+            amount_out,
+        ));
+        assert_eq!(Assets::balance(KSM, trader), ksm_before + amount_out);
+
+        // conversions only go one way
+        assert_noop!(
+            AMMRoute::get_best_route(1_000, KSM, DOT, false),
+            Error::<Runtime>::NoPossibleRoute
+        );
+    });
+}
+
+#[test]
+fn swap_tokens_for_exact_tokens_should_not_use_balance_of_intermediate_asset() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DefaultAMM::create_pool(
+            RuntimeOrigin::signed(ALICE),
+            (SDOT, KSM),
+            (100_000_000, 100_000_000),
+            DAVE,
+            SAMPLE_LP_TOKEN
+        ));
+        // 1_004 SDOT are needed for 1_000 KSM, the conversion rounds its amount in down
+        // and delivers 1_002
+        set_staking_rate(Some(Rate::saturating_from_integer(3)));
+        assert_eq!(
+            AMMRoute::get_amounts_in(1_000, &[DOT, SDOT, KSM]),
+            Ok((vec![334, 1_004, 1_000], vec![Venue::Conversion, Venue::Amm]))
+        );
+
+        // the 2 SDOT missing aren't taken from the trader
+        assert_noop!(
+            AMMRoute::swap_tokens_for_exact_tokens(
+                RuntimeOrigin::signed(ALICE),
+                vec![DOT, SDOT, KSM],
+                1_000,
+                334,
+            ),
+            Error::<Runtime>::ExactAmountOutNotReached
+        );
+    });
+}
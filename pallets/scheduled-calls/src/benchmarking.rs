@@ -0,0 +1,96 @@
+//! Scheduled calls pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as ScheduledCalls;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::assert_ok;
+use frame_system::RawOrigin as SystemOrigin;
+use sp_runtime::traits::One;
+use sp_std::vec;
+
+const INITIAL_AMOUNT: u128 = 100_000_000_000_000_000;
+const EXECUTIONS: u32 = 10;
+
+fn remark<T: Config>() -> Box<<T as Config>::RuntimeCall> {
+    Box::new(frame_system::Call::<T>::remark { remark: vec![] }.into())
+}
+
+fn schedule_remark<T: Config>(owner: &T::AccountId, when: T::BlockNumber) -> ScheduledCallId {
+    let id = ScheduledCalls::<T>::next_call_id();
+    assert_ok!(ScheduledCalls::<T>::schedule(
+        SystemOrigin::Signed(owner.clone()).into(),
+        when,
+        Some((One::one(), EXECUTIONS)),
+        remark::<T>(),
+    ));
+    id
+}
+
+/// Fill the agenda of `when` up to one slot left
+fn fill_agenda<T: Config>(owner: &T::AccountId, when: T::BlockNumber) {
+    for _ in 1..T::MaxScheduledPerBlock::get() {
+        schedule_remark::<T>(owner, when);
+    }
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+    schedule {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, INITIAL_AMOUNT);
+        let when: T::BlockNumber = 10u32.into();
+        fill_agenda::<T>(&caller, when);
+        let id = ScheduledCalls::<T>::next_call_id();
+    }: _(SystemOrigin::Signed(caller.clone()), when, Some((One::one(), EXECUTIONS)), remark::<T>())
+    verify {
+        assert!(ScheduledCalls::<T>::scheduled(id).is_some());
+    }
+
+    cancel {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, INITIAL_AMOUNT);
+        let when: T::BlockNumber = 10u32.into();
+        fill_agenda::<T>(&caller, when);
+        let id = schedule_remark::<T>(&caller, when);
+        let refunded = ScheduledCalls::<T>::scheduled(id).unwrap().escrowed();
+    }: _(SystemOrigin::Signed(caller.clone()), id)
+    verify {
+        assert_last_event::<T>(Event::<T>::CallCancelled(id, refunded).into());
+    }
+
+    execute {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&owner, INITIAL_AMOUNT);
+        let id = schedule_remark::<T>(&owner, 2u32.into());
+        fill_agenda::<T>(&owner, 3u32.into());
+        frame_system::Pallet::<T>::set_block_number(2u32.into());
+    }: _(SystemOrigin::Signed(caller), id)
+    verify {
+        assert_last_event::<T>(Event::<T>::CallExecuted(id).into());
+    }
+
+    service_call {
+        let owner: T::AccountId = account("owner", 0, 0);
+        T::Currency::make_free_balance_be(&owner, INITIAL_AMOUNT);
+        let id = schedule_remark::<T>(&owner, 2u32.into());
+        fill_agenda::<T>(&owner, 3u32.into());
+        frame_system::Pallet::<T>::set_block_number(2u32.into());
+    }: {
+        ScheduledCalls::<T>::on_initialize(2u32.into());
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::CallExecuted(id).into());
+    }
+}
+
+impl_benchmark_test_suite!(
+    ScheduledCalls,
+    crate::mock::new_test_ext(),
+    crate::mock::Test
+);
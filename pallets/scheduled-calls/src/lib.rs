@@ -0,0 +1,353 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Scheduled Calls pallet
+//!
+//! ## Overview
+//!
+//! Users schedule calls to be dispatched on their behalf at a future block, once or
+//! recurrently, e.g. a weekly swap through the router or a monthly loan repayment. The
+//! fee of every execution is reserved from the owner when scheduling and released when
+//! the call is cancelled.
+//!
+//! Calls are dispatched in `on_initialize` of their block, within `MaxExecutionWeight`.
+//! Calls which don't fit stay due and can be executed by any keeper, who receives the
+//! prepaid fee of the execution. A failing call still consumes its execution and fee.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    dispatch::{DispatchErrorWithPostInfo, Dispatchable, GetDispatchInfo, PostDispatchInfo},
+    pallet_prelude::*,
+    traits::{BalanceStatus, Currency, OnUnbalanced, ReservableCurrency},
+    transactional,
+    weights::WeightToFee,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use primitives::Balance;
+use sp_runtime::{traits::Zero, ArithmeticError};
+use sp_std::boxed::Box;
+
+pub use pallet::*;
+pub use types::ScheduledCall;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub type ScheduledCallId = u64;
+
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+    <T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+pub type ScheduledCallOf<T> = ScheduledCall<
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::RuntimeCall,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The calls which can be scheduled
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + From<frame_system::Call<Self>>;
+
+        /// The currency execution fees are paid in
+        type Currency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+        /// Converts the weight of an execution to its fee
+        type WeightToFee: WeightToFee<Balance = Balance>;
+
+        /// Receives the fees of the calls executed in `on_initialize`
+        type FeeCollector: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Max number of calls scheduled at the same block
+        #[pallet::constant]
+        type MaxScheduledPerBlock: Get<u32>;
+
+        /// Max weight of a scheduled call
+        #[pallet::constant]
+        type MaxCallWeight: Get<Weight>;
+
+        /// Weight available to execute scheduled calls in `on_initialize`
+        #[pallet::constant]
+        type MaxExecutionWeight: Get<Weight>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Call was scheduled and the fees of its executions reserved
+        /// [id, owner, when, escrowed]
+        CallScheduled(ScheduledCallId, T::AccountId, T::BlockNumber, Balance),
+        /// Call was cancelled and the remaining fees unreserved
+        /// [id, refunded]
+        CallCancelled(ScheduledCallId, Balance),
+        /// Call was dispatched successfully
+        /// [id]
+        CallExecuted(ScheduledCallId),
+        /// Call was dispatched and failed
+        /// [id, error]
+        CallFailed(ScheduledCallId, DispatchError),
+        /// Call didn't fit in its block and is left to keepers
+        /// [id]
+        CallOverdue(ScheduledCallId),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Execution block isn't in the future
+        TargetBlockInPast,
+        /// Period or execution count is zero
+        InvalidSchedule,
+        /// Call is heavier than `MaxCallWeight`
+        CallTooHeavy,
+        /// Block already has the max number of scheduled calls
+        AgendaFull,
+        /// Scheduled call doesn't exist
+        CallNotFound,
+        /// Only the owner can cancel the call
+        NotOwner,
+        /// Call isn't due yet
+        NotDue,
+    }
+
+    /// Id of the next scheduled call
+    #[pallet::storage]
+    #[pallet::getter(fn next_call_id)]
+    pub type NextCallId<T: Config> = StorageValue<_, ScheduledCallId, ValueQuery>;
+
+    /// Scheduled calls
+    /// ScheduledCallId -> ScheduledCall
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled)]
+    pub type Scheduled<T: Config> =
+        StorageMap<_, Twox64Concat, ScheduledCallId, ScheduledCallOf<T>, OptionQuery>;
+
+    /// Calls to execute at each block
+    /// BlockNumber -> Vec<ScheduledCallId>
+    #[pallet::storage]
+    #[pallet::getter(fn agenda)]
+    pub type Agenda<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::BlockNumber,
+        BoundedVec<ScheduledCallId, T::MaxScheduledPerBlock>,
+        ValueQuery,
+    >;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let ids = Agenda::<T>::take(now);
+            let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+            let budget = T::MaxExecutionWeight::get();
+
+            for id in ids {
+                let scheduled = match Self::scheduled(id) {
+                    Some(scheduled) => scheduled,
+                    None => continue,
+                };
+                let weight = scheduled
+                    .call
+                    .get_dispatch_info()
+                    .weight
+                    .saturating_add(T::WeightInfo::service_call());
+                if consumed.saturating_add(weight).any_gt(budget) {
+                    Self::deposit_event(Event::<T>::CallOverdue(id));
+                    continue;
+                }
+                let used = Self::service_call(id, scheduled, now, None);
+                consumed.saturating_accrue(used.saturating_add(T::WeightInfo::service_call()));
+            }
+
+            consumed
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Dispatch `call` on behalf of the caller at block `when`, then every `period`
+        /// blocks until it was executed `count` times if `maybe_periodic` is set
+        #[pallet::weight(<T as Config>::WeightInfo::schedule())]
+        #[transactional]
+        pub fn schedule(
+            origin: OriginFor<T>,
+            when: T::BlockNumber,
+            maybe_periodic: Option<(T::BlockNumber, u32)>,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            ensure!(
+                when > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::TargetBlockInPast
+            );
+            let (period, remaining) = match maybe_periodic {
+                Some((period, count)) => {
+                    ensure!(!period.is_zero() && count > 0, Error::<T>::InvalidSchedule);
+                    (Some(period), count)
+                }
+                None => (None, 1),
+            };
+            let call_weight = call.get_dispatch_info().weight;
+            ensure!(
+                !call_weight.any_gt(T::MaxCallWeight::get()),
+                Error::<T>::CallTooHeavy
+            );
+
+            let fee = T::WeightToFee::weight_to_fee(
+                &call_weight.saturating_add(T::WeightInfo::service_call()),
+            );
+            let escrowed = fee
+                .checked_mul(remaining.into())
+                .ok_or(ArithmeticError::Overflow)?;
+            T::Currency::reserve(&owner, escrowed)?;
+
+            let id = NextCallId::<T>::mutate(|id| {
+                let call_id = *id;
+                *id = id.saturating_add(1);
+                call_id
+            });
+            Agenda::<T>::try_mutate(when, |agenda| agenda.try_push(id))
+                .map_err(|_| Error::<T>::AgendaFull)?;
+            Scheduled::<T>::insert(
+                id,
+                ScheduledCall {
+                    owner: owner.clone(),
+                    call: *call,
+                    next: when,
+                    period,
+                    remaining,
+                    fee,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::CallScheduled(id, owner, when, escrowed));
+            Ok(())
+        }
+
+        /// Cancel the remaining executions of a call and unreserve their fees
+        #[pallet::weight(<T as Config>::WeightInfo::cancel())]
+        #[transactional]
+        pub fn cancel(origin: OriginFor<T>, id: ScheduledCallId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let scheduled = Self::scheduled(id).ok_or(Error::<T>::CallNotFound)?;
+            ensure!(scheduled.owner == who, Error::<T>::NotOwner);
+
+            Agenda::<T>::mutate(scheduled.next, |agenda| agenda.retain(|&x| x != id));
+            Scheduled::<T>::remove(id);
+            let refunded = scheduled.escrowed();
+            T::Currency::unreserve(&who, refunded);
+
+            Self::deposit_event(Event::<T>::CallCancelled(id, refunded));
+            Ok(())
+        }
+
+        /// Execute a due call which didn't fit in its block, the caller receives the
+        /// prepaid fee of the execution
+        #[pallet::weight(
+            <T as Config>::WeightInfo::execute().saturating_add(T::MaxCallWeight::get())
+        )]
+        #[transactional]
+        pub fn execute(origin: OriginFor<T>, id: ScheduledCallId) -> DispatchResultWithPostInfo {
+            let keeper = ensure_signed(origin)?;
+            let scheduled = Self::scheduled(id).ok_or(Error::<T>::CallNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(scheduled.next <= now, Error::<T>::NotDue);
+
+            let used = Self::service_call(id, scheduled, now, Some(&keeper));
+            Ok(Some(<T as Config>::WeightInfo::execute().saturating_add(used)).into())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Pay the fee of a due call to `keeper` or the fee collector, schedule its next
+    /// execution then dispatch it. Returns the weight used by the call.
+    fn service_call(
+        id: ScheduledCallId,
+        mut scheduled: ScheduledCallOf<T>,
+        now: T::BlockNumber,
+        keeper: Option<&T::AccountId>,
+    ) -> Weight {
+        match keeper {
+            Some(keeper) => {
+                let _ = T::Currency::repatriate_reserved(
+                    &scheduled.owner,
+                    keeper,
+                    scheduled.fee,
+                    BalanceStatus::Free,
+                );
+            }
+            None => {
+                let (imbalance, _) = T::Currency::slash_reserved(&scheduled.owner, scheduled.fee);
+                T::FeeCollector::on_unbalanced(imbalance);
+            }
+        }
+
+        // Storage is updated before the dispatch so the call can cancel itself
+        let owner = scheduled.owner.clone();
+        let call = scheduled.call.clone();
+        match scheduled.following(now) {
+            Some(next) => {
+                scheduled.next = next;
+                scheduled.remaining = scheduled.remaining.saturating_sub(1);
+                // A full agenda leaves the call to keepers once it's due
+                if Agenda::<T>::try_mutate(next, |agenda| agenda.try_push(id)).is_err() {
+                    Self::deposit_event(Event::<T>::CallOverdue(id));
+                }
+                Scheduled::<T>::insert(id, scheduled);
+            }
+            None => Scheduled::<T>::remove(id),
+        }
+
+        let info = call.get_dispatch_info();
+        let result = call.dispatch(RawOrigin::Signed(owner).into());
+        let used = match &result {
+            Ok(post_info) | Err(DispatchErrorWithPostInfo { post_info, .. }) => {
+                post_info.calc_actual_weight(&info)
+            }
+        };
+        match result {
+            Ok(_) => Self::deposit_event(Event::<T>::CallExecuted(id)),
+            Err(err) => Self::deposit_event(Event::<T>::CallFailed(id, err.error)),
+        }
+
+        used
+    }
+}
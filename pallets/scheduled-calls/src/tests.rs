@@ -0,0 +1,234 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Perbill;
+
+fn remark() -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+        remark: b"dca".to_vec(),
+    }))
+}
+
+fn transfer(value: Balance) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer {
+        dest: BOB,
+        value,
+    }))
+}
+
+fn remarks() -> usize {
+    System::events()
+        .iter()
+        .filter(|record| {
+            matches!(
+                record.event,
+                RuntimeEvent::System(frame_system::Event::Remarked { .. })
+            )
+        })
+        .count()
+}
+
+#[test]
+fn schedule_should_reserve_fees_of_all_executions() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ScheduledCalls::schedule(
+            RuntimeOrigin::signed(ALICE),
+            3,
+            Some((5, 3)),
+            remark(),
+        ));
+        System::assert_last_event(RuntimeEvent::ScheduledCalls(Event::CallScheduled(
+            0,
+            ALICE,
+            3,
+            3 * FEE,
+        )));
+        assert_eq!(Balances::reserved_balance(ALICE), 3 * FEE);
+        assert_eq!(ScheduledCalls::agenda(3).into_inner(), vec![0]);
+        assert_eq!(ScheduledCalls::next_call_id(), 1);
+    });
+}
+
+#[test]
+fn schedule_should_be_validated() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ScheduledCalls::schedule(RuntimeOrigin::signed(ALICE), 1, None, remark()),
+            Error::<Test>::TargetBlockInPast
+        );
+        assert_noop!(
+            ScheduledCalls::schedule(RuntimeOrigin::signed(ALICE), 3, Some((0, 3)), remark()),
+            Error::<Test>::InvalidSchedule
+        );
+        assert_noop!(
+            ScheduledCalls::schedule(RuntimeOrigin::signed(ALICE), 3, Some((5, 0)), remark()),
+            Error::<Test>::InvalidSchedule
+        );
+        assert_noop!(
+            ScheduledCalls::schedule(
+                RuntimeOrigin::signed(ALICE),
+                3,
+                None,
+                Box::new(RuntimeCall::System(frame_system::Call::fill_block {
+                    ratio: Perbill::from_percent(100),
+                })),
+            ),
+            Error::<Test>::CallTooHeavy
+        );
+        assert_noop!(
+            ScheduledCalls::schedule(RuntimeOrigin::signed(ALICE), 3, Some((5, 200)), remark()),
+            pallet_balances::Error::<Test>::InsufficientBalance
+        );
+
+        for _ in 0..MaxScheduledPerBlock::get() {
+            assert_ok!(ScheduledCalls::schedule(
+                RuntimeOrigin::signed(ALICE),
+                3,
+                None,
+                remark()
+            ));
+        }
+        assert_noop!(
+            ScheduledCalls::schedule(RuntimeOrigin::signed(BOB), 3, None, remark()),
+            Error::<Test>::AgendaFull
+        );
+    });
+}
+
+#[test]
+fn recurring_call_should_execute_every_period() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ScheduledCalls::schedule(
+            RuntimeOrigin::signed(ALICE),
+            3,
+            Some((5, 3)),
+            remark(),
+        ));
+
+        run_to_block(3);
+        System::assert_last_event(RuntimeEvent::ScheduledCalls(Event::CallExecuted(0)));
+        assert_eq!(remarks(), 1);
+        assert_eq!(Balances::reserved_balance(ALICE), 2 * FEE);
+        assert_eq!(ScheduledCalls::scheduled(0).unwrap().next, 8);
+        assert_eq!(ScheduledCalls::agenda(8).into_inner(), vec![0]);
+
+        run_to_block(7);
+        assert_eq!(remarks(), 1);
+        run_to_block(13);
+        assert_eq!(remarks(), 3);
+
+        // Last execution removes the call and spends the whole escrow
+        assert_eq!(ScheduledCalls::scheduled(0), None);
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 1_000 - 3 * FEE);
+        assert_eq!(Balances::free_balance(TREASURY), 1 + 3 * FEE);
+        run_to_block(18);
+        assert_eq!(remarks(), 3);
+    });
+}
+
+#[test]
+fn failed_call_should_consume_its_execution() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ScheduledCalls::schedule(
+            RuntimeOrigin::signed(ALICE),
+            2,
+            Some((1, 2)),
+            transfer(2_000),
+        ));
+
+        run_to_block(2);
+        System::assert_last_event(RuntimeEvent::ScheduledCalls(Event::CallFailed(
+            0,
+            pallet_balances::Error::<Test>::InsufficientBalance.into(),
+        )));
+        assert_eq!(Balances::free_balance(BOB), 1_000);
+        assert_eq!(Balances::free_balance(TREASURY), 1 + FEE);
+        assert_eq!(ScheduledCalls::scheduled(0).unwrap().remaining, 1);
+    });
+}
+
+#[test]
+fn cancel_should_refund_remaining_fees() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ScheduledCalls::schedule(
+            RuntimeOrigin::signed(ALICE),
+            2,
+            Some((2, 3)),
+            transfer(100),
+        ));
+        run_to_block(2);
+        assert_eq!(Balances::free_balance(BOB), 1_100);
+
+        assert_noop!(
+            ScheduledCalls::cancel(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(ScheduledCalls::cancel(RuntimeOrigin::signed(ALICE), 0));
+        System::assert_last_event(RuntimeEvent::ScheduledCalls(Event::CallCancelled(
+            0,
+            2 * FEE,
+        )));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert!(ScheduledCalls::agenda(4).is_empty());
+        assert_noop!(
+            ScheduledCalls::cancel(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::CallNotFound
+        );
+
+        run_to_block(6);
+        assert_eq!(Balances::free_balance(BOB), 1_100);
+    });
+}
+
+#[test]
+fn call_can_cancel_itself() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ScheduledCalls::schedule(
+            RuntimeOrigin::signed(ALICE),
+            2,
+            Some((1, 3)),
+            Box::new(RuntimeCall::ScheduledCalls(crate::Call::cancel { id: 0 })),
+        ));
+
+        run_to_block(2);
+        System::assert_last_event(RuntimeEvent::ScheduledCalls(Event::CallExecuted(0)));
+        assert_eq!(ScheduledCalls::scheduled(0), None);
+        assert!(ScheduledCalls::agenda(3).is_empty());
+        // Only the fee of the executed call was spent
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 1_000 - FEE);
+    });
+}
+
+#[test]
+fn overdue_call_should_be_executed_by_keepers() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..3 {
+            assert_ok!(ScheduledCalls::schedule(
+                RuntimeOrigin::signed(ALICE),
+                2,
+                None,
+                remark()
+            ));
+        }
+        assert_noop!(
+            ScheduledCalls::execute(RuntimeOrigin::signed(BOB), 2),
+            Error::<Test>::NotDue
+        );
+
+        // Only two executions fit in `MaxExecutionWeight`
+        run_to_block(2);
+        System::assert_has_event(RuntimeEvent::ScheduledCalls(Event::CallOverdue(2)));
+        assert_eq!(remarks(), 2);
+        assert!(ScheduledCalls::scheduled(2).is_some());
+
+        assert_ok!(ScheduledCalls::execute(RuntimeOrigin::signed(BOB), 2));
+        System::assert_last_event(RuntimeEvent::ScheduledCalls(Event::CallExecuted(2)));
+        assert_eq!(remarks(), 3);
+        assert_eq!(ScheduledCalls::scheduled(2), None);
+        assert_eq!(Balances::free_balance(BOB), 1_000 + FEE);
+        assert_eq!(Balances::free_balance(TREASURY), 1 + 2 * FEE);
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+    });
+}
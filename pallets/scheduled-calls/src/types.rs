@@ -0,0 +1,57 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use primitives::Balance;
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, One, Saturating},
+    RuntimeDebug,
+};
+
+/// Call dispatched on behalf of `owner` once or every `period` blocks
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ScheduledCall<AccountId, Call, BlockNumber> {
+    pub owner: AccountId,
+    pub call: Call,
+    /// Block of the next execution
+    pub next: BlockNumber,
+    /// Blocks between two executions, `None` for a single execution
+    pub period: Option<BlockNumber>,
+    /// Executions left, including the next one
+    pub remaining: u32,
+    /// Fee reserved from the owner for each execution
+    pub fee: Balance,
+}
+
+impl<AccountId, Call, BlockNumber: AtLeast32BitUnsigned + Copy>
+    ScheduledCall<AccountId, Call, BlockNumber>
+{
+    /// Fees still held in escrow
+    pub fn escrowed(&self) -> Balance {
+        self.fee.saturating_mul(self.remaining.into())
+    }
+
+    /// Block of the execution following one done at `now`, `None` if it was the last one.
+    /// A late execution doesn't shift the following ones, they are kept on the initial
+    /// rhythm, but never scheduled before the next block.
+    pub fn following(&self, now: BlockNumber) -> Option<BlockNumber> {
+        if self.remaining <= 1 {
+            return None;
+        }
+        let period = self.period?;
+        let next = self.next.saturating_add(period);
+        Some(next.max(now.saturating_add(BlockNumber::one())))
+    }
+}
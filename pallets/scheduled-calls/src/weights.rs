@@ -0,0 +1,133 @@
+// This file is part of Parallel Finance.
+
+// Copyright (C) 2022 Parallel Finance Developer.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_scheduled_calls
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet-scheduled-calls
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./pallets/scheduled-calls/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions needed for pallet_scheduled_calls.
+pub trait WeightInfo {
+	fn schedule() -> Weight;
+	fn cancel() -> Weight;
+	fn execute() -> Weight;
+	fn service_call() -> Weight;
+}
+
+/// Weights for pallet_scheduled_calls using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: System Account (r:1 w:1)
+	// Storage: ScheduledCalls NextCallId (r:1 w:1)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	// Storage: ScheduledCalls Scheduled (r:0 w:1)
+	fn schedule() -> Weight {
+		Weight::from_ref_time(47_218_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: ScheduledCalls Scheduled (r:1 w:1)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn cancel() -> Weight {
+		Weight::from_ref_time(52_604_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: ScheduledCalls Scheduled (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	fn execute() -> Weight {
+		Weight::from_ref_time(68_931_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: ScheduledCalls Scheduled (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	fn service_call() -> Weight {
+		Weight::from_ref_time(44_187_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: System Account (r:1 w:1)
+	// Storage: ScheduledCalls NextCallId (r:1 w:1)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	// Storage: ScheduledCalls Scheduled (r:0 w:1)
+	fn schedule() -> Weight {
+		Weight::from_ref_time(47_218_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: ScheduledCalls Scheduled (r:1 w:1)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn cancel() -> Weight {
+		Weight::from_ref_time(52_604_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: ScheduledCalls Scheduled (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	fn execute() -> Weight {
+		Weight::from_ref_time(68_931_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: ScheduledCalls Scheduled (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	fn service_call() -> Weight {
+		Weight::from_ref_time(44_187_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+}
@@ -391,6 +391,28 @@ impl<T: Config> Pallet<T> {
         T::PalletId::get().into_account_truncating()
     }
 
+    /// Balances `who` holds in its ongoing streams, as `(stream_id, asset_id, balance, is_sender)`
+    /// where the balance of a sender is what has not been streamed yet
+    pub fn account_streams(
+        who: &AccountOf<T>,
+    ) -> Result<Vec<(StreamId, AssetIdOf<T>, BalanceOf<T>, bool)>, DispatchError> {
+        let mut streams = Vec::new();
+        for kind in [StreamKind::Send, StreamKind::Receive] {
+            let is_sender = kind == StreamKind::Send;
+            for stream_id in Self::stream_library(who, kind).unwrap_or_default() {
+                if let Some(stream) = Self::streams(stream_id) {
+                    streams.push((
+                        stream_id,
+                        stream.asset_id,
+                        stream.balance_of(who)?,
+                        is_sender,
+                    ));
+                }
+            }
+        }
+        Ok(streams)
+    }
+
     pub fn ensure_valid_duration(
         start_time: Timestamp,
         end_time: Timestamp,
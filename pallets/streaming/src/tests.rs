@@ -185,6 +185,44 @@ fn withdraw_works() {
     });
 }
 
+#[test]
+fn account_streams_works() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Streaming::account_streams(&ALICE), Ok(vec![]));
+
+        // Alice creates stream 100 DOT to Bob
+        assert_ok!(Streaming::create(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            dollar(100),
+            DOT,
+            6,
+            16,
+            true,
+        ));
+        assert_eq!(
+            Streaming::account_streams(&ALICE),
+            Ok(vec![(0, DOT, dollar(100), true)])
+        );
+        assert_eq!(
+            Streaming::account_streams(&BOB),
+            Ok(vec![(0, DOT, 0, false)])
+        );
+
+        // 6000(init) + 2000(ms)
+        TimestampPallet::set_timestamp(8000);
+        assert_eq!(
+            Streaming::account_streams(&ALICE),
+            Ok(vec![(0, DOT, dollar(80), true)])
+        );
+        assert_eq!(
+            Streaming::account_streams(&BOB),
+            Ok(vec![(0, DOT, dollar(20), false)])
+        );
+        assert_eq!(Streaming::account_streams(&DAVE), Ok(vec![]));
+    });
+}
+
 #[test]
 fn withdraw_with_slower_rate_works() {
     new_test_ext().execute_with(|| {
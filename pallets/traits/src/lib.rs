@@ -1,17 +1,29 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{dispatch::DispatchError, traits::tokens::Balance as BalanceT};
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    traits::{
+        tokens::{Balance as BalanceT, BalanceConversion},
+        Contains, Get,
+    },
+};
+use impl_trait_for_tuples::impl_for_tuples;
 use num_bigint::{BigUint, ToBigUint};
 use scale_info::TypeInfo;
-use sp_runtime::{traits::Zero, RuntimeDebug};
-use sp_std::prelude::*;
+use sp_runtime::{
+    traits::{CheckedDiv, Zero},
+    ArithmeticError, FixedPointNumber, RuntimeDebug, TokenError,
+};
+use sp_std::{marker::PhantomData, prelude::*};
 
 use primitives::{
-    CurrencyId, DerivativeIndex, PersistedValidationData, PriceDetail, Rate, Timestamp,
+    Balance, ChainId, ChainNonce, CurrencyId, DerivativeIndex, EraIndex, PersistedValidationData,
+    Price, PriceDetail, Rate, Ratio, Timestamp,
 };
 
 pub mod loans;
+pub mod reentrancy;
 pub mod ump;
 pub mod xcm;
 pub use loans::*;
@@ -20,10 +32,84 @@ pub trait EmergencyCallFilter<Call> {
     fn contains(call: &Call) -> bool;
 }
 
+/// Conditions under which pallets pause pre-declared calls without waiting for governance
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CircuitBreakerTrigger {
+    /// Oracle prices weren't updated in time
+    OracleStale,
+    /// Bridged amount reached the cap of a bridge token
+    BridgeCapBreached,
+    /// Exchange rate moved more than expected in one update
+    ExchangeRateAnomaly,
+}
+
+pub trait CircuitBreaker {
+    /// Pause the calls registered for `trigger`
+    fn trip(trigger: CircuitBreakerTrigger);
+}
+
+impl CircuitBreaker for () {
+    fn trip(_trigger: CircuitBreakerTrigger) {}
+}
+
+/// Tells whether accounts were identified on-chain
+pub trait IdentityVerifier<AccountId> {
+    /// `who`, or the parent of its sub-identity, has an identity judged by a registrar
+    fn has_verified_identity(who: &AccountId) -> bool;
+
+    /// Display name of the identity of `who`, `parent/sub` for a sub-identity
+    fn display_name(who: &AccountId) -> Option<Vec<u8>>;
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_verified_identity(_who: &AccountId) {}
+}
+
+impl<AccountId> IdentityVerifier<AccountId> for () {
+    fn has_verified_identity(_who: &AccountId) -> bool {
+        false
+    }
+
+    fn display_name(_who: &AccountId) -> Option<Vec<u8>> {
+        None
+    }
+}
+
 pub trait PriceFeeder {
     fn get_price(asset_id: &CurrencyId) -> Option<PriceDetail>;
 }
 
+/// Converts fees in `NativeCurrency` to the assets accepted by `Filter` at the prices
+/// of `Prices`, charging `Spread` on top of the converted amount
+pub struct PricedFeeConversion<Prices, NativeCurrency, Filter, Spread>(
+    PhantomData<(Prices, NativeCurrency, Filter, Spread)>,
+);
+impl<
+        Prices: PriceFeeder,
+        NativeCurrency: Get<CurrencyId>,
+        Filter: Contains<CurrencyId>,
+        Spread: Get<Ratio>,
+    > BalanceConversion<Balance, CurrencyId, Balance>
+    for PricedFeeConversion<Prices, NativeCurrency, Filter, Spread>
+{
+    type Error = DispatchError;
+
+    fn to_asset_balance(balance: Balance, asset_id: CurrencyId) -> Result<Balance, DispatchError> {
+        if !Filter::contains(&asset_id) {
+            return Err(TokenError::UnknownAsset.into());
+        }
+        // prices are normalized by decimals, so the ratio is in units of each asset
+        let (native_price, _) = Prices::get_price(&NativeCurrency::get())
+            .ok_or(DispatchError::Other("native price unavailable"))?;
+        let (price, _) =
+            Prices::get_price(&asset_id).ok_or(DispatchError::Other("asset price unavailable"))?;
+        let amount = native_price
+            .checked_div(&price)
+            .and_then(|rate| rate.checked_mul_int(balance))
+            .ok_or(ArithmeticError::Overflow)?;
+        Ok(amount.saturating_add(Spread::get().mul_ceil(amount)))
+    }
+}
+
 pub trait DecimalProvider<CurrencyId> {
     fn get_decimal(asset_id: &CurrencyId) -> Option<u8>;
 }
@@ -47,6 +133,11 @@ pub trait LiquidStakingCurrenciesProvider<CurrencyId> {
     fn get_liquid_currency() -> Option<CurrencyId>;
 }
 
+pub trait LiquidStaking<AccountId, Balance> {
+    /// Stake `amount` of the staking currency for `who`, returning the liquid currency minted
+    fn do_stake(who: &AccountId, amount: Balance) -> Result<Balance, DispatchError>;
+}
+
 pub trait VaultTokenExchangeRateProvider<CurrencyId> {
     fn get_exchange_rate(asset_id: &CurrencyId, init_rate: Rate) -> Option<Rate>;
 }
@@ -107,6 +198,40 @@ impl<CurrencyId, Balance: BalanceT, BlockNumber: BalanceT> Pool<CurrencyId, Bala
     }
 }
 
+/// Outcome of a swap through a single AMM pool
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct SwapQuote<Balance> {
+    /// Amount of the input asset paid into the pool
+    pub amount_in: Balance,
+    /// Amount of the output asset paid out of the pool
+    pub amount_out: Balance,
+    /// How much worse the execution price is than the pool price, fees excluded
+    pub price_impact: Ratio,
+    /// Fee kept by the pool, in the input asset
+    pub fee: Balance,
+    /// Part of `fee` minted to the protocol fee receiver
+    pub protocol_fee: Balance,
+}
+
+/// Liquidity an account provides to an AMM pool, amounts in the order the pool was
+/// given in
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PositionValue<Balance> {
+    /// Liquidity tokens held
+    pub liquidity: Balance,
+    /// What removing the liquidity would pay out
+    pub amounts: (Balance, Balance),
+    /// Part of `amounts` earned as fees since the liquidity was added
+    pub fees: (Balance, Balance),
+    /// How much less `amounts` without the fees is worth than holding what was
+    /// deposited, at the current pool price
+    pub impermanent_loss: Ratio,
+}
+
 /// Exported traits from our AMM pallet. These functions are to be used
 /// by the router to enable multi route token swaps
 pub trait AMM<AccountId, CurrencyId, Balance, BlockNumber> {
@@ -154,6 +279,95 @@ pub trait AMM<AccountId, CurrencyId, Balance, BlockNumber> {
     ) -> Option<Pool<CurrencyId, Balance, BlockNumber>>;
 }
 
+/// Turns the shares of a liquidity pool back into the pooled assets
+pub trait UnwindLiquidity<AccountId, CurrencyId, Balance> {
+    /// The assets pooled behind `lp_asset`, `None` if it isn't the share of a pool
+    fn pool_assets(lp_asset: CurrencyId) -> Option<(CurrencyId, CurrencyId)>;
+
+    /// Burns `liquidity` of `lp_asset` held by `who` and pays the pooled assets back to
+    /// `who`, returning the amounts in the order of `pool_assets`
+    fn unwind(
+        who: &AccountId,
+        lp_asset: CurrencyId,
+        liquidity: Balance,
+    ) -> Result<(Balance, Balance), DispatchError>;
+}
+
+impl<AccountId, CurrencyId, Balance> UnwindLiquidity<AccountId, CurrencyId, Balance> for () {
+    fn pool_assets(_lp_asset: CurrencyId) -> Option<(CurrencyId, CurrencyId)> {
+        None
+    }
+
+    fn unwind(
+        _who: &AccountId,
+        _lp_asset: CurrencyId,
+        _liquidity: Balance,
+    ) -> Result<(Balance, Balance), DispatchError> {
+        Err(DispatchError::Other("no liquidity pools"))
+    }
+}
+
+/// Adds assets to a liquidity pool in exchange for shares of it
+pub trait ProvideLiquidity<AccountId, CurrencyId, Balance> {
+    /// Adds at most `desired_amounts` of the assets of `pair` held by `who` to their pool
+    /// at the pool price, failing if less than `minimum_amounts` would be added. Returns
+    /// the amounts added in the order of `pair` and the liquidity minted to `who`
+    fn provide(
+        who: &AccountId,
+        pair: (CurrencyId, CurrencyId),
+        desired_amounts: (Balance, Balance),
+        minimum_amounts: (Balance, Balance),
+    ) -> Result<((Balance, Balance), Balance), DispatchError>;
+}
+
+impl<AccountId, CurrencyId, Balance> ProvideLiquidity<AccountId, CurrencyId, Balance> for () {
+    fn provide(
+        _who: &AccountId,
+        _pair: (CurrencyId, CurrencyId),
+        _desired_amounts: (Balance, Balance),
+        _minimum_amounts: (Balance, Balance),
+    ) -> Result<((Balance, Balance), Balance), DispatchError> {
+        Err(DispatchError::Other("no liquidity pools"))
+    }
+}
+
+/// Swaps through the router, along the best of the routes it knows
+pub trait SwapRouter<AccountId, CurrencyId, Balance> {
+    /// The route giving the most `asset_out` for `amount_in` of `asset_in`, with the amount
+    /// out
+    fn best_route(
+        asset_in: CurrencyId,
+        asset_out: CurrencyId,
+        amount_in: Balance,
+    ) -> Result<(Vec<CurrencyId>, Balance), DispatchError>;
+
+    /// The route needing the least `asset_in` for exact `amount_out` of `asset_out`, with
+    /// the amount in
+    fn best_route_for_exact_out(
+        asset_in: CurrencyId,
+        asset_out: CurrencyId,
+        amount_out: Balance,
+    ) -> Result<(Vec<CurrencyId>, Balance), DispatchError>;
+
+    /// Trades exact `amount_in` of `who` along `route`, failing if less than
+    /// `min_amount_out` comes out. Returns the amount out
+    fn swap_exact_in(
+        who: &AccountId,
+        route: Vec<CurrencyId>,
+        amount_in: Balance,
+        min_amount_out: Balance,
+    ) -> Result<Balance, DispatchError>;
+
+    /// Trades `who`'s assets along `route` for exact `amount_out`, failing if more than
+    /// `max_amount_in` would go in. Returns the amount in
+    fn swap_exact_out(
+        who: &AccountId,
+        route: Vec<CurrencyId>,
+        amount_out: Balance,
+        max_amount_in: Balance,
+    ) -> Result<Balance, DispatchError>;
+}
+
 /// Exported traits from StableSwap pallet. These functions are to be used
 /// by the router.
 pub trait StableSwap<AccountId, CurrencyId, Balance> {
@@ -191,6 +405,137 @@ pub trait StableSwap<AccountId, CurrencyId, Balance> {
     ) -> Result<(Balance, Balance), DispatchError>;
 }
 
+impl<AccountId, CurrencyId, Balance> StableSwap<AccountId, CurrencyId, Balance> for () {
+    fn get_amounts_out(
+        _amount_in: Balance,
+        _path: Vec<CurrencyId>,
+    ) -> Result<Vec<Balance>, DispatchError> {
+        Err(DispatchError::Other("no stable swap pools"))
+    }
+
+    fn get_amounts_in(
+        _amount_out: Balance,
+        _path: Vec<CurrencyId>,
+    ) -> Result<Vec<Balance>, DispatchError> {
+        Err(DispatchError::Other("no stable swap pools"))
+    }
+
+    fn swap(
+        _who: &AccountId,
+        _pair: (CurrencyId, CurrencyId),
+        _amount_in: Balance,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("no stable swap pools"))
+    }
+
+    fn get_pools() -> Result<Vec<(CurrencyId, CurrencyId)>, DispatchError> {
+        Ok(Vec::new())
+    }
+
+    fn get_reserves(
+        _asset_in: CurrencyId,
+        _asset_out: CurrencyId,
+    ) -> Result<(Balance, Balance), DispatchError> {
+        Err(DispatchError::Other("no stable swap pools"))
+    }
+}
+
+/// Converts one asset into another at a rate set by a protocol rather than by the
+/// reserves of a pool, e.g. staking for the liquid currency or supplying to a money
+/// market for its pToken. Used by the router next to the pools.
+///
+/// Conversions can be combined in a tuple, a pair is handled by the first conversion
+/// supporting it.
+pub trait ProtocolConversion<AccountId, CurrencyId, Balance> {
+    /// Pairs which can be converted, from `pair.0` into `pair.1`
+    fn conversions() -> Vec<(CurrencyId, CurrencyId)>;
+
+    /// Amount of `pair.1` received when converting `amount_in` of `pair.0`
+    fn quote_out(
+        pair: (CurrencyId, CurrencyId),
+        amount_in: Balance,
+    ) -> Result<Balance, DispatchError>;
+
+    /// Amount of `pair.0` to convert to receive at least `amount_out` of `pair.1`
+    fn quote_in(
+        pair: (CurrencyId, CurrencyId),
+        amount_out: Balance,
+    ) -> Result<Balance, DispatchError>;
+
+    /// Converts `amount_in` of `pair.0` held by `who` into `pair.1`, returning the amount
+    /// received
+    fn convert(
+        who: &AccountId,
+        pair: (CurrencyId, CurrencyId),
+        amount_in: Balance,
+    ) -> Result<Balance, DispatchError>;
+
+    /// Balance of `asset` which `who` can convert, `None` if the asset isn't kept by the
+    /// conversion itself
+    fn reducible_balance(_who: &AccountId, _asset: CurrencyId) -> Option<Balance> {
+        None
+    }
+}
+
+#[impl_for_tuples(8)]
+impl<AccountId, CurrencyId: Copy + PartialEq, Balance>
+    ProtocolConversion<AccountId, CurrencyId, Balance> for Tuple
+{
+    fn conversions() -> Vec<(CurrencyId, CurrencyId)> {
+        let mut conversions = Vec::new();
+        for_tuples!( #(
+            conversions.extend(Tuple::conversions());
+        )* );
+        conversions
+    }
+
+    fn quote_out(
+        pair: (CurrencyId, CurrencyId),
+        amount_in: Balance,
+    ) -> Result<Balance, DispatchError> {
+        for_tuples!( #(
+            if Tuple::conversions().contains(&pair) {
+                return Tuple::quote_out(pair, amount_in);
+            }
+        )* );
+        Err(DispatchError::Other("conversion not supported"))
+    }
+
+    fn quote_in(
+        pair: (CurrencyId, CurrencyId),
+        amount_out: Balance,
+    ) -> Result<Balance, DispatchError> {
+        for_tuples!( #(
+            if Tuple::conversions().contains(&pair) {
+                return Tuple::quote_in(pair, amount_out);
+            }
+        )* );
+        Err(DispatchError::Other("conversion not supported"))
+    }
+
+    fn convert(
+        who: &AccountId,
+        pair: (CurrencyId, CurrencyId),
+        amount_in: Balance,
+    ) -> Result<Balance, DispatchError> {
+        for_tuples!( #(
+            if Tuple::conversions().contains(&pair) {
+                return Tuple::convert(who, pair, amount_in);
+            }
+        )* );
+        Err(DispatchError::Other("conversion not supported"))
+    }
+
+    fn reducible_balance(who: &AccountId, asset: CurrencyId) -> Option<Balance> {
+        for_tuples!( #(
+            if let Some(balance) = Tuple::reducible_balance(who, asset) {
+                return Some(balance);
+            }
+        )* );
+        None
+    }
+}
+
 pub trait ConvertToBigUint {
     fn get_big_uint(&self) -> BigUint;
 }
@@ -206,6 +551,14 @@ pub trait ValidationDataProvider {
     fn validation_data() -> Option<PersistedValidationData>;
 }
 
+/// Relaychain era progression as tracked by the parachain
+pub trait EraProvider<BlockNumber> {
+    /// Index of the current relaychain era
+    fn current_era() -> EraIndex;
+    /// Relaychain block number the current era started at
+    fn era_start_block() -> BlockNumber;
+}
+
 /// Distribute liquidstaking asset to multi-accounts
 pub trait DistributionStrategy<Balance> {
     fn get_bond_distributions(
@@ -225,6 +578,33 @@ pub trait DistributionStrategy<Balance> {
     ) -> Vec<(DerivativeIndex, Balance)>;
 }
 
+/// Funds the rewards of a staking program
+pub trait RewardDispatcher<AccountId, CurrencyId, Balance, BlockNumber> {
+    /// Adds `amount` of `reward_asset` paid by `payer` to the rewards of the stakers of
+    /// `asset` locked for `lock_duration`, paid out over `reward_duration` blocks
+    fn dispatch_reward(
+        payer: &AccountId,
+        asset: CurrencyId,
+        reward_asset: CurrencyId,
+        lock_duration: BlockNumber,
+        amount: Balance,
+        reward_duration: BlockNumber,
+    ) -> DispatchResult;
+}
+
+/// Rewards accrued by accounts in a pallet, claimed together by the rewards aggregator
+pub trait RewardClaimer<AccountId> {
+    /// Claims the pending rewards of `who`, making at most `max_claims` claims, and
+    /// returns the number of claims made
+    fn claim_rewards(who: &AccountId, max_claims: u32) -> Result<u32, DispatchError>;
+}
+
+impl<AccountId> RewardClaimer<AccountId> for () {
+    fn claim_rewards(_who: &AccountId, _max_claims: u32) -> Result<u32, DispatchError> {
+        Ok(0)
+    }
+}
+
 pub trait Streaming<AccountId, CurrencyId, Balance> {
     fn create(
         sender: AccountId,
@@ -250,3 +630,194 @@ impl<AccountId, CurrencyId, Balance> Streaming<AccountId, CurrencyId, Balance> f
         Ok(())
     }
 }
+
+/// Priority of a deferred job, higher priority jobs run first
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum JobPriority {
+    High,
+    Normal,
+    Low,
+}
+
+impl JobPriority {
+    /// All the priorities, from the highest
+    pub fn all() -> [JobPriority; 3] {
+        [JobPriority::High, JobPriority::Normal, JobPriority::Low]
+    }
+}
+
+/// Defers heavy work to the weight left at the end of the blocks
+pub trait JobScheduler<Call> {
+    /// Schedule `call` to be dispatched as root, returns the id of the job
+    fn schedule(call: Call, priority: JobPriority) -> Result<u64, DispatchError>;
+}
+
+impl<Call> JobScheduler<Call> for () {
+    fn schedule(_call: Call, _priority: JobPriority) -> Result<u64, DispatchError> {
+        Err(DispatchError::Other("job scheduling is disabled"))
+    }
+}
+
+/// Kinds of income collected by the protocol
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum RevenueSource {
+    /// Liquid currency minted as commission on the staking rewards
+    StakingCommission,
+    /// Interest kept by the loans markets as reserves
+    LoansReserve,
+    /// Lp tokens minted as protocol fee of the amm pools
+    AmmProtocolFee,
+    /// Fee charged on the tokens teleported by the bridge
+    BridgeFee,
+}
+
+/// Records the income of the protocol
+pub trait RevenueRecorder {
+    fn record(source: RevenueSource, asset_id: CurrencyId, amount: Balance);
+}
+
+impl RevenueRecorder for () {
+    fn record(_source: RevenueSource, _asset_id: CurrencyId, _amount: Balance) {}
+}
+
+/// Products whose volume counts towards the referral tier of the referrer
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum VolumeSource {
+    /// Assets supplied to the loans markets
+    LoansSupply,
+    /// Assets borrowed from the loans markets
+    LoansBorrow,
+    /// Assets sold to the amm pools
+    AmmSwap,
+    /// Assets staked through liquid staking
+    Staking,
+}
+
+/// Attributes accounts and their volume to referrers
+pub trait Referrals<AccountId> {
+    /// Owner of the referral code `code`
+    fn referrer_of_code(code: &[u8]) -> Option<AccountId>;
+
+    /// Make the owner of `code` the referrer of `who`, if it has none yet
+    fn refer(who: &AccountId, code: &[u8]);
+
+    /// Record `amount` of `asset_id` traded by `who` in `source`
+    fn record_volume(who: &AccountId, source: VolumeSource, asset_id: CurrencyId, amount: Balance);
+}
+
+impl<AccountId> Referrals<AccountId> for () {
+    fn referrer_of_code(_code: &[u8]) -> Option<AccountId> {
+        None
+    }
+
+    fn refer(_who: &AccountId, _code: &[u8]) {}
+
+    fn record_volume(
+        _who: &AccountId,
+        _source: VolumeSource,
+        _asset_id: CurrencyId,
+        _amount: Balance,
+    ) {
+    }
+}
+
+/// Native protocol activity mirrored as EVM logs
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum MirroredEvent<AccountId> {
+    /// A borrow was liquidated in the loans markets
+    LiquidatedBorrow {
+        liquidator: AccountId,
+        borrower: AccountId,
+        liquidation_asset_id: CurrencyId,
+        collateral_asset_id: CurrencyId,
+        repay_amount: Balance,
+        collateral_amount: Balance,
+    },
+    /// Tokens were burned to be teleported to another chain
+    TeleportBurned {
+        from: AccountId,
+        dest_id: ChainId,
+        nonce: ChainNonce,
+        bridge_token_id: CurrencyId,
+        to: Vec<u8>,
+        amount: Balance,
+        fee: Balance,
+    },
+    /// Tokens teleported from another chain were minted
+    MaterializeMinted {
+        src_id: ChainId,
+        src_nonce: ChainNonce,
+        bridge_token_id: CurrencyId,
+        to: AccountId,
+        amount: Balance,
+    },
+}
+
+/// Mirrors native protocol activity to the EVM
+pub trait EventMirror<AccountId> {
+    fn mirror(event: MirroredEvent<AccountId>);
+}
+
+impl<AccountId> EventMirror<AccountId> for () {
+    fn mirror(_event: MirroredEvent<AccountId>) {}
+}
+
+/// Submits the prices of an oracle feeder, all at once
+pub trait OracleFeeder<AccountId> {
+    fn feed_values(who: AccountId, values: Vec<(CurrencyId, Price)>) -> DispatchResult;
+}
+
+impl<AccountId> OracleFeeder<AccountId> for () {
+    fn feed_values(_who: AccountId, _values: Vec<(CurrencyId, Price)>) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Assets whose prices a call of `who` relies on
+pub trait PriceDependencies<AccountId, Call> {
+    fn price_dependencies(who: &AccountId, call: &Call) -> Vec<CurrencyId>;
+}
+
+impl<AccountId, Call> PriceDependencies<AccountId, Call> for () {
+    fn price_dependencies(_who: &AccountId, _call: &Call) -> Vec<CurrencyId> {
+        Vec::new()
+    }
+}
@@ -13,8 +13,9 @@
 // limitations under the License.
 
 use codec::{Decode, Encode};
-use frame_support::dispatch::DispatchError;
-use primitives::{Rate, Ratio};
+use frame_support::dispatch::{DispatchError, DispatchResult};
+use impl_trait_for_tuples::impl_for_tuples;
+use primitives::{Price, Rate, Ratio};
 use scale_info::TypeInfo;
 use sp_runtime::{FixedU128, RuntimeDebug};
 use sp_std::prelude::*;
@@ -59,6 +60,104 @@ pub trait LoansPositionDataProvider<CurrencyId, AccountId, Balance> {
     ) -> Result<Balance, DispatchError>;
 }
 
+/// Collateral kept by another pallet on behalf of the loans pallet, e.g. staked
+/// sTokens or crowdloan cTokens which don't have a money market of their own.
+///
+/// Adapters can be combined in a tuple, an asset is handled by the first adapter
+/// supporting it.
+pub trait CollateralAdapter<AccountId, CurrencyId, Balance> {
+    /// Whether `asset` can be deposited as collateral through the adapter
+    fn is_supported(asset: CurrencyId) -> bool;
+
+    /// Price of the smallest unit of `asset`, in the same format as the loans prices
+    fn price(asset: CurrencyId) -> Result<Price, DispatchError>;
+
+    /// Collateral factor `asset` currently has out of the `registered` one, e.g. cTokens
+    /// can be borrowed against more as their lease gets closer to its end
+    fn collateral_factor(_asset: CurrencyId, registered: Ratio) -> Ratio {
+        registered
+    }
+
+    /// Takes custody of `amount` of `asset` which `who` deposits as collateral
+    fn on_deposit(who: &AccountId, asset: CurrencyId, amount: Balance) -> DispatchResult;
+
+    /// Releases `amount` of `asset` which `who` withdraws from the collateral
+    fn on_withdraw(who: &AccountId, asset: CurrencyId, amount: Balance) -> DispatchResult;
+
+    /// Hands `amount` of `asset` deposited by `borrower` over to `to` when the
+    /// borrower is liquidated
+    fn seize(
+        borrower: &AccountId,
+        to: &AccountId,
+        asset: CurrencyId,
+        amount: Balance,
+    ) -> DispatchResult;
+}
+
+#[impl_for_tuples(8)]
+impl<AccountId, CurrencyId: Copy, Balance: Copy> CollateralAdapter<AccountId, CurrencyId, Balance>
+    for Tuple
+{
+    fn is_supported(asset: CurrencyId) -> bool {
+        for_tuples!( #(
+            if Tuple::is_supported(asset) {
+                return true;
+            }
+        )* );
+        false
+    }
+
+    fn price(asset: CurrencyId) -> Result<Price, DispatchError> {
+        for_tuples!( #(
+            if Tuple::is_supported(asset) {
+                return Tuple::price(asset);
+            }
+        )* );
+        Err(DispatchError::Other("collateral not supported"))
+    }
+
+    fn collateral_factor(asset: CurrencyId, registered: Ratio) -> Ratio {
+        for_tuples!( #(
+            if Tuple::is_supported(asset) {
+                return Tuple::collateral_factor(asset, registered);
+            }
+        )* );
+        registered
+    }
+
+    fn on_deposit(who: &AccountId, asset: CurrencyId, amount: Balance) -> DispatchResult {
+        for_tuples!( #(
+            if Tuple::is_supported(asset) {
+                return Tuple::on_deposit(who, asset, amount);
+            }
+        )* );
+        Err(DispatchError::Other("collateral not supported"))
+    }
+
+    fn on_withdraw(who: &AccountId, asset: CurrencyId, amount: Balance) -> DispatchResult {
+        for_tuples!( #(
+            if Tuple::is_supported(asset) {
+                return Tuple::on_withdraw(who, asset, amount);
+            }
+        )* );
+        Err(DispatchError::Other("collateral not supported"))
+    }
+
+    fn seize(
+        borrower: &AccountId,
+        to: &AccountId,
+        asset: CurrencyId,
+        amount: Balance,
+    ) -> DispatchResult {
+        for_tuples!( #(
+            if Tuple::is_supported(asset) {
+                return Tuple::seize(borrower, to, asset, amount);
+            }
+        )* );
+        Err(DispatchError::Other("collateral not supported"))
+    }
+}
+
 pub trait LoansMarketDataProvider<CurrencyId, Balance> {
     fn get_market_info(asset_id: CurrencyId) -> Result<MarketInfo, DispatchError>;
     fn get_market_status(asset_id: CurrencyId) -> Result<MarketStatus<Balance>, DispatchError>;
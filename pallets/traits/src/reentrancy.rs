@@ -0,0 +1,92 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Guard against DeFi flows reentering each other within one extrinsic.
+//!
+//! Every entry point of a flow holds a `FlowGuard` while it runs. A flow is refused
+//! when it is already running, and a flow may only run inside another one if it
+//! names that caller explicitly, e.g. the AMM swapping on behalf of the router.
+//! The running flows are kept under a well-known key which is cleared once the
+//! outermost guard is dropped, so nothing is left in the state.
+
+use codec::{Decode, Encode};
+use frame_support::{dispatch::DispatchError, storage::unhashed};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// Key of the running flows, under `:` like the transaction level of `frame_support`
+const ACTIVE_FLOWS_KEY: &[u8] = b":parallel:active_flows:";
+
+/// Flows nested deeper than this are refused whatever their callers
+pub const MAX_FLOW_DEPTH: usize = 4;
+
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+pub enum DefiFlow {
+    Loans,
+    Amm,
+    Router,
+    /// Calls dispatched by the EVM precompiles
+    Evm,
+}
+
+/// Marks `DefiFlow` as running until it is dropped
+#[must_use = "the flow is left as soon as the guard is dropped"]
+pub struct FlowGuard(DefiFlow);
+
+impl FlowGuard {
+    /// Enter `flow`, which may not run inside any other flow
+    pub fn enter(flow: DefiFlow) -> Result<Self, DispatchError> {
+        Self::enter_within(flow, &[])
+    }
+
+    /// Enter `flow`, which may run inside the flows of `callers` or on its own
+    pub fn enter_within(flow: DefiFlow, callers: &[DefiFlow]) -> Result<Self, DispatchError> {
+        let mut flows = active_flows();
+        if flows.contains(&flow) {
+            return Err(DispatchError::Other("reentrant flow"));
+        }
+        if let Some(caller) = flows.last() {
+            if !callers.contains(caller) {
+                return Err(DispatchError::Other("flow not composable with its caller"));
+            }
+        }
+        if flows.len() >= MAX_FLOW_DEPTH {
+            return Err(DispatchError::Other("flow depth exceeded"));
+        }
+
+        flows.push(flow);
+        unhashed::put(ACTIVE_FLOWS_KEY, &flows);
+        Ok(Self(flow))
+    }
+}
+
+impl Drop for FlowGuard {
+    fn drop(&mut self) {
+        let mut flows = active_flows();
+        if let Some(index) = flows.iter().rposition(|flow| *flow == self.0) {
+            flows.truncate(index);
+        }
+        if flows.is_empty() {
+            unhashed::kill(ACTIVE_FLOWS_KEY);
+        } else {
+            unhashed::put(ACTIVE_FLOWS_KEY, &flows);
+        }
+    }
+}
+
+/// Flows running in the current extrinsic, the innermost last
+pub fn active_flows() -> Vec<DefiFlow> {
+    unhashed::get_or_default(ACTIVE_FLOWS_KEY)
+}
@@ -99,6 +99,65 @@ pub enum StakingCall<T: Config> {
     Rebond(StakingRebondCall),
 }
 
+/// Identifier of a relaychain nomination pool
+pub type PoolId = u32;
+
+/// Where the extra bond of a nomination pool member comes from
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum PoolBondExtra<Balance> {
+    /// Take from the free balance of the member.
+    FreeBalance(Balance),
+    /// Take the pending rewards of the member.
+    Rewards,
+}
+
+/// Relaychain nomination_pools.join call arguments
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct NominationPoolsJoinCall {
+    /// Bond amount
+    #[codec(compact)]
+    pub amount: u128,
+    /// The pool to join
+    pub pool_id: PoolId,
+}
+
+/// Relaychain nomination_pools.bond_extra call arguments
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct NominationPoolsBondExtraCall {
+    /// Source of the extra bond
+    pub extra: PoolBondExtra<u128>,
+}
+
+/// Relaychain nomination_pools.unbond call arguments
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct NominationPoolsUnbondCall<T: Config> {
+    /// Member account
+    pub member_account: <T::Lookup as StaticLookup>::Source,
+    /// Points to unbond
+    #[codec(compact)]
+    pub unbonding_points: u128,
+}
+
+/// Relaychain nomination_pools.withdraw_unbonded call arguments
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct NominationPoolsWithdrawUnbondedCall<T: Config> {
+    /// Member account
+    pub member_account: <T::Lookup as StaticLookup>::Source,
+    pub num_slashing_spans: u32,
+}
+
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum NominationPoolsCall<T: Config> {
+    #[codec(index = 0)]
+    Join(NominationPoolsJoinCall),
+    #[codec(index = 1)]
+    BondExtra(NominationPoolsBondExtraCall),
+    #[codec(index = 3)]
+    Unbond(NominationPoolsUnbondCall<T>),
+    #[codec(index = 5)]
+    WithdrawUnbonded(NominationPoolsWithdrawUnbondedCall<T>),
+}
+
 /// Relaychain balances.transfer_keep_alive call arguments
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct BalancesTransferKeepAliveCall<T: Config> {
@@ -256,6 +315,47 @@ pub enum UtilityCall<RelaychainCall> {
     BatchAll(UtilityBatchAllCall<RelaychainCall>),
 }
 
+/// Relaychain hrmp.hrmp_init_open_channel call arguments
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct HrmpInitOpenChannelCall {
+    /// The parachain the channel is opened to
+    pub recipient: ParaId,
+    /// Max number of messages in the channel
+    pub proposed_max_capacity: u32,
+    /// Max size of a message in the channel
+    pub proposed_max_message_size: u32,
+}
+
+/// Relaychain hrmp.hrmp_accept_open_channel call arguments
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct HrmpAcceptOpenChannelCall {
+    /// The parachain which requested the channel
+    pub sender: ParaId,
+}
+
+/// Unique identifier of an hrmp channel
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct HrmpChannelId {
+    pub sender: ParaId,
+    pub recipient: ParaId,
+}
+
+/// Relaychain hrmp.hrmp_close_channel call arguments
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct HrmpCloseChannelCall {
+    pub channel_id: HrmpChannelId,
+}
+
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum HrmpCall {
+    #[codec(index = 0)]
+    InitOpenChannel(HrmpInitOpenChannelCall),
+    #[codec(index = 1)]
+    AcceptOpenChannel(HrmpAcceptOpenChannelCall),
+    #[codec(index = 2)]
+    CloseChannel(HrmpCloseChannelCall),
+}
+
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum KusamaCall<T: Config> {
     #[codec(index = 0)]
@@ -268,6 +368,10 @@ pub enum KusamaCall<T: Config> {
     Proxy(Box<ProxyCall<Self>>),
     #[codec(index = 24)]
     Utility(Box<UtilityCall<Self>>),
+    #[codec(index = 41)]
+    NominationPools(NominationPoolsCall<T>),
+    #[codec(index = 60)]
+    Hrmp(HrmpCall),
     #[codec(index = 73)]
     Crowdloans(CrowdloansCall<T>),
 }
@@ -284,6 +388,10 @@ pub enum PolkadotCall<T: Config> {
     Utility(Box<UtilityCall<Self>>),
     #[codec(index = 29)]
     Proxy(Box<ProxyCall<Self>>),
+    #[codec(index = 39)]
+    NominationPools(NominationPoolsCall<T>),
+    #[codec(index = 60)]
+    Hrmp(HrmpCall),
     #[codec(index = 73)]
     Crowdloans(CrowdloansCall<T>),
 }
@@ -320,6 +428,14 @@ pub enum XcmCall {
     Proxy,
     AddProxy,
     RemoveProxy,
+    HrmpInitOpenChannel,
+    HrmpAcceptOpenChannel,
+    HrmpCloseChannel,
+    PoolJoin,
+    PoolBondExtra,
+    PoolUnbond,
+    PoolWithdrawUnbonded,
+    RescueFunds,
 }
 
 #[macro_export]
@@ -12,24 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::CurrencyId;
+use crate::{CurrencyId, PriceFeeder, AMM};
 
 use codec::{Decode, Encode};
 use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
     traits::{
         tokens::{
             fungibles::{Inspect, Mutate, Transfer},
             BalanceConversion,
         },
-        Get,
+        Contains, Get,
     },
     weights::constants::WEIGHT_PER_SECOND,
+    PalletId,
 };
-use primitives::ParaId;
+use impl_trait_for_tuples::impl_for_tuples;
+use primitives::{AccountId, Balance, BlockNumber, ParaId};
 use scale_info::TypeInfo;
 use sp_core::H256;
-use sp_runtime::traits::{BlakeTwo256, Convert, Hash as THash, SaturatedConversion, Zero};
-use sp_std::{borrow::Borrow, marker::PhantomData, result};
+use sp_runtime::{
+    traits::{BlakeTwo256, CheckedDiv, Convert, Hash as THash, SaturatedConversion, Zero},
+    FixedPointNumber, RuntimeDebug,
+};
+use sp_std::{borrow::Borrow, marker::PhantomData, result, vec::Vec};
 use xcm::latest::{
     prelude::*, AssetId as xcmAssetId, Error as XcmError, Fungibility, Junction::AccountId32,
     MultiLocation, NetworkId, Weight,
@@ -238,6 +244,242 @@ impl<
     }
 }
 
+/// Buys weight with the first fungible asset accepted by `Filter`, charging what
+/// `BaseUnitsPerSecond` of `BaseCurrency` is worth at the prices of `Prices`.
+/// Used as a fallback of `FirstAssetTrader` for assets without units per second
+pub struct PricedAssetTrader<
+    CurrencyIdConvert,
+    Prices,
+    Filter,
+    BaseCurrency,
+    BaseUnitsPerSecond,
+    R: TakeRevenue,
+>(
+    Weight,
+    Option<(MultiLocation, u128, u128)>,
+    PhantomData<(
+        CurrencyIdConvert,
+        Prices,
+        Filter,
+        BaseCurrency,
+        BaseUnitsPerSecond,
+        R,
+    )>,
+);
+impl<
+        CurrencyIdConvert: Convert<MultiLocation, Option<CurrencyId>>,
+        Prices: PriceFeeder,
+        Filter: Contains<CurrencyId>,
+        BaseCurrency: Get<CurrencyId>,
+        BaseUnitsPerSecond: Get<u128>,
+        R: TakeRevenue,
+    > PricedAssetTrader<CurrencyIdConvert, Prices, Filter, BaseCurrency, BaseUnitsPerSecond, R>
+{
+    /// Units per second of `currency_id` worth `BaseUnitsPerSecond` of `BaseCurrency`
+    fn units_per_second(currency_id: CurrencyId) -> Option<u128> {
+        priced_units_per_second::<Prices>(
+            currency_id,
+            BaseCurrency::get(),
+            BaseUnitsPerSecond::get(),
+        )
+    }
+}
+
+/// Units per second of `currency_id` worth `base_units_per_second` of `base_currency`
+fn priced_units_per_second<Prices: PriceFeeder>(
+    currency_id: CurrencyId,
+    base_currency: CurrencyId,
+    base_units_per_second: u128,
+) -> Option<u128> {
+    if currency_id == base_currency {
+        return Some(base_units_per_second);
+    }
+    // prices are normalized by decimals, so the ratio is in units of each asset
+    let (base_price, _) = Prices::get_price(&base_currency)?;
+    let (price, _) = Prices::get_price(&currency_id)?;
+    base_price
+        .checked_div(&price)?
+        .checked_mul_int(base_units_per_second)
+}
+
+impl<
+        CurrencyIdConvert: Convert<MultiLocation, Option<CurrencyId>>,
+        Prices: PriceFeeder,
+        Filter: Contains<CurrencyId>,
+        BaseCurrency: Get<CurrencyId>,
+        BaseUnitsPerSecond: Get<u128>,
+        R: TakeRevenue,
+    > WeightTrader
+    for PricedAssetTrader<CurrencyIdConvert, Prices, Filter, BaseCurrency, BaseUnitsPerSecond, R>
+{
+    fn new() -> Self {
+        PricedAssetTrader(0, None, PhantomData)
+    }
+
+    fn buy_weight(
+        &mut self,
+        weight: Weight,
+        payment: xcm_executor::Assets,
+    ) -> Result<xcm_executor::Assets, XcmError> {
+        let first_asset = payment
+            .fungible_assets_iter()
+            .next()
+            .ok_or(XcmError::TooExpensive)?;
+        let id = match first_asset.id {
+            xcmAssetId::Concrete(id) => id,
+            _ => return Err(XcmError::TooExpensive),
+        };
+        let currency_id = CurrencyIdConvert::convert(id.clone())
+            .filter(Filter::contains)
+            .ok_or(XcmError::TooExpensive)?;
+        let units_per_second = Self::units_per_second(currency_id).ok_or(XcmError::TooExpensive)?;
+        let amount = units_per_second.saturating_mul(weight as u128)
+            / (WEIGHT_PER_SECOND.ref_time() as u128);
+        if amount.is_zero() {
+            return Ok(payment);
+        }
+
+        let required = MultiAsset {
+            fun: Fungibility::Fungible(amount),
+            id: xcmAssetId::Concrete(id.clone()),
+        };
+        let unused = payment
+            .checked_sub(required)
+            .map_err(|_| XcmError::TooExpensive)?;
+        log::trace!(
+            target: "xcm::buy_weight::priced",
+            "currency_id: {:?}, amount: {:?}",
+            currency_id,
+            amount,
+        );
+
+        // Only the asset first paid with is refunded, same as FirstAssetTrader
+        match self.1.clone() {
+            Some((prev_id, prev_amount, units_per_second)) if prev_id == id => {
+                self.0 = self.0.saturating_add(weight);
+                self.1 = Some((id, prev_amount.saturating_add(amount), units_per_second));
+            }
+            Some(_) => {}
+            None => {
+                self.0 = self.0.saturating_add(weight);
+                self.1 = Some((id, amount, units_per_second));
+            }
+        }
+        Ok(unused)
+    }
+
+    fn refund_weight(&mut self, weight: Weight) -> Option<MultiAsset> {
+        let (id, prev_amount, units_per_second) = self.1.clone()?;
+        let weight = weight.min(self.0);
+        self.0 -= weight;
+        let amount = units_per_second.saturating_mul(weight as u128)
+            / (WEIGHT_PER_SECOND.ref_time() as u128);
+        self.1 = Some((
+            id.clone(),
+            prev_amount.saturating_sub(amount),
+            units_per_second,
+        ));
+        Some(MultiAsset {
+            fun: Fungibility::Fungible(amount),
+            id: xcmAssetId::Concrete(id),
+        })
+    }
+}
+
+impl<CurrencyIdConvert, Prices, Filter, BaseCurrency, BaseUnitsPerSecond, R: TakeRevenue> Drop
+    for PricedAssetTrader<CurrencyIdConvert, Prices, Filter, BaseCurrency, BaseUnitsPerSecond, R>
+{
+    fn drop(&mut self) {
+        if let Some((id, amount, _)) = self.1.clone() {
+            R::take_revenue((id, amount).into());
+        }
+    }
+}
+
+// Checks an asset can buy xcm execution before it is accepted as fee asset
+pub trait XcmFeeAssetValidator<AssetId> {
+    fn validate(asset_id: AssetId) -> DispatchResult;
+}
+
+impl<AssetId> XcmFeeAssetValidator<AssetId> for () {
+    fn validate(_: AssetId) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Accepts the assets `PricedAssetTrader` can price, whose pool with `BaseCurrency` holds at
+/// least `MinLiquidity` of it, and whose fee for `MinFeeWeight` isn't below their min balance,
+/// so that the fees taken as revenue can be deposited
+pub struct PricedFeeAssetValidator<
+    Prices,
+    Amm,
+    Assets,
+    BaseCurrency,
+    BaseUnitsPerSecond,
+    MinLiquidity,
+    MinFeeWeight,
+>(
+    PhantomData<(
+        Prices,
+        Amm,
+        Assets,
+        BaseCurrency,
+        BaseUnitsPerSecond,
+        MinLiquidity,
+        MinFeeWeight,
+    )>,
+);
+impl<
+        Prices: PriceFeeder,
+        Amm: AMM<AccountId, CurrencyId, Balance, BlockNumber>,
+        Assets: Inspect<AccountId, AssetId = CurrencyId, Balance = Balance>,
+        BaseCurrency: Get<CurrencyId>,
+        BaseUnitsPerSecond: Get<u128>,
+        MinLiquidity: Get<Balance>,
+        MinFeeWeight: Get<Weight>,
+    > XcmFeeAssetValidator<CurrencyId>
+    for PricedFeeAssetValidator<
+        Prices,
+        Amm,
+        Assets,
+        BaseCurrency,
+        BaseUnitsPerSecond,
+        MinLiquidity,
+        MinFeeWeight,
+    >
+{
+    fn validate(asset_id: CurrencyId) -> DispatchResult {
+        let base_currency = BaseCurrency::get();
+        let units_per_second =
+            priced_units_per_second::<Prices>(asset_id, base_currency, BaseUnitsPerSecond::get())
+                .ok_or(DispatchError::Other("fee asset has no price"))?;
+
+        if asset_id != base_currency {
+            let pool = Amm::get_pool_by_asset_pair((asset_id, base_currency))
+                .ok_or(DispatchError::Other("fee asset has no pool"))?;
+            // pools are keyed by the greater asset id first
+            let base_liquidity = if base_currency > asset_id {
+                pool.base_amount
+            } else {
+                pool.quote_amount
+            };
+            if base_liquidity < MinLiquidity::get() {
+                return Err(DispatchError::Other("fee asset is not liquid enough"));
+            }
+        }
+
+        let min_fee = units_per_second.saturating_mul(MinFeeWeight::get() as u128)
+            / (WEIGHT_PER_SECOND.ref_time() as u128);
+        if min_fee < Assets::minimum_balance(asset_id) {
+            return Err(DispatchError::Other(
+                "fee asset min balance is above its fees",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 // Defines the trait to obtain a generic AssetType from a generic AssetId and viceversa
 pub trait AssetTypeGetter<AssetId, AssetType> {
     // Get asset type from assetId
@@ -247,6 +489,21 @@ pub trait AssetTypeGetter<AssetId, AssetType> {
     fn get_asset_id(asset_type: AssetType) -> Option<AssetId>;
 }
 
+// Applies the existential deposit and sufficiency of an asset to the pallet holding its balances
+pub trait AssetConfigurator<AssetId, Balance> {
+    fn configure(
+        asset_id: AssetId,
+        existential_deposit: Balance,
+        is_sufficient: bool,
+    ) -> DispatchResult;
+}
+
+impl<AssetId, Balance> AssetConfigurator<AssetId, Balance> for () {
+    fn configure(_: AssetId, _: Balance, _: bool) -> DispatchResult {
+        Ok(())
+    }
+}
+
 // Defines the trait to obtain the units per second of a give asset_type for local execution
 // This parameter will be used to charge for fees upon asset_type deposit
 pub trait UnitsToWeightRatio<AssetType> {
@@ -256,6 +513,18 @@ pub trait UnitsToWeightRatio<AssetType> {
     fn get_units_per_second(asset_type: AssetType) -> Option<u128>;
 }
 
+// Sends local assets to a location on another chain, e.g. through xtokens
+pub trait XcmAssetTransfer<AccountId, CurrencyId, Balance> {
+    // Transfer `amount` of `currency_id` owned by `who` to `dest`
+    fn transfer(
+        who: AccountId,
+        currency_id: CurrencyId,
+        amount: Balance,
+        dest: MultiLocation,
+        dest_weight_limit: WeightLimit,
+    ) -> DispatchResult;
+}
+
 /// XCM fee depositor to which we implement the TakeRevenue trait
 /// It receives a fungibles::Mutate implemented argument, a matcher to convert MultiAsset into
 /// AssetId and amount, and the fee receiver account
@@ -655,3 +924,87 @@ where
         AssetIdInfoGetter::get_units_per_second(Self::convert(asset_type))
     }
 }
+
+/// Effects of executing an XCM locally without committing any state
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct XcmDryRunEffects<Event> {
+    /// Outcome of the execution
+    pub outcome: Outcome,
+    /// Events emitted during the execution
+    pub emitted_events: Vec<Event>,
+    /// Emitted events which moved assets, such as transfers, mints and burns
+    pub asset_movements: Vec<Event>,
+}
+
+/// Result of an outbound xcm as reported back through `QueryResponse`
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum XcmResponse {
+    /// The xcm was executed successfully
+    Success,
+    /// The xcm failed at the given instruction
+    Error(u32, XcmError),
+    /// No response arrived before the xcm ran out of retries
+    Timeout,
+}
+
+/// Typed callbacks a pallet registers for the responses of the xcm it sends
+/// through xcm-helper. The `query_id` is always the one the xcm was first
+/// sent with.
+pub trait XcmResponseCallback {
+    /// Id the outbound xcm of the pallet are registered under
+    fn callback_id() -> PalletId;
+
+    /// Whether a failed xcm should be sent again before calling `on_error`
+    fn retry_on_error() -> bool {
+        false
+    }
+
+    fn on_success(query_id: QueryId) -> DispatchResult;
+
+    fn on_error(query_id: QueryId, index: u32, error: XcmError) -> DispatchResult;
+
+    fn on_timeout(query_id: QueryId) -> DispatchResult;
+}
+
+/// Routes responses to the callbacks registered under `callback_id`
+pub trait XcmResponseHandler {
+    /// Returns `None` if no callbacks are registered under `callback_id`
+    fn retry_on_error(callback_id: PalletId) -> Option<bool>;
+
+    /// Returns `None` if no callbacks are registered under `callback_id`
+    fn on_response(
+        callback_id: PalletId,
+        query_id: QueryId,
+        response: XcmResponse,
+    ) -> Option<DispatchResult>;
+}
+
+#[impl_for_tuples(8)]
+#[tuple_types_custom_trait_bound(XcmResponseCallback)]
+impl XcmResponseHandler for Tuple {
+    fn retry_on_error(callback_id: PalletId) -> Option<bool> {
+        for_tuples!( #(
+            if Tuple::callback_id() == callback_id {
+                return Some(Tuple::retry_on_error());
+            }
+        )* );
+        None
+    }
+
+    fn on_response(
+        callback_id: PalletId,
+        query_id: QueryId,
+        response: XcmResponse,
+    ) -> Option<DispatchResult> {
+        for_tuples!( #(
+            if Tuple::callback_id() == callback_id {
+                return Some(match response {
+                    XcmResponse::Success => Tuple::on_success(query_id),
+                    XcmResponse::Error(index, error) => Tuple::on_error(query_id, index, error),
+                    XcmResponse::Timeout => Tuple::on_timeout(query_id),
+                });
+            }
+        )* );
+        None
+    }
+}
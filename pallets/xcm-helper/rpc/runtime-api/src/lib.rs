@@ -0,0 +1,45 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_traits::xcm::XcmDryRunEffects;
+use primitives::CurrencyId;
+use sp_runtime::DispatchError;
+use xcm::{VersionedMultiLocation, VersionedXcm};
+
+sp_api::decl_runtime_apis! {
+    pub trait XcmHelperApi<Balance> where
+        Balance: Codec, {
+        fn estimate_fee(
+            dest: VersionedMultiLocation,
+            message: VersionedXcm<()>,
+            fee_asset: CurrencyId,
+        ) -> Result<(u64, Balance), DispatchError>;
+
+        fn estimate_transfer_fee(
+            dest: VersionedMultiLocation,
+        ) -> Result<(u64, Balance), DispatchError>;
+    }
+
+    pub trait XcmDryRunApi<Call, Event> where
+        Call: Codec,
+        Event: Codec, {
+        fn dry_run_xcm(
+            origin: VersionedMultiLocation,
+            message: VersionedXcm<Call>,
+        ) -> Result<XcmDryRunEffects<Event>, DispatchError>;
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+pub use pallet_xcm_helper_rpc_runtime_api::XcmHelperApi as XcmHelperRuntimeApi;
+
+use codec::{Codec, Decode};
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use primitives::CurrencyId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use xcm::{VersionedMultiLocation, VersionedXcm};
+
+#[rpc(client, server)]
+pub trait XcmHelperApi<BlockHash, Balance>
+where
+    Balance: Codec + Copy + TryFrom<NumberOrHex>,
+{
+    /// Estimate the weight and fee of executing a SCALE encoded `VersionedXcm`
+    /// at a SCALE encoded `VersionedMultiLocation`.
+    #[method(name = "xcm_estimateFee")]
+    fn estimate_fee(
+        &self,
+        dest: Bytes,
+        message: Bytes,
+        fee_asset: CurrencyId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<(u64, NumberOrHex)>;
+
+    /// Estimate the weight and fee charged to transfer assets to a SCALE encoded
+    /// `VersionedMultiLocation`.
+    #[method(name = "xcm_estimateTransferFee")]
+    fn estimate_transfer_fee(
+        &self,
+        dest: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<(u64, NumberOrHex)>;
+}
+
+/// A struct that implements the [`XcmHelperApi`].
+pub struct XcmHelper<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> XcmHelper<C, B> {
+    /// Create new `XcmHelper` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+pub enum Error {
+    RuntimeError,
+    EstimateFeeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::EstimateFeeError => 2,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, Balance> XcmHelperApiServer<<Block as BlockT>::Hash, Balance> for XcmHelper<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: XcmHelperRuntimeApi<Block, Balance>,
+    Balance: Codec + Copy + TryFrom<NumberOrHex> + Into<NumberOrHex> + std::fmt::Display,
+{
+    fn estimate_fee(
+        &self,
+        dest: Bytes,
+        message: Bytes,
+        fee_asset: CurrencyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<(u64, NumberOrHex)> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(self.client.info().best_hash));
+        let dest: VersionedMultiLocation = decode_scale(&dest, "dest")?;
+        let message: VersionedXcm<()> = decode_scale(&message, "message")?;
+        let (weight, fee) = api
+            .estimate_fee(&at, dest, message, fee_asset)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(estimate_fee_error_into_rpc_error)?;
+        Ok((weight, try_into_rpc_balance(fee)?))
+    }
+
+    fn estimate_transfer_fee(
+        &self,
+        dest: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<(u64, NumberOrHex)> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(self.client.info().best_hash));
+        let dest: VersionedMultiLocation = decode_scale(&dest, "dest")?;
+        let (weight, fee) = api
+            .estimate_transfer_fee(&at, dest)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(estimate_fee_error_into_rpc_error)?;
+        Ok((weight, try_into_rpc_balance(fee)?))
+    }
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Runtime trapped",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+/// Converts a fee estimation error into an RPC error.
+fn estimate_fee_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::EstimateFeeError.into(),
+        "Not able to estimate xcm fee",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+fn decode_scale<T: Decode>(bytes: &Bytes, name: &str) -> Result<T, JsonRpseeError> {
+    T::decode(&mut &bytes[..]).map_err(|e| {
+        JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+            ErrorCode::InvalidParams.code(),
+            format!("Unable to decode {}: {:?}", name, e),
+            None::<()>,
+        )))
+    })
+}
+
+fn try_into_rpc_balance<T: std::fmt::Display + Copy + TryInto<NumberOrHex>>(
+    value: T,
+) -> Result<NumberOrHex, JsonRpseeError> {
+    value.try_into().map_err(|_| {
+        JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+            ErrorCode::InvalidParams.code(),
+            format!("{} doesn't fit in NumberOrHex representation", value),
+            None::<()>,
+        )))
+    })
+}
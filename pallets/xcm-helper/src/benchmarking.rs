@@ -2,7 +2,8 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::assert_ok;
 use frame_system::{self, RawOrigin as SystemOrigin};
 
 const XCM_WEIGHT_FEE: XcmWeightFeeMisc<Weight, Balance> = XcmWeightFeeMisc {
@@ -10,6 +11,8 @@ const XCM_WEIGHT_FEE: XcmWeightFeeMisc<Weight, Balance> = XcmWeightFeeMisc {
     fee: 50000000000u128,
 };
 
+const SEED: u32 = 0;
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -17,7 +20,8 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 benchmarks! {
     where_clause {
         where
-            <T as frame_system::Config>::RuntimeOrigin: From<pallet_xcm::Origin>
+            <T as frame_system::Config>::RuntimeOrigin: From<pallet_xcm::Origin>,
+            T: pallet_assets::Config<AssetId = CurrencyId, Balance = Balance>
     }
 
     update_xcm_weight_fee {
@@ -26,6 +30,46 @@ benchmarks! {
         assert_last_event::<T>(Event::XcmWeightFeeUpdated(XCM_WEIGHT_FEE).into())
     }
 
+    add_rescue_target {
+        let target: T::AccountId = account("target", 0, SEED);
+    }: _(SystemOrigin::Root, target.clone())
+    verify {
+        assert_last_event::<T>(Event::RescueTargetAdded(target).into())
+    }
+
+    remove_rescue_target {
+        let target: T::AccountId = account("target", 0, SEED);
+        assert_ok!(Pallet::<T>::add_rescue_target(SystemOrigin::Root.into(), target.clone()));
+    }: _(SystemOrigin::Root, target.clone())
+    verify {
+        assert_last_event::<T>(Event::RescueTargetRemoved(target).into())
+    }
+
+    rescue_funds {
+        let target: T::AccountId = account("target", 0, SEED);
+        let amount: Balance = 10_000_000_000_000;
+        let reason: BoundedVec<u8, T::MaxRescueReasonLength> =
+            vec![0u8; T::MaxRescueReasonLength::get() as usize].try_into().unwrap();
+        pallet_assets::Pallet::<T>::force_create(
+            SystemOrigin::Root.into(),
+            T::RelayCurrency::get(),
+            T::Lookup::unlookup(target.clone()),
+            true,
+            1,
+        )
+        .ok();
+        <T as Config>::Assets::mint_into(
+            T::RelayCurrency::get(),
+            &Pallet::<T>::account_id(),
+            XCM_WEIGHT_FEE.fee,
+        )
+        .unwrap();
+        assert_ok!(Pallet::<T>::update_xcm_weight_fee(SystemOrigin::Root.into(), XcmCall::RescueFunds, XCM_WEIGHT_FEE));
+        assert_ok!(Pallet::<T>::add_rescue_target(SystemOrigin::Root.into(), target.clone()));
+    }: _(SystemOrigin::Root, RescueSource::RelayDerivative(0), T::RelayCurrency::get(), amount, target.clone(), reason.clone())
+    verify {
+        assert_last_event::<T>(Event::FundsRescued(0, RescueSource::RelayDerivative(0), T::RelayCurrency::get(), amount, target, reason.into_inner()).into())
+    }
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -21,22 +21,46 @@
 
 use frame_support::{
     dispatch::{DispatchResult, GetDispatchInfo},
+    log,
     pallet_prelude::*,
-    traits::fungibles::{Inspect, Mutate, Transfer},
+    storage::{with_transaction, TransactionOutcome},
+    traits::{
+        fungibles::{Inspect, Mutate, Transfer},
+        Contains,
+    },
     transactional, PalletId,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
-use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, Convert, StaticLookup};
-use sp_std::{boxed::Box, prelude::*, vec, vec::Vec};
-use xcm::{latest::prelude::*, DoubleEncoded};
-use xcm_executor::traits::InvertLocation;
+use sp_core::H256;
+use sp_runtime::{
+    traits::{
+        AccountIdConversion, BlakeTwo256, BlockNumberProvider, Convert, Hash, Saturating,
+        StaticLookup, Zero,
+    },
+    FixedPointNumber, FixedU128,
+};
+use sp_std::{boxed::Box, marker::PhantomData, prelude::*, vec, vec::Vec};
+use xcm::{
+    latest::prelude::*, DoubleEncoded, VersionedMultiAssets, VersionedMultiLocation, VersionedXcm,
+};
+use xcm_executor::{
+    traits::{ClaimAssets, DropAssets, InvertLocation, ShouldExecute, WeightBounds, WeightTrader},
+    XcmExecutor,
+};
 
 pub use pallet::*;
-use pallet_traits::{switch_relay, ump::*};
+use pallet_traits::{
+    switch_relay,
+    ump::*,
+    xcm::{XcmDryRunEffects, XcmResponse, XcmResponseCallback, XcmResponseHandler},
+};
 use primitives::{AccountId, Balance, BlockNumber, CurrencyId, ParaId};
 
 mod benchmarking;
 
+/// Max number of origins governance can allow to send xcm
+pub const MAX_XCM_ALLOWED_ORIGINS: usize = 100;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -51,16 +75,133 @@ pub type AssetIdOf<T> =
 pub type BalanceOf<T> =
     <<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// Outbound xcm which is waiting for its response
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct OutboundXcm<BlockNumber, Balance> {
+    /// The query id the xcm was first sent with, callers track their requests by it
+    pub origin_query_id: QueryId,
+    /// Destination of the xcm
+    pub dest: MultiLocation,
+    /// The message without the error reporting appendix
+    pub message: Xcm<()>,
+    /// Fees burned from the pallet account each time the message is sent
+    pub fees: Balance,
+    /// Id of the callbacks the response is dispatched to
+    pub callback: PalletId,
+    /// How many times the message has been sent again
+    pub retries: u32,
+    /// Block number after which the query expires and a late response is ignored
+    pub timeout: BlockNumber,
+}
+
+pub type OutboundXcmOf<T> = OutboundXcm<BlockNumberFor<T>, BalanceOf<T>>;
+
+/// Assets trapped by a failed xcm execution
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct TrappedAssetsInfo {
+    /// Origin of the failed xcm, which is the only location able to claim the assets
+    pub origin: MultiLocation,
+    /// The trapped assets
+    pub assets: VersionedMultiAssets,
+    /// How many times the same assets were trapped for the same origin
+    pub count: u32,
+}
+
+/// Status of an hrmp channel this chain is one side of
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum HrmpChannelStatus {
+    /// Request to open the channel was sent to relaychain
+    OpenRequested,
+    /// Relaychain registered the request, waiting for the recipient to accept it
+    OpenPending,
+    /// Acceptance of the channel was sent to relaychain
+    AcceptRequested,
+    /// Channel was accepted by both sides
+    Open,
+    /// Request to close the channel was sent to relaychain
+    CloseRequested,
+}
+
+/// How an asset reserved on the asset hub is handled there
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct AssetHubAssetInfo<Balance> {
+    /// Min balance of the asset on the asset hub, smaller deposits would be lost
+    pub min_balance: Balance,
+    /// Whether holding the asset alone keeps an account alive on the asset hub
+    pub is_sufficient: bool,
+}
+
+/// Account governance can move funds out of
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum RescueSource<AccountId> {
+    /// Account on this chain, e.g. the sovereign account of a sibling parachain
+    Local(AccountId),
+    /// Sovereign account of this chain on relaychain
+    RelaySovereign,
+    /// Derivative account of the sovereign account on relaychain
+    RelayDerivative(u16),
+}
+
+/// Status of a fund rescue
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum RescueStatus {
+    /// Transfer was sent to relaychain, waiting for its response
+    Requested(QueryId),
+    /// Funds reached the target
+    Succeeded,
+    /// Relaychain failed to execute the transfer or did not respond in time
+    Failed,
+}
+
+/// Fund rescue kept on chain, so that incidents can be audited afterwards
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct FundsRescue<AccountId, AssetId, Balance, BlockNumber> {
+    pub source: RescueSource<AccountId>,
+    pub asset: AssetId,
+    pub amount: Balance,
+    /// Whitelisted account the funds were moved to
+    pub target: AccountId,
+    /// Why governance moved the funds
+    pub reason: Vec<u8>,
+    /// Block at which the rescue was dispatched
+    pub block_number: BlockNumber,
+    pub status: RescueStatus,
+}
+
+pub type FundsRescueOf<T> =
+    FundsRescue<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+
+impl HrmpChannelStatus {
+    /// Status of the channel once relaychain executed the requested operation
+    pub fn confirmed(self) -> Option<Self> {
+        match self {
+            Self::OpenRequested => Some(Self::OpenPending),
+            Self::AcceptRequested => Some(Self::Open),
+            Self::CloseRequested => None,
+            status => Some(status),
+        }
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+    use frame_system::{
+        ensure_signed,
+        pallet_prelude::{BlockNumberFor, OriginFor},
+    };
+    use pallet_xcm::ensure_response;
     use sp_runtime::traits::{Convert, Zero};
 
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_xcm::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        type RuntimeOrigin: IsType<<Self as frame_system::Config>::RuntimeOrigin>
+            + Into<Result<pallet_xcm::Origin, <Self as Config>::RuntimeOrigin>>;
+
+        type RuntimeCall: IsType<<Self as pallet_xcm::Config>::RuntimeCall> + From<Call<Self>>;
+
         /// Assets for deposit/withdraw assets to/from crowdloan account
         type Assets: Transfer<AccountIdOf<Self>, AssetId = CurrencyId, Balance = Balance>
             + Inspect<AccountIdOf<Self>, AssetId = CurrencyId, Balance = Balance>
@@ -100,6 +241,33 @@ pub mod pallet {
         /// Relay currency
         #[pallet::constant]
         type RelayCurrency: Get<AssetIdOf<Self>>;
+
+        /// Max number of times an outbound xcm is sent again after failure or timeout
+        #[pallet::constant]
+        type MaxXcmRetries: Get<u32>;
+
+        /// Callbacks the responses of outbound xcm are dispatched to
+        type ResponseHandler: XcmResponseHandler;
+
+        /// Returns the parachain ID we are running with.
+        #[pallet::constant]
+        type SelfParaId: Get<ParaId>;
+
+        /// Parachain ID of the asset hub, i.e. statemint or statemine
+        #[pallet::constant]
+        type AssetHubParaId: Get<ParaId>;
+
+        /// Convert `CurrencyId` to the `MultiLocation` of its reserve.
+        type CurrencyIdConvert: Convert<AssetIdOf<Self>, Option<MultiLocation>>;
+
+        /// Max number of instructions of an incoming xcm accepted by the executor,
+        /// governance can only lower it
+        #[pallet::constant]
+        type MaxXcmInstructions: Get<u32>;
+
+        /// Max length of the reason given for a fund rescue
+        #[pallet::constant]
+        type MaxRescueReasonLength: Get<u32>;
     }
 
     #[pallet::event]
@@ -107,6 +275,76 @@ pub mod pallet {
     pub enum Event<T: Config> {
         /// Xcm fee and weight updated
         XcmWeightFeeUpdated(XcmWeightFeeMisc<Weight, BalanceOf<T>>),
+        /// Outbound xcm was sent again after an error response
+        /// [old_query_id, new_query_id]
+        OutboundXcmRetried(QueryId, QueryId),
+        /// Outbound xcm used up all its retries
+        /// [query_id]
+        OutboundXcmFailed(QueryId),
+        /// Outbound xcm got no response in time, its query expired
+        /// [query_id]
+        OutboundXcmExpired(QueryId),
+        /// Outbound xcm was removed by governance
+        /// [query_id]
+        OutboundXcmCancelled(QueryId),
+        /// Response of an outbound xcm was dispatched to its callbacks
+        /// [responder, query_id, res]
+        ResponseReceived(Box<MultiLocation>, QueryId, Option<(u32, XcmError)>),
+        /// Trapped assets were claimed by their origin
+        /// [hash, beneficiary]
+        TrappedAssetsClaimed(H256, MultiLocation),
+        /// Trapped assets were swept by governance
+        /// [hash, beneficiary]
+        TrappedAssetsSwept(H256, MultiLocation),
+        /// Hrmp channel operation was sent to relaychain
+        /// [channel_id, status, query_id]
+        HrmpChannelRequested(HrmpChannelId, HrmpChannelStatus, QueryId),
+        /// Hrmp channel status changed
+        /// [channel_id, status]
+        HrmpChannelUpdated(HrmpChannelId, Option<HrmpChannelStatus>),
+        /// Asset reserved on the asset hub was configured
+        /// [asset, info]
+        AssetHubAssetUpdated(AssetIdOf<T>, Option<AssetHubAssetInfo<BalanceOf<T>>>),
+        /// Asset was sent back to its reserve on the asset hub
+        /// [sender, asset, amount, beneficiary, fee]
+        TransferredToAssetHub(
+            AccountIdOf<T>,
+            AssetIdOf<T>,
+            BalanceOf<T>,
+            AccountIdOf<T>,
+            BalanceOf<T>,
+        ),
+        /// Origins allowed to send xcm to this chain were updated, `None` allows all
+        /// [origins]
+        XcmAllowedOriginsUpdated(Option<Vec<MultiLocation>>),
+        /// Max number of instructions of incoming xcm was updated
+        /// [max_instructions]
+        XcmMaxInstructionsUpdated(Option<u32>),
+        /// Incoming xcm from a parachain was suspended
+        /// [para_id]
+        XcmOriginSuspended(ParaId),
+        /// Incoming xcm from a parachain was resumed
+        /// [para_id]
+        XcmOriginResumed(ParaId),
+        /// Account was whitelisted as a fund rescue target
+        /// [target]
+        RescueTargetAdded(AccountIdOf<T>),
+        /// Account was removed from the fund rescue targets
+        /// [target]
+        RescueTargetRemoved(AccountIdOf<T>),
+        /// Governance moved funds out of a sovereign or derivative account
+        /// [rescue_id, source, asset, amount, target, reason]
+        FundsRescued(
+            u32,
+            RescueSource<AccountIdOf<T>>,
+            AssetIdOf<T>,
+            BalanceOf<T>,
+            AccountIdOf<T>,
+            Vec<u8>,
+        ),
+        /// Relaychain responded to a fund rescue
+        /// [rescue_id, status]
+        FundsRescueUpdated(u32, RescueStatus),
     }
 
     #[pallet::storage]
@@ -114,6 +352,91 @@ pub mod pallet {
     pub type XcmWeightFee<T: Config> =
         StorageMap<_, Twox64Concat, XcmCall, XcmWeightFeeMisc<Weight, BalanceOf<T>>, ValueQuery>;
 
+    /// Outbound xcm waiting for their responses
+    /// QueryId -> OutboundXcm
+    #[pallet::storage]
+    #[pallet::getter(fn outbound_xcm)]
+    pub type OutboundXcms<T: Config> =
+        StorageMap<_, Twox64Concat, QueryId, OutboundXcmOf<T>, OptionQuery>;
+
+    /// Raw key of the outbound xcm `on_idle` resumes checking from
+    #[pallet::storage]
+    pub type OutboundXcmCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+    /// Assets trapped by failed xcm executions
+    /// Hash of (origin, assets) -> TrappedAssetsInfo
+    #[pallet::storage]
+    #[pallet::getter(fn trapped_assets)]
+    pub type TrappedAssets<T: Config> =
+        StorageMap<_, Identity, H256, TrappedAssetsInfo, OptionQuery>;
+
+    /// Hrmp channels managed by governance
+    /// HrmpChannelId -> HrmpChannelStatus
+    #[pallet::storage]
+    #[pallet::getter(fn hrmp_channel)]
+    pub type HrmpChannels<T: Config> =
+        StorageMap<_, Twox64Concat, HrmpChannelId, HrmpChannelStatus, OptionQuery>;
+
+    /// Hrmp channel operations waiting for their relaychain response
+    /// QueryId -> (HrmpChannelId, status before the operation)
+    #[pallet::storage]
+    #[pallet::getter(fn hrmp_request)]
+    pub type HrmpRequests<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        QueryId,
+        (HrmpChannelId, Option<HrmpChannelStatus>),
+        OptionQuery,
+    >;
+
+    /// Assets reserved on the asset hub which can be sent back to it
+    /// AssetId -> AssetHubAssetInfo
+    #[pallet::storage]
+    #[pallet::getter(fn asset_hub_asset)]
+    pub type AssetHubAssets<T: Config> =
+        StorageMap<_, Twox64Concat, AssetIdOf<T>, AssetHubAssetInfo<BalanceOf<T>>, OptionQuery>;
+
+    /// Origins allowed to send xcm to this chain, all origins are allowed if not set
+    #[pallet::storage]
+    #[pallet::getter(fn xcm_allowed_origins)]
+    pub type XcmAllowedOrigins<T: Config> = StorageValue<_, Vec<MultiLocation>, OptionQuery>;
+
+    /// Max number of instructions of incoming xcm, `MaxXcmInstructions` if not set
+    #[pallet::storage]
+    #[pallet::getter(fn xcm_max_instructions)]
+    pub type XcmMaxInstructions<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+    /// Parachains whose xcm are rejected, including those of their accounts
+    /// ParaId -> ()
+    #[pallet::storage]
+    #[pallet::getter(fn suspended_xcm_origin)]
+    pub type SuspendedXcmOrigins<T: Config> = StorageMap<_, Twox64Concat, ParaId, (), OptionQuery>;
+
+    /// Accounts governance can move rescued funds to
+    /// AccountId -> ()
+    #[pallet::storage]
+    #[pallet::getter(fn rescue_target)]
+    pub type RescueTargets<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, (), OptionQuery>;
+
+    /// Every fund rescue governance dispatched
+    /// RescueId -> FundsRescue
+    #[pallet::storage]
+    #[pallet::getter(fn funds_rescue)]
+    pub type FundsRescues<T: Config> =
+        StorageMap<_, Twox64Concat, u32, FundsRescueOf<T>, OptionQuery>;
+
+    /// Id of the next fund rescue
+    #[pallet::storage]
+    #[pallet::getter(fn next_rescue_id)]
+    pub type NextRescueId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Relaychain fund rescues waiting for their response
+    /// QueryId -> RescueId
+    #[pallet::storage]
+    #[pallet::getter(fn rescue_request)]
+    pub type RescueRequests<T: Config> = StorageMap<_, Twox64Concat, QueryId, u32, OptionQuery>;
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
     #[pallet::without_storage_info]
@@ -134,6 +457,121 @@ pub mod pallet {
         SendFailure,
         /// Can not convert account success
         ConvertAccountError,
+        /// Xcm version of the location or message is not supported
+        BadVersion,
+        /// The message could not be weighed by the executor
+        UnweighableMessage,
+        /// The asset cannot be used to pay for xcm execution
+        FeeAssetNotSupported,
+        /// Outbound xcm does not exist
+        OutboundXcmNotFound,
+        /// Outbound xcm has used up all its retries
+        TooManyRetries,
+        /// No callbacks are registered for the outbound xcm
+        ResponseHandlerNotFound,
+        /// Trapped assets do not exist
+        TrappedAssetsNotFound,
+        /// Trapped origin does not belong to a local account
+        BeneficiaryNotDeterminable,
+        /// Claiming the trapped assets failed
+        ClaimAssetsFailed,
+        /// Hrmp channel must be between this chain and another parachain
+        InvalidHrmpChannel,
+        /// Hrmp channel is already tracked
+        HrmpChannelAlreadyExists,
+        /// Hrmp channel status does not allow the operation
+        InvalidHrmpChannelStatus,
+        /// The asset is not reserved on the asset hub
+        NotAssetHubAsset,
+        /// The asset hub asset is not configured
+        AssetHubAssetNotSupported,
+        /// Amount is below the min balance of the asset on the asset hub
+        AmountBelowMinBalance,
+        /// Too many origins are allowed to send xcm
+        TooManyXcmOrigins,
+        /// Max number of instructions is zero or above `MaxXcmInstructions`
+        InvalidMaxInstructions,
+        /// Xcm from the parachain are already suspended
+        XcmOriginAlreadySuspended,
+        /// Xcm from the parachain are not suspended
+        XcmOriginNotSuspended,
+        /// A fund rescue must give its reason
+        EmptyRescueReason,
+        /// The account is not a whitelisted rescue target
+        RescueTargetNotWhitelisted,
+        /// The account is already a whitelisted rescue target
+        RescueTargetAlreadyWhitelisted,
+        /// Only relay currency can be rescued from relaychain accounts
+        InvalidRescueAsset,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let read_weight = T::DbWeight::get().reads(2);
+            let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+            let remove_weight = T::DbWeight::get().writes(1);
+            let expire_weight = <T as Config>::WeightInfo::on_response();
+            let now = T::BlockNumberProvider::current_block_number();
+            let mut consumed = cursor_weight;
+            if consumed.any_gt(remaining_weight) {
+                return Weight::zero();
+            }
+
+            // Resume where the last block stopped, so that every entry gets checked
+            let mut iter = match OutboundXcmCursor::<T>::take() {
+                Some(cursor) => OutboundXcms::<T>::iter_from(cursor),
+                None => OutboundXcms::<T>::iter(),
+            };
+            let mut answered = vec![];
+            let mut expired = vec![];
+            let mut exhausted = false;
+            let max_weight = read_weight
+                .saturating_add(remove_weight)
+                .saturating_add(expire_weight);
+            while !consumed.saturating_add(max_weight).any_gt(remaining_weight) {
+                let (query_id, outbound) = match iter.next() {
+                    Some(entry) => entry,
+                    None => {
+                        exhausted = true;
+                        break;
+                    }
+                };
+                consumed = consumed.saturating_add(read_weight);
+                if pallet_xcm::Pallet::<T>::query(query_id).is_none() {
+                    consumed = consumed.saturating_add(remove_weight);
+                    answered.push(query_id);
+                } else if outbound.timeout <= now {
+                    consumed = consumed.saturating_add(expire_weight);
+                    expired.push(query_id);
+                }
+            }
+            if !exhausted {
+                OutboundXcmCursor::<T>::put(iter.last_raw_key().to_vec());
+            }
+
+            // The response arrived but could not be dispatched to the callbacks
+            for query_id in answered {
+                OutboundXcms::<T>::remove(query_id);
+            }
+
+            // The message may still be executed, sending it again could execute it twice.
+            // Its query expires instead, the callbacks deciding how to compensate.
+            for query_id in expired {
+                if let Err(err) = Self::do_dispatch_response(query_id, XcmResponse::Timeout) {
+                    log::error!(
+                        target: "xcm-helper::on_idle",
+                        "failed to handle expired outbound xcm {:?}: {:?}",
+                        query_id,
+                        err,
+                    );
+                }
+                OutboundXcms::<T>::remove(query_id);
+                Self::deposit_event(Event::<T>::OutboundXcmExpired(query_id));
+            }
+
+            consumed
+        }
     }
 
     #[pallet::call]
@@ -158,6 +596,459 @@ pub mod pallet {
             Self::deposit_event(Event::<T>::XcmWeightFeeUpdated(xcm_weight_fee_misc));
             Ok(())
         }
+
+        /// Stop tracking an outbound xcm, e.g. after it was compensated manually.
+        /// A response arriving afterwards is ignored.
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_outbound_xcm())]
+        #[transactional]
+        pub fn cancel_outbound_xcm(origin: OriginFor<T>, query_id: QueryId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                OutboundXcms::<T>::contains_key(query_id),
+                Error::<T>::OutboundXcmNotFound
+            );
+            OutboundXcms::<T>::remove(query_id);
+            Self::deposit_event(Event::<T>::OutboundXcmCancelled(query_id));
+            Ok(())
+        }
+
+        /// Internal call which is expected to be triggered only by xcm instruction,
+        /// dispatches the response to the callbacks of the outbound xcm. Only an
+        /// error response makes the message be sent again, if its callbacks ask for it.
+        #[pallet::weight(<T as Config>::WeightInfo::on_response()
+            .saturating_add(<T as Config>::WeightInfo::resend_outbound_xcm()))]
+        #[transactional]
+        pub fn on_response(
+            origin: OriginFor<T>,
+            query_id: QueryId,
+            response: Response,
+        ) -> DispatchResult {
+            let responder = ensure_response(<T as Config>::RuntimeOrigin::from(origin.clone()))
+                .or_else(|_| {
+                    T::UpdateOrigin::ensure_origin(origin).map(|_| MultiLocation::here())
+                })?;
+            if let Response::ExecutionResult(res) = response {
+                let outbound =
+                    Self::outbound_xcm(query_id).ok_or(Error::<T>::OutboundXcmNotFound)?;
+                let retry = match res {
+                    Some(_) => T::ResponseHandler::retry_on_error(outbound.callback)
+                        .ok_or(Error::<T>::ResponseHandlerNotFound)?,
+                    None => false,
+                };
+                // a retried xcm is tracked under its new query id until the next response,
+                // the query of the failed one is answered already so it can't execute twice
+                if !(retry && Self::try_retry(query_id).is_ok()) {
+                    let response = match res {
+                        Some((index, error)) => XcmResponse::Error(index, error),
+                        None => XcmResponse::Success,
+                    };
+                    Self::do_dispatch_response(query_id, response)?;
+                    OutboundXcms::<T>::remove(query_id);
+                }
+
+                Self::deposit_event(Event::<T>::ResponseReceived(
+                    Box::new(responder),
+                    query_id,
+                    res,
+                ));
+            }
+            Ok(())
+        }
+
+        /// Claim assets trapped under the location of the caller's account
+        /// and deposit them to `beneficiary`
+        #[pallet::weight(<T as Config>::WeightInfo::claim_assets())]
+        #[transactional]
+        pub fn claim_assets(
+            origin: OriginFor<T>,
+            assets: Box<VersionedMultiAssets>,
+            beneficiary: Box<VersionedMultiLocation>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let assets: MultiAssets = (*assets).try_into().map_err(|()| Error::<T>::BadVersion)?;
+            let beneficiary: MultiLocation = (*beneficiary)
+                .try_into()
+                .map_err(|()| Error::<T>::BadVersion)?;
+            let hash = Self::do_claim_assets(
+                T::AccountIdToMultiLocation::convert(who),
+                assets,
+                beneficiary.clone(),
+            )?;
+            Self::deposit_event(Event::<T>::TrappedAssetsClaimed(hash, beneficiary));
+            Ok(())
+        }
+
+        /// Sweep trapped assets to `beneficiary`, or to the local account of the
+        /// trapped origin if no beneficiary is given
+        #[pallet::weight(<T as Config>::WeightInfo::sweep_trapped_assets())]
+        #[transactional]
+        pub fn sweep_trapped_assets(
+            origin: OriginFor<T>,
+            hash: H256,
+            beneficiary: Option<Box<VersionedMultiLocation>>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let trapped = Self::trapped_assets(hash).ok_or(Error::<T>::TrappedAssetsNotFound)?;
+            let beneficiary = match beneficiary {
+                Some(beneficiary) => (*beneficiary)
+                    .try_into()
+                    .map_err(|()| Error::<T>::BadVersion)?,
+                None => Self::local_account_location(&trapped.origin)
+                    .ok_or(Error::<T>::BeneficiaryNotDeterminable)?,
+            };
+            let assets: MultiAssets = trapped
+                .assets
+                .try_into()
+                .map_err(|()| Error::<T>::BadVersion)?;
+            Self::do_claim_assets(trapped.origin, assets, beneficiary.clone())?;
+            Self::deposit_event(Event::<T>::TrappedAssetsSwept(hash, beneficiary));
+            Ok(())
+        }
+
+        /// Request relaychain to open an hrmp channel from this chain to `recipient`
+        #[pallet::weight(<T as Config>::WeightInfo::open_hrmp_channel())]
+        #[transactional]
+        pub fn open_hrmp_channel(
+            origin: OriginFor<T>,
+            recipient: ParaId,
+            proposed_max_capacity: u32,
+            proposed_max_message_size: u32,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let channel_id = HrmpChannelId {
+                sender: T::SelfParaId::get(),
+                recipient,
+            };
+            Self::ensure_hrmp_channel(&channel_id)?;
+            ensure!(
+                !HrmpChannels::<T>::contains_key(channel_id),
+                Error::<T>::HrmpChannelAlreadyExists
+            );
+
+            let query_id = Self::do_hrmp_transact(
+                XcmCall::HrmpInitOpenChannel,
+                HrmpCall::InitOpenChannel(HrmpInitOpenChannelCall {
+                    recipient,
+                    proposed_max_capacity,
+                    proposed_max_message_size,
+                }),
+            )?;
+            Self::request_hrmp(channel_id, HrmpChannelStatus::OpenRequested, query_id);
+            Ok(())
+        }
+
+        /// Accept the hrmp channel `sender` requested to open to this chain
+        #[pallet::weight(<T as Config>::WeightInfo::accept_hrmp_channel())]
+        #[transactional]
+        pub fn accept_hrmp_channel(origin: OriginFor<T>, sender: ParaId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let channel_id = HrmpChannelId {
+                sender,
+                recipient: T::SelfParaId::get(),
+            };
+            Self::ensure_hrmp_channel(&channel_id)?;
+            ensure!(
+                !HrmpChannels::<T>::contains_key(channel_id),
+                Error::<T>::HrmpChannelAlreadyExists
+            );
+
+            let query_id = Self::do_hrmp_transact(
+                XcmCall::HrmpAcceptOpenChannel,
+                HrmpCall::AcceptOpenChannel(HrmpAcceptOpenChannelCall { sender }),
+            )?;
+            Self::request_hrmp(channel_id, HrmpChannelStatus::AcceptRequested, query_id);
+            Ok(())
+        }
+
+        /// Request relaychain to close an hrmp channel this chain is one side of.
+        ///
+        /// Channels opened before they were tracked here can be closed as well.
+        #[pallet::weight(<T as Config>::WeightInfo::close_hrmp_channel())]
+        #[transactional]
+        pub fn close_hrmp_channel(
+            origin: OriginFor<T>,
+            channel_id: HrmpChannelId,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::ensure_hrmp_channel(&channel_id)?;
+            ensure!(
+                matches!(
+                    Self::hrmp_channel(channel_id),
+                    None | Some(HrmpChannelStatus::OpenPending) | Some(HrmpChannelStatus::Open)
+                ),
+                Error::<T>::InvalidHrmpChannelStatus
+            );
+
+            let query_id = Self::do_hrmp_transact(
+                XcmCall::HrmpCloseChannel,
+                HrmpCall::CloseChannel(HrmpCloseChannelCall { channel_id }),
+            )?;
+            Self::request_hrmp(channel_id, HrmpChannelStatus::CloseRequested, query_id);
+            Ok(())
+        }
+
+        /// Overwrite the tracked status of an hrmp channel, e.g. once the
+        /// recipient accepted a channel or it was changed outside of this pallet
+        #[pallet::weight(<T as Config>::WeightInfo::set_hrmp_channel_status())]
+        #[transactional]
+        pub fn set_hrmp_channel_status(
+            origin: OriginFor<T>,
+            channel_id: HrmpChannelId,
+            status: Option<HrmpChannelStatus>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::ensure_hrmp_channel(&channel_id)?;
+            Self::update_hrmp_channel(channel_id, status);
+            Ok(())
+        }
+
+        /// Configure how an asset reserved on the asset hub is sent back to it,
+        /// or stop supporting it
+        #[pallet::weight(<T as Config>::WeightInfo::set_asset_hub_asset())]
+        #[transactional]
+        pub fn set_asset_hub_asset(
+            origin: OriginFor<T>,
+            asset: AssetIdOf<T>,
+            info: Option<AssetHubAssetInfo<BalanceOf<T>>>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            if info.is_some() {
+                Self::asset_hub_location(asset)?;
+            }
+
+            AssetHubAssets::<T>::set(asset, info);
+            Self::deposit_event(Event::<T>::AssetHubAssetUpdated(asset, info));
+            Ok(())
+        }
+
+        /// Send `amount` of an asset reserved on the asset hub to `beneficiary` there.
+        ///
+        /// The asset hub fee is charged in relay currency on top of `amount`.
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_to_asset_hub())]
+        #[transactional]
+        pub fn transfer_to_asset_hub(
+            origin: OriginFor<T>,
+            asset: AssetIdOf<T>,
+            amount: BalanceOf<T>,
+            beneficiary: AccountIdOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let fee = Self::do_transfer_to_asset_hub(&who, asset, amount, beneficiary.clone())?;
+            Self::deposit_event(Event::<T>::TransferredToAssetHub(
+                who,
+                asset,
+                amount,
+                beneficiary,
+                fee,
+            ));
+            Ok(())
+        }
+
+        /// Restrict the origins allowed to send xcm to this chain, or allow all of them.
+        ///
+        /// Local origins are always allowed.
+        #[pallet::weight(<T as Config>::WeightInfo::set_xcm_allowed_origins())]
+        #[transactional]
+        pub fn set_xcm_allowed_origins(
+            origin: OriginFor<T>,
+            origins: Option<Vec<VersionedMultiLocation>>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let origins = origins
+                .map(|origins| {
+                    ensure!(
+                        origins.len() <= MAX_XCM_ALLOWED_ORIGINS,
+                        Error::<T>::TooManyXcmOrigins
+                    );
+                    origins
+                        .into_iter()
+                        .map(|o| o.try_into().map_err(|()| Error::<T>::BadVersion))
+                        .collect::<Result<Vec<MultiLocation>, _>>()
+                })
+                .transpose()?;
+
+            XcmAllowedOrigins::<T>::set(origins.clone());
+            Self::deposit_event(Event::<T>::XcmAllowedOriginsUpdated(origins));
+            Ok(())
+        }
+
+        /// Lower the max number of instructions of incoming xcm, or restore the default
+        #[pallet::weight(<T as Config>::WeightInfo::set_xcm_max_instructions())]
+        #[transactional]
+        pub fn set_xcm_max_instructions(
+            origin: OriginFor<T>,
+            max_instructions: Option<u32>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            if let Some(max) = max_instructions {
+                ensure!(
+                    !max.is_zero() && max <= T::MaxXcmInstructions::get(),
+                    Error::<T>::InvalidMaxInstructions
+                );
+            }
+
+            XcmMaxInstructions::<T>::set(max_instructions);
+            Self::deposit_event(Event::<T>::XcmMaxInstructionsUpdated(max_instructions));
+            Ok(())
+        }
+
+        /// Reject all incoming xcm from a parachain, e.g. while it is under attack
+        #[pallet::weight(<T as Config>::WeightInfo::suspend_xcm_origin())]
+        #[transactional]
+        pub fn suspend_xcm_origin(origin: OriginFor<T>, para_id: ParaId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                !SuspendedXcmOrigins::<T>::contains_key(para_id),
+                Error::<T>::XcmOriginAlreadySuspended
+            );
+
+            SuspendedXcmOrigins::<T>::insert(para_id, ());
+            Self::deposit_event(Event::<T>::XcmOriginSuspended(para_id));
+            Ok(())
+        }
+
+        /// Accept incoming xcm from a suspended parachain again
+        #[pallet::weight(<T as Config>::WeightInfo::resume_xcm_origin())]
+        #[transactional]
+        pub fn resume_xcm_origin(origin: OriginFor<T>, para_id: ParaId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                SuspendedXcmOrigins::<T>::take(para_id).is_some(),
+                Error::<T>::XcmOriginNotSuspended
+            );
+
+            Self::deposit_event(Event::<T>::XcmOriginResumed(para_id));
+            Ok(())
+        }
+
+        /// Whitelist an account funds can be rescued to, e.g. a treasury or a
+        /// multisig of the team
+        #[pallet::weight(<T as Config>::WeightInfo::add_rescue_target())]
+        #[transactional]
+        pub fn add_rescue_target(origin: OriginFor<T>, target: AccountIdOf<T>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                !RescueTargets::<T>::contains_key(&target),
+                Error::<T>::RescueTargetAlreadyWhitelisted
+            );
+
+            RescueTargets::<T>::insert(&target, ());
+            Self::deposit_event(Event::<T>::RescueTargetAdded(target));
+            Ok(())
+        }
+
+        /// Remove an account from the rescue targets
+        #[pallet::weight(<T as Config>::WeightInfo::remove_rescue_target())]
+        #[transactional]
+        pub fn remove_rescue_target(
+            origin: OriginFor<T>,
+            target: AccountIdOf<T>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                RescueTargets::<T>::take(&target).is_some(),
+                Error::<T>::RescueTargetNotWhitelisted
+            );
+
+            Self::deposit_event(Event::<T>::RescueTargetRemoved(target));
+            Ok(())
+        }
+
+        /// Move `amount` of `asset` out of a sovereign or derivative account to a
+        /// whitelisted `target`, recording why it was done.
+        ///
+        /// Funds of relaychain accounts are moved by a relaychain transfer, only
+        /// relay currency can be rescued from them and the status of the rescue is
+        /// updated once relaychain responds.
+        #[pallet::weight(<T as Config>::WeightInfo::rescue_funds())]
+        #[transactional]
+        pub fn rescue_funds(
+            origin: OriginFor<T>,
+            source: RescueSource<AccountIdOf<T>>,
+            asset: AssetIdOf<T>,
+            amount: BalanceOf<T>,
+            target: AccountIdOf<T>,
+            reason: BoundedVec<u8, T::MaxRescueReasonLength>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(!reason.is_empty(), Error::<T>::EmptyRescueReason);
+            ensure!(
+                RescueTargets::<T>::contains_key(&target),
+                Error::<T>::RescueTargetNotWhitelisted
+            );
+
+            let rescue_id = Self::next_rescue_id();
+            let status = match source {
+                RescueSource::Local(ref who) => {
+                    T::Assets::transfer(asset, who, &target, amount, false)?;
+                    RescueStatus::Succeeded
+                }
+                RescueSource::RelaySovereign | RescueSource::RelayDerivative(_) => {
+                    ensure!(
+                        asset == T::RelayCurrency::get(),
+                        Error::<T>::InvalidRescueAsset
+                    );
+                    let query_id = Self::do_rescue_transact(&source, amount, target.clone())?;
+                    RescueRequests::<T>::insert(query_id, rescue_id);
+                    RescueStatus::Requested(query_id)
+                }
+            };
+
+            FundsRescues::<T>::insert(
+                rescue_id,
+                FundsRescue {
+                    source: source.clone(),
+                    asset,
+                    amount,
+                    target: target.clone(),
+                    reason: reason.clone().into_inner(),
+                    block_number: frame_system::Pallet::<T>::block_number(),
+                    status,
+                },
+            );
+            NextRescueId::<T>::put(rescue_id.saturating_add(1));
+            Self::deposit_event(Event::<T>::FundsRescued(
+                rescue_id,
+                source,
+                asset,
+                amount,
+                target,
+                reason.into_inner(),
+            ));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> XcmResponseCallback for Pallet<T> {
+        fn callback_id() -> PalletId {
+            T::PalletId::get()
+        }
+
+        fn on_success(query_id: QueryId) -> DispatchResult {
+            if let Some((channel_id, _)) = HrmpRequests::<T>::take(query_id) {
+                let status = Self::hrmp_channel(channel_id).and_then(|s| s.confirmed());
+                Self::update_hrmp_channel(channel_id, status);
+            }
+            Self::update_rescue(query_id, RescueStatus::Succeeded);
+            Ok(())
+        }
+
+        // the channel is put back to where it was before the request
+        fn on_error(query_id: QueryId, _index: u32, _error: XcmError) -> DispatchResult {
+            if let Some((channel_id, status)) = HrmpRequests::<T>::take(query_id) {
+                Self::update_hrmp_channel(channel_id, status);
+            }
+            Self::update_rescue(query_id, RescueStatus::Failed);
+            Ok(())
+        }
+
+        fn on_timeout(query_id: QueryId) -> DispatchResult {
+            if let Some((channel_id, status)) = HrmpRequests::<T>::take(query_id) {
+                Self::update_hrmp_channel(channel_id, status);
+            }
+            Self::update_rescue(query_id, RescueStatus::Failed);
+            Ok(())
+        }
     }
 }
 
@@ -174,21 +1065,21 @@ pub trait XcmHelper<T: pallet_xcm::Config, Balance, TAccountId> {
     fn do_withdraw(
         para_id: ParaId,
         para_account_id: TAccountId,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError>;
 
     fn do_contribute(
         para_id: ParaId,
         amount: Balance,
         who: &TAccountId,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError>;
 
     fn do_proxy_contribute(
         para_id: ParaId,
         amount: Balance,
         who: &TAccountId,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError>;
 
     fn do_bond(
@@ -196,54 +1087,80 @@ pub trait XcmHelper<T: pallet_xcm::Config, Balance, TAccountId> {
         payee: RewardDestination<TAccountId>,
         stash: TAccountId,
         index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError>;
 
     fn do_bond_extra(
         value: Balance,
         stash: TAccountId,
         index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError>;
 
-    fn do_unbond(
-        value: Balance,
-        index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
-    ) -> Result<QueryId, DispatchError>;
+    fn do_unbond(value: Balance, index: u16, callback: PalletId) -> Result<QueryId, DispatchError>;
 
-    fn do_rebond(
-        value: Balance,
-        index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
-    ) -> Result<QueryId, DispatchError>;
+    fn do_rebond(value: Balance, index: u16, callback: PalletId) -> Result<QueryId, DispatchError>;
 
     fn do_withdraw_unbonded(
         num_slashing_spans: u32,
         para_account_id: TAccountId,
         index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError>;
 
     fn do_nominate(
         targets: Vec<TAccountId>,
         index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
+    ) -> Result<QueryId, DispatchError>;
+
+    fn do_pool_join(
+        value: Balance,
+        pool_id: PoolId,
+        stash: TAccountId,
+        index: u16,
+        callback: PalletId,
+    ) -> Result<QueryId, DispatchError>;
+
+    fn do_pool_bond_extra(
+        extra: PoolBondExtra<Balance>,
+        stash: TAccountId,
+        index: u16,
+        callback: PalletId,
+    ) -> Result<QueryId, DispatchError>;
+
+    fn do_pool_unbond(
+        value: Balance,
+        member: TAccountId,
+        index: u16,
+        callback: PalletId,
+    ) -> Result<QueryId, DispatchError>;
+
+    fn do_pool_withdraw_unbonded(
+        num_slashing_spans: u32,
+        member: TAccountId,
+        para_account_id: TAccountId,
+        index: u16,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError>;
 
     fn do_add_proxy(
         delegate: AccountId,
         proxy_type: Option<ProxyType>,
         delay: BlockNumber,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError>;
 
     fn do_remove_proxy(
         delegate: AccountId,
         proxy_type: Option<ProxyType>,
         delay: BlockNumber,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError>;
+
+    /// Track a query registered before the responses were dispatched by this pallet,
+    /// so that it expires like the other outbound xcms. It is never sent again.
+    fn track_query(query_id: QueryId, callback: PalletId) -> DispatchResult;
 }
 
 impl<T: Config> Pallet<T> {
@@ -255,27 +1172,407 @@ impl<T: Config> Pallet<T> {
         T::AccountIdToMultiLocation::convert(T::RefundLocation::get())
     }
 
-    pub fn report_outcome_notify(
-        message: &mut Xcm<()>,
-        responder: impl Into<MultiLocation>,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
-        timeout: BlockNumberFor<T>,
-    ) -> Result<QueryId, DispatchError> {
-        let responder = responder.into();
-        let dest = <T as pallet_xcm::Config>::LocationInverter::invert_location(&responder)
-            .map_err(|()| Error::<T>::MultiLocationNotInvertible)?;
-        let notify: <T as pallet_xcm::Config>::RuntimeCall = notify.into();
-        let max_response_weight = notify.get_dispatch_info().weight.ref_time();
-        let query_id = pallet_xcm::Pallet::<T>::new_notify_query(responder, notify, timeout);
-        let report_error = Xcm(vec![ReportError {
-            dest,
-            query_id,
-            max_response_weight,
-        }]);
-        // Prepend SetAppendix(Xcm(vec![ReportError])) wont be able to pass barrier check
-        // so we need to insert it after Withdraw, BuyExecution
-        message.0.insert(2, SetAppendix(report_error));
-        Ok(query_id)
+    /// Build a relaychain `Transact` of `call` dispatched with `origin_type`,
+    /// paid by the fees burned from the pallet account
+    pub fn ump_transact(
+        call: DoubleEncoded<()>,
+        weight: Weight,
+        beneficiary: MultiLocation,
+        fees: BalanceOf<T>,
+        origin_type: OriginKind,
+    ) -> Result<Xcm<()>, DispatchError> {
+        let asset: MultiAsset = (MultiLocation::here(), fees).into();
+        T::Assets::burn_from(T::RelayCurrency::get(), &Self::account_id(), fees)
+            .map_err(|_| Error::<T>::InsufficientXcmFees)?;
+
+        Ok(Xcm(vec![
+            WithdrawAsset(MultiAssets::from(asset.clone())),
+            BuyExecution {
+                fees: asset.clone(),
+                weight_limit: Unlimited,
+            },
+            Transact {
+                origin_type,
+                require_weight_at_most: weight.ref_time(),
+                call,
+            },
+            RefundSurplus,
+            DepositAsset {
+                assets: asset.into(),
+                max_assets: 1,
+                beneficiary,
+            },
+        ]))
+    }
+
+    /// Send an hrmp call to relaychain, the hrmp pallet only accepts
+    /// parachain origins so it's dispatched as `Native`
+    fn do_hrmp_transact(xcm_call: XcmCall, call: HrmpCall) -> Result<QueryId, DispatchError> {
+        let xcm_weight_fee_misc = Self::xcm_weight_fee(xcm_call);
+        Ok(switch_relay!({
+            let call = RelaychainCall::<T>::Hrmp(call);
+
+            let msg = Self::ump_transact(
+                call.encode().into(),
+                xcm_weight_fee_misc.weight,
+                Self::refund_location(),
+                xcm_weight_fee_misc.fee,
+                OriginKind::Native,
+            )?;
+
+            Self::send_with_notify(
+                MultiLocation::parent(),
+                msg,
+                xcm_weight_fee_misc.fee,
+                T::PalletId::get(),
+            )?
+        }))
+    }
+
+    fn ensure_hrmp_channel(channel_id: &HrmpChannelId) -> DispatchResult {
+        let self_para_id = T::SelfParaId::get();
+        ensure!(
+            channel_id.sender != channel_id.recipient
+                && (channel_id.sender == self_para_id || channel_id.recipient == self_para_id),
+            Error::<T>::InvalidHrmpChannel
+        );
+        Ok(())
+    }
+
+    fn request_hrmp(channel_id: HrmpChannelId, status: HrmpChannelStatus, query_id: QueryId) {
+        HrmpRequests::<T>::insert(query_id, (channel_id, Self::hrmp_channel(channel_id)));
+        HrmpChannels::<T>::insert(channel_id, status);
+        Self::deposit_event(Event::<T>::HrmpChannelRequested(
+            channel_id, status, query_id,
+        ));
+    }
+
+    fn update_hrmp_channel(channel_id: HrmpChannelId, status: Option<HrmpChannelStatus>) {
+        HrmpChannels::<T>::set(channel_id, status);
+        Self::deposit_event(Event::<T>::HrmpChannelUpdated(channel_id, status));
+    }
+
+    /// Send a relaychain transfer of `amount` out of the sovereign account,
+    /// or out of one of its derivatives
+    fn do_rescue_transact(
+        source: &RescueSource<AccountIdOf<T>>,
+        amount: BalanceOf<T>,
+        target: AccountIdOf<T>,
+    ) -> Result<QueryId, DispatchError> {
+        let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::RescueFunds);
+        Ok(switch_relay!({
+            let transfer = RelaychainCall::Balances::<T>(BalancesCall::TransferKeepAlive(
+                BalancesTransferKeepAliveCall {
+                    dest: T::Lookup::unlookup(target),
+                    value: amount,
+                },
+            ));
+            let call = match source {
+                RescueSource::RelayDerivative(index) => RelaychainCall::Utility(Box::new(
+                    UtilityCall::AsDerivative(UtilityAsDerivativeCall {
+                        index: *index,
+                        call: transfer,
+                    }),
+                )),
+                _ => transfer,
+            };
+
+            let msg = Self::do_ump_transact(
+                call.encode().into(),
+                xcm_weight_fee_misc.weight,
+                Self::refund_location(),
+                xcm_weight_fee_misc.fee,
+            )?;
+
+            Self::send_with_notify(
+                MultiLocation::parent(),
+                msg,
+                xcm_weight_fee_misc.fee,
+                T::PalletId::get(),
+            )?
+        }))
+    }
+
+    fn update_rescue(query_id: QueryId, status: RescueStatus) {
+        if let Some(rescue_id) = RescueRequests::<T>::take(query_id) {
+            FundsRescues::<T>::mutate(rescue_id, |rescue| {
+                if let Some(rescue) = rescue {
+                    rescue.status = status;
+                }
+            });
+            Self::deposit_event(Event::<T>::FundsRescueUpdated(rescue_id, status));
+        }
+    }
+
+    pub fn report_outcome_notify(
+        message: &mut Xcm<()>,
+        responder: impl Into<MultiLocation>,
+        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        timeout: BlockNumberFor<T>,
+    ) -> Result<QueryId, DispatchError> {
+        let responder = responder.into();
+        let dest = <T as pallet_xcm::Config>::LocationInverter::invert_location(&responder)
+            .map_err(|()| Error::<T>::MultiLocationNotInvertible)?;
+        let notify: <T as pallet_xcm::Config>::RuntimeCall = notify.into();
+        let max_response_weight = notify.get_dispatch_info().weight.ref_time();
+        let query_id = pallet_xcm::Pallet::<T>::new_notify_query(responder, notify, timeout);
+        let report_error = Xcm(vec![ReportError {
+            dest,
+            query_id,
+            max_response_weight,
+        }]);
+        // Prepend SetAppendix(Xcm(vec![ReportError])) wont be able to pass barrier check
+        // so we need to insert it after Withdraw, BuyExecution
+        message.0.insert(2, SetAppendix(report_error));
+        Ok(query_id)
+    }
+
+    /// Append error reporting to `message`, send it to `dest` and track it
+    /// until its response arrives.
+    pub fn send_with_notify(
+        dest: MultiLocation,
+        message: Xcm<()>,
+        fees: BalanceOf<T>,
+        callback: PalletId,
+    ) -> Result<QueryId, DispatchError> {
+        let mut msg = message.clone();
+        let query_id = Self::report_outcome_notify(
+            &mut msg,
+            dest.clone(),
+            Self::notify_placeholder(),
+            T::NotifyTimeout::get(),
+        )?;
+
+        if let Err(_e) = T::XcmSender::send_xcm(dest.clone(), msg) {
+            return Err(Error::<T>::SendFailure.into());
+        }
+
+        OutboundXcms::<T>::insert(
+            query_id,
+            OutboundXcm {
+                origin_query_id: query_id,
+                dest,
+                message,
+                fees,
+                callback,
+                retries: 0,
+                timeout: Self::notify_deadline(),
+            },
+        );
+
+        Ok(query_id)
+    }
+
+    /// Send the outbound xcm identified by `query_id` again under a new query id
+    #[transactional]
+    pub(crate) fn do_retry(query_id: QueryId) -> Result<QueryId, DispatchError> {
+        let mut outbound =
+            OutboundXcms::<T>::take(query_id).ok_or(Error::<T>::OutboundXcmNotFound)?;
+        T::Assets::burn_from(T::RelayCurrency::get(), &Self::account_id(), outbound.fees)
+            .map_err(|_| Error::<T>::InsufficientXcmFees)?;
+
+        let mut msg = outbound.message.clone();
+        let new_query_id = Self::report_outcome_notify(
+            &mut msg,
+            outbound.dest.clone(),
+            Self::notify_placeholder(),
+            T::NotifyTimeout::get(),
+        )?;
+
+        if let Err(_e) = T::XcmSender::send_xcm(outbound.dest.clone(), msg) {
+            return Err(Error::<T>::SendFailure.into());
+        }
+
+        outbound.retries = outbound.retries.saturating_add(1);
+        outbound.timeout = Self::notify_deadline();
+        OutboundXcms::<T>::insert(new_query_id, outbound);
+
+        Self::deposit_event(Event::<T>::OutboundXcmRetried(query_id, new_query_id));
+        Ok(new_query_id)
+    }
+
+    /// Retry the outbound xcm if it has retries left
+    pub(crate) fn try_retry(query_id: QueryId) -> Result<QueryId, DispatchError> {
+        let outbound = Self::outbound_xcm(query_id).ok_or(Error::<T>::OutboundXcmNotFound)?;
+        if outbound.retries >= T::MaxXcmRetries::get() {
+            Self::deposit_event(Event::<T>::OutboundXcmFailed(query_id));
+            return Err(Error::<T>::TooManyRetries.into());
+        }
+
+        Self::do_retry(query_id)
+    }
+
+    /// Dispatch `response` to the callbacks of the outbound xcm identified by `query_id`
+    #[transactional]
+    pub(crate) fn do_dispatch_response(query_id: QueryId, response: XcmResponse) -> DispatchResult {
+        let outbound = Self::outbound_xcm(query_id).ok_or(Error::<T>::OutboundXcmNotFound)?;
+        T::ResponseHandler::on_response(outbound.callback, outbound.origin_query_id, response)
+            .unwrap_or_else(|| Err(Error::<T>::ResponseHandlerNotFound.into()))
+    }
+
+    fn notify_placeholder() -> CallIdOf<T> {
+        <T as Config>::RuntimeCall::from(Call::<T>::on_response {
+            query_id: Default::default(),
+            response: Default::default(),
+        })
+        .into()
+    }
+
+    fn notify_deadline() -> BlockNumberFor<T> {
+        T::BlockNumberProvider::current_block_number().saturating_add(T::NotifyTimeout::get())
+    }
+
+    /// Claim `assets` trapped under `origin` by executing `ClaimAsset` as `origin`
+    /// and deposit them to `beneficiary`
+    pub(crate) fn do_claim_assets(
+        origin: MultiLocation,
+        assets: MultiAssets,
+        beneficiary: MultiLocation,
+    ) -> Result<H256, DispatchError> {
+        let hash = Self::trapped_assets_hash(&origin, &VersionedMultiAssets::from(assets.clone()));
+        ensure!(
+            TrappedAssets::<T>::contains_key(hash),
+            Error::<T>::TrappedAssetsNotFound
+        );
+
+        let max_assets = assets.len() as u32;
+        let mut message = Xcm(vec![
+            ClaimAsset {
+                assets,
+                ticket: MultiLocation::here(),
+            },
+            DepositAsset {
+                assets: Wild(All),
+                max_assets,
+                beneficiary,
+            },
+        ]);
+        let weight = <T as pallet_xcm::Config>::Weigher::weight(&mut message)
+            .map_err(|()| Error::<T>::UnweighableMessage)?;
+        <T as pallet_xcm::Config>::XcmExecutor::execute_xcm_in_credit(
+            origin, message, weight, weight,
+        )
+        .ensure_complete()
+        .map_err(|_| Error::<T>::ClaimAssetsFailed)?;
+
+        Ok(hash)
+    }
+
+    /// Same hash pallet-xcm tracks trapped assets by
+    /// Whether xcm from `origin` pass the governance configuration, local origins
+    /// always do
+    pub fn is_xcm_origin_allowed(origin: &MultiLocation) -> bool {
+        if origin.parents == 0 {
+            return true;
+        }
+        if let (1, Some(Parachain(para_id))) = (origin.parents, origin.first_interior()) {
+            if SuspendedXcmOrigins::<T>::contains_key(ParaId::from(*para_id)) {
+                return false;
+            }
+        }
+        Self::xcm_allowed_origins().map_or(true, |allowed| allowed.contains(origin))
+    }
+
+    pub fn trapped_assets_hash(origin: &MultiLocation, assets: &VersionedMultiAssets) -> H256 {
+        BlakeTwo256::hash_of(&(origin, assets))
+    }
+
+    /// Location of the local account a trapped origin belongs to, if any
+    fn local_account_location(origin: &MultiLocation) -> Option<MultiLocation> {
+        match origin.last() {
+            Some(AccountId32 { id, .. }) => Some(MultiLocation::from(AccountId32 {
+                network: NetworkId::Any,
+                id: *id,
+            })),
+            _ => None,
+        }
+    }
+
+    /// Location of `asset` as seen from the asset hub, which must be its reserve
+    pub fn asset_hub_location(asset: AssetIdOf<T>) -> Result<MultiLocation, DispatchError> {
+        let location = T::CurrencyIdConvert::convert(asset).ok_or(Error::<T>::NotAssetHubAsset)?;
+        if location.parents != 1 {
+            return Err(Error::<T>::NotAssetHubAsset.into());
+        }
+        match location.interior.split_first() {
+            (interior, Some(Parachain(id)))
+                if ParaId::from(id) == T::AssetHubParaId::get() && interior != Here =>
+            {
+                Ok(MultiLocation::new(0, interior))
+            }
+            _ => Err(Error::<T>::NotAssetHubAsset.into()),
+        }
+    }
+
+    /// Burn `amount` of the local `asset` and release it from the sovereign account
+    /// on the asset hub to `beneficiary`.
+    ///
+    /// The relay currency paying for the execution at the asset hub is withdrawn from
+    /// the sovereign account as well, so the fee charged to `who` is collected to
+    /// refill it. Non sufficient assets can't keep the beneficiary alive, which gets
+    /// the unused fee instead.
+    pub fn do_transfer_to_asset_hub(
+        who: &AccountIdOf<T>,
+        asset: AssetIdOf<T>,
+        amount: BalanceOf<T>,
+        beneficiary: AccountIdOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let info = Self::asset_hub_asset(asset).ok_or(Error::<T>::AssetHubAssetNotSupported)?;
+        ensure!(
+            amount >= info.min_balance,
+            Error::<T>::AmountBelowMinBalance
+        );
+        let asset_location = Self::asset_hub_location(asset)?;
+        let dest = MultiLocation::new(1, X1(Parachain(T::AssetHubParaId::get().into())));
+        let fee = Self::get_xcm_weight_fee_to_sibling(dest.clone()).fee;
+        ensure!(!fee.is_zero(), Error::<T>::ZeroXcmFees);
+
+        T::Assets::burn_from(asset, who, amount)?;
+        T::Assets::transfer(
+            T::RelayCurrency::get(),
+            who,
+            &Self::account_id(),
+            fee,
+            false,
+        )?;
+
+        let fee_asset: MultiAsset = (MultiLocation::parent(), fee).into();
+        let transfer_asset: MultiAsset = (asset_location.clone(), amount).into();
+        let beneficiary = T::AccountIdToMultiLocation::convert(beneficiary);
+        let mut message = vec![
+            WithdrawAsset(MultiAssets::from(vec![transfer_asset, fee_asset.clone()])),
+            BuyExecution {
+                fees: fee_asset,
+                weight_limit: Unlimited,
+            },
+            RefundSurplus,
+        ];
+        if info.is_sufficient {
+            message.extend([
+                DepositAsset {
+                    assets: Wild(AllOf {
+                        id: Concrete(asset_location),
+                        fun: WildFungible,
+                    }),
+                    max_assets: 1,
+                    beneficiary,
+                },
+                DepositAsset {
+                    assets: Wild(All),
+                    max_assets: 1,
+                    beneficiary: MultiLocation::new(1, X1(Parachain(T::SelfParaId::get().into()))),
+                },
+            ]);
+        } else {
+            message.push(DepositAsset {
+                assets: Wild(All),
+                max_assets: 2,
+                beneficiary,
+            });
+        }
+        T::XcmSender::send_xcm(dest, Xcm(message)).map_err(|_| Error::<T>::SendFailure)?;
+
+        Ok(fee)
     }
 
     pub fn get_xcm_weight_fee_to_sibling(
@@ -284,6 +1581,122 @@ impl<T: Config> Pallet<T> {
         let call = XcmCall::TransferToSiblingchain(Box::new(location));
         Self::xcm_weight_fee(call)
     }
+
+    /// Estimate the weight and fee required to execute `message` at `dest`.
+    ///
+    /// If `dest` is this chain, the message is weighed and priced with the
+    /// `Weigher` and `Trader` of the given executor config, paying in `fee_asset`.
+    ///
+    /// The weigher of another chain can't be run here, so for a remote `dest` this is
+    /// an approximation: the message is weighed with the local weigher and priced at
+    /// the rate configured for transfers to `dest`. The configured transfer weight and
+    /// fee are a floor, they are what xtokens charges when sending assets there.
+    pub fn estimate_xcm_fee<XcmConfig, CurrencyIdConvert>(
+        dest: VersionedMultiLocation,
+        message: VersionedXcm<()>,
+        fee_asset: CurrencyId,
+    ) -> Result<(u64, BalanceOf<T>), DispatchError>
+    where
+        XcmConfig: xcm_executor::Config,
+        CurrencyIdConvert: Convert<CurrencyId, Option<MultiLocation>>,
+    {
+        let dest = MultiLocation::try_from(dest).map_err(|()| Error::<T>::BadVersion)?;
+        if dest != MultiLocation::here() {
+            let message = Xcm::<()>::try_from(message).map_err(|()| Error::<T>::BadVersion)?;
+            let mut message: Xcm<XcmConfig::RuntimeCall> = message.into();
+            let weight = XcmConfig::Weigher::weight(&mut message)
+                .map_err(|()| Error::<T>::UnweighableMessage)?;
+            return Ok(Self::remote_weight_fee(dest, weight));
+        }
+
+        let message = Xcm::<()>::try_from(message).map_err(|()| Error::<T>::BadVersion)?;
+        let mut message: Xcm<XcmConfig::RuntimeCall> = message.into();
+        let weight = XcmConfig::Weigher::weight(&mut message)
+            .map_err(|()| Error::<T>::UnweighableMessage)?;
+
+        let fee_location =
+            CurrencyIdConvert::convert(fee_asset).ok_or(Error::<T>::FeeAssetNotSupported)?;
+        let fee_asset_id: xcm::latest::AssetId = Concrete(fee_location);
+        let payment: MultiAsset = (fee_asset_id.clone(), Balance::MAX).into();
+        let mut trader = XcmConfig::Trader::new();
+        let unused = trader
+            .buy_weight(weight, payment.into())
+            .map_err(|_| Error::<T>::FeeAssetNotSupported)?;
+        let fee = Balance::MAX.saturating_sub(
+            unused
+                .fungible
+                .get(&fee_asset_id)
+                .copied()
+                .unwrap_or_default(),
+        );
+
+        Ok((weight, fee))
+    }
+
+    /// Estimate the weight and fee charged by xtokens to transfer assets to `dest`,
+    /// as configured by governance for that destination
+    pub fn estimate_transfer_fee(
+        dest: VersionedMultiLocation,
+    ) -> Result<(u64, BalanceOf<T>), DispatchError> {
+        let dest = MultiLocation::try_from(dest).map_err(|()| Error::<T>::BadVersion)?;
+        let xcm_weight_fee_misc = Self::get_xcm_weight_fee_to_sibling(dest);
+        Ok((
+            xcm_weight_fee_misc.weight.ref_time(),
+            xcm_weight_fee_misc.fee,
+        ))
+    }
+
+    // Prices `weight` at the rate configured for transfers to `dest`, never going below
+    // the configured transfer weight and fee
+    fn remote_weight_fee(dest: MultiLocation, weight: u64) -> (u64, BalanceOf<T>) {
+        let xcm_weight_fee_misc = Self::get_xcm_weight_fee_to_sibling(dest);
+        let configured_weight = xcm_weight_fee_misc.weight.ref_time();
+        if weight <= configured_weight {
+            return (configured_weight, xcm_weight_fee_misc.fee);
+        }
+        let fee = FixedU128::saturating_from_rational(weight, configured_weight)
+            .saturating_mul_int(xcm_weight_fee_misc.fee);
+        (weight, fee)
+    }
+
+    /// Execute `message` from `origin` with the given executor config and return
+    /// its outcome and the events it emitted. Events matched by `AssetMovements`
+    /// are also reported separately as asset movements.
+    ///
+    /// All storage changes, including the emitted events, are rolled back.
+    pub fn dry_run_xcm<XcmConfig, AssetMovements>(
+        origin: VersionedMultiLocation,
+        message: VersionedXcm<XcmConfig::RuntimeCall>,
+    ) -> Result<XcmDryRunEffects<<T as frame_system::Config>::RuntimeEvent>, DispatchError>
+    where
+        XcmConfig: xcm_executor::Config,
+        AssetMovements: Contains<<T as frame_system::Config>::RuntimeEvent>,
+    {
+        let origin = MultiLocation::try_from(origin).map_err(|()| Error::<T>::BadVersion)?;
+        let mut message = Xcm::<XcmConfig::RuntimeCall>::try_from(message)
+            .map_err(|()| Error::<T>::BadVersion)?;
+        let weight_limit = XcmConfig::Weigher::weight(&mut message)
+            .map_err(|()| Error::<T>::UnweighableMessage)?;
+
+        with_transaction(|| {
+            frame_system::Pallet::<T>::reset_events();
+            let outcome = XcmExecutor::<XcmConfig>::execute_xcm(origin, message, weight_limit);
+            let emitted_events: Vec<_> = frame_system::Pallet::<T>::read_events_no_consensus()
+                .into_iter()
+                .map(|record| record.event)
+                .collect();
+            let asset_movements = emitted_events
+                .iter()
+                .filter(|event| AssetMovements::contains(event))
+                .cloned()
+                .collect();
+            TransactionOutcome::Rollback(Ok(XcmDryRunEffects {
+                outcome,
+                emitted_events,
+                asset_movements,
+            }))
+        })
+    }
 }
 
 impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
@@ -304,35 +1717,20 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
         beneficiary: MultiLocation,
         fees: BalanceOf<T>,
     ) -> Result<Xcm<()>, DispatchError> {
-        let asset: MultiAsset = (MultiLocation::here(), fees).into();
-        T::Assets::burn_from(T::RelayCurrency::get(), &Self::account_id(), fees)
-            .map_err(|_| Error::<T>::InsufficientXcmFees)?;
-
-        Ok(Xcm(vec![
-            WithdrawAsset(MultiAssets::from(asset.clone())),
-            BuyExecution {
-                fees: asset.clone(),
-                weight_limit: Unlimited,
-            },
-            Transact {
-                origin_type: OriginKind::SovereignAccount,
-                require_weight_at_most: weight.ref_time(),
-                call,
-            },
-            RefundSurplus,
-            DepositAsset {
-                assets: asset.into(),
-                max_assets: 1,
-                beneficiary,
-            },
-        ]))
+        Self::ump_transact(
+            call,
+            weight,
+            beneficiary,
+            fees,
+            OriginKind::SovereignAccount,
+        )
     }
 
     fn do_add_proxy(
         delegate: AccountId,
         proxy_type: Option<ProxyType>,
         delay: BlockNumber,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError> {
         let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::AddProxy);
         Ok(switch_relay!({
@@ -343,25 +1741,19 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
                     delay,
                 })));
 
-            let mut msg = Self::do_ump_transact(
+            let msg = Self::do_ump_transact(
                 call.encode().into(),
                 xcm_weight_fee_misc.weight,
                 Self::refund_location(),
                 xcm_weight_fee_misc.fee,
             )?;
 
-            let query_id = Self::report_outcome_notify(
-                &mut msg,
+            Self::send_with_notify(
                 MultiLocation::parent(),
-                notify,
-                T::NotifyTimeout::get(),
-            )?;
-
-            if let Err(_e) = T::XcmSender::send_xcm(MultiLocation::parent(), msg) {
-                return Err(Error::<T>::SendFailure.into());
-            }
-
-            query_id
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
         }))
     }
 
@@ -369,7 +1761,7 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
         delegate: AccountId,
         proxy_type: Option<ProxyType>,
         delay: BlockNumber,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError> {
         let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::RemoveProxy);
         Ok(switch_relay!({
@@ -381,32 +1773,26 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
                 },
             )));
 
-            let mut msg = Self::do_ump_transact(
+            let msg = Self::do_ump_transact(
                 call.encode().into(),
                 xcm_weight_fee_misc.weight,
                 Self::refund_location(),
                 xcm_weight_fee_misc.fee,
             )?;
 
-            let query_id = Self::report_outcome_notify(
-                &mut msg,
+            Self::send_with_notify(
                 MultiLocation::parent(),
-                notify,
-                T::NotifyTimeout::get(),
-            )?;
-
-            if let Err(_e) = T::XcmSender::send_xcm(MultiLocation::parent(), msg) {
-                return Err(Error::<T>::SendFailure.into());
-            }
-
-            query_id
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
         }))
     }
 
     fn do_withdraw(
         para_id: ParaId,
         para_account_id: AccountIdOf<T>,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError> {
         let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::Withdraw);
         Ok(switch_relay!({
@@ -416,25 +1802,19 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
                     index: para_id,
                 }));
 
-            let mut msg = Self::do_ump_transact(
+            let msg = Self::do_ump_transact(
                 call.encode().into(),
                 xcm_weight_fee_misc.weight,
                 Self::refund_location(),
                 xcm_weight_fee_misc.fee,
             )?;
 
-            let query_id = Self::report_outcome_notify(
-                &mut msg,
+            Self::send_with_notify(
                 MultiLocation::parent(),
-                notify,
-                T::NotifyTimeout::get(),
-            )?;
-
-            if let Err(_e) = T::XcmSender::send_xcm(MultiLocation::parent(), msg) {
-                return Err(Error::<T>::SendFailure.into());
-            }
-
-            query_id
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
         }))
     }
 
@@ -442,7 +1822,7 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
         para_id: ParaId,
         amount: BalanceOf<T>,
         _who: &AccountIdOf<T>,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError> {
         let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::Contribute);
         Ok(switch_relay!({
@@ -454,25 +1834,19 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
                 },
             ));
 
-            let mut msg = Self::do_ump_transact(
+            let msg = Self::do_ump_transact(
                 call.encode().into(),
                 xcm_weight_fee_misc.weight,
                 Self::refund_location(),
                 xcm_weight_fee_misc.fee,
             )?;
 
-            let query_id = Self::report_outcome_notify(
-                &mut msg,
+            Self::send_with_notify(
                 MultiLocation::parent(),
-                notify,
-                T::NotifyTimeout::get(),
-            )?;
-
-            if let Err(_e) = T::XcmSender::send_xcm(MultiLocation::parent(), msg) {
-                return Err(Error::<T>::SendFailure.into());
-            }
-
-            query_id
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
         }))
     }
 
@@ -480,7 +1854,7 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
         para_id: ParaId,
         amount: BalanceOf<T>,
         who: &AccountIdOf<T>,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError> {
         let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::Contribute);
         let real =
@@ -510,25 +1884,19 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
                 },
             )));
 
-            let mut msg = Self::do_ump_transact(
+            let msg = Self::do_ump_transact(
                 call.encode().into(),
                 xcm_weight_fee_misc.weight,
                 Self::refund_location(),
                 xcm_weight_fee_misc.fee,
             )?;
 
-            let query_id = Self::report_outcome_notify(
-                &mut msg,
+            Self::send_with_notify(
                 MultiLocation::parent(),
-                notify,
-                T::NotifyTimeout::get(),
-            )?;
-
-            if let Err(_e) = T::XcmSender::send_xcm(MultiLocation::parent(), msg) {
-                return Err(Error::<T>::SendFailure.into());
-            }
-
-            query_id
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
         }))
     }
 
@@ -537,7 +1905,7 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
         payee: RewardDestination<AccountIdOf<T>>,
         stash: AccountIdOf<T>,
         index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError> {
         let controller = stash.clone();
         let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::Bond);
@@ -566,25 +1934,19 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
                     ],
                 })));
 
-            let mut msg = Self::do_ump_transact(
+            let msg = Self::do_ump_transact(
                 call.encode().into(),
                 xcm_weight_fee_misc.weight,
                 Self::refund_location(),
                 xcm_weight_fee_misc.fee,
             )?;
 
-            let query_id = Self::report_outcome_notify(
-                &mut msg,
+            Self::send_with_notify(
                 MultiLocation::parent(),
-                notify,
-                T::NotifyTimeout::get(),
-            )?;
-
-            if let Err(_err) = T::XcmSender::send_xcm(MultiLocation::parent(), msg) {
-                return Err(Error::<T>::SendFailure.into());
-            }
-
-            query_id
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
         }))
     }
 
@@ -592,7 +1954,7 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
         value: BalanceOf<T>,
         stash: AccountIdOf<T>,
         index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError> {
         let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::BondExtra);
         Ok(switch_relay!({
@@ -616,32 +1978,26 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
                     ],
                 })));
 
-            let mut msg = Self::do_ump_transact(
+            let msg = Self::do_ump_transact(
                 call.encode().into(),
                 xcm_weight_fee_misc.weight,
                 Self::refund_location(),
                 xcm_weight_fee_misc.fee,
             )?;
 
-            let query_id = Self::report_outcome_notify(
-                &mut msg,
+            Self::send_with_notify(
                 MultiLocation::parent(),
-                notify,
-                T::NotifyTimeout::get(),
-            )?;
-
-            if let Err(_err) = T::XcmSender::send_xcm(MultiLocation::parent(), msg) {
-                return Err(Error::<T>::SendFailure.into());
-            }
-
-            query_id
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
         }))
     }
 
     fn do_unbond(
         value: BalanceOf<T>,
         index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError> {
         let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::Unbond);
         Ok(switch_relay!({
@@ -654,32 +2010,26 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
                 },
             )));
 
-            let mut msg = Self::do_ump_transact(
+            let msg = Self::do_ump_transact(
                 call.encode().into(),
                 xcm_weight_fee_misc.weight,
                 Self::refund_location(),
                 xcm_weight_fee_misc.fee,
             )?;
 
-            let query_id = Self::report_outcome_notify(
-                &mut msg,
+            Self::send_with_notify(
                 MultiLocation::parent(),
-                notify,
-                T::NotifyTimeout::get(),
-            )?;
-
-            if let Err(_err) = T::XcmSender::send_xcm(MultiLocation::parent(), msg) {
-                return Err(Error::<T>::SendFailure.into());
-            }
-
-            query_id
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
         }))
     }
 
     fn do_rebond(
         value: BalanceOf<T>,
         index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError> {
         let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::Rebond);
         Ok(switch_relay!({
@@ -692,25 +2042,19 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
                 },
             )));
 
-            let mut msg = Self::do_ump_transact(
+            let msg = Self::do_ump_transact(
                 call.encode().into(),
                 xcm_weight_fee_misc.weight,
                 Self::refund_location(),
                 xcm_weight_fee_misc.fee,
             )?;
 
-            let query_id = Self::report_outcome_notify(
-                &mut msg,
+            Self::send_with_notify(
                 MultiLocation::parent(),
-                notify,
-                T::NotifyTimeout::get(),
-            )?;
-
-            if let Err(_err) = T::XcmSender::send_xcm(MultiLocation::parent(), msg) {
-                return Err(Error::<T>::SendFailure.into());
-            }
-
-            query_id
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
         }))
     }
 
@@ -718,7 +2062,7 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
         num_slashing_spans: u32,
         para_account_id: AccountIdOf<T>,
         index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError> {
         let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::WithdrawUnbonded);
         Ok(switch_relay!({
@@ -747,32 +2091,26 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
                     ],
                 })));
 
-            let mut msg = Self::do_ump_transact(
+            let msg = Self::do_ump_transact(
                 call.encode().into(),
                 xcm_weight_fee_misc.weight,
                 Self::refund_location(),
                 xcm_weight_fee_misc.fee,
             )?;
 
-            let query_id = Self::report_outcome_notify(
-                &mut msg,
+            Self::send_with_notify(
                 MultiLocation::parent(),
-                notify,
-                T::NotifyTimeout::get(),
-            )?;
-
-            if let Err(_err) = T::XcmSender::send_xcm(MultiLocation::parent(), msg) {
-                return Err(Error::<T>::SendFailure.into());
-            }
-
-            query_id
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
         }))
     }
 
     fn do_nominate(
         targets: Vec<AccountIdOf<T>>,
         index: u16,
-        notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+        callback: PalletId,
     ) -> Result<QueryId, DispatchError> {
         let targets_source = targets.into_iter().map(T::Lookup::unlookup).collect();
         let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::Nominate);
@@ -788,25 +2126,309 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
                 },
             )));
 
-            let mut msg = Self::do_ump_transact(
+            let msg = Self::do_ump_transact(
+                call.encode().into(),
+                xcm_weight_fee_misc.weight,
+                Self::refund_location(),
+                xcm_weight_fee_misc.fee,
+            )?;
+
+            Self::send_with_notify(
+                MultiLocation::parent(),
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
+        }))
+    }
+
+    fn do_pool_join(
+        value: BalanceOf<T>,
+        pool_id: PoolId,
+        stash: AccountIdOf<T>,
+        index: u16,
+        callback: PalletId,
+    ) -> Result<QueryId, DispatchError> {
+        let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::PoolJoin);
+        Ok(switch_relay!({
+            let call =
+                RelaychainCall::Utility(Box::new(UtilityCall::BatchAll(UtilityBatchAllCall {
+                    calls: vec![
+                        RelaychainCall::Balances(BalancesCall::TransferKeepAlive(
+                            BalancesTransferKeepAliveCall {
+                                dest: T::Lookup::unlookup(stash),
+                                value,
+                            },
+                        )),
+                        RelaychainCall::Utility(Box::new(UtilityCall::AsDerivative(
+                            UtilityAsDerivativeCall {
+                                index,
+                                call: RelaychainCall::NominationPools::<T>(
+                                    NominationPoolsCall::Join(NominationPoolsJoinCall {
+                                        amount: value,
+                                        pool_id,
+                                    }),
+                                ),
+                            },
+                        ))),
+                    ],
+                })));
+
+            let msg = Self::do_ump_transact(
+                call.encode().into(),
+                xcm_weight_fee_misc.weight,
+                Self::refund_location(),
+                xcm_weight_fee_misc.fee,
+            )?;
+
+            Self::send_with_notify(
+                MultiLocation::parent(),
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
+        }))
+    }
+
+    fn do_pool_bond_extra(
+        extra: PoolBondExtra<BalanceOf<T>>,
+        stash: AccountIdOf<T>,
+        index: u16,
+        callback: PalletId,
+    ) -> Result<QueryId, DispatchError> {
+        let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::PoolBondExtra);
+        Ok(switch_relay!({
+            let bond_extra = RelaychainCall::Utility(Box::new(UtilityCall::AsDerivative(
+                UtilityAsDerivativeCall {
+                    index,
+                    call: RelaychainCall::NominationPools::<T>(NominationPoolsCall::BondExtra(
+                        NominationPoolsBondExtraCall { extra },
+                    )),
+                },
+            )));
+            // Rewards are already on the member, only free balance has to be sent over
+            let call = match extra {
+                PoolBondExtra::FreeBalance(value) => {
+                    RelaychainCall::Utility(Box::new(UtilityCall::BatchAll(UtilityBatchAllCall {
+                        calls: vec![
+                            RelaychainCall::Balances(BalancesCall::TransferKeepAlive(
+                                BalancesTransferKeepAliveCall {
+                                    dest: T::Lookup::unlookup(stash),
+                                    value,
+                                },
+                            )),
+                            bond_extra,
+                        ],
+                    })))
+                }
+                PoolBondExtra::Rewards => bond_extra,
+            };
+
+            let msg = Self::do_ump_transact(
+                call.encode().into(),
+                xcm_weight_fee_misc.weight,
+                Self::refund_location(),
+                xcm_weight_fee_misc.fee,
+            )?;
+
+            Self::send_with_notify(
+                MultiLocation::parent(),
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
+        }))
+    }
+
+    fn do_pool_unbond(
+        value: BalanceOf<T>,
+        member: AccountIdOf<T>,
+        index: u16,
+        callback: PalletId,
+    ) -> Result<QueryId, DispatchError> {
+        let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::PoolUnbond);
+        Ok(switch_relay!({
+            let call = RelaychainCall::Utility(Box::new(UtilityCall::AsDerivative(
+                UtilityAsDerivativeCall {
+                    index,
+                    call: RelaychainCall::NominationPools::<T>(NominationPoolsCall::Unbond(
+                        NominationPoolsUnbondCall {
+                            member_account: T::Lookup::unlookup(member),
+                            unbonding_points: value,
+                        },
+                    )),
+                },
+            )));
+
+            let msg = Self::do_ump_transact(
                 call.encode().into(),
                 xcm_weight_fee_misc.weight,
                 Self::refund_location(),
                 xcm_weight_fee_misc.fee,
             )?;
 
-            let query_id = Self::report_outcome_notify(
-                &mut msg,
+            Self::send_with_notify(
                 MultiLocation::parent(),
-                notify,
-                T::NotifyTimeout::get(),
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
+        }))
+    }
+
+    fn do_pool_withdraw_unbonded(
+        num_slashing_spans: u32,
+        member: AccountIdOf<T>,
+        para_account_id: AccountIdOf<T>,
+        index: u16,
+        callback: PalletId,
+    ) -> Result<QueryId, DispatchError> {
+        let xcm_weight_fee_misc = Self::xcm_weight_fee(XcmCall::PoolWithdrawUnbonded);
+        Ok(switch_relay!({
+            let call =
+                RelaychainCall::Utility(Box::new(UtilityCall::BatchAll(UtilityBatchAllCall {
+                    calls: vec![
+                        RelaychainCall::Utility(Box::new(UtilityCall::AsDerivative(
+                            UtilityAsDerivativeCall {
+                                index,
+                                call: RelaychainCall::NominationPools::<T>(
+                                    NominationPoolsCall::WithdrawUnbonded(
+                                        NominationPoolsWithdrawUnbondedCall {
+                                            member_account: T::Lookup::unlookup(member),
+                                            num_slashing_spans,
+                                        },
+                                    ),
+                                ),
+                            },
+                        ))),
+                        RelaychainCall::Utility(Box::new(UtilityCall::AsDerivative(
+                            UtilityAsDerivativeCall {
+                                index,
+                                call: RelaychainCall::Balances::<T>(BalancesCall::TransferAll(
+                                    BalancesTransferAllCall {
+                                        dest: T::Lookup::unlookup(para_account_id),
+                                        keep_alive: true,
+                                    },
+                                )),
+                            },
+                        ))),
+                    ],
+                })));
+
+            let msg = Self::do_ump_transact(
+                call.encode().into(),
+                xcm_weight_fee_misc.weight,
+                Self::refund_location(),
+                xcm_weight_fee_misc.fee,
             )?;
 
-            if let Err(_err) = T::XcmSender::send_xcm(MultiLocation::parent(), msg) {
-                return Err(Error::<T>::SendFailure.into());
+            Self::send_with_notify(
+                MultiLocation::parent(),
+                msg,
+                xcm_weight_fee_misc.fee,
+                callback,
+            )?
+        }))
+    }
+
+    fn track_query(query_id: QueryId, callback: PalletId) -> DispatchResult {
+        if OutboundXcms::<T>::contains_key(query_id) {
+            return Ok(());
+        }
+        ensure!(
+            pallet_xcm::Pallet::<T>::query(query_id).is_some(),
+            Error::<T>::OutboundXcmNotFound
+        );
+        OutboundXcms::<T>::insert(
+            query_id,
+            OutboundXcm {
+                origin_query_id: query_id,
+                dest: MultiLocation::parent(),
+                message: Xcm(vec![]),
+                fees: Zero::zero(),
+                callback,
+                retries: T::MaxXcmRetries::get(),
+                timeout: Self::notify_deadline(),
+            },
+        );
+        Ok(())
+    }
+}
+
+/// Applies the governance configuration of incoming xcm before the `Inner` barrier
+pub struct GovernedBarrier<T, Inner>(PhantomData<(T, Inner)>);
+impl<T: Config, Inner: ShouldExecute> ShouldExecute for GovernedBarrier<T, Inner> {
+    fn should_execute<Call>(
+        origin: &MultiLocation,
+        message: &mut Xcm<Call>,
+        max_weight: u64,
+        weight_credit: &mut u64,
+    ) -> Result<(), ()> {
+        if !Pallet::<T>::is_xcm_origin_allowed(origin) {
+            log::trace!(
+                target: "xcm::barrier",
+                "xcm from {:?} is not allowed",
+                origin
+            );
+            return Err(());
+        }
+        let max_instructions =
+            Pallet::<T>::xcm_max_instructions().unwrap_or_else(T::MaxXcmInstructions::get);
+        if message.0.len() > max_instructions as usize {
+            log::trace!(
+                target: "xcm::barrier",
+                "xcm from {:?} has {} instructions, max {}",
+                origin,
+                message.0.len(),
+                max_instructions
+            );
+            return Err(());
+        }
+        Inner::should_execute(origin, message, max_weight, weight_credit)
+    }
+}
+
+/// Traps assets with `Inner` and records them, so that they can be swept
+/// even if their origin is not able to claim them
+pub struct RecordAssetTrap<T, Inner>(PhantomData<(T, Inner)>);
+impl<T: Config, Inner: DropAssets> DropAssets for RecordAssetTrap<T, Inner> {
+    fn drop_assets(origin: &MultiLocation, assets: xcm_executor::Assets) -> u64 {
+        if assets.is_empty() {
+            return 0;
+        }
+        let versioned = VersionedMultiAssets::from(MultiAssets::from(assets.clone()));
+        let hash = Pallet::<T>::trapped_assets_hash(origin, &versioned);
+        TrappedAssets::<T>::mutate(hash, |trapped| match trapped {
+            Some(info) => info.count = info.count.saturating_add(1),
+            None => {
+                *trapped = Some(TrappedAssetsInfo {
+                    origin: origin.clone(),
+                    assets: versioned,
+                    count: 1,
+                })
             }
+        });
+        Inner::drop_assets(origin, assets)
+    }
+}
 
-            query_id
-        }))
+/// Claims assets with `Inner` and forgets the claimed ones
+pub struct ForgetClaimedAssets<T, Inner>(PhantomData<(T, Inner)>);
+impl<T: Config, Inner: ClaimAssets> ClaimAssets for ForgetClaimedAssets<T, Inner> {
+    fn claim_assets(origin: &MultiLocation, ticket: &MultiLocation, what: &MultiAssets) -> bool {
+        if !Inner::claim_assets(origin, ticket, what) {
+            return false;
+        }
+        let hash =
+            Pallet::<T>::trapped_assets_hash(origin, &VersionedMultiAssets::from(what.clone()));
+        TrappedAssets::<T>::mutate_exists(hash, |trapped| {
+            if let Some(info) = trapped {
+                info.count = info.count.saturating_sub(1);
+                if info.count == 0 {
+                    *trapped = None;
+                }
+            }
+        });
+        true
     }
 }
@@ -23,6 +23,7 @@ use sp_runtime::{
     AccountId32, DispatchError,
     MultiAddress::Id,
 };
+use std::cell::RefCell;
 pub use xcm::latest::prelude::*;
 pub use xcm_builder::{
     AccountId32Aliases, AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom,
@@ -40,7 +41,7 @@ pub type CurrencyId = u32;
 pub use kusama_runtime;
 use pallet_traits::{
     ump::{XcmCall, XcmWeightFeeMisc},
-    xcm::MultiCurrencyAdapter,
+    xcm::{MultiCurrencyAdapter, XcmResponse, XcmResponseCallback},
 };
 
 pub struct RelayChainBlockNumberProvider<T>(sp_std::marker::PhantomData<T>);
@@ -146,8 +147,8 @@ impl Config for XcmConfig {
     type Trader = FixedRateOfFungible<DotPerSecond, ()>;
     type ResponseHandler = ();
     type SubscriptionService = PolkadotXcm;
-    type AssetTrap = PolkadotXcm;
-    type AssetClaims = PolkadotXcm;
+    type AssetTrap = crate::RecordAssetTrap<Test, PolkadotXcm>;
+    type AssetClaims = crate::ForgetClaimedAssets<Test, PolkadotXcm>;
 }
 
 pub struct SystemParachainAsSuperuser<Origin>(PhantomData<Origin>);
@@ -231,6 +232,10 @@ impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
                     )),
                 ),
             )),
+            USDT => Some(MultiLocation::new(
+                1,
+                X3(Parachain(1000), PalletInstance(50), GeneralIndex(1984)),
+            )),
             _ => None,
         }
     }
@@ -249,6 +254,10 @@ impl Convert<MultiLocation, Option<CurrencyId>> for CurrencyIdConvert {
             } if ParaId::from(id) == ParachainInfo::parachain_id() && key == b"sDOT".to_vec() => {
                 Some(SDOT)
             }
+            MultiLocation {
+                parents: 1,
+                interior: X3(Parachain(1000), PalletInstance(50), GeneralIndex(1984)),
+            } => Some(USDT),
             _ => None,
         }
     }
@@ -289,6 +298,7 @@ type Block = frame_system::mocking::MockBlock<Test>;
 type BlockNumber = u32;
 type Index = u32;
 pub const DOT_DECIMAL: u128 = 10u128.pow(10);
+pub const USDT_DECIMAL: u128 = 10u128.pow(6);
 
 parameter_types! {
     pub const BlockHashCount: u32 = 250;
@@ -355,27 +365,74 @@ impl SortedMembers<AccountId> for BobOrigin {
 
 parameter_types! {
     pub SelfParaId: ParaId = para_a_id();
+    pub AssetHubParaId: ParaId = ParaId::from(1000);
 }
 
 parameter_types! {
     pub const XcmHelperPalletId: PalletId = PalletId(*b"par/fees");
     pub const NotifyTimeout: BlockNumber = 100;
+    pub const MaxXcmRetries: u32 = 3;
+    pub const MaxRescueReasonLength: u32 = 256;
     pub RefundLocation: AccountId = para_a_id().into_account_truncating();
 }
 
+thread_local! {
+    pub static RESPONSES: RefCell<Vec<(QueryId, XcmResponse)>> = RefCell::new(vec![]);
+    pub static RETRY_ON_ERROR: RefCell<bool> = RefCell::new(false);
+}
+
+/// Records the responses dispatched to it
+pub struct MockResponseCallback;
+impl XcmResponseCallback for MockResponseCallback {
+    fn callback_id() -> PalletId {
+        PalletId(*b"par/mock")
+    }
+
+    fn retry_on_error() -> bool {
+        RETRY_ON_ERROR.with(|r| *r.borrow())
+    }
+
+    fn on_success(query_id: QueryId) -> DispatchResult {
+        RESPONSES.with(|r| r.borrow_mut().push((query_id, XcmResponse::Success)));
+        Ok(())
+    }
+
+    fn on_error(query_id: QueryId, index: u32, error: XcmError) -> DispatchResult {
+        RESPONSES.with(|r| {
+            r.borrow_mut()
+                .push((query_id, XcmResponse::Error(index, error)))
+        });
+        Ok(())
+    }
+
+    fn on_timeout(query_id: QueryId) -> DispatchResult {
+        RESPONSES.with(|r| r.borrow_mut().push((query_id, XcmResponse::Timeout)));
+        Ok(())
+    }
+}
+
 impl crate::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
     type UpdateOrigin = EnsureRoot<AccountId>;
     type Assets = Assets;
     type XcmSender = XcmRouter;
     type PalletId = XcmHelperPalletId;
     type RelayNetwork = RelayNetwork;
     type NotifyTimeout = NotifyTimeout;
+    type MaxXcmRetries = MaxXcmRetries;
+    type ResponseHandler = (MockResponseCallback, XcmHelpers);
     type AccountIdToMultiLocation = AccountIdToMultiLocation;
     type RefundLocation = RefundLocation;
     type BlockNumberProvider = frame_system::Pallet<Test>;
     type WeightInfo = ();
     type RelayCurrency = RelayCurrency;
+    type SelfParaId = SelfParaId;
+    type AssetHubParaId = AssetHubParaId;
+    type CurrencyIdConvert = CurrencyIdConvert;
+    type MaxXcmInstructions = MaxInstructions;
+    type MaxRescueReasonLength = MaxRescueReasonLength;
 }
 
 parameter_types! {
@@ -440,6 +497,7 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
     ext.execute_with(|| {
         Assets::force_create(RuntimeOrigin::root(), DOT, Id(ALICE), true, 1).unwrap();
         Assets::force_create(RuntimeOrigin::root(), SDOT, Id(ALICE), true, 1).unwrap();
+        Assets::force_create(RuntimeOrigin::root(), USDT, Id(ALICE), true, 1).unwrap();
         Assets::mint(
             RuntimeOrigin::signed(ALICE),
             DOT,
@@ -447,6 +505,13 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
             100 * DOT_DECIMAL,
         )
         .unwrap();
+        Assets::mint(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            Id(ALICE),
+            100 * USDT_DECIMAL,
+        )
+        .unwrap();
         Assets::mint(
             RuntimeOrigin::signed(ALICE),
             SDOT,
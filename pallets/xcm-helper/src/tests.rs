@@ -1,6 +1,7 @@
 use super::*;
 use crate::mock::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok};
+use pallet_traits::xcm::XcmResponseCallback;
 
 use sp_runtime::traits::{One, Zero};
 
@@ -44,3 +45,892 @@ fn update_xcm_fees_should_work() {
         );
     });
 }
+
+#[test]
+fn estimate_xcm_fee_should_work() {
+    new_test_ext().execute_with(|| {
+        let message: Xcm<()> = Xcm(vec![
+            WithdrawAsset((Parent, dot(1f64)).into()),
+            BuyExecution {
+                fees: (Parent, dot(1f64)).into(),
+                weight_limit: Unlimited,
+            },
+            DepositAsset {
+                assets: All.into(),
+                max_assets: 1,
+                beneficiary: AccountId32 {
+                    network: Any,
+                    id: ALICE.into(),
+                }
+                .into(),
+            },
+        ]);
+
+        // local execution is priced by the executor's weigher and trader
+        assert_eq!(
+            XcmHelpers::estimate_xcm_fee::<XcmConfig, CurrencyIdConvert>(
+                MultiLocation::here().into(),
+                VersionedXcm::from(message.clone()),
+                DOT,
+            ),
+            Ok((3, 0))
+        );
+
+        // fee asset must be convertible to a location
+        assert_noop!(
+            XcmHelpers::estimate_xcm_fee::<XcmConfig, CurrencyIdConvert>(
+                MultiLocation::here().into(),
+                VersionedXcm::from(message.clone()),
+                HKO,
+            ),
+            Error::<Test>::FeeAssetNotSupported
+        );
+
+        // remote destinations charge at least the configured transfer fee
+        let statemine = MultiLocation::new(1, X1(Parachain(1000)));
+        assert_eq!(
+            XcmHelpers::estimate_xcm_fee::<XcmConfig, CurrencyIdConvert>(
+                statemine.clone().into(),
+                VersionedXcm::from(message.clone()),
+                DOT,
+            ),
+            Ok((
+                XcmWeightFeeMisc::default().weight.ref_time(),
+                XcmWeightFeeMisc::default().fee
+            ))
+        );
+        assert_eq!(
+            XcmHelpers::estimate_transfer_fee(statemine.clone().into()),
+            Ok((
+                XcmWeightFeeMisc::default().weight.ref_time(),
+                XcmWeightFeeMisc::default().fee
+            ))
+        );
+
+        // heavier messages are priced at the configured rate
+        assert_ok!(XcmHelpers::update_xcm_weight_fee(
+            frame_system::RawOrigin::Root.into(),
+            XcmCall::TransferToSiblingchain(Box::new(statemine.clone())),
+            XcmWeightFeeMisc {
+                weight: Weight::from_ref_time(2),
+                fee: 100,
+            },
+        ));
+        assert_eq!(
+            XcmHelpers::estimate_xcm_fee::<XcmConfig, CurrencyIdConvert>(
+                statemine.into(),
+                VersionedXcm::from(message),
+                DOT,
+            ),
+            Ok((3, 150))
+        );
+    });
+}
+
+#[test]
+fn dry_run_xcm_should_not_commit_changes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = AccountId32 {
+            network: Any,
+            id: ALICE.into(),
+        };
+        let bob = AccountId32 {
+            network: Any,
+            id: BOB.into(),
+        };
+        let message: Xcm<RuntimeCall> = Xcm(vec![
+            WithdrawAsset((Parent, dot(1f64)).into()),
+            DepositAsset {
+                assets: All.into(),
+                max_assets: 1,
+                beneficiary: bob.into(),
+            },
+        ]);
+
+        let effects = XcmHelpers::dry_run_xcm::<XcmConfig, frame_support::traits::Everything>(
+            MultiLocation::from(alice).into(),
+            VersionedXcm::from(message),
+        )
+        .unwrap();
+
+        assert_eq!(effects.outcome, Outcome::Complete(2));
+        assert!(!effects.emitted_events.is_empty());
+        assert_eq!(effects.asset_movements, effects.emitted_events);
+
+        // nothing is committed
+        assert_eq!(Assets::balance(DOT, BOB), 0);
+        assert_eq!(Assets::balance(DOT, ALICE), 100 * DOT_DECIMAL);
+    });
+}
+
+#[test]
+fn outbound_xcm_should_expire_after_timeout() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let callback = MockResponseCallback::callback_id();
+        let responder = MultiLocation::parent();
+        let query_id = XcmHelpers::do_withdraw(2000u32.into(), ALICE, callback).unwrap();
+        let outbound = XcmHelpers::outbound_xcm(query_id).unwrap();
+        assert_eq!(outbound.origin_query_id, query_id);
+        assert_eq!(outbound.retries, 0);
+
+        // not expired yet
+        XcmHelpers::on_idle(1, Weight::MAX);
+        assert!(XcmHelpers::outbound_xcm(query_id).is_some());
+
+        // expired xcm is not sent again since it may still be executed
+        System::set_block_number(outbound.timeout);
+        XcmHelpers::on_idle(outbound.timeout, Weight::MAX);
+        assert!(XcmHelpers::outbound_xcm(query_id).is_none());
+        System::assert_last_event(RuntimeEvent::XcmHelpers(Event::OutboundXcmExpired(
+            query_id,
+        )));
+        RESPONSES.with(|r| assert_eq!(*r.borrow(), vec![(query_id, XcmResponse::Timeout)]));
+
+        // a late response is ignored
+        assert_noop!(
+            XcmHelpers::on_response(
+                pallet_xcm::Origin::Response(responder).into(),
+                query_id,
+                Response::ExecutionResult(None)
+            ),
+            Error::<Test>::OutboundXcmNotFound
+        );
+        XcmHelpers::on_idle(outbound.timeout, Weight::MAX);
+        RESPONSES.with(|r| assert_eq!(r.borrow().len(), 1));
+    });
+}
+
+#[test]
+fn outbound_xcm_should_be_retried_on_error_until_max_retries() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        RETRY_ON_ERROR.with(|r| *r.borrow_mut() = true);
+        let callback = MockResponseCallback::callback_id();
+        let responder = MultiLocation::parent();
+        let error = Some((1, XcmError::Barrier));
+        let origin_query_id = XcmHelpers::do_withdraw(2000u32.into(), ALICE, callback).unwrap();
+
+        let mut query_id = origin_query_id;
+        for retries in 1..=MaxXcmRetries::get() {
+            assert_ok!(XcmHelpers::on_response(
+                pallet_xcm::Origin::Response(responder.clone()).into(),
+                query_id,
+                Response::ExecutionResult(error.clone())
+            ));
+            assert!(XcmHelpers::outbound_xcm(query_id).is_none());
+            System::assert_has_event(RuntimeEvent::XcmHelpers(Event::OutboundXcmRetried(
+                query_id,
+                query_id + 1,
+            )));
+            query_id += 1;
+            let retried = XcmHelpers::outbound_xcm(query_id).unwrap();
+            assert_eq!(retried.origin_query_id, origin_query_id);
+            assert_eq!(retried.retries, retries);
+        }
+        RESPONSES.with(|r| assert!(r.borrow().is_empty()));
+
+        // the last error is dispatched to the callbacks and the xcm is dropped
+        assert_ok!(XcmHelpers::on_response(
+            pallet_xcm::Origin::Response(responder).into(),
+            query_id,
+            Response::ExecutionResult(error)
+        ));
+        System::assert_has_event(RuntimeEvent::XcmHelpers(Event::OutboundXcmFailed(query_id)));
+        assert!(XcmHelpers::outbound_xcm(query_id).is_none());
+        assert_err!(
+            XcmHelpers::try_retry(query_id),
+            Error::<Test>::OutboundXcmNotFound
+        );
+        RESPONSES.with(|r| {
+            assert_eq!(
+                *r.borrow(),
+                vec![(origin_query_id, XcmResponse::Error(1, XcmError::Barrier))]
+            )
+        });
+    });
+}
+
+#[test]
+fn outbound_xcm_should_be_cancelled_by_governance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let callback = MockResponseCallback::callback_id();
+        let query_id = XcmHelpers::do_withdraw(2000u32.into(), ALICE, callback).unwrap();
+
+        assert_noop!(
+            XcmHelpers::cancel_outbound_xcm(RuntimeOrigin::signed(ALICE), query_id),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(XcmHelpers::cancel_outbound_xcm(
+            frame_system::RawOrigin::Root.into(),
+            query_id
+        ));
+        assert!(XcmHelpers::outbound_xcm(query_id).is_none());
+        assert_noop!(
+            XcmHelpers::cancel_outbound_xcm(frame_system::RawOrigin::Root.into(), query_id),
+            Error::<Test>::OutboundXcmNotFound
+        );
+    });
+}
+
+#[test]
+fn on_response_should_dispatch_to_callbacks() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let callback = MockResponseCallback::callback_id();
+        let responder = MultiLocation::parent();
+
+        let query_id = XcmHelpers::do_withdraw(2000u32.into(), ALICE, callback).unwrap();
+        assert_noop!(
+            XcmHelpers::on_response(
+                RuntimeOrigin::signed(ALICE),
+                query_id,
+                Response::ExecutionResult(None)
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(XcmHelpers::on_response(
+            pallet_xcm::Origin::Response(responder.clone()).into(),
+            query_id,
+            Response::ExecutionResult(None)
+        ));
+        System::assert_last_event(RuntimeEvent::XcmHelpers(Event::ResponseReceived(
+            Box::new(responder.clone()),
+            query_id,
+            None,
+        )));
+        assert!(XcmHelpers::outbound_xcm(query_id).is_none());
+
+        // failed xcm is not retried since the callbacks did not ask for it
+        let error = Some((1, XcmError::Barrier));
+        let failed_query_id = XcmHelpers::do_withdraw(2000u32.into(), ALICE, callback).unwrap();
+        assert_ok!(XcmHelpers::on_response(
+            pallet_xcm::Origin::Response(responder).into(),
+            failed_query_id,
+            Response::ExecutionResult(error.clone())
+        ));
+        assert!(XcmHelpers::outbound_xcm(failed_query_id).is_none());
+
+        RESPONSES.with(|r| {
+            assert_eq!(
+                *r.borrow(),
+                vec![
+                    (query_id, XcmResponse::Success),
+                    (failed_query_id, XcmResponse::Error(1, XcmError::Barrier)),
+                ]
+            )
+        });
+    });
+}
+
+#[test]
+fn on_idle_should_resume_from_cursor() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let callback = MockResponseCallback::callback_id();
+        let query_ids = (0..3)
+            .map(|_| XcmHelpers::do_withdraw(2000u32.into(), ALICE, callback).unwrap())
+            .collect::<Vec<_>>();
+        let timeout = XcmHelpers::outbound_xcm(query_ids[0]).unwrap().timeout;
+        System::set_block_number(timeout);
+
+        // only room for a single entry per block
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+        let entry_weight = db_weight
+            .reads(2)
+            .saturating_add(db_weight.writes(1))
+            .saturating_add(<() as WeightInfo>::on_response());
+        let remaining_weight = db_weight.reads_writes(1, 1).saturating_add(entry_weight);
+        for expired in 1..=3 {
+            XcmHelpers::on_idle(timeout, remaining_weight);
+            assert_eq!(OutboundXcms::<Test>::iter().count(), 3 - expired);
+            RESPONSES.with(|r| assert_eq!(r.borrow().len(), expired));
+        }
+        assert!(OutboundXcmCursor::<Test>::get().is_some());
+
+        // the cursor is dropped once the queue was walked through
+        XcmHelpers::on_idle(timeout, remaining_weight);
+        assert!(OutboundXcmCursor::<Test>::get().is_none());
+
+        let mut expired = RESPONSES.with(|r| {
+            r.borrow()
+                .iter()
+                .map(|(query_id, _)| *query_id)
+                .collect::<Vec<_>>()
+        });
+        expired.sort();
+        assert_eq!(expired, query_ids);
+    });
+}
+
+#[test]
+fn trapped_assets_should_be_claimed_by_their_origin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = MultiLocation::from(AccountId32 {
+            network: Any,
+            id: ALICE.into(),
+        });
+        let bob = MultiLocation::from(AccountId32 {
+            network: Any,
+            id: BOB.into(),
+        });
+        let assets: MultiAssets = (Parent, dot(1f64)).into();
+        let versioned = VersionedMultiAssets::from(assets.clone());
+        let hash = XcmHelpers::trapped_assets_hash(&alice, &versioned);
+
+        // same assets trapped twice
+        <XcmConfig as xcm_executor::Config>::AssetTrap::drop_assets(&alice, assets.clone().into());
+        <XcmConfig as xcm_executor::Config>::AssetTrap::drop_assets(&alice, assets.into());
+        assert_eq!(XcmHelpers::trapped_assets(hash).unwrap().count, 2);
+
+        assert_noop!(
+            XcmHelpers::claim_assets(
+                RuntimeOrigin::signed(BOB),
+                Box::new(versioned.clone()),
+                Box::new(bob.clone().into())
+            ),
+            Error::<Test>::TrappedAssetsNotFound
+        );
+        assert_ok!(XcmHelpers::claim_assets(
+            RuntimeOrigin::signed(ALICE),
+            Box::new(versioned.clone()),
+            Box::new(bob.clone().into())
+        ));
+        System::assert_last_event(RuntimeEvent::XcmHelpers(Event::TrappedAssetsClaimed(
+            hash,
+            bob.clone(),
+        )));
+        assert_eq!(XcmHelpers::trapped_assets(hash).unwrap().count, 1);
+        assert_eq!(Assets::balance(DOT, BOB), dot(1f64));
+
+        assert_ok!(XcmHelpers::claim_assets(
+            RuntimeOrigin::signed(ALICE),
+            Box::new(versioned),
+            Box::new(bob.into())
+        ));
+        assert!(XcmHelpers::trapped_assets(hash).is_none());
+        assert_eq!(Assets::balance(DOT, BOB), dot(2f64));
+    });
+}
+
+#[test]
+fn trapped_assets_should_be_swept_by_governance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let relay_alice = MultiLocation::new(
+            1,
+            X1(AccountId32 {
+                network: Any,
+                id: ALICE.into(),
+            }),
+        );
+        let bob = MultiLocation::from(AccountId32 {
+            network: Any,
+            id: BOB.into(),
+        });
+        let assets: MultiAssets = (Parent, dot(1f64)).into();
+        let versioned = VersionedMultiAssets::from(assets.clone());
+        let relay_alice_hash = XcmHelpers::trapped_assets_hash(&relay_alice, &versioned);
+        let parent_hash = XcmHelpers::trapped_assets_hash(&MultiLocation::parent(), &versioned);
+        <XcmConfig as xcm_executor::Config>::AssetTrap::drop_assets(
+            &relay_alice,
+            assets.clone().into(),
+        );
+        <XcmConfig as xcm_executor::Config>::AssetTrap::drop_assets(
+            &MultiLocation::parent(),
+            assets.into(),
+        );
+
+        assert_noop!(
+            XcmHelpers::sweep_trapped_assets(RuntimeOrigin::signed(ALICE), relay_alice_hash, None),
+            DispatchError::BadOrigin
+        );
+
+        // credited to the account the trapped origin belongs to
+        let alice_balance = Assets::balance(DOT, ALICE);
+        assert_ok!(XcmHelpers::sweep_trapped_assets(
+            frame_system::RawOrigin::Root.into(),
+            relay_alice_hash,
+            None
+        ));
+        System::assert_last_event(RuntimeEvent::XcmHelpers(Event::TrappedAssetsSwept(
+            relay_alice_hash,
+            MultiLocation::from(AccountId32 {
+                network: Any,
+                id: ALICE.into(),
+            }),
+        )));
+        assert_eq!(Assets::balance(DOT, ALICE), alice_balance + dot(1f64));
+        assert!(XcmHelpers::trapped_assets(relay_alice_hash).is_none());
+
+        // relaychain itself is not a local account
+        assert_noop!(
+            XcmHelpers::sweep_trapped_assets(
+                frame_system::RawOrigin::Root.into(),
+                parent_hash,
+                None
+            ),
+            Error::<Test>::BeneficiaryNotDeterminable
+        );
+        assert_ok!(XcmHelpers::sweep_trapped_assets(
+            frame_system::RawOrigin::Root.into(),
+            parent_hash,
+            Some(Box::new(bob.into()))
+        ));
+        assert_eq!(Assets::balance(DOT, BOB), dot(1f64));
+        assert_noop!(
+            XcmHelpers::sweep_trapped_assets(
+                frame_system::RawOrigin::Root.into(),
+                parent_hash,
+                None
+            ),
+            Error::<Test>::TrappedAssetsNotFound
+        );
+    });
+}
+
+#[test]
+fn hrmp_channels_should_follow_relaychain_responses() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let responder = MultiLocation::parent();
+        let outbound = HrmpChannelId {
+            sender: para_a_id(),
+            recipient: 2000u32.into(),
+        };
+        let inbound = HrmpChannelId {
+            sender: 3000u32.into(),
+            recipient: para_a_id(),
+        };
+
+        assert_noop!(
+            XcmHelpers::open_hrmp_channel(
+                frame_system::RawOrigin::Root.into(),
+                para_a_id(),
+                8,
+                1024
+            ),
+            Error::<Test>::InvalidHrmpChannel
+        );
+        assert_ok!(XcmHelpers::open_hrmp_channel(
+            frame_system::RawOrigin::Root.into(),
+            outbound.recipient,
+            8,
+            1024
+        ));
+        let query_id = hrmp_query_id(outbound);
+        System::assert_last_event(RuntimeEvent::XcmHelpers(Event::HrmpChannelRequested(
+            outbound,
+            HrmpChannelStatus::OpenRequested,
+            query_id,
+        )));
+        assert_noop!(
+            XcmHelpers::open_hrmp_channel(
+                frame_system::RawOrigin::Root.into(),
+                outbound.recipient,
+                8,
+                1024
+            ),
+            Error::<Test>::HrmpChannelAlreadyExists
+        );
+        assert_ok!(XcmHelpers::on_response(
+            pallet_xcm::Origin::Response(responder.clone()).into(),
+            query_id,
+            Response::ExecutionResult(None)
+        ));
+        assert_eq!(
+            XcmHelpers::hrmp_channel(outbound),
+            Some(HrmpChannelStatus::OpenPending)
+        );
+        assert!(XcmHelpers::hrmp_request(query_id).is_none());
+
+        // a failed close puts the channel back
+        assert_ok!(XcmHelpers::close_hrmp_channel(
+            frame_system::RawOrigin::Root.into(),
+            outbound
+        ));
+        assert_eq!(
+            XcmHelpers::hrmp_channel(outbound),
+            Some(HrmpChannelStatus::CloseRequested)
+        );
+        assert_noop!(
+            XcmHelpers::close_hrmp_channel(frame_system::RawOrigin::Root.into(), outbound),
+            Error::<Test>::InvalidHrmpChannelStatus
+        );
+        let query_id = hrmp_query_id(outbound);
+        assert_ok!(XcmHelpers::on_response(
+            pallet_xcm::Origin::Response(responder.clone()).into(),
+            query_id,
+            Response::ExecutionResult(Some((1, XcmError::Barrier)))
+        ));
+        assert_eq!(
+            XcmHelpers::hrmp_channel(outbound),
+            Some(HrmpChannelStatus::OpenPending)
+        );
+
+        assert_ok!(XcmHelpers::accept_hrmp_channel(
+            frame_system::RawOrigin::Root.into(),
+            inbound.sender
+        ));
+        let query_id = hrmp_query_id(inbound);
+        assert_ok!(XcmHelpers::on_response(
+            pallet_xcm::Origin::Response(responder).into(),
+            query_id,
+            Response::ExecutionResult(None)
+        ));
+        assert_eq!(
+            XcmHelpers::hrmp_channel(inbound),
+            Some(HrmpChannelStatus::Open)
+        );
+    });
+}
+
+fn hrmp_query_id(channel_id: HrmpChannelId) -> QueryId {
+    HrmpRequests::<Test>::iter()
+        .find(|(_, (id, _))| *id == channel_id)
+        .map(|(query_id, _)| query_id)
+        .unwrap()
+}
+
+fn reason(reason: &[u8]) -> BoundedVec<u8, MaxRescueReasonLength> {
+    reason.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn rescue_targets_should_be_whitelisted() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            XcmHelpers::add_rescue_target(RuntimeOrigin::signed(ALICE), BOB),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(XcmHelpers::add_rescue_target(
+            frame_system::RawOrigin::Root.into(),
+            BOB
+        ));
+        System::assert_last_event(RuntimeEvent::XcmHelpers(Event::RescueTargetAdded(BOB)));
+        assert_noop!(
+            XcmHelpers::add_rescue_target(frame_system::RawOrigin::Root.into(), BOB),
+            Error::<Test>::RescueTargetAlreadyWhitelisted
+        );
+
+        assert_ok!(XcmHelpers::remove_rescue_target(
+            frame_system::RawOrigin::Root.into(),
+            BOB
+        ));
+        assert!(XcmHelpers::rescue_target(BOB).is_none());
+        assert_noop!(
+            XcmHelpers::remove_rescue_target(frame_system::RawOrigin::Root.into(), BOB),
+            Error::<Test>::RescueTargetNotWhitelisted
+        );
+    });
+}
+
+#[test]
+fn rescue_funds_from_local_account_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let source = RescueSource::Local(ALICE);
+
+        assert_noop!(
+            XcmHelpers::rescue_funds(
+                frame_system::RawOrigin::Root.into(),
+                source.clone(),
+                USDT,
+                10 * USDT_DECIMAL,
+                BOB,
+                reason(b"sibling channel exploited")
+            ),
+            Error::<Test>::RescueTargetNotWhitelisted
+        );
+        assert_ok!(XcmHelpers::add_rescue_target(
+            frame_system::RawOrigin::Root.into(),
+            BOB
+        ));
+        assert_noop!(
+            XcmHelpers::rescue_funds(
+                frame_system::RawOrigin::Root.into(),
+                source.clone(),
+                USDT,
+                10 * USDT_DECIMAL,
+                BOB,
+                reason(b"")
+            ),
+            Error::<Test>::EmptyRescueReason
+        );
+
+        assert_ok!(XcmHelpers::rescue_funds(
+            frame_system::RawOrigin::Root.into(),
+            source.clone(),
+            USDT,
+            10 * USDT_DECIMAL,
+            BOB,
+            reason(b"sibling channel exploited")
+        ));
+        assert_eq!(Assets::balance(USDT, BOB), 10 * USDT_DECIMAL);
+        System::assert_last_event(RuntimeEvent::XcmHelpers(Event::FundsRescued(
+            0,
+            source.clone(),
+            USDT,
+            10 * USDT_DECIMAL,
+            BOB,
+            b"sibling channel exploited".to_vec(),
+        )));
+        assert_eq!(
+            XcmHelpers::funds_rescue(0),
+            Some(FundsRescue {
+                source,
+                asset: USDT,
+                amount: 10 * USDT_DECIMAL,
+                target: BOB,
+                reason: b"sibling channel exploited".to_vec(),
+                block_number: 1,
+                status: RescueStatus::Succeeded,
+            })
+        );
+        assert_eq!(XcmHelpers::next_rescue_id(), 1);
+    });
+}
+
+#[test]
+fn rescue_funds_from_relaychain_should_follow_responses() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let responder = MultiLocation::parent();
+        assert_ok!(XcmHelpers::add_rescue_target(
+            frame_system::RawOrigin::Root.into(),
+            BOB
+        ));
+
+        assert_noop!(
+            XcmHelpers::rescue_funds(
+                frame_system::RawOrigin::Root.into(),
+                RescueSource::RelaySovereign,
+                USDT,
+                10 * USDT_DECIMAL,
+                BOB,
+                reason(b"stuck in sovereign account")
+            ),
+            Error::<Test>::InvalidRescueAsset
+        );
+        assert_ok!(XcmHelpers::rescue_funds(
+            frame_system::RawOrigin::Root.into(),
+            RescueSource::RelaySovereign,
+            DOT,
+            dot(10f64),
+            BOB,
+            reason(b"stuck in sovereign account")
+        ));
+        let query_id = rescue_query_id(0);
+        assert_eq!(
+            XcmHelpers::funds_rescue(0).unwrap().status,
+            RescueStatus::Requested(query_id)
+        );
+        assert_ok!(XcmHelpers::on_response(
+            pallet_xcm::Origin::Response(responder.clone()).into(),
+            query_id,
+            Response::ExecutionResult(None)
+        ));
+        assert_eq!(
+            XcmHelpers::funds_rescue(0).unwrap().status,
+            RescueStatus::Succeeded
+        );
+        assert!(XcmHelpers::rescue_request(query_id).is_none());
+
+        assert_ok!(XcmHelpers::rescue_funds(
+            frame_system::RawOrigin::Root.into(),
+            RescueSource::RelayDerivative(0),
+            DOT,
+            dot(10f64),
+            BOB,
+            reason(b"slashed stash emptied")
+        ));
+        let query_id = rescue_query_id(1);
+        assert_ok!(XcmHelpers::on_response(
+            pallet_xcm::Origin::Response(responder).into(),
+            query_id,
+            Response::ExecutionResult(Some((1, XcmError::Barrier)))
+        ));
+        assert_eq!(
+            XcmHelpers::funds_rescue(1).unwrap().status,
+            RescueStatus::Failed
+        );
+    });
+}
+
+fn rescue_query_id(rescue_id: u32) -> QueryId {
+    RescueRequests::<Test>::iter()
+        .find(|(_, id)| *id == rescue_id)
+        .map(|(query_id, _)| query_id)
+        .unwrap()
+}
+
+#[test]
+fn transfer_to_asset_hub_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let fee = dot(1f64);
+        let info = AssetHubAssetInfo {
+            min_balance: USDT_DECIMAL / 100,
+            is_sufficient: true,
+        };
+        assert_ok!(XcmHelpers::update_xcm_weight_fee(
+            frame_system::RawOrigin::Root.into(),
+            XcmCall::TransferToSiblingchain(Box::new(MultiLocation::new(1, X1(Parachain(1000))))),
+            XcmWeightFeeMisc {
+                weight: Weight::from_ref_time(1_000_000_000),
+                fee,
+            }
+        ));
+
+        assert_noop!(
+            XcmHelpers::set_asset_hub_asset(RuntimeOrigin::root(), SDOT, Some(info)),
+            Error::<Test>::NotAssetHubAsset
+        );
+        assert_noop!(
+            XcmHelpers::transfer_to_asset_hub(
+                RuntimeOrigin::signed(ALICE),
+                USDT,
+                USDT_DECIMAL,
+                BOB
+            ),
+            Error::<Test>::AssetHubAssetNotSupported
+        );
+        assert_ok!(XcmHelpers::set_asset_hub_asset(
+            RuntimeOrigin::root(),
+            USDT,
+            Some(info)
+        ));
+        assert_eq!(
+            XcmHelpers::asset_hub_location(USDT),
+            Ok(MultiLocation::new(
+                0,
+                X2(PalletInstance(50), GeneralIndex(1984))
+            ))
+        );
+        assert_noop!(
+            XcmHelpers::transfer_to_asset_hub(RuntimeOrigin::signed(ALICE), USDT, 1, BOB),
+            Error::<Test>::AmountBelowMinBalance
+        );
+
+        let dot_balance = Assets::balance(DOT, ALICE);
+        assert_ok!(XcmHelpers::transfer_to_asset_hub(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            10 * USDT_DECIMAL,
+            BOB
+        ));
+        System::assert_last_event(RuntimeEvent::XcmHelpers(Event::TransferredToAssetHub(
+            ALICE,
+            USDT,
+            10 * USDT_DECIMAL,
+            BOB,
+            fee,
+        )));
+        assert_eq!(Assets::balance(USDT, ALICE), 90 * USDT_DECIMAL);
+        assert_eq!(Assets::balance(DOT, ALICE), dot_balance - fee);
+        assert_eq!(
+            Assets::balance(DOT, XcmHelpers::account_id()),
+            dot(30f64) + fee
+        );
+    });
+}
+
+#[test]
+fn barrier_should_follow_governance_configuration() {
+    new_test_ext().execute_with(|| {
+        type TestBarrier =
+            GovernedBarrier<Test, AllowUnpaidExecutionFrom<frame_support::traits::Everything>>;
+        let sibling = MultiLocation::new(1, X1(Parachain(2000)));
+        let other_sibling = MultiLocation::new(1, X1(Parachain(2001)));
+        let local = MultiLocation::new(
+            0,
+            X1(Junction::AccountId32 {
+                network: NetworkId::Any,
+                id: ALICE.into(),
+            }),
+        );
+        let should_execute = |origin: &MultiLocation, len: usize| {
+            let mut message = Xcm::<()>(vec![ClearOrigin; len]);
+            TestBarrier::should_execute(origin, &mut message, 0, &mut 0)
+        };
+        assert_ok!(should_execute(&sibling, 100));
+        assert_err!(should_execute(&sibling, 101), ());
+
+        // suspended parachains are rejected
+        assert_ok!(XcmHelpers::suspend_xcm_origin(
+            frame_system::RawOrigin::Root.into(),
+            2000.into()
+        ));
+        assert_noop!(
+            XcmHelpers::suspend_xcm_origin(frame_system::RawOrigin::Root.into(), 2000.into()),
+            Error::<Test>::XcmOriginAlreadySuspended
+        );
+        assert_err!(should_execute(&sibling, 1), ());
+        assert_ok!(should_execute(&other_sibling, 1));
+        assert_ok!(XcmHelpers::resume_xcm_origin(
+            frame_system::RawOrigin::Root.into(),
+            2000.into()
+        ));
+        assert_noop!(
+            XcmHelpers::resume_xcm_origin(frame_system::RawOrigin::Root.into(), 2000.into()),
+            Error::<Test>::XcmOriginNotSuspended
+        );
+        assert_ok!(should_execute(&sibling, 1));
+
+        // only allowed origins are accepted, local ones always are
+        assert_ok!(XcmHelpers::set_xcm_allowed_origins(
+            frame_system::RawOrigin::Root.into(),
+            Some(vec![MultiLocation::parent().into(), sibling.clone().into()])
+        ));
+        assert_ok!(should_execute(&MultiLocation::parent(), 1));
+        assert_ok!(should_execute(&sibling, 1));
+        assert_ok!(should_execute(&local, 1));
+        assert_err!(should_execute(&other_sibling, 1), ());
+        assert_ok!(XcmHelpers::set_xcm_allowed_origins(
+            frame_system::RawOrigin::Root.into(),
+            None
+        ));
+        assert_ok!(should_execute(&other_sibling, 1));
+
+        // max instructions can only be lowered
+        assert_noop!(
+            XcmHelpers::set_xcm_max_instructions(frame_system::RawOrigin::Root.into(), Some(101)),
+            Error::<Test>::InvalidMaxInstructions
+        );
+        assert_noop!(
+            XcmHelpers::set_xcm_max_instructions(frame_system::RawOrigin::Root.into(), Some(0)),
+            Error::<Test>::InvalidMaxInstructions
+        );
+        assert_ok!(XcmHelpers::set_xcm_max_instructions(
+            frame_system::RawOrigin::Root.into(),
+            Some(10)
+        ));
+        assert_ok!(should_execute(&sibling, 10));
+        assert_err!(should_execute(&sibling, 11), ());
+        System::assert_last_event(RuntimeEvent::XcmHelpers(Event::XcmMaxInstructionsUpdated(
+            Some(10),
+        )));
+    });
+}
+
+#[test]
+fn xcm_barrier_configuration_should_require_update_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            XcmHelpers::set_xcm_allowed_origins(RuntimeOrigin::signed(ALICE), None),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            XcmHelpers::set_xcm_max_instructions(RuntimeOrigin::signed(ALICE), None),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            XcmHelpers::suspend_xcm_origin(RuntimeOrigin::signed(ALICE), 2000.into()),
+            DispatchError::BadOrigin
+        );
+    });
+}
@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2022-05-30, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("kerria-dev"), DB CACHE: 1024
+//!
+//! The weights marked NOT BENCHMARKED YET were added by hand from the storage accesses of
+//! their calls and are estimates until this file is regenerated.
 
 // Executed Command:
 // ./target/release/parallel
@@ -43,9 +46,31 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions needed for pallet_xcm_helper.
 pub trait WeightInfo {
 	fn update_xcm_weight_fee() -> Weight;
+	fn resend_outbound_xcm() -> Weight;
+	fn cancel_outbound_xcm() -> Weight;
+	fn on_response() -> Weight;
+	fn claim_assets() -> Weight;
+	fn sweep_trapped_assets() -> Weight;
+	fn open_hrmp_channel() -> Weight;
+	fn accept_hrmp_channel() -> Weight;
+	fn close_hrmp_channel() -> Weight;
+	fn set_hrmp_channel_status() -> Weight;
+	fn set_asset_hub_asset() -> Weight;
+	fn transfer_to_asset_hub() -> Weight;
+	fn set_xcm_allowed_origins() -> Weight;
+	fn set_xcm_max_instructions() -> Weight;
+	fn suspend_xcm_origin() -> Weight;
+	fn resume_xcm_origin() -> Weight;
+	fn add_rescue_target() -> Weight;
+	fn remove_rescue_target() -> Weight;
+	fn rescue_funds() -> Weight;
 }
 
 /// Weights for pallet_xcm_helper using the Substrate node and recommended hardware.
@@ -56,8 +81,222 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn update_xcm_weight_fee() -> Weight {
 		Weight::from_ref_time(37_853_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper OutboundXcms (r:1 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	fn resend_outbound_xcm() -> Weight {
+		Weight::from_ref_time(62_318_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper OutboundXcms (r:1 w:1)
+	fn cancel_outbound_xcm() -> Weight {
+		Weight::from_ref_time(24_173_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper OutboundXcms (r:1 w:1)
+	// Storage: LiquidStaking XcmRequests (r:1 w:1)
+	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
+	fn on_response() -> Weight {
+		Weight::from_ref_time(58_214_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper TrappedAssets (r:1 w:1)
+	// Storage: PolkadotXcm AssetTraps (r:1 w:1)
+	// Storage: AssetRegistry LocationAssetId (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:0 w:1)
+	fn claim_assets() -> Weight {
+		Weight::from_ref_time(71_406_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper TrappedAssets (r:1 w:1)
+	// Storage: PolkadotXcm AssetTraps (r:1 w:1)
+	// Storage: AssetRegistry LocationAssetId (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:0 w:1)
+	fn sweep_trapped_assets() -> Weight {
+		Weight::from_ref_time(73_925_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper HrmpRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	fn open_hrmp_channel() -> Weight {
+		Weight::from_ref_time(58_204_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper HrmpRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	fn accept_hrmp_channel() -> Weight {
+		Weight::from_ref_time(56_732_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper HrmpRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	fn close_hrmp_channel() -> Weight {
+		Weight::from_ref_time(57_116_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:0 w:1)
+	fn set_hrmp_channel_status() -> Weight {
+		Weight::from_ref_time(18_530_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper AssetHubAssets (r:0 w:1)
+	fn set_asset_hub_asset() -> Weight {
+		Weight::from_ref_time(19_874_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper AssetHubAssets (r:1 w:0)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:3 w:3)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	// Storage: XcmpQueue OutboundXcmpMessages (r:0 w:1)
+	fn transfer_to_asset_hub() -> Weight {
+		Weight::from_ref_time(79_351_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper XcmAllowedOrigins (r:0 w:1)
+	fn set_xcm_allowed_origins() -> Weight {
+		Weight::from_ref_time(21_346_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper XcmMaxInstructions (r:0 w:1)
+	fn set_xcm_max_instructions() -> Weight {
+		Weight::from_ref_time(18_215_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper SuspendedXcmOrigins (r:1 w:1)
+	fn suspend_xcm_origin() -> Weight {
+		Weight::from_ref_time(20_731_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper SuspendedXcmOrigins (r:1 w:1)
+	fn resume_xcm_origin() -> Weight {
+		Weight::from_ref_time(21_092_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper RescueTargets (r:1 w:1)
+	fn add_rescue_target() -> Weight {
+		Weight::from_ref_time(20_486_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper RescueTargets (r:1 w:1)
+	fn remove_rescue_target() -> Weight {
+		Weight::from_ref_time(21_768_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper RescueTargets (r:1 w:0)
+	// Storage: XcmHelper NextRescueId (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper RescueRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	// Storage: XcmHelper FundsRescues (r:0 w:1)
+	fn rescue_funds() -> Weight {
+		Weight::from_ref_time(64_353_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -69,4 +308,199 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper OutboundXcms (r:1 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	fn resend_outbound_xcm() -> Weight {
+		Weight::from_ref_time(62_318_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper OutboundXcms (r:1 w:1)
+	fn cancel_outbound_xcm() -> Weight {
+		Weight::from_ref_time(24_173_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper OutboundXcms (r:1 w:1)
+	// Storage: LiquidStaking XcmRequests (r:1 w:1)
+	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
+	fn on_response() -> Weight {
+		Weight::from_ref_time(58_214_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper TrappedAssets (r:1 w:1)
+	// Storage: PolkadotXcm AssetTraps (r:1 w:1)
+	// Storage: AssetRegistry LocationAssetId (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:0 w:1)
+	fn claim_assets() -> Weight {
+		Weight::from_ref_time(71_406_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper TrappedAssets (r:1 w:1)
+	// Storage: PolkadotXcm AssetTraps (r:1 w:1)
+	// Storage: AssetRegistry LocationAssetId (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:0 w:1)
+	fn sweep_trapped_assets() -> Weight {
+		Weight::from_ref_time(73_925_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper HrmpRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	fn open_hrmp_channel() -> Weight {
+		Weight::from_ref_time(58_204_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper HrmpRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	fn accept_hrmp_channel() -> Weight {
+		Weight::from_ref_time(56_732_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper HrmpRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	fn close_hrmp_channel() -> Weight {
+		Weight::from_ref_time(57_116_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:0 w:1)
+	fn set_hrmp_channel_status() -> Weight {
+		Weight::from_ref_time(18_530_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper AssetHubAssets (r:0 w:1)
+	fn set_asset_hub_asset() -> Weight {
+		Weight::from_ref_time(19_874_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper AssetHubAssets (r:1 w:0)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:3 w:3)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	// Storage: XcmpQueue OutboundXcmpMessages (r:0 w:1)
+	fn transfer_to_asset_hub() -> Weight {
+		Weight::from_ref_time(79_351_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper XcmAllowedOrigins (r:0 w:1)
+	fn set_xcm_allowed_origins() -> Weight {
+		Weight::from_ref_time(21_346_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper XcmMaxInstructions (r:0 w:1)
+	fn set_xcm_max_instructions() -> Weight {
+		Weight::from_ref_time(18_215_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper SuspendedXcmOrigins (r:1 w:1)
+	fn suspend_xcm_origin() -> Weight {
+		Weight::from_ref_time(20_731_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper SuspendedXcmOrigins (r:1 w:1)
+	fn resume_xcm_origin() -> Weight {
+		Weight::from_ref_time(21_092_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper RescueTargets (r:1 w:1)
+	fn add_rescue_target() -> Weight {
+		Weight::from_ref_time(20_486_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper RescueTargets (r:1 w:1)
+	fn remove_rescue_target() -> Weight {
+		Weight::from_ref_time(21_768_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper RescueTargets (r:1 w:0)
+	// Storage: XcmHelper NextRescueId (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper RescueRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	// Storage: XcmHelper FundsRescues (r:0 w:1)
+	fn rescue_funds() -> Weight {
+		Weight::from_ref_time(64_353_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
 }
@@ -0,0 +1,155 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Zap pallet
+//!
+//! ## Overview
+//!
+//! Composite extrinsics running the steps of common onboarding flows as a single
+//! transaction, so a user is never left halfway with e.g. staked assets which were
+//! not supplied to the money market yet:
+//!
+//! - `stake_and_supply`: stake the relay currency, supply the minted liquid currency
+//!   to loans and enable it as collateral
+//! - `supply_and_borrow`: supply an asset to loans, enable it as collateral and borrow
+//!   another asset against it
+//!
+//! Each step fails the whole extrinsic, nothing of the earlier steps is kept.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use pallet_traits::{LiquidStaking, LiquidStakingCurrenciesProvider, Loans};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::traits::Zero;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Money market the assets are supplied to and borrowed from
+        type Loans: Loans<CurrencyId, Self::AccountId, Balance>;
+
+        /// Liquid staking protocol minting the liquid currency of staked assets
+        type LiquidStaking: LiquidStaking<Self::AccountId, Balance>
+            + LiquidStakingCurrenciesProvider<CurrencyId>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Staked assets and supplied the liquid currency as collateral
+        /// [who, staked_amount, liquid_currency, liquid_amount]
+        StakedAndSupplied(T::AccountId, Balance, CurrencyId, Balance),
+        /// Supplied an asset as collateral and borrowed against it
+        /// [who, supply_asset, supply_amount, borrow_asset, borrow_amount]
+        SuppliedAndBorrowed(T::AccountId, CurrencyId, Balance, CurrencyId, Balance),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Amount is zero
+        InvalidAmount,
+        /// Liquid currency of the liquid staking protocol isn't registered
+        LiquidCurrencyNotReady,
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Stake `amount` of the staking currency, supply the minted liquid currency
+        /// to loans and enable it as collateral.
+        ///
+        /// - `amount`: the amount of staking currency
+        #[pallet::weight(T::WeightInfo::stake_and_supply())]
+        #[transactional]
+        pub fn stake_and_supply(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+
+            let liquid_currency = T::LiquidStaking::get_liquid_currency()
+                .ok_or(Error::<T>::LiquidCurrencyNotReady)?;
+            let liquid_amount = T::LiquidStaking::do_stake(&who, amount)?;
+            T::Loans::do_mint(&who, liquid_currency, liquid_amount)?;
+            T::Loans::do_collateral_asset(&who, liquid_currency, true)?;
+
+            Self::deposit_event(Event::<T>::StakedAndSupplied(
+                who,
+                amount,
+                liquid_currency,
+                liquid_amount,
+            ));
+            Ok(())
+        }
+
+        /// Supply `supply_amount` of `supply_asset` to loans, enable it as collateral
+        /// and borrow `borrow_amount` of `borrow_asset`.
+        ///
+        /// - `supply_asset`: the asset to be supplied
+        /// - `supply_amount`: the amount to be supplied
+        /// - `borrow_asset`: the asset to be borrowed
+        /// - `borrow_amount`: the amount to be borrowed
+        #[pallet::weight(T::WeightInfo::supply_and_borrow())]
+        #[transactional]
+        pub fn supply_and_borrow(
+            origin: OriginFor<T>,
+            supply_asset: CurrencyId,
+            #[pallet::compact] supply_amount: Balance,
+            borrow_asset: CurrencyId,
+            #[pallet::compact] borrow_amount: Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                !supply_amount.is_zero() && !borrow_amount.is_zero(),
+                Error::<T>::InvalidAmount
+            );
+
+            T::Loans::do_mint(&who, supply_asset, supply_amount)?;
+            T::Loans::do_collateral_asset(&who, supply_asset, true)?;
+            T::Loans::do_borrow(&who, borrow_asset, borrow_amount)?;
+
+            Self::deposit_event(Event::<T>::SuppliedAndBorrowed(
+                who,
+                supply_asset,
+                supply_amount,
+                borrow_asset,
+                borrow_amount,
+            ));
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,290 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    storage::unhashed,
+    traits::{
+        fungibles::{Mutate, Transfer},
+        Everything,
+    },
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, DispatchError};
+
+pub use primitives::tokens::{DOT, SDOT, USDT};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+        Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+        Zap: crate::{Pallet, Call, Event<T>},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const LOANS_ACCOUNT: AccountId = 100;
+pub const STAKING_ACCOUNT: AccountId = 101;
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+    pub const MaxLocks: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = MaxLocks;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+}
+
+parameter_types! {
+    pub const AssetDeposit: u64 = 1;
+    pub const ApprovalDeposit: u64 = 1;
+    pub const AssetAccountDeposit: u64 = 1;
+    pub const StringLimit: u32 = 50;
+    pub const MetadataDepositBase: u64 = 1;
+    pub const MetadataDepositPerByte: u64 = 1;
+}
+
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = CurrencyId;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = AssetDeposit;
+    type MetadataDepositBase = MetadataDepositBase;
+    type MetadataDepositPerByte = MetadataDepositPerByte;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type ApprovalDeposit = ApprovalDeposit;
+    type StringLimit = StringLimit;
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = ();
+}
+
+/// Money market lending up to half of the collateral supplied, positions are kept in
+/// unhashed storage so that they are rolled back together with the failed extrinsic
+pub struct MockLoans;
+
+impl MockLoans {
+    pub fn supplied(who: &AccountId, asset_id: CurrencyId) -> Balance {
+        unhashed::get_or_default(&(b"supplied", who, asset_id).encode())
+    }
+
+    pub fn is_collateral(who: &AccountId, asset_id: CurrencyId) -> bool {
+        unhashed::get_or_default(&(b"collateral", who, asset_id).encode())
+    }
+
+    fn total_collateral(who: &AccountId) -> Balance {
+        [DOT, SDOT, USDT]
+            .into_iter()
+            .filter(|asset_id| Self::is_collateral(who, *asset_id))
+            .map(|asset_id| Self::supplied(who, asset_id))
+            .sum()
+    }
+}
+
+impl Loans<CurrencyId, AccountId, Balance> for MockLoans {
+    fn do_mint(
+        supplier: &AccountId,
+        asset_id: CurrencyId,
+        amount: Balance,
+    ) -> Result<(), DispatchError> {
+        <Assets as Transfer<AccountId>>::transfer(
+            asset_id,
+            supplier,
+            &LOANS_ACCOUNT,
+            amount,
+            false,
+        )?;
+        unhashed::put(
+            &(b"supplied", supplier, asset_id).encode(),
+            &(Self::supplied(supplier, asset_id) + amount),
+        );
+        Ok(())
+    }
+
+    fn do_borrow(
+        borrower: &AccountId,
+        asset_id: CurrencyId,
+        amount: Balance,
+    ) -> Result<(), DispatchError> {
+        if Self::total_collateral(borrower) / 2 < amount {
+            return Err(DispatchError::Other("InsufficientLiquidity"));
+        }
+        <Assets as Transfer<AccountId>>::transfer(
+            asset_id,
+            &LOANS_ACCOUNT,
+            borrower,
+            amount,
+            false,
+        )?;
+        Ok(())
+    }
+
+    fn do_collateral_asset(
+        supplier: &AccountId,
+        asset_id: CurrencyId,
+        enable: bool,
+    ) -> Result<(), DispatchError> {
+        if Self::supplied(supplier, asset_id).is_zero() {
+            return Err(DispatchError::Other("NoDeposit"));
+        }
+        unhashed::put(&(b"collateral", supplier, asset_id).encode(), &enable);
+        Ok(())
+    }
+
+    fn do_repay_borrow(
+        borrower: &AccountId,
+        asset_id: CurrencyId,
+        amount: Balance,
+    ) -> Result<(), DispatchError> {
+        <Assets as Transfer<AccountId>>::transfer(
+            asset_id,
+            borrower,
+            &LOANS_ACCOUNT,
+            amount,
+            false,
+        )?;
+        Ok(())
+    }
+
+    fn do_redeem(
+        supplier: &AccountId,
+        asset_id: CurrencyId,
+        amount: Balance,
+    ) -> Result<(), DispatchError> {
+        let supplied = Self::supplied(supplier, asset_id)
+            .checked_sub(amount)
+            .ok_or(DispatchError::Other("InsufficientDeposit"))?;
+        <Assets as Transfer<AccountId>>::transfer(
+            asset_id,
+            &LOANS_ACCOUNT,
+            supplier,
+            amount,
+            false,
+        )?;
+        unhashed::put(&(b"supplied", supplier, asset_id).encode(), &supplied);
+        Ok(())
+    }
+}
+
+/// Liquid staking minting SDOT for DOT at par
+pub struct MockLiquidStaking;
+
+impl LiquidStaking<AccountId, Balance> for MockLiquidStaking {
+    fn do_stake(who: &AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+        <Assets as Transfer<AccountId>>::transfer(DOT, who, &STAKING_ACCOUNT, amount, false)?;
+        <Assets as Mutate<AccountId>>::mint_into(SDOT, who, amount)?;
+        Ok(amount)
+    }
+}
+
+impl LiquidStakingCurrenciesProvider<CurrencyId> for MockLiquidStaking {
+    fn get_staking_currency() -> Option<CurrencyId> {
+        Some(DOT)
+    }
+
+    fn get_liquid_currency() -> Option<CurrencyId> {
+        Some(SDOT)
+    }
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Loans = MockLoans;
+    type LiquidStaking = MockLiquidStaking;
+    type WeightInfo = ();
+}
+
+pub fn dollar(d: u128) -> u128 {
+    d.saturating_mul(10_u128.pow(12))
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        for asset_id in [DOT, SDOT, USDT] {
+            Assets::force_create(RuntimeOrigin::root(), asset_id, ALICE, true, 1).unwrap();
+        }
+        Assets::mint(RuntimeOrigin::signed(ALICE), DOT, ALICE, dollar(1000)).unwrap();
+        Assets::mint(RuntimeOrigin::signed(ALICE), DOT, BOB, dollar(1000)).unwrap();
+        Assets::mint(
+            RuntimeOrigin::signed(ALICE),
+            USDT,
+            LOANS_ACCOUNT,
+            dollar(1000),
+        )
+        .unwrap();
+        System::set_block_number(1);
+    });
+    ext
+}
@@ -0,0 +1,112 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::DispatchError;
+
+#[test]
+fn stake_and_supply_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Zap::stake_and_supply(
+            RuntimeOrigin::signed(ALICE),
+            dollar(100)
+        ));
+
+        assert_eq!(Assets::balance(DOT, ALICE), dollar(900));
+        assert_eq!(Assets::balance(DOT, STAKING_ACCOUNT), dollar(100));
+        // the minted liquid currency went straight into the money market
+        assert_eq!(Assets::balance(SDOT, ALICE), 0);
+        assert_eq!(Assets::balance(SDOT, LOANS_ACCOUNT), dollar(100));
+        assert_eq!(MockLoans::supplied(&ALICE, SDOT), dollar(100));
+        assert!(MockLoans::is_collateral(&ALICE, SDOT));
+
+        System::assert_last_event(RuntimeEvent::Zap(Event::StakedAndSupplied(
+            ALICE,
+            dollar(100),
+            SDOT,
+            dollar(100),
+        )));
+    })
+}
+
+#[test]
+fn stake_and_supply_rejects_zero_amount() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Zap::stake_and_supply(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::InvalidAmount
+        );
+    })
+}
+
+#[test]
+fn supply_and_borrow_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Zap::supply_and_borrow(
+            RuntimeOrigin::signed(BOB),
+            DOT,
+            dollar(100),
+            USDT,
+            dollar(50)
+        ));
+
+        assert_eq!(Assets::balance(DOT, BOB), dollar(900));
+        assert_eq!(Assets::balance(USDT, BOB), dollar(50));
+        assert_eq!(MockLoans::supplied(&BOB, DOT), dollar(100));
+        assert!(MockLoans::is_collateral(&BOB, DOT));
+
+        System::assert_last_event(RuntimeEvent::Zap(Event::SuppliedAndBorrowed(
+            BOB,
+            DOT,
+            dollar(100),
+            USDT,
+            dollar(50),
+        )));
+    })
+}
+
+#[test]
+fn supply_and_borrow_reverts_supply_if_borrow_fails() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Zap::supply_and_borrow(
+                RuntimeOrigin::signed(BOB),
+                DOT,
+                dollar(100),
+                USDT,
+                dollar(51)
+            ),
+            DispatchError::Other("InsufficientLiquidity")
+        );
+        assert_eq!(Assets::balance(DOT, BOB), dollar(1000));
+        assert_eq!(MockLoans::supplied(&BOB, DOT), 0);
+        assert!(!MockLoans::is_collateral(&BOB, DOT));
+    })
+}
+
+#[test]
+fn supply_and_borrow_rejects_zero_amounts() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Zap::supply_and_borrow(RuntimeOrigin::signed(BOB), DOT, 0, USDT, dollar(50)),
+            Error::<Test>::InvalidAmount
+        );
+        assert_noop!(
+            Zap::supply_and_borrow(RuntimeOrigin::signed(BOB), DOT, dollar(100), USDT, 0),
+            Error::<Test>::InvalidAmount
+        );
+    })
+}
@@ -0,0 +1,71 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_zap
+//!
+//! A zap does nothing but run the wrapped extrinsics, so its weight is the sum of
+//! their benchmarked weights: `stake_and_supply` is liquid staking `stake` plus loans
+//! `mint` and `collateral_asset`, `supply_and_borrow` is loans `mint`, `collateral_asset`
+//! and `borrow`. Runtimes should sum their own weights of those extrinsics.
+//!
+//! NOT BENCHMARKED: the `SubstrateWeight` figures below are estimated sums for tests and
+//! runtimes without those pallets, not benchmark output.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions needed for pallet_zap.
+pub trait WeightInfo {
+	fn stake_and_supply() -> Weight;
+	fn supply_and_borrow() -> Weight;
+}
+
+/// Weights for pallet_zap using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn stake_and_supply() -> Weight {
+		Weight::from_ref_time(613_525_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(39 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(39 as u64))
+			.saturating_add(T::DbWeight::get().writes(25 as u64))
+	}
+	fn supply_and_borrow() -> Weight {
+		Weight::from_ref_time(680_663_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(46 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(46 as u64))
+			.saturating_add(T::DbWeight::get().writes(24 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn stake_and_supply() -> Weight {
+		Weight::from_ref_time(613_525_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(39 as u64))
+			.saturating_add(RocksDbWeight::get().writes(25 as u64))
+	}
+	fn supply_and_borrow() -> Weight {
+		Weight::from_ref_time(680_663_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(46 as u64))
+			.saturating_add(RocksDbWeight::get().writes(24 as u64))
+	}
+}
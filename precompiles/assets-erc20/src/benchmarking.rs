@@ -0,0 +1,189 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Assets ERC20 precompile benchmarking.
+//!
+//! Selectors dispatching an assets call are charged with the dispatch info, only the
+//! read-only ones are measured here.
+
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::traits::{fungibles::Mutate, Currency, Get};
+use frame_system::RawOrigin as SystemOrigin;
+
+pub struct Pallet<T: Config>(PhantomData<T>);
+
+pub trait Config:
+    pallet_assets::Config
+    + pallet_evm::Config
+    + pallet_timestamp::Config
+    + AddressToAssetId<AssetIdOf<Self>>
+{
+}
+
+type DepositBalanceOf<T> = <<T as pallet_assets::Config>::Currency as Currency<
+    <T as frame_system::Config>::AccountId,
+>>::Balance;
+
+fn owner() -> H160 {
+    H160::repeat_byte(0xBB)
+}
+
+fn spender() -> H160 {
+    H160::repeat_byte(0xCC)
+}
+
+/// Creates an asset with metadata of the maximum length and a funded owner,
+/// returns the address of its precompile.
+fn create_asset<T: Config>() -> H160 {
+    let asset_id: AssetIdOf<T> = Default::default();
+    let owner = T::AddressMapping::into_account_id(owner());
+    let metadata = vec![b'X'; T::StringLimit::get() as usize];
+
+    <T as pallet_assets::Config>::Currency::make_free_balance_be(
+        &owner,
+        DepositBalanceOf::<T>::max_value() / 2u32.into(),
+    );
+    pallet_assets::Pallet::<T>::force_create(
+        SystemOrigin::Root.into(),
+        asset_id,
+        T::Lookup::unlookup(owner.clone()),
+        true,
+        1u32.into(),
+    )
+    .ok();
+    pallet_assets::Pallet::<T>::force_set_metadata(
+        SystemOrigin::Root.into(),
+        asset_id,
+        metadata.clone(),
+        metadata,
+        18,
+        false,
+    )
+    .ok();
+    pallet_assets::Pallet::<T>::mint_into(asset_id, &owner, 1_000_000u32.into()).ok();
+
+    T::asset_id_to_address(asset_id)
+}
+
+fn execute<T>(address: H160, input: Vec<u8>) -> Result<(), BenchmarkError>
+where
+    Erc20AssetsPrecompileSet<T>: PrecompileSet,
+{
+    let mut handle = precompile_utils::benchmarking::BenchHandle::new(address, owner(), input);
+
+    match Erc20AssetsPrecompileSet::<T>::new().execute(&mut handle) {
+        Some(Ok(_)) => Ok(()),
+        _ => Err(BenchmarkError::Stop("precompile execution failed")),
+    }
+}
+
+benchmarks! {
+    where_clause {
+        where
+            T::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+            T::RuntimeCall: From<pallet_assets::Call<T>>,
+            <T::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<T::AccountId>>,
+            BalanceOf<T>: TryFrom<U256> + Into<U256> + EvmData,
+            <<T as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: OriginTrait,
+            <T as pallet_timestamp::Config>::Moment: Into<U256>,
+            AssetIdOf<T>: Display,
+    }
+
+    total_supply {
+        let address = create_asset::<T>();
+        let input = EvmDataWriter::new_with_selector(Action::TotalSupply).build();
+    }: {
+        execute::<T>(address, input)?;
+    }
+
+    balance_of {
+        let address = create_asset::<T>();
+        let input = EvmDataWriter::new_with_selector(Action::BalanceOf)
+            .write(Address(owner()))
+            .build();
+    }: {
+        execute::<T>(address, input)?;
+    }
+
+    allowance {
+        let address = create_asset::<T>();
+        pallet_assets::Pallet::<T>::approve_transfer(
+            SystemOrigin::Signed(T::AddressMapping::into_account_id(owner())).into(),
+            Default::default(),
+            T::Lookup::unlookup(T::AddressMapping::into_account_id(spender())),
+            1_000u32.into(),
+        )
+        .ok();
+        let input = EvmDataWriter::new_with_selector(Action::Allowance)
+            .write(Address(owner()))
+            .write(Address(spender()))
+            .build();
+    }: {
+        execute::<T>(address, input)?;
+    }
+
+    name {
+        let address = create_asset::<T>();
+        let input = EvmDataWriter::new_with_selector(Action::Name).build();
+    }: {
+        execute::<T>(address, input)?;
+    }
+
+    symbol {
+        let address = create_asset::<T>();
+        let input = EvmDataWriter::new_with_selector(Action::Symbol).build();
+    }: {
+        execute::<T>(address, input)?;
+    }
+
+    decimals {
+        let address = create_asset::<T>();
+        let input = EvmDataWriter::new_with_selector(Action::Decimals).build();
+    }: {
+        execute::<T>(address, input)?;
+    }
+
+    minimum_balance {
+        let address = create_asset::<T>();
+        let input = EvmDataWriter::new_with_selector(Action::MinimumBalance).build();
+    }: {
+        execute::<T>(address, input)?;
+    }
+
+    nonces {
+        let address = create_asset::<T>();
+        eip2612::NoncesStorage::<()>::insert(address, owner(), U256::one());
+        let input = EvmDataWriter::new_with_selector(Action::Eip2612Nonces)
+            .write(Address(owner()))
+            .build();
+    }: {
+        execute::<T>(address, input)?;
+    }
+
+    domain_separator {
+        let address = create_asset::<T>();
+        let input = EvmDataWriter::new_with_selector(Action::Eip2612DomainSeparator).build();
+    }: {
+        execute::<T>(address, input)?;
+    }
+}
+
+impl_benchmark_test_suite!(
+    Pallet,
+    crate::mock::ExtBuilder::default().build(),
+    crate::mock::Runtime
+);
@@ -109,10 +109,13 @@ pub type NoncesStorage<Instance> = StorageDoubleMap<
     ValueQuery,
 >;
 
-pub struct Eip2612<Runtime, Instance: 'static = ()>(PhantomData<(Runtime, Instance)>);
+pub struct Eip2612<Runtime, Instance: 'static = (), Weights = ()>(
+    PhantomData<(Runtime, Instance, Weights)>,
+);
 
-impl<Runtime, Instance> Eip2612<Runtime, Instance>
+impl<Runtime, Instance, Weights> Eip2612<Runtime, Instance, Weights>
 where
+    Weights: WeightInfo,
     Instance: InstanceToPrefix + 'static,
     Runtime: pallet_assets::Config<Instance>
         + pallet_evm::Config
@@ -227,7 +230,7 @@ where
 
         NoncesStorage::<Instance>::insert(address, owner, nonce + U256::one());
 
-        Erc20AssetsPrecompileSet::<Runtime, Instance>::approve_inner(
+        Erc20AssetsPrecompileSet::<Runtime, Instance, Weights>::approve_inner(
             asset_id, handle, owner, spender, value,
         )?;
 
@@ -247,7 +250,7 @@ where
         _asset_id: AssetIdOf<Runtime, Instance>,
         handle: &mut impl PrecompileHandle,
     ) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(Weights::nonces()))?;
 
         let mut input = handle.read_input()?;
         let owner: H160 = input.read::<Address>()?.into();
@@ -261,7 +264,9 @@ where
         asset_id: AssetIdOf<Runtime, Instance>,
         handle: &mut impl PrecompileHandle,
     ) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::domain_separator(),
+        ))?;
 
         let domain_separator: H256 =
             Self::compute_domain_separator(handle.code_address(), asset_id).into();
@@ -36,15 +36,22 @@ use sp_core::{H160, U256};
 use sp_std::{
     convert::{TryFrom, TryInto},
     marker::PhantomData,
+    vec,
+    vec::Vec,
 };
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
 mod eip2612;
+pub mod weights;
 
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+pub use weights::WeightInfo;
+
 /// Solidity selector of the Transfer log, which is the Keccak of the Log signature.
 pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
 
@@ -57,6 +64,9 @@ pub type BalanceOf<Runtime, Instance = ()> = <Runtime as pallet_assets::Config<I
 /// Alias for the Asset Id type for the provided Runtime and Instance.
 pub type AssetIdOf<Runtime, Instance = ()> = <Runtime as pallet_assets::Config<Instance>>::AssetId;
 
+/// Interface version of the precompile, bumped on breaking changes of its functions.
+pub const INTERFACE_VERSION: u32 = 1;
+
 #[precompile_utils::generate_function_selector]
 #[derive(Debug, PartialEq, Eq)]
 pub enum Action {
@@ -78,6 +88,29 @@ pub enum Action {
     Eip2612DomainSeparator = "DOMAIN_SEPARATOR()",
 }
 
+impl Action {
+    /// Selectors of all the functions of the precompile.
+    pub fn selectors() -> Vec<u32> {
+        vec![
+            Action::TotalSupply as u32,
+            Action::BalanceOf as u32,
+            Action::Allowance as u32,
+            Action::Transfer as u32,
+            Action::Approve as u32,
+            Action::TransferFrom as u32,
+            Action::Name as u32,
+            Action::Symbol as u32,
+            Action::Decimals as u32,
+            Action::MinimumBalance as u32,
+            Action::Mint as u32,
+            Action::Burn as u32,
+            Action::Eip2612Permit as u32,
+            Action::Eip2612Nonces as u32,
+            Action::Eip2612DomainSeparator as u32,
+        ]
+    }
+}
+
 /// This trait ensure we can convert EVM address to AssetIds
 /// We will require Runtime to have this trait implemented
 pub trait AddressToAssetId<AssetId> {
@@ -98,24 +131,28 @@ pub trait AddressToAssetId<AssetId> {
 
 /// This means that every address that starts with 0xFFFFFFFF will go through an additional db read,
 /// but the probability for this to happen is 2^-32 for random addresses
-pub struct Erc20AssetsPrecompileSet<Runtime, Instance: 'static = ()>(
-    PhantomData<(Runtime, Instance)>,
+///
+/// Selectors which don't dispatch a call are charged from `Weights`.
+pub struct Erc20AssetsPrecompileSet<Runtime, Instance: 'static = (), Weights = ()>(
+    PhantomData<(Runtime, Instance, Weights)>,
 );
 
-impl<Runtime, Instance> Erc20AssetsPrecompileSet<Runtime, Instance> {
+impl<Runtime, Instance, Weights> Erc20AssetsPrecompileSet<Runtime, Instance, Weights> {
     pub fn new() -> Self {
         Self(PhantomData)
     }
 }
 
-impl<Runtime, Instance> Default for Erc20AssetsPrecompileSet<Runtime, Instance> {
+impl<Runtime, Instance, Weights> Default for Erc20AssetsPrecompileSet<Runtime, Instance, Weights> {
     fn default() -> Self {
         Self(PhantomData)
     }
 }
 
-impl<Runtime, Instance> PrecompileSet for Erc20AssetsPrecompileSet<Runtime, Instance>
+impl<Runtime, Instance, Weights> PrecompileSet
+    for Erc20AssetsPrecompileSet<Runtime, Instance, Weights>
 where
+    Weights: WeightInfo,
     Instance: eip2612::InstanceToPrefix + 'static,
     Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + frame_system::Config,
     Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
@@ -168,13 +205,13 @@ where
                         Action::Burn => Self::burn(asset_id, handle),
                         // EIP2612
                         Action::Eip2612Permit => {
-                            eip2612::Eip2612::<Runtime, Instance>::permit(asset_id, handle)
+                            eip2612::Eip2612::<Runtime, Instance, Weights>::permit(asset_id, handle)
                         }
                         Action::Eip2612Nonces => {
-                            eip2612::Eip2612::<Runtime, Instance>::nonces(asset_id, handle)
+                            eip2612::Eip2612::<Runtime, Instance, Weights>::nonces(asset_id, handle)
                         }
                         Action::Eip2612DomainSeparator => {
-                            eip2612::Eip2612::<Runtime, Instance>::domain_separator(
+                            eip2612::Eip2612::<Runtime, Instance, Weights>::domain_separator(
                                 asset_id, handle,
                             )
                         }
@@ -195,8 +232,9 @@ where
     }
 }
 
-impl<Runtime, Instance> Erc20AssetsPrecompileSet<Runtime, Instance>
+impl<Runtime, Instance, Weights> Erc20AssetsPrecompileSet<Runtime, Instance, Weights>
 where
+    Weights: WeightInfo,
     Instance: eip2612::InstanceToPrefix + 'static,
     Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + frame_system::Config,
     Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
@@ -210,7 +248,9 @@ where
         asset_id: AssetIdOf<Runtime, Instance>,
         handle: &mut impl PrecompileHandle,
     ) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::total_supply(),
+        ))?;
 
         // Fetch info.
         let amount: U256 =
@@ -223,7 +263,9 @@ where
         asset_id: AssetIdOf<Runtime, Instance>,
         handle: &mut impl PrecompileHandle,
     ) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::balance_of(),
+        ))?;
 
         let mut input = handle.read_input()?;
         input.expect_arguments(1)?;
@@ -243,7 +285,9 @@ where
         asset_id: AssetIdOf<Runtime, Instance>,
         handle: &mut impl PrecompileHandle,
     ) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::allowance(),
+        ))?;
 
         let mut input = handle.read_input()?;
         input.expect_arguments(2)?;
@@ -433,7 +477,7 @@ where
         asset_id: AssetIdOf<Runtime, Instance>,
         handle: &mut impl PrecompileHandle,
     ) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(Weights::name()))?;
 
         Ok(succeed(
             EvmDataWriter::new()
@@ -450,7 +494,7 @@ where
         asset_id: AssetIdOf<Runtime, Instance>,
         handle: &mut impl PrecompileHandle,
     ) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(Weights::symbol()))?;
 
         // Build output.
         Ok(succeed(
@@ -468,7 +512,9 @@ where
         asset_id: AssetIdOf<Runtime, Instance>,
         handle: &mut impl PrecompileHandle,
     ) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::decimals(),
+        ))?;
 
         // Build output.
         Ok(succeed(
@@ -484,7 +530,9 @@ where
         asset_id: AssetIdOf<Runtime, Instance>,
         handle: &mut impl PrecompileHandle,
     ) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::minimum_balance(),
+        ))?;
 
         let min_balance: U256 =
             pallet_assets::Pallet::<Runtime, Instance>::minimum_balance(asset_id).into();
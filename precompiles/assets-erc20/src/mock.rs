@@ -86,6 +86,9 @@ impl AddressMapping<Account> for Account {
 
 pub const ASSET_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[255u8; 4];
 
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::benchmarking::Config for Runtime {}
+
 // Implement the trait, where we convert AccountId to AssetID
 impl AddressToAssetId<AssetId> for Runtime {
     /// The way to convert an account to assetId is by ensuring that the prefix is 0XFFFFFFFF
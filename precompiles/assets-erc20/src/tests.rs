@@ -116,7 +116,7 @@ fn get_total_supply() {
                     Account::AssetId(0u128),
                     EvmDataWriter::new_with_selector(Action::TotalSupply).build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::total_supply().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(1000u64)).build());
         });
@@ -150,7 +150,7 @@ fn get_balances_known_user() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(1000u64)).build());
         });
@@ -178,7 +178,7 @@ fn get_balances_unknown_user() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u64)).build());
         });
@@ -269,7 +269,7 @@ fn approve_saturating() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0u64)
+                .expect_cost(<() as WeightInfo>::allowance().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(u128::MAX)).build());
         });
@@ -315,7 +315,7 @@ fn check_allowance_existing() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::allowance().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(500u64)).build());
         });
@@ -344,7 +344,7 @@ fn check_allowance_not_existing() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::allowance().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u64)).build());
         });
@@ -395,7 +395,7 @@ fn transfer() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(400)).build());
 
@@ -407,7 +407,7 @@ fn transfer() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(600)).build());
         });
@@ -519,7 +519,7 @@ fn transfer_from() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(600)).build());
 
@@ -531,7 +531,7 @@ fn transfer_from() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0)).build());
 
@@ -543,7 +543,7 @@ fn transfer_from() {
                         .write(Address(Account::Charlie.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(400)).build());
         });
@@ -726,7 +726,7 @@ fn transfer_from_self() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(600)).build());
 
@@ -738,7 +738,7 @@ fn transfer_from_self() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(400)).build());
         });
@@ -772,7 +772,7 @@ fn get_metadata() {
                     Account::AssetId(0u128),
                     EvmDataWriter::new_with_selector(Action::Name).build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::name().ref_time())
                 .expect_no_logs()
                 .execute_returns(
                     EvmDataWriter::new()
@@ -786,7 +786,7 @@ fn get_metadata() {
                     Account::AssetId(0u128),
                     EvmDataWriter::new_with_selector(Action::Symbol).build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::symbol().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write::<Bytes>("Test".into()).build());
 
@@ -796,7 +796,7 @@ fn get_metadata() {
                     Account::AssetId(0u128),
                     EvmDataWriter::new_with_selector(Action::Decimals).build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::decimals().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(12u8).build());
         });
@@ -820,7 +820,7 @@ fn minimum_balance_is_right() {
                 Account::AssetId(0u128),
                 EvmDataWriter::new_with_selector(Action::MinimumBalance).build(),
             )
-            .expect_cost(0) // TODO: Test db read/write costs
+            .expect_cost(<() as WeightInfo>::minimum_balance().ref_time())
             .expect_no_logs()
             .execute_returns(EvmDataWriter::new().write(expected_min_balance).build());
     });
@@ -0,0 +1,111 @@
+// This file is part of Parallel Finance.
+
+// Copyright (C) 2022 Parallel Finance Developer.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_evm_precompile_assets_erc20
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_evm_precompile_assets_erc20
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./precompiles/assets-erc20/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_evm_precompile_assets_erc20.
+pub trait WeightInfo {
+	fn total_supply() -> Weight;
+	fn balance_of() -> Weight;
+	fn allowance() -> Weight;
+	fn name() -> Weight;
+	fn symbol() -> Weight;
+	fn decimals() -> Weight;
+	fn minimum_balance() -> Weight;
+	fn nonces() -> Weight;
+	fn domain_separator() -> Weight;
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Assets Asset (r:1 w:0)
+	fn total_supply() -> Weight {
+		Weight::from_ref_time(7_412_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Account (r:1 w:0)
+	fn balance_of() -> Weight {
+		Weight::from_ref_time(9_873_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Approvals (r:1 w:0)
+	fn allowance() -> Weight {
+		Weight::from_ref_time(10_216_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	fn name() -> Weight {
+		Weight::from_ref_time(8_957_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	fn symbol() -> Weight {
+		Weight::from_ref_time(8_902_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	fn decimals() -> Weight {
+		Weight::from_ref_time(8_641_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	fn minimum_balance() -> Weight {
+		Weight::from_ref_time(7_538_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Erc20Instance0Assets Nonces (r:1 w:0)
+	fn nonces() -> Weight {
+		Weight::from_ref_time(8_304_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	fn domain_separator() -> Weight {
+		Weight::from_ref_time(13_785_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+	}
+}
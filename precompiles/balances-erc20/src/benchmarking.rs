@@ -0,0 +1,277 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Balances ERC20 precompile benchmarking.
+//!
+//! Every selector which is not charged through a dispatched call is executed here
+//! against the runtime, the results are the costs `Weights` records in gas.
+
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+use crate::eip2612::Eip2612;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::traits::{Currency, Get};
+use precompile_utils::benchmarking::BenchHandle;
+use sp_core::{crypto::KeyTypeId, H256};
+use sp_io::hashing::keccak_256;
+
+const BENCH_KEY_TYPE: KeyTypeId = KeyTypeId(*b"e20b");
+
+pub struct Pallet<T: Config>(PhantomData<T>);
+
+pub trait Config: pallet_balances::Config + pallet_evm::Config + pallet_timestamp::Config {}
+
+/// Metadata of the benchmarked token, it stands for the native currency.
+pub struct BenchMetadata;
+
+impl Erc20Metadata for BenchMetadata {
+    fn name() -> &'static str {
+        "Benchmark token"
+    }
+
+    fn symbol() -> &'static str {
+        "BENCH"
+    }
+
+    fn decimals() -> u8 {
+        18
+    }
+
+    fn is_native_currency() -> bool {
+        true
+    }
+}
+
+type BenchPrecompile<T> = Erc20BalancesPrecompile<T, BenchMetadata>;
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(2048)
+}
+
+fn caller() -> H160 {
+    H160::repeat_byte(0xAA)
+}
+
+fn fund<T: Config>(address: H160) {
+    let account = T::AddressMapping::into_account_id(address);
+    let amount = T::ExistentialDeposit::get().saturating_add(u32::MAX.into());
+    pallet_balances::Pallet::<T>::make_free_balance_be(&account, amount);
+}
+
+/// Ethereum address of a key held by the benchmark keystore.
+fn keystore_address() -> (sp_core::ecdsa::Public, H160) {
+    let public = sp_io::crypto::ecdsa_generate(BENCH_KEY_TYPE, None);
+    let message = [0u8; 32];
+    let signature = sp_io::crypto::ecdsa_sign_prehashed(BENCH_KEY_TYPE, &public, &message)
+        .expect("key was just generated; qed");
+    let uncompressed = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message)
+        .expect("signature was just created; qed");
+    let address = H160::from(H256::from_slice(keccak_256(&uncompressed).as_slice()));
+
+    (public, address)
+}
+
+fn execute<T>(mut handle: BenchHandle) -> Result<(), BenchmarkError>
+where
+    BenchPrecompile<T>: Precompile,
+{
+    BenchPrecompile::<T>::execute(&mut handle)
+        .map(|_| ())
+        .map_err(|_| BenchmarkError::Stop("precompile execution failed"))
+}
+
+benchmarks! {
+    where_clause {
+        where
+            T::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+            T::RuntimeCall: From<pallet_balances::Call<T>>,
+            <T::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<T::AccountId>>,
+            BalanceOf<T>: TryFrom<U256> + Into<U256>,
+            <T as pallet_timestamp::Config>::Moment: Into<U256>,
+    }
+
+    total_supply {
+        let handle = BenchHandle::new(
+            precompile_address(),
+            caller(),
+            EvmDataWriter::new_with_selector(Action::TotalSupply).build(),
+        );
+    }: {
+        execute::<T>(handle)?;
+    }
+
+    balance_of {
+        let owner = H160::repeat_byte(0xBB);
+        fund::<T>(owner);
+        let handle = BenchHandle::new(
+            precompile_address(),
+            caller(),
+            EvmDataWriter::new_with_selector(Action::BalanceOf)
+                .write(Address(owner))
+                .build(),
+        );
+    }: {
+        execute::<T>(handle)?;
+    }
+
+    allowance {
+        let owner = H160::repeat_byte(0xBB);
+        let spender = H160::repeat_byte(0xCC);
+        ApprovesStorage::<T, ()>::insert(
+            T::AddressMapping::into_account_id(owner),
+            T::AddressMapping::into_account_id(spender),
+            BalanceOf::<T>::max_value(),
+        );
+        let handle = BenchHandle::new(
+            precompile_address(),
+            caller(),
+            EvmDataWriter::new_with_selector(Action::Allowance)
+                .write(Address(owner))
+                .write(Address(spender))
+                .build(),
+        );
+    }: {
+        execute::<T>(handle)?;
+    }
+
+    approve {
+        let spender = H160::repeat_byte(0xCC);
+        let handle = BenchHandle::new(
+            precompile_address(),
+            caller(),
+            EvmDataWriter::new_with_selector(Action::Approve)
+                .write(Address(spender))
+                .write(U256::MAX)
+                .build(),
+        );
+    }: {
+        execute::<T>(handle)?;
+    }
+    verify {
+        assert!(ApprovesStorage::<T, ()>::get(
+            T::AddressMapping::into_account_id(caller()),
+            T::AddressMapping::into_account_id(spender),
+        )
+        .is_some());
+    }
+
+    name {
+        let handle = BenchHandle::new(
+            precompile_address(),
+            caller(),
+            EvmDataWriter::new_with_selector(Action::Name).build(),
+        );
+    }: {
+        execute::<T>(handle)?;
+    }
+
+    symbol {
+        let handle = BenchHandle::new(
+            precompile_address(),
+            caller(),
+            EvmDataWriter::new_with_selector(Action::Symbol).build(),
+        );
+    }: {
+        execute::<T>(handle)?;
+    }
+
+    decimals {
+        let handle = BenchHandle::new(
+            precompile_address(),
+            caller(),
+            EvmDataWriter::new_with_selector(Action::Decimals).build(),
+        );
+    }: {
+        execute::<T>(handle)?;
+    }
+
+    withdraw {
+        fund::<T>(caller());
+        let handle = BenchHandle::new(
+            precompile_address(),
+            caller(),
+            EvmDataWriter::new_with_selector(Action::Withdraw)
+                .write(U256::one())
+                .build(),
+        );
+    }: {
+        execute::<T>(handle)?;
+    }
+
+    permit {
+        let (public, owner) = keystore_address();
+        let spender = H160::repeat_byte(0xCC);
+        let value = U256::MAX;
+        let deadline = U256::MAX;
+        let permit = Eip2612::<T, BenchMetadata>::generate_permit(
+            precompile_address(),
+            owner,
+            spender,
+            value,
+            NoncesStorage::<()>::get(owner),
+            deadline,
+        );
+        let signature = sp_io::crypto::ecdsa_sign_prehashed(BENCH_KEY_TYPE, &public, &permit)
+            .expect("key was just generated; qed");
+        let handle = BenchHandle::new(
+            precompile_address(),
+            caller(),
+            EvmDataWriter::new_with_selector(Action::Eip2612Permit)
+                .write(Address(owner))
+                .write(Address(spender))
+                .write(value)
+                .write(deadline)
+                .write(signature.0[64])
+                .write(H256::from_slice(&signature.0[0..32]))
+                .write(H256::from_slice(&signature.0[32..64]))
+                .build(),
+        );
+    }: {
+        execute::<T>(handle)?;
+    }
+    verify {
+        assert_eq!(NoncesStorage::<()>::get(owner), U256::one());
+    }
+
+    nonces {
+        let owner = H160::repeat_byte(0xBB);
+        NoncesStorage::<()>::insert(owner, U256::one());
+        let handle = BenchHandle::new(
+            precompile_address(),
+            caller(),
+            EvmDataWriter::new_with_selector(Action::Eip2612Nonces)
+                .write(Address(owner))
+                .build(),
+        );
+    }: {
+        execute::<T>(handle)?;
+    }
+
+    domain_separator {
+        let handle = BenchHandle::new(
+            precompile_address(),
+            caller(),
+            EvmDataWriter::new_with_selector(Action::Eip2612DomainSeparator).build(),
+        );
+    }: {
+        execute::<T>(handle)?;
+    }
+}
+
+impl_benchmark_test_suite!(
+    Pallet,
+    crate::mock::ExtBuilder::default().build_with_keystore(),
+    crate::mock::Runtime
+);
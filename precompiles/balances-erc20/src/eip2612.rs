@@ -28,11 +28,14 @@ const PERMIT_DOMAIN: [u8; 32] = keccak256!(
     "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
 );
 
-pub struct Eip2612<Runtime, Metadata, Instance = ()>(PhantomData<(Runtime, Metadata, Instance)>);
+pub struct Eip2612<Runtime, Metadata, Instance = (), Weights = ()>(
+    PhantomData<(Runtime, Metadata, Instance, Weights)>,
+);
 
-impl<Runtime, Metadata, Instance> Eip2612<Runtime, Metadata, Instance>
+impl<Runtime, Metadata, Instance, Weights> Eip2612<Runtime, Metadata, Instance, Weights>
 where
     Metadata: Erc20Metadata,
+    Weights: WeightInfo,
     Instance: InstanceToPrefix + 'static,
     Runtime: pallet_balances::Config<Instance> + pallet_evm::Config + pallet_timestamp::Config,
     Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
@@ -89,7 +92,7 @@ where
     // Translated from
     // https://github.com/Uniswap/v2-core/blob/master/contracts/UniswapV2ERC20.sol#L81
     pub(crate) fn permit(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(Weights::permit()))?;
 
         let mut input = EvmDataReader::new_skip_selector(handle.input())?;
         let owner: H160 = input.read::<Address>()?.into();
@@ -134,8 +137,10 @@ where
 
         {
             let amount =
-                Erc20BalancesPrecompile::<Runtime, Metadata, Instance>::u256_to_amount(value)
-                    .unwrap_or_else(|_| Bounded::max_value());
+                Erc20BalancesPrecompile::<Runtime, Metadata, Instance, Weights>::u256_to_amount(
+                    value,
+                )
+                .unwrap_or_else(|_| Bounded::max_value());
 
             let owner: Runtime::AccountId = Runtime::AddressMapping::into_account_id(owner);
             let spender: Runtime::AccountId = Runtime::AddressMapping::into_account_id(spender);
@@ -155,7 +160,7 @@ where
     }
 
     pub(crate) fn nonces(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(Weights::nonces()))?;
 
         let mut input = EvmDataReader::new_skip_selector(handle.input())?;
         let owner: H160 = input.read::<Address>()?.into();
@@ -168,7 +173,9 @@ where
     pub(crate) fn domain_separator(
         handle: &mut impl PrecompileHandle,
     ) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::domain_separator(),
+        ))?;
 
         let domain_separator: H256 =
             Self::compute_domain_separator(handle.context().address).into();
@@ -32,6 +32,8 @@ use sp_core::{H160, U256};
 use sp_std::{
     convert::{TryFrom, TryInto},
     marker::PhantomData,
+    vec,
+    vec::Vec,
 };
 
 use precompile_utils::{
@@ -40,7 +42,10 @@ use precompile_utils::{
     RuntimeHelper,
 };
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
 mod eip2612;
+pub mod weights;
 
 #[cfg(test)]
 mod mock;
@@ -48,6 +53,8 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub use weights::WeightInfo;
+
 /// Solidity selector of the Transfer log, which is the Keccak of the Log signature.
 pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
 
@@ -156,6 +163,9 @@ pub type NoncesStorage<Instance> = StorageMap<
     ValueQuery,
 >;
 
+/// Interface version of the precompile, bumped on breaking changes of its functions.
+pub const INTERFACE_VERSION: u32 = 1;
+
 #[generate_function_selector]
 #[derive(Debug, PartialEq, Eq)]
 pub enum Action {
@@ -176,6 +186,28 @@ pub enum Action {
     Eip2612DomainSeparator = "DOMAIN_SEPARATOR()",
 }
 
+impl Action {
+    /// Selectors of all the functions of the precompile.
+    pub fn selectors() -> Vec<u32> {
+        vec![
+            Action::TotalSupply as u32,
+            Action::BalanceOf as u32,
+            Action::Allowance as u32,
+            Action::Transfer as u32,
+            Action::Approve as u32,
+            Action::TransferFrom as u32,
+            Action::Name as u32,
+            Action::Symbol as u32,
+            Action::Decimals as u32,
+            Action::Deposit as u32,
+            Action::Withdraw as u32,
+            Action::Eip2612Permit as u32,
+            Action::Eip2612Nonces as u32,
+            Action::Eip2612DomainSeparator as u32,
+        ]
+    }
+}
+
 /// Metadata of an ERC20 token.
 pub trait Erc20Metadata {
     /// Returns the name of the token.
@@ -195,21 +227,27 @@ pub trait Erc20Metadata {
 /// Precompile exposing a pallet_balance as an ERC20.
 /// Multiple precompiles can support instances of pallet_balance.
 /// The precompile uses an additional storage to store approvals.
-pub struct Erc20BalancesPrecompile<Runtime, Metadata: Erc20Metadata, Instance: 'static = ()>(
-    PhantomData<(Runtime, Metadata, Instance)>,
-);
+/// Selectors which don't dispatch a call are charged from `Weights`.
+pub struct Erc20BalancesPrecompile<
+    Runtime,
+    Metadata: Erc20Metadata,
+    Instance: 'static = (),
+    Weights = (),
+>(PhantomData<(Runtime, Metadata, Instance, Weights)>);
 
-impl<Runtime, Metadata: Erc20Metadata, Instance> Default
-    for Erc20BalancesPrecompile<Runtime, Metadata, Instance>
+impl<Runtime, Metadata: Erc20Metadata, Instance, Weights> Default
+    for Erc20BalancesPrecompile<Runtime, Metadata, Instance, Weights>
 {
     fn default() -> Self {
         Self(PhantomData)
     }
 }
 
-impl<Runtime, Metadata, Instance> Erc20BalancesPrecompile<Runtime, Metadata, Instance>
+impl<Runtime, Metadata, Instance, Weights>
+    Erc20BalancesPrecompile<Runtime, Metadata, Instance, Weights>
 where
     Metadata: Erc20Metadata,
+    Weights: WeightInfo,
     Instance: InstanceToPrefix + 'static,
     Runtime: pallet_balances::Config<Instance> + pallet_evm::Config + pallet_timestamp::Config,
     Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
@@ -223,10 +261,11 @@ where
     }
 }
 
-impl<Runtime, Metadata, Instance> Precompile
-    for Erc20BalancesPrecompile<Runtime, Metadata, Instance>
+impl<Runtime, Metadata, Instance, Weights> Precompile
+    for Erc20BalancesPrecompile<Runtime, Metadata, Instance, Weights>
 where
     Metadata: Erc20Metadata,
+    Weights: WeightInfo,
     Instance: InstanceToPrefix + 'static,
     Runtime: pallet_balances::Config<Instance> + pallet_evm::Config + pallet_timestamp::Config,
     Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
@@ -255,27 +294,29 @@ where
             Action::Approve => Self::approve(handle),
             Action::Transfer => Self::transfer(handle),
             Action::TransferFrom => Self::transfer_from(handle),
-            Action::Name => Self::name(),
-            Action::Symbol => Self::symbol(),
-            Action::Decimals => Self::decimals(),
+            Action::Name => Self::name(handle),
+            Action::Symbol => Self::symbol(handle),
+            Action::Decimals => Self::decimals(handle),
             Action::Deposit => Self::deposit(handle),
             Action::Withdraw => Self::withdraw(handle),
             Action::Eip2612Permit => {
-                eip2612::Eip2612::<Runtime, Metadata, Instance>::permit(handle)
+                eip2612::Eip2612::<Runtime, Metadata, Instance, Weights>::permit(handle)
             }
             Action::Eip2612Nonces => {
-                eip2612::Eip2612::<Runtime, Metadata, Instance>::nonces(handle)
+                eip2612::Eip2612::<Runtime, Metadata, Instance, Weights>::nonces(handle)
             }
             Action::Eip2612DomainSeparator => {
-                eip2612::Eip2612::<Runtime, Metadata, Instance>::domain_separator(handle)
+                eip2612::Eip2612::<Runtime, Metadata, Instance, Weights>::domain_separator(handle)
             }
         }
     }
 }
 
-impl<Runtime, Metadata, Instance> Erc20BalancesPrecompile<Runtime, Metadata, Instance>
+impl<Runtime, Metadata, Instance, Weights>
+    Erc20BalancesPrecompile<Runtime, Metadata, Instance, Weights>
 where
     Metadata: Erc20Metadata,
+    Weights: WeightInfo,
     Instance: InstanceToPrefix + 'static,
     Runtime: pallet_balances::Config<Instance> + pallet_evm::Config + pallet_timestamp::Config,
     Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
@@ -285,7 +326,9 @@ where
     <Runtime as pallet_timestamp::Config>::Moment: Into<U256>,
 {
     fn total_supply(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::total_supply(),
+        ))?;
 
         // Parse input.
         let input = handle.read_input()?;
@@ -299,7 +342,9 @@ where
     }
 
     fn balance_of(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::balance_of(),
+        ))?;
 
         // Read input.
         let mut input = handle.read_input()?;
@@ -318,7 +363,9 @@ where
     }
 
     fn allowance(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::allowance(),
+        ))?;
 
         // Read input.
         let mut input = handle.read_input()?;
@@ -342,7 +389,7 @@ where
     }
 
     fn approve(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
-        handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(Weights::approve()))?;
         handle.record_log_costs_manual(3, 32)?;
 
         // Parse input.
@@ -478,7 +525,9 @@ where
         Ok(succeed(EvmDataWriter::new().write(true).build()))
     }
 
-    fn name() -> EvmResult<PrecompileOutput> {
+    fn name(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(Weights::name()))?;
+
         // Build output.
         Ok(succeed(
             EvmDataWriter::new()
@@ -487,7 +536,9 @@ where
         ))
     }
 
-    fn symbol() -> EvmResult<PrecompileOutput> {
+    fn symbol(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(Weights::symbol()))?;
+
         // Build output.
         Ok(succeed(
             EvmDataWriter::new()
@@ -496,7 +547,11 @@ where
         ))
     }
 
-    fn decimals() -> EvmResult<PrecompileOutput> {
+    fn decimals(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::decimals(),
+        ))?;
+
         // Build output.
         Ok(succeed(
             EvmDataWriter::new().write(Metadata::decimals()).build(),
@@ -549,6 +604,9 @@ where
             return Err(revert("unknown selector"));
         }
 
+        handle.record_cost(RuntimeHelper::<Runtime>::weight_gas_cost(
+            Weights::withdraw(),
+        ))?;
         handle.record_log_costs_manual(2, 32)?;
 
         let mut input = handle.read_input()?;
@@ -207,6 +207,9 @@ construct_runtime!(
     }
 );
 
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::benchmarking::Config for Runtime {}
+
 /// ERC20 metadata for the native token.
 pub struct NativeErc20Metadata;
 
@@ -291,6 +294,17 @@ impl ExtBuilder {
         ext.execute_with(|| System::set_block_number(1));
         ext
     }
+
+    /// Externalities with a keystore, benchmarks sign EIP2612 permits with it.
+    #[cfg(feature = "runtime-benchmarks")]
+    pub(crate) fn build_with_keystore(self) -> sp_io::TestExternalities {
+        use sp_keystore::{testing::KeyStore, KeystoreExt};
+        use std::sync::Arc;
+
+        let mut ext = self.build();
+        ext.register_extension(KeystoreExt(Arc::new(KeyStore::new())));
+        ext
+    }
 }
 
 pub(crate) fn events() -> Vec<RuntimeEvent> {
@@ -75,7 +75,7 @@ fn get_total_supply() {
                     Account::Precompile,
                     EvmDataWriter::new_with_selector(Action::TotalSupply).build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::total_supply().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(3500u64)).build());
         });
@@ -95,7 +95,7 @@ fn get_balances_known_user() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(1000u64)).build());
         });
@@ -115,7 +115,7 @@ fn get_balances_unknown_user() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u64)).build());
         });
@@ -136,7 +136,7 @@ fn approve() {
                         .write(U256::from(500))
                         .build(),
                 )
-                .expect_cost(1756)
+                .expect_cost(<() as WeightInfo>::approve().ref_time() + 1756)
                 .expect_log(LogsBuilder::new(Account::Precompile.into()).log3(
                     SELECTOR_LOG_APPROVAL,
                     Account::Alice,
@@ -162,7 +162,7 @@ fn approve_saturating() {
                         .write(U256::MAX)
                         .build(),
                 )
-                .expect_cost(1756u64)
+                .expect_cost(<() as WeightInfo>::approve().ref_time() + 1756)
                 .expect_log(LogsBuilder::new(Account::Precompile.into()).log3(
                     SELECTOR_LOG_APPROVAL,
                     Account::Alice,
@@ -180,7 +180,7 @@ fn approve_saturating() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0)
+                .expect_cost(<() as WeightInfo>::allowance().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(u128::MAX)).build());
         });
@@ -212,7 +212,7 @@ fn check_allowance_existing() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::allowance().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(500u64)).build());
         });
@@ -233,7 +233,7 @@ fn check_allowance_not_existing() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::allowance().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u64)).build());
         });
@@ -271,7 +271,7 @@ fn transfer() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(600)).build());
 
@@ -283,7 +283,7 @@ fn transfer() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(400)).build());
         });
@@ -357,7 +357,7 @@ fn transfer_from() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(600)).build());
 
@@ -369,7 +369,7 @@ fn transfer_from() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(400)).build());
 
@@ -382,7 +382,7 @@ fn transfer_from() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::allowance().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(100u64)).build());
         });
@@ -452,7 +452,7 @@ fn transfer_from_self() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(600)).build());
 
@@ -464,7 +464,7 @@ fn transfer_from_self() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(400)).build());
         });
@@ -482,7 +482,7 @@ fn get_metadata_name() {
                     Account::Precompile,
                     EvmDataWriter::new_with_selector(Action::Name).build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::name().ref_time())
                 .expect_no_logs()
                 .execute_returns(
                     EvmDataWriter::new()
@@ -504,7 +504,7 @@ fn get_metadata_symbol() {
                     Account::Precompile,
                     EvmDataWriter::new_with_selector(Action::Symbol).build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::symbol().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write::<Bytes>("MOCK".into()).build());
         });
@@ -522,7 +522,7 @@ fn get_metadata_decimals() {
                     Account::Precompile,
                     EvmDataWriter::new_with_selector(Action::Decimals).build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::decimals().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(18u8).build());
         });
@@ -542,7 +542,7 @@ fn deposit(data: Vec<u8>) {
                         .write(Address(Account::Precompile.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0)).build());
 
@@ -608,7 +608,7 @@ fn deposit(data: Vec<u8>) {
                         .write(Address(Account::Precompile.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0)).build());
 
@@ -621,7 +621,7 @@ fn deposit(data: Vec<u8>) {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(1000)).build());
         });
@@ -657,7 +657,7 @@ fn deposit_zero() {
                         .write(Address(Account::Precompile.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0)).build());
 
@@ -694,7 +694,7 @@ fn deposit_zero() {
                         .write(Address(Account::Precompile.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0)).build());
 
@@ -707,7 +707,7 @@ fn deposit_zero() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(1000)).build());
         });
@@ -728,7 +728,7 @@ fn withdraw() {
                         .write(Address(Account::Precompile.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0)).build());
 
@@ -741,7 +741,7 @@ fn withdraw() {
                         .write(U256::from(500))
                         .build(),
                 )
-                .expect_cost(1381)
+                .expect_cost(<() as WeightInfo>::withdraw().ref_time() + 1381)
                 .expect_log(LogsBuilder::new(Account::Precompile.into()).log2(
                     SELECTOR_LOG_WITHDRAWAL,
                     Account::Alice,
@@ -758,7 +758,7 @@ fn withdraw() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(1000)).build());
         });
@@ -779,7 +779,7 @@ fn withdraw_more_than_owned() {
                         .write(Address(Account::Precompile.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0)).build());
 
@@ -803,7 +803,7 @@ fn withdraw_more_than_owned() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::balance_of().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(1000)).build());
         });
@@ -841,7 +841,7 @@ fn permit_valid() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::nonces().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u8)).build());
 
@@ -859,7 +859,7 @@ fn permit_valid() {
                         .write(H256::from(rs.s.b32()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::permit().ref_time())
                 .expect_log(LogsBuilder::new(Account::Precompile.into()).log3(
                     SELECTOR_LOG_APPROVAL,
                     Account::Alice,
@@ -877,7 +877,7 @@ fn permit_valid() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::allowance().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(500u16)).build());
 
@@ -889,7 +889,7 @@ fn permit_valid() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::nonces().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(1u8)).build());
         });
@@ -927,7 +927,7 @@ fn permit_invalid_nonce() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::nonces().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u8)).build());
 
@@ -956,7 +956,7 @@ fn permit_invalid_nonce() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::allowance().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u16)).build());
 
@@ -968,7 +968,7 @@ fn permit_invalid_nonce() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::nonces().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u8)).build());
         });
@@ -993,7 +993,7 @@ fn permit_invalid_signature() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::nonces().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u8)).build());
 
@@ -1022,7 +1022,7 @@ fn permit_invalid_signature() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::allowance().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u16)).build());
 
@@ -1034,7 +1034,7 @@ fn permit_invalid_signature() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::nonces().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u8)).build());
         });
@@ -1074,7 +1074,7 @@ fn permit_invalid_deadline() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::nonces().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u8)).build());
 
@@ -1103,7 +1103,7 @@ fn permit_invalid_deadline() {
                         .write(Address(Account::Bob.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::allowance().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u16)).build());
 
@@ -1115,7 +1115,7 @@ fn permit_invalid_deadline() {
                         .write(Address(Account::Alice.into()))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::nonces().ref_time())
                 .expect_no_logs()
                 .execute_returns(EvmDataWriter::new().write(U256::from(0u8)).build());
         });
@@ -1277,7 +1277,7 @@ fn permit_valid_with_metamask_signed_data() {
                         .write(H256::from(s_real))
                         .build(),
                 )
-                .expect_cost(0) // TODO: Test db read/write costs
+                .expect_cost(<() as WeightInfo>::permit().ref_time())
                 .expect_log(LogsBuilder::new(Account::Precompile.into()).log3(
                     SELECTOR_LOG_APPROVAL,
                     Account::Alice,
@@ -0,0 +1,111 @@
+// This file is part of Parallel Finance.
+
+// Copyright (C) 2022 Parallel Finance Developer.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_evm_precompile_balances_erc20
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_evm_precompile_balances_erc20
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=./.maintain/frame-weight-template.hbs
+// --output=./precompiles/balances-erc20/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_evm_precompile_balances_erc20.
+pub trait WeightInfo {
+	fn total_supply() -> Weight;
+	fn balance_of() -> Weight;
+	fn allowance() -> Weight;
+	fn approve() -> Weight;
+	fn name() -> Weight;
+	fn symbol() -> Weight;
+	fn decimals() -> Weight;
+	fn withdraw() -> Weight;
+	fn permit() -> Weight;
+	fn nonces() -> Weight;
+	fn domain_separator() -> Weight;
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Balances TotalIssuance (r:1 w:0)
+	fn total_supply() -> Weight {
+		Weight::from_ref_time(3_412_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	// Storage: System Account (r:1 w:0)
+	fn balance_of() -> Weight {
+		Weight::from_ref_time(5_836_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	// Storage: Erc20Instance0Balances Approves (r:1 w:0)
+	fn allowance() -> Weight {
+		Weight::from_ref_time(6_127_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	// Storage: Erc20Instance0Balances Approves (r:0 w:1)
+	fn approve() -> Weight {
+		Weight::from_ref_time(8_904_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn name() -> Weight {
+		Weight::from_ref_time(1_208_000 as u64)
+	}
+	fn symbol() -> Weight {
+		Weight::from_ref_time(1_185_000 as u64)
+	}
+	fn decimals() -> Weight {
+		Weight::from_ref_time(1_061_000 as u64)
+	}
+	// Storage: System Account (r:1 w:0)
+	fn withdraw() -> Weight {
+		Weight::from_ref_time(5_902_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Erc20Instance0Balances Nonces (r:1 w:1)
+	// Storage: Erc20Instance0Balances Approves (r:0 w:1)
+	fn permit() -> Weight {
+		Weight::from_ref_time(54_317_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Erc20Instance0Balances Nonces (r:1 w:0)
+	fn nonces() -> Weight {
+		Weight::from_ref_time(4_713_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	fn domain_separator() -> Weight {
+		Weight::from_ref_time(9_256_000 as u64)
+	}
+}
@@ -0,0 +1,72 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Precompile metrics
+//!
+//! Reports every precompile call made by the runtime to a host-side recorder, which keeps
+//! per-selector prometheus counters of the calls, the gas they consumed and how many reverted.
+//! The node has to register [`precompile_metrics::HostFunctions`] with its executor and call
+//! [`register`] with its prometheus registry, otherwise the recorded calls are dropped.
+//!
+//! Only runtimes built for tracing may use it, the wasm run by the relay chain validators
+//! can't import host functions they don't provide.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{PrecompileHandle, PrecompileResult};
+use sp_core::H160;
+use sp_runtime_interface::runtime_interface;
+
+#[cfg(feature = "std")]
+mod recorder;
+
+#[cfg(feature = "std")]
+pub use recorder::register;
+
+/// Selector reported for calls whose input doesn't carry one.
+pub const NO_SELECTOR: u32 = 0;
+
+#[runtime_interface]
+pub trait PrecompileMetrics {
+    /// Record a call to the precompile at `address`.
+    fn record(address: H160, selector: u32, gas_used: u64, reverted: bool) {
+        recorder::record(address, selector, gas_used, reverted)
+    }
+}
+
+/// Executes a precompile with `execute` and reports the call to the host.
+///
+/// The selector is read from the first 4 bytes of the input when `with_selector` is set, raw
+/// input precompiles should not set it as their input is arbitrary data.
+pub fn recorded<H: PrecompileHandle>(
+    handle: &mut H,
+    with_selector: bool,
+    execute: impl FnOnce(&mut H) -> Option<PrecompileResult>,
+) -> Option<PrecompileResult> {
+    let address = handle.code_address();
+    let selector = match handle.input() {
+        input if with_selector && input.len() >= 4 => {
+            u32::from_be_bytes([input[0], input[1], input[2], input[3]])
+        }
+        _ => NO_SELECTOR,
+    };
+    let gas_before = handle.remaining_gas();
+
+    let result = execute(handle)?;
+
+    let gas_used = gas_before.saturating_sub(handle.remaining_gas());
+    precompile_metrics::record(address, selector, gas_used, result.is_err());
+
+    Some(result)
+}
@@ -0,0 +1,120 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prometheus backend of the precompile metrics, only available to the host.
+
+use once_cell::sync::OnceCell;
+use sp_core::H160;
+use std::{collections::BTreeSet, sync::Mutex};
+use substrate_prometheus_endpoint::{
+    register as register_metric, CounterVec, Opts, PrometheusError, Registry, U64,
+};
+
+/// Maximum number of (address, selector) series tracked, calls beyond it are folded into the
+/// `other` selector so junk inputs can't grow the registry without bound.
+const MAX_SERIES: usize = 1024;
+
+const OTHER_SELECTOR: &str = "other";
+
+static METRICS: OnceCell<Metrics> = OnceCell::new();
+
+struct Metrics {
+    calls: CounterVec<U64>,
+    gas: CounterVec<U64>,
+    reverts: CounterVec<U64>,
+    series: Mutex<BTreeSet<(H160, u32)>>,
+}
+
+impl Metrics {
+    fn new(registry: &Registry) -> Result<Self, PrometheusError> {
+        let labels = &["address", "selector"];
+        Ok(Self {
+            calls: register_metric(
+                CounterVec::new(
+                    Opts::new(
+                        "parallel_precompile_calls_total",
+                        "Number of calls to a precompile selector",
+                    ),
+                    labels,
+                )?,
+                registry,
+            )?,
+            gas: register_metric(
+                CounterVec::new(
+                    Opts::new(
+                        "parallel_precompile_gas_total",
+                        "Gas consumed by the calls to a precompile selector",
+                    ),
+                    labels,
+                )?,
+                registry,
+            )?,
+            reverts: register_metric(
+                CounterVec::new(
+                    Opts::new(
+                        "parallel_precompile_reverts_total",
+                        "Number of calls to a precompile selector which failed",
+                    ),
+                    labels,
+                )?,
+                registry,
+            )?,
+            series: Mutex::new(BTreeSet::new()),
+        })
+    }
+
+    fn selector_label(&self, address: H160, selector: u32) -> String {
+        let mut series = match self.series.lock() {
+            Ok(series) => series,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if series.contains(&(address, selector)) || series.len() < MAX_SERIES {
+            series.insert((address, selector));
+            format!("{:#010x}", selector)
+        } else {
+            OTHER_SELECTOR.into()
+        }
+    }
+}
+
+/// Register the precompile metrics with the node's prometheus registry.
+///
+/// Only the first registry is used, later calls are no-ops.
+pub fn register(registry: &Registry) -> Result<(), PrometheusError> {
+    METRICS
+        .get_or_try_init(|| Metrics::new(registry))
+        .map(|_| ())
+}
+
+pub(crate) fn record(address: H160, selector: u32, gas_used: u64, reverted: bool) {
+    let metrics = match METRICS.get() {
+        Some(metrics) => metrics,
+        None => return,
+    };
+    let address_label = format!("{:?}", address);
+    let selector_label = metrics.selector_label(address, selector);
+    let labels = [address_label.as_str(), selector_label.as_str()];
+
+    metrics.calls.with_label_values(&labels).inc();
+    metrics.gas.with_label_values(&labels).inc_by(gas_used);
+    if reverted {
+        metrics.reverts.with_label_values(&labels).inc();
+        log::trace!(
+            target: "precompile-metrics",
+            "precompile {:?} reverted on selector {}",
+            address,
+            selector_label,
+        );
+    }
+}
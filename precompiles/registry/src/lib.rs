@@ -0,0 +1,190 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Precompile listing the precompiles of the runtime, so that contracts and tooling can
+//! detect which features are available instead of hard-coding addresses per runtime version.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(test, feature(assert_matches))]
+
+use fp_evm::{Precompile, PrecompileHandle, PrecompileOutput};
+use sp_core::{H160, H256};
+use sp_std::{marker::PhantomData, vec, vec::Vec};
+
+use precompile_utils::{
+    generate_function_selector, succeed, Address, EvmDataWriter, EvmResult, FunctionModifier,
+    PrecompileHandleExt, RuntimeHelper,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// Interface version of the registry precompile.
+pub const INTERFACE_VERSION: u32 = 1;
+
+#[generate_function_selector]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Action {
+    Precompiles = "precompiles()",
+    IsPrecompile = "isPrecompile(address)",
+    InterfaceVersion = "interfaceVersion(address)",
+    Selectors = "selectors(address)",
+    SupportsSelector = "supportsSelector(address,bytes4)",
+}
+
+impl Action {
+    /// Selectors of all the functions of the registry.
+    pub fn selectors() -> Vec<u32> {
+        vec![
+            Action::Precompiles as u32,
+            Action::IsPrecompile as u32,
+            Action::InterfaceVersion as u32,
+            Action::Selectors as u32,
+            Action::SupportsSelector as u32,
+        ]
+    }
+}
+
+/// Interface of a precompile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrecompileInfo {
+    /// Version of the interface, bumped whenever it changes in a breaking way.
+    pub version: u32,
+    /// Selectors of the functions, empty if the precompile takes raw input.
+    pub selectors: Vec<u32>,
+}
+
+impl PrecompileInfo {
+    pub fn new(version: u32, selectors: Vec<u32>) -> Self {
+        Self { version, selectors }
+    }
+}
+
+/// Precompiles which can be discovered through the registry.
+pub trait PrecompileRegistry {
+    /// Addresses of the precompiles which aren't derived from on chain data.
+    fn precompile_addresses() -> Vec<H160>;
+
+    /// Interface of the precompile at `address`, `None` if it's not a precompile.
+    fn precompile_info(address: H160) -> Option<PrecompileInfo>;
+}
+
+/// Precompile exposing a `PrecompileRegistry` to the EVM.
+pub struct RegistryPrecompile<Runtime, Registry>(PhantomData<(Runtime, Registry)>);
+
+impl<Runtime, Registry> Precompile for RegistryPrecompile<Runtime, Registry>
+where
+    Runtime: pallet_evm::Config,
+    Registry: PrecompileRegistry,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let selector = handle.read_selector()?;
+
+        handle.check_function_modifier(FunctionModifier::View)?;
+
+        // Precompiles at dynamic addresses are looked up in the storage.
+        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+        match selector {
+            Action::Precompiles => Self::precompiles(handle),
+            Action::IsPrecompile => Self::is_precompile(handle),
+            Action::InterfaceVersion => Self::interface_version(handle),
+            Action::Selectors => Self::selectors(handle),
+            Action::SupportsSelector => Self::supports_selector(handle),
+        }
+    }
+}
+
+impl<Runtime, Registry> RegistryPrecompile<Runtime, Registry>
+where
+    Runtime: pallet_evm::Config,
+    Registry: PrecompileRegistry,
+{
+    fn precompiles(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.read_input()?.expect_arguments(0)?;
+
+        let addresses: Vec<Address> = Registry::precompile_addresses()
+            .into_iter()
+            .map(Address)
+            .collect();
+
+        Ok(succeed(EvmDataWriter::new().write(addresses).build()))
+    }
+
+    fn is_precompile(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let info = Self::read_info(handle)?;
+
+        Ok(succeed(EvmDataWriter::new().write(info.is_some()).build()))
+    }
+
+    fn interface_version(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let info = Self::read_info(handle)?;
+
+        // 0 is never used as a version, it means there is no precompile at the address.
+        let version = info.map(|info| info.version).unwrap_or_default();
+
+        Ok(succeed(EvmDataWriter::new().write(version).build()))
+    }
+
+    fn selectors(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let info = Self::read_info(handle)?;
+
+        let selectors: Vec<H256> = info
+            .map(|info| info.selectors)
+            .unwrap_or_default()
+            .into_iter()
+            .map(selector_to_bytes4)
+            .collect();
+
+        Ok(succeed(EvmDataWriter::new().write(selectors).build()))
+    }
+
+    fn supports_selector(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(2)?;
+
+        let address: H160 = input.read::<Address>()?.into();
+        let selector = bytes4_to_selector(input.read::<H256>()?);
+        let supported = Registry::precompile_info(address)
+            .map(|info| info.selectors.contains(&selector))
+            .unwrap_or_default();
+
+        Ok(succeed(EvmDataWriter::new().write(supported).build()))
+    }
+
+    fn read_info(handle: &mut impl PrecompileHandle) -> EvmResult<Option<PrecompileInfo>> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+
+        let address: H160 = input.read::<Address>()?.into();
+
+        Ok(Registry::precompile_info(address))
+    }
+}
+
+/// `bytes4` is encoded left aligned in a 32 bytes word.
+fn selector_to_bytes4(selector: u32) -> H256 {
+    let mut word = H256::zero();
+    word.0[..4].copy_from_slice(&selector.to_be_bytes());
+    word
+}
+
+fn bytes4_to_selector(word: H256) -> u32 {
+    let mut selector = [0u8; 4];
+    selector.copy_from_slice(&word.0[..4]);
+    u32::from_be_bytes(selector)
+}
@@ -0,0 +1,249 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use pallet_evm::{AddressMapping, EnsureAddressNever, EnsureAddressRoot, PrecompileSet};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+
+pub type AccountId = Account;
+pub type Balance = u128;
+pub type BlockNumber = u64;
+pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+pub type Block = frame_system::mocking::MockBlock<Runtime>;
+
+pub const PRECOMPILE_ADDRESS: u64 = 1;
+pub const RAW_PRECOMPILE_ADDRESS: u64 = 2;
+pub const VERSIONED_PRECOMPILE_ADDRESS: u64 = 3;
+
+/// Selectors of the mock precompile at `VERSIONED_PRECOMPILE_ADDRESS`.
+pub const VERSIONED_SELECTORS: [u32; 2] = [0x70a08231, 0x18160ddd];
+
+/// A simple account type.
+#[derive(
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Clone,
+    Encode,
+    Decode,
+    Debug,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+    derive_more::Display,
+    TypeInfo,
+)]
+pub enum Account {
+    Alice,
+    Bogus,
+    Precompile,
+}
+
+impl Default for Account {
+    fn default() -> Self {
+        Self::Bogus
+    }
+}
+
+impl AddressMapping<Account> for Account {
+    fn into_account_id(h160_account: H160) -> Account {
+        match h160_account {
+            a if a == H160::repeat_byte(0xAA) => Self::Alice,
+            a if a == H160::from_low_u64_be(PRECOMPILE_ADDRESS) => Self::Precompile,
+            _ => Self::Bogus,
+        }
+    }
+}
+
+impl From<Account> for H160 {
+    fn from(x: Account) -> H160 {
+        match x {
+            Account::Alice => H160::repeat_byte(0xAA),
+            Account::Precompile => H160::from_low_u64_be(PRECOMPILE_ADDRESS),
+            Account::Bogus => Default::default(),
+        }
+    }
+}
+
+impl From<H160> for Account {
+    fn from(x: H160) -> Account {
+        Account::into_account_id(x)
+    }
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = Everything;
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type BlockWeights = ();
+    type BlockLength = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+    pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u128 = 0;
+}
+
+impl pallet_balances::Config for Runtime {
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+    type MaxLocks = ();
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+        pub BlockGasLimit: U256 = U256::max_value();
+        pub const PrecompilesValue: Precompiles<Runtime> = Precompiles(PhantomData);
+        pub WeightPerGas: u64 = 1;
+}
+
+impl pallet_evm::Config for Runtime {
+    type FeeCalculator = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type CallOrigin = EnsureAddressRoot<AccountId>;
+    type WithdrawOrigin = EnsureAddressNever<AccountId>;
+    type AddressMapping = AccountId;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type PrecompilesType = Precompiles<Self>;
+    type PrecompilesValue = PrecompilesValue;
+    type ChainId = ();
+    type OnChargeTransaction = ();
+    type BlockGasLimit = BlockGasLimit;
+    type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+    type FindAuthor = ();
+    type WeightPerGas = WeightPerGas;
+}
+
+// Configure a mock runtime to test the pallet.
+construct_runtime!(
+    pub enum Runtime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Evm: pallet_evm::{Pallet, Call, Storage, Event<T>},
+        Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+    }
+);
+
+#[derive(Default)]
+pub struct Precompiles<R>(PhantomData<R>);
+
+impl<R> PrecompileSet for Precompiles<R>
+where
+    R: pallet_evm::Config,
+{
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<EvmResult<PrecompileOutput>> {
+        match handle.code_address() {
+            a if a == hash(PRECOMPILE_ADDRESS) => {
+                Some(RegistryPrecompile::<R, Self>::execute(handle))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_precompile(&self, address: H160) -> bool {
+        Self::precompile_info(address).is_some()
+    }
+}
+
+impl<R> PrecompileRegistry for Precompiles<R> {
+    fn precompile_addresses() -> Vec<H160> {
+        vec![
+            hash(PRECOMPILE_ADDRESS),
+            hash(RAW_PRECOMPILE_ADDRESS),
+            hash(VERSIONED_PRECOMPILE_ADDRESS),
+        ]
+    }
+
+    fn precompile_info(address: H160) -> Option<PrecompileInfo> {
+        match address {
+            a if a == hash(PRECOMPILE_ADDRESS) => {
+                Some(PrecompileInfo::new(INTERFACE_VERSION, Action::selectors()))
+            }
+            a if a == hash(RAW_PRECOMPILE_ADDRESS) => Some(PrecompileInfo::new(1, vec![])),
+            a if a == hash(VERSIONED_PRECOMPILE_ADDRESS) => {
+                Some(PrecompileInfo::new(2, VERSIONED_SELECTORS.to_vec()))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn hash(a: u64) -> H160 {
+    H160::from_low_u64_be(a)
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Runtime>()
+        .expect("Frame system builds valid default genesis config");
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,181 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{mock::*, *};
+use precompile_utils::PrecompileTesterExt;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn bytes4(selector: u32) -> H256 {
+    selector_to_bytes4(selector)
+}
+
+#[test]
+fn selectors() {
+    assert_eq!(Action::Precompiles as u32, 0x61a51e2b);
+    assert_eq!(Action::IsPrecompile as u32, 0x446b450e);
+    assert_eq!(Action::InterfaceVersion as u32, 0x1760eb5e);
+    assert_eq!(Action::Selectors as u32, 0xa83b9d7f);
+    assert_eq!(Action::SupportsSelector as u32, 0x277ccff9);
+
+    assert_eq!(bytes4_to_selector(bytes4(0x70a08231)), 0x70a08231);
+}
+
+#[test]
+fn lists_precompiles() {
+    new_test_ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Account::Alice,
+                Account::Precompile,
+                EvmDataWriter::new_with_selector(Action::Precompiles).build(),
+            )
+            .expect_cost(0)
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(vec![
+                        Address(hash(PRECOMPILE_ADDRESS)),
+                        Address(hash(RAW_PRECOMPILE_ADDRESS)),
+                        Address(hash(VERSIONED_PRECOMPILE_ADDRESS)),
+                    ])
+                    .build(),
+            );
+    });
+}
+
+#[test]
+fn is_precompile_works() {
+    new_test_ext().execute_with(|| {
+        for (address, expected) in [
+            (hash(RAW_PRECOMPILE_ADDRESS), true),
+            (hash(VERSIONED_PRECOMPILE_ADDRESS), true),
+            (H160::repeat_byte(0xAA), false),
+        ] {
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::Precompile,
+                    EvmDataWriter::new_with_selector(Action::IsPrecompile)
+                        .write(Address(address))
+                        .build(),
+                )
+                .expect_cost(0)
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(expected).build());
+        }
+    });
+}
+
+#[test]
+fn interface_version_works() {
+    new_test_ext().execute_with(|| {
+        for (address, expected) in [
+            (hash(PRECOMPILE_ADDRESS), INTERFACE_VERSION),
+            (hash(VERSIONED_PRECOMPILE_ADDRESS), 2u32),
+            (H160::repeat_byte(0xAA), 0u32),
+        ] {
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::Precompile,
+                    EvmDataWriter::new_with_selector(Action::InterfaceVersion)
+                        .write(Address(address))
+                        .build(),
+                )
+                .expect_cost(0)
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(expected).build());
+        }
+    });
+}
+
+#[test]
+fn selectors_works() {
+    new_test_ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Account::Alice,
+                Account::Precompile,
+                EvmDataWriter::new_with_selector(Action::Selectors)
+                    .write(Address(hash(VERSIONED_PRECOMPILE_ADDRESS)))
+                    .build(),
+            )
+            .expect_cost(0)
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(
+                        VERSIONED_SELECTORS
+                            .iter()
+                            .map(|selector| bytes4(*selector))
+                            .collect::<Vec<_>>(),
+                    )
+                    .build(),
+            );
+
+        // Raw input precompiles don't have any selector.
+        precompiles()
+            .prepare_test(
+                Account::Alice,
+                Account::Precompile,
+                EvmDataWriter::new_with_selector(Action::Selectors)
+                    .write(Address(hash(RAW_PRECOMPILE_ADDRESS)))
+                    .build(),
+            )
+            .expect_cost(0)
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(Vec::<H256>::new()).build());
+    });
+}
+
+#[test]
+fn supports_selector_works() {
+    new_test_ext().execute_with(|| {
+        for (address, selector, expected) in [
+            (hash(VERSIONED_PRECOMPILE_ADDRESS), 0x70a08231, true),
+            (hash(VERSIONED_PRECOMPILE_ADDRESS), 0xa9059cbb, false),
+            (hash(PRECOMPILE_ADDRESS), Action::Selectors as u32, true),
+            (H160::repeat_byte(0xAA), 0x70a08231, false),
+        ] {
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::Precompile,
+                    EvmDataWriter::new_with_selector(Action::SupportsSelector)
+                        .write(Address(address))
+                        .write(bytes4(selector))
+                        .build(),
+                )
+                .expect_cost(0)
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(expected).build());
+        }
+    });
+}
+
+#[test]
+fn unknown_selector_reverts() {
+    new_test_ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Account::Alice,
+                Account::Precompile,
+                vec![1u8, 2u8, 3u8, 4u8],
+            )
+            .execute_reverts(|output| output == b"unknown selector");
+    });
+}
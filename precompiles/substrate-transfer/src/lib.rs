@@ -0,0 +1,149 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Precompile moving assets from the account of the calling EVM address to a substrate
+//! account, the counterpart of `transfer_to_evm` of the EVM signatures pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{Precompile, PrecompileHandle, PrecompileOutput};
+use frame_support::{
+    dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+    traits::Get,
+};
+use pallet_evm::AddressMapping;
+use pallet_evm_precompile_assets_erc20::AddressToAssetId;
+use pallet_evm_signatures::{AssetBalanceOf, AssetIdOf};
+use sp_core::{H160, H256};
+use sp_std::{marker::PhantomData, vec, vec::Vec};
+
+use precompile_utils::{
+    generate_function_selector, keccak256, revert, succeed, Address, EvmData, EvmDataWriter,
+    EvmResult, FunctionModifier, LogExt, LogsBuilder, PrecompileHandleExt, RuntimeHelper,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// Interface version of the substrate transfer precompile.
+pub const INTERFACE_VERSION: u32 = 1;
+
+/// Solidity selector of the TransferToSubstrate log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER_TO_SUBSTRATE: [u8; 32] =
+    keccak256!("TransferToSubstrate(address,address,bytes32,uint256)");
+
+#[generate_function_selector]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Action {
+    TransferToSubstrate = "transferToSubstrate(address,uint256,bytes32)",
+}
+
+impl Action {
+    /// Selectors of all the functions of the precompile.
+    pub fn selectors() -> Vec<u32> {
+        vec![Action::TransferToSubstrate as u32]
+    }
+}
+
+/// Precompile dispatching `transfer_to_substrate` of the EVM signatures pallet for the caller.
+///
+/// Assets are identified by their ERC20 precompile address, `NativeAddress` being the one of
+/// the native balances.
+pub struct SubstrateTransferPrecompile<Runtime, NativeAddress>(
+    PhantomData<(Runtime, NativeAddress)>,
+);
+
+impl<Runtime, NativeAddress> Precompile for SubstrateTransferPrecompile<Runtime, NativeAddress>
+where
+    Runtime:
+        pallet_evm::Config + pallet_evm_signatures::Config + AddressToAssetId<AssetIdOf<Runtime>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+        + GetDispatchInfo
+        + From<pallet_evm_signatures::Call<Runtime>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::AccountId: From<[u8; 32]>,
+    AssetBalanceOf<Runtime>: EvmData,
+    NativeAddress: Get<H160>,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let selector = handle.read_selector()?;
+
+        handle.check_function_modifier(FunctionModifier::NonPayable)?;
+
+        match selector {
+            Action::TransferToSubstrate => Self::transfer_to_substrate(handle),
+        }
+    }
+}
+
+impl<Runtime, NativeAddress> SubstrateTransferPrecompile<Runtime, NativeAddress>
+where
+    Runtime:
+        pallet_evm::Config + pallet_evm_signatures::Config + AddressToAssetId<AssetIdOf<Runtime>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+        + GetDispatchInfo
+        + From<pallet_evm_signatures::Call<Runtime>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::AccountId: From<[u8; 32]>,
+    AssetBalanceOf<Runtime>: EvmData,
+    NativeAddress: Get<H160>,
+{
+    fn transfer_to_substrate(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_log_costs_manual(3, 64)?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(3)?;
+
+        let asset_address: H160 = input.read::<Address>()?.into();
+        let amount = input.read::<AssetBalanceOf<Runtime>>()?;
+        let dest = input.read::<H256>()?;
+
+        let asset = if asset_address == NativeAddress::get() {
+            <Runtime as pallet_evm_signatures::Config>::GetNativeCurrencyId::get()
+        } else {
+            Runtime::address_to_asset_id(asset_address)
+                .ok_or_else(|| revert("asset is not an ERC20 precompile"))?
+        };
+
+        let caller = handle.context().caller;
+        {
+            let origin = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(caller);
+
+            RuntimeHelper::<Runtime>::try_dispatch(
+                handle,
+                Some(origin).into(),
+                pallet_evm_signatures::Call::<Runtime>::transfer_to_substrate {
+                    asset,
+                    address: caller,
+                    dest: Runtime::AccountId::from(dest.0),
+                    amount,
+                },
+            )?;
+        }
+
+        LogsBuilder::new(handle.context().address)
+            .log3(
+                SELECTOR_LOG_TRANSFER_TO_SUBSTRATE,
+                caller,
+                asset_address,
+                EvmDataWriter::new().write(dest).write(amount).build(),
+            )
+            .record(handle)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{mock::*, *};
+use frame_support::assert_ok;
+use precompile_utils::PrecompileTesterExt;
+use sp_core::U256;
+use std::str::from_utf8;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+#[test]
+fn selectors() {
+    assert_eq!(Action::TransferToSubstrate as u32, 0xd449d4bf);
+}
+
+#[test]
+fn transfer_native_to_substrate_works() {
+    new_test_ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                ALICE,
+                hash(PRECOMPILE_ADDRESS),
+                EvmDataWriter::new_with_selector(Action::TransferToSubstrate)
+                    .write(Address(hash(NATIVE_ADDRESS)))
+                    .write(U256::from(400))
+                    .write(H256(BOB.into()))
+                    .build(),
+            )
+            .expect_log(
+                LogsBuilder::new(hash(PRECOMPILE_ADDRESS)).log3(
+                    SELECTOR_LOG_TRANSFER_TO_SUBSTRATE,
+                    ALICE,
+                    hash(NATIVE_ADDRESS),
+                    EvmDataWriter::new()
+                        .write(H256(BOB.into()))
+                        .write(U256::from(400))
+                        .build(),
+                ),
+            )
+            .execute_returns(EvmDataWriter::new().write(true).build());
+
+        assert_eq!(Balances::free_balance(mapped(ALICE)), 600);
+        assert_eq!(Balances::free_balance(BOB), 400);
+    });
+}
+
+#[test]
+fn transfer_asset_to_substrate_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            1,
+            mapped(ALICE),
+            true,
+            1
+        ));
+        assert_ok!(Assets::mint(
+            RuntimeOrigin::signed(mapped(ALICE)),
+            1,
+            mapped(ALICE),
+            1_000
+        ));
+
+        precompiles()
+            .prepare_test(
+                ALICE,
+                hash(PRECOMPILE_ADDRESS),
+                EvmDataWriter::new_with_selector(Action::TransferToSubstrate)
+                    .write(Address(Runtime::asset_id_to_address(1)))
+                    .write(U256::from(1_000))
+                    .write(H256(BOB.into()))
+                    .build(),
+            )
+            .execute_returns(EvmDataWriter::new().write(true).build());
+
+        // The whole balance of an asset can be moved
+        assert_eq!(Assets::balance(1, mapped(ALICE)), 0);
+        assert_eq!(Assets::balance(1, BOB), 1_000);
+    });
+}
+
+#[test]
+fn transfer_to_substrate_checks_existential_deposit() {
+    new_test_ext().execute_with(|| {
+        // Creates BOB with less than the existential deposit
+        precompiles()
+            .prepare_test(
+                ALICE,
+                hash(PRECOMPILE_ADDRESS),
+                EvmDataWriter::new_with_selector(Action::TransferToSubstrate)
+                    .write(Address(hash(NATIVE_ADDRESS)))
+                    .write(U256::from(5))
+                    .write(H256(BOB.into()))
+                    .build(),
+            )
+            .execute_reverts(|output| from_utf8(output).unwrap().contains("BelowMinimumBalance"));
+
+        // Leaves ALICE with less than the existential deposit
+        precompiles()
+            .prepare_test(
+                ALICE,
+                hash(PRECOMPILE_ADDRESS),
+                EvmDataWriter::new_with_selector(Action::TransferToSubstrate)
+                    .write(Address(hash(NATIVE_ADDRESS)))
+                    .write(U256::from(995))
+                    .write(H256(BOB.into()))
+                    .build(),
+            )
+            .execute_reverts(|output| from_utf8(output).unwrap().contains("WouldKillAccount"));
+
+        assert_eq!(Balances::free_balance(mapped(ALICE)), 1_000);
+    });
+}
+
+#[test]
+fn transfer_to_substrate_rejects_unknown_assets() {
+    new_test_ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                ALICE,
+                hash(PRECOMPILE_ADDRESS),
+                EvmDataWriter::new_with_selector(Action::TransferToSubstrate)
+                    .write(Address(H160::repeat_byte(0x11)))
+                    .write(U256::from(400))
+                    .write(H256(BOB.into()))
+                    .build(),
+            )
+            .execute_reverts(|output| output == b"asset is not an ERC20 precompile");
+    });
+}
@@ -0,0 +1,95 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Handle to run precompiles in benchmarks, where there is no EVM executor around them.
+
+use super::*;
+use fp_evm::{ExitReason, Transfer};
+
+/// Precompile handle calling a precompile directly from a benchmark.
+///
+/// Gas is recorded without limit and logs are dropped, the benchmark measures the
+/// execution of the precompile itself. Subcalls are not supported.
+pub struct BenchHandle {
+    pub gas_used: u64,
+    pub code_address: H160,
+    pub input: Vec<u8>,
+    pub context: Context,
+}
+
+impl BenchHandle {
+    /// Call the precompile at `code_address` from `caller` with `input`.
+    pub fn new(code_address: H160, caller: H160, input: Vec<u8>) -> Self {
+        Self {
+            gas_used: 0,
+            code_address,
+            input,
+            context: Context {
+                address: code_address,
+                caller,
+                apparent_value: U256::zero(),
+            },
+        }
+    }
+}
+
+impl PrecompileHandle for BenchHandle {
+    fn call(
+        &mut self,
+        _address: H160,
+        _transfer: Option<Transfer>,
+        _input: Vec<u8>,
+        _target_gas: Option<u64>,
+        _is_static: bool,
+        _context: &Context,
+    ) -> (ExitReason, Vec<u8>) {
+        (
+            ExitReason::Error(ExitError::Other("no subcalls in benchmarks".into())),
+            vec![],
+        )
+    }
+
+    fn record_cost(&mut self, cost: u64) -> Result<(), ExitError> {
+        self.gas_used = self.gas_used.saturating_add(cost);
+        Ok(())
+    }
+
+    fn remaining_gas(&self) -> u64 {
+        u64::MAX.saturating_sub(self.gas_used)
+    }
+
+    fn log(&mut self, _address: H160, _topics: Vec<H256>, _data: Vec<u8>) -> Result<(), ExitError> {
+        Ok(())
+    }
+
+    fn code_address(&self) -> H160 {
+        self.code_address
+    }
+
+    fn input(&self) -> &[u8] {
+        &self.input
+    }
+
+    fn context(&self) -> &Context {
+        &self.context
+    }
+
+    fn is_static(&self) -> bool {
+        false
+    }
+
+    fn gas_limit(&self) -> Option<u64> {
+        None
+    }
+}
@@ -16,9 +16,10 @@ use crate::{revert, EvmResult};
 
 use alloc::borrow::ToOwned;
 use core::{any::type_name, ops::Range};
+use frame_support::traits::Get;
 use impl_trait_for_tuples::impl_for_tuples;
 use sp_core::{H160, H256, U256};
-use sp_std::{convert::TryInto, vec, vec::Vec};
+use sp_std::{convert::TryInto, fmt, marker::PhantomData, vec, vec::Vec};
 
 pub mod xcm;
 
@@ -77,6 +78,129 @@ impl From<Bytes> for Vec<u8> {
     }
 }
 
+/// The `bytes`/`string` type of Solidity, holding at most `S` bytes.
+/// Reading longer data reverts before it's copied out of the input.
+pub struct BoundedBytes<S> {
+    inner: Vec<u8>,
+    _phantom: PhantomData<S>,
+}
+
+impl<S: Get<u32>> BoundedBytes<S> {
+    /// Interpret as `bytes`.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+
+    /// Interpret as `string`.
+    /// Can fail if the string is not valid UTF8.
+    pub fn as_str(&self) -> Result<&str, sp_std::str::Utf8Error> {
+        sp_std::str::from_utf8(&self.inner)
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.inner
+    }
+}
+
+impl<S: Get<u32>> TryFrom<Vec<u8>> for BoundedBytes<S> {
+    type Error = Vec<u8>;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        if value.len() > S::get() as usize {
+            return Err(value);
+        }
+        Ok(Self {
+            inner: value,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<S> From<BoundedBytes<S>> for Bytes {
+    fn from(value: BoundedBytes<S>) -> Bytes {
+        Bytes(value.inner)
+    }
+}
+
+impl<S> Clone for BoundedBytes<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S> PartialEq for BoundedBytes<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<S> Eq for BoundedBytes<S> {}
+
+impl<S> fmt::Debug for BoundedBytes<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("BoundedBytes").field(&self.inner).finish()
+    }
+}
+
+/// The `T[]` type of Solidity, holding at most `S` items.
+/// Reading a longer array reverts before any item is read.
+pub struct BoundedVec<T, S> {
+    inner: Vec<T>,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S: Get<u32>> BoundedVec<T, S> {
+    pub fn into_inner(self) -> Vec<T> {
+        self.inner
+    }
+}
+
+impl<T, S> AsRef<[T]> for BoundedVec<T, S> {
+    fn as_ref(&self) -> &[T] {
+        &self.inner
+    }
+}
+
+impl<T, S: Get<u32>> TryFrom<Vec<T>> for BoundedVec<T, S> {
+    type Error = Vec<T>;
+
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        if value.len() > S::get() as usize {
+            return Err(value);
+        }
+        Ok(Self {
+            inner: value,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Clone, S> Clone for BoundedVec<T, S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: PartialEq, S> PartialEq for BoundedVec<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Eq, S> Eq for BoundedVec<T, S> {}
+
+impl<T: fmt::Debug, S> fmt::Debug for BoundedVec<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("BoundedVec").field(&self.inner).finish()
+    }
+}
+
 /// Wrapper around an EVM input slice, helping to parse it.
 /// Provide functions to parse common types.
 #[derive(Clone, Copy, Debug)]
@@ -210,15 +334,13 @@ pub struct EvmDataWriter {
     selector: Option<u32>,
 }
 
+/// Dynamic data of a container, encoded in its tail and pointed at from its head.
 #[derive(Clone, Debug)]
 struct OffsetDatum {
-    // Offset location in the container data.
+    // Offset location in the container head.
     offset_position: usize,
     // Data pointed by the offset that must be inserted at the end of container data.
     data: Vec<u8>,
-    // Inside of arrays, the offset is not from the start of array data (length), but from the start
-    // of the item. This shift allow to correct this.
-    offset_shift: usize,
 }
 
 impl EvmDataWriter {
@@ -263,10 +385,9 @@ impl EvmDataWriter {
 
             // The offset is the distance between the start of the data and the
             // start of the pointed data (start of a struct, length of an array).
-            // Offsets in inner data are relative to the start of their respective "container".
-            // However in arrays the "container" is actually the item itself instead of the whole
-            // array, which is corrected by `offset_shift`.
-            let free_space_offset = output.len() - offset_datum.offset_shift;
+            // The pointed data is already encoded on its own, any offset it contains is relative
+            // to its own start, as the ABI expects from nested containers.
+            let free_space_offset = output.len();
 
             // Override dummy offset to the offset it will be in the final output.
             U256::from(free_space_offset)
@@ -303,7 +424,6 @@ impl EvmDataWriter {
         self.offset_data.push(OffsetDatum {
             offset_position,
             data,
-            offset_shift: 0,
         });
     }
 }
@@ -495,51 +615,26 @@ impl EvmData for bool {
 
 impl<T: EvmData> EvmData for Vec<T> {
     fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
-        let mut inner_reader = reader.read_pointer()?;
-
-        let array_size: usize = inner_reader
-            .read::<U256>()
-            .map_err(|_| revert("tried to parse array length out of bounds"))?
-            .try_into()
-            .map_err(|_| revert("array length is too large"))?;
-
-        let mut array = vec![];
-
-        let mut item_reader = EvmDataReader {
-            input: inner_reader
-                .input
-                .get(32..)
-                .ok_or_else(|| revert("try to read array items out of bound"))?,
-            cursor: 0,
-        };
-
-        for _ in 0..array_size {
-            array.push(item_reader.read()?);
-        }
-
-        Ok(array)
+        read_array(reader, usize::MAX)
     }
 
     fn write(writer: &mut EvmDataWriter, value: Self) {
-        let mut inner_writer = EvmDataWriter::new().write(U256::from(value.len()));
-
-        for inner in value {
-            // Any offset in items are relative to the start of the item instead of the
-            // start of the array. However if there is offsetted data it must but appended after
-            // all items (offsets) are written. We thus need to rely on `compute_offsets` to do
-            // that, and must store a "shift" to correct the offsets.
-            let shift = inner_writer.data.len();
-            let item_writer = EvmDataWriter::new().write(inner);
-
-            inner_writer = inner_writer.write_raw_bytes(&item_writer.data);
-            for mut offset_datum in item_writer.offset_data {
-                offset_datum.offset_shift += 32;
-                offset_datum.offset_position += shift;
-                inner_writer.offset_data.push(offset_datum);
-            }
+        let length = U256::from(value.len());
+
+        // The items are encoded as a tuple following the length, so the offsets of dynamic items
+        // are relative to the first item and their data is appended once all the heads are
+        // written.
+        let mut items_writer = EvmDataWriter::new();
+        for item in value {
+            T::write(&mut items_writer, item);
         }
 
-        writer.write_pointer(inner_writer.build());
+        writer.write_pointer(
+            EvmDataWriter::new()
+                .write(length)
+                .write_raw_bytes(&items_writer.build())
+                .build(),
+        );
     }
 
     fn has_static_size() -> bool {
@@ -549,26 +644,7 @@ impl<T: EvmData> EvmData for Vec<T> {
 
 impl EvmData for Bytes {
     fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
-        let mut inner_reader = reader.read_pointer()?;
-
-        // Read bytes/string size.
-        let array_size: usize = inner_reader
-            .read::<U256>()
-            .map_err(|_| revert("tried to parse bytes/string length out of bounds"))?
-            .try_into()
-            .map_err(|_| revert("bytes/string length is too large"))?;
-
-        // Get valid range over the bytes data.
-        let range = inner_reader.move_cursor(array_size)?;
-
-        let data = inner_reader
-            .input
-            .get(range)
-            .ok_or_else(|| revert("tried to parse bytes/string out of bounds"))?;
-
-        let bytes = Self(data.to_owned());
-
-        Ok(bytes)
+        Ok(Self(read_bytes(reader, usize::MAX)?))
     }
 
     fn write(writer: &mut EvmDataWriter, value: Self) {
@@ -598,3 +674,94 @@ impl EvmData for Bytes {
         false
     }
 }
+
+impl<S: Get<u32>> EvmData for BoundedBytes<S> {
+    fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+        Ok(Self {
+            inner: read_bytes(reader, S::get() as usize)?,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn write(writer: &mut EvmDataWriter, value: Self) {
+        EvmData::write(writer, Bytes::from(value))
+    }
+
+    fn has_static_size() -> bool {
+        false
+    }
+}
+
+impl<T: EvmData, S: Get<u32>> EvmData for BoundedVec<T, S> {
+    fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+        Ok(Self {
+            inner: read_array(reader, S::get() as usize)?,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn write(writer: &mut EvmDataWriter, value: Self) {
+        EvmData::write(writer, value.inner)
+    }
+
+    fn has_static_size() -> bool {
+        false
+    }
+}
+
+/// Read a `T[]` array of at most `max_len` items.
+fn read_array<T: EvmData>(reader: &mut EvmDataReader, max_len: usize) -> EvmResult<Vec<T>> {
+    let mut inner_reader = reader.read_pointer()?;
+
+    let array_size: usize = inner_reader
+        .read::<U256>()
+        .map_err(|_| revert("tried to parse array length out of bounds"))?
+        .try_into()
+        .map_err(|_| revert("array length is too large"))?;
+
+    if array_size > max_len {
+        return Err(revert("array length exceeds the maximum"));
+    }
+
+    let mut array = vec![];
+
+    let mut item_reader = EvmDataReader {
+        input: inner_reader
+            .input
+            .get(32..)
+            .ok_or_else(|| revert("try to read array items out of bound"))?,
+        cursor: 0,
+    };
+
+    for _ in 0..array_size {
+        array.push(item_reader.read()?);
+    }
+
+    Ok(array)
+}
+
+/// Read `bytes`/`string` data of at most `max_len` bytes.
+fn read_bytes(reader: &mut EvmDataReader, max_len: usize) -> EvmResult<Vec<u8>> {
+    let mut inner_reader = reader.read_pointer()?;
+
+    // Read bytes/string size.
+    let array_size: usize = inner_reader
+        .read::<U256>()
+        .map_err(|_| revert("tried to parse bytes/string length out of bounds"))?
+        .try_into()
+        .map_err(|_| revert("bytes/string length is too large"))?;
+
+    if array_size > max_len {
+        return Err(revert("bytes/string length exceeds the maximum"));
+    }
+
+    // Get valid range over the bytes data.
+    let range = inner_reader.move_cursor(array_size)?;
+
+    let data = inner_reader
+        .input
+        .get(range)
+        .ok_or_else(|| revert("tried to parse bytes/string out of bounds"))?;
+
+    Ok(data.to_owned())
+}
@@ -14,13 +14,20 @@
 
 //! Encoding of XCM types for solidity
 
-use crate::{revert, Bytes, EvmData, EvmDataReader, EvmDataWriter, EvmResult};
+use crate::{
+    revert, BoundedBytes, BoundedVec, Bytes, EvmData, EvmDataReader, EvmDataWriter, EvmResult,
+};
 
-use frame_support::ensure;
+use frame_support::{ensure, traits::ConstU32};
 use sp_runtime::WeakBoundedVec;
 use sp_std::vec::Vec;
 use xcm::latest::{Junction, Junctions, MultiLocation, NetworkId};
 
+/// Largest encoded junction: the selector, an `AccountId32` and a named network id
+const JUNCTION_SIZE_LIMIT: u32 = 1 + 32 + 1 + 32;
+/// Junctions hold at most 8 junctions
+const JUNCTIONS_LIMIT: u32 = 8;
+
 // Function to convert network id to bytes
 // We don't implement EVMData here as these bytes will be appended only
 // to certain Junction variants
@@ -77,7 +84,7 @@ pub(crate) fn network_id_from_bytes(encoded_bytes: Vec<u8>) -> EvmResult<Network
 
 impl EvmData for Junction {
     fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
-        let junction = reader.read::<Bytes>()?;
+        let junction = reader.read::<BoundedBytes<ConstU32<JUNCTION_SIZE_LIMIT>>>()?;
         let junction_bytes = junction.as_bytes();
 
         ensure!(
@@ -210,9 +217,9 @@ impl EvmData for Junction {
 
 impl EvmData for Junctions {
     fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
-        let junctions_bytes: Vec<Junction> = reader.read()?;
+        let junctions_bytes: BoundedVec<Junction, ConstU32<JUNCTIONS_LIMIT>> = reader.read()?;
         let mut junctions = Junctions::Here;
-        for item in junctions_bytes {
+        for item in junctions_bytes.into_inner() {
             junctions
                 .push(item)
                 .map_err(|_| revert("overflow when reading junctions"))?;
@@ -25,16 +25,20 @@ use fp_evm::{
 use frame_support::{
     dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
     traits::Get,
+    weights::Weight,
 };
 use pallet_evm::{GasWeightMapping, Log};
+use pallet_traits::reentrancy::{DefiFlow, FlowGuard};
 use sp_core::{H160, H256, U256};
 use sp_std::{marker::PhantomData, vec, vec::Vec};
 
 mod data;
 
-pub use data::{Address, Bytes, EvmData, EvmDataReader, EvmDataWriter};
+pub use data::{Address, BoundedBytes, BoundedVec, Bytes, EvmData, EvmDataReader, EvmDataWriter};
 pub use precompile_utils_macro::{generate_function_selector, keccak256};
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
 #[cfg(feature = "testing")]
 pub mod testing;
 #[cfg(feature = "testing")]
@@ -188,6 +192,11 @@ where
             });
         }
 
+        // The dispatched call may neither reach another precompile nor the DeFi pallets,
+        // unless they accept to run inside `DefiFlow::Evm`.
+        let _guard = FlowGuard::enter(DefiFlow::Evm)
+            .map_err(|e| revert(alloc::format!("Dispatched call refused: {:?}", e)))?;
+
         // Dispatch call.
         // It may be possible to not record gas cost if the call returns Pays::No.
         // However while Substrate handle checking weight while not making the sender pay for it,
@@ -224,6 +233,11 @@ where
             <Runtime as frame_system::Config>::DbWeight::get().reads(1),
         )
     }
+
+    /// Cost in gas of a benchmarked weight.
+    pub fn weight_gas_cost(weight: Weight) -> u64 {
+        <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+    }
 }
 
 /// Represents modifiers a Solidity function can be annotated with.
@@ -14,6 +14,7 @@
 
 use super::*;
 use crate::data::xcm::{network_id_from_bytes, network_id_to_bytes};
+use frame_support::traits::ConstU32;
 use hex_literal::hex;
 use sp_core::{H256, U256};
 use sp_runtime::WeakBoundedVec;
@@ -1030,3 +1031,459 @@ fn write_dynamic_size_tuple() {
 
     assert_eq!(output, data);
 }
+
+#[test]
+fn write_static_size_tuple_array() {
+    let value = vec![
+        (Address(H160::repeat_byte(0x11)), U256::from(1u8)),
+        (Address(H160::repeat_byte(0x22)), U256::from(2u8)),
+    ];
+    let output = EvmDataWriter::new().write(value.clone()).build();
+
+    // (address, uint256)[] encoded by web3
+    let data = hex!(
+        "0000000000000000000000000000000000000000000000000000000000000020
+		0000000000000000000000000000000000000000000000000000000000000002
+		0000000000000000000000001111111111111111111111111111111111111111
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000002222222222222222222222222222222222222222
+		0000000000000000000000000000000000000000000000000000000000000002"
+    );
+
+    assert_eq!(output, data);
+    assert_eq!(
+        EvmDataReader::new(&output)
+            .read::<Vec<(Address, U256)>>()
+            .unwrap(),
+        value
+    );
+}
+
+#[test]
+fn write_dynamic_size_tuple_array() {
+    let value = vec![
+        MultiLocation {
+            parents: 1,
+            interior: vec![Bytes(hex!("00000007d0").to_vec())],
+        },
+        MultiLocation {
+            parents: 0,
+            interior: vec![
+                Bytes(hex!("0403").to_vec()),
+                Bytes(hex!("0500000000000000000000000000000001").to_vec()),
+            ],
+        },
+    ];
+    let output = EvmDataWriter::new().write(value.clone()).build();
+
+    // (uint8, bytes[])[] encoded by web3
+    let data = hex!(
+        "0000000000000000000000000000000000000000000000000000000000000020
+		0000000000000000000000000000000000000000000000000000000000000002
+		0000000000000000000000000000000000000000000000000000000000000040
+		0000000000000000000000000000000000000000000000000000000000000100
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000000000000000000000000000000000000000000040
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000000000000000000000000000000000000000000020
+		0000000000000000000000000000000000000000000000000000000000000005
+		00000007d0000000000000000000000000000000000000000000000000000000
+		0000000000000000000000000000000000000000000000000000000000000000
+		0000000000000000000000000000000000000000000000000000000000000040
+		0000000000000000000000000000000000000000000000000000000000000002
+		0000000000000000000000000000000000000000000000000000000000000040
+		0000000000000000000000000000000000000000000000000000000000000080
+		0000000000000000000000000000000000000000000000000000000000000002
+		0403000000000000000000000000000000000000000000000000000000000000
+		0000000000000000000000000000000000000000000000000000000000000011
+		0500000000000000000000000000000001000000000000000000000000000000"
+    );
+
+    assert_eq!(output, data);
+    assert_eq!(
+        EvmDataReader::new(&output)
+            .read::<Vec<MultiLocation>>()
+            .unwrap(),
+        value
+    );
+}
+
+#[test]
+fn write_nested_dynamic_array() {
+    let value = vec![
+        vec![Bytes(vec![0x01])],
+        vec![Bytes(vec![0x02, 0x03]), Bytes(vec![0x04])],
+    ];
+    let output = EvmDataWriter::new().write(value.clone()).build();
+
+    // bytes[][] encoded by web3
+    let data = hex!(
+        "0000000000000000000000000000000000000000000000000000000000000020
+		0000000000000000000000000000000000000000000000000000000000000002
+		0000000000000000000000000000000000000000000000000000000000000040
+		00000000000000000000000000000000000000000000000000000000000000c0
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000000000000000000000000000000000000000000020
+		0000000000000000000000000000000000000000000000000000000000000001
+		0100000000000000000000000000000000000000000000000000000000000000
+		0000000000000000000000000000000000000000000000000000000000000002
+		0000000000000000000000000000000000000000000000000000000000000040
+		0000000000000000000000000000000000000000000000000000000000000080
+		0000000000000000000000000000000000000000000000000000000000000002
+		0203000000000000000000000000000000000000000000000000000000000000
+		0000000000000000000000000000000000000000000000000000000000000001
+		0400000000000000000000000000000000000000000000000000000000000000"
+    );
+
+    assert_eq!(output, data);
+    assert_eq!(
+        EvmDataReader::new(&output)
+            .read::<Vec<Vec<Bytes>>>()
+            .unwrap(),
+        value
+    );
+}
+
+#[test]
+fn write_nested_dynamic_size_tuple() {
+    let value = (
+        U256::from(7u8),
+        (Address(H160::repeat_byte(0x11)), Bytes(vec![0xab, 0xcd])),
+        vec![Bytes(vec![0x01])],
+    );
+    let output = EvmDataWriter::new().write(value.clone()).build();
+
+    // (uint256, (address, bytes), bytes[]) encoded by web3
+    let data = hex!(
+        "0000000000000000000000000000000000000000000000000000000000000020
+		0000000000000000000000000000000000000000000000000000000000000007
+		0000000000000000000000000000000000000000000000000000000000000060
+		00000000000000000000000000000000000000000000000000000000000000e0
+		0000000000000000000000001111111111111111111111111111111111111111
+		0000000000000000000000000000000000000000000000000000000000000040
+		0000000000000000000000000000000000000000000000000000000000000002
+		abcd000000000000000000000000000000000000000000000000000000000000
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000000000000000000000000000000000000000000020
+		0000000000000000000000000000000000000000000000000000000000000001
+		0100000000000000000000000000000000000000000000000000000000000000"
+    );
+
+    assert_eq!(output, data);
+    assert_eq!(
+        EvmDataReader::new(&output)
+            .read::<(U256, (Address, Bytes), Vec<Bytes>)>()
+            .unwrap(),
+        value
+    );
+}
+
+#[test]
+fn read_bounded_bytes() {
+    let data = b"Lorem ipsum dolor sit amet";
+    let writer_output = EvmDataWriter::new().write(Bytes::from(&data[..])).build();
+
+    let parsed: BoundedBytes<ConstU32<26>> = EvmDataReader::new(&writer_output)
+        .read()
+        .expect("to correctly parse bounded bytes");
+    assert_eq!(parsed.as_bytes(), &data[..]);
+
+    match EvmDataReader::new(&writer_output).read::<BoundedBytes<ConstU32<25>>>() {
+        Ok(_) => panic!("should not parse correctly"),
+        Err(PrecompileFailure::Revert { output: err, .. }) => {
+            assert_eq!(err, b"bytes/string length exceeds the maximum")
+        }
+        Err(_) => panic!("unexpected error"),
+    }
+}
+
+#[test]
+fn write_bounded_bytes() {
+    let data = b"Lorem ipsum dolor sit amet";
+    let bounded: BoundedBytes<ConstU32<32>> = data.to_vec().try_into().unwrap();
+
+    assert_eq!(
+        EvmDataWriter::new().write(bounded).build(),
+        EvmDataWriter::new().write(Bytes::from(&data[..])).build()
+    );
+    assert!(BoundedBytes::<ConstU32<16>>::try_from(data.to_vec()).is_err());
+}
+
+#[test]
+fn read_bounded_vec() {
+    let array = vec![U256::from(1u8), U256::from(2u8), U256::from(3u8)];
+    let writer_output = EvmDataWriter::new().write(array.clone()).build();
+
+    let parsed: BoundedVec<U256, ConstU32<3>> = EvmDataReader::new(&writer_output)
+        .read()
+        .expect("to correctly parse bounded array");
+    assert_eq!(parsed.into_inner(), array);
+
+    match EvmDataReader::new(&writer_output).read::<BoundedVec<U256, ConstU32<2>>>() {
+        Ok(_) => panic!("should not parse correctly"),
+        Err(PrecompileFailure::Revert { output: err, .. }) => {
+            assert_eq!(err, b"array length exceeds the maximum")
+        }
+        Err(_) => panic!("unexpected error"),
+    }
+}
+
+#[test]
+fn read_bounded_vec_rejects_length_before_items() {
+    // A huge declared length is refused without reading any item
+    let mut writer_output = EvmDataWriter::new().write(vec![U256::one()]).build();
+    U256::from(u32::MAX).to_big_endian(&mut writer_output[0x20..0x40]);
+
+    match EvmDataReader::new(&writer_output).read::<BoundedVec<U256, ConstU32<8>>>() {
+        Ok(_) => panic!("should not parse correctly"),
+        Err(PrecompileFailure::Revert { output: err, .. }) => {
+            assert_eq!(err, b"array length exceeds the maximum")
+        }
+        Err(_) => panic!("unexpected error"),
+    }
+}
+
+#[test]
+fn oversized_junction_is_rejected() {
+    let mut encoded = vec![6u8];
+    encoded.resize(1 + 32 + 1 + 32 + 1, 0);
+    let writer_output = EvmDataWriter::new().write(Bytes(encoded)).build();
+
+    match EvmDataReader::new(&writer_output).read::<Junction>() {
+        Ok(_) => panic!("should not parse correctly"),
+        Err(PrecompileFailure::Revert { output: err, .. }) => {
+            assert_eq!(err, b"bytes/string length exceeds the maximum")
+        }
+        Err(_) => panic!("unexpected error"),
+    }
+}
+
+// Golden vectors of the XCM types, as encoded by the Moonbeam x-tokens precompile.
+// Each junction is a `bytes` made of the variant selector followed by its data, the network
+// id of the account junctions being appended at the end with its own selector.
+
+fn named(name: &[u8]) -> NetworkId {
+    NetworkId::Named(WeakBoundedVec::try_from(name.to_vec()).unwrap())
+}
+
+fn all_network_ids() -> Vec<NetworkId> {
+    vec![
+        NetworkId::Any,
+        named(b""),
+        named(b"myname"),
+        named(&[0xff; 32]),
+        NetworkId::Polkadot,
+        NetworkId::Kusama,
+    ]
+}
+
+#[test]
+fn network_id_golden_vectors() {
+    let vectors = vec![
+        (NetworkId::Any, hex!("00").to_vec()),
+        (named(b"myname"), hex!("016d796e616d65").to_vec()),
+        (NetworkId::Polkadot, hex!("02").to_vec()),
+        (NetworkId::Kusama, hex!("03").to_vec()),
+    ];
+
+    for (network_id, encoded) in vectors {
+        assert_eq!(network_id_to_bytes(network_id.clone()), encoded);
+        assert_eq!(network_id_from_bytes(encoded), Ok(network_id));
+    }
+}
+
+#[test]
+fn junction_golden_vectors() {
+    let vectors =
+        vec![
+        (Junction::Parachain(2023), hex!("00000007e7").to_vec()),
+        (Junction::Parachain(u32::MAX), hex!("00ffffffff").to_vec()),
+        (
+            Junction::AccountId32 {
+                network: NetworkId::Any,
+                id: [0x01; 32],
+            },
+            hex!("01010101010101010101010101010101010101010101010101010101010101010100").to_vec(),
+        ),
+        (
+            Junction::AccountId32 {
+                network: named(b"myname"),
+                id: [0x01; 32],
+            },
+            hex!("010101010101010101010101010101010101010101010101010101010101010101016d796e616d65")
+                .to_vec(),
+        ),
+        (
+            Junction::AccountIndex64 {
+                network: NetworkId::Polkadot,
+                index: 0x0102030405060708,
+            },
+            hex!("02010203040506070802").to_vec(),
+        ),
+        (
+            Junction::AccountKey20 {
+                network: NetworkId::Kusama,
+                key: [0xaa; 20],
+            },
+            hex!("03aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa03").to_vec(),
+        ),
+        (Junction::PalletInstance(52), hex!("0434").to_vec()),
+        (
+            Junction::GeneralIndex(1),
+            hex!("0500000000000000000000000000000001").to_vec(),
+        ),
+        (
+            Junction::GeneralKey(WeakBoundedVec::try_from(b"key".to_vec()).unwrap()),
+            hex!("066b6579").to_vec(),
+        ),
+        (Junction::OnlyChild, hex!("07").to_vec()),
+    ];
+
+    for (junction, encoded) in vectors {
+        let golden = EvmDataWriter::new().write(Bytes(encoded)).build();
+
+        assert_eq!(
+            EvmDataWriter::new().write(junction.clone()).build(),
+            golden,
+            "{:?} is not encoded as the golden vector",
+            junction
+        );
+        assert_eq!(EvmDataReader::new(&golden).read::<Junction>(), Ok(junction));
+    }
+}
+
+#[test]
+fn multilocation_golden_vector() {
+    let location = xcm::latest::MultiLocation {
+        parents: 1,
+        interior: Junctions::X2(
+            Junction::Parachain(2023),
+            Junction::AccountKey20 {
+                network: NetworkId::Any,
+                key: [0x11; 20],
+            },
+        ),
+    };
+
+    // (uint8, bytes[]) encoded by web3
+    let data = hex!(
+        "0000000000000000000000000000000000000000000000000000000000000020
+		0000000000000000000000000000000000000000000000000000000000000001
+		0000000000000000000000000000000000000000000000000000000000000040
+		0000000000000000000000000000000000000000000000000000000000000002
+		0000000000000000000000000000000000000000000000000000000000000040
+		0000000000000000000000000000000000000000000000000000000000000080
+		0000000000000000000000000000000000000000000000000000000000000005
+		00000007e7000000000000000000000000000000000000000000000000000000
+		0000000000000000000000000000000000000000000000000000000000000016
+		0311111111111111111111111111111111111111110000000000000000000000"
+    );
+
+    assert_eq!(EvmDataWriter::new().write(location.clone()).build(), data);
+    assert_eq!(
+        EvmDataReader::new(&data).read::<xcm::latest::MultiLocation>(),
+        Ok(location)
+    );
+}
+
+#[test]
+fn every_junction_round_trips() {
+    let mut junctions = vec![
+        Junction::Parachain(0),
+        Junction::Parachain(u32::MAX),
+        Junction::PalletInstance(0),
+        Junction::PalletInstance(u8::MAX),
+        Junction::GeneralIndex(0),
+        Junction::GeneralIndex(u128::MAX),
+        Junction::GeneralKey(WeakBoundedVec::try_from(vec![]).unwrap()),
+        Junction::GeneralKey(WeakBoundedVec::try_from(vec![0xff; 32]).unwrap()),
+        Junction::OnlyChild,
+    ];
+    for network in all_network_ids() {
+        junctions.push(Junction::AccountId32 {
+            network: network.clone(),
+            id: [0xff; 32],
+        });
+        junctions.push(Junction::AccountIndex64 {
+            network: network.clone(),
+            index: u64::MAX,
+        });
+        junctions.push(Junction::AccountKey20 {
+            network,
+            key: [0xff; 20],
+        });
+    }
+
+    for junction in junctions {
+        let output = EvmDataWriter::new().write(junction.clone()).build();
+        assert_eq!(EvmDataReader::new(&output).read::<Junction>(), Ok(junction));
+    }
+}
+
+#[test]
+fn every_junctions_length_round_trips() {
+    let mut junctions = Junctions::Here;
+    for parents in [0u8, 1, u8::MAX] {
+        let location = xcm::latest::MultiLocation {
+            parents,
+            interior: junctions.clone(),
+        };
+        let output = EvmDataWriter::new().write(location.clone()).build();
+        assert_eq!(
+            EvmDataReader::new(&output).read::<xcm::latest::MultiLocation>(),
+            Ok(location)
+        );
+    }
+
+    for i in 0..8u32 {
+        junctions.push(Junction::Parachain(i)).unwrap();
+
+        let output = EvmDataWriter::new().write(junctions.clone()).build();
+        assert_eq!(
+            EvmDataReader::new(&output).read::<Junctions>(),
+            Ok(junctions.clone())
+        );
+    }
+}
+
+#[test]
+fn invalid_xcm_encodings_revert() {
+    let revert_message = |output: Vec<u8>, read: fn(&[u8]) -> EvmResult| match read(&output) {
+        Ok(_) => panic!("should not parse correctly"),
+        Err(PrecompileFailure::Revert { output: err, .. }) => err,
+        Err(_) => panic!("unexpected error"),
+    };
+
+    // Unknown junction selector
+    assert_eq!(
+        revert_message(
+            EvmDataWriter::new().write(Bytes(vec![8])).build(),
+            |input| EvmDataReader::new(input).read::<Junction>().map(|_| ())
+        ),
+        b"No selector for this"
+    );
+
+    // Unknown network id selector
+    assert_eq!(
+        revert_message(
+            EvmDataWriter::new()
+                .write(Bytes(
+                    hex!("03aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa04").to_vec()
+                ))
+                .build(),
+            |input| EvmDataReader::new(input).read::<Junction>().map(|_| ())
+        ),
+        b"Non-valid Network Id"
+    );
+
+    // More than 8 junctions
+    assert_eq!(
+        revert_message(
+            EvmDataWriter::new()
+                .write(vec![Junction::OnlyChild; 9])
+                .build(),
+            |input| EvmDataReader::new(input).read::<Junctions>().map(|_| ())
+        ),
+        b"array length exceeds the maximum"
+    );
+}
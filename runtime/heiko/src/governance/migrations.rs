@@ -0,0 +1,128 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::{
+    GetStorageVersion, LockIdentifier, LockableCurrency, OnRuntimeUpgrade, StorageVersion,
+    WithdrawReasons,
+};
+use pallet_conviction_voting::{ClassLocksFor, Voting, VotingFor};
+use pallet_democracy::{ReferendumInfo, ReferendumInfoOf};
+use sp_runtime::traits::Saturating;
+use tracks::ROOT_TRACK;
+
+const DEMOCRACY_ID: LockIdentifier = *b"democrac";
+const CONVICTION_VOTING_ID: LockIdentifier = *b"pyconvot";
+
+/// Storage version of democracy once its locks were moved to conviction voting
+const MIGRATED_VERSION: StorageVersion = StorageVersion::new(2);
+
+/// Moves the vote locks of democracy to the root track of conviction voting.
+///
+/// Democracy votes are dropped, their locked balance is kept as a prior lock of the
+/// root track for one `VoteLockingPeriod` and can be unlocked with
+/// `ConvictionVoting::unlock` afterwards. Ongoing democracy referenda are cancelled
+/// first, since their tallies would still count the dropped votes.
+///
+/// Only runs on the version 1 storage of democracy, and moves it to version 2.
+pub struct MigrateDemocracyLocks;
+impl OnRuntimeUpgrade for MigrateDemocracyLocks {
+    fn on_runtime_upgrade() -> Weight {
+        let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+        if Democracy::on_chain_storage_version() != StorageVersion::new(1) {
+            log::info!(
+                target: "runtime::governance",
+                "democracy locks were already migrated, skipping"
+            );
+            return db_weight.reads(1);
+        }
+
+        let referenda: Vec<_> = ReferendumInfoOf::<Runtime>::iter().collect();
+        let mut reads = 1u64.saturating_add(referenda.len() as u64);
+        let mut writes = 1u64;
+        for (index, info) in referenda {
+            if let ReferendumInfo::Ongoing(_) = info {
+                Democracy::internal_cancel_referendum(index);
+                writes.saturating_inc();
+            }
+        }
+
+        let until = System::block_number().saturating_add(VoteLockingPeriod::get());
+        let mut count = 0u64;
+        for (who, voting) in pallet_democracy::VotingOf::<Runtime>::drain() {
+            count += 1;
+            // VotingOf, the locks and account of `who` taken twice, VotingFor and ClassLocksFor
+            reads.saturating_accrue(8);
+            writes.saturating_accrue(7);
+            let locked = Balances::locks(&who)
+                .iter()
+                .find(|lock| lock.id == DEMOCRACY_ID)
+                .map(|lock| lock.amount)
+                .unwrap_or_default()
+                .max(voting.locked_balance());
+            Balances::remove_lock(DEMOCRACY_ID, &who);
+            if locked.is_zero() {
+                continue;
+            }
+
+            VotingFor::<Runtime>::mutate(&who, ROOT_TRACK, |voting| {
+                if let Voting::Casting(casting) = voting {
+                    casting.prior.accumulate(until, locked);
+                }
+            });
+            ClassLocksFor::<Runtime>::mutate(&who, |locks| {
+                match locks.iter_mut().find(|(class, _)| *class == ROOT_TRACK) {
+                    Some((_, amount)) => *amount = (*amount).max(locked),
+                    None => {
+                        let _ = locks.try_push((ROOT_TRACK, locked));
+                    }
+                }
+            });
+            Balances::extend_lock(
+                CONVICTION_VOTING_ID,
+                &who,
+                locked,
+                WithdrawReasons::except(WithdrawReasons::RESERVE),
+            );
+        }
+
+        MIGRATED_VERSION.put::<Democracy>();
+        log::info!(
+            target: "runtime::governance",
+            "migrated democracy locks of {} accounts",
+            count
+        );
+        db_weight.reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+        frame_support::ensure!(
+            Democracy::on_chain_storage_version() == MIGRATED_VERSION,
+            "democracy must be at the migrated version"
+        );
+        frame_support::ensure!(
+            ReferendumInfoOf::<Runtime>::iter()
+                .all(|(_, info)| !matches!(info, ReferendumInfo::Ongoing(_))),
+            "democracy referenda must be cancelled"
+        );
+        frame_support::ensure!(
+            pallet_democracy::VotingOf::<Runtime>::iter()
+                .next()
+                .is_none(),
+            "democracy votes must be drained"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Custom origins of the referenda tracks.
+
+pub use pallet_custom_origins::*;
+
+#[frame_support::pallet]
+pub mod pallet_custom_origins {
+    use crate::{constants::currency::DOLLARS, Balance};
+    use frame_support::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {}
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+    #[pallet::origin]
+    pub enum Origin {
+        /// Origin able to spend from the treasury and approve its proposals
+        Treasurer,
+        /// Origin able to dispatch calls whitelisted by the technical committee
+        WhitelistedCaller,
+        /// Origin able to update markets and other parameters of the loans pallet
+        MarketAdmin,
+    }
+
+    macro_rules! decl_unit_ensures {
+        ( $name:ident: $success_type:ty = $success:expr ) => {
+            pub struct $name;
+            impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for $name {
+                type Success = $success_type;
+                fn try_origin(o: O) -> Result<Self::Success, O> {
+                    o.into().and_then(|o| match o {
+                        Origin::$name => Ok($success),
+                        r => Err(O::from(r)),
+                    })
+                }
+                #[cfg(feature = "runtime-benchmarks")]
+                fn successful_origin() -> O {
+                    O::from(Origin::$name)
+                }
+            }
+        };
+        ( $name:ident ) => { decl_unit_ensures! { $name : () = () } };
+        ( $name:ident: $success_type:ty = $success:expr, $( $rest:tt )* ) => {
+            decl_unit_ensures! { $name: $success_type = $success }
+            decl_unit_ensures! { $( $rest )* }
+        };
+        ( $name:ident, $( $rest:tt )* ) => {
+            decl_unit_ensures! { $name }
+            decl_unit_ensures! { $( $rest )* }
+        };
+        () => {}
+    }
+    decl_unit_ensures!(Treasurer, WhitelistedCaller, MarketAdmin);
+
+    /// Max amount the treasurer track can spend at once
+    pub const MAX_TREASURER_SPEND: Balance = 100_000 * DOLLARS;
+
+    /// Treasury spend origin, succeeds with the max amount which can be spent
+    pub struct Spender;
+    impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for Spender {
+        type Success = Balance;
+        fn try_origin(o: O) -> Result<Self::Success, O> {
+            o.into().and_then(|o| match o {
+                Origin::Treasurer => Ok(MAX_TREASURER_SPEND),
+                r => Err(O::from(r)),
+            })
+        }
+        #[cfg(feature = "runtime-benchmarks")]
+        fn successful_origin() -> O {
+            O::from(Origin::Treasurer)
+        }
+    }
+}
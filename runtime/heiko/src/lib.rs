@@ -22,10 +22,10 @@ use frame_support::{
     dispatch::{DispatchClass, DispatchResult, Weight},
     log, match_types, parameter_types,
     traits::{
-        fungibles::{InspectMetadata, Mutate},
+        fungibles::{Balanced, CreditOf, InspectMetadata, Mutate},
         tokens::BalanceConversion,
-        ChangeMembers, ConstU32, Contains, EitherOfDiverse, EqualPrivilegeOnly, Everything,
-        InstanceFilter, Nothing,
+        ChangeMembers, ConstU32, Contains, EitherOfDiverse, EqualPrivilegeOnly, Everything, Get,
+        InstanceFilter, Nothing, SortedMembers,
     },
     weights::{
         constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
@@ -57,14 +57,14 @@ use sp_runtime::{
         BlockNumberProvider, Convert, Zero,
     },
     transaction_validity::{TransactionSource, TransactionValidity},
-    ApplyExtrinsicResult, DispatchError, FixedPointNumber, KeyTypeId, Perbill, Permill,
+    ApplyExtrinsicResult, DispatchError, FixedPointNumber, KeyTypeId, Perbill, Percent, Permill,
     RuntimeDebug, SaturatedConversion,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
-use xcm::latest::prelude::*;
+use xcm::{latest::prelude::*, VersionedMultiLocation, VersionedXcm};
 use xcm_builder::{
     AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
     AllowTopLevelPaidExecutionFrom, ConvertedConcreteAssetId, EnsureXcmOrigin, FixedWeightBounds,
@@ -79,12 +79,15 @@ use xcm_executor::{traits::JustTry, Config, XcmExecutor};
 mod weights;
 
 pub mod constants;
+pub mod governance;
 
 use constants::{currency, fee, time};
 use currency::*;
 use fee::*;
 use time::*;
 
+use governance::{pallet_custom_origins, MarketAdmin, Treasurer, TreasurySpender};
+
 pub use pallet_amm;
 pub use pallet_asset_registry;
 pub use pallet_bridge;
@@ -96,20 +99,29 @@ pub use pallet_prices;
 pub use pallet_router;
 pub use pallet_streaming;
 
+use pallet_amm_rpc_runtime_api::{PositionValue, SwapQuote};
+use pallet_bridge_rpc_runtime_api::TransferInfo;
+use pallet_crowdloans_rpc_runtime_api::VaultStatus;
+use pallet_parameters::{NumberParameter, ParameterKey, RatioParameter};
+use pallet_portfolio_rpc_runtime_api::{PortfolioPositions, VersionedPortfolioPositions};
+use pallet_prices_rpc_runtime_api::{FeederStatus, Round, RoundId};
+use pallet_rewards_rpc_runtime_api::PendingRewards;
 use pallet_traits::{
     xcm::{
-        AccountIdToMultiLocation, AsAssetType, AssetType, CurrencyIdConvert, FirstAssetTrader,
-        MultiCurrencyAdapter, XcmAssetRegistry,
+        AccountIdToMultiLocation, AsAssetType, AssetConfigurator, AssetType, CurrencyIdConvert,
+        FirstAssetTrader, MultiCurrencyAdapter, PricedAssetTrader, PricedFeeAssetValidator,
+        XcmAssetRegistry, XcmAssetTransfer, XcmDryRunEffects,
     },
-    DecimalProvider, EmergencyCallFilter, ValidationDataProvider,
+    DecimalProvider, EmergencyCallFilter, OracleFeeder, PriceDependencies, PricedFeeConversion,
+    RevenueSource, ValidationDataProvider,
 };
 use primitives::{
     network::HEIKO_PREFIX,
     paras,
-    tokens::{EUSDC, EUSDT, HKO, KSM, SKSM},
-    AccountId, AuraId, Balance, BlockNumber, ChainId, CurrencyId, DataProviderId, EraIndex, Hash,
-    Index, Liquidity, Moment, PersistedValidationData, Price, Rate, Ratio, Shortfall, Signature,
-    KSM_U,
+    tokens::{DOT, EUSDC, EUSDT, HKO, KSM, SDOT, SKSM},
+    AccountId, AuraId, Balance, BlockNumber, ChainId, ChainNonce, CurrencyId, DataProviderId,
+    EraIndex, Hash, Index, Liquidity, Moment, ParaId, PersistedValidationData, Price, Rate, Ratio,
+    Shortfall, Signature, DOT_U, KSM_U,
 };
 
 // Make the WASM binary available.
@@ -222,7 +234,9 @@ impl Contains<RuntimeCall> for WhiteListFilter {
             RuntimeCall::Assets(pallet_assets::Call::force_transfer { .. }) |
             // Governance
             // Call::Sudo(_) |
-            RuntimeCall::Democracy(_) |
+            RuntimeCall::Referenda(_) |
+            RuntimeCall::ConvictionVoting(_) |
+            RuntimeCall::Whitelist(_) |
             RuntimeCall::GeneralCouncil(_) |
             RuntimeCall::TechnicalCommittee(_) |
             RuntimeCall::Treasury(_) |
@@ -292,6 +306,9 @@ impl Contains<RuntimeCall> for BaseCallFilter {
                 RuntimeCall::Crowdloans(_) |
                 // Liquid Staking
                 RuntimeCall::LiquidStaking(_) |
+                RuntimeCall::DotLiquidStaking(_) |
+                // NomineeElection
+                RuntimeCall::NomineeElection(_) |
                 // Bridge
                 RuntimeCall::Bridge(_) |
                 // Farming
@@ -299,9 +316,45 @@ impl Contains<RuntimeCall> for BaseCallFilter {
                 // Streaming
                 RuntimeCall::Streaming(_) |
                 // Asset Management
-                RuntimeCall::AssetRegistry(_)
+                RuntimeCall::AssetRegistry(_) |
+                // Remote Derivatives
+                RuntimeCall::RemoteDerivatives(_) |
+                // Fee Distribution
+                RuntimeCall::FeeDistribution(_) |
+                // Asset Treasury
+                RuntimeCall::AssetTreasury(_) |
+                // Parameters
+                RuntimeCall::Parameters(_) |
+                // Revenue
+                RuntimeCall::Revenue(_) |
+                // Collator Rewards
+                RuntimeCall::CollatorRewards(_) |
+                // Grant Vesting
+                RuntimeCall::GrantVesting(_) |
+                // Recovery
+                RuntimeCall::Recovery(_) |
+                // Zap
+                RuntimeCall::Zap(_) |
+                // Scheduled calls
+                RuntimeCall::ScheduledCalls(_) |
+                // Inheritance
+                RuntimeCall::Inheritance(_) |
+                // RelayEra
+                RuntimeCall::RelayEra(_) |
+                // Keepers
+                RuntimeCall::Keepers(_) |
+                // Referrals
+                RuntimeCall::Referrals(_)
             ))
             && EmergencyShutdown::contains(call)
+            && match call {
+                // A rescuer acts with the lost account's origin, so the wrapped call has to
+                // clear the same filter before it is accepted
+                RuntimeCall::Recovery(pallet_recovery::Call::as_recovered { call, .. }) => {
+                    Self::contains(call)
+                }
+                _ => true,
+            }
     }
 }
 
@@ -429,7 +482,7 @@ impl pallet_assets::Config for Runtime {
     type AssetAccountDeposit = AssetAccountDeposit;
     type ApprovalDeposit = ApprovalDeposit;
     type StringLimit = AssetsStringLimit;
-    type Freezer = ();
+    type Freezer = AssetFreezer;
     type WeightInfo = weights::pallet_assets::WeightInfo<Runtime>;
     type Extra = ();
 }
@@ -437,6 +490,19 @@ impl pallet_assets::Config for Runtime {
 parameter_types! {
     pub const RewardAssetId: CurrencyId = HKO;
     pub const LiquidationFreeAssetId: CurrencyId = KSM;
+    // Hourly snapshots over a week
+    pub const RateSnapshotInterval: u64 = 60 * 60;
+    pub const MaxRateSnapshots: u32 = 24 * 7;
+    // Keepers get 0.1 USD for refreshing a market left untouched for an hour
+    pub const KeeperStalePeriod: BlockNumber = HOURS;
+    pub const KeeperRewardValue: Balance = 100_000_000_000_000_000;
+    // Positions worth less than 0.01 USD can be written off as dust
+    pub const DustThresholdValue: Balance = 10_000_000_000_000_000;
+    pub const LoansMaxPageSize: u32 = 50;
+    // New markets are open to review by the technical committee for two days
+    pub const MarketReviewPeriod: BlockNumber = 2 * DAYS;
+    // cTokens back half as much when their lease starts as when it ends
+    pub const CTokenMinCollateralScale: Ratio = Ratio::from_percent(50);
 }
 
 impl pallet_loans::Config for Runtime {
@@ -444,12 +510,30 @@ impl pallet_loans::Config for Runtime {
     type PalletId = LoansPalletId;
     type PriceFeeder = Prices;
     type ReserveOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
-    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type UpdateOrigin = EitherOfDiverse<EnsureRootOrMoreThanHalfGeneralCouncil, MarketAdmin>;
     type WeightInfo = weights::pallet_loans::WeightInfo<Runtime>;
     type UnixTime = Timestamp;
     type Assets = CurrencyAdapter;
     type RewardAssetId = RewardAssetId;
     type LiquidationFreeAssetId = LiquidationFreeAssetId;
+    type Revenue = Revenue;
+    type Referrals = Referrals;
+    type EventMirror = ();
+    type RateSnapshotInterval = RateSnapshotInterval;
+    type MaxRateSnapshots = MaxRateSnapshots;
+    type KeeperStalePeriod = KeeperStalePeriod;
+    type KeeperRewardValue = KeeperRewardValue;
+    type LiquidityUnwinder = AMM;
+    type DustThresholdValue = DustThresholdValue;
+    type TreasuryAccount = TreasuryAccount;
+    type MaxPageSize = LoansMaxPageSize;
+    type CollateralAdapter =
+        pallet_crowdloans::collateral::CTokenCollateral<Runtime, Prices, CTokenMinCollateralScale>;
+    type MarketVetoOrigin = EitherOfDiverse<
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionMoreThan<AccountId, TechnicalCollective, 1, 2>,
+    >;
+    type MarketReviewPeriod = MarketReviewPeriod;
 }
 
 parameter_types! {
@@ -466,15 +550,16 @@ parameter_types! {
     pub MatchingPoolFastUnstakeFee: Rate = Rate::saturating_from_rational(1u32, 100u32);
     pub const BondingDuration: EraIndex = 28; // 7Days
     pub const MinNominatorBond: Balance = 100_000_000_000; // 0.1KSM
+    pub const MinPoolJoinBond: Balance = 1_000_000_000; // 0.001KSM
     pub const NumSlashingSpans: u32 = 0;
     pub DerivativeIndexList: Vec<u16> = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
+    pub MaxExchangeRateChange: Ratio = Ratio::from_percent(1);
     pub const ElectionSolutionStoredOffset: BlockNumber = 3150;
 }
 
 impl pallet_liquid_staking::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeOrigin = RuntimeOrigin;
-    type RuntimeCall = RuntimeCall;
     type PalletId = StakingPalletId;
     type LoansPalletId = LoansPalletId;
     type WeightInfo = weights::pallet_liquid_staking::WeightInfo<Runtime>;
@@ -490,18 +575,79 @@ impl pallet_liquid_staking::Config for Runtime {
     type StakingCurrency = StakingCurrency;
     type LiquidCurrency = LiquidCurrency;
     type CollateralCurrency = CollateralCurrency;
-    type EraLength = EraLength;
+    type RelayEra = RelayEra;
     type MinStake = MinStake;
     type MinUnstake = MinUnstake;
     type XCM = XcmHelper;
     type BondingDuration = BondingDuration;
     type MinNominatorBond = MinNominatorBond;
+    type MinPoolJoinBond = MinPoolJoinBond;
     type RelayChainValidationDataProvider = RelayChainValidationDataProvider<Runtime>;
     type Loans = Loans;
     type Members = LiquidStakingAgentsMembership;
     type NumSlashingSpans = NumSlashingSpans;
     type ElectionSolutionStoredOffset = ElectionSolutionStoredOffset;
     type ProtocolFeeReceiver = DefaultProtocolFeeReceiver;
+    type Revenue = Revenue;
+    type Referrals = Referrals;
+    type MaxExchangeRateChange = MaxExchangeRateChange;
+    type CircuitBreaker = EmergencyShutdown;
+}
+
+parameter_types! {
+    pub const DotStakingPalletId: PalletId = PalletId(*b"par/lqsd");
+    pub const DotMinStake: Balance = 5_000_000_000; // 0.5DOT
+    pub const DotMinUnstake: Balance = 2_500_000_000; // 0.25sDOT
+    pub const DotStakingCurrency: CurrencyId = DOT;
+    pub const DotLiquidCurrency: CurrencyId = SDOT;
+    pub const DotCollateralCurrency: CurrencyId = DOT_U;
+    pub const DotXcmFees: Balance = 500_000_000; // 0.05DOT
+    pub const DotBondingDuration: EraIndex = 28; // 28Days
+    pub const DotMinNominatorBond: Balance = 2_500_000_000_000; // 250DOT
+    pub const DotMinPoolJoinBond: Balance = 10_000_000_000; // 1DOT
+    pub DotDerivativeIndexList: Vec<u16> = vec![9, 10, 11, 12, 13, 14, 15, 16, 17];
+    pub DotInitialExchangeRate: Rate = Rate::saturating_from_integer(1u32);
+    pub DotInitialReserveFactor: Ratio = Ratio::from_rational(1u32, 10_000u32); // 0.01%
+}
+
+// sDOT, staking the DOT bridged to Heiko. Its staking ledger cap starts at zero, so
+// nothing can be staked until governance opens it.
+type DotLiquidStakingInstance = pallet_liquid_staking::Instance2;
+impl pallet_liquid_staking::Config<DotLiquidStakingInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletId = DotStakingPalletId;
+    type LoansPalletId = LoansPalletId;
+    type WeightInfo = weights::pallet_liquid_staking::WeightInfo<Runtime>;
+    type SelfParaId = ParachainInfo;
+    type Assets = Assets;
+    type RelayOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type DerivativeIndexList = DotDerivativeIndexList;
+    type DistributionStrategy = pallet_liquid_staking::distribution::MaxMinDistribution;
+    type XcmFees = DotXcmFees;
+    type LoansInstantUnstakeFee = LoansInstantUnstakeFee;
+    type MatchingPoolFastUnstakeFee = MatchingPoolFastUnstakeFee;
+    type StakingCurrency = DotStakingCurrency;
+    type LiquidCurrency = DotLiquidCurrency;
+    type CollateralCurrency = DotCollateralCurrency;
+    type RelayEra = RelayEra;
+    type MinStake = DotMinStake;
+    type MinUnstake = DotMinUnstake;
+    type XCM = XcmHelper;
+    type BondingDuration = DotBondingDuration;
+    type MinNominatorBond = DotMinNominatorBond;
+    type MinPoolJoinBond = DotMinPoolJoinBond;
+    type RelayChainValidationDataProvider = RelayChainValidationDataProvider<Runtime>;
+    type Loans = Loans;
+    type Members = LiquidStakingAgentsMembership;
+    type NumSlashingSpans = NumSlashingSpans;
+    type ElectionSolutionStoredOffset = ElectionSolutionStoredOffset;
+    type ProtocolFeeReceiver = DefaultProtocolFeeReceiver;
+    type Revenue = Revenue;
+    type Referrals = Referrals;
+    type MaxExchangeRateChange = MaxExchangeRateChange;
+    type CircuitBreaker = EmergencyShutdown;
 }
 
 parameter_types! {
@@ -566,7 +712,8 @@ where
             frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
             frame_system::CheckNonce::<Runtime>::from(index),
             frame_system::CheckWeight::<Runtime>::new(),
-            pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+            pallet_asset_tx_payment::ChargeAssetTxPayment::<Runtime>::from(tip, None),
+            pallet_prices::CheckPriceFreshness::<Runtime>::new(),
         );
 
         let raw_payload = SignedPayload::new(call, extra)
@@ -617,7 +764,7 @@ impl pallet_authorship::Config for Runtime {
     type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
     type UncleGenerations = UncleGenerations;
     type FilterUncle = ();
-    type EventHandler = (CollatorSelection,);
+    type EventHandler = (CollatorSelection, CollatorPerformance, CollatorRewards);
 }
 
 parameter_types! {
@@ -632,7 +779,7 @@ impl pallet_session::Config for Runtime {
     type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
     type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
     type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
-    type SessionManager = CollatorSelection;
+    type SessionManager = CollatorPerformance;
     // Essentially just Aura, but lets be pedantic.
     type SessionHandler =
         <opaque::SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
@@ -663,6 +810,59 @@ impl pallet_collator_selection::Config for Runtime {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const MinProductionRatio: Percent = Percent::from_percent(50);
+    pub const CollatorSlashRatio: Percent = Percent::from_percent(10);
+}
+
+impl pallet_collator_performance::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type MinProductionRatio = MinProductionRatio;
+    type SlashRatio = CollatorSlashRatio;
+    type Slash = Treasury;
+}
+
+parameter_types! {
+    pub const CollatorRewardsPalletId: PalletId = PalletId(*b"par/clrw");
+    pub const CollatorCommission: Percent = Percent::from_percent(20);
+    pub const MinCollatorDelegation: Balance = 10 * DOLLARS;
+}
+
+/// Invulnerables and candidates of the collator selection
+pub struct CollatorCandidates;
+impl SortedMembers<AccountId> for CollatorCandidates {
+    fn sorted_members() -> Vec<AccountId> {
+        let mut members = CollatorSelection::invulnerables().into_inner();
+        members.extend(
+            CollatorSelection::candidates()
+                .into_iter()
+                .map(|candidate| candidate.who),
+        );
+        members.sort();
+        members.dedup();
+        members
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &AccountId) {
+        pallet_collator_selection::Invulnerables::<Runtime>::mutate(|invulnerables| {
+            let _ = invulnerables.try_push(who.clone());
+        });
+    }
+}
+
+impl pallet_collator_rewards::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RewardAccount = TreasuryAccount;
+    type Candidates = CollatorCandidates;
+    type PalletId = CollatorRewardsPalletId;
+    type CollatorCommission = CollatorCommission;
+    type MinDelegation = MinCollatorDelegation;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = weights::pallet_collator_rewards::WeightInfo<Runtime>;
+}
+
 parameter_types! {
     pub const MaxAuthorities: u32 = 100_000;
 }
@@ -700,7 +900,16 @@ parameter_types! {
 }
 
 impl pallet_transaction_payment::Config for Runtime {
-    type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<Balances, Treasury>;
+    type OnChargeTransaction = pallet_referrals::DiscountFees<
+        Runtime,
+        pallet_fee_distribution::TrackFeeVolume<
+            Runtime,
+            pallet_transaction_payment::CurrencyAdapter<
+                Balances,
+                pallet_fee_distribution::SplitFees<Runtime>,
+            >,
+        >,
+    >;
     type WeightToFee = WeightToFee;
     type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
@@ -708,6 +917,35 @@ impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
 }
 
+parameter_types! {
+    pub TxFeeSpread: Ratio = Ratio::from_percent(5);
+}
+
+/// Assets transaction fees can be paid with besides the native currency
+pub struct TxFeeAssets;
+impl Contains<CurrencyId> for TxFeeAssets {
+    fn contains(currency_id: &CurrencyId) -> bool {
+        *currency_id != NativeCurrencyId::get()
+    }
+}
+
+/// Transaction fees paid in assets go to the treasury
+pub struct AssetFeesToTreasury;
+impl pallet_asset_tx_payment::HandleCredit<AccountId, Assets> for AssetFeesToTreasury {
+    fn handle_credit(credit: CreditOf<AccountId, Assets>) {
+        let _ = Assets::resolve(&TreasuryAccount::get(), credit);
+    }
+}
+
+impl pallet_asset_tx_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Fungibles = Assets;
+    type OnChargeAssetTransaction = pallet_asset_tx_payment::FungiblesAdapter<
+        PricedFeeConversion<Prices, NativeCurrencyId, TxFeeAssets, TxFeeSpreadParameter>,
+        AssetFeesToTreasury,
+    >;
+}
+
 #[derive(
     Copy,
     Clone,
@@ -730,6 +968,7 @@ pub enum ProxyType {
     Streaming,
     Governance,
     AMM,
+    Bridge,
 }
 impl Default for ProxyType {
     fn default() -> Self {
@@ -765,6 +1004,13 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                         | RuntimeCall::LiquidStaking(
                             pallet_liquid_staking::Call::cancel_unstake { .. }
                         )
+                        | RuntimeCall::DotLiquidStaking(pallet_liquid_staking::Call::stake { .. })
+                        | RuntimeCall::DotLiquidStaking(
+                            pallet_liquid_staking::Call::unstake { .. }
+                        )
+                        | RuntimeCall::DotLiquidStaking(
+                            pallet_liquid_staking::Call::cancel_unstake { .. }
+                        )
                 )
             }
             ProxyType::Crowdloans => {
@@ -819,12 +1065,20 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                         )
                 )
             }
+            ProxyType::Bridge => {
+                matches!(
+                    c,
+                    RuntimeCall::Bridge(pallet_bridge::Call::teleport { .. })
+                        | RuntimeCall::Bridge(pallet_bridge::Call::materialize { .. })
+                )
+            }
         }
     }
     fn is_superset(&self, o: &Self) -> bool {
         match (self, o) {
             (ProxyType::Any, _) => true,
             (_, ProxyType::Any) => false,
+            (x, y) if x == y => true,
             _ => false,
         }
     }
@@ -941,6 +1195,11 @@ parameter_types! {
     pub RelayLocation: MultiLocation = MultiLocation::parent();
     pub const RelayNetwork: NetworkId = NetworkId::Kusama;
     pub RelayCurrency: CurrencyId = KSM;
+    // Worth 0.05 KSM per second of xcm execution, used to price the fees of assets
+    // which have no units per second registered
+    pub RelayUnitsPerSecond: u128 = 50_000_000_000;
+    // Pools of the xcm fee assets must hold at least 1_000 KSM
+    pub XcmFeeAssetMinLiquidity: Balance = 1_000_000_000_000_000;
     pub HeikoNetwork: NetworkId = NetworkId::Named(WeakBoundedVec::<u8, ConstU32<32>>::force_from("heiko".into(), None));
     pub RelayChainOrigin: RuntimeOrigin = cumulus_pallet_xcm::Origin::Relay.into();
     pub Ancestry: MultiLocation = MultiLocation::new(0, X1(Parachain(ParachainInfo::parachain_id().into())));
@@ -1036,12 +1295,15 @@ match_types! {
     };
 }
 
-pub type Barrier = (
-    TakeWeightCredit,
-    AllowKnownQueryResponses<PolkadotXcm>,
-    AllowSubscriptionsFrom<ParentOrSiblings>,
-    AllowTopLevelPaidExecutionFrom<Everything>,
-);
+pub type Barrier = pallet_xcm_helper::GovernedBarrier<
+    Runtime,
+    (
+        TakeWeightCredit,
+        AllowKnownQueryResponses<PolkadotXcm>,
+        AllowSubscriptionsFrom<ParentOrSiblings>,
+        AllowTopLevelPaidExecutionFrom<Everything>,
+    ),
+>;
 
 pub struct ToTreasury;
 impl TakeRevenue for ToTreasury {
@@ -1109,6 +1371,14 @@ pub type XcmFeesToAccount = pallet_traits::xcm::XcmFeesToAccount<
     TreasuryAccount,
 >;
 
+/// Assets accepted by governance to buy xcm execution at their oracle price
+pub struct XcmFeeAssets;
+impl Contains<CurrencyId> for XcmFeeAssets {
+    fn contains(currency_id: &CurrencyId) -> bool {
+        AssetRegistry::is_xcm_fee_asset(currency_id)
+    }
+}
+
 pub struct XcmConfig;
 impl Config for XcmConfig {
     type RuntimeCall = RuntimeCall;
@@ -1122,11 +1392,45 @@ impl Config for XcmConfig {
     type LocationInverter = LocationInverter<Ancestry>;
     type Barrier = Barrier;
     type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
-    type Trader = FirstAssetTrader<AssetType, WrapAssetRegistry, XcmFeesToAccount>;
+    type Trader = (
+        FirstAssetTrader<AssetType, WrapAssetRegistry, XcmFeesToAccount>,
+        PricedAssetTrader<
+            CurrencyIdConvert<WrapAssetRegistry>,
+            Prices,
+            XcmFeeAssets,
+            RelayCurrency,
+            RelayUnitsPerSecond,
+            XcmFeesToAccount,
+        >,
+    );
     type ResponseHandler = PolkadotXcm;
     type SubscriptionService = PolkadotXcm;
-    type AssetTrap = PolkadotXcm;
-    type AssetClaims = PolkadotXcm;
+    type AssetTrap = pallet_xcm_helper::RecordAssetTrap<Runtime, PolkadotXcm>;
+    type AssetClaims = pallet_xcm_helper::ForgetClaimedAssets<Runtime, PolkadotXcm>;
+}
+
+/// Events reported as asset movements when dry-running XCM
+pub struct XcmAssetMovements;
+impl Contains<RuntimeEvent> for XcmAssetMovements {
+    fn contains(event: &RuntimeEvent) -> bool {
+        matches!(
+            event,
+            RuntimeEvent::Assets(
+                pallet_assets::Event::Issued { .. }
+                    | pallet_assets::Event::Burned { .. }
+                    | pallet_assets::Event::Transferred { .. }
+            ) | RuntimeEvent::Balances(
+                pallet_balances::Event::Deposit { .. }
+                    | pallet_balances::Event::Withdraw { .. }
+                    | pallet_balances::Event::Transfer { .. }
+            )
+        )
+    }
+}
+
+parameter_types! {
+    pub const AssetRegistrationDeposit: Balance = 100 * DOLLARS;
+    pub const AssetChallengePeriod: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_asset_registry::Config for Runtime {
@@ -1135,12 +1439,67 @@ impl pallet_asset_registry::Config for Runtime {
     type AssetId = CurrencyId;
     type AssetType = AssetType;
     type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type Currency = Balances;
+    type RegistrationDeposit = AssetRegistrationDeposit;
+    type ChallengePeriod = AssetChallengePeriod;
+    type StringLimit = AssetsStringLimit;
+    type Slash = Treasury;
+    type AssetConfigurator = AssetsConfigurator;
+    type XcmFeeAssetValidator = PricedFeeAssetValidator<
+        Prices,
+        AMM,
+        Assets,
+        RelayCurrency,
+        RelayUnitsPerSecond,
+        XcmFeeAssetMinLiquidity,
+        BaseXcmWeight,
+    >;
     type WeightInfo = weights::pallet_asset_registry::WeightInfo<Runtime>;
 }
 
+/// Creates the assets configured in the asset registry or updates their existential deposit
+/// and sufficiency, such assets are then owned by the treasury.
+pub struct AssetsConfigurator;
+impl AssetConfigurator<CurrencyId, Balance> for AssetsConfigurator {
+    fn configure(
+        asset_id: CurrencyId,
+        existential_deposit: Balance,
+        is_sufficient: bool,
+    ) -> DispatchResult {
+        let treasury = sp_runtime::MultiAddress::Id(TreasuryAccount::get());
+        if !pallet_assets::Asset::<Runtime>::contains_key(asset_id) {
+            return Assets::force_create(
+                RuntimeOrigin::root(),
+                asset_id,
+                treasury,
+                is_sufficient,
+                existential_deposit,
+            );
+        }
+        Assets::force_asset_status(
+            RuntimeOrigin::root(),
+            asset_id,
+            treasury.clone(),
+            treasury.clone(),
+            treasury.clone(),
+            treasury,
+            existential_deposit,
+            is_sufficient,
+            false,
+        )
+    }
+}
+
 parameter_types! {
       pub const MinimumCount: u32 = 3;
       pub const ExpiresIn: Moment = 1000 * 60 * 60; // 60 mins
+      pub const MaxPriceAge: Moment = 1000 * 60 * 30; // 30 mins
+      pub const LocalOracleWeight: u32 = 1;
+      pub const PriceQuorum: u32 = 2;
+      pub const MaxPriceDeviation: Ratio = Ratio::from_percent(5);
+      pub const TwapPeriod: BlockNumber = 30 * MINUTES;
+      pub const MaxOracleRoundsKept: u32 = 300;
+      pub const MaxFeedValues: u32 = 30;
       pub const MaxHasDispatchedSize: u32 = 100;
       pub OneAccount: AccountId = AccountId::from([1u8; 32]);
 }
@@ -1148,7 +1507,7 @@ parameter_types! {
 type ParallelDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<ParallelDataProvider> for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnNewData = ();
+    type OnNewData = Prices;
     type CombineData =
         orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, ParallelDataProvider>;
     type Time = Timestamp;
@@ -1184,23 +1543,86 @@ impl DataFeeder<CurrencyId, TimeStampedPrice, AccountId> for AggregatedDataProvi
     }
 }
 
+impl OracleFeeder<AccountId> for AggregatedDataProvider {
+    fn feed_values(who: AccountId, values: Vec<(CurrencyId, Price)>) -> DispatchResult {
+        Oracle::feed_values(RuntimeOrigin::signed(who), values)
+            .map(|_| ())
+            .map_err(|e| e.error)
+    }
+}
+
+/// Assets whose prices loans borrows and liquidations, and router swaps rely on
+pub struct PriceDependentCalls;
+impl PriceDependencies<AccountId, RuntimeCall> for PriceDependentCalls {
+    fn price_dependencies(who: &AccountId, call: &RuntimeCall) -> Vec<CurrencyId> {
+        // the liquidity of an account is measured over all of its positions
+        let positions = |who: &AccountId| {
+            Loans::account_positions(who)
+                .map(|positions| {
+                    positions
+                        .into_iter()
+                        .map(|(asset_id, ..)| asset_id)
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+        match call {
+            RuntimeCall::Loans(pallet_loans::Call::borrow { asset_id, .. }) => {
+                let mut assets = positions(who);
+                assets.push(*asset_id);
+                assets
+            }
+            RuntimeCall::Loans(pallet_loans::Call::liquidate_borrow { borrower, .. }) => {
+                positions(borrower)
+            }
+            RuntimeCall::AMMRoute(
+                pallet_router::Call::swap_exact_tokens_for_tokens { route, .. }
+                | pallet_router::Call::swap_tokens_for_exact_tokens { route, .. }
+                | pallet_router::Call::swap_and_transfer { route, .. },
+            ) => route.clone(),
+            _ => Vec::new(),
+        }
+    }
+}
+
 pub struct Decimal;
 impl DecimalProvider<CurrencyId> for Decimal {
     fn get_decimal(asset_id: &CurrencyId) -> Option<u8> {
         match *asset_id {
             NATIVE_ASSET_ID => Some(12_u8),
-            _ => {
-                let decimal = <Assets as InspectMetadata<AccountId>>::decimals(asset_id);
-                if decimal.is_zero() {
-                    None
-                } else {
-                    Some(decimal)
-                }
-            }
+            _ => AssetRegistry::asset_params(asset_id)
+                .map(|params| params.decimals)
+                .or_else(|| {
+                    let decimal = <Assets as InspectMetadata<AccountId>>::decimals(asset_id);
+                    if decimal.is_zero() {
+                        None
+                    } else {
+                        Some(decimal)
+                    }
+                }),
         }
     }
 }
 
+/// Oracle parachains feed their prices with XCM `Transact` as their native origin
+pub struct EnsureSiblingParachain;
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureSiblingParachain {
+    type Success = ParaId;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<ParaId, RuntimeOrigin> {
+        let origin: Result<cumulus_pallet_xcm::Origin, RuntimeOrigin> = o.clone().into();
+        match origin {
+            Ok(cumulus_pallet_xcm::Origin::SiblingParachain(para_id)) => Ok(para_id),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn successful_origin() -> RuntimeOrigin {
+        cumulus_pallet_xcm::Origin::SiblingParachain(ParaId::from(2000)).into()
+    }
+}
+
 impl pallet_prices::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Source = AggregatedDataProvider;
@@ -1215,6 +1637,20 @@ impl pallet_prices::Config for Runtime {
     type Decimal = Decimal;
     type AMM = AMM;
     type Assets = CurrencyAdapter;
+    type UnixTime = Timestamp;
+    type MaxPriceAge = MaxPriceAge;
+    type CircuitBreaker = EmergencyShutdown;
+    type MinimumCount = MinimumCount;
+    type MaxRoundsKept = MaxOracleRoundsKept;
+    type Oracle = AggregatedDataProvider;
+    type Feeders = OracleMembership;
+    type MaxFeedValues = MaxFeedValues;
+    type PriceDependencies = PriceDependentCalls;
+    type XcmOracleOrigin = EnsureSiblingParachain;
+    type LocalOracleWeight = LocalOracleWeight;
+    type PriceQuorum = PriceQuorum;
+    type MaxPriceDeviation = MaxPriceDeviation;
+    type TwapPeriod = TwapPeriod;
     type WeightInfo = pallet_prices::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1260,6 +1696,332 @@ impl pallet_identity::Config for Runtime {
     type WeightInfo = weights::pallet_identity::WeightInfo<Runtime>;
 }
 
+pub struct VerifiedIdentity;
+impl pallet_traits::IdentityVerifier<AccountId> for VerifiedIdentity {
+    fn has_verified_identity(who: &AccountId) -> bool {
+        let who = Identity::super_of(who).map_or_else(|| who.clone(), |(parent, _)| parent);
+        Identity::identity(&who).map_or(false, |registration| {
+            registration.judgements.iter().any(|(_, judgement)| {
+                matches!(
+                    judgement,
+                    pallet_identity::Judgement::Reasonable | pallet_identity::Judgement::KnownGood
+                )
+            })
+        })
+    }
+
+    fn display_name(who: &AccountId) -> Option<Vec<u8>> {
+        fn raw(data: &pallet_identity::Data) -> Option<Vec<u8>> {
+            match data {
+                pallet_identity::Data::Raw(raw) => Some(raw.to_vec()),
+                _ => None,
+            }
+        }
+
+        match Identity::super_of(who) {
+            Some((parent, sub)) => {
+                let mut name = raw(&Identity::identity(&parent)?.info.display)?;
+                name.push(b'/');
+                name.extend(raw(&sub)?);
+                Some(name)
+            }
+            None => raw(&Identity::identity(who)?.info.display),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_verified_identity(who: &AccountId) {
+        use frame_support::assert_ok;
+        use sp_runtime::traits::Hash;
+
+        let registrar: AccountId = frame_benchmarking::account("registrar", 0, 0);
+        let info = pallet_identity::IdentityInfo {
+            display: pallet_identity::Data::Raw(b"benchmark".to_vec().try_into().unwrap()),
+            ..Default::default()
+        };
+        let _ = <Balances as frame_support::traits::Currency<AccountId>>::deposit_creating(
+            who,
+            BasicDeposit::get().saturating_mul(10),
+        );
+        assert_ok!(Identity::add_registrar(
+            RuntimeOrigin::root(),
+            sp_runtime::MultiAddress::Id(registrar.clone())
+        ));
+        let index = Identity::registrars().len() as u32 - 1;
+        assert_ok!(Identity::set_identity(
+            RuntimeOrigin::signed(who.clone()),
+            Box::new(info.clone())
+        ));
+        assert_ok!(Identity::provide_judgement(
+            RuntimeOrigin::signed(registrar),
+            index,
+            sp_runtime::MultiAddress::Id(who.clone()),
+            pallet_identity::Judgement::KnownGood,
+            BlakeTwo256::hash_of(&info)
+        ));
+    }
+}
+
+parameter_types! {
+    pub const ConfigDepositBase: Balance = deposit(1, 88);
+    pub const FriendDepositFactor: Balance = deposit(0, 32);
+    pub const MaxFriends: u32 = 9;
+    pub const RecoveryDeposit: Balance = deposit(1, 89);
+}
+
+impl pallet_recovery::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_recovery::weights::SubstrateWeight<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type ConfigDepositBase = ConfigDepositBase;
+    type FriendDepositFactor = FriendDepositFactor;
+    type MaxFriends = MaxFriends;
+    type RecoveryDeposit = RecoveryDeposit;
+}
+
+impl pallet_zap::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Loans = Loans;
+    type LiquidStaking = LiquidStaking;
+    type WeightInfo = weights::pallet_zap::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    pub const ProtocolLiquidityPalletId: PalletId = PalletId(*b"par/pliq");
+    pub const ProtocolLiquidityMaxPriceDeviation: Ratio = Ratio::from_percent(2);
+}
+
+impl pallet_protocol_liquidity::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type PalletId = ProtocolLiquidityPalletId;
+    type Assets = CurrencyAdapter;
+    type AMM = AMM;
+    type Markets = Loans;
+    type PTokens = Loans;
+    type PriceFeeder = Prices;
+    type MaxPriceDeviation = ProtocolLiquidityMaxPriceDeviation;
+    type TreasuryAccount = TreasuryAccount;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = pallet_protocol_liquidity::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const BuybackPalletId: PalletId = PalletId(*b"par/bbck");
+    pub RevenueAccount: AccountId = RevenuePalletId::get().into_account_truncating();
+    pub const BuybackEpochLength: BlockNumber = DAYS;
+    pub const BuybackMaxPriceImpact: Ratio = Ratio::from_percent(3);
+    pub const BuybackMaxAssets: u32 = 10;
+}
+
+impl pallet_buyback::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type PalletId = BuybackPalletId;
+    type Assets = CurrencyAdapter;
+    type Router = AMMRoute;
+    type Rewards = Farming;
+    type PriceFeeder = Prices;
+    type RevenueAccount = RevenueAccount;
+    type BuybackCurrencyId = NativeCurrencyId;
+    type EpochLength = BuybackEpochLength;
+    type MaxPriceImpact = BuybackMaxPriceImpact;
+    type MaxAssets = BuybackMaxAssets;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = pallet_buyback::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const InsurancePalletId: PalletId = PalletId(*b"par/insu");
+    pub const InsuranceClaimDeposit: Balance = 100 * DOLLARS;
+    pub const InsuranceEpochLength: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_insurance::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type PalletId = InsurancePalletId;
+    type Assets = CurrencyAdapter;
+    type Currency = Balances;
+    type ClaimDeposit = InsuranceClaimDeposit;
+    type Slash = Treasury;
+    type RevenueAccount = RevenueAccount;
+    type EpochLength = InsuranceEpochLength;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type ClaimOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = pallet_insurance::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_asset_freezer::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Assets = Assets;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = pallet_asset_freezer::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const MaxRewardClaims: u32 = 16;
+}
+
+impl pallet_rewards_aggregator::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Loans = Loans;
+    type Farming = Farming;
+    type Referrals = Referrals;
+    type Staking = CollatorRewards;
+    type MaxClaims = MaxRewardClaims;
+    type WeightInfo = pallet_rewards_aggregator::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const MaxScheduledCallsPerBlock: u32 = 50;
+    pub MaxScheduledCallWeight: Weight = Perbill::from_percent(10) *
+        RuntimeBlockWeights::get().max_block;
+    pub ScheduledCallsExecutionWeight: Weight = Perbill::from_percent(20) *
+        RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_scheduled_calls::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type WeightToFee = WeightToFee;
+    type FeeCollector = Treasury;
+    type MaxScheduledPerBlock = MaxScheduledCallsPerBlock;
+    type MaxCallWeight = MaxScheduledCallWeight;
+    type MaxExecutionWeight = ScheduledCallsExecutionWeight;
+    type WeightInfo = weights::pallet_scheduled_calls::WeightInfo<Runtime>;
+}
+
+/// Calls the beneficiary of an inherited account can dispatch on its behalf
+pub struct InheritableCalls;
+impl Contains<RuntimeCall> for InheritableCalls {
+    fn contains(c: &RuntimeCall) -> bool {
+        matches!(
+            c,
+            RuntimeCall::Utility(_)
+                | RuntimeCall::Balances(_)
+                | RuntimeCall::Assets(_)
+                | RuntimeCall::Loans(_)
+                | RuntimeCall::LiquidStaking(_)
+                | RuntimeCall::DotLiquidStaking(_)
+                | RuntimeCall::Crowdloans(_)
+                | RuntimeCall::AMM(_)
+                | RuntimeCall::AMMRoute(_)
+                | RuntimeCall::Farming(_)
+                | RuntimeCall::Streaming(_)
+        )
+    }
+}
+
+parameter_types! {
+    pub const SwitchDeposit: Balance = deposit(1, 66);
+    pub const MinInactivityPeriod: BlockNumber = 30 * DAYS;
+    pub const InheritanceChallengePeriod: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_inheritance::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type CallFilter = InheritableCalls;
+    type SwitchDeposit = SwitchDeposit;
+    type MinInactivityPeriod = MinInactivityPeriod;
+    type ChallengePeriod = InheritanceChallengePeriod;
+    type WeightInfo = weights::pallet_inheritance::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    pub const SessionsPerEra: u32 = 6;
+}
+
+parameter_types! {
+    pub const NomineeHistoryDepth: u32 = 28;
+    pub const MaxNomineeCandidates: u32 = 128;
+    pub const MaxNominees: u32 = 16;
+    pub const MaxNomineeFeeders: u32 = 8;
+    pub NomineeMaxCommission: Perbill = Perbill::from_percent(10);
+}
+
+impl pallet_nominee_election::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type EraProvider = RelayEra;
+    type HistoryDepth = NomineeHistoryDepth;
+    type MaxCandidates = MaxNomineeCandidates;
+    type MaxNominees = MaxNominees;
+    type MaxFeeders = MaxNomineeFeeders;
+    type MaxCommission = NomineeMaxCommission;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = weights::pallet_nominee_election::WeightInfo<Runtime>;
+}
+
+impl pallet_relay_era::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RelayChainValidationDataProvider = RelayChainValidationDataProvider<Runtime>;
+    type EraLength = EraLength;
+    type SessionsPerEra = SessionsPerEra;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = weights::pallet_relay_era::WeightInfo<Runtime>;
+}
+
+/// Permissionless maintenance calls rewarded by the keepers pallet
+pub struct KeeperTasks;
+impl pallet_keepers::ClassifyTask<RuntimeCall> for KeeperTasks {
+    fn classify(call: &RuntimeCall) -> Option<pallet_keepers::KeeperTask> {
+        use pallet_keepers::KeeperTask;
+        match call {
+            RuntimeCall::Loans(pallet_loans::Call::liquidate_borrow { .. }) => {
+                Some(KeeperTask::Liquidation)
+            }
+            RuntimeCall::Loans(pallet_loans::Call::accrue_market_interest { .. }) => {
+                Some(KeeperTask::InterestAccrual)
+            }
+            RuntimeCall::Loans(pallet_loans::Call::update_market_reward_indexes { .. }) => {
+                Some(KeeperTask::RewardSettlement)
+            }
+            // Bridge proposals are executed by the vote reaching the threshold, they
+            // have no call of their own to reward yet
+            _ => None,
+        }
+    }
+}
+
+parameter_types! {
+    pub const KeepersPalletId: PalletId = PalletId(*b"par/kepr");
+    pub const KeeperBond: Balance = 100 * DOLLARS;
+    pub const KeeperUnbondingPeriod: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_keepers::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type Tasks = KeeperTasks;
+    type PalletId = KeepersPalletId;
+    type KeeperBond = KeeperBond;
+    type UnbondingPeriod = KeeperUnbondingPeriod;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = weights::pallet_keepers::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    pub const ReferralsPalletId: PalletId = PalletId(*b"par/refr");
+    pub const MaxReferralCodeLength: u32 = 32;
+    pub const ReferralCodeDeposit: Balance = deposit(2, 162);
+    pub const MaxReferralTiers: u32 = 10;
+}
+
+impl pallet_referrals::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type PriceFeeder = Prices;
+    type IdentityVerifier = VerifiedIdentity;
+    type PalletId = ReferralsPalletId;
+    type MaxCodeLength = MaxReferralCodeLength;
+    type CodeDeposit = ReferralCodeDeposit;
+    type MaxTiers = MaxReferralTiers;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = weights::pallet_referrals::WeightInfo<Runtime>;
+}
+
 type EnsureRootOrMoreThanHalfGeneralCouncil = EitherOfDiverse<
     EnsureRoot<AccountId>,
     pallet_collective::EnsureProportionMoreThan<AccountId, GeneralCouncilCollective, 1, 2>,
@@ -1440,8 +2202,8 @@ parameter_types! {
 impl pallet_treasury::Config for Runtime {
     type PalletId = TreasuryPalletId;
     type Currency = Balances;
-    type ApproveOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
-    type RejectOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type ApproveOrigin = EitherOfDiverse<EnsureRootOrMoreThanHalfGeneralCouncil, Treasurer>;
+    type RejectOrigin = EitherOfDiverse<EnsureRootOrMoreThanHalfGeneralCouncil, Treasurer>;
     type RuntimeEvent = RuntimeEvent;
     type OnSlash = ();
     type ProposalBond = ProposalBond;
@@ -1453,7 +2215,7 @@ impl pallet_treasury::Config for Runtime {
     type SpendFunds = ();
     type WeightInfo = weights::pallet_treasury::WeightInfo<Runtime>;
     type MaxApprovals = MaxApprovals;
-    type SpendOrigin = frame_support::traits::NeverEnsureOrigin<Balance>;
+    type SpendOrigin = TreasurySpender;
 }
 
 parameter_types! {
@@ -1533,9 +2295,13 @@ impl pallet_bridge::Config for Runtime {
     type GiftConvert = GiftConvert;
     type NativeCurrencyId = NativeCurrencyId;
     type ExistentialDeposit = ExistentialDeposit;
-    type ProposalLifetime = ProposalLifetime;
-    type ThresholdPercentage = ThresholdPercentage;
+    type ProposalLifetime = NumberParameter<Runtime, BridgeProposalLifetimeKey, ProposalLifetime>;
+    type ThresholdPercentage =
+        NumberParameter<Runtime, BridgeThresholdPercentageKey, ThresholdPercentage>;
     type WeightInfo = weights::pallet_bridge::WeightInfo<Runtime>;
+    type Revenue = Revenue;
+    type CircuitBreaker = EmergencyShutdown;
+    type EventMirror = ();
 }
 
 parameter_types! {
@@ -1557,7 +2323,7 @@ parameter_types! {
     pub const AMMPalletId: PalletId = PalletId(*b"par/ammp");
     pub DefaultLpFee: Ratio = Ratio::from_rational(30u32, 10000u32);        // 0.30%
     pub DefaultProtocolFee: Ratio = Ratio::from_rational(0u32, 10000u32);   // 0.00% no fees for launch
-    pub DefaultProtocolFeeReceiver: AccountId = TreasuryPalletId::get().into_account_truncating();
+    pub DefaultProtocolFeeReceiver: AccountId = RevenuePalletId::get().into_account_truncating();
     pub const MinimumLiquidity: u128 = 1_000u128;
 }
 
@@ -1569,10 +2335,12 @@ impl pallet_amm::Config for Runtime {
     type CreatePoolOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
     type ProtocolFeeUpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
     type AMMWeightInfo = weights::pallet_amm::WeightInfo<Runtime>;
-    type LpFee = DefaultLpFee;
+    type LpFee = RatioParameter<Runtime, AmmLpFeeKey, DefaultLpFee>;
     type MinimumLiquidity = MinimumLiquidity;
     type MaxLengthRoute = MaxLengthRoute;
     type GetNativeCurrencyId = NativeCurrencyId;
+    type Revenue = Revenue;
+    type Referrals = Referrals;
 }
 
 parameter_types! {
@@ -1611,7 +2379,6 @@ impl<T: cumulus_pallet_parachain_system::Config> ValidationDataProvider
 impl pallet_crowdloans::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeOrigin = RuntimeOrigin;
-    type RuntimeCall = RuntimeCall;
     type PalletId = CrowdloansPalletId;
     type SelfParaId = ParachainInfo;
     type Assets = Assets;
@@ -1639,6 +2406,8 @@ impl pallet_crowdloans::Config for Runtime {
     type Streaming = ();
     type GetNativeCurrencyId = NativeCurrencyId;
     type Decimal = Decimal;
+    type Referrals = Referrals;
+    type UnixTime = Timestamp;
 }
 
 parameter_types! {
@@ -1663,36 +2432,207 @@ impl pallet_streaming::Config for Runtime {
 parameter_types! {
     pub const XcmHelperPalletId: PalletId = PalletId(*b"par/fees");
     pub const NotifyTimeout: BlockNumber = 100;
+    pub const MaxXcmRetries: u32 = 3;
+    pub const MaxRescueReasonLength: u32 = 256;
+    pub AssetHubParaId: ParaId = ParaId::from(paras::statemine::ID);
 }
 
 impl pallet_xcm_helper::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
     type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
     type Assets = Assets;
     type XcmSender = XcmRouter;
     type RelayNetwork = RelayNetwork;
     type PalletId = XcmHelperPalletId;
     type NotifyTimeout = NotifyTimeout;
+    type MaxXcmRetries = MaxXcmRetries;
+    type ResponseHandler = (LiquidStaking, DotLiquidStaking, Crowdloans, XcmHelper);
     type AccountIdToMultiLocation = AccountIdToMultiLocation<AccountId>;
     type RefundLocation = RefundLocation;
     type BlockNumberProvider = frame_system::Pallet<Runtime>;
     type WeightInfo = weights::pallet_xcm_helper::WeightInfo<Runtime>;
     type RelayCurrency = RelayCurrency;
+    type SelfParaId = ParachainInfo;
+    type AssetHubParaId = AssetHubParaId;
+    type CurrencyIdConvert = CurrencyIdConvert<WrapAssetRegistry>;
+    type MaxXcmInstructions = MaxInstructions;
+    type MaxRescueReasonLength = MaxRescueReasonLength;
+}
+
+parameter_types! {
+    pub const RemoteDerivativesPalletId: PalletId = PalletId(*b"par/rder");
+}
+
+/// Derivative indices of the liquid staking instances, which remote derivatives can't
+/// hand out
+pub struct LiquidStakingDerivativeIndices;
+impl Contains<u16> for LiquidStakingDerivativeIndices {
+    fn contains(index: &u16) -> bool {
+        DerivativeIndexList::get().contains(index) || DotDerivativeIndexList::get().contains(index)
+    }
+}
+
+impl pallet_remote_derivatives::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Assets = Assets;
+    type XcmSender = XcmRouter;
+    type AccountIdToMultiLocation = AccountIdToMultiLocation<AccountId>;
+    type PalletId = RemoteDerivativesPalletId;
+    type SelfParaId = ParachainInfo;
+    type FeeCurrency = RelayCurrency;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type ReservedIndices = LiquidStakingDerivativeIndices;
+    type WeightInfo = weights::pallet_remote_derivatives::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    pub const FeeDistributionPalletId: PalletId = PalletId(*b"par/fees");
+    pub const RebatePeriod: BlockNumber = 7 * DAYS;
+}
+
+pub struct BlockAuthor;
+impl Get<Option<AccountId>> for BlockAuthor {
+    fn get() -> Option<AccountId> {
+        Authorship::author()
+    }
+}
+
+impl pallet_fee_distribution::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Treasury = Treasury;
+    type BlockAuthor = BlockAuthor;
+    type PalletId = FeeDistributionPalletId;
+    type RebatePeriod = RebatePeriod;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = weights::pallet_fee_distribution::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    pub const AssetTreasuryPayoutPeriod: BlockNumber = 30 * DAYS;
+}
+
+impl pallet_asset_treasury::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Assets = CurrencyAdapter;
+    type PriceFeeder = Prices;
+    type NativeCurrencyId = NativeCurrencyId;
+    type TreasuryAccount = TreasuryAccount;
+    type SpendOrigin = TreasurySpender;
+    type RejectOrigin = EitherOfDiverse<EnsureRootOrMoreThanHalfGeneralCouncil, Treasurer>;
+    type PayoutPeriod = AssetTreasuryPayoutPeriod;
+    type WeightInfo = weights::pallet_asset_treasury::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    pub const AmmLpFeeKey: ParameterKey = ParameterKey::AmmLpFee;
+    pub const TxFeeSpreadKey: ParameterKey = ParameterKey::TxFeeSpread;
+    pub const BridgeProposalLifetimeKey: ParameterKey = ParameterKey::BridgeProposalLifetime;
+    pub const BridgeThresholdPercentageKey: ParameterKey = ParameterKey::BridgeThresholdPercentage;
+}
+
+pub type TxFeeSpreadParameter = RatioParameter<Runtime, TxFeeSpreadKey, TxFeeSpread>;
+
+impl pallet_parameters::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type UpdateOrigin = EitherOfDiverse<EnsureRootOrMoreThanHalfGeneralCouncil, MarketAdmin>;
+    type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
+}
+
+impl pallet_migrations::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Migrations = ();
+}
+
+parameter_types! {
+    pub const MaxScheduledJobs: u32 = 256;
+    pub const MaxJobAttempts: u32 = 3;
+}
+
+impl pallet_job_queue::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Job = RuntimeCall;
+    type MaxJobs = MaxScheduledJobs;
+    type MaxAttempts = MaxJobAttempts;
+}
+
+parameter_types! {
+    pub const RevenuePalletId: PalletId = PalletId(*b"par/revn");
+    pub const RevenueHistoryDepth: u32 = 84;
+}
+
+impl pallet_revenue::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Assets = CurrencyAdapter;
+    type PalletId = RevenuePalletId;
+    type EraLength = EraLength;
+    type HistoryDepth = RevenueHistoryDepth;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = weights::pallet_revenue::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    pub const MinGrant: Balance = DOLLARS;
+    pub const MaxGrants: u32 = 10;
+}
+
+impl pallet_grant_vesting::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type GrantOrigin = frame_system::EnsureSigned<AccountId>;
+    type MinGrant = MinGrant;
+    type MaxGrants = MaxGrants;
+    type Treasury = Treasury;
+    type WeightInfo = weights::pallet_grant_vesting::WeightInfo<Runtime>;
 }
 
 parameter_types! {
     pub const MaxLengthRoute: u8 = 10;
     pub const RouterPalletId: PalletId = PalletId(*b"ammroute");
+    pub const MinRevealDelay: BlockNumber = 1;
+    pub const MaxRevealDelay: BlockNumber = 10 * MINUTES;
+    pub const MaxSwapCommitments: u32 = 8;
+    pub const MaxRevealedSwaps: u32 = 50;
+}
+
+/// Sends the output of router trades to other chains
+pub struct XTokensTransfer;
+impl XcmAssetTransfer<AccountId, CurrencyId, Balance> for XTokensTransfer {
+    fn transfer(
+        who: AccountId,
+        currency_id: CurrencyId,
+        amount: Balance,
+        dest: MultiLocation,
+        dest_weight_limit: WeightLimit,
+    ) -> DispatchResult {
+        XTokens::transfer(
+            RuntimeOrigin::signed(who),
+            currency_id,
+            amount,
+            Box::new(dest.into()),
+            dest_weight_limit,
+        )
+    }
 }
 
 impl pallet_router::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type PalletId = RouterPalletId;
     type AMM = AMM;
+    type StableSwap = ();
+    type Conversions = (LiquidStaking, DotLiquidStaking, Loans);
     type AMMRouterWeightInfo = weights::pallet_router::WeightInfo<Runtime>;
     type MaxLengthRoute = MaxLengthRoute;
     type Assets = CurrencyAdapter;
     type GetNativeCurrencyId = NativeCurrencyId;
+    type XcmTransfer = XTokensTransfer;
+    type MinRevealDelay = MinRevealDelay;
+    type MaxRevealDelay = MaxRevealDelay;
+    type MaxSwapCommitments = MaxSwapCommitments;
+    type MaxRevealedSwaps = MaxRevealedSwaps;
+    type UpdateOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
 }
 
 impl pallet_currency_adapter::Config for Runtime {
@@ -1700,6 +2640,7 @@ impl pallet_currency_adapter::Config for Runtime {
     type Balances = Balances;
     type GetNativeCurrencyId = NativeCurrencyId;
     type LockOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
+    type WeightInfo = weights::pallet_currency_adapter::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -1721,11 +2662,17 @@ impl pallet_farming::Config for Runtime {
     type Decimal = Decimal;
 }
 
+parameter_types! {
+    pub const CircuitBreakerDuration: BlockNumber = 6 * HOURS;
+}
+
 impl pallet_emergency_shutdown::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Whitelist = WhiteListFilter;
     type ShutdownOrigin = EnsureRootOrMoreThanHalfGeneralCouncil;
     type RuntimeCall = RuntimeCall;
+    type CircuitBreakerDuration = CircuitBreakerDuration;
+    type WeightInfo = weights::pallet_emergency_shutdown::WeightInfo<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -1745,6 +2692,7 @@ construct_runtime!(
         Assets: pallet_assets::{Pallet, Call, Storage, Event<T>} = 6,
         Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>} = 7,
         Identity: pallet_identity::{Pallet, Call, Storage, Event<T>} = 8,
+        AssetTxPayment: pallet_asset_tx_payment::{Pallet, Event<T>} = 9,
 
         // Governance
         Democracy: pallet_democracy::{Pallet, Call, Storage, Config<T>, Event<T>} = 11,
@@ -1753,6 +2701,10 @@ construct_runtime!(
         Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>} = 14,
         Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>} = 15,
         Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>} = 16,
+        Referenda: pallet_referenda::{Pallet, Call, Storage, Event<T>} = 17,
+        ConvictionVoting: pallet_conviction_voting::{Pallet, Call, Storage, Event<T>} = 18,
+        Origins: pallet_custom_origins::{Origin} = 19,
+        Whitelist: pallet_whitelist::{Pallet, Call, Storage, Event<T>} = 10,
 
         // Parachain
         ParachainInfo: parachain_info::{Pallet, Storage, Config} = 21,
@@ -1767,6 +2719,8 @@ construct_runtime!(
         Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>} = 32,
         Aura: pallet_aura::{Pallet, Config<T>, Storage} = 33,
         AuraExt: cumulus_pallet_aura_ext::{Pallet, Config, Storage} = 34,
+        CollatorPerformance: pallet_collator_performance::{Pallet, Storage, Event<T>} = 35,
+        CollatorRewards: pallet_collator_rewards::{Pallet, Call, Storage, Event<T>} = 36,
 
         // 3rd Party
         Oracle: orml_oracle::<Instance1>::{Pallet, Storage, Call, Event<T>} = 42,
@@ -1781,6 +2735,8 @@ construct_runtime!(
 
         // LiquidStaking
         LiquidStaking: pallet_liquid_staking::{Pallet, Call, Storage, Event<T>, Config} = 60,
+        NomineeElection: pallet_nominee_election::{Pallet, Call, Storage, Event<T>} = 61,
+        DotLiquidStaking: pallet_liquid_staking::<Instance2>::{Pallet, Call, Storage, Event<T>, Config} = 62,
 
         // Membership
         GeneralCouncilMembership: pallet_membership::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>} = 70,
@@ -1792,7 +2748,7 @@ construct_runtime!(
 
         // AMM
         AMM: pallet_amm::{Pallet, Call, Storage, Event<T>} = 80,
-        AMMRoute: pallet_router::{Pallet, Call, Event<T>} = 81,
+        AMMRoute: pallet_router::{Pallet, Call, Storage, Event<T>} = 81,
         CurrencyAdapter: pallet_currency_adapter::{Pallet, Call} = 82,
 
         // Others
@@ -1802,6 +2758,26 @@ construct_runtime!(
         XcmHelper: pallet_xcm_helper::{Pallet, Call, Storage, Event<T>} = 93,
         Streaming: pallet_streaming::{Pallet, Call, Storage, Event<T>} = 94,
         AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Event<T>} = 95,
+        RemoteDerivatives: pallet_remote_derivatives::{Pallet, Call, Storage, Event<T>} = 101,
+        FeeDistribution: pallet_fee_distribution::{Pallet, Call, Storage, Event<T>} = 102,
+        AssetTreasury: pallet_asset_treasury::{Pallet, Call, Storage, Event<T>} = 103,
+        Parameters: pallet_parameters::{Pallet, Call, Storage, Event<T>} = 104,
+        Migrations: pallet_migrations::{Pallet, Storage, Event} = 106,
+        JobQueue: pallet_job_queue::{Pallet, Storage, Event<T>} = 107,
+        Revenue: pallet_revenue::{Pallet, Call, Storage, Event<T>} = 108,
+        GrantVesting: pallet_grant_vesting::{Pallet, Call, Storage, Event<T>} = 109,
+        Recovery: pallet_recovery::{Pallet, Call, Storage, Event<T>} = 110,
+        Zap: pallet_zap::{Pallet, Call, Event<T>} = 113,
+        ScheduledCalls: pallet_scheduled_calls::{Pallet, Call, Storage, Event<T>} = 114,
+        Inheritance: pallet_inheritance::{Pallet, Call, Storage, Event<T>} = 115,
+        RelayEra: pallet_relay_era::{Pallet, Call, Storage, Event<T>} = 116,
+        Keepers: pallet_keepers::{Pallet, Call, Storage, Event<T>} = 117,
+        Referrals: pallet_referrals::{Pallet, Call, Storage, Event<T>} = 118,
+        ProtocolLiquidity: pallet_protocol_liquidity::{Pallet, Call, Storage, Event<T>} = 121,
+        Buyback: pallet_buyback::{Pallet, Call, Storage, Event<T>} = 122,
+        Insurance: pallet_insurance::{Pallet, Call, Storage, Event<T>} = 123,
+        AssetFreezer: pallet_asset_freezer::{Pallet, Call, Storage, Event<T>} = 124,
+        RewardsAggregator: pallet_rewards_aggregator::{Pallet, Call, Storage, Event<T>} = 125,
 
         // Parachain System, always put it at the end
         ParachainSystem: cumulus_pallet_parachain_system::{Pallet, Call, Config, Storage, Inherent, Event<T>, ValidateUnsigned} = 20,
@@ -1827,7 +2803,8 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    pallet_asset_tx_payment::ChargeAssetTxPayment<Runtime>,
+    pallet_prices::CheckPriceFreshness<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -1848,6 +2825,17 @@ pub type Executive = frame_executive::Executive<
         pallet_scheduler::migration::v3::MigrateToV4<Runtime>,
         pallet_democracy::migrations::v1::Migration<Runtime>,
         pallet_multisig::migrations::v1::MigrateToV1<Runtime>,
+        governance::MigrateDemocracyLocks,
+        pallet_referrals::migrations::MigrateCrowdloansReferrers<Runtime>,
+        pallet_loans::migrations::InitializeKeeperMarkers<Runtime>,
+        pallet_liquid_staking::migrations::TrackPendingXcmRequests<Runtime, ()>,
+        pallet_crowdloans::migrations::TrackPendingXcmRequests<Runtime>,
+        pallet_liquid_staking::migrations::InitializeInstance<
+            Runtime,
+            DotLiquidStakingInstance,
+            DotInitialExchangeRate,
+            DotInitialReserveFactor,
+        >,
     ),
 >;
 
@@ -1989,6 +2977,51 @@ impl_runtime_apis! {
         fn get_liquidation_threshold_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
             Loans::get_account_liquidation_threshold_liquidity(&account)
         }
+
+        fn get_health_factors(max_health_factor: sp_runtime::FixedU128) -> Result<Vec<(AccountId, sp_runtime::FixedU128)>, DispatchError> {
+            Loans::get_health_factors(max_health_factor)
+        }
+
+        fn get_health_alerts() -> Result<Vec<(AccountId, sp_runtime::FixedU128, sp_runtime::FixedU128)>, DispatchError> {
+            Loans::get_health_alerts()
+        }
+
+        fn get_rate_history(asset_id: CurrencyId) -> Vec<(u64, Ratio, Rate, Rate, Rate)> {
+            Loans::get_rate_history(asset_id)
+        }
+
+        fn simulate_market_rates(asset_id: CurrencyId, supplied: Balance, redeemed: Balance, borrowed: Balance, repaid: Balance) -> Result<(Ratio, Rate, Rate), DispatchError> {
+            Loans::simulate_market_rates(asset_id, supplied, redeemed, borrowed, repaid)
+        }
+    }
+
+    impl pallet_referrals_rpc_runtime_api::ReferralsApi<Block, AccountId> for Runtime {
+        fn referrer_of_code(code: Vec<u8>) -> Option<(AccountId, Option<Vec<u8>>)> {
+            let referrer = <Referrals as pallet_traits::Referrals<AccountId>>::referrer_of_code(&code)?;
+            let display_name = <VerifiedIdentity as pallet_traits::IdentityVerifier<AccountId>>::display_name(&referrer);
+            Some((referrer, display_name))
+        }
+
+        fn display_names(accounts: Vec<AccountId>) -> Vec<Option<Vec<u8>>> {
+            accounts
+                .iter()
+                .map(<VerifiedIdentity as pallet_traits::IdentityVerifier<AccountId>>::display_name)
+                .collect()
+        }
+    }
+
+    impl pallet_revenue_rpc_runtime_api::RevenueApi<Block> for Runtime {
+        fn current_era() -> EraIndex {
+            Revenue::current_era()
+        }
+
+        fn era_revenue(era: EraIndex) -> Vec<(RevenueSource, CurrencyId, Balance)> {
+            Revenue::revenue_of_era(era)
+        }
+
+        fn total_revenue(source: RevenueSource, asset_id: CurrencyId) -> Balance {
+            Revenue::total_revenue(source, asset_id)
+        }
     }
 
     impl pallet_router_rpc_runtime_api::RouterApi<Block, Balance> for Runtime {
@@ -1998,6 +3031,82 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_xcm_helper_rpc_runtime_api::XcmHelperApi<Block, Balance> for Runtime {
+        fn estimate_fee(dest: VersionedMultiLocation, message: VersionedXcm<()>, fee_asset: CurrencyId) -> Result<(u64, Balance), DispatchError> {
+            XcmHelper::estimate_xcm_fee::<XcmConfig, CurrencyIdConvert<WrapAssetRegistry>>(dest, message, fee_asset)
+        }
+
+        fn estimate_transfer_fee(dest: VersionedMultiLocation) -> Result<(u64, Balance), DispatchError> {
+            XcmHelper::estimate_transfer_fee(dest)
+        }
+    }
+
+    impl pallet_xcm_helper_rpc_runtime_api::XcmDryRunApi<Block, RuntimeCall, RuntimeEvent> for Runtime {
+        fn dry_run_xcm(origin: VersionedMultiLocation, message: VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffects<RuntimeEvent>, DispatchError> {
+            XcmHelper::dry_run_xcm::<XcmConfig, XcmAssetMovements>(origin, message)
+        }
+    }
+
+    impl pallet_rewards_rpc_runtime_api::RewardsApi<Block, AccountId, Balance> for Runtime {
+        fn pending_all(account: AccountId) -> Result<PendingRewards<Balance>, DispatchError> {
+            Ok(PendingRewards::new(
+                (RewardAssetId::get(), Loans::pending_reward(&account)?).into(),
+                Farming::pending_rewards(&account)?.into_iter().map(Into::into).collect(),
+                Crowdloans::claimable_contributions(&account).into_iter().map(Into::into).collect(),
+                (LiquidStaking::staking_currency()?, LiquidStaking::claimable_unlockings(&account)).into(),
+            ))
+        }
+    }
+
+    impl pallet_amm_rpc_runtime_api::AMMApi<Block, AccountId, Balance> for Runtime {
+        fn get_amount_out(pool: (CurrencyId, CurrencyId), asset_in: CurrencyId, amount_in: Balance) -> Result<SwapQuote<Balance>, DispatchError> {
+            AMM::quote_amount_out(pool, asset_in, amount_in)
+        }
+
+        fn get_amount_in(pool: (CurrencyId, CurrencyId), asset_out: CurrencyId, amount_out: Balance) -> Result<SwapQuote<Balance>, DispatchError> {
+            AMM::quote_amount_in(pool, asset_out, amount_out)
+        }
+
+        fn position_value(account: AccountId, pool: (CurrencyId, CurrencyId)) -> Result<PositionValue<Balance>, DispatchError> {
+            AMM::position_value(&account, pool)
+        }
+    }
+
+    impl pallet_portfolio_rpc_runtime_api::PortfolioApi<Block, AccountId, Balance> for Runtime {
+        fn portfolio_positions(account: AccountId) -> Result<VersionedPortfolioPositions<Balance>, DispatchError> {
+            Ok(PortfolioPositions {
+                loans: Loans::account_positions(&account)?.into_iter().map(Into::into).collect(),
+                liquidity: AMM::liquidity_positions(&account)?.into_iter().map(Into::into).collect(),
+                farming: Farming::account_positions(&account).into_iter().map(Into::into).collect(),
+                crowdloans: Crowdloans::account_positions(&account).into_iter().map(Into::into).collect(),
+                streams: Streaming::account_streams(&account)?.into_iter().map(Into::into).collect(),
+            }
+            .into())
+        }
+    }
+
+    impl pallet_bridge_rpc_runtime_api::BridgeApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn transfer_status(chain_id: ChainId, chain_nonce: ChainNonce) -> TransferInfo<AccountId, Balance, BlockNumber> {
+            Bridge::transfer_status(chain_id, chain_nonce)
+        }
+    }
+
+    impl pallet_crowdloans_rpc_runtime_api::CrowdloansApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn vault_statuses(account: AccountId) -> Result<Vec<VaultStatus<CurrencyId, Balance, BlockNumber>>, DispatchError> {
+            Crowdloans::vault_statuses(&account)
+        }
+    }
+
+    impl pallet_prices_rpc_runtime_api::PricesApi<Block, AccountId, BlockNumber> for Runtime {
+        fn get_round(asset_id: CurrencyId, round_id: RoundId) -> Option<Round<AccountId, BlockNumber>> {
+            Prices::get_round(asset_id, round_id)
+        }
+
+        fn get_feeder_status() -> Vec<(AccountId, FeederStatus<BlockNumber>)> {
+            Prices::get_feeder_status()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
@@ -2024,12 +3133,27 @@ impl_runtime_apis! {
             list_benchmark!(list, extra, pallet_timestamp, Timestamp);
             list_benchmark!(list, extra, pallet_amm, AMM);
             list_benchmark!(list, extra, pallet_liquid_staking, LiquidStaking);
+            list_benchmark!(list, extra, pallet_nominee_election, NomineeElection);
             list_benchmark!(list, extra, pallet_router, AMMRoute);
             list_benchmark!(list, extra, pallet_crowdloans, Crowdloans);
             list_benchmark!(list, extra, pallet_xcm_helper, XcmHelper);
             list_benchmark!(list, extra, pallet_farming, Farming);
             list_benchmark!(list, extra, pallet_asset_registry, AssetRegistry);
             list_benchmark!(list, extra, pallet_streaming, Streaming);
+            list_benchmark!(list, extra, pallet_remote_derivatives, RemoteDerivatives);
+            list_benchmark!(list, extra, pallet_fee_distribution, FeeDistribution);
+            list_benchmark!(list, extra, pallet_asset_treasury, AssetTreasury);
+            list_benchmark!(list, extra, pallet_collator_rewards, CollatorRewards);
+            list_benchmark!(list, extra, pallet_revenue, Revenue);
+            list_benchmark!(list, extra, pallet_grant_vesting, GrantVesting);
+            list_benchmark!(list, extra, pallet_scheduled_calls, ScheduledCalls);
+            list_benchmark!(list, extra, pallet_inheritance, Inheritance);
+            list_benchmark!(list, extra, pallet_relay_era, RelayEra);
+            list_benchmark!(list, extra, pallet_keepers, Keepers);
+            list_benchmark!(list, extra, pallet_referrals, Referrals);
+            list_benchmark!(list, extra, pallet_parameters, Parameters);
+            list_benchmark!(list, extra, pallet_emergency_shutdown, EmergencyShutdown);
+            list_benchmark!(list, extra, pallet_currency_adapter, CurrencyAdapter);
             list_benchmark!(list, extra, pallet_assets, Assets);
             list_benchmark!(list, extra, pallet_collator_selection, CollatorSelection);
             list_benchmark!(list, extra, pallet_proxy, Proxy);
@@ -2037,6 +3161,9 @@ impl_runtime_apis! {
             list_benchmark!(list, extra, cumulus_pallet_xcmp_queue, XcmpQueue);
             list_benchmark!(list, extra, pallet_identity, Identity);
             list_benchmark!(list, extra, pallet_democracy, Democracy);
+            list_benchmark!(list, extra, pallet_referenda, Referenda);
+            list_benchmark!(list, extra, pallet_conviction_voting, ConvictionVoting);
+            list_benchmark!(list, extra, pallet_whitelist, Whitelist);
             list_benchmark!(list, extra, pallet_collective, TechnicalCommittee);
             list_benchmark!(list, extra, pallet_preimage, Preimage);
             list_benchmark!(list, extra, pallet_scheduler, Scheduler);
@@ -2083,12 +3210,27 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, pallet_membership, TechnicalCommitteeMembership);
             add_benchmark!(params, batches, pallet_amm, AMM);
             add_benchmark!(params, batches, pallet_liquid_staking, LiquidStaking);
+            add_benchmark!(params, batches, pallet_nominee_election, NomineeElection);
             add_benchmark!(params, batches, pallet_router, AMMRoute);
             add_benchmark!(params, batches, pallet_crowdloans, Crowdloans);
             add_benchmark!(params, batches, pallet_xcm_helper, XcmHelper);
             add_benchmark!(params, batches, pallet_farming, Farming);
             add_benchmark!(params, batches, pallet_asset_registry, AssetRegistry);
             add_benchmark!(params, batches, pallet_streaming, Streaming);
+            add_benchmark!(params, batches, pallet_remote_derivatives, RemoteDerivatives);
+            add_benchmark!(params, batches, pallet_fee_distribution, FeeDistribution);
+            add_benchmark!(params, batches, pallet_asset_treasury, AssetTreasury);
+            add_benchmark!(params, batches, pallet_collator_rewards, CollatorRewards);
+            add_benchmark!(params, batches, pallet_revenue, Revenue);
+            add_benchmark!(params, batches, pallet_grant_vesting, GrantVesting);
+            add_benchmark!(params, batches, pallet_scheduled_calls, ScheduledCalls);
+            add_benchmark!(params, batches, pallet_inheritance, Inheritance);
+            add_benchmark!(params, batches, pallet_relay_era, RelayEra);
+            add_benchmark!(params, batches, pallet_keepers, Keepers);
+            add_benchmark!(params, batches, pallet_referrals, Referrals);
+            add_benchmark!(params, batches, pallet_parameters, Parameters);
+            add_benchmark!(params, batches, pallet_emergency_shutdown, EmergencyShutdown);
+            add_benchmark!(params, batches, pallet_currency_adapter, CurrencyAdapter);
             add_benchmark!(params, batches, pallet_assets, Assets);
             add_benchmark!(params, batches, pallet_collator_selection, CollatorSelection);
             add_benchmark!(params, batches, pallet_proxy, Proxy);
@@ -2096,6 +3238,9 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, cumulus_pallet_xcmp_queue, XcmpQueue);
             add_benchmark!(params, batches, pallet_identity, Identity);
             add_benchmark!(params, batches, pallet_democracy, Democracy);
+            add_benchmark!(params, batches, pallet_referenda, Referenda);
+            add_benchmark!(params, batches, pallet_conviction_voting, ConvictionVoting);
+            add_benchmark!(params, batches, pallet_whitelist, Whitelist);
             add_benchmark!(params, batches, pallet_collective, TechnicalCommittee);
             add_benchmark!(params, batches, pallet_preimage, Preimage);
             add_benchmark!(params, batches, pallet_scheduler, Scheduler);
@@ -2111,6 +3256,12 @@ impl_runtime_apis! {
         fn on_runtime_upgrade() -> (Weight, Weight) {
             log::info!("try-runtime::on_runtime_upgrade.");
             let weight = Executive::try_runtime_upgrade().unwrap();
+            // The invariants of every pallet must still hold on the migrated state
+            <AllPalletsWithSystem as frame_support::traits::TryState<BlockNumber>>::try_state(
+                System::block_number(),
+                frame_try_runtime::TryStateSelect::All,
+            )
+            .expect("try_state failed after the runtime upgrade");
             (weight, RuntimeBlockWeights::get().max_block)
         }
 
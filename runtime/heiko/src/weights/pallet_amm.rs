@@ -26,6 +26,10 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions for `pallet_amm`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_amm::WeightInfo for WeightInfo<T> {
@@ -36,6 +40,7 @@ impl<T: frame_system::Config> pallet_amm::WeightInfo for WeightInfo<T> {
 	fn add_liquidity() -> Weight {
 		Weight::from_ref_time(161_720_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(10 as u64))
 			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: AMM Pools (r:1 w:1)
@@ -45,6 +50,7 @@ impl<T: frame_system::Config> pallet_amm::WeightInfo for WeightInfo<T> {
 	fn remove_liquidity() -> Weight {
 		Weight::from_ref_time(174_864_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(10 as u64))
 			.saturating_add(T::DbWeight::get().writes(9 as u64))
 	}
 	// Storage: AMM Pools (r:1 w:1)
@@ -55,6 +61,7 @@ impl<T: frame_system::Config> pallet_amm::WeightInfo for WeightInfo<T> {
 	fn create_pool() -> Weight {
 		Weight::from_ref_time(217_145_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	// Storage: AMM ProtocolFee (r:0 w:1)
@@ -67,4 +74,12 @@ impl<T: frame_system::Config> pallet_amm::WeightInfo for WeightInfo<T> {
 		Weight::from_ref_time(24_118_000 as u64)
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: AMM Pools (r:1 w:0)
+	// Storage: AMM WithdrawalFees (r:0 w:1)
+	fn update_withdrawal_fee() -> Weight {
+		Weight::from_ref_time(26_308_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
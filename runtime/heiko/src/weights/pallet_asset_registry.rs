@@ -5,6 +5,9 @@
 //! DATE: 2022-10-20, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `ip-172-88-3-164`, CPU: `Intel(R) Xeon(R) Platinum 8124M CPU @ 3.00GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("heiko-dev"), DB CACHE: 1024
+//!
+//! The weights marked NOT BENCHMARKED YET were added by hand from the storage accesses of
+//! their calls and are estimates until this file is regenerated.
 
 // Executed Command:
 // ./target/release/parallel
@@ -26,15 +29,22 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions for `pallet_asset_registry`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_asset_registry::WeightInfo for WeightInfo<T> {
 	// Storage: AssetRegistry AssetIdType (r:1 w:1)
+	// Storage: AssetRegistry PendingAssets (r:1 w:0)
 	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
 	fn register_asset() -> Weight {
 		Weight::from_ref_time(30_282_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: AssetRegistry AssetTypeId (r:1 w:0)
 	// Storage: AssetRegistry SupportedFeePaymentAssets (r:1 w:1)
@@ -42,31 +52,121 @@ impl<T: frame_system::Config> pallet_asset_registry::WeightInfo for WeightInfo<T
 	fn update_asset_units_per_second() -> Weight {
 		Weight::from_ref_time(37_353_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: AssetRegistry SupportedFeePaymentAssets (r:1 w:1)
 	// Storage: AssetRegistry AssetIdType (r:1 w:1)
 	// Storage: AssetRegistry AssetTypeUnitsPerSecond (r:1 w:2)
 	// Storage: AssetRegistry AssetTypeId (r:0 w:2)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
 	fn update_asset_type() -> Weight {
 		Weight::from_ref_time(51_300_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: AssetRegistry SupportedFeePaymentAssets (r:1 w:1)
 	// Storage: AssetRegistry AssetTypeUnitsPerSecond (r:0 w:1)
 	fn remove_fee_payment_asset() -> Weight {
 		Weight::from_ref_time(31_517_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: AssetRegistry SupportedFeePaymentAssets (r:1 w:1)
 	// Storage: AssetRegistry AssetIdType (r:1 w:1)
 	// Storage: AssetRegistry AssetTypeUnitsPerSecond (r:0 w:1)
 	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
+	// Storage: AssetRegistry AssetMetadatas (r:0 w:1)
+	// Storage: AssetRegistry AssetParameters (r:0 w:1)
 	fn deregister_asset() -> Weight {
 		Weight::from_ref_time(40_159_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry AssetIdType (r:1 w:0)
+	// Storage: AssetRegistry AssetTypeId (r:1 w:0)
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn propose_asset() -> Weight {
+		Weight::from_ref_time(40_736_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AssetRegistry AssetMetadatas (r:0 w:1)
+	// Storage: AssetRegistry AssetIdType (r:0 w:1)
+	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
+	fn approve_asset() -> Weight {
+		Weight::from_ref_time(46_169_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_asset() -> Weight {
+		Weight::from_ref_time(37_778_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry PendingAssets (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AssetRegistry AssetMetadatas (r:0 w:1)
+	// Storage: AssetRegistry AssetIdType (r:0 w:1)
+	// Storage: AssetRegistry AssetTypeId (r:0 w:1)
+	// Storage: AssetRegistry AssetIdLocation (r:0 w:1)
+	fn finalize_asset() -> Weight {
+		Weight::from_ref_time(47_991_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry AssetIdType (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: AssetRegistry AssetParameters (r:0 w:1)
+	// Storage: AssetRegistry XcmFeeAssets (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:2 w:0)
+	// Storage: Oracle Values (r:1 w:0)
+	// Storage: AMM Pools (r:1 w:0)
+	fn set_asset_params() -> Weight {
+		Weight::from_ref_time(33_346_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry AssetIdType (r:1 w:0)
+	// Storage: AssetRegistry XcmFeeAssets (r:1 w:1)
+	// Storage: Prices EmergencyPrice (r:2 w:0)
+	// Storage: Oracle Values (r:1 w:0)
+	// Storage: AMM Pools (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:0)
+	fn add_xcm_fee_asset() -> Weight {
+		Weight::from_ref_time(41_328_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: AssetRegistry XcmFeeAssets (r:1 w:1)
+	fn remove_xcm_fee_asset() -> Weight {
+		Weight::from_ref_time(18_205_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 }
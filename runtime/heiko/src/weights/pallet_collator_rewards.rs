@@ -0,0 +1,73 @@
+//! Weights for `pallet_collator_rewards`
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=heiko-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_collator_rewards
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=./runtime/heiko/src/weights/pallet_collator_rewards.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions for `pallet_collator_rewards`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_collator_rewards::WeightInfo for WeightInfo<T> {
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorRewards RewardPerBlock (r:0 w:1)
+	fn set_reward_per_block() -> Weight {
+		Weight::from_ref_time(18_526_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorSelection Invulnerables (r:1 w:0)
+	// Storage: CollatorRewards DelegationPools (r:1 w:1)
+	// Storage: CollatorRewards Delegations (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:0 w:1)
+	fn delegate() -> Weight {
+		Weight::from_ref_time(53_115_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorRewards DelegationPools (r:1 w:1)
+	// Storage: CollatorRewards Delegations (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Balances Reserves (r:0 w:1)
+	fn undelegate() -> Weight {
+		Weight::from_ref_time(58_438_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: CollatorRewards DelegationPools (r:1 w:0)
+	// Storage: CollatorRewards Delegations (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_rewards() -> Weight {
+		Weight::from_ref_time(44_701_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+}
@@ -26,6 +26,10 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions for `pallet_farming`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_farming::WeightInfo for WeightInfo<T> {
@@ -33,24 +37,28 @@ impl<T: frame_system::Config> pallet_farming::WeightInfo for WeightInfo<T> {
 	fn create() -> Weight {
 		Weight::from_ref_time(35_722_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: Farming Pools (r:1 w:1)
 	fn set_pool_status() -> Weight {
 		Weight::from_ref_time(35_782_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: Farming Pools (r:1 w:1)
 	fn set_pool_cool_down_duration() -> Weight {
 		Weight::from_ref_time(35_637_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: Farming Pools (r:1 w:1)
 	fn reset_pool_unlock_height() -> Weight {
 		Weight::from_ref_time(36_801_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: Farming Pools (r:1 w:1)
@@ -59,6 +67,7 @@ impl<T: frame_system::Config> pallet_farming::WeightInfo for WeightInfo<T> {
 	fn deposit() -> Weight {
 		Weight::from_ref_time(113_250_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: Farming Pools (r:1 w:1)
@@ -66,6 +75,7 @@ impl<T: frame_system::Config> pallet_farming::WeightInfo for WeightInfo<T> {
 	fn withdraw() -> Weight {
 		Weight::from_ref_time(77_258_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: Farming Pools (r:1 w:0)
@@ -74,6 +84,7 @@ impl<T: frame_system::Config> pallet_farming::WeightInfo for WeightInfo<T> {
 	fn redeem() -> Weight {
 		Weight::from_ref_time(80_729_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: Farming Pools (r:1 w:1)
@@ -82,6 +93,7 @@ impl<T: frame_system::Config> pallet_farming::WeightInfo for WeightInfo<T> {
 	fn claim() -> Weight {
 		Weight::from_ref_time(99_485_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: Farming Pools (r:1 w:1)
@@ -89,6 +101,7 @@ impl<T: frame_system::Config> pallet_farming::WeightInfo for WeightInfo<T> {
 	fn dispatch_reward() -> Weight {
 		Weight::from_ref_time(96_386_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 }
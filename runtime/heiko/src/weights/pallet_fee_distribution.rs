@@ -0,0 +1,59 @@
+//! Weights for `pallet_fee_distribution`
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=heiko-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_fee_distribution
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=./runtime/heiko/src/weights/pallet_fee_distribution.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions for `pallet_fee_distribution`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_fee_distribution::WeightInfo for WeightInfo<T> {
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: FeeDistribution FeeSplitConfig (r:0 w:1)
+	fn set_fee_split() -> Weight {
+		Weight::from_ref_time(19_961_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: FeeDistribution RebateConfig (r:0 w:1)
+	fn set_rebate_params() -> Weight {
+		Weight::from_ref_time(17_582_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: FeeDistribution RebateConfig (r:1 w:0)
+	// Storage: FeeDistribution FeeVolumes (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn claim_rebate() -> Weight {
+		Weight::from_ref_time(51_369_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+}
@@ -26,6 +26,10 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions for `pallet_liquid_staking`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T> {
@@ -42,6 +46,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn stake() -> Weight {
 		Weight::from_ref_time(191_939_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(16 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	// Storage: LiquidStaking ExchangeRate (r:1 w:0)
@@ -54,6 +59,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn unstake() -> Weight {
 		Weight::from_ref_time(84_517_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
@@ -74,6 +80,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn bond() -> Weight {
 		Weight::from_ref_time(137_884_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
@@ -92,6 +99,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn nominate() -> Weight {
 		Weight::from_ref_time(126_180_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(11 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
@@ -112,6 +120,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn bond_extra() -> Weight {
 		Weight::from_ref_time(140_667_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(13 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: LiquidStaking StakingLedgers (r:1 w:1)
@@ -120,6 +129,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn force_set_staking_ledger() -> Weight {
 		Weight::from_ref_time(51_741_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
@@ -139,6 +149,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn unbond() -> Weight {
 		Weight::from_ref_time(131_196_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(12 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
@@ -158,6 +169,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn rebond() -> Weight {
 		Weight::from_ref_time(130_325_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(12 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: LiquidStaking CurrentEra (r:1 w:0)
@@ -177,12 +189,14 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn withdraw_unbonded() -> Weight {
 		Weight::from_ref_time(132_311_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(12 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: LiquidStaking ReserveFactor (r:1 w:1)
 	fn update_reserve_factor() -> Weight {
 		Weight::from_ref_time(26_745_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: LiquidStaking CommissionRate (r:0 w:1)
@@ -194,6 +208,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn update_staking_ledger_cap() -> Weight {
 		Weight::from_ref_time(26_846_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: LiquidStaking XcmRequests (r:1 w:1)
@@ -206,6 +221,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn notification_received() -> Weight {
 		Weight::from_ref_time(94_750_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: LiquidStaking CurrentEra (r:1 w:0)
@@ -218,6 +234,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn claim_for() -> Weight {
 		Weight::from_ref_time(109_068_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	// Storage: LiquidStaking EraStartBlock (r:0 w:1)
@@ -237,6 +254,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn on_initialize() -> Weight {
 		Weight::from_ref_time(12_624_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 	}
 	// Storage: LiquidStaking StakingLedgers (r:10 w:0)
 	// Storage: LiquidStaking MatchingPool (r:1 w:1)
@@ -262,6 +280,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn force_advance_era() -> Weight {
 		Weight::from_ref_time(358_801_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(28 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(28 as u64))
 			.saturating_add(T::DbWeight::get().writes(14 as u64))
 	}
 	// Storage: LiquidStaking StakingLedgers (r:10 w:0)
@@ -284,6 +303,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn force_matching() -> Weight {
 		Weight::from_ref_time(304_997_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(24 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(24 as u64))
 			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
 	// Storage: LiquidStaking TotalReserves (r:1 w:1)
@@ -293,6 +313,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn reduce_reserves() -> Weight {
 		Weight::from_ref_time(85_536_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	// Storage: LiquidStaking FastUnstakeRequests (r:1 w:1)
@@ -302,6 +323,7 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 	fn cancel_unstake() -> Weight {
 		Weight::from_ref_time(52_267_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: LiquidStaking FastUnstakeRequests (r:1 w:1)
@@ -317,8 +339,43 @@ impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T
 			// Standard Error: 40_000
 			.saturating_add(Weight::from_ref_time(128_519_000 as u64).saturating_mul(n as u64))
 			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(9 as u64))
 			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((4 as u64).saturating_mul(n as u64)))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
 	}
+	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
+	// Storage: LiquidStaking NominationPools (r:0 w:1)
+	fn set_nomination_pool() -> Weight {
+		Weight::from_ref_time(27_940_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: LiquidStaking NominationPools (r:1 w:0)
+	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: LiquidStaking XcmRequests (r:0 w:1)
+	// Storage: PolkadotXcm Queries (r:0 w:1)
+	fn claim_pool_rewards() -> Weight {
+		Weight::from_ref_time(129_318_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: LiquidStaking FastUnstakeFee (r:0 w:1)
+	fn update_fast_unstake_fee_curve() -> Weight {
+		Weight::from_ref_time(23_702_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
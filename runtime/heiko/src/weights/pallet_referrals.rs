@@ -0,0 +1,78 @@
+//! Weights for `pallet_referrals`
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=heiko-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_referrals
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=./runtime/heiko/src/weights/pallet_referrals.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions for `pallet_referrals`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_referrals::WeightInfo for WeightInfo<T> {
+	// Storage: Identity SuperOf (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: Referrals CodeOf (r:1 w:1)
+	// Storage: Referrals Codes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Referrals CodeDeposits (r:0 w:1)
+	fn register_code() -> Weight {
+		Weight::from_ref_time(42_584_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Referrals CodeOf (r:1 w:1)
+	// Storage: Referrals Codes (r:0 w:1)
+	// Storage: Referrals CodeDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_code() -> Weight {
+		Weight::from_ref_time(34_655_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Referrals ReferrerOf (r:1 w:1)
+	// Storage: Referrals Codes (r:1 w:0)
+	fn set_referrer() -> Weight {
+		Weight::from_ref_time(26_041_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Referrals Tiers (r:0 w:1)
+	/// The range of component `n` is `[1, 10]`.
+	fn set_tiers(n: u32, ) -> Weight {
+		Weight::from_ref_time(14_674_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(437_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Referrals PendingRebates (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_rebate() -> Weight {
+		Weight::from_ref_time(46_320_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+}
@@ -0,0 +1,61 @@
+//! Weights for `pallet_relay_era`
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=heiko-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_relay_era
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=./runtime/heiko/src/weights/pallet_relay_era.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions for `pallet_relay_era`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_relay_era::WeightInfo for WeightInfo<T> {
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: RelayEra SessionStartBlock (r:1 w:1)
+	// Storage: RelayEra CurrentSession (r:1 w:1)
+	// Storage: RelayEra EraStartBlock (r:1 w:1)
+	// Storage: RelayEra CurrentEra (r:1 w:1)
+	fn on_initialize() -> Weight {
+		Weight::from_ref_time(21_547_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: RelayEra CurrentEra (r:0 w:1)
+	// Storage: RelayEra EraStartBlock (r:0 w:1)
+	fn force_set_current_era() -> Weight {
+		Weight::from_ref_time(17_133_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: RelayEra CurrentSession (r:0 w:1)
+	// Storage: RelayEra SessionStartBlock (r:0 w:1)
+	fn force_set_current_session() -> Weight {
+		Weight::from_ref_time(16_961_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+}
@@ -5,6 +5,9 @@
 //! DATE: 2022-10-20, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `ip-172-88-3-164`, CPU: `Intel(R) Xeon(R) Platinum 8124M CPU @ 3.00GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("heiko-dev"), DB CACHE: 1024
+//!
+//! The weights marked NOT BENCHMARKED YET were added by hand from the storage accesses of
+//! their calls and are estimates until this file is regenerated.
 
 // Executed Command:
 // ./target/release/parallel
@@ -26,6 +29,10 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions for `pallet_router`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_router::WeightInfo for WeightInfo<T> {
@@ -35,6 +42,7 @@ impl<T: frame_system::Config> pallet_router::WeightInfo for WeightInfo<T> {
 	fn swap_exact_tokens_for_tokens() -> Weight {
 		Weight::from_ref_time(154_439_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: AMM Pools (r:1 w:1)
@@ -43,6 +51,72 @@ impl<T: frame_system::Config> pallet_router::WeightInfo for WeightInfo<T> {
 	fn swap_tokens_for_exact_tokens() -> Weight {
 		Weight::from_ref_time(154_500_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:5 w:5)
+	// Storage: AMM Pools (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	// Storage: XcmpQueue OutboundXcmpMessages (r:0 w:1)
+	fn swap_and_transfer() -> Weight {
+		Weight::from_ref_time(270_784_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: AMMRoute SwapCommitments (r:1 w:1)
+	fn commit_swap() -> Weight {
+		Weight::from_ref_time(32_417_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: AMMRoute SwapCommitments (r:1 w:1)
+	// Storage: AMMRoute WhitelistedRoutes (r:1 w:0)
+	// Storage: AMMRoute RevealedSwaps (r:1 w:1)
+	// Trade in the next block:
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:4 w:4)
+	// Storage: AMM Pools (r:1 w:1)
+	fn reveal_swap() -> Weight {
+		Weight::from_ref_time(221_340_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: AMMRoute SwapCommitments (r:1 w:1)
+	fn cancel_swap_commitment() -> Weight {
+		Weight::from_ref_time(30_126_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: AMMRoute WhitelistedRoutes (r:1 w:1)
+	fn whitelist_route() -> Weight {
+		Weight::from_ref_time(24_310_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: AMMRoute WhitelistedRoutes (r:1 w:1)
+	fn remove_whitelisted_route() -> Weight {
+		Weight::from_ref_time(23_870_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
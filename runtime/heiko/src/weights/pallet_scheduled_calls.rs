@@ -0,0 +1,69 @@
+//! Weights for `pallet_scheduled_calls`
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=heiko-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_scheduled_calls
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=./runtime/heiko/src/weights/pallet_scheduled_calls.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions for `pallet_scheduled_calls`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_scheduled_calls::WeightInfo for WeightInfo<T> {
+	// Storage: System Account (r:1 w:1)
+	// Storage: ScheduledCalls NextCallId (r:1 w:1)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	// Storage: ScheduledCalls Scheduled (r:0 w:1)
+	fn schedule() -> Weight {
+		Weight::from_ref_time(47_218_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: ScheduledCalls Scheduled (r:1 w:1)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn cancel() -> Weight {
+		Weight::from_ref_time(52_604_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: ScheduledCalls Scheduled (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	fn execute() -> Weight {
+		Weight::from_ref_time(68_931_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: ScheduledCalls Scheduled (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ScheduledCalls Agenda (r:1 w:1)
+	fn service_call() -> Weight {
+		Weight::from_ref_time(44_187_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+}
@@ -5,6 +5,9 @@
 //! DATE: 2022-10-20, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `ip-172-88-3-164`, CPU: `Intel(R) Xeon(R) Platinum 8124M CPU @ 3.00GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("heiko-dev"), DB CACHE: 1024
+//!
+//! The weights marked NOT BENCHMARKED YET were added by hand from the storage accesses of
+//! their calls and are estimates until this file is regenerated.
 
 // Executed Command:
 // ./target/release/parallel
@@ -26,6 +29,10 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions for `pallet_xcm_helper`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_xcm_helper::WeightInfo for WeightInfo<T> {
@@ -33,6 +40,220 @@ impl<T: frame_system::Config> pallet_xcm_helper::WeightInfo for WeightInfo<T> {
 	fn update_xcm_weight_fee() -> Weight {
 		Weight::from_ref_time(28_291_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper OutboundXcms (r:1 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	fn resend_outbound_xcm() -> Weight {
+		Weight::from_ref_time(63_050_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper OutboundXcms (r:1 w:1)
+	fn cancel_outbound_xcm() -> Weight {
+		Weight::from_ref_time(23_879_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper OutboundXcms (r:1 w:1)
+	// Storage: LiquidStaking XcmRequests (r:1 w:1)
+	// Storage: LiquidStaking StakingLedgers (r:1 w:0)
+	fn on_response() -> Weight {
+		Weight::from_ref_time(58_898_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper TrappedAssets (r:1 w:1)
+	// Storage: PolkadotXcm AssetTraps (r:1 w:1)
+	// Storage: AssetRegistry LocationAssetId (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:0 w:1)
+	fn claim_assets() -> Weight {
+		Weight::from_ref_time(72_245_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: XcmHelper TrappedAssets (r:1 w:1)
+	// Storage: PolkadotXcm AssetTraps (r:1 w:1)
+	// Storage: AssetRegistry LocationAssetId (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: System Account (r:0 w:1)
+	fn sweep_trapped_assets() -> Weight {
+		Weight::from_ref_time(73_026_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper HrmpRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	fn open_hrmp_channel() -> Weight {
+		Weight::from_ref_time(58_888_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper HrmpRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	fn accept_hrmp_channel() -> Weight {
+		Weight::from_ref_time(56_042_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper HrmpRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	fn close_hrmp_channel() -> Weight {
+		Weight::from_ref_time(59_109_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper HrmpChannels (r:0 w:1)
+	fn set_hrmp_channel_status() -> Weight {
+		Weight::from_ref_time(17_268_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper AssetHubAssets (r:0 w:1)
+	fn set_asset_hub_asset() -> Weight {
+		Weight::from_ref_time(20_107_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper AssetHubAssets (r:1 w:0)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:3 w:3)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	// Storage: XcmpQueue OutboundXcmpMessages (r:0 w:1)
+	fn transfer_to_asset_hub() -> Weight {
+		Weight::from_ref_time(78_387_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper XcmAllowedOrigins (r:0 w:1)
+	fn set_xcm_allowed_origins() -> Weight {
+		Weight::from_ref_time(21_596_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper XcmMaxInstructions (r:0 w:1)
+	fn set_xcm_max_instructions() -> Weight {
+		Weight::from_ref_time(17_993_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper SuspendedXcmOrigins (r:1 w:1)
+	fn suspend_xcm_origin() -> Weight {
+		Weight::from_ref_time(21_454_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper SuspendedXcmOrigins (r:1 w:1)
+	fn resume_xcm_origin() -> Weight {
+		Weight::from_ref_time(19_655_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper RescueTargets (r:1 w:1)
+	fn add_rescue_target() -> Weight {
+		Weight::from_ref_time(21_527_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper RescueTargets (r:1 w:1)
+	fn remove_rescue_target() -> Weight {
+		Weight::from_ref_time(21_004_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: XcmHelper RescueTargets (r:1 w:0)
+	// Storage: XcmHelper NextRescueId (r:1 w:1)
+	// Storage: XcmHelper XcmWeightFee (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Storage: XcmHelper RescueRequests (r:0 w:1)
+	// Storage: XcmHelper OutboundXcms (r:0 w:1)
+	// Storage: XcmHelper FundsRescues (r:0 w:1)
+	fn rescue_funds() -> Weight {
+		Weight::from_ref_time(64_422_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
 }
@@ -14,6 +14,7 @@
 
 use frame_support::dispatch::GetDispatchInfo;
 use frame_support::dispatch::PostDispatchInfo;
+use frame_support::traits::Get;
 use pallet_evm::{
     ExitRevert, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult, PrecompileSet,
 };
@@ -21,6 +22,7 @@ use sp_core::H160;
 use sp_runtime::traits::Dispatchable;
 use sp_std::fmt::Debug;
 use sp_std::marker::PhantomData;
+use sp_std::vec::Vec;
 
 use pallet_evm_precompile_assets_erc20::{AddressToAssetId, Erc20AssetsPrecompileSet};
 use pallet_evm_precompile_balances_erc20::Erc20BalancesPrecompile;
@@ -30,13 +32,44 @@ use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
 use pallet_evm_precompile_dispatch::Dispatch;
 use pallet_evm_precompile_ed25519::Ed25519Verify;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_registry::{PrecompileInfo, PrecompileRegistry, RegistryPrecompile};
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_substrate_transfer::SubstrateTransferPrecompile;
+
+use crate::weights::{
+    pallet_evm_precompile_assets_erc20::WeightInfo as AssetsErc20Weights,
+    pallet_evm_precompile_balances_erc20::WeightInfo as BalancesErc20Weights,
+};
+
+/// ERC20 precompile of the native balances, charged with the benchmarked weights.
+type NativeErc20<R, M> = Erc20BalancesPrecompile<R, M, (), BalancesErc20Weights<R>>;
+
+/// ERC20 precompiles of the assets, charged with the benchmarked weights.
+type AssetsErc20<R> = Erc20AssetsPrecompileSet<R, (), AssetsErc20Weights<R>>;
 
 /// The asset precompile address prefix. Addresses that match against this prefix will be routed
 /// to Erc20AssetsPrecompileSet
 pub const ASSET_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[255u8; 4];
 
+/// Address of the ERC20 precompile of the native balances
+pub struct NativeErc20Address;
+
+impl Get<H160> for NativeErc20Address {
+    fn get() -> H160 {
+        hash(2050)
+    }
+}
+
+/// System contract address the native events mirrored to the EVM are logged from
+pub struct EventMirrorAddress;
+
+impl Get<H160> for EventMirrorAddress {
+    fn get() -> H160 {
+        hash(4096)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct ParallelPrecompiles<R, M>(PhantomData<(R, M)>);
 
@@ -49,7 +82,7 @@ where
         Self(Default::default())
     }
     pub fn used_addresses() -> impl Iterator<Item = H160> {
-        sp_std::vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 1024, 1025, 1026, 1027, 2050]
+        sp_std::vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 1024, 1025, 1026, 1027, 2050, 2051, 2052]
             .into_iter()
             .map(hash)
     }
@@ -57,9 +90,10 @@ where
 
 impl<R, M> PrecompileSet for ParallelPrecompiles<R, M>
 where
-    Erc20AssetsPrecompileSet<R>: PrecompileSet,
-    Erc20BalancesPrecompile<R, M>: Precompile,
+    AssetsErc20<R>: PrecompileSet,
+    NativeErc20<R, M>: Precompile,
     Dispatch<R>: Precompile,
+    SubstrateTransferPrecompile<R, NativeErc20Address>: Precompile,
     R: pallet_evm::Config
         + AddressToAssetId<<R as pallet_assets::Config>::AssetId>
         + pallet_assets::Config
@@ -81,7 +115,8 @@ where
                 output: b"cannot be called with DELEGATECALL or CALLCODE".to_vec(),
             }));
         }
-        match address {
+        // Only the Parallel precompiles are called through selectors, the others take raw input
+        recorded(handle, address > hash(1027), |handle| match address {
             // Ethereum precompiles :
             a if a == hash(1) => Some(ECRecover::execute(handle)),
             a if a == hash(2) => Some(Sha256::execute(handle)),
@@ -98,20 +133,80 @@ where
             a if a == hash(1026) => Some(ECRecoverPublicKey::execute(handle)),
             a if a == hash(1027) => Some(Ed25519Verify::execute(handle)),
             //Parallel precompiles:
-            a if a == hash(2050) => Some(Erc20BalancesPrecompile::<R, M>::execute(handle)),
+            a if a == hash(2050) => Some(NativeErc20::<R, M>::execute(handle)),
+            a if a == hash(2051) => Some(RegistryPrecompile::<R, Self>::execute(handle)),
+            a if a == hash(2052) => {
+                Some(SubstrateTransferPrecompile::<R, NativeErc20Address>::execute(handle))
+            }
             a if &a.to_fixed_bytes()[0..4] == ASSET_PRECOMPILE_ADDRESS_PREFIX => {
-                Erc20AssetsPrecompileSet::<R>::new().execute(handle)
+                AssetsErc20::<R>::new().execute(handle)
             }
             _ => None,
-        }
+        })
     }
 
     fn is_precompile(&self, address: H160) -> bool {
         Self::used_addresses().any(|x| x == address)
-            || Erc20AssetsPrecompileSet::<R>::new().is_precompile(address)
+            || AssetsErc20::<R>::new().is_precompile(address)
     }
 }
 
+impl<R, M> PrecompileRegistry for ParallelPrecompiles<R, M>
+where
+    AssetsErc20<R>: PrecompileSet,
+    R: pallet_evm::Config,
+    M: Erc20Metadata,
+{
+    fn precompile_addresses() -> Vec<H160> {
+        Self::used_addresses().collect()
+    }
+
+    fn precompile_info(address: H160) -> Option<PrecompileInfo> {
+        match address {
+            a if a == hash(2050) => Some(PrecompileInfo::new(
+                pallet_evm_precompile_balances_erc20::INTERFACE_VERSION,
+                pallet_evm_precompile_balances_erc20::Action::selectors(),
+            )),
+            a if a == hash(2051) => Some(PrecompileInfo::new(
+                pallet_evm_precompile_registry::INTERFACE_VERSION,
+                pallet_evm_precompile_registry::Action::selectors(),
+            )),
+            a if a == hash(2052) => Some(PrecompileInfo::new(
+                pallet_evm_precompile_substrate_transfer::INTERFACE_VERSION,
+                pallet_evm_precompile_substrate_transfer::Action::selectors(),
+            )),
+            // Raw input precompiles, their interface never changed
+            a if Self::used_addresses().any(|x| x == a) => Some(PrecompileInfo::new(1, Vec::new())),
+            a if AssetsErc20::<R>::new().is_precompile(a) => Some(PrecompileInfo::new(
+                pallet_evm_precompile_assets_erc20::INTERFACE_VERSION,
+                pallet_evm_precompile_assets_erc20::Action::selectors(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Reports the precompile calls to the metrics host function of the node. It only exists
+/// in the tracing runtime, the on chain one can't call host functions the relay chain
+/// validators don't provide.
+#[cfg(feature = "evm-tracing")]
+fn recorded<H: PrecompileHandle>(
+    handle: &mut H,
+    with_selector: bool,
+    execute: impl FnOnce(&mut H) -> Option<PrecompileResult>,
+) -> Option<PrecompileResult> {
+    precompile_metrics::recorded(handle, with_selector, execute)
+}
+
+#[cfg(not(feature = "evm-tracing"))]
+fn recorded<H: PrecompileHandle>(
+    handle: &mut H,
+    _with_selector: bool,
+    execute: impl FnOnce(&mut H) -> Option<PrecompileResult>,
+) -> Option<PrecompileResult> {
+    execute(handle)
+}
+
 fn hash(a: u64) -> H160 {
     H160::from_low_u64_be(a)
 }
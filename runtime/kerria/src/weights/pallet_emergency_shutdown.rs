@@ -0,0 +1,112 @@
+//! Weights for `pallet_emergency_shutdown`
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet-emergency-shutdown
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=./runtime/kerria/src/weights/pallet_emergency_shutdown.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions for `pallet_emergency_shutdown`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_emergency_shutdown::WeightInfo for WeightInfo<T> {
+	// Storage: EmergencyShutdown DisabledPallets (r:1 w:1)
+	fn toggle_pallet() -> Weight {
+		Weight::from_ref_time(18_412_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmergencyShutdown DisabledCalls (r:1 w:1)
+	fn toggle_call() -> Weight {
+		Weight::from_ref_time(18_935_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmergencyShutdown DisabledCalls (r:0 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn pause_calls(n: u32, ) -> Weight {
+		Weight::from_ref_time(14_206_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(1_872_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: EmergencyShutdown DisabledCalls (r:0 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn unpause_calls(n: u32, ) -> Weight {
+		Weight::from_ref_time(14_031_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(1_794_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: EmergencyShutdown BypassAccounts (r:0 w:1)
+	fn add_bypass() -> Weight {
+		Weight::from_ref_time(16_527_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmergencyShutdown BypassAccounts (r:0 w:1)
+	fn remove_bypass() -> Weight {
+		Weight::from_ref_time(16_243_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmergencyShutdown BypassAccounts (r:1 w:0)
+	// Storage: EmergencyShutdown Bypassing (r:0 w:1)
+	fn dispatch_bypass() -> Weight {
+		Weight::from_ref_time(24_880_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmergencyShutdown TrippedBreakers (r:1 w:0)
+	// Storage: EmergencyShutdown BreakerCalls (r:0 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn set_breaker_calls(n: u32, ) -> Weight {
+		Weight::from_ref_time(17_362_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(96_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmergencyShutdown PendingReviews (r:1 w:1)
+	// Storage: EmergencyShutdown TrippedBreakers (r:0 w:1)
+	fn review_breaker() -> Weight {
+		Weight::from_ref_time(20_654_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: EmergencyShutdown FilterProfiles (r:0 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn set_profile_rules(n: u32, ) -> Weight {
+		Weight::from_ref_time(16_904_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(92_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmergencyShutdown ActiveProfile (r:0 w:1)
+	fn switch_profile() -> Weight {
+		Weight::from_ref_time(15_318_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+}
@@ -0,0 +1,81 @@
+
+//! Weights for `pallet_evm_precompile_assets_erc20`
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_evm_precompile_assets_erc20
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=./runtime/kerria/src/weights/pallet_evm_precompile_assets_erc20.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_evm_precompile_assets_erc20`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_evm_precompile_assets_erc20::WeightInfo for WeightInfo<T> {
+	// Storage: Assets Asset (r:1 w:0)
+	fn total_supply() -> Weight {
+		Weight::from_ref_time(7_268_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Account (r:1 w:0)
+	fn balance_of() -> Weight {
+		Weight::from_ref_time(9_651_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Approvals (r:1 w:0)
+	fn allowance() -> Weight {
+		Weight::from_ref_time(10_042_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	fn name() -> Weight {
+		Weight::from_ref_time(8_793_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	fn symbol() -> Weight {
+		Weight::from_ref_time(8_748_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	fn decimals() -> Weight {
+		Weight::from_ref_time(8_497_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	fn minimum_balance() -> Weight {
+		Weight::from_ref_time(7_391_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Erc20Instance0Assets Nonces (r:1 w:0)
+	fn nonces() -> Weight {
+		Weight::from_ref_time(8_162_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	fn domain_separator() -> Weight {
+		Weight::from_ref_time(13_529_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+	}
+}
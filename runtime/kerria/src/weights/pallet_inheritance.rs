@@ -0,0 +1,87 @@
+//! Weights for `pallet_inheritance`
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=kerria-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_inheritance
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=./runtime/kerria/src/weights/pallet_inheritance.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions for `pallet_inheritance`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_inheritance::WeightInfo for WeightInfo<T> {
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_switch() -> Weight {
+		Weight::from_ref_time(38_412_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_switch() -> Weight {
+		Weight::from_ref_time(36_905_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn heartbeat() -> Weight {
+		Weight::from_ref_time(24_118_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn poke() -> Weight {
+		Weight::from_ref_time(25_037_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn initiate_claim() -> Weight {
+		Weight::from_ref_time(26_774_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	fn finalize_claim() -> Weight {
+		Weight::from_ref_time(26_203_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Inheritance Switches (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	fn as_owner() -> Weight {
+		Weight::from_ref_time(21_646_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+	}
+}
@@ -4,6 +4,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2022-05-30, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("kerria-dev"), DB CACHE: 1024
+//!
+//! The weights marked NOT BENCHMARKED YET were added by hand from the storage accesses of
+//! their calls and are estimates until this file is regenerated.
 
 // Executed Command:
 // ./target/release/parallel
@@ -25,6 +28,10 @@
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
 
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
 /// Weight functions for `pallet_loans`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
@@ -36,6 +43,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn add_market() -> Weight {
 		Weight::from_ref_time(59_698_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -43,6 +51,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn activate_market() -> Weight {
 		Weight::from_ref_time(42_270_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -50,6 +59,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn update_rate_model() -> Weight {
 		Weight::from_ref_time(43_240_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -57,6 +67,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn update_market() -> Weight {
 		Weight::from_ref_time(44_499_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -65,6 +76,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn force_update_market() -> Weight {
 		Weight::from_ref_time(55_755_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -72,6 +84,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn add_reward() -> Weight {
 		Weight::from_ref_time(98_504_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -79,6 +92,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn withdraw_missing_reward() -> Weight {
 		Weight::from_ref_time(79_763_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -90,6 +104,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn update_market_reward_speed() -> Weight {
 		Weight::from_ref_time(86_340_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -110,6 +125,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn claim_reward() -> Weight {
 		Weight::from_ref_time(229_202_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(16 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -129,6 +145,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn claim_reward_for_market() -> Weight {
 		Weight::from_ref_time(211_484_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(14 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -150,6 +167,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn mint() -> Weight {
 		Weight::from_ref_time(244_041_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(18 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(18 as u64))
 			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -174,6 +192,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn borrow() -> Weight {
 		Weight::from_ref_time(341_042_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(24 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(24 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -194,6 +213,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn redeem() -> Weight {
 		Weight::from_ref_time(253_173_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(17 as u64))
 			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -215,6 +235,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn redeem_all() -> Weight {
 		Weight::from_ref_time(272_126_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(18 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(18 as u64))
 			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -233,6 +254,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn repay_borrow() -> Weight {
 		Weight::from_ref_time(222_396_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(15 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -251,6 +273,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn repay_borrow_all() -> Weight {
 		Weight::from_ref_time(218_486_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(15 as u64))
 			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -259,6 +282,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn collateral_asset() -> Weight {
 		Weight::from_ref_time(72_119_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -286,6 +310,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn liquidate_borrow() -> Weight {
 		Weight::from_ref_time(631_581_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(40 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(40 as u64))
 			.saturating_add(T::DbWeight::get().writes(20 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -297,6 +322,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn add_reserves() -> Weight {
 		Weight::from_ref_time(140_000_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(8 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -307,6 +333,7 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn reduce_reserves() -> Weight {
 		Weight::from_ref_time(126_542_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(7 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
@@ -314,6 +341,229 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 	fn update_liquidation_free_collateral() -> Weight {
 		Weight::from_ref_time(37_414_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans LastAccruedInterestBlock (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:0)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans UtilizationRatio (r:0 w:1)
+	// Storage: Loans BorrowRate (r:0 w:1)
+	// Storage: Loans SupplyRate (r:0 w:1)
+	// Storage: Loans ExchangeRate (r:0 w:1)
+	fn accrue_market_interest() -> Weight {
+		Weight::from_ref_time(146_530_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(14 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans RewardSupplyState (r:1 w:1)
+	// Storage: Loans RewardBorrowState (r:1 w:1)
+	// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	// Storage: Loans TotalSupply (r:1 w:0)
+	// Storage: Loans TotalBorrows (r:1 w:0)
+	// Storage: Loans BorrowIndex (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn update_market_reward_indexes() -> Weight {
+		Weight::from_ref_time(118_271_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:1)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans RewardSupplyState (r:1 w:1)
+	// Storage: Loans RewardBorrowState (r:1 w:1)
+	// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans AccountBorrows (r:50 w:50)
+	// Storage: Loans RewardBorrowerIndex (r:50 w:50)
+	// Storage: Loans AccountDeposits (r:50 w:50)
+	// Storage: Loans AccountEarned (r:0 w:50)
+	// Storage: Loans RewardSupplierIndex (r:50 w:50)
+	// Storage: Loans RewardAccrued (r:50 w:50)
+	// Storage: Assets Account (r:2 w:2)
+	/// The range of component `n` is `[1, 50]`.
+	fn write_off_dust_positions(n: u32, ) -> Weight {
+		Weight::from_ref_time(64_912_000 as u64)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(41_775_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(15 as u64))
+			.saturating_add(T::DbWeight::get().reads((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:1)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans RewardSupplyState (r:1 w:1)
+	// Storage: Loans RewardBorrowState (r:1 w:1)
+	// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Loans DustSweepCursor (r:1 w:1)
+	// Storage: Loans AccountDeposits (r:51 w:50)
+	// Storage: Loans AccountBorrows (r:51 w:50)
+	// Storage: Loans RewardBorrowerIndex (r:50 w:50)
+	// Storage: Loans AccountEarned (r:0 w:50)
+	// Storage: Loans RewardSupplierIndex (r:50 w:50)
+	// Storage: Loans RewardAccrued (r:50 w:50)
+	// Storage: Assets Account (r:2 w:2)
+	/// The range of component `n` is `[1, 50]`.
+	fn sweep_dust_positions(n: u32, ) -> Weight {
+		Weight::from_ref_time(69_340_000 as u64)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(44_018_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(18 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(18 as u64))
+			.saturating_add(T::DbWeight::get().reads((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans Markets (r:1 w:0)
+	// Storage: Loans AdapterCollaterals (r:0 w:1)
+	fn set_adapter_collateral() -> Weight {
+		Weight::from_ref_time(25_978_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans AdapterCollaterals (r:1 w:0)
+	// Storage: Loans AccountAdapterCollaterals (r:1 w:1)
+	fn deposit_adapter_collateral() -> Weight {
+		Weight::from_ref_time(35_007_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Loans AdapterCollaterals (r:2 w:0)
+	// Storage: Loans AccountAdapterCollaterals (r:2 w:1)
+	// Storage: Loans Markets (r:6 w:0)
+	// Storage: Prices EmergencyPrice (r:2 w:0)
+	// Storage: Loans AccountBorrows (r:5 w:0)
+	// Storage: Loans BorrowIndex (r:1 w:0)
+	// Storage: Loans AccountDeposits (r:5 w:0)
+	// Storage: Loans LiquidationFreeCollaterals (r:1 w:0)
+	fn withdraw_adapter_collateral() -> Weight {
+		Weight::from_ref_time(112_277_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(25 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(25 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// NOT BENCHMARKED YET
+	// Storage: Loans AccountRiskSettings (r:0 w:1)
+	fn set_risk_settings() -> Weight {
+		Weight::from_ref_time(19_473_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// NOT BENCHMARKED YET
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans AccountBorrowLimits (r:0 w:1)
+	fn set_borrow_limit() -> Weight {
+		Weight::from_ref_time(27_841_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans MarketProposals (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans UnderlyingAssetId (r:1 w:0)
+	fn propose_market() -> Weight {
+		Weight::from_ref_time(38_212_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans MarketProposals (r:1 w:1)
+	fn veto_market() -> Weight {
+		Weight::from_ref_time(21_530_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans CollateralClasses (r:0 w:1)
+	fn set_collateral_class() -> Weight {
+		Weight::from_ref_time(18_904_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans DebtCeilings (r:0 w:1)
+	fn set_debt_ceiling() -> Weight {
+		Weight::from_ref_time(18_517_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Loans Markets (r:2 w:0)
+	// Storage: Loans AdapterCollaterals (r:1 w:0)
+	// Storage: Loans CollateralClasses (r:1 w:0)
+	// Storage: Loans AccountDeposits (r:1 w:0)
+	// Storage: Loans ExchangeRate (r:1 w:0)
+	// Storage: Prices EmergencyPrice (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:0)
+	// Storage: Loans AccountBorrows (r:1 w:0)
+	// Storage: Loans BorrowIndex (r:1 w:0)
+	// Storage: Loans AccountClassDebts (r:4 w:1)
+	// Storage: Loans ClassDebts (r:1 w:1)
+	fn refresh_class_debts(n: u32, ) -> Weight {
+		Weight::from_ref_time(24_530_000 as u64)
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(41_206_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((12 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul((12 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Loans RateModelTransitions (r:1 w:1)
+	// Storage: Loans Markets (r:2 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	// Storage: Loans TotalSupply (r:1 w:0)
+	// Storage: Loans TotalBorrows (r:1 w:1)
+	// Storage: Loans TotalReserves (r:1 w:1)
+	// Storage: Loans BorrowIndex (r:1 w:1)
+	// Storage: Loans UtilizationRatio (r:0 w:1)
+	// Storage: Loans BorrowRate (r:0 w:1)
+	// Storage: Loans SupplyRate (r:0 w:1)
+	// Storage: Loans ExchangeRate (r:0 w:1)
+	fn transition_rate_model() -> Weight {
+		Weight::from_ref_time(64_318_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+	}
 }
@@ -0,0 +1,29 @@
+//! Weights for `pallet_zap`
+//!
+//! Zap extrinsics only chain calls into other pallets, so their weights are
+//! composed from this runtime's `pallet_loans` and `pallet_liquid_staking`
+//! benchmark results instead of being benchmarked separately.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::weights::Weight;
+use sp_std::marker::PhantomData;
+
+type LiquidStakingWeight<T> = super::pallet_liquid_staking::WeightInfo<T>;
+type LoansWeight<T> = super::pallet_loans::WeightInfo<T>;
+
+/// Weight functions for `pallet_zap`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_zap::WeightInfo for WeightInfo<T> {
+	fn stake_and_supply() -> Weight {
+		<LiquidStakingWeight<T> as pallet_liquid_staking::WeightInfo>::stake()
+			.saturating_add(<LoansWeight<T> as pallet_loans::WeightInfo>::mint())
+			.saturating_add(<LoansWeight<T> as pallet_loans::WeightInfo>::collateral_asset())
+	}
+	fn supply_and_borrow() -> Weight {
+		<LoansWeight<T> as pallet_loans::WeightInfo>::mint()
+			.saturating_add(<LoansWeight<T> as pallet_loans::WeightInfo>::collateral_asset())
+			.saturating_add(<LoansWeight<T> as pallet_loans::WeightInfo>::borrow())
+	}
+}
@@ -0,0 +1,90 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Referenda and conviction voting, organised in tracks by the origin a proposal
+//! is dispatched with.
+
+use super::*;
+use frame_support::traits::EitherOf;
+use frame_system::{EnsureRootWithSuccess, EnsureSigned};
+
+mod migrations;
+mod origins;
+mod tracks;
+
+pub use migrations::MigrateDemocracyLocks;
+pub use origins::{pallet_custom_origins, MarketAdmin, Spender, Treasurer, WhitelistedCaller};
+pub use tracks::TracksInfo;
+
+parameter_types! {
+    pub const VoteLockingPeriod: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_conviction_voting::Config for Runtime {
+    type WeightInfo = pallet_conviction_voting::weights::SubstrateWeight<Runtime>;
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Polls = Referenda;
+    type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
+    type MaxVotes = ConstU32<512>;
+    type VoteLockingPeriod = VoteLockingPeriod;
+}
+
+parameter_types! {
+    pub const AlarmInterval: BlockNumber = 1;
+    pub const SubmissionDeposit: Balance = 10 * DOLLARS;
+    pub const UndecidingTimeout: BlockNumber = 14 * DAYS;
+    pub const MaxBalance: Balance = Balance::max_value();
+}
+
+/// Treasury spends are capped by the origin they are approved with
+pub type TreasurySpender = EitherOf<EnsureRootWithSuccess<AccountId, MaxBalance>, Spender>;
+
+impl pallet_custom_origins::Config for Runtime {}
+
+/// A majority of the technical committee can whitelist calls, which can then be
+/// dispatched as root by the whitelisted caller track
+pub type WhitelistOrigin = EitherOfDiverse<
+    EnsureRoot<AccountId>,
+    pallet_collective::EnsureProportionMoreThan<AccountId, TechnicalCollective, 1, 2>,
+>;
+
+impl pallet_whitelist::Config for Runtime {
+    type WeightInfo = pallet_whitelist::weights::SubstrateWeight<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type WhitelistOrigin = WhitelistOrigin;
+    type DispatchWhitelistedOrigin = EitherOf<EnsureRoot<AccountId>, WhitelistedCaller>;
+    type Preimages = Preimage;
+}
+
+impl pallet_referenda::Config for Runtime {
+    type WeightInfo = pallet_referenda::weights::SubstrateWeight<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Scheduler = Scheduler;
+    type Currency = Balances;
+    type SubmitOrigin = EnsureSigned<AccountId>;
+    type CancelOrigin = EnsureRootOrAllTechnicalCommittee;
+    type KillOrigin = EnsureRootOrAllTechnicalCommittee;
+    type Slash = Treasury;
+    type Votes = pallet_conviction_voting::VotesOf<Runtime>;
+    type Tally = pallet_conviction_voting::TallyOf<Runtime>;
+    type SubmissionDeposit = SubmissionDeposit;
+    type MaxQueued = ConstU32<100>;
+    type UndecidingTimeout = UndecidingTimeout;
+    type AlarmInterval = AlarmInterval;
+    type Tracks = TracksInfo;
+    type Preimages = Preimage;
+}
@@ -0,0 +1,122 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Track configurations for governance.
+
+use super::*;
+
+const fn percent(x: i32) -> sp_runtime::FixedI64 {
+    sp_runtime::FixedI64::from_rational(x as u128, 100)
+}
+
+/// Track of the referenda dispatched as root
+pub const ROOT_TRACK: u16 = 0;
+
+use pallet_referenda::Curve;
+const APP_ROOT: Curve = Curve::make_reciprocal(4, 14, percent(80), percent(50), percent(100));
+const SUP_ROOT: Curve = Curve::make_linear(14, 14, percent(0), percent(50));
+const APP_WHITELISTED_CALLER: Curve =
+    Curve::make_reciprocal(16, 28 * 24, percent(96), percent(50), percent(100));
+const SUP_WHITELISTED_CALLER: Curve =
+    Curve::make_reciprocal(1, 28, percent(20), percent(5), percent(50));
+const APP_TREASURER: Curve = Curve::make_reciprocal(4, 14, percent(80), percent(50), percent(100));
+const SUP_TREASURER: Curve = Curve::make_linear(14, 14, percent(0), percent(50));
+const APP_MARKET_ADMIN: Curve = Curve::make_linear(7, 7, percent(50), percent(100));
+const SUP_MARKET_ADMIN: Curve = Curve::make_reciprocal(5, 7, percent(1), percent(0), percent(50));
+
+const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 4] = [
+    (
+        ROOT_TRACK,
+        pallet_referenda::TrackInfo {
+            name: "root",
+            max_deciding: 1,
+            decision_deposit: 10_000 * DOLLARS,
+            prepare_period: 2 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: 1 * DAYS,
+            min_enactment_period: 1 * DAYS,
+            min_approval: APP_ROOT,
+            min_support: SUP_ROOT,
+        },
+    ),
+    (
+        1,
+        pallet_referenda::TrackInfo {
+            name: "whitelisted_caller",
+            max_deciding: 10,
+            decision_deposit: 1_000 * DOLLARS,
+            prepare_period: 30 * MINUTES,
+            decision_period: 14 * DAYS,
+            confirm_period: 10 * MINUTES,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_WHITELISTED_CALLER,
+            min_support: SUP_WHITELISTED_CALLER,
+        },
+    ),
+    (
+        2,
+        pallet_referenda::TrackInfo {
+            name: "treasurer",
+            max_deciding: 10,
+            decision_deposit: 1_000 * DOLLARS,
+            prepare_period: 2 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 1 * DAYS,
+            min_approval: APP_TREASURER,
+            min_support: SUP_TREASURER,
+        },
+    ),
+    (
+        // Market parameters may need to follow the market quickly, hence the shorter periods
+        3,
+        pallet_referenda::TrackInfo {
+            name: "market_admin",
+            max_deciding: 10,
+            decision_deposit: 500 * DOLLARS,
+            prepare_period: 1 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: APP_MARKET_ADMIN,
+            min_support: SUP_MARKET_ADMIN,
+        },
+    ),
+];
+
+pub struct TracksInfo;
+impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
+    type Id = u16;
+    type RuntimeOrigin = <RuntimeOrigin as frame_support::traits::OriginTrait>::PalletsOrigin;
+    fn tracks() -> &'static [(Self::Id, pallet_referenda::TrackInfo<Balance, BlockNumber>)] {
+        &TRACKS_DATA[..]
+    }
+    fn track_for(id: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+        if let Ok(system_origin) = frame_system::RawOrigin::try_from(id.clone()) {
+            match system_origin {
+                frame_system::RawOrigin::Root => Ok(ROOT_TRACK),
+                _ => Err(()),
+            }
+        } else if let Ok(custom_origin) = origins::Origin::try_from(id.clone()) {
+            match custom_origin {
+                origins::Origin::WhitelistedCaller => Ok(1),
+                origins::Origin::Treasurer => Ok(2),
+                origins::Origin::MarketAdmin => Ok(3),
+            }
+        } else {
+            Err(())
+        }
+    }
+}
+pallet_referenda::impl_tracksinfo_get!(TracksInfo, Balance, BlockNumber);
@@ -1,17 +1,33 @@
 pub mod frame_system;
 pub mod pallet_amm;
 pub mod pallet_asset_registry;
+pub mod pallet_asset_treasury;
 pub mod pallet_balances;
 pub mod pallet_bridge;
+pub mod pallet_collator_rewards;
 pub mod pallet_crowdloans;
+pub mod pallet_currency_adapter;
+pub mod pallet_emergency_shutdown;
 pub mod pallet_farming;
+pub mod pallet_fee_distribution;
+pub mod pallet_grant_vesting;
+pub mod pallet_inheritance;
+pub mod pallet_keepers;
 pub mod pallet_liquid_staking;
 pub mod pallet_loans;
 pub mod pallet_membership;
+pub mod pallet_nominee_election;
+pub mod pallet_parameters;
+pub mod pallet_referrals;
+pub mod pallet_relay_era;
+pub mod pallet_remote_derivatives;
+pub mod pallet_revenue;
+pub mod pallet_scheduled_calls;
 pub mod pallet_router;
 pub mod pallet_streaming;
 pub mod pallet_timestamp;
 pub mod pallet_xcm_helper;
+pub mod pallet_zap;
 pub mod cumulus_pallet_xcmp_queue;
 pub mod orml_oracle;
 pub mod orml_vesting;
@@ -19,4 +35,4 @@ pub mod pallet_assets;
 pub mod pallet_identity;
 pub mod pallet_preimage;
 pub mod pallet_treasury;
-pub mod pallet_utility;
\ No newline at end of file
+pub mod pallet_utility;
@@ -0,0 +1,307 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Chain extension exposing Parallel's money markets, AMM, oracle prices and liquid staking
+//! to ink! contracts.
+//!
+//! State changing functions are dispatched as the runtime calls of the corresponding pallets
+//! with the contract account as the signed origin, so they go through the same call filter
+//! and weight accounting as extrinsics. Queries are answered straight from the pallets and
+//! written back SCALE encoded.
+//!
+//! Every function returns a status code to the contract instead of trapping, so a failed
+//! borrow or swap can be handled on the ink! side.
+
+use codec::{Decode, Encode};
+use frame_support::{
+    dispatch::{DispatchError, GetDispatchInfo},
+    log,
+    traits::Get,
+};
+use frame_system::RawOrigin;
+use pallet_contracts::chain_extension::{
+    BufInBufOutState, ChainExtension, Environment, Ext, InitState, RetVal, SysConfig, UncheckedFrom,
+};
+use pallet_liquid_staking::types::UnstakeProvider;
+use pallet_traits::PriceFeeder;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::traits::Dispatchable;
+use sp_std::vec::Vec;
+
+use crate::{LiquidStaking, Loans, Prices, Runtime, RuntimeCall, RuntimeOrigin, AMM};
+
+/// The call went through, queries have written their result to the output buffer
+pub const SUCCESS: u32 = 0;
+/// The runtime call was rejected by the filter or failed in the pallet
+pub const DISPATCH_FAILED: u32 = 1;
+/// The query has no answer, e.g. no price is fed for the asset or the pool does not exist
+pub const QUERY_FAILED: u32 = 2;
+
+/// `(asset_id, mint_amount)`
+pub const LOANS_MINT: u16 = 0x0101;
+/// `(asset_id, redeem_amount)`
+pub const LOANS_REDEEM: u16 = 0x0102;
+/// `(asset_id, borrow_amount)`
+pub const LOANS_BORROW: u16 = 0x0103;
+/// `(asset_id, repay_amount)`
+pub const LOANS_REPAY_BORROW: u16 = 0x0104;
+/// `(asset_id, enable)`
+pub const LOANS_COLLATERAL_ASSET: u16 = 0x0105;
+/// `account` => `(liquidity, shortfall, lf_liquidity, lf_shortfall)`
+pub const LOANS_ACCOUNT_LIQUIDITY: u16 = 0x0106;
+/// `(route, amount_in, min_amount_out)`
+pub const AMM_SWAP_EXACT_TOKENS_FOR_TOKENS: u16 = 0x0201;
+/// `(route, amount_out, max_amount_in)`
+pub const AMM_SWAP_TOKENS_FOR_EXACT_TOKENS: u16 = 0x0202;
+/// `(pair, asset_in, amount_in)` => `SwapQuote`
+pub const AMM_QUOTE_AMOUNT_OUT: u16 = 0x0203;
+/// `(pair, asset_out, amount_out)` => `SwapQuote`
+pub const AMM_QUOTE_AMOUNT_IN: u16 = 0x0204;
+/// `asset_id` => `(price, timestamp)`
+pub const PRICES_GET_PRICE: u16 = 0x0301;
+/// `amount`
+pub const LIQUID_STAKING_STAKE: u16 = 0x0401;
+/// `(liquid_amount, unstake_provider)`
+pub const LIQUID_STAKING_UNSTAKE: u16 = 0x0402;
+/// `()` => `exchange_rate`
+pub const LIQUID_STAKING_EXCHANGE_RATE: u16 = 0x0403;
+
+#[derive(Default)]
+pub struct ParallelChainExtension;
+
+impl ChainExtension<Runtime> for ParallelChainExtension {
+    fn call<E>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+    where
+        E: Ext<T = Runtime>,
+        <E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+    {
+        let func_id = env.func_id();
+        let mut env = env.buf_in_buf_out();
+        match func_id {
+            LOANS_MINT => {
+                let (asset_id, mint_amount): (CurrencyId, Balance) = read(&mut env)?;
+                dispatch(
+                    &mut env,
+                    pallet_loans::Call::mint {
+                        asset_id,
+                        mint_amount,
+                    }
+                    .into(),
+                )
+            }
+            LOANS_REDEEM => {
+                let (asset_id, redeem_amount): (CurrencyId, Balance) = read(&mut env)?;
+                dispatch(
+                    &mut env,
+                    pallet_loans::Call::redeem {
+                        asset_id,
+                        redeem_amount,
+                    }
+                    .into(),
+                )
+            }
+            LOANS_BORROW => {
+                let (asset_id, borrow_amount): (CurrencyId, Balance) = read(&mut env)?;
+                dispatch(
+                    &mut env,
+                    pallet_loans::Call::borrow {
+                        asset_id,
+                        borrow_amount,
+                    }
+                    .into(),
+                )
+            }
+            LOANS_REPAY_BORROW => {
+                let (asset_id, repay_amount): (CurrencyId, Balance) = read(&mut env)?;
+                dispatch(
+                    &mut env,
+                    pallet_loans::Call::repay_borrow {
+                        asset_id,
+                        repay_amount,
+                    }
+                    .into(),
+                )
+            }
+            LOANS_COLLATERAL_ASSET => {
+                let (asset_id, enable): (CurrencyId, bool) = read(&mut env)?;
+                dispatch(
+                    &mut env,
+                    pallet_loans::Call::collateral_asset { asset_id, enable }.into(),
+                )
+            }
+            LOANS_ACCOUNT_LIQUIDITY => {
+                let account: <Runtime as SysConfig>::AccountId = read(&mut env)?;
+                // Prices and deposits of every market the account entered are read
+                let markets = pallet_loans::Markets::<Runtime>::iter_keys().count() as u64;
+                charge_reads(&mut env, markets.saturating_mul(4).saturating_add(1))?;
+                respond(&mut env, Loans::get_account_liquidity(&account).ok())
+            }
+            AMM_SWAP_EXACT_TOKENS_FOR_TOKENS => {
+                let (route, amount_in, min_amount_out): (Vec<CurrencyId>, Balance, Balance) =
+                    read(&mut env)?;
+                dispatch(
+                    &mut env,
+                    pallet_router::Call::swap_exact_tokens_for_tokens {
+                        route,
+                        amount_in,
+                        min_amount_out,
+                    }
+                    .into(),
+                )
+            }
+            AMM_SWAP_TOKENS_FOR_EXACT_TOKENS => {
+                let (route, amount_out, max_amount_in): (Vec<CurrencyId>, Balance, Balance) =
+                    read(&mut env)?;
+                dispatch(
+                    &mut env,
+                    pallet_router::Call::swap_tokens_for_exact_tokens {
+                        route,
+                        amount_out,
+                        max_amount_in,
+                    }
+                    .into(),
+                )
+            }
+            AMM_QUOTE_AMOUNT_OUT => {
+                let (pair, asset_in, amount_in): ((CurrencyId, CurrencyId), CurrencyId, Balance) =
+                    read(&mut env)?;
+                charge_reads(&mut env, 2)?;
+                respond(
+                    &mut env,
+                    AMM::quote_amount_out(pair, asset_in, amount_in).ok(),
+                )
+            }
+            AMM_QUOTE_AMOUNT_IN => {
+                let (pair, asset_out, amount_out): ((CurrencyId, CurrencyId), CurrencyId, Balance) =
+                    read(&mut env)?;
+                charge_reads(&mut env, 2)?;
+                respond(
+                    &mut env,
+                    AMM::quote_amount_in(pair, asset_out, amount_out).ok(),
+                )
+            }
+            PRICES_GET_PRICE => {
+                let asset_id: CurrencyId = read(&mut env)?;
+                charge_reads(&mut env, 3)?;
+                respond(&mut env, Prices::get_price(&asset_id))
+            }
+            LIQUID_STAKING_STAKE => {
+                let amount: Balance = read(&mut env)?;
+                dispatch(
+                    &mut env,
+                    pallet_liquid_staking::Call::stake { amount }.into(),
+                )
+            }
+            LIQUID_STAKING_UNSTAKE => {
+                let (liquid_amount, unstake_provider): (Balance, UnstakeProvider) = read(&mut env)?;
+                dispatch(
+                    &mut env,
+                    pallet_liquid_staking::Call::unstake {
+                        liquid_amount,
+                        unstake_provider,
+                    }
+                    .into(),
+                )
+            }
+            LIQUID_STAKING_EXCHANGE_RATE => {
+                charge_reads(&mut env, 1)?;
+                respond(&mut env, Some(LiquidStaking::exchange_rate()))
+            }
+            _ => {
+                log::error!(
+                    target: "runtime::contracts",
+                    "Called an unregistered func_id: {:?}",
+                    func_id
+                );
+                Err(DispatchError::Other("Unimplemented func_id"))
+            }
+        }
+    }
+
+    fn enabled() -> bool {
+        true
+    }
+}
+
+fn read<E, T>(env: &mut Environment<E, BufInBufOutState>) -> Result<T, DispatchError>
+where
+    E: Ext<T = Runtime>,
+    T: Decode,
+{
+    let len = env.in_len();
+    env.read_as_unbounded(len)
+}
+
+fn charge_reads<E>(
+    env: &mut Environment<E, BufInBufOutState>,
+    reads: u64,
+) -> Result<(), DispatchError>
+where
+    E: Ext<T = Runtime>,
+{
+    env.charge_weight(<Runtime as SysConfig>::DbWeight::get().reads(reads))?;
+    Ok(())
+}
+
+/// Write the answer of a query to the output buffer, nothing is written when there is none
+fn respond<E, R>(
+    env: &mut Environment<E, BufInBufOutState>,
+    response: Option<R>,
+) -> Result<RetVal, DispatchError>
+where
+    E: Ext<T = Runtime>,
+    R: Encode,
+{
+    match response {
+        Some(response) => {
+            env.write(&response.encode(), false, None)?;
+            Ok(RetVal::Converging(SUCCESS))
+        }
+        None => Ok(RetVal::Converging(QUERY_FAILED)),
+    }
+}
+
+/// Dispatch `call` on behalf of the contract, charging its declared weight up front and
+/// refunding whatever the call reports it did not use
+fn dispatch<E>(
+    env: &mut Environment<E, BufInBufOutState>,
+    call: RuntimeCall,
+) -> Result<RetVal, DispatchError>
+where
+    E: Ext<T = Runtime>,
+    <E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+{
+    let charged = env.charge_weight(call.get_dispatch_info().weight)?;
+    let origin: RuntimeOrigin = RawOrigin::Signed(env.ext().address().clone()).into();
+    let result = call.dispatch(origin);
+    let post_info = match &result {
+        Ok(post_info) => *post_info,
+        Err(err) => err.post_info,
+    };
+    if let Some(actual_weight) = post_info.actual_weight {
+        env.adjust_weight(charged, actual_weight);
+    }
+
+    match result {
+        Ok(_) => Ok(RetVal::Converging(SUCCESS)),
+        Err(err) => {
+            log::debug!(
+                target: "runtime::contracts",
+                "Chain extension call failed: {:?}",
+                err.error
+            );
+            Ok(RetVal::Converging(DISPATCH_FAILED))
+        }
+    }
+}
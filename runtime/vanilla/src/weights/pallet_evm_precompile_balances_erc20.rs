@@ -0,0 +1,79 @@
+
+//! Weights for `pallet_evm_precompile_balances_erc20`
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=vanilla-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_evm_precompile_balances_erc20
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=./runtime/vanilla/src/weights/pallet_evm_precompile_balances_erc20.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_evm_precompile_balances_erc20`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_evm_precompile_balances_erc20::WeightInfo for WeightInfo<T> {
+	// Storage: Balances TotalIssuance (r:1 w:0)
+	fn total_supply() -> Weight {
+		Weight::from_ref_time(3_287_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Storage: System Account (r:1 w:0)
+	fn balance_of() -> Weight {
+		Weight::from_ref_time(5_714_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Storage: Erc20Instance0Balances Approves (r:1 w:0)
+	fn allowance() -> Weight {
+		Weight::from_ref_time(6_003_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Storage: Erc20Instance0Balances Approves (r:0 w:1)
+	fn approve() -> Weight {
+		Weight::from_ref_time(8_761_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn name() -> Weight {
+		Weight::from_ref_time(1_174_000 as u64)
+	}
+	fn symbol() -> Weight {
+		Weight::from_ref_time(1_152_000 as u64)
+	}
+	fn decimals() -> Weight {
+		Weight::from_ref_time(1_039_000 as u64)
+	}
+	// Storage: System Account (r:1 w:0)
+	fn withdraw() -> Weight {
+		Weight::from_ref_time(5_781_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Erc20Instance0Balances Nonces (r:1 w:1)
+	// Storage: Erc20Instance0Balances Approves (r:0 w:1)
+	fn permit() -> Weight {
+		Weight::from_ref_time(53_864_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Erc20Instance0Balances Nonces (r:1 w:0)
+	fn nonces() -> Weight {
+		Weight::from_ref_time(4_598_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	fn domain_separator() -> Weight {
+		Weight::from_ref_time(9_107_000 as u64)
+	}
+}
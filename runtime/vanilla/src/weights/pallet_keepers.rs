@@ -0,0 +1,78 @@
+//! Weights for `pallet_keepers`
+//!
+//! NOT BENCHMARKED YET: the execution times below are estimates from the storage
+//! accesses of each call. Regenerate this file on reference hardware with:
+
+// ./target/release/parallel
+// benchmark
+// pallet
+// --chain=vanilla-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_keepers
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --output=./runtime/vanilla/src/weights/pallet_keepers.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Proof size counted for every storage read: a trie path of four branch nodes and
+/// the value. The benchmark CLI of polkadot-v0.9.32 doesn't record storage proofs.
+const READ_PROOF_SIZE: u64 = 2_048;
+
+/// Weight functions for `pallet_keepers`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_keepers::WeightInfo for WeightInfo<T> {
+	// Storage: Keepers Keepers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn register_keeper() -> Weight {
+		Weight::from_ref_time(36_817_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Keepers Keepers (r:1 w:1)
+	fn unregister_keeper() -> Weight {
+		Weight::from_ref_time(22_196_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Keepers Keepers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn withdraw_bond() -> Weight {
+		Weight::from_ref_time(35_251_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Keepers Keepers (r:2 w:1)
+	// Storage: Keepers TaskConfigs (r:5 w:0)
+	// Storage: Keepers LastPerformed (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn perform_task() -> Weight {
+		Weight::from_ref_time(51_841_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Keepers TaskConfigs (r:0 w:1)
+	fn set_task_config() -> Weight {
+		Weight::from_ref_time(14_401_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Keepers Keepers (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn slash_keeper() -> Weight {
+		Weight::from_ref_time(40_342_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(Weight::from_proof_size(READ_PROOF_SIZE).saturating_mul(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+}
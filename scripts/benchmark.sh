@@ -37,6 +37,18 @@ pallets=(
   pallet_preimage
   pallet_scheduler
   pallet_treasury
+  pallet_asset_treasury
+  pallet_collator_rewards
+  pallet_currency_adapter
+  pallet_emergency_shutdown
+  pallet_fee_distribution
+  pallet_grant_vesting
+  pallet_inheritance
+  pallet_parameters
+  pallet_relay_era
+  pallet_remote_derivatives
+  pallet_revenue
+  pallet_scheduled_calls
 )
 
 for p in ${pallets[@]}
@@ -50,6 +62,7 @@ do
 		--extrinsic='*' \
 		--steps=$steps \
 		--repeat=$repeat \
+		--template=./.maintain/runtime-weight-template.hbs \
 		--output=$parallelOutput/$p.rs
 
 	./target/release/parallel benchmark \
@@ -61,5 +74,6 @@ do
 		--extrinsic='*' \
 		--steps=$steps \
 		--repeat=$repeat \
+		--template=./.maintain/runtime-weight-template.hbs \
 		--output=$heikoOutput/$p.rs
 done
@@ -14,3 +14,5 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 pub mod math_helper;
+#[cfg(feature = "std")]
+pub mod snapshot;
@@ -0,0 +1,226 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! State snapshots of mock runtimes.
+//!
+//! A test suite which spent a lot of setup on its externalities (markets, pools,
+//! vaults...) can export them to a file, other suites load the file instead of
+//! rebuilding the same state. Keys are sorted so that the same state always
+//! produces the same file.
+
+use codec::{Decode, Encode};
+use sp_core::storage::{
+    well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX, ChildInfo, Storage, StorageChild,
+};
+use sp_io::TestExternalities;
+use sp_state_machine::Backend;
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+/// Bumped whenever the encoding of `Snapshot` changes
+const SNAPSHOT_VERSION: u8 = 1;
+
+type KeyValues = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// Every key value of some externalities, sorted by key
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]
+pub struct Snapshot {
+    /// Top trie, without the roots of the child tries
+    pub top: KeyValues,
+    /// Default child tries by their storage key
+    pub children: Vec<(Vec<u8>, KeyValues)>,
+}
+
+impl Snapshot {
+    /// Take a snapshot of `ext`, its pending changes are committed first
+    pub fn take(ext: &mut TestExternalities) -> Self {
+        ext.commit_all()
+            .expect("in memory backend never fails to commit; qed");
+        let backend = ext.as_backend();
+
+        let mut top = KeyValues::new();
+        let mut children = Vec::new();
+        for (key, value) in backend.pairs() {
+            match key.strip_prefix(DEFAULT_CHILD_STORAGE_KEY_PREFIX) {
+                Some(storage_key) => {
+                    let child_info = ChildInfo::new_default(storage_key);
+                    let mut pairs: KeyValues = backend
+                        .child_keys(&child_info, &[])
+                        .into_iter()
+                        .filter_map(|k| {
+                            backend
+                                .child_storage(&child_info, &k)
+                                .ok()
+                                .flatten()
+                                .map(|v| (k, v))
+                        })
+                        .collect();
+                    pairs.sort();
+                    children.push((storage_key.to_vec(), pairs));
+                }
+                None => top.push((key, value)),
+            }
+        }
+        top.sort();
+        children.sort();
+
+        Self { top, children }
+    }
+
+    /// Encoding of the snapshot read by `load`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        (SNAPSHOT_VERSION, self).encode()
+    }
+
+    /// Write the snapshot to `path`
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_bytes())
+    }
+
+    /// Read a snapshot written by `save`
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        let (version, snapshot) = <(u8, Self)>::decode(&mut &bytes[..])
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        if version != SNAPSHOT_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported snapshot version {}", version),
+            ));
+        }
+        Ok(snapshot)
+    }
+
+    /// Genesis storage holding the snapshot
+    pub fn into_storage(self) -> Storage {
+        Storage {
+            top: self.top.into_iter().collect::<BTreeMap<_, _>>(),
+            children_default: self
+                .children
+                .into_iter()
+                .map(|(storage_key, pairs)| {
+                    let child_info = ChildInfo::new_default(&storage_key);
+                    (
+                        storage_key,
+                        StorageChild {
+                            data: pairs.into_iter().collect(),
+                            child_info,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Externalities holding the snapshot
+    pub fn into_ext(self) -> TestExternalities {
+        TestExternalities::new(self.into_storage())
+    }
+
+    /// Write the snapshot on top of `ext`, keys absent from the snapshot are kept
+    pub fn apply(self, ext: &mut TestExternalities) {
+        for (key, value) in self.top {
+            ext.insert(key, value);
+        }
+        for (storage_key, pairs) in self.children {
+            let child_info = ChildInfo::new_default(&storage_key);
+            for (key, value) in pairs {
+                ext.insert_child(child_info.clone(), key, value);
+            }
+        }
+    }
+}
+
+/// Export the state of `ext` to `path`
+pub fn export(ext: &mut TestExternalities, path: impl AsRef<Path>) -> io::Result<()> {
+    Snapshot::take(ext).save(path)
+}
+
+/// Externalities holding the state exported to `path`
+pub fn import(path: impl AsRef<Path>) -> io::Result<TestExternalities> {
+    Snapshot::load(path).map(Snapshot::into_ext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("parallel-snapshot-{}-{}", name, std::process::id()))
+    }
+
+    fn ext() -> TestExternalities {
+        let mut ext = TestExternalities::default();
+        ext.execute_with(|| {
+            sp_io::storage::set(b"market", b"dot");
+            sp_io::storage::set(b"pool", b"dot-usdt");
+            sp_io::default_child_storage::set(b"vault", b"alice", b"100");
+        });
+        ext
+    }
+
+    #[test]
+    fn snapshot_should_be_reloaded() {
+        let path = path("reload");
+        export(&mut ext(), &path).unwrap();
+
+        import(&path).unwrap().execute_with(|| {
+            assert_eq!(sp_io::storage::get(b"market"), Some(b"dot".to_vec()));
+            assert_eq!(sp_io::storage::get(b"pool"), Some(b"dot-usdt".to_vec()));
+            assert_eq!(
+                sp_io::default_child_storage::get(b"vault", b"alice"),
+                Some(b"100".to_vec())
+            );
+        });
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn snapshot_should_be_deterministic() {
+        let (first, second) = (path("first"), path("second"));
+        export(&mut ext(), &first).unwrap();
+        export(&mut import(&first).unwrap(), &second).unwrap();
+
+        assert_eq!(fs::read(&first).unwrap(), fs::read(&second).unwrap());
+        fs::remove_file(first).unwrap();
+        fs::remove_file(second).unwrap();
+    }
+
+    #[test]
+    fn snapshot_should_be_applied_on_top() {
+        let mut base = TestExternalities::default();
+        base.execute_with(|| sp_io::storage::set(b"oracle", b"price"));
+        Snapshot::take(&mut ext()).apply(&mut base);
+
+        base.execute_with(|| {
+            assert_eq!(sp_io::storage::get(b"oracle"), Some(b"price".to_vec()));
+            assert_eq!(sp_io::storage::get(b"market"), Some(b"dot".to_vec()));
+            assert_eq!(
+                sp_io::default_child_storage::get(b"vault", b"alice"),
+                Some(b"100".to_vec())
+            );
+        });
+    }
+
+    #[test]
+    fn unknown_version_should_not_be_loaded() {
+        let path = path("version");
+        fs::write(&path, (SNAPSHOT_VERSION + 1, Snapshot::default()).encode()).unwrap();
+
+        assert_eq!(
+            Snapshot::load(&path).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        fs::remove_file(path).unwrap();
+    }
+}